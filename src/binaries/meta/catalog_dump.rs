@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::BufRead;
+
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_api::export_catalog;
+use common_meta_api::CatalogDump;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_sled_store::init_sled_db;
+use common_meta_store::MetaStoreProvider;
+use databend_meta::configs::Config;
+
+/// Prints the catalog dump of the running cluster at `--grpc-api-address` to stdout.
+pub async fn export_catalog_to_stdout(conf: &Config) -> Result<()> {
+    let rpc_conf = RpcClientConf {
+        address: conf.grpc_api_address.clone(),
+        username: conf.username.clone(),
+        password: conf.password.clone(),
+        ..Default::default()
+    };
+    let client = MetaStoreProvider::new(rpc_conf).create_meta_store().await?;
+
+    for line in export_catalog(&client).await? {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Imports the catalog dump from stdin into the state machine in `--raft-dir`.
+///
+/// The dir has to be a fresh one, the node is started with `--single` after the import, and the
+/// other nodes join it to form the new cluster.
+pub async fn import_catalog_from_stdin(conf: &Config) -> Result<()> {
+    let lines = io::stdin().lock().lines().collect::<io::Result<Vec<_>>>()?;
+    let dump = CatalogDump::parse(lines)?;
+
+    init_sled_db(conf.raft_config.raft_dir.clone());
+
+    // A fresh node opens the state machine 0.
+    let sm = StateMachine::open(&conf.raft_config, 0).await?;
+    sm.import_catalog(&dump).await?;
+
+    eprintln!(
+        "imported {} keys into {}",
+        dump.kvs.len(),
+        conf.raft_config.raft_dir
+    );
+    Ok(())
+}
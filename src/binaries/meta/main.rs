@@ -35,6 +35,7 @@ use databend_meta::version::METASRV_SEMVER;
 use databend_meta::version::MIN_METACLI_SEMVER;
 use tracing::info;
 
+mod catalog_dump;
 mod kvapi;
 
 pub use kvapi::KvApiCommand;
@@ -198,6 +199,16 @@ async fn run_cmd(conf: &Config) -> bool {
                 pretty(&conf).unwrap_or_else(|e| format!("error format config: {}", e))
             );
         }
+        "export" => {
+            if let Err(e) = catalog_dump::export_catalog_to_stdout(conf).await {
+                eprintln!("{}", e);
+            }
+        }
+        "import" => {
+            if let Err(e) = catalog_dump::import_catalog_from_stdin(conf).await {
+                eprintln!("{}", e);
+            }
+        }
         cmd => {
             if cmd.starts_with(CMD_KVAPI_PREFIX) {
                 if let Some(op) = cmd.strip_prefix(CMD_KVAPI_PREFIX) {
@@ -213,6 +224,10 @@ async fn run_cmd(conf: &Config) -> bool {
             eprintln!("    Print effective config");
             eprintln!("  --cmd kvapi::<cmd>");
             eprintln!("    Run kvapi command (upsert, get, mget, list)");
+            eprintln!("  --cmd export");
+            eprintln!("    Print the catalog dump of the cluster at --grpc-api-address");
+            eprintln!("  --cmd import");
+            eprintln!("    Import the catalog dump from stdin into a fresh --raft-dir");
         }
     }
 
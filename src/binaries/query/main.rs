@@ -26,9 +26,11 @@ use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::metrics::MetricService;
+use databend_query::servers::FlightSQLHandler;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::Config;
@@ -94,6 +96,33 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let mut handler = PostgresHandler::create()?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -Uroot -ddefault",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
+    // Arrow Flight SQL handler.
+    {
+        let hostname = conf.query.flight_sql_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.flight_sql_handler_port);
+        let mut handler = FlightSQLHandler::create()?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!("Listening for Arrow Flight SQL protocol: {}", listening);
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -171,6 +200,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
     println!("Log:");
     println!("    File: {}", conf.log.file);
     println!("    Stderr: {}", conf.log.stderr);
+    println!("    Tracing: {}", conf.log.tracing);
     println!(
         "Meta: {}",
         if conf.meta.address.is_empty() && conf.meta.endpoints.is_empty() {
@@ -194,6 +224,20 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         "    connect via: mysql -uroot -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -h{} -p{} -Uroot -ddefault",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!("Arrow Flight SQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.flight_sql_handler_host, conf.query.flight_sql_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
@@ -17,24 +17,33 @@ use std::io::Write;
 use arrow::array::Array;
 use arrow::chunk::Chunk;
 use arrow::datatypes::Schema;
+use arrow::error::Error;
 use arrow::error::Result;
+use arrow::io::parquet::write::array_to_columns;
 use arrow::io::parquet::write::to_parquet_schema;
-use arrow::io::parquet::write::RowGroupIterator;
+use arrow::io::parquet::write::WriteOptions as ColumnWriteOptions;
+use parquet2::encoding::Encoding;
+use parquet2::error::Error as ParquetError;
 use parquet2::metadata::ThriftFileMetaData;
+use parquet2::schema::types::ParquetType;
+use parquet2::write::Compressor;
+use parquet2::write::DynIter;
+use parquet2::write::DynStreamingIterator;
 use parquet2::write::FileWriter;
+use parquet2::write::RowGroupIter;
 use parquet2::write::WriteOptions;
+use parquet2::FallibleStreamingIterator;
 
 // a simple wrapper for code reuse
-pub fn write_parquet_file<W: Write, A, I>(
+pub fn write_parquet_file<W: Write, I>(
     writer: &mut W,
-    row_groups: RowGroupIterator<A, I>,
+    row_groups: I,
     schema: Schema,
     options: WriteOptions,
 ) -> Result<(u64, ThriftFileMetaData)>
 where
     W: Write,
-    A: AsRef<dyn Array> + 'static + Send + Sync,
-    I: Iterator<Item = Result<Chunk<A>>>,
+    I: Iterator<Item = Result<RowGroupIter<'static, Error>>>,
 {
     let parquet_schema = to_parquet_schema(&schema)?;
 
@@ -49,3 +58,28 @@ where
     let (_meta_size, thrift_file_meta_data) = file_writer.into_inner_and_metadata();
     Ok((file_size, thrift_file_meta_data))
 }
+
+/// Encodes the arrays of a chunk into a row group, each column with its own options,
+/// which allows the columns of a file to be compressed with different codecs.
+pub fn row_group_with_options<A>(
+    chunk: Chunk<A>,
+    fields: &[ParquetType],
+    encodings: &[Vec<Encoding>],
+    options: &[ColumnWriteOptions],
+) -> Result<RowGroupIter<'static, Error>>
+where
+    A: AsRef<dyn Array> + 'static + Send + Sync,
+{
+    let mut columns = Vec::with_capacity(fields.len());
+    let arrays = chunk.into_arrays().into_iter();
+    for (((array, type_), encoding), options) in arrays.zip(fields).zip(encodings).zip(options) {
+        for pages in array_to_columns(array, type_.clone(), *options, encoding)? {
+            let pages =
+                DynIter::new(pages.map(|x| x.map_err(|e| ParquetError::OutOfSpec(e.to_string()))));
+            let compressed_pages =
+                Compressor::new(pages, options.compression, vec![]).map_err(Error::from);
+            columns.push(Ok(DynStreamingIterator::new(compressed_pages)));
+        }
+    }
+    Ok(DynIter::new(columns.into_iter()))
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::time::Duration;
 
 use common_exception::ErrorCode;
@@ -21,6 +22,8 @@ use pprof::protos::Message;
 pub struct Profiling {
     duration: Duration,
     frequency: i32,
+    // Only keeps the samples of the threads if set, e.g. the processor threads of a query.
+    thread_ids: Option<HashSet<u64>>,
 }
 
 impl Profiling {
@@ -28,17 +31,36 @@ impl Profiling {
         Self {
             duration,
             frequency,
+            thread_ids: None,
         }
     }
 
+    /// Only keeps the samples of the threads, by the ids of `current_thread_id`.
+    pub fn with_threads(mut self, thread_ids: HashSet<u64>) -> Self {
+        self.thread_ids = Some(thread_ids);
+        self
+    }
+
+    /// The id of the current thread, as the thread id of the samples.
+    pub fn current_thread_id() -> u64 {
+        unsafe { libc::pthread_self() as u64 }
+    }
+
     pub async fn report(&self) -> Result<pprof::Report> {
         let guard = pprof::ProfilerGuard::new(self.frequency)
             .map_err(|e| ErrorCode::UnknownException(e.to_string()))?;
         tokio::time::sleep(self.duration).await;
-        guard
+        let mut report = guard
             .report()
             .build()
-            .map_err(|e| ErrorCode::UnknownException(e.to_string()))
+            .map_err(|e| ErrorCode::UnknownException(e.to_string()))?;
+
+        if let Some(thread_ids) = &self.thread_ids {
+            report
+                .data
+                .retain(|frames, _| thread_ids.contains(&frames.thread_id));
+        }
+        Ok(report)
     }
 
     pub async fn dump_flamegraph(&self) -> Result<Vec<u8>> {
@@ -65,4 +87,26 @@ impl Profiling {
 
         Ok(body)
     }
+
+    /// The samples in the collapsed stack format of the flamegraph tools, one line of each stack,
+    /// e.g. `thread;root_function;...;leaf_function 42`.
+    pub async fn dump_collapsed_stacks(&self) -> Result<Vec<u8>> {
+        let report = self.report().await?;
+
+        let mut lines = Vec::with_capacity(report.data.len());
+        for (frames, count) in report.data.iter() {
+            let mut line = frames.thread_name.clone();
+            for frame in frames.frames.iter().rev() {
+                for symbol in frame.iter().rev() {
+                    line.push(';');
+                    line.push_str(&symbol.name());
+                }
+            }
+            line.push_str(&format!(" {}", count));
+            lines.push(line);
+        }
+        lines.sort();
+
+        Ok(lines.join("\n").into_bytes())
+    }
 }
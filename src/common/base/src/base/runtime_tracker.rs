@@ -57,6 +57,21 @@ impl ThreadTracker {
         }
     }
 
+    /// Replaces the runtime tracker of the current thread, the memory allocated after it is
+    /// tracked by the new one, e.g. the processor threads of a query.
+    pub fn replace_runtime_tracker(rt_tracker: Arc<RuntimeTracker>) {
+        unsafe {
+            if TRACKER.is_null() {
+                ThreadTracker::create(rt_tracker);
+                return;
+            }
+
+            let untracked_memory = std::mem::replace(&mut (*TRACKER).untracked_memory, 0);
+            let old_tracker = std::mem::replace(&mut (*TRACKER).rt_tracker, rt_tracker);
+            old_tracker.memory_tracker.alloc_memory(untracked_memory);
+        }
+    }
+
     #[inline]
     pub fn alloc_memory(size: i64) {
         unsafe {
@@ -103,6 +118,8 @@ impl ThreadTracker {
 
 pub struct MemoryTracker {
     memory_usage: AtomicI64,
+    // The limit of the memory usage in bytes, no limit if it's zero.
+    memory_limit: AtomicI64,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -111,6 +128,7 @@ impl MemoryTracker {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
             memory_usage: AtomicI64::new(0),
+            memory_limit: AtomicI64::new(0),
         })
     }
 
@@ -147,6 +165,37 @@ impl MemoryTracker {
     pub fn get_memory_usage(&self) -> i64 {
         self.memory_usage.load(Ordering::Relaxed)
     }
+
+    pub fn set_memory_limit(&self, limit: i64) {
+        self.memory_limit.store(limit, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn get_memory_limit(&self) -> i64 {
+        self.memory_limit.load(Ordering::Relaxed)
+    }
+
+    /// Whether the memory usage exceeds the limit of this tracker or of one of its ancestors,
+    /// the large operators spill to the disk then.
+    #[inline]
+    pub fn is_exceeded(&self) -> bool {
+        self.get_exceeded().is_some()
+    }
+
+    /// The memory usage and the limit of the nearest tracker exceeding its limit, from this
+    /// tracker up to its ancestors.
+    pub fn get_exceeded(&self) -> Option<(i64, i64)> {
+        let limit = self.get_memory_limit();
+        let usage = self.get_memory_usage();
+        if limit > 0 && usage > limit {
+            return Some((usage, limit));
+        }
+
+        match &self.parent_memory_tracker {
+            None => None,
+            Some(parent_memory_tracker) => parent_memory_tracker.get_exceeded(),
+        }
+    }
 }
 
 pub struct RuntimeTracker {
@@ -161,6 +210,14 @@ impl RuntimeTracker {
         })
     }
 
+    /// Creates the tracker of which the memory is tracked by the given tracker as well, instead
+    /// of the tracker of the current thread, e.g. the tracker of the query of an executor.
+    pub fn create_with_parent(parent_memory_tracker: Arc<MemoryTracker>) -> Arc<RuntimeTracker> {
+        Arc::new(RuntimeTracker {
+            memory_tracker: MemoryTracker::create(Some(parent_memory_tracker)),
+        })
+    }
+
     #[inline]
     pub fn get_memory_tracker(&self) -> &MemoryTracker {
         &self.memory_tracker
@@ -0,0 +1,56 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::MemoryTracker;
+
+#[test]
+fn test_memory_tracker_limit() {
+    let parent = MemoryTracker::create(None);
+    let tracker = MemoryTracker::create(Some(parent.clone()));
+    assert!(!tracker.is_exceeded());
+
+    // No limit.
+    tracker.alloc_memory(2048);
+    assert!(!tracker.is_exceeded());
+
+    tracker.set_memory_limit(1024);
+    assert!(tracker.is_exceeded());
+    assert!(!parent.is_exceeded());
+    assert_eq!(parent.get_memory_usage(), 2048);
+
+    tracker.dealloc_memory(1536);
+    assert!(!tracker.is_exceeded());
+    assert_eq!(parent.get_memory_usage(), 512);
+}
+
+#[test]
+fn test_memory_tracker_parent_limit() {
+    // The executors of a query are limited by the tracker of the query.
+    let query = MemoryTracker::create(None);
+    query.set_memory_limit(1024);
+    let executor1 = MemoryTracker::create(Some(query.clone()));
+    let executor2 = MemoryTracker::create(Some(query.clone()));
+
+    executor1.alloc_memory(768);
+    assert!(!executor1.is_exceeded());
+    assert!(!executor2.is_exceeded());
+
+    executor2.alloc_memory(512);
+    assert!(executor1.is_exceeded());
+    assert!(executor2.is_exceeded());
+    assert_eq!(executor2.get_exceeded(), Some((1280, 1024)));
+
+    executor1.dealloc_memory(768);
+    assert!(!executor2.is_exceeded());
+}
@@ -17,6 +17,13 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use metrics::counter;
+
+/// The bytes read from the storage by all the queries of the process.
+pub static METRIC_STORAGE_READ_BYTES: &str = "storage.read_bytes";
+/// The bytes written to the storage by all the queries of the process.
+pub static METRIC_STORAGE_WRITE_BYTES: &str = "storage.write_bytes";
+
 /// DalMetrics represents the metrics of a DAL (all bytes metrics are compressed size).
 #[derive(Clone, Debug, Default)]
 pub struct DalMetrics {
@@ -38,6 +45,7 @@ impl DalMetrics {
     pub fn inc_read_bytes(&self, v: usize) {
         if v > 0 {
             self.read_bytes.fetch_add(v, Ordering::Relaxed);
+            counter!(METRIC_STORAGE_READ_BYTES, v as u64);
         }
     }
 
@@ -68,6 +76,7 @@ impl DalMetrics {
     pub fn inc_write_bytes(&self, v: usize) {
         if v > 0 {
             self.write_bytes.fetch_add(v, Ordering::Relaxed);
+            counter!(METRIC_STORAGE_WRITE_BYTES, v as u64);
         }
     }
 
@@ -23,6 +23,9 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
+use crate::ErrorCategory;
+use crate::ErrorCodeInfo;
+
 #[derive(Clone)]
 pub enum ErrorCodeBacktrace {
     Serialized(Arc<String>),
@@ -91,6 +94,24 @@ impl ErrorCode {
         self.code
     }
 
+    /// The name of the code in the catalog, e.g. `UnknownTable`.
+    pub fn name(&self) -> &'static str {
+        ErrorCodeInfo::of(self.code).map_or("Unknown", |info| info.name)
+    }
+
+    /// The category of the code, the codes out of the catalog are internal.
+    pub fn category(&self) -> ErrorCategory {
+        ErrorCodeInfo::of(self.code).map_or(ErrorCategory::Internal, |info| info.category)
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.category().is_retryable()
+    }
+
+    pub fn is_user_error(&self) -> bool {
+        self.category().is_user_error()
+    }
+
     pub fn message(&self) -> String {
         self.cause
             .as_ref()
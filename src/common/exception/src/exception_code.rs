@@ -15,16 +15,129 @@
 #![allow(non_snake_case)]
 
 use std::backtrace::Backtrace;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use crate::exception::ErrorCodeBacktrace;
 use crate::ErrorCode;
 
-pub static ABORT_SESSION: u16 = 1042;
-pub static ABORT_QUERY: u16 = 1043;
+pub const ABORT_SESSION: u16 = 1042;
+pub const ABORT_QUERY: u16 = 1043;
+
+/// The category of an error code, for the clients and the retries to act on the errors without
+/// matching the codes or the messages one by one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// A bug or an unexpected state of the server.
+    Internal,
+    /// The server or the node is misconfigured.
+    Config,
+    /// The storage fails or its data is broken.
+    Storage,
+    /// The SQL cannot be parsed.
+    Syntax,
+    /// The statement is invalid, e.g. the arguments or the options are wrong.
+    Semantic,
+    /// The object referred to does not exist.
+    NotFound,
+    /// The object to create exists already.
+    AlreadyExists,
+    /// The data is invalid, e.g. a value cannot be parsed or overflows.
+    Data,
+    /// The user is not authenticated or not allowed to do it.
+    Permission,
+    /// The feature is not supported.
+    Unsupported,
+    /// A quota or a limit of the resources is exceeded.
+    QuotaExceeded,
+    /// The server is too busy to take the query for now.
+    Overloaded,
+    /// The query conflicts with the others committed concurrently.
+    Conflict,
+    /// A node, the meta service or the storage is unreachable for now.
+    Unavailable,
+    /// The query or the session is killed or timed out.
+    Aborted,
+}
+
+impl ErrorCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Internal => "internal",
+            ErrorCategory::Config => "config",
+            ErrorCategory::Storage => "storage",
+            ErrorCategory::Syntax => "syntax",
+            ErrorCategory::Semantic => "semantic",
+            ErrorCategory::NotFound => "not_found",
+            ErrorCategory::AlreadyExists => "already_exists",
+            ErrorCategory::Data => "data",
+            ErrorCategory::Permission => "permission",
+            ErrorCategory::Unsupported => "unsupported",
+            ErrorCategory::QuotaExceeded => "quota_exceeded",
+            ErrorCategory::Overloaded => "overloaded",
+            ErrorCategory::Conflict => "conflict",
+            ErrorCategory::Unavailable => "unavailable",
+            ErrorCategory::Aborted => "aborted",
+        }
+    }
+
+    /// The errors of which the same query may succeed if it's retried later as is.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCategory::Overloaded | ErrorCategory::Conflict | ErrorCategory::Unavailable
+        )
+    }
+
+    /// The errors caused by the query or the data of the user, rather than by the server.
+    pub fn is_user_error(&self) -> bool {
+        matches!(
+            self,
+            ErrorCategory::Syntax
+                | ErrorCategory::Semantic
+                | ErrorCategory::NotFound
+                | ErrorCategory::AlreadyExists
+                | ErrorCategory::Data
+                | ErrorCategory::Permission
+                | ErrorCategory::Unsupported
+                | ErrorCategory::QuotaExceeded
+        )
+    }
+}
+
+impl Display for ErrorCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// An entry of the catalog of the error codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorCodeInfo {
+    pub code: u16,
+    pub name: &'static str,
+    pub category: ErrorCategory,
+}
+
+impl ErrorCodeInfo {
+    /// All the error codes, ordered by the codes.
+    pub fn catalog() -> impl Iterator<Item = &'static ErrorCodeInfo> {
+        INTERNAL_ERRORS
+            .iter()
+            .chain(META_ERRORS.iter())
+            .chain(STORAGE_ERRORS.iter())
+            .chain(CACHE_ERRORS.iter())
+            .chain(SERVICE_ERRORS.iter())
+    }
+
+    pub fn of(code: u16) -> Option<&'static ErrorCodeInfo> {
+        Self::catalog().find(|info| info.code == code)
+    }
+}
 
 macro_rules! build_exceptions {
-    ($($body:ident($code:expr)),*$(,)*) => {
+    ($catalog:ident; $($body:ident($code:expr, $category:ident)),*$(,)*) => {
             impl ErrorCode {
                 $(
                 pub fn $body(display_text: impl Into<String>) -> ErrorCode {
@@ -47,228 +160,359 @@ macro_rules! build_exceptions {
                 }
                 )*
             }
+
+            static $catalog: &[ErrorCodeInfo] = &[
+                $(
+                ErrorCodeInfo {
+                    code: $code,
+                    name: stringify!($body),
+                    category: ErrorCategory::$category,
+                },
+                )*
+            ];
     }
 }
 
 // Internal errors [0, 2000].
 build_exceptions! {
-    Ok(0),
-    UnknownTypeOfQuery(1001),
-    UnImplement(1002),
-    UnknownDatabase(1003),
-    UnknownDatabaseId(1004),
-    SyntaxException(1005),
-    BadArguments(1006),
-    IllegalDataType(1007),
-    UnknownFunction(1008),
-    IllegalFunctionState(1009),
-    BadDataValueType(1010),
-    UnknownPlan(1011),
-    IllegalPipelineState(1012),
-    BadTransformType(1013),
-    IllegalTransformConnectionState(1014),
-    LogicalError(1015),
-    EmptyData(1016),
-    DataStructMissMatch(1017),
-    BadDataArrayLength(1018),
-    UnknownContextID(1019),
-    UnknownTableId(1020),
-    UnknownTableFunction(1021),
-    BadOption(1022),
-    CannotReadFile(1023),
-    ParquetError(1024),
-    UnknownTable(1025),
-    IllegalAggregateExp(1026),
-    UnknownAggregateFunction(1027),
-    NumberArgumentsNotMatch(1028),
-    NotFoundStream(1029),
-    EmptyDataFromServer(1030),
-    NotFoundLocalNode(1031),
-    PlanScheduleError(1032),
-    BadPlanInputs(1033),
-    DuplicateClusterNode(1034),
-    NotFoundClusterNode(1035),
-    BadAddressFormat(1036),
-    DnsParseError(1037),
-    CannotConnectNode(1038),
-    DuplicateGetStream(1039),
-    Timeout(1040),
-    TooManyUserConnections(1041),
-    AbortedSession(ABORT_SESSION),
-    AbortedQuery(ABORT_QUERY),
-    NotFoundSession(1044),
-    CannotListenerPort(1045),
-    BadBytes(1046),
-    InitPrometheusFailure(1047),
-    ScalarSubqueryBadRows(1048),
-    Overflow(1049),
-    InvalidMetaBinaryFormat(1050),
-    AuthenticateFailure(1051),
-    TLSConfigurationFailure(1052),
-    UnknownSession(1053),
-    UnexpectedError(1054),
-    DateTimeParseError(1055),
-    BadPredicateRows(1056),
-    SHA1CheckFailed(1057),
-    UnknownColumn(1058),
-    InvalidSourceFormat(1059),
-    StrParseError(1060),
-    IllegalGrant(1061),
-    ManagementModePermissionDenied(1062),
-    PermissionDenied(1063),
-    UnmarshalError(1064),
-    SemanticError(1065),
+    INTERNAL_ERRORS;
+
+    Ok(0, Internal),
+    UnknownTypeOfQuery(1001, Unsupported),
+    // Also the code of the errors converted from the other errors, so it's internal.
+    UnImplement(1002, Internal),
+    UnknownDatabase(1003, NotFound),
+    UnknownDatabaseId(1004, NotFound),
+    SyntaxException(1005, Syntax),
+    BadArguments(1006, Semantic),
+    IllegalDataType(1007, Semantic),
+    UnknownFunction(1008, NotFound),
+    IllegalFunctionState(1009, Internal),
+    BadDataValueType(1010, Data),
+    UnknownPlan(1011, Internal),
+    IllegalPipelineState(1012, Internal),
+    BadTransformType(1013, Internal),
+    IllegalTransformConnectionState(1014, Internal),
+    LogicalError(1015, Internal),
+    EmptyData(1016, Internal),
+    DataStructMissMatch(1017, Internal),
+    BadDataArrayLength(1018, Internal),
+    UnknownContextID(1019, Internal),
+    UnknownTableId(1020, NotFound),
+    UnknownTableFunction(1021, NotFound),
+    BadOption(1022, Semantic),
+    CannotReadFile(1023, Storage),
+    ParquetError(1024, Data),
+    UnknownTable(1025, NotFound),
+    IllegalAggregateExp(1026, Semantic),
+    UnknownAggregateFunction(1027, NotFound),
+    NumberArgumentsNotMatch(1028, Semantic),
+    NotFoundStream(1029, Internal),
+    EmptyDataFromServer(1030, Unavailable),
+    NotFoundLocalNode(1031, Config),
+    PlanScheduleError(1032, Internal),
+    BadPlanInputs(1033, Internal),
+    DuplicateClusterNode(1034, Internal),
+    NotFoundClusterNode(1035, Unavailable),
+    BadAddressFormat(1036, Config),
+    DnsParseError(1037, Unavailable),
+    CannotConnectNode(1038, Unavailable),
+    DuplicateGetStream(1039, Internal),
+    Timeout(1040, Unavailable),
+    TooManyUserConnections(1041, Overloaded),
+    AbortedSession(ABORT_SESSION, Aborted),
+    AbortedQuery(ABORT_QUERY, Aborted),
+    NotFoundSession(1044, NotFound),
+    CannotListenerPort(1045, Config),
+    BadBytes(1046, Data),
+    InitPrometheusFailure(1047, Config),
+    ScalarSubqueryBadRows(1048, Data),
+    Overflow(1049, Data),
+    InvalidMetaBinaryFormat(1050, Internal),
+    AuthenticateFailure(1051, Permission),
+    TLSConfigurationFailure(1052, Config),
+    UnknownSession(1053, NotFound),
+    UnexpectedError(1054, Internal),
+    DateTimeParseError(1055, Data),
+    BadPredicateRows(1056, Internal),
+    SHA1CheckFailed(1057, Internal),
+    UnknownColumn(1058, NotFound),
+    InvalidSourceFormat(1059, Data),
+    StrParseError(1060, Data),
+    IllegalGrant(1061, Semantic),
+    ManagementModePermissionDenied(1062, Permission),
+    PermissionDenied(1063, Permission),
+    UnmarshalError(1064, Data),
+    SemanticError(1065, Semantic),
 
     // Uncategorized error codes.
-    UnexpectedResponseType(1066),
-    UnknownException(1067),
-    TokioError(1068),
+    UnexpectedResponseType(1066, Internal),
+    UnknownException(1067, Internal),
+    TokioError(1068, Internal),
 
     // Pipeline executor error codes.
-    PipelineAreadlyStarted(1069),
-    PipelineNotStarted(1070),
-    PipelineUnInitialized(1071),
+    PipelineAreadlyStarted(1069, Internal),
+    PipelineNotStarted(1070, Internal),
+    PipelineUnInitialized(1071, Internal),
 
     // Http query error codes.
-    HttpNotFound(1072),
+    HttpNotFound(1072, NotFound),
 
     // Network error codes.
-    NetworkRequestError(1073),
+    NetworkRequestError(1073, Unavailable),
 
-    UnknownFormat(1074),
-    UnknownCompressionType(1075),
-    InvalidCompressionData(1076),
+    UnknownFormat(1074, Semantic),
+    UnknownCompressionType(1075, Semantic),
+    InvalidCompressionData(1076, Data),
 
-    InvalidAuthInfo(1077),
-    InvalidTimezone(1078),
-    InvalidDate(1079),
-    InvalidTimestamp(1080),
-    InvalidClusterKeys(1081),
-    UnknownFragmentExchange(1082),
+    InvalidAuthInfo(1077, Semantic),
+    InvalidTimezone(1078, Semantic),
+    InvalidDate(1079, Data),
+    InvalidTimestamp(1080, Data),
+    InvalidClusterKeys(1081, Semantic),
+    UnknownFragmentExchange(1082, Internal),
 
     // Tenant error codes.
-    TenantIsEmpty(1101),
-    IndexOutOfBounds(1102),
+    TenantIsEmpty(1101, Config),
+    IndexOutOfBounds(1102, Internal),
 
     // Layout error code.
-    LayoutError(1103),
+    LayoutError(1103, Internal),
 
-    PanicError(1104),
+    PanicError(1104, Internal),
 
     // Async insert error codes
-    AsyncInsertTimeoutError(1105),
-
-    TableInfoError(1106),
-    ReadTableDataError(1107),
+    AsyncInsertTimeoutError(1105, Aborted),
+
+    TableInfoError(1106, Internal),
+    ReadTableDataError(1107, Storage),
+    OrcError(1108, Data),
+    AvroError(1109, Data),
+    IcebergError(1110, Storage),
+    DeltaError(1111, Storage),
+    KafkaError(1112, Unavailable),
+    MemoryLimitExceeded(1113, QuotaExceeded),
+
+    // Query queue error codes.
+    QueryQueueFull(1114, Overloaded),
+    QueryQueueTimeout(1115, Overloaded),
+
+    // Transaction error codes.
+    // BEGIN in a transaction.
+    InvalidTransactionState(1116, Semantic),
+    // The tables mutated by a transaction are changed by the others before it commits.
+    TransactionConflict(1117, Conflict),
+
+    // Script error codes.
+    // The error raised by `RAISE` of the script.
+    ScriptRaisedError(1118, Semantic),
+    // The script is invalid when it runs, e.g. it assigns an undeclared variable.
+    ScriptSemanticError(1119, Semantic),
+
+    // Collation error codes.
+    // The collation is unknown, e.g. a locale not supported.
+    UnknownCollation(1120, NotFound),
+
+    // Spill error codes.
+    // The files spilled by a query exceed the spill_query_quota_bytes.
+    SpillQuotaExceeded(1121, QuotaExceeded),
+
+    // Replay error codes.
+    // The replayed query differs from the one captured in the replay bundle.
+    ReplayBundleMismatch(1122, Semantic),
+
+    // Read only mode error codes.
+    // The query writes the data or changes the schema on a read only node.
+    ReadOnlyModePermissionDenied(1123, Permission),
+
+    // Stream error codes.
+    // The blocks consumed by a stream are rewritten, e.g. by a compaction or a DELETE.
+    StreamInvalidated(1124, Unsupported),
+
+    // Query timeout error codes.
+    // The query is aborted as it runs beyond the max_execute_time, the same query would time out again.
+    QueryTimeout(1125, Aborted),
 }
 
 // Metasvr errors [2001, 3000].
 build_exceptions! {
+    META_ERRORS;
+
     // Meta service does not work.
-    MetaServiceError(2001),
-    InvalidConfig(2002),
-    MetaStorageError(2003),
-    InvalidArgument(2004),
+    MetaServiceError(2001, Unavailable),
+    InvalidConfig(2002, Config),
+    MetaStorageError(2003, Internal),
+    InvalidArgument(2004, Semantic),
     // Meta service replied with invalid data
-    InvalidReply(2005),
+    InvalidReply(2005, Internal),
 
-    TableVersionMismatched(2009),
-    OCCRetryFailure(2011),
-    TableNotWritable(2012),
-    TableHistoricalDataNotFound(2013),
+    TableVersionMismatched(2009, Conflict),
+    OCCRetryFailure(2011, Conflict),
+    TableNotWritable(2012, Permission),
+    TableHistoricalDataNotFound(2013, NotFound),
 
     // User api error codes.
-    UnknownUser(2201),
-    UserAlreadyExists(2202),
-    IllegalUserInfoFormat(2203),
-    UnknownRole(2204),
-    IllegalUserSettingFormat(2205),
-    InvalidRole(2206),
+    UnknownUser(2201, NotFound),
+    UserAlreadyExists(2202, AlreadyExists),
+    IllegalUserInfoFormat(2203, Semantic),
+    UnknownRole(2204, NotFound),
+    IllegalUserSettingFormat(2205, Semantic),
+    InvalidRole(2206, Semantic),
 
     // Meta api error codes.
-    DatabaseAlreadyExists(2301),
-    TableAlreadyExists(2302),
-    IllegalMetaState(2304),
-    MetaNodeInternalError(2305),
-    ViewAlreadyExists(2306),
-    CreateTableWithDropTime(2307),
-    UndropTableAlreadyExists(2308),
-    UndropTableHasNoHistory(2309),
-    CreateDatabaseWithDropTime(2310),
-    UndropDbHasNoHistory(2312),
-    UndropTableWithNoDropTime(2313),
-    DropTableWithDropTime(2314),
-    DropDbWithDropTime(2315),
-    UndropDbWithNoDropTime(2316),
-    TxnRetryMaxTimes(2317),
+    DatabaseAlreadyExists(2301, AlreadyExists),
+    TableAlreadyExists(2302, AlreadyExists),
+    IllegalMetaState(2304, Internal),
+    MetaNodeInternalError(2305, Internal),
+    ViewAlreadyExists(2306, AlreadyExists),
+    CreateTableWithDropTime(2307, Internal),
+    UndropTableAlreadyExists(2308, AlreadyExists),
+    UndropTableHasNoHistory(2309, NotFound),
+    CreateDatabaseWithDropTime(2310, Internal),
+    UndropDbHasNoHistory(2312, NotFound),
+    UndropTableWithNoDropTime(2313, Semantic),
+    DropTableWithDropTime(2314, Semantic),
+    DropDbWithDropTime(2315, Semantic),
+    UndropDbWithNoDropTime(2316, Semantic),
+    TxnRetryMaxTimes(2317, Conflict),
+    TableLockConflict(2318, Conflict),
 
     // Cluster error codes.
-    ClusterUnknownNode(2401),
-    ClusterNodeAlreadyExists(2402),
+    ClusterUnknownNode(2401, NotFound),
+    ClusterNodeAlreadyExists(2402, AlreadyExists),
 
     // Stage error codes.
-    UnknownStage(2501),
-    StageAlreadyExists(2502),
-    IllegalUserStageFormat(2503),
-    StageFileAlreadyExists(2504),
-    IllegalStageFileFormat(2505),
+    UnknownStage(2501, NotFound),
+    StageAlreadyExists(2502, AlreadyExists),
+    IllegalUserStageFormat(2503, Semantic),
+    StageFileAlreadyExists(2504, AlreadyExists),
+    IllegalStageFileFormat(2505, Semantic),
 
     // User defined function error codes.
-    IllegalUDFFormat(2601),
-    UnknownUDF(2602),
-    UdfAlreadyExists(2603),
+    IllegalUDFFormat(2601, Semantic),
+    UnknownUDF(2602, NotFound),
+    UdfAlreadyExists(2603, AlreadyExists),
+
+    // Row access policy error codes.
+    IllegalRowAccessPolicyFormat(2611, Semantic),
+    UnknownRowAccessPolicy(2612, NotFound),
+    RowAccessPolicyAlreadyExists(2613, AlreadyExists),
+
+    // Masking policy error codes.
+    IllegalMaskingPolicyFormat(2621, Semantic),
+    UnknownMaskingPolicy(2622, NotFound),
+    MaskingPolicyAlreadyExists(2623, AlreadyExists),
+
+    // Network policy error codes.
+    IllegalNetworkPolicyFormat(2631, Semantic),
+    UnknownNetworkPolicy(2632, NotFound),
+    NetworkPolicyAlreadyExists(2633, AlreadyExists),
+    NetworkPolicyIsUsedByUser(2634, Semantic),
+    NetworkPolicyDenied(2635, Permission),
+
+    // Connection error codes.
+    IllegalConnectionFormat(2641, Semantic),
+    UnknownConnection(2642, NotFound),
+    ConnectionAlreadyExists(2643, AlreadyExists),
+
+    // Resource group error codes.
+    IllegalResourceGroupFormat(2651, Semantic),
+    UnknownResourceGroup(2652, NotFound),
+    ResourceGroupAlreadyExists(2653, AlreadyExists),
+    ResourceGroupIsUsedByUser(2654, Semantic),
+
+    // Background task error codes.
+    UnknownBackgroundTask(2661, NotFound),
+
+    // Sequence error codes.
+    IllegalSequenceFormat(2671, Semantic),
+    UnknownSequence(2672, NotFound),
+    SequenceAlreadyExists(2673, AlreadyExists),
+    SequenceOverflow(2674, Data),
+
+    // Scheduled task error codes.
+    IllegalTaskFormat(2681, Semantic),
+    UnknownTask(2682, NotFound),
+    TaskAlreadyExists(2683, AlreadyExists),
+    IllegalTaskSchedule(2684, Semantic),
+    TaskIsRunning(2685, Conflict),
+    TaskHasSuccessor(2686, Semantic),
+
+    // Dictionary error codes.
+    IllegalDictionaryFormat(2691, Semantic),
+    UnknownDictionary(2692, NotFound),
+    DictionaryAlreadyExists(2693, AlreadyExists),
+    DictionarySourceError(2694, Unavailable),
+
+    // Aggregating index error codes.
+    IllegalAggregatingIndexFormat(2695, Semantic),
+    UnknownAggregatingIndex(2696, NotFound),
+    AggregatingIndexAlreadyExists(2697, AlreadyExists),
 
     // Database error codes.
-    UnknownDatabaseEngine(2701),
-    UnknownTableEngine(2702),
-    UnsupportedEngineParams(2703),
+    UnknownDatabaseEngine(2701, NotFound),
+    UnknownTableEngine(2702, NotFound),
+    UnsupportedEngineParams(2703, Unsupported),
 
     // Share error codes.
-    ShareAlreadyExists(2705),
-    UnknownShare(2706),
-    UnknownShareId(2707),
-    ShareAccountsAlreadyExists(2708),
-    UnknownShareAccounts(2709),
-    WrongShareObject(2710),
-    WrongShare(2711),
-    ShareHasNoGrantedDatabase(2712),
-    ShareHasNoGrantedPrivilege(2713),
+    ShareAlreadyExists(2705, AlreadyExists),
+    UnknownShare(2706, NotFound),
+    UnknownShareId(2707, NotFound),
+    ShareAccountsAlreadyExists(2708, AlreadyExists),
+    UnknownShareAccounts(2709, NotFound),
+    WrongShareObject(2710, Semantic),
+    WrongShare(2711, Semantic),
+    ShareHasNoGrantedDatabase(2712, Semantic),
+    ShareHasNoGrantedPrivilege(2713, Permission),
+
+    // Plan hint error codes.
+    IllegalPlanHintFormat(2721, Semantic),
+    UnknownPlanHint(2722, NotFound),
+    PlanHintAlreadyExists(2723, AlreadyExists),
 
     // Variable error codes.
-    UnknownVariable(2801),
-    OnlySupportAsciiChars(2802),
-    WrongValueForVariable(2803),
+    UnknownVariable(2801, NotFound),
+    OnlySupportAsciiChars(2802, Semantic),
+    WrongValueForVariable(2803, Semantic),
 
     // Tenant quota error codes.
-    IllegalTenantQuotaFormat(2901),
-    TenantQuotaUnknown(2902),
-    TenantQuotaExceeded(2903),
+    IllegalTenantQuotaFormat(2901, Semantic),
+    TenantQuotaUnknown(2902, NotFound),
+    TenantQuotaExceeded(2903, QuotaExceeded),
 
 }
 
 // Storage errors [3001, 4000].
 build_exceptions! {
-    StorageNotFound(3001),
-    StoragePermissionDenied(3002),
-    StorageUnavailable(3901),
-    StorageUnsupported(3902),
-    StorageInsecure(3903),
-    DeprecatedIndexFormat(3904),
-    StorageOther(4000),
+    STORAGE_ERRORS;
+
+    StorageNotFound(3001, Storage),
+    StoragePermissionDenied(3002, Storage),
+    StorageUnavailable(3901, Unavailable),
+    StorageUnsupported(3902, Unsupported),
+    StorageInsecure(3903, Config),
+    DeprecatedIndexFormat(3904, Storage),
+    // The checksum of an object of a table mismatches its content.
+    DataCorruption(3905, Storage),
+    StorageOther(4000, Storage),
 }
 
 // Cache errors [4001, 5000].
 build_exceptions! {
-    DiskCacheIOError(4001),
-    DiskCacheFileTooLarge(4002),
-    DiskCacheFileNotInCache(4003),
+    CACHE_ERRORS;
+
+    DiskCacheIOError(4001, Storage),
+    DiskCacheFileTooLarge(4002, Storage),
+    DiskCacheFileNotInCache(4003, Storage),
 }
 
 // Service errors [5001,6000].
 build_exceptions! {
+    SERVICE_ERRORS;
+
     // A task that already stopped and can not stop twice.
-    AlreadyStarted(5001),
+    AlreadyStarted(5001, Internal),
 
     // A task that already started and can not start twice.
-    AlreadyStopped(5002),
+    AlreadyStopped(5002, Internal),
 }
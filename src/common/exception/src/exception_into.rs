@@ -242,6 +242,8 @@ impl From<tonic::Status> for ErrorCode {
                     },
                 }
             }
+            // The node is down or the connection is broken, e.g. the stream of the flight.
+            tonic::Code::Unavailable => ErrorCode::CannotConnectNode(status.message()),
             _ => ErrorCode::UnImplement(status.to_string()),
         }
     }
@@ -20,6 +20,8 @@ mod exception_into;
 pub use exception::ErrorCode;
 pub use exception::Result;
 pub use exception::ToErrorCode;
+pub use exception_code::ErrorCategory;
+pub use exception_code::ErrorCodeInfo;
 pub use exception_code::ABORT_QUERY;
 pub use exception_code::ABORT_SESSION;
 pub use exception_into::SerializedError;
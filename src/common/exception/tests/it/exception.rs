@@ -135,5 +135,69 @@ fn test_from_and_to_status() -> anyhow::Result<()> {
         assert_eq!("foo", e1.message());
     }
 
+    // test unavailable node
+    let status2 = Status::unavailable("foo");
+    {
+        let e2: ErrorCode = status2.into();
+        assert_eq!(1038, e2.code());
+        assert_eq!("foo", e2.message());
+    }
+
     Ok(())
 }
+
+#[test]
+fn test_error_category() {
+    use common_exception::ErrorCategory;
+
+    let err = ErrorCode::UnknownTable("test message");
+    assert_eq!(err.name(), "UnknownTable");
+    assert_eq!(err.category(), ErrorCategory::NotFound);
+    assert!(err.is_user_error());
+    assert!(!err.is_retryable());
+
+    let err = ErrorCode::TransactionConflict("test message");
+    assert_eq!(err.category(), ErrorCategory::Conflict);
+    assert!(!err.is_user_error());
+    assert!(err.is_retryable());
+
+    assert!(ErrorCode::StorageUnavailable("").is_retryable());
+    assert!(ErrorCode::QueryQueueFull("").is_retryable());
+    assert!(!ErrorCode::AsyncInsertTimeoutError("").is_retryable());
+    assert_eq!(ErrorCode::QueryTimeout("").code(), 1125);
+    assert_eq!(
+        ErrorCode::QueryTimeout("").category(),
+        ErrorCategory::Aborted
+    );
+    assert!(!ErrorCode::QueryTimeout("").is_retryable());
+    assert!(ErrorCode::MemoryLimitExceeded("").is_user_error());
+    assert!(!ErrorCode::LogicalError("").is_user_error());
+
+    // The errors converted from the other errors are internal.
+    let err: ErrorCode = "test message".into();
+    assert_eq!(err.category(), ErrorCategory::Internal);
+
+    // The codes out of the catalog, e.g. of a newer node, are internal.
+    let err = ErrorCode::create(u16::MAX, "test message".to_string(), None, None);
+    assert_eq!(err.name(), "Unknown");
+    assert_eq!(err.category(), ErrorCategory::Internal);
+    assert_eq!(err.category().to_string(), "internal");
+}
+
+#[test]
+fn test_error_code_catalog() {
+    use std::collections::HashSet;
+
+    use common_exception::ErrorCodeInfo;
+
+    let mut codes = HashSet::new();
+    let mut names = HashSet::new();
+    for info in ErrorCodeInfo::catalog() {
+        assert!(codes.insert(info.code), "duplicated code {}", info.code);
+        assert!(names.insert(info.name), "duplicated name {}", info.name);
+    }
+
+    let info = ErrorCodeInfo::of(ErrorCode::aborted_query_code()).unwrap();
+    assert_eq!(info.name, "AbortedQuery");
+    assert!(ErrorCodeInfo::of(u16::MAX).is_none());
+}
@@ -18,6 +18,9 @@ use std::time::Duration;
 pub struct RpcClientTlsConfig {
     pub rpc_tls_server_root_ca_cert: String,
     pub domain_name: String,
+    /// The cert and the key the client presents, if the server verifies the clients.
+    pub rpc_tls_client_cert: String,
+    pub rpc_tls_client_key: String,
 }
 
 impl RpcClientTlsConfig {
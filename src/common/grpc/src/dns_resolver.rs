@@ -37,6 +37,7 @@ use tonic::transport::Certificate;
 use tonic::transport::Channel;
 use tonic::transport::ClientTlsConfig;
 use tonic::transport::Endpoint;
+use tonic::transport::Identity;
 use tracing::info;
 use trust_dns_resolver::TokioAsyncResolver;
 
@@ -207,9 +208,14 @@ impl ConnectionFactory {
         let server_root_ca_cert = std::fs::read(conf.rpc_tls_server_root_ca_cert.as_str())?;
         let server_root_ca_cert = Certificate::from_pem(server_root_ca_cert);
 
-        let tls = ClientTlsConfig::new()
+        let mut tls = ClientTlsConfig::new()
             .domain_name(conf.domain_name.to_string())
             .ca_certificate(server_root_ca_cert);
+        if !conf.rpc_tls_client_cert.is_empty() && !conf.rpc_tls_client_key.is_empty() {
+            let cert = std::fs::read(conf.rpc_tls_client_cert.as_str())?;
+            let key = std::fs::read(conf.rpc_tls_client_key.as_str())?;
+            tls = tls.identity(Identity::from_pem(cert, key));
+        }
         Ok(tls)
     }
 }
@@ -196,6 +196,16 @@ impl<
         }
     }
 
+    /// Prefetches the place of the hash into the cache, for the key of the hash inserted later,
+    /// so that the probes of a batch of keys do not wait for the misses of the cache one by one.
+    #[inline(always)]
+    pub fn prefetch_hash(&self, hash: u64) {
+        unsafe {
+            let place_value = self.grower.place(hash);
+            std::intrinsics::prefetch_write_data(self.entities.offset(place_value), 3);
+        }
+    }
+
     #[inline(always)]
     pub fn find_key(&self, key: &Key) -> Option<*mut Entity> {
         if !key.is_zero() {
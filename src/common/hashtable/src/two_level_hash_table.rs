@@ -91,6 +91,14 @@ impl<
         }
     }
 
+    #[inline(always)]
+    pub fn prefetch_hash(&self, hash: u64) {
+        match self {
+            HashTableKind::HashTable(data) => data.prefetch_hash(hash),
+            HashTableKind::TwoLevelHashTable(data) => data.prefetch_hash(hash),
+        }
+    }
+
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn convert_to_two_level(&mut self) {
         let mut two_level_hash_table = Self::create_two_level_hash_table();
@@ -211,6 +219,12 @@ impl<
         self.hash_tables[bucket].insert_hash_key(key, hash, inserted)
     }
 
+    #[inline(always)]
+    pub fn prefetch_hash(&self, hash: u64) {
+        let bucket = self.get_bucket_from_hash(&hash);
+        self.hash_tables[bucket].prefetch_hash(hash)
+    }
+
     #[inline(always)]
     pub fn find_key(&self, key: &Key) -> Option<*mut Entity> {
         let hash = key.fast_hash();
@@ -17,6 +17,7 @@ use std::sync::Once;
 
 use metrics::counter;
 use metrics::decrement_gauge;
+use metrics::gauge;
 use metrics::histogram;
 use metrics::increment_gauge;
 use metrics_exporter_prometheus::PrometheusBuilder;
@@ -54,6 +55,15 @@ pub fn label_increment_gauge_with_val_and_labels(
     increment_gauge!(name, val, &labels);
 }
 
+#[inline]
+pub fn label_gauge_with_val_and_labels(
+    name: &'static str,
+    labels: Vec<(&'static str, String)>,
+    val: f64,
+) {
+    gauge!(name, val, &labels);
+}
+
 #[inline]
 pub fn label_decrement_gauge_with_val_and_labels(
     name: &'static str,
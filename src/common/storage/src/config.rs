@@ -44,6 +44,7 @@ pub enum StorageParams {
     Memory,
     Obs(StorageObsConfig),
     S3(StorageS3Config),
+    Webhdfs(StorageWebhdfsConfig),
 }
 
 impl Default for StorageParams {
@@ -93,6 +94,9 @@ impl Display for StorageParams {
                     v.bucket, v.root, v.endpoint_url
                 )
             }
+            StorageParams::Webhdfs(v) => {
+                write!(f, "webhdfs://root={},endpoint={}", v.root, v.endpoint_url)
+            }
         }
     }
 }
@@ -114,6 +118,7 @@ impl StorageParams {
             StorageParams::Obs(v) => v.endpoint_url.starts_with("https://"),
             StorageParams::S3(v) => v.endpoint_url.starts_with("https://"),
             StorageParams::Gcs(v) => v.endpoint_url.starts_with("https://"),
+            StorageParams::Webhdfs(v) => v.endpoint_url.starts_with("https://"),
         }
     }
 }
@@ -337,3 +342,27 @@ impl Debug for StorageObsConfig {
             .finish()
     }
 }
+
+/// Config for storage backend webhdfs.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageWebhdfsConfig {
+    /// The http address of the name node, e.g. `http://127.0.0.1:9870`.
+    pub endpoint_url: String,
+    pub root: String,
+    /// The user of the simple authentication.
+    pub user: String,
+    /// The delegation token of the kerberized clusters, the requests are authenticated by
+    /// the token instead of the user if it's set.
+    pub delegation: String,
+}
+
+impl Debug for StorageWebhdfsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageWebhdfsConfig")
+            .field("endpoint_url", &self.endpoint_url)
+            .field("root", &self.root)
+            .field("user", &self.user)
+            .field("delegation", &mask_string(&self.delegation, 3))
+            .finish()
+    }
+}
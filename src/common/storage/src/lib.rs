@@ -28,6 +28,7 @@ pub use config::StorageIpfsConfig;
 pub use config::StorageObsConfig;
 pub use config::StorageParams;
 pub use config::StorageS3Config;
+pub use config::StorageWebhdfsConfig;
 pub use config::STORAGE_FTP_DEFAULT_ENDPOINT;
 pub use config::STORAGE_GCS_DEFAULT_ENDPOINT;
 pub use config::STORAGE_IPFS_DEFAULT_ENDPOINT;
@@ -42,3 +43,6 @@ pub use location::parse_uri_location;
 pub use location::UriLocation;
 
 mod utils;
+
+mod webhdfs;
+pub use webhdfs::WebhdfsBackend;
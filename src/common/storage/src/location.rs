@@ -20,6 +20,8 @@ use std::io::Result;
 use anyhow::anyhow;
 use opendal::Scheme;
 use percent_encoding::percent_decode_str;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::config::StorageHttpConfig;
 use crate::config::StorageIpfsConfig;
@@ -28,9 +30,10 @@ use crate::config::STORAGE_S3_DEFAULT_ENDPOINT;
 use crate::StorageAzblobConfig;
 use crate::StorageParams;
 use crate::StorageS3Config;
+use crate::StorageWebhdfsConfig;
 use crate::STORAGE_GCS_DEFAULT_ENDPOINT;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UriLocation {
     pub protocol: String,
     pub name: String,
@@ -61,6 +64,22 @@ pub fn parse_uri_location(l: &UriLocation) -> Result<(StorageParams, String)> {
         ("/", l.path.as_str())
     };
 
+    // WebHDFS is served by the name nodes over HTTP, which is not a scheme of opendal.
+    if l.protocol == "webhdfs" {
+        let endpoint = l
+            .connection
+            .get("endpoint_url")
+            .cloned()
+            .unwrap_or_else(|| l.name.clone());
+        let sp = StorageParams::Webhdfs(StorageWebhdfsConfig {
+            endpoint_url: secure_omission(endpoint),
+            root: root.to_string(),
+            user: l.connection.get("user").cloned().unwrap_or_default(),
+            delegation: l.connection.get("delegation").cloned().unwrap_or_default(),
+        });
+        return Ok((sp, path.to_string()));
+    }
+
     let protocol = l.protocol.parse::<Scheme>()?;
 
     let sp = match protocol {
@@ -109,16 +128,17 @@ pub fn parse_uri_location(l: &UriLocation) -> Result<(StorageParams, String)> {
         }
         #[cfg(feature = "storage-hdfs")]
         Scheme::Hdfs => StorageParams::Hdfs(crate::StorageHdfsConfig {
-            name_node: l
-                .connection
-                .get("name_node")
-                .ok_or_else(|| {
-                    Error::new(
+            // The name node is the host of the location if it's not specified.
+            name_node: match l.connection.get("name_node") {
+                Some(name_node) => name_node.to_string(),
+                None if !l.name.is_empty() => format!("hdfs://{}", l.name),
+                None => {
+                    return Err(Error::new(
                         ErrorKind::InvalidInput,
                         anyhow!("name_node is required for storage hdfs"),
-                    )
-                })?
-                .to_string(),
+                    ));
+                }
+            },
             root: root.to_string(),
         }),
         Scheme::Ipfs => {
@@ -14,6 +14,7 @@
 
 use std::env;
 use std::io::Result;
+use std::sync::Arc;
 
 use backon::ExponentialBackoff;
 use common_base::base::GlobalIORuntime;
@@ -44,6 +45,8 @@ use crate::config::StorageGcsConfig;
 use crate::config::StorageHttpConfig;
 use crate::config::StorageObsConfig;
 use crate::StorageConfig;
+use crate::StorageWebhdfsConfig;
+use crate::WebhdfsBackend;
 
 /// init_operator will init an opendal operator based on storage config.
 pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
@@ -59,6 +62,7 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
         StorageParams::Memory => init_memory_operator()?,
         StorageParams::Obs(cfg) => init_obs_operator(cfg)?,
         StorageParams::S3(cfg) => init_s3_operator(cfg)?,
+        StorageParams::Webhdfs(cfg) => init_webhdfs_operator(cfg)?,
     };
 
     let op = op
@@ -189,6 +193,13 @@ fn init_http_operator(cfg: &StorageHttpConfig) -> Result<Operator> {
     Ok(Operator::new(builder.build()?).layer(immutable_layer))
 }
 
+/// init_webhdfs_operator will init an operator of the name node REST API of hdfs.
+fn init_webhdfs_operator(cfg: &StorageWebhdfsConfig) -> Result<Operator> {
+    let backend = WebhdfsBackend::try_create(cfg)?;
+
+    Ok(Operator::new(Arc::new(backend)))
+}
+
 /// init_memory_operator will init a opendal memory operator.
 fn init_memory_operator() -> Result<Operator> {
     let mut builder = memory::Builder::default();
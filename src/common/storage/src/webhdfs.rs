@@ -0,0 +1,358 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The WebHDFS backend, which accesses HDFS by the REST API of the name nodes instead of
+//! libhdfs, so no JVM or hadoop client is required on the query nodes.
+//!
+//! Refer to [WebHDFS REST API](https://hadoop.apache.org/docs/stable/hadoop-project-dist/hadoop-hdfs/WebHDFS.html).
+
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use futures::AsyncReadExt;
+use futures::TryStreamExt;
+use opendal::ops::OpCreate;
+use opendal::ops::OpDelete;
+use opendal::ops::OpList;
+use opendal::ops::OpRead;
+use opendal::ops::OpStat;
+use opendal::ops::OpWrite;
+use opendal::Accessor;
+use opendal::AccessorMetadata;
+use opendal::BytesReader;
+use opendal::DirEntry;
+use opendal::DirStreamer;
+use opendal::ObjectMetadata;
+use opendal::ObjectMode;
+use opendal::Scheme;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::CONTROLS;
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
+use reqwest::Method;
+use reqwest::Response;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::StorageWebhdfsConfig;
+
+// The characters of the paths which have special meanings in the urls.
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'#').add(b'%').add(b'?');
+
+#[derive(Clone, Debug)]
+pub struct WebhdfsBackend {
+    // `<endpoint>/webhdfs/v1`
+    base_url: String,
+    // Always starts and ends with `/`.
+    root: String,
+    // The authentication params appended to all the requests.
+    auth_params: Vec<(String, String)>,
+    // The redirections to the data nodes are followed by hand, the data of a write is sent
+    // to the data node only.
+    client: reqwest::Client,
+}
+
+impl WebhdfsBackend {
+    pub fn try_create(cfg: &StorageWebhdfsConfig) -> Result<Self> {
+        if cfg.endpoint_url.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!("endpoint_url is required for storage webhdfs"),
+            ));
+        }
+
+        let mut root = format!("/{}", cfg.root.trim_matches('/'));
+        if !root.ends_with('/') {
+            root.push('/');
+        }
+
+        // The delegation token authenticates the requests to the kerberized clusters, the user
+        // is the identity of the simple authentication otherwise.
+        let mut auth_params = vec![];
+        if !cfg.delegation.is_empty() {
+            auth_params.push(("delegation".to_string(), cfg.delegation.clone()));
+        } else if !cfg.user.is_empty() {
+            auth_params.push(("user.name".to_string(), cfg.user.clone()));
+        }
+
+        let client = reqwest::Client::builder()
+            .redirect(Policy::none())
+            .build()
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+
+        Ok(WebhdfsBackend {
+            base_url: format!("{}/webhdfs/v1", cfg.endpoint_url.trim_end_matches('/')),
+            root,
+            auth_params,
+            client,
+        })
+    }
+
+    fn abs_path(&self, path: &str) -> String {
+        let path = format!("{}{}", self.root, path.trim_start_matches('/'));
+        match path.trim_end_matches('/') {
+            "" => "/".to_string(),
+            path => path.to_string(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        let path = utf8_percent_encode(&self.abs_path(path), PATH_ENCODE_SET);
+        format!("{}{}", self.base_url, path)
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        op: &str,
+        params: &[(&str, String)],
+    ) -> Result<Response> {
+        let resp = self
+            .client
+            .request(method.clone(), self.url(path))
+            .query(&[("op", op)])
+            .query(&self.auth_params)
+            .query(params)
+            .send()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+
+        // The reads are redirected to the data nodes holding the blocks.
+        if resp.status() == StatusCode::TEMPORARY_REDIRECT && method == Method::GET {
+            let location = redirect_location(&resp)?;
+            let resp = self
+                .client
+                .get(location)
+                .send()
+                .await
+                .map_err(|e| Error::new(ErrorKind::Other, e))?;
+            return check_status(resp, path).await;
+        }
+        check_status(resp, path).await
+    }
+
+    async fn file_status(&self, path: &str) -> Result<FileStatus> {
+        let resp = self
+            .request(Method::GET, path, "GETFILESTATUS", &[])
+            .await?;
+        let status: FileStatusWrapper = parse_json(resp).await?;
+        Ok(status.file_status)
+    }
+}
+
+#[async_trait]
+impl Accessor for WebhdfsBackend {
+    fn metadata(&self) -> AccessorMetadata {
+        let mut am = AccessorMetadata::default();
+        am.set_scheme(Scheme::Hdfs).set_root(&self.root);
+        am
+    }
+
+    async fn create(&self, path: &str, args: OpCreate) -> Result<()> {
+        match args.mode() {
+            ObjectMode::DIR => {
+                self.request(Method::PUT, path, "MKDIRS", &[]).await?;
+                Ok(())
+            }
+            _ => self
+                .write(path, OpWrite::new(0), Box::new(futures::io::empty()))
+                .await
+                .map(|_| ()),
+        }
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<BytesReader> {
+        let mut params = vec![];
+        if let Some(offset) = args.offset() {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(size) = args.size() {
+            params.push(("length", size.to_string()));
+        }
+        let resp = self.request(Method::GET, path, "OPEN", &params).await?;
+        let stream = resp
+            .bytes_stream()
+            .map_err(|e| Error::new(ErrorKind::Other, e));
+        Ok(Box::new(Box::pin(stream).into_async_read()))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite, mut r: BytesReader) -> Result<u64> {
+        let mut data = Vec::with_capacity(args.size() as usize);
+        r.read_to_end(&mut data).await?;
+        let size = data.len() as u64;
+
+        // The name node redirects the creation to a data node, the data is sent to it then.
+        let resp = self
+            .client
+            .put(self.url(path))
+            .query(&[("op", "CREATE"), ("overwrite", "true")])
+            .query(&self.auth_params)
+            .send()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+        let resp = match resp.status() {
+            StatusCode::TEMPORARY_REDIRECT => {
+                let location = redirect_location(&resp)?;
+                self.client
+                    .put(location)
+                    .body(data)
+                    .send()
+                    .await
+                    .map_err(|e| Error::new(ErrorKind::Other, e))?
+            }
+            _ => resp,
+        };
+        check_status(resp, path).await?;
+        Ok(size)
+    }
+
+    async fn stat(&self, path: &str, _args: OpStat) -> Result<ObjectMetadata> {
+        let status = self.file_status(path).await?;
+        Ok(status.to_metadata())
+    }
+
+    async fn delete(&self, path: &str, _args: OpDelete) -> Result<()> {
+        // Deleting an object not existing is not an error, as the other backends.
+        self.request(Method::DELETE, path, "DELETE", &[]).await?;
+        Ok(())
+    }
+
+    async fn list(&self, path: &str, _args: OpList) -> Result<DirStreamer> {
+        let resp = self.request(Method::GET, path, "LISTSTATUS", &[]).await?;
+        let statuses: FileStatusesWrapper = parse_json(resp).await?;
+
+        let dir = path.trim_start_matches('/').trim_end_matches('/');
+        let backend: Arc<dyn Accessor> = Arc::new(self.clone());
+        let entries = statuses
+            .file_statuses
+            .file_status
+            .into_iter()
+            .map(|status| {
+                let name = match dir.is_empty() {
+                    true => status.path_suffix.clone(),
+                    false => format!("{}/{}", dir, status.path_suffix),
+                };
+                let mode = status.mode();
+                let name = match mode {
+                    ObjectMode::DIR => format!("{}/", name),
+                    _ => name,
+                };
+                Ok(DirEntry::new(backend.clone(), mode, &name))
+            })
+            .collect::<Vec<_>>();
+        Ok(Box::new(futures::stream::iter(entries)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatusWrapper {
+    #[serde(rename = "FileStatus")]
+    file_status: FileStatus,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatusesWrapper {
+    #[serde(rename = "FileStatuses")]
+    file_statuses: FileStatuses,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatuses {
+    #[serde(rename = "FileStatus")]
+    file_status: Vec<FileStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileStatus {
+    path_suffix: String,
+    length: u64,
+    // The milliseconds since the epoch.
+    modification_time: i64,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl FileStatus {
+    fn mode(&self) -> ObjectMode {
+        match self.ty.as_str() {
+            "FILE" => ObjectMode::FILE,
+            "DIRECTORY" => ObjectMode::DIR,
+            _ => ObjectMode::Unknown,
+        }
+    }
+
+    fn to_metadata(&self) -> ObjectMetadata {
+        let mut meta = ObjectMetadata::new(self.mode());
+        meta.set_content_length(self.length);
+        if let Ok(t) =
+            OffsetDateTime::from_unix_timestamp_nanos(self.modification_time as i128 * 1_000_000)
+        {
+            meta.set_last_modified(t);
+        }
+        meta
+    }
+}
+
+fn redirect_location(resp: &Response) -> Result<String> {
+    resp.headers()
+        .get(LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Other,
+                anyhow!("webhdfs redirects without the location"),
+            )
+        })
+}
+
+async fn check_status(resp: Response, path: &str) -> Result<Response> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+
+    let kind = match status {
+        StatusCode::NOT_FOUND => ErrorKind::NotFound,
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ErrorKind::PermissionDenied,
+        _ => ErrorKind::Other,
+    };
+    // The body is the RemoteException of the name node, which explains the error.
+    let body = resp.text().await.unwrap_or_default();
+    Err(Error::new(
+        kind,
+        anyhow!(
+            "webhdfs request on {} failed with {}: {}",
+            path,
+            status,
+            body
+        ),
+    ))
+}
+
+async fn parse_json<T: for<'de> Deserialize<'de>>(resp: Response) -> Result<T> {
+    let body = resp
+        .bytes()
+        .await
+        .map_err(|e| Error::new(ErrorKind::Other, e))?;
+    serde_json::from_slice(&body).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
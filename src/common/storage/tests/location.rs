@@ -22,6 +22,7 @@ use common_storage::StorageHttpConfig;
 use common_storage::StorageIpfsConfig;
 use common_storage::StorageParams;
 use common_storage::StorageS3Config;
+use common_storage::StorageWebhdfsConfig;
 use common_storage::UriLocation;
 use common_storage::STORAGE_GCS_DEFAULT_ENDPOINT;
 use common_storage::STORAGE_IPFS_DEFAULT_ENDPOINT;
@@ -278,6 +279,51 @@ fn test_parse_uri_location() -> Result<()> {
                 "/".to_string(),
             ),
         ),
+        (
+            "webhdfs_with_delegation",
+            UriLocation {
+                protocol: "webhdfs".to_string(),
+                name: "namenode:9870".to_string(),
+                path: "/data/".to_string(),
+                connection: vec![
+                    ("endpoint_url", "http://namenode:9870"),
+                    ("delegation", "token"),
+                ]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            },
+            (
+                StorageParams::Webhdfs(StorageWebhdfsConfig {
+                    endpoint_url: "http://namenode:9870".to_string(),
+                    root: "/data/".to_string(),
+                    user: "".to_string(),
+                    delegation: "token".to_string(),
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "webhdfs_default_endpoint",
+            UriLocation {
+                protocol: "webhdfs".to_string(),
+                name: "namenode:9871".to_string(),
+                path: "/data/1.csv".to_string(),
+                connection: vec![("user", "hadoop")]
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            },
+            (
+                StorageParams::Webhdfs(StorageWebhdfsConfig {
+                    endpoint_url: "https://namenode:9871".to_string(),
+                    root: "/".to_string(),
+                    user: "hadoop".to_string(),
+                    delegation: "".to_string(),
+                }),
+                "/data/1.csv".to_string(),
+            ),
+        ),
     ];
 
     for (name, input, expected) in cases {
@@ -24,6 +24,7 @@ mod tracing_to_jaeger;
 pub use config::Config;
 pub use config::FileConfig;
 pub use config::StderrConfig;
+pub use config::TracingConfig;
 pub use logging::init_logging;
 pub use logging::init_query_logger;
 pub use logging::QueryLogger;
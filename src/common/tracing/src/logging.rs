@@ -21,6 +21,10 @@ use common_exception::Result;
 use once_cell::sync::OnceCell;
 use opentelemetry::global;
 use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace;
+use opentelemetry::sdk::Resource;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
 use sentry_tracing::EventFilter;
 use tracing::Level;
 use tracing::Subscriber;
@@ -115,6 +119,34 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<WorkerGuard> {
     }
     let subscriber = subscriber.with(jaeger_layer);
 
+    // OpenTelemetry layer, exports the spans to the collector with OTLP.
+    // The trace context is propagated to the other nodes of the cluster by the propagator.
+    let mut otlp_layer = None;
+    if cfg.tracing.on {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let exporter = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(&cfg.tracing.otlp_endpoint);
+        let tracer =
+            opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(exporter)
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", name.to_string()),
+                ])))
+                .install_batch(opentelemetry::runtime::Tokio)
+                .expect("install");
+
+        let env_filter = EnvFilter::new(&cfg.tracing.level);
+        otlp_layer = Some(
+            tracing_opentelemetry::layer()
+                .with_tracer(tracer)
+                .with_filter(env_filter),
+        );
+    }
+    let subscriber = subscriber.with(otlp_layer);
+
     // Sentry Layer.
     // TODO: we should support config this in the future.
     let mut sentry_layer = None;
@@ -175,6 +207,7 @@ pub fn init_query_logger(
 
 pub struct QueryLogger {
     subscriber: Option<Arc<dyn Subscriber + Send + Sync>>,
+    slow_query_subscriber: Option<Arc<dyn Subscriber + Send + Sync>>,
 
     /// log_guard preserve the nonblocking logger's guards so that our logger
     /// can flushes spans/events on a drop
@@ -194,19 +227,25 @@ impl QueryLogger {
         let app_name = format!("databend-query-{}", app_name_shuffle);
         let mut _log_guards = init_logging(app_name.as_str(), config);
         let query_detail_dir = format!("{}/query-detail", config.file.dir);
+        let slow_query_dir = format!("{}/slow-query", config.file.dir);
 
         v.init(match config.file.on {
             true => {
                 let (_guards, subscriber) = init_query_logger(&app_name_shuffle, &query_detail_dir);
                 _log_guards.extend(_guards);
+                let (_guards, slow_query_subscriber) =
+                    init_query_logger(&app_name_shuffle, &slow_query_dir);
+                _log_guards.extend(_guards);
 
                 Arc::new(QueryLogger {
                     _log_guards,
                     subscriber: Some(subscriber),
+                    slow_query_subscriber: Some(slow_query_subscriber),
                 })
             }
             false => Arc::new(QueryLogger {
                 subscriber: None,
+                slow_query_subscriber: None,
                 _log_guards: vec![],
             }),
         })?;
@@ -225,4 +264,9 @@ impl QueryLogger {
     pub fn get_subscriber(&self) -> Option<Arc<dyn Subscriber + Send + Sync>> {
         self.subscriber.clone()
     }
+
+    /// The subscriber writing the slow query log to the `slow-query` dir of the log files.
+    pub fn get_slow_query_subscriber(&self) -> Option<Arc<dyn Subscriber + Send + Sync>> {
+        self.slow_query_subscriber.clone()
+    }
 }
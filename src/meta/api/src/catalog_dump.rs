@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A catalog dump is a portable copy of the keys of the catalog, e.g. the databases, the tables,
+//! the users and the stages, to restore them into a fresh meta cluster.
+//!
+//! Unlike the export of the raft data, it contains no raft logs, no membership and no nodes, so it
+//! can be imported by a cluster of any nodes.
+
+use common_meta_types::anyerror::AnyError;
+use common_meta_types::InvalidArgument;
+use common_meta_types::KVAppError;
+use common_meta_types::MetaNetworkError;
+use common_meta_types::SeqV;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::KVApi;
+
+/// The version of the format of the catalog dump.
+pub const CATALOG_DUMP_VERSION: u64 = 1;
+
+/// A line of a catalog dump, in json.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum CatalogDumpLine {
+    /// The first line of a dump.
+    ///
+    /// `max_seq` is the greatest seq of the exported keys. The ids of the databases and the tables
+    /// are the seqs of the id generators, the cluster importing the dump has to advance its seq
+    /// beyond `max_seq` not to issue the same ids again.
+    Header {
+        version: u64,
+        max_seq: u64,
+        keys: u64,
+    },
+
+    /// A key with its seq, its value and its expiration.
+    KV { key: String, value: SeqV },
+}
+
+/// A catalog dump parsed from the lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogDump {
+    pub max_seq: u64,
+    pub kvs: Vec<(String, SeqV)>,
+}
+
+impl CatalogDump {
+    /// Parses the lines of a dump, the header first and then the keys.
+    pub fn parse(lines: impl IntoIterator<Item = String>) -> Result<CatalogDump, MetaNetworkError> {
+        let mut lines = lines.into_iter().filter(|l| !l.trim().is_empty());
+
+        let (max_seq, keys) = match lines.next().map(|l| parse_line(&l)).transpose()? {
+            Some(CatalogDumpLine::Header {
+                version,
+                max_seq,
+                keys,
+            }) => {
+                if version != CATALOG_DUMP_VERSION {
+                    return Err(invalid_dump(format!(
+                        "unsupported catalog dump version {}, expect {}",
+                        version, CATALOG_DUMP_VERSION
+                    )));
+                }
+                (max_seq, keys)
+            }
+            _ => return Err(invalid_dump("catalog dump has no header")),
+        };
+
+        let mut kvs = Vec::with_capacity(keys as usize);
+        for line in lines {
+            match parse_line(&line)? {
+                CatalogDumpLine::KV { key, value } => kvs.push((key, value)),
+                CatalogDumpLine::Header { .. } => {
+                    return Err(invalid_dump("catalog dump has more than one header"));
+                }
+            }
+        }
+
+        // A truncated file must not be imported as a whole catalog.
+        if kvs.len() as u64 != keys {
+            return Err(invalid_dump(format!(
+                "catalog dump has {} keys, expect {}",
+                kvs.len(),
+                keys
+            )));
+        }
+
+        Ok(CatalogDump { max_seq, kvs })
+    }
+}
+
+/// Exports all the keys of the meta-service as the lines of a catalog dump.
+///
+/// It reads the keys by one `prefix_list_kv()`, which is consistent on a single node and on the
+/// leader of a cluster.
+pub async fn export_catalog(kv_api: &(impl KVApi + ?Sized)) -> Result<Vec<String>, KVAppError> {
+    let kvs = kv_api.prefix_list_kv("").await?;
+    let max_seq = kvs.iter().map(|(_, v)| v.seq).max().unwrap_or_default();
+
+    let mut lines = Vec::with_capacity(kvs.len() + 1);
+    lines.push(to_line(&CatalogDumpLine::Header {
+        version: CATALOG_DUMP_VERSION,
+        max_seq,
+        keys: kvs.len() as u64,
+    })?);
+    for (key, value) in kvs {
+        lines.push(to_line(&CatalogDumpLine::KV { key, value })?);
+    }
+    Ok(lines)
+}
+
+fn to_line(line: &CatalogDumpLine) -> Result<String, MetaNetworkError> {
+    serde_json::to_string(line)
+        .map_err(|e| MetaNetworkError::InvalidArgument(InvalidArgument::new(e, "")))
+}
+
+fn parse_line(line: &str) -> Result<CatalogDumpLine, MetaNetworkError> {
+    serde_json::from_str(line).map_err(|e| {
+        MetaNetworkError::InvalidArgument(InvalidArgument::new(e, "invalid catalog dump line"))
+    })
+}
+
+fn invalid_dump(msg: impl Into<String>) -> MetaNetworkError {
+    let msg = msg.into();
+    MetaNetworkError::InvalidArgument(InvalidArgument::new(AnyError::error(&msg), msg))
+}
@@ -15,6 +15,7 @@
 #![deny(unused_crate_dependencies)]
 extern crate common_meta_types;
 
+mod catalog_dump;
 mod id;
 mod id_generator;
 mod kv_api;
@@ -24,6 +25,7 @@ mod schema_api;
 mod schema_api_impl;
 mod schema_api_keys;
 mod schema_api_test_suite;
+mod schema_watch;
 mod share_api;
 mod share_api_impl;
 mod share_api_keys;
@@ -31,6 +33,10 @@ mod share_api_test_suite;
 pub(crate) mod testing;
 pub(crate) mod util;
 
+pub use catalog_dump::export_catalog;
+pub use catalog_dump::CatalogDump;
+pub use catalog_dump::CatalogDumpLine;
+pub use catalog_dump::CATALOG_DUMP_VERSION;
 pub use id::Id;
 pub(crate) use id_generator::IdGenerator;
 pub use kv_api::get_start_and_end_of_prefix;
@@ -44,6 +50,7 @@ pub use kv_api_test_suite::KVApiTestSuite;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
+pub use schema_watch::SchemaChange;
 pub use share_api::ShareApi;
 pub use share_api_test_suite::ShareApiTestSuite;
 pub use util::db_has_to_exist;
@@ -60,6 +67,7 @@ pub use util::get_u64_value;
 pub use util::is_all_db_data_removed;
 pub use util::is_db_need_to_be_remove;
 pub use util::list_keys;
+pub use util::list_struct_value;
 pub use util::list_u64_value;
 pub use util::send_txn;
 pub use util::serialize_struct;
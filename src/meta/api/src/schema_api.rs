@@ -14,6 +14,10 @@
 
 use std::sync::Arc;
 
+use common_meta_app::schema::AcquireTableLockReply;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::CommitTableReply;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
@@ -31,19 +35,28 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ReleaseTableLockReply;
+use common_meta_app::schema::ReleaseTableLockReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SwapTableReply;
+use common_meta_app::schema::SwapTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistory;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -84,6 +97,11 @@ pub trait SchemaApi: Send + Sync {
         req: RenameDatabaseReq,
     ) -> Result<RenameDatabaseReply, KVAppError>;
 
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply, KVAppError>;
+
     async fn get_database_history(
         &self,
         req: ListDatabaseReq,
@@ -93,12 +111,29 @@ pub trait SchemaApi: Send + Sync {
 
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, KVAppError>;
 
+    /// Creates a table that is invisible by its name until `commit_staged_table()`.
+    ///
+    /// A staged table never committed is purged as a dropped table.
+    async fn create_staged_table(
+        &self,
+        req: CreateTableReq,
+    ) -> Result<CreateTableReply, KVAppError>;
+
+    /// Makes a staged table visible by its name, in one transaction.
+    async fn commit_staged_table(
+        &self,
+        req: CommitTableReq,
+    ) -> Result<CommitTableReply, KVAppError>;
+
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply, KVAppError>;
 
     async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, KVAppError>;
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, KVAppError>;
 
+    /// Swaps the names of two tables of a database, in one transaction.
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, KVAppError>;
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, KVAppError>;
 
     async fn get_table_history(&self, req: ListTableReq)
@@ -111,6 +146,13 @@ pub trait SchemaApi: Send + Sync {
         table_id: MetaId,
     ) -> Result<(TableIdent, Arc<TableMeta>), KVAppError>;
 
+    /// The versions of the schema and the options of the table, a version is made by creating
+    /// the table and by each change of the definition of the table.
+    async fn get_table_meta_history(
+        &self,
+        table_id: MetaId,
+    ) -> Result<TableMetaHistory, KVAppError>;
+
     async fn get_table_copied_file_info(
         &self,
         req: GetTableCopiedFileReq,
@@ -124,6 +166,22 @@ pub trait SchemaApi: Send + Sync {
     async fn truncate_table(&self, req: TruncateTableReq)
     -> Result<TruncateTableReply, KVAppError>;
 
+    /// Acquires the lock of a table for a mutation, it fails at once with `TableLockConflict`
+    /// if the lock is held by another holder.
+    ///
+    /// The lock expires at `expire_at` unless it is acquired again by the same holder before,
+    /// so a lock held by a crashed holder does not block the table for ever.
+    async fn acquire_table_lock(
+        &self,
+        req: AcquireTableLockReq,
+    ) -> Result<AcquireTableLockReply, KVAppError>;
+
+    /// Releases the lock of a table if it is still held by the holder.
+    async fn release_table_lock(
+        &self,
+        req: ReleaseTableLockReq,
+    ) -> Result<ReleaseTableLockReply, KVAppError>;
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -134,6 +192,13 @@ pub trait SchemaApi: Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply, KVAppError>;
 
+    /// Updates the metas of the tables in one transaction, none of them is updated if one of
+    /// them fails.
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError>;
+
     // gc dropped {table|db} which out of retention time.
     async fn gc_dropped_data(
         &self,
@@ -19,6 +19,10 @@ use std::sync::Arc;
 
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Utc;
+use common_meta_app::schema::AcquireTableLockReply;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::CommitTableReply;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CountTablesKey;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
@@ -45,10 +49,14 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ReleaseTableLockReply;
+use common_meta_app::schema::ReleaseTableLockReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SwapTableReply;
+use common_meta_app::schema::SwapTableReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileLock;
 use common_meta_app::schema::TableCopiedFileLockKey;
@@ -59,7 +67,12 @@ use common_meta_app::schema::TableIdListKey;
 use common_meta_app::schema::TableIdToName;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableLockKey;
+use common_meta_app::schema::TableLockMeta;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistory;
+use common_meta_app::schema::TableMetaVersion;
+use common_meta_app::schema::TableMetaVersionIdent;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
@@ -67,6 +80,10 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -86,6 +103,7 @@ use common_meta_types::errors::app_error::DropTableWithDropTime;
 use common_meta_types::errors::app_error::ShareHasNoGrantedDatabase;
 use common_meta_types::errors::app_error::ShareHasNoGrantedPrivilege;
 use common_meta_types::errors::app_error::TableAlreadyExists;
+use common_meta_types::errors::app_error::TableLockConflict;
 use common_meta_types::errors::app_error::TableVersionMismatched;
 use common_meta_types::errors::app_error::TxnRetryMaxTimes;
 use common_meta_types::errors::app_error::UndropDbHasNoHistory;
@@ -121,6 +139,7 @@ use crate::get_struct_value;
 use crate::get_u64_value;
 use crate::is_db_need_to_be_remove;
 use crate::list_keys;
+use crate::list_struct_value;
 use crate::list_u64_value;
 use crate::send_txn;
 use crate::serialize_struct;
@@ -138,6 +157,9 @@ use crate::TXN_MAX_RETRY_TIMES;
 
 const DEFAULT_DATA_RETENTION_SECONDS: i64 = 24 * 60 * 60;
 
+/// The option of the fuse table to keep its meta and data for the days once dropped.
+const OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "data_retention_time_in_days";
+
 /// SchemaApi is implemented upon KVApi.
 /// Thus every type that impl KVApi impls SchemaApi.
 #[tonic::async_trait]
@@ -710,6 +732,73 @@ impl<KV: KVApi> SchemaApi for KV {
         )))
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let tenant_dbname = &req.name_ident;
+        let mut retry = 0;
+
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let res = get_db_or_err(
+                self,
+                tenant_dbname,
+                format!("update_database_comment: {}", &tenant_dbname),
+            )
+            .await;
+
+            let (db_id_seq, db_id, db_meta_seq, mut db_meta) = match res {
+                Ok(x) => x,
+                Err(e) => {
+                    if let KVAppError::AppError(AppError::UnknownDatabase(_)) = e {
+                        if req.if_exists {
+                            return Ok(UpdateDatabaseCommentReply {});
+                        }
+                    }
+
+                    return Err(e);
+                }
+            };
+
+            db_meta.comment = req.comment.clone();
+            db_meta.updated_on = Utc::now();
+
+            let db_id_key = DatabaseId { db_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // Prevent renaming, deleting or updating the db in other threads.
+                    txn_cond_seq(tenant_dbname, Eq, db_id_seq),
+                    txn_cond_seq(&db_id_key, Eq, db_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&db_id_key, serialize_struct(&db_meta)?), // (db_id) -> db_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&tenant_dbname),
+                database_id = debug(&db_id),
+                succ = display(succ),
+                "update_database_comment"
+            );
+
+            if succ {
+                return Ok(UpdateDatabaseCommentReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("update_database_comment", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
@@ -862,72 +951,77 @@ impl<KV: KVApi> SchemaApi for KV {
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
 
+        create_table_or_staged_table(self, req, false).await
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_staged_table(
+        &self,
+        req: CreateTableReq,
+    ) -> Result<CreateTableReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        create_table_or_staged_table(self, req, true).await
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn commit_staged_table(
+        &self,
+        req: CommitTableReq,
+    ) -> Result<CommitTableReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
         let tenant_dbname_tbname = &req.name_ident;
         let tenant_dbname = req.name_ident.db_name_ident();
+        let table_id = req.table_id;
         let mut tbcount_found = false;
         let mut tb_count = 0;
         let mut tb_count_seq;
 
-        if req.table_meta.drop_on.is_some() {
-            return Err(KVAppError::AppError(AppError::CreateTableWithDropTime(
-                CreateTableWithDropTime::new(&tenant_dbname_tbname.table_name),
-            )));
-        }
-
         let mut retry = 0;
         while retry < TXN_MAX_RETRY_TIMES {
             retry += 1;
-            // Get db by name to ensure presence
 
             let (_, db_id, db_meta_seq, db_meta) =
-                get_db_or_err(self, &tenant_dbname, "create_table").await?;
-
-            // cannot operate on shared database
-            if let Some(from_share) = db_meta.from_share {
-                return Err(KVAppError::AppError(AppError::ShareHasNoGrantedPrivilege(
-                    ShareHasNoGrantedPrivilege::new(&from_share.tenant, &from_share.share_name),
-                )));
-            }
+                get_db_or_err(self, &tenant_dbname, "commit_staged_table").await?;
 
-            // Get table by tenant,db_id, table_name to assert absence.
+            // The name may have been taken since the table is staged.
 
             let dbid_tbname = DBIdTableName {
                 db_id,
                 table_name: req.name_ident.table_name.clone(),
             };
+            let (tb_id_seq, _) = get_u64_value(self, &dbid_tbname).await?;
+            table_has_to_not_exist(tb_id_seq, tenant_dbname_tbname, "commit_staged_table")?;
 
-            let (tb_id_seq, tb_id) = get_u64_value(self, &dbid_tbname).await?;
-            if tb_id_seq > 0 {
-                return if req.if_not_exists {
-                    Ok(CreateTableReply { table_id: tb_id })
-                } else {
-                    Err(KVAppError::AppError(AppError::TableAlreadyExists(
-                        TableAlreadyExists::new(
-                            &tenant_dbname_tbname.table_name,
-                            format!("create_table: {}", tenant_dbname_tbname),
-                        ),
-                    )))
-                };
-            }
-
-            // get table id list from _fd_table_id_list/db_id/table_name
-            let dbid_tbname_idlist = TableIdListKey {
-                db_id,
-                table_name: req.name_ident.table_name.clone(),
-            };
-            let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
-                get_struct_value(self, &dbid_tbname_idlist).await?;
+            // The staged table has to be staged with the name and not be purged yet.
 
-            let mut tb_id_list = if tb_id_list_seq == 0 {
-                TableIdList::new()
-            } else {
-                match tb_id_list_opt {
-                    Some(list) => list,
-                    None => TableIdList::new(),
+            let tbid = TableId { table_id };
+            let (tb_meta_seq, tb_meta): (_, Option<TableMeta>) =
+                get_struct_value(self, &tbid).await?;
+            let mut tb_meta = match tb_meta {
+                Some(tb_meta) if tb_meta_seq > 0 && tb_meta.drop_on.is_some() => tb_meta,
+                _ => {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(table_id, "commit_staged_table"),
+                    )));
                 }
             };
 
-            // get current table count from _fd_table_count/tenant
+            let (_, staged_name): (_, Option<DBIdTableName>) =
+                get_struct_value(self, &TableIdToName { table_id }).await?;
+            if staged_name.as_ref() != Some(&dbid_tbname) {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(
+                        table_id,
+                        format!(
+                            "commit_staged_table: not staged as {}",
+                            tenant_dbname_tbname
+                        ),
+                    ),
+                )));
+            }
+
             let tb_count_key = CountTablesKey {
                 tenant: tenant_dbname.tenant.clone(),
             };
@@ -943,77 +1037,45 @@ impl<KV: KVApi> SchemaApi for KV {
                     (0, tb_count)
                 }
             };
-            // Create table by inserting these record:
-            // (db_id, table_name) -> table_id
-            // (table_id) -> table_meta
-            // append table_id into _fd_table_id_list/db_id/table_name
-            // (table_id) -> table_name
-
-            let table_id = fetch_id(self, IdGenerator::table_id()).await?;
 
-            let tbid = TableId { table_id };
+            tb_meta.drop_on = None;
 
-            // get table id name
-            let table_id_to_name_key = TableIdToName { table_id };
-            let db_id_table_name = DBIdTableName {
-                db_id,
-                table_name: req.name_ident.table_name.clone(),
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // db has not to change, i.e., no new table is created.
+                    txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+                    // no other table with the same name is inserted.
+                    txn_cond_seq(&dbid_tbname, Eq, 0),
+                    // the staged table is not changed or purged.
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                    // update table count atomicly
+                    txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
+                    txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
+                    txn_op_put(&tbid, serialize_struct(&tb_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
+                    txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
+                ],
+                else_then: vec![],
             };
 
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
             debug!(
-                table_id,
                 name = debug(&tenant_dbname_tbname),
-                "new table id"
+                id = debug(&tbid),
+                succ = display(succ),
+                "commit_staged_table"
             );
 
-            {
-                // append new table_id into list
-                tb_id_list.append(table_id);
-
-                let txn_req = TxnRequest {
-                    condition: vec![
-                        // db has not to change, i.e., no new table is created.
-                        // Renaming db is OK and does not affect the seq of db_meta.
-                        txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
-                        // no other table with the same name is inserted.
-                        txn_cond_seq(&dbid_tbname, Eq, 0),
-                        // no other table id with the same name is append.
-                        txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
-                        // update table count atomicly
-                        txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
-                        txn_cond_seq(&table_id_to_name_key, Eq, 0),
-                    ],
-                    if_then: vec![
-                        // Changing a table in a db has to update the seq of db_meta,
-                        // to block the batch-delete-tables when deleting a db.
-                        // TODO: test this when old metasrv is replaced with kv-txn based SchemaApi.
-                        txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
-                        txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
-                        txn_op_put(&tbid, serialize_struct(&req.table_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
-                        txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
-                        txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
-                        txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
-                    ],
-                    else_then: vec![],
-                };
-
-                let (succ, _responses) = send_txn(self, txn_req).await?;
-
-                debug!(
-                    name = debug(&tenant_dbname_tbname),
-                    id = debug(&tbid),
-                    succ = display(succ),
-                    "create_table"
-                );
-
-                if succ {
-                    return Ok(CreateTableReply { table_id });
-                }
+            if succ {
+                return Ok(CommitTableReply {});
             }
         }
 
         Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
-            TxnRetryMaxTimes::new("create_table", TXN_MAX_RETRY_TIMES),
+            TxnRetryMaxTimes::new("commit_staged_table", TXN_MAX_RETRY_TIMES),
         )))
     }
 
@@ -1262,6 +1324,13 @@ impl<KV: KVApi> SchemaApi for KV {
                         UndropTableWithNoDropTime::new(&tenant_dbname_tbname.table_name),
                     )));
                 }
+                // the meta and data of the table may be removed by gc at any time once out of
+                // the retention time.
+                if is_table_drop_time_out_of_retention_time(&tb_meta, &Utc::now()) {
+                    return Err(KVAppError::AppError(AppError::UndropTableHasNoHistory(
+                        UndropTableHasNoHistory::new(&tenant_dbname_tbname.table_name),
+                    )));
+                }
                 tb_meta.drop_on = None;
 
                 let txn_req = TxnRequest {
@@ -1514,6 +1583,130 @@ impl<KV: KVApi> SchemaApi for KV {
         )))
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let tenant_dbname_tbname = &req.name_ident;
+        let tenant_dbname = tenant_dbname_tbname.db_name_ident();
+        let tenant_dbname_target = TableNameIdent {
+            tenant: tenant_dbname_tbname.tenant.clone(),
+            db_name: tenant_dbname_tbname.db_name.clone(),
+            table_name: req.target_table_name.clone(),
+        };
+
+        if req.name_ident.table_name == req.target_table_name {
+            return Err(KVAppError::AppError(AppError::TableAlreadyExists(
+                TableAlreadyExists::new(
+                    &req.target_table_name,
+                    format!(
+                        "swap_table: can not swap {} with itself",
+                        tenant_dbname_tbname
+                    ),
+                ),
+            )));
+        }
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (_, db_id, db_meta_seq, db_meta) =
+                get_db_or_err(self, &tenant_dbname, "swap_table").await?;
+
+            // cannot operate on shared database
+            if let Some(from_share) = db_meta.from_share {
+                return Err(KVAppError::AppError(AppError::ShareHasNoGrantedPrivilege(
+                    ShareHasNoGrantedPrivilege::new(&from_share.tenant, &from_share.share_name),
+                )));
+            }
+
+            let mut condition = vec![
+                // db has not to change, i.e., no new table is created.
+                txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+            ];
+            let mut if_then = vec![
+                // Changing a table in a db has to update the seq of db_meta,
+                // to block the batch-delete-tables when deleting a db.
+                txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
+            ];
+
+            let (table_id, tb_id_list) =
+                get_swapped_table(self, db_id, tenant_dbname_tbname, &mut condition).await?;
+            let (target_table_id, target_tb_id_list) =
+                get_swapped_table(self, db_id, &tenant_dbname_target, &mut condition).await?;
+
+            // Each name refers to the table of the other one.
+            for (table_name, mut tb_id_list, table_id, other_table_name, other_table_id) in [
+                (
+                    &req.name_ident.table_name,
+                    tb_id_list,
+                    table_id,
+                    &req.target_table_name,
+                    target_table_id,
+                ),
+                (
+                    &req.target_table_name,
+                    target_tb_id_list,
+                    target_table_id,
+                    &req.name_ident.table_name,
+                    table_id,
+                ),
+            ] {
+                let dbid_tbname = DBIdTableName {
+                    db_id,
+                    table_name: table_name.clone(),
+                };
+                let dbid_tbname_idlist = TableIdListKey {
+                    db_id,
+                    table_name: table_name.clone(),
+                };
+                let db_id_other_table_name = DBIdTableName {
+                    db_id,
+                    table_name: other_table_name.clone(),
+                };
+
+                tb_id_list.pop();
+                tb_id_list.append(other_table_id);
+
+                if_then.push(txn_op_put(&dbid_tbname, serialize_u64(other_table_id)?)); /* (db_id, tb_name) -> tb_id */
+                if_then.push(txn_op_put(
+                    &dbid_tbname_idlist,
+                    serialize_struct(&tb_id_list)?,
+                )); /* _fd_table_id_list/db_id/table_name -> tb_id_list */
+                if_then.push(txn_op_put(
+                    &TableIdToName { table_id },
+                    serialize_struct(&db_id_other_table_name)?,
+                )); /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+            }
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&tenant_dbname_tbname),
+                target = debug(&tenant_dbname_target),
+                table_id,
+                target_table_id,
+                succ = display(succ),
+                "swap_table"
+            );
+
+            if succ {
+                return Ok(SwapTableReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("swap_table", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
@@ -1660,7 +1853,7 @@ impl<KV: KVApi> SchemaApi for KV {
 
                 // Safe unwrap() because: tb_meta_seq > 0
                 let tb_meta = tb_meta.unwrap();
-                if is_drop_time_out_of_retention_time(&tb_meta.drop_on, &now) {
+                if is_table_drop_time_out_of_retention_time(&tb_meta, &now) {
                     continue;
                 }
 
@@ -1742,6 +1935,27 @@ impl<KV: KVApi> SchemaApi for KV {
         ))
     }
 
+    async fn get_table_meta_history(
+        &self,
+        table_id: MetaId,
+    ) -> Result<TableMetaHistory, KVAppError> {
+        debug!(req = debug(&table_id), "SchemaApi: {}", func_name!());
+
+        // Assert the table exists.
+        self.get_table_by_id(table_id).await?;
+
+        let versions = list_table_meta_versions(self, table_id)
+            .await?
+            .into_iter()
+            .map(|(ident, _seq, table_meta)| TableMetaVersion {
+                version: ident.version,
+                table_meta,
+            })
+            .collect();
+
+        Ok(TableMetaHistory { versions })
+    }
+
     async fn get_table_copied_file_info(
         &self,
         req: GetTableCopiedFileReq,
@@ -1940,22 +2154,144 @@ impl<KV: KVApi> SchemaApi for KV {
     }
 
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
-    async fn upsert_table_option(
+    async fn acquire_table_lock(
         &self,
-        req: UpsertTableOptionReq,
-    ) -> Result<UpsertTableOptionReply, KVAppError> {
+        req: AcquireTableLockReq,
+    ) -> Result<AcquireTableLockReply, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
 
-        let tbid = TableId {
-            table_id: req.table_id,
-        };
-        let req_seq = req.seq;
-
-        loop {
-            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
-                get_struct_value(self, &tbid).await?;
+        let table_id = req.table_id;
+        let lock_key = TableLockKey { table_id };
 
-            debug!(ident = display(&tbid), "upsert_table_option");
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let tbid = TableId { table_id };
+            let (tb_meta_seq, _): (_, Option<TableMeta>) = get_struct_value(self, &tbid).await?;
+            if tb_meta_seq == 0 {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(table_id, "acquire_table_lock"),
+                )));
+            }
+
+            // An expired lock is removed by the meta-service, i.e. its holder is gone.
+            let (lock_seq, lock_opt): (_, Option<TableLockMeta>) =
+                get_struct_value(self, &lock_key).await?;
+
+            let lock = match lock_opt {
+                Some(lock) if lock.holder != req.holder => {
+                    return Err(KVAppError::AppError(AppError::TableLockConflict(
+                        TableLockConflict::new(
+                            table_id,
+                            lock.holder,
+                            lock.operation,
+                            "acquire_table_lock",
+                        ),
+                    )));
+                }
+                // Held by the same holder, extends the lease.
+                Some(lock) => lock,
+                None => TableLockMeta {
+                    holder: req.holder.clone(),
+                    operation: req.operation.clone(),
+                    acquired_on: Utc::now(),
+                },
+            };
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&lock_key, Eq, lock_seq)],
+                if_then: vec![txn_op_put_with_expire(
+                    &lock_key,
+                    serialize_struct(&lock)?,
+                    req.expire_at,
+                )],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                key = debug(&lock_key),
+                succ = display(succ),
+                "acquire_table_lock"
+            );
+
+            if succ {
+                return Ok(AcquireTableLockReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("acquire_table_lock", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn release_table_lock(
+        &self,
+        req: ReleaseTableLockReq,
+    ) -> Result<ReleaseTableLockReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let lock_key = TableLockKey {
+            table_id: req.table_id,
+        };
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (lock_seq, lock_opt): (_, Option<TableLockMeta>) =
+                get_struct_value(self, &lock_key).await?;
+
+            // The lock is expired, or taken by another holder since then.
+            match lock_opt {
+                Some(lock) if lock.holder == req.holder => {}
+                _ => return Ok(ReleaseTableLockReply {}),
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&lock_key, Eq, lock_seq)],
+                if_then: vec![txn_op_del(&lock_key)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                key = debug(&lock_key),
+                succ = display(succ),
+                "release_table_lock"
+            );
+
+            if succ {
+                return Ok(ReleaseTableLockReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("release_table_lock", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn upsert_table_option(
+        &self,
+        req: UpsertTableOptionReq,
+    ) -> Result<UpsertTableOptionReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+
+        loop {
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_struct_value(self, &tbid).await?;
+
+            debug!(ident = display(&tbid), "upsert_table_option");
 
             if tb_meta_seq == 0 || table_meta.is_none() {
                 return Err(KVAppError::AppError(AppError::UnknownTableId(
@@ -1972,7 +2308,8 @@ impl<KV: KVApi> SchemaApi for KV {
                     ),
                 )));
             }
-            let mut table_meta = table_meta.unwrap();
+            let old_table_meta = table_meta.unwrap();
+            let mut table_meta = old_table_meta.clone();
             // update table options
             let opts = &mut table_meta.options;
 
@@ -1986,14 +2323,28 @@ impl<KV: KVApi> SchemaApi for KV {
                     }
                 }
             }
+            let mut condition = vec![
+                // table is not changed
+                txn_cond_seq(&tbid, Eq, tb_meta_seq),
+            ];
+            let mut if_then = vec![
+                txn_op_put(&tbid, serialize_struct(&table_meta)?), // tb_id -> tb_meta
+            ];
+            if table_meta.is_definition_changed(&old_table_meta) {
+                append_table_meta_version(
+                    self,
+                    req.table_id,
+                    &old_table_meta,
+                    &table_meta,
+                    &mut condition,
+                    &mut if_then,
+                )
+                .await?;
+            }
+
             let txn_req = TxnRequest {
-                condition: vec![
-                    // table is not changed
-                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                ],
-                if_then: vec![
-                    txn_op_put(&tbid, serialize_struct(&table_meta)?), // tb_id -> tb_meta
-                ],
+                condition,
+                if_then,
                 else_then: vec![],
             };
 
@@ -2018,50 +2369,57 @@ impl<KV: KVApi> SchemaApi for KV {
     ) -> Result<UpdateTableMetaReply, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
 
-        let tbid = TableId {
-            table_id: req.table_id,
-        };
-        let req_seq = req.seq;
-
         loop {
-            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
-                get_struct_value(self, &tbid).await?;
+            let mut condition = vec![];
+            let mut if_then = vec![];
+            append_update_table_meta(self, &req, &mut condition, &mut if_then).await?;
 
-            debug!(ident = display(&tbid), "update_table_meta");
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
 
-            if tb_meta_seq == 0 || table_meta.is_none() {
-                return Err(KVAppError::AppError(AppError::UnknownTableId(
-                    UnknownTableId::new(req.table_id, "update_table_meta"),
-                )));
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id = debug(&req.table_id),
+                succ = display(succ),
+                "update_table_meta"
+            );
+
+            if succ {
+                return Ok(UpdateTableMetaReply {});
             }
-            if req_seq.match_seq(tb_meta_seq).is_err() {
-                return Err(KVAppError::AppError(AppError::from(
-                    TableVersionMismatched::new(
-                        req.table_id,
-                        req.seq,
-                        tb_meta_seq,
-                        "update_table_meta",
-                    ),
-                )));
+        }
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut condition = vec![];
+            let mut if_then = vec![];
+            for update in req.update_table_metas.iter() {
+                append_update_table_meta(self, update, &mut condition, &mut if_then).await?;
             }
 
             let txn_req = TxnRequest {
-                condition: vec![
-                    // table is not changed
-                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                ],
-                if_then: vec![
-                    txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?), // tb_id -> tb_meta
-                ],
+                condition,
+                if_then,
                 else_then: vec![],
             };
 
             let (succ, _responses) = send_txn(self, txn_req).await?;
 
-            debug!(id = debug(&tbid), succ = display(succ), "update_table_meta");
+            debug!(succ = display(succ), "update_multi_table_meta");
 
             if succ {
-                return Ok(UpdateTableMetaReply {});
+                return Ok(UpdateMultiTableMetaReply {});
             }
         }
     }
@@ -2153,6 +2511,249 @@ impl<KV: KVApi> SchemaApi for KV {
     }
 }
 
+/// Creates a table, or a staged table if `staged` is true.
+///
+/// A staged table is written as a dropped table: it has a `drop_on`, is in the table id list
+/// and has a id-to-name mapping, but its name does not refer to it. It is invisible until
+/// `commit_staged_table()` is called, or is purged by the gc of the dropped tables if the
+/// creation is never committed, e.g., the query node creating it is down.
+async fn create_table_or_staged_table(
+    kv_api: &impl KVApi,
+    req: CreateTableReq,
+    staged: bool,
+) -> Result<CreateTableReply, KVAppError> {
+    let tenant_dbname_tbname = &req.name_ident;
+    let tenant_dbname = req.name_ident.db_name_ident();
+    let mut tbcount_found = false;
+    let mut tb_count = 0;
+    let mut tb_count_seq;
+
+    if req.table_meta.drop_on.is_some() {
+        return Err(KVAppError::AppError(AppError::CreateTableWithDropTime(
+            CreateTableWithDropTime::new(&tenant_dbname_tbname.table_name),
+        )));
+    }
+
+    let mut retry = 0;
+    while retry < TXN_MAX_RETRY_TIMES {
+        retry += 1;
+        // Get db by name to ensure presence
+
+        let (_, db_id, db_meta_seq, db_meta) =
+            get_db_or_err(kv_api, &tenant_dbname, "create_table").await?;
+
+        // cannot operate on shared database
+        if let Some(from_share) = db_meta.from_share {
+            return Err(KVAppError::AppError(AppError::ShareHasNoGrantedPrivilege(
+                ShareHasNoGrantedPrivilege::new(&from_share.tenant, &from_share.share_name),
+            )));
+        }
+
+        // Get table by tenant,db_id, table_name to assert absence.
+
+        let dbid_tbname = DBIdTableName {
+            db_id,
+            table_name: req.name_ident.table_name.clone(),
+        };
+
+        let (tb_id_seq, tb_id) = get_u64_value(kv_api, &dbid_tbname).await?;
+        if tb_id_seq > 0 {
+            return if req.if_not_exists && !staged {
+                Ok(CreateTableReply { table_id: tb_id })
+            } else {
+                Err(KVAppError::AppError(AppError::TableAlreadyExists(
+                    TableAlreadyExists::new(
+                        &tenant_dbname_tbname.table_name,
+                        format!("create_table: {}", tenant_dbname_tbname),
+                    ),
+                )))
+            };
+        }
+
+        // get table id list from _fd_table_id_list/db_id/table_name
+        let dbid_tbname_idlist = TableIdListKey {
+            db_id,
+            table_name: req.name_ident.table_name.clone(),
+        };
+        let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
+            get_struct_value(kv_api, &dbid_tbname_idlist).await?;
+
+        let mut tb_id_list = if tb_id_list_seq == 0 {
+            TableIdList::new()
+        } else {
+            match tb_id_list_opt {
+                Some(list) => list,
+                None => TableIdList::new(),
+            }
+        };
+
+        // get current table count from _fd_table_count/tenant
+        let tb_count_key = CountTablesKey {
+            tenant: tenant_dbname.tenant.clone(),
+        };
+        (tb_count_seq, tb_count) = {
+            let (seq, count) = get_u64_value(kv_api, &tb_count_key).await?;
+            if seq > 0 {
+                (seq, count)
+            } else if !tbcount_found {
+                // only count_tables for the first time.
+                tbcount_found = true;
+                (0, count_tables(kv_api, &tb_count_key).await?)
+            } else {
+                (0, tb_count)
+            }
+        };
+        // Create table by inserting these record:
+        // (db_id, table_name) -> table_id
+        // (table_id) -> table_meta
+        // append table_id into _fd_table_id_list/db_id/table_name
+        // (table_id) -> table_name
+
+        let table_id = fetch_id(kv_api, IdGenerator::table_id()).await?;
+
+        let tbid = TableId { table_id };
+
+        // get table id name
+        let table_id_to_name_key = TableIdToName { table_id };
+        let db_id_table_name = DBIdTableName {
+            db_id,
+            table_name: req.name_ident.table_name.clone(),
+        };
+
+        // the first version of the table meta
+        let tb_meta_version = TableMetaVersionIdent {
+            table_id,
+            version: 1,
+        };
+
+        debug!(
+            table_id,
+            name = debug(&tenant_dbname_tbname),
+            "new table id"
+        );
+
+        {
+            // append new table_id into list
+            tb_id_list.append(table_id);
+
+            let mut condition = vec![
+                // db has not to change, i.e., no new table is created.
+                // Renaming db is OK and does not affect the seq of db_meta.
+                txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+                // no other table with the same name is inserted.
+                txn_cond_seq(&dbid_tbname, Eq, 0),
+                // no other table id with the same name is append.
+                txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
+                txn_cond_seq(&table_id_to_name_key, Eq, 0),
+            ];
+
+            let mut table_meta = req.table_meta.clone();
+            if staged {
+                // A staged table is a dropped one until it is committed.
+                table_meta.drop_on = Some(Utc::now());
+            }
+
+            let mut if_then = vec![
+                // Changing a table in a db has to update the seq of db_meta,
+                // to block the batch-delete-tables when deleting a db.
+                // TODO: test this when old metasrv is replaced with kv-txn based SchemaApi.
+                txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
+                txn_op_put(&tbid, serialize_struct(&table_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
+                txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
+                txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+                txn_op_put(&tb_meta_version, serialize_struct(&req.table_meta)?), /* __fd_table_meta_history/table_id/1 -> tb_meta */
+            ];
+
+            // The name and the count of a staged table are written by `commit_staged_table()`.
+            if !staged {
+                // update table count atomicly
+                condition.push(txn_cond_seq(&tb_count_key, Eq, tb_count_seq));
+
+                if_then.push(txn_op_put(&dbid_tbname, serialize_u64(table_id)?)); /* (tenant, db_id, tb_name) -> tb_id */
+                if_then.push(txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?)); /* _fd_table_count/tenant -> tb_count */
+            }
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(kv_api, txn_req).await?;
+
+            debug!(
+                name = debug(&tenant_dbname_tbname),
+                id = debug(&tbid),
+                succ = display(succ),
+                staged,
+                "create_table"
+            );
+
+            if succ {
+                return Ok(CreateTableReply { table_id });
+            }
+        }
+    }
+
+    Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+        TxnRetryMaxTimes::new("create_table", TXN_MAX_RETRY_TIMES),
+    )))
+}
+
+/// Returns the id and the id list of a table to swap, and adds the conditions that the name,
+/// the id list and the id-to-name mapping of the table are not changed.
+async fn get_swapped_table(
+    kv_api: &impl KVApi,
+    db_id: u64,
+    name_ident: &TableNameIdent,
+    condition: &mut Vec<TxnCondition>,
+) -> Result<(u64, TableIdList), KVAppError> {
+    let dbid_tbname = DBIdTableName {
+        db_id,
+        table_name: name_ident.table_name.clone(),
+    };
+    let (tb_id_seq, table_id) = get_u64_value(kv_api, &dbid_tbname).await?;
+    table_has_to_exist(tb_id_seq, name_ident, "swap_table")?;
+
+    let dbid_tbname_idlist = TableIdListKey {
+        db_id,
+        table_name: name_ident.table_name.clone(),
+    };
+    let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
+        get_struct_value(kv_api, &dbid_tbname_idlist).await?;
+    let tb_id_list = match tb_id_list_opt {
+        Some(list) if list.last() == Some(&table_id) => list,
+        // the table is created before the id list is added
+        None => {
+            let mut list = TableIdList::new();
+            list.append(table_id);
+            list
+        }
+        Some(_) => {
+            return Err(KVAppError::AppError(AppError::UnknownTable(
+                UnknownTable::new(
+                    &name_ident.table_name,
+                    format!("swap_table: {}", name_ident),
+                ),
+            )));
+        }
+    };
+
+    let table_id_to_name_key = TableIdToName { table_id };
+    let (table_id_to_name_seq, _): (_, Option<DBIdTableName>) =
+        get_struct_value(kv_api, &table_id_to_name_key).await?;
+
+    condition.push(txn_cond_seq(&dbid_tbname, Eq, tb_id_seq));
+    condition.push(txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq));
+    condition.push(txn_cond_seq(
+        &table_id_to_name_key,
+        Eq,
+        table_id_to_name_seq,
+    ));
+
+    Ok((table_id, tb_id_list))
+}
+
 async fn remove_table_copied_files(
     kv_api: &impl KVApi,
     table_id: u64,
@@ -2188,6 +2789,114 @@ async fn remove_table_copied_files(
     Ok(())
 }
 
+/// Lists the versions of the table meta, the oldest first.
+/// Appends the conditions and the operations of updating the meta of a table to a transaction.
+///
+/// The table must be of the version of the request when the transaction is sent, the versions
+/// of the definition of the table are kept as well if it's changed.
+async fn append_update_table_meta(
+    kv_api: &impl KVApi,
+    req: &UpdateTableMetaReq,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    let tbid = TableId {
+        table_id: req.table_id,
+    };
+    let (tb_meta_seq, table_meta): (_, Option<TableMeta>) = get_struct_value(kv_api, &tbid).await?;
+
+    debug!(ident = display(&tbid), "update_table_meta");
+
+    if tb_meta_seq == 0 || table_meta.is_none() {
+        return Err(KVAppError::AppError(AppError::UnknownTableId(
+            UnknownTableId::new(req.table_id, "update_table_meta"),
+        )));
+    }
+    if req.seq.match_seq(tb_meta_seq).is_err() {
+        return Err(KVAppError::AppError(AppError::from(
+            TableVersionMismatched::new(req.table_id, req.seq, tb_meta_seq, "update_table_meta"),
+        )));
+    }
+
+    // table is not changed
+    condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+    // tb_id -> tb_meta
+    if_then.push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+    // Safe unwrap() because: tb_meta_seq > 0
+    let old_table_meta = table_meta.unwrap();
+    if req.new_table_meta.is_definition_changed(&old_table_meta) {
+        append_table_meta_version(
+            kv_api,
+            req.table_id,
+            &old_table_meta,
+            &req.new_table_meta,
+            condition,
+            if_then,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn list_table_meta_versions(
+    kv_api: &impl KVApi,
+    table_id: u64,
+) -> Result<Vec<(TableMetaVersionIdent, u64, TableMeta)>, KVAppError> {
+    let prefix = format!("{}/{}/", TableMetaVersionIdent::PREFIX, table_id);
+    let mut versions: Vec<(TableMetaVersionIdent, u64, TableMeta)> =
+        list_struct_value(kv_api, &prefix).await?;
+    // The keys are listed in the order of the strings, "10" is before "9".
+    versions.sort_by_key(|(ident, _, _)| ident.version);
+    Ok(versions)
+}
+
+/// Appends the new table meta as the next version of the history in the txn, the old table meta
+/// is appended first if the table was created without the history.
+async fn append_table_meta_version(
+    kv_api: &impl KVApi,
+    table_id: u64,
+    old_table_meta: &TableMeta,
+    new_table_meta: &TableMeta,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    let versions = list_table_meta_versions(kv_api, table_id).await?;
+    let mut version = match versions.last() {
+        Some((ident, _, _)) => ident.version,
+        None => {
+            let ident = TableMetaVersionIdent {
+                table_id,
+                version: 1,
+            };
+            condition.push(txn_cond_seq(&ident, Eq, 0));
+            if_then.push(txn_op_put(&ident, serialize_struct(old_table_meta)?));
+            1
+        }
+    };
+
+    version += 1;
+    let ident = TableMetaVersionIdent { table_id, version };
+    let mut table_meta = new_table_meta.clone();
+    table_meta.updated_on = Utc::now();
+    condition.push(txn_cond_seq(&ident, Eq, 0));
+    if_then.push(txn_op_put(&ident, serialize_struct(&table_meta)?)); // table_id/version -> tb_meta
+
+    Ok(())
+}
+
+async fn remove_table_meta_history(
+    kv_api: &impl KVApi,
+    table_id: u64,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    for (ident, seq, _) in list_table_meta_versions(kv_api, table_id).await? {
+        condition.push(txn_cond_seq(&ident, Eq, seq));
+        if_then.push(txn_op_del(&ident));
+    }
+    Ok(())
+}
+
 async fn gc_dropped_table(
     kv_api: &impl KVApi,
     tenant: String,
@@ -2263,7 +2972,7 @@ async fn gc_dropped_table(
                 }
                 // Safe unwrap() because: tb_meta_seq > 0
                 let tb_meta = tb_meta.unwrap();
-                if is_drop_time_out_of_retention_time(&tb_meta.drop_on, &now) {
+                if is_table_drop_time_out_of_retention_time(&tb_meta, &now) {
                     remove_table_keys.push((tbid.clone(), tb_meta_seq));
                     remove_table_id_mappings.push((id_to_name, name_seq));
                     continue;
@@ -2296,6 +3005,10 @@ async fn gc_dropped_table(
                 // remove stage file info of the table
                 remove_table_copied_files(kv_api, key.0.table_id, &mut condition, &mut if_then)
                     .await?;
+
+                // remove the versions of the table meta
+                remove_table_meta_history(kv_api, key.0.table_id, &mut condition, &mut if_then)
+                    .await?;
             }
             // remove table_id -> table_name mappings
             for (key, seq) in remove_table_id_mappings.iter() {
@@ -2445,9 +3158,29 @@ async fn gc_dropped_db(
 fn is_drop_time_out_of_retention_time(
     drop_on: &Option<DateTime<Utc>>,
     now: &DateTime<Utc>,
+) -> bool {
+    is_drop_time_out_of_retention_seconds(drop_on, now, DEFAULT_DATA_RETENTION_SECONDS)
+}
+
+// Same as `is_drop_time_out_of_retention_time`, but a table keeps its meta and data for the
+// days of its `data_retention_time_in_days` option once dropped, if it is set.
+fn is_table_drop_time_out_of_retention_time(tb_meta: &TableMeta, now: &DateTime<Utc>) -> bool {
+    let retention_seconds = tb_meta
+        .options
+        .get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS)
+        .and_then(|days| days.parse::<i64>().ok())
+        .map(|days| days * 24 * 60 * 60)
+        .unwrap_or(DEFAULT_DATA_RETENTION_SECONDS);
+    is_drop_time_out_of_retention_seconds(&tb_meta.drop_on, now, retention_seconds)
+}
+
+fn is_drop_time_out_of_retention_seconds(
+    drop_on: &Option<DateTime<Utc>>,
+    now: &DateTime<Utc>,
+    retention_seconds: i64,
 ) -> bool {
     if let Some(drop_on) = drop_on {
-        return now.timestamp() - drop_on.timestamp() >= DEFAULT_DATA_RETENTION_SECONDS;
+        return now.timestamp() - drop_on.timestamp() >= retention_seconds;
     }
 
     false
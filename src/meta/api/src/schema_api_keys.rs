@@ -25,6 +25,8 @@ use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdListKey;
 use common_meta_app::schema::TableIdToName;
+use common_meta_app::schema::TableLockKey;
+use common_meta_app::schema::TableMetaVersionIdent;
 use kv_api_key::check_segment;
 use kv_api_key::check_segment_absent;
 use kv_api_key::check_segment_present;
@@ -47,6 +49,8 @@ const PREFIX_DATABASE_ID_TO_NAME: &str = "__fd_database_id_to_name";
 const PREFIX_TABLE_ID_TO_NAME: &str = "__fd_table_id_to_name";
 const PREFIX_TABLE_COPIED_FILES: &str = "__fd_table_copied_files";
 const PREFIX_TABLE_COPIED_FILES_LOCK: &str = "__fd_table_copied_file_lock";
+const PREFIX_TABLE_META_HISTORY: &str = "__fd_table_meta_history";
+const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
 
 pub(crate) const ID_GEN_TABLE: &str = "table_id";
 pub(crate) const ID_GEN_DATABASE: &str = "database_id";
@@ -346,9 +350,60 @@ impl KVApiKey for TableCopiedFileLockKey {
     }
 }
 
+/// "__fd_table_lock/<table_id>" -> TableLockMeta
+impl KVApiKey for TableLockKey {
+    const PREFIX: &'static str = PREFIX_TABLE_LOCK;
+
+    fn to_key(&self) -> String {
+        format!("{}/{}", Self::PREFIX, self.table_id)
+    }
+
+    fn from_key(s: &str) -> Result<Self, KVApiKeyError> {
+        let mut elts = s.split('/');
+
+        let prefix = check_segment_present(elts.next(), 0, s)?;
+        check_segment(prefix, 0, Self::PREFIX)?;
+
+        let table_id = check_segment_present(elts.next(), 1, s)?;
+        let table_id = decode_id(table_id)?;
+
+        check_segment_absent(elts.next(), 2, s)?;
+
+        Ok(TableLockKey { table_id })
+    }
+}
+
+/// "__fd_table_meta_history/<table_id>/<version>" -> TableMeta
+impl KVApiKey for TableMetaVersionIdent {
+    const PREFIX: &'static str = PREFIX_TABLE_META_HISTORY;
+
+    fn to_key(&self) -> String {
+        format!("{}/{}/{}", Self::PREFIX, self.table_id, self.version)
+    }
+
+    fn from_key(s: &str) -> Result<Self, KVApiKeyError> {
+        let mut elts = s.split('/');
+
+        let prefix = check_segment_present(elts.next(), 0, s)?;
+        check_segment(prefix, 0, Self::PREFIX)?;
+
+        let table_id = check_segment_present(elts.next(), 1, s)?;
+        let table_id = decode_id(table_id)?;
+
+        let version = check_segment_present(elts.next(), 2, s)?;
+        let version = decode_id(version)?;
+
+        check_segment_absent(elts.next(), 3, s)?;
+
+        Ok(TableMetaVersionIdent { table_id, version })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common_meta_app::schema::TableCopiedFileNameIdent;
+    use common_meta_app::schema::TableLockKey;
+    use common_meta_app::schema::TableMetaVersionIdent;
 
     use crate::kv_api_key::KVApiKey;
     use crate::KVApiKeyError;
@@ -399,4 +454,31 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_table_meta_version_ident_conversion() -> Result<(), KVApiKeyError> {
+        let ident = TableMetaVersionIdent {
+            table_id: 2,
+            version: 10,
+        };
+
+        let key = ident.to_key();
+        assert_eq!(key, "__fd_table_meta_history/2/10");
+        assert_eq!(TableMetaVersionIdent::from_key(&key)?, ident);
+
+        let res = TableMetaVersionIdent::from_key("__fd_table_meta_history/2");
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_lock_key_conversion() -> Result<(), KVApiKeyError> {
+        let key = TableLockKey { table_id: 3 };
+        assert_eq!(key.to_key(), "__fd_table_lock/3");
+        assert_eq!(TableLockKey::from_key("__fd_table_lock/3")?, key);
+
+        let res = TableLockKey::from_key("__fd_table_lock/3/4");
+        assert!(res.is_err());
+        Ok(())
+    }
 }
@@ -20,6 +20,8 @@ use common_datavalues::chrono::Duration;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
@@ -39,8 +41,10 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
+use common_meta_app::schema::ReleaseTableLockReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SwapTableReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -55,6 +59,8 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
@@ -226,6 +232,7 @@ impl SchemaApiTestSuite {
         suite.database_list(&b.build().await).await?;
         suite.database_list_in_diff_tenant(&b.build().await).await?;
         suite.database_rename(&b.build().await).await?;
+        suite.database_update_comment(&b.build().await).await?;
         suite
             .database_drop_undrop_list_history(&b.build().await)
             .await?;
@@ -237,6 +244,8 @@ impl SchemaApiTestSuite {
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
+        suite.table_meta_history(&b.build().await).await?;
+        suite.table_staged_create_and_swap(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite
             .table_drop_undrop_list_history(&b.build().await)
@@ -250,6 +259,11 @@ impl SchemaApiTestSuite {
         suite
             .table_drop_out_of_retention_time_history(&b.build().await)
             .await?;
+        suite
+            .table_drop_undrop_retention_time(&b.build().await)
+            .await?;
+        suite.table_lock_acquire_release(&b.build().await).await?;
+        suite.update_multi_table_meta(&b.build().await).await?;
         suite.get_table_by_id(&b.build().await).await?;
         suite.get_table_copied_file(&b.build().await).await?;
         suite.truncate_table(&b.build().await).await?;
@@ -1162,6 +1176,61 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn database_update_comment<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+
+        let name_ident = DatabaseNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db_name.to_string(),
+        };
+
+        info!("--- update the comment of not exists db1");
+        {
+            let req = UpdateDatabaseCommentReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+                comment: "comment1".to_string(),
+            };
+
+            let res = mt.update_database_comment(req).await;
+            info!("update database comment res: {:?}", res);
+            assert!(res.is_err());
+            assert_eq!(
+                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+
+            let req = UpdateDatabaseCommentReq {
+                if_exists: true,
+                name_ident: name_ident.clone(),
+                comment: "comment1".to_string(),
+            };
+            mt.update_database_comment(req).await?;
+        }
+
+        info!("--- update the comment of db1");
+        {
+            self.create_database(mt, tenant, db_name, "eng1").await?;
+
+            let req = UpdateDatabaseCommentReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+                comment: "comment1".to_string(),
+            };
+            mt.update_database_comment(req).await?;
+
+            let res = mt
+                .get_database(GetDatabaseReq::new(tenant, db_name))
+                .await?;
+            assert_eq!("comment1", res.meta.comment);
+            assert_eq!("eng1", res.meta.engine);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn database_drop_undrop_list_history<MT: SchemaApi>(
         &self,
@@ -2216,6 +2285,284 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_staged_create_and_swap<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb2";
+        let other_tbl_name = "tb3";
+
+        let schema = || {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )]))
+        };
+
+        let table_meta = TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            ..TableMeta::default()
+        };
+
+        let create_req = |table_name: &str| CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta: table_meta.clone(),
+        };
+
+        info!("--- prepare db");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+        }
+
+        info!("--- a staged table is invisible by its name");
+        let staged_id = {
+            let staged_id = mt.create_staged_table(create_req(tbl_name)).await?.table_id;
+
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(got.unwrap_err()).code()
+            );
+
+            let (_, staged_meta) = mt.get_table_by_id(staged_id).await?;
+            assert!(staged_meta.drop_on.is_some());
+            staged_id
+        };
+
+        info!("--- commit the staged table");
+        {
+            mt.commit_staged_table(CommitTableReq {
+                name_ident: create_req(tbl_name).name_ident,
+                table_id: staged_id,
+            })
+            .await?;
+
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(staged_id, got.ident.table_id);
+            assert!(got.meta.drop_on.is_none());
+        }
+
+        info!("--- commit a staged table whose name is taken");
+        {
+            let staged_id = mt
+                .create_staged_table(create_req(other_tbl_name))
+                .await?
+                .table_id;
+            let other_id = mt.create_table(create_req(other_tbl_name)).await?.table_id;
+
+            let got = mt
+                .commit_staged_table(CommitTableReq {
+                    name_ident: create_req(other_tbl_name).name_ident,
+                    table_id: staged_id,
+                })
+                .await;
+            assert_eq!(
+                ErrorCode::TableAlreadyExists("").code(),
+                ErrorCode::from(got.unwrap_err()).code()
+            );
+
+            let got = mt
+                .get_table((tenant, db_name, other_tbl_name).into())
+                .await?;
+            assert_eq!(other_id, got.ident.table_id);
+        }
+
+        info!("--- swap two tables");
+        {
+            let tb_id = mt
+                .get_table((tenant, db_name, tbl_name).into())
+                .await?
+                .ident
+                .table_id;
+            let other_id = mt
+                .get_table((tenant, db_name, other_tbl_name).into())
+                .await?
+                .ident
+                .table_id;
+
+            mt.swap_table(SwapTableReq {
+                name_ident: create_req(tbl_name).name_ident,
+                target_table_name: other_tbl_name.to_string(),
+            })
+            .await?;
+
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(other_id, got.ident.table_id);
+            let got = mt
+                .get_table((tenant, db_name, other_tbl_name).into())
+                .await?;
+            assert_eq!(tb_id, got.ident.table_id);
+
+            let names = mt
+                .list_tables(ListTableReq::new(tenant, db_name))
+                .await?
+                .iter()
+                .map(|t| (t.name.clone(), t.ident.table_id))
+                .collect::<BTreeMap<_, _>>();
+            assert_eq!(Some(&other_id), names.get(tbl_name));
+            assert_eq!(Some(&tb_id), names.get(other_tbl_name));
+        }
+
+        info!("--- swap with an unknown table");
+        {
+            let got = mt
+                .swap_table(SwapTableReq {
+                    name_ident: create_req(tbl_name).name_ident,
+                    target_table_name: "unknown".to_string(),
+                })
+                .await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(got.unwrap_err()).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_meta_history<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb2";
+
+        let schema = || {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )]))
+        };
+
+        let created_on = Utc::now();
+        let table_meta = TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            created_on,
+            updated_on: created_on,
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and table");
+        let table_id = {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: table_meta.clone(),
+            };
+            mt.create_table(req).await?.table_id
+        };
+
+        info!("--- the created table has the first version");
+        {
+            let history = mt.get_table_meta_history(table_id).await?;
+            assert_eq!(1, history.versions.len());
+            assert_eq!(1, history.versions[0].version);
+            assert_eq!(table_meta.schema, history.versions[0].table_meta.schema);
+        }
+
+        info!("--- upsert an option adds a version");
+        {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            mt.upsert_table_option(UpsertTableOptionReq::new(&table.ident, "key1", "val1"))
+                .await?;
+
+            let history = mt.get_table_meta_history(table_id).await?;
+            assert_eq!(2, history.versions.len());
+            let latest = history.latest().unwrap();
+            assert_eq!(2, latest.version);
+            assert_eq!(
+                Some(&"val1".to_string()),
+                latest.table_meta.options.get("key1")
+            );
+        }
+
+        info!("--- upsert the snapshot location adds no version");
+        {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            mt.upsert_table_option(UpsertTableOptionReq::new(
+                &table.ident,
+                "snapshot_location",
+                "_ss/1",
+            ))
+            .await?;
+
+            let history = mt.get_table_meta_history(table_id).await?;
+            assert_eq!(2, history.versions.len());
+        }
+
+        info!("--- update the cluster keys adds a version");
+        {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.default_cluster_key = Some("(number)".to_string());
+            new_table_meta.default_cluster_key_id = Some(0);
+            new_table_meta.cluster_keys = vec!["(number)".to_string()];
+            new_table_meta.updated_on = Utc::now();
+            mt.update_table_meta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta,
+            })
+            .await?;
+
+            let history = mt.get_table_meta_history(table_id).await?;
+            assert_eq!(
+                vec![1, 2, 3],
+                history
+                    .versions
+                    .iter()
+                    .map(|v| v.version)
+                    .collect::<Vec<_>>()
+            );
+            let latest = history.latest().unwrap();
+            assert_eq!(
+                Some("(number)".to_string()),
+                latest.table_meta.default_cluster_key
+            );
+
+            // The version in effect at the creation of the table is the first one.
+            let first = history.version_at(&created_on).unwrap();
+            assert_eq!(1, first.version);
+            let at_now = history.version_at(&Utc::now()).unwrap();
+            assert_eq!(3, at_now.version);
+            assert!(
+                history
+                    .version_at(&(created_on - Duration::days(1)))
+                    .is_none()
+            );
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn database_drop_out_of_retention_time_history<MT: SchemaApi + AsKVApi>(
         self,
@@ -2676,6 +3023,316 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_drop_undrop_retention_time<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant_table_drop_undrop_retention_time";
+        let db_name = "db1_table_drop_undrop_retention_time";
+        let tbl_name = "tb1_table_drop_undrop_retention_time";
+        let tbl_name_ident = TableNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db_name.to_string(),
+            table_name: tbl_name.to_string(),
+        };
+
+        let schema = || {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )]))
+        };
+        let table_meta = |retention_days: &str| TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            options: maplit::btreemap! {
+                "data_retention_time_in_days".into() => retention_days.into(),
+            },
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+        }
+
+        info!("--- drop and undrop a table within its retention time");
+        {
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: tbl_name_ident.clone(),
+                table_meta: table_meta("1"),
+            };
+            mt.create_table(req).await?;
+            mt.drop_table(DropTableReq {
+                if_exists: false,
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+
+            let res = mt
+                .get_table_history(ListTableReq::new(tenant, db_name))
+                .await?;
+            assert_eq!(res.len(), 1);
+
+            mt.undrop_table(UndropTableReq {
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert!(got.meta.drop_on.is_none());
+        }
+
+        info!("--- a table with no retention time can not be undropped once dropped");
+        {
+            mt.drop_table(DropTableReq {
+                if_exists: false,
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: tbl_name_ident.clone(),
+                table_meta: table_meta("0"),
+            };
+            mt.create_table(req).await?;
+            mt.drop_table(DropTableReq {
+                if_exists: false,
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+
+            // only the first table, dropped within its retention time, is listed.
+            let res = mt
+                .get_table_history(ListTableReq::new(tenant, db_name))
+                .await?;
+            assert_eq!(res.len(), 1);
+            assert_eq!(
+                res[0].meta.options.get("data_retention_time_in_days"),
+                Some(&"1".to_string())
+            );
+
+            let res = mt
+                .undrop_table(UndropTableReq {
+                    name_ident: tbl_name_ident.clone(),
+                })
+                .await;
+            assert_eq!(
+                ErrorCode::UndropTableHasNoHistory("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_lock_acquire_release<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant_table_lock_acquire_release";
+        let db_name = "db1_table_lock_acquire_release";
+        let tbl_name = "tb1_table_lock_acquire_release";
+
+        let schema = || {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )]))
+        };
+
+        info!("--- prepare db and table");
+        let table_id = {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta {
+                    schema: schema(),
+                    engine: "JSON".to_string(),
+                    ..TableMeta::default()
+                },
+            };
+            mt.create_table(req).await?.table_id
+        };
+
+        let expire_at = (Utc::now().timestamp() + 60) as u64;
+        let acquire = |holder: &str, expire_at: u64| AcquireTableLockReq {
+            table_id,
+            holder: holder.to_string(),
+            operation: "delete".to_string(),
+            expire_at,
+        };
+        let release = |holder: &str| ReleaseTableLockReq {
+            table_id,
+            holder: holder.to_string(),
+        };
+
+        info!("--- the lock is held by one holder at a time");
+        {
+            mt.acquire_table_lock(acquire("q1", expire_at)).await?;
+            // acquired again by the holder to extend the lease.
+            mt.acquire_table_lock(acquire("q1", expire_at + 60)).await?;
+
+            let res = mt.acquire_table_lock(acquire("q2", expire_at)).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::TableLockConflict("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            // released only by its holder.
+            mt.release_table_lock(release("q2")).await?;
+            let res = mt.acquire_table_lock(acquire("q2", expire_at)).await;
+            assert!(res.is_err());
+
+            mt.release_table_lock(release("q1")).await?;
+            mt.acquire_table_lock(acquire("q2", expire_at)).await?;
+            mt.release_table_lock(release("q2")).await?;
+        }
+
+        info!("--- an expired lock does not block the others");
+        {
+            let expired = (Utc::now().timestamp() - 1) as u64;
+            mt.acquire_table_lock(acquire("q1", expired)).await?;
+            mt.acquire_table_lock(acquire("q2", expire_at)).await?;
+            mt.release_table_lock(release("q2")).await?;
+        }
+
+        info!("--- lock an unknown table");
+        {
+            let res = mt
+                .acquire_table_lock(AcquireTableLockReq {
+                    table_id: table_id + 1000,
+                    ..acquire("q1", expire_at)
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTableId("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn update_multi_table_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant_update_multi_table_meta";
+        let db_name = "db1_update_multi_table_meta";
+
+        let schema = || {
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )]))
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in ["tb1", "tb2"] {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta {
+                        schema: schema(),
+                        engine: "JSON".to_string(),
+                        ..TableMeta::default()
+                    },
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update = |table: &TableInfo, data_bytes: u64, seq: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+            }
+        };
+
+        info!("--- update the tables at once");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![
+                    update(&tb1, 1, tb1.ident.seq),
+                    update(&tb2, 2, tb2.ident.seq),
+                ],
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta.statistics.data_bytes, 1);
+            assert_eq!(tb2.meta.statistics.data_bytes, 2);
+        }
+
+        info!("--- none of the tables is updated if a version mismatches");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update(&tb1, 3, tb1.ident.seq),
+                        update(&tb2, 4, tb2.ident.seq + 1),
+                    ],
+                })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::table_version_mismatched_code(), err.code());
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta.statistics.data_bytes, 1);
+            assert_eq!(tb2.meta.statistics.data_bytes, 2);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_drop_undrop_list_history<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant_drop_undrop_list_history_db1";
@@ -0,0 +1,164 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Watches the changes of the databases and the tables in the meta-service.
+//!
+//! A query node subscribes to the watch streams of `SchemaChange::watch_requests()`, and converts
+//! the key of each event with `SchemaChange::from_key()` to invalidate what it caches.
+
+use common_meta_app::schema::DBIdTableName;
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableId;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+
+use crate::get_start_and_end_of_prefix;
+use crate::KVApiKey;
+
+/// A change of a database or a table, made by a write of one of its keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaChange {
+    /// A database name is created, dropped or renamed.
+    DatabaseName(DatabaseNameIdent),
+
+    /// The meta of a database is changed. It is written by each change of the tables in it too.
+    Database(DatabaseId),
+
+    /// A table name is created, dropped or renamed.
+    TableName(DBIdTableName),
+
+    /// The meta of a table is changed, e.g. by `ALTER TABLE`, `DROP TABLE` or the insertions.
+    Table(TableId),
+}
+
+impl SchemaChange {
+    /// The requests to watch all the changes of the schema, one of each kind of the keys.
+    pub fn watch_requests() -> Vec<WatchRequest> {
+        [
+            DatabaseNameIdent::PREFIX,
+            DatabaseId::PREFIX,
+            DBIdTableName::PREFIX,
+            TableId::PREFIX,
+        ]
+        .iter()
+        .map(|prefix| {
+            // The trailing `/` keeps `__fd_table` from matching `__fd_table_by_id` etc.
+            let (key, key_end) = get_start_and_end_of_prefix(&format!("{}/", prefix))
+                .expect("the prefixes of the schema keys are ASCII");
+            WatchRequest {
+                key,
+                key_end: Some(key_end),
+                filter_type: FilterType::All.into(),
+            }
+        })
+        .collect()
+    }
+
+    /// Returns the change made by writing `key`, or None if it is not a key of the schema.
+    pub fn from_key(key: &str) -> Option<SchemaChange> {
+        let prefix = key.split('/').next()?;
+
+        match prefix {
+            DatabaseNameIdent::PREFIX => DatabaseNameIdent::from_key(key)
+                .ok()
+                .map(SchemaChange::DatabaseName),
+            DatabaseId::PREFIX => DatabaseId::from_key(key).ok().map(SchemaChange::Database),
+            DBIdTableName::PREFIX => DBIdTableName::from_key(key)
+                .ok()
+                .map(SchemaChange::TableName),
+            TableId::PREFIX => TableId::from_key(key).ok().map(SchemaChange::Table),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_meta_app::schema::DBIdTableName;
+    use common_meta_app::schema::DatabaseId;
+    use common_meta_app::schema::DatabaseNameIdent;
+    use common_meta_app::schema::TableId;
+    use common_meta_app::schema::TableIdListKey;
+
+    use crate::KVApiKey;
+    use crate::SchemaChange;
+
+    #[test]
+    fn test_schema_change_from_key() {
+        let db_name = DatabaseNameIdent {
+            tenant: "t1".to_string(),
+            db_name: "db1".to_string(),
+        };
+        assert_eq!(
+            Some(SchemaChange::DatabaseName(db_name.clone())),
+            SchemaChange::from_key(&db_name.to_key())
+        );
+
+        let db_id = DatabaseId { db_id: 1 };
+        assert_eq!(
+            Some(SchemaChange::Database(db_id.clone())),
+            SchemaChange::from_key(&db_id.to_key())
+        );
+
+        let tb_name = DBIdTableName {
+            db_id: 1,
+            table_name: "tb1".to_string(),
+        };
+        assert_eq!(
+            Some(SchemaChange::TableName(tb_name.clone())),
+            SchemaChange::from_key(&tb_name.to_key())
+        );
+
+        let tb_id = TableId { table_id: 2 };
+        assert_eq!(
+            Some(SchemaChange::Table(tb_id.clone())),
+            SchemaChange::from_key(&tb_id.to_key())
+        );
+
+        let tb_id_list = TableIdListKey {
+            db_id: 1,
+            table_name: "tb1".to_string(),
+        };
+        assert_eq!(None, SchemaChange::from_key(&tb_id_list.to_key()));
+        assert_eq!(None, SchemaChange::from_key("__fd_users/t1/u1"));
+    }
+
+    #[test]
+    fn test_schema_change_watch_requests() {
+        let reqs = SchemaChange::watch_requests();
+        assert_eq!(4, reqs.len());
+
+        let watched = |key: &str| {
+            reqs.iter()
+                .any(|r| r.key.as_str() <= key && Some(key) < r.key_end.as_deref())
+        };
+
+        assert!(watched(&TableId { table_id: 2 }.to_key()));
+        assert!(watched(
+            &DBIdTableName {
+                db_id: 1,
+                table_name: "tb1".to_string(),
+            }
+            .to_key()
+        ));
+        assert!(!watched(
+            &TableIdListKey {
+                db_id: 1,
+                table_name: "tb1".to_string(),
+            }
+            .to_key()
+        ));
+    }
+}
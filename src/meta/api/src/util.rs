@@ -153,6 +153,33 @@ pub async fn list_u64_value<K: KVApiKey>(
     Ok((structured_keys, values))
 }
 
+/// List kvs whose value's type is a struct, by the prefix of the keys, such as:
+/// all the versions of a table meta with prefix `__fd_table_meta_history/<table_id>/`.
+///
+/// It returns a vec of structured key, seq and value.
+pub async fn list_struct_value<K, T>(
+    kv_api: &(impl KVApi + ?Sized),
+    prefix: &str,
+) -> Result<Vec<(K, u64, T)>, KVAppError>
+where
+    K: KVApiKey,
+    T: FromToProto,
+    T::PB: common_protos::prost::Message + Default,
+{
+    let res = kv_api.prefix_list_kv(prefix).await?;
+
+    let mut values = Vec::with_capacity(res.len());
+    for (str_key, seqv) in res.iter() {
+        let struct_key = K::from_key(str_key).map_err(to_bytes_err).map_err(|e| {
+            let inv = InvalidReply::new("list_struct_value", &e);
+            MetaNetworkError::InvalidReply(inv)
+        })?;
+        values.push((struct_key, seqv.seq, deserialize_struct(&seqv.data)?));
+    }
+
+    Ok(values)
+}
+
 pub fn serialize_u64(value: impl Into<Id>) -> Result<Vec<u8>, MetaNetworkError> {
     let v = serde_json::to_vec(&*value.into()).map_err(|e| {
         let inv = InvalidArgument::new(e, "");
@@ -215,6 +215,26 @@ impl Display for RenameDatabaseReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RenameDatabaseReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseCommentReq {
+    pub if_exists: bool,
+    pub name_ident: DatabaseNameIdent,
+    pub comment: String,
+}
+
+impl Display for UpdateDatabaseCommentReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "update_database_comment:{}/{}={}",
+            self.name_ident.tenant, self.name_ident.db_name, self.comment
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseCommentReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseReq {
     pub if_exists: bool,
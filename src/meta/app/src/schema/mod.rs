@@ -35,6 +35,12 @@ pub use database::RenameDatabaseReply;
 pub use database::RenameDatabaseReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
+pub use database::UpdateDatabaseCommentReply;
+pub use database::UpdateDatabaseCommentReq;
+pub use table::AcquireTableLockReply;
+pub use table::AcquireTableLockReq;
+pub use table::CommitTableReply;
+pub use table::CommitTableReq;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -47,8 +53,12 @@ pub use table::GetTableCopiedFileReply;
 pub use table::GetTableCopiedFileReq;
 pub use table::GetTableReq;
 pub use table::ListTableReq;
+pub use table::ReleaseTableLockReply;
+pub use table::ReleaseTableLockReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
+pub use table::SwapTableReply;
+pub use table::SwapTableReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileLock;
 pub use table::TableCopiedFileLockKey;
@@ -59,16 +69,24 @@ pub use table::TableIdListKey;
 pub use table::TableIdToName;
 pub use table::TableIdent;
 pub use table::TableInfo;
+pub use table::TableLockKey;
+pub use table::TableLockMeta;
 pub use table::TableMeta;
+pub use table::TableMetaHistory;
+pub use table::TableMetaVersion;
+pub use table::TableMetaVersionIdent;
 pub use table::TableNameIdent;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use table::TABLE_STATE_OPTION_KEYS;
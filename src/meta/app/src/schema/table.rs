@@ -278,8 +278,33 @@ impl TableMeta {
         self.default_cluster_key_id
             .zip(self.default_cluster_key.clone())
     }
+
+    /// Whether the definition of the table differs from the other, i.e. the schema, the engine,
+    /// the cluster keys, the comments or the options except the [TABLE_STATE_OPTION_KEYS].
+    pub fn is_definition_changed(&self, other: &TableMeta) -> bool {
+        let definition_options = |meta: &TableMeta| {
+            meta.options
+                .iter()
+                .filter(|(k, _)| !TABLE_STATE_OPTION_KEYS.contains(&k.as_str()))
+                .collect::<Vec<_>>()
+        };
+
+        self.schema != other.schema
+            || self.engine != other.engine
+            || self.engine_options != other.engine_options
+            || self.default_cluster_key != other.default_cluster_key
+            || self.cluster_keys != other.cluster_keys
+            || self.comment != other.comment
+            || self.field_comments != other.field_comments
+            || definition_options(self) != definition_options(other)
+    }
 }
 
+/// The table options updated by the writes to the table rather than by the DDLs, e.g. the
+/// location of the latest snapshot, changing them doesn't make a new version of the table meta.
+pub const TABLE_STATE_OPTION_KEYS: &[&str] =
+    &["snapshot_location", "snapshot_loc", "stream_offset"];
+
 impl Display for TableMeta {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -491,6 +516,76 @@ pub struct RenameTableReply {
     pub table_id: u64,
 }
 
+/// Makes a staged table, created by `create_staged_table()`, visible by its name.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CommitTableReq {
+    pub name_ident: TableNameIdent,
+    pub table_id: u64,
+}
+
+impl CommitTableReq {
+    pub fn tenant(&self) -> &str {
+        &self.name_ident.tenant
+    }
+    pub fn db_name(&self) -> &str {
+        &self.name_ident.db_name
+    }
+    pub fn table_name(&self) -> &str {
+        &self.name_ident.table_name
+    }
+}
+
+impl Display for CommitTableReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "commit_table:{}/{}-{}={}",
+            self.tenant(),
+            self.db_name(),
+            self.table_name(),
+            self.table_id
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CommitTableReply {}
+
+/// Swaps the names of two tables in the same database.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SwapTableReq {
+    pub name_ident: TableNameIdent,
+    pub target_table_name: String,
+}
+
+impl SwapTableReq {
+    pub fn tenant(&self) -> &str {
+        &self.name_ident.tenant
+    }
+    pub fn db_name(&self) -> &str {
+        &self.name_ident.db_name
+    }
+    pub fn table_name(&self) -> &str {
+        &self.name_ident.table_name
+    }
+}
+
+impl Display for SwapTableReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "swap_table:{}/{}-{}<=>{}",
+            self.tenant(),
+            self.db_name(),
+            self.table_name(),
+            self.target_table_name
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SwapTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
@@ -510,6 +605,16 @@ pub struct UpdateTableMetaReq {
     pub new_table_meta: TableMeta,
 }
 
+/// Updates the metas of several tables at once, e.g. the tables mutated by a transaction. None
+/// of them is updated if the version of one of them does not match.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -623,6 +728,41 @@ pub struct TableIdToName {
     pub table_id: u64,
 }
 
+/// A version of the schema and the options of a table, `__fd_table_meta_history/<table_id>/<version>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TableMetaVersionIdent {
+    pub table_id: u64,
+    pub version: u64,
+}
+
+/// The table meta of a version, the `updated_on` of the meta is the time the version is made.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableMetaVersion {
+    pub version: u64,
+    pub table_meta: TableMeta,
+}
+
+/// The versions of the table meta, the oldest first.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TableMetaHistory {
+    pub versions: Vec<TableMetaVersion>,
+}
+
+impl TableMetaHistory {
+    /// The version in effect at the time, e.g. the time a snapshot of the table is written, to
+    /// read the data files written by the schema of that time.
+    pub fn version_at(&self, time: &DateTime<Utc>) -> Option<&TableMetaVersion> {
+        self.versions
+            .iter()
+            .rev()
+            .find(|v| v.table_meta.updated_on <= *time)
+    }
+
+    pub fn latest(&self) -> Option<&TableMetaVersion> {
+        self.versions.last()
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 pub struct TableCopiedFileNameIdent {
     pub table_id: u64,
@@ -672,3 +812,41 @@ pub struct TableCopiedFileLockKey {
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct TableCopiedFileLock {}
+
+/// The lock of a table held by a mutation, e.g. a delete or a compaction, to serialize the
+/// mutations of the table, `__fd_table_lock/<table_id>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableLockKey {
+    pub table_id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableLockMeta {
+    /// Who holds the lock, e.g. the id of the query.
+    pub holder: String,
+    /// The mutation the lock is held for, to tell the conflicting ones.
+    pub operation: String,
+    pub acquired_on: DateTime<Utc>,
+}
+
+/// Acquires the lock of a table, or extends the lease of the lock if it is already held by the
+/// same holder. The lock is released at `expire_at`, in seconds, unless it is extended before.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcquireTableLockReq {
+    pub table_id: u64,
+    pub holder: String,
+    pub operation: String,
+    pub expire_at: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AcquireTableLockReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseTableLockReq {
+    pub table_id: u64,
+    pub holder: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReleaseTableLockReply {}
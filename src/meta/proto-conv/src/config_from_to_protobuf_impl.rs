@@ -15,7 +15,9 @@
 use common_protos::pb;
 use common_storage::StorageFsConfig;
 use common_storage::StorageGcsConfig;
+use common_storage::StorageHdfsConfig;
 use common_storage::StorageS3Config;
+use common_storage::StorageWebhdfsConfig;
 
 use crate::check_ver;
 use crate::FromToProto;
@@ -107,3 +109,53 @@ impl FromToProto for StorageFsConfig {
         })
     }
 }
+
+impl FromToProto for StorageWebhdfsConfig {
+    type PB = pb::WebhdfsStorageConfig;
+
+    fn from_pb(p: pb::WebhdfsStorageConfig) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.version, p.min_compatible)?;
+
+        Ok(StorageWebhdfsConfig {
+            endpoint_url: p.endpoint_url,
+            root: p.root,
+            user: p.user,
+            delegation: p.delegation,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WebhdfsStorageConfig, Incompatible> {
+        Ok(pb::WebhdfsStorageConfig {
+            version: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            endpoint_url: self.endpoint_url.clone(),
+            root: self.root.clone(),
+            user: self.user.clone(),
+            delegation: self.delegation.clone(),
+        })
+    }
+}
+
+impl FromToProto for StorageHdfsConfig {
+    type PB = pb::HdfsStorageConfig;
+
+    fn from_pb(p: pb::HdfsStorageConfig) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.version, p.min_compatible)?;
+
+        Ok(StorageHdfsConfig {
+            name_node: p.name_node,
+            root: p.root,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::HdfsStorageConfig, Incompatible> {
+        Ok(pb::HdfsStorageConfig {
+            version: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            name_node: self.name_node.clone(),
+            root: self.root.clone(),
+        })
+    }
+}
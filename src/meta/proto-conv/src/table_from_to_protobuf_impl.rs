@@ -78,6 +78,31 @@ impl FromToProto for mt::TableCopiedFileLock {
     }
 }
 
+impl FromToProto for mt::TableLockMeta {
+    type PB = pb::TableLockMeta;
+    fn from_pb(p: pb::TableLockMeta) -> Result<Self, Incompatible> {
+        check_ver(p.ver, p.min_compatible)?;
+
+        let v = Self {
+            holder: p.holder,
+            operation: p.operation,
+            acquired_on: DateTime::<Utc>::from_pb(p.acquired_on)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableLockMeta, Incompatible> {
+        let p = pb::TableLockMeta {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            holder: self.holder.clone(),
+            operation: self.operation.clone(),
+            acquired_on: self.acquired_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto for mt::TableNameIdent {
     type PB = pb::TableNameIdent;
     fn from_pb(p: pb::TableNameIdent) -> Result<Self, Incompatible> {
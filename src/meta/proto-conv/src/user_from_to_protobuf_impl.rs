@@ -27,6 +27,8 @@ use common_storage::StorageFsConfig;
 use common_storage::StorageGcsConfig;
 use common_storage::StorageParams;
 use common_storage::StorageS3Config;
+use common_storage::StorageWebhdfsConfig;
+use common_storage::UriLocation;
 use enumflags2::BitFlags;
 use num::FromPrimitive;
 
@@ -45,6 +47,7 @@ impl FromToProto for mt::AuthInfo {
         match p.info {
             Some(pb::auth_info::Info::None(pb::auth_info::None {})) => Ok(mt::AuthInfo::None),
             Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})) => Ok(mt::AuthInfo::JWT),
+            Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {})) => Ok(mt::AuthInfo::LDAP),
             Some(pb::auth_info::Info::Password(pb::auth_info::Password {
                 hash_value,
                 hash_method,
@@ -64,6 +67,7 @@ impl FromToProto for mt::AuthInfo {
         let info = match self {
             mt::AuthInfo::None => Some(pb::auth_info::Info::None(pb::auth_info::None {})),
             mt::AuthInfo::JWT => Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})),
+            mt::AuthInfo::LDAP => Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {})),
             mt::AuthInfo::Password {
                 hash_value,
                 hash_method,
@@ -91,7 +95,10 @@ impl FromToProto for mt::UserOption {
 
         Ok(mt::UserOption::default()
             .with_flags(flags)
-            .with_default_role(p.default_role))
+            .with_default_role(p.default_role)
+            .with_network_policy(p.network_policy)
+            .with_resource_group(p.resource_group)
+            .with_default_query_tag(p.default_query_tag))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -100,6 +107,9 @@ impl FromToProto for mt::UserOption {
             min_compatible: MIN_COMPATIBLE_VER,
             flags: self.flags().bits(),
             default_role: self.default_role().cloned(),
+            network_policy: self.network_policy().cloned(),
+            resource_group: self.resource_group().cloned(),
+            default_query_tag: self.default_query_tag().cloned(),
         })
     }
 }
@@ -147,6 +157,9 @@ impl FromToProto for mt::GrantObject {
                 db,
                 table,
             })) => Ok(mt::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::FutureTables(
+                pb::grant_object::GrantFutureTablesObject { catalog, db },
+            )) => Ok(mt::GrantObject::FutureTables(catalog, db)),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -171,6 +184,12 @@ impl FromToProto for mt::GrantObject {
                     table: table.clone(),
                 },
             )),
+            mt::GrantObject::FutureTables(catalog, db) => Some(
+                pb::grant_object::Object::FutureTables(pb::grant_object::GrantFutureTablesObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -425,6 +444,18 @@ impl FromToProto for StorageParams {
             Some(pb::user_stage_info::stage_storage::Storage::Gcs(s)) => {
                 Ok(StorageParams::Gcs(StorageGcsConfig::from_pb(s)?))
             }
+            Some(pb::user_stage_info::stage_storage::Storage::Webhdfs(s)) => {
+                Ok(StorageParams::Webhdfs(StorageWebhdfsConfig::from_pb(s)?))
+            }
+            #[cfg(feature = "storage-hdfs")]
+            Some(pb::user_stage_info::stage_storage::Storage::Hdfs(s)) => Ok(StorageParams::Hdfs(
+                common_storage::StorageHdfsConfig::from_pb(s)?,
+            )),
+            #[cfg(not(feature = "storage-hdfs"))]
+            Some(pb::user_stage_info::stage_storage::Storage::Hdfs(_)) => Err(Incompatible {
+                reason: "StageStorage.storage hdfs is not enabled, build with feature storage-hdfs"
+                    .to_string(),
+            }),
             None => Err(Incompatible {
                 reason: "StageStorage.storage cannot be None".to_string(),
             }),
@@ -442,6 +473,17 @@ impl FromToProto for StorageParams {
             StorageParams::Gcs(v) => Ok(pb::user_stage_info::StageStorage {
                 storage: Some(pb::user_stage_info::stage_storage::Storage::Gcs(v.to_pb()?)),
             }),
+            StorageParams::Webhdfs(v) => Ok(pb::user_stage_info::StageStorage {
+                storage: Some(pb::user_stage_info::stage_storage::Storage::Webhdfs(
+                    v.to_pb()?,
+                )),
+            }),
+            #[cfg(feature = "storage-hdfs")]
+            StorageParams::Hdfs(v) => Ok(pb::user_stage_info::StageStorage {
+                storage: Some(pb::user_stage_info::stage_storage::Storage::Hdfs(
+                    v.to_pb()?,
+                )),
+            }),
             _ => todo!("other stage storage are not supported"),
         }
     }
@@ -455,12 +497,36 @@ impl FromToProto for mt::StageParams {
             storage: StorageParams::from_pb(p.storage.ok_or_else(|| Incompatible {
                 reason: "pb::user_stage_info::StageParams.storage cannot be None".to_string(),
             })?)?,
+            location: p.location.map(UriLocation::from_pb).transpose()?,
         })
     }
 
     fn to_pb(&self) -> Result<pb::user_stage_info::StageParams, Incompatible> {
         Ok(pb::user_stage_info::StageParams {
             storage: Some(StorageParams::to_pb(&self.storage)?),
+            location: self.location.as_ref().map(UriLocation::to_pb).transpose()?,
+        })
+    }
+}
+
+impl FromToProto for UriLocation {
+    type PB = pb::user_stage_info::StageLocation;
+    fn from_pb(p: pb::user_stage_info::StageLocation) -> Result<Self, Incompatible>
+    where Self: Sized {
+        Ok(UriLocation {
+            protocol: p.protocol,
+            name: p.name,
+            path: p.path,
+            connection: p.connection,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::user_stage_info::StageLocation, Incompatible> {
+        Ok(pb::user_stage_info::StageLocation {
+            protocol: self.protocol.clone(),
+            name: self.name.clone(),
+            path: self.path.clone(),
+            connection: self.connection.clone(),
         })
     }
 }
@@ -489,6 +555,10 @@ impl FromToProto for mt::FileFormatOptions {
             field_delimiter: p.field_delimiter.clone(),
             record_delimiter: p.record_delimiter,
             compression,
+            quote: p.quote,
+            escape: p.escape,
+            null_display: p.null_display,
+            error_on_column_count_mismatch: p.error_on_column_count_mismatch.unwrap_or(true),
         })
     }
 
@@ -503,6 +573,10 @@ impl FromToProto for mt::FileFormatOptions {
             field_delimiter: self.field_delimiter.clone(),
             record_delimiter: self.record_delimiter.clone(),
             compression,
+            quote: self.quote.clone(),
+            escape: self.escape.clone(),
+            null_display: self.null_display.clone(),
+            error_on_column_count_mismatch: Some(self.error_on_column_count_mismatch),
         })
     }
 }
@@ -52,6 +52,25 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
         11,
         "2022-09-29: Add: users.proto/CopyOptions::single and CopyOptions::max_file_size",
     ),
+    (
+        12,
+        "2022-10-11: Add: users.proto/FileFormatOptions::{quote,escape,null_display,error_on_column_count_mismatch}",
+    ),
+    (13, "2022-10-14: Add: user.proto/AuthInfo::LDAP"),
+    (14, "2022-10-14: Add: user.proto/UserOption::network_policy"),
+    (15, "2022-10-14: Add: user.proto/UserOption::resource_group"),
+    (16, "2022-10-14: Add: table.proto/TableLockMeta"),
+    (
+        17,
+        "2022-10-14: Add: user.proto/UserOption::default_query_tag",
+    ),
+    (
+        18,
+        "2022-10-15: Add: user.proto/GrantObject::GrantFutureTablesObject",
+    ),
+    (19, "2022-10-15: Add: config.proto/WebhdfsStorageConfig"),
+    (20, "2022-10-15: Add: user.proto/StageParams::location"),
+    (21, "2022-10-15: Add: config.proto/HdfsStorageConfig"),
 ];
 
 pub const VER: u64 = META_CHANGE_LOG.last().unwrap().0;
@@ -27,6 +27,7 @@ use common_proto_conv::VER;
 use common_protos::pb;
 use maplit::btreemap;
 
+use crate::common;
 use crate::common::print_err;
 
 fn s(ss: impl ToString) -> String {
@@ -679,3 +680,23 @@ fn test_load_old() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_table_lock_meta() -> anyhow::Result<()> {
+    let lock = mt::TableLockMeta {
+        holder: s("q1"),
+        operation: s("delete"),
+        acquired_on: Utc.ymd(2014, 11, 28).and_hms(12, 0, 9),
+    };
+    common::test_pb_from_to("table_lock_meta", lock.clone())?;
+
+    // Encoded data of version 16 of table_lock_meta:
+    // It is generated with common::test_pb_from_to.
+    let table_lock_meta_v16 = vec![
+        10, 2, 113, 49, 18, 6, 100, 101, 108, 101, 116, 101, 26, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 16, 168, 6, 1,
+    ];
+    common::test_load_old(func_name!(), table_lock_meta_v16.as_slice(), lock)?;
+
+    Ok(())
+}
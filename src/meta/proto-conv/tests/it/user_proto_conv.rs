@@ -33,6 +33,7 @@ use common_storage::StorageS3Config;
 use enumflags2::make_bitflags;
 use pretty_assertions::assert_eq;
 
+use crate::common;
 use crate::common::print_err;
 
 fn test_user_info() -> UserInfo {
@@ -74,6 +75,7 @@ pub(crate) fn test_fs_stage_info() -> mt::UserStageInfo {
             storage: StorageParams::Fs(StorageFsConfig {
                 root: "/dir/to/files".to_string(),
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -81,6 +83,7 @@ pub(crate) fn test_fs_stage_info() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -108,6 +111,7 @@ pub(crate) fn test_s3_stage_info() -> mt::UserStageInfo {
                 master_key: "my_master_key".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -115,6 +119,10 @@ pub(crate) fn test_s3_stage_info() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            quote: "'".to_string(),
+            escape: "\\".to_string(),
+            null_display: "NULL".to_string(),
+            error_on_column_count_mismatch: false,
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -140,6 +148,7 @@ pub(crate) fn test_gcs_stage_info() -> mt::UserStageInfo {
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -147,6 +156,7 @@ pub(crate) fn test_gcs_stage_info() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -462,3 +472,89 @@ fn test_old_stage_file() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_ldap_auth_info() -> anyhow::Result<()> {
+    common::test_pb_from_to("auth_info_ldap", mt::AuthInfo::LDAP)?;
+
+    // Encoded data of version 13 of auth_info_ldap:
+    // It is generated with common::test_pb_from_to.
+    let auth_info_ldap_v13 = vec![34, 0, 160, 6, 13, 168, 6, 1];
+    common::test_load_old(
+        func_name!(),
+        auth_info_ldap_v13.as_slice(),
+        mt::AuthInfo::LDAP,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_user_option_network_policy() -> anyhow::Result<()> {
+    let option = mt::UserOption::default().with_network_policy(Some("p1".to_string()));
+    common::test_pb_from_to("user_option_network_policy", option.clone())?;
+
+    // Encoded data of version 14 of user_option_network_policy:
+    // It is generated with common::test_pb_from_to.
+    let user_option_network_policy_v14 = vec![26, 2, 112, 49, 160, 6, 14, 168, 6, 1];
+    common::test_load_old(
+        func_name!(),
+        user_option_network_policy_v14.as_slice(),
+        option,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_user_option_resource_group() -> anyhow::Result<()> {
+    let option = mt::UserOption::default().with_resource_group(Some("etl".to_string()));
+    common::test_pb_from_to("user_option_resource_group", option.clone())?;
+
+    // Encoded data of version 15 of user_option_resource_group:
+    // It is generated with common::test_pb_from_to.
+    let user_option_resource_group_v15 = vec![34, 3, 101, 116, 108, 160, 6, 15, 168, 6, 1];
+    common::test_load_old(
+        func_name!(),
+        user_option_resource_group_v15.as_slice(),
+        option,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_user_option_default_query_tag() -> anyhow::Result<()> {
+    let option = mt::UserOption::default().with_default_query_tag(Some("etl".to_string()));
+    common::test_pb_from_to("user_option_default_query_tag", option.clone())?;
+
+    // Encoded data of version 17 of user_option_default_query_tag:
+    // It is generated with common::test_pb_from_to.
+    let user_option_default_query_tag_v17 = vec![42, 3, 101, 116, 108, 160, 6, 17, 168, 6, 1];
+    common::test_load_old(
+        func_name!(),
+        user_option_default_query_tag_v17.as_slice(),
+        option,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_grant_object_future_tables() -> anyhow::Result<()> {
+    let object = mt::GrantObject::FutureTables("default".to_string(), "db1".to_string());
+    common::test_pb_from_to("grant_object_future_tables", object.clone())?;
+
+    // Encoded data of version 18 of grant_object_future_tables:
+    // It is generated with common::test_pb_from_to.
+    let grant_object_future_tables_v18 = vec![
+        34, 14, 10, 7, 100, 101, 102, 97, 117, 108, 116, 18, 3, 100, 98, 49, 160, 6, 18, 168, 6, 1,
+    ];
+    common::test_load_old(
+        func_name!(),
+        grant_object_future_tables_v18.as_slice(),
+        object,
+    )?;
+
+    Ok(())
+}
@@ -14,11 +14,15 @@
 
 //! Test UserStageInfo
 
+use std::collections::BTreeMap;
+
 use common_meta_types as mt;
 use common_storage::StorageFsConfig;
 use common_storage::StorageGcsConfig;
 use common_storage::StorageParams;
 use common_storage::StorageS3Config;
+use common_storage::StorageWebhdfsConfig;
+use common_storage::UriLocation;
 
 use crate::common;
 use crate::user_proto_conv::test_fs_stage_info;
@@ -43,6 +47,145 @@ fn test_user_stage_gcs_latest() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_user_stage_webhdfs() -> anyhow::Result<()> {
+    let storage = StorageParams::Webhdfs(StorageWebhdfsConfig {
+        endpoint_url: "http://namenode:9870".to_string(),
+        root: "/data/".to_string(),
+        user: "hadoop".to_string(),
+        delegation: "token".to_string(),
+    });
+    common::test_pb_from_to("user_stage_webhdfs", storage.clone())?;
+
+    // Encoded data of version 19 of user_stage_webhdfs:
+    // It is generated with common::test_pb_from_to.
+    let user_stage_webhdfs_v19 = vec![
+        34, 51, 10, 20, 104, 116, 116, 112, 58, 47, 47, 110, 97, 109, 101, 110, 111, 100, 101, 58,
+        57, 56, 55, 48, 18, 6, 47, 100, 97, 116, 97, 47, 26, 6, 104, 97, 100, 111, 111, 112, 34, 5,
+        116, 111, 107, 101, 110, 160, 6, 19, 168, 6, 1,
+    ];
+    common::test_load_old(func_name!(), user_stage_webhdfs_v19.as_slice(), storage)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "storage-hdfs")]
+#[test]
+fn test_user_stage_hdfs() -> anyhow::Result<()> {
+    let storage = StorageParams::Hdfs(common_storage::StorageHdfsConfig {
+        name_node: "hdfs://namenode:8020".to_string(),
+        root: "/data/".to_string(),
+    });
+    common::test_pb_from_to("user_stage_hdfs", storage.clone())?;
+
+    // Encoded data of version 21 of user_stage_hdfs:
+    // It is generated with common::test_pb_from_to.
+    let user_stage_hdfs_v21 = vec![
+        42, 36, 10, 20, 104, 100, 102, 115, 58, 47, 47, 110, 97, 109, 101, 110, 111, 100, 101, 58,
+        56, 48, 50, 48, 18, 6, 47, 100, 97, 116, 97, 47, 160, 6, 21, 168, 6, 1,
+    ];
+    common::test_load_old(func_name!(), user_stage_hdfs_v21.as_slice(), storage)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_user_stage_location() -> anyhow::Result<()> {
+    let stage_params = mt::StageParams {
+        storage: StorageParams::S3(StorageS3Config {
+            region: "us-east-2".to_string(),
+            endpoint_url: "https://s3.amazonaws.com".to_string(),
+            bucket: "mybucket".to_string(),
+            root: "/data/".to_string(),
+            ..Default::default()
+        }),
+        location: Some(UriLocation {
+            protocol: "s3".to_string(),
+            name: "mybucket".to_string(),
+            path: "/data/".to_string(),
+            connection: BTreeMap::from([("connection_name".to_string(), "my_conn".to_string())]),
+        }),
+    };
+    common::test_pb_from_to("user_stage_location", stage_params.clone())?;
+
+    // Encoded data of version 20 of user_stage_location:
+    // It is generated with common::test_pb_from_to.
+    let user_stage_location_v20 = vec![
+        10, 63, 10, 61, 10, 9, 117, 115, 45, 101, 97, 115, 116, 45, 50, 18, 24, 104, 116, 116, 112,
+        115, 58, 47, 47, 115, 51, 46, 97, 109, 97, 122, 111, 110, 97, 119, 115, 46, 99, 111, 109,
+        42, 8, 109, 121, 98, 117, 99, 107, 101, 116, 50, 6, 47, 100, 97, 116, 97, 47, 160, 6, 20,
+        168, 6, 1, 18, 50, 10, 2, 115, 51, 18, 8, 109, 121, 98, 117, 99, 107, 101, 116, 26, 6, 47,
+        100, 97, 116, 97, 47, 34, 26, 10, 15, 99, 111, 110, 110, 101, 99, 116, 105, 111, 110, 95,
+        110, 97, 109, 101, 18, 7, 109, 121, 95, 99, 111, 110, 110,
+    ];
+    common::test_load_old(
+        func_name!(),
+        user_stage_location_v20.as_slice(),
+        stage_params,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_user_stage_s3_v12() -> anyhow::Result<()> {
+    // Encoded data of version 12 of user_stage_s3:
+    // It is generated with common::test_pb_from_to.
+    let user_stage_s3_v12 = vec![
+        10, 24, 115, 51, 58, 47, 47, 109, 121, 98, 117, 99, 107, 101, 116, 47, 100, 97, 116, 97,
+        47, 102, 105, 108, 101, 115, 16, 1, 26, 119, 10, 117, 10, 115, 18, 24, 104, 116, 116, 112,
+        115, 58, 47, 47, 115, 51, 46, 97, 109, 97, 122, 111, 110, 97, 119, 115, 46, 99, 111, 109,
+        26, 9, 109, 121, 95, 107, 101, 121, 95, 105, 100, 34, 13, 109, 121, 95, 115, 101, 99, 114,
+        101, 116, 95, 107, 101, 121, 42, 8, 109, 121, 98, 117, 99, 107, 101, 116, 50, 11, 47, 100,
+        97, 116, 97, 47, 102, 105, 108, 101, 115, 58, 13, 109, 121, 95, 109, 97, 115, 116, 101,
+        114, 95, 107, 101, 121, 82, 17, 109, 121, 95, 115, 101, 99, 117, 114, 105, 116, 121, 95,
+        116, 111, 107, 101, 110, 160, 6, 12, 168, 6, 1, 34, 34, 8, 1, 16, 128, 8, 26, 1, 124, 34,
+        2, 47, 47, 40, 2, 50, 1, 39, 58, 1, 92, 66, 4, 78, 85, 76, 76, 72, 0, 160, 6, 12, 168, 6,
+        1, 42, 10, 10, 3, 32, 154, 5, 16, 142, 8, 24, 1, 50, 4, 116, 101, 115, 116, 160, 6, 12,
+        168, 6, 1,
+    ];
+
+    let want = mt::UserStageInfo {
+        stage_name: "s3://mybucket/data/files".to_string(),
+        stage_type: mt::StageType::External,
+        stage_params: mt::StageParams {
+            storage: StorageParams::S3(StorageS3Config {
+                bucket: "mybucket".to_string(),
+                root: "/data/files".to_string(),
+                access_key_id: "my_key_id".to_string(),
+                secret_access_key: "my_secret_key".to_string(),
+                master_key: "my_master_key".to_string(),
+                security_token: "my_security_token".to_string(),
+                ..Default::default()
+            }),
+            location: None,
+        },
+        file_format_options: mt::FileFormatOptions {
+            format: mt::StageFileFormatType::Json,
+            skip_header: 1024,
+            field_delimiter: "|".to_string(),
+            record_delimiter: "//".to_string(),
+            compression: mt::StageFileCompression::Bz2,
+            quote: "'".to_string(),
+            escape: "\\".to_string(),
+            null_display: "NULL".to_string(),
+            error_on_column_count_mismatch: false,
+        },
+        copy_options: mt::CopyOptions {
+            on_error: mt::OnErrorMode::SkipFileNum(666),
+            size_limit: 1038,
+            purge: true,
+            single: false,
+            max_file_size: 0,
+        },
+        comment: "test".to_string(),
+        ..Default::default()
+    };
+
+    common::test_load_old(func_name!(), user_stage_s3_v12.as_slice(), want)?;
+    Ok(())
+}
+
 #[test]
 fn test_user_stage_s3_v11() -> anyhow::Result<()> {
     // Encoded data of version 11 of user_stage_s3:
@@ -73,6 +216,7 @@ fn test_user_stage_s3_v11() -> anyhow::Result<()> {
                 security_token: "my_security_token".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -80,6 +224,7 @@ fn test_user_stage_s3_v11() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -124,6 +269,7 @@ fn test_user_stage_s3_v9() -> anyhow::Result<()> {
                 master_key: "my_master_key".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -131,6 +277,7 @@ fn test_user_stage_s3_v9() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -166,6 +313,7 @@ fn test_user_stage_fs_v6() -> anyhow::Result<()> {
             storage: StorageParams::Fs(StorageFsConfig {
                 root: "/dir/to/files".to_string(),
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -173,6 +321,7 @@ fn test_user_stage_fs_v6() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -218,6 +367,7 @@ fn test_user_stage_s3_v6() -> anyhow::Result<()> {
                 master_key: "my_master_key".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -225,6 +375,7 @@ fn test_user_stage_s3_v6() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -266,6 +417,7 @@ fn test_user_stage_gcs_v6() -> anyhow::Result<()> {
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -273,6 +425,7 @@ fn test_user_stage_gcs_v6() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -307,6 +460,7 @@ fn test_user_stage_fs_v4() -> anyhow::Result<()> {
             storage: StorageParams::Fs(StorageFsConfig {
                 root: "/dir/to/files".to_string(),
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -314,6 +468,7 @@ fn test_user_stage_fs_v4() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -359,6 +514,7 @@ fn test_user_stage_s3_v4() -> anyhow::Result<()> {
                 master_key: "my_master_key".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -366,6 +522,7 @@ fn test_user_stage_s3_v4() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -406,6 +563,7 @@ fn test_user_stage_gcs_v4() -> anyhow::Result<()> {
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -413,6 +571,7 @@ fn test_user_stage_gcs_v4() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -455,6 +614,7 @@ fn test_user_stage_s3_v1() -> anyhow::Result<()> {
                 master_key: "my_master_key".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         file_format_options: mt::FileFormatOptions {
             format: mt::StageFileFormatType::Json,
@@ -462,6 +622,7 @@ fn test_user_stage_s3_v1() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            ..Default::default()
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
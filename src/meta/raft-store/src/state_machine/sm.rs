@@ -20,6 +20,7 @@ use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use common_meta_api::CatalogDump;
 use common_meta_sled_store::get_sled_db;
 use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::EffectiveMembership;
@@ -30,6 +31,7 @@ use common_meta_sled_store::SledKeySpace;
 use common_meta_sled_store::SledTree;
 use common_meta_sled_store::Store;
 use common_meta_sled_store::TransactionSledTree;
+use common_meta_types::anyerror::AnyError;
 use common_meta_types::error_context::WithContext;
 use common_meta_types::txn_condition;
 use common_meta_types::txn_op;
@@ -49,6 +51,7 @@ use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::Operation;
 use common_meta_types::PbSeqV;
+use common_meta_types::SeqNum;
 use common_meta_types::SeqV;
 use common_meta_types::TxnCondition;
 use common_meta_types::TxnDeleteByPrefixRequest;
@@ -885,6 +888,34 @@ impl StateMachine {
         Ok(())
     }
 
+    /// Imports a catalog dump into the state machine of a fresh node, the keys keep their seqs.
+    ///
+    /// The seq of the generic kv is advanced to the max seq of the dump, so that the ids generated
+    /// after the import do not collide with the imported ones.
+    pub async fn import_catalog(&self, dump: &CatalogDump) -> MetaStorageResult<()> {
+        let kvs = self.kvs();
+        if kvs.last()?.is_some() {
+            return Err(MetaStorageError::SnapshotError(AnyError::error(
+                "can not import a catalog dump into a state machine having keys",
+            )));
+        }
+        kvs.append(&dump.kvs).await?;
+
+        let sequences = self.sequences();
+        let key = GenericKV::NAME.to_string();
+        let curr = sequences.get(&key)?.unwrap_or_default();
+        if curr.0 < dump.max_seq {
+            sequences.insert(&key, &SeqNum(dump.max_seq)).await?;
+        }
+
+        info!(
+            "imported catalog dump: {} keys, max seq {}",
+            dump.kvs.len(),
+            dump.max_seq
+        );
+        Ok(())
+    }
+
     pub fn get_client_last_resp(&self, key: &str) -> MetaResult<Option<(u64, AppliedState)>> {
         let client_last_resps = self.client_last_resps();
         let v: Option<ClientLastRespValue> = client_last_resps.get(&key.to_string())?;
@@ -16,6 +16,8 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use common_base::base::tokio;
+use common_meta_api::export_catalog;
+use common_meta_api::CatalogDump;
 use common_meta_api::KVApi;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
@@ -30,6 +32,7 @@ use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::UpsertKV;
+use common_meta_types::UpsertKVReq;
 use common_meta_types::With;
 use openraft::raft::Entry;
 use openraft::raft::EntryPayload;
@@ -468,3 +471,64 @@ async fn test_state_machine_snapshot() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_import_catalog() -> anyhow::Result<()> {
+    // - Export the keys of a state machine as a catalog dump.
+    // - Import the dump into another state machine, the keys keep their seqs.
+    // - The keys written after the import have greater seqs than the imported ones.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    for key in ["a", "b", "c"] {
+        sm.upsert_kv(UpsertKVReq::new(
+            key,
+            MatchSeq::Any,
+            Operation::Update(key.as_bytes().to_vec()),
+            None,
+        ))
+        .await?;
+    }
+    // The deleted key is not exported, the seq of "c" is still the max one.
+    sm.upsert_kv(UpsertKVReq::new(
+        "a",
+        MatchSeq::Any,
+        Operation::Delete,
+        None,
+    ))
+    .await?;
+
+    let lines = export_catalog(&sm).await?;
+    let dump = CatalogDump::parse(lines)?;
+    assert_eq!(3, dump.max_seq);
+    assert_eq!(
+        vec!["b".to_string(), "c".to_string()],
+        dump.kvs.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>()
+    );
+
+    let imported = StateMachine::open(&tc.raft_config, 2).await?;
+    imported.import_catalog(&dump).await?;
+
+    let got = imported.get_kv("c").await?;
+    assert_eq!(Some(SeqV::with_meta(3, None, b"c".to_vec())), got);
+
+    let res = imported
+        .upsert_kv(UpsertKVReq::new(
+            "d",
+            MatchSeq::Any,
+            Operation::Update(b"d".to_vec()),
+            None,
+        ))
+        .await?;
+    assert_eq!(4, res.result.unwrap().seq);
+
+    // A state machine having keys refuses the import.
+    let res = imported.import_catalog(&dump).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
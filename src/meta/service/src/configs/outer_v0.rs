@@ -22,6 +22,7 @@ use common_meta_types::MetaStartupError;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::StderrConfig as InnerStderrLogConfig;
+use common_tracing::TracingConfig as InnerTracingLogConfig;
 use serde::Deserialize;
 use serde::Serialize;
 use serfig::collectors::from_env;
@@ -42,6 +43,8 @@ pub struct Config {
     /// - `ver`: print version and quit.
     /// - `show-config`: print effective config and quit.
     /// - `kvapi::<cmd>`: run kvapi command. The command can be `upsert`, `get`, `mget` and `list`
+    /// - `export`: print the catalog dump of the cluster at `grpc_api_address` to stdout.
+    /// - `import`: import the catalog dump from stdin into a fresh `raft_dir`.
     #[clap(long, default_value = "")]
     pub cmd: String,
 
@@ -504,6 +507,8 @@ impl Into<InnerLogConfig> for LogConfig {
         InnerLogConfig {
             file: self.file.into(),
             stderr: self.stderr.into(),
+            // The OTLP export is only for the spans of the queries.
+            tracing: InnerTracingLogConfig::default(),
         }
     }
 }
@@ -45,6 +45,7 @@ async fn test_tls_server() -> anyhow::Result<()> {
     let tls_conf = RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: TEST_CA_CERT.to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     };
 
     let client = MetaGrpcClient::try_create(
@@ -81,6 +82,7 @@ async fn test_tls_client_config_failure() -> anyhow::Result<()> {
     let tls_conf = RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: "../tests/data/certs/not_exist.pem".to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     };
 
     let r = MetaGrpcClient::try_create(
@@ -21,6 +21,8 @@ use common_meta_api::KVApi;
 use common_meta_client::ClientHandle;
 use common_meta_client::MetaGrpcClient;
 use common_meta_embedded::MetaEmbedded;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::GetKVReply;
 use common_meta_types::KVAppError;
 use common_meta_types::ListKVReply;
@@ -30,6 +32,7 @@ use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
 use common_meta_types::UpsertKVReq;
+use tonic::codec::Streaming;
 use tracing::info;
 
 #[derive(Clone)]
@@ -56,6 +59,20 @@ impl MetaStore {
         }
     }
 
+    /// Subscribes to the changes of the keys in the range of `req`.
+    ///
+    /// An embedded meta store does not support watching and returns None, the changes made in
+    /// the same process need no notification.
+    pub async fn watch(
+        &self,
+        req: WatchRequest,
+    ) -> std::result::Result<Option<Streaming<WatchResponse>>, MetaError> {
+        match self {
+            MetaStore::L(_) => Ok(None),
+            MetaStore::R(grpc_client) => Ok(Some(grpc_client.request(req).await?)),
+        }
+    }
+
     pub async fn get_local_addr(&self) -> std::result::Result<Option<String>, MetaError> {
         match self {
             MetaStore::L(_) => Ok(None),
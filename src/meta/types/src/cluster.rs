@@ -61,6 +61,29 @@ pub struct NodeInfo {
     pub cpu_nums: u64,
     pub version: u32,
     pub flight_address: String,
+    /// The version of the databend-query binary of the node.
+    pub binary_version: String,
+    /// The load of the node, refreshed by each heartbeat.
+    pub stats: NodeStats,
+    /// No new fragment is scheduled to the draining node, set by `ALTER CLUSTER DRAIN NODE`.
+    /// It's kept apart from the node info rewritten by the heartbeats, and filled by listing.
+    pub draining: bool,
+}
+
+/// The load of the query node published to the meta-service by the heartbeat.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Default)]
+#[serde(default)]
+pub struct NodeStats {
+    /// The milliseconds since the epoch of the last heartbeat.
+    pub heartbeat_on: u64,
+    pub running_queries: u64,
+    /// The memory tracked by the running queries in bytes.
+    pub memory_usage: i64,
+    pub memory_cache_bytes: u64,
+    pub disk_cache_bytes: u64,
+    /// The queries of which the fragments are running on the node, including the ones
+    /// coordinated by the other nodes.
+    pub running_fragments: u64,
 }
 
 impl NodeInfo {
@@ -70,6 +93,9 @@ impl NodeInfo {
             cpu_nums,
             version: 0,
             flight_address,
+            binary_version: String::new(),
+            stats: NodeStats::default(),
+            draining: false,
         }
     }
 
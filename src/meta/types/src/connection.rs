@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The connection, the parameters of the storage referenced by name from the stages,
+/// the external tables and COPY. The secret parameters are kept encrypted.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct ConnectionInfo {
+    pub name: String,
+    pub storage_type: String,
+    pub storage_params: BTreeMap<String, String>,
+    pub comment: String,
+}
+
+impl ConnectionInfo {
+    pub fn new(
+        name: &str,
+        storage_type: &str,
+        storage_params: BTreeMap<String, String>,
+        comment: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            storage_type: storage_type.to_string(),
+            storage_params,
+            comment: comment.to_string(),
+        }
+    }
+
+    /// The secret parameters are the ones named like the secrets, e.g. `secret_access_key`,
+    /// `session_token` and `credential`, they are encrypted and masked in the display.
+    pub fn is_secret_param(name: &str) -> bool {
+        let name = name.to_lowercase();
+        ["secret", "key", "token", "password", "credential"]
+            .iter()
+            .any(|word| name.contains(word))
+    }
+
+    /// The parameters with the secrets masked.
+    pub fn masked_storage_params(&self) -> BTreeMap<String, String> {
+        self.storage_params
+            .iter()
+            .map(|(k, v)| match Self::is_secret_param(k) {
+                true => (k.clone(), "******".to_string()),
+                false => (k.clone(), v.clone()),
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<u8>> for ConnectionInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(connection) => Ok(connection),
+            Err(serialize_error) => Err(ErrorCode::IllegalConnectionFormat(format!(
+                "Cannot deserialize connection from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -430,6 +430,33 @@ impl TxnRetryMaxTimes {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "TableLockConflict: table {table_id} is locked by `{holder}` for `{operation}` while `{context}`"
+)]
+pub struct TableLockConflict {
+    table_id: u64,
+    holder: String,
+    operation: String,
+    context: String,
+}
+
+impl TableLockConflict {
+    pub fn new(
+        table_id: u64,
+        holder: impl Into<String>,
+        operation: impl Into<String>,
+        context: impl Into<String>,
+    ) -> Self {
+        Self {
+            table_id,
+            holder: holder.into(),
+            operation: operation.into(),
+            context: context.into(),
+        }
+    }
+}
+
 /// Application error.
 ///
 /// The application does not get expected result but there is nothing wrong with meta-service.
@@ -486,6 +513,9 @@ pub enum AppError {
     #[error(transparent)]
     TxnRetryMaxTimes(#[from] TxnRetryMaxTimes),
 
+    #[error(transparent)]
+    TableLockConflict(#[from] TableLockConflict),
+
     // share api errors
     #[error(transparent)]
     ShareAlreadyExists(#[from] ShareAlreadyExists),
@@ -671,6 +701,15 @@ impl AppErrorMessage for UndropDbWithNoDropTime {
     }
 }
 
+impl AppErrorMessage for TableLockConflict {
+    fn message(&self) -> String {
+        format!(
+            "Table {} is locked by '{}' for '{}', retry after it is done",
+            self.table_id, self.holder, self.operation
+        )
+    }
+}
+
 impl AppErrorMessage for DropDbWithDropTime {
     fn message(&self) -> String {
         format!("Drop db '{}' with drop_on time", self.db_name)
@@ -726,6 +765,7 @@ impl From<AppError> for ErrorCode {
             }
             AppError::WrongShare(err) => ErrorCode::WrongShare(err.message()),
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
+            AppError::TableLockConflict(err) => ErrorCode::TableLockConflict(err.message()),
         }
     }
 }
@@ -21,19 +21,29 @@ mod change;
 mod cluster;
 mod cmd;
 pub mod config;
+mod connection;
+mod dictionary;
 mod endpoint;
 pub mod errors;
 mod kv_message;
 mod log_entry;
+mod masking_policy;
 mod match_seq;
 mod message;
+mod network_policy;
 mod operation;
+mod plan_hint;
 mod raft_txid;
 mod raft_types;
+mod resource_group;
 mod role_info;
+mod row_access_policy;
 mod seq_errors;
 mod seq_num;
 mod seq_value;
+mod sequence;
+mod table_collations;
+mod task;
 mod tenant_quota;
 mod user_auth;
 mod user_defined_function;
@@ -65,9 +75,15 @@ pub use applied_state::AppliedState;
 pub use change::Change;
 pub use cluster::Node;
 pub use cluster::NodeInfo;
+pub use cluster::NodeStats;
 pub use cluster::Slot;
 pub use cmd::Cmd;
 pub use cmd::UpsertKV;
+pub use connection::ConnectionInfo;
+pub use dictionary::DictionaryColumn;
+pub use dictionary::DictionaryFormat;
+pub use dictionary::DictionaryMeta;
+pub use dictionary::DictionarySource;
 pub use endpoint::Endpoint;
 pub use errors::app_error::AppError;
 pub use errors::app_error::CreateDatabaseWithDropTime;
@@ -77,6 +93,7 @@ pub use errors::app_error::DropDbWithDropTime;
 pub use errors::app_error::DropTableWithDropTime;
 pub use errors::app_error::ShareAlreadyExists;
 pub use errors::app_error::TableAlreadyExists;
+pub use errors::app_error::TableLockConflict;
 pub use errors::app_error::TableVersionMismatched;
 pub use errors::app_error::UndropDbHasNoHistory;
 pub use errors::app_error::UndropDbWithNoDropTime;
@@ -125,6 +142,8 @@ pub use kv_message::MGetKVReq;
 pub use kv_message::UpsertKVReply;
 pub use kv_message::UpsertKVReq;
 pub use log_entry::LogEntry;
+pub use masking_policy::MaskingPolicy;
+pub use masking_policy::TableMaskingPolicies;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
 pub use message::ForwardRequest;
@@ -132,10 +151,12 @@ pub use message::ForwardRequestBody;
 pub use message::ForwardResponse;
 pub use message::JoinRequest;
 pub use message::LeaveRequest;
+pub use network_policy::NetworkPolicy;
 pub use operation::GCDroppedDataReply;
 pub use operation::GCDroppedDataReq;
 pub use operation::MetaId;
 pub use operation::Operation;
+pub use plan_hint::PlanHint;
 pub use principal_identity::PrincipalIdentity;
 pub use protobuf::txn_condition;
 pub use protobuf::txn_condition::ConditionResult;
@@ -159,14 +180,21 @@ pub use raft_types::LogId;
 pub use raft_types::LogIndex;
 pub use raft_types::NodeId;
 pub use raft_types::Term;
+pub use resource_group::ResourceGroup;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::RowAccessPolicy;
+pub use row_access_policy::TableRowAccessPolicy;
 pub use seq_errors::ConflictSeq;
 pub use seq_num::SeqNum;
 pub use seq_value::IntoSeqV;
 pub use seq_value::KVMeta;
 pub use seq_value::PbSeqV;
 pub use seq_value::SeqV;
+pub use sequence::Sequence;
+pub use table_collations::TableCollations;
+pub use task::CronSchedule;
+pub use task::ScheduledTask;
 pub use tenant_quota::TenantQuota;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
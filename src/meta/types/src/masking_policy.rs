@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The masking policy, an expression of the parameter casted to the return type. The values
+/// of the columns the policy is attached to are replaced with the result of the expression.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct MaskingPolicy {
+    pub name: String,
+    pub parameter: String,
+    pub return_type: String,
+    pub definition: String,
+}
+
+impl MaskingPolicy {
+    pub fn new(name: &str, parameter: &str, return_type: &str, definition: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            parameter: parameter.to_string(),
+            return_type: return_type.to_string(),
+            definition: definition.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for MaskingPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalMaskingPolicyFormat(format!(
+                "Cannot deserialize masking policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
+
+/// The masking policies attached to the columns of a table, by the names of the columns.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct TableMaskingPolicies {
+    pub columns: BTreeMap<String, String>,
+}
+
+impl TableMaskingPolicies {
+    /// The value of the table option the attached policies are kept in.
+    pub fn to_option_value(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn try_from_option_value(value: &str) -> Result<Self> {
+        serde_json::from_str(value).map_err(|e| {
+            ErrorCode::IllegalMaskingPolicyFormat(format!(
+                "Cannot deserialize the masking policies of the table, cause {}",
+                e
+            ))
+        })
+    }
+}
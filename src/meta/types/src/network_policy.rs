@@ -0,0 +1,61 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The network policy, the lists of the IPs or the CIDRs the clients of the users the policy
+/// is attached to are allowed to or blocked from connecting from.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: String,
+}
+
+impl NetworkPolicy {
+    pub fn new(
+        name: &str,
+        allowed_ip_list: Vec<String>,
+        blocked_ip_list: Vec<String>,
+        comment: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            allowed_ip_list,
+            blocked_ip_list,
+            comment: comment.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for NetworkPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalNetworkPolicyFormat(format!(
+                "Cannot deserialize network policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The plan hint, the hints applied to the queries of the shape of `statement` as if they were
+/// written after the `SELECT` of the queries, e.g. to pin the join strategy of a slow query
+/// without changing the SQL of the application.
+///
+/// The shape is the statement with its literals replaced by `?`, so the hint applies to all the
+/// statements differing only in the literals.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PlanHint {
+    pub name: String,
+    pub statement: String,
+    pub shape: String,
+    pub hints: String,
+}
+
+impl PlanHint {
+    pub fn new(name: &str, statement: &str, shape: &str, hints: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            statement: statement.to_string(),
+            shape: shape.to_string(),
+            hints: hints.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for PlanHint {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(plan_hint) => Ok(plan_hint),
+            Err(serialize_error) => Err(ErrorCode::IllegalPlanHintFormat(format!(
+                "Cannot deserialize plan hint from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
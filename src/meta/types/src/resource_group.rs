@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The resource group, the limits of the queries of the users or the sessions assigned to it.
+/// The zero limits are unlimited.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct ResourceGroup {
+    pub name: String,
+    /// The percentage of the max_threads the queries execute with.
+    pub cpu_share: u64,
+    /// The maximum memory reserved by the running queries in bytes.
+    pub max_memory_usage: u64,
+    /// The maximum number of the queries running at the same time.
+    pub max_concurrency: u64,
+    pub comment: String,
+}
+
+impl Default for ResourceGroup {
+    fn default() -> Self {
+        ResourceGroup {
+            name: "".to_string(),
+            cpu_share: 100,
+            max_memory_usage: 0,
+            max_concurrency: 0,
+            comment: "".to_string(),
+        }
+    }
+}
+
+impl ResourceGroup {
+    pub fn new(
+        name: &str,
+        cpu_share: u64,
+        max_memory_usage: u64,
+        max_concurrency: u64,
+        comment: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            cpu_share,
+            max_memory_usage,
+            max_concurrency,
+            comment: comment.to_string(),
+        }
+    }
+
+    /// The threads the queries of the group execute with, at least one.
+    pub fn max_threads(&self, max_threads: u64) -> u64 {
+        (max_threads * self.cpu_share.min(100) / 100).max(1)
+    }
+}
+
+impl TryFrom<Vec<u8>> for ResourceGroup {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(group) => Ok(group),
+            Err(serialize_error) => Err(ErrorCode::IllegalResourceGroupFormat(format!(
+                "Cannot deserialize resource group from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The row access policy, a boolean expression of the parameters. The rows of the tables
+/// the policy is attached to are visible only if the expression of the columns is true.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct RowAccessPolicy {
+    pub name: String,
+    pub parameters: Vec<String>,
+    pub definition: String,
+}
+
+impl RowAccessPolicy {
+    pub fn new(name: &str, parameters: Vec<String>, definition: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+            definition: definition.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for RowAccessPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalRowAccessPolicyFormat(format!(
+                "Cannot deserialize row access policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
+
+/// The row access policy attached to a table, the columns are passed to the parameters
+/// of the policy in order.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl TableRowAccessPolicy {
+    pub fn new(policy: &str, columns: Vec<String>) -> Self {
+        Self {
+            policy: policy.to_string(),
+            columns,
+        }
+    }
+
+    /// The value of the table option the attached policy is kept in.
+    pub fn to_option_value(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn try_from_option_value(value: &str) -> Result<Self> {
+        serde_json::from_str(value).map_err(|e| {
+            ErrorCode::IllegalRowAccessPolicyFormat(format!(
+                "Cannot deserialize the row access policy of the table, cause {}",
+                e
+            ))
+        })
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The sequence, a counter of the values `start`, `start + increment`, ... `next_value` is the
+/// next value not allocated yet, the values are allocated to the query nodes in batches.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct Sequence {
+    pub name: String,
+    pub start: i64,
+    pub increment: i64,
+    pub next_value: i64,
+    pub comment: String,
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Self::new("", 1, 1)
+    }
+}
+
+impl Sequence {
+    pub fn new(name: &str, start: i64, increment: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            start,
+            increment,
+            next_value: start,
+            comment: "".to_string(),
+        }
+    }
+
+    /// Allocates the next `count` values, returns the first one. The values are the first one
+    /// plus the multiples of the increment.
+    pub fn allocate(&mut self, count: u64) -> Result<i64> {
+        let first = self.next_value;
+        self.next_value = i64::try_from(count)
+            .ok()
+            .and_then(|count| count.checked_mul(self.increment))
+            .and_then(|step| first.checked_add(step))
+            .ok_or_else(|| {
+                ErrorCode::SequenceOverflow(format!(
+                    "sequence {} reached the limit of its values",
+                    self.name
+                ))
+            })?;
+        Ok(first)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Sequence {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(sequence) => Ok(sequence),
+            Err(serialize_error) => Err(ErrorCode::IllegalSequenceFormat(format!(
+                "Cannot deserialize sequence from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
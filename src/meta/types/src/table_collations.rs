@@ -0,0 +1,44 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The collations of the string columns of a table, by the names of the columns, set by
+/// `<column> <type> COLLATE '<collation>'` of `CREATE TABLE`. The columns not in it are binary.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct TableCollations {
+    pub columns: BTreeMap<String, String>,
+}
+
+impl TableCollations {
+    /// The value of the table option the collations are kept in.
+    pub fn to_option_value(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn try_from_option_value(value: &str) -> Result<Self> {
+        serde_json::from_str(value).map_err(|e| {
+            ErrorCode::BadOption(format!(
+                "Cannot deserialize the collations of the table, cause {}",
+                e
+            ))
+        })
+    }
+}
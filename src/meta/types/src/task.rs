@@ -0,0 +1,217 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Timelike;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::UserIdentity;
+
+/// The scheduled task, running its SQL on a cron schedule, or after its predecessor task
+/// succeeds. The SQL runs as the owner of the task, in the database the task is created in.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct ScheduledTask {
+    pub name: String,
+    /// The cron expression of the schedule, `None` if the task runs after its predecessor.
+    pub schedule: Option<String>,
+    /// The predecessor task, `None` if the task runs on its schedule.
+    pub after: Option<String>,
+    pub sql: String,
+    pub owner: UserIdentity,
+    pub database: String,
+    /// A suspended task doesn't run on its schedule nor after its predecessor.
+    pub suspended: bool,
+    pub comment: String,
+    /// The time the task is created, in microseconds.
+    pub created_on: i64,
+}
+
+impl ScheduledTask {
+    /// The parsed schedule, `None` if the task runs after its predecessor.
+    pub fn cron_schedule(&self) -> Result<Option<CronSchedule>> {
+        self.schedule
+            .as_deref()
+            .map(CronSchedule::parse)
+            .transpose()
+    }
+}
+
+impl TryFrom<Vec<u8>> for ScheduledTask {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(task) => Ok(task),
+            Err(serialize_error) => Err(ErrorCode::IllegalTaskFormat(format!(
+                "Cannot deserialize task from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
+
+/// The cron schedule of the 5 fields `minute hour day-of-month month day-of-week`, in UTC.
+///
+/// A field is `*`, a value, a range `a-b`, a step `*/n` or `a-b/n`, or a list of them separated by
+/// `,`. The day of the week is 0 to 7, both 0 and 7 are Sunday. The shortcuts `@hourly`, `@daily`,
+/// `@weekly`, `@monthly` and `@yearly` are accepted as well.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CronSchedule {
+    // The bits of the values matched by each field.
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // As the cron does, a day matches if it matches both the day of the month and the day of the
+    // week when one of them starts with `*`, or either of them otherwise.
+    any_day: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<CronSchedule> {
+        let expr = match expr.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" => "0 0 1 1 *",
+            expr => expr,
+        };
+
+        let fields = expr.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(ErrorCode::IllegalTaskSchedule(format!(
+                "schedule '{}' must have 5 fields: minute hour day-of-month month day-of-week",
+                expr
+            )));
+        }
+
+        let mut days_of_week = parse_field(fields[4], 0, 7, expr)?;
+        // both 0 and 7 are Sunday
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(CronSchedule {
+            minutes: parse_field(fields[0], 0, 59, expr)?,
+            hours: parse_field(fields[1], 0, 23, expr)?,
+            days_of_month: parse_field(fields[2], 1, 31, expr)?,
+            months: parse_field(fields[3], 1, 12, expr)?,
+            days_of_week,
+            any_day: fields[2].starts_with('*') || fields[4].starts_with('*'),
+        })
+    }
+
+    /// The first time matched by the schedule strictly after the given time, of the whole minute,
+    /// in microseconds. `None` if no time is matched, e.g. of February 30th.
+    pub fn next_after(&self, after: i64) -> Option<i64> {
+        let after = NaiveDateTime::from_timestamp_opt(after.div_euclid(1_000_000), 0)?;
+        let mut time = after.date().and_hms_opt(after.hour(), after.minute(), 0)?;
+        time += Duration::minutes(1);
+
+        // February 29th may be 8 years apart, across a year like 2100
+        let limit = time + Duration::days(366 * 9);
+        while time < limit {
+            if !has_bit(self.months, time.month()) {
+                let (year, month) = match time.month() {
+                    12 => (time.year() + 1, 1),
+                    month => (time.year(), month + 1),
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.matches_day(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !has_bit(self.hours, time.hour()) {
+                time = time.date().and_hms_opt(time.hour(), 0, 0)? + Duration::hours(1);
+                continue;
+            }
+            if !has_bit(self.minutes, time.minute()) {
+                time += Duration::minutes(1);
+                continue;
+            }
+            return Some(time.timestamp() * 1_000_000);
+        }
+        None
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day_of_month = has_bit(self.days_of_month, date.day());
+        let day_of_week = has_bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        match self.any_day {
+            true => day_of_month && day_of_week,
+            false => day_of_month || day_of_week,
+        }
+    }
+}
+
+fn has_bit(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+// The bits of the values in [min, max] matched by the field.
+fn parse_field(field: &str, min: u32, max: u32, expr: &str) -> Result<u64> {
+    let illegal = || {
+        ErrorCode::IllegalTaskSchedule(format!(
+            "illegal field '{}' of schedule '{}', the values are {} to {}",
+            field, expr, min, max
+        ))
+    };
+    let parse_value = |value: &str| -> Result<u32> {
+        match value.parse::<u32>() {
+            Ok(value) if (min..=max).contains(&value) => Ok(value),
+            _ => Err(illegal()),
+        }
+    };
+
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => match step.parse::<usize>() {
+                Ok(step) if step > 0 => (range, Some(step)),
+                _ => return Err(illegal()),
+            },
+            None => (part, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+            // `a/n` is of the values from `a` to the max
+            None if step.is_some() => (parse_value(range)?, max),
+            None => {
+                let value = parse_value(range)?;
+                (value, value)
+            }
+        };
+        if start > end {
+            return Err(illegal());
+        }
+        for value in (start..=end).step_by(step.unwrap_or(1)) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
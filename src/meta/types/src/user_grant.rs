@@ -26,6 +26,9 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    /// The tables created in the database in the future, the privileges are granted on each
+    /// table as it's created.
+    FutureTables(String, String),
 }
 
 impl GrantObject {
@@ -38,7 +41,8 @@ impl GrantObject {
             (GrantObject::Database(lcat, ldb), GrantObject::Database(rcat, rdb)) => {
                 lcat == rcat && ldb == rdb
             }
-            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
+            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _))
+            | (GrantObject::Database(lcat, ldb), GrantObject::FutureTables(rcat, rdb)) => {
                 lcat == rcat && ldb == rdb
             }
             (
@@ -46,6 +50,8 @@ impl GrantObject {
                 GrantObject::Table(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            // grants nothing on the existing objects
+            (GrantObject::FutureTables(_, _), _) => false,
         }
     }
 
@@ -54,7 +60,9 @@ impl GrantObject {
         match self {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
-            GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Table(_, _, _) | GrantObject::FutureTables(_, _) => {
+                UserPrivilegeSet::available_privileges_on_table()
+            }
         }
     }
 }
@@ -67,6 +75,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::FutureTables(ref cat, ref db) => {
+                write!(f, "FUTURE TABLES IN DATABASE '{}'.'{}'", cat, db)
+            }
         }
     }
 }
@@ -96,6 +96,12 @@ pub struct UserOption {
     flags: BitFlags<UserOptionFlag>,
 
     default_role: Option<String>,
+
+    network_policy: Option<String>,
+
+    resource_group: Option<String>,
+
+    default_query_tag: Option<String>,
 }
 
 impl UserOption {
@@ -103,6 +109,9 @@ impl UserOption {
         Self {
             flags,
             default_role: None,
+            network_policy: None,
+            resource_group: None,
+            default_query_tag: None,
         }
     }
 
@@ -120,6 +129,21 @@ impl UserOption {
         self
     }
 
+    pub fn with_network_policy(mut self, network_policy: Option<String>) -> Self {
+        self.network_policy = network_policy;
+        self
+    }
+
+    pub fn with_resource_group(mut self, resource_group: Option<String>) -> Self {
+        self.resource_group = resource_group;
+        self
+    }
+
+    pub fn with_default_query_tag(mut self, default_query_tag: Option<String>) -> Self {
+        self.default_query_tag = default_query_tag;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -137,6 +161,30 @@ impl UserOption {
         self.default_role = default_role;
     }
 
+    pub fn network_policy(&self) -> Option<&String> {
+        self.network_policy.as_ref()
+    }
+
+    pub fn set_network_policy(&mut self, network_policy: Option<String>) {
+        self.network_policy = network_policy;
+    }
+
+    pub fn resource_group(&self) -> Option<&String> {
+        self.resource_group.as_ref()
+    }
+
+    pub fn set_resource_group(&mut self, resource_group: Option<String>) {
+        self.resource_group = resource_group;
+    }
+
+    pub fn default_query_tag(&self) -> Option<&String> {
+        self.default_query_tag.as_ref()
+    }
+
+    pub fn set_default_query_tag(&mut self, default_query_tag: Option<String>) {
+        self.default_query_tag = default_query_tag;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
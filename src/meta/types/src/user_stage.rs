@@ -18,6 +18,7 @@ use std::str::FromStr;
 use chrono::DateTime;
 use chrono::Utc;
 use common_storage::StorageParams;
+use common_storage::UriLocation;
 
 use crate::UserIdentity;
 
@@ -158,6 +159,14 @@ pub struct FileFormatOptions {
     pub field_delimiter: String,
     pub record_delimiter: String,
     pub compression: StageFileCompression,
+    // The quote and escape char of CSV, empty for the default of the format.
+    pub quote: String,
+    pub escape: String,
+    // The string to be loaded as NULL, empty for the default of the format.
+    pub null_display: String,
+    // Whether a row with a different number of fields from the table is an error,
+    // otherwise, the missing fields are filled with the default and the extra ones are ignored.
+    pub error_on_column_count_mismatch: bool,
 }
 
 impl Default for FileFormatOptions {
@@ -168,6 +177,10 @@ impl Default for FileFormatOptions {
             field_delimiter: ",".to_string(),
             skip_header: 0,
             compression: StageFileCompression::default(),
+            quote: "".to_string(),
+            escape: "".to_string(),
+            null_display: "".to_string(),
+            error_on_column_count_mismatch: true,
         }
     }
 }
@@ -176,6 +189,9 @@ impl Default for FileFormatOptions {
 #[serde(default)]
 pub struct StageParams {
     pub storage: StorageParams,
+    /// The location of the external stage if it references a connection. The storage is kept
+    /// without the parameters of the connection, which are resolved when the stage is used.
+    pub location: Option<UriLocation>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -200,6 +216,7 @@ impl FromStr for OnErrorMode {
             "" => Ok(OnErrorMode::None),
             "CONTINUE" => Ok(OnErrorMode::Continue),
             "SKIP_FILE" => Ok(OnErrorMode::SkipFile),
+            "ABORT_STATEMENT" => Ok(OnErrorMode::AbortStatement),
             v => {
                 let num_str = v.replace("SKIP_FILE_", "");
                 let nums = num_str.parse::<u64>();
@@ -243,7 +260,10 @@ impl UserStageInfo {
         UserStageInfo {
             stage_name: format!("{storage},path={path}"),
             stage_type: StageType::External,
-            stage_params: StageParams { storage },
+            stage_params: StageParams {
+                storage,
+                location: None,
+            },
             ..Default::default()
         }
     }
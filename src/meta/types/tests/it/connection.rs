@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::exception::Result;
+use common_meta_types::ConnectionInfo;
+
+#[test]
+fn test_connection() -> Result<()> {
+    let connection = ConnectionInfo::new(
+        "my_s3",
+        "s3",
+        BTreeMap::from([
+            ("access_key_id".to_string(), "minioadmin".to_string()),
+            ("secret_access_key".to_string(), "v1:c2VjcmV0".to_string()),
+        ]),
+        "",
+    );
+    let ser = serde_json::to_string(&connection)?;
+
+    let de = ConnectionInfo::try_from(ser.into_bytes())?;
+    assert_eq!(connection, de);
+
+    assert!(ConnectionInfo::is_secret_param("secret_access_key"));
+    assert!(!ConnectionInfo::is_secret_param("endpoint_url"));
+    assert_eq!(
+        connection.masked_storage_params(),
+        BTreeMap::from([
+            ("access_key_id".to_string(), "******".to_string()),
+            ("secret_access_key".to_string(), "******".to_string()),
+        ])
+    );
+
+    Ok(())
+}
@@ -13,7 +13,17 @@
 //  limitations under the License.
 
 mod cluster;
+mod connection;
+mod dictionary;
+mod masking_policy;
 mod match_seq;
+mod network_policy;
+mod plan_hint;
+mod resource_group;
+mod row_access_policy;
+mod sequence;
+mod table_collations;
+mod task;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::NetworkPolicy;
+
+#[test]
+fn test_network_policy() -> Result<()> {
+    let policy = NetworkPolicy::new(
+        "office",
+        vec!["192.168.0.0/16".to_string()],
+        vec!["192.168.1.99".to_string()],
+        "the office network",
+    );
+    let ser = serde_json::to_string(&policy)?;
+
+    let de = NetworkPolicy::try_from(ser.into_bytes())?;
+    assert_eq!(policy, de);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::PlanHint;
+
+#[test]
+fn test_plan_hint() -> Result<()> {
+    let plan_hint = PlanHint::new(
+        "slow_join",
+        "SELECT * FROM t1, t2 WHERE t1.a = t2.a AND t1.b = 'x'",
+        "SELECT * FROM t1 , t2 WHERE t1 . a = t2 . a AND t1 . b = ?",
+        "BROADCAST(t2)",
+    );
+    let ser = serde_json::to_string(&plan_hint)?;
+
+    let de = PlanHint::try_from(ser.into_bytes())?;
+    assert_eq!(plan_hint, de);
+
+    Ok(())
+}
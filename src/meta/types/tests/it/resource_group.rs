@@ -0,0 +1,31 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::ResourceGroup;
+
+#[test]
+fn test_resource_group() -> Result<()> {
+    let group = ResourceGroup::new("etl", 50, 1 << 30, 4, "the etl workload");
+    let ser = serde_json::to_string(&group)?;
+
+    let de = ResourceGroup::try_from(ser.into_bytes())?;
+    assert_eq!(group, de);
+
+    assert_eq!(group.max_threads(16), 8);
+    assert_eq!(group.max_threads(1), 1);
+    assert_eq!(ResourceGroup::default().max_threads(16), 16);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::TableRowAccessPolicy;
+
+#[test]
+fn test_row_access_policy() -> Result<()> {
+    let policy = RowAccessPolicy::new("tenant_policy", vec!["t".to_string()], "t = current_role()");
+    let ser = serde_json::to_string(&policy)?;
+
+    let de = RowAccessPolicy::try_from(ser.into_bytes())?;
+    assert_eq!(policy, de);
+
+    Ok(())
+}
+
+#[test]
+fn test_table_row_access_policy() -> Result<()> {
+    let attached = TableRowAccessPolicy::new("tenant_policy", vec!["tenant".to_string()]);
+    let value = attached.to_option_value()?;
+
+    let de = TableRowAccessPolicy::try_from_option_value(&value)?;
+    assert_eq!(attached, de);
+
+    assert!(TableRowAccessPolicy::try_from_option_value("tenant_policy").is_err());
+
+    Ok(())
+}
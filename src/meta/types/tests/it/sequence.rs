@@ -0,0 +1,46 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::Sequence;
+
+#[test]
+fn test_sequence() -> Result<()> {
+    let mut sequence = Sequence::new("seq", 10, 5);
+    sequence.comment = "the seq".to_string();
+    let ser = serde_json::to_string(&sequence)?;
+
+    let de = Sequence::try_from(ser.into_bytes())?;
+    assert_eq!(sequence, de);
+
+    Ok(())
+}
+
+#[test]
+fn test_sequence_allocate() -> Result<()> {
+    let mut sequence = Sequence::new("seq", 10, 5);
+    assert_eq!(sequence.allocate(3)?, 10);
+    assert_eq!(sequence.allocate(1)?, 25);
+    assert_eq!(sequence.next_value, 30);
+
+    let mut sequence = Sequence::new("seq", 0, -2);
+    assert_eq!(sequence.allocate(2)?, 0);
+    assert_eq!(sequence.allocate(2)?, -4);
+
+    let mut sequence = Sequence::new("seq", i64::MAX - 1, 1);
+    assert!(sequence.allocate(2).is_err());
+    assert_eq!(sequence.next_value, i64::MAX - 1);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::TableCollations;
+
+#[test]
+fn test_table_collations() -> Result<()> {
+    let mut collations = TableCollations::default();
+    collations
+        .columns
+        .insert("name".to_string(), "utf8_ci".to_string());
+    let value = collations.to_option_value()?;
+
+    let de = TableCollations::try_from_option_value(&value)?;
+    assert_eq!(collations, de);
+
+    assert!(TableCollations::try_from_option_value("utf8_ci").is_err());
+
+    Ok(())
+}
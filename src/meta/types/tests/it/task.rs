@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDateTime;
+use common_exception::exception::Result;
+use common_meta_types::CronSchedule;
+use common_meta_types::ScheduledTask;
+use common_meta_types::UserIdentity;
+
+fn micros(time: &str) -> i64 {
+    NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S")
+        .unwrap()
+        .timestamp()
+        * 1_000_000
+}
+
+fn next_after(schedule: &str, after: &str) -> Result<Option<i64>> {
+    Ok(CronSchedule::parse(schedule)?.next_after(micros(after)))
+}
+
+#[test]
+fn test_scheduled_task() -> Result<()> {
+    let task = ScheduledTask {
+        name: "t1".to_string(),
+        schedule: Some("*/5 * * * *".to_string()),
+        sql: "INSERT INTO t SELECT 1".to_string(),
+        owner: UserIdentity::new("u1", "%"),
+        database: "default".to_string(),
+        comment: "the t1".to_string(),
+        ..Default::default()
+    };
+    let ser = serde_json::to_string(&task)?;
+
+    let de = ScheduledTask::try_from(ser.into_bytes())?;
+    assert_eq!(task, de);
+    assert!(de.cron_schedule()?.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_schedule_next_after() -> Result<()> {
+    // 2022-10-14 is a Friday
+    let now = "2022-10-14 10:03:20";
+    let cases = [
+        ("* * * * *", "2022-10-14 10:04:00"),
+        ("*/15 * * * *", "2022-10-14 10:15:00"),
+        ("0 0 * * *", "2022-10-15 00:00:00"),
+        ("30 9 * * 1-5", "2022-10-17 09:30:00"),
+        ("0 10-18/4 * * *", "2022-10-14 14:00:00"),
+        ("0 0 29 2 *", "2024-02-29 00:00:00"),
+        // either the day of the month or the day of the week if both are restricted
+        ("0 0 1,15 * 0", "2022-10-15 00:00:00"),
+        ("0 0 * * 7", "2022-10-16 00:00:00"),
+        ("@weekly", "2022-10-16 00:00:00"),
+        ("@yearly", "2023-01-01 00:00:00"),
+    ];
+    for (schedule, expected) in cases {
+        assert_eq!(
+            next_after(schedule, now)?,
+            Some(micros(expected)),
+            "{}",
+            schedule
+        );
+    }
+
+    // strictly after the time
+    assert_eq!(
+        next_after("*/15 * * * *", "2022-10-14 10:15:00")?,
+        Some(micros("2022-10-14 10:30:00"))
+    );
+    assert_eq!(next_after("0 0 30 2 *", now)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_cron_schedule_illegal() -> Result<()> {
+    for schedule in [
+        "* * *",
+        "* * * * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "* * 0 * *",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "a * * * *",
+        "@never",
+    ] {
+        assert!(CronSchedule::parse(schedule).is_err(), "{}", schedule);
+    }
+
+    Ok(())
+}
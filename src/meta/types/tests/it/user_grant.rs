@@ -77,6 +77,16 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::Database("default".into(), "db1".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -95,6 +95,12 @@ pub enum Expr<'a> {
         expr: Box<Expr<'a>>,
         target_type: TypeName,
     },
+    /// `<expr> COLLATE '<collation>'` expression
+    Collate {
+        span: &'a [Token<'a>],
+        expr: Box<Expr<'a>>,
+        collation: String,
+    },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
         span: &'a [Token<'a>],
@@ -344,6 +350,7 @@ impl<'a> Expr<'a> {
             | Expr::UnaryOp { span, .. }
             | Expr::Cast { span, .. }
             | Expr::TryCast { span, .. }
+            | Expr::Collate { span, .. }
             | Expr::Extract { span, .. }
             | Expr::Position { span, .. }
             | Expr::Substring { span, .. }
@@ -710,6 +717,11 @@ impl<'a> Display for Expr<'a> {
             } => {
                 write!(f, "TRY_CAST({expr} AS {target_type})")?;
             }
+            Expr::Collate {
+                expr, collation, ..
+            } => {
+                write!(f, "{expr} COLLATE '{collation}'")?;
+            }
             Expr::Extract {
                 kind: field, expr, ..
             } => {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
 use common_datavalues::IntervalKind;
@@ -348,6 +349,23 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_collate(
+        &mut self,
+        _span: &'ast [Token<'ast>],
+        expr: &'ast Expr<'ast>,
+        collation: &'ast str,
+    ) {
+        self.visit_expr(expr);
+        let expr_child = self.children.pop().unwrap();
+        let collation_format_ctx = AstFormatContext::new(format!("Collation {collation}"));
+        let collation_child = FormatTreeNode::new(collation_format_ctx);
+
+        let name = "Collate".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![expr_child, collation_child]);
+        self.children.push(node);
+    }
+
     fn visit_extract(
         &mut self,
         _span: &'ast [Token<'ast>],
@@ -722,6 +740,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Fragments => "Fragments",
             ExplainKind::Raw => "Raw",
             ExplainKind::Plan => "Plan",
+            ExplainKind::Analyze => "Analyze",
+            ExplainKind::Validate => "Validate",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
         let node = FormatTreeNode::with_children(format_ctx, vec![child]);
@@ -907,6 +927,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_begin(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_rollback(&mut self) {
+        let name = "Rollback".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_set_variable(
         &mut self,
         is_global: bool,
@@ -923,6 +964,34 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_set_user_variable(
+        &mut self,
+        variable: &'ast Identifier<'ast>,
+        value: &'ast Expr<'ast>,
+    ) {
+        self.visit_expr(value);
+        let child = self.children.pop().unwrap();
+
+        let name = format!("SetVariable {}", variable);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_unset_user_variable(&mut self, variable: &'ast Identifier<'ast>) {
+        let name = format!("UnsetVariable {}", variable);
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_execute_immediate(&mut self, _script: &'ast str) {
+        let name = "ExecuteImmediate".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_insert(&mut self, insert: &'ast InsertStmt<'ast>) {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
@@ -952,6 +1021,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_replace(&mut self, replace: &'ast ReplaceStmt<'ast>) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&replace.catalog, &replace.database, &replace.table);
+        children.push(self.children.pop().unwrap());
+        for (name, columns) in [
+            ("Columns", &replace.columns),
+            ("OnConflictColumns", &replace.on_conflict_columns),
+        ] {
+            if columns.is_empty() {
+                continue;
+            }
+            let mut columns_children = Vec::with_capacity(columns.len());
+            for column in columns.iter() {
+                self.visit_identifier(column);
+                columns_children.push(self.children.pop().unwrap());
+            }
+            let columns_format_ctx =
+                AstFormatContext::with_children(name.to_string(), columns_children.len());
+            let columns_node = FormatTreeNode::with_children(columns_format_ctx, columns_children);
+            children.push(columns_node);
+        }
+        self.visit_insert_source(&replace.source);
+        children.push(self.children.pop().unwrap());
+
+        let name = "Replace".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_insert_source(&mut self, insert_source: &'ast InsertSource<'ast>) {
         match insert_source {
             InsertSource::Streaming { format, .. } => {
@@ -1063,6 +1162,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_backup_database(&mut self, stmt: &'ast BackupDatabaseStmt<'ast>) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let child = self.children.pop().unwrap();
+        let name = format!("BackupDatabase {}", stmt.location);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_restore_database(&mut self, stmt: &'ast RestoreDatabaseStmt<'ast>) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let child = self.children.pop().unwrap();
+        let name = format!("RestoreDatabase {}", stmt.location);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_alter_database(&mut self, stmt: &'ast AlterDatabaseStmt<'ast>) {
         self.visit_database_ref(&stmt.catalog, &stmt.database);
         let database_child = self.children.pop().unwrap();
@@ -1073,6 +1190,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterDatabaseAction::SetComment { comment } => {
+                let action_name = format!("Action SetComment '{}'", comment);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterDatabase".to_string();
@@ -1144,6 +1266,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_drop_tables(&mut self, stmt: &'ast ShowDropTablesStmt<'ast>) {
+        let mut children = Vec::new();
+        if let Some(database) = &stmt.database {
+            let database_name = format!("Database {}", database);
+            let database_format_ctx = AstFormatContext::new(database_name);
+            let database_node = FormatTreeNode::new(database_format_ctx);
+            children.push(database_node);
+        }
+        if let Some(limit) = &stmt.limit {
+            self.visit_show_limit(limit);
+            children.push(self.children.pop().unwrap());
+        }
+        let name = "ShowDropTables".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_table(&mut self, stmt: &'ast CreateTableStmt<'ast>) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
@@ -1158,6 +1298,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let engine_node = FormatTreeNode::new(engine_format_ctx);
             children.push(engine_node);
         }
+        if let Some(external) = &stmt.external {
+            let external_name = format!("ExternalTableSource {}", external);
+            let external_format_ctx = AstFormatContext::new(external_name);
+            let external_node = FormatTreeNode::new(external_format_ctx);
+            children.push(external_node);
+        }
         if !stmt.cluster_by.is_empty() {
             let mut cluster_by_children = Vec::with_capacity(stmt.cluster_by.len());
             for cluster_by in stmt.cluster_by.iter() {
@@ -1257,6 +1403,26 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_attach_table(&mut self, stmt: &'ast AttachTableStmt<'ast>) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+
+        let name = format!("AttachTable {}", stmt.location);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_detach_table(&mut self, stmt: &'ast DetachTableStmt<'ast>) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+
+        let name = "DetachTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_alter_table(&mut self, stmt: &'ast AlterTableStmt<'ast>) {
         self.visit_table_reference(&stmt.table_reference);
         let table_child = self.children.pop().unwrap();
@@ -1267,6 +1433,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::SwapWith { target_table } => {
+                let action_name = format!("Action SwapWith {}", target_table);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let mut cluster_by_children = Vec::with_capacity(cluster_by.len());
                 for cluster_by_expr in cluster_by.iter() {
@@ -1294,6 +1465,73 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(action_name, children.len());
                 FormatTreeNode::with_children(action_format_ctx, children)
             }
+            AlterTableAction::SetOptions { set_options } => {
+                let mut children = Vec::with_capacity(set_options.len());
+                for (key, value) in set_options.iter() {
+                    let option_name = format!("TableOption {} = {}", key, value);
+                    children.push(FormatTreeNode::new(AstFormatContext::new(option_name)));
+                }
+                let action_name = "Action SetOptions".to_string();
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::AddColumn { column } => {
+                self.visit_column_definition(column);
+                let column_child = self.children.pop().unwrap();
+                let action_name = "Action AddColumn".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![column_child])
+            }
+            AlterTableAction::AddVirtualColumn { column, expr } => {
+                self.visit_expr(expr);
+                let expr_child = self.children.pop().unwrap();
+                let action_name = format!("Action AddVirtualColumn {}", column);
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![expr_child])
+            }
+            AlterTableAction::DropVirtualColumn { column } => {
+                let action_name = format!("Action DropVirtualColumn {}", column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let mut columns_children = Vec::with_capacity(columns.len());
+                for column in columns.iter() {
+                    self.visit_identifier(column);
+                    columns_children.push(self.children.pop().unwrap());
+                }
+                let action_name = format!("Action AddRowAccessPolicy {}", policy);
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, columns_children.len());
+                FormatTreeNode::with_children(action_format_ctx, columns_children)
+            }
+            AlterTableAction::DropRowAccessPolicy => {
+                let action_name = "Action DropRowAccessPolicy".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::SetMaskingPolicy { column, policy } => {
+                self.visit_identifier(column);
+                let column_child = self.children.pop().unwrap();
+                let action_name = format!("Action SetMaskingPolicy {}", policy);
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![column_child])
+            }
+            AlterTableAction::UnsetMaskingPolicy { column } => {
+                self.visit_identifier(column);
+                let column_child = self.children.pop().unwrap();
+                let action_name = "Action UnsetMaskingPolicy".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![column_child])
+            }
+            AlterTableAction::ModifyColumnComment { column, comment } => {
+                self.visit_identifier(column);
+                let column_child = self.children.pop().unwrap();
+                let action_name = format!("Action ModifyColumnComment '{}'", comment);
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![column_child])
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1340,6 +1578,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_analyze_table(&mut self, stmt: &'ast AnalyzeTableStmt<'ast>) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+
+        let name = "AnalyzeTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_exists_table(&mut self, stmt: &'ast ExistsTableStmt<'ast>) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1384,6 +1632,55 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt<'ast>) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt<'ast>) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_aggregating_index(&mut self, stmt: &'ast CreateAggregatingIndexStmt<'ast>) {
+        let index_format_ctx = AstFormatContext::new(format!("IndexIdentifier {}", stmt.index));
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateAggregatingIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![index_child, table_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_aggregating_index(&mut self, stmt: &'ast DropAggregatingIndexStmt<'ast>) {
+        let index_format_ctx = AstFormatContext::new(format!("IndexIdentifier {}", stmt.index));
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropAggregatingIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1511,6 +1808,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_set_role(&mut self, role_name: &'ast str) {
+        let role_name = format!("Role {}", role_name);
+        let role_format_ctx = AstFormatContext::new(role_name);
+        let child = FormatTreeNode::new(role_format_ctx);
+
+        let name = "SetRole".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_grant(&mut self, grant: &'ast GrantStmt) {
         let source_child = match &grant.source {
             AccountMgrSource::Role { role } => {
@@ -1700,6 +2008,434 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(
+        &mut self,
+        _if_not_exists: bool,
+        policy_name: &'ast Identifier<'ast>,
+        parameters: &'ast [Identifier<'ast>],
+        definition: &'ast Expr<'ast>,
+    ) {
+        let mut children = Vec::new();
+        let policy_name_format_ctx =
+            AstFormatContext::new(format!("RowAccessPolicyIdentifier {}", policy_name));
+        children.push(FormatTreeNode::new(policy_name_format_ctx));
+        if !parameters.is_empty() {
+            let mut parameters_children = Vec::with_capacity(parameters.len());
+            for parameter in parameters.iter() {
+                self.visit_identifier(parameter);
+                parameters_children.push(self.children.pop().unwrap());
+            }
+            let parameters_name = "RowAccessPolicyParameters".to_string();
+            let parameters_format_ctx =
+                AstFormatContext::with_children(parameters_name, parameters_children.len());
+            children.push(FormatTreeNode::with_children(
+                parameters_format_ctx,
+                parameters_children,
+            ));
+        }
+        self.visit_expr(definition);
+        let definition_child = self.children.pop().unwrap();
+        let definition_name = "RowAccessPolicyDefinition".to_string();
+        let definition_format_ctx = AstFormatContext::with_children(definition_name, 1);
+        children.push(FormatTreeNode::with_children(definition_format_ctx, vec![
+            definition_child,
+        ]));
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(
+        &mut self,
+        _if_exists: bool,
+        policy_name: &'ast Identifier<'ast>,
+    ) {
+        let policy_name_format_ctx =
+            AstFormatContext::new(format!("RowAccessPolicyIdentifier {}", policy_name));
+        let child = FormatTreeNode::new(policy_name_format_ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_masking_policy(
+        &mut self,
+        _if_not_exists: bool,
+        policy_name: &'ast Identifier<'ast>,
+        parameter: &'ast Identifier<'ast>,
+        return_type: &'ast TypeName,
+        definition: &'ast Expr<'ast>,
+    ) {
+        let mut children = Vec::new();
+        let policy_name_format_ctx =
+            AstFormatContext::new(format!("MaskingPolicyIdentifier {}", policy_name));
+        children.push(FormatTreeNode::new(policy_name_format_ctx));
+        self.visit_identifier(parameter);
+        let parameter_child = self.children.pop().unwrap();
+        let parameter_name = "MaskingPolicyParameter".to_string();
+        let parameter_format_ctx = AstFormatContext::with_children(parameter_name, 1);
+        children.push(FormatTreeNode::with_children(parameter_format_ctx, vec![
+            parameter_child,
+        ]));
+        let return_type_format_ctx =
+            AstFormatContext::new(format!("MaskingPolicyReturnType {}", return_type));
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+        self.visit_expr(definition);
+        let definition_child = self.children.pop().unwrap();
+        let definition_name = "MaskingPolicyDefinition".to_string();
+        let definition_format_ctx = AstFormatContext::with_children(definition_name, 1);
+        children.push(FormatTreeNode::with_children(definition_format_ctx, vec![
+            definition_child,
+        ]));
+
+        let name = "CreateMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_masking_policy(&mut self, _if_exists: bool, policy_name: &'ast Identifier<'ast>) {
+        let policy_name_format_ctx =
+            AstFormatContext::new(format!("MaskingPolicyIdentifier {}", policy_name));
+        let child = FormatTreeNode::new(policy_name_format_ctx);
+
+        let name = "DropMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_network_policy(
+        &mut self,
+        _if_not_exists: bool,
+        policy_name: &'ast Identifier<'ast>,
+        allowed_ip_list: &'ast [String],
+        blocked_ip_list: &'ast [String],
+        comment: &'ast Option<String>,
+    ) {
+        let mut children = Vec::new();
+        let policy_name_format_ctx =
+            AstFormatContext::new(format!("NetworkPolicyIdentifier {}", policy_name));
+        children.push(FormatTreeNode::new(policy_name_format_ctx));
+        let allowed_ip_list_format_ctx =
+            AstFormatContext::new(format!("AllowedIpList {}", allowed_ip_list.join(", ")));
+        children.push(FormatTreeNode::new(allowed_ip_list_format_ctx));
+        if !blocked_ip_list.is_empty() {
+            let blocked_ip_list_format_ctx =
+                AstFormatContext::new(format!("BlockedIpList {}", blocked_ip_list.join(", ")));
+            children.push(FormatTreeNode::new(blocked_ip_list_format_ctx));
+        }
+        if let Some(comment) = comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_network_policy(&mut self, _if_exists: bool, policy_name: &'ast Identifier<'ast>) {
+        let policy_name_format_ctx =
+            AstFormatContext::new(format!("NetworkPolicyIdentifier {}", policy_name));
+        let child = FormatTreeNode::new(policy_name_format_ctx);
+
+        let name = "DropNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_task(&mut self, stmt: &'ast CreateTaskStmt<'ast>) {
+        let mut children = Vec::new();
+        let task_name_format_ctx =
+            AstFormatContext::new(format!("TaskIdentifier {}", stmt.task_name));
+        children.push(FormatTreeNode::new(task_name_format_ctx));
+        let schedule_format_ctx = AstFormatContext::new(format!("TaskSchedule {}", stmt.schedule));
+        children.push(FormatTreeNode::new(schedule_format_ctx));
+        self.visit_statement(&stmt.sql);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreateTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_task(&mut self, _if_exists: bool, task_name: &'ast Identifier<'ast>) {
+        let task_name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", task_name));
+        let child = FormatTreeNode::new(task_name_format_ctx);
+
+        let name = "DropTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_tasks(&mut self) {
+        let name = "ShowTasks".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_alter_task(
+        &mut self,
+        task_name: &'ast Identifier<'ast>,
+        action: &'ast AlterTaskAction,
+    ) {
+        let task_name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", task_name));
+        let child = FormatTreeNode::new(task_name_format_ctx);
+
+        let name = format!("AlterTask {}", action);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_resource_group(
+        &mut self,
+        _if_not_exists: bool,
+        group_name: &'ast Identifier<'ast>,
+        cpu_share: &'ast Option<u64>,
+        max_memory_usage: &'ast Option<u64>,
+        max_concurrency: &'ast Option<u64>,
+        comment: &'ast Option<String>,
+    ) {
+        let mut children = Vec::new();
+        let group_name_format_ctx =
+            AstFormatContext::new(format!("ResourceGroupIdentifier {}", group_name));
+        children.push(FormatTreeNode::new(group_name_format_ctx));
+        if let Some(cpu_share) = cpu_share {
+            let cpu_share_format_ctx = AstFormatContext::new(format!("CpuShare {}", cpu_share));
+            children.push(FormatTreeNode::new(cpu_share_format_ctx));
+        }
+        if let Some(max_memory_usage) = max_memory_usage {
+            let max_memory_usage_format_ctx =
+                AstFormatContext::new(format!("MaxMemoryUsage {}", max_memory_usage));
+            children.push(FormatTreeNode::new(max_memory_usage_format_ctx));
+        }
+        if let Some(max_concurrency) = max_concurrency {
+            let max_concurrency_format_ctx =
+                AstFormatContext::new(format!("MaxConcurrency {}", max_concurrency));
+            children.push(FormatTreeNode::new(max_concurrency_format_ctx));
+        }
+        if let Some(comment) = comment {
+            let comment_format_ctx = AstFormatContext::new(format!("Comment {}", comment));
+            children.push(FormatTreeNode::new(comment_format_ctx));
+        }
+
+        let name = "CreateResourceGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_resource_group(&mut self, _if_exists: bool, group_name: &'ast Identifier<'ast>) {
+        let group_name_format_ctx =
+            AstFormatContext::new(format!("ResourceGroupIdentifier {}", group_name));
+        let child = FormatTreeNode::new(group_name_format_ctx);
+
+        let name = "DropResourceGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_connection(
+        &mut self,
+        _if_not_exists: bool,
+        connection_name: &'ast Identifier<'ast>,
+        storage_type: &'ast str,
+        storage_params: &'ast BTreeMap<String, String>,
+    ) {
+        let mut children = Vec::new();
+        let connection_name_format_ctx =
+            AstFormatContext::new(format!("ConnectionIdentifier {}", connection_name));
+        children.push(FormatTreeNode::new(connection_name_format_ctx));
+        let storage_type_format_ctx =
+            AstFormatContext::new(format!("StorageType {}", storage_type));
+        children.push(FormatTreeNode::new(storage_type_format_ctx));
+        for key in storage_params.keys() {
+            let storage_param_format_ctx = AstFormatContext::new(format!("StorageParam {}", key));
+            children.push(FormatTreeNode::new(storage_param_format_ctx));
+        }
+
+        let name = "CreateConnection".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_connection(&mut self, _if_exists: bool, connection_name: &'ast Identifier<'ast>) {
+        let connection_name_format_ctx =
+            AstFormatContext::new(format!("ConnectionIdentifier {}", connection_name));
+        let child = FormatTreeNode::new(connection_name_format_ctx);
+
+        let name = "DropConnection".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_connections(&mut self) {
+        let name = "ShowConnections".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_describe_connection(&mut self, connection_name: &'ast Identifier<'ast>) {
+        let connection_name_format_ctx =
+            AstFormatContext::new(format!("ConnectionIdentifier {}", connection_name));
+        let child = FormatTreeNode::new(connection_name_format_ctx);
+
+        let name = "DescribeConnection".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_sequence(
+        &mut self,
+        _if_not_exists: bool,
+        sequence_name: &'ast Identifier<'ast>,
+        start: &'ast Option<i64>,
+        increment: &'ast Option<i64>,
+        _comment: &'ast Option<String>,
+    ) {
+        let mut children = Vec::new();
+        let sequence_name_format_ctx =
+            AstFormatContext::new(format!("SequenceIdentifier {}", sequence_name));
+        children.push(FormatTreeNode::new(sequence_name_format_ctx));
+        if let Some(start) = start {
+            let start_format_ctx = AstFormatContext::new(format!("SequenceStart {}", start));
+            children.push(FormatTreeNode::new(start_format_ctx));
+        }
+        if let Some(increment) = increment {
+            let increment_format_ctx =
+                AstFormatContext::new(format!("SequenceIncrement {}", increment));
+            children.push(FormatTreeNode::new(increment_format_ctx));
+        }
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, _if_exists: bool, sequence_name: &'ast Identifier<'ast>) {
+        let sequence_name_format_ctx =
+            AstFormatContext::new(format!("SequenceIdentifier {}", sequence_name));
+        let child = FormatTreeNode::new(sequence_name_format_ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_sequences(&mut self) {
+        let name = "ShowSequences".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_plan_hint(
+        &mut self,
+        _if_not_exists: bool,
+        hint_name: &'ast Identifier<'ast>,
+        statement: &'ast str,
+        hints: &'ast str,
+    ) {
+        let hint_name_format_ctx =
+            AstFormatContext::new(format!("PlanHintIdentifier {}", hint_name));
+        let statement_format_ctx =
+            AstFormatContext::new(format!("PlanHintStatement {}", statement));
+        let hints_format_ctx = AstFormatContext::new(format!("PlanHints {}", hints));
+        let children = vec![
+            FormatTreeNode::new(hint_name_format_ctx),
+            FormatTreeNode::new(statement_format_ctx),
+            FormatTreeNode::new(hints_format_ctx),
+        ];
+
+        let name = "CreatePlanHint".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_plan_hint(&mut self, _if_exists: bool, hint_name: &'ast Identifier<'ast>) {
+        let hint_name_format_ctx =
+            AstFormatContext::new(format!("PlanHintIdentifier {}", hint_name));
+        let child = FormatTreeNode::new(hint_name_format_ctx);
+
+        let name = "DropPlanHint".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_plan_hints(&mut self) {
+        let name = "ShowPlanHints".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_drain_node(&mut self, node_id: &'ast str) {
+        let node_id_format_ctx = AstFormatContext::new(format!("NodeId {}", node_id));
+        let child = FormatTreeNode::new(node_id_format_ctx);
+
+        let name = "DrainNode".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_dictionary(&mut self, stmt: &'ast CreateDictionaryStmt<'ast>) {
+        let mut children = Vec::new();
+        let dictionary_name_format_ctx =
+            AstFormatContext::new(format!("DictionaryIdentifier {}", stmt.dictionary_name));
+        children.push(FormatTreeNode::new(dictionary_name_format_ctx));
+        for column in stmt.columns.iter() {
+            let column_format_ctx = AstFormatContext::new(format!("DictionaryColumn {}", column));
+            children.push(FormatTreeNode::new(column_format_ctx));
+        }
+        let primary_key_format_ctx =
+            AstFormatContext::new(format!("DictionaryPrimaryKey {}", stmt.primary_key));
+        children.push(FormatTreeNode::new(primary_key_format_ctx));
+
+        let name = "CreateDictionary".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_dictionary(&mut self, _if_exists: bool, dictionary_name: &'ast Identifier<'ast>) {
+        let dictionary_name_format_ctx =
+            AstFormatContext::new(format!("DictionaryIdentifier {}", dictionary_name));
+        let child = FormatTreeNode::new(dictionary_name_format_ctx);
+
+        let name = "DropDictionary".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_dictionaries(&mut self) {
+        let name = "ShowDictionaries".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_create_stage(&mut self, stmt: &'ast CreateStageStmt) {
         let mut children = Vec::new();
         let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stmt.stage_name));
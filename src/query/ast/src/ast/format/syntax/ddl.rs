@@ -17,6 +17,7 @@ use pretty::RcDoc;
 use super::expr::pretty_expr;
 use super::query::pretty_query;
 use super::query::pretty_table;
+use crate::ast::format::syntax::inline_comma;
 use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthenized;
 use crate::ast::format::syntax::NEST_FACTOR;
@@ -153,6 +154,9 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc {
         AlterTableAction::RenameTable { new_table } => RcDoc::line()
             .append(RcDoc::text("RENAME TO "))
             .append(RcDoc::text(new_table.to_string())),
+        AlterTableAction::SwapWith { target_table } => RcDoc::line()
+            .append(RcDoc::text("SWAP WITH "))
+            .append(RcDoc::text(target_table.to_string())),
         AlterTableAction::AlterTableClusterKey { cluster_by } => RcDoc::line()
             .append(RcDoc::text("CLUSTER BY "))
             .append(parenthenized(
@@ -180,6 +184,56 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc {
             } else {
                 RcDoc::nil()
             }),
+        AlterTableAction::SetOptions { set_options } => RcDoc::line()
+            .append(RcDoc::text("SET "))
+            .append(interweave_comma(set_options.into_iter().map(|(k, v)| {
+                RcDoc::text(k)
+                    .append(RcDoc::space())
+                    .append(RcDoc::text("="))
+                    .append(RcDoc::space())
+                    .append(RcDoc::text("'"))
+                    .append(RcDoc::text(v))
+                    .append(RcDoc::text("'"))
+            })))
+            .group(),
+        AlterTableAction::AddColumn { column } => RcDoc::line()
+            .append(RcDoc::text("ADD COLUMN "))
+            .append(RcDoc::text(column.to_string())),
+        AlterTableAction::AddVirtualColumn { column, expr } => RcDoc::line()
+            .append(RcDoc::text("ADD VIRTUAL COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(" AS"))
+            .append(RcDoc::line().nest(NEST_FACTOR).append(pretty_expr(expr)))
+            .group(),
+        AlterTableAction::DropVirtualColumn { column } => RcDoc::line()
+            .append(RcDoc::text("DROP VIRTUAL COLUMN "))
+            .append(RcDoc::text(column.to_string())),
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text("ADD ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string()))
+            .append(RcDoc::text(" ON ("))
+            .append(inline_comma(
+                columns
+                    .into_iter()
+                    .map(|column| RcDoc::text(column.to_string())),
+            ))
+            .append(RcDoc::text(")")),
+        AlterTableAction::DropRowAccessPolicy => {
+            RcDoc::line().append(RcDoc::text("DROP ROW ACCESS POLICY"))
+        }
+        AlterTableAction::SetMaskingPolicy { column, policy } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(" SET MASKING POLICY "))
+            .append(RcDoc::text(policy.to_string())),
+        AlterTableAction::UnsetMaskingPolicy { column } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(" UNSET MASKING POLICY")),
+        AlterTableAction::ModifyColumnComment { column, comment } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::text(format!(" COMMENT '{comment}'"))),
     }
 }
 
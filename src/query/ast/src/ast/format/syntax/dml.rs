@@ -159,6 +159,13 @@ pub(crate) fn pretty_copy(copy_stmt: CopyStmt) -> RcDoc {
         } else {
             RcDoc::nil()
         })
+        .append(if !copy_stmt.on_error.is_empty() {
+            RcDoc::line()
+                .append(RcDoc::text("ON_ERROR = "))
+                .append(RcDoc::text(copy_stmt.on_error))
+        } else {
+            RcDoc::nil()
+        })
         .append(if copy_stmt.size_limit != 0 {
             RcDoc::line()
                 .append(RcDoc::text("SIZE_LIMIT = "))
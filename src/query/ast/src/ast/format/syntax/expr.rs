@@ -156,6 +156,13 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc {
             .append(RcDoc::space())
             .append(RcDoc::text(target_type.to_string()))
             .append(RcDoc::text(")")),
+        Expr::Collate {
+            expr, collation, ..
+        } => pretty_expr(*expr)
+            .append(RcDoc::space())
+            .append(RcDoc::text("COLLATE"))
+            .append(RcDoc::space())
+            .append(RcDoc::text(format!("'{collation}'"))),
         Expr::Extract {
             kind: field, expr, ..
         } => RcDoc::text("EXTRACT(")
@@ -19,6 +19,7 @@ use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::Literal;
 use crate::parser::token::Token;
 
 /// Root node of a query tree
@@ -69,6 +70,8 @@ pub struct SetOperation<'a> {
 pub struct SelectStmt<'a> {
     pub span: &'a [Token<'a>],
     pub distinct: bool,
+    // Optimizer hints, like `/*+ BROADCAST(t) */`
+    pub hints: Vec<SelectHint<'a>>,
     // Result set of current subquery
     pub select_list: Vec<SelectTarget<'a>>,
     // `FROM` clause, a list of table references.
@@ -83,6 +86,21 @@ pub struct SelectStmt<'a> {
     pub having: Option<Expr<'a>>,
 }
 
+/// An optimizer hint in the comment after `SELECT`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectHint<'a> {
+    /// Broadcasts the tables to all the nodes in the distributed joins,
+    /// instead of shuffling both sides of the joins.
+    Broadcast(Vec<Identifier<'a>>),
+    /// Overrides the max_execute_time setting of the session for the query, in milliseconds.
+    MaxExecutionTime(u64),
+    /// Overrides a setting of the session for the query.
+    SetVar {
+        variable: Identifier<'a>,
+        value: Literal,
+    },
+}
+
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
 #[derive(Debug, Clone, PartialEq)]
 pub enum SetExpr<'a> {
@@ -381,6 +399,13 @@ impl<'a> Display for SelectStmt<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // SELECT clause
         write!(f, "SELECT ")?;
+        if !self.hints.is_empty() {
+            write!(f, "/*+ ")?;
+            for hint in self.hints.iter() {
+                write!(f, "{hint} ")?;
+            }
+            write!(f, "*/ ")?;
+        }
         if self.distinct {
             write!(f, "DISTINCT ")?;
         }
@@ -412,6 +437,20 @@ impl<'a> Display for SelectStmt<'a> {
     }
 }
 
+impl<'a> Display for SelectHint<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectHint::Broadcast(tables) => {
+                write!(f, "BROADCAST(")?;
+                write_comma_separated_list(f, tables)?;
+                write!(f, ")")
+            }
+            SelectHint::MaxExecutionTime(millis) => write!(f, "MAX_EXECUTION_TIME({millis})"),
+            SelectHint::SetVar { variable, value } => write!(f, "SET_VAR({variable} = {value})"),
+        }
+    }
+}
+
 impl<'a> Display for SetExpr<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -37,6 +37,7 @@ pub struct CopyStmt<'a> {
     pub file_format: BTreeMap<String, String>,
     /// TODO(xuanwo): parse into validation_mode directly.
     pub validation_mode: String,
+    pub on_error: String,
     pub size_limit: usize,
     pub max_file_size: usize,
     pub single: bool,
@@ -72,6 +73,10 @@ impl Display for CopyStmt<'_> {
             write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
+        if !self.on_error.is_empty() {
+            write!(f, " ON_ERROR = {}", self.on_error)?;
+        }
+
         if self.size_limit != 0 {
             write!(f, " SIZE_LIMIT = {}", self.size_limit)?;
         }
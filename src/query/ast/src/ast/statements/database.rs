@@ -20,6 +20,7 @@ use common_meta_app::share::ShareNameIdent;
 use crate::ast::statements::show::ShowLimit;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
+use crate::ast::UriLocation;
 
 #[derive(Debug, Clone, PartialEq)] // Databases
 pub struct ShowDatabasesStmt<'a> {
@@ -60,6 +61,7 @@ pub struct CreateDatabaseStmt<'a> {
     pub engine: Option<DatabaseEngine>,
     pub options: Vec<SQLProperty>,
     pub from_share: Option<ShareNameIdent>,
+    pub comment: Option<String>,
 }
 
 impl Display for CreateDatabaseStmt<'_> {
@@ -79,6 +81,9 @@ impl Display for CreateDatabaseStmt<'_> {
                 from_share.tenant, from_share.share_name
             )?;
         }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
         // TODO(leiysky): display rest information
         Ok(())
     }
@@ -117,6 +122,39 @@ impl Display for UndropDatabaseStmt<'_> {
     }
 }
 
+/// Copies the tables of the database with their data to the location, the data kept by the
+/// last backup in the location are not copied again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupDatabaseStmt<'a> {
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Identifier<'a>,
+    pub location: UriLocation,
+}
+
+impl Display for BackupDatabaseStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BACKUP DATABASE ")?;
+        write_period_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO {}", self.location)
+    }
+}
+
+/// Creates the database with the tables and the data of the backup kept in the location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreDatabaseStmt<'a> {
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Identifier<'a>,
+    pub location: UriLocation,
+}
+
+impl Display for RestoreDatabaseStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RESTORE DATABASE ")?;
+        write_period_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM {}", self.location)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AlterDatabaseStmt<'a> {
     pub if_exists: bool,
@@ -136,6 +174,9 @@ impl Display for AlterDatabaseStmt<'_> {
             AlterDatabaseAction::RenameDatabase { new_db } => {
                 write!(f, " RENAME TO {new_db}")?;
             }
+            AlterDatabaseAction::SetComment { comment } => {
+                write!(f, " COMMENT = '{comment}'")?;
+            }
         }
 
         Ok(())
@@ -145,6 +186,7 @@ impl Display for AlterDatabaseStmt<'_> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlterDatabaseAction<'a> {
     RenameDatabase { new_db: Identifier<'a> },
+    SetComment { comment: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,67 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_space_seperated_map;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateDictionaryStmt<'a> {
+    pub if_not_exists: bool,
+    pub dictionary_name: Identifier<'a>,
+    pub columns: Vec<DictionaryColumnDefinition<'a>>,
+    pub primary_key: Identifier<'a>,
+    /// The options of `SOURCE = (...)`, the names are in lowercase.
+    pub source_options: BTreeMap<String, String>,
+    pub lifetime: Option<u64>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryColumnDefinition<'a> {
+    pub name: Identifier<'a>,
+    pub data_type: TypeName,
+}
+
+impl Display for CreateDictionaryStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE DICTIONARY")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {} (", self.dictionary_name)?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ") PRIMARY KEY {} SOURCE = (", self.primary_key)?;
+        write_space_seperated_map(f, &self.source_options)?;
+        write!(f, ")")?;
+        if let Some(lifetime) = self.lifetime {
+            write!(f, " LIFETIME = {lifetime}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for DictionaryColumnDefinition<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
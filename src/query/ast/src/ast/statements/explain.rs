@@ -21,4 +21,7 @@ pub enum ExplainKind {
     Fragments,
     Raw,
     Plan,
+    Analyze,
+    /// Builds the pipeline of the statement without executing it, and reports the problems of it.
+    Validate,
 }
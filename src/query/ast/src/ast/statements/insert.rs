@@ -54,6 +54,39 @@ impl Display for InsertStmt<'_> {
     }
 }
 
+/// `REPLACE INTO <table> [(<column>, ...)] ON (<column>, ...) <source>`, the rows of the table
+/// with the same values of the `ON` columns as the rows inserted are replaced by them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaceStmt<'a> {
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Option<Identifier<'a>>,
+    pub table: Identifier<'a>,
+    pub columns: Vec<Identifier<'a>>,
+    pub on_conflict_columns: Vec<Identifier<'a>>,
+    pub source: InsertSource<'a>,
+}
+
+impl Display for ReplaceStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "REPLACE INTO ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if !self.columns.is_empty() {
+            write!(f, " (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
+        write!(f, " ON (")?;
+        write_comma_separated_list(f, &self.on_conflict_columns)?;
+        write!(f, ") {}", self.source)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum InsertSource<'a> {
     Streaming {
@@ -12,32 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregating_index;
 mod call;
 mod copy;
 mod database;
+mod dictionary;
 mod explain;
 mod insert;
 mod kill;
 mod presign;
+mod script;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
+mod task;
 mod user;
 mod view;
 
+pub use aggregating_index::*;
 pub use call::*;
 pub use copy::*;
 pub use database::*;
+pub use dictionary::*;
 pub use explain::*;
 pub use insert::*;
 pub use kill::*;
 pub use presign::*;
+pub use script::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use user::*;
 pub use view::*;
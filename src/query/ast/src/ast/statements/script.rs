@@ -0,0 +1,163 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::Expr;
+use crate::ast::Identifier;
+
+/// The block of the script of `EXECUTE IMMEDIATE`,
+/// `BEGIN <statement>; ... [EXCEPTION WHEN OTHER THEN <statement>; ...] END`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptBlock<'a> {
+    pub body: Vec<ScriptStatement<'a>>,
+    /// The statements run if any statement of the body fails.
+    pub exception_handler: Option<Vec<ScriptStatement<'a>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptStatement<'a> {
+    /// `LET <variable> := <expr>`
+    Let {
+        variable: Identifier<'a>,
+        value: Expr<'a>,
+    },
+    /// `<variable> := <expr>`, the variable must be declared by `LET`.
+    Assign {
+        variable: Identifier<'a>,
+        value: Expr<'a>,
+    },
+    /// `IF <expr> THEN ... [ELSEIF <expr> THEN ...] [ELSE ...] END IF`
+    If {
+        conditions: Vec<Expr<'a>>,
+        results: Vec<Vec<ScriptStatement<'a>>>,
+        else_result: Option<Vec<ScriptStatement<'a>>>,
+    },
+    /// `LOOP ... END LOOP`
+    Loop {
+        body: Vec<ScriptStatement<'a>>,
+    },
+    /// `WHILE <expr> DO ... END WHILE`
+    While {
+        condition: Expr<'a>,
+        body: Vec<ScriptStatement<'a>>,
+    },
+    /// `FOR <variable> IN <expr> TO <expr> DO ... END FOR`, both of the bounds are inclusive.
+    For {
+        variable: Identifier<'a>,
+        lower: Expr<'a>,
+        upper: Expr<'a>,
+        body: Vec<ScriptStatement<'a>>,
+    },
+    Break,
+    Continue,
+    /// `RETURN [<expr>]`, the value is the result of the script.
+    Return {
+        value: Option<Expr<'a>>,
+    },
+    /// `RAISE [<expr>]`, raises an error of the message, or re-raises the error being handled
+    /// in the exception handler.
+    Raise {
+        message: Option<Expr<'a>>,
+    },
+    Block(ScriptBlock<'a>),
+    /// The SQL statement, which is planned and executed by the interpreters when it runs, so
+    /// the variables of the script are resolved by then.
+    Sql(String),
+}
+
+fn write_statements(f: &mut Formatter<'_>, stmts: &[ScriptStatement<'_>]) -> std::fmt::Result {
+    for stmt in stmts {
+        write!(f, " {stmt};")?;
+    }
+    Ok(())
+}
+
+impl Display for ScriptBlock<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BEGIN")?;
+        write_statements(f, &self.body)?;
+        if let Some(exception_handler) = &self.exception_handler {
+            write!(f, " EXCEPTION WHEN OTHER THEN")?;
+            write_statements(f, exception_handler)?;
+        }
+        write!(f, " END")
+    }
+}
+
+impl Display for ScriptStatement<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptStatement::Let { variable, value } => write!(f, "LET {variable} := {value}"),
+            ScriptStatement::Assign { variable, value } => write!(f, "{variable} := {value}"),
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result,
+            } => {
+                for (i, (condition, result)) in conditions.iter().zip(results).enumerate() {
+                    match i {
+                        0 => write!(f, "IF {condition} THEN")?,
+                        _ => write!(f, " ELSEIF {condition} THEN")?,
+                    }
+                    write_statements(f, result)?;
+                }
+                if let Some(else_result) = else_result {
+                    write!(f, " ELSE")?;
+                    write_statements(f, else_result)?;
+                }
+                write!(f, " END IF")
+            }
+            ScriptStatement::Loop { body } => {
+                write!(f, "LOOP")?;
+                write_statements(f, body)?;
+                write!(f, " END LOOP")
+            }
+            ScriptStatement::While { condition, body } => {
+                write!(f, "WHILE {condition} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END WHILE")
+            }
+            ScriptStatement::For {
+                variable,
+                lower,
+                upper,
+                body,
+            } => {
+                write!(f, "FOR {variable} IN {lower} TO {upper} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END FOR")
+            }
+            ScriptStatement::Break => write!(f, "BREAK"),
+            ScriptStatement::Continue => write!(f, "CONTINUE"),
+            ScriptStatement::Return { value } => {
+                write!(f, "RETURN")?;
+                if let Some(value) = value {
+                    write!(f, " {value}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Raise { message } => {
+                write!(f, "RAISE")?;
+                if let Some(message) = message {
+                    write!(f, " {message}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::Block(block) => write!(f, "{block}"),
+            ScriptStatement::Sql(sql) => write!(f, "{sql}"),
+        }
+    }
+}
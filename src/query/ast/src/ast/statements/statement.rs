@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -20,11 +21,13 @@ use common_meta_types::UserIdentity;
 
 use super::*;
 use crate::ast::write_comma_separated_list;
+use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Literal;
 use crate::ast::Query;
 use crate::ast::TableReference;
+use crate::ast::TypeName;
 
 // SQL statement
 #[allow(clippy::large_enum_variant)]
@@ -54,13 +57,31 @@ pub enum Statement<'a> {
         object_id: String,
     },
 
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
+
     SetVariable {
         is_global: bool,
         variable: Identifier<'a>,
         value: Literal,
     },
+    SetUserVariable {
+        variable: Identifier<'a>,
+        value: Box<Expr<'a>>,
+    },
+    UnsetUserVariable {
+        variable: Identifier<'a>,
+    },
+
+    // Scripting
+    ExecuteImmediate {
+        script: String,
+    },
 
     Insert(InsertStmt<'a>),
+    Replace(ReplaceStmt<'a>),
 
     Delete {
         table_reference: TableReference<'a>,
@@ -73,6 +94,8 @@ pub enum Statement<'a> {
     DropDatabase(DropDatabaseStmt<'a>),
     UndropDatabase(UndropDatabaseStmt<'a>),
     AlterDatabase(AlterDatabaseStmt<'a>),
+    BackupDatabase(BackupDatabaseStmt<'a>),
+    RestoreDatabase(RestoreDatabaseStmt<'a>),
     UseDatabase {
         database: Identifier<'a>,
     },
@@ -82,13 +105,17 @@ pub enum Statement<'a> {
     ShowCreateTable(ShowCreateTableStmt<'a>),
     DescribeTable(DescribeTableStmt<'a>),
     ShowTablesStatus(ShowTablesStatusStmt<'a>),
+    ShowDropTables(ShowDropTablesStmt<'a>),
     CreateTable(CreateTableStmt<'a>),
     DropTable(DropTableStmt<'a>),
     UndropTable(UndropTableStmt<'a>),
+    AttachTable(AttachTableStmt<'a>),
+    DetachTable(DetachTableStmt<'a>),
     AlterTable(AlterTableStmt<'a>),
     RenameTable(RenameTableStmt<'a>),
     TruncateTable(TruncateTableStmt<'a>),
     OptimizeTable(OptimizeTableStmt<'a>),
+    AnalyzeTable(AnalyzeTableStmt<'a>),
     ExistsTable(ExistsTableStmt<'a>),
 
     // Views
@@ -96,6 +123,14 @@ pub enum Statement<'a> {
     AlterView(AlterViewStmt<'a>),
     DropView(DropViewStmt<'a>),
 
+    // Streams
+    CreateStream(CreateStreamStmt<'a>),
+    DropStream(DropStreamStmt<'a>),
+
+    // Aggregating indexes
+    CreateAggregatingIndex(CreateAggregatingIndexStmt<'a>),
+    DropAggregatingIndex(DropAggregatingIndexStmt<'a>),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -113,6 +148,9 @@ pub enum Statement<'a> {
         if_exists: bool,
         role_name: String,
     },
+    SetRole {
+        role_name: String,
+    },
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -138,6 +176,126 @@ pub enum Statement<'a> {
         description: Option<String>,
     },
 
+    // Row access policies
+    CreateRowAccessPolicy {
+        if_not_exists: bool,
+        policy_name: Identifier<'a>,
+        parameters: Vec<Identifier<'a>>,
+        definition: Box<Expr<'a>>,
+    },
+    DropRowAccessPolicy {
+        if_exists: bool,
+        policy_name: Identifier<'a>,
+    },
+
+    // Masking policies
+    CreateMaskingPolicy {
+        if_not_exists: bool,
+        policy_name: Identifier<'a>,
+        parameter: Identifier<'a>,
+        return_type: TypeName,
+        definition: Box<Expr<'a>>,
+    },
+    DropMaskingPolicy {
+        if_exists: bool,
+        policy_name: Identifier<'a>,
+    },
+
+    // Network policies
+    CreateNetworkPolicy {
+        if_not_exists: bool,
+        policy_name: Identifier<'a>,
+        allowed_ip_list: Vec<String>,
+        blocked_ip_list: Vec<String>,
+        comment: Option<String>,
+    },
+    DropNetworkPolicy {
+        if_exists: bool,
+        policy_name: Identifier<'a>,
+    },
+
+    // Tasks
+    CreateTask(CreateTaskStmt<'a>),
+    DropTask {
+        if_exists: bool,
+        task_name: Identifier<'a>,
+    },
+    ShowTasks,
+    AlterTask {
+        task_name: Identifier<'a>,
+        action: AlterTaskAction,
+    },
+
+    // Resource groups
+    CreateResourceGroup {
+        if_not_exists: bool,
+        group_name: Identifier<'a>,
+        cpu_share: Option<u64>,
+        max_memory_usage: Option<u64>,
+        max_concurrency: Option<u64>,
+        comment: Option<String>,
+    },
+    DropResourceGroup {
+        if_exists: bool,
+        group_name: Identifier<'a>,
+    },
+
+    // Connections
+    CreateConnection {
+        if_not_exists: bool,
+        connection_name: Identifier<'a>,
+        storage_type: String,
+        storage_params: BTreeMap<String, String>,
+    },
+    DropConnection {
+        if_exists: bool,
+        connection_name: Identifier<'a>,
+    },
+    ShowConnections,
+    DescribeConnection {
+        connection_name: Identifier<'a>,
+    },
+
+    // Sequences
+    CreateSequence {
+        if_not_exists: bool,
+        sequence_name: Identifier<'a>,
+        start: Option<i64>,
+        increment: Option<i64>,
+        comment: Option<String>,
+    },
+    DropSequence {
+        if_exists: bool,
+        sequence_name: Identifier<'a>,
+    },
+    ShowSequences,
+
+    // Plan hints
+    CreatePlanHint {
+        if_not_exists: bool,
+        hint_name: Identifier<'a>,
+        statement: String,
+        hints: String,
+    },
+    DropPlanHint {
+        if_exists: bool,
+        hint_name: Identifier<'a>,
+    },
+    ShowPlanHints,
+
+    // Cluster
+    DrainNode {
+        node_id: String,
+    },
+
+    // Dictionaries
+    CreateDictionary(CreateDictionaryStmt<'a>),
+    DropDictionary {
+        if_exists: bool,
+        dictionary_name: Identifier<'a>,
+    },
+    ShowDictionaries,
+
     // Stages
     CreateStage(CreateStageStmt),
     ShowStages,
@@ -181,20 +339,22 @@ impl<'a> Display for Statement<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Statement::Explain { kind, query } => {
-                write!(f, "EXPLAIN")?;
                 match *kind {
-                    ExplainKind::Ast(_) => write!(f, " AST")?,
-                    ExplainKind::Syntax(_) => write!(f, " SYNTAX")?,
-                    ExplainKind::Graph => write!(f, " GRAPH")?,
-                    ExplainKind::Pipeline => write!(f, " PIPELINE")?,
-                    ExplainKind::Fragments => write!(f, " FRAGMENTS")?,
-                    ExplainKind::Raw => write!(f, " RAW")?,
-                    ExplainKind::Plan => (),
+                    ExplainKind::Ast(_) => write!(f, "EXPLAIN AST")?,
+                    ExplainKind::Syntax(_) => write!(f, "EXPLAIN SYNTAX")?,
+                    ExplainKind::Graph => write!(f, "EXPLAIN GRAPH")?,
+                    ExplainKind::Pipeline => write!(f, "EXPLAIN PIPELINE")?,
+                    ExplainKind::Fragments => write!(f, "EXPLAIN FRAGMENTS")?,
+                    ExplainKind::Raw => write!(f, "EXPLAIN RAW")?,
+                    ExplainKind::Plan => write!(f, "EXPLAIN")?,
+                    ExplainKind::Analyze => write!(f, "EXPLAIN ANALYZE")?,
+                    ExplainKind::Validate => write!(f, "VALIDATE")?,
                 }
                 write!(f, " {query}")?;
             }
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
+            Statement::Replace(replace) => write!(f, "{replace}")?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -202,7 +362,7 @@ impl<'a> Display for Statement<'a> {
             } => {
                 write!(f, "DELETE FROM {table_reference}")?;
                 if let Some(conditions) = selection {
-                    write!(f, "WHERE {conditions} ")?;
+                    write!(f, " WHERE {conditions}")?;
                 }
             }
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
@@ -213,6 +373,9 @@ impl<'a> Display for Statement<'a> {
                 }
             }
             Statement::ShowProcessList => write!(f, "SHOW PROCESSLIST")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Rollback => write!(f, "ROLLBACK")?,
             Statement::ShowMetrics => write!(f, "SHOW METRICS")?,
             Statement::ShowEngines => write!(f, "SHOW ENGINES")?,
             Statement::ShowFunctions { limit } => {
@@ -243,28 +406,47 @@ impl<'a> Display for Statement<'a> {
                 }
                 write!(f, "{variable} = {value}")?;
             }
+            Statement::SetUserVariable { variable, value } => {
+                write!(f, "SET VARIABLE {variable} = {value}")?;
+            }
+            Statement::UnsetUserVariable { variable } => {
+                write!(f, "UNSET VARIABLE {variable}")?;
+            }
+            Statement::ExecuteImmediate { script } => {
+                write!(f, "EXECUTE IMMEDIATE $${script}$$")?;
+            }
             Statement::ShowDatabases(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::BackupDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateTable(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowDropTables(stmt) => write!(f, "{stmt}")?,
             Statement::CreateTable(stmt) => write!(f, "{stmt}")?,
             Statement::DropTable(stmt) => write!(f, "{stmt}")?,
             Statement::UndropTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AttachTable(stmt) => write!(f, "{stmt}")?,
+            Statement::DetachTable(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTable(stmt) => write!(f, "{stmt}")?,
             Statement::RenameTable(stmt) => write!(f, "{stmt}")?,
             Statement::TruncateTable(stmt) => write!(f, "{stmt}")?,
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateAggregatingIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropAggregatingIndex(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
@@ -296,6 +478,7 @@ impl<'a> Display for Statement<'a> {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::SetRole { role_name } => write!(f, "SET ROLE '{role_name}'")?,
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants { principal } => {
                 write!(f, "SHOW GRANTS")?;
@@ -346,6 +529,242 @@ impl<'a> Display for Statement<'a> {
                     write!(f, " DESC = '{description}'")?;
                 }
             }
+            Statement::CreateRowAccessPolicy {
+                if_not_exists,
+                policy_name,
+                parameters,
+                definition,
+            } => {
+                write!(f, "CREATE ROW ACCESS POLICY")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {policy_name} AS (")?;
+                write_comma_separated_list(f, parameters)?;
+                write!(f, ") RETURNS BOOLEAN -> {definition}")?;
+            }
+            Statement::DropRowAccessPolicy {
+                if_exists,
+                policy_name,
+            } => {
+                write!(f, "DROP ROW ACCESS POLICY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {policy_name}")?;
+            }
+            Statement::CreateMaskingPolicy {
+                if_not_exists,
+                policy_name,
+                parameter,
+                return_type,
+                definition,
+            } => {
+                write!(f, "CREATE MASKING POLICY")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(
+                    f,
+                    " {policy_name} AS ({parameter}) RETURNS {return_type} -> {definition}"
+                )?;
+            }
+            Statement::DropMaskingPolicy {
+                if_exists,
+                policy_name,
+            } => {
+                write!(f, "DROP MASKING POLICY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {policy_name}")?;
+            }
+            Statement::CreateNetworkPolicy {
+                if_not_exists,
+                policy_name,
+                allowed_ip_list,
+                blocked_ip_list,
+                comment,
+            } => {
+                write!(f, "CREATE NETWORK POLICY")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {policy_name} ALLOWED_IP_LIST = (")?;
+                write_quoted_comma_separated_list(f, allowed_ip_list)?;
+                write!(f, ")")?;
+                if !blocked_ip_list.is_empty() {
+                    write!(f, " BLOCKED_IP_LIST = (")?;
+                    write_quoted_comma_separated_list(f, blocked_ip_list)?;
+                    write!(f, ")")?;
+                }
+                if let Some(comment) = comment {
+                    write!(f, " COMMENT = '{comment}'")?;
+                }
+            }
+            Statement::DropNetworkPolicy {
+                if_exists,
+                policy_name,
+            } => {
+                write!(f, "DROP NETWORK POLICY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {policy_name}")?;
+            }
+            Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTask {
+                if_exists,
+                task_name,
+            } => {
+                write!(f, "DROP TASK")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {task_name}")?;
+            }
+            Statement::ShowTasks => write!(f, "SHOW TASKS")?,
+            Statement::AlterTask { task_name, action } => {
+                write!(f, "ALTER TASK {task_name} {action}")?;
+            }
+            Statement::CreateResourceGroup {
+                if_not_exists,
+                group_name,
+                cpu_share,
+                max_memory_usage,
+                max_concurrency,
+                comment,
+            } => {
+                write!(f, "CREATE RESOURCE GROUP")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {group_name}")?;
+                if let Some(cpu_share) = cpu_share {
+                    write!(f, " CPU_SHARE = {cpu_share}")?;
+                }
+                if let Some(max_memory_usage) = max_memory_usage {
+                    write!(f, " MAX_MEMORY_USAGE = {max_memory_usage}")?;
+                }
+                if let Some(max_concurrency) = max_concurrency {
+                    write!(f, " MAX_CONCURRENCY = {max_concurrency}")?;
+                }
+                if let Some(comment) = comment {
+                    write!(f, " COMMENT = '{comment}'")?;
+                }
+            }
+            Statement::DropResourceGroup {
+                if_exists,
+                group_name,
+            } => {
+                write!(f, "DROP RESOURCE GROUP")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {group_name}")?;
+            }
+            Statement::CreateConnection {
+                if_not_exists,
+                connection_name,
+                storage_type,
+                storage_params,
+            } => {
+                write!(f, "CREATE CONNECTION")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {connection_name} STORAGE_TYPE = '{storage_type}'")?;
+                for (k, v) in storage_params {
+                    write!(f, " {k} = '{v}'")?;
+                }
+            }
+            Statement::DropConnection {
+                if_exists,
+                connection_name,
+            } => {
+                write!(f, "DROP CONNECTION")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {connection_name}")?;
+            }
+            Statement::ShowConnections => write!(f, "SHOW CONNECTIONS")?,
+            Statement::DescribeConnection { connection_name } => {
+                write!(f, "DESC CONNECTION {connection_name}")?
+            }
+            Statement::CreateSequence {
+                if_not_exists,
+                sequence_name,
+                start,
+                increment,
+                comment,
+            } => {
+                write!(f, "CREATE SEQUENCE")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {sequence_name}")?;
+                if let Some(start) = start {
+                    write!(f, " START WITH {start}")?;
+                }
+                if let Some(increment) = increment {
+                    write!(f, " INCREMENT BY {increment}")?;
+                }
+                if let Some(comment) = comment {
+                    write!(f, " COMMENT = '{comment}'")?;
+                }
+            }
+            Statement::DropSequence {
+                if_exists,
+                sequence_name,
+            } => {
+                write!(f, "DROP SEQUENCE")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {sequence_name}")?;
+            }
+            Statement::ShowSequences => write!(f, "SHOW SEQUENCES")?,
+            Statement::CreatePlanHint {
+                if_not_exists,
+                hint_name,
+                statement,
+                hints,
+            } => {
+                write!(f, "CREATE PLAN HINT")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(
+                    f,
+                    " {hint_name} FOR $${statement}$$ USING '{}'",
+                    hints.replace('\'', "''")
+                )?;
+            }
+            Statement::DropPlanHint {
+                if_exists,
+                hint_name,
+            } => {
+                write!(f, "DROP PLAN HINT")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {hint_name}")?;
+            }
+            Statement::ShowPlanHints => write!(f, "SHOW PLAN HINTS")?,
+            Statement::DrainNode { node_id } => write!(f, "ALTER CLUSTER DRAIN NODE '{node_id}'")?,
+            Statement::CreateDictionary(stmt) => write!(f, "{stmt}")?,
+            Statement::DropDictionary {
+                if_exists,
+                dictionary_name,
+            } => {
+                write!(f, "DROP DICTIONARY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {dictionary_name}")?;
+            }
+            Statement::ShowDictionaries => write!(f, "SHOW DICTIONARIES")?,
             Statement::ListStage { location, pattern } => {
                 write!(f, "LIST @{location}")?;
                 if !pattern.is_empty() {
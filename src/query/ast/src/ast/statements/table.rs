@@ -25,6 +25,7 @@ use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::TableReference;
 use crate::ast::TypeName;
+use crate::ast::UriLocation;
 
 #[derive(Debug, Clone, PartialEq)] // Tables
 pub struct ShowTablesStmt<'a> {
@@ -95,6 +96,26 @@ impl Display for ShowTablesStatusStmt<'_> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowDropTablesStmt<'a> {
+    pub database: Option<Identifier<'a>>,
+    pub limit: Option<ShowLimit<'a>>,
+}
+
+impl Display for ShowDropTablesStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SHOW DROP TABLES")?;
+        if let Some(database) = &self.database {
+            write!(f, " FROM {database}")?;
+        }
+        if let Some(limit) = &self.limit {
+            write!(f, " {limit}")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateTableStmt<'a> {
     pub if_not_exists: bool,
@@ -107,6 +128,9 @@ pub struct CreateTableStmt<'a> {
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query<'a>>>,
     pub transient: bool,
+    /// The temporary table is visible only to the session, and is dropped with the session.
+    pub temporary: bool,
+    pub external: Option<ExternalTableSource<'a>>,
 }
 
 impl Display for CreateTableStmt<'_> {
@@ -115,6 +139,12 @@ impl Display for CreateTableStmt<'_> {
         if self.transient {
             write!(f, "TRANSIENT ")?;
         }
+        if self.temporary {
+            write!(f, "TEMPORARY ")?;
+        }
+        if self.external.is_some() {
+            write!(f, "EXTERNAL ")?;
+        }
         write!(f, "TABLE ")?;
         if self.if_not_exists {
             write!(f, "IF NOT EXISTS ")?;
@@ -141,6 +171,10 @@ impl Display for CreateTableStmt<'_> {
             write!(f, ")")?
         }
 
+        if let Some(external) = &self.external {
+            write!(f, " {external}")?;
+        }
+
         // Format table options
         write_space_seperated_map(f, self.table_options.iter())?;
         if let Some(as_query) = &self.as_query {
@@ -151,6 +185,39 @@ impl Display for CreateTableStmt<'_> {
     }
 }
 
+/// The files of `CREATE EXTERNAL TABLE`, which are listed and read at query time.
+///
+/// For examples: `PARTITION BY (dt) LOCATION = 's3://bucket/path/' PATTERN = '.*[.]parquet' FILE_FORMAT = (type = 'parquet')`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalTableSource<'a> {
+    pub partition_by: Vec<Identifier<'a>>,
+    pub location: UriLocation,
+    pub pattern: Option<String>,
+    pub file_format: BTreeMap<String, String>,
+}
+
+impl Display for ExternalTableSource<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY (")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, ") ")?;
+        }
+        write!(f, "LOCATION = {}", self.location)?;
+        if let Some(pattern) = &self.pattern {
+            write!(f, " PATTERN = '{pattern}'")?;
+        }
+        if !self.file_format.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            for (k, v) in self.file_format.iter() {
+                write!(f, " {} = '{}'", k, v)?;
+            }
+            write!(f, " )")?;
+        }
+        Ok(())
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource<'a> {
@@ -187,11 +254,16 @@ pub struct DescribeTableStmt<'a> {
     pub catalog: Option<Identifier<'a>>,
     pub database: Option<Identifier<'a>>,
     pub table: Identifier<'a>,
+    /// `DESCRIBE EXTENDED`, with the statistics of the columns in the storage.
+    pub extended: bool,
 }
 
 impl Display for DescribeTableStmt<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DESCRIBE ")?;
+        if self.extended {
+            write!(f, "EXTENDED ")?;
+        }
         write_period_separated_list(
             f,
             self.catalog
@@ -251,6 +323,60 @@ impl Display for UndropTableStmt<'_> {
     }
 }
 
+/// Attaches a fuse table kept in the location, e.g. the location of a table of another tenant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachTableStmt<'a> {
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Option<Identifier<'a>>,
+    pub table: Identifier<'a>,
+    pub location: UriLocation,
+    pub read_only: bool,
+}
+
+impl Display for AttachTableStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ATTACH TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " {}", self.location)?;
+        if self.read_only {
+            write!(f, " READ_ONLY")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes the table from the catalog, the data of it is kept in the storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetachTableStmt<'a> {
+    pub if_exists: bool,
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Option<Identifier<'a>>,
+    pub table: Identifier<'a>,
+}
+
+impl Display for DetachTableStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DETACH TABLE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct AlterTableStmt<'a> {
     pub if_exists: bool,
@@ -274,6 +400,9 @@ pub enum AlterTableAction<'a> {
     RenameTable {
         new_table: Identifier<'a>,
     },
+    SwapWith {
+        target_table: Identifier<'a>,
+    },
     AlterTableClusterKey {
         cluster_by: Vec<Expr<'a>>,
     },
@@ -282,6 +411,35 @@ pub enum AlterTableAction<'a> {
         is_final: bool,
         selection: Option<Expr<'a>>,
     },
+    SetOptions {
+        set_options: BTreeMap<String, String>,
+    },
+    AddColumn {
+        column: ColumnDefinition<'a>,
+    },
+    AddVirtualColumn {
+        column: Identifier<'a>,
+        expr: Expr<'a>,
+    },
+    DropVirtualColumn {
+        column: Identifier<'a>,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier<'a>,
+        columns: Vec<Identifier<'a>>,
+    },
+    DropRowAccessPolicy,
+    SetMaskingPolicy {
+        column: Identifier<'a>,
+        policy: Identifier<'a>,
+    },
+    UnsetMaskingPolicy {
+        column: Identifier<'a>,
+    },
+    ModifyColumnComment {
+        column: Identifier<'a>,
+        comment: String,
+    },
 }
 
 impl Display for AlterTableAction<'_> {
@@ -290,6 +448,9 @@ impl Display for AlterTableAction<'_> {
             AlterTableAction::RenameTable { new_table } => {
                 write!(f, "RENAME TO {new_table}")
             }
+            AlterTableAction::SwapWith { target_table } => {
+                write!(f, "SWAP WITH {target_table}")
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 write!(f, "CLUSTER BY ")?;
                 write_comma_separated_list(f, cluster_by)
@@ -310,6 +471,32 @@ impl Display for AlterTableAction<'_> {
                 }
                 Ok(())
             }
+            AlterTableAction::SetOptions { set_options } => {
+                write!(f, "SET ")?;
+                write_space_seperated_map(f, set_options.iter())
+            }
+            AlterTableAction::AddColumn { column } => write!(f, "ADD COLUMN {column}"),
+            AlterTableAction::AddVirtualColumn { column, expr } => {
+                write!(f, "ADD VIRTUAL COLUMN {column} AS {expr}")
+            }
+            AlterTableAction::DropVirtualColumn { column } => {
+                write!(f, "DROP VIRTUAL COLUMN {column}")
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy => write!(f, "DROP ROW ACCESS POLICY"),
+            AlterTableAction::SetMaskingPolicy { column, policy } => {
+                write!(f, "MODIFY COLUMN {column} SET MASKING POLICY {policy}")
+            }
+            AlterTableAction::UnsetMaskingPolicy { column } => {
+                write!(f, "MODIFY COLUMN {column} UNSET MASKING POLICY")
+            }
+            AlterTableAction::ModifyColumnComment { column, comment } => {
+                write!(f, "MODIFY COLUMN {column} COMMENT '{comment}'")
+            }
         }
     }
 }
@@ -401,6 +588,26 @@ impl Display for OptimizeTableStmt<'_> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzeTableStmt<'a> {
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Option<Identifier<'a>>,
+    pub table: Identifier<'a>,
+}
+
+impl Display for AnalyzeTableStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ANALYZE TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExistsTableStmt<'a> {
     pub catalog: Option<Identifier<'a>>,
@@ -428,6 +635,9 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    External,
+    Iceberg,
+    Delta,
 }
 
 impl Display for Engine {
@@ -438,6 +648,9 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::External => write!(f, "EXTERNAL"),
+            Engine::Iceberg => write!(f, "ICEBERG"),
+            Engine::Delta => write!(f, "DELTA"),
         }
     }
 }
@@ -447,6 +660,7 @@ pub enum OptimizeTableAction {
     All,
     Purge,
     Compact,
+    Verify,
 }
 
 impl Display for OptimizeTableAction {
@@ -455,6 +669,7 @@ impl Display for OptimizeTableAction {
             OptimizeTableAction::All => write!(f, "ALL"),
             OptimizeTableAction::Purge => write!(f, "PURGE"),
             OptimizeTableAction::Compact => write!(f, "COMPACT"),
+            OptimizeTableAction::Verify => write!(f, "VERIFY"),
         }
     }
 }
@@ -463,7 +678,10 @@ impl Display for OptimizeTableAction {
 pub struct ColumnDefinition<'a> {
     pub name: Identifier<'a>,
     pub data_type: TypeName,
+    /// The collation of the strings of the column, by which they are compared and sorted.
+    pub collation: Option<String>,
     pub default_expr: Option<Box<Expr<'a>>>,
+    pub auto_increment: bool,
     pub comment: Option<String>,
 }
 
@@ -471,6 +689,10 @@ impl<'a> Display for ColumnDefinition<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
 
+        if let Some(collation) = &self.collation {
+            write!(f, " COLLATE '{collation}'")?;
+        }
+
         if !matches!(self.data_type, TypeName::Nullable(_)) {
             write!(f, " NOT NULL")?;
         }
@@ -478,6 +700,9 @@ impl<'a> Display for ColumnDefinition<'a> {
         if let Some(default_expr) = &self.default_expr {
             write!(f, " DEFAULT {default_expr}")?;
         }
+        if self.auto_increment {
+            write!(f, " AUTOINCREMENT")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
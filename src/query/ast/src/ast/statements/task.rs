@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::Identifier;
+use crate::ast::Statement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTaskStmt<'a> {
+    pub if_not_exists: bool,
+    pub task_name: Identifier<'a>,
+    pub schedule: TaskSchedule<'a>,
+    pub comment: Option<String>,
+    pub sql: Box<Statement<'a>>,
+}
+
+/// When a task runs, on a cron schedule or after its predecessor task succeeds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskSchedule<'a> {
+    Cron(String),
+    After(Identifier<'a>),
+}
+
+impl Display for CreateTaskStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE TASK")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {} {}", self.task_name, self.schedule)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        write!(f, " AS {}", self.sql)
+    }
+}
+
+impl Display for TaskSchedule<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskSchedule::Cron(schedule) => write!(f, "SCHEDULE = '{schedule}'"),
+            TaskSchedule::After(task_name) => write!(f, "AFTER {task_name}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterTaskAction {
+    Suspend,
+    Resume,
+    Execute,
+}
+
+impl Display for AlterTaskAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterTaskAction::Suspend => write!(f, "SUSPEND"),
+            AlterTaskAction::Resume => write!(f, "RESUME"),
+            AlterTaskAction::Execute => write!(f, "EXECUTE"),
+        }
+    }
+}
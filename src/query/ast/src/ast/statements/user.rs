@@ -152,12 +152,19 @@ pub enum AccountMgrLevel {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    // The tables created in the database later on.
+    FutureTables(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    SetNetworkPolicy(String),
+    UnsetNetworkPolicy,
+    SetResourceGroup(String),
+    UnsetResourceGroup,
+    DefaultQueryTag(String),
 }
 
 impl UserOptionItem {
@@ -167,6 +174,13 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
+            Self::UnsetNetworkPolicy => option.set_network_policy(None),
+            Self::SetResourceGroup(v) => option.set_resource_group(Some(v.clone())),
+            Self::UnsetResourceGroup => option.set_resource_group(None),
+            // an empty tag unsets the default query tag
+            Self::DefaultQueryTag(v) if v.is_empty() => option.set_default_query_tag(None),
+            Self::DefaultQueryTag(v) => option.set_default_query_tag(Some(v.clone())),
         }
     }
 }
@@ -195,6 +209,9 @@ impl Display for AccountMgrSource {
                             write!(f, " {table_name}")?;
                         }
                     }
+                    AccountMgrLevel::FutureTables(database_name) => {
+                        write!(f, " FUTURE TABLES IN DATABASE {database_name}")?;
+                    }
                 }
             }
             AccountMgrSource::ALL { level, .. } => {
@@ -216,6 +233,9 @@ impl Display for AccountMgrSource {
                             write!(f, " {table_name}")?;
                         }
                     }
+                    AccountMgrLevel::FutureTables(database_name) => {
+                        write!(f, " FUTURE TABLES IN DATABASE {database_name}")?;
+                    }
                 }
             }
         }
@@ -229,6 +249,11 @@ impl Display for UserOptionItem {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY = '{}'", v),
+            UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
+            UserOptionItem::SetResourceGroup(v) => write!(f, "SET RESOURCE GROUP = '{}'", v),
+            UserOptionItem::UnsetResourceGroup => write!(f, "UNSET RESOURCE GROUP"),
+            UserOptionItem::DefaultQueryTag(v) => write!(f, "DEFAULT_QUERY_TAG = '{}'", v),
         }
     }
 }
@@ -193,6 +193,10 @@ pub enum ExprElement<'a> {
     PgCast {
         target_type: TypeName,
     },
+    /// `COLLATE '<collation>'` expression
+    Collate {
+        collation: String,
+    },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
         field: IntervalKind,
@@ -339,6 +343,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement<'a>>>> PrattParser<I> for E
                 BinaryOperator::StringConcat => Affix::Infix(Precedence(40), Associativity::Left),
             },
             ExprElement::PgCast { .. } => Affix::Postfix(Precedence(50)),
+            ExprElement::Collate { .. } => Affix::Postfix(Precedence(50)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -559,6 +564,11 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement<'a>>>> PrattParser<I> for E
                 target_type,
                 pg_style: true,
             },
+            ExprElement::Collate { collation } => Expr::Collate {
+                span: elem.span.0,
+                expr: Box::new(lhs),
+                collation,
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -639,6 +649,12 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
         |(_, target_type)| ExprElement::PgCast { target_type },
     );
+    let collate = map(
+        rule! {
+            COLLATE ~ ^#literal_string
+        },
+        |(_, collation)| ExprElement::Collate { collation },
+    );
     let extract = map(
         rule! {
             EXTRACT ~ ^"(" ~ ^#interval_kind ~ ^FROM ~ ^#subexpr(0) ~ ^")"
@@ -753,6 +769,22 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             params,
         },
     );
+    // `$x` is the shorthand of `getvariable('x')`.
+    let variable = map(consumed(rule! { Variable }), |(span, token)| {
+        ExprElement::FunctionCall {
+            distinct: false,
+            name: Identifier {
+                name: "getvariable".to_string(),
+                quote: None,
+                span: token.clone(),
+            },
+            args: vec![Expr::Literal {
+                span: span.0,
+                lit: Literal::String(token.text()[1..].to_string()),
+            }],
+            params: vec![],
+        }
+    });
     let case = map(
         rule! {
             CASE ~ #subexpr(0)?
@@ -882,12 +914,14 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #function_call_with_param : "<function>"
             | #function_call : "<function>"
             | #literal : "<literal>"
+            | #variable : "`$<variable>`"
             | #case : "`CASE ... END`"
             | #subquery : "`(SELECT ...)`"
             | #tuple : "`(<expr> [, ...])`"
             | #column_ref : "<column>"
             | #map_access : "[<key>] | .<key> | :<key>"
             | #array : "`[...]`"
+            | #collate : "`COLLATE '<collation>'`"
         ),
     )))(i)?;
 
@@ -1040,6 +1074,17 @@ pub fn literal_string(i: Input) -> IResult<String> {
     )(i)
 }
 
+/// The string of code, e.g. the scripts, quoted by `$$` or as the string literal.
+pub fn code_string(i: Input) -> IResult<String> {
+    let dollar_quoted = map(rule! { LiteralCodeString }, |token| {
+        token.text()[2..token.text().len() - 2].to_string()
+    });
+    rule!(
+        #dollar_quoted
+        | #literal_string
+    )(i)
+}
+
 pub fn literal_string_eq_ignore_case(s: &str) -> impl FnMut(Input) -> IResult<()> + '_ {
     move |i| {
         map_res(rule! { QuotedString }, |token| {
@@ -14,6 +14,7 @@
 
 pub mod expr;
 pub mod query;
+pub mod script;
 pub mod statement;
 pub mod token;
 pub mod unescape;
@@ -23,13 +24,16 @@ use common_exception::Result;
 
 use self::expr::subexpr;
 use crate::ast::Expr;
+use crate::ast::ScriptBlock;
 use crate::ast::Statement;
 use crate::input::Dialect;
 use crate::input::Input;
+use crate::parser::script::script_block;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
 use crate::parser::token::Tokenizer;
+use crate::rule;
 use crate::util::comma_separated_list0;
 use crate::Backtrace;
 use crate::DisplayError;
@@ -38,6 +42,30 @@ pub fn tokenize_sql(sql: &str) -> Result<Vec<Token>> {
     Tokenizer::new(sql).collect::<Result<Vec<_>>>()
 }
 
+/// Split a SQL string of semicolon-separated statements into the statements, the empty ones
+/// are skipped. The semicolons in the strings, the quoted identifiers and the comments are not
+/// separators.
+pub fn split_sql(sql: &str) -> Result<Vec<&str>> {
+    let mut statements = vec![];
+    let mut start = None;
+    let mut end = 0;
+    for token in Tokenizer::new(sql) {
+        let token = token?;
+        match token.kind {
+            TokenKind::SemiColon | TokenKind::EOI => {
+                if let Some(start) = start.take() {
+                    statements.push(&sql[start..end]);
+                }
+            }
+            _ => {
+                start.get_or_insert(token.span.start);
+                end = token.span.end;
+            }
+        }
+    }
+    Ok(statements)
+}
+
 /// Parse a SQL string into `Statement`s.
 pub fn parse_sql<'a>(
     sql_tokens: &'a [Token<'a>],
@@ -56,6 +84,25 @@ pub fn parse_sql<'a>(
     }
 }
 
+/// Parse the script of `EXECUTE IMMEDIATE` into `ScriptBlock`.
+pub fn parse_script<'a>(
+    script_tokens: &'a [Token<'a>],
+    dialect: Dialect,
+    backtrace: &'a Backtrace<'a>,
+) -> Result<ScriptBlock<'a>> {
+    let mut parser = rule! { #script_block ~ ";"? };
+    match parser(Input(script_tokens, dialect, backtrace)) {
+        Ok((rest, (block, _))) if rest[0].kind == TokenKind::EOI => Ok(block),
+        Ok((rest, _)) => Err(ErrorCode::SyntaxException(
+            rest[0].display_error("unable to parse rest of the script".to_string()),
+        )),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(ErrorCode::SyntaxException(err.display_error(())))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!(),
+    }
+}
+
 /// Parse udf function into Expr
 pub fn parse_expr<'a>(
     sql_tokens: &'a [Token<'a>],
@@ -425,6 +425,7 @@ pub fn set_operation(i: Input) -> IResult<SetExpr> {
 pub enum SetOperationElement<'a> {
     SelectStmt {
         distinct: bool,
+        hints: Vec<SelectHint<'a>>,
         select_list: Box<Vec<SelectTarget<'a>>>,
         from: Box<Vec<TableReference<'a>>>,
         selection: Box<Option<Expr<'a>>>,
@@ -458,7 +459,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     );
     let select_stmt = map(
         rule! {
-             SELECT ~ DISTINCT? ~ ^#comma_separated_list1(select_target)
+             SELECT ~ #select_hints? ~ DISTINCT? ~ ^#comma_separated_list1(select_target)
                 ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
                 ~ ( WHERE ~ ^#expr )?
                 ~ ( GROUP ~ ^BY ~ ^#comma_separated_list1(expr) )?
@@ -466,6 +467,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
         },
         |(
             _select,
+            opt_hints,
             opt_distinct,
             select_list,
             opt_from_block,
@@ -475,6 +477,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
         )| {
             SetOperationElement::SelectStmt {
                 distinct: opt_distinct.is_some(),
+                hints: opt_hints.unwrap_or_default(),
                 select_list: Box::new(select_list),
                 from: Box::new(
                     opt_from_block
@@ -502,6 +505,86 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     Ok((rest, WithSpan { span, elem }))
 }
 
+/// Parses the hints in the comment after `SELECT`, like `/*+ BROADCAST(t1, t2) SET_VAR(max_threads = 4) */`,
+/// the unknown hints are ignored as the comments.
+pub fn select_hints<'a>(i: Input<'a>) -> IResult<'a, Vec<SelectHint<'a>>> {
+    let (rest, token) = match_token(Hint)(i)?;
+    let text = token.text();
+    let tokens = match Tokenizer::new(&text[3..text.len() - 2])
+        .collect::<common_exception::Result<Vec<_>>>()
+    {
+        Ok(tokens) => tokens,
+        Err(_) => return Ok((rest, vec![])),
+    };
+
+    let hint_ident = |token: &Token<'a>| match token.kind {
+        Ident => Some(Identifier {
+            span: token.clone(),
+            name: token.text().to_string(),
+            quote: None,
+        }),
+        QuotedString => {
+            let quote = token
+                .text()
+                .chars()
+                .next()
+                .filter(|c| i.1.is_ident_quote(*c))?;
+            Some(Identifier {
+                span: token.clone(),
+                name: token.text()[1..token.text().len() - 1].to_string(),
+                quote: Some(quote),
+            })
+        }
+        kind if kind.is_keyword() => Some(Identifier {
+            span: token.clone(),
+            name: token.text().to_string(),
+            quote: None,
+        }),
+        _ => None,
+    };
+
+    let mut hints = vec![];
+    let mut others = tokens.as_slice();
+    while let Some(end) = others.iter().position(|token| token.kind == RParen) {
+        let (hint, rest) = others.split_at(end + 1);
+        others = rest;
+        match hint {
+            [name, lparen, args @ .., _]
+                if lparen.kind == LParen && name.text().eq_ignore_ascii_case("BROADCAST") =>
+            {
+                let tables = args
+                    .iter()
+                    .filter(|token| token.kind != Comma)
+                    .filter_map(hint_ident)
+                    .collect();
+                hints.push(SelectHint::Broadcast(tables));
+            }
+            [name, lparen, millis, _]
+                if lparen.kind == LParen
+                    && millis.kind == LiteralInteger
+                    && name.text().eq_ignore_ascii_case("MAX_EXECUTION_TIME") =>
+            {
+                if let Ok(millis) = millis.text().parse() {
+                    hints.push(SelectHint::MaxExecutionTime(millis));
+                }
+            }
+            [name, lparen, variable, eq, value, _]
+                if lparen.kind == LParen
+                    && eq.kind == Eq
+                    && name.text().eq_ignore_ascii_case("SET_VAR") =>
+            {
+                let value = literal(Input(std::slice::from_ref(value), i.1, i.2));
+                if let (Some(variable), Ok((_, value))) = (hint_ident(variable), value) {
+                    hints.push(SelectHint::SetVar { variable, value });
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok((rest, hints))
+}
+
 struct SetOperationParser;
 
 impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement<'a>>>> PrattParser<I>
@@ -529,6 +612,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement<'a>>>> PrattParser<
             SetOperationElement::Group(expr) => expr,
             SetOperationElement::SelectStmt {
                 distinct,
+                hints,
                 select_list,
                 from,
                 selection,
@@ -537,6 +621,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement<'a>>>> PrattParser<
             } => SetExpr::Select(Box::new(SelectStmt {
                 span: input.span.0,
                 distinct,
+                hints,
                 select_list: *select_list,
                 from: *from,
                 selection: *selection,
@@ -0,0 +1,155 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::combinator::map;
+use nom::combinator::value;
+use nom::multi::many0;
+use nom::Slice;
+
+use crate::ast::*;
+use crate::input::Input;
+use crate::parser::expr::*;
+use crate::parser::token::*;
+use crate::rule;
+use crate::util::*;
+use crate::Error;
+use crate::ErrorKind;
+
+pub fn script_block(i: Input) -> IResult<ScriptBlock> {
+    map(
+        rule! {
+            BEGIN ~ #script_statements
+            ~ ( EXCEPTION ~ ^WHEN ~ ^OTHER ~ ^THEN ~ #script_statements )?
+            ~ END
+        },
+        |(_, body, opt_exception_handler, _)| ScriptBlock {
+            body,
+            exception_handler: opt_exception_handler.map(|(_, _, _, _, stmts)| stmts),
+        },
+    )(i)
+}
+
+pub fn script_statements(i: Input) -> IResult<Vec<ScriptStatement>> {
+    many0(map(rule! { #script_statement ~ ";" }, |(stmt, _)| stmt))(i)
+}
+
+pub fn script_statement(i: Input) -> IResult<ScriptStatement> {
+    let let_stmt = map(
+        rule! {
+            LET ~ #ident ~ ":=" ~ #expr
+        },
+        |(_, variable, _, value)| ScriptStatement::Let { variable, value },
+    );
+    let assign = map(
+        rule! {
+            #ident ~ ":=" ~ #expr
+        },
+        |(variable, _, value)| ScriptStatement::Assign { variable, value },
+    );
+    let if_stmt = map(
+        rule! {
+            IF ~ #expr ~ THEN ~ #script_statements
+            ~ ( ELSEIF ~ #expr ~ THEN ~ #script_statements )*
+            ~ ( ELSE ~ #script_statements )?
+            ~ END ~ IF
+        },
+        |(_, condition, _, result, elseifs, opt_else_result, _, _)| {
+            let mut conditions = vec![condition];
+            let mut results = vec![result];
+            for (_, condition, _, result) in elseifs {
+                conditions.push(condition);
+                results.push(result);
+            }
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result: opt_else_result.map(|(_, stmts)| stmts),
+            }
+        },
+    );
+    let loop_stmt = map(
+        rule! {
+            LOOP ~ #script_statements ~ END ~ LOOP
+        },
+        |(_, body, _, _)| ScriptStatement::Loop { body },
+    );
+    let while_stmt = map(
+        rule! {
+            WHILE ~ #expr ~ DO ~ #script_statements ~ END ~ WHILE
+        },
+        |(_, condition, _, body, _, _)| ScriptStatement::While { condition, body },
+    );
+    let for_stmt = map(
+        rule! {
+            FOR ~ #ident ~ IN ~ #expr ~ TO ~ #expr ~ DO ~ #script_statements ~ END ~ FOR
+        },
+        |(_, variable, _, lower, _, upper, _, body, _, _)| ScriptStatement::For {
+            variable,
+            lower,
+            upper,
+            body,
+        },
+    );
+    let break_stmt = value(ScriptStatement::Break, rule! { BREAK });
+    let continue_stmt = value(ScriptStatement::Continue, rule! { CONTINUE });
+    let return_stmt = map(
+        rule! {
+            RETURN ~ #expr?
+        },
+        |(_, value)| ScriptStatement::Return { value },
+    );
+    let raise = map(
+        rule! {
+            RAISE ~ #expr?
+        },
+        |(_, message)| ScriptStatement::Raise { message },
+    );
+
+    rule!(
+        #let_stmt : "`LET <variable> := <expr>`"
+        | #assign : "`<variable> := <expr>`"
+        | #if_stmt : "`IF <expr> THEN <statement>; ... [ELSEIF <expr> THEN <statement>; ...] [ELSE <statement>; ...] END IF`"
+        | #loop_stmt : "`LOOP <statement>; ... END LOOP`"
+        | #while_stmt : "`WHILE <expr> DO <statement>; ... END WHILE`"
+        | #for_stmt : "`FOR <variable> IN <expr> TO <expr> DO <statement>; ... END FOR`"
+        | #break_stmt : "`BREAK`"
+        | #continue_stmt : "`CONTINUE`"
+        | #return_stmt : "`RETURN [<expr>]`"
+        | #raise : "`RAISE [<expr>]`"
+        | #map(script_block, ScriptStatement::Block) : "`BEGIN <statement>; ... [EXCEPTION WHEN OTHER THEN <statement>; ...] END`"
+        | #map(script_sql, ScriptStatement::Sql) : "<sql statement>"
+    )(i)
+}
+
+// The SQL statement till the semicolon, which is kept as the text and parsed when it runs. The
+// keywords ending the blocks are not the start of the statements.
+fn script_sql(i: Input) -> IResult<String> {
+    let len =
+        i.0.iter()
+            .take_while(|token| !matches!(token.kind, SemiColon | EOI))
+            .count();
+    match i.0.first().map(|token| token.kind) {
+        Some(END | ELSE | ELSEIF | EXCEPTION | SemiColon | EOI) | None => Err(nom::Err::Error(
+            Error::from_error_kind(i, ErrorKind::Other("expected a SQL statement")),
+        )),
+        Some(_) => {
+            let first_token = &i.0[0];
+            let last_token = &i.0[len - 1];
+            Ok((
+                i.slice(len..),
+                first_token.source[first_token.span.start..last_token.span.end].to_string(),
+            ))
+        }
+    }
+}
@@ -79,6 +79,24 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let explain_analyze = map(
+        rule! {
+            EXPLAIN ~ ANALYZE ~ #statement
+        },
+        |(_, _, statement)| Statement::Explain {
+            kind: ExplainKind::Analyze,
+            query: Box::new(statement.stmt),
+        },
+    );
+    let validate = map(
+        rule! {
+            VALIDATE ~ #statement
+        },
+        |(_, statement)| Statement::Explain {
+            kind: ExplainKind::Validate,
+            query: Box::new(statement.stmt),
+        },
+    );
     let insert = map(
         rule! {
             INSERT ~ ( INTO | OVERWRITE ) ~ TABLE?
@@ -100,6 +118,39 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let replace = map(
+        rule! {
+            REPLACE ~ INTO ~ TABLE?
+            ~ #peroid_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ #insert_source
+        },
+        |(
+            _,
+            _,
+            _,
+            (catalog, database, table),
+            opt_columns,
+            _,
+            _,
+            on_conflict_columns,
+            _,
+            source,
+        )| {
+            Statement::Replace(ReplaceStmt {
+                catalog,
+                database,
+                table,
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                on_conflict_columns,
+                source,
+            })
+        },
+    );
+
     let delete = map(
         rule! {
             DELETE ~ FROM ~ #table_reference_only
@@ -130,6 +181,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         |(_, _, limit)| Statement::ShowFunctions { limit },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ (TRANSACTION | WORK)? });
+    let start_transaction = value(Statement::Begin, rule! { START ~ TRANSACTION });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let rollback = value(Statement::Rollback, rule! { ROLLBACK ~ WORK? });
+
     // kill query 199;
     let kill_stmt = map(
         rule! {
@@ -151,6 +207,27 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             value,
         },
     );
+    let set_user_variable = map(
+        rule! {
+            SET ~ VARIABLE ~ #user_variable ~ "=" ~ #expr
+        },
+        |(_, _, variable, _, value)| Statement::SetUserVariable {
+            variable,
+            value: Box::new(value),
+        },
+    );
+    let unset_user_variable = map(
+        rule! {
+            UNSET ~ VARIABLE ~ #user_variable
+        },
+        |(_, _, variable)| Statement::UnsetUserVariable { variable },
+    );
+    let execute_immediate = map(
+        rule! {
+            EXECUTE ~ IMMEDIATE ~ #code_string
+        },
+        |(_, _, script)| Statement::ExecuteImmediate { script },
+    );
     let show_databases = map(
         rule! {
             SHOW ~ ( DATABASES | SCHEMAS ) ~ #show_limit?
@@ -167,9 +244,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_database = map(
         rule! {
-            CREATE ~ ( DATABASE | SCHEMA ) ~ ( IF ~ NOT ~ EXISTS )? ~ #peroid_separated_idents_1_to_2 ~ #create_database_option?
+            CREATE ~ ( DATABASE | SCHEMA ) ~ ( IF ~ NOT ~ EXISTS )? ~ #peroid_separated_idents_1_to_2 ~ #create_database_option? ~ ( COMMENT ~ "=" ~ #literal_string )?
         },
-        |(_, _, opt_if_not_exists, (catalog, database), create_database_option)| {
+        |(_, _, opt_if_not_exists, (catalog, database), create_database_option, opt_comment)| {
+            let comment = opt_comment.map(|(_, _, comment)| comment);
             match create_database_option {
                 Some(CreateDatabaseOption::DatabaseEngine(engine)) => {
                     Statement::CreateDatabase(CreateDatabaseStmt {
@@ -179,6 +257,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                         engine: Some(engine),
                         options: vec![],
                         from_share: None,
+                        comment,
                     })
                 }
                 Some(CreateDatabaseOption::FromShare(share_name)) => {
@@ -189,6 +268,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                         engine: None,
                         options: vec![],
                         from_share: Some(share_name),
+                        comment,
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -198,6 +278,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     engine: None,
                     options: vec![],
                     from_share: None,
+                    comment,
                 }),
             }
         },
@@ -224,6 +305,31 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let backup_database = map(
+        rule! {
+            BACKUP ~ DATABASE ~ #peroid_separated_idents_1_to_2 ~ TO ~ #uri_location
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::BackupDatabase(BackupDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+    let restore_database = map(
+        rule! {
+            RESTORE ~ DATABASE ~ #peroid_separated_idents_1_to_2 ~ FROM ~ #uri_location
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::RestoreDatabase(RestoreDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
     let alter_database = map(
         rule! {
             ALTER ~ DATABASE ~ ( IF ~ EXISTS )? ~ #peroid_separated_idents_1_to_2 ~ #alter_database_action
@@ -270,13 +376,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let describe_table = map(
         rule! {
-            ( DESC | DESCRIBE ) ~ #peroid_separated_idents_1_to_3
+            ( DESC | DESCRIBE ) ~ TABLE? ~ EXTENDED? ~ #peroid_separated_idents_1_to_3
         },
-        |(_, (catalog, database, table))| {
+        |(_, _, opt_extended, (catalog, database, table))| {
             Statement::DescribeTable(DescribeTableStmt {
                 catalog,
                 database,
                 table,
+                extended: opt_extended.is_some(),
             })
         },
     );
@@ -291,6 +398,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 catalog,
                 database,
                 table,
+                extended: false,
             })
         },
     );
@@ -306,9 +414,20 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let show_drop_tables = map(
+        rule! {
+            SHOW ~ DROP ~ TABLES ~ ( ( FROM | IN ) ~ ^#ident )? ~ #show_limit?
+        },
+        |(_, _, _, opt_database, limit)| {
+            Statement::ShowDropTables(ShowDropTablesStmt {
+                database: opt_database.map(|(_, database)| database),
+                limit,
+            })
+        },
+    );
     let create_table = map(
         rule! {
-            CREATE ~ TRANSIENT? ~ TABLE ~ ( IF ~ NOT ~ EXISTS )?
+            CREATE ~ ( TRANSIENT | TEMPORARY )? ~ TABLE ~ ( IF ~ NOT ~ EXISTS )?
             ~ #peroid_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
@@ -318,7 +437,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
         |(
             _,
-            opt_transient,
+            opt_kind,
             _,
             opt_if_not_exists,
             (catalog, database, table),
@@ -340,7 +459,60 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
-                transient: opt_transient.is_some(),
+                transient: matches!(opt_kind, Some(token) if token.kind == TRANSIENT),
+                temporary: matches!(opt_kind, Some(token) if token.kind == TEMPORARY),
+                external: None,
+            })
+        },
+    );
+    let create_external_table = map(
+        rule! {
+            CREATE ~ EXTERNAL ~ TABLE ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #peroid_separated_idents_1_to_3
+            ~ #create_table_source?
+            ~ ( #engine )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ LOCATION ~ ^"=" ~ ^#uri_location
+            ~ ( PATTERN ~ "=" ~ #literal_string )?
+            ~ ( FILE_FORMAT ~ "=" ~ #options )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, table),
+            source,
+            engine,
+            opt_partition_by,
+            _,
+            _,
+            location,
+            opt_pattern,
+            opt_file_format,
+        )| {
+            Statement::CreateTable(CreateTableStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                table,
+                source,
+                engine,
+                cluster_by: vec![],
+                table_options: BTreeMap::new(),
+                as_query: None,
+                transient: false,
+                temporary: false,
+                external: Some(ExternalTableSource {
+                    partition_by: opt_partition_by
+                        .map(|(_, _, _, columns, _)| columns)
+                        .unwrap_or_default(),
+                    location,
+                    pattern: opt_pattern.map(|(_, _, pattern)| pattern),
+                    file_format: opt_file_format
+                        .map(|(_, _, file_format)| file_format)
+                        .unwrap_or_default(),
+                }),
             })
         },
     );
@@ -370,6 +542,33 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let attach_table = map(
+        rule! {
+            ATTACH ~ TABLE ~ #peroid_separated_idents_1_to_3 ~ #uri_location ~ ( READ_ONLY )?
+        },
+        |(_, _, (catalog, database, table), location, opt_read_only)| {
+            Statement::AttachTable(AttachTableStmt {
+                catalog,
+                database,
+                table,
+                location,
+                read_only: opt_read_only.is_some(),
+            })
+        },
+    );
+    let detach_table = map(
+        rule! {
+            DETACH ~ TABLE ~ ( IF ~ EXISTS )? ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, table))| {
+            Statement::DetachTable(DetachTableStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
     let alter_table = map(
         rule! {
             ALTER ~ TABLE ~ ( IF ~ EXISTS )? ~ #table_reference_only ~ #alter_table_action
@@ -431,6 +630,18 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let analyze_table = map(
+        rule! {
+            ANALYZE ~ TABLE ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, (catalog, database, table))| {
+            Statement::AnalyzeTable(AnalyzeTableStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
     let exists_table = map(
         rule! {
             EXISTS ~ TABLE ~ #peroid_separated_idents_1_to_3
@@ -472,6 +683,79 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #peroid_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #peroid_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+    let create_aggregating_index = map(
+        rule! {
+            CREATE ~ AGGREGATING ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #peroid_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, index, _, (catalog, database, table), _, query)| {
+            Statement::CreateAggregatingIndex(CreateAggregatingIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_aggregating_index = map(
+        rule! {
+            DROP ~ AGGREGATING ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ #ident ~ ON ~ #peroid_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index, _, (catalog, database, table))| {
+            Statement::DropAggregatingIndex(DropAggregatingIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -556,6 +840,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             role_name,
         },
     );
+    let set_role = map(
+        rule! {
+            SET ~ ROLE ~ #literal_string
+        },
+        |(_, _, role_name)| Statement::SetRole { role_name },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -652,6 +942,342 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    // row access policies
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ RETURNS ~ BOOLEAN ~ "->" ~ #expr
+        },
+        |(_, _, _, _, opt_if_not_exists, policy_name, _, _, parameters, _, _, _, _, definition)| {
+            Statement::CreateRowAccessPolicy {
+                if_not_exists: opt_if_not_exists.is_some(),
+                policy_name,
+                parameters,
+                definition: Box::new(definition),
+            }
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, policy_name)| Statement::DropRowAccessPolicy {
+            if_exists: opt_if_exists.is_some(),
+            policy_name,
+        },
+    );
+
+    // masking policies
+    let create_masking_policy = map(
+        rule! {
+            CREATE ~ MASKING ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ AS ~ "(" ~ #ident ~ ")"
+            ~ RETURNS ~ #type_name ~ "->" ~ #expr
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            policy_name,
+            _,
+            _,
+            parameter,
+            _,
+            _,
+            return_type,
+            _,
+            definition,
+        )| Statement::CreateMaskingPolicy {
+            if_not_exists: opt_if_not_exists.is_some(),
+            policy_name,
+            parameter,
+            return_type,
+            definition: Box::new(definition),
+        },
+    );
+    let drop_masking_policy = map(
+        rule! {
+            DROP ~ MASKING ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, policy_name)| Statement::DropMaskingPolicy {
+            if_exists: opt_if_exists.is_some(),
+            policy_name,
+        },
+    );
+
+    // network policies
+    let create_network_policy = map(
+        rule! {
+            CREATE ~ NETWORK ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ALLOWED_IP_LIST ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")"
+            ~ ( BLOCKED_IP_LIST ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")" )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            policy_name,
+            _,
+            _,
+            _,
+            allowed_ip_list,
+            _,
+            opt_blocked_ip_list,
+            opt_comment,
+        )| Statement::CreateNetworkPolicy {
+            if_not_exists: opt_if_not_exists.is_some(),
+            policy_name,
+            allowed_ip_list,
+            blocked_ip_list: opt_blocked_ip_list
+                .map(|(_, _, _, blocked_ip_list, _)| blocked_ip_list)
+                .unwrap_or_default(),
+            comment: opt_comment.map(|(_, _, comment)| comment),
+        },
+    );
+    let drop_network_policy = map(
+        rule! {
+            DROP ~ NETWORK ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, policy_name)| Statement::DropNetworkPolicy {
+            if_exists: opt_if_exists.is_some(),
+            policy_name,
+        },
+    );
+
+    // tasks
+    let create_task = map(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ #task_schedule
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+            ~ AS ~ #statement
+        },
+        |(_, _, opt_if_not_exists, task_name, schedule, opt_comment, _, statement)| {
+            Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                task_name,
+                schedule,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                sql: Box::new(statement.stmt),
+            })
+        },
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, task_name)| Statement::DropTask {
+            if_exists: opt_if_exists.is_some(),
+            task_name,
+        },
+    );
+    let show_tasks = value(Statement::ShowTasks, rule! { SHOW ~ TASKS });
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ #ident ~ #alter_task_action
+        },
+        |(_, _, task_name, action)| Statement::AlterTask { task_name, action },
+    );
+
+    // resource groups
+    let create_resource_group = map(
+        rule! {
+            CREATE ~ RESOURCE ~ GROUP ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ( CPU_SHARE ~ "=" ~ #literal_u64 )?
+            ~ ( MAX_MEMORY_USAGE ~ "=" ~ #literal_u64 )?
+            ~ ( MAX_CONCURRENCY ~ "=" ~ #literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            group_name,
+            opt_cpu_share,
+            opt_max_memory_usage,
+            opt_max_concurrency,
+            opt_comment,
+        )| Statement::CreateResourceGroup {
+            if_not_exists: opt_if_not_exists.is_some(),
+            group_name,
+            cpu_share: opt_cpu_share.map(|(_, _, share)| share),
+            max_memory_usage: opt_max_memory_usage.map(|(_, _, bytes)| bytes),
+            max_concurrency: opt_max_concurrency.map(|(_, _, queries)| queries),
+            comment: opt_comment.map(|(_, _, comment)| comment),
+        },
+    );
+    let drop_resource_group = map(
+        rule! {
+            DROP ~ RESOURCE ~ GROUP ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, group_name)| Statement::DropResourceGroup {
+            if_exists: opt_if_exists.is_some(),
+            group_name,
+        },
+    );
+
+    // connections
+    let create_connection = map(
+        rule! {
+            CREATE ~ CONNECTION ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ STORAGE_TYPE ~ "=" ~ #literal_string
+            ~ ( #ident_to_string ~ "=" ~ #parameter_to_string )*
+        },
+        |(_, _, opt_if_not_exists, connection_name, _, _, storage_type, opts)| {
+            Statement::CreateConnection {
+                if_not_exists: opt_if_not_exists.is_some(),
+                connection_name,
+                storage_type,
+                storage_params: BTreeMap::from_iter(
+                    opts.into_iter().map(|(k, _, v)| (k.to_lowercase(), v)),
+                ),
+            }
+        },
+    );
+    let drop_connection = map(
+        rule! {
+            DROP ~ CONNECTION ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, connection_name)| Statement::DropConnection {
+            if_exists: opt_if_exists.is_some(),
+            connection_name,
+        },
+    );
+    let show_connections = value(Statement::ShowConnections, rule! { SHOW ~ CONNECTIONS });
+    let desc_connection = map(
+        rule! {
+            (DESC | DESCRIBE) ~ CONNECTION ~ #ident
+        },
+        |(_, _, connection_name)| Statement::DescribeConnection { connection_name },
+    );
+
+    // sequences
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ( START ~ WITH? ~ "="? ~ #signed_integer )?
+            ~ ( INCREMENT ~ BY? ~ "="? ~ #signed_integer )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, sequence_name, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence {
+                if_not_exists: opt_if_not_exists.is_some(),
+                sequence_name,
+                start: opt_start.map(|(_, _, _, start)| start),
+                increment: opt_increment.map(|(_, _, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            }
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, sequence_name)| Statement::DropSequence {
+            if_exists: opt_if_exists.is_some(),
+            sequence_name,
+        },
+    );
+    let show_sequences = value(Statement::ShowSequences, rule! { SHOW ~ SEQUENCES });
+
+    // plan hints
+    let create_plan_hint = map(
+        rule! {
+            CREATE ~ PLAN ~ HINT ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ FOR ~ #code_string
+            ~ USING ~ #literal_string
+        },
+        |(_, _, _, opt_if_not_exists, hint_name, _, statement, _, hints)| {
+            Statement::CreatePlanHint {
+                if_not_exists: opt_if_not_exists.is_some(),
+                hint_name,
+                statement,
+                hints,
+            }
+        },
+    );
+    let drop_plan_hint = map(
+        rule! {
+            DROP ~ PLAN ~ HINT ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, hint_name)| Statement::DropPlanHint {
+            if_exists: opt_if_exists.is_some(),
+            hint_name,
+        },
+    );
+    let show_plan_hints = value(Statement::ShowPlanHints, rule! { SHOW ~ PLAN ~ HINTS });
+
+    // cluster
+    let drain_node = map(
+        rule! {
+            ALTER ~ CLUSTER ~ DRAIN ~ NODE ~ #literal_string
+        },
+        |(_, _, _, _, node_id)| Statement::DrainNode { node_id },
+    );
+
+    // dictionaries
+    let create_dictionary = map(
+        rule! {
+            CREATE ~ DICTIONARY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ "(" ~ #comma_separated_list1(dictionary_column_def) ~ ")"
+            ~ PRIMARY ~ KEY ~ #ident
+            ~ SOURCE ~ "=" ~ #options
+            ~ ( LIFETIME ~ "=" ~ #literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            dictionary_name,
+            _,
+            columns,
+            _,
+            _,
+            _,
+            primary_key,
+            _,
+            _,
+            source_options,
+            opt_lifetime,
+            opt_comment,
+        )| {
+            Statement::CreateDictionary(CreateDictionaryStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                dictionary_name,
+                columns,
+                primary_key,
+                source_options,
+                lifetime: opt_lifetime.map(|(_, _, lifetime)| lifetime),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_dictionary = map(
+        rule! {
+            DROP ~ DICTIONARY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, dictionary_name)| Statement::DropDictionary {
+            if_exists: opt_if_exists.is_some(),
+            dictionary_name,
+        },
+    );
+    let show_dictionaries = value(Statement::ShowDictionaries, rule! { SHOW ~ DICTIONARIES });
+
     // stages
     let create_stage = map_res(
         rule! {
@@ -740,6 +1366,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ ( PATTERN ~ "=" ~ #literal_string)?
             ~ ( FILE_FORMAT ~ "=" ~ #options)?
             ~ ( VALIDATION_MODE ~ "=" ~ #literal_string)?
+            ~ ( ON_ERROR ~ "=" ~ #ident)?
             ~ ( SIZE_LIMIT ~ "=" ~ #literal_u64)?
             ~ ( MAX_FILE_SIZE ~ "=" ~ #literal_u64)?
             ~ ( SINGLE ~ "=" ~ #literal_bool)?
@@ -756,6 +1383,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             pattern,
             file_format,
             validation_mode,
+            on_error,
             size_limit,
             max_file_size,
             single,
@@ -769,6 +1397,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 pattern: pattern.map(|v| v.2).unwrap_or_default(),
                 file_format: file_format.map(|v| v.2).unwrap_or_default(),
                 validation_mode: validation_mode.map(|v| v.2).unwrap_or_default(),
+                on_error: on_error.map(|v| v.2.to_string()).unwrap_or_default(),
                 size_limit: size_limit.map(|v| v.2).unwrap_or_default() as usize,
                 max_file_size: max_file_size.map(|v| v.2).unwrap_or_default() as usize,
                 single: single.map(|v| v.2).unwrap_or_default(),
@@ -888,7 +1517,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
+            | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
             | #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] ON (<column>, ...) (VALUES <values> | <query>)`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
@@ -901,7 +1532,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #show_databases : "`SHOW DATABASES [<show_limit>]`"
             | #undrop_database : "`UNDROP DATABASE <database>`"
             | #show_create_database : "`SHOW CREATE DATABASE <database>`"
-            | #create_database : "`CREATE DATABASE [IF NOT EXIST] <database> [ENGINE = <engine>]`"
+            | #create_database : "`CREATE DATABASE [IF NOT EXIST] <database> [ENGINE = <engine>] [COMMENT = '<comment>']`"
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
@@ -912,19 +1543,44 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #describe_table : "`DESCRIBE [<database>.]<table>`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
+            | #show_drop_tables : "`SHOW DROP TABLES [FROM <database>] [<show_limit>]`"
             | #create_table : "`CREATE TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
+            | #create_external_table : "`CREATE EXTERNAL TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [ENGINE = ICEBERG | DELTA] [PARTITION BY (<column>, ...)] LOCATION = <uri_location> [PATTERN = '<regex_pattern>'] [FILE_FORMAT = (<format_options>)]`"
             | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
+            | #attach_table : "`ATTACH TABLE [<database>.]<table> <uri_location> [READ_ONLY]`"
+            | #detach_table : "`DETACH TABLE [IF EXISTS] [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
-            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT)`"
+            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT | VERIFY)`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
         ),
+        rule!(
+            #backup_database : "`BACKUP DATABASE <database> TO <uri_location>`"
+            | #restore_database : "`RESTORE DATABASE <database> FROM <uri_location>`"
+        ),
         rule!(
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> AS SELECT ...`"
+            | #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [ COMMENT = '<string_literal>' ]`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+            | #create_aggregating_index : "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> AS SELECT <column>, ..., <aggregate>(<column>), ... FROM <table> [GROUP BY <column>, ...]`"
+            | #drop_aggregating_index : "`DROP AGGREGATING INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+        ),
+        rule!(
+            #begin : "`BEGIN [TRANSACTION | WORK]`"
+            | #start_transaction : "`START TRANSACTION`"
+            | #commit : "`COMMIT [WORK]`"
+            | #rollback : "`ROLLBACK [WORK]`"
+            | #validate : "`VALIDATE <statement>`"
+        ),
+        rule!(
+            #set_user_variable : "`SET VARIABLE <variable> = <expr>`"
+            | #unset_user_variable : "`UNSET VARIABLE <variable>`"
+            | #execute_immediate : "`EXECUTE IMMEDIATE $$ BEGIN <statement>; ... END $$`"
         ),
         rule!(
             #show_users : "`SHOW USERS`"
@@ -934,10 +1590,40 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] '<role_name>']`"
             | #drop_role : "`DROP ROLE [IF EXISTS] '<role_name>'`"
+            | #set_role : "`SET ROLE '<role_name>'`"
             | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <udf_name> (<parameter>, ...) -> <definition expr> [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
         ),
+        rule!(
+            #create_row_access_policy : "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy_name> AS (<parameter>, ...) RETURNS BOOLEAN -> <definition expr>`"
+            | #drop_row_access_policy : "`DROP ROW ACCESS POLICY [IF EXISTS] <policy_name>`"
+            | #create_masking_policy : "`CREATE MASKING POLICY [IF NOT EXISTS] <policy_name> AS (<parameter>) RETURNS <type> -> <definition expr>`"
+            | #drop_masking_policy : "`DROP MASKING POLICY [IF EXISTS] <policy_name>`"
+            | #create_network_policy : "`CREATE NETWORK POLICY [IF NOT EXISTS] <policy_name> ALLOWED_IP_LIST = ('<ip>', ...) [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<string_literal>']`"
+            | #drop_network_policy : "`DROP NETWORK POLICY [IF EXISTS] <policy_name>`"
+            | #create_resource_group : "`CREATE RESOURCE GROUP [IF NOT EXISTS] <group_name> [CPU_SHARE = <share>] [MAX_MEMORY_USAGE = <bytes>] [MAX_CONCURRENCY = <queries>] [COMMENT = '<string_literal>']`"
+            | #drop_resource_group : "`DROP RESOURCE GROUP [IF EXISTS] <group_name>`"
+            | #create_task : "`CREATE TASK [IF NOT EXISTS] <task_name> { SCHEDULE = '<cron>' | AFTER <task_name> } [COMMENT = '<string_literal>'] AS <statement>`"
+            | #drop_task : "`DROP TASK [IF EXISTS] <task_name>`"
+            | #show_tasks : "`SHOW TASKS`"
+            | #alter_task : "`ALTER TASK <task_name> { SUSPEND | RESUME | EXECUTE }`"
+            | #create_connection : "`CREATE CONNECTION [IF NOT EXISTS] <connection_name> STORAGE_TYPE = '<type>' [<option> = '<value>' ...]`"
+            | #drop_connection : "`DROP CONNECTION [IF EXISTS] <connection_name>`"
+            | #show_connections : "`SHOW CONNECTIONS`"
+            | #desc_connection : "`DESC CONNECTION <connection_name>`"
+            | #create_sequence : "`CREATE SEQUENCE [IF NOT EXISTS] <sequence_name> [START [WITH] <start>] [INCREMENT [BY] <increment>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence : "`DROP SEQUENCE [IF EXISTS] <sequence_name>`"
+            | #show_sequences : "`SHOW SEQUENCES`"
+        ),
+        rule!(
+            #create_dictionary : "`CREATE DICTIONARY [IF NOT EXISTS] <dictionary_name> (<column_name> <type>, ...) PRIMARY KEY <column_name> SOURCE = (TYPE = '<type>' <option> = '<value>' ...) [LIFETIME = <seconds>] [COMMENT = '<string_literal>']`"
+            | #drop_dictionary : "`DROP DICTIONARY [IF EXISTS] <dictionary_name>`"
+            | #show_dictionaries : "`SHOW DICTIONARIES`"
+            | #create_plan_hint : "`CREATE PLAN HINT [IF NOT EXISTS] <hint_name> FOR $$<statement>$$ USING '<hints>'`"
+            | #drop_plan_hint : "`DROP PLAN HINT [IF EXISTS] <hint_name>`"
+            | #show_plan_hints : "`SHOW PLAN HINTS`"
+        ),
         rule!(
             #create_stage: "`CREATE STAGE [ IF NOT EXISTS ] <internal_stage_name>
                 [ FILE_FORMAT = ( { TYPE = { CSV | PARQUET } [ formatTypeOptions ] ) } ]
@@ -961,6 +1647,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! (
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
         ),
+        rule!(
+            #drain_node: "`ALTER CLUSTER DRAIN NODE '<node_id>'`"
+        ),
         rule!(
             #grant : "`GRANT { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } TO { [ROLE <role_name>] | [USER] <user> }`"
             | #show_grants : "`SHOW GRANTS {FOR  { ROLE <role_name> | USER <user> }] | ON {DATABASE <db_name> | TABLE <db_name>.<table_name>} }`"
@@ -1038,11 +1727,19 @@ pub fn rest_str<'a>(i: Input<'a>) -> IResult<(&'a str, usize)> {
     ))
 }
 
+pub fn dictionary_column_def(i: Input) -> IResult<DictionaryColumnDefinition> {
+    map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        DictionaryColumnDefinition { name, data_type }
+    })(i)
+}
+
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint<'a> {
         Nullable(bool),
         DefaultExpr(Box<Expr<'a>>),
+        AutoIncrement,
+        Collation(String),
     }
 
     let nullable = alt((
@@ -1055,6 +1752,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         },
         |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
     );
+    let auto_increment = value(ColumnConstraint::AutoIncrement, rule! {
+        AUTOINCREMENT | AUTO_INCREMENT
+    });
 
     let comment = map(
         rule! {
@@ -1063,19 +1763,28 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         |(_, comment)| comment,
     );
 
+    let collation = map(
+        rule! {
+            COLLATE ~ ^#literal_string
+        },
+        |(_, collation)| ColumnConstraint::Collation(collation),
+    );
+
     map(
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #default_expr )*
+            ~ ( #nullable | #collation | #default_expr | #auto_increment )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <default value>] [COMMENT '<comment>']`"
+            : "`<column name> <type> [COLLATE '<collation>'] [DEFAULT <default value> | AUTOINCREMENT] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
                 name,
                 data_type,
+                collation: None,
                 default_expr: None,
+                auto_increment: false,
                 comment,
             };
             for constraint in constraints {
@@ -1088,6 +1797,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                             def.data_type = def.data_type.wrap_nullable();
                         }
                     }
+                    ColumnConstraint::AutoIncrement => def.auto_increment = true,
+                    ColumnConstraint::Collation(collation) => def.collation = Some(collation),
                 }
             }
             def
@@ -1095,6 +1806,19 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     )(i)
 }
 
+// An integer literal with an optional minus sign.
+pub fn signed_integer(i: Input) -> IResult<i64> {
+    map_res(
+        rule! {
+            "-"? ~ LiteralInteger
+        },
+        |(minus, token)| match minus {
+            Some(_) => Ok(format!("-{}", token.text()).parse::<i64>()?),
+            None => Ok(token.text().parse::<i64>()?),
+        },
+    )(i)
+}
+
 pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     let role = map(
         rule! {
@@ -1165,6 +1889,14 @@ pub fn grant_share_object_name(i: Input) -> IResult<ShareGrantObjectName> {
         |(_, database)| ShareGrantObjectName::Database(database.to_string()),
     );
 
+    // FUTURE TABLES IN DATABASE db
+    let future_tables = map(
+        rule! {
+            FUTURE ~ TABLES ~ IN ~ DATABASE ~ #ident
+        },
+        |(_, _, _, _, database)| AccountMgrLevel::FutureTables(database.name),
+    );
+
     // `db01`.'tb1' or `db01`.`tb1` or `db01`.tb1
     let table = map(
         rule! {
@@ -1206,6 +1938,7 @@ pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
     rule!(
         #global : "*.*"
         | #db : "<database>.*"
+        | #future_tables : "FUTURE TABLES IN DATABASE <database>"
         | #table : "<database>.<table>"
     )(i)
 }
@@ -1292,8 +2025,16 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         |(_, _, new_db)| AlterDatabaseAction::RenameDatabase { new_db },
     );
 
+    let set_comment = map(
+        rule! {
+            COMMENT ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, comment)| AlterDatabaseAction::SetComment { comment },
+    );
+
     rule!(
         #rename_database
+        | #set_comment
     )(i)
 }
 
@@ -1305,6 +2046,13 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, new_table)| AlterTableAction::RenameTable { new_table },
     );
 
+    let swap_table = map(
+        rule! {
+            SWAP ~ ^WITH ~ ^#ident
+        },
+        |(_, _, target_table)| AlterTableAction::SwapWith { target_table },
+    );
+
     let alter_table_cluster_key = map(
         rule! {
             CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
@@ -1329,11 +2077,94 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
     );
 
+    let set_table_options = map(
+        rule! {
+            SET ~ #table_option
+        },
+        |(_, set_options)| AlterTableAction::SetOptions { set_options },
+    );
+
+    let set_table_comment = map(
+        rule! {
+            COMMENT ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, comment)| AlterTableAction::SetOptions {
+            set_options: BTreeMap::from([("comment".to_string(), comment)]),
+        },
+    );
+
+    let add_column = map(
+        rule! {
+            ADD ~ COLUMN ~ ^#column_def
+        },
+        |(_, _, column)| AlterTableAction::AddColumn { column },
+    );
+
+    let add_virtual_column = map(
+        rule! {
+            ADD ~ VIRTUAL ~ ^COLUMN ~ ^#ident ~ ^AS ~ ^#expr
+        },
+        |(_, _, _, column, _, expr)| AlterTableAction::AddVirtualColumn { column, expr },
+    );
+
+    let drop_virtual_column = map(
+        rule! {
+            DROP ~ VIRTUAL ~ ^COLUMN ~ ^#ident
+        },
+        |(_, _, _, column)| AlterTableAction::DropVirtualColumn { column },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ^ACCESS ~ ^POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = value(AlterTableAction::DropRowAccessPolicy, rule! {
+        DROP ~ ROW ~ ^ACCESS ~ ^POLICY
+    });
+
+    let set_masking_policy = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ SET ~ ^MASKING ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, column, _, _, _, policy)| AlterTableAction::SetMaskingPolicy { column, policy },
+    );
+
+    let unset_masking_policy = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ UNSET ~ ^MASKING ~ ^POLICY
+        },
+        |(_, _, column, _, _, _)| AlterTableAction::UnsetMaskingPolicy { column },
+    );
+
+    let modify_column_comment = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ COMMENT ~ ^#literal_string
+        },
+        |(_, _, column, _, comment)| AlterTableAction::ModifyColumnComment { column, comment },
+    );
+
     rule!(
         #rename_table
+        | #swap_table
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
+        | #set_table_options
+        | #set_table_comment
+        | #add_column
+        | #add_virtual_column
+        | #drop_virtual_column
+        | #add_row_access_policy
+        | #drop_row_access_policy
+        | #set_masking_policy
+        | #unset_masking_policy
+        | #modify_column_comment
     )(i)
 }
 
@@ -1342,6 +2173,27 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
         value(OptimizeTableAction::All, rule! { ALL }),
         value(OptimizeTableAction::Purge, rule! { PURGE }),
         value(OptimizeTableAction::Compact, rule! { COMPACT }),
+        value(OptimizeTableAction::Verify, rule! { VERIFY }),
+    ))(i)
+}
+
+pub fn task_schedule(i: Input) -> IResult<TaskSchedule> {
+    alt((
+        map(
+            rule! { SCHEDULE ~ "=" ~ #literal_string },
+            |(_, _, schedule)| TaskSchedule::Cron(schedule),
+        ),
+        map(rule! { AFTER ~ #ident }, |(_, task_name)| {
+            TaskSchedule::After(task_name)
+        }),
+    ))(i)
+}
+
+pub fn alter_task_action(i: Input) -> IResult<AlterTaskAction> {
+    alt((
+        value(AlterTaskAction::Suspend, rule! { SUSPEND }),
+        value(AlterTaskAction::Resume, rule! { RESUME }),
+        value(AlterTaskAction::Execute, rule! { EXECUTE }),
     ))(i)
 }
 
@@ -1357,6 +2209,17 @@ pub fn kill_target(i: Input) -> IResult<KillTarget> {
 /// # Notes
 ///
 /// It's required to parse stage location first. Or stage could be parsed as table.
+/// The name of the session variable, `x` or `@x`.
+pub fn user_variable(i: Input) -> IResult<Identifier> {
+    let at_variable = map(rule! { AtString }, |token| Identifier {
+        name: token.text()[1..].to_string(),
+        quote: None,
+        span: token.clone(),
+    });
+
+    alt((ident, at_variable))(i)
+}
+
 pub fn copy_unit(i: Input) -> IResult<CopyUnit> {
     // Parse input like `@my_stage/path/to/dir`
     let stage_location = |i| {
@@ -1492,6 +2355,8 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Iceberg, rule! { ICEBERG }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
@@ -1549,6 +2414,24 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, role)| UserOptionItem::DefaultRole(role),
     );
+    let set_network_policy_option = map(
+        rule! {
+            SET ~ NETWORK ~ ^POLICY ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetNetworkPolicy(policy),
+    );
+    let set_resource_group_option = map(
+        rule! {
+            SET ~ RESOURCE ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| UserOptionItem::SetResourceGroup(group),
+    );
+    let default_query_tag_option = map(
+        rule! {
+            "DEFAULT_QUERY_TAG" ~ "=" ~ #literal_string
+        },
+        |(_, _, tag)| UserOptionItem::DefaultQueryTag(tag),
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -1556,6 +2439,17 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        set_network_policy_option,
+        value(
+            UserOptionItem::UnsetNetworkPolicy,
+            rule! { UNSET ~ NETWORK ~ POLICY },
+        ),
+        set_resource_group_option,
+        value(
+            UserOptionItem::UnsetResourceGroup,
+            rule! { UNSET ~ RESOURCE ~ GROUP },
+        ),
+        default_query_tag_option,
     ))(i)
 }
 
@@ -1579,6 +2473,7 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::LDAP, rule! { LDAP }),
     ))(i)
 }
 
@@ -1590,9 +2485,13 @@ pub fn u64_to_string(i: Input) -> IResult<String> {
     map(literal_u64, |v| v.to_string())(i)
 }
 
+pub fn bool_to_string(i: Input) -> IResult<String> {
+    map(literal_bool, |v| v.to_string())(i)
+}
+
 pub fn parameter_to_string(i: Input) -> IResult<String> {
     map(
-        rule! { ( #literal_string | #ident_to_string | #u64_to_string ) },
+        rule! { ( #literal_string | #ident_to_string | #u64_to_string | #bool_to_string ) },
         |parameter| parameter,
     )(i)
 }
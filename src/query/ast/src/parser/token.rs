@@ -51,6 +51,7 @@ impl<'a> std::fmt::Debug for Token<'a> {
 pub struct Tokenizer<'a> {
     source: &'a str,
     lexer: Lexer<'a, TokenKind>,
+    prev_token: Option<TokenKind>,
     eoi: bool,
 }
 
@@ -59,6 +60,7 @@ impl<'a> Tokenizer<'a> {
         Tokenizer {
             source,
             lexer: TokenKind::lexer(source),
+            prev_token: None,
             eoi: false,
         }
     }
@@ -68,7 +70,14 @@ impl<'a> Iterator for Tokenizer<'a> {
     type Item = Result<Token<'a>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.lexer.next() {
+        let mut kind = self.lexer.next();
+        // The hints are only recognized after `SELECT`, otherwise they are comments.
+        while kind == Some(TokenKind::Hint) && self.prev_token != Some(TokenKind::SELECT) {
+            kind = self.lexer.next();
+        }
+        self.prev_token = kind;
+
+        match kind {
             Some(kind) if kind == TokenKind::Error => {
                 let rest_span = Token {
                     source: self.source,
@@ -110,6 +119,9 @@ pub enum TokenKind {
     #[regex(r"/\*([^\*]|(\*[^/]))*\*/", logos::skip)]
     CommentBlock,
 
+    #[regex(r"/\*\+([^\*]|(\*[^/]))*\*/")]
+    Hint,
+
     #[regex(r#"[_a-zA-Z][_$a-zA-Z0-9]*"#)]
     Ident,
 
@@ -121,6 +133,14 @@ pub enum TokenKind {
     #[regex(r#"@([^\s`;'"])+"#)]
     AtString,
 
+    /// The dollar-quoted string `$$...$$`, of which the content is not escaped, e.g. the
+    /// scripts of `EXECUTE IMMEDIATE`
+    #[regex(r"\$\$([^\$]|\$[^\$])*\$\$")]
+    LiteralCodeString,
+
+    #[regex(r#"\$[_a-zA-Z][_a-zA-Z0-9]*"#)]
+    Variable,
+
     #[regex(r"[xX]'[a-fA-F0-9]*'")]
     PGLiteralHex,
     #[regex(r"0[xX][a-fA-F0-9]+")]
@@ -175,6 +195,9 @@ pub enum TokenKind {
     Colon,
     #[token("::")]
     DoubleColon,
+    /// The assignment of the variables of the scripts
+    #[token(":=")]
+    ColonEq,
     #[token(";")]
     SemiColon,
     #[token("\\")]
@@ -243,10 +266,16 @@ pub enum TokenKind {
     ALL,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("AFTER", ignore(ascii_case))]
+    AFTER,
+    #[token("AGGREGATING", ignore(ascii_case))]
+    AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
     #[token("SOME", ignore(ascii_case))]
     SOME,
+    #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
+    ALLOWED_IP_LIST,
     #[token("ALTER", ignore(ascii_case))]
     ALTER,
     #[token("ANALYZE", ignore(ascii_case))]
@@ -261,6 +290,12 @@ pub enum TokenKind {
     AST,
     #[token("AT", ignore(ascii_case))]
     AT,
+    #[token("ATTACH", ignore(ascii_case))]
+    ATTACH,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
+    #[token("AUTO_INCREMENT", ignore(ascii_case))]
+    AUTO_INCREMENT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
     #[token("AWS_KEY_ID", ignore(ascii_case))]
@@ -269,16 +304,26 @@ pub enum TokenKind {
     AWS_SECRET_KEY,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
+    #[token("BACKUP", ignore(ascii_case))]
+    BACKUP,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
     BIGINT,
+    #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
+    BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
     BOOLEAN,
     #[token("BOTH", ignore(ascii_case))]
     BOTH,
+    #[token("BREAK", ignore(ascii_case))]
+    BREAK,
     #[token("BY", ignore(ascii_case))]
     BY,
     #[token("CALL", ignore(ascii_case))]
@@ -291,14 +336,24 @@ pub enum TokenKind {
     CENTURY,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
+    #[token("COLLATE", ignore(ascii_case))]
+    COLLATE,
+    #[token("COLUMN", ignore(ascii_case))]
+    COLUMN,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
+    #[token("CONTINUE", ignore(ascii_case))]
+    CONTINUE,
+    #[token("CONNECTIONS", ignore(ascii_case))]
+    CONNECTIONS,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
     #[token("CHARACTER", ignore(ascii_case))]
@@ -309,6 +364,8 @@ pub enum TokenKind {
     COPY,
     #[token("COUNT", ignore(ascii_case))]
     COUNT,
+    #[token("CPU_SHARE", ignore(ascii_case))]
+    CPU_SHARE,
     #[token("CREATE", ignore(ascii_case))]
     CREATE,
     #[token("CREDENTIALS", ignore(ascii_case))]
@@ -341,14 +398,24 @@ pub enum TokenKind {
     DEFAULT,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
     DESCRIBE,
+    #[token("DETACH", ignore(ascii_case))]
+    DETACH,
+    #[token("DICTIONARIES", ignore(ascii_case))]
+    DICTIONARIES,
+    #[token("DICTIONARY", ignore(ascii_case))]
+    DICTIONARY,
     #[token("DISTINCT", ignore(ascii_case))]
     DISTINCT,
     #[token("DIV", ignore(ascii_case))]
     DIV,
+    #[token("DO", ignore(ascii_case))]
+    DO,
     #[token("DOUBLE_SHA1_PASSWORD", ignore(ascii_case))]
     DOUBLE_SHA1_PASSWORD,
     #[token("DOUBLE", ignore(ascii_case))]
@@ -359,12 +426,20 @@ pub enum TokenKind {
     DOY,
     #[token("DOWNLOAD", ignore(ascii_case))]
     DOWNLOAD,
+    #[token("DRAIN", ignore(ascii_case))]
+    DRAIN,
     #[token("DROP", ignore(ascii_case))]
     DROP,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
+    #[token("EXCEPTION", ignore(ascii_case))]
+    EXCEPTION,
+    #[token("EXECUTE", ignore(ascii_case))]
+    EXECUTE,
     #[token("ELSE", ignore(ascii_case))]
     ELSE,
+    #[token("ELSEIF", ignore(ascii_case))]
+    ELSEIF,
     #[token("END", ignore(ascii_case))]
     END,
     #[token("ENDPOINT_URL", ignore(ascii_case))]
@@ -383,6 +458,10 @@ pub enum TokenKind {
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXTENDED", ignore(ascii_case))]
+    EXTENDED,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
@@ -421,6 +500,8 @@ pub enum TokenKind {
     FUNCTIONS,
     #[token("FUSE", ignore(ascii_case))]
     FUSE,
+    #[token("FUTURE", ignore(ascii_case))]
+    FUTURE,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -429,18 +510,30 @@ pub enum TokenKind {
     GROUP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HINT", ignore(ascii_case))]
+    HINT,
+    #[token("HINTS", ignore(ascii_case))]
+    HINTS,
     #[token("HISTORY", ignore(ascii_case))]
     HISTORY,
     #[token("HOUR", ignore(ascii_case))]
     HOUR,
     #[token("INTERSECT", ignore(ascii_case))]
     INTERSECT,
+    #[token("ICEBERG", ignore(ascii_case))]
+    ICEBERG,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("IMMEDIATE", ignore(ascii_case))]
+    IMMEDIATE,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
+    #[token("INDEX", ignore(ascii_case))]
+    INDEX,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -481,20 +574,36 @@ pub enum TokenKind {
     KILL,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LDAP", ignore(ascii_case))]
+    LDAP,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
+    #[token("LET", ignore(ascii_case))]
+    LET,
     #[token("LEFT", ignore(ascii_case))]
     LEFT,
+    #[token("LIFETIME", ignore(ascii_case))]
+    LIFETIME,
     #[token("LIKE", ignore(ascii_case))]
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
     LIMIT,
     #[token("LIST", ignore(ascii_case))]
     LIST,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
+    #[token("LOOP", ignore(ascii_case))]
+    LOOP,
     #[token("MAP", ignore(ascii_case))]
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
+    #[token("MAX_MEMORY_USAGE", ignore(ascii_case))]
+    MAX_MEMORY_USAGE,
+    #[token("MASKING", ignore(ascii_case))]
+    MASKING,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEMORY", ignore(ascii_case))]
@@ -509,10 +618,16 @@ pub enum TokenKind {
     MILLISECONDS,
     #[token("MINUTE", ignore(ascii_case))]
     MINUTE,
+    #[token("MODIFY", ignore(ascii_case))]
+    MODIFY,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
     #[token("NATURAL", ignore(ascii_case))]
     NATURAL,
+    #[token("NETWORK", ignore(ascii_case))]
+    NETWORK,
+    #[token("NODE", ignore(ascii_case))]
+    NODE,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NOT", ignore(ascii_case))]
@@ -535,6 +650,8 @@ pub enum TokenKind {
     OR,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHER", ignore(ascii_case))]
+    OTHER,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
     #[token("ON_ERROR", ignore(ascii_case))]
@@ -543,14 +660,20 @@ pub enum TokenKind {
     OVERWRITE,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
+    #[token("PARTITION", ignore(ascii_case))]
+    PARTITION,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
+    #[token("POLICY", ignore(ascii_case))]
+    POLICY,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
     #[token("PURGE", ignore(ascii_case))]
@@ -559,6 +682,10 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("READ_ONLY", ignore(ascii_case))]
+    READ_ONLY,
+    #[token("RAISE", ignore(ascii_case))]
+    RAISE,
     #[token("RECLUSTER", ignore(ascii_case))]
     RECLUSTER,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
@@ -573,14 +700,32 @@ pub enum TokenKind {
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
+    #[token("ROW", ignore(ascii_case))]
+    ROW,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("REPLACE", ignore(ascii_case))]
+    REPLACE,
+    #[token("RESOURCE", ignore(ascii_case))]
+    RESOURCE,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
+    #[token("RETURN", ignore(ascii_case))]
+    RETURN,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
+    #[token("RESUME", ignore(ascii_case))]
+    RESUME,
     #[token("RECURSIVE", ignore(ascii_case))]
     RECURSIVE,
     #[token("GRANTS", ignore(ascii_case))]
@@ -591,6 +736,8 @@ pub enum TokenKind {
     RLIKE,
     #[token("RAW", ignore(ascii_case))]
     RAW,
+    #[token("SCHEDULE", ignore(ascii_case))]
+    SCHEDULE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -599,6 +746,10 @@ pub enum TokenKind {
     SECOND,
     #[token("SELECT", ignore(ascii_case))]
     SELECT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
+    #[token("SEQUENCES", ignore(ascii_case))]
+    SEQUENCES,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("SETTINGS", ignore(ascii_case))]
@@ -621,8 +772,12 @@ pub enum TokenKind {
     SMALLINT,
     #[token("SNAPSHOT", ignore(ascii_case))]
     SNAPSHOT,
+    #[token("SOURCE", ignore(ascii_case))]
+    SOURCE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -637,8 +792,16 @@ pub enum TokenKind {
     SHARES,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
+    #[token("SUSPEND", ignore(ascii_case))]
+    SUSPEND,
+    #[token("SWAP", ignore(ascii_case))]
+    SWAP,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
+    #[token("STORAGE_TYPE", ignore(ascii_case))]
+    STORAGE_TYPE,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("STRING", ignore(ascii_case))]
     STRING,
     #[token("SUBSTRING", ignore(ascii_case))]
@@ -649,8 +812,14 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TASK", ignore(ascii_case))]
+    TASK,
+    #[token("TASKS", ignore(ascii_case))]
+    TASKS,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
+    #[token("TEMPORARY", ignore(ascii_case))]
+    TEMPORARY,
     #[token("TENANTSETTING", ignore(ascii_case))]
     TENANTSETTING,
     #[token("TENANTS", ignore(ascii_case))]
@@ -673,6 +842,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -699,6 +870,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNSET", ignore(ascii_case))]
+    UNSET,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -711,24 +884,36 @@ pub enum TokenKind {
     USERS,
     #[token("USING", ignore(ascii_case))]
     USING,
+    #[token("VALIDATE", ignore(ascii_case))]
+    VALIDATE,
     #[token("VALUES", ignore(ascii_case))]
     VALUES,
     #[token("VALIDATION_MODE", ignore(ascii_case))]
     VALIDATION_MODE,
     #[token("VARCHAR", ignore(ascii_case))]
     VARCHAR,
+    #[token("VARIABLE", ignore(ascii_case))]
+    VARIABLE,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VERIFY", ignore(ascii_case))]
+    VERIFY,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
+    #[token("VIRTUAL", ignore(ascii_case))]
+    VIRTUAL,
     #[token("WEEK", ignore(ascii_case))]
     WEEK,
     #[token("WHEN", ignore(ascii_case))]
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
     WHERE,
+    #[token("WHILE", ignore(ascii_case))]
+    WHILE,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
     #[token("XOR", ignore(ascii_case))]
     XOR,
     #[token("YEAR", ignore(ascii_case))]
@@ -756,6 +941,7 @@ impl TokenKind {
             self,
             Ident
                 | QuotedString
+                | LiteralCodeString
                 | PGLiteralHex
                 | MySQLLiteralHex
                 | LiteralInteger
@@ -780,6 +966,7 @@ impl TokenKind {
                 | Period
                 | Colon
                 | DoubleColon
+                | ColonEq
                 | SemiColon
                 | Backslash
                 | LBracket
@@ -840,7 +1027,7 @@ impl TokenKind {
             // | TokenKind::DEFERRABLE
             | TokenKind::DESC
             | TokenKind::DISTINCT
-            // | TokenKind::DO
+            | TokenKind::DO
             | TokenKind::ELSE
             | TokenKind::END
             | TokenKind::EXISTS
@@ -966,7 +1153,7 @@ impl TokenKind {
             | TokenKind::CASE
             | TokenKind::CAST
             // | TokenKind::CHECK
-            // | TokenKind::COLLATE
+            | TokenKind::COLLATE
             // | TokenKind::COLLATION
             // | TokenKind::COLUMN
             // | TokenKind::CONCURRENTLY
@@ -982,7 +1169,7 @@ impl TokenKind {
             // | TokenKind::DEFERRABLE
             | TokenKind::DESC
             | TokenKind::DISTINCT
-            // | TokenKind::DO
+            | TokenKind::DO
             | TokenKind::ELSE
             | TokenKind::END
             | TokenKind::FALSE
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_datavalues::IntervalKind;
 use common_meta_types::PrincipalIdentity;
 use common_meta_types::UserIdentity;
@@ -174,6 +176,15 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, expr);
     }
 
+    fn visit_collate(
+        &mut self,
+        _span: &'ast [Token<'ast>],
+        expr: &'ast Expr<'ast>,
+        _collation: &'ast str,
+    ) {
+        walk_expr(self, expr);
+    }
+
     fn visit_extract(
         &mut self,
         _span: &'ast [Token<'ast>],
@@ -364,6 +375,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_kill(&mut self, _kill_target: &'ast KillTarget, _object_id: &'ast str) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_rollback(&mut self) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -372,8 +389,21 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_set_user_variable(
+        &mut self,
+        _variable: &'ast Identifier<'ast>,
+        _value: &'ast Expr<'ast>,
+    ) {
+    }
+
+    fn visit_unset_user_variable(&mut self, _variable: &'ast Identifier<'ast>) {}
+
+    fn visit_execute_immediate(&mut self, _script: &'ast str) {}
+
     fn visit_insert(&mut self, _insert: &'ast InsertStmt<'ast>) {}
 
+    fn visit_replace(&mut self, _replace: &'ast ReplaceStmt<'ast>) {}
+
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource<'ast>) {}
 
     fn visit_delete(
@@ -395,6 +425,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_database(&mut self, _stmt: &'ast AlterDatabaseStmt<'ast>) {}
 
+    fn visit_backup_database(&mut self, _stmt: &'ast BackupDatabaseStmt<'ast>) {}
+
+    fn visit_restore_database(&mut self, _stmt: &'ast RestoreDatabaseStmt<'ast>) {}
+
     fn visit_use_database(&mut self, _database: &'ast Identifier<'ast>) {}
 
     fn visit_show_tables(&mut self, _stmt: &'ast ShowTablesStmt<'ast>) {}
@@ -405,6 +439,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_tables_status(&mut self, _stmt: &'ast ShowTablesStatusStmt<'ast>) {}
 
+    fn visit_show_drop_tables(&mut self, _stmt: &'ast ShowDropTablesStmt<'ast>) {}
+
     fn visit_create_table(&mut self, _stmt: &'ast CreateTableStmt<'ast>) {}
 
     fn visit_create_table_source(&mut self, _source: &'ast CreateTableSource<'ast>) {}
@@ -415,6 +451,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_undrop_table(&mut self, _stmt: &'ast UndropTableStmt<'ast>) {}
 
+    fn visit_attach_table(&mut self, _stmt: &'ast AttachTableStmt<'ast>) {}
+
+    fn visit_detach_table(&mut self, _stmt: &'ast DetachTableStmt<'ast>) {}
+
     fn visit_alter_table(&mut self, _stmt: &'ast AlterTableStmt<'ast>) {}
 
     fn visit_rename_table(&mut self, _stmt: &'ast RenameTableStmt<'ast>) {}
@@ -423,6 +463,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_optimize_table(&mut self, _stmt: &'ast OptimizeTableStmt<'ast>) {}
 
+    fn visit_analyze_table(&mut self, _stmt: &'ast AnalyzeTableStmt<'ast>) {}
+
     fn visit_exists_table(&mut self, _stmt: &'ast ExistsTableStmt<'ast>) {}
 
     fn visit_create_view(&mut self, _stmt: &'ast CreateViewStmt<'ast>) {}
@@ -431,6 +473,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt<'ast>) {}
 
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt<'ast>) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt<'ast>) {}
+
+    fn visit_create_aggregating_index(&mut self, _stmt: &'ast CreateAggregatingIndexStmt<'ast>) {}
+
+    fn visit_drop_aggregating_index(&mut self, _stmt: &'ast DropAggregatingIndexStmt<'ast>) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -445,6 +495,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &'ast str) {}
 
+    fn visit_set_role(&mut self, _role_name: &'ast str) {}
+
     fn visit_grant(&mut self, _grant: &'ast GrantStmt) {}
 
     fn visit_show_grant(&mut self, _principal: &'ast Option<PrincipalIdentity>) {}
@@ -472,6 +524,143 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_create_row_access_policy(
+        &mut self,
+        _if_not_exists: bool,
+        _policy_name: &'ast Identifier<'ast>,
+        _parameters: &'ast [Identifier<'ast>],
+        _definition: &'ast Expr<'ast>,
+    ) {
+    }
+
+    fn visit_drop_row_access_policy(
+        &mut self,
+        _if_exists: bool,
+        _policy_name: &'ast Identifier<'ast>,
+    ) {
+    }
+
+    fn visit_create_masking_policy(
+        &mut self,
+        _if_not_exists: bool,
+        _policy_name: &'ast Identifier<'ast>,
+        _parameter: &'ast Identifier<'ast>,
+        _return_type: &'ast TypeName,
+        _definition: &'ast Expr<'ast>,
+    ) {
+    }
+
+    fn visit_drop_masking_policy(
+        &mut self,
+        _if_exists: bool,
+        _policy_name: &'ast Identifier<'ast>,
+    ) {
+    }
+
+    fn visit_create_network_policy(
+        &mut self,
+        _if_not_exists: bool,
+        _policy_name: &'ast Identifier<'ast>,
+        _allowed_ip_list: &'ast [String],
+        _blocked_ip_list: &'ast [String],
+        _comment: &'ast Option<String>,
+    ) {
+    }
+
+    fn visit_drop_network_policy(
+        &mut self,
+        _if_exists: bool,
+        _policy_name: &'ast Identifier<'ast>,
+    ) {
+    }
+
+    fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt<'ast>) {}
+
+    fn visit_drop_task(&mut self, _if_exists: bool, _task_name: &'ast Identifier<'ast>) {}
+
+    fn visit_show_tasks(&mut self) {}
+
+    fn visit_alter_task(
+        &mut self,
+        _task_name: &'ast Identifier<'ast>,
+        _action: &'ast AlterTaskAction,
+    ) {
+    }
+
+    fn visit_create_resource_group(
+        &mut self,
+        _if_not_exists: bool,
+        _group_name: &'ast Identifier<'ast>,
+        _cpu_share: &'ast Option<u64>,
+        _max_memory_usage: &'ast Option<u64>,
+        _max_concurrency: &'ast Option<u64>,
+        _comment: &'ast Option<String>,
+    ) {
+    }
+
+    fn visit_drop_resource_group(&mut self, _if_exists: bool, _group_name: &'ast Identifier<'ast>) {
+    }
+
+    fn visit_create_connection(
+        &mut self,
+        _if_not_exists: bool,
+        _connection_name: &'ast Identifier<'ast>,
+        _storage_type: &'ast str,
+        _storage_params: &'ast BTreeMap<String, String>,
+    ) {
+    }
+
+    fn visit_drop_connection(
+        &mut self,
+        _if_exists: bool,
+        _connection_name: &'ast Identifier<'ast>,
+    ) {
+    }
+
+    fn visit_show_connections(&mut self) {}
+
+    fn visit_describe_connection(&mut self, _connection_name: &'ast Identifier<'ast>) {}
+
+    fn visit_create_sequence(
+        &mut self,
+        _if_not_exists: bool,
+        _sequence_name: &'ast Identifier<'ast>,
+        _start: &'ast Option<i64>,
+        _increment: &'ast Option<i64>,
+        _comment: &'ast Option<String>,
+    ) {
+    }
+
+    fn visit_drop_sequence(&mut self, _if_exists: bool, _sequence_name: &'ast Identifier<'ast>) {}
+
+    fn visit_show_sequences(&mut self) {}
+
+    fn visit_create_plan_hint(
+        &mut self,
+        _if_not_exists: bool,
+        _hint_name: &'ast Identifier<'ast>,
+        _statement: &'ast str,
+        _hints: &'ast str,
+    ) {
+    }
+
+    fn visit_drop_plan_hint(&mut self, _if_exists: bool, _hint_name: &'ast Identifier<'ast>) {}
+
+    fn visit_show_plan_hints(&mut self) {}
+
+    fn visit_drain_node(&mut self, _node_id: &'ast str) {}
+
+    fn visit_create_dictionary(&mut self, _stmt: &'ast CreateDictionaryStmt<'ast>) {}
+
+    fn visit_drop_dictionary(
+        &mut self,
+        _if_exists: bool,
+        _dictionary_name: &'ast Identifier<'ast>,
+    ) {
+    }
+
+    fn visit_show_dictionaries(&mut self) {}
+
     fn visit_create_stage(&mut self, _stmt: &'ast CreateStageStmt) {}
 
     fn visit_show_stages(&mut self) {}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_datavalues::IntervalKind;
 use common_meta_types::PrincipalIdentity;
 use common_meta_types::UserIdentity;
@@ -174,6 +176,15 @@ pub trait VisitorMut: Sized {
         walk_expr_mut(self, expr);
     }
 
+    fn visit_collate(
+        &mut self,
+        _span: &mut &[Token<'_>],
+        expr: &mut Expr<'_>,
+        _collation: &mut String,
+    ) {
+        walk_expr_mut(self, expr);
+    }
+
     fn visit_extract(
         &mut self,
         _span: &mut &[Token<'_>],
@@ -367,6 +378,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_kill(&mut self, _kill_target: &mut KillTarget, _object_id: &mut String) {}
 
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_rollback(&mut self) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -375,8 +392,16 @@ pub trait VisitorMut: Sized {
     ) {
     }
 
+    fn visit_set_user_variable(&mut self, _variable: &mut Identifier<'_>, _value: &mut Expr<'_>) {}
+
+    fn visit_unset_user_variable(&mut self, _variable: &mut Identifier<'_>) {}
+
+    fn visit_execute_immediate(&mut self, _script: &mut String) {}
+
     fn visit_insert(&mut self, _insert: &mut InsertStmt<'_>) {}
 
+    fn visit_replace(&mut self, _replace: &mut ReplaceStmt<'_>) {}
+
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource<'_>) {}
 
     fn visit_delete(
@@ -398,6 +423,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_database(&mut self, _stmt: &mut AlterDatabaseStmt<'_>) {}
 
+    fn visit_backup_database(&mut self, _stmt: &mut BackupDatabaseStmt<'_>) {}
+
+    fn visit_restore_database(&mut self, _stmt: &mut RestoreDatabaseStmt<'_>) {}
+
     fn visit_use_database(&mut self, _database: &mut Identifier<'_>) {}
 
     fn visit_show_tables(&mut self, _stmt: &mut ShowTablesStmt<'_>) {}
@@ -408,6 +437,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_tables_status(&mut self, _stmt: &mut ShowTablesStatusStmt<'_>) {}
 
+    fn visit_show_drop_tables(&mut self, _stmt: &mut ShowDropTablesStmt<'_>) {}
+
     fn visit_create_table(&mut self, _stmt: &mut CreateTableStmt<'_>) {}
 
     fn visit_create_table_source(&mut self, _source: &mut CreateTableSource<'_>) {}
@@ -418,6 +449,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_undrop_table(&mut self, _stmt: &mut UndropTableStmt<'_>) {}
 
+    fn visit_attach_table(&mut self, _stmt: &mut AttachTableStmt<'_>) {}
+
+    fn visit_detach_table(&mut self, _stmt: &mut DetachTableStmt<'_>) {}
+
     fn visit_alter_table(&mut self, _stmt: &mut AlterTableStmt<'_>) {}
 
     fn visit_rename_table(&mut self, _stmt: &mut RenameTableStmt<'_>) {}
@@ -426,6 +461,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_optimize_table(&mut self, _stmt: &mut OptimizeTableStmt<'_>) {}
 
+    fn visit_analyze_table(&mut self, _stmt: &mut AnalyzeTableStmt<'_>) {}
+
     fn visit_exists_table(&mut self, _stmt: &mut ExistsTableStmt<'_>) {}
 
     fn visit_create_view(&mut self, _stmt: &mut CreateViewStmt<'_>) {}
@@ -434,6 +471,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt<'_>) {}
 
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt<'_>) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt<'_>) {}
+
+    fn visit_create_aggregating_index(&mut self, _stmt: &mut CreateAggregatingIndexStmt<'_>) {}
+
+    fn visit_drop_aggregating_index(&mut self, _stmt: &mut DropAggregatingIndexStmt<'_>) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
@@ -448,6 +493,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &mut String) {}
 
+    fn visit_set_role(&mut self, _role_name: &mut String) {}
+
     fn visit_grant(&mut self, _grant: &mut GrantStmt) {}
 
     fn visit_show_grant(&mut self, _principal: &mut Option<PrincipalIdentity>) {}
@@ -475,6 +522,118 @@ pub trait VisitorMut: Sized {
     ) {
     }
 
+    fn visit_create_row_access_policy(
+        &mut self,
+        _if_not_exists: bool,
+        _policy_name: &mut Identifier<'_>,
+        _parameters: &mut [Identifier<'_>],
+        _definition: &mut Expr<'_>,
+    ) {
+    }
+
+    fn visit_drop_row_access_policy(
+        &mut self,
+        _if_exists: bool,
+        _policy_name: &mut Identifier<'_>,
+    ) {
+    }
+
+    fn visit_create_masking_policy(
+        &mut self,
+        _if_not_exists: bool,
+        _policy_name: &mut Identifier<'_>,
+        _parameter: &mut Identifier<'_>,
+        _return_type: &mut TypeName,
+        _definition: &mut Expr<'_>,
+    ) {
+    }
+
+    fn visit_drop_masking_policy(&mut self, _if_exists: bool, _policy_name: &mut Identifier<'_>) {}
+
+    fn visit_create_network_policy(
+        &mut self,
+        _if_not_exists: bool,
+        _policy_name: &mut Identifier<'_>,
+        _allowed_ip_list: &mut Vec<String>,
+        _blocked_ip_list: &mut Vec<String>,
+        _comment: &mut Option<String>,
+    ) {
+    }
+
+    fn visit_drop_network_policy(&mut self, _if_exists: bool, _policy_name: &mut Identifier<'_>) {}
+
+    fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt<'_>) {}
+
+    fn visit_drop_task(&mut self, _if_exists: bool, _task_name: &mut Identifier<'_>) {}
+
+    fn visit_show_tasks(&mut self) {}
+
+    fn visit_alter_task(&mut self, _task_name: &mut Identifier<'_>, _action: &mut AlterTaskAction) {
+    }
+
+    fn visit_create_resource_group(
+        &mut self,
+        _if_not_exists: bool,
+        _group_name: &mut Identifier<'_>,
+        _cpu_share: &mut Option<u64>,
+        _max_memory_usage: &mut Option<u64>,
+        _max_concurrency: &mut Option<u64>,
+        _comment: &mut Option<String>,
+    ) {
+    }
+
+    fn visit_drop_resource_group(&mut self, _if_exists: bool, _group_name: &mut Identifier<'_>) {}
+
+    fn visit_create_connection(
+        &mut self,
+        _if_not_exists: bool,
+        _connection_name: &mut Identifier<'_>,
+        _storage_type: &mut String,
+        _storage_params: &mut BTreeMap<String, String>,
+    ) {
+    }
+
+    fn visit_drop_connection(&mut self, _if_exists: bool, _connection_name: &mut Identifier<'_>) {}
+
+    fn visit_show_connections(&mut self) {}
+
+    fn visit_describe_connection(&mut self, _connection_name: &mut Identifier<'_>) {}
+
+    fn visit_create_sequence(
+        &mut self,
+        _if_not_exists: bool,
+        _sequence_name: &mut Identifier<'_>,
+        _start: &mut Option<i64>,
+        _increment: &mut Option<i64>,
+        _comment: &mut Option<String>,
+    ) {
+    }
+
+    fn visit_drop_sequence(&mut self, _if_exists: bool, _sequence_name: &mut Identifier<'_>) {}
+
+    fn visit_show_sequences(&mut self) {}
+
+    fn visit_create_plan_hint(
+        &mut self,
+        _if_not_exists: bool,
+        _hint_name: &mut Identifier<'_>,
+        _statement: &mut String,
+        _hints: &mut String,
+    ) {
+    }
+
+    fn visit_drop_plan_hint(&mut self, _if_exists: bool, _hint_name: &mut Identifier<'_>) {}
+
+    fn visit_show_plan_hints(&mut self) {}
+
+    fn visit_drain_node(&mut self, _node_id: &mut String) {}
+
+    fn visit_create_dictionary(&mut self, _stmt: &mut CreateDictionaryStmt<'_>) {}
+
+    fn visit_drop_dictionary(&mut self, _if_exists: bool, _dictionary_name: &mut Identifier<'_>) {}
+
+    fn visit_show_dictionaries(&mut self) {}
+
     fn visit_create_stage(&mut self, _stmt: &mut CreateStageStmt) {}
 
     fn visit_show_stages(&mut self) {}
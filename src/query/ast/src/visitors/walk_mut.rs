@@ -67,6 +67,11 @@ pub fn walk_expr_mut<'a, V: VisitorMut>(visitor: &mut V, expr: &mut Expr<'a>) {
             expr,
             target_type,
         } => visitor.visit_try_cast(span, expr, target_type),
+        Expr::Collate {
+            span,
+            expr,
+            collation,
+        } => visitor.visit_collate(span, expr, collation),
         Expr::Extract { span, kind, expr } => visitor.visit_extract(span, kind, expr),
         Expr::Position {
             span,
@@ -295,6 +300,7 @@ pub fn walk_statement_mut<'a, V: VisitorMut>(visitor: &mut V, statement: &mut St
         Statement::Explain { kind, query } => visitor.visit_explain(kind, &mut *query),
         Statement::Query(query) => visitor.visit_query(&mut *query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
+        Statement::Replace(replace) => visitor.visit_replace(replace),
         Statement::Delete {
             table_reference,
             selection,
@@ -310,33 +316,51 @@ pub fn walk_statement_mut<'a, V: VisitorMut>(visitor: &mut V, statement: &mut St
             kill_target,
             object_id,
         } => visitor.visit_kill(kill_target, object_id),
+        Statement::Begin => visitor.visit_begin(),
+        Statement::Commit => visitor.visit_commit(),
+        Statement::Rollback => visitor.visit_rollback(),
         Statement::SetVariable {
             is_global,
             variable,
             value,
         } => visitor.visit_set_variable(*is_global, variable, value),
+        Statement::SetUserVariable { variable, value } => {
+            visitor.visit_set_user_variable(variable, value)
+        }
+        Statement::UnsetUserVariable { variable } => visitor.visit_unset_user_variable(variable),
+        Statement::ExecuteImmediate { script } => visitor.visit_execute_immediate(script),
         Statement::ShowDatabases(stmt) => visitor.visit_show_databases(stmt),
         Statement::ShowCreateDatabase(stmt) => visitor.visit_show_create_databases(stmt),
         Statement::CreateDatabase(stmt) => visitor.visit_create_database(stmt),
         Statement::DropDatabase(stmt) => visitor.visit_drop_database(stmt),
         Statement::UndropDatabase(stmt) => visitor.visit_undrop_database(stmt),
         Statement::AlterDatabase(stmt) => visitor.visit_alter_database(stmt),
+        Statement::BackupDatabase(stmt) => visitor.visit_backup_database(stmt),
+        Statement::RestoreDatabase(stmt) => visitor.visit_restore_database(stmt),
         Statement::UseDatabase { database } => visitor.visit_use_database(database),
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
         Statement::ShowCreateTable(stmt) => visitor.visit_show_create_table(stmt),
         Statement::DescribeTable(stmt) => visitor.visit_describe_table(stmt),
         Statement::ShowTablesStatus(stmt) => visitor.visit_show_tables_status(stmt),
+        Statement::ShowDropTables(stmt) => visitor.visit_show_drop_tables(stmt),
         Statement::CreateTable(stmt) => visitor.visit_create_table(stmt),
         Statement::DropTable(stmt) => visitor.visit_drop_table(stmt),
         Statement::UndropTable(stmt) => visitor.visit_undrop_table(stmt),
+        Statement::AttachTable(stmt) => visitor.visit_attach_table(stmt),
+        Statement::DetachTable(stmt) => visitor.visit_detach_table(stmt),
         Statement::AlterTable(stmt) => visitor.visit_alter_table(stmt),
         Statement::RenameTable(stmt) => visitor.visit_rename_table(stmt),
         Statement::TruncateTable(stmt) => visitor.visit_truncate_table(stmt),
         Statement::OptimizeTable(stmt) => visitor.visit_optimize_table(stmt),
+        Statement::AnalyzeTable(stmt) => visitor.visit_analyze_table(stmt),
         Statement::ExistsTable(stmt) => visitor.visit_exists_table(stmt),
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
+        Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
+        Statement::CreateAggregatingIndex(stmt) => visitor.visit_create_aggregating_index(stmt),
+        Statement::DropAggregatingIndex(stmt) => visitor.visit_drop_aggregating_index(stmt),
         Statement::ShowUsers => visitor.visit_show_users(),
         Statement::ShowRoles => visitor.visit_show_roles(),
         Statement::CreateUser(stmt) => visitor.visit_create_user(stmt),
@@ -350,6 +374,7 @@ pub fn walk_statement_mut<'a, V: VisitorMut>(visitor: &mut V, statement: &mut St
             if_exists,
             role_name,
         } => visitor.visit_drop_role(*if_exists, role_name),
+        Statement::SetRole { role_name } => visitor.visit_set_role(role_name),
         Statement::Grant(stmt) => visitor.visit_grant(stmt),
         Statement::ShowGrants { principal } => visitor.visit_show_grant(principal),
         Statement::Revoke(stmt) => visitor.visit_revoke(stmt),
@@ -376,6 +401,132 @@ pub fn walk_statement_mut<'a, V: VisitorMut>(visitor: &mut V, statement: &mut St
             definition,
             description,
         } => visitor.visit_alter_udf(udf_name, parameters, definition, description),
+        Statement::CreateRowAccessPolicy {
+            if_not_exists,
+            policy_name,
+            parameters,
+            definition,
+        } => visitor.visit_create_row_access_policy(
+            *if_not_exists,
+            policy_name,
+            parameters,
+            definition,
+        ),
+        Statement::DropRowAccessPolicy {
+            if_exists,
+            policy_name,
+        } => visitor.visit_drop_row_access_policy(*if_exists, policy_name),
+        Statement::CreateMaskingPolicy {
+            if_not_exists,
+            policy_name,
+            parameter,
+            return_type,
+            definition,
+        } => visitor.visit_create_masking_policy(
+            *if_not_exists,
+            policy_name,
+            parameter,
+            return_type,
+            definition,
+        ),
+        Statement::DropMaskingPolicy {
+            if_exists,
+            policy_name,
+        } => visitor.visit_drop_masking_policy(*if_exists, policy_name),
+        Statement::CreateNetworkPolicy {
+            if_not_exists,
+            policy_name,
+            allowed_ip_list,
+            blocked_ip_list,
+            comment,
+        } => visitor.visit_create_network_policy(
+            *if_not_exists,
+            policy_name,
+            allowed_ip_list,
+            blocked_ip_list,
+            comment,
+        ),
+        Statement::DropNetworkPolicy {
+            if_exists,
+            policy_name,
+        } => visitor.visit_drop_network_policy(*if_exists, policy_name),
+        Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
+        Statement::DropTask {
+            if_exists,
+            task_name,
+        } => visitor.visit_drop_task(*if_exists, task_name),
+        Statement::ShowTasks => visitor.visit_show_tasks(),
+        Statement::AlterTask { task_name, action } => visitor.visit_alter_task(task_name, action),
+        Statement::CreateResourceGroup {
+            if_not_exists,
+            group_name,
+            cpu_share,
+            max_memory_usage,
+            max_concurrency,
+            comment,
+        } => visitor.visit_create_resource_group(
+            *if_not_exists,
+            group_name,
+            cpu_share,
+            max_memory_usage,
+            max_concurrency,
+            comment,
+        ),
+        Statement::DropResourceGroup {
+            if_exists,
+            group_name,
+        } => visitor.visit_drop_resource_group(*if_exists, group_name),
+        Statement::CreateConnection {
+            if_not_exists,
+            connection_name,
+            storage_type,
+            storage_params,
+        } => visitor.visit_create_connection(
+            *if_not_exists,
+            connection_name,
+            storage_type,
+            storage_params,
+        ),
+        Statement::DropConnection {
+            if_exists,
+            connection_name,
+        } => visitor.visit_drop_connection(*if_exists, connection_name),
+        Statement::ShowConnections => visitor.visit_show_connections(),
+        Statement::DescribeConnection { connection_name } => {
+            visitor.visit_describe_connection(connection_name)
+        }
+        Statement::CreateSequence {
+            if_not_exists,
+            sequence_name,
+            start,
+            increment,
+            comment,
+        } => {
+            visitor.visit_create_sequence(*if_not_exists, sequence_name, start, increment, comment)
+        }
+        Statement::DropSequence {
+            if_exists,
+            sequence_name,
+        } => visitor.visit_drop_sequence(*if_exists, sequence_name),
+        Statement::ShowSequences => visitor.visit_show_sequences(),
+        Statement::CreatePlanHint {
+            if_not_exists,
+            hint_name,
+            statement,
+            hints,
+        } => visitor.visit_create_plan_hint(*if_not_exists, hint_name, statement, hints),
+        Statement::DropPlanHint {
+            if_exists,
+            hint_name,
+        } => visitor.visit_drop_plan_hint(*if_exists, hint_name),
+        Statement::ShowPlanHints => visitor.visit_show_plan_hints(),
+        Statement::DrainNode { node_id } => visitor.visit_drain_node(node_id),
+        Statement::CreateDictionary(stmt) => visitor.visit_create_dictionary(stmt),
+        Statement::DropDictionary {
+            if_exists,
+            dictionary_name,
+        } => visitor.visit_drop_dictionary(*if_exists, dictionary_name),
+        Statement::ShowDictionaries => visitor.visit_show_dictionaries(),
         Statement::ListStage { location, pattern } => visitor.visit_list_stage(location, pattern),
         Statement::ShowStages => visitor.visit_show_stages(),
         Statement::DropStage {
@@ -17,6 +17,7 @@ use std::io::Write;
 use common_ast::parser::expr::*;
 use common_ast::parser::parse_sql;
 use common_ast::parser::query::*;
+use common_ast::parser::split_sql;
 use common_ast::parser::token::*;
 use common_ast::parser::tokenize_sql;
 use common_ast::rule;
@@ -67,19 +68,31 @@ fn test_statement() {
         r#"show databases format TabSeparatedWithNamesAndTypes;"#,
         r#"show tables"#,
         r#"show tables format TabSeparatedWithNamesAndTypes;"#,
+        r#"show drop tables from db like 't%';"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"start transaction;"#,
+        r#"commit work;"#,
+        r#"rollback;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
+        r#"explain analyze select a from b;"#,
+        r#"validate select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
+        r#"describe table extended a.b;"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
         r#"create table if not exists a.b (c integer default 1 not null, b varchar) as select * from t;"#,
         r#"create table if not exists a.b (c tuple(m integer, n string), d tuple(integer, string));"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create external table t (a int, dt string) partition by (dt) location = 's3://bucket/path/' pattern = '.*[.]parquet' file_format = (type = 'parquet');"#,
+        r#"create external table t engine = iceberg location = 's3://bucket/warehouse/db/t/';"#,
+        r#"create external table t engine = delta location = 's3://bucket/lake/t';"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
+        r#"analyze table "a".b;"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
         r#"use "a";"#,
@@ -87,6 +100,7 @@ fn test_statement() {
         r#"create database catalog.t engine = Default;"#,
         r#"create database t engine = Default;"#,
         r#"create database t FROM SHARE a.s;"#,
+        r#"create database t COMMENT = 'the db';"#,
         r#"drop database catalog.t;"#,
         r#"drop database if exists t;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
@@ -96,14 +110,21 @@ fn test_statement() {
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
+        r#"optimize table test_db.test verify;"#,
         r#"DROP table table1;"#,
         r#"DROP table IF EXISTS table1;"#,
         r#"CREATE TABLE t(c1 int null, c2 bigint null, c3 varchar null);"#,
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"CREATE TABLE t(c1 int autoincrement, c2 int);"#,
+        r#"CREATE TEMPORARY TABLE t(c1 int) ENGINE = MEMORY;"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
+        r#"ALTER USER u1 WITH SET NETWORK POLICY = 'p1';"#,
+        r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET RESOURCE GROUP = 'etl';"#,
+        r#"ALTER USER u1 WITH DEFAULT_QUERY_TAG = 'billing';"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
@@ -134,6 +155,7 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"replace into t on (c1) values (1, 2), (3, 4);"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(FORMAT = CSV compression = GZIP record_delimiter=',')"#,
         r#"list @stage_a;"#,
@@ -142,12 +164,63 @@ fn test_statement() {
         r#"alter user 'test-e'@'localhost' identified by 'new-password';"#,
         r#"create role 'test'"#,
         r#"drop role if exists 'test'"#,
+        r#"set role 'test'"#,
+        r#"CREATE ROW ACCESS POLICY IF NOT EXISTS p AS (c) RETURNS BOOLEAN -> c = 'a';"#,
+        r#"DROP ROW ACCESS POLICY p;"#,
+        r#"CREATE MASKING POLICY m AS (v) RETURNS STRING -> '***';"#,
+        r#"DROP MASKING POLICY m;"#,
+        r#"CREATE NETWORK POLICY IF NOT EXISTS p ALLOWED_IP_LIST = ('192.168.0.0/16') BLOCKED_IP_LIST = ('192.168.1.99') COMMENT = 'office';"#,
+        r#"DROP NETWORK POLICY p;"#,
+        r#"CREATE RESOURCE GROUP IF NOT EXISTS etl CPU_SHARE = 50 MAX_MEMORY_USAGE = 1073741824 MAX_CONCURRENCY = 4 COMMENT = 'the etl workload';"#,
+        r#"DROP RESOURCE GROUP etl;"#,
+        r#"ALTER TASK query_log_flush SUSPEND;"#,
+        r#"ALTER TASK query_log_flush EXECUTE;"#,
+        r#"CREATE TASK IF NOT EXISTS t1 SCHEDULE = '*/5 * * * *' COMMENT = 'the t1' AS DELETE FROM t;"#,
+        r#"CREATE TASK t2 AFTER t1 AS DELETE FROM t;"#,
+        r#"DROP TASK IF EXISTS t1;"#,
+        r#"SHOW TASKS;"#,
+        r#"SET VARIABLE @x = $y;"#,
+        r#"UNSET VARIABLE x;"#,
+        r#"EXECUTE IMMEDIATE $$ BEGIN LET x := 1; RETURN x; END $$;"#,
+        r#"CREATE CONNECTION IF NOT EXISTS my_s3 STORAGE_TYPE = 's3' access_key_id = 'minioadmin' secret_access_key = 'minioadmin' endpoint_url = 'http://127.0.0.1:9900';"#,
+        r#"DROP CONNECTION IF EXISTS my_s3;"#,
+        r#"SHOW CONNECTIONS;"#,
+        r#"DESC CONNECTION my_s3;"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq START WITH 10 INCREMENT BY -2 COMMENT = 'the seq';"#,
+        r#"DROP SEQUENCE IF EXISTS seq;"#,
+        r#"SHOW SEQUENCES;"#,
+        r#"CREATE PLAN HINT IF NOT EXISTS slow_join FOR $$SELECT * FROM t1, t2 WHERE t1.a = t2.a AND t1.b = 'x'$$ USING 'BROADCAST(t2)';"#,
+        r#"DROP PLAN HINT IF EXISTS slow_join;"#,
+        r#"SHOW PLAN HINTS;"#,
+        r#"ALTER CLUSTER DRAIN NODE 'query-node-1';"#,
+        r#"CREATE DICTIONARY IF NOT EXISTS users (id UInt64, country VARCHAR NULL) PRIMARY KEY id SOURCE = (type = 'mysql' url = 'mysql://root@127.0.0.1:3306/db' table = 'users') LIFETIME = 300 COMMENT = 'the users';"#,
+        r#"CREATE DICTIONARY d (k STRING, v Int32) PRIMARY KEY k SOURCE = (type = 'file' path = '/tmp/d.csv' format = 'csv');"#,
+        r#"DROP DICTIONARY IF EXISTS users;"#,
+        r#"SHOW DICTIONARIES;"#,
+        r#"CREATE AGGREGATING INDEX idx ON t AS SELECT a, sum(b) FROM t GROUP BY a;"#,
+        r#"DROP AGGREGATING INDEX IF EXISTS idx ON db.t;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
+        r#"ALTER TABLE t SET DATA_RETENTION_TIME_IN_DAYS = 7;"#,
+        r#"ALTER TABLE t COMMENT = 'the t';"#,
+        r#"ALTER TABLE t ADD COLUMN c int DEFAULT 1;"#,
+        r#"ALTER TABLE t ADD VIRTUAL COLUMN v AS c:k1.k2::STRING;"#,
+        r#"ALTER TABLE t DROP VIRTUAL COLUMN v;"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY p ON (c1, c2);"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY;"#,
+        r#"ALTER TABLE t MODIFY COLUMN c SET MASKING POLICY m;"#,
+        r#"ALTER TABLE t MODIFY COLUMN c UNSET MASKING POLICY;"#,
+        r#"ALTER TABLE t MODIFY COLUMN c COMMENT 'the c';"#,
+        r#"ALTER TABLE t SWAP WITH t1;"#,
+        r#"ATTACH TABLE t 's3://bucket/root/1/2/' READ_ONLY;"#,
+        r#"DETACH TABLE IF EXISTS db.t;"#,
         r#"ALTER DATABASE IF EXISTS catalog.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
+        r#"ALTER DATABASE c COMMENT = 'the db';"#,
         r#"ALTER DATABASE catalog.c RENAME TO a;"#,
+        r#"BACKUP DATABASE db TO 's3://bucket/backup/';"#,
+        r#"RESTORE DATABASE catalog.db FROM 's3://bucket/backup/';"#,
         r#"CREATE TABLE t (a INT COMMENT 'col comment') COMMENT='table comment';"#,
         r#"GRANT SELECT, CREATE ON * TO 'test-grant'@'localhost';"#,
         r#"GRANT SELECT, CREATE ON *.* TO 'test-grant'@'localhost';"#,
@@ -167,6 +240,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO USER 'test-grant'@'localhost';"#,
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
+        r#"GRANT SELECT ON FUTURE TABLES IN DATABASE db01 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
@@ -261,6 +335,13 @@ fn test_statement() {
                     skip_header = 1
                 )
                 force=true;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                PATTERN = '.*[.]csv'
+                FILE_FORMAT = (
+                    type = 'CSV'
+                )
+                ON_ERROR = CONTINUE;"#,
         // We used to support COPY FROM a quoted at string
         // r#"COPY INTO mytable
         //         FROM '@external_stage/path/to/file.csv'
@@ -363,6 +444,26 @@ fn test_statement_error() {
     }
 }
 
+#[test]
+fn test_split_sql() {
+    assert_eq!(split_sql("").unwrap(), Vec::<&str>::new());
+    assert_eq!(split_sql(" ; ;\n").unwrap(), Vec::<&str>::new());
+    assert_eq!(split_sql("select 1").unwrap(), vec!["select 1"]);
+    assert_eq!(
+        split_sql("create table t(a int);\ninsert into t values (1) ;select 'a;b' -- c;\n")
+            .unwrap(),
+        vec![
+            "create table t(a int)",
+            "insert into t values (1)",
+            "select 'a;b'"
+        ]
+    );
+    assert_eq!(
+        split_sql("select \"a;\" from t; /* ; */ select 2;").unwrap(),
+        vec!["select \"a;\" from t", "select 2"]
+    );
+}
+
 #[test]
 fn test_query() {
     let mut mint = Mint::new("tests/it/testdata");
@@ -402,6 +503,9 @@ fn test_query() {
         r#"select * from t1 union select * from t2 intersect select * from t3"#,
         r#"(select * from t1 union select * from t2) union select * from t3"#,
         r#"select * from t1 union (select * from t2 union select * from t3)"#,
+        r#"select /*+ BROADCAST(b) */ * from a"#,
+        r#"select /*+ MAX_EXECUTION_TIME(1000) */ * from a"#,
+        r#"select /*+ SET_VAR(max_threads = 4) */ * from a"#,
     ];
 
     for case in cases {
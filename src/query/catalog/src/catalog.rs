@@ -18,29 +18,43 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::AcquireTableLockReply;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::CommitTableReply;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::ReleaseTableLockReply;
+use common_meta_app::schema::ReleaseTableLockReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SwapTableReply;
+use common_meta_app::schema::SwapTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistory;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -108,6 +122,11 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply>;
 
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply>;
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -116,6 +135,13 @@ pub trait Catalog: DynClone + Send + Sync {
     // Get the table meta by meta id.
     async fn get_table_meta_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)>;
 
+    // Get the versions of the schema and the options of the table by meta id.
+    async fn get_table_meta_history(&self, _table_id: MetaId) -> Result<TableMetaHistory> {
+        Err(ErrorCode::UnImplement(
+            "'get_table_meta_history' not implemented",
+        ))
+    }
+
     // Get one table by db and table name.
     async fn get_table(
         &self,
@@ -135,6 +161,25 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
 
+    // Create a table that is invisible by its name until it is committed.
+    async fn create_staged_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
+        Err(ErrorCode::UnImplement(
+            "'create_staged_table' not implemented",
+        ))
+    }
+
+    // Make a staged table visible by its name.
+    async fn commit_staged_table(&self, _req: CommitTableReq) -> Result<CommitTableReply> {
+        Err(ErrorCode::UnImplement(
+            "'commit_staged_table' not implemented",
+        ))
+    }
+
+    // Swap the names of two tables of a database.
+    async fn swap_table(&self, _req: SwapTableReq) -> Result<SwapTableReply> {
+        Err(ErrorCode::UnImplement("'swap_table' not implemented"))
+    }
+
     // Check a db.table is exists or not.
     async fn exists_table(&self, tenant: &str, db_name: &str, table_name: &str) -> Result<bool> {
         match self.get_table(tenant, db_name, table_name).await {
@@ -163,6 +208,16 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply>;
 
+    // Update the metas of the tables at once, none of them is updated if one of them fails.
+    async fn update_multi_table_meta(
+        &self,
+        _req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::UnImplement(
+            "'update_multi_table_meta' not implemented",
+        ))
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     async fn get_table_copied_file_info(
@@ -186,6 +241,20 @@ pub trait Catalog: DynClone + Send + Sync {
         req: TruncateTableReq,
     ) -> Result<TruncateTableReply>;
 
+    // Acquire the lock of a table for a mutation, or extend it if held by the same holder.
+    async fn acquire_table_lock(&self, _req: AcquireTableLockReq) -> Result<AcquireTableLockReply> {
+        Err(ErrorCode::UnImplement(
+            "'acquire_table_lock' not implemented",
+        ))
+    }
+
+    // Release the lock of a table if it is still held by the holder.
+    async fn release_table_lock(&self, _req: ReleaseTableLockReq) -> Result<ReleaseTableLockReply> {
+        Err(ErrorCode::UnImplement(
+            "'release_table_lock' not implemented",
+        ))
+    }
+
     /// Table function
 
     // Get function by name.
@@ -18,7 +18,10 @@ use common_meta_types::NodeInfo;
 
 pub struct Cluster {
     pub local_id: String,
+    /// The nodes the fragments are scheduled to, the local node is always one of them.
     pub nodes: Vec<Arc<NodeInfo>>,
+    /// The other draining nodes, which run the fragments scheduled before only.
+    pub draining_nodes: Vec<Arc<NodeInfo>>,
 }
 
 impl Cluster {
@@ -21,4 +21,6 @@ pub mod table;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod table_lock;
 pub mod table_mutator;
+pub mod txn;
@@ -14,13 +14,17 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
 use common_datablocks::DataBlock;
 use common_datavalues::chrono;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_legacy_expression::LegacyExpression;
@@ -112,6 +116,49 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Adds a column at the end of the schema. The values of the new column in the existing
+    /// rows are made by `fill` of the blocks of them, e.g. by the default expression.
+    async fn add_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        field: DataField,
+        comment: String,
+        fill: ColumnFiller,
+    ) -> Result<()> {
+        let (_, _, _, _) = (ctx, field, comment, fill);
+
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support ADD COLUMN",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    /// Adds a virtual column of the expression over the paths of a variant column, the
+    /// statistics of the virtual column are collected while the blocks are written.
+    async fn add_virtual_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column: String,
+        expr: String,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, column, expr);
+
+        Err(ErrorCode::UnsupportedEngineParams(format!(
+            "Unsupported virtual columns for engine: {}",
+            self.engine()
+        )))
+    }
+
+    async fn drop_virtual_column(&self, ctx: Arc<dyn TableContext>, column: String) -> Result<()> {
+        let (_, _) = (ctx, column);
+
+        Err(ErrorCode::UnsupportedEngineParams(format!(
+            "Unsupported virtual columns for engine: {}",
+            self.engine()
+        )))
+    }
+
     // defaults to generate one single part and empty statistics
     async fn read_partitions(
         &self,
@@ -168,6 +215,23 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    /// Commits the rows appended as `commit_insertion()`, and deletes the rows of the table with
+    /// the same values of `on_conflict_fields` as them in the same commit, for `REPLACE INTO`.
+    async fn commit_replace(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        on_conflict_fields: &[String],
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, operations, on_conflict_fields);
+
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support REPLACE INTO",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     async fn truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
         let (_, _) = (ctx, purge);
 
@@ -183,12 +247,70 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    /// Scans the objects of the table for corruptions, returns the keys of the corrupted
+    /// objects with the causes.
+    async fn verify(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<(String, String)>> {
+        let _ = ctx;
+
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support OPTIMIZE TABLE VERIFY",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     async fn statistics(&self, ctx: Arc<dyn TableContext>) -> Result<Option<TableStatistics>> {
         let _ = ctx;
 
         Ok(None)
     }
 
+    /// The statistics of the columns, keyed by the indices of the fields in the schema.
+    async fn column_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<HashMap<usize, TableColumnStatistics>> {
+        let _ = ctx;
+
+        Ok(HashMap::new())
+    }
+
+    /// The sizes of the columns in the storage, keyed by the indices of the fields in the schema.
+    async fn column_sizes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<HashMap<usize, TableColumnSize>> {
+        let _ = ctx;
+
+        Ok(HashMap::new())
+    }
+
+    /// The compression of the data in the storage, e.g. `zstd(9)`.
+    fn compression(&self) -> Option<String> {
+        None
+    }
+
+    /// The compressions of the columns in the storage, keyed by the indices of the fields in the
+    /// schema.
+    fn column_compressions(&self) -> HashMap<usize, String> {
+        HashMap::new()
+    }
+
+    /// The id of the current snapshot, if the engine keeps the snapshots of the table.
+    fn snapshot_id(&self) -> Option<String> {
+        None
+    }
+
+    async fn analyze(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let _ = ctx;
+
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support ANALYZE TABLE",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     async fn navigate_to(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -243,6 +365,9 @@ pub trait Table: Sync + Send {
     }
 }
 
+/// Makes the values of a column added to a table in the rows of a block of the table.
+pub type ColumnFiller = Arc<dyn Fn(&DataBlock) -> Result<ColumnRef> + Send + Sync>;
+
 #[derive(Debug)]
 pub enum NavigationPoint {
     SnapshotID(String),
@@ -256,3 +381,23 @@ pub struct TableStatistics {
     pub data_size_compressed: Option<u64>,
     pub index_size: Option<u64>,
 }
+
+#[derive(Debug, Clone)]
+pub struct TableColumnStatistics {
+    pub min: DataValue,
+    pub max: DataValue,
+    pub null_count: u64,
+    pub number_of_distinct_values: Option<u64>,
+    /// The upper bounds and the number of values of the buckets of an equi-height
+    /// histogram of the non-null values, ordered by the upper bounds.
+    pub histogram: Option<Vec<(DataValue, u64)>>,
+}
+
+/// The sizes of a column summed over the blocks of the table.
+#[derive(Debug, Clone, Default)]
+pub struct TableColumnSize {
+    /// The size of the values in memory, before the compression.
+    pub uncompressed_size: u64,
+    /// The size of the values in the storage, after the compression.
+    pub compressed_size: u64,
+}
@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
@@ -21,6 +22,8 @@ use common_config::Config;
 use common_contexts::DalContext;
 use common_contexts::DalMetrics;
 use common_datablocks::DataBlock;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_exception::Result;
 use common_functions::scalars::FunctionContext;
 use common_io::prelude::FormatSettings;
@@ -33,6 +36,7 @@ use opendal::Operator;
 use crate::catalog::Catalog;
 use crate::cluster_info::Cluster;
 use crate::table::Table;
+use crate::txn::Transaction;
 
 pub struct ProcessInfo {
     pub id: String,
@@ -46,9 +50,52 @@ pub struct ProcessInfo {
     pub memory_usage: i64,
     pub dal_metrics: Option<DalMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    /// The estimated total of the scan, from the statistics of the partitions to read.
+    pub scan_progress_total_value: Option<ProgressValues>,
+    pub elapsed: Option<Duration>,
+    /// The processors being executed by the executor of the query.
+    pub pipeline_stage: Option<String>,
+    /// The tag of the running query, or else of the queries of the session.
+    pub query_tag: String,
     pub mysql_connection_id: Option<u32>,
 }
 
+pub struct QueryCacheInfo {
+    pub query_id: String,
+    pub query: String,
+    pub num_rows: u64,
+    pub result_bytes: u64,
+    pub hits: u64,
+    /// The microseconds since the epoch the result is cached on.
+    pub created_on: i64,
+    /// The microseconds since the epoch the result expires on.
+    pub expires_on: i64,
+}
+
+pub struct BackgroundTaskInfo {
+    pub name: String,
+    pub kind: String,
+    /// One of `Running`, `Scheduled` and `Suspended`.
+    pub state: String,
+    pub interval_secs: u64,
+    pub runs: u64,
+    pub failures: u64,
+    /// The microseconds since the epoch the last run started on, `None` if it never runs.
+    pub last_run_on: Option<i64>,
+    pub last_run_duration_ms: Option<u64>,
+    /// The summary of the last run, e.g. the rows it writes, or the error it fails with.
+    pub last_result: Option<String>,
+    /// The microseconds since the epoch the next run is scheduled on, `None` if it's suspended.
+    pub next_run_on: Option<i64>,
+    /// The work waiting for the next run, e.g. the buffered rows.
+    pub progress: String,
+}
+
+pub struct LoadedDictionaryInfo {
+    pub name: String,
+    pub num_rows: u64,
+}
+
 #[async_trait::async_trait]
 pub trait TableContext: Send + Sync {
     /// Build a table instance the plan wants to operate on.
@@ -76,6 +123,7 @@ pub trait TableContext: Send + Sync {
     fn get_config(&self) -> Config;
     fn get_current_user(&self) -> Result<UserInfo>;
     fn set_current_user(&self, user: UserInfo);
+    fn get_current_role(&self) -> Option<String>;
     fn get_fuse_version(&self) -> String;
     fn get_changed_settings(&self) -> Arc<Settings>;
     fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()>;
@@ -87,6 +135,8 @@ pub trait TableContext: Send + Sync {
     fn get_query_str(&self) -> String;
     /// Get the kind of session running query.
     fn get_query_kind(&self) -> String;
+    /// The root span of the query, the spans of the stages of the query are its children.
+    fn get_query_span(&self) -> tracing::Span;
     // Get the storage data accessor operator from the session manager.
     fn get_storage_operator(&self) -> Result<Operator>;
     fn get_dal_context(&self) -> &DalContext;
@@ -94,9 +144,41 @@ pub trait TableContext: Send + Sync {
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
     fn try_get_function_context(&self) -> Result<FunctionContext>;
     fn get_connection_id(&self) -> String;
+    /// The value of the variable of the running script, or of the session variable set by
+    /// `SET VARIABLE`, `None` if it's not set.
+    fn get_variable(&self, name: &str) -> Option<(DataValue, DataTypeImpl)>;
     fn get_settings(&self) -> Arc<Settings>;
+    /// Set a setting for the query only, e.g. by the `SET_VAR` hint, the settings of the session
+    /// are left as they are.
+    fn set_query_setting(&self, name: String, value: String) -> Result<()>;
+    /// The explicit transaction of the session, the tables mutated in it are staged in it.
+    fn get_transaction(&self) -> Option<Arc<Transaction>>;
+    /// Set the max execution time of the query, overriding the max_execute_time of the settings.
+    fn set_query_max_execution_time(&self, max_execution_time: Duration);
+    /// Get the max execution time of the query, if it overrides the one of the settings.
+    fn get_query_max_execution_time(&self) -> Option<Duration>;
+    /// Mark the result of the query not cacheable, e.g. it calls the non-deterministic functions.
+    fn set_cacheable(&self, cacheable: bool);
+    fn get_cacheable(&self) -> bool;
     fn get_cluster(&self) -> Arc<Cluster>;
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
+    /// Get the table read by the query. All the tables read by the query are read as of the same
+    /// point in time, so a concurrent insert is not seen by a part of the query only.
+    async fn get_read_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>>;
+    /// Forget the tables read by the former statement planned in the context, the next statement
+    /// reads the tables as of a new point in time.
+    fn reset_read_tables(&self);
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    /// The query results cached for the tenant.
+    fn get_query_cache_info(&self) -> Vec<QueryCacheInfo>;
+    /// The background tasks of the node.
+    fn get_background_tasks_info(&self) -> Vec<BackgroundTaskInfo>;
+    /// The dictionaries of the tenant loaded on the node.
+    fn get_loaded_dictionaries_info(&self) -> Vec<LoadedDictionaryInfo>;
 }
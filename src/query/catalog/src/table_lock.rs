@@ -0,0 +1,139 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::ReleaseTableLockReq;
+use tracing::warn;
+
+use crate::catalog::Catalog;
+
+/// The lock of a table held by a mutation, e.g. a DELETE or an OPTIMIZE, so that the
+/// concurrent mutations of the same table fail fast with `TableLockConflict`, rather than
+/// overwrite the snapshot committed by each other.
+///
+/// The lease of the lock is extended in the background while the guard is alive, and the lock
+/// is released once the guard is dropped. The lock of a crashed query expires with its lease.
+pub struct TableLockGuard {
+    catalog: Arc<dyn Catalog>,
+    table_id: u64,
+    holder: String,
+    heartbeat: Option<JoinHandle<()>>,
+}
+
+impl TableLockGuard {
+    /// Acquires the lock of the table, returns None if the catalog keeps no locks, e.g. the
+    /// catalogs of the external tables.
+    pub async fn try_lock(
+        catalog: Arc<dyn Catalog>,
+        table_id: u64,
+        holder: &str,
+        operation: &str,
+        lease: Duration,
+    ) -> Result<Option<TableLockGuard>> {
+        let lease = lease.max(Duration::from_secs(1));
+        let req = AcquireTableLockReq {
+            table_id,
+            holder: holder.to_string(),
+            operation: operation.to_string(),
+            expire_at: expire_at(lease),
+        };
+        match catalog.acquire_table_lock(req.clone()).await {
+            Ok(_) => {}
+            Err(cause) if cause.code() == ErrorCode::UnImplement("").code() => return Ok(None),
+            Err(cause) => return Err(cause),
+        }
+
+        let heartbeat = {
+            let catalog = catalog.clone();
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(lease / 3).await;
+                    let req = AcquireTableLockReq {
+                        expire_at: expire_at(lease),
+                        ..req.clone()
+                    };
+                    if let Err(cause) = catalog.acquire_table_lock(req).await {
+                        warn!("failed to extend the lock of table {}: {}", table_id, cause);
+                        return;
+                    }
+                }
+            })
+        };
+
+        Ok(Some(TableLockGuard {
+            catalog,
+            table_id,
+            holder: holder.to_string(),
+            heartbeat: Some(heartbeat),
+        }))
+    }
+
+    /// Releases the lock at once, rather than in the background once dropped.
+    pub async fn release(mut self) -> Result<()> {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+        self.catalog.release_table_lock(self.release_req()).await?;
+        Ok(())
+    }
+
+    fn release_req(&self) -> ReleaseTableLockReq {
+        ReleaseTableLockReq {
+            table_id: self.table_id,
+            holder: self.holder.clone(),
+        }
+    }
+}
+
+impl Drop for TableLockGuard {
+    fn drop(&mut self) {
+        let heartbeat = match self.heartbeat.take() {
+            None => return,
+            Some(heartbeat) => heartbeat,
+        };
+        heartbeat.abort();
+
+        // The lock expires with its lease if it can not be released here.
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let catalog = self.catalog.clone();
+            let req = self.release_req();
+            let table_id = self.table_id;
+            handle.spawn(async move {
+                if let Err(cause) = catalog.release_table_lock(req).await {
+                    warn!(
+                        "failed to release the lock of table {}: {}",
+                        table_id, cause
+                    );
+                }
+            });
+        }
+    }
+}
+
+// The expiry of the lock in seconds since the epoch, as the meta-service expects.
+fn expire_at(lease: Duration) -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    (now + lease).as_secs()
+}
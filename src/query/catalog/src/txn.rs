@@ -0,0 +1,115 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use parking_lot::RwLock;
+
+use crate::catalog::Catalog;
+use crate::table::Table;
+
+/// The tables mutated by an explicit transaction of a session, from `BEGIN` to `COMMIT` or
+/// `ROLLBACK`.
+///
+/// The new snapshot of a mutated table is written to the storage as usual, but the meta pointing
+/// to it is staged here rather than committed to the meta service. The statements of the
+/// transaction read the staged tables, and the metas of all of them are committed at once by
+/// `COMMIT`, which fails if one of the tables is changed by the others since the transaction
+/// mutated it.
+#[derive(Default)]
+pub struct Transaction {
+    // The catalog of the staged tables, a transaction mutates the tables of one catalog only.
+    catalog: RwLock<Option<String>>,
+    // The staged tables by their ids, of the new metas and the versions they are mutated from.
+    tables: RwLock<BTreeMap<u64, TableInfo>>,
+}
+
+impl Transaction {
+    /// Stages the new meta of a table, the table keeps the version it is first mutated from, so
+    /// that the changes of the others since then are detected by the commit.
+    pub fn stage_table(&self, catalog: &str, table_info: TableInfo) -> Result<()> {
+        {
+            let mut txn_catalog = self.catalog.write();
+            match txn_catalog.as_deref() {
+                None => *txn_catalog = Some(catalog.to_string()),
+                Some(txn_catalog) if txn_catalog == catalog => {}
+                Some(txn_catalog) => {
+                    return Err(ErrorCode::UnImplement(format!(
+                        "a transaction can not mutate the tables of catalog {} and {}",
+                        txn_catalog, catalog
+                    )));
+                }
+            }
+        }
+
+        let mut tables = self.tables.write();
+        let table_id = table_info.ident.table_id;
+        match tables.get_mut(&table_id) {
+            Some(staged) => staged.meta = table_info.meta,
+            None => {
+                tables.insert(table_id, table_info);
+            }
+        }
+        Ok(())
+    }
+
+    /// The table staged by the transaction, None if the table is not mutated by it.
+    pub fn get_staged_table(&self, catalog: &str, table_id: u64) -> Option<TableInfo> {
+        if self.catalog.read().as_deref() != Some(catalog) {
+            return None;
+        }
+        self.tables.read().get(&table_id).cloned()
+    }
+
+    /// The table staged by the transaction in place of the table read from the catalog, if it's
+    /// mutated by the transaction.
+    pub fn resolve_table(
+        &self,
+        catalog: &dyn Catalog,
+        table: Arc<dyn Table>,
+    ) -> Result<Arc<dyn Table>> {
+        let table_info = table.get_table_info();
+        match self.get_staged_table(table_info.catalog(), table_info.ident.table_id) {
+            Some(staged) => catalog.get_table_by_info(&staged),
+            None => Ok(table),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.read().is_empty()
+    }
+
+    /// The catalog of the staged tables and the request to commit them.
+    pub fn commit_request(&self) -> Option<(String, UpdateMultiTableMetaReq)> {
+        let catalog = self.catalog.read().clone()?;
+        let update_table_metas = self
+            .tables
+            .read()
+            .values()
+            .map(|table_info| UpdateTableMetaReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta: table_info.meta.clone(),
+            })
+            .collect();
+        Some((catalog, UpdateMultiTableMetaReq { update_table_metas }))
+    }
+}
@@ -79,6 +79,15 @@ impl Config {
         !self.query.rpc_tls_server_key.is_empty() && !self.query.rpc_tls_server_cert.is_empty()
     }
 
+    pub fn tls_mysql_server_enabled(&self) -> bool {
+        !self.query.mysql_tls_server_key.is_empty() && !self.query.mysql_tls_server_cert.is_empty()
+    }
+
+    pub fn tls_postgres_server_enabled(&self) -> bool {
+        !self.query.postgres_tls_server_key.is_empty()
+            && !self.query.postgres_tls_server_cert.is_empty()
+    }
+
     /// Transform config into the outer style.
     ///
     /// This function should only be used for end-users.
@@ -99,21 +108,48 @@ pub struct QueryConfig {
     pub tenant_id: String,
     /// ID for construct the cluster.
     pub cluster_id: String,
+    /// The node leaves the cluster if no heartbeat is received in the timeout.
+    pub cluster_heartbeat_timeout_secs: u64,
     pub num_cpus: u64,
     pub mysql_handler_host: String,
     pub mysql_handler_port: u16,
+    /// Offer the zlib and zstd compression of the MySQL protocol to the clients.
+    pub mysql_handler_compression_enabled: bool,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
+    /// The certificate and the private key of the PostgreSQL handler, the password of the
+    /// users is only asked for on the SSL connections.
+    pub postgres_tls_server_cert: String,
+    pub postgres_tls_server_key: String,
+    pub flight_sql_handler_host: String,
+    pub flight_sql_handler_port: u16,
     pub max_active_sessions: u64,
+    /// The sessions idle for the seconds are closed, 0 to keep them.
+    pub session_idle_timeout_secs: u64,
+    /// The sessions are closed the seconds after they are created, along with their running
+    /// queries, 0 to keep them.
+    pub session_max_lifetime_secs: u64,
+    /// The sessions of a user at the same time, 0 is unlimited.
+    pub max_connections_per_user: u64,
     pub clickhouse_http_handler_host: String,
     pub clickhouse_http_handler_port: u16,
     pub http_handler_host: String,
     pub http_handler_port: u16,
     pub http_handler_result_timeout_millis: u64,
     pub flight_api_address: String,
+    /// The compression of the data exchanged between the cluster nodes, `none`, `lz4` or `zstd`.
+    pub flight_exchange_compression: String,
+    /// The credits of the data packets buffered for each exchange channel, 0 is unlimited.
+    pub flight_exchange_credits: u64,
     pub admin_api_address: String,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
     pub http_handler_tls_server_key: String,
     pub http_handler_tls_server_root_ca_cert: String,
+    pub mysql_tls_server_cert: String,
+    pub mysql_tls_server_key: String,
+    /// The CA of the certificates of the MySQL clients, which are verified if it is set.
+    pub mysql_tls_server_root_ca_cert: String,
     pub api_tls_server_cert: String,
     pub api_tls_server_key: String,
     pub api_tls_server_root_ca_cert: String,
@@ -121,9 +157,14 @@ pub struct QueryConfig {
     pub rpc_tls_server_cert: String,
     /// key for rpc server cert
     pub rpc_tls_server_key: String,
+    /// The CA of the certificates of the rpc clients, which are verified if it is set
+    pub rpc_tls_server_root_ca_cert: String,
     /// Certificate for client to identify query rpc server
     pub rpc_tls_query_server_root_ca_cert: String,
     pub rpc_tls_query_service_domain_name: String,
+    /// The cert and the key the client presents to the query rpc server
+    pub rpc_tls_query_client_cert: String,
+    pub rpc_tls_query_client_key: String,
     /// Table engine memory enabled
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
@@ -136,18 +177,100 @@ pub struct QueryConfig {
     pub table_cache_segment_count: u64,
     /// Max number of cached table block meta
     pub table_cache_block_meta_count: u64,
-    /// Table memory cache size (mb)
+    /// Table memory cache size (mb) of block column data, 0 to disable
     pub table_memory_cache_mb_size: u64,
     /// Table disk cache folder root
     pub table_disk_cache_root: String,
-    /// Table disk cache size (mb)
+    /// Table disk cache size (mb) of block column data, 0 to disable, the default
     pub table_disk_cache_mb_size: u64,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
+    /// If in read only mode, only the queries reading the data are served, e.g. by the replicas
+    /// scaling out the reads.
+    pub read_only: bool,
+    /// The url of the JWKS to verify the JWT of the users.
     pub jwt_key_file: String,
+    /// The interval in seconds to refresh the keys of the JWKS.
+    pub jwt_key_refresh_interval_secs: u64,
+    /// The claim of the JWT taken as the user name.
+    pub jwt_user_claim: String,
+    /// The claim of the JWT taken as the role of the session.
+    pub jwt_role_claim: String,
+    /// The url of the LDAP server to verify the passwords of the users.
+    pub ldap_url: String,
+    /// The DN template to bind as the user, `{user}` is replaced by the user name, empty to search the DN of the user.
+    pub ldap_bind_dn_template: String,
+    /// The DN to bind as to search the DN of the user, empty to search anonymously.
+    pub ldap_search_bind_dn: String,
+    /// The password of the DN to bind as to search the DN of the user.
+    pub ldap_search_bind_password: String,
+    /// The base DN to search the DN of the user.
+    pub ldap_search_base: String,
+    /// The filter to search the DN of the user, `{user}` is replaced by the user name.
+    pub ldap_search_filter: String,
+    /// The attribute of the user entry listing the groups of the user.
+    pub ldap_group_attribute: String,
+    /// The roles granted to the members of the groups, in the form of `<group>:<role>;<group>:<role>`.
+    pub ldap_group_role_mapping: String,
+    /// Record the authentications and the statements in `system.audit_log`.
+    pub audit_log_enabled: bool,
+    /// The comma separated categories of the events recorded in the audit log, of `auth`, `ddl`, `dml` and `privilege`.
+    pub audit_log_filter: String,
+    /// The maximum number of the events kept in the audit log.
+    pub audit_log_max_rows: usize,
+    /// The seconds the events are kept in the audit log, 0 to keep them until the log is full.
+    pub audit_log_retention_secs: u64,
+    /// The key encrypting the secret parameters of the connections.
+    pub connection_encryption_key: String,
+    /// The url of the KMS serving the key encrypting the secret parameters of the connections, instead of `connection_encryption_key`.
+    pub connection_kms_url: String,
+    /// The bearer token of the requests to the KMS.
+    pub connection_kms_token: String,
+    /// The local directory the large sorts spill to beyond the max_memory_usage, the temp directory if it's empty.
+    pub spill_local_disk_path: String,
+    /// The maximum bytes of the files spilled by a query, no limit if it's zero.
+    pub spill_query_quota_bytes: u64,
+    /// Encrypt the spilled files by AES with the key generated by the process.
+    pub spill_encryption_enabled: bool,
+    /// The maximum number of the queries running at the same time, no limit if it's zero.
+    pub max_running_queries: u64,
+    /// The maximum memory reserved by the running queries in bytes, each query reserves its max_memory_usage, no limit if it's zero.
+    pub max_reserved_memory_usage: u64,
+    /// The maximum number of the queries waiting to run, the excess queries are rejected.
+    pub max_queued_queries: u64,
+    /// The seconds a query waits to run before being rejected.
+    pub query_queue_timeout_secs: u64,
+    /// The maximum memory of the cached query results in bytes.
+    pub query_result_cache_max_bytes: u64,
+    /// The maximum number of the cached query plans.
+    pub plan_cache_max_entries: u64,
+    /// The maximum number of the concurrent requests to each storage backend, no limit if it's zero.
+    pub storage_io_max_requests: u64,
+    /// The adjacent ranges of an object whose gap is at most this size in bytes are read by one request.
+    pub storage_io_coalesce_gap_bytes: u64,
+    /// Persist the query log into the `system_history.query_log` fuse table, read by `system.query_log`.
+    pub query_log_persistent_enabled: bool,
+    /// The seconds the query log events are buffered before being written to the persistent table.
+    pub query_log_flush_interval_secs: u64,
+    /// The number of the buffered query log events written to the persistent table at once, before the interval.
+    pub query_log_flush_batch_rows: u64,
     pub async_insert_max_data_size: u64,
+    pub async_insert_max_rows: u64,
     pub async_insert_busy_timeout: u64,
     pub async_insert_stale_timeout: u64,
+    /// Compact the fragmented tables in the background, by a node of the cluster at a time.
+    pub auto_compaction_enabled: bool,
+    /// The seconds between the checks of the tables for the fragmentation.
+    pub auto_compaction_interval_secs: u64,
+    /// The excess blocks a table has at least to be compacted, see `CompactionEstimate`.
+    pub auto_compaction_min_excess_blocks: u64,
+    /// The compressed bytes the compactions of a check read and write at most, the first
+    /// compaction of a check runs even if it exceeds the budget.
+    pub auto_compaction_max_bytes_per_run: u64,
+    /// The threads of a compaction, the compactions run one at a time.
+    pub auto_compaction_max_threads: u64,
+    /// The time window of the compactions, e.g. `01:00-05:00` in UTC, at any time if it's empty.
+    pub auto_compaction_window: String,
     pub idm: IDMConfig,
 }
 
@@ -156,16 +279,29 @@ impl Default for QueryConfig {
         Self {
             tenant_id: "admin".to_string(),
             cluster_id: "".to_string(),
+            cluster_heartbeat_timeout_secs: 60,
             num_cpus: 0,
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
+            mysql_handler_compression_enabled: true,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
+            postgres_tls_server_cert: "".to_string(),
+            postgres_tls_server_key: "".to_string(),
+            flight_sql_handler_host: "127.0.0.1".to_string(),
+            flight_sql_handler_port: 8900,
             max_active_sessions: 256,
+            session_idle_timeout_secs: 0,
+            session_max_lifetime_secs: 0,
+            max_connections_per_user: 0,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
             clickhouse_http_handler_port: 8124,
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_millis: 10000,
             flight_api_address: "127.0.0.1:9090".to_string(),
+            flight_exchange_compression: "none".to_string(),
+            flight_exchange_credits: 16,
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
@@ -174,10 +310,16 @@ impl Default for QueryConfig {
             http_handler_tls_server_cert: "".to_string(),
             http_handler_tls_server_key: "".to_string(),
             http_handler_tls_server_root_ca_cert: "".to_string(),
+            mysql_tls_server_cert: "".to_string(),
+            mysql_tls_server_key: "".to_string(),
+            mysql_tls_server_root_ca_cert: "".to_string(),
             rpc_tls_server_cert: "".to_string(),
             rpc_tls_server_key: "".to_string(),
+            rpc_tls_server_root_ca_cert: "".to_string(),
             rpc_tls_query_server_root_ca_cert: "".to_string(),
             rpc_tls_query_service_domain_name: "localhost".to_string(),
+            rpc_tls_query_client_cert: "".to_string(),
+            rpc_tls_query_client_key: "".to_string(),
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10000,
@@ -187,12 +329,52 @@ impl Default for QueryConfig {
             table_cache_block_meta_count: 102400,
             table_memory_cache_mb_size: 256,
             table_disk_cache_root: "_cache".to_string(),
-            table_disk_cache_mb_size: 1024,
+            table_disk_cache_mb_size: 0,
             management_mode: false,
+            read_only: false,
             jwt_key_file: "".to_string(),
+            jwt_key_refresh_interval_secs: 900,
+            jwt_user_claim: "sub".to_string(),
+            jwt_role_claim: "role".to_string(),
+            ldap_url: "".to_string(),
+            ldap_bind_dn_template: "".to_string(),
+            ldap_search_bind_dn: "".to_string(),
+            ldap_search_bind_password: "".to_string(),
+            ldap_search_base: "".to_string(),
+            ldap_search_filter: "(uid={user})".to_string(),
+            ldap_group_attribute: "memberOf".to_string(),
+            ldap_group_role_mapping: "".to_string(),
+            audit_log_enabled: false,
+            audit_log_filter: "auth,ddl,dml,privilege".to_string(),
+            audit_log_max_rows: 10000,
+            audit_log_retention_secs: 0,
+            connection_encryption_key: "".to_string(),
+            connection_kms_url: "".to_string(),
+            connection_kms_token: "".to_string(),
+            spill_local_disk_path: "".to_string(),
+            spill_query_quota_bytes: 0,
+            spill_encryption_enabled: false,
+            max_running_queries: 0,
+            max_reserved_memory_usage: 0,
+            max_queued_queries: 100,
+            query_queue_timeout_secs: 60,
+            query_result_cache_max_bytes: 268435456,
+            plan_cache_max_entries: 1024,
+            storage_io_max_requests: 64,
+            storage_io_coalesce_gap_bytes: 65536,
+            query_log_persistent_enabled: false,
+            query_log_flush_interval_secs: 10,
+            query_log_flush_batch_rows: 1000,
             async_insert_max_data_size: 10000,
+            async_insert_max_rows: 10000,
             async_insert_busy_timeout: 200,
             async_insert_stale_timeout: 0,
+            auto_compaction_enabled: false,
+            auto_compaction_interval_secs: 300,
+            auto_compaction_min_excess_blocks: 100,
+            auto_compaction_max_bytes_per_run: 1024 * 1024 * 1024,
+            auto_compaction_max_threads: 2,
+            auto_compaction_window: "".to_string(),
             idm: IDMConfig::default(),
         }
     }
@@ -203,6 +385,8 @@ impl QueryConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert.clone(),
             domain_name: self.rpc_tls_query_service_domain_name.clone(),
+            rpc_tls_client_cert: self.rpc_tls_query_client_cert.clone(),
+            rpc_tls_client_key: self.rpc_tls_query_client_key.clone(),
         }
     }
 }
@@ -295,6 +479,8 @@ impl MetaConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert.to_string(),
             domain_name: self.rpc_tls_meta_service_domain_name.to_string(),
+            rpc_tls_client_cert: "".to_string(),
+            rpc_tls_client_key: "".to_string(),
         }
     }
 
@@ -37,6 +37,7 @@ use common_storage::StorageS3Config as InnerStorageS3Config;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::StderrConfig as InnerStderrLogConfig;
+use common_tracing::TracingConfig as InnerTracingLogConfig;
 use common_users::idm_config::IDMConfig as InnerIDMConfig;
 use serde::Deserialize;
 use serde::Serialize;
@@ -692,6 +693,10 @@ pub struct QueryConfig {
     #[clap(long, default_value_t)]
     pub cluster_id: String,
 
+    /// The node leaves the cluster if no heartbeat is received in the timeout.
+    #[clap(long, default_value = "60")]
+    pub cluster_heartbeat_timeout_secs: u64,
+
     #[clap(long, default_value_t)]
     pub num_cpus: u64,
 
@@ -701,9 +706,46 @@ pub struct QueryConfig {
     #[clap(long, default_value = "3307")]
     pub mysql_handler_port: u16,
 
+    /// Offer the zlib and zstd compression of the MySQL protocol to the clients, which
+    /// compress the connections by their own options, such as `mysql --compress`.
+    #[clap(long, parse(try_from_str), default_value = "true")]
+    pub mysql_handler_compression_enabled: bool,
+
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "5433")]
+    pub postgres_handler_port: u16,
+
+    /// The certificate and the private key of the PostgreSQL handler, the password of the
+    /// users is only asked for on the SSL connections.
+    #[clap(long, default_value_t)]
+    pub postgres_tls_server_cert: String,
+
+    #[clap(long, default_value_t)]
+    pub postgres_tls_server_key: String,
+
+    #[clap(long, default_value = "127.0.0.1")]
+    pub flight_sql_handler_host: String,
+
+    #[clap(long, default_value = "8900")]
+    pub flight_sql_handler_port: u16,
+
     #[clap(long, default_value = "256")]
     pub max_active_sessions: u64,
 
+    /// The sessions idle for the seconds are closed, 0 to keep them.
+    #[clap(long, default_value = "0")]
+    pub session_idle_timeout_secs: u64,
+
+    /// The sessions are closed the seconds after they are created, 0 to keep them.
+    #[clap(long, default_value = "0")]
+    pub session_max_lifetime_secs: u64,
+
+    /// The sessions of a user at the same time, 0 is unlimited.
+    #[clap(long, default_value = "0")]
+    pub max_connections_per_user: u64,
+
     #[deprecated(note = "clickhouse tcp support is deprecated")]
     #[clap(long, default_value = "127.0.0.1")]
     pub clickhouse_handler_host: String,
@@ -730,6 +772,14 @@ pub struct QueryConfig {
     #[clap(long, default_value = "127.0.0.1:9090")]
     pub flight_api_address: String,
 
+    /// The compression of the data exchanged between the cluster nodes, `none`, `lz4` or `zstd`.
+    #[clap(long, default_value = "none")]
+    pub flight_exchange_compression: String,
+
+    /// The credits of the data packets buffered for each exchange channel, 0 is unlimited.
+    #[clap(long, default_value = "16")]
+    pub flight_exchange_credits: u64,
+
     #[clap(long, default_value = "127.0.0.1:8080")]
     pub admin_api_address: String,
 
@@ -745,6 +795,16 @@ pub struct QueryConfig {
     #[clap(long, default_value_t)]
     pub http_handler_tls_server_root_ca_cert: String,
 
+    #[clap(long, default_value_t)]
+    pub mysql_tls_server_cert: String,
+
+    #[clap(long, default_value_t)]
+    pub mysql_tls_server_key: String,
+
+    /// The CA of the certificates of the MySQL clients, which are verified if it is set.
+    #[clap(long, default_value_t)]
+    pub mysql_tls_server_root_ca_cert: String,
+
     #[clap(long, default_value_t)]
     pub api_tls_server_cert: String,
 
@@ -762,6 +822,10 @@ pub struct QueryConfig {
     #[clap(long, default_value_t)]
     pub rpc_tls_server_key: String,
 
+    /// The CA of the certificates of the rpc clients, which are verified if it is set
+    #[clap(long, default_value_t)]
+    pub rpc_tls_server_root_ca_cert: String,
+
     /// Certificate for client to identify query rpc server
     #[clap(long, default_value_t)]
     pub rpc_tls_query_server_root_ca_cert: String,
@@ -769,6 +833,14 @@ pub struct QueryConfig {
     #[clap(long, default_value = "localhost")]
     pub rpc_tls_query_service_domain_name: String,
 
+    /// The cert the client presents to the query rpc server
+    #[clap(long, default_value_t)]
+    pub rpc_tls_query_client_cert: String,
+
+    /// The key of the cert the client presents to the query rpc server
+    #[clap(long, default_value_t)]
+    pub rpc_tls_query_client_key: String,
+
     /// Table engine memory enabled
     #[clap(long, parse(try_from_str), default_value = "true")]
     pub table_engine_memory_enabled: bool,
@@ -799,7 +871,7 @@ pub struct QueryConfig {
     #[clap(long, default_value = "102400")]
     pub table_cache_block_meta_count: u64,
 
-    /// Table memory cache size (mb)
+    /// Table memory cache size (mb) of block column data, 0 to disable
     #[clap(long, default_value = "256")]
     pub table_memory_cache_mb_size: u64,
 
@@ -807,21 +879,159 @@ pub struct QueryConfig {
     #[clap(long, default_value = "_cache")]
     pub table_disk_cache_root: String,
 
-    /// Table disk cache size (mb)
-    #[clap(long, default_value = "1024")]
+    /// Table disk cache size (mb) of block column data, 0 to disable, the default
+    #[clap(long, default_value = "0")]
     pub table_disk_cache_mb_size: u64,
 
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     #[clap(long)]
     pub management_mode: bool,
 
+    /// If in read only mode, only the queries reading the data are served, the writes and the
+    /// changes of the schema are rejected.
+    #[clap(long)]
+    pub read_only: bool,
+
+    /// The url of the JWKS to verify the JWT of the users, empty to disable the JWT authentication.
     #[clap(long, default_value_t)]
     pub jwt_key_file: String,
 
+    /// The interval in seconds to refresh the keys of the JWKS.
+    #[clap(long, default_value = "900")]
+    pub jwt_key_refresh_interval_secs: u64,
+
+    /// The claim of the JWT taken as the user name.
+    #[clap(long, default_value = "sub")]
+    pub jwt_user_claim: String,
+
+    /// The claim of the JWT taken as the role of the session.
+    #[clap(long, default_value = "role")]
+    pub jwt_role_claim: String,
+
+    /// The url of the LDAP server to verify the passwords of the users, empty to disable the LDAP authentication.
+    #[clap(long, default_value_t)]
+    pub ldap_url: String,
+
+    /// The DN template to bind as the user, `{user}` is replaced by the user name, empty to search the DN of the user.
+    #[clap(long, default_value_t)]
+    pub ldap_bind_dn_template: String,
+
+    /// The DN to bind as to search the DN of the user, empty to search anonymously.
+    #[clap(long, default_value_t)]
+    pub ldap_search_bind_dn: String,
+
+    /// The password of the DN to bind as to search the DN of the user.
+    #[clap(long, default_value_t)]
+    pub ldap_search_bind_password: String,
+
+    /// The base DN to search the DN of the user.
+    #[clap(long, default_value_t)]
+    pub ldap_search_base: String,
+
+    /// The filter to search the DN of the user, `{user}` is replaced by the user name.
+    #[clap(long, default_value = "(uid={user})")]
+    pub ldap_search_filter: String,
+
+    /// The attribute of the user entry listing the groups of the user.
+    #[clap(long, default_value = "memberOf")]
+    pub ldap_group_attribute: String,
+
+    /// The roles granted to the members of the groups, in the form of `<group>:<role>;<group>:<role>`.
+    #[clap(long, default_value_t)]
+    pub ldap_group_role_mapping: String,
+
+    /// Record the authentications and the statements in `system.audit_log`.
+    #[clap(long)]
+    pub audit_log_enabled: bool,
+
+    /// The comma separated categories of the events recorded in the audit log, of `auth`, `ddl`, `dml` and `privilege`.
+    #[clap(long, default_value = "auth,ddl,dml,privilege")]
+    pub audit_log_filter: String,
+
+    /// The maximum number of the events kept in the audit log.
+    #[clap(long, default_value = "10000")]
+    pub audit_log_max_rows: usize,
+
+    /// The seconds the events are kept in the audit log, 0 to keep them until the log is full.
+    #[clap(long, default_value = "0")]
+    pub audit_log_retention_secs: u64,
+
+    /// The key encrypting the secret parameters of the connections.
+    #[clap(long, default_value_t)]
+    pub connection_encryption_key: String,
+
+    /// The url of the KMS serving the key encrypting the secret parameters of the connections, instead of `connection_encryption_key`.
+    #[clap(long, default_value_t)]
+    pub connection_kms_url: String,
+
+    /// The bearer token of the requests to the KMS.
+    #[clap(long, default_value_t)]
+    pub connection_kms_token: String,
+
+    /// The local directory the large sorts spill to beyond the max_memory_usage, the temp directory if it's empty.
+    #[clap(long, default_value_t)]
+    pub spill_local_disk_path: String,
+
+    /// The maximum bytes of the files spilled by a query, no limit if it's zero.
+    #[clap(long, default_value = "0")]
+    pub spill_query_quota_bytes: u64,
+
+    /// Encrypt the spilled files by AES with the key generated by the process.
+    #[clap(long)]
+    pub spill_encryption_enabled: bool,
+
+    /// The maximum number of the queries running at the same time, no limit if it's zero.
+    #[clap(long, default_value = "0")]
+    pub max_running_queries: u64,
+
+    /// The maximum memory reserved by the running queries in bytes, each query reserves its max_memory_usage, no limit if it's zero.
+    #[clap(long, default_value = "0")]
+    pub max_reserved_memory_usage: u64,
+
+    /// The maximum number of the queries waiting to run, the excess queries are rejected.
+    #[clap(long, default_value = "100")]
+    pub max_queued_queries: u64,
+
+    /// The seconds a query waits to run before being rejected.
+    #[clap(long, default_value = "60")]
+    pub query_queue_timeout_secs: u64,
+
+    /// The maximum memory of the cached query results in bytes.
+    #[clap(long, default_value = "268435456")]
+    pub query_result_cache_max_bytes: u64,
+
+    /// The maximum number of the cached query plans.
+    #[clap(long, default_value = "1024")]
+    pub plan_cache_max_entries: u64,
+
+    /// The maximum number of the concurrent requests to each storage backend, no limit if it's zero.
+    #[clap(long, default_value = "64")]
+    pub storage_io_max_requests: u64,
+
+    /// The adjacent ranges of an object whose gap is at most this size in bytes are read by one request.
+    #[clap(long, default_value = "65536")]
+    pub storage_io_coalesce_gap_bytes: u64,
+
+    /// Persist the query log into the `system_history.query_log` fuse table, read by `system.query_log`.
+    #[clap(long)]
+    pub query_log_persistent_enabled: bool,
+
+    /// The seconds the query log events are buffered before being written to the persistent table.
+    #[clap(long, default_value = "10")]
+    pub query_log_flush_interval_secs: u64,
+
+    /// The number of the buffered query log events written to the persistent table at once, before the interval.
+    #[clap(long, default_value = "1000")]
+    pub query_log_flush_batch_rows: u64,
+
     /// The maximum memory size of the buffered data collected per insert before being inserted.
     #[clap(long, default_value = "10000")]
     pub async_insert_max_data_size: u64,
 
+    /// The maximum rows of the buffered data collected per insert before being inserted.
+    #[clap(long, default_value = "10000")]
+    pub async_insert_max_rows: u64,
+
     /// The maximum timeout in milliseconds since the first insert before inserting collected data.
     #[clap(long, default_value = "200")]
     pub async_insert_busy_timeout: u64,
@@ -830,6 +1040,30 @@ pub struct QueryConfig {
     #[clap(long, default_value = "0")]
     pub async_insert_stale_timeout: u64,
 
+    /// Compact the fragmented tables in the background, by a node of the cluster at a time.
+    #[clap(long)]
+    pub auto_compaction_enabled: bool,
+
+    /// The seconds between the checks of the tables for the fragmentation.
+    #[clap(long, default_value = "300")]
+    pub auto_compaction_interval_secs: u64,
+
+    /// The excess blocks a table has at least to be compacted.
+    #[clap(long, default_value = "100")]
+    pub auto_compaction_min_excess_blocks: u64,
+
+    /// The compressed bytes the compactions of a check read and write at most.
+    #[clap(long, default_value = "1073741824")]
+    pub auto_compaction_max_bytes_per_run: u64,
+
+    /// The threads of a compaction, the compactions run one at a time.
+    #[clap(long, default_value = "2")]
+    pub auto_compaction_max_threads: u64,
+
+    /// The time window of the compactions, e.g. `01:00-05:00` in UTC, at any time if it's empty.
+    #[clap(long, default_value = "")]
+    pub auto_compaction_window: String,
+
     #[clap(skip)]
     users: Vec<UserConfig>,
 }
@@ -844,31 +1078,57 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
     type Error = ErrorCode;
 
     fn try_into(self) -> Result<InnerQueryConfig> {
+        // A zero timeout expires the node as soon as it registers to the cluster.
+        if self.cluster_heartbeat_timeout_secs == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "cluster_heartbeat_timeout_secs must be greater than 0",
+            ));
+        }
+
         Ok(InnerQueryConfig {
             tenant_id: self.tenant_id,
             cluster_id: self.cluster_id,
+            cluster_heartbeat_timeout_secs: self.cluster_heartbeat_timeout_secs,
             num_cpus: self.num_cpus,
             mysql_handler_host: self.mysql_handler_host,
             mysql_handler_port: self.mysql_handler_port,
+            mysql_handler_compression_enabled: self.mysql_handler_compression_enabled,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_tls_server_cert: self.postgres_tls_server_cert,
+            postgres_tls_server_key: self.postgres_tls_server_key,
+            flight_sql_handler_host: self.flight_sql_handler_host,
+            flight_sql_handler_port: self.flight_sql_handler_port,
             max_active_sessions: self.max_active_sessions,
+            session_idle_timeout_secs: self.session_idle_timeout_secs,
+            session_max_lifetime_secs: self.session_max_lifetime_secs,
+            max_connections_per_user: self.max_connections_per_user,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
             clickhouse_http_handler_port: self.clickhouse_http_handler_port,
             http_handler_host: self.http_handler_host,
             http_handler_port: self.http_handler_port,
             http_handler_result_timeout_millis: self.http_handler_result_timeout_millis,
             flight_api_address: self.flight_api_address,
+            flight_exchange_compression: self.flight_exchange_compression,
+            flight_exchange_credits: self.flight_exchange_credits,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
             http_handler_tls_server_key: self.http_handler_tls_server_key,
             http_handler_tls_server_root_ca_cert: self.http_handler_tls_server_root_ca_cert,
+            mysql_tls_server_cert: self.mysql_tls_server_cert,
+            mysql_tls_server_key: self.mysql_tls_server_key,
+            mysql_tls_server_root_ca_cert: self.mysql_tls_server_root_ca_cert,
             api_tls_server_cert: self.api_tls_server_cert,
             api_tls_server_key: self.api_tls_server_key,
             api_tls_server_root_ca_cert: self.api_tls_server_root_ca_cert,
             rpc_tls_server_cert: self.rpc_tls_server_cert,
             rpc_tls_server_key: self.rpc_tls_server_key,
+            rpc_tls_server_root_ca_cert: self.rpc_tls_server_root_ca_cert,
             rpc_tls_query_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert,
             rpc_tls_query_service_domain_name: self.rpc_tls_query_service_domain_name,
+            rpc_tls_query_client_cert: self.rpc_tls_query_client_cert,
+            rpc_tls_query_client_key: self.rpc_tls_query_client_key,
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
@@ -880,10 +1140,50 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_disk_cache_root: self.table_disk_cache_root,
             table_disk_cache_mb_size: self.table_disk_cache_mb_size,
             management_mode: self.management_mode,
+            read_only: self.read_only,
             jwt_key_file: self.jwt_key_file,
+            jwt_key_refresh_interval_secs: self.jwt_key_refresh_interval_secs,
+            jwt_user_claim: self.jwt_user_claim,
+            jwt_role_claim: self.jwt_role_claim,
+            ldap_url: self.ldap_url,
+            ldap_bind_dn_template: self.ldap_bind_dn_template,
+            ldap_search_bind_dn: self.ldap_search_bind_dn,
+            ldap_search_bind_password: self.ldap_search_bind_password,
+            ldap_search_base: self.ldap_search_base,
+            ldap_search_filter: self.ldap_search_filter,
+            ldap_group_attribute: self.ldap_group_attribute,
+            ldap_group_role_mapping: self.ldap_group_role_mapping,
+            audit_log_enabled: self.audit_log_enabled,
+            audit_log_filter: self.audit_log_filter,
+            audit_log_max_rows: self.audit_log_max_rows,
+            audit_log_retention_secs: self.audit_log_retention_secs,
+            connection_encryption_key: self.connection_encryption_key,
+            connection_kms_url: self.connection_kms_url,
+            connection_kms_token: self.connection_kms_token,
+            spill_local_disk_path: self.spill_local_disk_path,
+            spill_query_quota_bytes: self.spill_query_quota_bytes,
+            spill_encryption_enabled: self.spill_encryption_enabled,
+            max_running_queries: self.max_running_queries,
+            max_reserved_memory_usage: self.max_reserved_memory_usage,
+            max_queued_queries: self.max_queued_queries,
+            query_queue_timeout_secs: self.query_queue_timeout_secs,
+            query_result_cache_max_bytes: self.query_result_cache_max_bytes,
+            plan_cache_max_entries: self.plan_cache_max_entries,
+            storage_io_max_requests: self.storage_io_max_requests,
+            storage_io_coalesce_gap_bytes: self.storage_io_coalesce_gap_bytes,
+            query_log_persistent_enabled: self.query_log_persistent_enabled,
+            query_log_flush_interval_secs: self.query_log_flush_interval_secs,
+            query_log_flush_batch_rows: self.query_log_flush_batch_rows,
             async_insert_max_data_size: self.async_insert_max_data_size,
+            async_insert_max_rows: self.async_insert_max_rows,
             async_insert_busy_timeout: self.async_insert_busy_timeout,
             async_insert_stale_timeout: self.async_insert_stale_timeout,
+            auto_compaction_enabled: self.auto_compaction_enabled,
+            auto_compaction_interval_secs: self.auto_compaction_interval_secs,
+            auto_compaction_min_excess_blocks: self.auto_compaction_min_excess_blocks,
+            auto_compaction_max_bytes_per_run: self.auto_compaction_max_bytes_per_run,
+            auto_compaction_max_threads: self.auto_compaction_max_threads,
+            auto_compaction_window: self.auto_compaction_window,
             idm: InnerIDMConfig {
                 users: users_to_inner(self.users)?,
             },
@@ -897,10 +1197,21 @@ impl From<InnerQueryConfig> for QueryConfig {
         Self {
             tenant_id: inner.tenant_id,
             cluster_id: inner.cluster_id,
+            cluster_heartbeat_timeout_secs: inner.cluster_heartbeat_timeout_secs,
             num_cpus: inner.num_cpus,
             mysql_handler_host: inner.mysql_handler_host,
             mysql_handler_port: inner.mysql_handler_port,
+            mysql_handler_compression_enabled: inner.mysql_handler_compression_enabled,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_tls_server_cert: inner.postgres_tls_server_cert,
+            postgres_tls_server_key: inner.postgres_tls_server_key,
+            flight_sql_handler_host: inner.flight_sql_handler_host,
+            flight_sql_handler_port: inner.flight_sql_handler_port,
             max_active_sessions: inner.max_active_sessions,
+            session_idle_timeout_secs: inner.session_idle_timeout_secs,
+            session_max_lifetime_secs: inner.session_max_lifetime_secs,
+            max_connections_per_user: inner.max_connections_per_user,
 
             // clickhouse tcp is deprecated
             clickhouse_handler_host: "127.0.0.1".to_string(),
@@ -912,18 +1223,26 @@ impl From<InnerQueryConfig> for QueryConfig {
             http_handler_port: inner.http_handler_port,
             http_handler_result_timeout_millis: inner.http_handler_result_timeout_millis,
             flight_api_address: inner.flight_api_address,
+            flight_exchange_compression: inner.flight_exchange_compression,
+            flight_exchange_credits: inner.flight_exchange_credits,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
             http_handler_tls_server_key: inner.http_handler_tls_server_key,
             http_handler_tls_server_root_ca_cert: inner.http_handler_tls_server_root_ca_cert,
+            mysql_tls_server_cert: inner.mysql_tls_server_cert,
+            mysql_tls_server_key: inner.mysql_tls_server_key,
+            mysql_tls_server_root_ca_cert: inner.mysql_tls_server_root_ca_cert,
             api_tls_server_cert: inner.api_tls_server_cert,
             api_tls_server_key: inner.api_tls_server_key,
             api_tls_server_root_ca_cert: inner.api_tls_server_root_ca_cert,
             rpc_tls_server_cert: inner.rpc_tls_server_cert,
             rpc_tls_server_key: inner.rpc_tls_server_key,
+            rpc_tls_server_root_ca_cert: inner.rpc_tls_server_root_ca_cert,
             rpc_tls_query_server_root_ca_cert: inner.rpc_tls_query_server_root_ca_cert,
             rpc_tls_query_service_domain_name: inner.rpc_tls_query_service_domain_name,
+            rpc_tls_query_client_cert: inner.rpc_tls_query_client_cert,
+            rpc_tls_query_client_key: inner.rpc_tls_query_client_key,
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             database_engine_github_enabled: true,
             wait_timeout_mills: inner.wait_timeout_mills,
@@ -936,10 +1255,50 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_disk_cache_root: inner.table_disk_cache_root,
             table_disk_cache_mb_size: inner.table_disk_cache_mb_size,
             management_mode: inner.management_mode,
+            read_only: inner.read_only,
             jwt_key_file: inner.jwt_key_file,
+            jwt_key_refresh_interval_secs: inner.jwt_key_refresh_interval_secs,
+            jwt_user_claim: inner.jwt_user_claim,
+            jwt_role_claim: inner.jwt_role_claim,
+            ldap_url: inner.ldap_url,
+            ldap_bind_dn_template: inner.ldap_bind_dn_template,
+            ldap_search_bind_dn: inner.ldap_search_bind_dn,
+            ldap_search_bind_password: inner.ldap_search_bind_password,
+            ldap_search_base: inner.ldap_search_base,
+            ldap_search_filter: inner.ldap_search_filter,
+            ldap_group_attribute: inner.ldap_group_attribute,
+            ldap_group_role_mapping: inner.ldap_group_role_mapping,
+            audit_log_enabled: inner.audit_log_enabled,
+            audit_log_filter: inner.audit_log_filter,
+            audit_log_max_rows: inner.audit_log_max_rows,
+            audit_log_retention_secs: inner.audit_log_retention_secs,
+            connection_encryption_key: inner.connection_encryption_key,
+            connection_kms_url: inner.connection_kms_url,
+            connection_kms_token: inner.connection_kms_token,
+            spill_local_disk_path: inner.spill_local_disk_path,
+            spill_query_quota_bytes: inner.spill_query_quota_bytes,
+            spill_encryption_enabled: inner.spill_encryption_enabled,
+            max_running_queries: inner.max_running_queries,
+            max_reserved_memory_usage: inner.max_reserved_memory_usage,
+            max_queued_queries: inner.max_queued_queries,
+            query_queue_timeout_secs: inner.query_queue_timeout_secs,
+            query_result_cache_max_bytes: inner.query_result_cache_max_bytes,
+            plan_cache_max_entries: inner.plan_cache_max_entries,
+            storage_io_max_requests: inner.storage_io_max_requests,
+            storage_io_coalesce_gap_bytes: inner.storage_io_coalesce_gap_bytes,
+            query_log_persistent_enabled: inner.query_log_persistent_enabled,
+            query_log_flush_interval_secs: inner.query_log_flush_interval_secs,
+            query_log_flush_batch_rows: inner.query_log_flush_batch_rows,
             async_insert_max_data_size: inner.async_insert_max_data_size,
+            async_insert_max_rows: inner.async_insert_max_rows,
             async_insert_busy_timeout: inner.async_insert_busy_timeout,
             async_insert_stale_timeout: inner.async_insert_stale_timeout,
+            auto_compaction_enabled: inner.auto_compaction_enabled,
+            auto_compaction_interval_secs: inner.auto_compaction_interval_secs,
+            auto_compaction_min_excess_blocks: inner.auto_compaction_min_excess_blocks,
+            auto_compaction_max_bytes_per_run: inner.auto_compaction_max_bytes_per_run,
+            auto_compaction_max_threads: inner.auto_compaction_max_threads,
+            auto_compaction_window: inner.auto_compaction_window,
             users: users_from_inner(inner.idm.users),
         }
     }
@@ -968,6 +1327,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub stderr: StderrLogConfig,
+
+    #[clap(flatten)]
+    pub tracing: TracingLogConfig,
 }
 
 impl Default for LogConfig {
@@ -991,6 +1353,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
+            tracing: self.tracing.try_into()?,
         })
     }
 }
@@ -1003,6 +1366,7 @@ impl From<InnerLogConfig> for LogConfig {
             query_enabled: false,
             file: inner.file.into(),
             stderr: inner.stderr.into(),
+            tracing: inner.tracing.into(),
         }
     }
 }
@@ -1092,6 +1456,56 @@ impl From<InnerStderrLogConfig> for StderrLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct TracingLogConfig {
+    /// Export the spans of the queries to the OpenTelemetry collector
+    #[clap(long = "log-tracing-on")]
+    #[serde(rename = "on")]
+    pub tracing_on: bool,
+
+    /// Level of the exported spans, DEBUG for the spans of the storage IO
+    #[clap(long = "log-tracing-level", default_value = "INFO")]
+    #[serde(rename = "level")]
+    pub tracing_level: String,
+
+    /// Endpoint of the OTLP gRPC receiver of the collector
+    #[clap(
+        long = "log-tracing-otlp-endpoint",
+        default_value = "http://127.0.0.1:4317"
+    )]
+    #[serde(rename = "otlp_endpoint")]
+    pub tracing_otlp_endpoint: String,
+}
+
+impl Default for TracingLogConfig {
+    fn default() -> Self {
+        InnerTracingLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerTracingLogConfig> for TracingLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerTracingLogConfig> {
+        Ok(InnerTracingLogConfig {
+            on: self.tracing_on,
+            level: self.tracing_level,
+            otlp_endpoint: self.tracing_otlp_endpoint,
+        })
+    }
+}
+
+impl From<InnerTracingLogConfig> for TracingLogConfig {
+    fn from(inner: InnerTracingLogConfig) -> Self {
+        Self {
+            tracing_on: inner.on,
+            tracing_level: inner.level,
+            tracing_otlp_endpoint: inner.otlp_endpoint,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct HiveCatalogConfig {
@@ -1287,6 +1701,7 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
+            AuthType::LDAP => check_no_auth_string(self.auth_string, AuthInfo::LDAP),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
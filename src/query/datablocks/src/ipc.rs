@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Write;
+use std::sync::Arc;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::Dictionaries;
+use common_arrow::arrow::io::ipc::read::FileMetadata;
+use common_arrow::arrow::io::ipc::read::FileReader;
+use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::mmap::mmap_dictionaries_unchecked;
+use common_arrow::arrow::mmap::mmap_unchecked;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+
+use crate::DataBlock;
+
+/// Writes the blocks of the same schema as an arrow IPC file. The buffers of the columns are
+/// written as they are, so that [`IpcBlockReader`] creates the columns over them.
+pub fn write_ipc_blocks<W: Write>(
+    writer: W,
+    schema: &DataSchemaRef,
+    blocks: &[DataBlock],
+    options: WriteOptions,
+) -> Result<()> {
+    let mut writer = FileWriter::new(writer, schema.to_arrow(), None, options);
+    writer.start()?;
+    for block in blocks {
+        writer.write(&Chunk::try_from(block.clone())?, None)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Reads the blocks of an arrow IPC file in memory, e.g. a mapped file or a buffer received from
+/// the network, written by [`write_ipc_blocks`].
+///
+/// The columns are created over the buffers of the file rather than copied from it, and keep the
+/// file alive. The compressed buffers, or the buffers not aligned for their types, are copied.
+pub struct IpcBlockReader<T> {
+    schema: DataSchemaRef,
+    data: Arc<T>,
+    metadata: FileMetadata,
+    dictionaries: Option<Dictionaries>,
+    next_block: usize,
+    // Reads the remaining blocks once a block can not be read in place.
+    copying_reader: Option<FileReader<Cursor<SharedBytes<T>>>>,
+}
+
+impl<T: AsRef<[u8]> + Send + Sync + 'static> IpcBlockReader<T> {
+    pub fn try_create(schema: DataSchemaRef, data: Arc<T>) -> Result<IpcBlockReader<T>> {
+        let metadata = read_file_metadata(&mut Cursor::new((*data).as_ref()))?;
+        // soundness: the files are written by `write_ipc_blocks()` of the same cluster
+        let dictionaries = unsafe { mmap_dictionaries_unchecked(&metadata, data.clone()) }.ok();
+        Ok(IpcBlockReader {
+            schema,
+            data,
+            metadata,
+            dictionaries,
+            next_block: 0,
+            copying_reader: None,
+        })
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.metadata.blocks.len()
+    }
+
+    /// Reads the next block of the file, `None` if all the blocks are read.
+    pub fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        if self.copying_reader.is_none() {
+            if self.next_block >= self.num_blocks() {
+                return Ok(None);
+            }
+
+            if let Some(dictionaries) = &self.dictionaries {
+                // soundness: the files are written by `write_ipc_blocks()` of the same cluster
+                let chunk = unsafe {
+                    mmap_unchecked(
+                        &self.metadata,
+                        dictionaries,
+                        self.data.clone(),
+                        self.next_block,
+                    )
+                };
+                if let Ok(chunk) = chunk {
+                    self.next_block += 1;
+                    return Ok(Some(DataBlock::from_chunk(&self.schema, &chunk)?));
+                }
+            }
+
+            // The blocks already read in place are skipped, the buffers of a file are mostly
+            // compressed or not from the first block.
+            let mut reader = FileReader::new(
+                Cursor::new(SharedBytes(self.data.clone())),
+                self.metadata.clone(),
+                None,
+                None,
+            );
+            for _ in 0..self.next_block {
+                reader.next().transpose()?;
+            }
+            self.copying_reader = Some(reader);
+        }
+
+        match self
+            .copying_reader
+            .as_mut()
+            .and_then(|reader| reader.next())
+        {
+            None => Ok(None),
+            Some(chunk) => Ok(Some(DataBlock::from_chunk(&self.schema, &chunk?)?)),
+        }
+    }
+}
+
+struct SharedBytes<T>(Arc<T>);
+
+impl<T: AsRef<[u8]>> AsRef<[u8]> for SharedBytes<T> {
+    fn as_ref(&self) -> &[u8] {
+        (*self.0).as_ref()
+    }
+}
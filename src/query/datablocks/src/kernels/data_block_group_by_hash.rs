@@ -19,8 +19,11 @@ use std::iter::TrustedLen;
 use std::marker::PhantomData;
 use std::ops::Not;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_datavalues::prelude::*;
+use common_datavalues::with_match_physical_primitive_type;
 use common_exception::Result;
+use common_io::prelude::put_uvarint;
 use common_io::prelude::FormatSettings;
 use primitive_types::U256;
 use primitive_types::U512;
@@ -240,13 +243,49 @@ impl HashMethod for HashMethodSerializer {
             return Ok(KeysState::Column(group_columns[0].convert_full_column()));
         }
 
+        // The columns encoded by dictionaries are kept, their keys are serialized from the
+        // distinct strings.
+        let group_columns = group_columns
+            .iter()
+            .map(|c| match c.is_const() {
+                true => c.convert_full_column(),
+                false => (*c).clone(),
+            })
+            .collect::<Vec<_>>();
+
+        // The keys are serialized column at a time: the sizes of all the keys first, then the
+        // values of each column are written into all the keys by a loop of its own type, rather
+        // than by a dynamic `serialize()` per value. The keys are the same as `serialize()`.
+        let mut sizes = vec![0usize; rows + 1];
+        if group_columns
+            .iter()
+            .all(|c| add_key_sizes(c, None, &mut sizes[1..]))
+        {
+            let mut total = 0;
+            for size in sizes.iter_mut() {
+                total += *size;
+                *size = total;
+            }
+
+            let mut values = vec![0u8; sizes[rows]];
+            let mut cursors = sizes[..rows].to_vec();
+            for col in &group_columns {
+                write_key_values(col, None, &mut values, &mut cursors);
+            }
+
+            let offsets = sizes.iter().map(|v| *v as i64).collect::<Vec<_>>();
+            let col = unsafe { StringColumn::from_data_unchecked(offsets.into(), values.into()) };
+            return Ok(KeysState::Column(col.arc()));
+        }
+
+        // The columns of the other types, e.g. the arrays, are serialized value by value.
         let approx_size = group_columns.len() * rows * 8;
         let mut values = Vec::with_capacity(approx_size);
         let mut offsets = Vec::with_capacity(rows + 1);
         offsets.push(0i64);
 
         for row in 0..rows {
-            for col in group_columns {
+            for col in &group_columns {
                 col.serialize(&mut values, row);
             }
             offsets.push(values.len() as i64);
@@ -533,3 +572,150 @@ fn build(
     *offsize += size;
     Ok(())
 }
+
+#[inline]
+fn is_valid(validity: Option<&Bitmap>, row: usize) -> bool {
+    validity.map(|v| v.get_bit(row)).unwrap_or(true)
+}
+
+#[inline]
+fn uvarint_size(v: u64) -> usize {
+    ((64 - (v | 1).leading_zeros() as usize) + 6) / 7
+}
+
+// Adds the sizes of the serialized values of the column to the sizes of the keys, the rows
+// not in `validity` are null and have no value. Returns false for the types not supported.
+fn add_key_sizes(column: &ColumnRef, validity: Option<&Bitmap>, sizes: &mut [usize]) -> bool {
+    if column.is_dictionary() {
+        let column: &DictionaryColumn = unsafe { Series::static_cast(column) };
+        let values = column.values();
+        for (row, (key, size)) in column.keys().iter().zip(sizes.iter_mut()).enumerate() {
+            if is_valid(validity, row) {
+                let len = values.get_data(*key as usize).len();
+                *size += uvarint_size(len as u64) + len;
+            }
+        }
+        return true;
+    }
+
+    let physical_type = column.data_type_id().to_physical_type();
+    let width = match physical_type {
+        PhysicalTypeID::Nullable => {
+            let column: &NullableColumn = unsafe { Series::static_cast(column) };
+            sizes.iter_mut().for_each(|size| *size += 1);
+            return add_key_sizes(column.inner(), Some(column.ensure_validity()), sizes);
+        }
+        PhysicalTypeID::String => {
+            let column: &StringColumn = unsafe { Series::static_cast(column) };
+            for (row, size) in sizes.iter_mut().enumerate() {
+                if is_valid(validity, row) {
+                    let len = column.get_data(row).len();
+                    *size += uvarint_size(len as u64) + len;
+                }
+            }
+            return true;
+        }
+        PhysicalTypeID::Boolean => 1,
+        _ => with_match_physical_primitive_type!(physical_type, |$T| {
+            <$T as PrimitiveType>::SIZE
+        }, {
+            return false;
+        }),
+    };
+
+    match validity {
+        None => sizes.iter_mut().for_each(|size| *size += width),
+        Some(validity) => {
+            for (row, size) in sizes.iter_mut().enumerate() {
+                if validity.get_bit(row) {
+                    *size += width;
+                }
+            }
+        }
+    }
+    true
+}
+
+// Writes the serialized values of the column into the keys at `cursors`, and advances the
+// cursors, the types are the ones accepted by `add_key_sizes()`.
+fn write_key_values(
+    column: &ColumnRef,
+    validity: Option<&Bitmap>,
+    values: &mut [u8],
+    cursors: &mut [usize],
+) {
+    if column.is_dictionary() {
+        let column: &DictionaryColumn = unsafe { Series::static_cast(column) };
+        let (serialized, offsets) = serialize_dictionary_values(column.values());
+        for (row, (key, cursor)) in column.keys().iter().zip(cursors.iter_mut()).enumerate() {
+            if is_valid(validity, row) {
+                let value = &serialized[offsets[*key as usize]..offsets[*key as usize + 1]];
+                values[*cursor..*cursor + value.len()].copy_from_slice(value);
+                *cursor += value.len();
+            }
+        }
+        return;
+    }
+
+    let physical_type = column.data_type_id().to_physical_type();
+    match physical_type {
+        PhysicalTypeID::Nullable => {
+            let column: &NullableColumn = unsafe { Series::static_cast(column) };
+            let validity = column.ensure_validity();
+            for (row, cursor) in cursors.iter_mut().enumerate() {
+                values[*cursor] = validity.get_bit(row) as u8;
+                *cursor += 1;
+            }
+            write_key_values(column.inner(), Some(validity), values, cursors);
+        }
+        PhysicalTypeID::String => {
+            let column: &StringColumn = unsafe { Series::static_cast(column) };
+            for (row, cursor) in cursors.iter_mut().enumerate() {
+                if is_valid(validity, row) {
+                    let value = column.get_data(row);
+                    *cursor += put_uvarint(&mut values[*cursor..], value.len() as u64);
+                    values[*cursor..*cursor + value.len()].copy_from_slice(value);
+                    *cursor += value.len();
+                }
+            }
+        }
+        PhysicalTypeID::Boolean => {
+            let column: &BooleanColumn = unsafe { Series::static_cast(column) };
+            for (row, cursor) in cursors.iter_mut().enumerate() {
+                if is_valid(validity, row) {
+                    values[*cursor] = column.values().get_bit(row) as u8;
+                    *cursor += 1;
+                }
+            }
+        }
+        _ => with_match_physical_primitive_type!(physical_type, |$T| {
+            let column: &PrimitiveColumn<$T> = unsafe { Series::static_cast(column) };
+            let width = <$T as PrimitiveType>::SIZE;
+            let iter = column.values().iter().zip(cursors.iter_mut()).enumerate();
+            for (row, (value, cursor)) in iter {
+                if is_valid(validity, row) {
+                    values[*cursor..*cursor + width].copy_from_slice(value.to_le_bytes().as_ref());
+                    *cursor += width;
+                }
+            }
+        }, {
+            unreachable!("the keys of {:?} are serialized by value", physical_type)
+        }),
+    }
+}
+
+// The distinct strings of a dictionary serialized once, and the offsets of each of them.
+fn serialize_dictionary_values(column: &StringColumn) -> (Vec<u8>, Vec<usize>) {
+    let mut serialized = vec![0u8; column.values().len() + column.len() * 10];
+    let mut offsets = Vec::with_capacity(column.len() + 1);
+    let mut cursor = 0;
+    offsets.push(0);
+    for value in column.iter() {
+        cursor += put_uvarint(&mut serialized[cursor..], value.len() as u64);
+        serialized[cursor..cursor + value.len()].copy_from_slice(value);
+        cursor += value.len();
+        offsets.push(cursor);
+    }
+    serialized.truncate(cursor);
+    (serialized, offsets)
+}
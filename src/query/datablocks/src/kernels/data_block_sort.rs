@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::iter::once;
 
 use common_arrow::arrow::array::ord as arrow_ord;
@@ -171,6 +172,84 @@ impl DataBlock {
             }
         }
     }
+
+    /// Compares the row `lhs_row` of `lhs` with the row `rhs_row` of `rhs` by the sort columns.
+    pub fn compare_sort_rows(
+        lhs: &DataBlock,
+        lhs_row: usize,
+        rhs: &DataBlock,
+        rhs_row: usize,
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<Ordering> {
+        with_sort_comparator(lhs, rhs, sort_columns_descriptions, |comparator| {
+            comparator(0, lhs_row, 1, rhs_row)
+        })
+    }
+
+    /// Returns the number of the leading rows of the sorted `block` which are not greater than
+    /// the row `bound_row` of `bound`, they can be emitted before the rows after the bound.
+    pub fn sort_rows_not_greater(
+        block: &DataBlock,
+        bound: &DataBlock,
+        bound_row: usize,
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<usize> {
+        with_sort_comparator(block, bound, sort_columns_descriptions, |comparator| {
+            let (mut low, mut high) = (0, block.num_rows());
+            while low < high {
+                let mid = low + (high - low) / 2;
+                match comparator(0, mid, 1, bound_row) {
+                    Ordering::Greater => high = mid,
+                    _ => low = mid + 1,
+                }
+            }
+            low
+        })
+    }
+}
+
+fn with_sort_comparator<T>(
+    lhs: &DataBlock,
+    rhs: &DataBlock,
+    sort_columns_descriptions: &[SortColumnDescription],
+    f: impl FnOnce(&dyn Fn(usize, usize, usize, usize) -> Ordering) -> T,
+) -> Result<T> {
+    let sort_arrays = sort_columns_descriptions
+        .iter()
+        .map(|f| {
+            let left = lhs.try_column_by_name(&f.column_name)?;
+            let right = rhs.try_column_by_name(&f.column_name)?;
+            Ok(vec![
+                left.as_arrow_array(left.data_type()),
+                right.as_arrow_array(right.data_type()),
+            ])
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let sort_dyn_arrays = sort_arrays
+        .iter()
+        .map(|f| vec![f[0].as_ref(), f[1].as_ref()])
+        .collect::<Vec<_>>();
+
+    let sort_options = sort_columns_descriptions
+        .iter()
+        .map(|f| arrow_sort::SortOptions {
+            descending: !f.asc,
+            nulls_first: f.nulls_first,
+        })
+        .collect::<Vec<_>>();
+
+    let sort_options_with_array = sort_dyn_arrays
+        .iter()
+        .zip(sort_options.iter())
+        .map(|(s, opt)| {
+            let paris: (&[&dyn Array], &SortOptions) = (s, opt);
+            paris
+        })
+        .collect::<Vec<_>>();
+
+    let comparator = build_comparator_impl(&sort_options_with_array, &build_compare)?;
+    Ok(f(comparator.as_ref()))
 }
 
 fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
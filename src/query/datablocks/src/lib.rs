@@ -17,12 +17,16 @@
 
 mod data_block;
 mod data_block_debug;
+mod ipc;
 mod kernels;
 mod memory;
 mod utils;
 
 pub use data_block::DataBlock;
 pub use data_block_debug::*;
+pub use ipc::write_ipc_blocks;
+pub use ipc::IpcBlockReader;
 pub use kernels::*;
+pub use memory::InMemoryBlocks;
 pub use memory::InMemoryData;
 pub use utils::*;
@@ -22,4 +22,13 @@ use crate::DataBlock;
 /// Shared store to support memory tables.
 ///
 /// Indexed by table id etc.
-pub type InMemoryData<K> = HashMap<K, Arc<RwLock<Vec<DataBlock>>>>;
+pub type InMemoryData<K> = HashMap<K, Arc<InMemoryBlocks>>;
+
+/// The blocks of a memory table.
+#[derive(Default)]
+pub struct InMemoryBlocks {
+    pub blocks: RwLock<Vec<DataBlock>>,
+    /// Whether the blocks of a persistent table are loaded from its snapshot, locked
+    /// while the snapshot is loaded or written so that the snapshots are written in order.
+    pub snapshot_loaded: futures::lock::Mutex<bool>,
+}
@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Write;
+
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::arrow::io::parquet::write::transverse;
-use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_arrow::parquet::compression::CompressionOptions;
 use common_arrow::parquet::encoding::Encoding;
+use common_arrow::parquet::metadata::SchemaDescriptor;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
+use common_arrow::parquet::write::FileWriter;
 use common_arrow::parquet::write::Version;
+use common_arrow::parquet::write::WriteOptions as FileWriteOptions;
+use common_arrow::row_group_with_options;
 use common_arrow::write_parquet_file;
-use common_datavalues::DataSchema;
+use common_arrow::ArrayRef;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -33,47 +41,159 @@ pub fn serialize_data_blocks_with_compression(
     schema: impl AsRef<DataSchema>,
     buf: &mut Vec<u8>,
     compression: CompressionOptions,
+) -> Result<(u64, ThriftFileMetaData)> {
+    let compressions = vec![compression; schema.as_ref().num_fields()];
+    serialize_data_blocks_with_column_compression(blocks, schema, buf, &compressions)
+}
+
+/// Serialize the blocks into a parquet file, compressing each column with
+/// the compression of the same position in `compressions`.
+pub fn serialize_data_blocks_with_column_compression(
+    blocks: Vec<DataBlock>,
+    schema: impl AsRef<DataSchema>,
+    buf: &mut Vec<u8>,
+    compressions: &[CompressionOptions],
 ) -> Result<(u64, ThriftFileMetaData)> {
     let arrow_schema = schema.as_ref().to_arrow();
+    if compressions.len() != arrow_schema.fields.len() {
+        return Err(ErrorCode::LogicalError(format!(
+            "expect compressions of {} columns, but got {}",
+            arrow_schema.fields.len(),
+            compressions.len()
+        )));
+    }
+    let parquet_schema = to_parquet_schema(&arrow_schema)?;
+    let column_write_options = column_write_options(compressions);
+    let encodings = column_encodings(&arrow_schema);
 
-    let row_group_write_options = WriteOptions {
-        write_statistics: false,
-        compression,
-        version: Version::V2,
-    };
     let batches = blocks
         .into_iter()
-        .map(Chunk::try_from)
+        .map(|block| block_chunk(block, &encodings))
         .collect::<Result<Vec<_>>>()?;
 
+    let row_groups = batches.into_iter().map(|(chunk, encodings)| {
+        row_group_with_options(
+            chunk,
+            parquet_schema.fields(),
+            &encodings,
+            &column_write_options,
+        )
+    });
+
+    match write_parquet_file(buf, row_groups, arrow_schema.clone(), file_write_options()) {
+        Ok(result) => Ok(result),
+        Err(cause) => Err(ErrorCode::ParquetError(cause.to_string())),
+    }
+}
+
+/// Writes the blocks into a parquet file one row group at a time, rather than all of them at once
+/// as [`serialize_data_blocks`] does, so that the file is written out while the blocks come.
+pub struct ParquetBlockWriter<W: Write> {
+    writer: FileWriter<W>,
+    parquet_schema: SchemaDescriptor,
+    column_write_options: Vec<WriteOptions>,
+    encodings: Vec<Vec<Encoding>>,
+}
+
+impl<W: Write> ParquetBlockWriter<W> {
+    pub fn try_create(
+        writer: W,
+        schema: impl AsRef<DataSchema>,
+        compression: CompressionOptions,
+    ) -> Result<Self> {
+        let arrow_schema = schema.as_ref().to_arrow();
+        let parquet_schema = to_parquet_schema(&arrow_schema)?;
+        let compressions = vec![compression; arrow_schema.fields.len()];
+        let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+        Ok(ParquetBlockWriter {
+            writer: FileWriter::new(
+                writer,
+                parquet_schema.clone(),
+                file_write_options(),
+                created_by,
+            ),
+            parquet_schema,
+            column_write_options: column_write_options(&compressions),
+            encodings: column_encodings(&arrow_schema),
+        })
+    }
+
+    /// Writes the block as a row group of the file.
+    pub fn write_block(&mut self, block: DataBlock) -> Result<()> {
+        let (chunk, encodings) = block_chunk(block, &self.encodings)?;
+        let row_group = row_group_with_options(
+            chunk,
+            self.parquet_schema.fields(),
+            &encodings,
+            &self.column_write_options,
+        )?;
+        self.writer
+            .write(row_group)
+            .map_err(|cause| ErrorCode::ParquetError(cause.to_string()))
+    }
+
+    /// Writes the footer of the file, returns the size of the file.
+    pub fn end(&mut self) -> Result<u64> {
+        self.writer
+            .end(None)
+            .map_err(|cause| ErrorCode::ParquetError(cause.to_string()))
+    }
+}
+
+fn file_write_options() -> FileWriteOptions {
+    FileWriteOptions {
+        write_statistics: false,
+        version: Version::V2,
+    }
+}
+
+fn column_write_options(compressions: &[CompressionOptions]) -> Vec<WriteOptions> {
+    compressions
+        .iter()
+        .map(|compression| WriteOptions {
+            write_statistics: false,
+            compression: *compression,
+            version: Version::V2,
+        })
+        .collect()
+}
+
+fn column_encodings(arrow_schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     let encoding_map = |data_type: &ArrowDataType| match data_type {
         ArrowDataType::Dictionary(..) => Encoding::RleDictionary,
         _ => col_encoding(data_type),
     };
 
-    let encodings: Vec<Vec<_>> = arrow_schema
+    arrow_schema
         .fields
         .iter()
         .map(|f| transverse(&f.data_type, encoding_map))
-        .collect::<Vec<_>>();
-
-    let row_groups = RowGroupIterator::try_new(
-        batches.into_iter().map(Ok),
-        &arrow_schema,
-        row_group_write_options,
-        encodings,
-    )?;
-
-    use common_arrow::parquet::write::WriteOptions as FileWriteOption;
-    let options = FileWriteOption {
-        write_statistics: false,
-        version: Version::V2,
-    };
+        .collect()
+}
 
-    match write_parquet_file(buf, row_groups, arrow_schema.clone(), options) {
-        Ok(result) => Ok(result),
-        Err(cause) => Err(ErrorCode::ParquetError(cause.to_string())),
+// The chunk of the block and the encodings of its columns, the encodings of the string columns
+// are chosen by each row group.
+fn block_chunk(
+    block: DataBlock,
+    encodings: &[Vec<Encoding>],
+) -> Result<(Chunk<ArrayRef>, Vec<Vec<Encoding>>)> {
+    let mut encodings = encodings.to_vec();
+    let mut arrays = Vec::with_capacity(block.num_columns());
+    for (i, (column, field)) in block
+        .columns()
+        .iter()
+        .zip(block.schema().fields())
+        .enumerate()
+    {
+        match dictionary_array(column) {
+            Some(array) => {
+                encodings[i] = vec![Encoding::RleDictionary];
+                arrays.push(array);
+            }
+            None => arrays.push(column.as_arrow_array(field.data_type().clone())),
+        }
     }
+    Ok((Chunk::try_new(arrays)?, encodings))
 }
 
 pub fn serialize_data_blocks(
@@ -84,6 +204,48 @@ pub fn serialize_data_blocks(
     serialize_data_blocks_with_compression(blocks, schema, buf, CompressionOptions::Lz4Raw)
 }
 
+// The string columns of a row group are encoded by dictionaries if they have at most half as many
+// distinct strings as rows, up to this many distinct strings.
+const MAX_DICTIONARY_VALUES: usize = 65536;
+
+// The dictionary array of the string column, or of the nullable string column, if the column
+// has few distinct strings. The columns read as dictionaries keep their dictionaries.
+fn dictionary_array(column: &ColumnRef) -> Option<ArrayRef> {
+    let column = match column.is_const() {
+        true => column.convert_full_column(),
+        false => column.clone(),
+    };
+    let (column, validity) = match column.is_nullable() {
+        true => {
+            let column: &NullableColumn = unsafe { Series::static_cast(&column) };
+            (
+                column.inner().clone(),
+                Some(column.ensure_validity().clone()),
+            )
+        }
+        false => (column, None),
+    };
+    if column.data_type_id() != TypeID::String || column.is_empty() {
+        return None;
+    }
+
+    let max_values = (column.len() / 2).min(MAX_DICTIONARY_VALUES);
+    let dictionary = match column.is_dictionary() {
+        true => {
+            let dictionary: &DictionaryColumn = unsafe { Series::static_cast(&column) };
+            match dictionary.values().len() <= max_values {
+                true => dictionary.clone(),
+                false => DictionaryColumn::try_encode(&dictionary.to_string_column(), max_values)?,
+            }
+        }
+        false => {
+            let column: &StringColumn = unsafe { Series::static_cast(&column) };
+            DictionaryColumn::try_encode(column, max_values)?
+        }
+    };
+    Some(Box::new(dictionary.to_arrow_dictionary(validity)))
+}
+
 fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
     // Although encoding does work, parquet2 has not implemented decoding of DeltaLengthByteArray yet, we fallback to Plain
     // From parquet2: Decoding "DeltaLengthByteArray"-encoded required V2 pages is not yet implemented for Binary.
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::io::ipc::write::Compression;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_ipc_blocks() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+    ]);
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![1i64, 2]),
+            Series::from_data(vec![Some("b1"), None]),
+        ]),
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![3i64]),
+            Series::from_data(vec![Some("b3")]),
+        ]),
+    ];
+    let expect = vec![
+        "+---+------+",
+        "| a | b    |",
+        "+---+------+",
+        "| 1 | b1   |",
+        "| 2 | NULL |",
+        "| 3 | b3   |",
+        "+---+------+",
+    ];
+
+    // the buffers read in place, and the compressed buffers copied
+    for compression in [None, Some(Compression::LZ4)] {
+        let mut data = vec![];
+        write_ipc_blocks(&mut data, &schema, &blocks, WriteOptions { compression })?;
+
+        let mut reader = IpcBlockReader::try_create(schema.clone(), Arc::new(data))?;
+        assert_eq!(reader.num_blocks(), 2);
+        let mut read_blocks = vec![];
+        while let Some(block) = reader.next_block()? {
+            read_blocks.push(block);
+        }
+        assert_eq!(read_blocks.len(), 2);
+        assert_blocks_eq(expect.clone(), &read_blocks);
+    }
+
+    Ok(())
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datablocks::*;
 use common_datavalues::prelude::*;
 use common_exception::Result;
@@ -57,3 +59,64 @@ fn test_data_block_group_by_hash() -> Result<()> {
     ]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_group_by_serializer_keys() -> Result<()> {
+    let long = "x".repeat(200);
+    let columns = vec![
+        Series::from_data(vec![1i8, -1, 2, 1, 2, 3]),
+        Series::from_data(vec![
+            Some(1u64),
+            None,
+            Some(u64::MAX),
+            None,
+            Some(0),
+            Some(7),
+        ]),
+        Series::from_data(vec!["", "x1", long.as_str(), "x1", "", "x3"]),
+        Series::from_data(vec![
+            Some("a"),
+            None,
+            Some(long.as_str()),
+            Some(""),
+            None,
+            Some("c"),
+        ]),
+        Series::from_data(vec![true, false, true, true, false, false]),
+        Series::from_data(vec![
+            Some(1.5f64),
+            Some(-0.0),
+            None,
+            None,
+            Some(2.5),
+            Some(3.0),
+        ]),
+        Arc::new(ConstColumn::new(Series::from_data(vec![9i32]), 6)),
+        Arc::new(DictionaryColumn::new(
+            vec![2u32, 0, 1, 1, 0, 2].into(),
+            StringColumn::new_from_slice(&["d1", long.as_str(), ""]),
+        )),
+        NullableColumn::wrap_inner(
+            Arc::new(DictionaryColumn::new(
+                vec![0u32, 0, 1, 0, 1, 1].into(),
+                StringColumn::new_from_slice(&["e1", "e2"]),
+            )),
+            Some([true, false, true, true, false, true].into_iter().collect()),
+        ),
+    ];
+
+    let hash = HashMethodSerializer::default();
+    let group_columns = columns.iter().collect::<Vec<_>>();
+    let state = hash.build_keys_state(&group_columns, 6)?;
+    let keys = hash.build_keys_iter(&state)?.collect::<Vec<_>>();
+
+    // The keys built column at a time are the same as the values serialized row by row.
+    for (row, key) in keys.iter().enumerate() {
+        let mut expect = vec![];
+        for column in &columns {
+            column.serialize(&mut expect, row);
+        }
+        assert_eq!(*key, expect.as_slice(), "row {}", row);
+    }
+    Ok(())
+}
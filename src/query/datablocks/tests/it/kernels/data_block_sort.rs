@@ -374,3 +374,47 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_sort_rows_not_greater() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let sorted = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 2, 3, 5]),
+        Series::from_data(vec!["b1", "b2", "b3", "b4", "b5"]),
+    ]);
+    let bound = DataBlock::create(schema, vec![
+        Series::from_data(vec![0i64, 2, 4, 6]),
+        Series::from_data(vec!["c1", "c2", "c3", "c4"]),
+    ]);
+
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: true,
+        nulls_first: false,
+    }];
+
+    assert_eq!(
+        DataBlock::compare_sort_rows(&sorted, 0, &bound, 0, &options)?,
+        std::cmp::Ordering::Greater
+    );
+    assert_eq!(
+        DataBlock::compare_sort_rows(&sorted, 1, &bound, 1, &options)?,
+        std::cmp::Ordering::Equal
+    );
+    assert_eq!(
+        DataBlock::compare_sort_rows(&sorted, 4, &bound, 3, &options)?,
+        std::cmp::Ordering::Less
+    );
+
+    let expected = [0, 3, 4, 5];
+    for (bound_row, expected) in expected.iter().enumerate() {
+        let rows = DataBlock::sort_rows_not_greater(&sorted, &bound, bound_row, &options)?;
+        assert_eq!(rows, *expected);
+    }
+
+    Ok(())
+}
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 mod data_block;
+mod ipc;
 mod kernels;
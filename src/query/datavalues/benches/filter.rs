@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use common_arrow::arrow::bitmap::utils::BitChunkIterExact;
+use common_arrow::arrow::bitmap::utils::BitChunksExact;
+use common_datavalues::prelude::*;
+use criterion::Criterion;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 65536;
+    let mut rng = StdRng::seed_from_u64(42);
+    let ints = Series::from_data((0..size).map(|i| i as i32).collect::<Vec<_>>());
+    let strings: StringColumn = NewColumn::new_from_iter((0..size).map(|i| format!("{}", i)));
+
+    for selectivity in [0.01, 0.1, 0.5, 0.9] {
+        let filter = BooleanColumn::from_iterator((0..size).map(|_| rng.gen_bool(selectivity)));
+
+        c.bench_function(&format!("selection_vector_{}", selectivity), |b| {
+            b.iter(|| criterion::black_box(selection_vector(filter.values())))
+        });
+
+        c.bench_function(&format!("selection_vector_scalar_{}", selectivity), |b| {
+            b.iter(|| criterion::black_box(selection_vector_scalar(&filter)))
+        });
+
+        c.bench_function(&format!("filter_i32_{}", selectivity), |b| {
+            b.iter(|| criterion::black_box(ints.filter(&filter)))
+        });
+
+        c.bench_function(&format!("filter_string_{}", selectivity), |b| {
+            b.iter(|| criterion::black_box(strings.filter(&filter)))
+        });
+    }
+}
+
+// Walks the set bits of the filter one by one, as the scalar columns were filtered.
+fn selection_vector_scalar(filter: &BooleanColumn) -> Vec<u32> {
+    let (slice, _, length) = filter.values().as_slice();
+    let mut selection = Vec::with_capacity(length);
+    let mut mask_chunks = BitChunksExact::<u64>::new(slice, length);
+    mask_chunks
+        .by_ref()
+        .enumerate()
+        .for_each(|(mask_index, mut mask)| {
+            while mask != 0 {
+                selection.push((mask_index * 64) as u32 + mask.trailing_zeros());
+                mask &= mask - 1;
+            }
+        });
+
+    let remainder_start = length - length % 64;
+    mask_chunks
+        .remainder_iter()
+        .enumerate()
+        .for_each(|(i, is_selected)| {
+            if is_selected {
+                selection.push((remainder_start + i) as u32);
+            }
+        });
+    selection
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
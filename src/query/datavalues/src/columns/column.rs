@@ -17,6 +17,8 @@ use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
 use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::IntegerType;
 use common_arrow::ArrayRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -57,6 +59,11 @@ pub trait Column: Send + Sync {
         false
     }
 
+    /// If the column is a string column encoded by a dictionary, see `DictionaryColumn`.
+    fn is_dictionary(&self) -> bool {
+        false
+    }
+
     fn len(&self) -> usize;
     /// whether the array is empty
     fn is_empty(&self) -> bool {
@@ -181,6 +188,10 @@ where A: AsRef<dyn Array>
 {
     fn into_column(self) -> ColumnRef {
         use TypeID::*;
+        if let ArrowType::Dictionary(IntegerType::UInt32, _, _) = self.as_ref().data_type() {
+            return Arc::new(DictionaryColumn::from_arrow_array(self.as_ref()));
+        }
+
         let data_type: DataTypeImpl = from_arrow_type(self.as_ref().data_type());
         match data_type.data_type_id() {
             // arrow type has no nullable type
@@ -0,0 +1,229 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::*;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::buffer::Buffer;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::ArrayRef;
+use common_io::prelude::BinaryWrite;
+
+use crate::prelude::*;
+
+/// A string column encoded by a dictionary: the distinct strings are kept once in `values`, and
+/// each row is the index of its string in `values`.
+///
+/// It has the type of the strings. The kernels aware of the dictionaries work on the keys and on
+/// the distinct strings, the others take the strings of the rows by `convert_full_column()`, as
+/// they do with the constant columns.
+#[derive(Clone)]
+pub struct DictionaryColumn {
+    keys: Buffer<u32>,
+    values: StringColumn,
+}
+
+impl DictionaryColumn {
+    /// # Panics
+    /// This function panics if a key is not an index of `values`.
+    pub fn new(keys: Buffer<u32>, values: StringColumn) -> Self {
+        assert!(
+            keys.iter().all(|key| (*key as usize) < values.len()),
+            "the keys of the dictionary column must be less than {}",
+            values.len()
+        );
+        Self { keys, values }
+    }
+
+    pub fn from_arrow_array(array: &dyn Array) -> Self {
+        let array = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<u32>>()
+            .unwrap();
+
+        // The keys of the null rows are not always indices of the values, they are taken as the
+        // first value, and an empty string is the value of a column of nulls.
+        let keys = match array.keys().validity() {
+            None => array.keys().values().clone(),
+            Some(validity) => array
+                .keys()
+                .values()
+                .iter()
+                .zip(validity.iter())
+                .map(|(key, valid)| if valid { *key } else { 0 })
+                .collect::<Vec<_>>()
+                .into(),
+        };
+        let mut values = StringColumn::from_arrow_array(array.values().as_ref());
+        if values.is_empty() {
+            values = StringColumn::new_from_slice(&[""]);
+        }
+        Self::new(keys, values)
+    }
+
+    /// Encodes the column by a dictionary, returns None if it has more than `max_values` distinct
+    /// strings.
+    pub fn try_encode(column: &StringColumn, max_values: usize) -> Option<Self> {
+        let mut dictionary: HashMap<&[u8], u32> = HashMap::new();
+        let mut values = MutableStringColumn::with_capacity(max_values.min(column.len()));
+        let mut keys = Vec::with_capacity(column.len());
+        for value in column.iter() {
+            let next_key = dictionary.len() as u32;
+            let key = *dictionary.entry(value).or_insert_with(|| {
+                values.append_value(value);
+                next_key
+            });
+            if dictionary.len() > max_values {
+                return None;
+            }
+            keys.push(key);
+        }
+
+        Some(Self {
+            keys: keys.into(),
+            values: values.finish(),
+        })
+    }
+
+    pub fn keys(&self) -> &[u32] {
+        self.keys.as_slice()
+    }
+
+    /// The distinct strings of the column.
+    pub fn values(&self) -> &StringColumn {
+        &self.values
+    }
+
+    #[inline]
+    pub fn value(&self, row: usize) -> &[u8] {
+        // soundness: the keys are the indices of the values
+        unsafe { self.values.value_unchecked(self.keys[row] as usize) }
+    }
+
+    pub fn to_string_column(&self) -> StringColumn {
+        StringColumn::new_from_iter(self.keys.iter().map(|key| unsafe {
+            // soundness: the keys are the indices of the values
+            self.values.value_unchecked(*key as usize)
+        }))
+    }
+
+    /// The arrow dictionary array of the column, with the nulls of `validity`.
+    pub fn to_arrow_dictionary(&self, validity: Option<Bitmap>) -> DictionaryArray<u32> {
+        let keys = PrimitiveArray::<u32>::from_data(ArrowType::UInt32, self.keys.clone(), validity);
+        let values = self.values.as_arrow_array(StringType::new_impl());
+        DictionaryArray::try_from_keys(keys, values).unwrap()
+    }
+
+    fn with_keys(&self, keys: Vec<u32>) -> ColumnRef {
+        Arc::new(Self {
+            keys: keys.into(),
+            values: self.values.clone(),
+        })
+    }
+}
+
+impl Column for DictionaryColumn {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> DataTypeImpl {
+        StringType::new_impl()
+    }
+
+    fn column_type_name(&self) -> String {
+        "Dictionary(String)".to_string()
+    }
+
+    fn is_dictionary(&self) -> bool {
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn memory_size(&self) -> usize {
+        self.keys.len() * std::mem::size_of::<u32>() + self.values.memory_size()
+    }
+
+    fn as_arrow_array(&self, logical_type: DataTypeImpl) -> ArrayRef {
+        self.to_string_column().as_arrow_array(logical_type)
+    }
+
+    fn arc(&self) -> ColumnRef {
+        Arc::new(self.clone())
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> ColumnRef {
+        let keys = unsafe { self.keys.clone().slice_unchecked(offset, length) };
+        Arc::new(Self {
+            keys,
+            values: self.values.clone(),
+        })
+    }
+
+    fn filter(&self, filter: &BooleanColumn) -> ColumnRef {
+        let length = filter.values().len() - filter.values().unset_bits();
+        if length == self.len() {
+            return self.arc();
+        }
+
+        let selection = selection_vector(filter.values());
+        self.with_keys(selection.iter().map(|i| self.keys[*i as usize]).collect())
+    }
+
+    fn scatter(&self, indices: &[usize], scattered_size: usize) -> Vec<ColumnRef> {
+        let mut keys = vec![Vec::with_capacity(self.len() / scattered_size.max(1)); scattered_size];
+        for (key, index) in self.keys.iter().zip(indices.iter()) {
+            keys[*index].push(*key);
+        }
+        keys.into_iter().map(|keys| self.with_keys(keys)).collect()
+    }
+
+    fn replicate(&self, offsets: &[usize]) -> ColumnRef {
+        debug_assert!(
+            offsets.len() == self.len(),
+            "Size of offsets must match size of column"
+        );
+
+        let mut keys = Vec::with_capacity(offsets.last().copied().unwrap_or_default());
+        let mut previous_offset = 0;
+        for (key, offset) in self.keys.iter().zip(offsets.iter()) {
+            keys.extend(std::iter::repeat(*key).take(*offset - previous_offset));
+            previous_offset = *offset;
+        }
+        self.with_keys(keys)
+    }
+
+    fn convert_full_column(&self) -> ColumnRef {
+        Arc::new(self.to_string_column())
+    }
+
+    fn get(&self, index: usize) -> DataValue {
+        DataValue::String(self.value(index).to_vec())
+    }
+
+    fn serialize(&self, vec: &mut Vec<u8>, row: usize) {
+        BinaryWrite::write_binary(vec, self.value(row)).unwrap()
+    }
+}
+
+impl std::fmt::Debug for DictionaryColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_string_column().fmt(f)
+    }
+}
@@ -20,6 +20,7 @@ mod boolean;
 mod column;
 mod column_with_field;
 mod const_;
+mod dictionary;
 mod eq;
 #[allow(clippy::ptr_arg)]
 mod group_hash;
@@ -28,6 +29,7 @@ mod null;
 mod nullable;
 mod object;
 mod primitive;
+mod selection;
 pub mod series;
 mod string;
 mod struct_;
@@ -39,12 +41,14 @@ pub use builder::*;
 pub use column::*;
 pub use column_with_field::*;
 pub use const_::*;
+pub use dictionary::*;
 pub use group_hash::GroupHash;
 pub use mutable::*;
 pub use null::*;
 pub use nullable::*;
 pub use object::*;
 pub use primitive::*;
+pub use selection::*;
 pub use series::*;
 pub use string::*;
 pub use struct_::*;
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::simd::u32x8;
+
+use common_arrow::arrow::bitmap::Bitmap;
+
+const LANES: usize = 8;
+
+/// For each byte of a filter, the positions of its set bits in ascending order, padded with the
+/// lowest one.
+static SELECTION_LUT: [[u32; LANES]; 256] = build_selection_lut();
+
+const fn build_selection_lut() -> [[u32; LANES]; 256] {
+    let mut lut = [[0; LANES]; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut n = 0;
+        let mut bit = 0;
+        while bit < LANES {
+            if byte & (1 << bit) != 0 {
+                lut[byte][n] = bit as u32;
+                n += 1;
+            }
+            bit += 1;
+        }
+        while n < LANES {
+            lut[byte][n] = lut[byte][0];
+            n += 1;
+        }
+        byte += 1;
+    }
+    lut
+}
+
+/// Returns the selection vector of a filter, i.e. the indices of the selected rows in ascending
+/// order.
+///
+/// The indices of a byte of the filter are looked up and stored as one vector of 8 lanes, the
+/// lanes past the selected rows are overwritten by the indices of the next byte. It is branch
+/// free, unlike walking the set bits one by one, which is still faster for the sparse filters.
+pub fn selection_vector(filter: &Bitmap) -> Vec<u32> {
+    let selected = filter.len() - filter.unset_bits();
+    let mut selection = Vec::<u32>::with_capacity(selected + LANES);
+    let mut dst = selection.as_mut_ptr();
+
+    // Less than one in 16 rows selected.
+    if selected * 16 < filter.len() {
+        for (base, mut byte) in filter_bytes(filter) {
+            while byte != 0 {
+                unsafe {
+                    dst.write(base.wrapping_add(byte.trailing_zeros()));
+                    dst = dst.add(1);
+                }
+                byte &= byte - 1;
+            }
+        }
+    } else {
+        for (base, byte) in filter_bytes(filter) {
+            let indices = u32x8::from_array(SELECTION_LUT[byte as usize]) + u32x8::splat(base);
+            unsafe {
+                std::ptr::write_unaligned(dst as *mut [u32; LANES], indices.to_array());
+                dst = dst.add(byte.count_ones() as usize);
+            }
+        }
+    }
+
+    unsafe { selection.set_len(selected) };
+    selection
+}
+
+// The bytes of the filter with the bits out of the filter cleared, each with the index of the row
+// of its lowest bit, which wraps below zero for the first byte of a filter with an offset.
+fn filter_bytes(filter: &Bitmap) -> impl Iterator<Item = (u32, u8)> + '_ {
+    let (bytes, offset, length) = filter.as_slice();
+    let end = offset + length;
+    bytes.iter().enumerate().map(move |(i, byte)| {
+        let mut byte = *byte;
+        if i == 0 {
+            byte &= 0xFF << offset;
+        }
+        if end < (i + 1) * LANES {
+            byte &= (1 << (end - i * LANES)) - 1;
+        }
+        (((i * LANES) as u32).wrapping_sub(offset as u32), byte)
+    })
+}
@@ -113,6 +113,20 @@ impl Series {
         }
     }
 
+    /// Expands the column encoded by a dictionary, or the nullable column of it, into the
+    /// strings of the rows.
+    pub fn remove_dictionary(column: &ColumnRef) -> ColumnRef {
+        if column.is_nullable() && !column.is_const() {
+            let col = unsafe { Self::static_cast::<NullableColumn>(column) };
+            if col.inner().is_dictionary() {
+                return column.convert_full_column();
+            }
+        } else if column.is_dictionary() {
+            return column.convert_full_column();
+        }
+        column.clone()
+    }
+
     pub fn concat(columns: &[ColumnRef]) -> Result<ColumnRef> {
         debug_assert!(!columns.is_empty());
         let is_nullable = columns[0].is_nullable();
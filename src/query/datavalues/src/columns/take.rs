@@ -28,6 +28,15 @@ impl Series {
     pub fn take<I: Index>(column: &ColumnRef, indices: &[I]) -> Result<ColumnRef> {
         if column.is_const() || column.is_null() {
             Ok(column.slice(0, indices.len()))
+        } else if column.is_dictionary() {
+            let dictionary_c: &DictionaryColumn = unsafe { Series::static_cast(column) };
+            let keys = dictionary_c.keys();
+            let keys = indices.iter().map(|index| keys[index.to_usize()]);
+            Ok(DictionaryColumn::new(
+                keys.collect::<Vec<_>>().into(),
+                dictionary_c.values().clone(),
+            )
+            .arc())
         } else if column.is_nullable() {
             let nullable_c: &NullableColumn = unsafe { Series::static_cast(column) };
             let inner_result = Self::take(nullable_c.inner(), indices)?;
@@ -15,6 +15,7 @@
 //! *Credits to the work of https://github.com/pola-rs/polars, which served as
 //! insipration for the crate*
 
+#![feature(portable_simd)]
 #![feature(trusted_len)]
 
 #[macro_use]
@@ -12,9 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_arrow::arrow::bitmap::utils::BitChunkIterExact;
-use common_arrow::arrow::bitmap::utils::BitChunksExact;
-
 use crate::prelude::*;
 
 pub fn filter_scalar_column<C: ScalarColumn>(c: &C, filter: &BooleanColumn) -> ColumnRef {
@@ -23,54 +20,12 @@ pub fn filter_scalar_column<C: ScalarColumn>(c: &C, filter: &BooleanColumn) -> C
     if length == c.len() {
         return c.convert_full_column();
     }
-    const CHUNK_SIZE: usize = 64;
-    let mut builder = <<C as ScalarColumn>::Builder>::with_capacity_meta(c.len(), meta);
-
-    let (mut slice, offset, mut length) = filter.values().as_slice();
-    let mut start_index: usize = 0;
 
-    if offset > 0 {
-        let n = 8 - offset;
-        start_index += n;
-
-        filter
-            .values()
-            .iter()
-            .enumerate()
-            .take(n)
-            .for_each(|(idx, is_selected)| {
-                if is_selected {
-                    builder.push(c.get_data(idx));
-                }
-            });
-        slice = &slice[1..];
-        length -= n;
+    let selection = selection_vector(filter.values());
+    let mut builder = <<C as ScalarColumn>::Builder>::with_capacity_meta(selection.len(), meta);
+    for i in selection {
+        builder.push(c.get_data(i as usize));
     }
-
-    let mut mask_chunks = BitChunksExact::<u64>::new(slice, length);
-
-    mask_chunks
-        .by_ref()
-        .enumerate()
-        .for_each(|(mask_index, mut mask)| {
-            while mask != 0 {
-                let n = mask.trailing_zeros() as usize;
-                let i = mask_index * CHUNK_SIZE + n + start_index;
-                builder.push(c.get_data(i));
-                mask = mask & (mask - 1);
-            }
-        });
-
-    let remainder_start = length - length % CHUNK_SIZE;
-    mask_chunks
-        .remainder_iter()
-        .enumerate()
-        .for_each(|(mask_index, is_selected)| {
-            if is_selected {
-                let i = mask_index + remainder_start + start_index;
-                builder.push(c.get_data(i));
-            }
-        });
     builder.to_column()
 }
 
@@ -25,12 +25,37 @@ use crate::types::serializations::helper::escape::write_escaped_string;
 #[derive(Clone)]
 pub struct StringSerializer<'a> {
     pub(crate) column: &'a StringColumn,
+    // The keys of the rows if the column is encoded by a dictionary, `column` is the dictionary
+    // then, and the strings are expanded only as they are written.
+    pub(crate) keys: Option<&'a [u32]>,
 }
 
 impl<'a> StringSerializer<'a> {
     pub fn try_create(col: &'a ColumnRef) -> Result<Self> {
+        if col.is_dictionary() {
+            let column: &DictionaryColumn = Series::check_get(col)?;
+            return Ok(Self {
+                column: column.values(),
+                keys: Some(column.keys()),
+            });
+        }
+
         let column: &StringColumn = Series::check_get(col)?;
-        Ok(Self { column })
+        Ok(Self { column, keys: None })
+    }
+
+    #[inline]
+    fn value(&self, row_index: usize) -> &'a [u8] {
+        match self.keys {
+            // soundness: the keys are the indices of the dictionary
+            Some(keys) => unsafe { self.column.value_unchecked(keys[row_index] as usize) },
+            None => unsafe { self.column.value_unchecked(row_index) },
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        let rows = self.keys.map_or(self.column.len(), |keys| keys.len());
+        (0..rows).map(|row_index| self.value(row_index))
     }
 }
 
@@ -40,7 +65,7 @@ impl<'a> TypeSerializer<'a> for StringSerializer<'a> {
     }
 
     fn write_field(&self, row_index: usize, buf: &mut Vec<u8>, _format: &FormatSettings) {
-        buf.extend_from_slice(unsafe { self.column.value_unchecked(row_index) });
+        buf.extend_from_slice(self.value(row_index));
     }
 
     fn write_field_escaped(
@@ -50,26 +75,17 @@ impl<'a> TypeSerializer<'a> for StringSerializer<'a> {
         _format: &FormatSettings,
         quote: u8,
     ) {
-        write_escaped_string(
-            unsafe { self.column.value_unchecked(row_index) },
-            buf,
-            quote,
-        )
+        write_escaped_string(self.value(row_index), buf, quote)
     }
 
     fn write_field_json(&self, row_index: usize, buf: &mut Vec<u8>, format: &FormatSettings) {
         buf.push(b'\"');
-        write_json_string(
-            unsafe { self.column.value_unchecked(row_index) },
-            buf,
-            format,
-        );
+        write_json_string(self.value(row_index), buf, format);
         buf.push(b'\"');
     }
 
     fn serialize_json_values(&self, _format: &FormatSettings) -> Result<Vec<Value>> {
         let result: Vec<Value> = self
-            .column
             .iter()
             .map(|x| serde_json::to_value(String::from_utf8_lossy(x).to_string()).unwrap())
             .collect();
@@ -81,9 +97,8 @@ impl<'a> TypeSerializer<'a> for StringSerializer<'a> {
         valids: Option<&Bitmap>,
         _format: &FormatSettings,
     ) -> Result<Vec<Value>> {
-        let column = self.column;
         let mut result: Vec<Value> = Vec::new();
-        for (i, v) in column.iter().enumerate() {
+        for (i, v) in self.iter().enumerate() {
             if let Some(valids) = valids {
                 if !valids.get_bit(i) {
                     result.push(Value::Null);
@@ -116,7 +131,6 @@ impl<'a> TypeSerializer<'a> for StringSerializer<'a> {
         _format: &FormatSettings,
     ) -> Result<Vec<Option<Value>>> {
         let result: Vec<Option<Value>> = self
-            .column
             .iter()
             .map(|v| match std::str::from_utf8(v) {
                 Ok(v) => match serde_json::from_str::<Value>(v) {
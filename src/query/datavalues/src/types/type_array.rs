@@ -19,6 +19,7 @@ use common_arrow::arrow::datatypes::Field;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::data_type::format_data_type_sql;
 use super::data_type::DataType;
 use super::data_type::DataTypeImpl;
 use super::type_id::TypeID;
@@ -61,6 +62,11 @@ impl DataType for ArrayType {
         format!("Array({})", self.inner.name())
     }
 
+    // The nullable items are `ARRAY(INT NULL)`, as they are parsed.
+    fn sql_name(&self) -> String {
+        format!("ARRAY({})", format_data_type_sql(&self.inner))
+    }
+
     fn default_value(&self) -> DataValue {
         DataValue::Array(vec![])
     }
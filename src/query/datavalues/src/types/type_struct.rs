@@ -19,6 +19,7 @@ use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::data_type::format_data_type_sql;
 use super::data_type::DataType;
 use super::data_type::DataTypeImpl;
 use super::data_type::ARROW_EXTENSION_NAME;
@@ -110,7 +111,7 @@ impl DataType for StructType {
                     sql_name.push_str(name);
                     sql_name.push('`');
                     sql_name.push(' ');
-                    sql_name.push_str(&ty.sql_name());
+                    sql_name.push_str(&format_data_type_sql(ty));
                 }
             }
             None => {
@@ -119,7 +120,7 @@ impl DataType for StructType {
                         sql_name.push_str(", ");
                     }
                     first = false;
-                    sql_name.push_str(&ty.sql_name());
+                    sql_name.push_str(&format_data_type_sql(ty));
                 }
             }
         }
@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+fn strings(column: &ColumnRef) -> Vec<Vec<u8>> {
+    let column = column.convert_full_column();
+    let column: &StringColumn = Series::check_get(&column).unwrap();
+    column.iter().map(|v| v.to_vec()).collect()
+}
+
+fn dictionary(column: &ColumnRef) -> &DictionaryColumn {
+    assert!(column.is_dictionary());
+    Series::check_get(column).unwrap()
+}
+
+#[test]
+fn test_dictionary_encode() {
+    let column = StringColumn::new_from_slice(&["a", "b", "a", "c", "b", "a"]);
+
+    let encoded = DictionaryColumn::try_encode(&column, 3).unwrap();
+    assert_eq!(encoded.keys(), &[0, 1, 0, 2, 1, 0]);
+    assert_eq!(encoded.values().len(), 3);
+    assert_eq!(encoded.value(3), b"c");
+    assert_eq!(encoded.data_type(), StringType::new_impl());
+    assert_eq!(
+        strings(&encoded.arc()),
+        strings(&Series::from_data(vec!["a", "b", "a", "c", "b", "a"]))
+    );
+
+    assert!(DictionaryColumn::try_encode(&column, 2).is_none());
+}
+
+#[test]
+fn test_dictionary_column_ops() -> Result<()> {
+    let column = StringColumn::new_from_slice(&["x", "y", "x", "z"]);
+    let column = DictionaryColumn::try_encode(&column, 4).unwrap().arc();
+
+    let filter = BooleanColumn::from_slice(&[true, false, true, true]);
+    let filtered = column.filter(&filter);
+    assert_eq!(dictionary(&filtered).keys(), &[0, 0, 2]);
+    assert_eq!(dictionary(&filtered).values().len(), 3);
+
+    let sliced = column.slice(1, 2);
+    assert_eq!(strings(&sliced), vec![b"y".to_vec(), b"x".to_vec()]);
+
+    let taken = Series::take(&column, &[3u32, 1, 1])?;
+    assert_eq!(dictionary(&taken).keys(), &[2, 1, 1]);
+
+    let replicated = column.replicate(&[1, 3, 3, 4]);
+    assert_eq!(dictionary(&replicated).keys(), &[0, 1, 1, 2]);
+
+    let scattered = column.scatter(&[1, 0, 1, 0], 2);
+    assert_eq!(strings(&scattered[0]), vec![b"y".to_vec(), b"z".to_vec()]);
+    assert_eq!(strings(&scattered[1]), vec![b"x".to_vec(), b"x".to_vec()]);
+
+    assert_eq!(column.get(3), DataValue::String(b"z".to_vec()));
+    Ok(())
+}
+
+#[test]
+fn test_dictionary_arrow_array() {
+    let column = StringColumn::new_from_slice(&["x", "y", "x"]);
+    let column = DictionaryColumn::try_encode(&column, 2).unwrap();
+
+    let array = column.to_arrow_dictionary(None);
+    let converted = array.into_column();
+    assert_eq!(dictionary(&converted).keys(), &[0, 1, 0]);
+    assert_eq!(strings(&converted), strings(&column.arc()));
+
+    let validity = [true, false, true].into_iter().collect::<Bitmap>();
+    let array = column.to_arrow_dictionary(Some(validity));
+    let converted = array.into_nullable_column();
+    let nullable: &NullableColumn = Series::check_get(&converted).unwrap();
+    assert!(nullable.inner().is_dictionary());
+    assert_eq!(converted.get(1), DataValue::Null);
+    assert_eq!(converted.get(2), DataValue::String(b"x".to_vec()));
+}
@@ -15,6 +15,8 @@
 mod array;
 mod boolean;
 mod builder;
+mod dictionary;
 mod object;
 mod primitive;
+mod selection;
 mod string;
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+
+#[test]
+fn test_selection_vector() {
+    const N: usize = 1000;
+    // from the sparse filters to the dense ones
+    for step in [1, 2, 3, 7, 16, 31, 100, N + 1] {
+        let bitmap: Bitmap = MutableBitmap::from_iter((0..N).map(|i| i % step == 0)).into();
+        for offset in [0, 1, 5, 8, 13] {
+            for length in [0, 1, 7, 8, 9, 64, 500, N - offset] {
+                let filter = bitmap.clone().slice(offset, length);
+                let expect: Vec<u32> = (0..length)
+                    .filter(|i| (i + offset) % step == 0)
+                    .map(|i| i as u32)
+                    .collect();
+                assert_eq!(
+                    selection_vector(&filter),
+                    expect,
+                    "step: {}, offset: {}, length: {}",
+                    step,
+                    offset,
+                    length
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_filter_with_offset() {
+    const N: usize = 200;
+    let data_column: StringColumn = NewColumn::new_from_iter((0..N).map(|i| format!("{}", i)));
+    let filter = BooleanColumn::from_iterator((0..N + 3).map(|i| i % 3 != 0));
+    let filter = filter.slice(3, N);
+    let filter: &BooleanColumn = unsafe { Series::static_cast(&filter) };
+
+    let res = data_column.filter(filter);
+    let expect: StringColumn =
+        NewColumn::new_from_iter((0..N).filter(|i| i % 3 != 0).map(|i| format!("{}", i)));
+    let res: &StringColumn = unsafe { Series::static_cast(&res) };
+    assert_eq!(res.values(), expect.values());
+    assert_eq!(res.offsets(), expect.offsets());
+}
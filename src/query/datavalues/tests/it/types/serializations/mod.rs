@@ -77,6 +77,16 @@ fn test_serializers() -> Result<()> {
             val_str: "hello",
             col_str: vec!["hello".to_owned(), "world".to_owned(), "NULL".to_owned()],
         },
+        Test {
+            name: "dictionary string",
+            data_type: StringType::new_impl(),
+            column: Arc::new(DictionaryColumn::new(
+                vec![1u32, 0, 1].into(),
+                StringColumn::new_from_slice(&["world", "hello"]),
+            )),
+            val_str: "hello",
+            col_str: vec!["hello".to_owned(), "world".to_owned(), "hello".to_owned()],
+        },
         Test {
             name: "array",
             data_type: DataTypeImpl::Array(ArrayType::create(StringType::new_impl())),
@@ -15,10 +15,17 @@
 mod format_diagnostic;
 mod format_factory;
 pub mod output_format;
+mod output_format_arrow;
 pub mod output_format_csv;
 mod output_format_json_each_row;
 mod output_format_parquet;
 mod output_format_values;
+mod shared_buffer;
 
 pub use format_diagnostic::verbose_string;
 pub use format_factory::FormatFactory;
+pub use output_format_arrow::result_arrow_schema;
+pub use output_format_arrow::result_field_metadata;
+pub use output_format_arrow::FIELD_META_DATABEND_TYPE;
+pub use output_format_arrow::FIELD_META_NULLABLE;
+pub use output_format_arrow::FIELD_META_TIMEZONE;
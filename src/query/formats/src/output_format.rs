@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_io::prelude::FormatSettings;
 use strum_macros::EnumIter;
 
+use crate::output_format_arrow::ArrowStreamOutputFormat;
 use crate::output_format_csv::CSVOutputFormat;
 use crate::output_format_csv::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format_csv::CSVWithNamesOutputFormat;
@@ -80,6 +81,7 @@ pub enum OutputFormatType {
     TSVWithNames,
     TSVWithNamesAndTypes,
     Parquet,
+    ArrowStream,
     JsonEachRow,
     JsonStringsEachRow,
     JsonCompactEachRow,
@@ -130,6 +132,7 @@ impl OutputFormatType {
         match self {
             OutputFormatType::TSV => vec!["TabSeparated".to_string()],
             OutputFormatType::JsonEachRow => vec!["NDJson".to_string()],
+            OutputFormatType::ArrowStream => vec!["Arrow".to_string()],
             _ => vec![],
         }
     }
@@ -144,6 +147,7 @@ impl OutputFormatType {
                 "text/csv; charset=UTF-8; header=present"
             }
             OutputFormatType::Parquet => "application/octet-stream",
+            OutputFormatType::ArrowStream => "application/vnd.apache.arrow.stream",
             OutputFormatType::JsonEachRow
             | OutputFormatType::JsonStringsEachRow
             | OutputFormatType::JsonCompactEachRow
@@ -184,6 +188,9 @@ impl OutputFormatType {
             OutputFormatType::Parquet => {
                 Box::new(ParquetOutputFormat::create(schema, format_setting))
             }
+            OutputFormatType::ArrowStream => {
+                Box::new(ArrowStreamOutputFormat::create(schema, format_setting))
+            }
             OutputFormatType::JsonEachRow => {
                 Box::new(JsonEachRowOutputFormat::create(schema, format_setting))
             }
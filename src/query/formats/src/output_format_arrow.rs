@@ -0,0 +1,120 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::TypeID;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+
+use crate::output_format::OutputFormat;
+use crate::shared_buffer::SharedBuffer;
+
+/// The databend type of the column, e.g. `TIMESTAMP(6)`, without the nullability.
+pub const FIELD_META_DATABEND_TYPE: &str = "databend.type";
+/// `true` if the column is nullable.
+pub const FIELD_META_NULLABLE: &str = "databend.nullable";
+/// The time zone the timestamps of the column are rendered in, the timestamp columns only.
+pub const FIELD_META_TIMEZONE: &str = "databend.timezone";
+
+/// The metadata describing the databend type of a result column, the clients map the arrow
+/// fields to their own types by it rather than by the arrow types, which are lossy, e.g. a
+/// `VARIANT` is a binary and a `DATE` is an int32 in arrow.
+pub fn result_field_metadata(
+    field: &DataField,
+    format_settings: &FormatSettings,
+) -> BTreeMap<String, String> {
+    let data_type = remove_nullable(field.data_type());
+    let mut metadata = BTreeMap::new();
+    metadata.insert(FIELD_META_DATABEND_TYPE.to_string(), data_type.sql_name());
+    metadata.insert(
+        FIELD_META_NULLABLE.to_string(),
+        field.is_nullable().to_string(),
+    );
+    if data_type.data_type_id() == TypeID::Timestamp {
+        metadata.insert(
+            FIELD_META_TIMEZONE.to_string(),
+            format_settings.timezone.name().to_string(),
+        );
+    }
+    metadata
+}
+
+/// The arrow schema of a result, of which the fields carry the metadata of their databend types.
+pub fn result_arrow_schema(schema: &DataSchema, format_settings: &FormatSettings) -> ArrowSchema {
+    let mut arrow_schema = schema.to_arrow();
+    for (arrow_field, field) in arrow_schema.fields.iter_mut().zip(schema.fields()) {
+        arrow_field
+            .metadata
+            .extend(result_field_metadata(field, format_settings));
+    }
+    arrow_schema
+}
+
+/// The arrow IPC streaming format, the schema message followed by a record batch message per
+/// block, each block is written out once it's serialized.
+pub struct ArrowStreamOutputFormat {
+    schema: DataSchemaRef,
+    format_settings: FormatSettings,
+    buffer: SharedBuffer,
+    writer: Option<StreamWriter<SharedBuffer>>,
+}
+
+impl ArrowStreamOutputFormat {
+    pub fn create(schema: DataSchemaRef, format_settings: FormatSettings) -> Self {
+        Self {
+            schema,
+            format_settings,
+            buffer: SharedBuffer::default(),
+            writer: None,
+        }
+    }
+
+    // The writer of the stream, started with the schema message on the first block.
+    fn writer(&mut self) -> Result<&mut StreamWriter<SharedBuffer>> {
+        if self.writer.is_none() {
+            let options = WriteOptions { compression: None };
+            let mut writer = StreamWriter::new(self.buffer.clone(), options);
+            let arrow_schema = result_arrow_schema(&self.schema, &self.format_settings);
+            writer.start(&arrow_schema, None)?;
+            self.writer = Some(writer);
+        }
+        Ok(self.writer.as_mut().unwrap())
+    }
+}
+
+impl OutputFormat for ArrowStreamOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        let chunk = Chunk::try_from(block.clone())?;
+        self.writer()?.write(&chunk, None)?;
+        Ok(self.buffer.take())
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        // The stream of no blocks has the schema message only.
+        self.writer()?.finish()?;
+        self.writer = None;
+        Ok(self.buffer.take())
+    }
+}
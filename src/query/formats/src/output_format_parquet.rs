@@ -12,18 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_datablocks::serialize_data_blocks;
+use common_arrow::parquet::compression::CompressionOptions;
 use common_datablocks::DataBlock;
+use common_datablocks::ParquetBlockWriter;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
 use crate::output_format::OutputFormat;
+use crate::shared_buffer::SharedBuffer;
+
+// The blocks are buffered until they make a row group of this size, then the row group is
+// written out, so that the file is streamed without making row groups of tiny blocks.
+const ROW_GROUP_BYTES: usize = 16 * 1024 * 1024;
 
-#[derive(Default)]
 pub struct ParquetOutputFormat {
     schema: DataSchemaRef,
     data_blocks: Vec<DataBlock>,
+    buffer: SharedBuffer,
+    writer: Option<ParquetBlockWriter<SharedBuffer>>,
 }
 
 impl ParquetOutputFormat {
@@ -31,14 +38,36 @@ impl ParquetOutputFormat {
         Self {
             schema,
             data_blocks: vec![],
+            buffer: SharedBuffer::default(),
+            writer: None,
+        }
+    }
+
+    // Writes the buffered blocks as a row group, the file is started on the first row group.
+    fn write_row_group(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(());
+        }
+        if self.writer.is_none() {
+            self.writer = Some(ParquetBlockWriter::try_create(
+                self.buffer.clone(),
+                &self.schema,
+                CompressionOptions::Lz4Raw,
+            )?);
         }
+        let block = DataBlock::concat_blocks(&blocks)?;
+        self.writer.as_mut().unwrap().write_block(block)
     }
 }
 
 impl OutputFormat for ParquetOutputFormat {
     fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
         self.data_blocks.push(block.clone());
-        Ok(vec![])
+        if self.buffer_size() >= ROW_GROUP_BYTES {
+            self.write_row_group()?;
+        }
+        Ok(self.buffer.take())
     }
 
     fn buffer_size(&mut self) -> usize {
@@ -46,12 +75,11 @@ impl OutputFormat for ParquetOutputFormat {
     }
 
     fn finalize(&mut self) -> Result<Vec<u8>> {
-        let blocks = std::mem::take(&mut self.data_blocks);
-        if blocks.is_empty() {
-            return Ok(vec![]);
+        self.write_row_group()?;
+        // No file is written for no blocks.
+        if let Some(mut writer) = self.writer.take() {
+            writer.end()?;
         }
-        let mut buf = Vec::with_capacity(100 * 1024 * 1024);
-        let _ = serialize_data_blocks(blocks, &self.schema, &mut buf)?;
-        Ok(buf)
+        Ok(self.buffer.take())
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod format_factory;
+mod output_format_arrow_parquet;
 mod output_format_json_each_row;
 mod output_format_tcsv;
 mod output_format_utils;
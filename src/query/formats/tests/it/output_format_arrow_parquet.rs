@@ -0,0 +1,129 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_arrow::arrow::io::parquet::read::read_metadata;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_formats::output_format::OutputFormatType;
+use common_formats::FIELD_META_DATABEND_TYPE;
+use common_formats::FIELD_META_NULLABLE;
+use common_formats::FIELD_META_TIMEZONE;
+use common_io::prelude::FormatSettings;
+
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_arrow_stream() -> Result<()> {
+    let block = get_simple_block(true)?;
+    let fmt = OutputFormatType::ArrowStream;
+    let mut formatter = fmt.create_format(block.schema().clone(), FormatSettings::default());
+
+    // each block is written out once it's serialized
+    let mut buffer = formatter.serialize_block(&block)?;
+    assert!(!buffer.is_empty());
+    buffer.extend(formatter.serialize_block(&block)?);
+    buffer.extend(formatter.finalize()?);
+
+    let mut reader = Cursor::new(buffer);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema.fields.len(), 5);
+    let mut rows = vec![];
+    for state in StreamReader::new(reader, metadata, None) {
+        if let StreamState::Some(chunk) = state? {
+            rows.push(chunk.len());
+        }
+    }
+    assert_eq!(rows, vec![3, 3]);
+
+    // the stream of no blocks has the schema only
+    let buffer = formatter.finalize()?;
+    let metadata = read_stream_metadata(&mut Cursor::new(buffer))?;
+    assert_eq!(metadata.schema.fields.len(), 5);
+
+    Ok(())
+}
+
+#[test]
+fn test_arrow_stream_field_metadata() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("c1", i32::to_data_type()),
+        DataField::new("c2", TimestampType::new_impl(6)),
+        DataField::new("c3", VariantType::new_impl()),
+    ]);
+    let block = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![Some(1i32), None]),
+        Series::from_data(vec![1_i64, 2_i64]),
+        Series::from_data(vec![VariantValue::from(serde_json::json!(1)); 2]),
+    ]);
+
+    let settings = FormatSettings {
+        timezone: "Asia/Shanghai".parse().unwrap(),
+        ..FormatSettings::default()
+    };
+    let mut formatter = OutputFormatType::ArrowStream.create_format(schema, settings);
+    let mut buffer = formatter.serialize_block(&block)?;
+    buffer.extend(formatter.finalize()?);
+
+    // the fields carry their databend types, which the arrow types lose
+    let metadata = read_stream_metadata(&mut Cursor::new(buffer))?;
+    let fields = &metadata.schema.fields;
+    let meta = |i: usize, key: &str| fields[i].metadata.get(key).cloned();
+    assert_eq!(meta(0, FIELD_META_DATABEND_TYPE), Some("INT".to_string()));
+    assert_eq!(meta(0, FIELD_META_NULLABLE), Some("true".to_string()));
+    assert_eq!(meta(0, FIELD_META_TIMEZONE), None);
+    assert_eq!(
+        meta(1, FIELD_META_DATABEND_TYPE),
+        Some("TIMESTAMP(6)".to_string())
+    );
+    assert_eq!(meta(1, FIELD_META_NULLABLE), Some("false".to_string()));
+    assert_eq!(
+        meta(1, FIELD_META_TIMEZONE),
+        Some("Asia/Shanghai".to_string())
+    );
+    assert_eq!(
+        meta(2, FIELD_META_DATABEND_TYPE),
+        Some("VARIANT".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet() -> Result<()> {
+    let block = get_simple_block(false)?;
+    let fmt = OutputFormatType::Parquet;
+    let mut formatter = fmt.create_format(block.schema().clone(), FormatSettings::default());
+
+    // the small blocks are buffered into one row group
+    let mut buffer = formatter.serialize_block(&block)?;
+    buffer.extend(formatter.serialize_block(&block)?);
+    assert!(buffer.is_empty());
+    assert!(formatter.buffer_size() > 0);
+    buffer.extend(formatter.finalize()?);
+
+    let metadata = read_metadata(&mut Cursor::new(buffer))?;
+    assert_eq!(metadata.num_rows, 6);
+    assert_eq!(metadata.row_groups.len(), 1);
+
+    // no file is written for no blocks
+    assert!(formatter.finalize()?.is_empty());
+
+    Ok(())
+}
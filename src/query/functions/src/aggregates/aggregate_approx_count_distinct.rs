@@ -0,0 +1,235 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+use twox_hash::XxHash64;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+// The number of the bits of the hash to choose the register, the standard error of the
+// estimation is about 1.04 / sqrt(2 ^ HLL_PRECISION), which is 1.6%.
+const HLL_PRECISION: usize = 12;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// The HyperLogLog sketch of the values, the sketches of the partial aggregations in the
+/// nodes of the cluster are merged by the maximum of the registers.
+#[derive(Serialize, Deserialize)]
+struct AggregateApproxCountDistinctState {
+    registers: Vec<u8>,
+}
+
+impl AggregateApproxCountDistinctState {
+    fn new() -> Self {
+        Self {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    #[inline(always)]
+    fn add_hash(&mut self, hash: u64) {
+        let index = (hash as usize) & (HLL_REGISTERS - 1);
+        let rank = ((hash >> HLL_PRECISION).trailing_zeros() as usize + 1)
+            .min(64 - HLL_PRECISION + 1) as u8;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn add(&mut self, column: &ColumnRef, row: usize) -> Result<()> {
+        let value = DataGroupValue::try_from(&column.get(row))?;
+        let mut hasher = XxHash64::default();
+        value.hash(&mut hasher);
+        self.add_hash(hasher.finish());
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let (sum, zeros) = self
+            .registers
+            .iter()
+            .fold((0.0, 0), |(sum, zeros), register| {
+                let zeros = if *register == 0 { zeros + 1 } else { zeros };
+                (sum + 1.0 / (1u64 << *register) as f64, zeros)
+            });
+
+        let estimate = alpha * m * m / sum;
+        // The linear counting is more accurate for the small cardinalities.
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+    _arguments: Vec<DataField>,
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(u64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateApproxCountDistinctState::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateApproxCountDistinctState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        match validity {
+            Some(bitmap) => {
+                for (row, is_valid) in bitmap.iter().enumerate().take(input_rows) {
+                    if is_valid {
+                        state.add(&columns[0], row)?;
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    state.add(&columns[0], row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let state = place
+                .next(offset)
+                .get::<AggregateApproxCountDistinctState>();
+            state.add(&columns[0], row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.add(&columns[0], row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let rhs = rhs.get::<AggregateApproxCountDistinctState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let column: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(column)?;
+        column.push(state.estimate());
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateApproxCountDistinctFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+        }))
+    }
+}
+
+pub fn aggregate_approx_count_distinct_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(AggregateApproxCountDistinctFunction::try_create),
+        features,
+    )
+}
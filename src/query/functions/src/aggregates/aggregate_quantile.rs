@@ -0,0 +1,246 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+#[derive(Serialize, Deserialize)]
+struct AggregateQuantileState {
+    values: Vec<f64>,
+}
+
+impl AggregateQuantileState {
+    // The quantile of the level, interpolated linearly between the closest ranks.
+    fn quantile(&mut self, level: f64) -> f64 {
+        if self.values.is_empty() {
+            return f64::NAN;
+        }
+
+        self.values.sort_by(|a, b| a.total_cmp(b));
+        let rank = level * (self.values.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let fraction = rank - lower as f64;
+        self.values[lower] + (self.values[upper] - self.values[lower]) * fraction
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    level: f64,
+    _arguments: Vec<DataField>,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(f64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateQuantileState { values: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.values.push(value.as_());
+                    }
+                }
+            }
+            None => {
+                state.values.extend(column.iter().map(|v| v.as_()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateQuantileState>();
+            state.values.push(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregateQuantileState>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.values.push(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let rhs = rhs.get::<AggregateQuantileState>();
+        state.values.extend_from_slice(&rhs.values);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let column: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(column)?;
+        column.push(state.quantile(self.level));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateQuantileState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        level: f64,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            level,
+            _arguments: arguments,
+            t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let level = match params.len() {
+        0 => 0.5,
+        1 => params[0].as_f64()?,
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most one parameter, but got {}",
+                display_name, n
+            )));
+        }
+    };
+    if !(0.0..=1.0).contains(&level) {
+        return Err(ErrorCode::BadArguments(format!(
+            "the level of {} must be between 0 and 1, but got {}",
+            display_name, level
+        )));
+    }
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        AggregateQuantileFunction::<$T>::try_create(display_name, level, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_median_function(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    try_create_aggregate_quantile_function(display_name, vec![], arguments)
+}
+
+pub fn aggregate_quantile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_function))
+}
+
+pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_median_function))
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
@@ -22,6 +23,8 @@ use super::aggregate_covariance::aggregate_covariance_sample_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
 use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
+use super::aggregate_quantile::aggregate_median_function_desc;
+use super::aggregate_quantile::aggregate_quantile_function_desc;
 use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
@@ -56,6 +59,13 @@ impl Aggregators {
         factory.register("uniq", aggregate_combinator_uniq_desc());
 
         factory.register("retention", aggregate_retention_function_desc());
+
+        factory.register(
+            "approx_count_distinct",
+            aggregate_approx_count_distinct_function_desc(),
+        );
+        factory.register("quantile", aggregate_quantile_function_desc());
+        factory.register("median", aggregate_median_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -26,6 +26,7 @@ mod adaptors;
 
 #[macro_use]
 mod macros;
+mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
 mod aggregate_combinator_distinct;
@@ -34,12 +35,14 @@ mod aggregate_covariance;
 mod aggregate_distinct_state;
 mod aggregate_min_max_any;
 mod aggregate_null_result;
+mod aggregate_quantile;
 mod aggregate_retention;
 mod aggregate_scalar_state;
 mod aggregate_stddev_pop;
 mod aggregate_window_funnel;
 
 pub use adaptors::*;
+pub use aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_avg::AggregateAvgFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
@@ -54,6 +57,7 @@ pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
 pub use aggregate_min_max_any::AggregateMinMaxAnyFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
+pub use aggregate_quantile::AggregateQuantileFunction;
 pub use aggregate_retention::AggregateRetentionFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
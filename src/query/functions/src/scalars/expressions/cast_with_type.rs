@@ -126,6 +126,7 @@ pub fn cast_with_type(
         return Ok(ConstColumn::new(res, column.len()).arc());
     }
 
+    let column = &Series::remove_dictionary(column);
     let (all_nulls, source_valids) = column.validity();
     if !target_type.is_nullable()
         && (all_nulls
@@ -19,6 +19,7 @@ use chrono_tz::Tz;
 use common_datavalues::ColumnRef;
 use common_datavalues::ColumnsWithField;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_datavalues::NullColumn;
 use common_datavalues::NullType;
 use common_exception::Result;
@@ -26,20 +27,42 @@ use dyn_clone::DynClone;
 
 use super::Monotonicity;
 
-/// for now, this is only store Timezone
+/// The timezone, the generator of the sequences for `nextval()`, and the provider of the
+/// dictionaries for `dict_get_or_default()`
 #[derive(Clone)]
 pub struct FunctionContext {
     pub tz: Tz,
+    pub sequence_generator: Option<Arc<dyn SequenceGenerator>>,
+    pub dictionary_provider: Option<Arc<dyn DictionaryProvider>>,
 }
 
 impl Default for FunctionContext {
     fn default() -> Self {
         Self {
             tz: "UTC".parse::<Tz>().unwrap(),
+            sequence_generator: None,
+            dictionary_provider: None,
         }
     }
 }
 
+/// Generates the values of the sequences of the tenant of the query.
+pub trait SequenceGenerator: Sync + Send {
+    /// The next `count` values of the sequence.
+    fn next_values(&self, name: &str, count: u64) -> Result<Vec<i64>>;
+}
+
+/// Looks up the dictionaries of the tenant of the query.
+pub trait DictionaryProvider: Sync + Send {
+    /// The values of the attribute of the rows of the keys, `None` if there is no row of the key.
+    fn get_values(
+        &self,
+        dictionary: &str,
+        attribute: &str,
+        keys: &ColumnRef,
+    ) -> Result<Vec<Option<DataValue>>>;
+}
+
 pub trait Function: fmt::Display + Sync + Send + DynClone {
     /// Returns the name of the function, should be unique.
     fn name(&self) -> &str;
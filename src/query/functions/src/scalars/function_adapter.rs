@@ -28,6 +28,7 @@ use common_datavalues::ConstColumn;
 use common_datavalues::DataField;
 use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::DictionaryColumn;
 use common_datavalues::NullColumn;
 use common_datavalues::NullType;
 use common_datavalues::NullableColumn;
@@ -44,6 +45,9 @@ use crate::scalars::FunctionContext;
 pub struct FunctionAdapter {
     inner: Option<Box<dyn Function>>,
     has_nullable: bool,
+    // The function is evaluated on the distinct strings of a column encoded by a dictionary only
+    // if the result of a row depends on nothing but the arguments of the row.
+    deterministic: bool,
 }
 
 impl FunctionAdapter {
@@ -51,6 +55,7 @@ impl FunctionAdapter {
         Box::new(Self {
             inner: Some(inner),
             has_nullable,
+            deterministic: false,
         })
     }
 
@@ -65,6 +70,7 @@ impl FunctionAdapter {
                 return Ok(Box::new(Self {
                     inner: None,
                     has_nullable: false,
+                    deterministic: false,
                 }));
             }
 
@@ -76,7 +82,58 @@ impl FunctionAdapter {
             ((desc.function_creator)(name, args)?, false)
         };
 
-        Ok(Self::create(inner, has_nullable))
+        Ok(Box::new(Self {
+            inner: Some(inner),
+            has_nullable,
+            deterministic: desc.features.is_deterministic,
+        }))
+    }
+
+    // A function of one column encoded by a dictionary and of constants is evaluated on the
+    // distinct strings of the dictionary only, and the result of each row is taken by its key.
+    // The columns encoded by dictionaries are expanded for the other functions.
+    fn eval_dictionary(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let dictionaries = columns
+            .iter()
+            .filter(|v| v.column().is_dictionary())
+            .count();
+        let only_constants = columns
+            .iter()
+            .all(|v| v.column().is_dictionary() || v.column().is_const());
+
+        if self.deterministic && self.passthrough_constant() && dictionaries == 1 && only_constants
+        {
+            let dictionary = columns.iter().find(|v| v.column().is_dictionary()).unwrap();
+            let dictionary: &DictionaryColumn = Series::check_get(dictionary.column())?;
+            let values = dictionary.values().arc();
+
+            let columns = columns
+                .iter()
+                .map(|v| match v.column().is_dictionary() {
+                    true => ColumnWithField::new(values.clone(), v.field().clone()),
+                    false => {
+                        ColumnWithField::new(v.column().slice(0, values.len()), v.field().clone())
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let col = self.eval(func_ctx, &columns, values.len())?;
+            return Series::take(&col, dictionary.keys());
+        }
+
+        let columns = columns
+            .iter()
+            .map(|v| ColumnWithField::new(Series::remove_dictionary(v.column()), v.field().clone()))
+            .collect::<Vec<_>>();
+        self.inner
+            .as_ref()
+            .unwrap()
+            .eval(func_ctx, &columns, input_rows)
     }
 }
 
@@ -192,6 +249,11 @@ impl Function for FunctionAdapter {
             return Ok(col);
         }
 
+        // dictionary
+        if columns.iter().any(|v| has_dictionary(v.column())) {
+            return self.eval_dictionary(func_ctx, columns, input_rows);
+        }
+
         inner.eval(func_ctx, columns, input_rows)
     }
 
@@ -219,3 +281,12 @@ impl std::fmt::Display for FunctionAdapter {
         }
     }
 }
+
+// If the column, or the inner column of a nullable column, is encoded by a dictionary.
+fn has_dictionary(column: &ColumnRef) -> bool {
+    if column.is_nullable() && !column.is_const() {
+        let column: &NullableColumn = unsafe { Series::static_cast(column) };
+        return column.inner().is_dictionary();
+    }
+    column.is_dictionary()
+}
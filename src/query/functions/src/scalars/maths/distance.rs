@@ -0,0 +1,191 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+const FUNC_COSINE: u8 = 1;
+const FUNC_L2: u8 = 2;
+
+/// `cosine_distance(vector, vector)`, one minus the cosine of the angle of the vectors.
+pub type CosineDistanceFunction = DistanceFunction<FUNC_COSINE>;
+/// `l2_distance(vector, vector)`, the euclidean distance of the vectors.
+pub type L2DistanceFunction = DistanceFunction<FUNC_L2>;
+
+/// The distance of the vectors, the arrays of numbers of the same dimension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "cosine_distance" => Some(VectorDistance::Cosine),
+            "l2_distance" => Some(VectorDistance::L2),
+            _ => None,
+        }
+    }
+
+    /// The cosine distance of the zero vector is NaN, it has no direction.
+    pub fn eval(&self, a: &[f64], b: &[f64]) -> f64 {
+        match self {
+            VectorDistance::Cosine => {
+                let (mut dot, mut norm_a, mut norm_b) = (0.0, 0.0, 0.0);
+                for (x, y) in a.iter().zip(b.iter()) {
+                    dot += x * y;
+                    norm_a += x * x;
+                    norm_b += y * y;
+                }
+                1.0 - dot / (norm_a.sqrt() * norm_b.sqrt())
+            }
+            VectorDistance::L2 => a
+                .iter()
+                .zip(b.iter())
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f64>()
+                .sqrt(),
+        }
+    }
+}
+
+/// Whether the type is an array of numbers, which is a vector.
+pub fn is_vector_type(data_type: &DataTypeImpl) -> bool {
+    match remove_nullable(data_type) {
+        DataTypeImpl::Array(array_type) => remove_nullable(array_type.inner_type())
+            .data_type_id()
+            .is_numeric(),
+        _ => false,
+    }
+}
+
+/// Read the vector of the row of the array column into the buffer.
+pub fn read_vector(column: &ArrayColumn, row: usize, vector: &mut Vec<f64>) -> Result<()> {
+    let offsets = column.offsets();
+    let values = column.values();
+    vector.clear();
+    for i in offsets[row] as usize..offsets[row + 1] as usize {
+        if values.null_at(i) {
+            return Err(ErrorCode::BadArguments(
+                "the elements of the vector must not be NULL",
+            ));
+        }
+        vector.push(values.get_f64(i)?);
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct DistanceFunction<const T: u8> {
+    display_name: String,
+}
+
+impl<const T: u8> DistanceFunction<T> {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        for arg in args {
+            if !is_vector_type(arg) {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Invalid argument types for function '{}': ({}, {}), expected arrays of numbers",
+                    display_name.to_uppercase(),
+                    args[0].name(),
+                    args[1].name(),
+                )));
+            }
+        }
+        Ok(Box::new(DistanceFunction::<T> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+
+    fn distance() -> VectorDistance {
+        match T {
+            FUNC_COSINE => VectorDistance::Cosine,
+            _ => VectorDistance::L2,
+        }
+    }
+}
+
+impl<const T: u8> Function for DistanceFunction<T> {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        Float64Type::new_impl()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        // The constant vector is read once, the other one is read row by row.
+        let mut arrays = Vec::with_capacity(2);
+        for column in columns.iter() {
+            let array_column: &ArrayColumn = if column.column().is_const() {
+                let const_column: &ConstColumn = Series::check_get(column.column())?;
+                Series::check_get(const_column.inner())?
+            } else {
+                Series::check_get(column.column())?
+            };
+            arrays.push((array_column, column.column().is_const()));
+        }
+
+        let distance = Self::distance();
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        let mut values = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let (array, is_const) = arrays[0];
+            if !is_const || row == 0 {
+                read_vector(array, if is_const { 0 } else { row }, &mut a)?;
+            }
+            let (array, is_const) = arrays[1];
+            if !is_const || row == 0 {
+                read_vector(array, if is_const { 0 } else { row }, &mut b)?;
+            }
+            if a.len() != b.len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "the vectors of function '{}' have different dimensions: {} and {}",
+                    self.display_name.to_uppercase(),
+                    a.len(),
+                    b.len()
+                )));
+            }
+            values.push(distance.eval(&a, &b));
+        }
+        Ok(Arc::new(PrimitiveColumn::<f64>::new_from_vec(values)))
+    }
+}
+
+impl<const T: u8> fmt::Display for DistanceFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -19,10 +19,12 @@ use super::round::TruncNumberFunction;
 use crate::scalars::AbsFunction;
 use crate::scalars::BaseHashFunction;
 use crate::scalars::CeilFunction;
+use crate::scalars::CosineDistanceFunction;
 use crate::scalars::DegressFunction;
 use crate::scalars::ExpFunction;
 use crate::scalars::FloorFunction;
 use crate::scalars::FunctionFactory;
+use crate::scalars::L2DistanceFunction;
 use crate::scalars::LnFunction;
 use crate::scalars::Log10Function;
 use crate::scalars::Log2Function;
@@ -79,5 +81,8 @@ impl MathsFunction {
 
         factory.register("degrees", DegressFunction::desc());
         factory.register("radians", RadiansFunction::desc());
+
+        factory.register("cosine_distance", CosineDistanceFunction::desc());
+        factory.register("l2_distance", L2DistanceFunction::desc());
     }
 }
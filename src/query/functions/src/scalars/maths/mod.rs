@@ -15,6 +15,7 @@
 mod abs;
 mod angle;
 mod ceil;
+mod distance;
 mod exp;
 mod floor;
 mod log;
@@ -31,6 +32,12 @@ pub use abs::AbsFunction;
 pub use angle::DegressFunction;
 pub use angle::RadiansFunction;
 pub use ceil::CeilFunction;
+pub use distance::is_vector_type;
+pub use distance::read_vector;
+pub use distance::CosineDistanceFunction;
+pub use distance::DistanceFunction;
+pub use distance::L2DistanceFunction;
+pub use distance::VectorDistance;
 pub use exp::ExpFunction;
 pub use floor::FloorFunction;
 pub use log::LnFunction;
@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `dict_get_or_default(dictionary, attribute, key, default)`, the attribute of the row of the key
+/// in the dictionary, or the default if there is no row of the key. The key is of the type of the
+/// primary key, and the default is of the type of the attribute.
+#[derive(Clone)]
+pub struct DictGetOrDefaultFunction {
+    display_name: String,
+    return_type: DataTypeImpl,
+}
+
+impl DictGetOrDefaultFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_string(args[0])?;
+        assert_string(args[1])?;
+        Ok(Box::new(DictGetOrDefaultFunction {
+            display_name: display_name.to_string(),
+            return_type: args[3].clone(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .disable_passthrough_null()
+                .num_arguments(4),
+        )
+    }
+
+    fn constant_name(&self, column: &ColumnRef, what: &str) -> Result<String> {
+        if !column.is_const() {
+            return Err(ErrorCode::BadArguments(format!(
+                "the {} of function {} must be a constant",
+                what, self.display_name
+            )));
+        }
+        let names = Vu8::try_create_viewer(column)?;
+        Ok(String::from_utf8_lossy(names.value_at(0)).to_string())
+    }
+}
+
+impl Function for DictGetOrDefaultFunction {
+    fn name(&self) -> &str {
+        "DictGetOrDefaultFunction"
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.return_type.clone()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &common_datavalues::ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<common_datavalues::ColumnRef> {
+        let provider = func_ctx.dictionary_provider.ok_or_else(|| {
+            ErrorCode::UnImplement(format!(
+                "function {} is not supported here",
+                self.display_name
+            ))
+        })?;
+        if input_rows == 0 {
+            return self.return_type.create_column(&[]);
+        }
+
+        let dictionary = self.constant_name(columns[0].column(), "dictionary")?;
+        let attribute = self.constant_name(columns[1].column(), "attribute")?;
+        let defaults = columns[3].column();
+        let values = provider
+            .get_values(&dictionary, &attribute, columns[2].column())?
+            .into_iter()
+            .enumerate()
+            .map(|(row, value)| value.unwrap_or_else(|| defaults.get(row)))
+            .collect::<Vec<_>>();
+        self.return_type.create_column(&values)
+    }
+
+    // The lookup of the constant key is not constant, the dictionary is loaded again.
+    fn passthrough_constant(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for DictGetOrDefaultFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "dict_get_or_default")
+    }
+}
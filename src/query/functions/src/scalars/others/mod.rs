@@ -13,11 +13,13 @@
 // limitations under the License.
 
 mod assume_not_null;
+mod dict_get;
 mod exists;
 mod humanize;
 mod ignore;
 mod inet_aton;
 mod inet_ntoa;
+mod nextval;
 mod other;
 mod running_difference_function;
 mod sleep;
@@ -25,6 +27,7 @@ mod to_nullable;
 mod type_of;
 
 pub use assume_not_null::AssumeNotNullFunction;
+pub use dict_get::DictGetOrDefaultFunction;
 pub use exists::ExistsFunction;
 pub use humanize::HumanizeNumberFunction;
 pub use humanize::HumanizeSizeFunction;
@@ -33,6 +36,7 @@ pub use inet_aton::InetAtonFunction;
 pub use inet_aton::TryInetAtonFunction;
 pub use inet_ntoa::InetNtoaFunction;
 pub use inet_ntoa::TryInetNtoaFunction;
+pub use nextval::NextvalFunction;
 pub use other::OtherFunction;
 pub use running_difference_function::RunningDifferenceFunction;
 pub use sleep::SleepFunction;
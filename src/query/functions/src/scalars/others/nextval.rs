@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// The next value of the sequence of the name, a different value for each row.
+#[derive(Clone)]
+pub struct NextvalFunction {
+    display_name: String,
+}
+
+impl NextvalFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_string(args[0])?;
+        Ok(Box::new(NextvalFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().num_arguments(1))
+    }
+}
+
+impl Function for NextvalFunction {
+    fn name(&self) -> &str {
+        "NextvalFunction"
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        Int64Type::new_impl()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &common_datavalues::ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<common_datavalues::ColumnRef> {
+        let generator = func_ctx.sequence_generator.ok_or_else(|| {
+            ErrorCode::UnImplement(format!(
+                "function {} is not supported here",
+                self.display_name
+            ))
+        })?;
+        if input_rows == 0 {
+            return Ok(Series::from_data(Vec::<i64>::new()));
+        }
+
+        let column = columns[0].column();
+        let names = Vu8::try_create_viewer(column)?;
+        let values = if column.is_const() {
+            let name = String::from_utf8_lossy(names.value_at(0));
+            generator.next_values(&name, input_rows as u64)?
+        } else {
+            let mut values = Vec::with_capacity(input_rows);
+            for name in names.iter() {
+                let name = String::from_utf8_lossy(name);
+                values.extend(generator.next_values(&name, 1)?);
+            }
+            values
+        };
+        Ok(Series::from_data(values))
+    }
+
+    // The function of the constant name is not constant.
+    fn passthrough_constant(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for NextvalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "nextval")
+    }
+}
@@ -20,8 +20,10 @@ use super::inet_aton::TryInetAtonFunction;
 use super::inet_ntoa::InetNtoaFunction;
 use super::inet_ntoa::TryInetNtoaFunction;
 use super::running_difference_function::RunningDifferenceFunction;
+use super::DictGetOrDefaultFunction;
 use super::ExistsFunction;
 use super::IgnoreFunction;
+use super::NextvalFunction;
 use super::SleepFunction;
 use super::ToNullableFunction;
 use super::TypeOfFunction;
@@ -55,5 +57,7 @@ impl OtherFunction {
 
         factory.register("assume_not_null", AssumeNotNullFunction::desc());
         factory.register("to_nullable", ToNullableFunction::desc());
+        factory.register("nextval", NextvalFunction::desc());
+        factory.register("dict_get_or_default", DictGetOrDefaultFunction::desc());
     }
 }
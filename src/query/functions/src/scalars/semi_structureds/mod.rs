@@ -30,6 +30,7 @@ pub use as_type::as_function_creator;
 pub use check_json::CheckJsonFunction;
 pub use get::GetFunction;
 pub use get::GetIgnoreCaseFunction;
+pub use get_path::parse_path_keys;
 pub use get_path::GetPathFunction;
 pub use json_extract_path_text::JsonExtractPathTextFunction;
 pub use length::VariantArrayLengthFunction;
@@ -0,0 +1,206 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use bstr::ByteSlice;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// The collation of the strings, by which the strings are compared, sorted and grouped.
+///
+/// The strings are collated by their keys: two strings are equal in the collation if their keys
+/// are equal, and ordered as the bytes of their keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Collation {
+    /// The bytes of the strings, the default.
+    Binary,
+    /// `utf8_ci`, the strings different in the case only are equal, the key is the lowercase.
+    Utf8CaseInsensitive,
+    /// The rules of the locale by ICU, e.g. `de_DE` or `sv`, the key is the sort key of ICU.
+    Locale(String),
+}
+
+impl Collation {
+    pub fn from_name(name: &str) -> Result<Collation> {
+        match name.to_lowercase().as_str() {
+            "binary" => Ok(Collation::Binary),
+            "utf8_ci" => Ok(Collation::Utf8CaseInsensitive),
+            _ => {
+                let collation = Collation::Locale(name.to_string());
+                collation.check_locale()?;
+                Ok(collation)
+            }
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Collation::Binary => "binary",
+            Collation::Utf8CaseInsensitive => "utf8_ci",
+            Collation::Locale(locale) => locale,
+        }
+    }
+
+    pub fn is_binary(&self) -> bool {
+        matches!(self, Collation::Binary)
+    }
+
+    /// The keys of the strings of the column.
+    pub fn key_column(&self, column: &ColumnRef) -> Result<ColumnRef> {
+        if self.is_binary() {
+            return Ok(column.clone());
+        }
+        let viewer = Vu8::try_create_viewer(column)?;
+        let mut builder = ColumnBuilder::<Vu8>::with_capacity(viewer.size());
+        match self {
+            Collation::Binary => unreachable!(),
+            Collation::Utf8CaseInsensitive => {
+                let mut key = String::new();
+                for value in viewer.iter() {
+                    key.clear();
+                    for ch in value.chars() {
+                        key.extend(ch.to_lowercase());
+                    }
+                    builder.append(key.as_bytes());
+                }
+            }
+            Collation::Locale(locale) => {
+                let collator = icu::Collator::try_create(locale)?;
+                for value in viewer.iter() {
+                    builder.append(&collator.sort_key(&value.to_str_lossy())?);
+                }
+            }
+        }
+        Ok(builder.build(viewer.size()))
+    }
+
+    fn check_locale(&self) -> Result<()> {
+        match self {
+            Collation::Locale(locale) => icu::Collator::try_create(locale).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "icu")]
+mod icu {
+    use std::convert::TryFrom;
+
+    use common_exception::ErrorCode;
+    use common_exception::Result;
+    use rust_icu_ucol::UCollator;
+    use rust_icu_ustring::UChar;
+
+    pub struct Collator(UCollator);
+
+    impl Collator {
+        pub fn try_create(locale: &str) -> Result<Collator> {
+            UCollator::try_from(locale).map(Collator).map_err(|e| {
+                ErrorCode::UnknownCollation(format!("unknown collation {}: {}", locale, e))
+            })
+        }
+
+        pub fn sort_key(&self, value: &str) -> Result<Vec<u8>> {
+            let value = UChar::try_from(value)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid string to collate: {}", e)))?;
+            Ok(self.0.get_sort_key(&value))
+        }
+    }
+}
+
+#[cfg(not(feature = "icu"))]
+mod icu {
+    use common_exception::ErrorCode;
+    use common_exception::Result;
+
+    pub struct Collator;
+
+    impl Collator {
+        pub fn try_create(locale: &str) -> Result<Collator> {
+            Err(ErrorCode::UnknownCollation(format!(
+                "unknown collation {}, the locale collations require the server built with the `icu` feature",
+                locale
+            )))
+        }
+
+        pub fn sort_key(&self, _value: &str) -> Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+}
+
+/// `collation_key(<string>, '<collation>')`, the key of the string in the collation, which
+/// `<string> COLLATE '<collation>'` is bound to.
+#[derive(Clone)]
+pub struct CollationKeyFunction {
+    display_name: String,
+}
+
+impl CollationKeyFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        for arg in args {
+            assert_string(arg)?;
+        }
+
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for CollationKeyFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        StringType::new_impl()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        _input_rows: usize,
+    ) -> Result<ColumnRef> {
+        if !columns[1].column().is_const() {
+            return Err(ErrorCode::BadArguments(format!(
+                "the collation of {} must be a constant",
+                self.display_name
+            )));
+        }
+        let name = columns[1].column().get_string(0)?;
+        let collation = Collation::from_name(&String::from_utf8_lossy(&name))?;
+        collation.key_column(columns[0].column())
+    }
+}
+
+impl fmt::Display for CollationKeyFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
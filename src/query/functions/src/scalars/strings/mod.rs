@@ -18,6 +18,7 @@ mod bin;
 mod bit_length;
 mod char_;
 mod char_length;
+mod collation;
 mod concat;
 mod concat_ws;
 mod elt;
@@ -52,6 +53,7 @@ mod string2number;
 mod string2string;
 mod substring;
 mod substring_index;
+mod text_match;
 mod trim;
 mod unhex;
 mod upper;
@@ -63,6 +65,8 @@ pub use bin::BinFunction;
 pub use bit_length::BitLengthFunction;
 pub use char_::CharFunction;
 pub use char_length::CharLengthFunction;
+pub use collation::Collation;
+pub use collation::CollationKeyFunction;
 pub use concat::ConcatFunction;
 pub use concat_ws::ConcatWsFunction;
 pub use elt::EltFunction;
@@ -104,6 +108,11 @@ pub use string2string::String2StringFunction;
 pub use string2string::StringOperator;
 pub use substring::SubstringFunction;
 pub use substring_index::SubstringIndexFunction;
+pub use text_match::tokenize;
+pub use text_match::MatchFunction;
+pub use text_match::QueryFunction;
+pub use text_match::TextMatchFunction;
+pub use text_match::TextQuery;
 pub use trim::LTrimFunction;
 pub use trim::RTrimFunction;
 pub use trim::TrimFunction;
@@ -19,6 +19,7 @@ use crate::scalars::BinFunction;
 use crate::scalars::BitLengthFunction;
 use crate::scalars::CharFunction;
 use crate::scalars::CharLengthFunction;
+use crate::scalars::CollationKeyFunction;
 use crate::scalars::ConcatFunction;
 use crate::scalars::ConcatWsFunction;
 use crate::scalars::EltFunction;
@@ -35,10 +36,12 @@ use crate::scalars::LeftFunction;
 use crate::scalars::LeftPadFunction;
 use crate::scalars::LocateFunction;
 use crate::scalars::LowerFunction;
+use crate::scalars::MatchFunction;
 use crate::scalars::OctFunction;
 use crate::scalars::OctetLengthFunction;
 use crate::scalars::OrdFunction;
 use crate::scalars::PositionFunction;
+use crate::scalars::QueryFunction;
 use crate::scalars::QuoteFunction;
 use crate::scalars::RTrimFunction;
 use crate::scalars::RegexpInStrFunction;
@@ -87,6 +90,7 @@ impl StringFunction {
         factory.register("octet_length", OctetLengthFunction::desc());
         factory.register("char_length", CharLengthFunction::desc());
         factory.register("character_length", CharLengthFunction::desc());
+        factory.register("collation_key", CollationKeyFunction::desc());
         factory.register("ord", OrdFunction::desc());
         factory.register("regexp_instr", RegexpInStrFunction::desc());
         factory.register("regexp_like", RegexpLikeFunction::desc());
@@ -120,5 +124,7 @@ impl StringFunction {
         factory.register("locate", LocateFunction::desc());
         factory.register("position", PositionFunction::desc());
         factory.register("instr", InstrFunction::desc());
+        factory.register("match", MatchFunction::desc());
+        factory.register("query", QueryFunction::desc());
     }
 }
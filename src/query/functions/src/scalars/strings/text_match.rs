@@ -0,0 +1,201 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use bstr::ByteSlice;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+const FUNC_MATCH: u8 = 1;
+const FUNC_QUERY: u8 = 2;
+
+/// `match(column, 'text')`, true if the value has all the tokens of the text.
+pub type MatchFunction = TextMatchFunction<FUNC_MATCH>;
+/// `query(column, 'query')`, true if the value matches the query, see [`TextQuery::parse`].
+pub type QueryFunction = TextMatchFunction<FUNC_QUERY>;
+
+/// Split the text into the lowercase tokens, the runs of the alphanumeric characters.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+}
+
+/// The query of the tokens, the values match if they match any of the clauses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextQuery {
+    clauses: Vec<TextClause>,
+}
+
+/// The clause matches the values having all the required tokens, and having not all the tokens
+/// of any of the excluded terms.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TextClause {
+    required: Vec<String>,
+    excluded: Vec<Vec<String>>,
+}
+
+impl TextQuery {
+    /// The query of `match()`, all the tokens of the text are required.
+    pub fn from_match(text: &str) -> TextQuery {
+        TextQuery {
+            clauses: vec![TextClause {
+                required: tokenize(text).collect(),
+                excluded: vec![],
+            }],
+        }
+    }
+
+    /// Parse the query of `query()`, the terms separated by spaces are all required, the terms
+    /// prefixed by `-` are excluded, and the clauses of the terms are separated by `OR`, e.g.
+    /// `error timeout OR panic -test`.
+    pub fn parse(query: &str) -> Result<TextQuery> {
+        let invalid = || ErrorCode::BadArguments(format!("invalid text query: '{}'", query));
+
+        let mut clauses = vec![];
+        let mut clause = TextClause {
+            required: vec![],
+            excluded: vec![],
+        };
+        let mut is_empty = true;
+        for term in query.split_whitespace() {
+            if term == "OR" {
+                if is_empty {
+                    return Err(invalid());
+                }
+                clauses.push(std::mem::replace(&mut clause, TextClause {
+                    required: vec![],
+                    excluded: vec![],
+                }));
+                is_empty = true;
+                continue;
+            }
+
+            match term.strip_prefix('-') {
+                Some(term) => {
+                    let tokens = tokenize(term).collect::<Vec<_>>();
+                    if !tokens.is_empty() {
+                        clause.excluded.push(tokens);
+                    }
+                }
+                None => clause.required.extend(tokenize(term)),
+            }
+            is_empty = false;
+        }
+        if is_empty {
+            return Err(invalid());
+        }
+        clauses.push(clause);
+        Ok(TextQuery { clauses })
+    }
+
+    /// Whether the text matches the query.
+    pub fn matches(&self, text: &str) -> bool {
+        let tokens = tokenize(text).collect::<HashSet<_>>();
+        let contains = |token: &String| tokens.contains(token);
+        self.clauses.iter().any(|clause| {
+            clause.required.iter().all(contains)
+                && !clause.excluded.iter().any(|term| term.iter().all(contains))
+        })
+    }
+
+    /// Whether the values of the tokens may match the query, false only if none of the clauses
+    /// has all of its required tokens. The excluded terms can't tell the values don't match.
+    pub fn may_match(&self, contains: impl Fn(&str) -> bool) -> bool {
+        self.clauses
+            .iter()
+            .any(|clause| clause.required.iter().all(|token| contains(token)))
+    }
+}
+
+#[derive(Clone)]
+pub struct TextMatchFunction<const T: u8> {
+    display_name: String,
+}
+
+impl<const T: u8> TextMatchFunction<T> {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_string(args[0])?;
+        assert_string(args[1])?;
+        Ok(Box::new(TextMatchFunction::<T> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+
+    fn parse_query(&self, text: &[u8]) -> Result<TextQuery> {
+        let text = text.to_str_lossy();
+        if T == FUNC_MATCH {
+            Ok(TextQuery::from_match(&text))
+        } else {
+            TextQuery::parse(&text)
+        }
+    }
+}
+
+impl<const T: u8> Function for TextMatchFunction<T> {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        BooleanType::new_impl()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(input_rows);
+        let values = Vu8::try_create_viewer(columns[0].column())?;
+
+        let query_column = columns[1].column();
+        if query_column.is_const() {
+            let query = self.parse_query(&query_column.get_string(0)?)?;
+            for value in values.iter() {
+                builder.append(query.matches(&value.to_str_lossy()));
+            }
+        } else {
+            let queries = Vu8::try_create_viewer(query_column)?;
+            for (value, query) in values.iter().zip(queries.iter()) {
+                let query = self.parse_query(query)?;
+                builder.append(query.matches(&value.to_str_lossy()));
+            }
+        }
+
+        Ok(Arc::new(builder.build_column()))
+    }
+}
+
+impl<const T: u8> fmt::Display for TextMatchFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
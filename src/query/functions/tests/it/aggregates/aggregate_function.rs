@@ -252,6 +252,42 @@ fn test_aggregate_function() -> Result<()> {
             input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
             expect_array: Series::from_data([-1.25000f64]),
         },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<u64>::default()),
+            expect_array: Series::from_data([4u64]),
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(0.25)],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([1.75f64]),
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([2.5f64]),
+        },
         Test {
             name: "window-funnel-passed",
             eval_nums: 2,
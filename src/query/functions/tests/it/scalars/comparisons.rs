@@ -416,3 +416,55 @@ fn test_not_regexp_comparison_function() -> Result<()> {
 
     test_scalar_functions("not regexp", &tests)
 }
+
+#[test]
+fn test_dictionary_comparison_function() -> Result<()> {
+    let dictionary = || -> ColumnRef {
+        DictionaryColumn::new(
+            vec![0u32, 1, 2, 1, 0].into(),
+            StringColumn::new_from_slice(&["a", "b", "c"]),
+        )
+        .arc()
+    };
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "dictionary-const-passed",
+            columns: vec![
+                dictionary(),
+                ConstColumn::new(Series::from_data(vec!["b"]), 5).arc(),
+            ],
+            expect: Series::from_data(vec![false, true, false, true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "dictionary-string-passed",
+            columns: vec![
+                dictionary(),
+                Series::from_data(vec!["a", "a", "c", "c", "b"]),
+            ],
+            expect: Series::from_data(vec![true, false, true, false, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullable-dictionary-const-passed",
+            columns: vec![
+                NullableColumn::wrap_inner(
+                    dictionary(),
+                    Some([true, false, true, true, true].into_iter().collect()),
+                ),
+                ConstColumn::new(Series::from_data(vec!["b"]), 5).arc(),
+            ],
+            expect: Series::from_data(vec![
+                Some(false),
+                None,
+                Some(false),
+                Some(true),
+                Some(false),
+            ]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions("=", &tests)
+}
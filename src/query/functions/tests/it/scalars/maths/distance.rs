@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::scalar_function_test::test_scalar_functions;
+use crate::scalars::scalar_function_test::ScalarFunctionTest;
+
+fn vectors(offsets: Vec<i64>, values: Vec<f32>) -> ColumnRef {
+    Arc::new(ArrayColumn::from_data(
+        DataTypeImpl::Array(ArrayType::create(Float32Type::new_impl())),
+        offsets.into(),
+        Series::from_data(values),
+    ))
+}
+
+fn const_vector(values: Vec<f32>, rows: usize) -> ColumnRef {
+    let offsets = vec![0, values.len() as i64];
+    ConstColumn::new(vectors(offsets, values), rows).arc()
+}
+
+#[test]
+fn test_cosine_distance_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "cosine-distance-with-constant",
+            columns: vec![
+                vectors(vec![0, 2, 4, 6], vec![1.0, 0.0, 0.0, 2.0, -2.0, 0.0]),
+                const_vector(vec![1.0, 0.0], 3),
+            ],
+            expect: Series::from_data([0_f64, 1.0, 2.0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "cosine-distance-with-series",
+            columns: vec![
+                vectors(vec![0, 2, 4], vec![1.0, 0.0, 0.0, 3.0]),
+                vectors(vec![0, 2, 4], vec![3.0, 0.0, 0.0, -1.0]),
+            ],
+            expect: Series::from_data([0_f64, 2.0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "cosine-distance-different-dimensions",
+            columns: vec![
+                vectors(vec![0, 2], vec![1.0, 0.0]),
+                const_vector(vec![1.0, 0.0, 0.0], 1),
+            ],
+            expect: Series::from_data([0_f64]),
+            error: "the vectors of function 'COSINE_DISTANCE' have different dimensions: 2 and 3",
+        },
+    ];
+
+    test_scalar_functions("cosine_distance", &tests)
+}
+
+#[test]
+fn test_l2_distance_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "l2-distance-with-constant",
+            columns: vec![
+                vectors(vec![0, 2, 4, 6], vec![1.0, 0.0, 0.0, 2.0, -2.0, 0.0]),
+                const_vector(vec![1.0, 0.0], 3),
+            ],
+            expect: Series::from_data([0_f64, 5_f64.sqrt(), 3.0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "l2-distance-of-integers",
+            columns: vec![
+                Series::from_data(vec![1i32]),
+                const_vector(vec![1.0, 0.0], 1),
+            ],
+            expect: Series::from_data([0_f64]),
+            error: "Invalid argument types for function 'L2_DISTANCE': (Int32, Array(Float32)), expected arrays of numbers",
+        },
+    ];
+
+    test_scalar_functions("l2_distance", &tests)
+}
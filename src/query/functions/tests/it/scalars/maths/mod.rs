@@ -16,6 +16,7 @@ mod abs;
 mod angle;
 mod ceil;
 mod crc32;
+mod distance;
 mod exp;
 mod floor;
 mod log;
@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::DictionaryProvider;
+use common_functions::scalars::FunctionContext;
+use common_functions::scalars::FunctionFactory;
+use pretty_assertions::assert_eq;
+
+// The dictionary `countries` of the keys 1 and 2.
+struct TestDictionaryProvider;
+
+impl DictionaryProvider for TestDictionaryProvider {
+    fn get_values(
+        &self,
+        dictionary: &str,
+        attribute: &str,
+        keys: &ColumnRef,
+    ) -> Result<Vec<Option<DataValue>>> {
+        if dictionary != "countries" || attribute != "name" {
+            return Err(ErrorCode::UnknownDictionary(format!(
+                "Unknown dictionary {}",
+                dictionary
+            )));
+        }
+        Ok((0..keys.len())
+            .map(|i| match keys.get(i) {
+                DataValue::UInt64(1) => Some(DataValue::String(b"France".to_vec())),
+                DataValue::UInt64(2) => Some(DataValue::String(b"Japan".to_vec())),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+fn eval_dict_get(
+    func_ctx: &FunctionContext,
+    dictionary: &str,
+    keys: ColumnRef,
+    defaults: ColumnRef,
+) -> Result<ColumnRef> {
+    let rows = keys.len();
+    let name = ConstColumn::new(Series::from_data(vec![dictionary]), rows).arc();
+    let attribute = ConstColumn::new(Series::from_data(vec!["name"]), rows).arc();
+    let columns = [name, attribute, keys, defaults];
+    let data_types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+    let func = FunctionFactory::instance().get(
+        "dict_get_or_default",
+        &data_types.iter().collect::<Vec<_>>(),
+    )?;
+    let arguments = columns
+        .into_iter()
+        .zip(data_types)
+        .enumerate()
+        .map(|(i, (column, data_type))| {
+            ColumnWithField::new(column, DataField::new(&format!("a{i}"), data_type))
+        })
+        .collect::<Vec<_>>();
+    func.eval(func_ctx.clone(), &arguments, rows)
+}
+
+#[test]
+fn test_dict_get_or_default_function() -> Result<()> {
+    let func_ctx = FunctionContext {
+        dictionary_provider: Some(Arc::new(TestDictionaryProvider)),
+        ..Default::default()
+    };
+
+    // The default is taken for the keys of no rows.
+    let keys = Series::from_data(vec![1u64, 3, 2]);
+    let defaults = ConstColumn::new(Series::from_data(vec!["unknown"]), 3).arc();
+    let column = eval_dict_get(&func_ctx, "countries", keys, defaults)?;
+    assert_eq!(
+        column,
+        Series::from_data(vec!["France", "unknown", "Japan"])
+    );
+
+    // The NULL default of `dict_get()`.
+    let keys = Series::from_data(vec![Some(2u64), None]);
+    let defaults = Series::from_data(vec![None::<&str>, None]);
+    let column = eval_dict_get(&func_ctx, "countries", keys, defaults)?;
+    assert_eq!(column, Series::from_data(vec![Some("Japan"), None]));
+
+    let keys = Series::from_data(vec![1u64]);
+    let defaults = Series::from_data(vec!["unknown"]);
+    let err = eval_dict_get(&func_ctx, "unknown", keys, defaults).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::unknown_dictionary_code());
+
+    // The dictionaries are not looked up without a provider, e.g. of a constant folding.
+    let keys = Series::from_data(vec![1u64]);
+    let defaults = Series::from_data(vec!["unknown"]);
+    assert!(eval_dict_get(&FunctionContext::default(), "countries", keys, defaults).is_err());
+
+    Ok(())
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dict_get;
 mod humanize;
 mod inet_aton;
 mod inet_ntoa;
+mod nextval;
 mod running_difference;
 mod type_of;
@@ -0,0 +1,85 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::FunctionContext;
+use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::SequenceGenerator;
+use pretty_assertions::assert_eq;
+
+// The sequences of the names, counting from 1.
+#[derive(Default)]
+struct TestSequenceGenerator {
+    sequences: Mutex<HashMap<String, i64>>,
+}
+
+impl SequenceGenerator for TestSequenceGenerator {
+    fn next_values(&self, name: &str, count: u64) -> Result<Vec<i64>> {
+        if name == "unknown" {
+            return Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            )));
+        }
+        let mut sequences = self.sequences.lock().unwrap();
+        let next = sequences.entry(name.to_string()).or_insert(1);
+        let values = (*next..*next + count as i64).collect();
+        *next += count as i64;
+        Ok(values)
+    }
+}
+
+fn eval_nextval(func_ctx: &FunctionContext, column: ColumnRef) -> Result<ColumnRef> {
+    let data_type = column.data_type();
+    let func = FunctionFactory::instance().get("nextval", &[&data_type])?;
+    let rows = column.len();
+    let arguments = [ColumnWithField::new(
+        column,
+        DataField::new("name", data_type),
+    )];
+    func.eval(func_ctx.clone(), &arguments, rows)
+}
+
+#[test]
+fn test_nextval_function() -> Result<()> {
+    let func_ctx = FunctionContext {
+        sequence_generator: Some(Arc::new(TestSequenceGenerator::default())),
+        ..Default::default()
+    };
+
+    // The values of a constant name are different for each row.
+    let name = ConstColumn::new(Series::from_data(vec!["a"]), 3).arc();
+    let column = eval_nextval(&func_ctx, name)?;
+    assert_eq!(column, Series::from_data(vec![1i64, 2, 3]));
+
+    let names = Series::from_data(vec!["a", "b", "a"]);
+    let column = eval_nextval(&func_ctx, names)?;
+    assert_eq!(column, Series::from_data(vec![4i64, 1, 5]));
+
+    let name = ConstColumn::new(Series::from_data(vec!["unknown"]), 1).arc();
+    let err = eval_nextval(&func_ctx, name).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::unknown_sequence_code());
+
+    // The sequences are not generated without a generator, e.g. of a constant folding.
+    let name = ConstColumn::new(Series::from_data(vec!["a"]), 1).arc();
+    assert!(eval_nextval(&FunctionContext::default(), name).is_err());
+
+    Ok(())
+}
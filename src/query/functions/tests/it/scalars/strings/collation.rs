@@ -0,0 +1,85 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::Collation;
+
+use crate::scalars::scalar_function_test::test_scalar_functions;
+use crate::scalars::scalar_function_test::ScalarFunctionTest;
+
+fn const_collation(name: &str, rows: usize) -> ColumnRef {
+    ConstColumn::new(Series::from_data(vec![name]), rows).arc()
+}
+
+#[test]
+fn test_collation_key_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "collation-key-binary",
+            columns: vec![
+                Series::from_data(vec!["Abc", "abc"]),
+                const_collation("binary", 2),
+            ],
+            expect: Series::from_data(vec!["Abc", "abc"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "collation-key-utf8-ci",
+            columns: vec![
+                Series::from_data(vec!["Abc", "ÄBC", "Dobrý DEN"]),
+                const_collation("utf8_ci", 3),
+            ],
+            expect: Series::from_data(vec!["abc", "äbc", "dobrý den"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "collation-key-not-constant",
+            columns: vec![
+                Series::from_data(vec!["Abc"]),
+                Series::from_data(vec!["utf8_ci"]),
+            ],
+            expect: Series::from_data(vec!["abc"]),
+            error: "the collation of collation_key must be a constant",
+        },
+    ];
+
+    test_scalar_functions("collation_key", &tests)
+}
+
+#[test]
+fn test_collation_key_nullable() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "collation-key-null-passed",
+        columns: vec![
+            Series::from_data(vec![Some("Abc"), None]),
+            const_collation("utf8_ci", 2),
+        ],
+        expect: Series::from_data(vec![Some("abc"), None]),
+        error: "",
+    }];
+
+    test_scalar_functions("collation_key", &tests)
+}
+
+#[test]
+fn test_collation_from_name() -> Result<()> {
+    assert_eq!(Collation::from_name("BINARY")?, Collation::Binary);
+    assert_eq!(
+        Collation::from_name("utf8_ci")?,
+        Collation::Utf8CaseInsensitive
+    );
+    assert_eq!(Collation::from_name("utf8_ci")?.name(), "utf8_ci");
+    Ok(())
+}
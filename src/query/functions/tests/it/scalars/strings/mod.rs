@@ -13,6 +13,7 @@
 // limitations under the License.
 
 // mod locate;
+mod collation;
 mod locate;
 mod lower;
 mod regexp_instr;
@@ -20,5 +21,6 @@ mod regexp_like;
 mod regexp_replace;
 mod regexp_substr;
 mod substring;
+mod text_match;
 mod trim;
 mod upper;
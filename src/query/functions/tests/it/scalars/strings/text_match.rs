@@ -0,0 +1,115 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::tokenize;
+use common_functions::scalars::TextQuery;
+
+use crate::scalars::scalar_function_test::test_scalar_functions;
+use crate::scalars::scalar_function_test::ScalarFunctionTest;
+
+#[test]
+fn test_tokenize() {
+    let tokens = tokenize("GET /api/v1/Users?id=42 -> 404 Not-Found").collect::<Vec<_>>();
+    assert_eq!(tokens, vec![
+        "get", "api", "v1", "users", "id", "42", "404", "not", "found"
+    ]);
+    assert_eq!(tokenize(" -- ").count(), 0);
+}
+
+#[test]
+fn test_text_query() -> Result<()> {
+    let query = TextQuery::parse("error timeout OR panic -test")?;
+    assert!(query.matches("ERROR: connect timeout"));
+    assert!(query.matches("thread panic at main.rs"));
+    assert!(!query.matches("error: connection refused"));
+    assert!(!query.matches("test panic, ignored"));
+
+    // the excluded terms don't prune
+    assert!(query.may_match(|token| token == "panic" || token == "test"));
+    assert!(!query.may_match(|token| token == "error"));
+
+    for invalid in ["", "  ", "OR error", "error OR", "error OR OR panic"] {
+        assert!(TextQuery::parse(invalid).is_err(), "{}", invalid);
+    }
+
+    let query = TextQuery::from_match("Not Found");
+    assert!(query.matches("404 not found"));
+    assert!(!query.matches("not available"));
+    Ok(())
+}
+
+#[test]
+fn test_match_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "match-passed",
+            columns: vec![
+                Series::from_data(vec!["user login failed", "login ok", "LOGIN-FAILED", ""]),
+                Series::from_data(vec!["login failed", "login failed", "login failed", "x"]),
+            ],
+            expect: Series::from_data(vec![true, false, true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "match-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some("disk full"), None]),
+                Series::from_data(vec![Some("full"), Some("full")]),
+            ],
+            expect: Series::from_data(vec![Some(true), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "match-type-error",
+            columns: vec![Series::from_data(vec!["abc"]), Series::from_data(vec![1])],
+            expect: Series::from_data(Vec::<bool>::new()),
+            error: "Expected a string type, but got Int32",
+        },
+    ];
+
+    test_scalar_functions("match", &tests)
+}
+
+#[test]
+fn test_query_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "query-passed",
+            columns: vec![
+                Series::from_data(vec![
+                    "error: timeout",
+                    "warn: timeout",
+                    "panic",
+                    "test panic",
+                ]),
+                Series::from_data(vec!["error timeout OR panic -test"; 4]),
+            ],
+            expect: Series::from_data(vec![true, false, true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "query-invalid",
+            columns: vec![
+                Series::from_data(vec!["error"]),
+                Series::from_data(vec!["OR error"]),
+            ],
+            expect: Series::from_data(Vec::<bool>::new()),
+            error: "invalid text query: 'OR error'",
+        },
+    ];
+
+    test_scalar_functions("query", &tests)
+}
@@ -17,4 +17,5 @@ pub enum StageKind {
     Normal,
     Expansive,
     Merge,
+    RoundRobin,
 }
@@ -23,6 +23,12 @@ pub trait PartInfo: Send + Sync {
 
     #[allow(clippy::borrowed_box)]
     fn equals(&self, info: &Box<dyn PartInfo>) -> bool;
+
+    /// The hash of the location of the data read by the part, the parts of the same hash
+    /// are expected to be read by the same node of the cluster to reuse the cached data.
+    fn location_hash(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl Debug for Box<dyn PartInfo> {
@@ -26,7 +26,10 @@ pub trait ClusterApi: Sync + Send {
     // Drop the tenant's cluster one node by node.id.
     async fn drop_node(&self, node_id: String, seq: Option<u64>) -> Result<()>;
 
-    // Keep the tenant's cluster node alive.
+    // Mark the node as draining, no new fragment is scheduled to it.
+    async fn drain_node(&self, node_id: &str) -> Result<()>;
+
+    // Keep the tenant's cluster node alive, and update the stats of the node.
     async fn heartbeat(&self, node: &NodeInfo, seq: Option<u64>) -> Result<u64>;
 
     async fn get_local_addr(&self) -> Result<Option<String>>;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::ops::Add;
 use std::time::Duration;
 use std::time::UNIX_EPOCH;
@@ -38,6 +39,8 @@ pub struct ClusterMgr {
     metastore: MetaStore,
     lift_time: Duration,
     cluster_prefix: String,
+    // The marks of the draining nodes, apart from the node infos rewritten by the heartbeats.
+    drain_prefix: String,
 }
 
 impl ClusterMgr {
@@ -62,6 +65,12 @@ impl ClusterMgr {
                 escape_for_key(tenant)?,
                 escape_for_key(cluster_id)?
             ),
+            drain_prefix: format!(
+                "{}/{}/{}/draining",
+                CLUSTER_API_KEY_PREFIX,
+                escape_for_key(tenant)?,
+                escape_for_key(cluster_id)?
+            ),
         })
     }
 
@@ -102,12 +111,20 @@ impl ClusterApi for ClusterMgr {
 
     async fn get_nodes(&self) -> Result<Vec<NodeInfo>> {
         let values = self.metastore.prefix_list_kv(&self.cluster_prefix).await?;
+        let draining = self
+            .metastore
+            .prefix_list_kv(&self.drain_prefix)
+            .await?
+            .into_iter()
+            .map(|(drain_key, _)| unescape_for_key(&drain_key[self.drain_prefix.len() + 1..]))
+            .collect::<Result<HashSet<_>>>()?;
 
         let mut nodes_info = Vec::with_capacity(values.len());
         for (node_key, value) in values {
             let mut node_info = serde_json::from_slice::<NodeInfo>(&value.data)?;
 
             node_info.id = unescape_for_key(&node_key[self.cluster_prefix.len() + 1..])?;
+            node_info.draining = draining.contains(&node_info.id);
             nodes_info.push(node_info);
         }
 
@@ -115,6 +132,17 @@ impl ClusterApi for ClusterMgr {
     }
 
     async fn drop_node(&self, node_id: String, seq: Option<u64>) -> Result<()> {
+        // The drain mark is dropped with the node, whether the node is draining or not.
+        let drain_key = format!("{}/{}", self.drain_prefix, escape_for_key(&node_id)?);
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &drain_key,
+                MatchSeq::Any,
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node_id)?);
         let upsert_node = self.metastore.upsert_kv(UpsertKVReq::new(
             &node_key,
@@ -136,6 +164,28 @@ impl ClusterApi for ClusterMgr {
         }
     }
 
+    async fn drain_node(&self, node_id: &str) -> Result<()> {
+        let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(node_id)?);
+        if self.metastore.get_kv(&node_key).await?.is_none() {
+            return Err(ErrorCode::ClusterUnknownNode(format!(
+                "unknown node {:?}",
+                node_id
+            )));
+        }
+
+        // The mark never expires, it is dropped with the node when the node shuts down.
+        let drain_key = format!("{}/{}", self.drain_prefix, escape_for_key(node_id)?);
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &drain_key,
+                MatchSeq::Any,
+                Operation::Update(node_id.as_bytes().to_vec()),
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
     async fn heartbeat(&self, node: &NodeInfo, seq: Option<u64>) -> Result<u64> {
         let meta = Some(self.new_lift_time());
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
@@ -144,9 +194,11 @@ impl ClusterApi for ClusterMgr {
             Some(exact) => MatchSeq::Exact(exact),
         };
 
-        let upsert_meta =
-            self.metastore
-                .upsert_kv(UpsertKVReq::new(&node_key, seq, Operation::AsIs, meta));
+        // Rewrites the node info to publish the stats of the node, besides refreshing the TTL.
+        let value = Operation::Update(serde_json::to_vec(node)?);
+        let upsert_meta = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&node_key, seq, value, meta));
 
         match upsert_meta.await? {
             UpsertKVReply {
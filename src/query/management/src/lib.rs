@@ -13,26 +13,53 @@
 // limitations under the License.
 
 mod cluster;
+mod connection;
+mod dictionary;
+mod masking_policy;
+mod network_policy;
+mod plan_hint;
 mod quota;
+mod resource_group;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use connection::ConnectionApi;
+pub use connection::ConnectionMgr;
+pub use dictionary::DictionaryApi;
+pub use dictionary::DictionaryMgr;
+pub use masking_policy::MaskingPolicyApi;
+pub use masking_policy::MaskingPolicyMgr;
+pub use network_policy::NetworkPolicyApi;
+pub use network_policy::NetworkPolicyMgr;
+pub use plan_hint::PlanHintApi;
+pub use plan_hint::PlanHintMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
+pub use resource_group::ResourceGroupApi;
+pub use resource_group::ResourceGroupMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
+pub use sequence::SequenceApi;
+pub use sequence::SequenceMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskApi;
+pub use task::TaskMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
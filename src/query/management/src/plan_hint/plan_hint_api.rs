@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::PlanHint;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait PlanHintApi: Sync + Send {
+    // Add a plan hint to /tenant/plan-hint-name.
+    async fn add_plan_hint(&self, plan_hint: PlanHint) -> Result<u64>;
+
+    // Get the plan hint by name.
+    async fn get_plan_hint(&self, name: &str, seq: Option<u64>) -> Result<SeqV<PlanHint>>;
+
+    // Get all the plan hints of a tenant.
+    async fn get_plan_hints(&self) -> Result<Vec<PlanHint>>;
+
+    // Drop the tenant's plan hint by name.
+    async fn drop_plan_hint(&self, name: &str, seq: Option<u64>) -> Result<()>;
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::ResourceGroup;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait ResourceGroupApi: Sync + Send {
+    // Add a resource group to /tenant/group-name.
+    async fn add_group(&self, group: ResourceGroup) -> Result<u64>;
+
+    // Get the resource group by name.
+    async fn get_group(&self, name: &str, seq: Option<u64>) -> Result<SeqV<ResourceGroup>>;
+
+    // Get all the resource groups of a tenant.
+    async fn get_groups(&self) -> Result<Vec<ResourceGroup>>;
+
+    // Drop the tenant's resource group by name.
+    async fn drop_group(&self, name: &str, seq: Option<u64>) -> Result<()>;
+}
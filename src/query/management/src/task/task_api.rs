@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::ScheduledTask;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    // Add a task to /tenant/task-name.
+    async fn add_task(&self, task: ScheduledTask) -> Result<u64>;
+
+    // Get the task by name.
+    async fn get_task(&self, name: &str, seq: Option<u64>) -> Result<SeqV<ScheduledTask>>;
+
+    // Get all the tasks of a tenant.
+    async fn get_tasks(&self) -> Result<Vec<ScheduledTask>>;
+
+    // Update the task if it's not changed since the seq.
+    async fn update_task(&self, task: ScheduledTask, seq: Option<u64>) -> Result<u64>;
+
+    // Drop the tenant's task by name.
+    async fn drop_task(&self, name: &str, seq: Option<u64>) -> Result<()>;
+
+    // Acquire or renew the lease of the scheduler of the tenant's tasks for the node, returns
+    // whether the node holds the lease. The lease expires after the ttl unless it's renewed.
+    async fn acquire_scheduler_lease(&self, node_id: &str, ttl_secs: u64) -> Result<bool>;
+
+    // Acquire or renew the lease of the scheduler of the tenant's background compactions for
+    // the node, the same as the lease of the scheduler of the tasks.
+    async fn acquire_compaction_scheduler_lease(
+        &self,
+        node_id: &str,
+        ttl_secs: u64,
+    ) -> Result<bool>;
+}
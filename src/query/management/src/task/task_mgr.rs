@@ -0,0 +1,182 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::IntoSeqV;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::Operation;
+use common_meta_types::ScheduledTask;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVReq;
+
+use crate::task::TaskApi;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_SCHEDULER_LEASE_KEY_PREFIX: &str = "__fd_task_scheduler";
+static COMPACTION_SCHEDULER_LEASE_KEY_PREFIX: &str = "__fd_compaction_scheduler";
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn KVApi>,
+    task_prefix: String,
+    lease_key: String,
+    compaction_lease_key: String,
+}
+
+impl TaskMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while task mgr create)",
+            ));
+        }
+
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, escape_for_key(tenant)?),
+            lease_key: format!(
+                "{}/{}",
+                TASK_SCHEDULER_LEASE_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+            compaction_lease_key: format!(
+                "{}/{}",
+                COMPACTION_SCHEDULER_LEASE_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    async fn acquire_lease(&self, key: &str, node_id: &str, ttl_secs: u64) -> Result<bool> {
+        let seq = match self.kv_api.get_kv(key).await? {
+            // the lease is held by another node and not expired yet
+            Some(value) if value.data != node_id.as_bytes() => return Ok(false),
+            Some(value) => MatchSeq::Exact(value.seq),
+            None => MatchSeq::Exact(0),
+        };
+
+        let expire_at = SystemTime::now()
+            .add(Duration::from_secs(ttl_secs))
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let meta = KVMeta {
+            expire_at: Some(expire_at.as_secs()),
+        };
+        let val = Operation::Update(node_id.as_bytes().to_vec());
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(key, seq, val, Some(meta)))
+            .await?;
+        // another node acquired the lease since it's read
+        Ok(res.changed())
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    async fn add_task(&self, task: ScheduledTask) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&task)?);
+        let key = format!("{}/{}", self.task_prefix, escape_for_key(&task.name)?);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::TaskAlreadyExists(format!("Task already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn get_task(&self, name: &str, seq: Option<u64>) -> Result<SeqV<ScheduledTask>> {
+        let key = format!("{}/{}", self.task_prefix, escape_for_key(name)?);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Unknown task {}", name)))?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownTask(format!("Unknown task {}", name))),
+        }
+    }
+
+    async fn get_tasks(&self) -> Result<Vec<ScheduledTask>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = serde_json::from_slice::<ScheduledTask>(&value.data)?;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    async fn update_task(&self, task: ScheduledTask, seq: Option<u64>) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&task)?);
+        let key = format!("{}/{}", self.task_prefix, escape_for_key(&task.name)?);
+        let match_seq = match seq {
+            Some(seq) => MatchSeq::Exact(seq),
+            // the task must exist
+            None => MatchSeq::GE(1),
+        };
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, val, None))
+            .await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) if res.changed() => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Unknown task, or seq not match {}",
+                task.name
+            ))),
+        }
+    }
+
+    async fn drop_task(&self, name: &str, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.task_prefix, escape_for_key(name)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq.into(), Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownTask(format!("Unknown task {}", name)))
+        }
+    }
+
+    async fn acquire_scheduler_lease(&self, node_id: &str, ttl_secs: u64) -> Result<bool> {
+        self.acquire_lease(&self.lease_key, node_id, ttl_secs).await
+    }
+
+    async fn acquire_compaction_scheduler_lease(
+        &self,
+        node_id: &str,
+        ttl_secs: u64,
+    ) -> Result<bool> {
+        self.acquire_lease(&self.compaction_lease_key, node_id, ttl_secs)
+            .await
+    }
+}
@@ -128,13 +128,49 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
     assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
 
     let current_time = current_seconds_time();
+    let mut node_info = node_info;
+    node_info.stats.heartbeat_on = current_time * 1000;
+    node_info.stats.running_queries = 2;
     cluster_api.heartbeat(&node_info, None).await?;
 
     let value = kv_api
         .get_kv("__fd_clusters/test%2dtenant%2did/test%2dcluster%2did/databend_query/test_node")
-        .await?;
+        .await?
+        .unwrap();
+
+    assert!(value.meta.unwrap().expire_at.unwrap() - current_time >= 60);
+
+    // The heartbeat publishes the load of the node.
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes, vec![node_info]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drain_node() -> Result<()> {
+    let (_, cluster_api) = new_cluster_api().await?;
+
+    let node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+    cluster_api.drain_node(&node_info.id).await?;
+
+    // The drain mark survives the heartbeats of the node.
+    cluster_api.heartbeat(&node_info, None).await?;
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes.len(), 1);
+    assert!(nodes[0].draining);
+
+    // The drain mark is dropped with the node.
+    cluster_api.drop_node(node_info.id.clone(), None).await?;
+    cluster_api.add_node(node_info.clone()).await?;
+    let nodes = cluster_api.get_nodes().await?;
+    assert!(!nodes[0].draining);
+
+    match cluster_api.drain_node("UNKNOWN_ID").await {
+        Ok(_) => panic!("Unknown node drain node must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2401),
+    }
 
-    assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
     Ok(())
 }
 
@@ -151,6 +187,7 @@ fn create_test_node_info() -> NodeInfo {
         cpu_nums: 0,
         version: 0,
         flight_address: String::from("ip:port"),
+        ..Default::default()
     }
 }
 
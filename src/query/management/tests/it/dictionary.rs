@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::DictionaryColumn;
+use common_meta_types::DictionaryFormat;
+use common_meta_types::DictionaryMeta;
+use common_meta_types::DictionarySource;
+use common_meta_types::SeqV;
+
+fn new_dictionary(name: &str) -> DictionaryMeta {
+    DictionaryMeta {
+        name: name.to_string(),
+        columns: vec![
+            DictionaryColumn {
+                name: "id".to_string(),
+                data_type: "UInt64".to_string(),
+            },
+            DictionaryColumn {
+                name: "name".to_string(),
+                data_type: "String".to_string(),
+            },
+        ],
+        primary_key: "id".to_string(),
+        source: DictionarySource::File {
+            path: "/tmp/users.csv".to_string(),
+            format: DictionaryFormat::Csv,
+        },
+        lifetime_secs: 300,
+        comment: String::new(),
+        created_on: 0,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_dictionary() -> Result<()> {
+    let (kv_api, dictionary_api) = new_dictionary_api().await?;
+
+    let dictionary = new_dictionary("d1");
+    dictionary_api.add_dictionary(dictionary.clone()).await?;
+    let value = kv_api.get_kv("__fd_dictionaries/admin/d1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&dictionary)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match dictionary_api.add_dictionary(dictionary.clone()).await {
+        Ok(_) => panic!("Already exists add dictionary must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2693),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_and_drop_dictionary() -> Result<()> {
+    let (_, dictionary_api) = new_dictionary_api().await?;
+
+    let dictionaries = dictionary_api.get_dictionaries().await?;
+    assert_eq!(dictionaries, vec![]);
+
+    let dictionary = new_dictionary("d1");
+    dictionary_api.add_dictionary(dictionary.clone()).await?;
+
+    let value = dictionary_api
+        .get_dictionary(&dictionary.name, None)
+        .await?;
+    assert_eq!(value.data, dictionary);
+    let dictionaries = dictionary_api.get_dictionaries().await?;
+    assert_eq!(dictionaries, vec![dictionary.clone()]);
+
+    dictionary_api
+        .drop_dictionary(&dictionary.name, None)
+        .await?;
+    let dictionaries = dictionary_api.get_dictionaries().await?;
+    assert_eq!(dictionaries, vec![]);
+
+    match dictionary_api.get_dictionary(&dictionary.name, None).await {
+        Ok(_) => panic!("Unknown dictionary get must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2692),
+    }
+    match dictionary_api.drop_dictionary(&dictionary.name, None).await {
+        Ok(_) => panic!("Unknown dictionary drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2692),
+    }
+
+    Ok(())
+}
+
+async fn new_dictionary_api() -> Result<(Arc<MetaEmbedded>, DictionaryMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = DictionaryMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
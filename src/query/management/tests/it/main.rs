@@ -13,7 +13,16 @@
 // limitations under the License.
 
 mod cluster;
+mod connection;
+mod dictionary;
+mod masking_policy;
+mod network_policy;
+mod plan_hint;
+mod resource_group;
+mod row_access_policy;
+mod sequence;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_network_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_network_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+    let value = kv_api.get_kv("__fd_network_policies/admin/office").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&policy)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match policy_api.add_policy(policy.clone()).await {
+        Ok(_) => panic!("Already exists add network policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2633),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_and_drop_network_policy() -> Result<()> {
+    let (_, policy_api) = new_network_policy_api().await?;
+
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![]);
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+
+    let value = policy_api.get_policy(&policy.name, None).await?;
+    assert_eq!(value.data, policy);
+
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![policy.clone()]);
+
+    policy_api.drop_policy(&policy.name, None).await?;
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![]);
+
+    match policy_api.drop_policy(&policy.name, None).await {
+        Ok(_) => panic!("Unknown network policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2632),
+    }
+
+    Ok(())
+}
+
+fn create_test_policy() -> NetworkPolicy {
+    NetworkPolicy::new(
+        "office",
+        vec!["192.168.0.0/16".to_string()],
+        vec!["192.168.1.99".to_string()],
+        "the office network",
+    )
+}
+
+async fn new_network_policy_api() -> Result<(Arc<MetaEmbedded>, NetworkPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = NetworkPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::PlanHint;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_plan_hint() -> Result<()> {
+    let (kv_api, plan_hint_api) = new_plan_hint_api().await?;
+
+    let plan_hint = create_test_plan_hint();
+    plan_hint_api.add_plan_hint(plan_hint.clone()).await?;
+    let value = kv_api.get_kv("__fd_plan_hints/admin/slow_join").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&plan_hint)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match plan_hint_api.add_plan_hint(plan_hint.clone()).await {
+        Ok(_) => panic!("Already exists add plan hint must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2723),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_and_drop_plan_hint() -> Result<()> {
+    let (_, plan_hint_api) = new_plan_hint_api().await?;
+
+    let plan_hints = plan_hint_api.get_plan_hints().await?;
+    assert_eq!(plan_hints, vec![]);
+
+    let plan_hint = create_test_plan_hint();
+    plan_hint_api.add_plan_hint(plan_hint.clone()).await?;
+
+    let value = plan_hint_api.get_plan_hint(&plan_hint.name, None).await?;
+    assert_eq!(value.data, plan_hint);
+
+    let plan_hints = plan_hint_api.get_plan_hints().await?;
+    assert_eq!(plan_hints, vec![plan_hint.clone()]);
+
+    plan_hint_api.drop_plan_hint(&plan_hint.name, None).await?;
+    let plan_hints = plan_hint_api.get_plan_hints().await?;
+    assert_eq!(plan_hints, vec![]);
+
+    match plan_hint_api.drop_plan_hint(&plan_hint.name, None).await {
+        Ok(_) => panic!("Unknown plan hint drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2722),
+    }
+
+    Ok(())
+}
+
+fn create_test_plan_hint() -> PlanHint {
+    PlanHint::new(
+        "slow_join",
+        "SELECT * FROM t1, t2 WHERE t1.a = t2.a AND t1.b = 'x'",
+        "SELECT * FROM t1 , t2 WHERE t1 . a = t2 . a AND t1 . b = ?",
+        "BROADCAST(t2)",
+    )
+}
+
+async fn new_plan_hint_api() -> Result<(Arc<MetaEmbedded>, PlanHintMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = PlanHintMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,108 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::SeqV;
+use common_meta_types::Sequence;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_sequence() -> Result<()> {
+    let (kv_api, sequence_api) = new_sequence_api().await?;
+
+    let sequence = Sequence::new("seq", 1, 1);
+    sequence_api.add_sequence(sequence.clone()).await?;
+    let value = kv_api.get_kv("__fd_sequences/admin/seq").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&sequence)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match sequence_api.add_sequence(sequence.clone()).await {
+        Ok(_) => panic!("Already exists add sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2673),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_and_drop_sequence() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    let sequences = sequence_api.get_sequences().await?;
+    assert_eq!(sequences, vec![]);
+
+    let sequence = Sequence::new("seq", 1, 1);
+    sequence_api.add_sequence(sequence.clone()).await?;
+
+    let value = sequence_api.get_sequence(&sequence.name, None).await?;
+    assert_eq!(value.data, sequence);
+
+    let sequences = sequence_api.get_sequences().await?;
+    assert_eq!(sequences, vec![sequence.clone()]);
+
+    sequence_api.drop_sequence(&sequence.name, None).await?;
+    let sequences = sequence_api.get_sequences().await?;
+    assert_eq!(sequences, vec![]);
+
+    match sequence_api.drop_sequence(&sequence.name, None).await {
+        Ok(_) => panic!("Unknown sequence drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2672),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_allocate_sequence_values() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    sequence_api
+        .add_sequence(Sequence::new("seq", 10, 2))
+        .await?;
+
+    let allocated = sequence_api.allocate_values("seq", 3).await?;
+    assert_eq!(allocated.next_value, 10);
+    let allocated = sequence_api.allocate_values("seq", 1).await?;
+    assert_eq!(allocated.next_value, 16);
+
+    let value = sequence_api.get_sequence("seq", None).await?;
+    assert_eq!(value.data.next_value, 18);
+
+    match sequence_api.allocate_values("unknown", 1).await {
+        Ok(_) => panic!("Unknown sequence allocate must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2672),
+    }
+
+    Ok(())
+}
+
+async fn new_sequence_api() -> Result<(Arc<MetaEmbedded>, SequenceMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SequenceMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -119,6 +119,7 @@ fn create_test_stage_info() -> UserStageInfo {
                 bucket: "mystage_bucket".to_string(),
                 ..Default::default()
             }),
+            location: None,
         },
         ..Default::default()
     }
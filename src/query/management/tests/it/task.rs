@@ -0,0 +1,150 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::ScheduledTask;
+use common_meta_types::SeqV;
+
+fn new_task(name: &str) -> ScheduledTask {
+    ScheduledTask {
+        name: name.to_string(),
+        schedule: Some("0 * * * *".to_string()),
+        sql: "SELECT 1".to_string(),
+        database: "default".to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_task() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+
+    let task = new_task("t1");
+    task_api.add_task(task.clone()).await?;
+    let value = kv_api.get_kv("__fd_tasks/admin/t1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&task)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match task_api.add_task(task.clone()).await {
+        Ok(_) => panic!("Already exists add task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2683),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_update_and_drop_task() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    let tasks = task_api.get_tasks().await?;
+    assert_eq!(tasks, vec![]);
+
+    let task = new_task("t1");
+    task_api.add_task(task.clone()).await?;
+
+    let value = task_api.get_task(&task.name, None).await?;
+    assert_eq!(value.data, task);
+
+    let mut suspended = task.clone();
+    suspended.suspended = true;
+    task_api
+        .update_task(suspended.clone(), Some(value.seq))
+        .await?;
+    let tasks = task_api.get_tasks().await?;
+    assert_eq!(tasks, vec![suspended.clone()]);
+
+    // the task is changed since the seq
+    match task_api.update_task(task.clone(), Some(value.seq)).await {
+        Ok(_) => panic!("Seq not match update task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2682),
+    }
+
+    task_api.drop_task(&task.name, None).await?;
+    let tasks = task_api.get_tasks().await?;
+    assert_eq!(tasks, vec![]);
+
+    match task_api.drop_task(&task.name, None).await {
+        Ok(_) => panic!("Unknown task drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2682),
+    }
+    match task_api.update_task(task.clone(), None).await {
+        Ok(_) => panic!("Unknown task update must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2682),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_acquire_scheduler_lease() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+
+    assert!(task_api.acquire_scheduler_lease("node1", 60).await?);
+    // renewed by the holder
+    assert!(task_api.acquire_scheduler_lease("node1", 60).await?);
+    assert!(!task_api.acquire_scheduler_lease("node2", 60).await?);
+
+    let value = kv_api.get_kv("__fd_task_scheduler/admin").await?;
+    assert_eq!(value.map(|v| v.data), Some(b"node1".to_vec()));
+
+    // the lease doesn't list with the tasks
+    assert_eq!(task_api.get_tasks().await?, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_acquire_compaction_scheduler_lease() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+
+    assert!(
+        task_api
+            .acquire_compaction_scheduler_lease("node1", 60)
+            .await?
+    );
+    assert!(
+        !task_api
+            .acquire_compaction_scheduler_lease("node2", 60)
+            .await?
+    );
+
+    // the lease is apart from the lease of the tasks
+    assert!(task_api.acquire_scheduler_lease("node2", 60).await?);
+    let value = kv_api.get_kv("__fd_compaction_scheduler/admin").await?;
+    assert_eq!(value.map(|v| v.data), Some(b"node1".to_vec()));
+
+    Ok(())
+}
+
+async fn new_task_api() -> Result<(Arc<MetaEmbedded>, TaskMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = TaskMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
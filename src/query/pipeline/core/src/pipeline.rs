@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -109,6 +110,65 @@ impl Pipeline {
         }
     }
 
+    /// Checks the ports of the pipes are connectable without executing the pipeline, i.e. each
+    /// pipe has as many inputs as the outputs of the pipe before it and no port is shared by the
+    /// processors, returns the problems found.
+    pub fn validate(&self) -> Vec<String> {
+        if self.pipes.is_empty() {
+            return vec!["the pipeline has no pipe".to_string()];
+        }
+
+        let mut problems = vec![];
+        let mut inputs = HashSet::new();
+        let mut outputs = HashSet::new();
+        for (index, pipe) in self.pipes.iter().enumerate() {
+            if index > 0 {
+                let prev_output_size = self.pipes[index - 1].output_size();
+                if pipe.input_size() != prev_output_size {
+                    problems.push(format!(
+                        "pipe {} has {} inputs for the {} outputs of pipe {}",
+                        index,
+                        pipe.input_size(),
+                        prev_output_size,
+                        index - 1
+                    ));
+                }
+            }
+
+            let (inputs_port, outputs_port) = match pipe {
+                Pipe::SimplePipe {
+                    inputs_port,
+                    outputs_port,
+                    ..
+                } => (inputs_port, outputs_port),
+                Pipe::ResizePipe {
+                    inputs_port,
+                    outputs_port,
+                    ..
+                } => (inputs_port, outputs_port),
+            };
+            if inputs_port
+                .iter()
+                .any(|port| !inputs.insert(Arc::as_ptr(port)))
+            {
+                problems.push(format!(
+                    "pipe {} shares an input port with the others",
+                    index
+                ));
+            }
+            if outputs_port
+                .iter()
+                .any(|port| !outputs.insert(Arc::as_ptr(port)))
+            {
+                problems.push(format!(
+                    "pipe {} shares an output port with the others",
+                    index
+                ));
+            }
+        }
+        problems
+    }
+
     pub fn set_max_threads(&mut self, max_threads: usize) {
         let mut max_pipe_size = 0;
         for pipe in &self.pipes {
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::ResizeProcessor;
+use common_pipeline_core::Pipe;
+use common_pipeline_core::Pipeline;
+
+fn resize_pipe(inputs: usize, outputs: usize) -> Pipe {
+    let processor = ResizeProcessor::create(inputs, outputs);
+    let inputs_port = processor.get_inputs().to_vec();
+    let outputs_port = processor.get_outputs().to_vec();
+    Pipe::ResizePipe {
+        inputs_port,
+        outputs_port,
+        processor: ProcessorPtr::create(Box::new(processor)),
+    }
+}
+
+#[test]
+fn test_validate_pipeline() {
+    let pipeline = Pipeline::create();
+    assert_eq!(pipeline.validate(), vec!["the pipeline has no pipe"]);
+
+    let mut pipeline = Pipeline::create();
+    pipeline.add_pipe(resize_pipe(1, 2));
+    pipeline.resize(4).unwrap();
+    assert!(pipeline.validate().is_empty());
+
+    let mut pipeline = Pipeline::create();
+    pipeline.add_pipe(resize_pipe(1, 2));
+    pipeline.add_pipe(resize_pipe(3, 1));
+    assert_eq!(pipeline.validate(), vec![
+        "pipe 1 has 3 inputs for the 2 outputs of pipe 0"
+    ]);
+
+    let mut pipeline = Pipeline::create();
+    let pipe = resize_pipe(2, 2);
+    pipeline.add_pipe(pipe.clone());
+    pipeline.add_pipe(pipe);
+    assert_eq!(pipeline.validate(), vec![
+        "pipe 1 shares an input port with the others",
+        "pipe 1 shares an output port with the others",
+    ]);
+}
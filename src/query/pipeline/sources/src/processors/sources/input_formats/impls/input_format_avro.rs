@@ -0,0 +1,371 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::new_null_array;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::avro::avro_schema;
+use common_arrow::arrow::io::avro::avro_schema::file::FileMetadata;
+use common_arrow::arrow::io::avro::read;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use opendal::Operator;
+
+use crate::processors::sources::input_formats::delimiter::RecordDelimiter;
+use crate::processors::sources::input_formats::input_context::CopyIntoPlan;
+use crate::processors::sources::input_formats::input_context::InputContext;
+use crate::processors::sources::input_formats::input_pipeline::AligningStateTrait;
+use crate::processors::sources::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::processors::sources::input_formats::input_pipeline::InputFormatPipe;
+use crate::processors::sources::input_formats::input_split::FileInfo;
+use crate::processors::sources::input_formats::input_split::SplitInfo;
+use crate::processors::sources::input_formats::InputFormat;
+
+pub struct InputFormatAvro;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    fn get_format_settings(&self, _settings: &Arc<Settings>) -> Result<FormatSettings> {
+        // not used now
+        Ok(FormatSettings::default())
+    }
+
+    fn default_record_delimiter(&self) -> RecordDelimiter {
+        RecordDelimiter::Crlf
+    }
+
+    fn default_field_delimiter(&self) -> u8 {
+        b'_'
+    }
+
+    async fn get_splits(
+        &self,
+        plan: &CopyIntoPlan,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+        _schema: &DataSchemaRef,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // The writer schema is only stored in the header of an avro object container file,
+        // so each file is read into memory as a whole.
+        let mut infos = vec![];
+        for path in &plan.files {
+            let size = op.object(path).metadata().await?.content_length() as usize;
+            let file_info = Arc::new(FileInfo {
+                path: path.clone(),
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file: file_info,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroFileInMemory;
+    type AligningState = AligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let op = ctx.source.get_operator()?;
+        let data = op.object(&split_info.file.path).read().await?;
+        let fill_null = ctx.settings.get_avro_incompatible_field()? == "null";
+        AvroFileInMemory::try_create(&split_info.file.path, data, &ctx.schema, fill_null)
+    }
+}
+
+pub struct AvroFileInMemory {
+    pub path: String,
+    // The blocks of the file, without the header.
+    pub data: Vec<u8>,
+    pub meta: FileMetadata,
+    // The arrow fields inferred from the writer schema, and the ones to be read.
+    pub fields: Vec<Field>,
+    pub projection: Vec<bool>,
+    // The position in the read chunk of each field of the table schema,
+    // None for the fields loaded as NULL.
+    pub columns: Vec<Option<usize>>,
+}
+
+impl AvroFileInMemory {
+    pub fn try_create(
+        path: &str,
+        mut data: Vec<u8>,
+        schema: &DataSchemaRef,
+        fill_null: bool,
+    ) -> Result<Self> {
+        let mut cursor = Cursor::new(&data);
+        let meta = avro_schema::read::read_metadata(&mut cursor).map_err(|e| {
+            ErrorCode::AvroError(format!("fail to read avro file {}: {:?}", path, e))
+        })?;
+        // The reader of the blocks starts right after the header.
+        let header_len = cursor.position() as usize;
+        data.drain(..header_len);
+        let fields = read::infer_schema(&meta.record)?.fields;
+        let (projection, columns) = resolve_fields(&fields, schema, fill_null)
+            .map_err(|e| e.add_message(format!(" avro file {}", path)))?;
+        Ok(Self {
+            path: path.to_string(),
+            data,
+            meta,
+            fields,
+            projection,
+            columns,
+        })
+    }
+
+    /// Read the blocks of the file, each one resolved to the schema the file is created with.
+    pub fn read_blocks(self, schema: &DataSchemaRef) -> Result<Vec<DataBlock>> {
+        // The arrow types the avro columns are resolved to.
+        let data_types = schema
+            .fields()
+            .iter()
+            .map(|f| f.to_arrow().data_type)
+            .collect::<Vec<_>>();
+        let path = self.path;
+        let columns = self.columns;
+        let reader = read::Reader::new(
+            Cursor::new(self.data),
+            self.meta,
+            self.fields,
+            Some(self.projection),
+        );
+
+        let mut blocks = vec![];
+        for chunk in reader {
+            let chunk = chunk.map_err(|e| {
+                ErrorCode::AvroError(format!("fail to read avro file {}: {}", path, e))
+            })?;
+            if chunk.is_empty() {
+                continue;
+            }
+            let chunk = resolve_chunk(&columns, &data_types, chunk)
+                .map_err(|e| e.add_message(format!(" avro file {}", path)))?;
+            blocks.push(DataBlock::from_chunk(schema, &chunk)?);
+        }
+        tracing::debug!(
+            "deserialize avro file {} into {} blocks",
+            path,
+            blocks.len()
+        );
+        Ok(blocks)
+    }
+}
+
+impl Debug for AvroFileInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AvroFileInMemory")
+    }
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn create(ctx: Arc<InputContext>) -> Self {
+        AvroBlockBuilder { ctx }
+    }
+
+    fn deserialize(&mut self, batch: Option<AvroFileInMemory>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(file) => file.read_blocks(&self.ctx.schema),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub struct AligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn try_create(ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        Ok(AligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroFileInMemory>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            Ok(vec![])
+        } else {
+            let file_in_memory = self.buffers.concat();
+            let fill_null = self.ctx.settings.get_avro_incompatible_field()? == "null";
+            tracing::debug!(
+                "aligning avro file {} of {} bytes",
+                self.split_info.file.path,
+                file_in_memory.len(),
+            );
+            Ok(vec![AvroFileInMemory::try_create(
+                &self.split_info.file.path,
+                file_in_memory,
+                &self.ctx.schema,
+                fill_null,
+            )?])
+        }
+    }
+}
+
+/// Match the fields of the table schema with the avro fields by name.
+///
+/// Unions of `null` and another type are already read as the nullable type,
+/// logical types (date, timestamp-millis, decimal, ...) as the arrow logical types,
+/// which are cast to the types of the table if they differ.
+/// A field missing from the file or of a type can not be cast to is an error,
+/// unless `fill_null` is set, then it is loaded as NULL.
+fn resolve_fields(
+    fields: &[Field],
+    schema: &DataSchemaRef,
+    fill_null: bool,
+) -> Result<(Vec<bool>, Vec<Option<usize>>)> {
+    let mut projection = vec![false; fields.len()];
+    let mut indexes = Vec::with_capacity(schema.num_fields());
+    for f in schema.fields().iter() {
+        let data_type = f.to_arrow().data_type;
+        let found = fields
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(f.name()));
+        match found {
+            Some(index) if is_compatible(&fields[index].data_type, &data_type) => {
+                projection[index] = true;
+                indexes.push(Some(index));
+            }
+            Some(index) if !fill_null => {
+                return Err(ErrorCode::AvroError(format!(
+                    "avro schema mismatch, column {} expected type {:?}, but got {:?}",
+                    f.name(),
+                    f.data_type(),
+                    fields[index].data_type
+                )));
+            }
+            None if !fill_null => {
+                return Err(ErrorCode::AvroError(format!(
+                    "schema field size mismatch, expected to find column: {}",
+                    f.name()
+                )));
+            }
+            _ => indexes.push(None),
+        }
+    }
+
+    if !projection.iter().any(|p| *p) {
+        return Err(ErrorCode::AvroError(
+            "none of the columns of the table found in avro file",
+        ));
+    }
+
+    // The read chunks only contain the projected fields, in the order of the file.
+    let columns = indexes
+        .into_iter()
+        .map(|index| index.map(|i| projection[..i].iter().filter(|p| **p).count()))
+        .collect();
+    Ok((projection, columns))
+}
+
+fn resolve_chunk(
+    columns: &[Option<usize>],
+    data_types: &[ArrowType],
+    chunk: Chunk<Box<dyn Array>>,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let num_rows = chunk.len();
+    let arrays = chunk.into_arrays();
+    let mut resolved = Vec::with_capacity(columns.len());
+    for (column, data_type) in columns.iter().zip(data_types.iter()) {
+        let array = match column {
+            Some(pos) => resolve_array(arrays[*pos].as_ref(), data_type)?,
+            None => new_null_array(data_type.clone(), num_rows),
+        };
+        resolved.push(array);
+    }
+    Ok(Chunk::try_new(resolved)?)
+}
+
+fn is_string(data_type: &ArrowType) -> bool {
+    matches!(
+        data_type,
+        ArrowType::Utf8 | ArrowType::LargeUtf8 | ArrowType::Binary | ArrowType::LargeBinary
+    )
+}
+
+fn is_compatible(from: &ArrowType, to: &ArrowType) -> bool {
+    if from == to || (is_string(from) && is_string(to)) {
+        return true;
+    }
+    match from {
+        // Avro enums are read as dictionaries of their symbols.
+        ArrowType::Dictionary(_, values, _) => is_compatible(values, to),
+        _ => cast::can_cast_types(from, to),
+    }
+}
+
+fn resolve_array(array: &dyn Array, to: &ArrowType) -> Result<Box<dyn Array>> {
+    let from = array.data_type();
+    if from == to || (is_string(from) && is_string(to)) {
+        return Ok(array.to_boxed());
+    }
+    match from {
+        ArrowType::Dictionary(_, values, _) => {
+            let array = cast::cast(array, values, CastOptions::default())?;
+            resolve_array(array.as_ref(), to)
+        }
+        _ => Ok(cast::cast(array, to, CastOptions::default())?),
+    }
+}
@@ -29,6 +29,7 @@ use csv_core::ReadRecordResult;
 use crate::processors::sources::input_formats::delimiter::RecordDelimiter;
 use crate::processors::sources::input_formats::impls::input_format_tsv::format_column_error;
 use crate::processors::sources::input_formats::input_format_text::get_time_zone;
+use crate::processors::sources::input_formats::input_format_text::rollback_row;
 use crate::processors::sources::input_formats::input_format_text::AligningState;
 use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
 use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
@@ -38,6 +39,7 @@ use crate::processors::sources::input_formats::InputContext;
 pub struct InputFormatCSV {}
 
 impl InputFormatCSV {
+    // The values of the row are rolled back on error.
     fn read_row(
         buf: &[u8],
         deserializers: &mut [common_datavalues::TypeDeserializerImpl],
@@ -48,7 +50,8 @@ impl InputFormatCSV {
         row_index: usize,
     ) -> Result<()> {
         let mut field_start = 0;
-        for (c, deserializer) in deserializers.iter_mut().enumerate() {
+        for c in 0..deserializers.len() {
+            let deserializer = &mut deserializers[c];
             let field_end = field_ends[c];
             let col_data = &buf[field_start..field_end];
             let mut reader = NestedCheckpointReader::new(col_data);
@@ -59,11 +62,13 @@ impl InputFormatCSV {
                 // todo(youngsofun): do not need escape, already done in csv-core
                 if let Err(e) = deserializer.de_text(&mut reader, format_settings) {
                     let err_msg = format_column_error(schema, c, col_data, &e.message());
+                    rollback_row(deserializers, c);
                     return Err(csv_error(&err_msg, path, row_index));
                 };
                 reader.ignore_white_spaces().expect("must success");
                 if reader.must_eof().is_err() {
                     let err_msg = format_column_error(schema, c, col_data, "bad field end");
+                    rollback_row(deserializers, c + 1);
                     return Err(csv_error(&err_msg, path, row_index));
                 }
             }
@@ -91,7 +96,7 @@ impl InputFormatTextBase for InputFormatCSV {
             field_delimiter: settings.get_field_delimiter()?.into_bytes(),
             empty_as_default: settings.get_empty_as_default()? > 0,
             quote_char: quote_char[0],
-            null_bytes: vec![b'\\', b'N'],
+            null_bytes: settings.get_null_display()?.into_bytes(),
             timezone,
             ..Default::default()
         })
@@ -102,22 +107,23 @@ impl InputFormatTextBase for InputFormatCSV {
     }
 
     fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
-        let columns = &mut builder.mutable_columns;
-        let n_column = columns.len();
+        let n_column = builder.mutable_columns.len();
         let mut start = 0usize;
         let start_row = batch.start_row.expect("must success");
         let mut field_end_idx = 0;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end];
-            Self::read_row(
+            if let Err(e) = Self::read_row(
                 buf,
-                columns,
+                &mut builder.mutable_columns,
                 &builder.ctx.schema,
                 &batch.field_ends[field_end_idx..field_end_idx + n_column],
                 &builder.ctx.format_settings,
                 &batch.path,
                 start_row + i,
-            )?;
+            ) {
+                builder.skip_row_on_error(e)?;
+            }
             start = *end;
             field_end_idx += n_column;
         }
@@ -127,7 +133,6 @@ impl InputFormatTextBase for InputFormatCSV {
     fn align(state: &mut AligningState<Self>, buf_in: &[u8]) -> Result<Vec<RowBatch>> {
         let num_fields = state.num_fields;
         let reader = state.csv_reader.as_mut().expect("must success");
-        let field_ends = &mut reader.field_ends[..];
         let start_row = state.rows;
         state.offset += buf_in.len();
 
@@ -140,7 +145,7 @@ impl InputFormatTextBase for InputFormatCSV {
             let (result, n_in, _, n_end) =
                 reader
                     .reader
-                    .read_record(buf, &mut out_tmp, &mut field_ends[endlen..]);
+                    .read_record(buf, &mut out_tmp, &mut reader.field_ends[endlen..]);
             buf = &buf[n_in..];
             endlen += n_end;
 
@@ -157,38 +162,10 @@ impl InputFormatTextBase for InputFormatCSV {
                     ));
                 }
                 ReadRecordResult::OutputEndsFull => {
-                    return Err(csv_error(
-                        &format!(
-                            "too many fields, expect {}, got more than {}",
-                            num_fields,
-                            field_ends.len()
-                        ),
-                        &state.path,
-                        state.rows,
-                    ));
+                    reader.grow_field_ends(num_fields, &state.path, state.rows)?;
                 }
                 ReadRecordResult::Record => {
-                    if endlen < num_fields {
-                        return Err(csv_error(
-                            &format!("expect {} fields, only found {} ", num_fields, n_end),
-                            &state.path,
-                            state.rows,
-                        ));
-                    } else if endlen > num_fields + 1 {
-                        return Err(csv_error(
-                            &format!("too many fields, expect {}, got {}", num_fields, n_end),
-                            &state.path,
-                            state.rows,
-                        ));
-                    } else if endlen == num_fields + 1
-                        && field_ends[num_fields] != field_ends[num_fields - 1]
-                    {
-                        return Err(csv_error(
-                            "CSV allow ending with ',', but should not have data after it",
-                            &state.path,
-                            state.rows,
-                        ));
-                    }
+                    reader.check_num_fields(endlen, num_fields, &state.path, state.rows)?;
 
                     state.rows_to_skip -= 1;
                     tracing::debug!(
@@ -220,10 +197,11 @@ impl InputFormatTextBase for InputFormatCSV {
         };
 
         while !buf.is_empty() {
-            let (result, n_in, n_out, n_end) =
-                reader
-                    .reader
-                    .read_record(buf, &mut out_tmp[out_pos..], &mut field_ends[endlen..]);
+            let (result, n_in, n_out, n_end) = reader.reader.read_record(
+                buf,
+                &mut out_tmp[out_pos..],
+                &mut reader.field_ends[endlen..],
+            );
             buf = &buf[n_in..];
             endlen += n_end;
             out_pos += n_out;
@@ -237,41 +215,33 @@ impl InputFormatTextBase for InputFormatCSV {
                     ));
                 }
                 ReadRecordResult::OutputEndsFull => {
-                    return Err(csv_error(
-                        &format!(
-                            "too many fields, expect {}, got more than {}",
-                            num_fields,
-                            field_ends.len()
-                        ),
+                    reader.grow_field_ends(
+                        num_fields,
                         &state.path,
                         start_row + row_batch.row_ends.len(),
-                    ));
+                    )?;
                 }
                 ReadRecordResult::Record => {
-                    if endlen < num_fields {
-                        return Err(csv_error(
-                            &format!("expect {} fields, only found {} ", num_fields, n_end),
-                            &state.path,
-                            start_row + row_batch.row_ends.len(),
-                        ));
-                    } else if endlen > num_fields + 1 {
-                        return Err(csv_error(
-                            &format!("too many fields, expect {}, got {}", num_fields, n_end),
-                            &state.path,
-                            start_row + row_batch.row_ends.len(),
-                        ));
-                    } else if endlen == num_fields + 1
-                        && field_ends[num_fields] != field_ends[num_fields - 1]
-                    {
-                        return Err(csv_error(
-                            "CSV allow ending with ',', but should not have data after it",
-                            &state.path,
-                            start_row + row_batch.row_ends.len(),
-                        ));
+                    reader.check_num_fields(
+                        endlen,
+                        num_fields,
+                        &state.path,
+                        start_row + row_batch.row_ends.len(),
+                    )?;
+                    if endlen >= num_fields {
+                        row_batch
+                            .field_ends
+                            .extend_from_slice(&reader.field_ends[..num_fields]);
+                    } else {
+                        // The missing fields are empty, and filled with the default later.
+                        let record_end = reader.field_ends[..endlen].last().cloned().unwrap_or(0);
+                        row_batch
+                            .field_ends
+                            .extend_from_slice(&reader.field_ends[..endlen]);
+                        row_batch
+                            .field_ends
+                            .resize(row_batch.field_ends.len() + num_fields - endlen, record_end);
                     }
-                    row_batch
-                        .field_ends
-                        .extend_from_slice(&field_ends[..num_fields]);
                     row_batch.row_ends.push(last_batch_remain_len + out_pos);
                     endlen = 0;
                     row_batch_end = out_pos;
@@ -329,6 +299,7 @@ pub struct CsvReaderState {
     pub out: Vec<u8>,
     pub field_ends: Vec<usize>,
     pub n_end: usize,
+    pub error_on_column_count_mismatch: bool,
 }
 
 impl CsvReaderState {
@@ -336,6 +307,7 @@ impl CsvReaderState {
         let reader = csv_core::ReaderBuilder::new()
             .delimiter(ctx.field_delimiter)
             .quote(ctx.format_settings.quote_char)
+            .escape(ctx.escape)
             .terminator(match ctx.record_delimiter {
                 RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
                 RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
@@ -346,8 +318,63 @@ impl CsvReaderState {
             out: vec![],
             field_ends: vec![0; ctx.schema.num_fields() + 6],
             n_end: 0,
+            error_on_column_count_mismatch: ctx.error_on_column_count_mismatch,
         }
     }
+
+    fn check_num_fields(
+        &self,
+        n_fields: usize,
+        num_fields: usize,
+        path: &str,
+        row: usize,
+    ) -> Result<()> {
+        if !self.error_on_column_count_mismatch {
+            return Ok(());
+        }
+        if n_fields < num_fields {
+            Err(csv_error(
+                &format!("expect {} fields, only found {} ", num_fields, n_fields),
+                path,
+                row,
+            ))
+        } else if n_fields > num_fields + 1 {
+            Err(csv_error(
+                &format!("too many fields, expect {}, got {}", num_fields, n_fields),
+                path,
+                row,
+            ))
+        } else if n_fields == num_fields + 1
+            && self.field_ends[num_fields] != self.field_ends[num_fields - 1]
+        {
+            Err(csv_error(
+                "CSV allow ending with ',', but should not have data after it",
+                path,
+                row,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    // The extra fields are ignored if the column count mismatch is allowed,
+    // but they still need room in the field ends.
+    fn grow_field_ends(&mut self, num_fields: usize, path: &str, row: usize) -> Result<()> {
+        if self.error_on_column_count_mismatch {
+            return Err(csv_error(
+                &format!(
+                    "too many fields, expect {}, got more than {}",
+                    num_fields,
+                    self.field_ends.len()
+                ),
+                path,
+                row,
+            ));
+        }
+        let len = self.field_ends.len();
+        self.field_ends.resize(len * 2, 0);
+        Ok(())
+    }
 }
 
 fn csv_error(msg: &str, path: &str, row: usize) -> ErrorCode {
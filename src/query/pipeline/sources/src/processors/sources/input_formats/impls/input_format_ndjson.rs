@@ -16,9 +16,13 @@ use std::borrow::Cow;
 use std::sync::Arc;
 
 use bstr::ByteSlice;
+use common_datavalues::remove_nullable;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
 use common_datavalues::TypeDeserializer;
 use common_datavalues::TypeDeserializerImpl;
+use common_datavalues::TypeID;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
@@ -26,6 +30,7 @@ use common_meta_types::StageFileFormatType;
 use common_settings::Settings;
 
 use crate::processors::sources::input_formats::input_format_text::get_time_zone;
+use crate::processors::sources::input_formats::input_format_text::rollback_row;
 use crate::processors::sources::input_formats::input_format_text::AligningState;
 use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
 use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
@@ -33,12 +38,36 @@ use crate::processors::sources::input_formats::input_format_text::RowBatch;
 
 pub struct InputFormatNDJson {}
 
+/// How to load the fields of the table missing from a json object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MissingFieldAs {
+    Error,
+    Null,
+    FieldDefault,
+}
+
+impl MissingFieldAs {
+    fn try_create(settings: &Arc<Settings>) -> Result<Self> {
+        match settings.get_missing_field_as()?.as_str() {
+            "error" => Ok(MissingFieldAs::Error),
+            "null" => Ok(MissingFieldAs::Null),
+            "field_default" => Ok(MissingFieldAs::FieldDefault),
+            v => Err(ErrorCode::InvalidArgument(format!(
+                "invalid value of missing_field_as: {}",
+                v
+            ))),
+        }
+    }
+}
+
 impl InputFormatNDJson {
+    // The values of the row are rolled back on error.
     fn read_row(
         buf: &[u8],
         deserializers: &mut [TypeDeserializerImpl],
         format_settings: &FormatSettings,
         schema: &DataSchemaRef,
+        missing_field_as: MissingFieldAs,
     ) -> Result<()> {
         let mut json: serde_json::Value = serde_json::from_reader(buf)?;
         // if it's not case_sensitive, we convert to lowercase
@@ -49,22 +78,39 @@ impl InputFormatNDJson {
             }
         }
 
-        for (f, deser) in schema.fields().iter().zip(deserializers.iter_mut()) {
+        for (c, f) in schema.fields().iter().enumerate() {
             let value = if format_settings.ident_case_sensitive {
-                &json[f.name().to_owned()]
+                json.get(f.name())
             } else {
-                &json[f.name().to_lowercase()]
+                json.get(f.name().to_lowercase())
+            };
+            let value = match (value, missing_field_as) {
+                (Some(v), _) => v,
+                (None, MissingFieldAs::Error) => {
+                    rollback_row(deserializers, c);
+                    return Err(ErrorCode::BadBytes(format!(
+                        "missing field, column={}",
+                        f.name()
+                    )));
+                }
+                (None, MissingFieldAs::Null) => &serde_json::Value::Null,
+                (None, MissingFieldAs::FieldDefault) => {
+                    deserializers[c].de_default(format_settings);
+                    continue;
+                }
             };
 
-            deser.de_json(value, format_settings).map_err(|e| {
+            let value = coerce_value(value, f.data_type());
+            if let Err(e) = deserializers[c].de_json(&value, format_settings) {
+                rollback_row(deserializers, c);
                 let value_str = format!("{:?}", value);
-                ErrorCode::BadBytes(format!(
+                return Err(ErrorCode::BadBytes(format!(
                     "{}. column={} value={}",
                     e,
                     f.name(),
                     maybe_truncated(&value_str, 1024),
-                ))
-            })?;
+                )));
+            }
         }
         Ok(())
     }
@@ -89,18 +135,19 @@ impl InputFormatTextBase for InputFormatNDJson {
     }
 
     fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
-        let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
         let start_row = batch.start_row;
+        let missing_field_as = MissingFieldAs::try_create(&builder.ctx.settings)?;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end];
             let buf = buf.trim();
             if !buf.is_empty() {
                 if let Err(e) = Self::read_row(
                     buf,
-                    columns,
+                    &mut builder.mutable_columns,
                     &builder.ctx.format_settings,
                     &builder.ctx.schema,
+                    missing_field_as,
                 ) {
                     let row_info = if let Some(r) = start_row {
                         format!("row={},", r + i)
@@ -114,7 +161,7 @@ impl InputFormatTextBase for InputFormatNDJson {
                         batch.offset + start,
                         row_info,
                     );
-                    return Err(ErrorCode::BadBytes(msg));
+                    builder.skip_row_on_error(ErrorCode::BadBytes(msg))?;
                 }
             }
             start = *end;
@@ -127,6 +174,44 @@ impl InputFormatTextBase for InputFormatNDJson {
     }
 }
 
+/// Convert the json scalars of other types into the ones expected by the column,
+/// log-style producers often quote numbers or write flags as 0/1.
+fn coerce_value<'a>(
+    value: &'a serde_json::Value,
+    data_type: &DataTypeImpl,
+) -> Cow<'a, serde_json::Value> {
+    let type_id = remove_nullable(data_type).data_type_id();
+    let coerced = match value {
+        serde_json::Value::String(s) if type_id.is_numeric() => {
+            serde_json::from_str::<serde_json::Number>(s.trim())
+                .ok()
+                .map(serde_json::Value::Number)
+        }
+        serde_json::Value::String(s) if type_id == TypeID::Boolean => {
+            if s.eq_ignore_ascii_case("true") {
+                Some(serde_json::Value::Bool(true))
+            } else if s.eq_ignore_ascii_case("false") {
+                Some(serde_json::Value::Bool(false))
+            } else {
+                None
+            }
+        }
+        serde_json::Value::Number(n) if type_id == TypeID::Boolean => match n.as_u64() {
+            Some(0) => Some(serde_json::Value::Bool(false)),
+            Some(1) => Some(serde_json::Value::Bool(true)),
+            _ => None,
+        },
+        serde_json::Value::Number(_) | serde_json::Value::Bool(_) if type_id.is_string() => {
+            Some(serde_json::Value::String(value.to_string()))
+        }
+        _ => None,
+    };
+    match coerced {
+        Some(v) => Cow::Owned(v),
+        None => Cow::Borrowed(value),
+    }
+}
+
 fn maybe_truncated(s: &str, limit: usize) -> Cow<'_, str> {
     if s.len() > limit {
         Cow::Owned(format!(
@@ -0,0 +1,365 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::orc::format;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_arrow::arrow::io::orc::read;
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::ColumnStatistics;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_io::prelude::FormatSettings;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use opendal::Operator;
+
+use crate::processors::sources::input_formats::delimiter::RecordDelimiter;
+use crate::processors::sources::input_formats::input_context::CopyIntoPlan;
+use crate::processors::sources::input_formats::input_context::InputContext;
+use crate::processors::sources::input_formats::input_context::StatisticsFilter;
+use crate::processors::sources::input_formats::input_pipeline::AligningStateTrait;
+use crate::processors::sources::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::processors::sources::input_formats::input_pipeline::InputFormatPipe;
+use crate::processors::sources::input_formats::input_split::FileInfo;
+use crate::processors::sources::input_formats::input_split::SplitInfo;
+use crate::processors::sources::input_formats::InputFormat;
+
+pub struct InputFormatOrc;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    fn get_format_settings(&self, _settings: &Arc<Settings>) -> Result<FormatSettings> {
+        // not used now
+        Ok(FormatSettings::default())
+    }
+
+    fn default_record_delimiter(&self) -> RecordDelimiter {
+        RecordDelimiter::Crlf
+    }
+
+    fn default_field_delimiter(&self) -> u8 {
+        b'_'
+    }
+
+    async fn get_splits(
+        &self,
+        plan: &CopyIntoPlan,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+        _schema: &DataSchemaRef,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // The metadata of orc can only be read from a seekable reader,
+        // so each file is read into memory as a whole and then split by stripes.
+        let mut infos = vec![];
+        for path in &plan.files {
+            let size = op.object(path).metadata().await?.content_length() as usize;
+            let file_info = Arc::new(FileInfo {
+                path: path.clone(),
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file: file_info,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = OrcFileInMemory;
+    type AligningState = AligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let op = ctx.source.get_operator()?;
+        let data = op.object(&split_info.file.path).read().await?;
+        OrcFileInMemory::try_create(&split_info.file.path, data, &ctx.schema)
+    }
+}
+
+pub struct OrcFileInMemory {
+    pub path: String,
+    pub data: Vec<u8>,
+    pub meta: FileMetadata,
+    // The orc column id and the arrow field of each field of the schema.
+    pub fields: Vec<(u32, Field)>,
+}
+
+impl OrcFileInMemory {
+    pub fn try_create(path: &str, data: Vec<u8>, schema: &DataSchemaRef) -> Result<Self> {
+        let mut cursor = Cursor::new(&data);
+        let meta = format::read::read_metadata(&mut cursor).map_err(|e| orc_error(path, e))?;
+        let fields = get_fields(&meta, schema)?;
+        Ok(Self {
+            path: path.to_string(),
+            data,
+            meta,
+            fields,
+        })
+    }
+
+    /// The stripes to read, the non-empty ones that the filter tells may have matched rows.
+    pub fn stripes_to_read(&self, filter: Option<&StatisticsFilter>) -> Vec<usize> {
+        let stripes = self.meta.footer.stripes.iter().enumerate();
+        stripes
+            .filter(|(stripe, info)| {
+                let rows = info.number_of_rows();
+                match filter {
+                    _ if rows == 0 => false,
+                    Some(filter) => filter(&self.stripe_statistics(*stripe), rows),
+                    None => true,
+                }
+            })
+            .map(|(stripe, _)| stripe)
+            .collect()
+    }
+
+    /// The statistics of the columns in a stripe, keyed by the index of the field in the schema.
+    ///
+    /// Only the integer, floating, string and date columns with both of the min and max values
+    /// are collected, the filter takes the others as unknown.
+    pub fn stripe_statistics(&self, stripe: usize) -> StatisticsOfColumns {
+        let mut stats = HashMap::with_capacity(self.fields.len());
+        let col_stats = match self.meta.metadata.stripe_stats.get(stripe) {
+            Some(stripe_stats) => &stripe_stats.col_stats,
+            None => return stats,
+        };
+        let rows = self.meta.footer.stripes[stripe].number_of_rows();
+        for (index, (column_id, _)) in self.fields.iter().enumerate() {
+            let column_stats = match col_stats.get(*column_id as usize) {
+                Some(column_stats) => column_stats,
+                None => continue,
+            };
+            let min_max = if let Some(s) = &column_stats.int_statistics {
+                s.minimum
+                    .zip(s.maximum)
+                    .map(|(min, max)| (DataValue::Int64(min), DataValue::Int64(max)))
+            } else if let Some(s) = &column_stats.double_statistics {
+                s.minimum
+                    .zip(s.maximum)
+                    .filter(|(min, max)| !min.is_nan() && !max.is_nan())
+                    .map(|(min, max)| (DataValue::Float64(min), DataValue::Float64(max)))
+            } else if let Some(s) = &column_stats.string_statistics {
+                s.minimum.clone().zip(s.maximum.clone()).map(|(min, max)| {
+                    (
+                        DataValue::String(min.into_bytes()),
+                        DataValue::String(max.into_bytes()),
+                    )
+                })
+            } else if let Some(s) = &column_stats.date_statistics {
+                s.minimum
+                    .zip(s.maximum)
+                    .map(|(min, max)| (DataValue::Int64(min as i64), DataValue::Int64(max as i64)))
+            } else {
+                None
+            };
+
+            if let Some((min, max)) = min_max {
+                let values = column_stats.number_of_values.unwrap_or(rows);
+                stats.insert(index as u32, ColumnStatistics {
+                    min,
+                    max,
+                    null_count: rows.saturating_sub(values),
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                    histogram: None,
+                });
+            }
+        }
+        stats
+    }
+
+    pub fn read_stripe(&self, stripe: usize) -> Result<Chunk<Box<dyn Array>>> {
+        let mut cursor = Cursor::new(&self.data);
+        let mut scratch = vec![];
+        let footer =
+            format::read::read_stripe_footer(&mut cursor, &self.meta, stripe, &mut scratch)
+                .map_err(|e| orc_error(&self.path, e))?;
+
+        let mut arrays = Vec::with_capacity(self.fields.len());
+        for (column_id, field) in &self.fields {
+            let column = format::read::read_stripe_column(
+                &mut cursor,
+                &self.meta,
+                stripe,
+                footer.clone(),
+                *column_id,
+                vec![],
+            )
+            .map_err(|e| orc_error(&self.path, e))?;
+            arrays.push(read::deserialize(field.data_type.clone(), &column)?);
+        }
+        Ok(Chunk::try_new(arrays)?)
+    }
+}
+
+impl Debug for OrcFileInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OrcFileInMemory")
+    }
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn create(ctx: Arc<InputContext>) -> Self {
+        OrcBlockBuilder { ctx }
+    }
+
+    fn deserialize(&mut self, batch: Option<OrcFileInMemory>) -> Result<Vec<DataBlock>> {
+        let file = match batch {
+            Some(file) => file,
+            None => return Ok(vec![]),
+        };
+
+        let stripes = file.stripes_to_read(self.ctx.statistics_filter.as_ref());
+        let mut blocks = Vec::with_capacity(stripes.len());
+        for stripe in stripes {
+            let chunk = file.read_stripe(stripe)?;
+            blocks.push(DataBlock::from_chunk(&self.ctx.schema, &chunk)?);
+        }
+        tracing::debug!(
+            "deserialize orc file {} into {} blocks, of {} stripes",
+            file.path,
+            blocks.len(),
+            file.meta.footer.stripes.len()
+        );
+        Ok(blocks)
+    }
+}
+
+pub struct AligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn try_create(ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        Ok(AligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<OrcFileInMemory>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            Ok(vec![])
+        } else {
+            let file_in_memory = self.buffers.concat();
+            tracing::debug!(
+                "aligning orc file {} of {} bytes",
+                self.split_info.file.path,
+                file_in_memory.len(),
+            );
+            Ok(vec![OrcFileInMemory::try_create(
+                &self.split_info.file.path,
+                file_in_memory,
+                &self.ctx.schema,
+            )?])
+        }
+    }
+}
+
+fn orc_error(path: &str, e: format::error::Error) -> ErrorCode {
+    ErrorCode::OrcError(format!("fail to read orc file {}: {:?}", path, e))
+}
+
+fn get_fields(meta: &FileMetadata, schema: &DataSchemaRef) -> Result<Vec<(u32, Field)>> {
+    let infer_schema = read::infer_schema(&meta.footer)?;
+    // ORC schemas always start with a struct, whose children are the top level fields.
+    let root = meta
+        .footer
+        .types
+        .first()
+        .ok_or_else(|| ErrorCode::OrcError("orc file without schema"))?;
+
+    let mut read_fields = Vec::with_capacity(schema.num_fields());
+    for f in schema.fields().iter() {
+        let found = infer_schema
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.name.eq_ignore_ascii_case(f.name()))
+            .last();
+        match found {
+            Some((pos, m)) => {
+                let tf = DataField::from(m);
+                if remove_nullable(tf.data_type()) != remove_nullable(f.data_type()) {
+                    return Err(ErrorCode::OrcError(format!(
+                        "orc schema mismatch, column {} expected type {:?}, but got {:?}",
+                        f.name(),
+                        f.data_type(),
+                        tf.data_type()
+                    )));
+                }
+                let column_id = root.subtypes.get(pos).cloned().ok_or_else(|| {
+                    ErrorCode::OrcError(format!("column {} not found in orc types", m.name))
+                })?;
+                read_fields.push((column_id, m.clone()));
+            }
+            None => {
+                return Err(ErrorCode::OrcError(format!(
+                    "schema field size mismatch, expected to find column: {}",
+                    f.name()
+                )));
+            }
+        }
+    }
+    Ok(read_fields)
+}
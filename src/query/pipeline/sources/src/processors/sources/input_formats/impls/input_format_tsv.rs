@@ -26,6 +26,7 @@ use common_meta_types::StageFileFormatType;
 use common_settings::Settings;
 
 use crate::processors::sources::input_formats::input_format_text::get_time_zone;
+use crate::processors::sources::input_formats::input_format_text::rollback_row;
 use crate::processors::sources::input_formats::input_format_text::AligningState;
 use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
 use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
@@ -34,6 +35,7 @@ use crate::processors::sources::input_formats::input_format_text::RowBatch;
 pub struct InputFormatTSV {}
 
 impl InputFormatTSV {
+    // The values of the row are rolled back on error.
     #[allow(clippy::too_many_arguments)]
     fn read_row(
         buf: &[u8],
@@ -49,6 +51,7 @@ impl InputFormatTSV {
         let mut column_index = 0;
         let mut field_start = 0;
         let mut pos = 0;
+        let mut num_deserialized = 0;
         let mut err_msg = None;
         let buf_len = buf.len();
         while pos <= buf_len {
@@ -56,6 +59,7 @@ impl InputFormatTSV {
                 let col_data = &buf[field_start..pos];
                 if col_data.is_empty() {
                     deserializers[column_index].de_default(format_settings);
+                    num_deserialized += 1;
                 } else {
                     let mut reader = NestedCheckpointReader::new(col_data);
                     reader.ignores(|c: u8| c == b' ').expect("must success");
@@ -70,6 +74,7 @@ impl InputFormatTSV {
                         ));
                         break;
                     };
+                    num_deserialized += 1;
                     reader.ignore_white_spaces().expect("must success");
                     if reader.must_eof().is_err() {
                         err_msg = Some(format_column_error(
@@ -99,6 +104,7 @@ impl InputFormatTSV {
         }
 
         if let Some(m) = err_msg {
+            rollback_row(deserializers, num_deserialized);
             let row_info = if let Some(r) = row_index {
                 format!("at row {},", r)
             } else {
@@ -149,22 +155,22 @@ impl InputFormatTextBase for InputFormatTSV {
             batch.start_row,
             batch.offset
         );
-        let schema = &builder.ctx.schema;
-        let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
         let start_row = batch.start_row;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end]; // include \n
-            Self::read_row(
+            if let Err(e) = Self::read_row(
                 buf,
-                columns,
-                schema,
+                &mut builder.mutable_columns,
+                &builder.ctx.schema,
                 &builder.ctx.format_settings,
                 &batch.path,
                 batch.batch_id,
                 batch.offset + start,
                 start_row.map(|n| n + i),
-            )?;
+            ) {
+                builder.skip_row_on_error(e)?;
+            }
             start = *end;
         }
         Ok(())
@@ -24,7 +24,9 @@ use common_base::base::Progress;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_fuse_meta::meta::StatisticsOfColumns;
 use common_io::prelude::FormatSettings;
+use common_meta_types::OnErrorMode;
 use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::UserStageInfo;
@@ -33,8 +35,10 @@ use opendal::io_util::CompressAlgorithm;
 use opendal::Operator;
 
 use crate::processors::sources::input_formats::delimiter::RecordDelimiter;
+use crate::processors::sources::input_formats::impls::input_format_avro::InputFormatAvro;
 use crate::processors::sources::input_formats::impls::input_format_csv::InputFormatCSV;
 use crate::processors::sources::input_formats::impls::input_format_ndjson::InputFormatNDJson;
+use crate::processors::sources::input_formats::impls::input_format_orc::InputFormatOrc;
 use crate::processors::sources::input_formats::impls::input_format_parquet::InputFormatParquet;
 use crate::processors::sources::input_formats::impls::input_format_tsv::InputFormatTSV;
 use crate::processors::sources::input_formats::input_format_text::InputFormatText;
@@ -45,6 +49,11 @@ use crate::processors::sources::input_formats::InputFormat;
 const MIN_ROW_PER_BLOCK: usize = 800 * 1000;
 const DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD: usize = 100 * 1024 * 1024;
 
+/// Tells if the rows of a part of a file, e.g. a stripe of orc, may match the filters of the
+/// query, by the statistics of the columns, keyed by the index of the field in the schema,
+/// and the number of rows of the part.
+pub type StatisticsFilter = Arc<dyn Fn(&StatisticsOfColumns, u64) -> bool + Send + Sync>;
+
 #[derive(Debug)]
 pub enum InputPlan {
     CopyInto(Box<CopyIntoPlan>),
@@ -114,6 +123,10 @@ pub struct InputContext {
     pub rows_to_skip: usize,
     pub field_delimiter: u8,
     pub record_delimiter: RecordDelimiter,
+    pub escape: Option<u8>,
+    pub error_on_column_count_mismatch: bool,
+    // rows failed to be deserialized are skipped if it is `Continue`
+    pub on_error_mode: OnErrorMode,
 
     // runtime config
     pub settings: Arc<Settings>,
@@ -124,6 +137,9 @@ pub struct InputContext {
     pub block_memory_size_threshold: usize,
 
     pub scan_progress: Arc<Progress>,
+
+    // the parts of the files are skipped if it tells that no row may match
+    pub statistics_filter: Option<StatisticsFilter>,
 }
 
 impl Debug for InputContext {
@@ -133,10 +149,17 @@ impl Debug for InputContext {
             .field("rows_to_skip", &self.rows_to_skip)
             .field("field_delimiter", &self.field_delimiter)
             .field("record_delimiter", &self.record_delimiter)
+            .field("escape", &self.escape)
+            .field(
+                "error_on_column_count_mismatch",
+                &self.error_on_column_count_mismatch,
+            )
+            .field("on_error_mode", &self.on_error_mode)
             .field("format_settings", &self.format_settings)
             .field("rows_per_block", &self.rows_per_block)
             .field("read_batch_size", &self.read_batch_size)
             .field("num_splits", &self.splits.len())
+            .field("statistics_filter", &self.statistics_filter.is_some())
             .finish()
     }
 }
@@ -150,6 +173,8 @@ impl InputContext {
                 Ok(Arc::new(InputFormatText::<InputFormatNDJson>::create()))
             }
             StageFileFormatType::Parquet => Ok(Arc::new(InputFormatParquet {})),
+            StageFileFormatType::Orc => Ok(Arc::new(InputFormatOrc {})),
+            StageFileFormatType::Avro => Ok(Arc::new(InputFormatAvro {})),
             format => Err(ErrorCode::LogicalError(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -169,13 +194,37 @@ impl InputContext {
         if files.is_empty() {
             return Err(ErrorCode::BadArguments("no file to copy"));
         }
-        let plan = Box::new(CopyIntoPlan { stage_info, files });
-        let read_batch_size = settings.get_input_read_buffer_size()? as usize;
-        let file_format_options = &plan.stage_info.file_format_options;
-        let format = Self::get_input_format(&file_format_options.format)?;
+        let plan = CopyIntoPlan { stage_info, files };
+        let format = Self::get_input_format(&plan.stage_info.file_format_options.format)?;
         let splits = format
             .get_splits(&plan, &operator, &settings, &schema)
             .await?;
+        Self::try_create_from_splits(
+            operator,
+            settings,
+            format_settings,
+            schema,
+            plan,
+            splits,
+            scan_progress,
+        )
+    }
+
+    /// Create the context of a copy with the splits of the files got in advance, e.g. by the
+    /// `read_partitions` of a table, so that the files are not visited again.
+    pub fn try_create_from_splits(
+        operator: Operator,
+        settings: Arc<Settings>,
+        mut format_settings: FormatSettings,
+        schema: DataSchemaRef,
+        plan: CopyIntoPlan,
+        splits: Vec<Arc<SplitInfo>>,
+        scan_progress: Arc<Progress>,
+    ) -> Result<Self> {
+        let plan = Box::new(plan);
+        let read_batch_size = settings.get_input_read_buffer_size()? as usize;
+        let file_format_options = &plan.stage_info.file_format_options;
+        let format = Self::get_input_format(&file_format_options.format)?;
         let rows_per_block = MIN_ROW_PER_BLOCK;
         let record_delimiter = {
             if file_format_options.record_delimiter.is_empty() {
@@ -193,6 +242,15 @@ impl InputContext {
                 file_format_options.field_delimiter.as_bytes()[0]
             }
         };
+        if !file_format_options.quote.is_empty() {
+            format_settings.quote_char = file_format_options.quote.as_bytes()[0];
+        }
+        if !file_format_options.null_display.is_empty() {
+            format_settings.null_bytes = file_format_options.null_display.as_bytes().to_vec();
+        }
+        let escape = file_format_options.escape.as_bytes().first().cloned();
+        let error_on_column_count_mismatch = file_format_options.error_on_column_count_mismatch;
+        let on_error_mode = plan.stage_info.copy_options.on_error.clone();
         Ok(InputContext {
             format,
             schema,
@@ -204,10 +262,14 @@ impl InputContext {
             read_batch_size,
             rows_to_skip,
             field_delimiter,
+            escape,
+            error_on_column_count_mismatch,
+            on_error_mode,
             scan_progress,
             source: InputSource::Operator(operator),
             plan: InputPlan::CopyInto(plan),
             block_memory_size_threshold: DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+            statistics_filter: None,
         })
     }
 
@@ -237,6 +299,8 @@ impl InputContext {
             }
         };
         let record_delimiter = RecordDelimiter::try_from(&settings.get_record_delimiter()?[..])?;
+        let escape = settings.get_escape_char()?.as_bytes().first().cloned();
+        let error_on_column_count_mismatch = settings.get_error_on_column_count_mismatch()?;
         let compression = settings.get_compression()?;
         let compression = if !compression.is_empty() {
             StageFileCompression::from_str(&compression).map_err(ErrorCode::BadArguments)?
@@ -258,14 +322,23 @@ impl InputContext {
             read_batch_size,
             field_delimiter,
             rows_to_skip,
+            escape,
+            error_on_column_count_mismatch,
+            on_error_mode: OnErrorMode::None,
             scan_progress,
             source: InputSource::Stream(Mutex::new(Some(stream_receiver))),
             plan: InputPlan::StreamingLoad(plan),
             splits: vec![],
             block_memory_size_threshold: DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+            statistics_filter: None,
         })
     }
 
+    pub fn with_statistics_filter(mut self, statistics_filter: StatisticsFilter) -> Self {
+        self.statistics_filter = Some(statistics_filter);
+        self
+    }
+
     pub fn num_prefetch_splits(&self) -> Result<usize> {
         Ok(self.settings.get_max_threads()? as usize)
     }
@@ -24,6 +24,7 @@ use common_datavalues::TypeDeserializerImpl;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
+use common_meta_types::OnErrorMode;
 use common_meta_types::StageFileFormatType;
 use common_pipeline_core::Pipeline;
 use common_settings::Settings;
@@ -358,6 +359,27 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
     fn memory_size(&self) -> usize {
         self.mutable_columns.iter().map(|x| x.memory_size()).sum()
     }
+
+    /// Skips the row failed to be deserialized if the copy continues on error,
+    /// the values of the row are expected to be rolled back already.
+    pub fn skip_row_on_error(&mut self, e: ErrorCode) -> Result<()> {
+        match self.ctx.on_error_mode {
+            OnErrorMode::Continue => {
+                tracing::warn!("skip a row on error: {}", e.message());
+                self.num_rows -= 1;
+                Ok(())
+            }
+            _ => Err(e),
+        }
+    }
+}
+
+/// Drops the values of the first `num_columns` columns deserialized for a row,
+/// so that the row can be skipped.
+pub fn rollback_row(deserializers: &mut [TypeDeserializerImpl], num_columns: usize) {
+    for deserializer in &mut deserializers[..num_columns] {
+        let _ = deserializer.pop_data_value();
+    }
 }
 
 impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
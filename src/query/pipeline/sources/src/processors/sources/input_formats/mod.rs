@@ -23,6 +23,10 @@ mod source_aligner;
 mod source_deserializer;
 mod transform_deserializer;
 
+pub use input_context::CopyIntoPlan;
 pub use input_context::InputContext;
+pub use input_context::StatisticsFilter;
 pub use input_format::InputFormat;
 pub use input_pipeline::StreamingReadBatch;
+pub use input_split::FileInfo;
+pub use input_split::SplitInfo;
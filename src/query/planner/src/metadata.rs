@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -49,6 +50,8 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// The aggregating indexes of the tables, by the indexes of the tables.
+    aggregating_indexes: HashMap<IndexType, Vec<TableEntry>>,
 }
 
 impl Metadata {
@@ -85,6 +88,35 @@ impl Metadata {
             .collect()
     }
 
+    /// Add an aggregating index of the table, the index is not a table of the query until it
+    /// is chosen by the optimizer to answer the aggregation of the table.
+    pub fn add_aggregating_index(
+        &mut self,
+        table_index: IndexType,
+        catalog: String,
+        database: String,
+        index_table: Arc<dyn Table>,
+    ) {
+        let entry = TableEntry::new(
+            table_index,
+            index_table.name().to_string(),
+            catalog,
+            database,
+            index_table,
+        );
+        self.aggregating_indexes
+            .entry(table_index)
+            .or_default()
+            .push(entry);
+    }
+
+    pub fn aggregating_indexes(&self, table_index: IndexType) -> &[TableEntry] {
+        self.aggregating_indexes
+            .get(&table_index)
+            .map(|indexes| indexes.as_slice())
+            .unwrap_or_default()
+    }
+
     pub fn add_column(
         &mut self,
         name: String,
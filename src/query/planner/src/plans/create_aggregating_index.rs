@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateAggregatingIndexPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub definition: AggregatingIndexDefinition,
+}
+
+impl CreateAggregatingIndexPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// The pre-aggregation of an aggregating index, the columns of the index are the group by
+/// columns followed by the aggregates.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregatingIndexDefinition {
+    pub group_by: Vec<String>,
+    pub aggregates: Vec<AggregatingIndexAggregate>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregatingIndexAggregate {
+    /// One of `sum`, `count`, `min` and `max`.
+    pub func_name: String,
+    /// The argument column, None of `count(*)`.
+    pub column: Option<String>,
+}
+
+impl AggregatingIndexAggregate {
+    /// The aggregate of the index data answering this aggregate of the table data, counts are
+    /// summed and the others are aggregated again.
+    pub fn rollup_func_name(&self) -> &str {
+        match self.func_name.as_str() {
+            "count" => "sum",
+            func_name => func_name,
+        }
+    }
+
+    /// The name of the column of the index storing this aggregate, like `sum(b)` or `count(*)`.
+    pub fn column_name(&self) -> String {
+        match &self.column {
+            Some(column) => format!("{}({})", self.func_name, column),
+            None => format!("{}(*)", self.func_name),
+        }
+    }
+}
+
+impl AggregatingIndexDefinition {
+    /// The definition stored in the option of the table of the index.
+    pub fn from_table_option(value: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(value)
+    }
+
+    pub fn to_table_option(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// The query computing the data of the index from the table.
+    pub fn query(&self, database: &str, table: &str) -> String {
+        let mut items = self
+            .group_by
+            .iter()
+            .map(|column| format!("`{}`", column))
+            .collect::<Vec<_>>();
+        for aggregate in &self.aggregates {
+            items.push(match &aggregate.column {
+                Some(column) => format!("{}(`{}`)", aggregate.func_name, column),
+                None => format!("{}(*)", aggregate.func_name),
+            });
+        }
+
+        let mut query = format!(
+            "SELECT {} FROM `{}`.`{}`",
+            items.join(", "),
+            database,
+            table
+        );
+        if !self.group_by.is_empty() {
+            let group_by = self
+                .group_by
+                .iter()
+                .map(|column| format!("`{}`", column))
+                .collect::<Vec<_>>();
+            query.push_str(&format!(" GROUP BY {}", group_by.join(", ")));
+        }
+        query
+    }
+}
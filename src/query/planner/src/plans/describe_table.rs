@@ -20,6 +20,8 @@ pub struct DescribeTablePlan {
     pub database: String,
     /// The table name.
     pub table: String,
+    /// Whether the statistics of the columns in the storage are described.
+    pub extended: bool,
     /// The schema description of the output.
     pub schema: DataSchemaRef,
 }
@@ -0,0 +1,28 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecuteImmediatePlan {
+    /// The script, it's parsed again and run statement by statement when the plan is executed.
+    pub script: String,
+}
+
+impl ExecuteImmediatePlan {
+    /// The value returned by the script, NULL if it returns nothing.
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![DataField::new_nullable("result", Vu8::to_data_type())])
+    }
+}
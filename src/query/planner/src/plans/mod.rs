@@ -12,26 +12,56 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod add_table_row_access_policy;
+mod add_table_virtual_column;
 mod alter_table_cluster_key;
+mod alter_task;
 mod alter_udf;
 mod alter_user;
 mod alter_view;
+mod analyze_table;
 mod call;
+mod create_aggregating_index;
+mod create_connection;
 mod create_database;
+mod create_dictionary;
+mod create_masking_policy;
+mod create_network_policy;
+mod create_plan_hint;
+mod create_resource_group;
 mod create_role;
+mod create_row_access_policy;
+mod create_sequence;
 mod create_stage;
+mod create_stream;
+mod create_task;
 mod create_udf;
 mod create_user;
 mod create_view;
 mod describe_table;
+mod drain_node;
+mod drop_aggregating_index;
+mod drop_connection;
 mod drop_database;
+mod drop_dictionary;
+mod drop_masking_policy;
+mod drop_network_policy;
+mod drop_plan_hint;
+mod drop_resource_group;
 mod drop_role;
+mod drop_row_access_policy;
+mod drop_sequence;
 mod drop_stage;
+mod drop_stream;
 mod drop_table;
 mod drop_table_cluster_key;
+mod drop_table_row_access_policy;
+mod drop_table_virtual_column;
+mod drop_task;
 mod drop_udf;
 mod drop_user;
 mod drop_view;
+mod execute_immediate;
 mod exists_table;
 mod grant_privilege;
 mod grant_role;
@@ -43,34 +73,76 @@ mod rename_database;
 mod rename_table;
 mod revoke_privilege;
 mod revoke_role;
+mod set_database_comment;
+mod set_role;
+mod set_table_column_comment;
+mod set_table_column_masking_policy;
+mod set_table_options;
+mod set_user_variable;
 mod show_create_database;
 mod show_create_table;
 mod show_grants;
+mod swap_table;
 mod truncate_table;
 mod undrop_database;
 mod undrop_table;
+mod unset_table_column_masking_policy;
+mod unset_user_variable;
 mod use_database;
 
+pub use add_table_row_access_policy::AddTableRowAccessPolicyPlan;
+pub use add_table_virtual_column::AddTableVirtualColumnPlan;
 pub use alter_table_cluster_key::AlterTableClusterKeyPlan;
+pub use alter_task::AlterTaskPlan;
+pub use alter_task::TaskAction;
 pub use alter_udf::AlterUDFPlan;
 pub use alter_user::AlterUserPlan;
 pub use alter_view::AlterViewPlan;
+pub use analyze_table::AnalyzeTablePlan;
 pub use call::CallPlan;
+pub use create_aggregating_index::AggregatingIndexAggregate;
+pub use create_aggregating_index::AggregatingIndexDefinition;
+pub use create_aggregating_index::CreateAggregatingIndexPlan;
+pub use create_connection::CreateConnectionPlan;
 pub use create_database::CreateDatabasePlan;
+pub use create_dictionary::CreateDictionaryPlan;
+pub use create_masking_policy::CreateMaskingPolicyPlan;
+pub use create_network_policy::CreateNetworkPolicyPlan;
+pub use create_plan_hint::CreatePlanHintPlan;
+pub use create_resource_group::CreateResourceGroupPlan;
 pub use create_role::CreateRolePlan;
+pub use create_row_access_policy::CreateRowAccessPolicyPlan;
+pub use create_sequence::CreateSequencePlan;
 pub use create_stage::CreateStagePlan;
+pub use create_stream::CreateStreamPlan;
+pub use create_task::CreateTaskPlan;
 pub use create_udf::CreateUDFPlan;
 pub use create_user::CreateUserPlan;
 pub use create_view::CreateViewPlan;
 pub use describe_table::DescribeTablePlan;
+pub use drain_node::DrainNodePlan;
+pub use drop_aggregating_index::DropAggregatingIndexPlan;
+pub use drop_connection::DropConnectionPlan;
 pub use drop_database::DropDatabasePlan;
+pub use drop_dictionary::DropDictionaryPlan;
+pub use drop_masking_policy::DropMaskingPolicyPlan;
+pub use drop_network_policy::DropNetworkPolicyPlan;
+pub use drop_plan_hint::DropPlanHintPlan;
+pub use drop_resource_group::DropResourceGroupPlan;
 pub use drop_role::DropRolePlan;
+pub use drop_row_access_policy::DropRowAccessPolicyPlan;
+pub use drop_sequence::DropSequencePlan;
 pub use drop_stage::DropStagePlan;
+pub use drop_stream::DropStreamPlan;
 pub use drop_table::DropTablePlan;
 pub use drop_table_cluster_key::DropTableClusterKeyPlan;
+pub use drop_table_row_access_policy::DropTableRowAccessPolicyPlan;
+pub use drop_table_virtual_column::DropTableVirtualColumnPlan;
+pub use drop_task::DropTaskPlan;
 pub use drop_udf::DropUDFPlan;
 pub use drop_user::DropUserPlan;
 pub use drop_view::DropViewPlan;
+pub use execute_immediate::ExecuteImmediatePlan;
 pub use exists_table::ExistsTablePlan;
 pub use grant_privilege::GrantPrivilegePlan;
 pub use grant_role::GrantRolePlan;
@@ -85,10 +157,19 @@ pub use rename_table::RenameTableEntity;
 pub use rename_table::RenameTablePlan;
 pub use revoke_privilege::RevokePrivilegePlan;
 pub use revoke_role::RevokeRolePlan;
+pub use set_database_comment::SetDatabaseCommentPlan;
+pub use set_role::SetRolePlan;
+pub use set_table_column_comment::SetTableColumnCommentPlan;
+pub use set_table_column_masking_policy::SetTableColumnMaskingPolicyPlan;
+pub use set_table_options::SetTableOptionsPlan;
+pub use set_user_variable::SetUserVariablePlan;
 pub use show_create_database::ShowCreateDatabasePlan;
 pub use show_create_table::ShowCreateTablePlan;
 pub use show_grants::ShowGrantsPlan;
+pub use swap_table::SwapTablePlan;
 pub use truncate_table::TruncateTablePlan;
 pub use undrop_database::UndropDatabasePlan;
 pub use undrop_table::UndropTablePlan;
+pub use unset_table_column_masking_policy::UnsetTableColumnMaskingPolicyPlan;
+pub use unset_user_variable::UnsetUserVariablePlan;
 pub use use_database::UseDatabasePlan;
@@ -13,8 +13,7 @@
 // limitations under the License.
 use std::sync::Arc;
 
-use common_datavalues::DataSchema;
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OptimizeTablePlan {
@@ -26,7 +25,13 @@ pub struct OptimizeTablePlan {
 
 impl OptimizeTablePlan {
     pub fn schema(&self) -> DataSchemaRef {
-        Arc::new(DataSchema::empty())
+        match self.action {
+            OptimizeTableAction::Verify => DataSchemaRefExt::create(vec![
+                DataField::new("object", Vu8::to_data_type()),
+                DataField::new("error", Vu8::to_data_type()),
+            ]),
+            _ => Arc::new(DataSchema::empty()),
+        }
     }
 }
 
@@ -35,4 +40,6 @@ pub enum OptimizeTableAction {
     All,
     Purge,
     Compact,
+    /// Scans the table for the corrupted objects.
+    Verify,
 }
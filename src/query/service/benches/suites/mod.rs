@@ -46,7 +46,7 @@ pub async fn select_executor(sql: &str) -> Result<()> {
     } = plan
     {
         let executor =
-            SelectInterpreterV2::try_create(ctx.clone(), *bind_context, *s_expr, metadata)?;
+            SelectInterpreterV2::try_create(ctx.clone(), *bind_context, *s_expr, metadata, None)?;
         let mut stream = executor.execute(ctx.clone()).await?;
         while let Some(_block) = stream.next().await {}
     } else {
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::num::NonZeroI32;
+use std::time::Duration;
+
+use common_base::base::Profiling;
+use common_exception::ErrorCode;
+use poem::error::BadRequest;
+use poem::error::InternalServerError;
+use poem::error::NotFound;
+use poem::web::Path;
+use poem::web::Query;
+use poem::Response;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::sessions::SessionManager;
+
+#[derive(Deserialize, Debug)]
+pub struct QueryPProfParams {
+    #[serde(default = "QueryPProfParams::default_seconds")]
+    seconds: u64,
+    #[serde(default = "QueryPProfParams::default_frequency")]
+    frequency: NonZeroI32,
+    /// One of `collapsed`, `flamegraph` and `pprof`.
+    #[serde(default = "QueryPProfParams::default_format")]
+    format: String,
+}
+
+impl QueryPProfParams {
+    fn default_seconds() -> u64 {
+        5
+    }
+
+    fn default_frequency() -> NonZeroI32 {
+        // safe unwrap
+        NonZeroI32::new(99).unwrap()
+    }
+
+    fn default_format() -> String {
+        "collapsed".to_string()
+    }
+}
+
+// Samples the stacks of the processor threads of the running query on this node.
+// example: /debug/pprof/query/<query_id>?seconds=5&frequency=99&format=collapsed
+#[poem::handler]
+pub async fn debug_query_pprof_handler(
+    Path(query_id): Path<String>,
+    Query(params): Query<QueryPProfParams>,
+) -> poem::Result<Response> {
+    let shared = SessionManager::instance()
+        .get_query_context_shared_by_query_id(&query_id)
+        .ok_or_else(|| {
+            NotFound(ErrorCode::BadArguments(format!(
+                "Query {} is not running on the node",
+                query_id
+            )))
+        })?;
+    let thread_ids = match shared.get_executor_threads() {
+        Some(thread_ids) if !thread_ids.is_empty() => thread_ids,
+        _ => {
+            return Err(BadRequest(ErrorCode::BadArguments(format!(
+                "Query {} is not executing the processors",
+                query_id
+            ))));
+        }
+    };
+
+    info!(
+        "start pprof request of query {} second: {:?} frequency: {:?} format: {}",
+        query_id, params.seconds, params.frequency, params.format
+    );
+    let profile = Profiling::create(
+        Duration::from_secs(params.seconds),
+        i32::from(params.frequency),
+    )
+    .with_threads(thread_ids);
+
+    let (body, content_type) = match params.format.as_str() {
+        "collapsed" => (profile.dump_collapsed_stacks().await, "text/plain"),
+        "flamegraph" => (profile.dump_flamegraph().await, "image/svg+xml"),
+        "pprof" => (profile.dump_proto().await, "application/octet-stream"),
+        format => {
+            return Err(BadRequest(ErrorCode::BadArguments(format!(
+                "Unknown format {}, expecting collapsed, flamegraph or pprof",
+                format
+            ))));
+        }
+    };
+    let body = body.map_err(InternalServerError)?;
+
+    info!("finished pprof request of query {}", query_id);
+    Ok(Response::builder().content_type(content_type).body(body))
+}
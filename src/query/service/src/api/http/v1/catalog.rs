@@ -0,0 +1,37 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_api::export_catalog;
+use common_users::UserApiProvider;
+use poem::http::StatusCode;
+use poem::IntoResponse;
+
+// GET /v1/catalog/dump
+// dump all the keys of the meta-service as a catalog dump, one json line of each key,
+// to be imported into a fresh meta cluster by `databend-meta --cmd import`.
+// return: the lines of the catalog dump, the header first
+#[poem::handler]
+pub async fn catalog_dump_handler() -> poem::Result<impl IntoResponse> {
+    let kv_api = UserApiProvider::instance().get_meta_store_client();
+    let lines = export_catalog(kv_api.as_ref()).await.map_err(|cause| {
+        poem::Error::from_string(
+            format!("Failed to dump the catalog. cause: {cause}"),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?;
+
+    let mut body = lines.join("\n");
+    body.push('\n');
+    Ok(body)
+}
@@ -47,5 +47,9 @@ async fn list_nodes(sessions: &Arc<SessionManager>) -> Result<Vec<Arc<NodeInfo>>
         .create_session(SessionType::HTTPAPI("WatchCluster".to_string()))
         .await?;
     let watch_cluster_context = watch_cluster_session.create_query_context().await?;
-    Ok(watch_cluster_context.get_cluster().get_nodes())
+    let cluster = watch_cluster_context.get_cluster();
+    // The draining nodes are listed too, their `draining` tells them apart.
+    let mut nodes = cluster.get_nodes();
+    nodes.extend(cluster.get_draining_nodes());
+    Ok(nodes)
 }
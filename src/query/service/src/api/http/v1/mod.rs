@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod catalog;
 pub mod cluster;
 pub mod config;
 pub mod instance_status;
@@ -64,8 +64,16 @@ impl HttpService {
                 "/v1/cluster/list",
                 get(super::http::v1::cluster::cluster_list_handler),
             )
+            .at(
+                "/v1/catalog/dump",
+                get(super::http::v1::catalog::catalog_dump_handler),
+            )
             .at("/debug/home", get(debug_home_handler))
-            .at("/debug/pprof/profile", get(debug_pprof_handler));
+            .at("/debug/pprof/profile", get(debug_pprof_handler))
+            .at(
+                "/debug/pprof/query/:query_id",
+                get(super::http::debug::query_pprof::debug_query_pprof_handler),
+            );
 
         if self.config.query.management_mode {
             route = route.at(
@@ -29,9 +29,11 @@ pub use rpc::FragmentData;
 pub use rpc::FragmentPayload;
 pub use rpc::FragmentPlanPacket;
 pub use rpc::InitNodesChannelPacket;
+pub use rpc::KillQueryPacket;
 pub use rpc::MergeExchange;
 pub use rpc::PrecommitBlock;
 pub use rpc::QueryFragmentsPlanPacket;
+pub use rpc::RoundRobinExchange;
 pub use rpc::ServerFlightExchange;
 pub use rpc::ShuffleDataExchange;
 pub use rpc::ShuffleDataExchangeV2;
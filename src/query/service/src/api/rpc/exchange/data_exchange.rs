@@ -20,6 +20,7 @@ use crate::sql::executor::PhysicalScalar;
 pub enum DataExchange {
     Merge(MergeExchange),
     Broadcast(BroadcastExchange),
+    RoundRobin(RoundRobinExchange),
     ShuffleDataExchange(ShuffleDataExchange),
     ShuffleDataExchangeV2(ShuffleDataExchangeV2),
 }
@@ -29,6 +30,7 @@ impl DataExchange {
         match self {
             DataExchange::Merge(exchange) => vec![exchange.destination_id.clone()],
             DataExchange::Broadcast(exchange) => exchange.destination_ids.clone(),
+            DataExchange::RoundRobin(exchange) => exchange.destination_ids.clone(),
             DataExchange::ShuffleDataExchange(exchange) => exchange.destination_ids.clone(),
             DataExchange::ShuffleDataExchangeV2(exchange) => exchange.destination_ids.clone(),
         }
@@ -39,6 +41,7 @@ impl DataExchange {
             DataExchange::Merge(_) => true,
             DataExchange::ShuffleDataExchange(_) => true,
             DataExchange::ShuffleDataExchangeV2(_) => true,
+            DataExchange::RoundRobin(_) => true,
             DataExchange::Broadcast(exchange) => exchange.from_multiple_nodes,
         }
     }
@@ -102,3 +105,14 @@ impl BroadcastExchange {
         })
     }
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoundRobinExchange {
+    pub destination_ids: Vec<String>,
+}
+
+impl RoundRobinExchange {
+    pub fn create(destination_ids: Vec<String>) -> DataExchange {
+        DataExchange::RoundRobin(RoundRobinExchange { destination_ids })
+    }
+}
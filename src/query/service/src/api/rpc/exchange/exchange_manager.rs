@@ -25,10 +25,13 @@ use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::ConnectionFactory;
+use futures::future::join_all;
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
+use tracing::warn;
 
+use crate::api::rpc::exchange::exchange_params::exchange_compression;
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
@@ -40,6 +43,7 @@ use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::flight_scatter_broadcast::BroadcastFlightScatter;
 use crate::api::rpc::flight_scatter_hash::HashFlightScatter;
 use crate::api::rpc::flight_scatter_hash_v2::HashFlightScatterV2;
+use crate::api::rpc::flight_scatter_round_robin::RoundRobinFlightScatter;
 use crate::api::rpc::Packet;
 use crate::api::DataExchange;
 use crate::api::FlightClient;
@@ -88,12 +92,17 @@ impl DataExchangeManager {
         let mut targets_exchanges = HashMap::new();
 
         let source = &packet.executor.id;
+        let credits = self.config.query.flight_exchange_credits as usize;
         for connection_info in &packet.connections_info {
             if connection_info.create_request_channel {
                 let query_id = &packet.query_id;
                 let address = &connection_info.target.flight_address;
                 let mut flight_client = Self::create_client(&self.config, address).await?;
-                request_exchanges.push(flight_client.request_server_exchange(query_id).await?);
+                request_exchanges.push(
+                    flight_client
+                        .request_server_exchange(query_id, credits)
+                        .await?,
+                );
             }
 
             for fragment in &connection_info.fragments {
@@ -103,7 +112,7 @@ impl DataExchangeManager {
                 targets_exchanges.insert(
                     (connection_info.target.id.clone(), *fragment),
                     flight_client
-                        .do_exchange(&packet.query_id, source, *fragment)
+                        .do_exchange(&packet.query_id, source, *fragment, credits)
                         .await?,
                 );
             }
@@ -126,6 +135,17 @@ impl DataExchangeManager {
         }
     }
 
+    pub fn get_config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The number of the queries of which the fragments are running on the node.
+    pub fn num_running_queries(&self) -> usize {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &*queries_coordinator_guard.deref().get() };
+        queries_coordinator.len()
+    }
+
     pub async fn create_client(config: &Config, address: &str) -> Result<FlightClient> {
         match config.tls_query_cli_enabled() {
             true => Ok(FlightClient::new(FlightServiceClient::new(
@@ -228,10 +248,62 @@ impl DataExchangeManager {
         }
     }
 
-    pub async fn commit_actions(
+    /// Submits the fragments to the nodes without executing them, the query is removed
+    /// from all the nodes if the submission fails, so that it can be submitted again.
+    pub async fn submit_actions(
+        &self,
+        ctx: Arc<QueryContext>,
+        actions: &QueryFragmentsActions,
+    ) -> Result<PipelineBuildResult> {
+        let query_id = actions.get_query_id();
+        let submit_res = self
+            .submit_actions_impl(ctx.clone(), &query_id, actions)
+            .await;
+        if submit_res.is_err() {
+            self.kill_actions(ctx, actions).await;
+        }
+
+        submit_res
+    }
+
+    /// Shutdowns and removes the fragments of the query from all the nodes. The nodes that
+    /// cannot be connected are skipped, their fragments are dropped with the broken channels.
+    pub async fn kill_actions(&self, ctx: Arc<QueryContext>, actions: &QueryFragmentsActions) {
+        let query_id = actions.get_query_id();
+        self.shutdown_query(&query_id);
+        self.on_finished_query(&query_id);
+
+        let timeout = match ctx.get_settings().get_flight_client_timeout() {
+            Ok(timeout) => timeout,
+            Err(cause) => {
+                warn!("Cannot kill query {} on the cluster: {:?}", query_id, cause);
+                return;
+            }
+        };
+
+        match actions.get_kill_query_packets() {
+            Err(cause) => warn!("Cannot kill query {} on the cluster: {:?}", query_id, cause),
+            Ok(packets) => {
+                let kills = packets
+                    .iter()
+                    .map(|packet| packet.commit(&self.config, timeout));
+                for (packet, res) in packets.iter().zip(join_all(kills).await) {
+                    if let Err(cause) = res {
+                        warn!(
+                            "Cannot kill query {} on node {}: {:?}",
+                            query_id, packet.executor, cause
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    async fn submit_actions_impl(
         &self,
         ctx: Arc<QueryContext>,
-        actions: QueryFragmentsActions,
+        query_id: &str,
+        actions: &QueryFragmentsActions,
     ) -> Result<PipelineBuildResult> {
         let settings = ctx.get_settings();
         let timeout = settings.get_flight_client_timeout()?;
@@ -256,27 +328,34 @@ impl DataExchangeManager {
         self.init_query_fragments_plan(&ctx, &local_query_fragments_plan_packet)?;
 
         // Get local pipeline of local task
-        let build_res = self.get_root_pipeline(ctx, root_actions)?;
+        self.get_root_pipeline(ctx, query_id, root_actions)
+    }
 
+    /// Executes the fragments submitted by `submit_actions()` on the nodes.
+    pub async fn execute_actions(
+        &self,
+        ctx: Arc<QueryContext>,
+        actions: &QueryFragmentsActions,
+    ) -> Result<()> {
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
         actions
             .get_execute_partial_query_packets()?
             .commit(&self.config, timeout)
-            .await?;
-        Ok(build_res)
+            .await
     }
 
     fn get_root_pipeline(
         &self,
         ctx: Arc<QueryContext>,
+        query_id: &str,
         root_actions: &QueryFragmentActions,
     ) -> Result<PipelineBuildResult> {
-        let query_id = ctx.get_id();
         let fragment_id = root_actions.fragment_id;
 
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
 
-        match queries_coordinator.get_mut(&query_id) {
+        match queries_coordinator.get_mut(query_id) {
             None => Err(ErrorCode::LogicalError("Query not exists.")),
             Some(query_coordinator) => {
                 query_coordinator.fragment_exchanges.clear();
@@ -288,8 +367,8 @@ impl DataExchangeManager {
 
                 let statistics_receiver: Mutex<StatisticsReceiver> =
                     Mutex::new(statistics_receiver);
+                let query_id = query_id.to_string();
                 build_res.main_pipeline.set_on_finished(move |may_error| {
-                    let query_id = ctx.get_id();
                     let mut statistics_receiver = statistics_receiver.lock();
 
                     statistics_receiver.shutdown();
@@ -550,7 +629,8 @@ impl QueryCoordinator {
             }
         }
 
-        let executor_settings = ExecutorSettings::try_create(&info.query_ctx.get_settings())?;
+        let executor_settings = ExecutorSettings::try_create(&info.query_ctx.get_settings())?
+            .with_query_memory_tracker(info.query_ctx.get_memory_tracker());
 
         let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
 
@@ -608,6 +688,9 @@ impl FragmentCoordinator {
         ctx: &Arc<QueryContext>,
         info: &QueryInfo,
     ) -> Result<ExchangeParams> {
+        let compression =
+            exchange_compression(&ctx.get_config().query.flight_exchange_compression)?;
+
         match &self.data_exchange {
             None => Err(ErrorCode::LogicalError("Cannot find data exchange.")),
             Some(DataExchange::Merge(exchange)) => {
@@ -616,6 +699,7 @@ impl FragmentCoordinator {
                     fragment_id: self.fragment_id,
                     query_id: info.query_id.to_string(),
                     destination_id: exchange.destination_id.clone(),
+                    compression,
                 }))
             }
             Some(DataExchange::Broadcast(exchange)) => {
@@ -628,6 +712,20 @@ impl FragmentCoordinator {
                     shuffle_scatter: Arc::new(Box::new(BroadcastFlightScatter::try_create(
                         exchange.destination_ids.len(),
                     )?)),
+                    compression,
+                }))
+            }
+            Some(DataExchange::RoundRobin(exchange)) => {
+                Ok(ExchangeParams::ShuffleExchange(ShuffleExchangeParams {
+                    schema: self.payload.schema()?,
+                    fragment_id: self.fragment_id,
+                    query_id: info.query_id.to_string(),
+                    executor_id: info.current_executor.to_string(),
+                    destination_ids: exchange.destination_ids.to_owned(),
+                    shuffle_scatter: Arc::new(Box::new(RoundRobinFlightScatter::try_create(
+                        exchange.destination_ids.len(),
+                    )?)),
+                    compression,
                 }))
             }
             Some(DataExchange::ShuffleDataExchange(exchange)) => {
@@ -643,6 +741,7 @@ impl FragmentCoordinator {
                         Some(exchange.exchange_expression.clone()),
                         exchange.destination_ids.len(),
                     )?)),
+                    compression,
                 }))
             }
             Some(DataExchange::ShuffleDataExchangeV2(exchange)) => {
@@ -657,6 +756,7 @@ impl FragmentCoordinator {
                         exchange.shuffle_keys.clone(),
                         exchange.destination_ids.len(),
                     )?),
+                    compression,
                 }))
             }
         }
@@ -14,9 +14,8 @@
 
 use std::sync::Arc;
 
-use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::Compression;
 use common_arrow::arrow::io::ipc::write::WriteOptions;
-use common_arrow::arrow::io::ipc::IpcField;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,7 +25,6 @@ use crate::api::rpc::flight_scatter::FlightScatter;
 #[derive(Clone)]
 pub struct SerializeParams {
     pub options: WriteOptions,
-    pub ipc_fields: Vec<IpcField>,
     pub local_executor_pos: usize,
 }
 
@@ -38,6 +36,7 @@ pub struct ShuffleExchangeParams {
     pub schema: DataSchemaRef,
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
+    pub compression: Option<Compression>,
 }
 
 #[derive(Clone)]
@@ -46,6 +45,7 @@ pub struct MergeExchangeParams {
     pub fragment_id: usize,
     pub destination_id: String,
     pub schema: DataSchemaRef,
+    pub compression: Option<Compression>,
 }
 
 pub enum ExchangeParams {
@@ -55,27 +55,24 @@ pub enum ExchangeParams {
 
 impl MergeExchangeParams {
     pub fn create_serialize_params(&self) -> Result<SerializeParams> {
-        let arrow_schema = self.schema.to_arrow();
-        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
         Ok(SerializeParams {
-            ipc_fields,
             local_executor_pos: 0,
-            options: WriteOptions { compression: None },
+            options: WriteOptions {
+                compression: self.compression,
+            },
         })
     }
 }
 
 impl ShuffleExchangeParams {
     pub fn create_serialize_params(&self) -> Result<SerializeParams> {
-        let arrow_schema = self.schema.to_arrow();
-        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
-
         for (index, executor) in self.destination_ids.iter().enumerate() {
             if executor == &self.executor_id {
                 return Ok(SerializeParams {
-                    ipc_fields,
                     local_executor_pos: index,
-                    options: WriteOptions { compression: None },
+                    options: WriteOptions {
+                        compression: self.compression,
+                    },
                 });
             }
         }
@@ -99,3 +96,17 @@ impl ExchangeParams {
         }
     }
 }
+
+// The compression of the blocks serialized to the other nodes, the compression of the
+// buffers is kept in the messages, so the receivers are not required to know it.
+pub fn exchange_compression(compression: &str) -> Result<Option<Compression>> {
+    match compression.to_lowercase().as_str() {
+        "" | "none" => Ok(None),
+        "lz4" => Ok(Some(Compression::LZ4)),
+        "zstd" => Ok(Some(Compression::ZSTD)),
+        compression => Err(ErrorCode::InvalidConfig(format!(
+            "Unsupported flight exchange compression: {}, expected none, lz4 or zstd",
+            compression
+        ))),
+    }
+}
@@ -15,10 +15,6 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use common_arrow::arrow::io::flight::default_ipc_fields;
-use common_arrow::arrow::io::flight::deserialize_batch;
-use common_arrow::arrow::io::flight::serialize_batch;
-use common_arrow::arrow::io::ipc::IpcSchema;
 use common_catalog::table_context::TableContext;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
@@ -29,6 +25,9 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::Pipeline;
+use futures::future::select;
+use futures::future::select_all;
+use futures::future::Either;
 
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::SerializeParams;
@@ -122,14 +121,24 @@ impl ExchangeTransform {
         }
     }
 
-    async fn send_packet(output_packet: DataPacket, exchange: FlightExchange) -> Result<()> {
-        if exchange.send(output_packet).await.is_err() {
-            return Err(ErrorCode::TokioError(
-                "Cannot send flight data to endpoint, because sender is closed.",
-            ));
+    // Receives the data of any of the other nodes, returns None if all the nodes are finished.
+    async fn recv_any(flight_exchanges: &[FlightExchange]) -> Result<Option<DataPacket>> {
+        let mut futures = flight_exchanges
+            .iter()
+            .map(|flight_exchange| Box::pin(flight_exchange.recv()))
+            .collect::<Vec<_>>();
+
+        while !futures.is_empty() {
+            let (data_packet, _index, remain_futures) = select_all(futures).await;
+
+            if let Some(data_packet) = data_packet? {
+                return Ok(Some(data_packet));
+            }
+
+            futures = remain_futures;
         }
 
-        Ok(())
+        Ok(None)
     }
 }
 
@@ -228,19 +237,9 @@ impl Processor for ExchangeTransform {
                     output_data.data_block = Some(data_block);
                     output_data.serialized_blocks.push(None);
                 } else {
-                    let chunks = data_block.try_into()?;
                     let options = &self.serialize_params.options;
-                    let ipc_fields = &self.serialize_params.ipc_fields;
-                    let (dicts, values) = serialize_batch(&chunks, ipc_fields, options)?;
-
-                    if !dicts.is_empty() {
-                        return Err(ErrorCode::UnImplement(
-                            "DatabendQuery does not implement dicts.",
-                        ));
-                    }
-
+                    let data = FragmentData::serialize(&data_block, options)?;
                     output_data.has_serialized_blocks = true;
-                    let data = FragmentData::create(values);
                     output_data
                         .serialized_blocks
                         .push(Some(DataPacket::FragmentData(data)));
@@ -265,15 +264,45 @@ impl Processor for ExchangeTransform {
 
     async fn async_process(&mut self) -> Result<()> {
         if let Some(mut output_data) = self.output_data.take() {
-            let mut futures = Vec::with_capacity(output_data.serialized_blocks.len());
             for index in 0..output_data.serialized_blocks.len() {
+                if output_data.serialized_blocks[index].is_none() {
+                    continue;
+                }
+
+                let flight_exchange = &self.flight_exchanges[index];
+                let credit = match self.remote_data.is_some() {
+                    true => flight_exchange.acquire_credit().await?,
+                    false => {
+                        // The nodes may be waiting for the credits of each other, so the data
+                        // of the other nodes is received while waiting for the credit.
+                        let acquire_credit = Box::pin(flight_exchange.acquire_credit());
+                        let recv_any = Box::pin(Self::recv_any(&self.flight_exchanges));
+
+                        match select(acquire_credit, recv_any).await {
+                            Either::Left((credit, _)) => credit?,
+                            Either::Right((data_packet, acquire_credit)) => match data_packet? {
+                                None => acquire_credit.await?,
+                                Some(data_packet) => {
+                                    self.remote_data = Some(data_packet);
+                                    self.output_data = Some(output_data);
+                                    return Ok(());
+                                }
+                            },
+                        }
+                    }
+                };
+
                 if let Some(output_packet) = output_data.serialized_blocks[index].take() {
-                    let exchange = self.flight_exchanges[index].clone();
-                    futures.push(Self::send_packet(output_packet, exchange));
+                    if flight_exchange
+                        .send_with_credit(output_packet, credit)
+                        .is_err()
+                    {
+                        return Err(ErrorCode::TokioError(
+                            "Cannot send flight data to endpoint, because sender is closed.",
+                        ));
+                    }
                 }
             }
-
-            futures::future::try_join_all(futures).await?;
         }
 
         if self.wait_channel_closed && !self.finished {
@@ -306,25 +335,10 @@ impl ExchangeTransform {
         }
 
         let schema = &self.shuffle_exchange_params.schema;
-
-        let arrow_schema = Arc::new(schema.to_arrow());
-        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
-        let ipc_schema = IpcSchema {
-            fields: ipc_fields,
-            is_little_endian: true,
-        };
-
-        let batch = deserialize_batch(
-            &fragment_data.data,
-            &arrow_schema.fields,
-            &ipc_schema,
-            &Default::default(),
-        )?;
-
         self.output_data = Some(OutputData {
             serialized_blocks: vec![],
             has_serialized_blocks: false,
-            data_block: Some(DataBlock::from_chunk(schema, &batch)?),
+            data_block: Some(fragment_data.deserialize(schema)?),
         });
 
         Ok(())
@@ -15,9 +15,6 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use common_arrow::arrow::io::flight::deserialize_batch;
-use common_arrow::arrow::io::ipc::write::default_ipc_fields;
-use common_arrow::arrow::io::ipc::IpcSchema;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -158,21 +155,7 @@ impl ExchangeSourceTransform {
 
     fn on_recv_data(&mut self, fragment_data: FragmentData) -> Result<()> {
         let schema = &self.exchange_params.schema;
-        let arrow_schema = Arc::new(schema.to_arrow());
-        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
-        let ipc_schema = IpcSchema {
-            fields: ipc_fields,
-            is_little_endian: true,
-        };
-
-        let batch = deserialize_batch(
-            &fragment_data.data,
-            &arrow_schema.fields,
-            &ipc_schema,
-            &Default::default(),
-        )?;
-
-        self.output_data = Some(DataBlock::from_chunk(schema, &batch)?);
+        self.output_data = Some(fragment_data.deserialize(schema)?);
 
         Ok(())
     }
@@ -23,9 +23,12 @@ use async_channel::Sender;
 use common_arrow::arrow_format::flight::data::Action;
 use common_arrow::arrow_format::flight::data::FlightData;
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
 use common_base::base::tokio::time::Duration;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use futures_util::Stream;
 use futures_util::StreamExt;
 use tonic::metadata::MetadataKey;
 use tonic::metadata::MetadataValue;
@@ -68,13 +71,18 @@ impl FlightClient {
         }
     }
 
-    pub async fn request_server_exchange(&mut self, query_id: &str) -> Result<FlightExchange> {
+    pub async fn request_server_exchange(
+        &mut self,
+        query_id: &str,
+        credits: usize,
+    ) -> Result<FlightExchange> {
         let (tx, rx) = async_channel::unbounded();
         Ok(FlightExchange::from_client(
             tx,
+            credits,
             self.inner
                 .do_exchange(
-                    RequestBuilder::create(Box::pin(rx))
+                    RequestBuilder::create(Box::pin(FlightExchange::credited_stream(rx)))
                         .with_metadata("x-type", "request_server_exchange")?
                         .with_metadata("x-query-id", query_id)?
                         .build(),
@@ -89,13 +97,15 @@ impl FlightClient {
         query_id: &str,
         source: &str,
         fragment_id: usize,
+        credits: usize,
     ) -> Result<FlightExchange> {
         let (tx, rx) = async_channel::unbounded();
         Ok(FlightExchange::from_client(
             tx,
+            credits,
             self.inner
                 .do_exchange(
-                    RequestBuilder::create(Box::pin(rx))
+                    RequestBuilder::create(Box::pin(FlightExchange::credited_stream(rx)))
                         .with_metadata("x-type", "exchange_fragment")?
                         .with_metadata("x-source", source)?
                         .with_metadata("x-query-id", query_id)?
@@ -153,7 +163,8 @@ pub enum FlightExchange {
 impl FlightExchange {
     pub fn from_server(
         streaming: Request<Streaming<FlightData>>,
-        response_tx: Sender<Result<FlightData, Status>>,
+        response_tx: Sender<(Result<FlightData, Status>, OwnedSemaphorePermit)>,
+        credits: usize,
     ) -> FlightExchange {
         let mut streaming = streaming.into_inner();
         let (tx, rx) = async_channel::bounded(1);
@@ -168,6 +179,7 @@ impl FlightExchange {
         FlightExchange::Server(ServerFlightExchange {
             response_tx,
             request_rx: rx,
+            credits: Self::create_credits(credits),
             state: Arc::new(ChannelState::create()),
             is_closed_request: AtomicBool::new(false),
             is_closed_response: AtomicBool::new(false),
@@ -175,7 +187,8 @@ impl FlightExchange {
     }
 
     pub fn from_client(
-        response_tx: Sender<FlightData>,
+        response_tx: Sender<(FlightData, OwnedSemaphorePermit)>,
+        credits: usize,
         mut streaming: Streaming<FlightData>,
     ) -> FlightExchange {
         let (tx, request_rx) = async_channel::bounded(1);
@@ -190,6 +203,7 @@ impl FlightExchange {
         FlightExchange::Client(ClientFlightExchange {
             request_rx,
             response_tx,
+            credits: Self::create_credits(credits),
             state: Arc::new(ChannelState::create()),
             is_closed_request: AtomicBool::new(false),
             is_closed_response: AtomicBool::new(false),
@@ -198,13 +212,55 @@ impl FlightExchange {
 }
 
 impl FlightExchange {
+    // The packets are buffered in the channel until they are sent to the remote node by the
+    // transport, each buffered packet holds a credit of the exchange, which is given back once
+    // the packet is taken by the transport. The senders wait for the credits so that a slow
+    // consumer node doesn't cause unbounded buffering on the producers.
+    fn create_credits(credits: usize) -> Arc<Semaphore> {
+        match credits {
+            0 => Arc::new(Semaphore::new(Semaphore::MAX_PERMITS)),
+            credits => Arc::new(Semaphore::new(credits)),
+        }
+    }
+
+    // The stream of the packets taken by the transport, giving back the credits of the packets.
+    pub fn credited_stream<T: Send + 'static>(
+        rx: Receiver<(T, OwnedSemaphorePermit)>,
+    ) -> impl Stream<Item = T> + Send + Sync + 'static {
+        rx.map(|(data, _credit)| data)
+    }
+
     pub async fn send(&self, data: DataPacket) -> Result<()> {
+        let credit = self.acquire_credit().await?;
+        self.send_with_credit(data, credit)
+    }
+
+    // Waits for a credit of the exchange, it's cancel safe, so the caller can wait for the
+    // credit and the data of the other nodes at the same time.
+    pub async fn acquire_credit(&self) -> Result<OwnedSemaphorePermit> {
+        let credits = match self {
+            FlightExchange::Dummy => {
+                return Err(ErrorCode::UnImplement(
+                    "Unimplemented send in dummy exchange.",
+                ));
+            }
+            FlightExchange::Client(exchange) => exchange.credits.clone(),
+            FlightExchange::Server(exchange) => exchange.credits.clone(),
+        };
+
+        credits
+            .acquire_owned()
+            .await
+            .map_err(|cause| ErrorCode::TokioError(format!("Cannot acquire credit, {}", cause)))
+    }
+
+    pub fn send_with_credit(&self, data: DataPacket, credit: OwnedSemaphorePermit) -> Result<()> {
         match self {
             FlightExchange::Dummy => Err(ErrorCode::UnImplement(
                 "Unimplemented send in dummy exchange.",
             )),
-            FlightExchange::Client(exchange) => exchange.send(data).await,
-            FlightExchange::Server(exchange) => exchange.send(data).await,
+            FlightExchange::Client(exchange) => exchange.send_with_credit(data, credit),
+            FlightExchange::Server(exchange) => exchange.send_with_credit(data, credit),
         }
     }
 
@@ -257,15 +313,16 @@ impl ChannelState {
 
 pub struct ClientFlightExchange {
     state: Arc<ChannelState>,
+    credits: Arc<Semaphore>,
     is_closed_request: AtomicBool,
     is_closed_response: AtomicBool,
-    response_tx: Sender<FlightData>,
+    response_tx: Sender<(FlightData, OwnedSemaphorePermit)>,
     request_rx: Receiver<Result<FlightData, Status>>,
 }
 
 impl ClientFlightExchange {
-    pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.response_tx.send(FlightData::from(data)).await {
+    pub fn send_with_credit(&self, data: DataPacket, credit: OwnedSemaphorePermit) -> Result<()> {
+        if let Err(_cause) = self.response_tx.try_send((FlightData::from(data), credit)) {
             return Err(ErrorCode::LogicalError("It's a bug"));
         }
 
@@ -316,6 +373,7 @@ impl Clone for ClientFlightExchange {
 
         ClientFlightExchange {
             state: self.state.clone(),
+            credits: self.credits.clone(),
             request_rx: self.request_rx.clone(),
             response_tx: self.response_tx.clone(),
             is_closed_request: AtomicBool::new(false),
@@ -342,10 +400,11 @@ impl Drop for ClientFlightExchange {
 
 pub struct ServerFlightExchange {
     state: Arc<ChannelState>,
+    credits: Arc<Semaphore>,
     is_closed_request: AtomicBool,
     is_closed_response: AtomicBool,
     request_rx: Receiver<Result<FlightData, Status>>,
-    response_tx: Sender<Result<FlightData, Status>>,
+    response_tx: Sender<(Result<FlightData, Status>, OwnedSemaphorePermit)>,
 }
 
 impl Clone for ServerFlightExchange {
@@ -355,6 +414,7 @@ impl Clone for ServerFlightExchange {
 
         ServerFlightExchange {
             state: self.state.clone(),
+            credits: self.credits.clone(),
             request_rx: self.request_rx.clone(),
             response_tx: self.response_tx.clone(),
             is_closed_request: AtomicBool::new(false),
@@ -380,8 +440,11 @@ impl Drop for ServerFlightExchange {
 }
 
 impl ServerFlightExchange {
-    pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.response_tx.send(Ok(FlightData::from(data))).await {
+    pub fn send_with_credit(&self, data: DataPacket, credit: OwnedSemaphorePermit) -> Result<()> {
+        if let Err(_cause) = self
+            .response_tx
+            .try_send((Ok(FlightData::from(data)), credit))
+        {
             return Err(ErrorCode::LogicalError("It's a bug"));
         }
 
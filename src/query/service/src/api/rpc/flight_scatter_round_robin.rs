@@ -0,0 +1,56 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+
+use crate::api::rpc::flight_scatter::FlightScatter;
+
+/// Sends the whole block to one of the destinations in turn, the other destinations
+/// get empty blocks, which are not sent.
+pub struct RoundRobinFlightScatter {
+    scattered_size: usize,
+    next_destination: AtomicUsize,
+}
+
+impl RoundRobinFlightScatter {
+    pub fn try_create(scattered_size: usize) -> Result<Self> {
+        Ok(RoundRobinFlightScatter {
+            scattered_size,
+            next_destination: AtomicUsize::new(0),
+        })
+    }
+}
+
+impl FlightScatter for RoundRobinFlightScatter {
+    fn execute(&self, data_block: &DataBlock, _num: usize) -> Result<Vec<DataBlock>> {
+        let destination =
+            self.next_destination.fetch_add(1, Ordering::Relaxed) % self.scattered_size;
+
+        let mut data_blocks = Vec::with_capacity(self.scattered_size);
+        for index in 0..self.scattered_size {
+            match index == destination {
+                true => data_blocks.push(data_block.clone()),
+                false => {
+                    data_blocks.push(DataBlock::empty_with_schema(data_block.schema().clone()))
+                }
+            }
+        }
+
+        Ok(data_blocks)
+    }
+}
@@ -51,6 +51,11 @@ impl DatabendQueryFlightService {
     pub fn create() -> Self {
         DatabendQueryFlightService {}
     }
+
+    fn exchange_credits() -> usize {
+        let exchange_manager = DataExchangeManager::instance();
+        exchange_manager.get_config().query.flight_exchange_credits as usize
+    }
 }
 
 type Response<T> = Result<RawResponse<T>, Status>;
@@ -106,10 +111,11 @@ impl FlightService for DatabendQueryFlightService {
             "request_server_exchange" => {
                 let query_id = req.get_metadata("x-query-id")?;
                 let (tx, rx) = async_channel::unbounded();
-                let exchange = FlightExchange::from_server(req, tx);
+                let exchange = FlightExchange::from_server(req, tx, Self::exchange_credits());
 
                 DataExchangeManager::instance().handle_statistics_exchange(query_id, exchange)?;
-                Ok(RawResponse::new(Box::pin(rx)))
+                let response_stream = FlightExchange::credited_stream(rx);
+                Ok(RawResponse::new(Box::pin(response_stream)))
             }
             "exchange_fragment" => {
                 let source = req.get_metadata("x-source")?;
@@ -117,11 +123,12 @@ impl FlightService for DatabendQueryFlightService {
                 let fragment = req.get_metadata("x-fragment-id")?.parse::<usize>().unwrap();
 
                 let (tx, rx) = async_channel::unbounded();
-                let exchange = FlightExchange::from_server(req, tx);
+                let exchange = FlightExchange::from_server(req, tx, Self::exchange_credits());
 
                 DataExchangeManager::instance()
                     .handle_exchange_fragment(query_id, source, fragment, exchange)?;
-                Ok(RawResponse::new(Box::pin(rx)))
+                let response_stream = FlightExchange::credited_stream(rx);
+                Ok(RawResponse::new(Box::pin(response_stream)))
             }
             exchange_type => Err(Status::unimplemented(format!(
                 "Unimplemented exchange type: {:?}",
@@ -132,7 +139,9 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoActionStream = FlightStream<FlightResult>;
 
-    #[tracing::instrument(level = "debug", skip_all)]
+    // At the INFO level, the spans of the fragments of the query executed on this node are exported
+    // as the children of the remote span of the query, in the same trace.
+    #[tracing::instrument(level = "info", skip_all)]
     async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
         common_tracing::extract_remote_span_as_parent(&request);
 
@@ -161,6 +170,13 @@ impl FlightService for DatabendQueryFlightService {
             FlightAction::ExecutePartialQuery(query_id) => {
                 DataExchangeManager::instance().execute_partial_query(query_id)?;
 
+                FlightResult { body: vec![] }
+            }
+            FlightAction::KillQuery(query_id) => {
+                let exchange_manager = DataExchangeManager::instance();
+                exchange_manager.shutdown_query(query_id);
+                exchange_manager.on_finished_query(query_id);
+
                 FlightResult { body: vec![] }
             }
         };
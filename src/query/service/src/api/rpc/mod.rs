@@ -23,6 +23,7 @@ mod flight_scatter;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
 mod flight_scatter_hash_v2;
+mod flight_scatter_round_robin;
 mod flight_service;
 mod packets;
 mod request_builder;
@@ -31,6 +32,7 @@ pub use exchange::BroadcastExchange;
 pub use exchange::DataExchange;
 pub use exchange::DataExchangeManager;
 pub use exchange::MergeExchange;
+pub use exchange::RoundRobinExchange;
 pub use exchange::ShuffleDataExchange;
 pub use exchange::ShuffleDataExchangeV2;
 pub use flight_client::ClientFlightExchange;
@@ -42,6 +44,7 @@ pub use packets::FragmentData;
 pub use packets::FragmentPayload;
 pub use packets::FragmentPlanPacket;
 pub use packets::InitNodesChannelPacket;
+pub use packets::KillQueryPacket;
 pub use packets::Packet;
 pub use packets::PrecommitBlock;
 pub use packets::QueryFragmentsPlanPacket;
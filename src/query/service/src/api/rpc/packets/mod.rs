@@ -17,6 +17,7 @@ mod packet_data;
 mod packet_execute;
 mod packet_executor;
 mod packet_fragment;
+mod packet_kill;
 mod packet_publisher;
 
 pub use packet::Packet;
@@ -29,5 +30,6 @@ pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
 pub use packet_fragment::FragmentPayload;
 pub use packet_fragment::FragmentPlanPacket;
+pub use packet_kill::KillQueryPacket;
 pub use packet_publisher::ConnectionInfo;
 pub use packet_publisher::InitNodesChannelPacket;
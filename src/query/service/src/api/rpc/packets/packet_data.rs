@@ -31,8 +31,11 @@ use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_arrow::arrow::io::ipc::IpcSchema;
 use common_arrow::arrow_format::flight::data::FlightData;
 use common_base::base::ProgressValues;
+use common_datablocks::write_ipc_blocks;
 use common_datablocks::DataBlock;
+use common_datablocks::IpcBlockReader;
 use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::Stream;
@@ -49,6 +52,34 @@ impl FragmentData {
     pub fn create(data: FlightData) -> FragmentData {
         FragmentData { data }
     }
+
+    /// Serializes the block as an arrow IPC file in the body of the flight data, the buffers of
+    /// the columns are written as they are.
+    pub fn serialize(data_block: &DataBlock, options: &WriteOptions) -> Result<FragmentData> {
+        let mut data_body = vec![];
+        let options = WriteOptions {
+            compression: options.compression,
+        };
+        let schema = data_block.schema();
+        write_ipc_blocks(&mut data_body, schema, &[data_block.clone()], options)?;
+        Ok(FragmentData::create(FlightData {
+            app_metadata: vec![],
+            data_header: vec![],
+            data_body,
+            flight_descriptor: None,
+        }))
+    }
+
+    /// Deserializes the block of `serialize()`, the columns are created over the received body
+    /// rather than copied from it, unless the buffers are compressed.
+    pub fn deserialize(self, schema: &DataSchemaRef) -> Result<DataBlock> {
+        let data = Arc::new(self.data.data_body);
+        let mut reader = IpcBlockReader::try_create(schema.clone(), data)?;
+        match reader.next_block()? {
+            Some(data_block) => Ok(data_block),
+            None => Err(ErrorCode::BadBytes("Fragment data has no block.")),
+        }
+    }
 }
 
 impl Debug for FragmentData {
@@ -23,6 +23,7 @@ use common_base::base::tokio::sync::Notify;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Certificate;
 use tonic::transport::Identity;
 use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
@@ -64,7 +65,12 @@ impl RpcService {
         let cert = tokio::fs::read(conf.query.rpc_tls_server_cert.as_str()).await?;
         let key = tokio::fs::read(conf.query.rpc_tls_server_key.as_str()).await?;
         let server_identity = Identity::from_pem(cert, key);
-        let tls_conf = ServerTlsConfig::new().identity(server_identity);
+        let mut tls_conf = ServerTlsConfig::new().identity(server_identity);
+        if !conf.query.rpc_tls_server_root_ca_cert.is_empty() {
+            let client_ca =
+                tokio::fs::read(conf.query.rpc_tls_server_root_ca_cert.as_str()).await?;
+            tls_conf = tls_conf.client_ca_root(Certificate::from_pem(client_ca));
+        }
         Ok(tls_conf)
     }
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -20,14 +21,23 @@ use common_meta_types::AuthInfo;
 use common_meta_types::UserInfo;
 use common_users::CustomClaims;
 use common_users::JwtAuthenticator;
+use common_users::LdapAuthenticator;
+use common_users::LdapConfig;
 use common_users::UserApiProvider;
 use jwtk::Claims;
+use tracing::error;
 
+use crate::interpreters::InterpreterAuditLog;
+use crate::interpreters::InterpreterQueryLog;
 use crate::sessions::Session;
+use crate::sessions::SessionManager;
 pub use crate::Config;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    jwt_user_claim: String,
+    jwt_role_claim: String,
+    ldap_auth: Option<LdapAuthenticator>,
 }
 
 pub enum Credential {
@@ -45,11 +55,50 @@ pub enum Credential {
 impl AuthMgr {
     pub async fn create(cfg: Config) -> Result<Arc<AuthMgr>> {
         Ok(Arc::new(AuthMgr {
-            jwt_auth: JwtAuthenticator::try_create(cfg.query.jwt_key_file).await?,
+            jwt_auth: JwtAuthenticator::try_create(
+                cfg.query.jwt_key_file,
+                Duration::from_secs(cfg.query.jwt_key_refresh_interval_secs),
+            )
+            .await?,
+            jwt_user_claim: cfg.query.jwt_user_claim,
+            jwt_role_claim: cfg.query.jwt_role_claim,
+            ldap_auth: LdapAuthenticator::try_create(LdapConfig {
+                url: cfg.query.ldap_url,
+                bind_dn_template: cfg.query.ldap_bind_dn_template,
+                search_bind_dn: cfg.query.ldap_search_bind_dn,
+                search_bind_password: cfg.query.ldap_search_bind_password,
+                search_base: cfg.query.ldap_search_base,
+                search_filter: cfg.query.ldap_search_filter,
+                group_attribute: cfg.query.ldap_group_attribute,
+                group_role_mapping: cfg.query.ldap_group_role_mapping,
+            })?,
         }))
     }
 
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
+        let (user_name, client_ip) = match credential {
+            Credential::Jwt { hostname, .. } => ("", hostname.as_deref()),
+            Credential::Password { name, hostname, .. } => (name.as_str(), hostname.as_deref()),
+        };
+        match self.authenticate(&session, credential, client_ip).await {
+            Ok(user_info) => {
+                InterpreterAuditLog::log_auth(&session, &user_info.name, client_ip, None);
+                session.set_current_user(user_info);
+                Ok(())
+            }
+            Err(e) => {
+                InterpreterAuditLog::log_auth(&session, user_name, client_ip, Some(&e));
+                Err(e)
+            }
+        }
+    }
+
+    async fn authenticate(
+        &self,
+        session: &Arc<Session>,
+        credential: &Credential,
+        client_ip: Option<&str>,
+    ) -> Result<UserInfo> {
         let user_info = match credential {
             Credential::Jwt {
                 token: t,
@@ -61,7 +110,7 @@ impl AuthMgr {
                     .ok_or_else(|| ErrorCode::AuthenticateFailure("jwt auth not configured."))?;
                 let parsed_jwt = jwt_auth.parse_jwt(t.as_str()).await?;
                 let (tenant, user_name) = self
-                    .process_jwt_claims(&session, parsed_jwt.claims())
+                    .process_jwt_claims(session, parsed_jwt.claims())
                     .await?;
                 UserApiProvider::instance()
                     .get_user_with_client_ip(
@@ -95,12 +144,71 @@ impl AuthMgr {
                             }
                         }
                     },
+                    AuthInfo::LDAP => self.auth_ldap(&tenant, user, p).await,
                     _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 }?
             }
         };
-        session.set_current_user(user_info);
-        Ok(())
+        self.check_network_policy(session, &user_info, client_ip)
+            .await?;
+        SessionManager::instance().check_user_connections(session, &user_info)?;
+        Ok(user_info)
+    }
+
+    /// Checks the client ip against the network policy of the user, the rejected logins
+    /// are recorded in the query log.
+    pub async fn check_network_policy(
+        &self,
+        session: &Arc<Session>,
+        user: &UserInfo,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
+        let tenant = session.get_current_tenant();
+        let res = UserApiProvider::instance()
+            .check_network_policy(&tenant, user, client_ip)
+            .await;
+        if let Err(e) = &res {
+            let ctx = session.create_query_context().await?;
+            InterpreterQueryLog::log_rejected_login(&ctx, user, client_ip, e)
+                .unwrap_or_else(|e| error!("fail to write query_log {:?}", e));
+        }
+        res
+    }
+
+    async fn auth_ldap(
+        &self,
+        tenant: &str,
+        mut user: UserInfo,
+        password: &Option<Vec<u8>>,
+    ) -> Result<UserInfo> {
+        let ldap_auth = self
+            .ldap_auth
+            .as_ref()
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("ldap auth not configured"))?;
+        let password = password
+            .as_ref()
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("password required"))?;
+        let groups = ldap_auth.authenticate(&user.name, password).await?;
+
+        // sync the roles in the group role mapping with the groups of the user, grant the
+        // roles of the groups and revoke the others
+        let mapped_roles = ldap_auth.mapped_roles(&groups);
+        let granted_roles = user.grants.roles();
+        for role in ldap_auth.managed_roles() {
+            let granted = granted_roles.contains(&role);
+            if mapped_roles.contains(&role) && !granted {
+                UserApiProvider::instance()
+                    .grant_role_to_user(tenant, user.identity(), role.clone())
+                    .await?;
+                user.grants.grant_role(role);
+            } else if !mapped_roles.contains(&role) && granted {
+                UserApiProvider::instance()
+                    .revoke_role_from_user(tenant, user.identity(), role.clone())
+                    .await?;
+                user.grants.revoke_role(&role);
+            }
+        }
+        Ok(user)
     }
 
     async fn process_jwt_claims(
@@ -114,15 +222,18 @@ impl AuthMgr {
         }
         let tenant = session.get_current_tenant();
 
-        // take `sub` field in the claims as user name
-        let user_name = claims
-            .sub
-            .clone()
-            .ok_or_else(|| ErrorCode::AuthenticateFailure("sub not found in claims"))?;
+        // take the user claim, `sub` by default, in the claims as user name
+        let user_name = string_claim(claims, &self.jwt_user_claim).ok_or_else(|| {
+            let field = match self.jwt_user_claim.as_str() {
+                "sub" => "subject",
+                claim => claim,
+            };
+            ErrorCode::AuthenticateFailure(format!("missing field `{}` in jwt", field))
+        })?;
 
-        // set user auth_role if claims contain extra.role
-        if let Some(ref auth_role) = claims.extra.role {
-            session.set_auth_role(auth_role.clone());
+        // set user auth_role if claims contain the role claim, `role` by default
+        if let Some(auth_role) = string_claim(claims, &self.jwt_role_claim) {
+            session.set_auth_role(auth_role);
         }
 
         // create user if not exists when the JWT claims contains ensure_user
@@ -143,3 +254,19 @@ impl AuthMgr {
         Ok((tenant, user_name))
     }
 }
+
+// The string value of the claim, `sub` is the registered claim of the subject, the others
+// are the custom claims.
+fn string_claim(claims: &Claims<CustomClaims>, name: &str) -> Option<String> {
+    match name {
+        "sub" => claims.sub.clone(),
+        "role" => claims.extra.role.clone(),
+        "tenant_id" => claims.extra.tenant_id.clone(),
+        _ => claims
+            .extra
+            .others
+            .get(name)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string()),
+    }
+}
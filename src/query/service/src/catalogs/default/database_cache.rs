@@ -0,0 +1,215 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::SchemaChange;
+use common_meta_app::schema::DatabaseInfo;
+use common_meta_app::schema::TableInfo;
+use common_meta_store::MetaStore;
+use futures::stream::select_all;
+use futures::StreamExt;
+use parking_lot::RwLock;
+use tracing::info;
+use tracing::warn;
+
+/// The databases cached by the mutable catalog, to resolve a database name without a round trip
+/// to the meta-service. The tables of the databases of the default engine are cached too if
+/// `cache_tables`, which is only for the read only nodes: a node writing the tables would see a
+/// stale table before its own change is watched.
+///
+/// The cache is in effect only while the changes of the schema are watched from the
+/// meta-service, each watched change invalidates the entries it affects at once. Without a
+/// watch, e.g. with an embedded meta store or while the watch is reconnecting, every lookup goes
+/// to the meta-service.
+#[derive(Default)]
+pub struct DatabaseCache {
+    cache_tables: bool,
+    inner: RwLock<DatabaseCacheInner>,
+}
+
+#[derive(Default)]
+struct DatabaseCacheInner {
+    watching: bool,
+    // Bumped by each invalidation: a database read from the meta-service before the latest
+    // invalidation may be stale, and is not cached.
+    version: u64,
+    databases: HashMap<(String, String), Arc<DatabaseInfo>>,
+    // Keyed by the id of the database and the table name, as the watched table names are.
+    tables: HashMap<(u64, String), Arc<TableInfo>>,
+}
+
+impl DatabaseCache {
+    pub fn create(cache_tables: bool) -> DatabaseCache {
+        DatabaseCache {
+            cache_tables,
+            inner: Default::default(),
+        }
+    }
+
+    pub fn is_caching_tables(&self) -> bool {
+        self.cache_tables
+    }
+
+    pub fn get(&self, tenant: &str, db_name: &str) -> Option<Arc<DatabaseInfo>> {
+        let inner = self.inner.read();
+        inner
+            .databases
+            .get(&(tenant.to_string(), db_name.to_string()))
+            .cloned()
+    }
+
+    /// The version to pass to `insert()`, taken before reading the database from the
+    /// meta-service.
+    pub fn version(&self) -> u64 {
+        self.inner.read().version
+    }
+
+    pub fn insert(&self, version: u64, db_info: Arc<DatabaseInfo>) {
+        let mut inner = self.inner.write();
+        if !inner.watching || inner.version != version {
+            return;
+        }
+
+        let key = (
+            db_info.name_ident.tenant.clone(),
+            db_info.name_ident.db_name.clone(),
+        );
+        inner.databases.insert(key, db_info);
+    }
+
+    pub fn get_table(&self, db_id: u64, table_name: &str) -> Option<Arc<TableInfo>> {
+        let inner = self.inner.read();
+        inner.tables.get(&(db_id, table_name.to_string())).cloned()
+    }
+
+    pub fn insert_table(&self, version: u64, db_id: u64, table_info: Arc<TableInfo>) {
+        let mut inner = self.inner.write();
+        if !self.cache_tables || !inner.watching || inner.version != version {
+            return;
+        }
+
+        inner
+            .tables
+            .insert((db_id, table_info.name.clone()), table_info);
+    }
+
+    pub fn invalidate(&self, change: &SchemaChange) {
+        let mut inner = self.inner.write();
+        match change {
+            SchemaChange::DatabaseName(name_ident) => {
+                inner.version += 1;
+                let removed = inner
+                    .databases
+                    .remove(&(name_ident.tenant.clone(), name_ident.db_name.clone()));
+                // The tables of a renamed database are described by the old name.
+                if let Some(db_info) = removed {
+                    let db_id = db_info.ident.db_id;
+                    inner.tables.retain(|(id, _), _| *id != db_id);
+                }
+            }
+            SchemaChange::Database(db_id) => {
+                inner.version += 1;
+                inner
+                    .databases
+                    .retain(|_, db_info| db_info.ident.db_id != db_id.db_id);
+            }
+            SchemaChange::TableName(name) => {
+                inner.version += 1;
+                inner.tables.remove(&(name.db_id, name.table_name.clone()));
+            }
+            SchemaChange::Table(table_id) => {
+                inner.version += 1;
+                inner
+                    .tables
+                    .retain(|_, table_info| table_info.ident.table_id != table_id.table_id);
+            }
+        }
+    }
+
+    /// The changes made while not watching are unknown, so all the entries are removed both
+    /// when the watch starts and when it stops.
+    pub fn set_watching(&self, watching: bool) {
+        let mut inner = self.inner.write();
+        inner.watching = watching;
+        inner.version += 1;
+        inner.databases.clear();
+        inner.tables.clear();
+    }
+
+    /// Spawns the task watching the changes of the schema for the cache, it reconnects if the
+    /// watch streams are broken, and exits once the cache is dropped.
+    pub fn spawn_watcher(self: &Arc<Self>, meta: MetaStore) {
+        if meta.is_local() {
+            return;
+        }
+
+        let cache = Arc::downgrade(self);
+        tokio::spawn(async move {
+            loop {
+                if let Err(cause) = Self::watch(&cache, &meta).await {
+                    warn!("database cache watch is broken, retry later: {}", cause);
+                }
+
+                match cache.upgrade() {
+                    None => return,
+                    Some(c) => c.set_watching(false),
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        });
+    }
+
+    async fn watch(cache: &Weak<Self>, meta: &MetaStore) -> Result<()> {
+        let mut streams = Vec::new();
+        for req in SchemaChange::watch_requests() {
+            match meta.watch(req).await? {
+                Some(stream) => streams.push(stream),
+                None => return Ok(()),
+            }
+        }
+
+        match cache.upgrade() {
+            None => return Ok(()),
+            Some(c) => c.set_watching(true),
+        }
+        info!("database cache starts watching the schema changes");
+
+        let mut events = select_all(streams);
+        while let Some(resp) = events.next().await {
+            let event = match resp?.event {
+                None => continue,
+                Some(event) => event,
+            };
+
+            let c = match cache.upgrade() {
+                None => return Ok(()),
+                Some(c) => c,
+            };
+            if let Some(change) = SchemaChange::from_key(&event.key) {
+                c.invalidate(&change);
+            }
+        }
+
+        Err(ErrorCode::MetaServiceError(
+            "the watch stream of the schema changes is closed",
+        ))
+    }
+}
@@ -17,29 +17,43 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::AcquireTableLockReply;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::CommitTableReply;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::ReleaseTableLockReply;
+use common_meta_app::schema::ReleaseTableLockReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SwapTableReply;
+use common_meta_app::schema::SwapTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistory;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -98,6 +112,18 @@ impl DatabaseCatalog {
         );
         Ok(res)
     }
+
+    // The built-in databases and their tables are looked up case-insensitively, e.g.
+    // `SYSTEM.ONE` of the identifiers normalized to upper case is `system.one`.
+    fn normalize_names(&self, db_name: &str, table_name: &str) -> (String, String) {
+        if self.is_case_insensitive_db(db_name) {
+            (db_name.to_uppercase(), table_name.to_uppercase())
+        } else if db_name.eq_ignore_ascii_case("system") {
+            (db_name.to_lowercase(), table_name.to_lowercase())
+        } else {
+            (db_name.to_string(), table_name.to_string())
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -117,11 +143,7 @@ impl Catalog for DatabaseCatalog {
             ));
         }
 
-        let db_name = if self.is_case_insensitive_db(db_name) {
-            db_name.to_uppercase()
-        } else {
-            db_name.to_string()
-        };
+        let (db_name, _) = self.normalize_names(db_name, "");
 
         let r = self.immutable_catalog.get_database(tenant, &db_name).await;
         match r {
@@ -213,6 +235,26 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_database(req).await
     }
 
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        if req.name_ident.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while update database comment)",
+            ));
+        }
+
+        if self
+            .immutable_catalog
+            .exists_database(&req.name_ident.tenant, &req.name_ident.db_name)
+            .await?
+        {
+            return self.immutable_catalog.update_database_comment(req).await;
+        }
+        self.mutable_catalog.update_database_comment(req).await
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res = self.immutable_catalog.get_table_by_info(table_info);
         match res {
@@ -237,6 +279,11 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    async fn get_table_meta_history(&self, table_id: MetaId) -> Result<TableMetaHistory> {
+        // The tables of the immutable catalog are not stored in the meta-service.
+        self.mutable_catalog.get_table_meta_history(table_id).await
+    }
+
     async fn get_table(
         &self,
         tenant: &str,
@@ -249,11 +296,7 @@ impl Catalog for DatabaseCatalog {
             ));
         }
 
-        let (db_name, table_name) = if self.is_case_insensitive_db(db_name) {
-            (db_name.to_uppercase(), table_name.to_uppercase())
-        } else {
-            (db_name.to_string(), table_name.to_string())
-        };
+        let (db_name, table_name) = self.normalize_names(db_name, table_name);
 
         let res = self
             .immutable_catalog
@@ -280,11 +323,7 @@ impl Catalog for DatabaseCatalog {
             ));
         }
 
-        let db_name = if self.is_case_insensitive_db(db_name) {
-            db_name.to_uppercase()
-        } else {
-            db_name.to_string()
-        };
+        let (db_name, _) = self.normalize_names(db_name, "");
 
         let r = self.immutable_catalog.list_tables(tenant, &db_name).await;
         match r {
@@ -310,11 +349,7 @@ impl Catalog for DatabaseCatalog {
             ));
         }
 
-        let db_name = if self.is_case_insensitive_db(db_name) {
-            db_name.to_uppercase()
-        } else {
-            db_name.to_string()
-        };
+        let (db_name, _) = self.normalize_names(db_name, "");
 
         let r = self
             .immutable_catalog
@@ -431,6 +466,50 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_table(req).await
     }
 
+    async fn create_staged_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        if req.tenant().is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while create staged table)",
+            ));
+        }
+        info!("Create staged table from req:{:?}", req);
+
+        // The tables of the immutable catalog are not stored in the meta-service.
+        self.mutable_catalog.create_staged_table(req).await
+    }
+
+    async fn commit_staged_table(&self, req: CommitTableReq) -> Result<CommitTableReply> {
+        if req.tenant().is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while commit staged table)",
+            ));
+        }
+        info!("Commit staged table from req:{:?}", req);
+
+        self.mutable_catalog.commit_staged_table(req).await
+    }
+
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply> {
+        if req.tenant().is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while swap table)",
+            ));
+        }
+        info!("Swap table from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(req.tenant(), req.db_name())
+            .await?
+        {
+            return Err(ErrorCode::UnImplement(
+                "Cannot swap tables of system databases",
+            ));
+        }
+
+        self.mutable_catalog.swap_table(req).await
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply> {
         if req.tenant.is_empty() {
             return Err(ErrorCode::TenantIsEmpty(
@@ -476,6 +555,15 @@ impl Catalog for DatabaseCatalog {
             .await
     }
 
+    async fn acquire_table_lock(&self, req: AcquireTableLockReq) -> Result<AcquireTableLockReply> {
+        // The tables of the immutable catalog are never mutated.
+        self.mutable_catalog.acquire_table_lock(req).await
+    }
+
+    async fn release_table_lock(&self, req: ReleaseTableLockReq) -> Result<ReleaseTableLockReply> {
+        self.mutable_catalog.release_table_lock(req).await
+    }
+
     async fn upsert_table_option(
         &self,
         tenant: &str,
@@ -498,6 +586,13 @@ impl Catalog for DatabaseCatalog {
             .await
     }
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        self.mutable_catalog.update_multi_table_meta(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -40,6 +40,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -124,6 +126,15 @@ impl Catalog for ImmutableCatalog {
         Err(ErrorCode::UnImplement("Cannot rename system database"))
     }
 
+    async fn update_database_comment(
+        &self,
+        _req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot update the comment of system database",
+        ))
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let table_id = table_info.ident.table_id;
 
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod catalog_context;
+mod database_cache;
 mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
 pub mod table_id_ranges;
 pub mod table_memory_meta;
 
+pub use database_cache::DatabaseCache;
 pub use database_catalog::DatabaseCatalog;
 // for "unit" test
 pub use immutable_catalog::ImmutableCatalog;
@@ -17,10 +17,16 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_meta_api::SchemaApi;
+use common_meta_api::SchemaChange;
+use common_meta_app::schema::AcquireTableLockReply;
+use common_meta_app::schema::AcquireTableLockReq;
+use common_meta_app::schema::CommitTableReply;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
@@ -32,20 +38,30 @@ use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
+use common_meta_app::schema::ReleaseTableLockReply;
+use common_meta_app::schema::ReleaseTableLockReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SwapTableReply;
+use common_meta_app::schema::SwapTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistory;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -57,7 +73,9 @@ use common_meta_types::MetaId;
 use tracing::info;
 
 use super::catalog_context::CatalogContext;
+use super::database_cache::DatabaseCache;
 use crate::catalogs::catalog::Catalog;
+use crate::connections::restore_kept_storage;
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
@@ -75,6 +93,7 @@ use crate::Config;
 #[derive(Clone)]
 pub struct MutableCatalog {
     ctx: CatalogContext,
+    database_cache: Arc<DatabaseCache>,
 }
 
 impl MutableCatalog {
@@ -126,7 +145,30 @@ impl MutableCatalog {
             database_factory: Arc::new(database_factory),
             in_memory_data: Arc::new(Default::default()),
         };
-        Ok(MutableCatalog { ctx })
+
+        // The cached databases are invalidated by the changes watched from the meta-service.
+        let database_cache = Arc::new(DatabaseCache::create(conf.query.read_only));
+        database_cache.spawn_watcher(ctx.meta.clone());
+
+        Ok(MutableCatalog {
+            ctx,
+            database_cache,
+        })
+    }
+
+    async fn get_database_info(&self, tenant: &str, db_name: &str) -> Result<Arc<DatabaseInfo>> {
+        if let Some(db_info) = self.database_cache.get(tenant, db_name) {
+            return Ok(db_info);
+        }
+
+        let version = self.database_cache.version();
+        let db_info = self
+            .ctx
+            .meta
+            .get_database(GetDatabaseReq::new(tenant, db_name))
+            .await?;
+        self.database_cache.insert(version, db_info.clone());
+        Ok(db_info)
     }
 
     fn build_db_instance(&self, db_info: &Arc<DatabaseInfo>) -> Result<Arc<dyn Database>> {
@@ -150,11 +192,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn get_database(&self, tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
-        let db_info = self
-            .ctx
-            .meta
-            .get_database(GetDatabaseReq::new(tenant, db_name))
-            .await?;
+        let db_info = self.get_database_info(tenant, db_name).await?;
         self.build_db_instance(&db_info)
     }
 
@@ -197,7 +235,10 @@ impl Catalog for MutableCatalog {
     }
 
     async fn drop_database(&self, req: DropDatabaseReq) -> Result<()> {
+        // The watched change may arrive later than the next query of the session.
+        let change = SchemaChange::DatabaseName(req.name_ident.clone());
         self.ctx.meta.drop_database(req).await?;
+        self.database_cache.invalidate(&change);
         Ok(())
     }
 
@@ -207,7 +248,19 @@ impl Catalog for MutableCatalog {
     }
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        let change = SchemaChange::DatabaseName(req.name_ident.clone());
         let res = self.ctx.meta.rename_database(req).await?;
+        self.database_cache.invalidate(&change);
+        Ok(res)
+    }
+
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        let change = SchemaChange::DatabaseName(req.name_ident.clone());
+        let res = self.ctx.meta.update_database_comment(req).await?;
+        self.database_cache.invalidate(&change);
         Ok(res)
     }
 
@@ -228,14 +281,41 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    async fn get_table_meta_history(&self, table_id: MetaId) -> Result<TableMetaHistory> {
+        let res = self.ctx.meta.get_table_meta_history(table_id).await?;
+        Ok(res)
+    }
+
     async fn get_table(
         &self,
         tenant: &str,
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
-        let db = self.get_database(tenant, db_name).await?;
-        db.get_table(table_name).await
+        let db_info = self.get_database_info(tenant, db_name).await?;
+        // The tables of the other engines are not saved in the meta-service.
+        let engine = db_info.engine();
+        if !self.database_cache.is_caching_tables()
+            || !(engine.is_empty() || engine.eq_ignore_ascii_case("DEFAULT"))
+        {
+            let db = self.build_db_instance(&db_info)?;
+            return db.get_table(table_name).await;
+        }
+
+        let db_id = db_info.ident.db_id;
+        if let Some(table_info) = self.database_cache.get_table(db_id, table_name) {
+            return self.get_table_by_info(&table_info);
+        }
+
+        let version = self.database_cache.version();
+        let table_info = self
+            .ctx
+            .meta
+            .get_table(GetTableReq::new(tenant, db_name, table_name))
+            .await?;
+        self.database_cache
+            .insert_table(version, db_id, table_info.clone());
+        self.get_table_by_info(&table_info)
     }
 
     async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
@@ -280,6 +360,21 @@ impl Catalog for MutableCatalog {
         db.rename_table(req).await
     }
 
+    async fn create_staged_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let res = self.ctx.meta.create_staged_table(req).await?;
+        Ok(res)
+    }
+
+    async fn commit_staged_table(&self, req: CommitTableReq) -> Result<CommitTableReply> {
+        let res = self.ctx.meta.commit_staged_table(req).await?;
+        Ok(res)
+    }
+
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply> {
+        let res = self.ctx.meta.swap_table(req).await?;
+        Ok(res)
+    }
+
     async fn upsert_table_option(
         &self,
         tenant: &str,
@@ -294,8 +389,10 @@ impl Catalog for MutableCatalog {
         &self,
         tenant: &str,
         db_name: &str,
-        req: UpdateTableMetaReq,
+        mut req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply> {
+        // The storages resolved by the connections are not kept.
+        restore_kept_storage(&mut req.new_table_meta.engine_options)?;
         let db = self.get_database(tenant, db_name).await?;
         db.update_table_meta(req).await
     }
@@ -330,6 +427,27 @@ impl Catalog for MutableCatalog {
         db.truncate_table(req).await
     }
 
+    async fn acquire_table_lock(&self, req: AcquireTableLockReq) -> Result<AcquireTableLockReply> {
+        let res = self.ctx.meta.acquire_table_lock(req).await?;
+        Ok(res)
+    }
+
+    async fn release_table_lock(&self, req: ReleaseTableLockReq) -> Result<ReleaseTableLockReply> {
+        let res = self.ctx.meta.release_table_lock(req).await?;
+        Ok(res)
+    }
+
+    async fn update_multi_table_meta(
+        &self,
+        mut req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        for update in req.update_table_metas.iter_mut() {
+            restore_kept_storage(&mut update.new_table_meta.engine_options)?;
+        }
+        let res = self.ctx.meta.update_multi_table_meta(req).await?;
+        Ok(res)
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply> {
         let res = self.ctx.meta.count_tables(req).await?;
         Ok(res)
@@ -19,6 +19,8 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_base::base::tokio;
@@ -40,6 +42,7 @@ use common_management::ClusterMgr;
 use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
 use common_meta_types::NodeInfo;
+use common_meta_types::NodeStats;
 use common_metrics::label_counter_with_val_and_labels;
 use futures::future::select;
 use futures::future::Either;
@@ -52,7 +55,10 @@ use rand::Rng;
 use tracing::error;
 use tracing::warn;
 
+use crate::api::DataExchangeManager;
 use crate::api::FlightClient;
+use crate::sessions::SessionManager;
+use crate::storages::cache::CacheManager;
 use crate::Config;
 
 pub struct ClusterDiscovery {
@@ -74,18 +80,28 @@ pub trait ClusterHelper {
     fn local_id(&self) -> String;
     async fn create_node_conn(&self, name: &str, config: &Config) -> Result<FlightClient>;
     fn get_nodes(&self) -> Vec<Arc<NodeInfo>>;
+    fn get_draining_nodes(&self) -> Vec<Arc<NodeInfo>>;
 }
 
 #[async_trait::async_trait]
 impl ClusterHelper for Cluster {
     fn create(nodes: Vec<Arc<NodeInfo>>, local_id: String) -> Arc<Cluster> {
-        Arc::new(Cluster { local_id, nodes })
+        // The local node runs the queries coordinated by itself even if it's draining.
+        let (draining_nodes, nodes) = nodes
+            .into_iter()
+            .partition(|node| node.draining && node.id != local_id);
+        Arc::new(Cluster {
+            local_id,
+            nodes,
+            draining_nodes,
+        })
     }
 
     fn empty() -> Arc<Cluster> {
         Arc::new(Cluster {
             local_id: String::from(""),
             nodes: Vec::new(),
+            draining_nodes: Vec::new(),
         })
     }
 
@@ -134,6 +150,10 @@ impl ClusterHelper for Cluster {
     fn get_nodes(&self) -> Vec<Arc<NodeInfo>> {
         self.nodes.to_vec()
     }
+
+    fn get_draining_nodes(&self) -> Vec<Arc<NodeInfo>> {
+        self.draining_nodes.to_vec()
+    }
 }
 
 static CLUSTER_DISCOVERY: OnceCell<Singleton<Arc<ClusterDiscovery>>> = OnceCell::new();
@@ -191,7 +211,7 @@ impl ClusterDiscovery {
         // TODO: generate if tenant or cluster id is empty
         let tenant_id = &cfg.query.tenant_id;
         let cluster_id = &cfg.query.cluster_id;
-        let lift_time = Duration::from_secs(60);
+        let lift_time = Duration::from_secs(cfg.query.cluster_heartbeat_timeout_secs);
         let cluster_manager = ClusterMgr::create(metastore, tenant_id, cluster_id, lift_time)?;
 
         Ok((lift_time, Arc::new(cluster_manager)))
@@ -273,6 +293,12 @@ impl ClusterDiscovery {
         }
     }
 
+    /// Marks the node as draining, the sessions created after it schedule no fragment to the
+    /// node, while the fragments running on the node run to the end.
+    pub async fn drain_node(&self, node_id: &str) -> Result<()> {
+        self.api_provider.drain_node(node_id).await
+    }
+
     async fn drop_invalid_nodes(self: &Arc<Self>, node_info: &NodeInfo) -> Result<()> {
         let current_nodes_info = match self.api_provider.get_nodes().await {
             Ok(nodes) => nodes,
@@ -375,7 +401,9 @@ impl ClusterDiscovery {
             }
         }
 
-        let node_info = NodeInfo::create(self.local_id.clone(), cpus, address);
+        let mut node_info = NodeInfo::create(self.local_id.clone(), cpus, address);
+        node_info.binary_version = crate::version::DATABEND_COMMIT_VERSION.to_string();
+        node_info.stats.heartbeat_on = now_millis();
 
         self.drop_invalid_nodes(&node_info).await?;
         match self.api_provider.add_node(node_info.clone()).await {
@@ -421,7 +449,7 @@ impl ClusterHeartbeat {
         }
     }
 
-    fn heartbeat_loop(&self, node: NodeInfo) -> impl Future<Output = ()> + 'static {
+    fn heartbeat_loop(&self, mut node: NodeInfo) -> impl Future<Output = ()> + 'static {
         let shutdown = self.shutdown.clone();
         let shutdown_notify = self.shutdown_notify.clone();
         let cluster_api = self.cluster_api.clone();
@@ -446,6 +474,7 @@ impl ClusterHeartbeat {
                     }
                     Either::Right((_, new_shutdown_notified)) => {
                         shutdown_notified = new_shutdown_notified;
+                        node.stats = local_node_stats();
                         let heartbeat = cluster_api.heartbeat(&node, None);
                         if let Err(failure) = heartbeat.await {
                             label_counter_with_val_and_labels(
@@ -499,6 +528,32 @@ impl ClusterHeartbeat {
     }
 }
 
+/// The load of the local node published to the meta-service by the heartbeat.
+fn local_node_stats() -> NodeStats {
+    let running_queries = SessionManager::instance()
+        .processes_info()
+        .into_iter()
+        .filter(|process| process.state == "Query")
+        .collect::<Vec<_>>();
+    let cache_manager = CacheManager::instance();
+
+    NodeStats {
+        heartbeat_on: now_millis(),
+        running_queries: running_queries.len() as u64,
+        memory_usage: running_queries.iter().map(|p| p.memory_usage).sum(),
+        memory_cache_bytes: cache_manager.get_memory_cache_bytes(),
+        disk_cache_bytes: cache_manager.get_disk_cache_bytes(),
+        running_fragments: DataExchangeManager::instance().num_running_queries() as u64,
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis() as u64
+}
+
 pub async fn create_client(config: &Config, address: &str) -> Result<FlightClient> {
     match config.tls_query_cli_enabled() {
         true => Ok(FlightClient::new(FlightServiceClient::new(
@@ -0,0 +1,303 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+use common_meta_types::UserStageInfo;
+use common_storage::parse_uri_location;
+use common_storage::StorageParams;
+use common_storage::UriLocation;
+use common_users::UserApiProvider;
+use once_cell::sync::OnceCell;
+use openssl::symm::decrypt_aead;
+use openssl::symm::encrypt_aead;
+use openssl::symm::Cipher;
+use rand::RngCore;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::catalogs::Catalog;
+use crate::storages::Table;
+use crate::Config;
+
+// The engine options of the tables kept on the storage of a location, such as the external, the
+// iceberg, the delta and the attached tables. The location is kept if it references a connection.
+pub const OPT_KEY_STORAGE: &str = "storage";
+pub const OPT_KEY_CONNECTION_LOCATION: &str = "connection_location";
+
+// The encrypted secrets are `v1:` followed by the base64 of the nonce, the ciphertext and
+// the tag of AES-256-GCM, the name of the parameter is the associated data.
+const ENCRYPTED_PREFIX: &str = "v1:";
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+// The key served by the KMS is fetched once per process.
+static KMS_KEY: OnceCell<[u8; 32]> = OnceCell::new();
+
+/// Encrypts the secret parameters of the connection before it's stored in the meta service.
+pub async fn encrypt_connection_params(
+    conf: &Config,
+    params: BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>> {
+    if !params.keys().any(|k| ConnectionInfo::is_secret_param(k)) {
+        return Ok(params);
+    }
+
+    let key = encryption_key(conf).await?;
+    params
+        .into_iter()
+        .map(|(k, v)| match ConnectionInfo::is_secret_param(&k) {
+            true => encrypt_value(&key, &k, &v).map(|v| (k, v)),
+            false => Ok((k, v)),
+        })
+        .collect()
+}
+
+/// Decrypts the secret parameters of the connection fetched from the meta service.
+pub async fn decrypt_connection_params(
+    conf: &Config,
+    params: BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>> {
+    if !params.keys().any(|k| ConnectionInfo::is_secret_param(k)) {
+        return Ok(params);
+    }
+
+    let key = encryption_key(conf).await?;
+    params
+        .into_iter()
+        .map(|(k, v)| match ConnectionInfo::is_secret_param(&k) {
+            true => decrypt_value(&key, &k, &v).map(|v| (k, v)),
+            false => Ok((k, v)),
+        })
+        .collect()
+}
+
+/// Resolves the connection referenced by `connection_name` in the connection of the location,
+/// the parameters of the connection are overridden by the ones given with the location.
+pub async fn resolve_uri_connection(
+    conf: &Config,
+    tenant: &str,
+    location: &UriLocation,
+) -> Result<UriLocation> {
+    let mut location = location.clone();
+    let name = match location.connection.remove("connection_name") {
+        None => return Ok(location),
+        Some(name) => name,
+    };
+
+    let connection = UserApiProvider::instance()
+        .get_connection(tenant, &name)
+        .await?;
+    if !connection
+        .storage_type
+        .eq_ignore_ascii_case(&location.protocol)
+    {
+        return Err(ErrorCode::BadArguments(format!(
+            "connection {} is of storage type {}, but the location is of {}",
+            name, connection.storage_type, location.protocol
+        )));
+    }
+
+    let mut params = decrypt_connection_params(conf, connection.storage_params).await?;
+    params.extend(location.connection);
+    location.connection = params;
+    Ok(location)
+}
+
+/// The storage kept by a stage or a table of the location. If the location references a
+/// connection, the storage is kept without the parameters of the connection, along with the
+/// location, which is resolved by the connection each time the storage is used.
+pub fn kept_location_storage(
+    location: &UriLocation,
+) -> Result<(StorageParams, Option<UriLocation>)> {
+    let mut without_connection = location.clone();
+    match without_connection.connection.remove("connection_name") {
+        None => Ok((parse_uri_location(location)?.0, None)),
+        Some(_) => Ok((
+            parse_uri_location(&without_connection)?.0,
+            Some(location.clone()),
+        )),
+    }
+}
+
+/// Keeps the storage of the location in the engine options of a table, see
+/// `kept_location_storage()`. The options are left as they are if the location doesn't
+/// reference a connection.
+pub fn keep_location_storage(
+    location: &UriLocation,
+    engine_options: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    if let (storage, Some(location)) = kept_location_storage(location)? {
+        engine_options.insert(
+            OPT_KEY_STORAGE.to_string(),
+            serde_json::to_string(&storage)?,
+        );
+        engine_options.insert(
+            OPT_KEY_CONNECTION_LOCATION.to_string(),
+            serde_json::to_string(&location)?,
+        );
+    }
+    Ok(())
+}
+
+/// Restores the storage kept in the engine options of a table before the meta of the table is
+/// updated, since the storage of the table read may be the one resolved by its connection.
+pub fn restore_kept_storage(engine_options: &mut BTreeMap<String, String>) -> Result<()> {
+    if let Some(location) = engine_options.get(OPT_KEY_CONNECTION_LOCATION) {
+        let location: UriLocation = serde_json::from_str(location)?;
+        keep_location_storage(&location, engine_options)?;
+    }
+    Ok(())
+}
+
+/// Resolves the storage of the stage by the connection referenced by its location.
+pub async fn resolve_stage_connection(
+    conf: &Config,
+    tenant: &str,
+    mut stage: UserStageInfo,
+) -> Result<UserStageInfo> {
+    if let Some(location) = &stage.stage_params.location {
+        let location = resolve_uri_connection(conf, tenant, location).await?;
+        stage.stage_params.storage = parse_uri_location(&location)?.0;
+    }
+    Ok(stage)
+}
+
+/// Resolves the storage of the table by the connection referenced by its location, the table is
+/// rebuilt with the storage resolved.
+pub async fn resolve_table_connection(
+    conf: &Config,
+    tenant: &str,
+    catalog: &dyn Catalog,
+    table: Arc<dyn Table>,
+) -> Result<Arc<dyn Table>> {
+    let table_info = table.get_table_info();
+    let location = match table_info.engine_options().get(OPT_KEY_CONNECTION_LOCATION) {
+        None => return Ok(table),
+        Some(location) => serde_json::from_str::<UriLocation>(location)?,
+    };
+
+    let location = resolve_uri_connection(conf, tenant, &location).await?;
+    let (storage, _) = parse_uri_location(&location)?;
+    let mut table_info = table_info.clone();
+    table_info.meta.engine_options.insert(
+        OPT_KEY_STORAGE.to_string(),
+        serde_json::to_string(&storage)?,
+    );
+    catalog.get_table_by_info(&table_info)
+}
+
+async fn encryption_key(conf: &Config) -> Result<[u8; 32]> {
+    let query = &conf.query;
+    if !query.connection_kms_url.is_empty() {
+        if let Some(key) = KMS_KEY.get() {
+            return Ok(*key);
+        }
+        let key = fetch_kms_key(&query.connection_kms_url, &query.connection_kms_token).await?;
+        let key = derive_key(&key);
+        let _ = KMS_KEY.set(key);
+        return Ok(key);
+    }
+
+    if query.connection_encryption_key.is_empty() {
+        return Err(ErrorCode::InvalidConfig(
+            "connection_encryption_key or connection_kms_url must be configured to keep the secrets of the connections",
+        ));
+    }
+    Ok(derive_key(&query.connection_encryption_key))
+}
+
+// The body of the response of the KMS is the key.
+async fn fetch_kms_key(url: &str, token: &str) -> Result<String> {
+    let mut request = reqwest::Client::new().get(url);
+    if !token.is_empty() {
+        request = request.bearer_auth(token);
+    }
+
+    let key = request
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| {
+            ErrorCode::NetworkRequestError(format!("fail to request the key from the KMS: {}", e))
+        })?
+        .text()
+        .await
+        .map_err(|e| {
+            ErrorCode::NetworkRequestError(format!("fail to read the key from the KMS: {}", e))
+        })?;
+
+    match key.trim() {
+        "" => Err(ErrorCode::InvalidConfig("the KMS served an empty key")),
+        key => Ok(key.to_string()),
+    }
+}
+
+fn derive_key(key: &str) -> [u8; 32] {
+    Sha256::digest(key.as_bytes()).into()
+}
+
+fn encrypt_value(key: &[u8], name: &str, value: &str) -> Result<String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let mut tag = [0u8; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(&nonce),
+        name.as_bytes(),
+        value.as_bytes(),
+        &mut tag,
+    )
+    .map_err(|e| {
+        ErrorCode::UnexpectedError(format!("fail to encrypt the secret {}: {}", name, e))
+    })?;
+
+    let mut data = Vec::with_capacity(NONCE_LEN + ciphertext.len() + TAG_LEN);
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&ciphertext);
+    data.extend_from_slice(&tag);
+    Ok(format!("{}{}", ENCRYPTED_PREFIX, base64::encode(data)))
+}
+
+fn decrypt_value(key: &[u8], name: &str, value: &str) -> Result<String> {
+    let illegal = || {
+        ErrorCode::IllegalConnectionFormat(format!(
+            "the secret {} of the connection is not encrypted by the configured key",
+            name
+        ))
+    };
+
+    let data = value
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .and_then(|data| base64::decode(data).ok())
+        .filter(|data| data.len() >= NONCE_LEN + TAG_LEN)
+        .ok_or_else(illegal)?;
+    let (nonce, data) = data.split_at(NONCE_LEN);
+    let (ciphertext, tag) = data.split_at(data.len() - TAG_LEN);
+    let plaintext = decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(nonce),
+        name.as_bytes(),
+        ciphertext,
+        tag,
+    )
+    .map_err(|_| illegal())?;
+    String::from_utf8(plaintext).map_err(|_| illegal())
+}
@@ -42,8 +42,11 @@ impl SystemDatabase {
             system::TablesTableWithHistory::create(sys_db_meta.next_table_id()),
             system::ClustersTable::create(sys_db_meta.next_table_id()),
             system::DatabasesTable::create(sys_db_meta.next_table_id()),
+            system::DictionariesTable::create(sys_db_meta.next_table_id()),
             Arc::new(system::TracingTable::create(sys_db_meta.next_table_id())),
             system::ProcessesTable::create(sys_db_meta.next_table_id()),
+            system::QueryCacheTable::create(sys_db_meta.next_table_id()),
+            system::BackgroundTasksTable::create(sys_db_meta.next_table_id()),
             system::ConfigsTable::create(sys_db_meta.next_table_id()),
             system::MetricsTable::create(sys_db_meta.next_table_id()),
             system::ColumnsTable::create(sys_db_meta.next_table_id()),
@@ -56,9 +59,25 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(system::AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.audit_log_max_rows,
+            )),
+            Arc::new(system::SlowQueryLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             system::EnginesTable::create(sys_db_meta.next_table_id()),
             system::RolesTable::create(sys_db_meta.next_table_id()),
             system::StagesTable::create(sys_db_meta.next_table_id()),
+            system::ConnectionsTable::create(sys_db_meta.next_table_id()),
+            system::SequencesTable::create(sys_db_meta.next_table_id()),
+            system::PlanHintsTable::create(sys_db_meta.next_table_id()),
+            system::TasksTable::create(sys_db_meta.next_table_id()),
+            Arc::new(system::TaskHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
         ];
 
         for tbl in table_list.into_iter() {
@@ -0,0 +1,134 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::FunctionContext;
+
+use crate::evaluator::EvalNode;
+
+// The conjuncts are measured on this many blocks before they are ordered.
+const SAMPLE_BLOCKS: usize = 4;
+
+/// A filter predicate of several conjuncts, evaluated one by one, each on the rows kept by the
+/// conjuncts before it.
+///
+/// The cost and the selectivity of each conjunct are measured on the first blocks, where each
+/// conjunct is evaluated on all the rows. The conjuncts are then ordered by the cost to filter
+/// out a row, so the cheap and selective conjuncts go first, and the others are evaluated on
+/// the fewer rows they keep.
+#[derive(Clone)]
+pub struct AdaptiveFilter {
+    conjuncts: Vec<EvalNode>,
+    stats: Vec<ConjunctStats>,
+    order: Vec<usize>,
+    sampled_blocks: usize,
+}
+
+#[derive(Clone, Default)]
+struct ConjunctStats {
+    rows: usize,
+    kept_rows: usize,
+    elapsed: Duration,
+}
+
+impl ConjunctStats {
+    // The time to evaluate the conjunct per row it filters out, a conjunct filtering out no
+    // row goes last.
+    fn rank(&self) -> f64 {
+        let filtered_rows = self.rows - self.kept_rows;
+        if filtered_rows == 0 {
+            return f64::INFINITY;
+        }
+        self.elapsed.as_nanos() as f64 / filtered_rows as f64
+    }
+}
+
+impl AdaptiveFilter {
+    pub fn create(conjuncts: Vec<EvalNode>) -> AdaptiveFilter {
+        AdaptiveFilter {
+            stats: vec![ConjunctStats::default(); conjuncts.len()],
+            order: (0..conjuncts.len()).collect(),
+            conjuncts,
+            sampled_blocks: 0,
+        }
+    }
+
+    /// The indices of the conjuncts in the order they are evaluated.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    pub fn filter(
+        &mut self,
+        func_ctx: &FunctionContext,
+        data_block: DataBlock,
+    ) -> Result<DataBlock> {
+        if self.sampled_blocks < SAMPLE_BLOCKS {
+            return self.sample(func_ctx, data_block);
+        }
+
+        let mut data_block = data_block;
+        for index in &self.order {
+            let predicate = self.conjuncts[*index].eval(func_ctx, &data_block)?;
+            data_block = DataBlock::filter_block(data_block, predicate.vector())?;
+            if data_block.num_rows() == 0 {
+                break;
+            }
+        }
+        Ok(data_block)
+    }
+
+    // Evaluates each conjunct on all the rows of the block to measure it, and filters the block
+    // by all of them at once.
+    fn sample(&mut self, func_ctx: &FunctionContext, data_block: DataBlock) -> Result<DataBlock> {
+        let rows = data_block.num_rows();
+        let mut filter: Option<Bitmap> = None;
+        for (conjunct, stats) in self.conjuncts.iter().zip(self.stats.iter_mut()) {
+            let start = Instant::now();
+            let predicate = conjunct.eval(func_ctx, &data_block)?;
+            let predicate = DataBlock::cast_to_nonull_boolean(predicate.vector())?;
+            let predicate = predicate.convert_full_column();
+            stats.elapsed += start.elapsed();
+
+            let predicate: &BooleanColumn = Series::check_get(&predicate)?;
+            stats.rows += rows;
+            stats.kept_rows += rows - predicate.values().unset_bits();
+            filter = Some(match filter {
+                None => predicate.values().clone(),
+                Some(filter) => &filter & predicate.values(),
+            });
+        }
+
+        self.sampled_blocks += 1;
+        if self.sampled_blocks == SAMPLE_BLOCKS {
+            let stats = &self.stats;
+            self.order
+                .sort_by(|a, b| stats[*a].rank().total_cmp(&stats[*b].rank()));
+        }
+
+        match filter {
+            None => Ok(data_block),
+            Some(filter) => DataBlock::filter_block_with_bool_column(
+                data_block,
+                &BooleanColumn::from_arrow_data(filter),
+            ),
+        }
+    }
+}
@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_datablocks::DataBlock;
+use common_datavalues::BooleanColumn;
+use common_datavalues::ColumnRef;
 use common_datavalues::ColumnWithField;
 use common_datavalues::DataField;
+use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
 use common_datavalues::NullType;
+use common_datavalues::Series;
 use common_exception::Result;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionContext;
@@ -41,9 +47,36 @@ pub enum EvalNode {
     IndexedVariable {
         index: usize,
     },
+    /// A conditional function, each argument is evaluated only on the rows whose result it
+    /// decides, e.g. the untaken branches of a CASE are never evaluated, nor fail.
+    Conditional {
+        kind: ConditionalKind,
+        func: Box<dyn Function>,
+        args: Vec<Self>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConditionalKind {
+    /// `if` and `multi_if`, of the conditions and their results in pairs, and the result of
+    /// the else branch at last.
+    If,
+    And,
+    Or,
 }
 
 impl EvalNode {
+    /// The node of the function, the conditional functions are evaluated lazily.
+    pub fn create_function(name: &str, func: Box<dyn Function>, args: Vec<EvalNode>) -> EvalNode {
+        let kind = match name.to_lowercase().as_str() {
+            "if" | "multi_if" => ConditionalKind::If,
+            "and" => ConditionalKind::And,
+            "or" => ConditionalKind::Or,
+            _ => return EvalNode::Function { func, args },
+        };
+        EvalNode::Conditional { kind, func, args }
+    }
+
     pub fn eval(&self, func_ctx: &FunctionContext, data_block: &DataBlock) -> Result<TypedVector> {
         match &self {
             EvalNode::Function { func, args } => {
@@ -74,9 +107,131 @@ impl EvalNode {
                     logical_type: data_type,
                 })
             }
+            EvalNode::Conditional { kind, func, args } => {
+                let args = match kind {
+                    ConditionalKind::If => Self::eval_if_args(args, func_ctx, data_block)?,
+                    ConditionalKind::And | ConditionalKind::Or => {
+                        let left = args[0].eval(func_ctx, data_block)?;
+                        let undecided = Self::undecided_rows(*kind, &left)?;
+                        let right = args[1].eval_selected(func_ctx, data_block, &undecided)?;
+                        vec![left, right]
+                    }
+                };
+                let args = args
+                    .into_iter()
+                    .map(|vector| {
+                        ColumnWithField::new(vector.vector, DataField::new("", vector.logical_type))
+                    })
+                    .collect::<Vec<_>>();
+                Ok(TypedVector::new(
+                    func.eval(func_ctx.clone(), &args, data_block.num_rows())?,
+                    func.return_type(),
+                ))
+            }
         }
     }
 
+    fn data_type(&self, data_block: &DataBlock) -> DataTypeImpl {
+        match self {
+            EvalNode::Function { func, .. } | EvalNode::Conditional { func, .. } => {
+                func.return_type()
+            }
+            EvalNode::Constant { data_type, .. } => data_type.clone(),
+            EvalNode::IndexedVariable { index } => {
+                data_block.schema().field(*index).data_type().clone()
+            }
+        }
+    }
+
+    // Each condition is evaluated on the rows not taken by the conditions before it, and each
+    // result on the rows taken by its condition.
+    fn eval_if_args(
+        args: &[EvalNode],
+        func_ctx: &FunctionContext,
+        data_block: &DataBlock,
+    ) -> Result<Vec<TypedVector>> {
+        let mut remaining = all_rows(data_block.num_rows());
+        let mut vectors = Vec::with_capacity(args.len());
+        for branch in args[..args.len() - 1].chunks(2) {
+            let condition = branch[0].eval_selected(func_ctx, data_block, &remaining)?;
+            let taken = &remaining & &true_rows(condition.vector())?;
+            let result = branch[1].eval_selected(func_ctx, data_block, &taken)?;
+            remaining = &remaining & &!&taken;
+            vectors.push(condition);
+            vectors.push(result);
+        }
+        vectors.push(args[args.len() - 1].eval_selected(func_ctx, data_block, &remaining)?);
+        Ok(vectors)
+    }
+
+    // The rows where the left argument of AND is not false, or of OR is not true, they are
+    // decided by the right argument.
+    fn undecided_rows(kind: ConditionalKind, left: &TypedVector) -> Result<Bitmap> {
+        let column = left.vector().convert_full_column();
+        let is_true = true_rows(&column)?;
+        let decided = match (kind, column.validity()) {
+            (ConditionalKind::Or, _) => is_true,
+            (_, (true, _)) => return Ok(all_rows(column.len())),
+            (_, (false, None)) => !&is_true,
+            (_, (false, Some(validity))) => validity & &!&is_true,
+        };
+        Ok(!&decided)
+    }
+
+    // Evaluates the node on the selected rows only, the other rows of the result are the
+    // default value of its type, which the conditional function never takes.
+    fn eval_selected(
+        &self,
+        func_ctx: &FunctionContext,
+        data_block: &DataBlock,
+        selection: &Bitmap,
+    ) -> Result<TypedVector> {
+        let rows = data_block.num_rows();
+        let selected = rows - selection.unset_bits();
+        let cheap = matches!(
+            self,
+            EvalNode::Constant { .. } | EvalNode::IndexedVariable { .. }
+        );
+        if selected == rows || cheap {
+            return self.eval(func_ctx, data_block);
+        }
+
+        if selected == 0 {
+            let data_type = self.data_type(data_block);
+            let vector = data_type.create_constant_column(&data_type.default_value(), rows)?;
+            return Ok(TypedVector::new(vector, data_type));
+        }
+
+        let filter = BooleanColumn::from_arrow_data(selection.clone());
+        let selected_block = DataBlock::filter_block_with_bool_column(data_block.clone(), &filter)?;
+        let vector = self.eval(func_ctx, &selected_block)?;
+
+        // The selected rows go back to their positions, the others take the default value
+        // appended after them.
+        let column = Series::remove_dictionary(&vector.vector().convert_full_column());
+        let data_type = column.data_type();
+        let default = data_type
+            .create_constant_column(&data_type.default_value(), 1)?
+            .convert_full_column();
+        let column = Series::concat(&[column, default])?;
+
+        let mut next = 0;
+        let indices = selection
+            .iter()
+            .map(|is_selected| match is_selected {
+                true => {
+                    next += 1;
+                    next - 1
+                }
+                false => selected as u32,
+            })
+            .collect::<Vec<u32>>();
+        Ok(TypedVector::new(
+            Series::take(&column, &indices)?,
+            vector.logical_type(),
+        ))
+    }
+
     /// Try to evaluate as a constant expression
     pub fn try_eval_const(&self, func_ctx: &FunctionContext) -> Result<(DataValue, DataTypeImpl)> {
         let dummy_column = DataValue::Null.as_const_column(&NullType::new_impl(), 1)?;
@@ -88,3 +243,16 @@ impl EvalNode {
         Ok((vector.vector.get(0), vector.logical_type))
     }
 }
+
+fn all_rows(rows: usize) -> Bitmap {
+    let mut bitmap = MutableBitmap::with_capacity(rows);
+    bitmap.extend_constant(rows, true);
+    bitmap.into()
+}
+
+// The rows where the column is true, rather than false or null.
+fn true_rows(column: &ColumnRef) -> Result<Bitmap> {
+    let column = DataBlock::cast_to_nonull_boolean(column)?.convert_full_column();
+    let column: &BooleanColumn = Series::check_get(&column)?;
+    Ok(column.values().clone())
+}
@@ -0,0 +1,308 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::compare_coercion;
+use common_datavalues::with_match_primitive_type_id;
+
+use crate::sql::executor::PhysicalScalar;
+
+/// A filter predicate evaluated in one pass over the block.
+///
+/// It evaluates the comparisons of the numeric columns with each other or with the constants,
+/// combined by AND, OR and NOT, 64 rows at once into the bits of the result, so unlike
+/// `EvalNode` it allocates no column for each function of the predicate. It's still
+/// interpreted, no machine code is generated for the predicate.
+///
+/// Only such predicates of the non-nullable columns are fused, the others are left to
+/// `EvalNode`.
+#[derive(Clone)]
+pub struct FusedPredicate {
+    node: FusedNode,
+}
+
+impl FusedPredicate {
+    /// Fuses the predicate, returns None if any function of it can not be fused.
+    pub fn try_create(predicate: &PhysicalScalar) -> Option<FusedPredicate> {
+        Some(FusedPredicate {
+            node: FusedNode::create(predicate)?,
+        })
+    }
+
+    /// Evaluates the predicate on the block, returns None if the columns of the block are not
+    /// the ones it is fused for, e.g. the constant columns, and the block is to be evaluated
+    /// by `EvalNode`.
+    pub fn eval(&self, data_block: &DataBlock) -> Option<BooleanColumn> {
+        let node = self.node.bind(data_block)?;
+        let rows = data_block.num_rows();
+        let mut bytes = Vec::with_capacity((rows + 63) / 64 * 8);
+        for offset in (0..rows).step_by(64) {
+            let mask = node.eval(offset, 64.min(rows - offset));
+            bytes.extend_from_slice(&mask.to_le_bytes());
+        }
+        bytes.truncate((rows + 7) / 8);
+        let values = MutableBitmap::from_vec(bytes, rows);
+        Some(BooleanColumn::from_arrow_data(values.into()))
+    }
+}
+
+#[derive(Clone)]
+enum FusedNode {
+    Compare(Arc<dyn FusedComparison>),
+    And(Box<FusedNode>, Box<FusedNode>),
+    Or(Box<FusedNode>, Box<FusedNode>),
+    Not(Box<FusedNode>),
+}
+
+impl FusedNode {
+    fn create(scalar: &PhysicalScalar) -> Option<FusedNode> {
+        let (name, args) = match scalar {
+            PhysicalScalar::Function {
+                name,
+                args,
+                return_type,
+            } if !return_type.is_nullable() => (name.to_lowercase(), args),
+            _ => return None,
+        };
+
+        match (name.as_str(), args.as_slice()) {
+            ("and" | "and_filters", [(left, _), (right, _)]) => Some(FusedNode::And(
+                Box::new(Self::create(left)?),
+                Box::new(Self::create(right)?),
+            )),
+            ("or", [(left, _), (right, _)]) => Some(FusedNode::Or(
+                Box::new(Self::create(left)?),
+                Box::new(Self::create(right)?),
+            )),
+            ("not", [(arg, _)]) => Some(FusedNode::Not(Box::new(Self::create(arg)?))),
+            (op, [(left, _), (right, _)]) => Self::create_comparison(op, left, right),
+            _ => None,
+        }
+    }
+
+    // The comparison of a column with a column of the same type, or with a constant converted
+    // into the type of the column, as the comparison functions cast both into the least super
+    // type.
+    fn create_comparison(
+        op: &str,
+        left: &PhysicalScalar,
+        right: &PhysicalScalar,
+    ) -> Option<FusedNode> {
+        let (op, left, right) = match (left, right) {
+            (PhysicalScalar::IndexedVariable { .. }, _) => (op, left, right),
+            (_, PhysicalScalar::IndexedVariable { .. }) => (flip(op)?, right, left),
+            _ => return None,
+        };
+        let (index, data_type) = match left {
+            PhysicalScalar::IndexedVariable {
+                index, data_type, ..
+            } => (*index, data_type),
+            _ => return None,
+        };
+        if compare_coercion(data_type, &right.data_type()).ok()? != *data_type {
+            return None;
+        }
+
+        with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+            let right = match right {
+                PhysicalScalar::IndexedVariable {
+                    index,
+                    data_type: right_type,
+                    ..
+                } if right_type == data_type => Operand::Column(*index),
+                PhysicalScalar::Constant { value, .. } => {
+                    Operand::Constant(constant_value::<$T>(value)?)
+                }
+                _ => return None,
+            };
+            comparison::<$T>(op, index, right)
+        }, {
+            None
+        })
+    }
+
+    fn bind<'a>(&self, data_block: &'a DataBlock) -> Option<BoundNode<'a>> {
+        Some(match self {
+            FusedNode::Compare(comparison) => BoundNode::Compare(comparison.bind(data_block)?),
+            FusedNode::And(left, right) => BoundNode::And(
+                Box::new(left.bind(data_block)?),
+                Box::new(right.bind(data_block)?),
+            ),
+            FusedNode::Or(left, right) => BoundNode::Or(
+                Box::new(left.bind(data_block)?),
+                Box::new(right.bind(data_block)?),
+            ),
+            FusedNode::Not(arg) => BoundNode::Not(Box::new(arg.bind(data_block)?)),
+        })
+    }
+}
+
+// A `FusedNode` with the columns of a block.
+enum BoundNode<'a> {
+    Compare(Box<dyn BoundComparison + 'a>),
+    And(Box<BoundNode<'a>>, Box<BoundNode<'a>>),
+    Or(Box<BoundNode<'a>>, Box<BoundNode<'a>>),
+    Not(Box<BoundNode<'a>>),
+}
+
+impl<'a> BoundNode<'a> {
+    // The bits of the rows in `offset..offset + len`, the bits past `len` are undefined.
+    fn eval(&self, offset: usize, len: usize) -> u64 {
+        match self {
+            BoundNode::Compare(comparison) => comparison.eval(offset, len),
+            BoundNode::And(left, right) => left.eval(offset, len) & right.eval(offset, len),
+            BoundNode::Or(left, right) => left.eval(offset, len) | right.eval(offset, len),
+            BoundNode::Not(arg) => !arg.eval(offset, len),
+        }
+    }
+}
+
+trait FusedComparison: Send + Sync {
+    fn bind<'a>(&self, data_block: &'a DataBlock) -> Option<Box<dyn BoundComparison + 'a>>;
+}
+
+trait BoundComparison {
+    fn eval(&self, offset: usize, len: usize) -> u64;
+}
+
+#[derive(Clone, Copy)]
+enum Operand<T> {
+    Column(usize),
+    Constant(T),
+}
+
+struct Comparison<T, O> {
+    left: usize,
+    right: Operand<T>,
+    _op: PhantomData<O>,
+}
+
+impl<T: PrimitiveType, O: CompareOp> FusedComparison for Comparison<T, O> {
+    fn bind<'a>(&self, data_block: &'a DataBlock) -> Option<Box<dyn BoundComparison + 'a>> {
+        let left = primitive_values::<T>(data_block, self.left)?;
+        let right = match self.right {
+            Operand::Column(index) => Operand::Column(primitive_values::<T>(data_block, index)?),
+            Operand::Constant(value) => Operand::Constant(value),
+        };
+        Some(Box::new(BoundComparisonImpl::<T, O> {
+            left,
+            right,
+            _op: PhantomData,
+        }))
+    }
+}
+
+struct BoundComparisonImpl<'a, T, O> {
+    left: &'a [T],
+    right: Operand<&'a [T]>,
+    _op: PhantomData<O>,
+}
+
+impl<'a, T: PrimitiveType, O: CompareOp> BoundComparison for BoundComparisonImpl<'a, T, O> {
+    fn eval(&self, offset: usize, len: usize) -> u64 {
+        let left = &self.left[offset..offset + len];
+        let mut mask = 0;
+        match self.right {
+            Operand::Column(right) => {
+                let right = &right[offset..offset + len];
+                for (i, (l, r)) in left.iter().zip(right.iter()).enumerate() {
+                    mask |= (O::compare(*l, *r) as u64) << i;
+                }
+            }
+            Operand::Constant(r) => {
+                for (i, l) in left.iter().enumerate() {
+                    mask |= (O::compare(*l, r) as u64) << i;
+                }
+            }
+        }
+        mask
+    }
+}
+
+// The values of a column of the block, if it is a non-nullable column of `T`.
+fn primitive_values<T: PrimitiveType>(data_block: &DataBlock, index: usize) -> Option<&[T]> {
+    let column = data_block.columns().get(index)?;
+    let column: &PrimitiveColumn<T> = column.as_any().downcast_ref()?;
+    Some(column.values())
+}
+
+fn constant_value<T: PrimitiveType>(value: &DataValue) -> Option<T> {
+    match value {
+        DataValue::Int64(v) => num::cast(*v),
+        DataValue::UInt64(v) => num::cast(*v),
+        DataValue::Float64(v) => num::cast(*v),
+        _ => None,
+    }
+}
+
+// The comparison with the operands swapped.
+fn flip(op: &str) -> Option<&str> {
+    match op {
+        "=" | "<>" | "!=" => Some(op),
+        "<" => Some(">"),
+        ">" => Some("<"),
+        "<=" => Some(">="),
+        ">=" => Some("<="),
+        _ => None,
+    }
+}
+
+fn comparison<T: PrimitiveType>(op: &str, left: usize, right: Operand<T>) -> Option<FusedNode> {
+    fn new<T: PrimitiveType, O: CompareOp>(left: usize, right: Operand<T>) -> FusedNode {
+        FusedNode::Compare(Arc::new(Comparison::<T, O> {
+            left,
+            right,
+            _op: PhantomData,
+        }))
+    }
+
+    match op {
+        "=" => Some(new::<T, Equal>(left, right)),
+        "<>" | "!=" => Some(new::<T, NotEqual>(left, right)),
+        "<" => Some(new::<T, Less>(left, right)),
+        "<=" => Some(new::<T, LessEqual>(left, right)),
+        ">" => Some(new::<T, Greater>(left, right)),
+        ">=" => Some(new::<T, GreaterEqual>(left, right)),
+        _ => None,
+    }
+}
+
+trait CompareOp: Send + Sync + 'static {
+    fn compare<T: PartialOrd>(l: T, r: T) -> bool;
+}
+
+macro_rules! impl_compare_op {
+    ($name: ident, $op: tt) => {
+        struct $name;
+
+        impl CompareOp for $name {
+            #[inline]
+            fn compare<T: PartialOrd>(l: T, r: T) -> bool {
+                l $op r
+            }
+        }
+    };
+}
+
+impl_compare_op!(Equal, ==);
+impl_compare_op!(NotEqual, !=);
+impl_compare_op!(Less, <);
+impl_compare_op!(LessEqual, <=);
+impl_compare_op!(Greater, >);
+impl_compare_op!(GreaterEqual, >=);
@@ -12,13 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adaptive;
 mod eval_node;
+mod fused;
 mod physical_scalar;
 mod scalar;
 
+pub use adaptive::AdaptiveFilter;
 use common_datavalues::ColumnRef;
 use common_datavalues::DataTypeImpl;
+pub use eval_node::ConditionalKind;
 pub use eval_node::EvalNode;
+pub use fused::FusedPredicate;
 
 pub struct Evaluator;
 
@@ -72,10 +72,7 @@ impl Evaluator {
 
                 let data_types: Vec<&DataTypeImpl> = args.iter().map(|(_, v)| v).collect();
                 let func = FunctionFactory::instance().get(name, &data_types)?;
-                Ok(EvalNode::Function {
-                    func,
-                    args: eval_args,
-                })
+                Ok(EvalNode::create_function(name, func, eval_args))
             }
             PhysicalScalar::Cast { target, input } => {
                 let from = input.data_type();
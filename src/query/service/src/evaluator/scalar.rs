@@ -41,13 +41,13 @@ impl Evaluator {
                 ];
                 let func = FunctionFactory::instance()
                     .get("and", &[&and.left.data_type(), &and.right.data_type()])?;
-                Ok(EvalNode::Function { func, args })
+                Ok(EvalNode::create_function("and", func, args))
             }
             Scalar::OrExpr(or) => {
                 let args = vec![Self::eval_scalar(&or.left)?, Self::eval_scalar(&or.right)?];
                 let func = FunctionFactory::instance()
                     .get("or", &[&or.left.data_type(), &or.right.data_type()])?;
-                Ok(EvalNode::Function { func, args })
+                Ok(EvalNode::create_function("or", func, args))
             }
             Scalar::ComparisonExpr(comp) => {
                 let args = vec![
@@ -99,11 +99,9 @@ impl Evaluator {
                 }
 
                 let arg_types: Vec<&DataTypeImpl> = func.arg_types.iter().collect();
-                let func = FunctionFactory::instance().get(func.func_name.as_str(), &arg_types)?;
-                Ok(EvalNode::Function {
-                    func,
-                    args: eval_args,
-                })
+                let name = func.func_name.as_str();
+                let func = FunctionFactory::instance().get(name, &arg_types)?;
+                Ok(EvalNode::create_function(name, func, eval_args))
             }
             Scalar::CastExpr(cast) => {
                 let arg = Self::eval_scalar(&cast.argument)?;
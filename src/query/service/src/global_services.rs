@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_base::base::GlobalIORuntime;
 use common_base::base::Runtime;
 use common_base::base::SingletonImpl;
+use common_base::base::TrySpawn;
 use common_catalog::catalog::CatalogManager;
 use common_config::Config;
 use common_exception::Result;
@@ -31,8 +32,11 @@ use opendal::Operator;
 use crate::api::DataExchangeManager;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
+use crate::pipelines::processors::QueryTempStage;
+use crate::pipelines::processors::SpillManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::storages::fuse::io::IOScheduler;
 
 pub struct GlobalServices {
     global_runtime: UnsafeCell<Option<Arc<Runtime>>>,
@@ -79,6 +83,16 @@ impl GlobalServices {
 
         StorageOperator::init(&config.storage, global_services.clone()).await?;
         CacheManager::init(&config.query, global_services.clone())?;
+        IOScheduler::init(
+            config.query.storage_io_max_requests as usize,
+            config.query.storage_io_coalesce_gap_bytes,
+        );
+        SpillManager::init(&config)?;
+        GlobalIORuntime::instance().spawn(async {
+            if let Err(cause) = QueryTempStage::remove_stale(StorageOperator::instance()).await {
+                tracing::warn!("Cannot remove the stale temporary stages: {}", cause);
+            }
+        });
         CatalogManager::init(&config, global_services.clone()).await?;
         HttpQueryManager::init(&config, global_services.clone()).await?;
         DataExchangeManager::init(config.clone(), global_services.clone())?;
@@ -89,6 +103,11 @@ impl GlobalServices {
             global_services.clone(),
         )
         .await?;
+        // The cached plans are invalidated by the changes watched from the meta-service.
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        SessionManager::instance()
+            .plan_cache
+            .spawn_watcher(meta.as_ref().clone());
         RoleCacheManager::init(global_services.clone())
     }
 }
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_exception::Result;
 
 use crate::interpreters::access::PrivilegeAccess;
+use crate::interpreters::access::ReadOnlyModeAccess;
 use crate::interpreters::ManagementModeAccess;
 use crate::sessions::QueryContext;
 use crate::sql::plans::Plan;
@@ -39,6 +40,10 @@ impl Accessor {
             "management".to_string(),
             ManagementModeAccess::create(ctx.clone()),
         );
+        accessors.insert(
+            "read_only".to_string(),
+            ReadOnlyModeAccess::create(ctx.clone()),
+        );
         accessors.insert("privilege".to_string(), PrivilegeAccess::create(ctx));
         Accessor { accessors }
     }
@@ -51,6 +51,12 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::ShowUsers
                             | RewriteKind::ShowStages
                             | RewriteKind::DescribeStage
+                            | RewriteKind::ShowConnections
+                            | RewriteKind::DescribeConnection
+                            | RewriteKind::ShowSequences
+                            | RewriteKind::ShowPlanHints
+                            | RewriteKind::ShowDictionaries
+                            | RewriteKind::ShowTasks
                             | RewriteKind::ShowRoles),
                         _ => false
                     }
@@ -67,6 +73,7 @@ impl AccessChecker for ManagementModeAccess {
                 // Table.
                 | Plan::DescribeTable(_)
                 | Plan::CreateTable(_)
+                | Plan::AttachTable(_)
                 | Plan::DropTable(_)
 
                 // User.
@@ -87,6 +94,42 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+
+                // Row access policy
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+
+                // Masking policy
+                | Plan::CreateMaskingPolicy(_)
+                | Plan::DropMaskingPolicy(_)
+
+                // Network policy
+                | Plan::CreateNetworkPolicy(_)
+                | Plan::DropNetworkPolicy(_)
+
+                // Resource group
+                | Plan::CreateResourceGroup(_)
+                | Plan::DropResourceGroup(_)
+
+                // Connection
+                | Plan::CreateConnection(_)
+                | Plan::DropConnection(_)
+
+                // Sequence
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
+
+                // Plan hint
+                | Plan::CreatePlanHint(_)
+                | Plan::DropPlanHint(_)
+
+                // Dictionary
+                | Plan::CreateDictionary(_)
+                | Plan::DropDictionary(_)
+
+                // Task
+                | Plan::CreateTask(_)
+                | Plan::DropTask(_)
                 | Plan::UseDatabase(_)
                 | Plan::Call(_) => true,
                 _ => false
@@ -14,14 +14,21 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::ExplainKind;
 use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_meta_types::UserPrivilegeType;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
+use crate::sql::plans::CopyPlanV2;
+use crate::sql::plans::InsertInputSource;
 use crate::sql::plans::Plan;
 
+fn is_system_database(database: &str) -> bool {
+    database.eq_ignore_ascii_case("system") || database.eq_ignore_ascii_case("information_schema")
+}
+
 pub struct PrivilegeAccess {
     ctx: Arc<QueryContext>,
 }
@@ -38,9 +45,59 @@ impl AccessChecker for PrivilegeAccess {
         let session = self.ctx.get_current_session();
 
         match plan {
-            Plan::Query { .. } => {}
+            Plan::Query {
+                metadata,
+                rewrite_kind,
+                ..
+            } => {
+                // The rewritten queries of `SHOW` are on the system tables.
+                if rewrite_kind.is_none() {
+                    let tables = metadata.read().tables().to_vec();
+                    for table in tables.iter() {
+                        if is_system_database(table.database()) {
+                            continue;
+                        }
+                        session
+                            .validate_privilege(
+                                &GrantObject::Table(
+                                    table.catalog().to_string(),
+                                    table.database().to_string(),
+                                    table.name().to_string(),
+                                ),
+                                UserPrivilegeType::Select,
+                            )
+                            .await?;
+                    }
+                }
+            }
+            // The query is executed by EXPLAIN ANALYZE.
+            Plan::Explain {
+                kind: ExplainKind::Analyze,
+                plan,
+            } => self.check(plan).await?,
             Plan::Explain { .. } => {}
-            Plan::Copy(_) => {}
+            Plan::Copy(plan) => match plan.as_ref() {
+                CopyPlanV2::IntoTable {
+                    catalog_name,
+                    database_name,
+                    table_name,
+                    ..
+                } => {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                catalog_name.clone(),
+                                database_name.clone(),
+                                table_name.clone(),
+                            ),
+                            UserPrivilegeType::Insert,
+                        )
+                        .await?;
+                }
+                CopyPlanV2::IntoStage { from, .. } => {
+                    self.check(from).await?;
+                }
+            },
             Plan::Call(_) => {}
 
             // Database.
@@ -65,6 +122,27 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, UserPrivilegeType::Alter)
                     .await?;
             }
+            Plan::SetDatabaseComment(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::BackupDatabase(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+            }
+            Plan::RestoreDatabase(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Create)
+                    .await?;
+            }
             Plan::UseDatabase(_) => {}
 
             // Table.
@@ -78,6 +156,14 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::AttachTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+            }
             Plan::DropTable(plan) => {
                 session
                     .validate_privilege(
@@ -94,7 +180,43 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
-            Plan::RenameTable(_) => {}
+            Plan::RenameTable(plan) => {
+                for entity in plan.entities.iter() {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                entity.catalog.clone(),
+                                entity.database.clone(),
+                                entity.table.clone(),
+                            ),
+                            UserPrivilegeType::Alter,
+                        )
+                        .await?;
+                    session
+                        .validate_privilege(
+                            &GrantObject::Database(
+                                entity.catalog.clone(),
+                                entity.new_database.clone(),
+                            ),
+                            UserPrivilegeType::Create,
+                        )
+                        .await?;
+                }
+            }
+            Plan::SwapTable(plan) => {
+                for table in [&plan.table, &plan.target_table] {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                plan.catalog.clone(),
+                                plan.database.clone(),
+                                table.clone(),
+                            ),
+                            UserPrivilegeType::Alter,
+                        )
+                        .await?;
+                }
+            }
             Plan::AlterTableClusterKey(plan) => {
                 session
                     .validate_privilege(
@@ -107,6 +229,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::SetTableOptions(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::SetTableColumnComment(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
             Plan::DropTableClusterKey(plan) => {
                 session
                     .validate_privilege(
@@ -119,6 +265,42 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::AddTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::AddTableVirtualColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+            }
+            Plan::DropTableVirtualColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Drop,
+                    )
+                    .await?;
+            }
             Plan::ReclusterTable(plan) => {
                 session
                     .validate_privilege(
@@ -144,11 +326,55 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             Plan::OptimizeTable(_) => {}
+            Plan::AnalyzeTable(_) => {}
             Plan::ExistsTable(_) => {}
 
             // Others.
-            Plan::Insert(_) => {}
-            Plan::Delete(_) => {}
+            Plan::Insert(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Insert,
+                    )
+                    .await?;
+                if let InsertInputSource::SelectPlan(select_plan) = &plan.source {
+                    self.check(select_plan).await?;
+                }
+            }
+            Plan::Replace(plan) => {
+                // The conflicting rows are deleted.
+                for privilege in [UserPrivilegeType::Insert, UserPrivilegeType::Delete] {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                plan.catalog.clone(),
+                                plan.database.clone(),
+                                plan.table.clone(),
+                            ),
+                            privilege,
+                        )
+                        .await?;
+                }
+                if let InsertInputSource::SelectPlan(select_plan) = &plan.source {
+                    self.check(select_plan).await?;
+                }
+            }
+            Plan::Delete(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog_name.clone(),
+                            plan.database_name.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        UserPrivilegeType::Delete,
+                    )
+                    .await?;
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -173,30 +399,169 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
-            Plan::AlterUser(_) => {}
-            Plan::CreateUser(_) => {}
-            Plan::DropUser(_) => {}
-            Plan::CreateUDF(_) => {}
-            Plan::AlterUDF(_) => {}
-            Plan::DropUDF(_) => {}
-            Plan::CreateRole(_) => {}
-            Plan::DropRole(_) => {}
-            Plan::GrantRole(_) => {}
-            Plan::GrantPriv(_) => {}
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Drop,
+                    )
+                    .await?;
+            }
+            Plan::CreateAggregatingIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+            }
+            Plan::DropAggregatingIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        UserPrivilegeType::Drop,
+                    )
+                    .await?;
+            }
+            Plan::AlterUser(plan) => {
+                // The users can change their own passwords, but not their own options, e.g.
+                // the default role or the network policy.
+                let is_self = plan.user == session.get_current_user()?.identity();
+                if !is_self || plan.user_option.is_some() {
+                    session
+                        .validate_privilege(&GrantObject::Global, UserPrivilegeType::CreateUser)
+                        .await?;
+                }
+            }
+            Plan::CreateUser(_) | Plan::DropUser(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::CreateUser)
+                    .await?;
+            }
+            Plan::CreateUDF(_) | Plan::AlterUDF(_) | Plan::DropUDF(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
+            // The policies are managed by the administrators, otherwise the users who can
+            // alter the tables could remove the policies to see all the rows and values.
+            Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_)
+            | Plan::CreateMaskingPolicy(_)
+            | Plan::DropMaskingPolicy(_)
+            | Plan::SetTableColumnMaskingPolicy(_)
+            | Plan::UnsetTableColumnMaskingPolicy(_)
+            | Plan::CreateNetworkPolicy(_)
+            | Plan::DropNetworkPolicy(_)
+            | Plan::CreateResourceGroup(_)
+            | Plan::DropResourceGroup(_)
+            | Plan::CreateTask(_)
+            | Plan::DropTask(_)
+            | Plan::AlterTask(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
+            // The connections keep the credentials of the storages.
+            Plan::CreateConnection(_) | Plan::DropConnection(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
+            // The dictionaries keep the credentials of their sources, and read the local files.
+            Plan::CreateDictionary(_) | Plan::DropDictionary(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
+            Plan::CreateSequence(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Create)
+                    .await?;
+            }
+            Plan::DropSequence(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Drop)
+                    .await?;
+            }
+            // The hints change the plans of the queries of all the users of the tenant.
+            Plan::CreatePlanHint(_) | Plan::DropPlanHint(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
+            // Draining a node changes where the queries of the cluster run.
+            Plan::DrainNode(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+            }
+            Plan::CreateRole(_) | Plan::DropRole(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::CreateRole)
+                    .await?;
+            }
+            Plan::GrantRole(_) | Plan::GrantPriv(_) | Plan::RevokePriv(_) | Plan::RevokeRole(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Grant)
+                    .await?;
+            }
             Plan::ShowGrants(_) => {}
-            Plan::RevokePriv(_) => {}
-            Plan::RevokeRole(_) => {}
+            Plan::SetRole(_) => {}
             Plan::ListStage(_) => {}
             Plan::CreateStage(_) => {}
             Plan::DropStage(_) => {}
             Plan::RemoveStage(_) => {}
             Plan::Presign(_) => {}
-            Plan::SetVariable(_) => {}
+            Plan::SetVariable(plan) => {
+                if plan.vars.iter().any(|var| var.is_global) {
+                    session
+                        .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                        .await?;
+                }
+            }
+            Plan::SetUserVariable(_) => {}
+            Plan::UnsetUserVariable(_) => {}
+            // The statements of the script are checked when they run.
+            Plan::ExecuteImmediate(_) => {}
             Plan::Kill(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
                     .await?;
             }
+            Plan::Begin => {}
+            Plan::Commit => {}
+            Plan::Rollback => {}
             Plan::CreateShare(_) => {}
             Plan::DropShare(_) => {}
             Plan::GrantShareObject(_) => {}
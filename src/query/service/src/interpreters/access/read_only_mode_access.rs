@@ -0,0 +1,94 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::OptimizeTableAction;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::plans::PresignAction;
+
+pub struct ReadOnlyModeAccess {
+    ctx: Arc<QueryContext>,
+}
+
+impl ReadOnlyModeAccess {
+    pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
+        Box::new(ReadOnlyModeAccess { ctx })
+    }
+}
+
+/// Whether the plan reads only, it writes no data and changes no schema, user or stage.
+pub fn is_read_only_plan(plan: &Plan) -> bool {
+    match plan {
+        Plan::Query { .. } | Plan::ExplainAst { .. } | Plan::ExplainSyntax { .. } => true,
+        // `EXPLAIN ANALYZE` runs the plan explained.
+        Plan::Explain { plan, .. } => is_read_only_plan(plan),
+
+        // Show.
+        Plan::ShowCreateDatabase(_)
+        | Plan::ShowCreateTable(_)
+        | Plan::DescribeTable(_)
+        | Plan::ExistsTable(_)
+        | Plan::ShowGrants(_)
+        | Plan::DescShare(_)
+        | Plan::ShowShares(_)
+        | Plan::ShowObjectGrantPrivileges(_)
+        | Plan::ShowGrantTenantsOfShare(_)
+        | Plan::ListStage(_) => true,
+
+        Plan::Presign(plan) => plan.action == PresignAction::Download,
+        Plan::OptimizeTable(plan) => plan.action == OptimizeTableAction::Verify,
+
+        // Session.
+        Plan::UseDatabase(_)
+        | Plan::SetRole(_)
+        | Plan::SetUserVariable(_)
+        | Plan::UnsetUserVariable(_)
+        | Plan::Kill(_)
+        | Plan::Begin
+        | Plan::Commit
+        | Plan::Rollback => true,
+        // The global settings are saved in the meta-service.
+        Plan::SetVariable(plan) => plan.vars.iter().all(|var| !var.is_global),
+
+        // Each statement of the script is checked as it runs.
+        Plan::ExecuteImmediate(_) => true,
+
+        // The nodes of the cluster are drained for maintenance, no data is written.
+        Plan::DrainNode(_) => true,
+
+        _ => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for ReadOnlyModeAccess {
+    // Check what we can do if in read only mode.
+    async fn check(&self, plan: &Plan) -> Result<()> {
+        if self.ctx.get_config().query.read_only && !is_read_only_plan(plan) {
+            return Err(ErrorCode::ReadOnlyModePermissionDenied(format!(
+                "Access denied for operation:{:?} in read-only mode, run it on a writable node",
+                plan.format_indent()
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -12,469 +12,328 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::hash::Hash;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
 
-use common_base::base::tokio::sync::Notify;
-use common_base::base::tokio::time::interval_at;
-use common_base::base::tokio::time::Duration;
-use common_base::base::tokio::time::Instant;
-use common_base::base::ProgressValues;
-use common_base::base::Runtime;
+use common_base::base::tokio;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserInfo;
+use futures::channel::oneshot;
 use parking_lot::Mutex;
-use parking_lot::RwLock;
+use tracing::error;
 
-use super::InsertInterpreterV2;
-use super::SelectInterpreterV2;
-use crate::pipelines::executor::PipelineCompleteExecutor;
-use crate::pipelines::processors::port::InputPort;
+use crate::interpreters::execute_pipeline;
+use crate::interpreters::InsertInterpreterV2;
+use crate::interpreters::Interpreter;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::BlocksSource;
-use crate::pipelines::SinkPipeBuilder;
 use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::BackgroundJob;
+use crate::sessions::BackgroundTask;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 use crate::sessions::Settings;
+use crate::sessions::TableContext;
 use crate::sql::plans::Insert;
 use crate::sql::plans::InsertInputSource;
-use crate::sql::plans::Plan;
+use crate::Config;
 
-#[derive(Clone)]
-pub struct InsertKey {
-    plan: Arc<Insert>,
-    // settings different with default settings
-    changed_settings: Arc<Settings>,
-}
-
-impl InsertKey {
-    pub fn get_serialized_changed_settings(&self) -> String {
-        let mut serialized_settings = String::new();
-        let values = self.changed_settings.get_setting_values();
-        for value in values.into_iter() {
-            let serialized = serde_json::to_string(&value).unwrap();
-            serialized_settings.push_str(&serialized);
-        }
-        serialized_settings
-    }
-}
-
-impl PartialEq for InsertKey {
-    fn eq(&self, other: &Self) -> bool {
-        self.plan.eq(&other.plan)
-            && self
-                .get_serialized_changed_settings()
-                .eq(&other.get_serialized_changed_settings())
-    }
-}
-
-impl Eq for InsertKey {}
+/// The background task flushing the inserts buffered by the async insert mode.
+pub const ASYNC_INSERT_FLUSH_TASK: &str = "async_insert_flush";
 
-impl Hash for InsertKey {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        let table = format!(
-            "{}.{}.{}",
-            self.plan.catalog, self.plan.database, self.plan.table
-        );
-        state.write(table.as_bytes());
-        let values = self.changed_settings.get_setting_values();
-        let serialized = serde_json::to_string(&values).unwrap();
-        state.write(serialized.as_bytes());
-    }
+/// The inserts with the same key are coalesced into a batch: they insert into the same columns
+/// of the same table, by the same user and roles, with the same settings.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InsertKey {
+    tenant: String,
+    user: String,
+    auth_role: Option<String>,
+    current_role: Option<String>,
+    catalog: String,
+    database: String,
+    table: String,
+    columns: Vec<String>,
+    // The settings different from the defaults, serialized.
+    changed_settings: String,
 }
 
 impl InsertKey {
-    pub fn try_create(plan: Arc<Insert>, changed_settings: Arc<Settings>) -> Self {
-        Self {
-            plan,
-            changed_settings,
-        }
+    pub fn create(ctx: &QueryContext, plan: &Insert) -> Result<InsertKey> {
+        let changed_settings = ctx.get_changed_settings().get_setting_values_short();
+        let session = ctx.get_current_session();
+        Ok(InsertKey {
+            tenant: ctx.get_tenant(),
+            user: ctx.get_current_user()?.identity().to_string(),
+            auth_role: session.get_auth_role(),
+            current_role: session.get_current_role(),
+            catalog: plan.catalog.clone(),
+            database: plan.database.clone(),
+            table: plan.table.clone(),
+            columns: plan
+                .schema
+                .fields()
+                .iter()
+                .map(|f| f.name().clone())
+                .collect(),
+            changed_settings: serde_json::to_string(&changed_settings)?,
+        })
     }
 }
 
-#[derive(Clone)]
-pub struct Entry {
+struct Entry {
     block: DataBlock,
-    notify: Arc<Notify>,
-    finished: Arc<RwLock<bool>>,
-    timeout: Arc<RwLock<bool>>,
-    error: Arc<RwLock<ErrorCode>>,
-}
-
-impl Entry {
-    pub fn try_create(block: DataBlock) -> Self {
-        Self {
-            block,
-            notify: Arc::new(Notify::new()),
-            finished: Arc::new(RwLock::new(false)),
-            timeout: Arc::new(RwLock::new(false)),
-            error: Arc::new(RwLock::new(ErrorCode::Ok(""))),
-        }
-    }
-
-    pub fn finish(&self) {
-        let mut finished = self.finished.write();
-        *finished = true;
-        self.notify.notify_one();
-    }
-
-    pub fn finish_with_err(&self, err: ErrorCode) {
-        let mut error = self.error.write();
-        *error = err;
-        self.notify.notify_one();
-    }
-
-    pub fn finish_with_timeout(&self) {
-        let mut timeout = self.timeout.write();
-        *timeout = true;
-        self.notify.notify_one();
-    }
-
-    pub async fn wait(&self) -> Result<()> {
-        if *self.finished.read() {
-            return Ok(());
-        }
-        self.notify.clone().notified().await;
-        match self.is_finished() {
-            true => Ok(()),
-            false => match self.is_timeout() {
-                true => Err(ErrorCode::AsyncInsertTimeoutError("Async insert timeout")),
-                false => Err((*self.error.read()).clone()),
-            },
-        }
-    }
-
-    pub fn is_finished(&self) -> bool {
-        return *self.finished.read();
-    }
-
-    pub fn is_timeout(&self) -> bool {
-        return *self.timeout.read();
-    }
+    // The result of the batch is sent to the insert waiting for it, if any.
+    tx: oneshot::Sender<Result<()>>,
 }
 
-#[derive(Clone)]
-pub struct InsertData {
-    entries: Vec<EntryPtr>,
+/// The blocks of the inserts of a key, written by one insert.
+pub struct InsertBatch {
+    // The plan of the first insert, to build the interpreter writing the batch.
+    plan: Insert,
+    // The user of the first insert, the batch is written on behalf of it.
+    user: UserInfo,
+    changed_settings: Arc<Settings>,
+    entries: Vec<Entry>,
+    rows: u64,
     data_size: u64,
     first_update: Instant,
     last_update: Instant,
 }
 
-impl InsertData {
-    pub fn try_create(
-        entries: Vec<EntryPtr>,
-        data_size: u64,
-        first_update: Instant,
-        last_update: Instant,
-    ) -> Self {
-        Self {
-            entries,
-            data_size,
-            first_update,
-            last_update,
+impl InsertBatch {
+    pub fn num_inserts(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn num_rows(&self) -> u64 {
+        self.rows
+    }
+
+    fn finish(self, res: Result<()>) {
+        for entry in self.entries {
+            // The insert may not wait for the result.
+            let _ = entry.tx.send(res.clone());
         }
     }
 }
 
-type EntryPtr = Arc<Entry>;
-type Queue = HashMap<InsertKey, InsertData>;
-type QueryIdToEntry = HashMap<String, EntryPtr>;
-
-#[derive(Clone)]
+/// Buffers the tiny inserts of `VALUES` in the memory and writes the inserts into the same table
+/// as one insert, to reduce the snapshots and the small blocks made by the frequent inserts.
+///
+/// A batch is written once it reaches `max_data_size` bytes or `max_rows` rows, `busy_timeout`
+/// after its first insert, or `stale_timeout` after its last insert if it's not zero.
 pub struct AsyncInsertQueue {
-    pub session_mgr: Arc<RwLock<Option<Arc<SessionManager>>>>,
-    runtime: Arc<Runtime>,
     max_data_size: u64,
+    max_rows: u64,
     busy_timeout: Duration,
     stale_timeout: Duration,
-    queue: Arc<RwLock<Queue>>,
-    current_processing_insert: Arc<RwLock<QueryIdToEntry>>,
+    queue: Mutex<HashMap<InsertKey, InsertBatch>>,
+    task: Arc<BackgroundTask>,
 }
 
 impl AsyncInsertQueue {
-    pub fn try_create(
-        // TODO(fkuner): maybe circular reference
-        session_mgr: Arc<RwLock<Option<Arc<SessionManager>>>>,
-        runtime: Arc<Runtime>,
+    pub fn create(
         max_data_size: u64,
+        max_rows: u64,
         busy_timeout: Duration,
         stale_timeout: Duration,
-    ) -> Self {
-        Self {
-            session_mgr,
-            runtime,
-            max_data_size,
-            busy_timeout,
-            stale_timeout,
-            queue: Arc::new(RwLock::new(Queue::default())),
-            current_processing_insert: Arc::new(RwLock::new(QueryIdToEntry::default())),
-        }
-    }
-
-    pub async fn start(self: Arc<Self>) {
-        let self_arc = self.clone();
-        // busy timeout
-        let busy_timeout = self_arc.busy_timeout;
-        self_arc.clone().runtime.as_ref().inner().spawn(async move {
-            let mut intv = interval_at(Instant::noew() + busy_timeout, busy_timeout);
-            loop {
-                intv.tick().await;
-                if self_arc.queue.read().is_empty() {
-                    continue;
-                }
-                let timeout = self_arc.clone().busy_check();
-                intv = interval(timeout);
-                if timeout != busy_timeout {
-                    intv = interval_at(Instant::now() + timeout, timeout);
-                }
+    ) -> Arc<AsyncInsertQueue> {
+        // The batches are checked twice per timeout, a batch is written at most half of the
+        // timeout late.
+        let timeout = match stale_timeout.is_zero() {
+            true => busy_timeout,
+            false => busy_timeout.min(stale_timeout),
+        };
+        let interval = (timeout / 2).max(Duration::from_millis(1));
+
+        Arc::new_cyclic(|queue| {
+            let job: Weak<dyn BackgroundJob> = queue.clone();
+            AsyncInsertQueue {
+                max_data_size,
+                max_rows,
+                busy_timeout,
+                stale_timeout,
+                queue: Mutex::new(HashMap::new()),
+                task: BackgroundTask::create(ASYNC_INSERT_FLUSH_TASK, "Flush", interval, job),
             }
-        });
-        // stale timeout
-        let stale_timeout = self.stale_timeout;
-        if !stale_timeout.is_zero() {
-            self.clone().runtime.as_ref().inner().spawn(async move {
-                let mut intv = interval_at(Instant::now() + stale_timeout, stale_timeout);
-                loop {
-                    intv.tick().await;
-                    if self.queue.read().is_empty() {
-                        continue;
-                    }
-                    let timeout = self.clone().stale_check();
-                    if timeout != busy_timeout {
-                        intv = interval_at(Instant::now() + timeout, timeout);
-                    }
-                }
-            });
-        }
+        })
     }
 
-    pub async fn push(self: Arc<Self>, plan: Arc<Insert>, ctx: Arc<QueryContext>) -> Result<()> {
-        let self_arc = self.clone();
-        let plan = plan.clone();
-        let settings = ctx.get_changed_settings();
-
-        let data_block = match &plan.source {
-            InsertInputSource::SelectPlan(plan) => {
-                let select_interpreter = match &**plan {
-                    Plan::Query {
-                        s_expr,
-                        metadata,
-                        bind_context,
-                        ..
-                    } => SelectInterpreterV2::try_create(
-                        ctx.clone(),
-                        *bind_context.clone(),
-                        s_expr.clone(),
-                        metadata.clone(),
-                    ),
-                    _ => unreachable!(),
-                };
-
-                let mut pipeline = select_interpreter?.create_new_pipeline().await?;
-
-                let mut sink_pipeline_builder = SinkPipeBuilder::create();
-                for _ in 0..pipeline.output_len() {
-                    let input_port = InputPort::create();
-                    sink_pipeline_builder.add_sink(
-                        input_port.clone(),
-                        ContextSink::create(input_port, ctx.clone()),
-                    );
-                }
-                pipeline.add_pipe(sink_pipeline_builder.finalize());
-
-                let query_need_abort = ctx.query_need_abort();
-                let executor = PipelineCompleteExecutor::try_create(
-                    self.runtime.clone(),
-                    query_need_abort,
-                    pipeline,
-                )?;
-                executor.execute()?;
-                drop(executor);
-                let blocks = ctx.consume_precommit_blocks();
-                DataBlock::concat_blocks(&blocks)?
-            }
-            InsertInputSource::StreamingWithFormat(_) => todo!(),
-            InsertInputSource::Values(values) => {
-                let data_block = values.block.clone();
+    pub fn from_config(conf: &Config) -> Arc<AsyncInsertQueue> {
+        AsyncInsertQueue::create(
+            conf.query.async_insert_max_data_size,
+            conf.query.async_insert_max_rows,
+            Duration::from_millis(conf.query.async_insert_busy_timeout),
+            Duration::from_millis(conf.query.async_insert_stale_timeout),
+        )
+    }
 
-                let progress_values = ProgressValues {
-                    rows: data_block.num_rows(),
-                    bytes: data_block.memory_size(),
-                };
-                ctx.get_scan_progress().incr(&progress_values);
+    /// The task flushing the batches due.
+    pub fn task(&self) -> Arc<BackgroundTask> {
+        self.task.clone()
+    }
 
-                data_block
-            }
+    /// Buffers the block of the insert, returns the receiver of the result of its batch. The
+    /// flush is woken up once the batch is full.
+    pub fn push(
+        &self,
+        ctx: &QueryContext,
+        plan: &Insert,
+        block: DataBlock,
+    ) -> Result<oneshot::Receiver<Result<()>>> {
+        let key = InsertKey::create(ctx, plan)?;
+        let user = ctx.get_current_user()?;
+        let (tx, rx) = oneshot::channel();
+        let rows = block.num_rows() as u64;
+        let data_size = block.memory_size() as u64;
+        let now = Instant::now();
+
+        let full = {
+            let mut queue = self.queue.lock();
+            let batch = queue.entry(key).or_insert_with(|| InsertBatch {
+                plan: Insert {
+                    source: InsertInputSource::Values(String::new()),
+                    ..plan.clone()
+                },
+                user,
+                changed_settings: ctx.get_changed_settings(),
+                entries: vec![],
+                rows: 0,
+                data_size: 0,
+                first_update: now,
+                last_update: now,
+            });
+            batch.entries.push(Entry { block, tx });
+            batch.rows += rows;
+            batch.data_size += data_size;
+            batch.last_update = now;
+            self.is_full(batch)
         };
 
-        let entry = Arc::new(Entry::try_create(data_block.clone()));
-        let key = InsertKey::try_create(plan, settings);
-
-        let mut queue = self_arc.queue.write();
-
-        match queue.get_mut(&key) {
-            Some(value) => {
-                value.entries.push(entry.clone());
-                value.data_size += data_block.memory_size() as u64;
-                value.last_update = Instant::now();
-                if value.data_size > self_arc.max_data_size {
-                    self_arc.clone().schedule(key.clone(), value.clone());
-                    queue.remove(&key);
-                }
-            }
-            None => {
-                let entries = vec![entry.clone()];
-                let data_size = data_block.memory_size();
-                let first_update = Instant::now();
-                let last_update = Instant::now();
-                let value =
-                    InsertData::try_create(entries, data_size as u64, first_update, last_update);
-                queue.insert(key.clone(), value.clone());
-                if data_size > self_arc.max_data_size as usize {
-                    self_arc.clone().schedule(key.clone(), value);
-                    queue.remove(&key);
-                }
-            }
+        if full {
+            self.task.wake();
         }
-        let mut current_processing_insert = self.current_processing_insert.write();
-        current_processing_insert.insert(ctx.get_id(), entry);
-        Ok(())
-    }
-
-    pub fn get_entry(&self, query_id: &str) -> Result<EntryPtr> {
-        let current_processing_insert = self.current_processing_insert.read();
-        Ok(current_processing_insert.get(query_id).unwrap().clone())
-    }
-
-    pub fn delete_entry(&self, query_id: &str) -> Result<()> {
-        let mut current_processing_insert = self.current_processing_insert.write();
-        current_processing_insert.remove(query_id);
-        Ok(())
+        Ok(rx)
     }
 
-    pub async fn wait_for_processing_insert(
-        self: Arc<Self>,
-        query_id: String,
-        time_out: Duration,
-    ) -> Result<()> {
-        let entry = self.get_entry(&query_id)?;
-        let e = entry.clone();
-        self.runtime.as_ref().inner().spawn(async move {
-            let mut intv = interval_at(Instant::now() + time_out, time_out);
-            intv.tick().await;
-            e.finish_with_timeout();
-        });
-        match entry.wait().await {
-            Ok(_) => {
-                self.delete_entry(&query_id)?;
-                Ok(())
-            }
-            Err(err) => {
-                self.delete_entry(&query_id)?;
-                Err(err)
-            }
+    /// Waits for the result of the batch of an insert, the insert is still written if the wait
+    /// times out.
+    pub async fn wait(rx: oneshot::Receiver<Result<()>>, timeout: Duration) -> Result<()> {
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(res)) => res,
+            Ok(Err(_)) => Err(ErrorCode::AbortedQuery(
+                "the batch of the async insert is dropped",
+            )),
+            Err(_) => Err(ErrorCode::AsyncInsertTimeoutError(format!(
+                "the async insert is not written in {:?}",
+                timeout
+            ))),
         }
     }
 
-    fn schedule(self: Arc<Self>, key: InsertKey, data: InsertData) {
-        self.runtime.as_ref().inner().spawn(async {
-            match self.process(key, data.clone()).await {
-                Ok(_) => {
-                    for entry in data.entries.into_iter() {
-                        entry.finish();
-                    }
-                }
-                Err(err) => {
-                    for entry in data.entries.into_iter() {
-                        entry.finish_with_err(err.clone());
-                    }
-                }
-            }
-        });
+    fn is_full(&self, batch: &InsertBatch) -> bool {
+        batch.data_size >= self.max_data_size || batch.rows >= self.max_rows
     }
 
-    async fn process(self: Arc<Self>, key: InsertKey, data: InsertData) -> Result<()> {
-        let insert_plan = key.plan;
-
-        let session_mgr = self.session_mgr.read().clone().unwrap();
-        let session = session_mgr.create_session(SessionType::HTTPQuery).await;
-        let ctx = session.unwrap().create_query_context().await?;
-        ctx.apply_changed_settings(key.changed_settings.clone())?;
-
-        let interpreter =
-            InsertInterpreterV2::try_create(ctx.clone(), insert_plan.as_ref().clone(), true)?;
-
-        let output_port = OutputPort::create();
-        let blocks = Arc::new(Mutex::new(VecDeque::from_iter(
-            data.entries.iter().map(|x| x.block.clone()),
-        )));
-        let source = BlocksSource::create(ctx, output_port.clone(), blocks)?;
-        let mut builder = SourcePipeBuilder::create();
-        builder.add_source(output_port.clone(), source);
-
-        interpreter
-            .as_ref()
-            .set_source_pipe_builder(Some(builder))?;
-        interpreter.execute(None).await?;
-        Ok(())
+    fn is_due(&self, batch: &InsertBatch, now: Instant) -> bool {
+        self.is_full(batch)
+            || now.duration_since(batch.first_update) >= self.busy_timeout
+            || (!self.stale_timeout.is_zero()
+                && now.duration_since(batch.last_update) >= self.stale_timeout)
     }
 
-    fn busy_check(self: Arc<Self>) -> Duration {
-        let mut keys = Vec::new();
-        let mut queue = self.queue.write();
-
-        let mut timeout = self.busy_timeout;
+    /// Takes the batches to write at `now`.
+    pub fn take_due(&self, now: Instant) -> Vec<(InsertKey, InsertBatch)> {
+        let mut queue = self.queue.lock();
+        let keys = queue
+            .iter()
+            .filter(|(_, batch)| self.is_due(batch, now))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        keys.into_iter()
+            .filter_map(|key| queue.remove(&key).map(|batch| (key, batch)))
+            .collect()
+    }
 
-        for (key, value) in queue.iter() {
-            if value.data_size == 0 {
-                continue;
-            }
-            let time_lag = Instant::now() - value.first_update;
-            if time_lag.cmp(&self.clone().busy_timeout) == Ordering::Greater {
-                self.clone().schedule(key.clone(), value.clone());
-                keys.push(key.clone());
-            } else {
-                timeout = timeout.min(self.busy_timeout - time_lag);
+    /// Writes the batches due, the result of each batch is sent to its inserts.
+    pub async fn flush(&self) -> Result<u64> {
+        let mut written_rows = 0;
+        let mut last_cause = None;
+        for (key, batch) in self.take_due(Instant::now()) {
+            let res = Self::write_batch(&key, &batch).await;
+            match &res {
+                Ok(_) => written_rows += batch.rows,
+                Err(cause) => {
+                    error!(
+                        "fail to write {} async inserts into {}.{}: {:?}",
+                        batch.num_inserts(),
+                        key.database,
+                        key.table,
+                        cause
+                    );
+                    last_cause = Some(cause.clone());
+                }
             }
+            batch.finish(res);
         }
 
-        for key in keys.iter() {
-            queue.remove(key);
+        match last_cause {
+            None => Ok(written_rows),
+            Some(cause) => Err(cause),
         }
-
-        timeout
     }
 
-    fn stale_check(self: Arc<Self>) -> Duration {
-        let mut keys = Vec::new();
-        let mut queue = self.queue.write();
-
-        let mut timeout = self.busy_timeout;
-
-        for (key, value) in queue.iter() {
-            if value.data_size == 0 {
-                continue;
-            }
-            let time_lag = Instant::now() - value.last_update;
-            if time_lag.cmp(&self.clone().stale_timeout) == Ordering::Greater {
-                self.clone().schedule(key.clone(), value.clone());
-                keys.push(key.clone());
-            } else {
-                timeout = timeout.min(self.stale_timeout - time_lag);
-            }
+    async fn write_batch(key: &InsertKey, batch: &InsertBatch) -> Result<()> {
+        // The batch is checked by each insert of it, it's neither queued nor checked again.
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("AsyncInsert".to_string()))
+            .await?;
+        session.set_current_tenant(key.tenant.clone());
+        session.set_current_user(batch.user.clone());
+        if let Some(auth_role) = &key.auth_role {
+            session.set_auth_role(auth_role.clone());
         }
+        session.set_current_role(key.current_role.clone());
+        let ctx = session.create_query_context().await?;
+        ctx.apply_changed_settings(batch.changed_settings.clone())?;
+
+        let blocks = batch
+            .entries
+            .iter()
+            .map(|entry| entry.block.clone())
+            .collect::<Vec<_>>();
+        let block = DataBlock::concat_blocks(&blocks)?;
+
+        let output = OutputPort::create();
+        let blocks = Arc::new(Mutex::new(VecDeque::from(vec![block])));
+        let source = BlocksSource::create(ctx.clone(), output.clone(), blocks)?;
+        let mut builder = SourcePipeBuilder::create();
+        builder.add_source(output, source);
 
-        for key in keys.iter() {
-            queue.remove(key);
-        }
+        let interpreter = InsertInterpreterV2::try_create(ctx.clone(), batch.plan.clone(), true)?;
+        interpreter.set_source_pipe_builder(Some(builder))?;
+        let build_res = interpreter.execute2().await?;
+        execute_pipeline(ctx, build_res)
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundJob for AsyncInsertQueue {
+    async fn run(&self) -> Result<String> {
+        let written_rows = self.flush().await?;
+        Ok(format!("{} rows written", written_rows))
+    }
 
-        timeout
+    fn progress(&self) -> String {
+        let queue = self.queue.lock();
+        let rows = queue.values().map(|batch| batch.rows).sum::<u64>();
+        format!("{} rows of {} tables buffered", rows, queue.len())
     }
 }
@@ -19,5 +19,6 @@ mod v2;
 pub use query_fragment_actions::QueryFragmentAction;
 pub use query_fragment_actions::QueryFragmentActions;
 pub use query_fragment_actions::QueryFragmentsActions;
+pub use v2::ExecutorsHashRing;
 pub use v2::Fragmenter;
 pub use v2::PlanFragment;
@@ -30,6 +30,7 @@ use crate::api::ExecutePartialQueryPacket;
 use crate::api::FragmentPayload;
 use crate::api::FragmentPlanPacket;
 use crate::api::InitNodesChannelPacket;
+use crate::api::KillQueryPacket;
 use crate::api::QueryFragmentsPlanPacket;
 use crate::clusters::ClusterHelper;
 use crate::sessions::QueryContext;
@@ -108,17 +109,30 @@ impl QueryFragmentActions {
 
 pub struct QueryFragmentsActions {
     ctx: Arc<QueryContext>,
+    query_id: String,
     pub fragments_actions: Vec<QueryFragmentActions>,
 }
 
 impl QueryFragmentsActions {
     pub fn create(ctx: Arc<QueryContext>) -> QueryFragmentsActions {
+        let query_id = ctx.get_id();
+        QueryFragmentsActions::create_with_query_id(ctx, query_id)
+    }
+
+    /// The query is known as the `query_id` in the cluster instead of the id of the
+    /// context, e.g. the query rescheduled on the surviving nodes after a node failure.
+    pub fn create_with_query_id(ctx: Arc<QueryContext>, query_id: String) -> QueryFragmentsActions {
         QueryFragmentsActions {
             ctx,
+            query_id,
             fragments_actions: Vec::new(),
         }
     }
 
+    pub fn get_query_id(&self) -> String {
+        self.query_id.clone()
+    }
+
     pub fn get_executors(&self) -> Vec<String> {
         let cluster = self.ctx.get_cluster();
         let cluster_nodes = cluster.get_nodes();
@@ -177,7 +191,7 @@ impl QueryFragmentsActions {
 
         let cluster = self.ctx.get_cluster();
         let local_query_fragments_plan_packet = QueryFragmentsPlanPacket::create(
-            self.ctx.get_id(),
+            self.query_id.clone(),
             cluster.local_id.clone(),
             fragments_packets.remove(&cluster.local_id).unwrap(),
             nodes_info.clone(),
@@ -185,7 +199,7 @@ impl QueryFragmentsActions {
         );
 
         for (executor, fragments) in fragments_packets.into_iter() {
-            let query_id = self.ctx.get_id();
+            let query_id = self.query_id.clone();
             let executors_info = nodes_info.clone();
 
             query_fragments_plan_packets.push(QueryFragmentsPlanPacket::create(
@@ -240,7 +254,7 @@ impl QueryFragmentsActions {
             }
 
             init_nodes_channel_packets.push(InitNodesChannelPacket::create(
-                self.ctx.get_id(),
+                self.query_id.clone(),
                 executor_node_info.clone(),
                 connections_info,
             ));
@@ -255,7 +269,7 @@ impl QueryFragmentsActions {
 
         for node_id in nodes_info.keys() {
             execute_partial_query_packets.push(ExecutePartialQueryPacket::create(
-                self.ctx.get_id(),
+                self.query_id.clone(),
                 node_id.to_owned(),
                 nodes_info.clone(),
             ));
@@ -264,6 +278,25 @@ impl QueryFragmentsActions {
         Ok(execute_partial_query_packets)
     }
 
+    /// The packets to shutdown the query on the other nodes, the local node is not included.
+    pub fn get_kill_query_packets(&self) -> Result<Vec<KillQueryPacket>> {
+        let local_id = self.get_local_executor();
+        let nodes_info = Self::nodes_info(&self.ctx);
+        let mut kill_query_packets = Vec::with_capacity(nodes_info.len());
+
+        for node_id in nodes_info.keys() {
+            if node_id != &local_id {
+                kill_query_packets.push(KillQueryPacket::create(
+                    self.query_id.clone(),
+                    node_id.to_owned(),
+                    nodes_info.clone(),
+                ));
+            }
+        }
+
+        Ok(kill_query_packets)
+    }
+
     /// unique map(source, map(target, vec(fragment_id)))
     fn fragments_connections(&self) -> HashMap<String, HashMap<String, Vec<usize>>> {
         let mut source_target_fragments = HashMap::<String, HashMap<String, Vec<usize>>>::new();
@@ -62,6 +62,7 @@ impl<'a> Display for QueryFragmentActionsWrap<'a> {
             match data_exchange {
                 DataExchange::Merge(_) => writeln!(f, "  DataExchange: Merge")?,
                 DataExchange::Broadcast(_) => writeln!(f, "  DataExchange: Broadcast")?,
+                DataExchange::RoundRobin(_) => writeln!(f, "  DataExchange: RoundRobin")?,
                 DataExchange::ShuffleDataExchange(_) => writeln!(f, "  DataExchange: Shuffle")?,
                 DataExchange::ShuffleDataExchangeV2(_) => writeln!(f, "  DataExchange: Shuffle")?,
             }
@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+/// The consistent hashing ring of the executors, most of the hashes are located to the same
+/// executors when the executors are changed.
+///
+/// The virtual nodes, as well as the locations of the parts, are hashed by the `DefaultHasher`,
+/// which is created with fixed keys, so the ring is the same for the same executors in a build.
+/// Its algorithm may change between the releases of Rust, the nodes of different builds may
+/// locate the same parts to different executors, which only misses the cached data.
+pub struct ExecutorsHashRing {
+    // The hashes of the virtual nodes and the indexes of the executors, ordered by the hashes.
+    ring: Vec<(u64, usize)>,
+    executors: usize,
+}
+
+impl ExecutorsHashRing {
+    // The virtual nodes of each executor to balance the hashes located to the executors.
+    const VIRTUAL_NODES: usize = 64;
+
+    // The load of an executor is bounded to the average load times the factor.
+    const LOAD_FACTOR: f64 = 1.25;
+
+    pub fn create(executors: &[String]) -> ExecutorsHashRing {
+        let mut ring = Vec::with_capacity(executors.len() * Self::VIRTUAL_NODES);
+        for (index, executor) in executors.iter().enumerate() {
+            for virtual_node in 0..Self::VIRTUAL_NODES {
+                let mut hasher = DefaultHasher::new();
+                (executor, virtual_node).hash(&mut hasher);
+                ring.push((hasher.finish(), index));
+            }
+        }
+
+        ring.sort_unstable();
+        ExecutorsHashRing {
+            ring,
+            executors: executors.len(),
+        }
+    }
+
+    // Locates the index of the executor of the first virtual node clockwise from the hash.
+    pub fn locate(&self, hash: u64) -> usize {
+        self.ring[self.position(hash)].1
+    }
+
+    /// Locates the hashes with the bounded loads, the executor of a hash is the first one
+    /// clockwise from the hash that is not full, an executor is full if it holds the average
+    /// load times `LOAD_FACTOR`. The hashes of the hot locations are spilled to the next
+    /// executors on the ring instead of overloading one executor.
+    pub fn locate_bounded(&self, hashes: &[u64]) -> Vec<usize> {
+        let capacity = Self::capacity(hashes.len(), self.executors);
+        let mut loads = vec![0; self.executors];
+
+        hashes
+            .iter()
+            .map(|hash| {
+                let mut position = self.position(*hash);
+                while loads[self.ring[position].1] >= capacity {
+                    position = (position + 1) % self.ring.len();
+                }
+
+                let executor = self.ring[position].1;
+                loads[executor] += 1;
+                executor
+            })
+            .collect()
+    }
+
+    pub fn capacity(hashes: usize, executors: usize) -> usize {
+        let average = hashes as f64 / executors as f64;
+        ((average * Self::LOAD_FACTOR).ceil() as usize).max(1)
+    }
+
+    fn position(&self, hash: u64) -> usize {
+        let position = self
+            .ring
+            .partition_point(|(node_hash, _)| *node_hash < hash);
+        position % self.ring.len()
+    }
+}
@@ -23,6 +23,7 @@ use super::PlanFragment;
 use crate::api::BroadcastExchange;
 use crate::api::DataExchange;
 use crate::api::MergeExchange;
+use crate::api::RoundRobinExchange;
 use crate::api::ShuffleDataExchangeV2;
 use crate::clusters::ClusterHelper;
 use crate::sessions::QueryContext;
@@ -47,7 +48,12 @@ pub struct Fragmenter {
 impl Fragmenter {
     pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
         let query_id = ctx.get_id();
+        Self::try_create_with_query_id(ctx, query_id)
+    }
 
+    /// The fragments are known as the fragments of the `query_id` in the cluster,
+    /// see `QueryFragmentsActions::create_with_query_id()`.
+    pub fn try_create_with_query_id(ctx: Arc<QueryContext>, query_id: String) -> Result<Self> {
         Ok(Self {
             ctx,
             fragments: vec![],
@@ -87,6 +93,9 @@ impl Fragmenter {
                     from_multiple_nodes,
                     Self::get_executors(ctx),
                 ))),
+                StageKind::RoundRobin => {
+                    Ok(Some(RoundRobinExchange::create(Self::get_executors(ctx))))
+                }
             },
             _ => Ok(None),
         }
@@ -148,6 +157,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
+            stat_info: plan.stat_info.clone(),
         }))
     }
 
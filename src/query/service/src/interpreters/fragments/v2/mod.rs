@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod executors_hash_ring;
 mod fragmenter;
 mod plan_fragment;
 
+pub use executors_hash_ring::ExecutorsHashRing;
 pub use fragmenter::Fragmenter;
 pub use plan_fragment::FragmentType;
 pub use plan_fragment::PlanFragment;
@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_legacy_planners::ReadDataSourcePlan;
 
+use super::ExecutorsHashRing;
 use super::Fragmenter;
 use crate::api::DataExchange;
 use crate::interpreters::QueryFragmentAction;
@@ -131,13 +132,33 @@ impl PlanFragment {
         // Redistribute partitions of ReadDataSourcePlan.
         let mut fragment_actions = QueryFragmentActions::create(true, self.fragment_id);
 
-        let partitions = &read_source.parts;
+        // The partitions of the locations are assigned to the executors by the consistent
+        // hashing, so that the data cached by the executors in the previous reads are reused,
+        // even if some executors join or leave the cluster.
+        let (located_partitions, partitions): (Vec<_>, Vec<_>) = read_source
+            .parts
+            .iter()
+            .cloned()
+            .partition(|part| part.location_hash().is_some());
+
+        let mut executors_parts = vec![vec![]; executors.len()];
+        let executors_ring = ExecutorsHashRing::create(&executors);
+        let hashes = located_partitions
+            .iter()
+            .filter_map(|part| part.location_hash())
+            .collect::<Vec<_>>();
+        let located_executors = executors_ring.locate_bounded(&hashes);
+        for (part, executor) in located_partitions.into_iter().zip(located_executors) {
+            executors_parts[executor].push(part);
+        }
+
         let parts_per_node = partitions.len() / executors.len();
 
         for (index, executor) in executors.iter().enumerate() {
             let begin = parts_per_node * index;
             let end = parts_per_node * (index + 1);
-            let mut parts = partitions[begin..end].to_vec();
+            let mut parts = std::mem::take(&mut executors_parts[index]);
+            parts.extend_from_slice(&partitions[begin..end]);
 
             if index == executors.len() - 1 {
                 // For some irregular partitions, we assign them to the last node
@@ -206,6 +227,7 @@ impl PhysicalPlanReplacer for ReplaceReadSource {
             source: Box::new(self.source.clone()),
             name_mapping: plan.name_mapping.clone(),
             table_index: plan.table_index,
+            stat_info: plan.stat_info.clone(),
         }))
     }
 }
@@ -24,8 +24,13 @@ use common_exception::Result;
 use common_streams::DataBlockStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
+use parking_lot::Mutex;
+use tracing::info_span;
+use tracing::Instrument;
 
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterSlowQueryLog;
 use crate::interpreters::PullingExecutorStream;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -33,6 +38,7 @@ use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SourcePipeBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::ResourceGroupQueues;
 use crate::sessions::SessionManager;
 
 #[async_trait::async_trait]
@@ -51,7 +57,11 @@ pub trait Interpreter: Sync + Send {
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
         log_query_start(&ctx);
 
-        let mut build_res = match self.execute2().await {
+        // The pipeline is built and executed in the stages of the span of the query, the spans
+        // of the processors and of the fragments executed by the cluster peers are their children.
+        let query_span = ctx.get_query_span();
+        let build_span = info_span!(parent: &query_span, "build_pipeline");
+        let mut build_res = match self.execute2().instrument(build_span).await {
             Ok(build_res) => build_res,
             Err(build_error) => {
                 log_query_finished(&ctx, Some(build_error.clone()));
@@ -69,8 +79,32 @@ pub trait Interpreter: Sync + Send {
             )));
         }
 
+        // The queries executing the pipelines wait in the queue of their resource group and then
+        // the query queue, the permits are released when the pipeline is finished or dropped.
+        let session_manager = SessionManager::instance();
+        let admitted = async {
+            let group = ResourceGroupQueues::get_group(&ctx).await?;
+            let group_permit = match &group {
+                None => None,
+                Some(group) => {
+                    let queues = &session_manager.resource_group_queues;
+                    queues.acquire_for_query(&ctx, group).await?
+                }
+            };
+            let permit = session_manager.query_queue.acquire_for_query(&ctx).await?;
+            Ok::<_, ErrorCode>((group, group_permit, permit))
+        };
+        let (group, permits) = match admitted.await {
+            Ok((group, group_permit, permit)) => (group, Mutex::new(Some((group_permit, permit)))),
+            Err(queue_error) => {
+                log_query_finished(&ctx, Some(queue_error.clone()));
+                return Err(queue_error);
+            }
+        };
+
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            permits.lock().take();
             log_query_finished(&query_ctx, may_error.clone());
 
             match may_error {
@@ -79,15 +113,25 @@ pub trait Interpreter: Sync + Send {
             }
         });
 
+        // The queries of the resource group execute with the cpu_share of the max_threads.
         let settings = ctx.get_settings();
-        build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings)?;
+        let max_threads = match &group {
+            None => settings.get_max_threads()?,
+            Some(group) => group.max_threads(settings.get_max_threads()?),
+        };
+        build_res.set_max_threads(max_threads as usize);
+        let settings = ExecutorSettings::try_create(&settings)?
+            .with_max_execute_time(ctx.get_query_max_execution_time())
+            .with_max_memory_usage(ctx.get_query_max_memory_usage())
+            .with_query_memory_tracker(ctx.get_memory_tracker());
 
+        let execute_span = info_span!(parent: &query_span, "execute");
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
             pipelines.push(build_res.main_pipeline);
 
-            let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+            let complete_executor = execute_span
+                .in_scope(|| PipelineCompleteExecutor::from_pipelines(pipelines, settings))?;
 
             ctx.set_executor(Arc::downgrade(&complete_executor.get_inner()));
             complete_executor.execute()?;
@@ -100,10 +144,14 @@ pub trait Interpreter: Sync + Send {
 
         // WTF: We need to implement different logic for the HTTP handler
         if let Some(handle) = ctx.get_http_query() {
-            return handle.execute(ctx.clone(), build_res, self.schema()).await;
+            return handle
+                .execute(ctx.clone(), build_res, self.schema())
+                .instrument(execute_span)
+                .await;
         }
 
-        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        let pulling_executor = execute_span
+            .in_scope(|| PipelinePullingExecutor::from_pipelines(build_res, settings))?;
 
         ctx.set_executor(Arc::downgrade(&pulling_executor.get_inner()));
         Ok(Box::pin(ProgressStream::try_create(
@@ -147,6 +195,8 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    InterpreterAuditLog::log_query(ctx, error.as_ref());
+    InterpreterSlowQueryLog::log_query(ctx, error.as_ref());
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         tracing::error!("interpreter.finish.error: {:?}", error)
     }
@@ -0,0 +1,161 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_planner::plans::AggregatingIndexDefinition;
+use common_planner::plans::CreateAggregatingIndexPlan;
+
+use crate::interpreters::refresh_aggregating_index;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_AGGREGATING_INDEXES;
+use crate::sql::OPT_KEY_AGGREGATING_INDEX_DEFINITION;
+use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::storages::fuse::FuseTable;
+
+pub struct CreateAggregatingIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateAggregatingIndexPlan,
+}
+
+impl CreateAggregatingIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateAggregatingIndexPlan) -> Result<Self> {
+        Ok(CreateAggregatingIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateAggregatingIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateAggregatingIndexInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table_info = fuse_table.get_table_info();
+
+        let mut indexes = FuseTable::parse_aggregating_indexes(table_info.options());
+        if indexes.contains(&plan.index_name) {
+            return match plan.if_not_exists {
+                true => Ok(PipelineBuildResult::create()),
+                false => Err(ErrorCode::AggregatingIndexAlreadyExists(format!(
+                    "aggregating index {} already exists on {}.{}",
+                    plan.index_name, plan.database, plan.table
+                ))),
+            };
+        }
+
+        // The index is a FUSE table in the database of the table, the data of which is
+        // overwritten by the query of the index whenever the table is changed.
+        let schema = aggregating_index_schema(&plan.definition, table_info.schema().as_ref())?;
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_AGGREGATING_INDEX_DEFINITION.to_string(),
+            plan.definition.to_table_option().map_err(|e| {
+                ErrorCode::IllegalAggregatingIndexFormat(format!(
+                    "invalid aggregating index definition: {}",
+                    e
+                ))
+            })?,
+        );
+        if let Some(database_id) = table_info.options().get(OPT_KEY_DATABASE_ID) {
+            options.insert(OPT_KEY_DATABASE_ID.to_string(), database_id.clone());
+        }
+        let req = CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.index_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(schema),
+                engine: "FUSE".to_string(),
+                options,
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        indexes.push(plan.index_name.clone());
+        let req = UpsertTableOptionReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_AGGREGATING_INDEXES.to_string(),
+                Some(indexes.join(",")),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        refresh_aggregating_index(
+            self.ctx.clone(),
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+            &plan.index_name,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// The group by columns keep the types of the table, the aggregates take the types of their
+/// results.
+fn aggregating_index_schema(
+    definition: &AggregatingIndexDefinition,
+    table_schema: &DataSchema,
+) -> Result<DataSchema> {
+    let mut fields = Vec::with_capacity(definition.group_by.len() + definition.aggregates.len());
+    for column in &definition.group_by {
+        let field = table_schema.field_with_name(column)?;
+        fields.push(DataField::new(column, field.data_type().clone()));
+    }
+    for aggregate in &definition.aggregates {
+        let arguments = match &aggregate.column {
+            Some(column) => vec![table_schema.field_with_name(column)?.clone()],
+            None => vec![],
+        };
+        let func =
+            AggregateFunctionFactory::instance().get(&aggregate.func_name, vec![], arguments)?;
+        fields.push(DataField::new(
+            &aggregate.column_name(),
+            func.return_type()?,
+        ));
+    }
+    Ok(DataSchema::new(fields))
+}
@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_planner::plans::DropAggregatingIndexPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_AGGREGATING_INDEXES;
+use crate::storages::fuse::FuseTable;
+
+pub struct DropAggregatingIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropAggregatingIndexPlan,
+}
+
+impl DropAggregatingIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropAggregatingIndexPlan) -> Result<Self> {
+        Ok(DropAggregatingIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropAggregatingIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropAggregatingIndexInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        let table_info = table.get_table_info();
+
+        let mut indexes = FuseTable::parse_aggregating_indexes(table_info.options());
+        if !indexes.contains(&plan.index_name) {
+            return match plan.if_exists {
+                true => Ok(PipelineBuildResult::create()),
+                false => Err(ErrorCode::UnknownAggregatingIndex(format!(
+                    "unknown aggregating index {} on {}.{}",
+                    plan.index_name, plan.database, plan.table
+                ))),
+            };
+        }
+
+        // The index is detached from the table before dropped, the queries never read a
+        // dropped index.
+        indexes.retain(|index| index != &plan.index_name);
+        let value = match indexes.is_empty() {
+            true => None,
+            false => Some(indexes.join(",")),
+        };
+        let req = UpsertTableOptionReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: HashMap::from([(OPT_KEY_AGGREGATING_INDEXES.to_string(), value)]),
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        let req = DropTableReq {
+            if_exists: true,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.index_name.clone(),
+            },
+        };
+        catalog.drop_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
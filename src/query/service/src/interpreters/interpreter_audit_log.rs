@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_preludes::system::AuditLogElement;
+use common_storages_preludes::system::AuditLogQueue;
+use serde_json;
+use tracing::error;
+use tracing::info;
+
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+use crate::Config;
+
+pub struct InterpreterAuditLog;
+
+/// The category of the query in the audit log by its kind, the name of its plan, the
+/// queries of no category, e.g. `Query`, are not audited.
+pub fn audit_category(query_kind: &str) -> Option<&'static str> {
+    match query_kind {
+        "CreateUser"
+        | "AlterUser"
+        | "DropUser"
+        | "CreateRole"
+        | "DropRole"
+        | "GrantRole"
+        | "GrantPriv"
+        | "RevokePriv"
+        | "RevokeRole"
+        | "GrantShareObject"
+        | "RevokeShareObject"
+        | "AlterShareTenants"
+        | "CreateRowAccessPolicy"
+        | "DropRowAccessPolicy"
+        | "AddTableRowAccessPolicy"
+        | "DropTableRowAccessPolicy"
+        | "CreateMaskingPolicy"
+        | "DropMaskingPolicy"
+        | "SetTableColumnMaskingPolicy"
+        | "UnsetTableColumnMaskingPolicy"
+        | "CreateNetworkPolicy"
+        | "DropNetworkPolicy"
+        | "CreateResourceGroup"
+        | "DropResourceGroup" => Some("privilege"),
+        "Insert" | "Delete" | "Copy" | "TruncateTable" | "RemoveStage" => Some("dml"),
+        kind if ["Create", "Drop", "Undrop", "Rename", "Alter", "Attach"]
+            .iter()
+            .any(|prefix| kind.starts_with(prefix))
+            || matches!(
+                kind,
+                "SetTableOptions"
+                    | "SetDatabaseComment"
+                    | "SetTableColumnComment"
+                    | "AddTableColumn"
+                    | "AddTableVirtualColumn"
+                    | "ReclusterTable"
+                    | "OptimizeTable"
+            ) =>
+        {
+            Some("ddl")
+        }
+        _ => None,
+    }
+}
+
+fn audited(config: &Config, category: &str) -> bool {
+    config.query.audit_log_enabled
+        && config
+            .query
+            .audit_log_filter
+            .split(',')
+            .any(|c| c.trim().eq_ignore_ascii_case(category))
+}
+
+impl InterpreterAuditLog {
+    fn write_log(config: &Config, event: AuditLogElement) -> Result<()> {
+        info!(target: "audit", "{}", serde_json::to_string(&event)?);
+
+        let queue = AuditLogQueue::instance()?;
+        queue.append_data(event)?;
+        // The events older than the retention are removed as the new ones come in.
+        let retention = config.query.audit_log_retention_secs as i64 * 1_000_000;
+        if retention > 0 {
+            let expire_time = now_micros() - retention;
+            queue.retain(|e| e.event_time >= expire_time);
+        }
+        Ok(())
+    }
+
+    /// Records the authentication of the user, from the handler of the session.
+    pub fn log_auth(
+        session: &Arc<Session>,
+        user: &str,
+        client_address: Option<&str>,
+        err: Option<&ErrorCode>,
+    ) {
+        let config = session.get_config();
+        if !audited(&config, "auth") {
+            return;
+        }
+
+        let event = AuditLogElement {
+            event_time: now_micros(),
+            category: "auth".to_string(),
+            action: "Login".to_string(),
+            tenant_id: session.get_current_tenant(),
+            cluster_id: config.query.cluster_id.clone(),
+            user: user.to_string(),
+            handler_type: session.get_type().to_string(),
+            client_address: client_address.unwrap_or_default().to_string(),
+            query_id: "".to_string(),
+            query_text: "".to_string(),
+            query_tag: session.get_query_tag(&session.get_settings()),
+            success: err.is_none(),
+            exception_code: err.map(|e| e.code().into()).unwrap_or_default(),
+            exception_text: err.map(|e| e.to_string()).unwrap_or_default(),
+        };
+        Self::write_log(&config, event)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    /// Records the finished query of the audited categories, the failed ones included.
+    pub fn log_query(ctx: &QueryContext, err: Option<&ErrorCode>) {
+        let config = ctx.get_config();
+        let query_kind = ctx.get_query_kind();
+        let category = match audit_category(&query_kind) {
+            Some(category) if audited(&config, category) => category,
+            _ => return,
+        };
+
+        let user = ctx
+            .get_current_user()
+            .map(|user| user.name)
+            .unwrap_or_default();
+        let client_address = match ctx.get_client_address() {
+            Some(addr) => format!("{:?}", addr),
+            None => "".to_string(),
+        };
+        let event = AuditLogElement {
+            event_time: now_micros(),
+            category: category.to_string(),
+            action: query_kind,
+            tenant_id: ctx.get_tenant(),
+            cluster_id: config.query.cluster_id.clone(),
+            user,
+            handler_type: ctx.get_current_session().get_type().to_string(),
+            client_address,
+            query_id: ctx.get_id(),
+            query_text: ctx.get_query_str(),
+            query_tag: ctx.get_query_tag(),
+            success: err.is_none(),
+            exception_code: err.map(|e| e.code().into()).unwrap_or_default(),
+            exception_text: err.map(|e| e.to_string()).unwrap_or_default(),
+        };
+        Self::write_log(&config, event)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+}
+
+fn now_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
+}
@@ -14,11 +14,13 @@
 
 use std::io;
 use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::TimeZone;
 use chrono::Utc;
 use common_base::base::GlobalIORuntime;
 use common_base::base::TrySpawn;
+use common_catalog::table_lock::TableLockGuard;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,16 +28,26 @@ use common_meta_types::GrantObject;
 use common_meta_types::StageFile;
 use common_meta_types::UserStageInfo;
 use common_pipeline_core::Pipeline;
+use common_planner::plans::AggregatingIndexDefinition;
+use common_users::UserApiProvider;
 use futures::TryStreamExt;
 use tracing::debug;
 use tracing::warn;
 
+use crate::interpreters::InsertInterpreterV2;
+use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::processors::TransformAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
 use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
+use crate::sql::OPT_KEY_AGGREGATING_INDEX_DEFINITION;
+use crate::storages::fuse::FuseTable;
 use crate::storages::stage::StageTable;
 use crate::storages::Table;
 
@@ -108,8 +120,56 @@ pub fn append2table(
     Ok(())
 }
 
+/// Appends the rows to the table as `append2table()`, replacing the rows of the table with the
+/// same values of `on_conflict_fields` as they are committed.
+pub fn replace2table(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    source_schema: DataSchemaRef,
+    build_res: &mut PipelineBuildResult,
+    on_conflict_fields: Vec<String>,
+) -> Result<()> {
+    append2table(
+        ctx.clone(),
+        table.clone(),
+        source_schema,
+        build_res,
+        false,
+        false,
+    )?;
+
+    build_res.main_pipeline.set_on_finished(move |may_error| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        let on_conflict_fields = on_conflict_fields.clone();
+
+        if may_error.is_none() {
+            let append_entries = ctx.consume_precommit_blocks();
+            let commit_handle = GlobalIORuntime::instance().spawn(async move {
+                table
+                    .commit_replace(ctx, append_entries, &on_conflict_fields)
+                    .await
+            });
+
+            return match futures::executor::block_on(commit_handle) {
+                Ok(Ok(_)) => Ok(()),
+                Ok(Err(error)) => Err(error),
+                Err(cause) => Err(ErrorCode::PanicError(format!(
+                    "Maybe panic while in commit replace. {}",
+                    cause
+                ))),
+            };
+        }
+
+        Err(may_error.as_ref().unwrap().clone())
+    });
+
+    Ok(())
+}
+
 pub fn execute_pipeline(ctx: Arc<QueryContext>, mut res: PipelineBuildResult) -> Result<()> {
-    let executor_settings = ExecutorSettings::try_create(&ctx.get_settings())?;
+    let executor_settings = ExecutorSettings::try_create(&ctx.get_settings())?
+        .with_query_memory_tracker(ctx.get_memory_tracker());
     res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
     let mut pipelines = res.sources_pipelines;
     pipelines.push(res.main_pipeline);
@@ -137,7 +197,8 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
-        GrantObject::Database(catalog_name, database_name) => {
+        GrantObject::Database(catalog_name, database_name)
+        | GrantObject::FutureTables(catalog_name, database_name) => {
             let catalog = ctx.get_catalog(catalog_name)?;
             if !catalog
                 .exists_database(tenant.as_str(), database_name)
@@ -155,6 +216,54 @@ pub async fn validate_grant_object_exists(
     Ok(())
 }
 
+/// Grants the privileges of the `FUTURE TABLES IN DATABASE` grants of the users and the roles
+/// on the table just created in the database.
+pub async fn apply_future_grants(
+    tenant: &str,
+    catalog_name: &str,
+    database_name: &str,
+    table_name: &str,
+) -> Result<()> {
+    let future_tables =
+        GrantObject::FutureTables(catalog_name.to_string(), database_name.to_string());
+    let table = GrantObject::Table(
+        catalog_name.to_string(),
+        database_name.to_string(),
+        table_name.to_string(),
+    );
+
+    let user_mgr = UserApiProvider::instance();
+    for user in user_mgr.get_users(tenant).await? {
+        for entry in user.grants.entries() {
+            if entry.matches_entry(&future_tables) {
+                user_mgr
+                    .grant_privileges_to_user(
+                        tenant,
+                        user.identity(),
+                        table.clone(),
+                        (*entry.privileges()).into(),
+                    )
+                    .await?;
+            }
+        }
+    }
+    for role in user_mgr.get_roles(tenant).await? {
+        for entry in role.grants.entries() {
+            if entry.matches_entry(&future_tables) {
+                user_mgr
+                    .grant_privileges_to_role(
+                        tenant,
+                        role.name.clone(),
+                        table.clone(),
+                        (*entry.privileges()).into(),
+                    )
+                    .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
 pub async fn stat_file(
     ctx: &Arc<QueryContext>,
     stage: &UserStageInfo,
@@ -240,3 +349,121 @@ pub async fn list_files(
     debug!("listed files: {:?}", matched_files);
     Ok(matched_files)
 }
+
+/// Locks the table for a mutation of the query, the lock is held until the returned guard is
+/// released or dropped, see [TableLockGuard].
+pub async fn lock_table_for_mutation(
+    ctx: &Arc<QueryContext>,
+    catalog: &str,
+    table: &dyn Table,
+    operation: &str,
+) -> Result<Option<TableLockGuard>> {
+    let lease = Duration::from_secs(ctx.get_settings().get_table_lock_expire_secs()?);
+    TableLockGuard::try_lock(
+        ctx.get_catalog(catalog)?,
+        table.get_table_info().ident.table_id,
+        &ctx.get_id(),
+        operation,
+        lease,
+    )
+    .await
+}
+
+/// Recomputes the aggregating indexes of the table after its changes are committed, see
+/// [refresh_aggregating_index].
+pub fn refresh_aggregating_indexes_on_finished(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    table: &dyn Table,
+    pipeline: &mut Pipeline,
+) {
+    let indexes = FuseTable::parse_aggregating_indexes(table.options());
+    if indexes.is_empty() {
+        return;
+    }
+
+    let catalog = catalog.to_string();
+    let database = database.to_string();
+    let table = table.name().to_string();
+    pipeline.set_on_finished(move |may_error| {
+        if let Some(error) = may_error {
+            return Err(error.clone());
+        }
+
+        let ctx = ctx.clone();
+        let catalog = catalog.clone();
+        let database = database.clone();
+        let table = table.clone();
+        let indexes = indexes.clone();
+        let refresh_handle = GlobalIORuntime::instance().spawn(async move {
+            for index in &indexes {
+                refresh_aggregating_index(ctx.clone(), &catalog, &database, &table, index).await?;
+            }
+            Ok::<_, ErrorCode>(())
+        });
+        match futures::executor::block_on(refresh_handle) {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(error)) => Err(error),
+            Err(cause) => Err(ErrorCode::PanicError(format!(
+                "Maybe panic while in refresh aggregating index. {}",
+                cause
+            ))),
+        }
+    });
+}
+
+/// Overwrites the data of the aggregating index by its query of the table.
+///
+/// The query is executed in a new query context, which reads the latest snapshot of the table
+/// instead of the one cached in the context of the change. The privileges of the user on the
+/// table have been checked by the change, the index is written without checking them again.
+pub async fn refresh_aggregating_index(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+    index: &str,
+) -> Result<()> {
+    let index_table = ctx
+        .get_catalog(catalog)?
+        .get_table(&ctx.get_tenant(), database, index)
+        .await?;
+    let definition = match index_table
+        .options()
+        .get(OPT_KEY_AGGREGATING_INDEX_DEFINITION)
+    {
+        Some(definition) => AggregatingIndexDefinition::from_table_option(definition)?,
+        None => {
+            return Err(ErrorCode::UnknownAggregatingIndex(format!(
+                "{}.{} is not an aggregating index",
+                database, index
+            )));
+        }
+    };
+
+    let session = SessionManager::instance()
+        .create_session(SessionType::HTTPAPI("RefreshAggregatingIndex".to_string()))
+        .await?;
+    session.set_current_tenant(ctx.get_tenant());
+    session.set_current_user(ctx.get_current_user()?);
+    let refresh_ctx = session.create_query_context().await?;
+
+    let sql = format!(
+        "INSERT OVERWRITE `{}`.`{}`.`{}` {}",
+        catalog,
+        database,
+        index,
+        definition.query(database, table)
+    );
+    let mut planner = Planner::new(refresh_ctx.clone());
+    let (plan, _, _) = planner.plan_sql(&sql).await?;
+    let insert = match plan {
+        Plan::Insert(insert) => *insert,
+        _ => unreachable!("the refresh of aggregating index must be an insert"),
+    };
+    let interpreter = InsertInterpreterV2::try_create(refresh_ctx.clone(), insert, false)?;
+    let stream = interpreter.execute(refresh_ctx).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_base::base::GlobalIORuntime;
 use common_base::base::TrySpawn;
+use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -30,6 +31,7 @@ use common_meta_types::UserStageInfo;
 use regex::Regex;
 
 use super::append2table;
+use crate::interpreters::execute_pipeline;
 use crate::interpreters::interpreter_common::list_files;
 use crate::interpreters::interpreter_common::stat_file;
 use crate::interpreters::Interpreter;
@@ -280,6 +282,7 @@ impl CopyInterpreterV2 {
             *(bind_context.clone()),
             *s_expr.clone(),
             metadata.clone(),
+            None,
         )?;
 
         // Building data schema from bind_context columns
@@ -313,7 +316,23 @@ impl CopyInterpreterV2 {
             false,
             true,
         )?;
-        Ok(build_res)
+
+        // The files are returned after all of them are written.
+        execute_pipeline(self.ctx.clone(), build_res)?;
+        let files = table
+            .as_any()
+            .downcast_ref::<StageTable>()
+            .map(|t| t.unloaded_files())
+            .unwrap_or_default();
+
+        let names: Vec<String> = files.iter().map(|f| f.path.clone()).collect();
+        let sizes: Vec<u64> = files.iter().map(|f| f.size as u64).collect();
+        let rows: Vec<u64> = files.iter().map(|f| f.rows as u64).collect();
+        PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), vec![
+            Series::from_data(names),
+            Series::from_data(sizes),
+            Series::from_data(rows),
+        ])])
     }
 }
 
@@ -323,6 +342,10 @@ impl Interpreter for CopyInterpreterV2 {
         "CopyInterpreterV2"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     #[tracing::instrument(level = "debug", name = "copy_interpreter_execute_v2", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         match &self.plan {
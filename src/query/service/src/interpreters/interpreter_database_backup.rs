@@ -0,0 +1,176 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::TableMeta;
+use common_storage::init_operator;
+use common_storage::parse_uri_location;
+use common_storage::UriLocation;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::BackupDatabasePlan;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FUSE_OPT_KEY_ATTACH_STORAGE;
+
+/// The file of the manifest of the last backup, in the root of the location of the backup.
+pub(crate) const BACKUP_MANIFEST: &str = "backup_manifest.json";
+
+/// The meta of the database and the tables kept by a backup, the data of the tables are kept
+/// at the same paths as in the storage of the query.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct BackupManifest {
+    pub database_id: u64,
+    pub database_meta: DatabaseMeta,
+    pub tables: Vec<TableBackup>,
+    pub created_on: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct TableBackup {
+    pub table_id: u64,
+    pub name: String,
+    pub meta: TableMeta,
+
+    // the location of the copied snapshot, None if the table has no data kept in the storage
+    // of the query, e.g. the views, the attached tables and the empty tables
+    pub snapshot_location: Option<String>,
+}
+
+impl BackupManifest {
+    pub(crate) async fn read(operator: &Operator) -> Result<Option<BackupManifest>> {
+        let object = operator.object(BACKUP_MANIFEST);
+        if !object.is_exist().await? {
+            return Ok(None);
+        }
+        let data = object.read().await?;
+        Ok(Some(serde_json::from_slice(&data)?))
+    }
+}
+
+/// The operator of the storage rooted at the location of the backup.
+pub(crate) fn backup_operator(ctx: &QueryContext, location: &UriLocation) -> Result<Operator> {
+    let (storage, _) = parse_uri_location(location)?;
+    if !storage.is_secure() && !ctx.get_config().storage.allow_insecure {
+        return Err(ErrorCode::StorageInsecure(
+            "copy to or from insecure storage is not allowed",
+        ));
+    }
+    init_operator(&storage)
+}
+
+pub struct BackupDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BackupDatabasePlan,
+}
+
+impl BackupDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BackupDatabasePlan) -> Result<Self> {
+        Ok(BackupDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BackupDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "BackupDatabaseInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let operator = backup_operator(&self.ctx, &self.plan.location)?;
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let db_info = db.get_db_info();
+        if db_info.meta.from_share.is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "database {} is from a share, back up the database of the share instead",
+                self.plan.database
+            )));
+        }
+
+        // The backup is incremental to the last backup in the same location, which has to
+        // be the backup of the same database.
+        let last_manifest = BackupManifest::read(&operator).await?;
+        if let Some(last_manifest) = &last_manifest {
+            if last_manifest.database_id != db_info.ident.db_id {
+                return Err(ErrorCode::BadArguments(format!(
+                    "the location {} keeps the backup of another database",
+                    self.plan.location.path
+                )));
+            }
+        }
+
+        let tables = catalog
+            .list_tables(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let mut table_backups = Vec::with_capacity(tables.len());
+        for table in tables {
+            let table_info = table.get_table_info();
+            let table_id = table_info.ident.table_id;
+            let last_backup = last_manifest
+                .iter()
+                .flat_map(|m| m.tables.iter())
+                .find(|t| t.table_id == table_id)
+                .and_then(|t| t.snapshot_location.as_deref());
+
+            let snapshot_location = match FuseTable::try_from_table(table.as_ref()) {
+                Ok(fuse_table)
+                    if !table_info
+                        .engine_options()
+                        .contains_key(FUSE_OPT_KEY_ATTACH_STORAGE) =>
+                {
+                    fuse_table
+                        .do_backup(self.ctx.clone(), &operator, last_backup)
+                        .await?
+                }
+                _ => None,
+            };
+
+            table_backups.push(TableBackup {
+                table_id,
+                name: table_info.name.clone(),
+                meta: table_info.meta.clone(),
+                snapshot_location,
+            });
+        }
+
+        // The manifest is written after all the data are copied, a backup broken in the
+        // middle leaves the manifest of the last backup.
+        let manifest = BackupManifest {
+            database_id: db_info.ident.db_id,
+            database_meta: db_info.meta.clone(),
+            tables: table_backups,
+            created_on: Utc::now(),
+        };
+        operator
+            .object(BACKUP_MANIFEST)
+            .write(serde_json::to_vec(&manifest)?)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CommitTableReq;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableNameIdent;
+use opendal::Operator;
+use tracing::warn;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::interpreter_database_backup::backup_operator;
+use crate::interpreters::interpreter_database_backup::BackupManifest;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::RestoreDatabasePlan;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::OPT_KEY_DATABASE_ID;
+use crate::storages::fuse::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use crate::storages::fuse::OPT_KEY_SNAPSHOT_LOCATION;
+
+pub struct RestoreDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreDatabasePlan,
+}
+
+impl RestoreDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreDatabasePlan) -> Result<Self> {
+        Ok(RestoreDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RestoreDatabaseInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let operator = backup_operator(&self.ctx, &self.plan.location)?;
+        let manifest = BackupManifest::read(&operator).await?.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "no backup found at the location {}",
+                self.plan.location.path
+            ))
+        })?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let name_ident = DatabaseNameIdent {
+            tenant: self.plan.tenant.clone(),
+            db_name: self.plan.database.clone(),
+        };
+        let now = Utc::now();
+        let reply = catalog
+            .create_database(CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                meta: DatabaseMeta {
+                    created_on: now,
+                    updated_on: now,
+                    drop_on: None,
+                    shared_by: BTreeSet::new(),
+                    from_share: None,
+                    ..manifest.database_meta.clone()
+                },
+            })
+            .await?;
+
+        // A database restored in part is dropped, so it can be restored again by the name.
+        if let Err(cause) = self
+            .restore_tables(catalog.as_ref(), reply.db_id, &operator, &manifest)
+            .await
+        {
+            let req = DropDatabaseReq {
+                if_exists: true,
+                name_ident,
+            };
+            if let Err(e) = catalog.drop_database(req).await {
+                warn!(
+                    "failed to drop the database {} restored in part: {}",
+                    self.plan.database, e
+                );
+            }
+            return Err(cause);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+impl RestoreDatabaseInterpreter {
+    async fn restore_tables(
+        &self,
+        catalog: &dyn Catalog,
+        db_id: u64,
+        operator: &Operator,
+        manifest: &BackupManifest,
+    ) -> Result<()> {
+        let now = Utc::now();
+        for table in &manifest.tables {
+            let name_ident = TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: table.name.clone(),
+            };
+
+            let mut table_meta = table.meta.clone();
+            table_meta.catalog = self.plan.catalog.clone();
+            table_meta.created_on = now;
+            table_meta.updated_on = now;
+            table_meta.drop_on = None;
+            table_meta.statistics = Default::default();
+            table_meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+            table_meta.options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+            if table_meta.options.contains_key(OPT_KEY_DATABASE_ID) {
+                table_meta
+                    .options
+                    .insert(OPT_KEY_DATABASE_ID.to_string(), db_id.to_string());
+            }
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                table_meta,
+            };
+
+            let snapshot_location = match &table.snapshot_location {
+                Some(snapshot_location) => snapshot_location,
+                None => {
+                    catalog.create_table(req).await?;
+                    continue;
+                }
+            };
+
+            // The table is staged until its data are copied, the dropped staged table is
+            // purged if the restoring is broken in the middle.
+            let reply = catalog.create_staged_table(req).await?;
+            let (ident, meta) = catalog.get_table_meta_by_id(reply.table_id).await?;
+            let table_info = TableInfo::new(
+                &self.plan.database,
+                &table.name,
+                ident,
+                meta.as_ref().clone(),
+            );
+            let new_table = catalog.get_table_by_info(&table_info)?;
+            FuseTable::try_from_table(new_table.as_ref())?
+                .do_restore(self.ctx.clone(), operator, snapshot_location)
+                .await?;
+
+            catalog
+                .commit_staged_table(CommitTableReq {
+                    name_ident,
+                    table_id: reply.table_id,
+                })
+                .await?;
+        }
+        Ok(())
+    }
+}
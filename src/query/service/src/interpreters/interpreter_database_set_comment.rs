@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_planner::plans::SetDatabaseCommentPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetDatabaseCommentInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetDatabaseCommentPlan,
+}
+
+impl SetDatabaseCommentInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetDatabaseCommentPlan) -> Result<Self> {
+        Ok(SetDatabaseCommentInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetDatabaseCommentInterpreter {
+    fn name(&self) -> &str {
+        "SetDatabaseCommentInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        catalog
+            .update_database_comment(UpdateDatabaseCommentReq {
+                if_exists: plan.if_exists,
+                name_ident: DatabaseNameIdent {
+                    tenant: plan.tenant.clone(),
+                    db_name: plan.database.clone(),
+                },
+                comment: plan.comment.clone(),
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -69,6 +69,12 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
                 info.push_str(&engine);
             }
         }
+        let comment = &db.get_db_info().meta.comment;
+        if !comment.is_empty() {
+            // Quoted as a SQL literal so that the statement can be parsed back.
+            let comment = comment.replace('\\', "\\\\").replace('\'', "\\'");
+            write!(info, " COMMENT='{}'", comment).expect("write to string must succeed");
+        }
 
         PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), vec![
             Series::from_data(vec![name.as_bytes()]),
@@ -18,10 +18,13 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_legacy_planners::DeletePlan;
 
+use crate::interpreters::lock_table_for_mutation;
+use crate::interpreters::refresh_aggregating_index;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
 
 /// interprets DeletePlan
 pub struct DeleteInterpreter {
@@ -55,7 +58,15 @@ impl Interpreter for DeleteInterpreter {
         let db_name = self.plan.database_name.as_str();
         let tbl_name = self.plan.table_name.as_str();
         let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let lock = lock_table_for_mutation(&self.ctx, catalog_name, tbl.as_ref(), "delete").await?;
         tbl.delete(self.ctx.clone(), self.plan.clone()).await?;
+        if let Some(lock) = lock {
+            lock.release().await?;
+        }
+        for index in FuseTable::parse_aggregating_indexes(tbl.options()) {
+            refresh_aggregating_index(self.ctx.clone(), catalog_name, db_name, tbl_name, &index)
+                .await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -0,0 +1,60 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planner::plans::DropDictionaryPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropDictionaryInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropDictionaryPlan,
+}
+
+impl DropDictionaryInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropDictionaryPlan) -> Result<Self> {
+        Ok(DropDictionaryInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropDictionaryInterpreter {
+    fn name(&self) -> &str {
+        "DropDictionaryInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        UserApiProvider::instance()
+            .drop_dictionary(&tenant, &plan.name, plan.if_exists)
+            .await?;
+        // The other nodes keep their loaded rows until the rows expire.
+        SessionManager::instance()
+            .dictionary_cache
+            .invalidate(&tenant, &plan.name);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
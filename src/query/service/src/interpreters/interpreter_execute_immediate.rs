@@ -0,0 +1,296 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::ScriptBlock;
+use common_ast::ast::ScriptStatement;
+use common_ast::parser::parse_script;
+use common_ast::parser::tokenize_sql;
+use common_ast::Backtrace;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::ExecuteImmediatePlan;
+use futures::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+pub struct ExecuteImmediateInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteImmediatePlan,
+}
+
+impl ExecuteImmediateInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteImmediatePlan) -> Result<Self> {
+        Ok(ExecuteImmediateInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteImmediateInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteImmediateInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tokens = tokenize_sql(&self.plan.script)?;
+        let backtrace = Backtrace::new();
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let block = parse_script(&tokens, sql_dialect, &backtrace)?;
+
+        let mut executor = ScriptExecutor {
+            ctx: self.ctx.clone(),
+            variables: HashMap::new(),
+            handling_errors: vec![],
+        };
+        let result = match executor.run_block(&block).await? {
+            Flow::Return(value) => value,
+            Flow::Next => DataValue::Null,
+            Flow::Break | Flow::Continue => {
+                return Err(ErrorCode::ScriptSemanticError(
+                    "BREAK or CONTINUE must be in a loop",
+                ));
+            }
+        };
+
+        let value = match result {
+            DataValue::String(value) => Some(value),
+            _ => None,
+        };
+        let column = Series::from_data(vec![value]);
+        PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), vec![column])])
+    }
+}
+
+// What to do after a statement of the script runs.
+enum Flow {
+    Next,
+    Break,
+    Continue,
+    // The value returned by the script, as a string.
+    Return(DataValue),
+}
+
+// Runs the script statement by statement. The SQL statements run as the queries of the session,
+// in the new query contexts, so they see the changes of the former statements, and the variables
+// of the script are resolved as `$x` in them.
+struct ScriptExecutor {
+    ctx: Arc<QueryContext>,
+    variables: HashMap<String, (DataValue, DataTypeImpl)>,
+    // The errors being handled by the exception handlers, the innermost one is re-raised by
+    // `RAISE`.
+    handling_errors: Vec<ErrorCode>,
+}
+
+impl ScriptExecutor {
+    async fn run_block(&mut self, block: &ScriptBlock<'_>) -> Result<Flow> {
+        let res = self.run_statements(&block.body).await;
+        match (res, &block.exception_handler) {
+            // The killed query is never handled, the script stops as well.
+            (Err(cause), Some(exception_handler))
+                if cause.code() != ErrorCode::AbortedQueryCode() =>
+            {
+                self.variables.insert(
+                    "sqlcode".to_string(),
+                    (DataValue::Int64(cause.code() as i64), Int64Type::new_impl()),
+                );
+                self.variables.insert(
+                    "sqlerrm".to_string(),
+                    (
+                        DataValue::String(cause.message().into_bytes()),
+                        StringType::new_impl(),
+                    ),
+                );
+                self.handling_errors.push(cause);
+                let res = self.run_statements(exception_handler).await;
+                self.handling_errors.pop();
+                res
+            }
+            (res, _) => res,
+        }
+    }
+
+    async fn run_statements(&mut self, stmts: &[ScriptStatement<'_>]) -> Result<Flow> {
+        for stmt in stmts {
+            match self.run_statement(stmt).await? {
+                Flow::Next => {}
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    #[async_recursion::async_recursion]
+    async fn run_statement(&mut self, stmt: &ScriptStatement<'_>) -> Result<Flow> {
+        match stmt {
+            ScriptStatement::Let { variable, value } => {
+                let value = self.eval(&format!("SELECT {value}")).await?;
+                self.variables.insert(variable.name.to_lowercase(), value);
+            }
+            ScriptStatement::Assign { variable, value } => {
+                let name = variable.name.to_lowercase();
+                if !self.variables.contains_key(&name) {
+                    return Err(ErrorCode::ScriptSemanticError(format!(
+                        "variable {} is not declared by LET",
+                        variable.name
+                    )));
+                }
+                let value = self.eval(&format!("SELECT {value}")).await?;
+                self.variables.insert(name, value);
+            }
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result,
+            } => {
+                for (condition, result) in conditions.iter().zip(results) {
+                    if self.eval_condition(condition).await? {
+                        return self.run_statements(result).await;
+                    }
+                }
+                if let Some(else_result) = else_result {
+                    return self.run_statements(else_result).await;
+                }
+            }
+            ScriptStatement::Loop { body } => loop {
+                match self.run_statements(body).await? {
+                    Flow::Next | Flow::Continue => {}
+                    Flow::Break => break,
+                    flow => return Ok(flow),
+                }
+            },
+            ScriptStatement::While { condition, body } => {
+                while self.eval_condition(condition).await? {
+                    match self.run_statements(body).await? {
+                        Flow::Next | Flow::Continue => {}
+                        Flow::Break => break,
+                        flow => return Ok(flow),
+                    }
+                }
+            }
+            ScriptStatement::For {
+                variable,
+                lower,
+                upper,
+                body,
+            } => {
+                let lower = self.eval_integer(lower).await?;
+                let upper = self.eval_integer(upper).await?;
+                for i in lower..=upper {
+                    self.variables.insert(
+                        variable.name.to_lowercase(),
+                        (DataValue::Int64(i), Int64Type::new_impl()),
+                    );
+                    match self.run_statements(body).await? {
+                        Flow::Next | Flow::Continue => {}
+                        Flow::Break => break,
+                        flow => return Ok(flow),
+                    }
+                }
+            }
+            ScriptStatement::Break => return Ok(Flow::Break),
+            ScriptStatement::Continue => return Ok(Flow::Continue),
+            ScriptStatement::Return { value } => {
+                let value = match value {
+                    Some(value) => self.eval_string(value).await?,
+                    None => DataValue::Null,
+                };
+                return Ok(Flow::Return(value));
+            }
+            ScriptStatement::Raise { message } => {
+                return match (message, self.handling_errors.last()) {
+                    (Some(message), _) => {
+                        let message = self.eval_string(message).await?;
+                        Err(ErrorCode::ScriptRaisedError(message.to_string()))
+                    }
+                    (None, Some(cause)) => Err(cause.clone()),
+                    (None, None) => Err(ErrorCode::ScriptSemanticError(
+                        "RAISE without message must be in the exception handler",
+                    )),
+                };
+            }
+            ScriptStatement::Block(block) => return self.run_block(block).await,
+            ScriptStatement::Sql(sql) => {
+                self.run_sql(sql).await?;
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    async fn run_sql(&self, sql: &str) -> Result<Vec<DataBlock>> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        ctx.set_script_variables(self.variables.clone());
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _, _) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.to_string(), sql);
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        stream.try_collect::<Vec<_>>().await
+    }
+
+    // The value of the first column of the first row of the query, NULL if it returns no rows.
+    async fn eval(&self, query: &str) -> Result<(DataValue, DataTypeImpl)> {
+        let blocks = self.run_sql(query).await?;
+        match blocks.iter().find(|block| block.num_rows() != 0) {
+            Some(block) => Ok((
+                block.column(0).get_checked(0)?,
+                block.schema().field(0).data_type().clone(),
+            )),
+            None => Ok((DataValue::Null, NullType::new_impl())),
+        }
+    }
+
+    // The NULL condition is false, as the `WHERE` clause.
+    async fn eval_condition(&self, expr: &Expr<'_>) -> Result<bool> {
+        let (value, _) = self
+            .eval(&format!("SELECT CAST(({expr}) AS BOOLEAN)"))
+            .await?;
+        Ok(matches!(value, DataValue::Boolean(true)))
+    }
+
+    async fn eval_integer(&self, expr: &Expr<'_>) -> Result<i64> {
+        let (value, _) = self
+            .eval(&format!("SELECT CAST(({expr}) AS BIGINT)"))
+            .await?;
+        value.as_i64().map_err(|_| {
+            ErrorCode::ScriptSemanticError(format!("the bound of FOR must not be NULL: {expr}"))
+        })
+    }
+
+    // The value formatted as the query results, e.g. the dates are not the numbers of days.
+    async fn eval_string(&self, expr: &Expr<'_>) -> Result<DataValue> {
+        let (value, _) = self
+            .eval(&format!("SELECT CAST(({expr}) AS VARCHAR)"))
+            .await?;
+        Ok(value)
+    }
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_ast::ast::ExplainKind;
+use common_ast::ast::FormatTreeNode;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
@@ -22,9 +24,15 @@ use common_planner::MetadataRef;
 
 use super::fragments::Fragmenter;
 use super::QueryFragmentsActions;
+use crate::interpreters::execute_pipeline;
 use crate::interpreters::Interpreter;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::EmptySink;
+use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SinkPipeBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::executor::PipelineBuilder;
@@ -86,6 +94,28 @@ impl Interpreter for ExplainInterpreterV2 {
                     return Err(ErrorCode::UnImplement("Unsupported EXPLAIN statement"));
                 }
             },
+            ExplainKind::Analyze => match &self.plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => {
+                    self.explain_analyze(*s_expr.clone(), metadata.clone())
+                        .await?
+                }
+                _ => {
+                    return Err(ErrorCode::UnImplement("Unsupported EXPLAIN statement"));
+                }
+            },
+            ExplainKind::Validate => match &self.plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => {
+                    self.validate_pipeline(*s_expr.clone(), metadata.clone())
+                        .await?
+                }
+                _ => {
+                    return Err(ErrorCode::UnImplement("Unsupported VALIDATE statement"));
+                }
+            },
             ExplainKind::Graph => {
                 return Err(ErrorCode::UnImplement("ExplainKind graph is unimplemented"));
             }
@@ -172,6 +202,127 @@ impl ExplainInterpreterV2 {
         Ok(blocks)
     }
 
+    // Builds the pipelines of the query without executing them, the problems of the physical plan
+    // and of the pipelines are reported, e.g. the columns missing in the input of an operator or
+    // the ports of the pipes not connectable.
+    async fn validate_pipeline(
+        &self,
+        s_expr: SExpr,
+        metadata: MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
+        let builder = PhysicalPlanBuilder::new(metadata, self.ctx.clone());
+        let plan = builder.build(&s_expr).await?;
+
+        let mut lines = vec![];
+        let problems = plan.validate();
+        if !problems.is_empty() {
+            // the pipeline can't be built from an invalid plan
+            lines.push("physical plan: invalid".to_string());
+            lines.extend(problems.into_iter().map(|problem| format!("  {problem}")));
+            return self.validate_result(lines);
+        }
+        lines.push("physical plan: ok".to_string());
+
+        let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
+        let build_res = match pipeline_builder.finalize(&plan) {
+            Ok(build_res) => build_res,
+            Err(cause) => {
+                lines.push("pipeline: invalid".to_string());
+                lines.push(format!("  {}", cause.message()));
+                return self.validate_result(lines);
+            }
+        };
+
+        // the main pipeline produces the result of the query, and the source pipelines are
+        // complete, e.g. of the build sides of the joins
+        let mut problems = build_res.main_pipeline.validate();
+        if !build_res.main_pipeline.pipes.is_empty() {
+            if build_res.main_pipeline.is_pushing_pipeline()? {
+                problems.push("the pipeline has no source".to_string());
+            }
+            if !build_res.main_pipeline.is_pulling_pipeline()? {
+                problems.push("the pipeline has no output".to_string());
+            }
+        }
+        lines.extend(Self::validate_lines(
+            "main pipeline",
+            &build_res.main_pipeline,
+            problems,
+        ));
+        for (index, pipeline) in build_res.sources_pipelines.iter().enumerate() {
+            let mut problems = pipeline.validate();
+            if !pipeline.pipes.is_empty() && !pipeline.is_complete_pipeline()? {
+                problems.push("the pipeline is not complete".to_string());
+            }
+            let name = format!("source pipeline {}", index + 1);
+            lines.extend(Self::validate_lines(&name, pipeline, problems));
+        }
+        self.validate_result(lines)
+    }
+
+    fn validate_lines(name: &str, pipeline: &Pipeline, problems: Vec<String>) -> Vec<String> {
+        if !problems.is_empty() {
+            let mut lines = vec![format!("{name}: invalid")];
+            lines.extend(problems.into_iter().map(|problem| format!("  {problem}")));
+            return lines;
+        }
+        let processors: usize = pipeline.pipes.iter().map(|pipe| pipe.size()).sum();
+        vec![format!(
+            "{name}: ok, {} pipes, {} processors",
+            pipeline.pipes.len(),
+            processors
+        )]
+    }
+
+    fn validate_result(&self, lines: Vec<String>) -> Result<Vec<DataBlock>> {
+        let lines = Series::from_data(lines.iter().map(|line| line.as_str()).collect::<Vec<_>>());
+        Ok(vec![DataBlock::create(self.schema.clone(), vec![lines])])
+    }
+
+    // Executes the query with the result discarded, the statistics of the execution are
+    // reported after the plan.
+    async fn explain_analyze(
+        &self,
+        s_expr: SExpr,
+        metadata: MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
+        let builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+        let plan = builder.build(&s_expr).await?;
+
+        let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
+        let mut build_res = pipeline_builder.finalize(&plan)?;
+
+        let mut sink_pipeline_builder = SinkPipeBuilder::create();
+        for _index in 0..build_res.main_pipeline.output_len() {
+            let input_port = InputPort::create();
+            sink_pipeline_builder.add_sink(input_port.clone(), EmptySink::create(input_port));
+        }
+        build_res
+            .main_pipeline
+            .add_pipe(sink_pipeline_builder.finalize());
+
+        let instant = Instant::now();
+        execute_pipeline(self.ctx.clone(), build_res)?;
+        let elapsed = instant.elapsed();
+
+        let scan_progress = self.ctx.get_scan_progress_value();
+        let spill_progress = self.ctx.get_spill_progress_value();
+        let execution = FormatTreeNode::with_children("Execution".to_string(), vec![
+            FormatTreeNode::new(format!("elapsed: {}ms", elapsed.as_millis())),
+            FormatTreeNode::new(format!("scan rows: {}", scan_progress.rows)),
+            FormatTreeNode::new(format!("scan bytes: {}", scan_progress.bytes)),
+            FormatTreeNode::new(format!("spilled rows: {}", spill_progress.rows)),
+            FormatTreeNode::new(format!("spilled bytes: {}", spill_progress.bytes)),
+        ]);
+
+        let result = format!("{}\n{}", plan.format(metadata)?, execution.format_pretty()?);
+        let line_splitted_result: Vec<&str> = result.lines().collect();
+        let formatted_plan = Series::from_data(line_splitted_result);
+        Ok(vec![DataBlock::create(self.schema.clone(), vec![
+            formatted_plan,
+        ])])
+    }
+
     async fn explain_fragments(
         &self,
         s_expr: SExpr,
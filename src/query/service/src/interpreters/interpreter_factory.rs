@@ -51,12 +51,14 @@ impl InterpreterFactory {
                 s_expr,
                 bind_context,
                 metadata,
+                formatted_ast,
                 ..
             } => Ok(Arc::new(SelectInterpreterV2::try_create(
                 ctx,
                 *bind_context.clone(),
                 *s_expr.clone(),
                 metadata.clone(),
+                formatted_ast.clone(),
             )?)),
             Plan::Explain { kind, plan } => Ok(Arc::new(ExplainInterpreterV2::try_create(
                 ctx,
@@ -104,6 +106,15 @@ impl InterpreterFactory {
             Plan::RenameDatabase(rename_database) => Ok(Arc::new(
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
+            Plan::SetDatabaseComment(set_database_comment) => Ok(Arc::new(
+                SetDatabaseCommentInterpreter::try_create(ctx, *set_database_comment.clone())?,
+            )),
+            Plan::BackupDatabase(backup_database) => Ok(Arc::new(
+                BackupDatabaseInterpreter::try_create(ctx, *backup_database.clone())?,
+            )),
+            Plan::RestoreDatabase(restore_database) => Ok(Arc::new(
+                RestoreDatabaseInterpreter::try_create(ctx, *restore_database.clone())?,
+            )),
 
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
@@ -116,6 +127,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::AttachTable(attach_table) => Ok(Arc::new(AttachTableInterpreter::try_create(
+                ctx,
+                *attach_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -128,6 +143,10 @@ impl InterpreterFactory {
                 ctx,
                 *rename_table.clone(),
             )?)),
+            Plan::SwapTable(swap_table) => Ok(Arc::new(SwapTableInterpreter::try_create(
+                ctx,
+                *swap_table.clone(),
+            )?)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -137,12 +156,64 @@ impl InterpreterFactory {
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
+            Plan::SetTableOptions(set_table_options) => Ok(Arc::new(
+                SetTableOptionsInterpreter::try_create(ctx, *set_table_options.clone())?,
+            )),
+            Plan::AddTableColumn(add_table_column) => Ok(Arc::new(
+                AddTableColumnInterpreter::try_create(ctx, *add_table_column.clone())?,
+            )),
+            Plan::AddTableVirtualColumn(add_table_virtual_column) => {
+                Ok(Arc::new(AddTableVirtualColumnInterpreter::try_create(
+                    ctx,
+                    *add_table_virtual_column.clone(),
+                )?))
+            }
+            Plan::DropTableVirtualColumn(drop_table_virtual_column) => {
+                Ok(Arc::new(DropTableVirtualColumnInterpreter::try_create(
+                    ctx,
+                    *drop_table_virtual_column.clone(),
+                )?))
+            }
+            Plan::AddTableRowAccessPolicy(add_table_row_access_policy) => {
+                Ok(Arc::new(AddTableRowAccessPolicyInterpreter::try_create(
+                    ctx,
+                    *add_table_row_access_policy.clone(),
+                )?))
+            }
+            Plan::DropTableRowAccessPolicy(drop_table_row_access_policy) => {
+                Ok(Arc::new(DropTableRowAccessPolicyInterpreter::try_create(
+                    ctx,
+                    *drop_table_row_access_policy.clone(),
+                )?))
+            }
+            Plan::SetTableColumnMaskingPolicy(set_table_column_masking_policy) => Ok(Arc::new(
+                SetTableColumnMaskingPolicyInterpreter::try_create(
+                    ctx,
+                    *set_table_column_masking_policy.clone(),
+                )?,
+            )),
+            Plan::UnsetTableColumnMaskingPolicy(unset_table_column_masking_policy) => Ok(Arc::new(
+                UnsetTableColumnMaskingPolicyInterpreter::try_create(
+                    ctx,
+                    *unset_table_column_masking_policy.clone(),
+                )?,
+            )),
+            Plan::SetTableColumnComment(set_table_column_comment) => {
+                Ok(Arc::new(SetTableColumnCommentInterpreter::try_create(
+                    ctx,
+                    *set_table_column_comment.clone(),
+                )?))
+            }
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
             Plan::OptimizeTable(optimize_table) => Ok(Arc::new(
                 OptimizeTableInterpreter::try_create(ctx, *optimize_table.clone())?,
             )),
+            Plan::AnalyzeTable(analyze_table) => Ok(Arc::new(AnalyzeTableInterpreter::try_create(
+                ctx,
+                *analyze_table.clone(),
+            )?)),
             Plan::ExistsTable(exists_table) => Ok(Arc::new(ExistsTableInterpreter::try_create(
                 ctx,
                 *exists_table.clone(),
@@ -162,6 +233,24 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
+            // Aggregating indexes
+            Plan::CreateAggregatingIndex(create_index) => Ok(Arc::new(
+                CreateAggregatingIndexInterpreter::try_create(ctx, *create_index.clone())?,
+            )),
+            Plan::DropAggregatingIndex(drop_index) => Ok(Arc::new(
+                DropAggregatingIndexInterpreter::try_create(ctx, *drop_index.clone())?,
+            )),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -178,6 +267,12 @@ impl InterpreterFactory {
 
             Plan::Insert(insert) => InsertInterpreterV2::try_create(ctx, *insert.clone(), false),
 
+            Plan::Replace(replace) => InsertInterpreterV2::try_create_replace(
+                ctx,
+                replace.to_insert(),
+                replace.on_conflict_fields.clone(),
+            ),
+
             Plan::Delete(delete) => Ok(Arc::new(DeleteInterpreter::try_create(
                 ctx,
                 *delete.clone(),
@@ -228,6 +323,10 @@ impl InterpreterFactory {
                 ctx,
                 *revoke_role.clone(),
             )?)),
+            Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
+                ctx,
+                *set_role.clone(),
+            )?)),
             Plan::CreateUDF(create_user_udf) => Ok(Arc::new(CreateUserUDFInterpreter::try_create(
                 ctx,
                 *create_user_udf.clone(),
@@ -240,6 +339,74 @@ impl InterpreterFactory {
                 ctx,
                 *drop_udf.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(create_row_access_policy) => {
+                Ok(Arc::new(CreateRowAccessPolicyInterpreter::try_create(
+                    ctx,
+                    *create_row_access_policy.clone(),
+                )?))
+            }
+            Plan::DropRowAccessPolicy(drop_row_access_policy) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *drop_row_access_policy.clone())?,
+            )),
+            Plan::CreateMaskingPolicy(create_masking_policy) => Ok(Arc::new(
+                CreateMaskingPolicyInterpreter::try_create(ctx, *create_masking_policy.clone())?,
+            )),
+            Plan::DropMaskingPolicy(drop_masking_policy) => Ok(Arc::new(
+                DropMaskingPolicyInterpreter::try_create(ctx, *drop_masking_policy.clone())?,
+            )),
+            Plan::CreateNetworkPolicy(create_network_policy) => Ok(Arc::new(
+                CreateNetworkPolicyInterpreter::try_create(ctx, *create_network_policy.clone())?,
+            )),
+            Plan::DropNetworkPolicy(drop_network_policy) => Ok(Arc::new(
+                DropNetworkPolicyInterpreter::try_create(ctx, *drop_network_policy.clone())?,
+            )),
+            Plan::CreateTask(create_task) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *create_task.clone(),
+            )?)),
+            Plan::DropTask(drop_task) => Ok(Arc::new(DropTaskInterpreter::try_create(
+                ctx,
+                *drop_task.clone(),
+            )?)),
+            Plan::AlterTask(alter_task) => Ok(Arc::new(AlterTaskInterpreter::try_create(
+                ctx,
+                *alter_task.clone(),
+            )?)),
+            Plan::CreateResourceGroup(create_resource_group) => Ok(Arc::new(
+                CreateResourceGroupInterpreter::try_create(ctx, *create_resource_group.clone())?,
+            )),
+            Plan::DropResourceGroup(drop_resource_group) => Ok(Arc::new(
+                DropResourceGroupInterpreter::try_create(ctx, *drop_resource_group.clone())?,
+            )),
+            Plan::CreateConnection(create_connection) => Ok(Arc::new(
+                CreateConnectionInterpreter::try_create(ctx, *create_connection.clone())?,
+            )),
+            Plan::DropConnection(drop_connection) => Ok(Arc::new(
+                DropConnectionInterpreter::try_create(ctx, *drop_connection.clone())?,
+            )),
+            Plan::CreateSequence(create_sequence) => Ok(Arc::new(
+                CreateSequenceInterpreter::try_create(ctx, *create_sequence.clone())?,
+            )),
+            Plan::DropSequence(drop_sequence) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *drop_sequence.clone(),
+            )?)),
+            Plan::CreatePlanHint(create_plan_hint) => Ok(Arc::new(
+                CreatePlanHintInterpreter::try_create(ctx, *create_plan_hint.clone())?,
+            )),
+            Plan::DropPlanHint(drop_plan_hint) => Ok(Arc::new(
+                DropPlanHintInterpreter::try_create(ctx, *drop_plan_hint.clone())?,
+            )),
+            Plan::DrainNode(drain_node) => Ok(Arc::new(DrainNodeInterpreter::try_create(
+                ctx,
+                *drain_node.clone(),
+            )?)),
+            Plan::CreateDictionary(create_dictionary) => Ok(Arc::new(
+                CreateDictionaryInterpreter::try_create(ctx, *create_dictionary.clone())?,
+            )),
+            Plan::DropDictionary(drop_dictionary) => Ok(Arc::new(
+                DropDictionaryInterpreter::try_create(ctx, *drop_dictionary.clone())?,
+            )),
 
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
@@ -250,12 +417,25 @@ impl InterpreterFactory {
                 ctx,
                 *set_variable.clone(),
             )?)),
+            Plan::SetUserVariable(set_user_variable) => Ok(Arc::new(
+                SetUserVariableInterpreter::try_create(ctx, *set_user_variable.clone())?,
+            )),
+            Plan::UnsetUserVariable(unset_user_variable) => Ok(Arc::new(
+                UnsetUserVariableInterpreter::try_create(ctx, *unset_user_variable.clone())?,
+            )),
+            Plan::ExecuteImmediate(execute_immediate) => Ok(Arc::new(
+                ExecuteImmediateInterpreter::try_create(ctx, *execute_immediate.clone())?,
+            )),
             Plan::UseDatabase(p) => Ok(Arc::new(UseDatabaseInterpreter::try_create(
                 ctx,
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Rollback => Ok(Arc::new(RollbackInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShare(p) => Ok(Arc::new(CreateShareInterpreter::try_create(
                 ctx,
@@ -12,16 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
 use std::io::Cursor;
 use std::ops::Not;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_ast::ast::Expr;
 use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_base::base::GlobalIORuntime;
+use common_base::base::ProgressValues;
 use common_base::base::TrySpawn;
+use common_catalog::txn::Transaction;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
@@ -39,8 +43,11 @@ use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use super::interpreter_common::append2table;
+use super::interpreter_common::refresh_aggregating_indexes_on_finished;
+use super::interpreter_common::replace2table;
 use super::plan_schedulers::build_schedule_pipeline;
 use crate::evaluator::Evaluator;
+use crate::interpreters::AsyncInsertQueue;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::port::OutputPort;
@@ -49,6 +56,7 @@ use crate::pipelines::processors::transforms::CompoundChunkOperator;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SourcePipeBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
 use crate::sql::binder::ScalarBinder;
 use crate::sql::executor::DistributedInsertSelect;
@@ -63,12 +71,21 @@ use crate::sql::plans::Plan;
 use crate::sql::plans::Scalar;
 use crate::sql::BindContext;
 use crate::sql::NameResolutionContext;
+use crate::storages::kafka::KafkaTable;
+use crate::storages::kafka::KAFKA_ENGINE;
+use crate::storages::stream::StreamTable;
+use crate::storages::stream::STREAM_ENGINE;
+use crate::storages::Table;
 
 pub struct InsertInterpreterV2 {
     ctx: Arc<QueryContext>,
     plan: Insert,
     source_pipe_builder: Mutex<Option<SourcePipeBuilder>>,
     async_insert: bool,
+    // The table to insert into, instead of the one resolved by the name in the plan.
+    table: Option<Arc<dyn Table>>,
+    // The fields identifying the rows replaced by the rows inserted, for `REPLACE INTO`.
+    on_conflict_fields: Option<Vec<String>>,
 }
 
 impl InsertInterpreterV2 {
@@ -82,9 +99,85 @@ impl InsertInterpreterV2 {
             plan,
             source_pipe_builder: Mutex::new(None),
             async_insert,
+            table: None,
+            on_conflict_fields: None,
         }))
     }
 
+    /// Creates an interpreter of `REPLACE INTO`, the rows of the table with the same values of
+    /// `on_conflict_fields` as the rows inserted are deleted as they are committed.
+    pub fn try_create_replace(
+        ctx: Arc<QueryContext>,
+        plan: Insert,
+        on_conflict_fields: Vec<String>,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(InsertInterpreterV2 {
+            ctx,
+            plan,
+            source_pipe_builder: Mutex::new(None),
+            async_insert: false,
+            table: None,
+            on_conflict_fields: Some(on_conflict_fields),
+        }))
+    }
+
+    /// Creates an interpreter inserting into `table`, e.g., a staged table that is not visible by
+    /// the name in the plan yet.
+    pub fn try_create_with_table(
+        ctx: Arc<QueryContext>,
+        plan: Insert,
+        table: Arc<dyn Table>,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(InsertInterpreterV2 {
+            ctx,
+            plan,
+            source_pipe_builder: Mutex::new(None),
+            async_insert: false,
+            table: Some(table),
+            on_conflict_fields: None,
+        }))
+    }
+
+    // Only the tiny inserts of `VALUES` are buffered in the async insert mode, the inserts of the
+    // other sources are large enough to be written at once.
+    fn is_async_insert(&self) -> Result<bool> {
+        Ok(!self.async_insert
+            && self.table.is_none()
+            && self.on_conflict_fields.is_none()
+            && !self.plan.overwrite
+            && matches!(self.plan.source, InsertInputSource::Values(_))
+            && self.ctx.get_settings().get_enable_async_insert()? != 0)
+    }
+
+    // Buffers the rows in the async insert queue, they are written in a batch with the other
+    // inserts into the table.
+    async fn push_async_insert(&self, data: &str) -> Result<PipelineBuildResult> {
+        let settings = self.ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let source = ValueSource::new(
+            data.to_string(),
+            self.ctx.clone(),
+            name_resolution_ctx,
+            self.plan.schema(),
+        );
+        let cursor = Cursor::new(data.as_bytes());
+        let mut reader = NestedCheckpointReader::new(BufferReader::new(cursor));
+        let block = source.read(&mut reader).await?;
+        let progress_values = ProgressValues {
+            rows: block.num_rows(),
+            bytes: block.memory_size(),
+        };
+
+        let queue = SessionManager::instance().async_insert_queue.clone();
+        let rx = queue.push(&self.ctx, &self.plan, block)?;
+        if settings.get_wait_for_async_insert()? != 0 {
+            let timeout = Duration::from_secs(settings.get_wait_for_async_insert_timeout()?);
+            AsyncInsertQueue::wait(rx, timeout).await?;
+            self.ctx.get_write_progress().incr(&progress_values);
+        }
+        Ok(PipelineBuildResult::create())
+    }
+
     fn check_schema_cast(&self, plan: &Plan) -> Result<bool> {
         let output_schema = &self.plan.schema;
         let select_schema = plan.schema();
@@ -100,6 +193,85 @@ impl InsertInterpreterV2 {
         let cast_needed = select_schema != *output_schema;
         Ok(cast_needed)
     }
+
+    // The tables whose offsets are moved forward once the rows read are committed,
+    // the streams and the kafka consumers.
+    fn consumed_tables(metadata: &MetadataRef) -> Vec<(String, String, Arc<dyn Table>)> {
+        metadata
+            .read()
+            .tables()
+            .iter()
+            .filter(|entry| {
+                let engine = entry.table().engine();
+                engine == STREAM_ENGINE || engine == KAFKA_ENGINE
+            })
+            .map(|entry| {
+                (
+                    entry.catalog().to_string(),
+                    entry.database().to_string(),
+                    entry.table(),
+                )
+            })
+            .collect()
+    }
+
+    // Commits the rows inserted by `commit` and moves the offsets of the consumed tables forward.
+    //
+    // The offsets of the streams are committed with the rows: out of a transaction, the new
+    // snapshot of the table and the offsets are staged by an implicit transaction of the
+    // statement, and committed by a single meta transaction. If the table is changed by the
+    // others in the meantime, the statement fails without advancing the streams. The kafka
+    // offsets are stored out of the meta, once the rows are committed.
+    async fn commit_consuming(
+        ctx: Arc<QueryContext>,
+        tables: &[(String, String, Arc<dyn Table>)],
+        commit: impl Future<Output = Result<()>>,
+    ) -> Result<()> {
+        let session = ctx.get_current_session();
+        let consumes_streams = tables
+            .iter()
+            .any(|(_, _, table)| table.engine() == STREAM_ENGINE);
+        let implicit_txn = match session.get_transaction() {
+            None if consumes_streams => {
+                let txn = Arc::new(Transaction::default());
+                session.set_transaction(Some(txn.clone()));
+                Some(txn)
+            }
+            _ => None,
+        };
+
+        let staged = async {
+            commit.await?;
+            for (catalog, database, table) in tables {
+                if table.engine() == STREAM_ENGINE {
+                    let stream = StreamTable::try_from_table(table.as_ref())?;
+                    stream.advance(ctx.clone(), catalog, database).await?;
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Some(txn) = implicit_txn {
+            session.set_transaction(None);
+            staged?;
+            if let Some((catalog, req)) = txn.commit_request() {
+                ctx.get_catalog(&catalog)?
+                    .update_multi_table_meta(req)
+                    .await?;
+            }
+        } else {
+            staged?;
+        }
+
+        for (_, _, table) in tables {
+            if table.engine() == KAFKA_ENGINE {
+                let kafka = KafkaTable::try_from_table(table.as_ref())?;
+                kafka.commit_offsets(ctx.clone()).await?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -109,11 +281,21 @@ impl Interpreter for InsertInterpreterV2 {
     }
 
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.is_async_insert()? {
+            if let InsertInputSource::Values(data) = &self.plan.source {
+                return self.push_async_insert(data).await;
+            }
+        }
+
         let plan = &self.plan;
-        let table = self
-            .ctx
-            .get_table(&plan.catalog, &plan.database, &plan.table)
-            .await?;
+        let table = match &self.table {
+            Some(table) => table.clone(),
+            None => {
+                self.ctx
+                    .get_table(&plan.catalog, &plan.database, &plan.table)
+                    .await?
+            }
+        };
 
         let mut build_res = PipelineBuildResult::create();
         let mut builder = SourcePipeBuilder::create();
@@ -150,7 +332,7 @@ impl Interpreter for InsertInterpreterV2 {
                 }
                 InsertInputSource::SelectPlan(plan) => {
                     let table1 = table.clone();
-                    let (mut select_plan, select_column_bindings) = match plan.as_ref() {
+                    let (mut select_plan, select_column_bindings, streams) = match plan.as_ref() {
                         Plan::Query {
                             s_expr,
                             metadata,
@@ -159,7 +341,11 @@ impl Interpreter for InsertInterpreterV2 {
                         } => {
                             let builder1 =
                                 PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
-                            (builder1.build(s_expr).await?, bind_context.columns.clone())
+                            (
+                                builder1.build(s_expr).await?,
+                                bind_context.columns.clone(),
+                                Self::consumed_tables(metadata),
+                            )
                         }
                         _ => unreachable!(),
                     };
@@ -170,7 +356,9 @@ impl Interpreter for InsertInterpreterV2 {
 
                     let insert_select_plan = match select_plan {
                         PhysicalPlan::Exchange(ref mut exchange) => {
-                            // insert can be dispatched to different nodes
+                            // insert can be dispatched to different nodes, each node writes its
+                            // own blocks and segments, which are sent back to the coordinator
+                            // as the precommit blocks and committed by it as a single snapshot.
                             let input = exchange.input.clone();
                             exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(
                                 Box::new(DistributedInsertSelect {
@@ -212,17 +400,40 @@ impl Interpreter for InsertInterpreterV2 {
 
                     let ctx = self.ctx.clone();
                     let overwrite = self.plan.overwrite;
+                    let on_conflict_fields = self.on_conflict_fields.clone();
+                    let indexed_table = table.clone();
                     build_res.main_pipeline.set_on_finished(move |may_error| {
                         // capture out variable
                         let overwrite = overwrite;
                         let ctx = ctx.clone();
                         let table = table.clone();
+                        let streams = streams.clone();
+                        let on_conflict_fields = on_conflict_fields.clone();
 
                         if may_error.is_none() {
                             let append_entries = ctx.consume_precommit_blocks();
                             // We must put the commit operation to global runtime, which will avoid the "dispatch dropped without returning error" in tower
                             let commit_handle = GlobalIORuntime::instance().spawn(async move {
-                                table.commit_insertion(ctx, append_entries, overwrite).await
+                                let commit = async {
+                                    match &on_conflict_fields {
+                                        None => {
+                                            table
+                                                .commit_insertion(
+                                                    ctx.clone(),
+                                                    append_entries,
+                                                    overwrite,
+                                                )
+                                                .await
+                                        }
+                                        Some(fields) => {
+                                            table
+                                                .commit_replace(ctx.clone(), append_entries, fields)
+                                                .await
+                                        }
+                                    }
+                                };
+                                // The consumed streams and kafka offsets are advanced only after the rows read from them are committed.
+                                Self::commit_consuming(ctx.clone(), &streams, commit).await
                             });
 
                             return match futures::executor::block_on(commit_handle) {
@@ -237,20 +448,43 @@ impl Interpreter for InsertInterpreterV2 {
 
                         Err(may_error.as_ref().unwrap().clone())
                     });
+                    refresh_aggregating_indexes_on_finished(
+                        self.ctx.clone(),
+                        &plan.catalog,
+                        &plan.database,
+                        indexed_table.as_ref(),
+                        &mut build_res.main_pipeline,
+                    );
 
                     return Ok(build_res);
                 }
             };
         }
 
-        append2table(
+        match &self.on_conflict_fields {
+            None => append2table(
+                self.ctx.clone(),
+                table.clone(),
+                plan.schema(),
+                &mut build_res,
+                self.plan.overwrite,
+                true,
+            )?,
+            Some(on_conflict_fields) => replace2table(
+                self.ctx.clone(),
+                table.clone(),
+                plan.schema(),
+                &mut build_res,
+                on_conflict_fields.clone(),
+            )?,
+        }
+        refresh_aggregating_indexes_on_finished(
             self.ctx.clone(),
-            table.clone(),
-            plan.schema(),
-            &mut build_res,
-            self.plan.overwrite,
-            true,
-        )?;
+            &plan.catalog,
+            &plan.database,
+            table.as_ref(),
+            &mut build_res.main_pipeline,
+        );
 
         Ok(build_res)
     }
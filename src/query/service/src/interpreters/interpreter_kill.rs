@@ -32,11 +32,17 @@ impl KillInterpreter {
         Ok(KillInterpreter { ctx, plan })
     }
 
-    async fn execute_kill(&self, session_id: &String) -> Result<PipelineBuildResult> {
-        match self.ctx.get_session_by_id(session_id) {
+    async fn execute_kill(&self, id: &String) -> Result<PipelineBuildResult> {
+        // The id is the session id, or the query id of the query running in the session.
+        let session = self
+            .ctx
+            .get_session_by_id(id)
+            .or_else(|| self.ctx.get_session_by_query_id(id));
+
+        match session {
             None => Err(ErrorCode::UnknownSession(format!(
-                "Not found session id {}",
-                session_id
+                "Not found session id or query id {}",
+                id
             ))),
             Some(kill_session) if self.plan.kill_connection => {
                 kill_session.force_kill_session();
@@ -0,0 +1,54 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planner::plans::DrainNodePlan;
+
+use crate::clusters::ClusterDiscovery;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DrainNodeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DrainNodePlan,
+}
+
+impl DrainNodeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DrainNodePlan) -> Result<Self> {
+        Ok(DrainNodeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DrainNodeInterpreter {
+    fn name(&self) -> &str {
+        "DrainNodeInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The node keeps running the fragments it has, it's safe to terminate once it's shown
+        // as drained in system.clusters.
+        ClusterDiscovery::instance()
+            .drain_node(&self.plan.node_id)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
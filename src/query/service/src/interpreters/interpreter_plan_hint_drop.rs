@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planner::plans::DropPlanHintPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropPlanHintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropPlanHintPlan,
+}
+
+impl DropPlanHintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropPlanHintPlan) -> Result<Self> {
+        Ok(DropPlanHintInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropPlanHintInterpreter {
+    fn name(&self) -> &str {
+        "DropPlanHintInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        UserApiProvider::instance()
+            .drop_plan_hint(&tenant, &plan.name, plan.if_exists)
+            .await?;
+        SessionManager::instance()
+            .plan_hint_cache
+            .invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -19,6 +19,7 @@ use std::time::UNIX_EPOCH;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserInfo;
 use common_storages_preludes::system::LogType;
 use common_storages_preludes::system::QueryLogElement;
 use common_storages_preludes::system::QueryLogQueue;
@@ -28,7 +29,9 @@ use tracing::error;
 use tracing::info;
 use tracing::subscriber;
 
+use crate::interpreters::InterpreterAuditLog;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
 
 pub struct InterpreterQueryLog;
@@ -67,10 +70,14 @@ impl InterpreterQueryLog {
             });
         };
 
+        SessionManager::instance()
+            .query_log_persister
+            .append(&event);
         QueryLogQueue::instance()?.append_data(event)
     }
 
     pub fn fail_to_start(ctx: Arc<QueryContext>, err: ErrorCode) {
+        InterpreterAuditLog::log_query(&ctx, Some(&err));
         InterpreterQueryLog::log_start(&ctx, SystemTime::now(), Some(err))
             .unwrap_or_else(|e| error!("fail to write query_log {:?}", e));
     }
@@ -89,6 +96,7 @@ impl InterpreterQueryLog {
         let query_id = ctx.get_id();
         let query_kind = ctx.get_query_kind();
         let query_text = ctx.get_query_str();
+        let query_tag = ctx.get_query_tag();
         // Schema.
         let current_database = ctx.get_current_database();
 
@@ -113,6 +121,7 @@ impl InterpreterQueryLog {
         let result_bytes = 0u64;
         let cpu_usage = ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = ctx.get_current_session().get_memory_usage() as u64;
+        let queued_time_ms = ctx.get_queued_time().as_millis() as u64;
 
         // Client.
         let client_address = match ctx.get_client_address() {
@@ -147,6 +156,7 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag,
             event_date,
             event_time,
             current_database,
@@ -168,6 +178,7 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            queued_time_ms,
             client_info: "".to_string(),
             client_address,
 
@@ -194,6 +205,7 @@ impl InterpreterQueryLog {
         let query_id = ctx.get_id();
         let query_kind = ctx.get_query_kind();
         let query_text = ctx.get_query_str();
+        let query_tag = ctx.get_query_tag();
 
         // Stats.
         let event_time = now
@@ -217,6 +229,7 @@ impl InterpreterQueryLog {
         let total_partitions = dal_metrics.get_partitions_total();
         let cpu_usage = ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = ctx.get_current_session().get_memory_usage() as u64;
+        let queued_time_ms = ctx.get_queued_time().as_millis() as u64;
 
         // Result.
         let result_rows = ctx.get_result_progress_value().rows as u64;
@@ -258,6 +271,7 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag,
             event_date,
             event_time,
             databases: "".to_string(),
@@ -278,6 +292,7 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            queued_time_ms,
             client_info: "".to_string(),
             client_address,
             current_database,
@@ -290,4 +305,64 @@ impl InterpreterQueryLog {
             extra: "".to_string(),
         })
     }
+
+    /// Records the login of the user rejected before the session starts, e.g. by the
+    /// network policy, as the query of the `Login` kind.
+    pub fn log_rejected_login(
+        ctx: &QueryContext,
+        user: &UserInfo,
+        client_address: Option<&str>,
+        err: &ErrorCode,
+    ) -> Result<()> {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+        let event_date = (event_time / (24 * 3_600_000_000)) as i32;
+
+        Self::write_log(QueryLogElement {
+            log_type: LogType::Error,
+            handler_type: ctx.get_current_session().get_type().to_string(),
+            tenant_id: ctx.get_tenant(),
+            cluster_id: ctx.get_config().query.cluster_id,
+            sql_user: user.name.clone(),
+            sql_user_quota: format!("{:?}", user.quota),
+            sql_user_privileges: user.grants.to_string(),
+            query_id: ctx.get_id(),
+            query_kind: "Login".to_string(),
+            query_text: "".to_string(),
+            query_tag: ctx.get_query_tag(),
+            event_date,
+            event_time,
+            current_database: "".to_string(),
+            databases: "".to_string(),
+            tables: "".to_string(),
+            columns: "".to_string(),
+            projections: "".to_string(),
+            written_rows: 0,
+            written_bytes: 0,
+            written_io_bytes: 0,
+            written_io_bytes_cost_ms: 0,
+            scan_rows: 0,
+            scan_bytes: 0,
+            scan_io_bytes: 0,
+            scan_io_bytes_cost_ms: 0,
+            scan_partitions: 0,
+            total_partitions: 0,
+            result_rows: 0,
+            result_bytes: 0,
+            cpu_usage: 0,
+            memory_usage: 0,
+            queued_time_ms: 0,
+            client_info: "".to_string(),
+            client_address: client_address.unwrap_or_default().to_string(),
+
+            exception_code: err.code().into(),
+            exception_text: err.to_string(),
+            stack_trace: "".to_string(),
+            server_version: "".to_string(),
+            session_settings: "".to_string(),
+            extra: "".to_string(),
+        })
+    }
 }
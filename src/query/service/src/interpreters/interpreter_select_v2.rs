@@ -12,16 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
+use common_pipeline_core::processors::port::OutputPort;
 use common_planner::MetadataRef;
+use parking_lot::Mutex;
 
 use super::plan_schedulers::schedule_query_v2;
 use crate::interpreters::Interpreter;
+use crate::pipelines::processors::BlocksSource;
+use crate::pipelines::processors::TransformWriteResultCache;
 use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SourcePipeBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryResultCacheKey;
+use crate::sessions::QueryResultCacheWriter;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::executor::PipelineBuilder;
@@ -34,6 +45,8 @@ pub struct SelectInterpreterV2 {
     s_expr: SExpr,
     bind_context: BindContext,
     metadata: MetadataRef,
+    /// The formatted statement of the query, the result is cached by it if it's some.
+    formatted_ast: Option<String>,
 }
 
 impl SelectInterpreterV2 {
@@ -42,12 +55,14 @@ impl SelectInterpreterV2 {
         bind_context: BindContext,
         s_expr: SExpr,
         metadata: MetadataRef,
+        formatted_ast: Option<String>,
     ) -> Result<Self> {
         Ok(SelectInterpreterV2 {
             ctx,
             s_expr,
             bind_context,
             metadata,
+            formatted_ast,
         })
     }
 
@@ -55,6 +70,12 @@ impl SelectInterpreterV2 {
         let builder = PhysicalPlanBuilder::new(self.metadata.clone(), self.ctx.clone());
         let physical_plan = builder.build(&self.s_expr).await?;
 
+        // The plan is captured for the slow query log, in case the query is slow.
+        if self.ctx.get_settings().get_slow_query_threshold_ms()? > 0 {
+            let plan = physical_plan.format(self.metadata.clone())?;
+            self.ctx.set_query_plan(plan);
+        }
+
         if self.ctx.get_cluster().is_empty() {
             let last_schema = physical_plan.output_schema()?;
             let pb = PipelineBuilder::create(self.ctx.clone());
@@ -74,6 +95,66 @@ impl SelectInterpreterV2 {
             schedule_query_v2(self.ctx.clone(), &self.bind_context.columns, &physical_plan).await
         }
     }
+
+    /// The key of the result cache, `None` if the result is not cached.
+    fn result_cache_key(&self) -> Result<Option<QueryResultCacheKey>> {
+        let settings = self.ctx.get_settings();
+        match &self.formatted_ast {
+            Some(query)
+                if settings.get_enable_query_result_cache()? && self.ctx.get_cacheable() =>
+            {
+                QueryResultCacheKey::try_create(&self.ctx, query, &self.metadata)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn build_cached_pipeline(&self, blocks: Vec<DataBlock>) -> Result<PipelineBuildResult> {
+        let output = OutputPort::create();
+        let blocks = Arc::new(Mutex::new(VecDeque::from(blocks)));
+        let source = BlocksSource::create(self.ctx.clone(), output.clone(), blocks)?;
+
+        let mut builder = SourcePipeBuilder::create();
+        builder.add_source(output, source);
+
+        let mut build_res = PipelineBuildResult::create();
+        build_res.main_pipeline.add_pipe(builder.finalize());
+        Ok(build_res)
+    }
+
+    /// Collects the result to the cache, it's cached once the pipeline is finished.
+    fn write_result_cache(
+        &self,
+        key: QueryResultCacheKey,
+        build_res: &mut PipelineBuildResult,
+    ) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        let ttl = Duration::from_secs(settings.get_query_result_cache_ttl_secs()?);
+        let writer = QueryResultCacheWriter::create(
+            SessionManager::instance().query_result_cache.clone(),
+            key,
+            self.ctx.get_id(),
+            ttl,
+            build_res.main_pipeline.output_len(),
+        );
+
+        let pipeline = &mut build_res.main_pipeline;
+        pipeline.add_transform(|input, output| {
+            Ok(TransformWriteResultCache::create(
+                input,
+                output,
+                writer.clone(),
+            ))
+        })?;
+
+        pipeline.set_on_finished(move |may_error| {
+            if may_error.is_none() {
+                writer.commit();
+            }
+            Ok(())
+        });
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -90,7 +171,18 @@ impl Interpreter for SelectInterpreterV2 {
     /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
     #[tracing::instrument(level = "debug", name = "select_interpreter_v2_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let build_res = self.build_pipeline().await?;
+        let key = self.result_cache_key()?;
+        if let Some(key) = &key {
+            let cache = &SessionManager::instance().query_result_cache;
+            if let Some(blocks) = cache.get(key) {
+                return self.build_cached_pipeline(blocks);
+            }
+        }
+
+        let mut build_res = self.build_pipeline().await?;
+        if let Some(key) = key {
+            self.write_result_cache(key, &mut build_res)?;
+        }
         Ok(build_res)
     }
 }
@@ -0,0 +1,139 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_preludes::system::SlowQueryLogElement;
+use common_storages_preludes::system::SlowQueryLogQueue;
+use common_tracing::QueryLogger;
+use serde_json;
+use tracing::error;
+use tracing::subscriber;
+use tracing::warn;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterSlowQueryLog;
+
+impl InterpreterSlowQueryLog {
+    fn write_log(event: SlowQueryLogElement) -> Result<()> {
+        let event_str = serde_json::to_string(&event)?;
+        warn!(target: "slow_query", "{}", event_str);
+
+        if let Some(logger) = QueryLogger::instance().get_slow_query_subscriber() {
+            subscriber::with_default(logger, || {
+                warn!("{}", event_str);
+            });
+        };
+
+        SlowQueryLogQueue::instance()?.append_data(event)
+    }
+
+    /// Records the finished query slower than the `slow_query_threshold_ms` setting, with its
+    /// plan and the profile of its processors on this node.
+    pub fn log_query(ctx: &QueryContext, err: Option<&ErrorCode>) {
+        let settings = ctx.get_settings();
+        let threshold_ms = match settings.get_slow_query_threshold_ms() {
+            Ok(threshold_ms) if threshold_ms > 0 => threshold_ms,
+            _ => return,
+        };
+        let query_duration_ms = ctx.get_query_elapsed().as_millis() as u64;
+        if query_duration_ms < threshold_ms {
+            return;
+        }
+
+        Self::write_log(Self::create_event(ctx, query_duration_ms, err))
+            .unwrap_or_else(|e| error!("fail to write slow_query_log {:?}", e));
+    }
+
+    fn create_event(
+        ctx: &QueryContext,
+        query_duration_ms: u64,
+        err: Option<&ErrorCode>,
+    ) -> SlowQueryLogElement {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+        let sql_user = ctx
+            .get_current_user()
+            .map(|user| user.name)
+            .unwrap_or_default();
+
+        let mut session_settings = String::new();
+        for (key, value) in ctx
+            .get_current_session()
+            .get_settings()
+            .get_setting_values_short()
+        {
+            write!(session_settings, "{}={:?}, ", key, value)
+                .expect("write to string must succeed");
+        }
+        session_settings.push_str("scope: SESSION");
+
+        let scan_progress = ctx.get_scan_progress_value();
+        let write_progress = ctx.get_write_progress_value();
+        let spill_progress = ctx.get_spill_progress_value();
+        let result_progress = ctx.get_result_progress_value();
+
+        SlowQueryLogElement {
+            event_time,
+            tenant_id: ctx.get_tenant(),
+            cluster_id: ctx.get_config().query.cluster_id,
+            sql_user,
+            query_id: ctx.get_id(),
+            query_kind: ctx.get_query_kind(),
+            query_text: ctx.get_query_str(),
+            current_database: ctx.get_current_database(),
+            session_settings,
+            plan: ctx.get_query_plan().unwrap_or_default(),
+            processors_profile: format_processors_profile(&ctx.get_processors_busy_time()),
+            query_duration_ms,
+            queued_time_ms: ctx.get_queued_time().as_millis() as u64,
+            scan_rows: scan_progress.rows as u64,
+            scan_bytes: scan_progress.bytes as u64,
+            written_rows: write_progress.rows as u64,
+            written_bytes: write_progress.bytes as u64,
+            spilled_rows: spill_progress.rows as u64,
+            spilled_bytes: spill_progress.bytes as u64,
+            result_rows: result_progress.rows as u64,
+            result_bytes: result_progress.bytes as u64,
+            memory_usage: ctx.get_current_session().get_memory_usage() as u64,
+            exception_code: err.map(|e| e.code().into()).unwrap_or_default(),
+            exception_text: err.map(|e| e.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+/// One line of each processor, e.g. `TransformFilter × 4: 12.345ms`, the busy time is the sum
+/// of the processors of the name.
+pub fn format_processors_profile(profile: &[(&str, usize, u64)]) -> String {
+    profile
+        .iter()
+        .map(|(name, count, busy_micros)| {
+            format!(
+                "{} × {}: {:.3}ms",
+                name,
+                count,
+                *busy_micros as f64 / 1000.0
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_planner::plans::CreateStreamPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::OPT_KEY_DATABASE_ID;
+use crate::storages::fuse::OPT_KEY_SNAPSHOT_LOCATION;
+use crate::storages::stream::OPT_KEY_STREAM_OFFSET;
+use crate::storages::stream::OPT_KEY_TABLE_CATALOG;
+use crate::storages::stream::OPT_KEY_TABLE_DATABASE;
+use crate::storages::stream::OPT_KEY_TABLE_ID;
+use crate::storages::stream::OPT_KEY_TABLE_NAME;
+use crate::storages::stream::STREAM_ENGINE;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(
+                &self.plan.catalog,
+                &self.plan.table_database,
+                &self.plan.table_name,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::UnexpectedError(format!(
+                "{}.{} is not a FUSE table, stream can only be created on FUSE tables",
+                &self.plan.table_database, &self.plan.table_name
+            ))
+        })?;
+
+        // A new stream starts from the current snapshot of the table,
+        // only rows appended after its creation will be returned.
+        let table_info = fuse_table.get_table_info();
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_TABLE_CATALOG.to_string(), self.plan.catalog.clone());
+        options.insert(
+            OPT_KEY_TABLE_DATABASE.to_string(),
+            self.plan.table_database.clone(),
+        );
+        options.insert(OPT_KEY_TABLE_NAME.to_string(), self.plan.table_name.clone());
+        options.insert(
+            OPT_KEY_TABLE_ID.to_string(),
+            table_info.ident.table_id.to_string(),
+        );
+        if let Some(database_id) = table_info.options().get(OPT_KEY_DATABASE_ID) {
+            options.insert(OPT_KEY_DATABASE_ID.to_string(), database_id.clone());
+        }
+        if let Some(snapshot_loc) = table_info.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+            options.insert(OPT_KEY_STREAM_OFFSET.to_string(), snapshot_loc.clone());
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let plan = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: table_info.schema(),
+                engine: STREAM_ENGINE.to_string(),
+                options,
+                comment: self.plan.comment.clone().unwrap_or_default(),
+                ..Default::default()
+            },
+        };
+        catalog.create_table(plan).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
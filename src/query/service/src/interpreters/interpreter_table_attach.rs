@@ -0,0 +1,209 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::TableStatistics;
+use common_storage::init_operator;
+use common_storage::parse_uri_location;
+use common_storage::UriLocation;
+
+use crate::connections::keep_location_storage;
+use crate::connections::resolve_uri_connection;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::AttachTablePlan;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::io::TableMetaLocationGenerator;
+use crate::storages::fuse::FUSE_OPT_KEY_ATTACH_READ_ONLY;
+use crate::storages::fuse::FUSE_OPT_KEY_ATTACH_STORAGE;
+use crate::storages::fuse::FUSE_OPT_KEY_ATTACH_STORAGE_PREFIX;
+use crate::storages::fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::storages::fuse::FUSE_TBL_SEGMENT_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::storages::fuse::OPT_KEY_DATABASE_ID;
+use crate::storages::fuse::OPT_KEY_SNAPSHOT_LOCATION;
+
+pub struct AttachTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AttachTablePlan,
+}
+
+impl AttachTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AttachTablePlan) -> Result<Self> {
+        Ok(AttachTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AttachTableInterpreter {
+    fn name(&self) -> &str {
+        "AttachTableInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let location = resolve_uri_connection(
+            &self.ctx.get_config(),
+            &self.plan.tenant,
+            &self.plan.location,
+        )
+        .await?;
+        let (storage, _) = parse_uri_location(&location)?;
+        if !storage.is_secure() && !self.ctx.get_config().storage.allow_insecure {
+            return Err(ErrorCode::StorageInsecure(
+                "read from insecure storage is not allowed",
+            ));
+        }
+
+        // The hint is kept in the location of the table by the last commit, it's the
+        // path of the last snapshot from the root of the storage of the table.
+        let operator = init_operator(&storage)?;
+        let hint = operator
+            .object(FUSE_TBL_LAST_SNAPSHOT_HINT)
+            .read()
+            .await
+            .map_err(|e| {
+                ErrorCode::TableInfoError(format!(
+                    "no fuse table found at the location {}: {}",
+                    location.path, e
+                ))
+            })?;
+        let hint = String::from_utf8(hint)?;
+        let snapshot_name = hint.rsplit('/').next().unwrap_or_default();
+        let snapshot_path = format!("{}/{}", FUSE_TBL_SNAPSHOT_PREFIX, snapshot_name);
+        let snapshot_version = TableMetaLocationGenerator::snapshot_version(&snapshot_path);
+        let reader = MetaReaders::table_snapshot_reader(self.ctx.clone(), operator);
+        let snapshot = reader
+            .read(snapshot_path.as_str(), None, snapshot_version)
+            .await?;
+
+        // The locations of the segments and the blocks are kept from the root of the
+        // storage of the table, which is the storage the table is attached to.
+        let segment = snapshot.segments.first().map(|(path, _)| path.as_str());
+        let (root, prefix) = split_table_location(&location.path, segment)?;
+        let (storage, _) = parse_uri_location(&UriLocation {
+            path: root.clone(),
+            ..location.clone()
+        })?;
+
+        let mut engine_options = BTreeMap::new();
+        engine_options.insert(
+            FUSE_OPT_KEY_ATTACH_STORAGE.to_string(),
+            serde_json::to_string(&storage)?,
+        );
+        // The parameters of the connection are not kept by the table.
+        keep_location_storage(
+            &UriLocation {
+                path: root,
+                ..self.plan.location.clone()
+            },
+            &mut engine_options,
+        )?;
+        engine_options.insert(
+            FUSE_OPT_KEY_ATTACH_STORAGE_PREFIX.to_string(),
+            prefix.clone(),
+        );
+        if self.plan.read_only {
+            engine_options.insert(FUSE_OPT_KEY_ATTACH_READ_ONLY.to_string(), "1".to_string());
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_DATABASE_ID.to_string(),
+            db.get_db_info().ident.db_id.to_string(),
+        );
+        options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_string(),
+            format!("{}/{}", prefix, snapshot_path),
+        );
+
+        let summary = &snapshot.summary;
+        let mut table_meta = TableMeta {
+            schema: Arc::new(snapshot.schema.clone()),
+            engine: "FUSE".to_string(),
+            engine_options,
+            options,
+            statistics: TableStatistics {
+                number_of_rows: summary.row_count,
+                data_bytes: summary.uncompressed_byte_size,
+                compressed_data_bytes: summary.compressed_byte_size,
+                index_data_bytes: summary.index_size,
+            },
+            ..Default::default()
+        };
+        // the blocks are clustered by the cluster key of the id kept in the snapshot
+        if let Some((id, cluster_key)) = &snapshot.cluster_key_meta {
+            table_meta.cluster_keys = vec![cluster_key.clone()];
+            table_meta.default_cluster_key = Some(cluster_key.clone());
+            table_meta.default_cluster_key_id = Some(*id);
+        }
+
+        let req = CreateTableReq {
+            if_not_exists: false,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            table_meta,
+        };
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+// Splits the path of the location of the table into the root of the storage and the
+// prefix of the table, by the location of a segment of the table, such as
+// `/root/1/2/` and `1/2/_sg/x.json` into `/root/` and `1/2`.
+//
+// The table without segments is kept in the last part of the path.
+fn split_table_location(path: &str, segment: Option<&str>) -> Result<(String, String)> {
+    let path = path.trim_end_matches('/');
+    let prefix = match segment {
+        Some(segment) => {
+            let pattern = format!("/{}/", FUSE_TBL_SEGMENT_PREFIX);
+            match segment.rfind(&pattern) {
+                Some(pos) => segment[..pos].to_string(),
+                None => {
+                    return Err(ErrorCode::TableInfoError(format!(
+                        "invalid location of the segment {}",
+                        segment
+                    )));
+                }
+            }
+        }
+        None => path.rsplit('/').next().unwrap_or_default().to_string(),
+    };
+
+    match path.strip_suffix(&prefix) {
+        Some(root) if !prefix.is_empty() && root.ends_with('/') => Ok((root.to_string(), prefix)),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "the location {} is not the location of the table kept in the prefix {}",
+            path, prefix
+        ))),
+    }
+}
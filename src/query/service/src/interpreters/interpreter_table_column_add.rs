@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::ColumnFiller;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_meta_types::Sequence;
+use common_users::UserApiProvider;
+
+use super::Interpreter;
+use crate::evaluator::EvalNode;
+use crate::evaluator::Evaluator;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalScalarBuilder;
+use crate::sql::plans::AddTableColumnPlan;
+
+pub struct AddTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableColumnPlan,
+}
+
+impl AddTableColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableColumnPlan) -> Result<Self> {
+        Ok(AddTableColumnInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "AddTableColumnInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        if let Some(sequence) = &plan.auto_increment_sequence {
+            UserApiProvider::instance()
+                .add_sequence(&tenant, Sequence::new(sequence, 1, 1), true)
+                .await?;
+        }
+
+        // The existing rows are backfilled by the default expression as the omitted columns
+        // of an insert, a non-constant expression is evaluated of each row.
+        let mut field = plan.field.clone();
+        let eval = match &plan.default_expr {
+            Some(scalar) => {
+                let mut builder = PhysicalScalarBuilder::new(&table.schema());
+                let physical_scalar = builder.build(scalar)?;
+                field = field.with_default_expr(Some(serde_json::to_string(&physical_scalar)?));
+                Evaluator::eval_physical_scalar(&physical_scalar)?
+            }
+            None => EvalNode::Constant {
+                value: field.data_type().default_value(),
+                data_type: field.data_type().clone(),
+            },
+        };
+        let func_ctx = self.ctx.try_get_function_context()?;
+        let fill: ColumnFiller = Arc::new(move |block: &DataBlock| {
+            let vector = eval.eval(&func_ctx, block)?;
+            Ok(vector.vector().convert_full_column())
+        });
+
+        table
+            .add_column(self.ctx.clone(), field, plan.comment.clone(), fill)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_planner::plans::SetTableColumnCommentPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct SetTableColumnCommentInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableColumnCommentPlan,
+}
+
+impl SetTableColumnCommentInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetTableColumnCommentPlan) -> Result<Self> {
+        Ok(SetTableColumnCommentInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableColumnCommentInterpreter {
+    fn name(&self) -> &str {
+        "SetTableColumnCommentInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(plan.tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        let schema = table_info.schema();
+        let index = schema.index_of(&plan.column).map_err(|_| {
+            ErrorCode::UnknownColumn(format!(
+                "column {} is not found in table {}",
+                plan.column, plan.table
+            ))
+        })?;
+
+        // The tables created by the old planner have no comment of the columns.
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .field_comments
+            .resize(schema.fields().len(), "".to_string());
+        new_table_meta.field_comments[index] = plan.comment.clone();
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog
+            .update_table_meta(&plan.tenant, &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::TableMaskingPolicies;
+use common_planner::plans::SetTableColumnMaskingPolicyPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_MASKING_POLICIES;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+pub struct SetTableColumnMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableColumnMaskingPolicyPlan,
+}
+
+impl SetTableColumnMaskingPolicyInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: SetTableColumnMaskingPolicyPlan,
+    ) -> Result<Self> {
+        Ok(SetTableColumnMaskingPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableColumnMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "SetTableColumnMaskingPolicyInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(plan.tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        if table.as_any().downcast_ref::<FuseTable>().is_none() {
+            return Err(ErrorCode::UnsupportedEngineParams(format!(
+                "masking policy can not be set to table {} of engine {}",
+                plan.table,
+                table.engine()
+            )));
+        }
+
+        let table_info = table.get_table_info();
+        let mut attached = match table_info.options().get(OPT_KEY_MASKING_POLICIES) {
+            Some(value) => TableMaskingPolicies::try_from_option_value(value)?,
+            None => TableMaskingPolicies::default(),
+        };
+        if let Some(policy) = attached.columns.get(&plan.column) {
+            return Err(ErrorCode::MaskingPolicyAlreadyExists(format!(
+                "column {} of table {} already has a masking policy {}, unset it first",
+                plan.column, plan.table, policy
+            )));
+        }
+        attached
+            .columns
+            .insert(plan.column.clone(), plan.policy.clone());
+
+        let req = UpsertTableOptionReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_MASKING_POLICIES.to_string(),
+                Some(attached.to_option_value()?),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -12,17 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_base::base::GlobalIORuntime;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::CommitTableReq;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
+use common_meta_types::Sequence;
 use common_users::UserApiProvider;
 
+use crate::catalogs::TEMP_TBL_ID_BEGIN;
+use crate::interpreters::interpreter_common::apply_future_grants;
 use crate::interpreters::InsertInterpreterV2;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -33,7 +42,12 @@ use crate::sql::plans::create_table_v2::CreateTablePlanV2;
 use crate::sql::plans::insert::Insert;
 use crate::sql::plans::insert::InsertInputSource;
 use crate::sql::plans::Plan;
+use crate::storages::memory::MemoryTable;
 use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+// The ids of the temporary tables, unique in the node.
+static NEXT_TEMPORARY_TABLE_ID: AtomicU64 = AtomicU64::new(TEMP_TBL_ID_BEGIN);
 
 pub struct CreateTableInterpreterV2 {
     ctx: Arc<QueryContext>,
@@ -53,6 +67,10 @@ impl Interpreter for CreateTableInterpreterV2 {
     }
 
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.plan.temporary {
+            return self.create_temporary_table().await;
+        }
+
         let tenant = self.plan.tenant.clone();
         let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
         let quota = quota_api.get_quota(None).await?.data;
@@ -100,6 +118,13 @@ impl Interpreter for CreateTableInterpreterV2 {
             }
         }
 
+        // The sequences of a table created before are reused, e.g. the table is recreated.
+        for sequence in &self.plan.auto_increment_sequences {
+            UserApiProvider::instance()
+                .add_sequence(&tenant, Sequence::new(sequence, 1, 1), true)
+                .await?;
+        }
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -112,12 +137,89 @@ impl CreateTableInterpreterV2 {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
 
-        // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.build_request()?).await?;
-        let table = catalog
-            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
-            .await?;
+        // An existing table is inserted into as before if IF NOT EXISTS is specified.
+        let existing = self.plan.if_not_exists
+            && catalog
+                .exists_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
+
+        // Otherwise the table is created as a staged table, which is invisible by its name, and
+        // is committed only after the insertion is committed, so a node that is down in the
+        // middle of the query leaves no half-created table, the dropped staged table is purged.
+        let (table, staged_table_id) = if existing {
+            let table = catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
+            (table, None)
+        } else {
+            let reply = catalog.create_staged_table(self.build_request()?).await?;
+            let (ident, meta) = catalog.get_table_meta_by_id(reply.table_id).await?;
+            let table_info = TableInfo::new(
+                &self.plan.database,
+                &self.plan.table,
+                ident,
+                meta.as_ref().clone(),
+            );
+            (
+                catalog.get_table_by_info(&table_info)?,
+                Some(reply.table_id),
+            )
+        };
+
+        let mut build_res = self.insert_select(table, select_plan).await?;
+
+        if let Some(table_id) = staged_table_id {
+            let name_ident = TableNameIdent {
+                tenant: self.plan.tenant.to_string(),
+                db_name: self.plan.database.to_string(),
+                table_name: self.plan.table.to_string(),
+            };
+            let catalog_name = self.plan.catalog.clone();
+
+            // Runs after the insertion is committed, and is not run if the insertion fails.
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                if let Some(error) = may_error {
+                    return Err(error.clone());
+                }
+
+                let catalog = catalog.clone();
+                let req = CommitTableReq {
+                    name_ident: name_ident.clone(),
+                    table_id,
+                };
+                let catalog_name = catalog_name.clone();
+                let commit_handle = GlobalIORuntime::instance().spawn(async move {
+                    let name_ident = req.name_ident.clone();
+                    catalog.commit_staged_table(req).await?;
+                    apply_future_grants(
+                        &name_ident.tenant,
+                        &catalog_name,
+                        &name_ident.db_name,
+                        &name_ident.table_name,
+                    )
+                    .await
+                });
+
+                match futures::executor::block_on(commit_handle) {
+                    Ok(Ok(_)) => Ok(()),
+                    Ok(Err(error)) => Err(error),
+                    Err(cause) => Err(ErrorCode::PanicError(format!(
+                        "Maybe panic while in commit the created table. {}",
+                        cause
+                    ))),
+                }
+            });
+        }
 
+        Ok(build_res)
+    }
+
+    // Inserts the result of the query into the created table.
+    async fn insert_select(
+        &self,
+        table: Arc<dyn Table>,
+        select_plan: Box<Plan>,
+    ) -> Result<PipelineBuildResult> {
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
         // we use the definitions to create the table schema. It may happen that the "AS SELECT" query's schema doesn't
         // match the table's schema. For example,
@@ -146,15 +248,89 @@ impl CreateTableInterpreterV2 {
             source: InsertInputSource::SelectPlan(select_plan),
         };
 
-        InsertInterpreterV2::try_create(self.ctx.clone(), insert_plan, false)?
+        InsertInterpreterV2::try_create_with_table(self.ctx.clone(), insert_plan, table)?
             .execute2()
             .await
     }
 
+    // The temporary table is kept in the session instead of the catalog, of the memory engine, it
+    // hides the table of the same name in the catalog until it's dropped, or the session ends.
+    async fn create_temporary_table(&self) -> Result<PipelineBuildResult> {
+        let session = self.ctx.get_current_session();
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        // The table is created in an existing database, as the permanent tables.
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        catalog.get_database(&self.plan.tenant, db_name).await?;
+
+        // An existing table is inserted into as before if IF NOT EXISTS is specified.
+        if let Some(table) = session.get_temporary_table(catalog_name, db_name, tbl_name) {
+            if !self.plan.if_not_exists {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "Temporary table '{}.{}' already exists",
+                    db_name, tbl_name
+                )));
+            }
+            return match &self.plan.as_select {
+                Some(select_plan) => self.insert_select(table, select_plan.clone()).await,
+                None => Ok(PipelineBuildResult::create()),
+            };
+        }
+
+        let table_id = NEXT_TEMPORARY_TABLE_ID.fetch_add(1, Ordering::Relaxed);
+        let table_info = TableInfo::new(
+            db_name,
+            tbl_name,
+            TableIdent::new(table_id, 0),
+            self.build_request()?.table_meta,
+        );
+        let table: Arc<dyn Table> = MemoryTable::create_temporary(table_info).into();
+
+        let select_plan = match &self.plan.as_select {
+            Some(select_plan) => select_plan.clone(),
+            None => {
+                session.add_temporary_table(catalog_name, db_name, table);
+                return Ok(PipelineBuildResult::create());
+            }
+        };
+
+        // The table is added to the session after the insertion is committed, as the staged tables.
+        let mut build_res = self.insert_select(table.clone(), select_plan).await?;
+        let catalog_name = catalog_name.to_string();
+        let db_name = db_name.to_string();
+        build_res.main_pipeline.set_on_finished(move |may_error| {
+            if let Some(error) = may_error {
+                return Err(error.clone());
+            }
+            session.add_temporary_table(&catalog_name, &db_name, table.clone());
+            Ok(())
+        });
+        Ok(build_res)
+    }
+
     async fn create_table(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
+
+        // The future grants are applied to the tables created only.
+        let existing = self.plan.if_not_exists
+            && catalog
+                .exists_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
         catalog.create_table(self.build_request()?).await?;
 
+        if !existing {
+            apply_future_grants(
+                tenant.as_str(),
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        }
+
         Ok(PipelineBuildResult::create())
     }
 
@@ -180,6 +356,7 @@ impl CreateTableInterpreterV2 {
         let mut table_meta = TableMeta {
             schema,
             engine: self.plan.engine.to_string(),
+            engine_options: self.plan.engine_options.clone(),
             options: self.plan.options.clone(),
             default_cluster_key: None,
             field_comments: self.plan.field_comments.clone(),
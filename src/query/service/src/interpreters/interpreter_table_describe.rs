@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -28,6 +29,7 @@ use crate::sql::executor::PhysicalScalar;
 use crate::sql::Planner;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::view::view_table::VIEW_ENGINE;
+use crate::storages::Table;
 
 pub struct DescribeTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -57,7 +59,8 @@ impl Interpreter for DescribeTableInterpreter {
         let table = self.ctx.get_table(catalog, database, table).await?;
         let tbl_info = table.get_table_info();
 
-        let schema = if tbl_info.engine() == VIEW_ENGINE {
+        let is_view = tbl_info.engine() == VIEW_ENGINE;
+        let schema = if is_view {
             if let Some(query) = tbl_info.options().get(QUERY) {
                 let mut planner = Planner::new(self.ctx.clone());
                 let (plan, _, _) = planner.plan_sql(query).await?;
@@ -101,12 +104,76 @@ impl Interpreter for DescribeTableInterpreter {
             extras.push("".to_string());
         }
 
-        PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), vec![
+        let mut columns = vec![
             Series::from_data(names),
             Series::from_data(types),
             Series::from_data(nulls),
             Series::from_data(default_exprs),
             Series::from_data(extras),
-        ])])
+        ];
+        if self.plan.extended {
+            columns.extend(
+                self.extended_columns(table.as_ref(), &schema, is_view)
+                    .await?,
+            );
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), columns)])
+    }
+}
+
+impl DescribeTableInterpreter {
+    // The comments of the columns, the sizes of the columns in the storage, and the number of
+    // the distinct values and the nulls aggregated from the statistics of the segments. The
+    // views keep no comment or statistics.
+    async fn extended_columns(
+        &self,
+        table: &dyn Table,
+        schema: &DataSchemaRef,
+        is_view: bool,
+    ) -> Result<Vec<ColumnRef>> {
+        let (column_sizes, column_stats) = match is_view {
+            true => (HashMap::new(), HashMap::new()),
+            false => (
+                table.column_sizes(self.ctx.clone()).await?,
+                table.column_statistics(self.ctx.clone()).await?,
+            ),
+        };
+
+        let num_fields = schema.num_fields();
+        // compatibility: the tables created in the old planner have no comment of the columns
+        let field_comments = table.field_comments();
+        let comments = match !is_view && field_comments.len() == num_fields {
+            true => field_comments.clone(),
+            false => vec!["".to_string(); num_fields],
+        };
+
+        let mut uncompressed_sizes = Vec::with_capacity(num_fields);
+        let mut compressed_sizes = Vec::with_capacity(num_fields);
+        let mut compression_ratios = Vec::with_capacity(num_fields);
+        let mut distinct_values = Vec::with_capacity(num_fields);
+        let mut null_counts = Vec::with_capacity(num_fields);
+        for index in 0..num_fields {
+            let size = column_sizes.get(&index);
+            uncompressed_sizes.push(size.map(|size| size.uncompressed_size));
+            compressed_sizes.push(size.map(|size| size.compressed_size));
+            compression_ratios.push(size.and_then(|size| match size.compressed_size {
+                0 => None,
+                compressed_size => Some(size.uncompressed_size as f64 / compressed_size as f64),
+            }));
+
+            let stats = column_stats.get(&index);
+            distinct_values.push(stats.and_then(|stats| stats.number_of_distinct_values));
+            null_counts.push(stats.map(|stats| stats.null_count));
+        }
+
+        Ok(vec![
+            Series::from_data(comments),
+            Series::from_data(uncompressed_sizes),
+            Series::from_data(compressed_sizes),
+            Series::from_data(compression_ratios),
+            Series::from_data(distinct_values),
+            Series::from_data(null_counts),
+        ])
     }
 }
@@ -16,12 +16,15 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::TableNameIdent;
 use common_planner::plans::DropTablePlan;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
 use crate::storages::view::view_table::VIEW_ENGINE;
 
 pub struct DropTableInterpreter {
@@ -45,6 +48,15 @@ impl Interpreter for DropTableInterpreter {
         let catalog_name = self.plan.catalog.as_str();
         let db_name = self.plan.database.as_str();
         let tbl_name = self.plan.table.as_str();
+
+        // The temporary table of the session is dropped instead of the table in the catalog.
+        let session = self.ctx.get_current_session();
+        if let Some(tbl) = session.remove_temporary_table(catalog_name, db_name, tbl_name) {
+            // the blocks are released at once, the running queries may still hold the table
+            let _ = tbl.truncate(self.ctx.clone(), true).await;
+            return Ok(PipelineBuildResult::create());
+        }
+
         let tbl = self
             .ctx
             .get_table(catalog_name, db_name, tbl_name)
@@ -64,6 +76,20 @@ impl Interpreter for DropTableInterpreter {
         catalog.drop_table(self.plan.clone().into()).await?;
 
         if let Some(tbl) = tbl {
+            // The aggregating indexes of the table are dropped with it.
+            for index in FuseTable::parse_aggregating_indexes(tbl.options()) {
+                catalog
+                    .drop_table(DropTableReq {
+                        if_exists: true,
+                        name_ident: TableNameIdent {
+                            tenant: self.plan.tenant.clone(),
+                            db_name: self.plan.database.clone(),
+                            table_name: index,
+                        },
+                    })
+                    .await?;
+            }
+
             // if `plan.all`, truncate, then purge the historical data
             if self.plan.all {
                 // errors of truncation are ignored
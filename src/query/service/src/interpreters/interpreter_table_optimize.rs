@@ -14,10 +14,13 @@
 
 use std::sync::Arc;
 
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planner::plans::OptimizeTableAction;
 use common_planner::plans::OptimizeTablePlan;
 
+use crate::interpreters::lock_table_for_mutation;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -43,6 +46,10 @@ impl Interpreter for OptimizeTableInterpreter {
         "OptimizeTableInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = &self.plan;
         let ctx = self.ctx.clone();
@@ -51,6 +58,21 @@ impl Interpreter for OptimizeTableInterpreter {
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
 
+        if plan.action == OptimizeTableAction::Verify {
+            // verifying reads the table only
+            let corrupted = table.verify(ctx.clone()).await?;
+            if corrupted.is_empty() {
+                return Ok(PipelineBuildResult::create());
+            }
+            let (objects, errors): (Vec<_>, Vec<_>) = corrupted.into_iter().unzip();
+            return PipelineBuildResult::from_blocks(vec![DataBlock::create(plan.schema(), vec![
+                Series::from_data(objects),
+                Series::from_data(errors),
+            ])]);
+        }
+
+        let lock = lock_table_for_mutation(&ctx, &plan.catalog, table.as_ref(), "optimize").await?;
+
         let action = &plan.action;
         let do_purge = matches!(
             action,
@@ -68,7 +90,8 @@ impl Interpreter for OptimizeTableInterpreter {
             if let Some(mutator) = mutator {
                 let settings = ctx.get_settings();
                 pipeline.set_max_threads(settings.get_max_threads()? as usize);
-                let executor_settings = ExecutorSettings::try_create(&settings)?;
+                let executor_settings = ExecutorSettings::try_create(&settings)?
+                    .with_query_memory_tracker(ctx.get_memory_tracker());
                 let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
 
                 ctx.set_executor(Arc::downgrade(&executor.get_inner()));
@@ -93,6 +116,9 @@ impl Interpreter for OptimizeTableInterpreter {
             table.optimize(self.ctx.clone(), true).await?;
         }
 
+        if let Some(lock) = lock {
+            lock.release().await?;
+        }
         Ok(PipelineBuildResult::create())
     }
 }
@@ -18,6 +18,7 @@ use std::time::SystemTime;
 use common_exception::Result;
 use common_legacy_planners::Extras;
 
+use crate::interpreters::lock_table_for_mutation;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
 use crate::pipelines::executor::ExecutorSettings;
@@ -65,6 +66,13 @@ impl Interpreter for ReclusterTableInterpreter {
                 })
             }
         };
+
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let lock =
+            lock_table_for_mutation(&ctx, &plan.catalog, table.as_ref(), "recluster").await?;
         loop {
             let table = self
                 .ctx
@@ -84,7 +92,8 @@ impl Interpreter for ReclusterTableInterpreter {
 
             pipeline.set_max_threads(settings.get_max_threads()? as usize);
 
-            let executor_settings = ExecutorSettings::try_create(&settings)?;
+            let executor_settings = ExecutorSettings::try_create(&settings)?
+                .with_query_memory_tracker(ctx.get_memory_tracker());
             let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
 
             ctx.set_executor(Arc::downgrade(&executor.get_inner()));
@@ -98,6 +107,10 @@ impl Interpreter for ReclusterTableInterpreter {
             }
         }
 
+        if let Some(lock) = lock {
+            lock.release().await?;
+        }
+
         InterpreterClusteringHistory::write_log(&ctx, start, &plan.database, &plan.table)?;
 
         Ok(PipelineBuildResult::create())
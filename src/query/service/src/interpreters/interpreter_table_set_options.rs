@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_planner::plans::SetTableOptionsPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_COMMENT;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+pub struct SetTableOptionsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableOptionsPlan,
+}
+
+impl SetTableOptionsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetTableOptionsPlan) -> Result<Self> {
+        Ok(SetTableOptionsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableOptionsInterpreter {
+    fn name(&self) -> &str {
+        "SetTableOptionsInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(plan.tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        // The options are only used by the fuse tables by now, the comment is kept by any table.
+        let only_comment = plan.set_options.keys().all(|k| k == OPT_KEY_COMMENT);
+        if !only_comment && table.as_any().downcast_ref::<FuseTable>().is_none() {
+            return Err(ErrorCode::UnsupportedEngineParams(format!(
+                "table options can not be set for table {} of engine {}",
+                plan.table,
+                table.engine()
+            )));
+        }
+
+        let table_info = table.get_table_info();
+        let req = UpsertTableOptionReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: plan
+                .set_options
+                .iter()
+                .map(|(k, v)| (k.clone(), Some(v.clone())))
+                .collect(),
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
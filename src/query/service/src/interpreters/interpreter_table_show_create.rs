@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use common_meta_types::TableCollations;
 use common_planner::plans::ShowCreateTablePlan;
 use tracing::debug;
 
@@ -26,6 +28,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::executor::PhysicalScalar;
 use crate::sql::is_internal_opt_key;
+use crate::sql::OPT_KEY_COLLATIONS;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -62,34 +65,46 @@ impl Interpreter for ShowCreateTableInterpreter {
         let field_comments = table.field_comments();
         let n_fields = schema.fields().len();
 
-        let mut table_create_sql = format!("CREATE TABLE `{}` (\n", name);
+        let table_info = table.get_table_info();
+        let collations = match table_info.options().get(OPT_KEY_COLLATIONS) {
+            Some(value) => TableCollations::try_from_option_value(value)?,
+            None => TableCollations::default(),
+        };
+        let format = self.ctx.get_format_settings()?;
+        // The transient tables are flagged by an option of the table.
+        let transient = table_info.options().contains_key("TRANSIENT");
+        let mut table_create_sql = match transient {
+            true => format!("CREATE TRANSIENT TABLE `{}` (\n", name),
+            false => format!("CREATE TABLE `{}` (\n", name),
+        };
 
         // Append columns.
         {
             let mut columns = vec![];
             for (idx, field) in schema.fields().iter().enumerate() {
+                let collation = match collations.columns.get(field.name()) {
+                    Some(collation) => format!(" COLLATE {}", quote_string(collation)),
+                    None => "".to_string(),
+                };
                 let default_expr = match field.default_expr() {
                     Some(expr) => {
                         let expression: PhysicalScalar = serde_json::from_str(expr)?;
-                        format!(" DEFAULT {expression}")
+                        format!(" DEFAULT {}", format_scalar_sql(&expression, &format)?)
                     }
                     None => "".to_string(),
                 };
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
-                    // make the display more readable.
-                    format!(
-                        " COMMENT '{}'",
-                        &field_comments[idx].as_str().replace('\'', "\\'")
-                    )
+                    format!(" COMMENT {}", quote_string(&field_comments[idx]))
                 } else {
                     "".to_string()
                 };
                 let column = format!(
-                    "  `{}` {}{}{}",
+                    "  `{}` {}{}{}{}",
                     field.name(),
                     format_data_type_sql(field.data_type()),
+                    collation,
                     default_expr,
                     comment
                 );
@@ -108,7 +123,6 @@ impl Interpreter for ShowCreateTableInterpreter {
         let table_engine = format!(") ENGINE={}", engine);
         table_create_sql.push_str(table_engine.as_str());
 
-        let table_info = table.get_table_info();
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
@@ -117,8 +131,8 @@ impl Interpreter for ShowCreateTableInterpreter {
             let mut opts = table_info.options().iter().collect::<Vec<_>>();
             opts.sort_by_key(|(k, _)| *k);
             opts.iter()
-                .filter(|(k, _)| !is_internal_opt_key(k))
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                .filter(|(k, _)| !is_internal_opt_key(k) && k.as_str() != "TRANSIENT")
+                .map(|(k, v)| format!(" {}={}", k.to_uppercase(), quote_string(v)))
                 .collect::<Vec<_>>()
                 .join("")
                 .as_str()
@@ -133,3 +147,65 @@ impl Interpreter for ShowCreateTableInterpreter {
         PipelineBuildResult::from_blocks(vec![block])
     }
 }
+
+// Quotes the string as a SQL literal.
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+// Formats the default expression the way it's parsed back: the constants of the strings, the
+// dates and the timestamps are quoted, and the casts are kept.
+fn format_scalar_sql(scalar: &PhysicalScalar, format: &FormatSettings) -> Result<String> {
+    match scalar {
+        PhysicalScalar::Constant { value, data_type } => {
+            format_constant_sql(value, data_type, format)
+        }
+        PhysicalScalar::Function { name, args, .. } => {
+            let args = args
+                .iter()
+                .map(|(arg, _)| format_scalar_sql(arg, format))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("{}({})", name, args.join(", ")))
+        }
+        PhysicalScalar::Cast { input, target } => Ok(format!(
+            "CAST({} AS {})",
+            format_scalar_sql(input, format)?,
+            format_data_type_sql(target)
+        )),
+        PhysicalScalar::IndexedVariable { display_name, .. } => Ok(display_name.clone()),
+    }
+}
+
+fn format_constant_sql(
+    value: &DataValue,
+    data_type: &DataTypeImpl,
+    format: &FormatSettings,
+) -> Result<String> {
+    let data_type = remove_nullable(data_type);
+    match (value, data_type.data_type_id()) {
+        (DataValue::Null, _) => Ok("NULL".to_string()),
+        // The dates and the timestamps are kept as the numbers of days and microseconds.
+        (_, TypeID::Date | TypeID::Timestamp) => {
+            let column = data_type
+                .create_constant_column(value, 1)?
+                .convert_full_column();
+            let serializer = data_type.create_serializer(&column)?;
+            Ok(quote_string(&serializer.serialize_field(0, format)?))
+        }
+        (DataValue::String(bytes), _) => Ok(quote_string(&String::from_utf8_lossy(bytes))),
+        (DataValue::Variant(v), _) => Ok(quote_string(&v.to_string())),
+        (DataValue::Array(values), TypeID::Array) => {
+            let inner_type = data_type
+                .as_any()
+                .downcast_ref::<ArrayType>()
+                .map(|array| array.inner_type().clone())
+                .unwrap_or_else(|| data_type.clone());
+            let values = values
+                .iter()
+                .map(|value| format_constant_sql(value, &inner_type, format))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("[{}]", values.join(", ")))
+        }
+        _ => Ok(value.to_string()),
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_app::schema::SwapTableReq;
+use common_meta_app::schema::TableNameIdent;
+use common_planner::plans::SwapTablePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SwapTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SwapTablePlan,
+}
+
+impl SwapTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SwapTablePlan) -> Result<Self> {
+        Ok(SwapTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SwapTableInterpreter {
+    fn name(&self) -> &str {
+        "SwapTableInterpreter"
+    }
+
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        if self.plan.if_exists
+            && !catalog
+                .exists_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // Both names are changed in one transaction of the meta-service, there is no moment
+        // at which either of the names refers to no table.
+        catalog
+            .swap_table(SwapTableReq {
+                name_ident: TableNameIdent {
+                    tenant: self.plan.tenant.clone(),
+                    db_name: self.plan.database.clone(),
+                    table_name: self.plan.table.clone(),
+                },
+                target_table_name: self.plan.target_table.clone(),
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
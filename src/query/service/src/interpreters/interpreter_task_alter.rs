@@ -0,0 +1,85 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planner::plans::AlterTaskPlan;
+use common_planner::plans::TaskAction;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTaskPlan,
+}
+
+impl AlterTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTaskPlan) -> Result<Self> {
+        Ok(AlterTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTaskInterpreter {
+    fn name(&self) -> &str {
+        "AlterTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The background tasks run on each node, the statement alters the task of the node it's
+        // executed on. Otherwise it alters the scheduled task of the tenant.
+        if let Ok(task) = SessionManager::instance()
+            .background_tasks
+            .get(&self.plan.name)
+        {
+            match self.plan.action {
+                TaskAction::Suspend => task.suspend(),
+                TaskAction::Resume => task.resume(),
+                TaskAction::Execute => task.trigger(),
+            }
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        match self.plan.action {
+            TaskAction::Suspend => {
+                user_mgr
+                    .set_task_suspended(&tenant, &self.plan.name, true)
+                    .await?
+            }
+            TaskAction::Resume => {
+                user_mgr
+                    .set_task_suspended(&tenant, &self.plan.name, false)
+                    .await?
+            }
+            TaskAction::Execute => {
+                let task = user_mgr.get_task(&tenant, &self.plan.name).await?.data;
+                SessionManager::instance()
+                    .task_scheduler
+                    .execute(&tenant, task)?;
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
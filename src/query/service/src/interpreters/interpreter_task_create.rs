@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::CreateTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTaskPlan,
+}
+
+impl CreateTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTaskPlan) -> Result<Self> {
+        Ok(CreateTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTaskInterpreter {
+    fn name(&self) -> &str {
+        "CreateTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        // ALTER TASK alters the background task of the same name first, the names must not clash.
+        if SessionManager::instance()
+            .background_tasks
+            .get(&plan.task.name)
+            .is_ok()
+        {
+            return Err(ErrorCode::TaskAlreadyExists(format!(
+                "task {} is a background task of the node",
+                plan.task.name
+            )));
+        }
+        if let Some(after) = &plan.task.after {
+            UserApiProvider::instance().get_task(&tenant, after).await?;
+        }
+
+        // The task runs as the user creating it, in the current database.
+        let mut task = plan.task;
+        task.owner = self.ctx.get_current_user()?.identity();
+        task.database = self.ctx.get_current_database();
+        task.created_on = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+        let _ = UserApiProvider::instance()
+            .add_task(&tenant, task, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
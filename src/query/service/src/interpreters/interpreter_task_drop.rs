@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::DropTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTaskPlan,
+}
+
+impl DropTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTaskPlan) -> Result<Self> {
+        Ok(DropTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTaskInterpreter {
+    fn name(&self) -> &str {
+        "DropTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        // The tasks after the task would never run once it's dropped.
+        let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+        if let Some(successor) = tasks
+            .iter()
+            .find(|task| task.after.as_ref() == Some(&plan.name))
+        {
+            return Err(ErrorCode::TaskHasSuccessor(format!(
+                "task {} runs after task {}, drop it first",
+                successor.name, plan.name
+            )));
+        }
+
+        UserApiProvider::instance()
+            .drop_task(&tenant, &plan.name, plan.if_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
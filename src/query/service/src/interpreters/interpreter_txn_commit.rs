@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let session = self.ctx.get_current_session();
+        // COMMIT out of a transaction does nothing, as in MySQL and PostgreSQL.
+        let txn = match session.get_transaction() {
+            None => return Ok(PipelineBuildResult::create()),
+            Some(txn) => txn,
+        };
+        // The transaction ends even if it fails to commit, the tables it stages are stale then.
+        session.set_transaction(None);
+
+        if let Some((catalog, req)) = txn.commit_request() {
+            let catalog = self.ctx.get_catalog(&catalog)?;
+            match catalog.update_multi_table_meta(req).await {
+                Ok(_) => {}
+                Err(cause) if cause.code() == ErrorCode::table_version_mismatched_code() => {
+                    return Err(ErrorCode::TransactionConflict(format!(
+                        "the transaction is rolled back, the tables it mutates are changed by the others: {}",
+                        cause.message()
+                    )));
+                }
+                Err(cause) => return Err(cause),
+            }
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct RollbackInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl RollbackInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(RollbackInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RollbackInterpreter {
+    fn name(&self) -> &str {
+        "RollbackInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The staged tables are dropped, the snapshots and the blocks they write to the storage
+        // are left to the purge, as the ones of a failed mutation.
+        self.ctx.get_current_session().set_transaction(None);
+        Ok(PipelineBuildResult::create())
+    }
+}
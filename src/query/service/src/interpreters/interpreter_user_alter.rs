@@ -45,6 +45,24 @@ impl Interpreter for AlterUserInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.plan.clone();
         let tenant = self.ctx.get_tenant();
+        if let Some(name) = plan
+            .user_option
+            .as_ref()
+            .and_then(|option| option.network_policy())
+        {
+            UserApiProvider::instance()
+                .get_network_policy(&tenant, name)
+                .await?;
+        }
+        if let Some(name) = plan
+            .user_option
+            .as_ref()
+            .and_then(|option| option.resource_group())
+        {
+            UserApiProvider::instance()
+                .get_resource_group(&tenant, name)
+                .await?;
+        }
         if plan.auth_info.is_some() || plan.user_option.is_some() {
             UserApiProvider::instance()
                 .update_user(&tenant, plan.user, plan.auth_info, plan.user_option)
@@ -51,6 +51,12 @@ impl Interpreter for CreateUserInterpreter {
 
         let user_mgr = UserApiProvider::instance();
         user_mgr.ensure_builtin_roles(&tenant).await?;
+        if let Some(name) = plan.user_option.network_policy() {
+            user_mgr.get_network_policy(&tenant, name).await?;
+        }
+        if let Some(name) = plan.user_option.resource_group() {
+            user_mgr.get_resource_group(&tenant, name).await?;
+        }
 
         let user_info = UserInfo {
             auth_info: plan.auth_info.clone(),
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataValue;
+use common_datavalues::NullType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::SetUserVariablePlan;
+use futures::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreterV2;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
+
+#[derive(Debug)]
+pub struct SetUserVariableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetUserVariablePlan,
+}
+
+impl SetUserVariableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetUserVariablePlan) -> Result<Self> {
+        Ok(SetUserVariableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetUserVariableInterpreter {
+    fn name(&self) -> &str {
+        "SetUserVariableInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The expression is evaluated by a query, so the subqueries and the non-deterministic
+        // functions are evaluated once, the variable holds the value but not the expression.
+        let query = format!("SELECT {}", self.plan.expr);
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _, _) = planner.plan_sql(&query).await?;
+
+        let stream = if let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } = plan
+        {
+            let interpreter = SelectInterpreterV2::try_create(
+                self.ctx.clone(),
+                *bind_context,
+                *s_expr,
+                metadata,
+                None,
+            )?;
+            interpreter.execute(self.ctx.clone()).await?
+        } else {
+            return Err(ErrorCode::LogicalError("set variable build query error"));
+        };
+
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let (value, data_type) = match blocks.iter().find(|block| block.num_rows() != 0) {
+            Some(block) => (
+                block.column(0).get_checked(0)?,
+                block.schema().field(0).data_type().clone(),
+            ),
+            None => (DataValue::Null, NullType::new_impl()),
+        };
+
+        self.ctx
+            .get_current_session()
+            .set_variable(self.plan.variable.clone(), value, data_type);
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -13,35 +13,60 @@
 // limitations under the License.
 
 mod access;
-// mod async_insert_queue_v2;
+mod async_insert_queue_v2;
 mod fragments;
 mod interpreter;
+mod interpreter_aggregating_index_create;
+mod interpreter_aggregating_index_drop;
+mod interpreter_audit_log;
 mod interpreter_call;
 mod interpreter_cluster_key_alter;
 mod interpreter_cluster_key_drop;
 mod interpreter_clustering_history;
 mod interpreter_common;
+mod interpreter_connection_create;
+mod interpreter_connection_drop;
 mod interpreter_copy_v2;
+mod interpreter_database_backup;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
+mod interpreter_database_restore;
+mod interpreter_database_set_comment;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
+mod interpreter_dictionary_create;
+mod interpreter_dictionary_drop;
+mod interpreter_execute_immediate;
 mod interpreter_explain_v2;
 mod interpreter_factory;
 mod interpreter_insert_v2;
 mod interpreter_kill;
 mod interpreter_list;
+mod interpreter_masking_policy_create;
+mod interpreter_masking_policy_drop;
+mod interpreter_network_policy_create;
+mod interpreter_network_policy_drop;
+mod interpreter_node_drain;
+mod interpreter_plan_hint_create;
+mod interpreter_plan_hint_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_query_log;
+mod interpreter_resource_group_create;
+mod interpreter_resource_group_drop;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
+mod interpreter_role_set;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select_v2;
+mod interpreter_sequence_create;
+mod interpreter_sequence_drop;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
@@ -53,6 +78,15 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_slow_query_log;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
+mod interpreter_table_analyze;
+mod interpreter_table_attach;
+mod interpreter_table_column_add;
+mod interpreter_table_column_comment_set;
+mod interpreter_table_column_masking_policy_set;
+mod interpreter_table_column_masking_policy_unset;
 mod interpreter_table_create_v2;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
@@ -60,9 +94,19 @@ mod interpreter_table_exists;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
+mod interpreter_table_row_access_policy_add;
+mod interpreter_table_row_access_policy_drop;
+mod interpreter_table_set_options;
 mod interpreter_table_show_create;
+mod interpreter_table_swap;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_task_alter;
+mod interpreter_task_create;
+mod interpreter_task_drop;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
+mod interpreter_txn_rollback;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
@@ -73,18 +117,33 @@ mod interpreter_user_stage_remove;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
+mod interpreter_user_variable_set;
+mod interpreter_user_variable_unset;
 mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
+mod interpreter_virtual_column_add;
+mod interpreter_virtual_column_drop;
 mod plan_schedulers;
 mod stream;
 
+pub use access::is_read_only_plan;
 pub use access::ManagementModeAccess;
+pub use access::ReadOnlyModeAccess;
+pub use async_insert_queue_v2::AsyncInsertQueue;
+pub use async_insert_queue_v2::InsertBatch;
+pub use async_insert_queue_v2::InsertKey;
+pub use async_insert_queue_v2::ASYNC_INSERT_FLUSH_TASK;
+pub use fragments::ExecutorsHashRing;
 pub use fragments::QueryFragmentAction;
 pub use fragments::QueryFragmentActions;
 pub use fragments::QueryFragmentsActions;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_aggregating_index_create::CreateAggregatingIndexInterpreter;
+pub use interpreter_aggregating_index_drop::DropAggregatingIndexInterpreter;
+pub use interpreter_audit_log::audit_category;
+pub use interpreter_audit_log::InterpreterAuditLog;
 pub use interpreter_call::CallInterpreter;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
@@ -92,25 +151,51 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_common::append2table;
 pub use interpreter_common::execute_pipeline;
 pub use interpreter_common::fill_missing_columns;
+pub use interpreter_common::lock_table_for_mutation;
+pub use interpreter_common::refresh_aggregating_index;
+pub use interpreter_common::refresh_aggregating_indexes_on_finished;
+pub use interpreter_common::replace2table;
+pub use interpreter_connection_create::CreateConnectionInterpreter;
+pub use interpreter_connection_drop::DropConnectionInterpreter;
+pub use interpreter_database_backup::BackupDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_restore::RestoreDatabaseInterpreter;
+pub use interpreter_database_set_comment::SetDatabaseCommentInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
+pub use interpreter_dictionary_create::CreateDictionaryInterpreter;
+pub use interpreter_dictionary_drop::DropDictionaryInterpreter;
+pub use interpreter_execute_immediate::ExecuteImmediateInterpreter;
 pub use interpreter_explain_v2::ExplainInterpreterV2;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert_v2::InsertInterpreterV2;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
+pub use interpreter_masking_policy_create::CreateMaskingPolicyInterpreter;
+pub use interpreter_masking_policy_drop::DropMaskingPolicyInterpreter;
+pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_node_drain::DrainNodeInterpreter;
+pub use interpreter_plan_hint_create::CreatePlanHintInterpreter;
+pub use interpreter_plan_hint_drop::DropPlanHintInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
+pub use interpreter_resource_group_create::CreateResourceGroupInterpreter;
+pub use interpreter_resource_group_drop::DropResourceGroupInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
+pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select_v2::SelectInterpreterV2;
+pub use interpreter_sequence_create::CreateSequenceInterpreter;
+pub use interpreter_sequence_drop::DropSequenceInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
@@ -121,6 +206,16 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_slow_query_log::format_processors_profile;
+pub use interpreter_slow_query_log::InterpreterSlowQueryLog;
+pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_drop::DropStreamInterpreter;
+pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_attach::AttachTableInterpreter;
+pub use interpreter_table_column_add::AddTableColumnInterpreter;
+pub use interpreter_table_column_comment_set::SetTableColumnCommentInterpreter;
+pub use interpreter_table_column_masking_policy_set::SetTableColumnMaskingPolicyInterpreter;
+pub use interpreter_table_column_masking_policy_unset::UnsetTableColumnMaskingPolicyInterpreter;
 pub use interpreter_table_create_v2::CreateTableInterpreterV2;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
@@ -128,9 +223,19 @@ pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_row_access_policy_add::AddTableRowAccessPolicyInterpreter;
+pub use interpreter_table_row_access_policy_drop::DropTableRowAccessPolicyInterpreter;
+pub use interpreter_table_set_options::SetTableOptionsInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
+pub use interpreter_table_swap::SwapTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_task_alter::AlterTaskInterpreter;
+pub use interpreter_task_create::CreateTaskInterpreter;
+pub use interpreter_task_drop::DropTaskInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
+pub use interpreter_txn_rollback::RollbackInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
@@ -141,7 +246,11 @@ pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFInterpreter;
 pub use interpreter_user_udf_create::CreateUserUDFInterpreter;
 pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
+pub use interpreter_user_variable_set::SetUserVariableInterpreter;
+pub use interpreter_user_variable_unset::UnsetUserVariableInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
+pub use interpreter_virtual_column_add::AddTableVirtualColumnInterpreter;
+pub use interpreter_virtual_column_drop::DropTableVirtualColumnInterpreter;
 pub use stream::PullingExecutorStream;
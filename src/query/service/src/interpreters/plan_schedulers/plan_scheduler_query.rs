@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use tracing::warn;
 
+use crate::clusters::ClusterDiscovery;
 use crate::interpreters::fragments::Fragmenter;
 use crate::interpreters::fragments::QueryFragmentsActions;
 use crate::pipelines::PipelineBuildResult;
@@ -25,6 +28,8 @@ use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PipelineBuilder as PipelineBuilderV2;
 use crate::sql::ColumnBinding;
 
+const MAX_RESCHEDULE_TIMES: usize = 3;
+
 pub async fn schedule_query_v2(
     ctx: Arc<QueryContext>,
     result_columns: &[ColumnBinding],
@@ -60,19 +65,71 @@ pub async fn build_schedule_pipeline(
     ctx: Arc<QueryContext>,
     plan: &PhysicalPlan,
 ) -> Result<PipelineBuildResult> {
-    let fragmenter = Fragmenter::try_create(ctx.clone())?;
-    let root_fragment = fragmenter.build_fragment(plan)?;
+    let exchange_manager = ctx.get_exchange_manager();
 
-    let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
-    root_fragment.get_actions(ctx.clone(), &mut fragments_actions)?;
+    let mut reschedule_times = 0;
+    loop {
+        // The fragments of the rescheduled query are known as a new query in the cluster,
+        // to avoid the conflicts with the fragments left by the failed submission.
+        let query_id = match reschedule_times {
+            0 => ctx.get_id(),
+            times => format!("{}-{}", ctx.get_id(), times),
+        };
 
-    let exchange_manager = ctx.get_exchange_manager();
+        let fragmenter = Fragmenter::try_create_with_query_id(ctx.clone(), query_id.clone())?;
+        let root_fragment = fragmenter.build_fragment(plan)?;
 
-    let mut build_res = exchange_manager
-        .commit_actions(ctx.clone(), fragments_actions)
-        .await?;
+        let mut fragments_actions =
+            QueryFragmentsActions::create_with_query_id(ctx.clone(), query_id);
+        root_fragment.get_actions(ctx.clone(), &mut fragments_actions)?;
 
-    let settings = ctx.get_settings();
-    build_res.set_max_threads(settings.get_max_threads()? as usize);
-    Ok(build_res)
+        // None of the fragments is executed before all of them are submitted, and none of
+        // the results is returned before all of them are executed. So the query is killed on
+        // the cluster and rescheduled on the surviving nodes if a node cannot be connected or
+        // the exchange stream to it is broken. The query fails if a node drops after that,
+        // the results may have been returned.
+        let schedule_res = match exchange_manager
+            .submit_actions(ctx.clone(), &fragments_actions)
+            .await
+        {
+            Err(cause) => Err(cause),
+            Ok(build_res) => match exchange_manager
+                .execute_actions(ctx.clone(), &fragments_actions)
+                .await
+            {
+                Ok(_) => Ok(build_res),
+                Err(cause) => {
+                    exchange_manager
+                        .kill_actions(ctx.clone(), &fragments_actions)
+                        .await;
+                    Err(cause)
+                }
+            },
+        };
+
+        match schedule_res {
+            Ok(mut build_res) => {
+                let settings = ctx.get_settings();
+                build_res.set_max_threads(settings.get_max_threads()? as usize);
+                return Ok(build_res);
+            }
+            Err(cause)
+                if cause.code() == ErrorCode::cannot_connect_node_code()
+                    && reschedule_times < MAX_RESCHEDULE_TIMES =>
+            {
+                warn!(
+                    "Cannot schedule the fragments of query {}, reschedule it on the surviving nodes. cause: {:?}",
+                    ctx.get_id(),
+                    cause
+                );
+
+                reschedule_times += 1;
+                let cluster = ClusterDiscovery::instance()
+                    .discover(&ctx.get_config())
+                    .await?;
+                ctx.set_cluster(cluster);
+            }
+            Err(cause) => return Err(cause),
+        }
+    }
 }
@@ -31,6 +31,7 @@ pub mod api;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
+pub mod connections;
 pub mod context_function;
 pub mod databases;
 pub mod evaluator;
@@ -38,6 +39,7 @@ pub mod interpreters;
 pub mod metrics;
 pub mod pipelines;
 pub mod procedures;
+pub mod replay;
 pub mod servers;
 pub mod sessions;
 pub mod sql;
@@ -15,6 +15,8 @@
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -52,6 +54,8 @@ struct Node {
     processor: ProcessorPtr,
 
     updated_list: Arc<UpdateList>,
+    // The time the processor was busy in the processor threads, in microseconds.
+    busy_micros: AtomicU64,
     #[allow(dead_code)]
     inputs_port: Vec<Arc<InputPort>>,
     #[allow(dead_code)]
@@ -68,6 +72,7 @@ impl Node {
             state: std::sync::Mutex::new(State::Idle),
             processor: processor.clone(),
             updated_list: UpdateList::create(),
+            busy_micros: AtomicU64::new(0),
             inputs_port: inputs_port.to_vec(),
             outputs_port: outputs_port.to_vec(),
         })
@@ -376,6 +381,48 @@ impl RunningGraph {
 
         true
     }
+
+    /// The names of the processors in processing, in the order of the graph, without duplicates.
+    pub fn processing_processors(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        for node_index in self.0.graph.node_indices() {
+            let node = &self.0.graph[node_index];
+            let state = node.state.lock().unwrap();
+            if matches!(&*state, State::Processing) {
+                let name = unsafe { node.processor.name() };
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        names
+    }
+
+    pub fn record_busy_time(&self, node_index: NodeIndex, busy_micros: u64) {
+        let node = &self.0.graph[node_index];
+        node.busy_micros.fetch_add(busy_micros, Ordering::Relaxed);
+    }
+
+    /// The number of the processors and their busy time in microseconds by the names of the
+    /// processors, in the order of the graph.
+    pub fn processors_busy_time(&self) -> Vec<(&'static str, usize, u64)> {
+        let mut profiles: Vec<(&'static str, usize, u64)> = Vec::new();
+        for node_index in self.0.graph.node_indices() {
+            let node = &self.0.graph[node_index];
+            let name = unsafe { node.processor.name() };
+            let busy_micros = node.busy_micros.load(Ordering::Relaxed);
+            match profiles.iter_mut().find(|(n, _, _)| *n == name) {
+                Some((_, count, micros)) => {
+                    *count += 1;
+                    *micros += busy_micros;
+                }
+                None => profiles.push((name, 1, busy_micros)),
+            }
+        }
+
+        profiles
+    }
 }
 
 impl Debug for Node {
@@ -0,0 +1,23 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The worker threads of the running pipelines' executors.
+pub static METRIC_EXECUTOR_WORKERS: &str = "executor.workers";
+/// The tasks scheduled to the workers but not picked up yet.
+pub static METRIC_EXECUTOR_PENDING_TASKS: &str = "executor.pending_tasks";
+/// The async tasks of the processors in flight.
+pub static METRIC_EXECUTOR_ASYNC_TASKS: &str = "executor.async_tasks";
+/// The time the workers spent on executing the tasks, the utilization of the workers is its rate
+/// divided by the workers.
+pub static METRIC_EXECUTOR_WORKER_BUSY_MICROSECONDS: &str = "executor.worker_busy_microseconds";
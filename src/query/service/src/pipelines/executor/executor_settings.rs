@@ -12,20 +12,54 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::time::Duration;
 
+use common_base::base::MemoryTracker;
 use common_exception::Result;
 use common_settings::Settings;
 
 pub struct ExecutorSettings {
     pub max_execute_time: Duration,
+    pub max_memory_usage: u64,
+    pub query_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
 impl ExecutorSettings {
     pub fn try_create(settings: &Settings) -> Result<ExecutorSettings> {
         let max_execute_time = settings.get_max_execute_time()?;
+        let max_memory_usage = settings.get_max_memory_usage()?;
         Ok(ExecutorSettings {
             max_execute_time: Duration::from_millis(max_execute_time),
+            max_memory_usage,
+            query_memory_tracker: None,
         })
     }
+
+    /// Overrides the max_execute_time of the session by the one of the query, like the
+    /// `MAX_EXECUTION_TIME` hint.
+    pub fn with_max_execute_time(mut self, max_execute_time: Option<Duration>) -> Self {
+        if let Some(max_execute_time) = max_execute_time {
+            self.max_execute_time = max_execute_time;
+        }
+
+        self
+    }
+
+    /// Overrides the max_memory_usage of the session by the one of the query, like the memory
+    /// reserved in the queue of the resource group.
+    pub fn with_max_memory_usage(mut self, max_memory_usage: Option<u64>) -> Self {
+        if let Some(max_memory_usage) = max_memory_usage {
+            self.max_memory_usage = max_memory_usage;
+        }
+
+        self
+    }
+
+    /// The executors of a query are tracked by the tracker of the query, on which the
+    /// max_memory_usage is checked, instead of by each of them apart.
+    pub fn with_query_memory_tracker(mut self, query_memory_tracker: Arc<MemoryTracker>) -> Self {
+        self.query_memory_tracker = Some(query_memory_tracker);
+        self
+    }
 }
@@ -19,11 +19,14 @@ use std::sync::Arc;
 
 use common_base::base::tokio::sync::Notify;
 use common_exception::Result;
+use metrics::decrement_gauge;
+use metrics::increment_gauge;
 use parking_lot::Mutex;
 use petgraph::prelude::NodeIndex;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
 use crate::pipelines::executor::executor_condvar::WorkersWaitingStatus;
+use crate::pipelines::executor::executor_metrics::METRIC_EXECUTOR_PENDING_TASKS;
 use crate::pipelines::executor::executor_worker_context::ExecutorTask;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::processors::processor::ProcessorPtr;
@@ -171,6 +174,7 @@ impl ExecutorTasksQueue {
 
         let mut worker_id = task.worker_id;
         workers_tasks.tasks_size += 1;
+        increment_gauge!(METRIC_EXECUTOR_PENDING_TASKS, 1.0);
         workers_tasks.workers_completed_async_tasks[worker_id].push_back(task);
 
         condvar.dec_active_async_worker();
@@ -297,6 +301,7 @@ impl ExecutorTasks {
                 }
                 other => {
                     self.tasks_size -= 1;
+                    decrement_gauge!(METRIC_EXECUTOR_PENDING_TASKS, 1.0);
                     return other;
                 }
             }
@@ -307,6 +312,7 @@ impl ExecutorTasks {
 
     pub fn push_task(&mut self, worker_id: usize, task: ExecutorTask) {
         self.tasks_size += 1;
+        increment_gauge!(METRIC_EXECUTOR_PENDING_TASKS, 1.0);
         debug_assert!(
             worker_id < self.workers_sync_tasks.len(),
             "out of index, {}, {}",
@@ -330,3 +336,12 @@ impl ExecutorTasks {
         }
     }
 }
+
+impl Drop for ExecutorTasks {
+    fn drop(&mut self) {
+        // The tasks left in the queues of the finished executor are never picked up.
+        if self.tasks_size != 0 {
+            decrement_gauge!(METRIC_EXECUTOR_PENDING_TASKS, self.tasks_size as f64);
+        }
+    }
+}
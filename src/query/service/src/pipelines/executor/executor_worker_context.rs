@@ -20,6 +20,8 @@ use common_base::base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use petgraph::prelude::NodeIndex;
+use tracing::Instrument;
+use tracing::Span;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
 use crate::pipelines::executor::executor_tasks::CompletedAsyncTask;
@@ -92,13 +94,16 @@ impl ExecutorWorkerContext {
         let workers_condvar = self.get_workers_condvar().clone();
         let tasks_queue = executor.global_tasks_queue.clone();
 
-        executor.async_runtime.spawn(ProcessorAsyncTask::create(
-            worker_id,
-            processor.clone(),
-            tasks_queue,
-            workers_condvar,
-            processor.async_process(),
-        ));
+        executor.async_runtime.spawn(
+            ProcessorAsyncTask::create(
+                worker_id,
+                processor.clone(),
+                tasks_queue,
+                workers_condvar,
+                processor.async_process(),
+            )
+            .instrument(Span::current()),
+        );
 
         Ok(None)
     }
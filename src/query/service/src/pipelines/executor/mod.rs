@@ -16,6 +16,7 @@ mod pipeline_executor;
 
 mod executor_condvar;
 mod executor_graph;
+mod executor_metrics;
 mod executor_settings;
 mod executor_tasks;
 mod executor_worker_context;
@@ -12,25 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_base::base::catch_unwind;
 use common_base::base::tokio;
 use common_base::base::tokio::sync::Notify;
 use common_base::base::GlobalIORuntime;
+use common_base::base::Profiling;
 use common_base::base::Runtime;
+use common_base::base::RuntimeTracker;
 use common_base::base::Thread;
 use common_base::base::ThreadJoinHandle;
+use common_base::base::ThreadTracker;
 use common_base::base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::future::select;
 use futures_util::future::Either;
+use metrics::counter;
+use metrics::decrement_gauge;
+use metrics::increment_gauge;
 use parking_lot::Mutex;
+use tracing::Span;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
 use crate::pipelines::executor::executor_graph::RunningGraph;
+use crate::pipelines::executor::executor_metrics::METRIC_EXECUTOR_WORKERS;
+use crate::pipelines::executor::executor_metrics::METRIC_EXECUTOR_WORKER_BUSY_MICROSECONDS;
 use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::executor::ExecutorSettings;
@@ -47,8 +59,15 @@ pub struct PipelineExecutor {
     pub global_tasks_queue: Arc<ExecutorTasksQueue>,
     on_finished_callback: FinishedCallback,
     settings: ExecutorSettings,
+    // Tracks the memory of the processor threads if the max_memory_usage is set.
+    runtime_tracker: Option<Arc<RuntimeTracker>>,
     finished_notify: Notify,
     finished_error: Mutex<Option<ErrorCode>>,
+    // The parent of the spans of the processors, the execution of the query on this node
+    // or the fragment of the remote query.
+    span: Span,
+    // The ids of the processor threads, to profile the processors of the query.
+    worker_threads: Mutex<HashSet<u64>>,
 }
 
 impl PipelineExecutor {
@@ -120,6 +139,24 @@ impl PipelineExecutor {
             }
             global_tasks_queue.init_tasks(tasks);
 
+            let runtime_tracker = match settings.max_memory_usage {
+                0 => None,
+                max_memory_usage => match &settings.query_memory_tracker {
+                    Some(query_memory_tracker) => {
+                        query_memory_tracker.set_memory_limit(max_memory_usage as i64);
+                        Some(RuntimeTracker::create_with_parent(
+                            query_memory_tracker.clone(),
+                        ))
+                    }
+                    None => {
+                        let runtime_tracker = RuntimeTracker::create();
+                        let memory_tracker = runtime_tracker.get_memory_tracker();
+                        memory_tracker.set_memory_limit(max_memory_usage as i64);
+                        Some(runtime_tracker)
+                    }
+                },
+            };
+
             Ok(Arc::new(PipelineExecutor {
                 graph,
                 threads_num,
@@ -128,8 +165,11 @@ impl PipelineExecutor {
                 on_finished_callback,
                 async_runtime: GlobalIORuntime::instance(),
                 settings,
+                runtime_tracker,
                 finished_notify: Notify::new(),
                 finished_error: Mutex::new(None),
+                span: Span::current(),
+                worker_threads: Mutex::new(HashSet::new()),
             }))
         }
     }
@@ -144,6 +184,19 @@ impl PipelineExecutor {
         self.global_tasks_queue.is_finished()
     }
 
+    pub fn processing_processors(&self) -> Vec<&'static str> {
+        self.graph.processing_processors()
+    }
+
+    pub fn processors_busy_time(&self) -> Vec<(&'static str, usize, u64)> {
+        self.graph.processors_busy_time()
+    }
+
+    /// The ids of the processor threads, as `Profiling::current_thread_id`.
+    pub fn worker_threads(&self) -> HashSet<u64> {
+        self.worker_threads.lock().clone()
+    }
+
     pub fn execute(self: &Arc<Self>) -> Result<()> {
         self.start_executor_daemon()?;
 
@@ -172,23 +225,31 @@ impl PipelineExecutor {
     }
 
     fn start_executor_daemon(self: &Arc<Self>) -> Result<()> {
-        if !self.settings.max_execute_time.is_zero() {
-            let this = self.clone();
-            self.async_runtime.spawn(async move {
-                let max_execute_time = this.settings.max_execute_time;
-                let finished_future = Box::pin(this.finished_notify.notified());
-                let max_execute_future = Box::pin(tokio::time::sleep(max_execute_time));
-                if let Either::Left(_) = select(max_execute_future, finished_future).await {
-                    this.finish(Some(ErrorCode::AbortedQuery(
-                        "Aborted query, because the execution time exceeds the maximum execution time limit",
-                    )));
-                }
+        let max_execute_time = self.settings.max_execute_time;
+        if !max_execute_time.is_zero() {
+            self.abort_after(max_execute_time, move || {
+                ErrorCode::QueryTimeout(format!(
+                    "Aborted query, because the execution time exceeds the max_execute_time {} ms",
+                    max_execute_time.as_millis()
+                ))
             });
         }
 
         Ok(())
     }
 
+    fn abort_after<F>(self: &Arc<Self>, timeout: Duration, cause: F)
+    where F: FnOnce() -> ErrorCode + Send + 'static {
+        let this = self.clone();
+        self.async_runtime.spawn(async move {
+            let finished_future = Box::pin(this.finished_notify.notified());
+            let timeout_future = Box::pin(tokio::time::sleep(timeout));
+            if let Either::Left(_) = select(timeout_future, finished_future).await {
+                this.finish(Some(cause()));
+            }
+        });
+    }
+
     fn execute_threads(self: &Arc<Self>, threads: usize) -> Vec<ThreadJoinHandle<Result<()>>> {
         let mut thread_join_handles = Vec::with_capacity(threads);
 
@@ -207,7 +268,18 @@ impl PipelineExecutor {
                 }
             }
 
+            let runtime_tracker = self.runtime_tracker.clone();
+            let span = self.span.clone();
             thread_join_handles.push(Thread::named_spawn(name, move || unsafe {
+                let _entered = span.enter();
+                this.worker_threads
+                    .lock()
+                    .insert(Profiling::current_thread_id());
+                if let Some(runtime_tracker) = runtime_tracker {
+                    ThreadTracker::replace_runtime_tracker(runtime_tracker);
+                }
+
+                increment_gauge!(METRIC_EXECUTOR_WORKERS, 1.0);
                 let this_clone = this.clone();
                 let try_result = catch_unwind(move || -> Result<()> {
                     match this_clone.execute_single_thread(thread_num) {
@@ -219,6 +291,7 @@ impl PipelineExecutor {
                     }
                 });
 
+                decrement_gauge!(METRIC_EXECUTOR_WORKERS, 1.0);
                 // finish the pipeline executor when has error or panic
                 if let Err(cause) = try_result.flatten() {
                     this.finish(Some(cause));
@@ -244,7 +317,13 @@ impl PipelineExecutor {
             }
 
             while !self.global_tasks_queue.is_finished() && context.has_task() {
-                if let Some(executed_pid) = context.execute_task(self)? {
+                let instant = Instant::now();
+                let executed_pid = context.execute_task(self);
+                let busy_micros = instant.elapsed().as_micros() as u64;
+                counter!(METRIC_EXECUTOR_WORKER_BUSY_MICROSECONDS, busy_micros);
+
+                if let Some(executed_pid) = executed_pid? {
+                    self.graph.record_busy_time(executed_pid, busy_micros);
                     // We immediately schedule the processor again.
                     let schedule_queue = self.graph.schedule_queue(executed_pid)?;
                     schedule_queue.schedule(&self.global_tasks_queue, &mut context);
@@ -27,8 +27,11 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use futures_util::future::BoxFuture;
 use futures_util::future::Either;
 use futures_util::FutureExt;
+use metrics::decrement_gauge;
+use metrics::increment_gauge;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
+use crate::pipelines::executor::executor_metrics::METRIC_EXECUTOR_ASYNC_TASKS;
 use crate::pipelines::executor::executor_tasks::CompletedAsyncTask;
 use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 
@@ -87,6 +90,7 @@ impl ProcessorAsyncTask {
             }
         };
 
+        increment_gauge!(METRIC_EXECUTOR_ASYNC_TASKS, 1.0);
         ProcessorAsyncTask {
             worker_id,
             processor,
@@ -139,3 +143,9 @@ impl Future for ProcessorAsyncTask {
         }
     }
 }
+
+impl Drop for ProcessorAsyncTask {
+    fn drop(&mut self) {
+        decrement_gauge!(METRIC_EXECUTOR_ASYNC_TASKS, 1.0);
+    }
+}
@@ -49,10 +49,15 @@ pub use transforms::KeyU64HashTable;
 pub use transforms::KeyU8HashTable;
 pub use transforms::MarkJoinCompactor;
 pub use transforms::ProjectionTransform;
+pub use transforms::QuerySpill;
+pub use transforms::QueryTempStage;
 pub use transforms::RightJoinCompactor;
 pub use transforms::SerializerHashTable;
 pub use transforms::SinkBuildHashTable;
 pub use transforms::SortMergeCompactor;
+pub use transforms::SpillManager;
+pub use transforms::SpillTarget;
+pub use transforms::Spiller;
 pub use transforms::TransformAddOn;
 pub use transforms::TransformAggregator;
 pub use transforms::TransformBlockCompact;
@@ -64,3 +69,5 @@ pub use transforms::TransformHashJoinProbe;
 pub use transforms::TransformLimit;
 pub use transforms::TransformSortMerge;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformSortSpill;
+pub use transforms::TransformWriteResultCache;
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use std::borrow::BorrowMut;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
 use std::sync::Arc;
 
+use bytes::BytesMut;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datablocks::HashMethodKeysU128;
@@ -25,8 +28,12 @@ use common_datablocks::HashMethodKeysU512;
 use common_datablocks::HashMethodKeysU64;
 use common_datablocks::HashMethodKeysU8;
 use common_datablocks::HashMethodSerializer;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_datavalues::MutableColumn;
+use common_datavalues::MutableStringColumn;
 use common_datavalues::ScalarColumn;
 use common_datavalues::Series;
 use common_datavalues::StringColumn;
@@ -34,11 +41,15 @@ use common_exception::Result;
 use common_functions::aggregates::StateAddr;
 use common_functions::aggregates::StateAddrs;
 
+use crate::pipelines::processors::transforms::check_memory_limit;
 use crate::pipelines::processors::transforms::group_by::AggregatorState;
 use crate::pipelines::processors::transforms::group_by::GroupColumnsBuilder;
+use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
 use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
 use crate::pipelines::processors::transforms::group_by::StateEntity;
+use crate::pipelines::processors::transforms::spiller::SpilledFile;
+use crate::pipelines::processors::transforms::spiller::Spiller;
 use crate::pipelines::processors::transforms::transform_aggregator::Aggregator;
 use crate::pipelines::processors::AggregatorParams;
 use crate::sessions::QueryContext;
@@ -58,6 +69,10 @@ pub type KeysU512FinalAggregator<const HAS_AGG: bool> =
 pub type SerializerFinalAggregator<const HAS_AGG: bool> =
     FinalAggregator<HAS_AGG, HashMethodSerializer>;
 
+// The spilled groups are partitioned by the hash of their keys into this many partitions, each
+// partition is merged in memory at once.
+const SPILL_PARTITIONS: usize = 16;
+
 pub struct FinalAggregator<
     const HAS_AGG: bool,
     Method: HashMethod + PolymorphicKeysHelper<Method> + Send,
@@ -71,6 +86,11 @@ pub struct FinalAggregator<
     // used for deserialization only, so we can reuse it during the loop
     temp_place: Option<StateAddr>,
     ctx: Arc<QueryContext>,
+
+    // The groups are spilled to the disk beyond the max_memory_usage if set.
+    spiller: Option<Spiller>,
+    // The files of the spilled groups by their partitions, a group is always in one partition.
+    spilled_partitions: Vec<Vec<SpilledFile>>,
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + Send>
@@ -80,6 +100,7 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
         ctx: Arc<QueryContext>,
         method: Method,
         params: Arc<AggregatorParams>,
+        spiller: Option<Spiller>,
     ) -> Result<Self> {
         let state = method.aggregate_state();
         let temp_place = if params.aggregate_functions.is_empty() {
@@ -96,10 +117,150 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
             params,
             temp_place,
             ctx,
+            spiller,
+            spilled_partitions: vec![],
         })
     }
 }
 
+impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + Send>
+    FinalAggregator<HAS_AGG, Method>
+where Self: Aggregator
+{
+    // Spills the groups beyond the max_memory_usage, the aggregation is aborted if it has no
+    // spiller.
+    fn release_states(&mut self) -> Result<Option<DataBlock>> {
+        match self.spiller.is_some() {
+            true => self.spill()?,
+            false => check_memory_limit("aggregation")?,
+        }
+        Ok(None)
+    }
+
+    // Spills the groups consumed so far by the partitions of their keys, and restarts with no
+    // groups. The spilled blocks are of the same layout as the input blocks.
+    fn spill(&mut self) -> Result<()> {
+        let (spiller, block) = match (&self.spiller, self.states_block()?) {
+            (Some(spiller), Some(block)) => (spiller, block),
+            _ => return Ok(()),
+        };
+
+        let keys_column = block.column(block.num_columns() - 1);
+        let indices = partition_indices(keys_column);
+        let partitions = DataBlock::scatter_block(&block, &indices, SPILL_PARTITIONS)?;
+
+        if self.spilled_partitions.is_empty() {
+            self.spilled_partitions = (0..SPILL_PARTITIONS).map(|_| vec![]).collect();
+        }
+        for (files, partition) in self.spilled_partitions.iter_mut().zip(partitions) {
+            if !partition.is_empty() {
+                files.push(spiller.spill(block.schema().clone(), &[partition])?);
+            }
+        }
+
+        self.reset_state();
+        Ok(())
+    }
+
+    // Merges the spilled groups partition by partition, the groups in memory are spilled first
+    // so that each group is merged from its partition only.
+    fn generate_spilled(
+        &mut self,
+        generate_block: fn(&mut Self) -> Result<Option<DataBlock>>,
+    ) -> Result<Option<DataBlock>> {
+        if self.state.len() > 0 {
+            self.spill()?;
+        }
+
+        while let Some(files) = self.spilled_partitions.pop() {
+            for mut file in files {
+                while let Some(block) = file.next_block()? {
+                    self.consume(block)?;
+                }
+            }
+
+            let block = generate_block(self)?;
+            self.reset_state();
+            if block.is_some() {
+                return Ok(block);
+            }
+        }
+        Ok(None)
+    }
+
+    // The states of the groups, serialized as the partial aggregators output them.
+    fn states_block(&self) -> Result<Option<DataBlock>> {
+        if self.state.len() == 0 {
+            return Ok(None);
+        }
+
+        let state_groups_len = self.state.len();
+        let funcs = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+
+        let mut state_builders: Vec<MutableStringColumn> = (0..funcs.len())
+            .map(|_| MutableStringColumn::with_capacity(state_groups_len * 4))
+            .collect();
+        let mut group_key_builder = self.method.keys_column_builder(state_groups_len);
+
+        let mut bytes = BytesMut::new();
+        for group_entity in self.state.iter() {
+            if !funcs.is_empty() {
+                let place: StateAddr = (*group_entity.get_state_value()).into();
+                for (idx, func) in funcs.iter().enumerate() {
+                    let arg_place = place.next(offsets_aggregate_states[idx]);
+                    func.serialize(arg_place, &mut bytes)?;
+                    state_builders[idx].append_value(&bytes[..]);
+                    bytes.clear();
+                }
+            }
+
+            group_key_builder.append_value(group_entity.get_state_key());
+        }
+
+        let mut columns: Vec<ColumnRef> = Vec::with_capacity(funcs.len() + 1);
+        for mut builder in state_builders {
+            columns.push(builder.to_column());
+        }
+        columns.push(group_key_builder.finish());
+
+        let fields = columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| DataField::new(&format!("_spilled_{}", idx), column.data_type()))
+            .collect();
+        Ok(Some(DataBlock::create(
+            DataSchemaRefExt::create(fields),
+            columns,
+        )))
+    }
+
+    fn reset_state(&mut self) {
+        self.drop_states();
+        self.state = self.method.aggregate_state();
+        self.temp_place = match self.params.aggregate_functions.is_empty() {
+            true => None,
+            false => self.state.alloc_layout(&self.params),
+        };
+        self.states_dropped = false;
+        self.is_generated = false;
+    }
+}
+
+// The partitions of the rows by the hash of their keys.
+fn partition_indices(keys_column: &ColumnRef) -> Vec<usize> {
+    let mut key = Vec::new();
+    (0..keys_column.len())
+        .map(|row| {
+            key.clear();
+            keys_column.serialize(&mut key, row);
+            let mut hasher = DefaultHasher::new();
+            hasher.write(&key);
+            hasher.finish() as usize % SPILL_PARTITIONS
+        })
+        .collect()
+}
+
 impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<true, Method> {
     /// Allocate aggregation function state for each key(the same key can always get the same state)
     #[inline(always)]
@@ -179,6 +340,19 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     }
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
+        match self.spilled_partitions.is_empty() {
+            true => self.generate_block(),
+            false => self.generate_spilled(Self::generate_block),
+        }
+    }
+
+    fn release(&mut self) -> Result<Option<DataBlock>> {
+        self.release_states()
+    }
+}
+
+impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<true, Method> {
+    fn generate_block(&mut self) -> Result<Option<DataBlock>> {
         match self.state.len() == 0 || self.is_generated {
             true => {
                 self.drop_states();
@@ -257,6 +431,19 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     }
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
+        match self.spilled_partitions.is_empty() {
+            true => self.generate_block(),
+            false => self.generate_spilled(Self::generate_block),
+        }
+    }
+
+    fn release(&mut self) -> Result<Option<DataBlock>> {
+        self.release_states()
+    }
+}
+
+impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<false, Method> {
+    fn generate_block(&mut self) -> Result<Option<DataBlock>> {
         match self.state.len() == 0 || self.is_generated {
             true => Ok(None),
             false => {
@@ -29,6 +29,7 @@ use common_datavalues::ColumnRef;
 use common_datavalues::MutableColumn;
 use common_datavalues::MutableStringColumn;
 use common_datavalues::ScalarColumnBuilder;
+use common_datavalues::Series;
 use common_exception::Result;
 use common_functions::aggregates::StateAddr;
 use common_functions::aggregates::StateAddrs;
@@ -92,10 +93,7 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
 
     #[inline(always)]
     fn lookup_key(keys_iter: Method::HashKeyIter<'_>, state: &mut Method::State) {
-        let mut inserted = true;
-        for key in keys_iter {
-            state.entity(key, &mut inserted);
-        }
+        state.lookup_keys(keys_iter);
     }
 
     /// Allocate aggregation function state for each key(the same key can always get the same state)
@@ -105,26 +103,7 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
         keys_iter: Method::HashKeyIter<'_>,
         state: &mut Method::State,
     ) -> StateAddrs {
-        let mut places = Vec::with_capacity(keys_iter.size_hint().0);
-
-        let mut inserted = true;
-        for key in keys_iter {
-            let entity = state.entity(key, &mut inserted);
-
-            match inserted {
-                true => {
-                    if let Some(place) = state.alloc_layout(params) {
-                        places.push(place);
-                        entity.set_state_value(place.addr());
-                    }
-                }
-                false => {
-                    let place: StateAddr = (*entity.get_state_value()).into();
-                    places.push(place);
-                }
-            }
-        }
-        places
+        state.lookup_places(keys_iter, params)
     }
 
     #[inline(always)]
@@ -139,8 +118,9 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
             let mut function_arguments_column = Vec::with_capacity(function_arguments.len());
 
             for argument_index in function_arguments {
+                // The aggregate functions take the strings of the rows.
                 let argument_column = block.column(*argument_index);
-                function_arguments_column.push(argument_column.clone());
+                function_arguments_column.push(Series::remove_dictionary(argument_column));
             }
 
             aggregate_arguments_columns.push(function_arguments_column);
@@ -255,6 +235,13 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         self.generate_data()
     }
+
+    fn release(&mut self) -> Result<Option<DataBlock>> {
+        // The final aggregators merge the states of a group from several blocks anyway.
+        let block = self.generate_data()?;
+        self.reset_state();
+        Ok(block)
+    }
 }
 
 impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
@@ -302,6 +289,12 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
             }
         }
     }
+
+    fn release(&mut self) -> Result<Option<DataBlock>> {
+        let block = self.generate()?;
+        self.reset_state();
+        Ok(block)
+    }
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method>>
@@ -335,6 +328,14 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method>>
             self.states_dropped = true;
         }
     }
+
+    // Restarts with no groups, once the groups are output.
+    fn reset_state(&mut self) {
+        self.drop_states();
+        self.state = self.method.aggregate_state();
+        self.states_dropped = false;
+        self.is_generated = false;
+    }
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method>> Drop
@@ -163,7 +163,7 @@ impl Aggregator for SingleStateAggregator<false> {
         for (idx, func) in self.funcs.iter().enumerate() {
             let mut arg_columns = vec![];
             for index in self.arg_indices[idx].iter() {
-                arg_columns.push(block.column(*index).clone());
+                arg_columns.push(Series::remove_dictionary(block.column(*index)));
             }
             let place = self.places[idx];
             func.accumulate(place, &arg_columns, None, rows)?;
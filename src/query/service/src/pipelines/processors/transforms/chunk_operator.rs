@@ -26,6 +26,7 @@ use common_pipeline_transforms::processors::transforms::Transform;
 use common_pipeline_transforms::processors::transforms::Transformer;
 
 use crate::evaluator::EvalNode;
+use crate::evaluator::FusedPredicate;
 
 /// `ChunkOperator` takes a `DataBlock` as input and produces a `DataBlock` as output.
 #[derive(Clone)]
@@ -37,8 +38,11 @@ pub enum ChunkOperator {
         eval: EvalNode,
     },
 
-    /// Filter the input `DataBlock` with the predicate `eval`.
-    Filter { eval: EvalNode },
+    /// Filter the input `DataBlock` with the predicate `eval`, or with `fused` if the predicate is fused.
+    Filter {
+        eval: EvalNode,
+        fused: Option<FusedPredicate>,
+    },
 
     /// Reorganize the input `DataBlock` with `offsets`.
     Project { offsets: Vec<usize> },
@@ -55,7 +59,11 @@ impl ChunkOperator {
                 input.add_column(result.vector, DataField::new(name, result.logical_type))
             }
 
-            ChunkOperator::Filter { eval } => {
+            ChunkOperator::Filter { eval, fused } => {
+                if let Some(predicate) = fused.as_ref().and_then(|fused| fused.eval(&input)) {
+                    return DataBlock::filter_block_with_bool_column(input, &predicate);
+                }
+
                 let result = eval.eval(func_ctx, &input)?;
                 let predicate = result.vector;
                 DataBlock::filter_block(input, &predicate)
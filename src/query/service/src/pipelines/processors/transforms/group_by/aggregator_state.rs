@@ -23,6 +23,7 @@ use common_datablocks::HashMethodFixedKeys;
 use common_datablocks::HashMethodSerializer;
 use common_datavalues::prelude::*;
 use common_functions::aggregates::StateAddr;
+use common_functions::aggregates::StateAddrs;
 use common_hashtable::HashMapIteratorKind;
 use common_hashtable::HashMapKind;
 use common_hashtable::HashTableEntity;
@@ -70,6 +71,52 @@ pub trait AggregatorState<Method: HashMethod>: Sync + Send {
 
     fn entity_by_key(&mut self, key: &Self::Key, inserted: &mut bool) -> *mut Self::Entity;
 
+    /// Locates the keys of a block, and returns the places of their aggregate states, the states
+    /// of the keys inserted are allocated.
+    fn lookup_places(
+        &mut self,
+        keys_iter: Method::HashKeyIter<'_>,
+        params: &NewAggregatorParams,
+    ) -> StateAddrs {
+        let mut places = Vec::with_capacity(keys_iter.size_hint().0);
+        let mut inserted = true;
+        for key in keys_iter {
+            let entity = self.entity(key, &mut inserted);
+            self.push_place(entity, inserted, params, &mut places);
+        }
+        places
+    }
+
+    /// Locates the keys of a block, for the group by without aggregate functions.
+    fn lookup_keys(&mut self, keys_iter: Method::HashKeyIter<'_>) {
+        let mut inserted = true;
+        for key in keys_iter {
+            self.entity(key, &mut inserted);
+        }
+    }
+
+    #[inline(always)]
+    fn push_place(
+        &self,
+        entity: *mut Self::Entity,
+        inserted: bool,
+        params: &NewAggregatorParams,
+        places: &mut StateAddrs,
+    ) {
+        match inserted {
+            true => {
+                if let Some(place) = self.alloc_layout(params) {
+                    places.push(place);
+                    entity.set_state_value(place.addr());
+                }
+            }
+            false => {
+                let place: StateAddr = (*entity.get_state_value()).into();
+                places.push(place);
+            }
+        }
+    }
+
     fn is_two_level(&self) -> bool {
         false
     }
@@ -176,6 +223,39 @@ where
         self.entity(*key, inserted)
     }
 
+    fn lookup_places(
+        &mut self,
+        keys_iter: <HashMethodFixedKeys<T> as HashMethod>::HashKeyIter<'_>,
+        params: &NewAggregatorParams,
+    ) -> StateAddrs {
+        let keys = keys_iter.collect::<Vec<_>>();
+        let hashes = keys.iter().map(|k| k.fast_hash()).collect::<Vec<_>>();
+
+        let mut places = Vec::with_capacity(keys.len());
+        let mut inserted = true;
+        for (row, (key, hash)) in keys.iter().zip(hashes.iter()).enumerate() {
+            if let Some(ahead) = hashes.get(row + PREFETCH_DISTANCE) {
+                self.data.prefetch_hash(*ahead);
+            }
+            let entity = self.data.insert_hash_key(key, *hash, &mut inserted);
+            self.push_place(entity, inserted, params, &mut places);
+        }
+        places
+    }
+
+    fn lookup_keys(&mut self, keys_iter: <HashMethodFixedKeys<T> as HashMethod>::HashKeyIter<'_>) {
+        let keys = keys_iter.collect::<Vec<_>>();
+        let hashes = keys.iter().map(|k| k.fast_hash()).collect::<Vec<_>>();
+
+        let mut inserted = true;
+        for (row, (key, hash)) in keys.iter().zip(hashes.iter()).enumerate() {
+            if let Some(ahead) = hashes.get(row + PREFETCH_DISTANCE) {
+                self.data.prefetch_hash(*ahead);
+            }
+            self.data.insert_hash_key(key, *hash, &mut inserted);
+        }
+    }
+
     #[inline(always)]
     fn is_two_level(&self) -> bool {
         self.two_level_flag
@@ -187,6 +267,11 @@ where
     }
 }
 
+// The keys of a block are hashed as a batch before the probes of the hash table, and the place
+// of each key is prefetched the keys ahead of its probe, so that the probes of a table bigger than
+// the cache do not wait for the misses of the cache one by one.
+const PREFETCH_DISTANCE: usize = 16;
+
 pub struct LongerFixedKeysAggregatorState<T: HashTableKeyable> {
     pub area: Bump,
     pub data: HashMapKind<T, usize>,
@@ -249,6 +334,39 @@ where
         self.entity(*key, inserted)
     }
 
+    fn lookup_places(
+        &mut self,
+        keys_iter: <HashMethodFixedKeys<T> as HashMethod>::HashKeyIter<'_>,
+        params: &NewAggregatorParams,
+    ) -> StateAddrs {
+        let keys = keys_iter.collect::<Vec<_>>();
+        let hashes = keys.iter().map(|k| k.fast_hash()).collect::<Vec<_>>();
+
+        let mut places = Vec::with_capacity(keys.len());
+        let mut inserted = true;
+        for (row, (key, hash)) in keys.iter().zip(hashes.iter()).enumerate() {
+            if let Some(ahead) = hashes.get(row + PREFETCH_DISTANCE) {
+                self.data.prefetch_hash(*ahead);
+            }
+            let entity = self.data.insert_hash_key(key, *hash, &mut inserted);
+            self.push_place(entity, inserted, params, &mut places);
+        }
+        places
+    }
+
+    fn lookup_keys(&mut self, keys_iter: <HashMethodFixedKeys<T> as HashMethod>::HashKeyIter<'_>) {
+        let keys = keys_iter.collect::<Vec<_>>();
+        let hashes = keys.iter().map(|k| k.fast_hash()).collect::<Vec<_>>();
+
+        let mut inserted = true;
+        for (row, (key, hash)) in keys.iter().zip(hashes.iter()).enumerate() {
+            if let Some(ahead) = hashes.get(row + PREFETCH_DISTANCE) {
+                self.data.prefetch_hash(*ahead);
+            }
+            self.data.insert_hash_key(key, *hash, &mut inserted);
+        }
+    }
+
     #[inline(always)]
     fn is_two_level(&self) -> bool {
         self.two_level_flag
@@ -300,26 +418,77 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
 
     #[inline(always)]
     fn entity(&mut self, keys: &[u8], inserted: &mut bool) -> *mut Self::Entity {
-        let mut keys_ref = KeysRef::create(keys.as_ptr() as usize, keys.len());
-        let state_entity = self.data_state_map.insert_key(&keys_ref, inserted);
+        let keys_ref = KeysRef::create(keys.as_ptr() as usize, keys.len());
+        self.entity_by_key(&keys_ref, inserted)
+    }
 
-        if *inserted {
-            unsafe {
-                // Keys will be destroyed after call we need copy the keys to the memory pool.
-                let global_keys = self.keys_area.alloc_slice_copy(keys);
-                let inserted_hash = state_entity.get_hash();
-                keys_ref.address = global_keys.as_ptr() as usize;
-                // TODO: maybe need set key method.
-                state_entity.set_key_and_hash(&keys_ref, inserted_hash)
+    #[inline(always)]
+    fn entity_by_key(&mut self, keys_ref: &KeysRef, inserted: &mut bool) -> *mut Self::Entity {
+        self.entity_by_hash(keys_ref, keys_ref.fast_hash(), inserted)
+    }
+
+    fn lookup_places(
+        &mut self,
+        keys_iter: <HashMethodSerializer as HashMethod>::HashKeyIter<'_>,
+        params: &NewAggregatorParams,
+    ) -> StateAddrs {
+        let keys = keys_iter
+            .map(|k| KeysRef::create(k.as_ptr() as usize, k.len()))
+            .collect::<Vec<_>>();
+        let hashes = keys.iter().map(|k| k.fast_hash()).collect::<Vec<_>>();
+
+        let mut places = Vec::with_capacity(keys.len());
+        let mut inserted = true;
+        for (row, (key, hash)) in keys.iter().zip(hashes.iter()).enumerate() {
+            if let Some(ahead) = hashes.get(row + PREFETCH_DISTANCE) {
+                self.data_state_map.prefetch_hash(*ahead);
             }
+            let entity = self.entity_by_hash(key, *hash, &mut inserted);
+            self.push_place(entity, inserted, params, &mut places);
         }
+        places
+    }
 
-        state_entity
+    fn lookup_keys(&mut self, keys_iter: <HashMethodSerializer as HashMethod>::HashKeyIter<'_>) {
+        let keys = keys_iter
+            .map(|k| KeysRef::create(k.as_ptr() as usize, k.len()))
+            .collect::<Vec<_>>();
+        let hashes = keys.iter().map(|k| k.fast_hash()).collect::<Vec<_>>();
+
+        let mut inserted = true;
+        for (row, (key, hash)) in keys.iter().zip(hashes.iter()).enumerate() {
+            if let Some(ahead) = hashes.get(row + PREFETCH_DISTANCE) {
+                self.data_state_map.prefetch_hash(*ahead);
+            }
+            self.entity_by_hash(key, *hash, &mut inserted);
+        }
     }
 
     #[inline(always)]
-    fn entity_by_key(&mut self, keys_ref: &KeysRef, inserted: &mut bool) -> *mut Self::Entity {
-        let state_entity = self.data_state_map.insert_key(keys_ref, inserted);
+    fn is_two_level(&self) -> bool {
+        self.two_level_flag
+    }
+
+    #[inline(always)]
+    fn convert_to_two_level(&mut self) {
+        unsafe {
+            self.data_state_map.convert_to_two_level();
+        }
+        self.two_level_flag = true;
+    }
+}
+
+impl SerializedKeysAggregatorState {
+    #[inline(always)]
+    fn entity_by_hash(
+        &mut self,
+        keys_ref: &KeysRef,
+        hash: u64,
+        inserted: &mut bool,
+    ) -> *mut KeyValueEntity<KeysRef, usize> {
+        let state_entity = self
+            .data_state_map
+            .insert_hash_key(keys_ref, hash, inserted);
 
         if *inserted {
             unsafe {
@@ -336,17 +505,4 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
 
         state_entity
     }
-
-    #[inline(always)]
-    fn is_two_level(&self) -> bool {
-        self.two_level_flag
-    }
-
-    #[inline(always)]
-    fn convert_to_two_level(&mut self) {
-        unsafe {
-            self.data_state_map.convert_to_two_level();
-        }
-        self.two_level_flag = true;
-    }
 }
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datablocks::DataBlock;
 use common_exception::Result;
 
 use super::ProbeState;
+use super::RuntimeFilter;
 
 #[async_trait::async_trait]
 /// Concurrent hash table for hash join.
@@ -35,6 +38,10 @@ pub trait HashJoinState: Send + Sync {
     /// Is building finished.
     fn is_finished(&self) -> Result<bool>;
 
+    /// Spill the build side to the disk once the memory usage exceeds the max_memory_usage, the
+    /// query is aborted if the join cannot spill.
+    fn spill_build(&self) -> Result<()>;
+
     /// Finish building hash table, will be called only once as soon as all handles
     /// have been detached from current state.
     fn finish(&self) -> Result<()>;
@@ -42,6 +49,21 @@ pub trait HashJoinState: Send + Sync {
     /// Wait until the build phase is finished
     async fn wait_finish(&self) -> Result<()>;
 
+    /// Attach a processor of the probe side to state
+    fn attach_probe(&self) -> Result<()>;
+
+    /// Detach a processor of the probe side once its input is finished, returns true if it's
+    /// the last one and the spilled partitions are left to join by it.
+    fn detach_probe(&self) -> Result<bool>;
+
+    /// Join the spilled partitions one by one, returns the matched rows of the next block of
+    /// the probe side, or None once all the partitions are joined.
+    fn probe_spilled(&self, probe_state: &mut ProbeState) -> Result<Option<Vec<DataBlock>>>;
+
+    /// Get the runtime filter of the build keys, must be called after the build phase
+    /// is finished.
+    fn runtime_filter(&self) -> Result<Arc<RuntimeFilter>>;
+
     /// Get mark join results
     fn mark_join_blocks(&self) -> Result<Vec<DataBlock>>;
 
@@ -49,13 +49,18 @@ use parking_lot::RwLock;
 use primitive_types::U256;
 use primitive_types::U512;
 
+use super::HashJoinSpill;
 use super::ProbeState;
+use super::RuntimeFilter;
+use crate::pipelines::processors::transforms::check_memory_limit;
 use crate::pipelines::processors::transforms::group_by::keys_ref::KeysRef;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
 use crate::pipelines::processors::transforms::hash_join::util::build_schema_wrap_nullable;
 use crate::pipelines::processors::transforms::hash_join::util::probe_schema_wrap_nullable;
+use crate::pipelines::processors::transforms::spiller::is_memory_exceeded;
+use crate::pipelines::processors::transforms::Spiller;
 use crate::pipelines::processors::HashJoinState;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -138,6 +143,13 @@ pub struct JoinHashTable {
     pub(crate) row_ptrs: RwLock<Vec<RowPtr>>,
     pub(crate) probe_schema: DataSchemaRef,
     finished_notify: Arc<Notify>,
+    /// The runtime filter of the build keys, generated by the first consumer
+    runtime_filter: Mutex<Option<Arc<RuntimeFilter>>>,
+    /// The partitions spilled to the disk beyond the max_memory_usage, only for the joins
+    /// returning the rows of the probe side, see `can_spill()`.
+    spill: Option<Mutex<HashJoinSpill>>,
+    /// The number of the probe processors whose input is not finished.
+    probe_count: Mutex<usize>,
 }
 
 impl JoinHashTable {
@@ -147,6 +159,7 @@ impl JoinHashTable {
         build_schema: DataSchemaRef,
         probe_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        spiller: Option<Spiller>,
     ) -> Result<Arc<JoinHashTable>> {
         let hash_key_types: Vec<DataTypeImpl> =
             build_keys.iter().map(|expr| expr.data_type()).collect();
@@ -161,6 +174,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU8(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -171,6 +185,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU16(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -181,6 +196,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU32(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -191,6 +207,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU64(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -201,6 +218,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU128(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -211,6 +229,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU256(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -221,6 +240,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU512(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -231,6 +251,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
         })
     }
@@ -241,6 +262,7 @@ impl JoinHashTable {
         mut build_data_schema: DataSchemaRef,
         mut probe_data_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        spiller: Option<Spiller>,
     ) -> Result<Self> {
         if hash_join_desc.join_type == JoinType::Left
             || hash_join_desc.join_type == JoinType::Single
@@ -254,6 +276,10 @@ impl JoinHashTable {
             build_data_schema = build_schema_wrap_nullable(&build_data_schema);
             probe_data_schema = probe_schema_wrap_nullable(&probe_data_schema);
         }
+        let spill = match Self::can_spill(&hash_join_desc.join_type) {
+            true => spiller.map(|spiller| Mutex::new(HashJoinSpill::create(spiller))),
+            false => None,
+        };
         Ok(Self {
             row_space: RowSpace::new(build_data_schema),
             ref_count: Mutex::new(0),
@@ -264,9 +290,231 @@ impl JoinHashTable {
             row_ptrs: RwLock::new(vec![]),
             probe_schema: probe_data_schema,
             finished_notify: Arc::new(Notify::new()),
+            runtime_filter: Mutex::new(None),
+            spill,
+            probe_count: Mutex::new(0),
         })
     }
 
+    // The build side is spilled only for the joins returning each row of the probe side on
+    // its own, the others keep the matches of the whole build side, e.g. the right joins.
+    fn can_spill(join_type: &JoinType) -> bool {
+        matches!(
+            join_type,
+            JoinType::Inner
+                | JoinType::Left
+                | JoinType::LeftSemi
+                | JoinType::LeftAnti
+                | JoinType::Single
+        )
+    }
+
+    fn is_spilled(&self) -> bool {
+        self.spill
+            .as_ref()
+            .map_or(false, |spill| spill.lock().unwrap().is_spilled())
+    }
+
+    // Joins the next block of the probe side of the spilled partitions, the build side of each
+    // partition is loaded into the hash table in turn.
+    fn probe_spilled_partitions(
+        &self,
+        spill: &Mutex<HashJoinSpill>,
+        probe_state: &mut ProbeState,
+    ) -> Result<Option<Vec<DataBlock>>> {
+        loop {
+            let probe_block = spill.lock().unwrap().next_probe_block()?;
+            if let Some(block) = probe_block {
+                return Ok(Some(self.probe_join(&block, probe_state)?));
+            }
+
+            let build_files = match spill.lock().unwrap().next_partition()? {
+                None => return Ok(None),
+                Some(build_files) => build_files,
+            };
+
+            self.clear_hash_table();
+            for mut file in build_files {
+                while let Some(block) = file.next_block()? {
+                    self.build(block)?;
+                }
+            }
+            check_memory_limit("a partition of the hash join build")?;
+            self.build_hash_table()?;
+        }
+    }
+
+    fn clear_hash_table(&self) {
+        match (*self.hash_table.write()).borrow_mut() {
+            HashTable::SerializerHashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU8HashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU16HashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU32HashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU64HashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU128HashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU256HashTable(table) => table.hash_table = HashMap::create(),
+            HashTable::KeyU512HashTable(table) => table.hash_table = HashMap::create(),
+        }
+        self.row_space.chunks.write().unwrap().clear();
+        self.row_ptrs.write().clear();
+    }
+
+    fn build_hash_table(&self) -> Result<()> {
+        macro_rules! insert_key {
+            ($table: expr, $markers: expr, $method: expr, $chunk: expr, $columns: expr,  $chunk_index: expr, ) => {{
+                let keys_state = $method.build_keys_state(&$columns, $chunk.num_rows())?;
+                let build_keys_iter = $method.build_keys_iter(&keys_state)?;
+
+                for (row_index, key) in build_keys_iter.enumerate().take($chunk.num_rows()) {
+                    let mut inserted = true;
+                    let ptr = RowPtr {
+                        chunk_index: $chunk_index as u32,
+                        row_index: row_index as u32,
+                        marker: $markers[row_index],
+                    };
+                    {
+                        let mut self_row_ptrs = self.row_ptrs.write();
+                        self_row_ptrs.push(ptr.clone());
+                    }
+                    let entity = $table.insert_key(&key, &mut inserted);
+                    if inserted {
+                        entity.set_value(vec![ptr]);
+                    } else {
+                        entity.get_mut_value().push(ptr);
+                    }
+                }
+            }};
+        }
+
+        let mut chunks = self.row_space.chunks.write().unwrap();
+        for chunk_index in 0..chunks.len() {
+            let chunk = &mut chunks[chunk_index];
+            let mut columns = Vec::with_capacity(chunk.cols.len());
+            let markers = if self.hash_join_desc.join_type == Mark {
+                let mut markers = vec![Some(MarkerKind::False); chunk.num_rows()];
+                // Only all columns' values are NULL, we set the marker to Null.
+                if chunk.cols.iter().any(|c| c.is_nullable() || c.is_null()) {
+                    let mut valids = None;
+                    for col in chunk.cols.iter() {
+                        let (is_all_null, tmp_valids) = col.validity();
+                        if is_all_null {
+                            let mut m = MutableBitmap::with_capacity(chunk.num_rows());
+                            m.extend_constant(chunk.num_rows(), false);
+                            valids = Some(m.into());
+                            break;
+                        } else {
+                            valids = combine_validities_3(valids, tmp_valids.cloned());
+                        }
+                    }
+                    if let Some(v) = valids {
+                        for (idx, marker) in markers.iter_mut().enumerate() {
+                            if !v.get_bit(idx) {
+                                *marker = Some(MarkerKind::Null);
+                            }
+                        }
+                    }
+                }
+                markers
+            } else {
+                vec![None; chunk.num_rows()]
+            };
+            for col in chunk.cols.iter() {
+                columns.push(col);
+            }
+            match (*self.hash_table.write()).borrow_mut() {
+                HashTable::SerializerHashTable(table) => {
+                    let mut build_cols_ref = Vec::with_capacity(chunk.cols.len());
+                    for build_col in chunk.cols.iter() {
+                        build_cols_ref.push(build_col);
+                    }
+                    let keys_state = table
+                        .hash_method
+                        .build_keys_state(&build_cols_ref, chunk.num_rows())?;
+                    chunk.keys_state = Some(keys_state);
+                    let build_keys_iter = table
+                        .hash_method
+                        .build_keys_iter(chunk.keys_state.as_ref().unwrap())?;
+                    for (row_index, key) in build_keys_iter.enumerate().take(chunk.num_rows()) {
+                        let mut inserted = true;
+                        let ptr = RowPtr {
+                            chunk_index: chunk_index as u32,
+                            row_index: row_index as u32,
+                            marker: markers[row_index],
+                        };
+                        {
+                            let mut self_row_ptrs = self.row_ptrs.write();
+                            self_row_ptrs.push(ptr);
+                        }
+                        let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+                        let entity = table.hash_table.insert_key(&keys_ref, &mut inserted);
+                        if inserted {
+                            entity.set_value(vec![ptr]);
+                        } else {
+                            entity.get_mut_value().push(ptr);
+                        }
+                    }
+                }
+                HashTable::KeyU8HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+                HashTable::KeyU16HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+                HashTable::KeyU32HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+                HashTable::KeyU64HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+                HashTable::KeyU128HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+                HashTable::KeyU256HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+                HashTable::KeyU512HashTable(table) => insert_key! {
+                    &mut table.hash_table,
+                    &markers,
+                    &table.hash_method,
+                    chunk,
+                    columns,
+                    chunk_index,
+                },
+            }
+        }
+        Ok(())
+    }
+
     // Merge build block and probe block that have the same number of rows
     pub(crate) fn merge_eq_block(
         &self,
@@ -474,6 +722,20 @@ impl HashJoinState for JoinHashTable {
     }
 
     fn probe(&self, input: &DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>> {
+        if let Some(spill) = self.spill.as_ref().filter(|_| self.is_spilled()) {
+            // The probe side is spilled by the same partitions as the build side.
+            let func_ctx = self.ctx.try_get_function_context()?;
+            let probe_keys = self
+                .hash_join_desc
+                .probe_keys
+                .iter()
+                .map(|expr| Ok(expr.eval(&func_ctx, input)?.vector().clone()))
+                .collect::<Result<Vec<ColumnRef>>>()?;
+            let mut spill = spill.lock().unwrap();
+            spill.push_probe(input.clone(), probe_keys, is_memory_exceeded())?;
+            return Ok(vec![]);
+        }
+
         match self.hash_join_desc.join_type {
             JoinType::Inner
             | JoinType::LeftSemi
@@ -513,160 +775,23 @@ impl HashJoinState for JoinHashTable {
         Ok(*self.is_finished.lock().unwrap())
     }
 
-    fn finish(&self) -> Result<()> {
-        macro_rules! insert_key {
-            ($table: expr, $markers: expr, $method: expr, $chunk: expr, $columns: expr,  $chunk_index: expr, ) => {{
-                let keys_state = $method.build_keys_state(&$columns, $chunk.num_rows())?;
-                let build_keys_iter = $method.build_keys_iter(&keys_state)?;
-
-                for (row_index, key) in build_keys_iter.enumerate().take($chunk.num_rows()) {
-                    let mut inserted = true;
-                    let ptr = RowPtr {
-                        chunk_index: $chunk_index as u32,
-                        row_index: row_index as u32,
-                        marker: $markers[row_index],
-                    };
-                    {
-                        let mut self_row_ptrs = self.row_ptrs.write();
-                        self_row_ptrs.push(ptr.clone());
-                    }
-                    let entity = $table.insert_key(&key, &mut inserted);
-                    if inserted {
-                        entity.set_value(vec![ptr]);
-                    } else {
-                        entity.get_mut_value().push(ptr);
-                    }
-                }
-            }};
+    fn spill_build(&self) -> Result<()> {
+        match &self.spill {
+            None => check_memory_limit("hash join build"),
+            Some(spill) => {
+                let chunks = std::mem::take(&mut *self.row_space.chunks.write().unwrap());
+                spill.lock().unwrap().spill_build(chunks)
+            }
         }
+    }
 
-        let mut chunks = self.row_space.chunks.write().unwrap();
-        for chunk_index in 0..chunks.len() {
-            let chunk = &mut chunks[chunk_index];
-            let mut columns = Vec::with_capacity(chunk.cols.len());
-            let markers = if self.hash_join_desc.join_type == Mark {
-                let mut markers = vec![Some(MarkerKind::False); chunk.num_rows()];
-                // Only all columns' values are NULL, we set the marker to Null.
-                if chunk.cols.iter().any(|c| c.is_nullable() || c.is_null()) {
-                    let mut valids = None;
-                    for col in chunk.cols.iter() {
-                        let (is_all_null, tmp_valids) = col.validity();
-                        if is_all_null {
-                            let mut m = MutableBitmap::with_capacity(chunk.num_rows());
-                            m.extend_constant(chunk.num_rows(), false);
-                            valids = Some(m.into());
-                            break;
-                        } else {
-                            valids = combine_validities_3(valids, tmp_valids.cloned());
-                        }
-                    }
-                    if let Some(v) = valids {
-                        for (idx, marker) in markers.iter_mut().enumerate() {
-                            if !v.get_bit(idx) {
-                                *marker = Some(MarkerKind::Null);
-                            }
-                        }
-                    }
-                }
-                markers
-            } else {
-                vec![None; chunk.num_rows()]
-            };
-            for col in chunk.cols.iter() {
-                columns.push(col);
-            }
-            match (*self.hash_table.write()).borrow_mut() {
-                HashTable::SerializerHashTable(table) => {
-                    let mut build_cols_ref = Vec::with_capacity(chunk.cols.len());
-                    for build_col in chunk.cols.iter() {
-                        build_cols_ref.push(build_col);
-                    }
-                    let keys_state = table
-                        .hash_method
-                        .build_keys_state(&build_cols_ref, chunk.num_rows())?;
-                    chunk.keys_state = Some(keys_state);
-                    let build_keys_iter = table
-                        .hash_method
-                        .build_keys_iter(chunk.keys_state.as_ref().unwrap())?;
-                    for (row_index, key) in build_keys_iter.enumerate().take(chunk.num_rows()) {
-                        let mut inserted = true;
-                        let ptr = RowPtr {
-                            chunk_index: chunk_index as u32,
-                            row_index: row_index as u32,
-                            marker: markers[row_index],
-                        };
-                        {
-                            let mut self_row_ptrs = self.row_ptrs.write();
-                            self_row_ptrs.push(ptr);
-                        }
-                        let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
-                        let entity = table.hash_table.insert_key(&keys_ref, &mut inserted);
-                        if inserted {
-                            entity.set_value(vec![ptr]);
-                        } else {
-                            entity.get_mut_value().push(ptr);
-                        }
-                    }
-                }
-                HashTable::KeyU8HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-                HashTable::KeyU16HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-                HashTable::KeyU32HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-                HashTable::KeyU64HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-                HashTable::KeyU128HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-                HashTable::KeyU256HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-                HashTable::KeyU512HashTable(table) => insert_key! {
-                    &mut table.hash_table,
-                    &markers,
-                    &table.hash_method,
-                    chunk,
-                    columns,
-                    chunk_index,
-                },
-            }
+    fn finish(&self) -> Result<()> {
+        if self.is_spilled() {
+            // The rest of the build side is spilled as well, the partitions are loaded into
+            // the hash table once the probe side is spilled.
+            return self.spill_build();
         }
-        Ok(())
+        self.build_hash_table()
     }
 
     async fn wait_finish(&self) -> Result<()> {
@@ -677,6 +802,42 @@ impl HashJoinState for JoinHashTable {
         Ok(())
     }
 
+    fn attach_probe(&self) -> Result<()> {
+        *self.probe_count.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    fn detach_probe(&self) -> Result<bool> {
+        let mut count = self.probe_count.lock().unwrap();
+        *count -= 1;
+        Ok(*count == 0 && self.is_spilled())
+    }
+
+    fn probe_spilled(&self, probe_state: &mut ProbeState) -> Result<Option<Vec<DataBlock>>> {
+        match &self.spill {
+            None => Ok(None),
+            Some(spill) => self.probe_spilled_partitions(spill, probe_state),
+        }
+    }
+
+    fn runtime_filter(&self) -> Result<Arc<RuntimeFilter>> {
+        let mut runtime_filter = self.runtime_filter.lock().unwrap();
+        if let Some(runtime_filter) = runtime_filter.as_ref() {
+            return Ok(runtime_filter.clone());
+        }
+
+        // The keys of the spilled build side are not in memory to filter the probe side.
+        let filter = match self.is_spilled() {
+            true => Arc::new(RuntimeFilter::keep_all()),
+            false => Arc::new(RuntimeFilter::try_create(
+                &self.row_space,
+                self.hash_join_desc.build_keys.len(),
+            )?),
+        };
+        *runtime_filter = Some(filter.clone());
+        Ok(filter)
+    }
+
     fn mark_join_blocks(&self) -> Result<Vec<DataBlock>> {
         let mut row_ptrs = self.row_ptrs.write();
         let has_null = self.hash_join_desc.marker_join_desc.has_null.read();
@@ -18,6 +18,8 @@ mod join_hash_table;
 mod probe_state;
 mod result_blocks;
 pub(crate) mod row;
+mod runtime_filter;
+mod spill;
 mod util;
 
 pub use desc::HashJoinDesc;
@@ -35,3 +37,5 @@ pub use join_hash_table::MarkJoinDesc;
 pub use join_hash_table::SerializerHashTable;
 pub use probe_state::ProbeState;
 pub use result_blocks::*;
+pub use runtime_filter::RuntimeFilter;
+pub use spill::HashJoinSpill;
@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datablocks::DataBlock;
+use common_datavalues::BooleanColumn;
+use common_datavalues::Column;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_functions::scalars::FunctionContext;
+use common_storages_index::filters::Filter;
+use common_storages_index::filters::FilterBuilder;
+use common_storages_index::filters::Xor8Builder;
+use common_storages_index::filters::Xor8Filter;
+
+use crate::evaluator::EvalNode;
+use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
+
+// The bloom filter is skipped for the build side with too many keys, the filter would
+// be both expensive to build and large, while only the min/max of the keys is kept.
+const RUNTIME_FILTER_BLOOM_MAX_KEYS: usize = 1 << 22;
+
+/// The filter of a key of the build side, the rows of the probe side out of the range
+/// of the keys, or not in the bloom filter of the keys, can never be matched.
+struct KeyRuntimeFilter {
+    /// The min and the max of the non-null keys, `None` if there is no such key.
+    range: Option<(DataValue, DataValue)>,
+    bloom: Option<Xor8Filter>,
+}
+
+impl KeyRuntimeFilter {
+    fn try_create(columns: &[&ColumnRef]) -> Result<Self> {
+        let mut range: Option<(DataValue, DataValue)> = None;
+        let mut builder = Xor8Builder::create();
+        let mut num_keys = 0;
+
+        for column in columns {
+            let keys = column
+                .to_values()
+                .into_iter()
+                .filter(|key| !key.is_null())
+                .collect::<Vec<_>>();
+            for key in keys.iter() {
+                range = match range {
+                    None => Some((key.clone(), key.clone())),
+                    Some((min, max)) => Some((min.min(key.clone()), max.max(key.clone()))),
+                };
+            }
+
+            num_keys += keys.len();
+            if num_keys <= RUNTIME_FILTER_BLOOM_MAX_KEYS {
+                builder.add_keys(&keys);
+            }
+        }
+
+        let bloom = match num_keys {
+            0 => None,
+            n if n > RUNTIME_FILTER_BLOOM_MAX_KEYS => None,
+            _ => Some(builder.build()?),
+        };
+        Ok(KeyRuntimeFilter { range, bloom })
+    }
+
+    fn may_match(&self, key: &DataValue) -> bool {
+        match &self.range {
+            None => false,
+            Some(_) if key.is_null() => false,
+            Some((min, max)) => {
+                key >= min
+                    && key <= max
+                    && self
+                        .bloom
+                        .as_ref()
+                        .map_or(true, |bloom| bloom.contains(key))
+            }
+        }
+    }
+}
+
+/// The runtime filter of the keys of the build side of the hash join, generated once
+/// the build is finished, and applied to the scans of the probe side to drop the rows
+/// which can never be matched before they reach the join.
+pub struct RuntimeFilter {
+    keys: Vec<KeyRuntimeFilter>,
+}
+
+impl RuntimeFilter {
+    pub(crate) fn try_create(row_space: &RowSpace, num_keys: usize) -> Result<Self> {
+        let chunks = row_space.chunks.read().unwrap();
+        let keys = (0..num_keys)
+            .map(|i| {
+                let columns = chunks
+                    .iter()
+                    .map(|chunk| &chunk.cols[i])
+                    .collect::<Vec<_>>();
+                KeyRuntimeFilter::try_create(&columns)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(RuntimeFilter { keys })
+    }
+
+    /// The filter keeping all the rows, when the keys of the build side are not known.
+    pub(crate) fn keep_all() -> Self {
+        RuntimeFilter { keys: vec![] }
+    }
+
+    /// Filters the block by the keys of the probe side, the keys are evaluated in the
+    /// same order as the keys of the build side.
+    pub fn filter(
+        &self,
+        func_ctx: &FunctionContext,
+        probe_keys: &[EvalNode],
+        mut block: DataBlock,
+    ) -> Result<DataBlock> {
+        for (probe_key, filter) in probe_keys.iter().zip(self.keys.iter()) {
+            if block.num_rows() == 0 {
+                break;
+            }
+
+            let column = probe_key.eval(func_ctx, &block)?.vector;
+            let mut bitmap = MutableBitmap::with_capacity(column.len());
+            for key in column.to_values().iter() {
+                bitmap.push(filter.may_match(key));
+            }
+            let predicate = BooleanColumn::from_arrow_data(bitmap.into()).arc();
+            block = DataBlock::filter_block(block, &predicate)?;
+        }
+        Ok(block)
+    }
+}
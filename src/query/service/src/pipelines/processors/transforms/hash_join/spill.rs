@@ -0,0 +1,173 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use common_datablocks::DataBlock;
+use common_datavalues::ColumnRef;
+use common_datavalues::Series;
+use common_exception::Result;
+
+use crate::pipelines::processors::transforms::hash_join::row::Chunk;
+use crate::pipelines::processors::transforms::spiller::SpilledFile;
+use crate::pipelines::processors::transforms::spiller::Spiller;
+
+// The rows of both sides are partitioned by the hash of their keys into this many partitions,
+// the build side of each partition is loaded in memory at once.
+const SPILL_PARTITIONS: usize = 16;
+
+/// The partitions of both sides of a grace hash join, spilled to the disk once the build side
+/// exceeds the max_memory_usage.
+///
+/// The rows of both sides are partitioned by the hash of their keys, so that the rows of a
+/// partition of the probe side can only match the rows of the same partition of the build side,
+/// and the partitions are joined one by one.
+pub struct HashJoinSpill {
+    spiller: Spiller,
+    build_partitions: Vec<Vec<SpilledFile>>,
+    probe_partitions: Vec<Vec<SpilledFile>>,
+    // The blocks of the probe side and their keys, spilled beyond the max_memory_usage.
+    probe_blocks: Vec<(DataBlock, Vec<ColumnRef>)>,
+    // The files of the probe side of the partition being joined.
+    probe_files: Vec<SpilledFile>,
+}
+
+impl HashJoinSpill {
+    pub fn create(spiller: Spiller) -> HashJoinSpill {
+        HashJoinSpill {
+            spiller,
+            build_partitions: vec![],
+            probe_partitions: vec![],
+            probe_blocks: vec![],
+            probe_files: vec![],
+        }
+    }
+
+    /// Whether the build side is spilled, it's then spilled as a whole.
+    pub fn is_spilled(&self) -> bool {
+        !self.build_partitions.is_empty()
+    }
+
+    pub fn spill_build(&mut self, chunks: Vec<Chunk>) -> Result<()> {
+        let blocks = chunks
+            .into_iter()
+            .map(|chunk| (chunk.data_block, chunk.cols))
+            .collect::<Vec<_>>();
+        Self::spill_partitions(&self.spiller, &mut self.build_partitions, blocks)
+    }
+
+    /// Buffers a block of the probe side, the blocks are spilled beyond the max_memory_usage.
+    pub fn push_probe(
+        &mut self,
+        block: DataBlock,
+        keys: Vec<ColumnRef>,
+        spill: bool,
+    ) -> Result<()> {
+        self.probe_blocks.push((block, keys));
+        if spill {
+            let blocks = std::mem::take(&mut self.probe_blocks);
+            Self::spill_partitions(&self.spiller, &mut self.probe_partitions, blocks)?;
+        }
+        Ok(())
+    }
+
+    /// Takes the build side of the next partition to join, its probe side is then read by
+    /// `next_probe_block()`. None if all the partitions are joined.
+    pub fn next_partition(&mut self) -> Result<Option<Vec<SpilledFile>>> {
+        if !self.probe_blocks.is_empty() {
+            let blocks = std::mem::take(&mut self.probe_blocks);
+            Self::spill_partitions(&self.spiller, &mut self.probe_partitions, blocks)?;
+        }
+
+        match self.build_partitions.pop() {
+            None => Ok(None),
+            Some(build_files) => {
+                // A partition of the probe side is left empty if no block is spilled.
+                self.probe_files = self.probe_partitions.pop().unwrap_or_default();
+                Ok(Some(build_files))
+            }
+        }
+    }
+
+    /// Reads the next block of the probe side of the partition being joined.
+    pub fn next_probe_block(&mut self) -> Result<Option<DataBlock>> {
+        while let Some(file) = self.probe_files.last_mut() {
+            match file.next_block()? {
+                Some(block) => return Ok(Some(block)),
+                None => {
+                    self.probe_files.pop();
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    // Spills each partition of the blocks to a file, the partitions of the blocks are
+    // appended to `partitions` in the same order.
+    fn spill_partitions(
+        spiller: &Spiller,
+        partitions: &mut Vec<Vec<SpilledFile>>,
+        blocks: Vec<(DataBlock, Vec<ColumnRef>)>,
+    ) -> Result<()> {
+        if partitions.is_empty() {
+            *partitions = (0..SPILL_PARTITIONS).map(|_| vec![]).collect();
+        }
+
+        let schema = match blocks.first() {
+            None => return Ok(()),
+            Some((block, _)) => block.schema().clone(),
+        };
+
+        let mut partitioned_blocks = vec![vec![]; SPILL_PARTITIONS];
+        for (block, keys) in blocks {
+            let indices = partition_indices(&keys, block.num_rows());
+            let scattered = DataBlock::scatter_block(&block, &indices, SPILL_PARTITIONS)?;
+            for (blocks, block) in partitioned_blocks.iter_mut().zip(scattered) {
+                if !block.is_empty() {
+                    blocks.push(block);
+                }
+            }
+        }
+
+        for (files, blocks) in partitions.iter_mut().zip(partitioned_blocks) {
+            if !blocks.is_empty() {
+                files.push(spiller.spill(schema.clone(), &blocks)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+// The partitions of the rows by the hash of their keys. The nulls are ignored, as the keys of
+// both sides are nullable or not on their own, and the rows of null keys never match.
+fn partition_indices(keys: &[ColumnRef], num_rows: usize) -> Vec<usize> {
+    let keys = keys
+        .iter()
+        .map(|column| Series::remove_nullable(&Series::remove_dictionary(column)))
+        .collect::<Vec<_>>();
+
+    let mut key = Vec::new();
+    (0..num_rows)
+        .map(|row| {
+            key.clear();
+            for column in keys.iter() {
+                column.serialize(&mut key, row);
+            }
+            let mut hasher = DefaultHasher::new();
+            hasher.write(&key);
+            hasher.finish() as usize % SPILL_PARTITIONS
+        })
+        .collect()
+}
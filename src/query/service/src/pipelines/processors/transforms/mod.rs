@@ -15,6 +15,10 @@
 mod aggregator;
 mod chunk_operator;
 pub(crate) mod hash_join;
+mod query_temp_stage;
+mod spill_manager;
+mod spiller;
+mod transform_adaptive_filter;
 mod transform_addon;
 mod transform_aggregator;
 use common_pipeline_transforms::processors::transforms::transform;
@@ -34,6 +38,9 @@ pub mod group_by;
 mod transform_merge_block;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
+mod transform_runtime_filter;
+mod transform_sort_spill;
+mod transform_write_result_cache;
 
 pub use aggregator::AggregatorParams;
 pub use aggregator::AggregatorTransformParams;
@@ -52,6 +59,13 @@ pub use hash_join::KeyU512HashTable;
 pub use hash_join::KeyU64HashTable;
 pub use hash_join::KeyU8HashTable;
 pub use hash_join::SerializerHashTable;
+pub use query_temp_stage::QueryTempStage;
+pub use spill_manager::QuerySpill;
+pub use spill_manager::SpillManager;
+pub use spiller::check_memory_limit;
+pub use spiller::SpillTarget;
+pub use spiller::Spiller;
+pub use transform_adaptive_filter::TransformAdaptiveFilter;
 pub use transform_addon::TransformAddOn;
 pub use transform_aggregator::TransformAggregator;
 pub use transform_block_compact::BlockCompactor;
@@ -74,7 +88,10 @@ pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
 pub use transform_right_semi_anti_join::RightSemiAntiJoinCompactor;
 pub use transform_right_semi_anti_join::TransformRightSemiAntiJoin;
+pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_merge::TransformSortMerge;
 pub use transform_sort_partial::get_sort_descriptions;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
+pub use transform_write_result_cache::TransformWriteResultCache;
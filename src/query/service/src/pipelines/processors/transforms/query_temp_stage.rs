@@ -0,0 +1,168 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::GlobalIORuntime;
+use common_base::base::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use uuid::Uuid;
+
+/// The temporary stages of the queries are `<prefix>/<created secs>-<query id>-<uuid>/` in the
+/// storage of the query.
+pub const QUERY_TEMP_STAGE_PREFIX: &str = "_query_temp";
+
+// The temporary stages created before are left by the crashed processes, no query is supposed
+// to run for so long.
+const STALE_TEMP_STAGE_SECS: u64 = 24 * 60 * 60;
+
+/// The objects of the intermediate results of a query in the storage, e.g. the blocks spilled
+/// by the sorts, the aggregations and the joins, which are not limited by the local disk.
+///
+/// The objects of a query are under the prefix of the query, which is removed as the temporary
+/// stage drops. The stages left by the crashed processes are removed as the processes start,
+/// see [QueryTempStage::remove_stale].
+pub struct QueryTempStage {
+    operator: Operator,
+    prefix: String,
+    written_bytes: AtomicU64,
+    // Whether any object is written, the stage is not removed otherwise.
+    used: AtomicBool,
+}
+
+impl QueryTempStage {
+    pub fn create(operator: Operator, query_id: &str) -> Arc<QueryTempStage> {
+        let prefix = format!(
+            "{}/{}-{}-{}/",
+            QUERY_TEMP_STAGE_PREFIX,
+            now_secs(),
+            query_id,
+            Uuid::new_v4().simple()
+        );
+        Arc::new(QueryTempStage {
+            operator,
+            prefix,
+            written_bytes: AtomicU64::new(0),
+            used: AtomicBool::new(false),
+        })
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The bytes of the objects of the stage, which are not removed yet.
+    pub fn get_written_bytes(&self) -> u64 {
+        self.written_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Writes the data to a new object of the stage, returns the path of the object.
+    pub(crate) fn write_object(&self, data: Vec<u8>) -> Result<String> {
+        let path = format!("{}{}", self.prefix, Uuid::new_v4().simple());
+        let bytes = data.len() as u64;
+        let object = self.operator.object(&path);
+        self.used.store(true, Ordering::Relaxed);
+        run_blocking(async move { Ok(object.write(data).await?) })?;
+        self.written_bytes.fetch_add(bytes, Ordering::Relaxed);
+        Ok(path)
+    }
+
+    pub(crate) fn read_object(&self, path: &str) -> Result<Vec<u8>> {
+        let object = self.operator.object(path);
+        run_blocking(async move { Ok(object.read().await?) })
+    }
+
+    /// Removes the object in the background, the removal never fails the query.
+    pub(crate) fn remove_object(&self, path: &str, bytes: u64) {
+        self.written_bytes.fetch_sub(bytes, Ordering::Relaxed);
+        let object = self.operator.object(path);
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(cause) = object.delete().await {
+                tracing::warn!(
+                    "Cannot remove the object {} of the temporary stage: {}",
+                    object.path(),
+                    cause
+                );
+            }
+        });
+    }
+
+    /// Removes the temporary stages left by the crashed processes in the storage.
+    pub async fn remove_stale(operator: Operator) -> Result<()> {
+        let now = now_secs();
+        let dir = format!("{}/", QUERY_TEMP_STAGE_PREFIX);
+        let mut entries = operator.object(&dir).list().await?;
+        while let Some(de) = entries.try_next().await? {
+            if !de.mode().is_dir() {
+                continue;
+            }
+            let created_secs = de
+                .name()
+                .split('-')
+                .next()
+                .and_then(|secs| secs.parse::<u64>().ok());
+            match created_secs {
+                Some(secs) if secs + STALE_TEMP_STAGE_SECS < now => {
+                    tracing::info!("Remove the stale temporary stage {}", de.path());
+                    operator.batch().remove_all(de.path()).await?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for QueryTempStage {
+    fn drop(&mut self) {
+        if !self.used.load(Ordering::Relaxed) {
+            return;
+        }
+        let operator = self.operator.clone();
+        let prefix = self.prefix.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(cause) = operator.batch().remove_all(&prefix).await {
+                tracing::warn!("Cannot remove the temporary stage {}: {}", prefix, cause);
+            }
+        });
+    }
+}
+
+// The IO of the storage is async, while the processors spilling are sync.
+fn run_blocking<T, F>(future: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T>> + Send + 'static,
+{
+    let join_handle = GlobalIORuntime::instance().spawn(future);
+    futures::executor::block_on(join_handle).map_err(|e| {
+        ErrorCode::TokioError(format!("the IO of the temporary stage panicked: {}", e))
+    })?
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,279 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use fs2::FileExt;
+use once_cell::sync::OnceCell;
+use openssl::symm::Cipher;
+use openssl::symm::Crypter;
+use openssl::symm::Mode;
+use rand::RngCore;
+use uuid::Uuid;
+
+use crate::Config;
+
+// The process holds the exclusive lock of the file in its directory while it runs.
+const LOCK_FILE: &str = "LOCK";
+const IV_LEN: usize = 16;
+
+static SPILL_MANAGER: OnceCell<Arc<SpillManager>> = OnceCell::new();
+
+/// Manages the files spilled by the sorts, the aggregations and the joins of the queries.
+///
+/// The files of a process are in its own directory under the spill directory, which is locked
+/// while the process runs, so the directories left by the crashed processes are removed as the
+/// next process starts. The files of a query are in the directory of the query, which is
+/// removed as the query ends, and are limited by the quota of the query.
+pub struct SpillManager {
+    dir: PathBuf,
+    query_quota_bytes: u64,
+    // The files are encrypted by AES-256-CTR with the key of the process, which never outlives
+    // the files.
+    encryption_key: Option<[u8; 32]>,
+    _lock: File,
+}
+
+impl SpillManager {
+    pub fn init(conf: &Config) -> Result<()> {
+        let root = match conf.query.spill_local_disk_path.is_empty() {
+            true => std::env::temp_dir().join("databend-spill"),
+            false => PathBuf::from(&conf.query.spill_local_disk_path),
+        };
+        SPILL_MANAGER.get_or_try_init(|| {
+            Self::create(
+                &root,
+                conf.query.spill_query_quota_bytes,
+                conf.query.spill_encryption_enabled,
+            )
+        })?;
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SpillManager> {
+        match SPILL_MANAGER.get() {
+            None => panic!("SpillManager is not init"),
+            Some(spill_manager) => spill_manager.clone(),
+        }
+    }
+
+    /// Creates the directory of the process under `root`, removing the directories left by the
+    /// crashed processes. The spilled files of a query are at most `query_quota_bytes`, no
+    /// limit if it's zero.
+    pub fn create(
+        root: &Path,
+        query_quota_bytes: u64,
+        encrypted: bool,
+    ) -> Result<Arc<SpillManager>> {
+        std::fs::create_dir_all(root)?;
+        Self::remove_stale_dirs(root);
+
+        let dir = root.join(Uuid::new_v4().simple().to_string());
+        std::fs::create_dir_all(&dir)?;
+        let lock = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .open(dir.join(LOCK_FILE))?;
+        lock.try_lock_exclusive()?;
+
+        let encryption_key = encrypted.then(|| {
+            let mut key = [0u8; 32];
+            rand::thread_rng().fill_bytes(&mut key);
+            key
+        });
+        Ok(Arc::new(SpillManager {
+            dir,
+            query_quota_bytes,
+            encryption_key,
+            _lock: lock,
+        }))
+    }
+
+    /// The spill of a query, the directory of which is removed as the spill drops. Each query
+    /// context has its own spill, even if the fragments of a query run on the same node.
+    pub fn create_query_spill(self: &Arc<Self>, query_id: &str) -> Arc<QuerySpill> {
+        let dir = self
+            .dir
+            .join(format!("{}-{}", query_id, Uuid::new_v4().simple()));
+        Arc::new(QuerySpill {
+            manager: self.clone(),
+            dir,
+            spilled_bytes: AtomicU64::new(0),
+        })
+    }
+
+    // The directories whose lock is not held are left by the crashed processes. The directory
+    // without the lock file is skipped, it may be just created by a starting process.
+    fn remove_stale_dirs(root: &Path) {
+        let entries = match std::fs::read_dir(root) {
+            Ok(entries) => entries,
+            Err(cause) => {
+                tracing::warn!("Cannot list the spill directory {:?}: {}", root, cause);
+                return;
+            }
+        };
+        for entry in entries.flatten() {
+            let dir = entry.path();
+            let lock = match File::open(dir.join(LOCK_FILE)) {
+                Ok(lock) => lock,
+                Err(_) => continue,
+            };
+            if lock.try_lock_exclusive().is_ok() {
+                tracing::info!(
+                    "Remove the spilled files left by the crashed process {:?}",
+                    dir
+                );
+                if let Err(cause) = std::fs::remove_dir_all(&dir) {
+                    tracing::warn!("Cannot remove the spill directory {:?}: {}", dir, cause);
+                }
+            }
+        }
+    }
+}
+
+/// The files spilled by a query, within the quota of the query.
+pub struct QuerySpill {
+    manager: Arc<SpillManager>,
+    dir: PathBuf,
+    spilled_bytes: AtomicU64,
+}
+
+impl QuerySpill {
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn get_spilled_bytes(&self) -> u64 {
+        self.spilled_bytes.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn create_file(&self) -> Result<(PathBuf, Box<dyn Write>)> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(Uuid::new_v4().simple().to_string());
+        let file = File::create(&path)?;
+        let writer: Box<dyn Write> = match &self.manager.encryption_key {
+            None => Box::new(file),
+            Some(key) => Box::new(EncryptedWriter::try_create(file, key)?),
+        };
+        Ok((path, writer))
+    }
+
+    /// The content of the file, which is decrypted if the files are encrypted.
+    pub(crate) fn read_file(&self, path: &Path) -> Result<Option<Vec<u8>>> {
+        let key = match &self.manager.encryption_key {
+            None => return Ok(None),
+            Some(key) => key,
+        };
+        let data = std::fs::read(path)?;
+        if data.len() < IV_LEN {
+            return Err(ErrorCode::StorageOther(format!(
+                "the spilled file {:?} is truncated",
+                path
+            )));
+        }
+        let (iv, ciphertext) = data.split_at(IV_LEN);
+        openssl::symm::decrypt(Cipher::aes_256_ctr(), key, Some(iv), ciphertext)
+            .map(Some)
+            .map_err(|e| {
+                ErrorCode::UnexpectedError(format!(
+                    "fail to decrypt the spilled file {:?}: {}",
+                    path, e
+                ))
+            })
+    }
+
+    /// Accounts the bytes of a new spilled file, fails if the files exceed the quota.
+    pub(crate) fn reserve(&self, bytes: u64) -> Result<()> {
+        let spilled_bytes = self.spilled_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        let quota = self.manager.query_quota_bytes;
+        if quota != 0 && spilled_bytes > quota {
+            self.spilled_bytes.fetch_sub(bytes, Ordering::Relaxed);
+            return Err(ErrorCode::SpillQuotaExceeded(format!(
+                "Aborted query, because the files spilled to the disk exceed the spill_query_quota_bytes {} bytes",
+                quota
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn release(&self, bytes: u64) {
+        self.spilled_bytes.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+impl Drop for QuerySpill {
+    fn drop(&mut self) {
+        match std::fs::remove_dir_all(&self.dir) {
+            Err(cause) if cause.kind() != std::io::ErrorKind::NotFound => {
+                tracing::warn!(
+                    "Cannot remove the spill directory {:?}: {}",
+                    self.dir,
+                    cause
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+// Encrypts the bytes written, after the random IV of the file.
+struct EncryptedWriter<W: Write> {
+    inner: W,
+    crypter: Crypter,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> EncryptedWriter<W> {
+    fn try_create(mut inner: W, key: &[u8]) -> Result<Self> {
+        let mut iv = [0u8; IV_LEN];
+        rand::thread_rng().fill_bytes(&mut iv);
+        let crypter =
+            Crypter::new(Cipher::aes_256_ctr(), Mode::Encrypt, key, Some(&iv)).map_err(|e| {
+                ErrorCode::UnexpectedError(format!("fail to encrypt the spilled file: {}", e))
+            })?;
+        inner.write_all(&iv)?;
+        Ok(EncryptedWriter {
+            inner,
+            crypter,
+            buf: vec![],
+        })
+    }
+}
+
+impl<W: Write> Write for EncryptedWriter<W> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        // The output of the stream cipher is as long as the input.
+        self.buf
+            .resize(data.len() + Cipher::aes_256_ctr().block_size(), 0);
+        let len = self
+            .crypter
+            .update(data, &mut self.buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        self.inner.write_all(&self.buf[..len])?;
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
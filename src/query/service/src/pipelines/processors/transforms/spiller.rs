@@ -0,0 +1,207 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_base::base::MemoryTracker;
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_datablocks::write_ipc_blocks;
+use common_datablocks::DataBlock;
+use common_datablocks::IpcBlockReader;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use memmap2::Mmap;
+
+use super::query_temp_stage::QueryTempStage;
+use super::spill_manager::QuerySpill;
+
+/// Whether the memory usage of the query exceeds the max_memory_usage, it's only tracked in
+/// the processor threads of the query.
+pub fn is_memory_exceeded() -> bool {
+    match MemoryTracker::current() {
+        None => false,
+        Some(memory_tracker) => memory_tracker.is_exceeded(),
+    }
+}
+
+/// Aborts the query if the memory usage exceeds the max_memory_usage, for the operators which
+/// cannot spill to the disk.
+pub fn check_memory_limit(operator: &str) -> Result<()> {
+    match MemoryTracker::current().and_then(|memory_tracker| memory_tracker.get_exceeded()) {
+        Some((memory_usage, memory_limit)) => Err(ErrorCode::MemoryLimitExceeded(format!(
+            "Aborted query, because the memory usage {} bytes exceeds the max_memory_usage {} bytes in {}, which cannot spill to the disk",
+            memory_usage, memory_limit, operator
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Where the blocks are spilled to, the local disk or the temporary stage of the query in the
+/// storage.
+#[derive(Clone)]
+pub enum SpillTarget {
+    Disk(Arc<QuerySpill>),
+    Storage(Arc<QueryTempStage>),
+}
+
+impl From<Arc<QuerySpill>> for SpillTarget {
+    fn from(spill: Arc<QuerySpill>) -> Self {
+        SpillTarget::Disk(spill)
+    }
+}
+
+impl From<Arc<QueryTempStage>> for SpillTarget {
+    fn from(temp_stage: Arc<QueryTempStage>) -> Self {
+        SpillTarget::Storage(temp_stage)
+    }
+}
+
+/// Spills the blocks to the files of the query, in the arrow IPC format.
+///
+/// The files on the disk are mapped into the memory to be read back, and the columns are
+/// created over them, unless they are encrypted. The objects in the storage are read back into
+/// the memory as a whole.
+pub struct Spiller {
+    target: SpillTarget,
+    progress: Arc<Progress>,
+}
+
+impl Spiller {
+    pub fn create(target: impl Into<SpillTarget>, progress: Arc<Progress>) -> Spiller {
+        Spiller {
+            target: target.into(),
+            progress,
+        }
+    }
+
+    /// Writes the blocks of the same schema to a new file, they are read back in the same order.
+    pub fn spill(&self, schema: DataSchemaRef, blocks: &[DataBlock]) -> Result<SpilledFile> {
+        // The buffers are not compressed, to be read in place.
+        let options = WriteOptions::default();
+        let rows = blocks.iter().map(|block| block.num_rows()).sum();
+
+        let spilled = match &self.target {
+            SpillTarget::Disk(spill) => {
+                let (path, writer) = spill.create_file()?;
+                let mut spilled = SpilledFile {
+                    target: self.target.clone(),
+                    path: path.to_string_lossy().to_string(),
+                    bytes: 0,
+                    schema: schema.clone(),
+                    reader: None,
+                };
+                write_ipc_blocks(BufWriter::new(writer), &schema, blocks, options)?;
+                let bytes = std::fs::metadata(&path)?.len();
+                spill.reserve(bytes)?;
+                spilled.bytes = bytes;
+                spilled
+            }
+            SpillTarget::Storage(temp_stage) => {
+                let mut data = vec![];
+                write_ipc_blocks(&mut data, &schema, blocks, options)?;
+                let bytes = data.len() as u64;
+                SpilledFile {
+                    target: self.target.clone(),
+                    path: temp_stage.write_object(data)?,
+                    bytes,
+                    schema: schema.clone(),
+                    reader: None,
+                }
+            }
+        };
+        let bytes = spilled.bytes;
+
+        self.progress.incr(&ProgressValues {
+            rows,
+            bytes: bytes as usize,
+        });
+
+        Ok(spilled)
+    }
+}
+
+enum SpilledData {
+    Mapped(Mmap),
+    Loaded(Vec<u8>),
+}
+
+impl AsRef<[u8]> for SpilledData {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            SpilledData::Mapped(data) => data.as_ref(),
+            SpilledData::Loaded(data) => data.as_ref(),
+        }
+    }
+}
+
+/// The file of the spilled blocks, it's removed when dropped.
+pub struct SpilledFile {
+    target: SpillTarget,
+    // The path of the file on the disk, or of the object in the storage.
+    path: String,
+    // The bytes accounted in the quota of the query.
+    bytes: u64,
+    schema: DataSchemaRef,
+    reader: Option<IpcBlockReader<SpilledData>>,
+}
+
+impl SpilledFile {
+    /// Reads the next block of the file, `None` if all the blocks are read.
+    pub fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        if self.reader.is_none() {
+            let data = match &self.target {
+                SpillTarget::Disk(spill) => match spill.read_file(Path::new(&self.path))? {
+                    Some(data) => SpilledData::Loaded(data),
+                    None => {
+                        let file = File::open(&self.path)?;
+                        // soundness: the file is private to the query, nor changed once spilled
+                        SpilledData::Mapped(unsafe { Mmap::map(&file)? })
+                    }
+                },
+                SpillTarget::Storage(temp_stage) => {
+                    SpilledData::Loaded(temp_stage.read_object(&self.path)?)
+                }
+            };
+            let reader = IpcBlockReader::try_create(self.schema.clone(), Arc::new(data))?;
+            self.reader = Some(reader);
+        }
+
+        match self.reader.as_mut() {
+            None => Ok(None),
+            Some(reader) => reader.next_block(),
+        }
+    }
+}
+
+impl Drop for SpilledFile {
+    fn drop(&mut self) {
+        self.reader.take();
+
+        match &self.target {
+            SpillTarget::Disk(spill) => {
+                if let Err(cause) = std::fs::remove_file(&self.path) {
+                    tracing::warn!("Cannot remove the spilled file {:?}: {}", self.path, cause);
+                }
+                spill.release(self.bytes);
+            }
+            SpillTarget::Storage(temp_stage) => temp_stage.remove_object(&self.path, self.bytes),
+        }
+    }
+}
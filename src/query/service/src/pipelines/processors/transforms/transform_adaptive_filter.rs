@@ -0,0 +1,54 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_functions::scalars::FunctionContext;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::Transform;
+use common_pipeline_transforms::processors::transforms::Transformer;
+
+use crate::evaluator::AdaptiveFilter;
+
+/// Filters the blocks by the conjuncts of a predicate in the order measured at runtime, each
+/// processor measures the blocks it filters.
+pub struct TransformAdaptiveFilter {
+    func_ctx: FunctionContext,
+    filter: AdaptiveFilter,
+}
+
+impl TransformAdaptiveFilter {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        filter: AdaptiveFilter,
+    ) -> ProcessorPtr {
+        Transformer::<Self>::create(input_port, output_port, Self { func_ctx, filter })
+    }
+}
+
+impl Transform for TransformAdaptiveFilter {
+    const NAME: &'static str = "AdaptiveFilter";
+
+    const SKIP_EMPTY_DATA_BLOCK: bool = true;
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        self.filter.filter(&self.func_ctx, data)
+    }
+}
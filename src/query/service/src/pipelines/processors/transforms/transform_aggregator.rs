@@ -25,6 +25,9 @@ use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::transforms::aggregator::*;
+use crate::pipelines::processors::transforms::check_memory_limit;
+use crate::pipelines::processors::transforms::spiller::is_memory_exceeded;
+use crate::pipelines::processors::transforms::Spiller;
 use crate::pipelines::processors::AggregatorTransformParams;
 use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
@@ -37,6 +40,7 @@ impl TransformAggregator {
         output_port: Arc<OutputPort>,
         transform_params: AggregatorTransformParams,
         ctx: Arc<QueryContext>,
+        spiller: Option<Spiller>,
     ) -> Result<ProcessorPtr> {
         let aggregator_params = transform_params.aggregator_params;
 
@@ -53,84 +57,159 @@ impl TransformAggregator {
                 HashMethodKind::KeysU8(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU8FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU8FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU16(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU16FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU16FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU32(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU32FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU32FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU64(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU64FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU64FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::Serializer(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    SerializerFinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    SerializerFinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU128(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU128FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU128FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU256(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU256FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU256FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU512(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU512FinalAggregator::<false>::create(ctx, method, aggregator_params)?,
+                    KeysU512FinalAggregator::<false>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
             },
             false => match transform_params.method {
                 HashMethodKind::KeysU8(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU8FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU8FinalAggregator::<true>::create(ctx, method, aggregator_params, spiller)?,
                 ),
                 HashMethodKind::KeysU16(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU16FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU16FinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU32(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU32FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU32FinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU64(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU64FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU64FinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::Serializer(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    SerializerFinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    SerializerFinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU128(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU128FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU128FinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU256(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU256FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU256FinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
                 HashMethodKind::KeysU512(method) => AggregatorTransform::create(
                     transform_params.transform_input_port,
                     transform_params.transform_output_port,
-                    KeysU512FinalAggregator::<true>::create(ctx, method, aggregator_params)?,
+                    KeysU512FinalAggregator::<true>::create(
+                        ctx,
+                        method,
+                        aggregator_params,
+                        spiller,
+                    )?,
                 ),
             },
         }
@@ -246,6 +325,14 @@ pub trait Aggregator: Sized + Send {
 
     fn consume(&mut self, data: DataBlock) -> Result<()>;
     fn generate(&mut self) -> Result<Option<DataBlock>>;
+
+    /// Releases the memory of the groups consumed so far, once the memory usage exceeds the
+    /// max_memory_usage: the partial aggregators output them before the input is finished, and
+    /// the final aggregators spill them to the disk. The query is aborted by default.
+    fn release(&mut self) -> Result<Option<DataBlock>> {
+        check_memory_limit("aggregation")?;
+        Ok(None)
+    }
 }
 
 enum AggregatorTransform<TAggregator: Aggregator> {
@@ -268,6 +355,7 @@ impl<TAggregator: Aggregator + 'static> AggregatorTransform<TAggregator> {
                 input_port,
                 output_port,
                 input_data_block: None,
+                output_data_block: None,
             },
         ))))
     }
@@ -279,7 +367,7 @@ impl<TAggregator: Aggregator + 'static> AggregatorTransform<TAggregator> {
                     inner: s.inner,
                     is_finished: false,
                     output_port: s.output_port,
-                    output_data_block: None,
+                    output_data_block: s.output_data_block,
                 }))
             }
             _ => Err(ErrorCode::LogicalError("")),
@@ -317,6 +405,17 @@ impl<TAggregator: Aggregator + 'static> AggregatorTransform<TAggregator> {
     #[inline(always)]
     fn consume_event(&mut self) -> Result<Event> {
         if let AggregatorTransform::ConsumeData(state) = self {
+            if state.output_data_block.is_some() {
+                if !state.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                state
+                    .output_port
+                    .push_data(Ok(state.output_data_block.take().unwrap()));
+                return Ok(Event::NeedConsume);
+            }
+
             if state.input_data_block.is_some() {
                 return Ok(Event::Sync);
             }
@@ -385,12 +484,17 @@ struct ConsumeState<TAggregator: Aggregator> {
     input_port: Arc<InputPort>,
     output_port: Arc<OutputPort>,
     input_data_block: Option<DataBlock>,
+    // The groups released beyond the max_memory_usage, output before the input is finished.
+    output_data_block: Option<DataBlock>,
 }
 
 impl<TAggregator: Aggregator> ConsumeState<TAggregator> {
     pub fn consume(&mut self) -> Result<()> {
         if let Some(input_data) = self.input_data_block.take() {
             self.inner.consume(input_data)?;
+            if is_memory_exceeded() {
+                self.output_data_block = self.inner.release()?;
+            }
         }
 
         Ok(())
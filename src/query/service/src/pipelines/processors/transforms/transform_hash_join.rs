@@ -26,6 +26,7 @@ use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::transforms::spiller::is_memory_exceeded;
 use crate::pipelines::processors::Processor;
 use crate::pipelines::processors::Sink;
 use crate::sessions::QueryContext;
@@ -50,13 +51,20 @@ impl Sink for SinkBuildHashTable {
     }
 
     fn consume(&mut self, data_block: DataBlock) -> Result<()> {
-        self.join_state.build(data_block)
+        self.join_state.build(data_block)?;
+        if is_memory_exceeded() {
+            self.join_state.spill_build()?;
+        }
+        Ok(())
     }
 }
 
 enum HashJoinStep {
     Build,
     Probe,
+    // Joins the partitions spilled by all the processors, by the last one to finish the probe.
+    ProbeSpilled,
+    Finished,
 }
 
 pub struct TransformHashJoinProbe {
@@ -71,15 +79,16 @@ pub struct TransformHashJoinProbe {
 }
 
 impl TransformHashJoinProbe {
-    pub fn create(
+    pub fn try_create(
         ctx: Arc<QueryContext>,
         input_port: Arc<InputPort>,
         output_port: Arc<OutputPort>,
         join_state: Arc<dyn HashJoinState>,
         _output_schema: DataSchemaRef,
-    ) -> ProcessorPtr {
+    ) -> Result<ProcessorPtr> {
+        join_state.attach_probe()?;
         let default_block_size = ctx.get_settings().get_max_block_size().unwrap_or(102400);
-        ProcessorPtr::create(Box::new(TransformHashJoinProbe {
+        Ok(ProcessorPtr::create(Box::new(TransformHashJoinProbe {
             input_data: None,
             output_data_blocks: VecDeque::new(),
             input_port,
@@ -87,7 +96,7 @@ impl TransformHashJoinProbe {
             step: HashJoinStep::Build,
             join_state,
             probe_state: ProbeState::with_capacity(default_block_size as usize),
-        }))
+        })))
     }
 
     fn probe(&mut self, block: &DataBlock) -> Result<()> {
@@ -139,6 +148,11 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
+                    if self.join_state.detach_probe()? {
+                        self.step = HashJoinStep::ProbeSpilled;
+                        return Ok(Event::Sync);
+                    }
+
                     self.output_port.finish();
                     return Ok(Event::Finished);
                 }
@@ -146,18 +160,47 @@ impl Processor for TransformHashJoinProbe {
                 self.input_port.set_need_data();
                 Ok(Event::NeedData)
             }
+            HashJoinStep::ProbeSpilled | HashJoinStep::Finished => {
+                if self.output_port.is_finished() {
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if let HashJoinStep::Finished = self.step {
+                    self.output_port.finish();
+                    return Ok(Event::Finished);
+                }
+                Ok(Event::Sync)
+            }
         }
     }
 
     fn process(&mut self) -> Result<()> {
         match self.step {
-            HashJoinStep::Build => Ok(()),
+            HashJoinStep::Build | HashJoinStep::Finished => Ok(()),
             HashJoinStep::Probe => {
                 if let Some(data) = self.input_data.take() {
                     self.probe(&data)?;
                 }
                 Ok(())
             }
+            HashJoinStep::ProbeSpilled => {
+                self.probe_state.clear();
+                match self.join_state.probe_spilled(&mut self.probe_state)? {
+                    None => self.step = HashJoinStep::Finished,
+                    Some(blocks) => self.output_data_blocks.extend(blocks),
+                }
+                Ok(())
+            }
         }
     }
 
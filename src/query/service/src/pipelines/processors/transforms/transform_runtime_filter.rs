@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_functions::scalars::FunctionContext;
+
+use super::hash_join::RuntimeFilter;
+use crate::evaluator::EvalNode;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::Processor;
+
+/// Filters the blocks of the scan of the probe side by the runtime filter of the hash
+/// join, the blocks are held until the build side of the join is finished.
+pub struct TransformRuntimeFilter {
+    input_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    func_ctx: FunctionContext,
+    join_state: Arc<dyn HashJoinState>,
+    probe_keys: Vec<EvalNode>,
+    runtime_filter: Option<Arc<RuntimeFilter>>,
+}
+
+impl TransformRuntimeFilter {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        join_state: Arc<dyn HashJoinState>,
+        probe_keys: Vec<EvalNode>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformRuntimeFilter {
+            input_data: None,
+            output_data: None,
+            input_port,
+            output_port,
+            func_ctx,
+            join_state,
+            probe_keys,
+            runtime_filter: None,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRuntimeFilter {
+    fn name(&self) -> &'static str {
+        "RuntimeFilter"
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.runtime_filter.is_none() {
+            return Ok(Event::Async);
+        }
+
+        if self.output_port.is_finished() {
+            self.input_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output_port.can_push() {
+            self.input_port.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data) = self.output_data.take() {
+            self.output_port.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.has_data() {
+            self.input_data = Some(self.input_port.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.is_finished() {
+            self.output_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input_port.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let (Some(data), Some(runtime_filter)) = (self.input_data.take(), &self.runtime_filter) {
+            let data = runtime_filter.filter(&self.func_ctx, &self.probe_keys, data)?;
+            if data.num_rows() > 0 {
+                self.output_data = Some(data);
+            }
+        }
+        Ok(())
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        if self.runtime_filter.is_none() {
+            self.join_state.wait_finish().await?;
+            self.runtime_filter = Some(self.join_state.runtime_filter()?);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+
+use super::spiller::is_memory_exceeded;
+use super::spiller::SpilledFile;
+use super::spiller::Spiller;
+
+enum SortSpillState {
+    Consume,
+    Spill,
+    Finalize,
+    Merge,
+    Finished,
+}
+
+struct SortedRun {
+    file: SpilledFile,
+    current: Option<DataBlock>,
+}
+
+/// Merges the sorted blocks like `TransformSortMerge`, but the buffered blocks are sorted and
+/// spilled to the disk as a run when the memory usage exceeds the max_memory_usage, the runs
+/// are merged block by block at last.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    state: SortSpillState,
+
+    limit: Option<usize>,
+    block_size: usize,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    spiller: Spiller,
+
+    buffered_blocks: Vec<DataBlock>,
+    sorted_runs: Vec<SortedRun>,
+    output_blocks: VecDeque<DataBlock>,
+    output_rows: usize,
+}
+
+impl TransformSortSpill {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        limit: Option<usize>,
+        block_size: usize,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        spiller: Spiller,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(TransformSortSpill {
+            input,
+            output,
+            state: SortSpillState::Consume,
+            limit,
+            block_size,
+            sort_columns_descriptions,
+            spiller,
+            buffered_blocks: vec![],
+            sorted_runs: vec![],
+            output_blocks: VecDeque::new(),
+            output_rows: 0,
+        })))
+    }
+
+    fn spill_buffered_blocks(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.buffered_blocks);
+        let block =
+            DataBlock::merge_sort_blocks(&blocks, &self.sort_columns_descriptions, self.limit)?;
+        drop(blocks);
+
+        if block.num_rows() != 0 {
+            let blocks = DataBlock::split_block_by_size(&block, self.block_size)?;
+            let file = self.spiller.spill(block.schema().clone(), &blocks)?;
+            self.sorted_runs.push(SortedRun {
+                file,
+                current: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn merge_sorted_runs(&mut self) -> Result<Option<DataBlock>> {
+        if matches!(self.limit, Some(limit) if self.output_rows >= limit) {
+            return Ok(None);
+        }
+
+        for run in &mut self.sorted_runs {
+            if run.current.is_none() {
+                run.current = run.file.next_block()?;
+            }
+        }
+
+        self.sorted_runs.retain(|run| run.current.is_some());
+        if self.sorted_runs.is_empty() {
+            return Ok(None);
+        }
+
+        // The rows not greater than the smallest last row of the current blocks can be merged,
+        // the current block of its run is merged entirely.
+        let descriptions = &self.sort_columns_descriptions;
+        let mut bound = 0;
+        for index in 1..self.sorted_runs.len() {
+            let lhs = self.sorted_runs[index].current.as_ref().unwrap();
+            let rhs = self.sorted_runs[bound].current.as_ref().unwrap();
+            let ordering = DataBlock::compare_sort_rows(
+                lhs,
+                lhs.num_rows() - 1,
+                rhs,
+                rhs.num_rows() - 1,
+                descriptions,
+            )?;
+
+            if ordering == Ordering::Less {
+                bound = index;
+            }
+        }
+
+        let bound_block = self.sorted_runs[bound].current.clone().unwrap();
+        let bound_row = bound_block.num_rows() - 1;
+
+        let mut blocks = Vec::with_capacity(self.sorted_runs.len());
+        for run in &mut self.sorted_runs {
+            let current = run.current.take().unwrap();
+            let rows =
+                DataBlock::sort_rows_not_greater(&current, &bound_block, bound_row, descriptions)?;
+
+            if rows != 0 {
+                blocks.push(DataBlock::slice_block(&current, 0, rows));
+            }
+
+            if rows != current.num_rows() {
+                let remain = current.num_rows() - rows;
+                run.current = Some(DataBlock::slice_block(&current, rows, remain));
+            }
+        }
+
+        let limit = self.limit.map(|limit| limit - self.output_rows);
+        let mut block = DataBlock::merge_sort_blocks(&blocks, descriptions, limit)?;
+        if let Some(limit) = limit {
+            if block.num_rows() > limit {
+                block = DataBlock::slice_block(&block, 0, limit);
+            }
+        }
+
+        self.output_rows += block.num_rows();
+        Ok(Some(block))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> &'static str {
+        "SortSpillTransform"
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            SortSpillState::Spill | SortSpillState::Finalize | SortSpillState::Merge => {
+                Ok(Event::Sync)
+            }
+            SortSpillState::Finished => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            SortSpillState::Consume => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+                    if block.num_rows() != 0 {
+                        self.buffered_blocks.push(block);
+                    }
+
+                    if is_memory_exceeded() && !self.buffered_blocks.is_empty() {
+                        self.state = SortSpillState::Spill;
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    self.state = SortSpillState::Finalize;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.state {
+            SortSpillState::Spill => {
+                self.spill_buffered_blocks()?;
+                self.state = SortSpillState::Consume;
+            }
+            SortSpillState::Finalize if self.sorted_runs.is_empty() => {
+                if !self.buffered_blocks.is_empty() {
+                    let block = DataBlock::merge_sort_blocks(
+                        &self.buffered_blocks,
+                        &self.sort_columns_descriptions,
+                        self.limit,
+                    )?;
+                    self.buffered_blocks.clear();
+                    self.output_blocks.push_back(block);
+                }
+                self.state = SortSpillState::Finished;
+            }
+            SortSpillState::Finalize => {
+                if !self.buffered_blocks.is_empty() {
+                    self.spill_buffered_blocks()?;
+                }
+                self.state = SortSpillState::Merge;
+            }
+            SortSpillState::Merge => match self.merge_sorted_runs()? {
+                None => self.state = SortSpillState::Finished,
+                Some(block) => self.output_blocks.push_back(block),
+            },
+            _ => return Err(ErrorCode::LogicalError("State invalid. it's a bug.")),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+
+use crate::sessions::QueryResultCacheWriter;
+
+/// Passes the result blocks through and collects them to the writer of the result cache.
+pub struct TransformWriteResultCache {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    writer: Arc<QueryResultCacheWriter>,
+    finished: bool,
+}
+
+impl TransformWriteResultCache {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        writer: Arc<QueryResultCacheWriter>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformWriteResultCache {
+            input,
+            output,
+            writer,
+            finished: false,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWriteResultCache {
+    fn name(&self) -> &'static str {
+        "WriteResultCacheTransform"
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            if !self.finished {
+                self.writer.abort();
+            }
+
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            let block = self.input.pull_data().unwrap()?;
+            self.writer.append(&block);
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.is_finished() {
+            if !self.finished {
+                self.finished = true;
+                self.writer.finish_input();
+            }
+
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Captures the inputs of the queries into the replay bundles, and replays them against the
+//! same data, to reproduce the bugs of the executors in the unit tests.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
+use crate::storages::fuse::FuseTable;
+
+/// The replay bundles are `<prefix>/<query id>.json` in the storage of the query.
+pub const REPLAY_BUNDLE_PREFIX: &str = "_replay";
+const REPLAY_BUNDLE_VERSION: u32 = 1;
+
+/// The inputs of a query to replay it: the statement, the plan, the settings and the snapshots
+/// of the tables it reads.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReplayBundle {
+    pub version: u32,
+    pub query_id: String,
+    pub sql: String,
+    /// The plan of the query, the replayed query must be planned the same.
+    pub plan: String,
+    pub current_database: String,
+    /// The settings of the query changed from the defaults.
+    pub settings: BTreeMap<String, String>,
+    pub tables: Vec<ReplayTable>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReplayTable {
+    pub catalog: String,
+    pub database: String,
+    pub name: String,
+    /// The snapshot of the fuse table read by the query, none if the table is empty or not a
+    /// fuse table, which is read as it is when replayed.
+    pub snapshot_id: Option<String>,
+    /// The locations of the segments of the snapshot.
+    pub segments: Vec<String>,
+}
+
+impl ReplayBundle {
+    pub fn location(query_id: &str) -> String {
+        format!("{}/{}.json", REPLAY_BUNDLE_PREFIX, query_id)
+    }
+
+    /// Captures the inputs of the query planned, its tables are the ones read by the query.
+    pub async fn capture(ctx: Arc<dyn TableContext>, sql: &str, plan: &Plan) -> Result<Self> {
+        let metadata = match plan {
+            Plan::Query { metadata, .. } => metadata.clone(),
+            _ => {
+                return Err(ErrorCode::LogicalError(
+                    "only the queries are captured to replay",
+                ));
+            }
+        };
+
+        let settings = ctx
+            .get_settings()
+            .get_changed_settings()
+            .get_setting_values()
+            .into_iter()
+            .filter(|(name, ..)| name != "enable_replay_capture")
+            .map(|(name, value, ..)| (name, value.to_string()))
+            .collect();
+
+        let entries = metadata.read().tables().to_vec();
+        let mut tables: Vec<ReplayTable> = Vec::with_capacity(entries.len());
+        for entry in entries {
+            if tables.iter().any(|t| {
+                t.catalog == entry.catalog()
+                    && t.database == entry.database()
+                    && t.name == entry.name()
+            }) {
+                continue;
+            }
+            let table = entry.table();
+            let snapshot = match FuseTable::try_from_table(table.as_ref()) {
+                Ok(fuse_table) => fuse_table.read_table_snapshot(ctx.clone()).await?,
+                Err(_) => None,
+            };
+            tables.push(ReplayTable {
+                catalog: entry.catalog().to_string(),
+                database: entry.database().to_string(),
+                name: entry.name().to_string(),
+                snapshot_id: snapshot
+                    .as_ref()
+                    .map(|snapshot| snapshot.snapshot_id.simple().to_string()),
+                segments: snapshot
+                    .map(|snapshot| {
+                        snapshot
+                            .segments
+                            .iter()
+                            .map(|(loc, _)| loc.clone())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            });
+        }
+
+        Ok(ReplayBundle {
+            version: REPLAY_BUNDLE_VERSION,
+            query_id: ctx.get_id(),
+            sql: sql.to_string(),
+            plan: plan.format_indent()?,
+            current_database: ctx.get_current_database(),
+            settings,
+            tables,
+        })
+    }
+
+    /// Writes the bundle into the storage of the query.
+    pub async fn save(&self, ctx: &dyn TableContext) -> Result<()> {
+        let data = serde_json::to_vec_pretty(self)?;
+        ctx.get_storage_operator()?
+            .object(&Self::location(&self.query_id))
+            .write(data)
+            .await?;
+        Ok(())
+    }
+
+    /// Reads the bundle of the query captured from the storage of the query.
+    pub async fn load(ctx: &dyn TableContext, query_id: &str) -> Result<Self> {
+        let data = ctx
+            .get_storage_operator()?
+            .object(&Self::location(query_id))
+            .read()
+            .await?;
+        Self::from_json(&data)
+    }
+
+    pub fn from_json(data: &[u8]) -> Result<Self> {
+        let bundle: ReplayBundle = serde_json::from_slice(data)?;
+        if bundle.version != REPLAY_BUNDLE_VERSION {
+            return Err(ErrorCode::ReplayBundleMismatch(format!(
+                "unsupported version {} of the replay bundle",
+                bundle.version
+            )));
+        }
+        Ok(bundle)
+    }
+}
+
+/// Replays the query of the bundle in the context: the tables are read as of the snapshots
+/// captured, with the settings captured, and the query is planned the same as captured.
+pub async fn replay_query(ctx: Arc<QueryContext>, bundle: &ReplayBundle) -> Result<Vec<DataBlock>> {
+    for (name, value) in &bundle.settings {
+        ctx.set_query_setting(name.clone(), value.clone())?;
+    }
+    // The query is planned and executed again, instead of being served by the caches.
+    ctx.set_query_setting("enable_plan_cache".to_string(), "0".to_string())?;
+    ctx.set_query_setting("enable_query_result_cache".to_string(), "0".to_string())?;
+    ctx.set_query_setting("enable_replay_capture".to_string(), "0".to_string())?;
+    ctx.set_current_database(bundle.current_database.clone())
+        .await?;
+
+    for replay_table in &bundle.tables {
+        let table = ctx
+            .get_table(
+                &replay_table.catalog,
+                &replay_table.database,
+                &replay_table.name,
+            )
+            .await?;
+        let snapshot_id = match &replay_table.snapshot_id {
+            Some(snapshot_id) => snapshot_id,
+            None => continue,
+        };
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table = fuse_table
+            .navigate_to_snapshot(ctx.clone(), snapshot_id)
+            .await?;
+        let segments: Vec<String> = table
+            .read_table_snapshot(ctx.clone())
+            .await?
+            .map(|snapshot| {
+                snapshot
+                    .segments
+                    .iter()
+                    .map(|(loc, _)| loc.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if segments != replay_table.segments {
+            return Err(ErrorCode::ReplayBundleMismatch(format!(
+                "the segments of the snapshot {} of table {}.{} differ from the captured ones",
+                snapshot_id, replay_table.database, replay_table.name
+            )));
+        }
+        ctx.pin_read_table(
+            &replay_table.catalog,
+            &replay_table.database,
+            &replay_table.name,
+            table,
+        );
+    }
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(&bundle.sql).await?;
+    let formatted_plan = plan.format_indent()?;
+    if formatted_plan != bundle.plan {
+        return Err(ErrorCode::ReplayBundleMismatch(format!(
+            "the replayed query is planned differently, captured:\n{}\nreplayed:\n{}",
+            bundle.plan, formatted_plan
+        )));
+    }
+
+    ctx.attach_query_str(plan.to_string(), &bundle.sql);
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+}
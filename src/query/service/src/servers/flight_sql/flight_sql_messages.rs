@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The messages of the Arrow Flight SQL protocol used by the handler, the messages are
+//! packed in `google.protobuf.Any` in the commands, the tickets and the actions.
+//!
+//! https://github.com/apache/arrow/blob/master/format/FlightSql.proto
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use prost::Message;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+
+pub const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+pub const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+/// The message of the Flight SQL protocol with the name in the `Any`.
+pub trait FlightSQLMessage: Message + Default {
+    const NAME: &'static str;
+}
+
+/// The `google.protobuf.Any`.
+#[derive(Clone, PartialEq, Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+impl Any {
+    pub fn pack<T: FlightSQLMessage>(message: &T) -> Any {
+        Any {
+            type_url: format!("{}{}", TYPE_URL_PREFIX, T::NAME),
+            value: message.encode_to_vec(),
+        }
+    }
+
+    pub fn decode_from(bytes: &[u8]) -> Result<Any> {
+        Any::decode(bytes)
+            .map_err(|cause| ErrorCode::BadBytes(format!("invalid flight sql message: {}", cause)))
+    }
+
+    /// The name of the message, e.g. `CommandStatementQuery`.
+    pub fn name(&self) -> &str {
+        self.type_url
+            .strip_prefix(TYPE_URL_PREFIX)
+            .unwrap_or(&self.type_url)
+    }
+
+    pub fn is<T: FlightSQLMessage>(&self) -> bool {
+        self.name() == T::NAME
+    }
+
+    pub fn unpack<T: FlightSQLMessage>(&self) -> Result<T> {
+        if !self.is::<T>() {
+            return Err(ErrorCode::BadBytes(format!(
+                "expected flight sql message {}, but got {}",
+                T::NAME,
+                self.type_url
+            )));
+        }
+
+        T::decode(self.value.as_slice())
+            .map_err(|cause| ErrorCode::BadBytes(format!("invalid {}: {}", T::NAME, cause)))
+    }
+}
+
+macro_rules! flight_sql_message {
+    ($name:ident) => {
+        impl FlightSQLMessage for $name {
+            const NAME: &'static str = stringify!($name);
+        }
+    };
+}
+
+/// Executes the query, the results are fetched by the ticket of the flight info.
+#[derive(Clone, PartialEq, Message)]
+pub struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    pub query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub transaction_id: Option<Vec<u8>>,
+}
+
+/// The ticket of the results of the `CommandStatementQuery`.
+#[derive(Clone, PartialEq, Message)]
+pub struct TicketStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    pub statement_handle: Vec<u8>,
+}
+
+/// Executes the query without results by `DoPut`, e.g. the insert or the DDL.
+#[derive(Clone, PartialEq, Message)]
+pub struct CommandStatementUpdate {
+    #[prost(string, tag = "1")]
+    pub query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub transaction_id: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct DoPutUpdateResult {
+    #[prost(int64, tag = "1")]
+    pub record_count: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ActionCreatePreparedStatementRequest {
+    #[prost(string, tag = "1")]
+    pub query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub transaction_id: Option<Vec<u8>>,
+}
+
+/// The schemas are the IPC encapsulated schemas, the parameter schema is empty as
+/// the parameters are not supported.
+#[derive(Clone, PartialEq, Message)]
+pub struct ActionCreatePreparedStatementResult {
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub dataset_schema: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub parameter_schema: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ActionClosePreparedStatementRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: Vec<u8>,
+}
+
+/// Executes the prepared statement with results, used in the flight descriptor and
+/// the ticket both.
+#[derive(Clone, PartialEq, Message)]
+pub struct CommandPreparedStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: Vec<u8>,
+}
+
+/// Executes the prepared statement without results by `DoPut`.
+#[derive(Clone, PartialEq, Message)]
+pub struct CommandPreparedStatementUpdate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: Vec<u8>,
+}
+
+flight_sql_message!(CommandStatementQuery);
+flight_sql_message!(TicketStatementQuery);
+flight_sql_message!(CommandStatementUpdate);
+flight_sql_message!(DoPutUpdateResult);
+flight_sql_message!(ActionCreatePreparedStatementRequest);
+flight_sql_message!(ActionCreatePreparedStatementResult);
+flight_sql_message!(ActionClosePreparedStatementRequest);
+flight_sql_message!(CommandPreparedStatementQuery);
+flight_sql_message!(CommandPreparedStatementUpdate);
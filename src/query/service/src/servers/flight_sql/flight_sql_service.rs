@@ -0,0 +1,624 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_arrow::arrow::io::flight::default_ipc_fields;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::flight::serialize_schema_to_info;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow_format::flight::data::Action;
+use common_arrow::arrow_format::flight::data::ActionType;
+use common_arrow::arrow_format::flight::data::Criteria;
+use common_arrow::arrow_format::flight::data::Empty;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
+use common_arrow::arrow_format::flight::data::FlightEndpoint;
+use common_arrow::arrow_format::flight::data::FlightInfo;
+use common_arrow::arrow_format::flight::data::HandshakeRequest;
+use common_arrow::arrow_format::flight::data::HandshakeResponse;
+use common_arrow::arrow_format::flight::data::PutResult;
+use common_arrow::arrow_format::flight::data::Result as FlightResult;
+use common_arrow::arrow_format::flight::data::SchemaResult;
+use common_arrow::arrow_format::flight::data::Ticket;
+use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
+use common_base::base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_types::UserInfo;
+use common_users::UserApiProvider;
+use futures::StreamExt;
+use headers::authorization::Basic;
+use headers::authorization::Bearer;
+use headers::authorization::Credentials;
+use http::HeaderValue;
+use parking_lot::RwLock;
+use prost::Message;
+use tokio_stream::Stream;
+use tonic::metadata::Ascii;
+use tonic::metadata::MetadataValue;
+use tonic::Request;
+use tonic::Response as RawResponse;
+use tonic::Status;
+use tonic::Streaming;
+
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::flight_sql::flight_sql_messages::ActionClosePreparedStatementRequest;
+use crate::servers::flight_sql::flight_sql_messages::ActionCreatePreparedStatementRequest;
+use crate::servers::flight_sql::flight_sql_messages::ActionCreatePreparedStatementResult;
+use crate::servers::flight_sql::flight_sql_messages::Any;
+use crate::servers::flight_sql::flight_sql_messages::CommandPreparedStatementQuery;
+use crate::servers::flight_sql::flight_sql_messages::CommandPreparedStatementUpdate;
+use crate::servers::flight_sql::flight_sql_messages::CommandStatementQuery;
+use crate::servers::flight_sql::flight_sql_messages::CommandStatementUpdate;
+use crate::servers::flight_sql::flight_sql_messages::DoPutUpdateResult;
+use crate::servers::flight_sql::flight_sql_messages::TicketStatementQuery;
+use crate::servers::flight_sql::flight_sql_messages::CLOSE_PREPARED_STATEMENT;
+use crate::servers::flight_sql::flight_sql_messages::CREATE_PREPARED_STATEMENT;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+// The bearer tokens of the handshake are expired once not used for this long.
+const FLIGHT_SQL_TOKEN_TTL: Duration = Duration::from_secs(3600);
+
+// The prepared statements are expired once not used for this long.
+const FLIGHT_SQL_PREPARED_STATEMENT_TTL: Duration = Duration::from_secs(3600);
+
+// The prepared statements of a user at the same time.
+const FLIGHT_SQL_MAX_PREPARED_STATEMENTS: usize = 256;
+
+// The number of the buffered flight data of the results, before being sent to the client.
+const FLIGHT_SQL_RESULT_BUFFER_SIZE: usize = 8;
+
+pub type FlightStream<T> =
+    Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send + Sync + 'static>>;
+
+type Response<T> = Result<RawResponse<T>, Status>;
+type StreamReq<T> = Request<Streaming<T>>;
+
+struct Token {
+    tenant: String,
+    user: UserInfo,
+    last_access: Instant,
+}
+
+struct PreparedStatement {
+    tenant: String,
+    user: String,
+    query: String,
+    last_access: Instant,
+}
+
+/// The Arrow Flight SQL service, the results are sent as the Arrow record batches.
+///
+/// Each call runs in a new session of the user authenticated by the `authorization`
+/// header, either the basic auth, the JWT, or the bearer token from the handshake. The
+/// user of the bearer token is validated again by each call, the token is revoked once
+/// the user is dropped or its password is changed.
+pub struct FlightSQLService {
+    tokens: RwLock<HashMap<String, Token>>,
+    prepared_statements: RwLock<HashMap<Vec<u8>, PreparedStatement>>,
+}
+
+impl FlightSQLService {
+    pub fn create() -> Self {
+        FlightSQLService {
+            tokens: RwLock::new(HashMap::new()),
+            prepared_statements: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn create_session<T>(&self, request: &Request<T>) -> Result<Arc<Session>, Status> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .ok_or_else(|| Status::unauthenticated("authorization header is required"))?
+            .to_str()
+            .map_err(|_| Status::unauthenticated("invalid authorization header"))?;
+
+        let session = SessionManager::instance()
+            .create_session(SessionType::FlightSQL)
+            .await?;
+        let client_ip = request.remote_addr().map(|addr| addr.ip().to_string());
+        if let Some(token) = authorization.strip_prefix("Bearer ") {
+            if let Some((tenant, user)) = self.token_user(token) {
+                session.set_current_tenant(tenant);
+                if let Err(cause) = Self::validate_user(&session, &user, client_ip).await {
+                    self.tokens.write().remove(token);
+                    return Err(Status::unauthenticated(cause.message()));
+                }
+                return Ok(session);
+            }
+        }
+
+        let credential = Self::credential(authorization, client_ip)?;
+        let ctx = session.create_query_context().await?;
+        ctx.get_auth_manager()
+            .auth(session.clone(), &credential)
+            .await
+            .map_err(|cause| Status::unauthenticated(cause.message()))?;
+        Ok(session)
+    }
+
+    fn credential(authorization: &str, hostname: Option<String>) -> Result<Credential, Status> {
+        let value = HeaderValue::from_str(authorization)
+            .map_err(|_| Status::unauthenticated("invalid authorization header"))?;
+        if authorization.starts_with("Basic ") {
+            let basic = Basic::decode(&value)
+                .ok_or_else(|| Status::unauthenticated("bad Basic auth header"))?;
+            let password = basic.password().as_bytes().to_vec();
+            Ok(Credential::Password {
+                name: basic.username().to_string(),
+                password: (!password.is_empty()).then_some(password),
+                hostname,
+            })
+        } else if authorization.starts_with("Bearer ") {
+            let bearer = Bearer::decode(&value)
+                .ok_or_else(|| Status::unauthenticated("bad Bearer auth header"))?;
+            Ok(Credential::Jwt {
+                token: bearer.token().to_string(),
+                hostname,
+            })
+        } else {
+            Err(Status::unauthenticated("bad auth header"))
+        }
+    }
+
+    fn token_user(&self, token: &str) -> Option<(String, UserInfo)> {
+        let mut tokens = self.tokens.write();
+        match tokens.get_mut(token) {
+            Some(token) if token.last_access.elapsed() < FLIGHT_SQL_TOKEN_TTL => {
+                token.last_access = Instant::now();
+                Some((token.tenant.clone(), token.user.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    // Validates the user of the token as the login does, with the current user info, the
+    // token is rejected if the user is dropped or its auth info is changed since the handshake.
+    async fn validate_user(
+        session: &Arc<Session>,
+        user: &UserInfo,
+        client_ip: Option<String>,
+    ) -> Result<()> {
+        let tenant = session.get_current_tenant();
+        let hostname = client_ip.as_deref().unwrap_or("%");
+        let current = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, &user.name, hostname)
+            .await?;
+        if current.auth_info != user.auth_info {
+            return Err(ErrorCode::AuthenticateFailure(
+                "the auth info of the user is changed, please login again",
+            ));
+        }
+
+        let ctx = session.create_query_context().await?;
+        ctx.get_auth_manager()
+            .check_network_policy(session, &current, client_ip.as_deref())
+            .await?;
+        SessionManager::instance().check_user_connections(session, &current)?;
+        session.set_current_user(current);
+        Ok(())
+    }
+
+    // The query of the command packed in the flight descriptor, with the ticket to fetch
+    // the results of the query.
+    fn command_query(&self, session: &Arc<Session>, cmd: &[u8]) -> Result<(String, Any), Status> {
+        let command = Any::decode_from(cmd)?;
+        if command.is::<CommandStatementQuery>() {
+            let command = command.unpack::<CommandStatementQuery>()?;
+            let ticket = Any::pack(&TicketStatementQuery {
+                statement_handle: command.query.clone().into_bytes(),
+            });
+            Ok((command.query, ticket))
+        } else if command.is::<CommandPreparedStatementQuery>() {
+            let handle = &command
+                .unpack::<CommandPreparedStatementQuery>()?
+                .prepared_statement_handle;
+            Ok((self.prepared_query(session, handle)?, command))
+        } else {
+            Err(Status::unimplemented(format!(
+                "Unsupported flight sql command: {}",
+                command.type_url
+            )))
+        }
+    }
+
+    fn prepared_query(&self, session: &Arc<Session>, handle: &[u8]) -> Result<String, Status> {
+        let user = session.get_current_user()?;
+        let tenant = session.get_current_tenant();
+        match self.prepared_statements.write().get_mut(handle) {
+            Some(statement)
+                if statement.tenant == tenant
+                    && statement.user == user.name
+                    && statement.last_access.elapsed() < FLIGHT_SQL_PREPARED_STATEMENT_TTL =>
+            {
+                statement.last_access = Instant::now();
+                Ok(statement.query.clone())
+            }
+            _ => Err(Status::not_found("prepared statement not found")),
+        }
+    }
+
+    // Adds the prepared statement of the user of the session, the expired statements are
+    // removed, and the statements of a user are limited.
+    fn add_prepared_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: Vec<u8>,
+        query: String,
+    ) -> Result<(), Status> {
+        let user = session.get_current_user()?.name;
+        let tenant = session.get_current_tenant();
+
+        let mut statements = self.prepared_statements.write();
+        statements.retain(|_, statement| {
+            statement.last_access.elapsed() < FLIGHT_SQL_PREPARED_STATEMENT_TTL
+        });
+        let user_statements = statements
+            .values()
+            .filter(|statement| statement.tenant == tenant && statement.user == user)
+            .count();
+        if user_statements >= FLIGHT_SQL_MAX_PREPARED_STATEMENTS {
+            return Err(Status::resource_exhausted(format!(
+                "too many prepared statements of the user, the max is {}",
+                FLIGHT_SQL_MAX_PREPARED_STATEMENTS
+            )));
+        }
+
+        statements.insert(handle, PreparedStatement {
+            tenant,
+            user,
+            query,
+            last_access: Instant::now(),
+        });
+        Ok(())
+    }
+
+    async fn plan_query(
+        session: &Arc<Session>,
+        query: &str,
+    ) -> Result<(Arc<QueryContext>, Arc<dyn Interpreter>)> {
+        let context = session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, _, _) = planner.plan_sql(query).await?;
+
+        context.attach_query_str(plan.to_string(), query);
+        match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => Ok((context, interpreter)),
+            Err(cause) => {
+                InterpreterQueryLog::fail_to_start(context, cause.clone());
+                Err(cause)
+            }
+        }
+    }
+
+    // The IPC encapsulated schema, as the schema of the flight info.
+    fn ipc_schema(schema: &DataSchemaRef) -> Result<Vec<u8>> {
+        let arrow_schema = schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        Ok(serialize_schema_to_info(&arrow_schema, Some(&ipc_fields))?)
+    }
+
+    fn block_flight_data(
+        block: DataBlock,
+        ipc_fields: &[IpcField],
+        options: &WriteOptions,
+    ) -> Result<Vec<FlightData>> {
+        let chunks = block.try_into()?;
+        let (mut flight_data, values) = serialize_batch(&chunks, ipc_fields, options)?;
+        flight_data.push(values);
+        Ok(flight_data)
+    }
+
+    // Executes the query in the runtime of the query, the session is kept until the
+    // results are all sent.
+    fn execute_query(
+        session: Arc<Session>,
+        context: Arc<QueryContext>,
+        interpreter: Arc<dyn Interpreter>,
+    ) -> Result<FlightStream<FlightData>> {
+        let arrow_schema = interpreter.schema().to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let schema = serialize_schema(&arrow_schema, Some(&ipc_fields));
+
+        let (tx, rx) = async_channel::bounded(FLIGHT_SQL_RESULT_BUFFER_SIZE);
+        context.try_spawn({
+            let ctx = context.clone();
+            async move {
+                let _session = session;
+                if tx.send(Ok(schema)).await.is_err() {
+                    return;
+                }
+
+                let mut blocks = match interpreter.execute(ctx).await {
+                    Ok(blocks) => blocks,
+                    Err(cause) => {
+                        let _ = tx.send(Err(cause.into())).await;
+                        return;
+                    }
+                };
+
+                let options = WriteOptions { compression: None };
+                while let Some(block) = blocks.next().await {
+                    let flight_data = block
+                        .and_then(|block| Self::block_flight_data(block, &ipc_fields, &options));
+                    match flight_data {
+                        Err(cause) => {
+                            let _ = tx.send(Err(cause.into())).await;
+                            return;
+                        }
+                        Ok(flight_data) => {
+                            for data in flight_data {
+                                if tx.send(Ok(data)).await.is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })?;
+
+        Ok(Box::pin(rx))
+    }
+
+    // Executes the query without results, returns the number of the written rows.
+    async fn execute_update(
+        context: Arc<QueryContext>,
+        interpreter: Arc<dyn Interpreter>,
+    ) -> Result<i64> {
+        let update = context.try_spawn({
+            let ctx = context.clone();
+            async move {
+                let mut blocks = interpreter.execute(ctx.clone()).await?;
+                while let Some(block) = blocks.next().await {
+                    block?;
+                }
+                Ok::<_, ErrorCode>(ctx.get_write_progress_value().rows as i64)
+            }
+        })?;
+
+        update.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+}
+
+#[async_trait::async_trait]
+impl FlightService for FlightSQLService {
+    type HandshakeStream = FlightStream<HandshakeResponse>;
+
+    // Authenticates the user by the basic auth, the bearer token in the response is
+    // used by the later calls of the client.
+    async fn handshake(
+        &self,
+        request: StreamReq<HandshakeRequest>,
+    ) -> Response<Self::HandshakeStream> {
+        let session = self.create_session(&request).await?;
+        let user = session.get_current_user()?;
+        let token = uuid::Uuid::new_v4().to_string();
+        {
+            let mut tokens = self.tokens.write();
+            tokens.retain(|_, token| token.last_access.elapsed() < FLIGHT_SQL_TOKEN_TTL);
+            tokens.insert(token.clone(), Token {
+                tenant: session.get_current_tenant(),
+                user,
+                last_access: Instant::now(),
+            });
+        }
+
+        let authorization = format!("Bearer {}", token)
+            .parse::<MetadataValue<Ascii>>()
+            .map_err(|_| Status::internal("invalid bearer token"))?;
+        let output = tokio_stream::once(Ok(HandshakeResponse {
+            protocol_version: 0,
+            payload: token.into_bytes(),
+        }));
+        let mut response = RawResponse::new(Box::pin(output) as FlightStream<HandshakeResponse>);
+        response
+            .metadata_mut()
+            .insert("authorization", authorization);
+        Ok(response)
+    }
+
+    type ListFlightsStream = FlightStream<FlightInfo>;
+
+    async fn list_flights(&self, _: Request<Criteria>) -> Response<Self::ListFlightsStream> {
+        Err(Status::unimplemented(
+            "DatabendQuery does not implement list_flights.",
+        ))
+    }
+
+    async fn get_flight_info(&self, request: Request<FlightDescriptor>) -> Response<FlightInfo> {
+        let session = self.create_session(&request).await?;
+        let descriptor = request.into_inner();
+        let (query, ticket) = self.command_query(&session, &descriptor.cmd)?;
+        let (_, interpreter) = Self::plan_query(&session, &query).await?;
+
+        Ok(RawResponse::new(FlightInfo {
+            schema: Self::ipc_schema(&interpreter.schema())?,
+            flight_descriptor: Some(descriptor),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket {
+                    ticket: ticket.encode_to_vec(),
+                }),
+                location: vec![],
+            }],
+            total_records: -1,
+            total_bytes: -1,
+        }))
+    }
+
+    async fn get_schema(&self, request: Request<FlightDescriptor>) -> Response<SchemaResult> {
+        let session = self.create_session(&request).await?;
+        let descriptor = request.into_inner();
+        let (query, _) = self.command_query(&session, &descriptor.cmd)?;
+        let (_, interpreter) = Self::plan_query(&session, &query).await?;
+
+        Ok(RawResponse::new(SchemaResult {
+            schema: Self::ipc_schema(&interpreter.schema())?,
+        }))
+    }
+
+    type DoGetStream = FlightStream<FlightData>;
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
+        let session = self.create_session(&request).await?;
+        let ticket = Any::decode_from(&request.into_inner().ticket)?;
+        let query = if ticket.is::<TicketStatementQuery>() {
+            let handle = ticket.unpack::<TicketStatementQuery>()?.statement_handle;
+            String::from_utf8(handle).map_err(ErrorCode::from)?
+        } else if ticket.is::<CommandPreparedStatementQuery>() {
+            let handle = ticket
+                .unpack::<CommandPreparedStatementQuery>()?
+                .prepared_statement_handle;
+            self.prepared_query(&session, &handle)?
+        } else {
+            return Err(Status::unimplemented(format!(
+                "Unsupported flight sql ticket: {}",
+                ticket.type_url
+            )));
+        };
+
+        let (context, interpreter) = Self::plan_query(&session, &query).await?;
+        let stream = Self::execute_query(session, context, interpreter)?;
+        Ok(RawResponse::new(stream))
+    }
+
+    type DoPutStream = FlightStream<PutResult>;
+
+    async fn do_put(&self, request: StreamReq<FlightData>) -> Response<Self::DoPutStream> {
+        let session = self.create_session(&request).await?;
+        let descriptor = request
+            .into_inner()
+            .message()
+            .await?
+            .and_then(|data| data.flight_descriptor)
+            .ok_or_else(|| Status::invalid_argument("flight descriptor is required"))?;
+
+        let command = Any::decode_from(&descriptor.cmd)?;
+        let query = if command.is::<CommandStatementUpdate>() {
+            command.unpack::<CommandStatementUpdate>()?.query
+        } else if command.is::<CommandPreparedStatementUpdate>() {
+            let handle = command
+                .unpack::<CommandPreparedStatementUpdate>()?
+                .prepared_statement_handle;
+            self.prepared_query(&session, &handle)?
+        } else {
+            return Err(Status::unimplemented(format!(
+                "Unsupported flight sql command: {}",
+                command.type_url
+            )));
+        };
+
+        let (context, interpreter) = Self::plan_query(&session, &query).await?;
+        let record_count = Self::execute_update(context, interpreter).await?;
+        let result = PutResult {
+            app_metadata: DoPutUpdateResult { record_count }.encode_to_vec(),
+        };
+        Ok(RawResponse::new(
+            Box::pin(tokio_stream::once(Ok(result))) as FlightStream<PutResult>
+        ))
+    }
+
+    type DoExchangeStream = FlightStream<FlightData>;
+
+    async fn do_exchange(&self, _: StreamReq<FlightData>) -> Response<Self::DoExchangeStream> {
+        Err(Status::unimplemented("unimplement do_exchange"))
+    }
+
+    type DoActionStream = FlightStream<FlightResult>;
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
+        let session = self.create_session(&request).await?;
+        let action = request.into_inner();
+        match action.r#type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let request = Any::decode_from(&action.body)?
+                    .unpack::<ActionCreatePreparedStatementRequest>()?;
+                let (_, interpreter) = Self::plan_query(&session, &request.query).await?;
+
+                let handle = uuid::Uuid::new_v4().as_bytes().to_vec();
+                self.add_prepared_statement(&session, handle.clone(), request.query)?;
+
+                let result = Any::pack(&ActionCreatePreparedStatementResult {
+                    prepared_statement_handle: handle,
+                    dataset_schema: Self::ipc_schema(&interpreter.schema())?,
+                    parameter_schema: vec![],
+                });
+                let result = FlightResult {
+                    body: result.encode_to_vec(),
+                };
+                Ok(RawResponse::new(
+                    Box::pin(tokio_stream::once(Ok(result))) as FlightStream<FlightResult>
+                ))
+            }
+            CLOSE_PREPARED_STATEMENT => {
+                let handle = Any::decode_from(&action.body)?
+                    .unpack::<ActionClosePreparedStatementRequest>()?
+                    .prepared_statement_handle;
+                self.prepared_query(&session, &handle)?;
+                self.prepared_statements.write().remove(&handle);
+                Ok(RawResponse::new(
+                    Box::pin(tokio_stream::empty()) as FlightStream<FlightResult>
+                ))
+            }
+            action_type => Err(Status::unimplemented(format!(
+                "Unimplemented action type: {:?}",
+                action_type
+            ))),
+        }
+    }
+
+    type ListActionsStream = FlightStream<ActionType>;
+
+    async fn list_actions(&self, _: Request<Empty>) -> Response<Self::ListActionsStream> {
+        let actions = vec![
+            Ok(ActionType {
+                r#type: CREATE_PREPARED_STATEMENT.to_string(),
+                description: "Creates a reusable prepared statement resource on the server."
+                    .to_string(),
+            }),
+            Ok(ActionType {
+                r#type: CLOSE_PREPARED_STATEMENT.to_string(),
+                description: "Closes a reusable prepared statement resource on the server."
+                    .to_string(),
+            }),
+        ];
+        Ok(RawResponse::new(
+            Box::pin(tokio_stream::iter(actions)) as FlightStream<ActionType>
+        ))
+    }
+}
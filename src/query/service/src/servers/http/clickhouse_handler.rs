@@ -39,11 +39,11 @@ use poem::error::Result as PoemResult;
 use poem::get;
 use poem::post;
 use poem::web::Query;
-use poem::web::WithContentType;
 use poem::Body;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -86,6 +86,11 @@ pub struct StatementHandlerParams {
     // in secs
     #[allow(unused)]
     with_stacktrace: Option<u8>,
+    // used to authenticate in the middleware.
+    #[allow(unused)]
+    user: Option<String>,
+    #[allow(unused)]
+    password: Option<String>,
     #[serde(flatten)]
     settings: HashMap<String, String>,
 }
@@ -98,6 +103,38 @@ impl StatementHandlerParams {
     pub fn query(&self) -> String {
         self.query.clone().unwrap_or_default()
     }
+
+    // the `database` param takes precedence over the `X-ClickHouse-Database` header.
+    fn database(&self, headers: &HeaderMap) -> Result<Option<String>> {
+        match (&self.database, headers.get("X-CLICKHOUSE-DATABASE")) {
+            (Some(database), _) => Ok(Some(database.clone())),
+            (None, Some(v)) => Ok(Some(
+                v.to_str()
+                    .map_err_to_code(
+                        ErrorCode::BadBytes,
+                        || "value of X-CLICKHOUSE-DATABASE is not string",
+                    )?
+                    .to_string(),
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+// The headers of the response of clickhouse, some clients rely on them, e.g. the
+// timezone to parse the datetime values.
+fn clickhouse_response(
+    ctx: &Arc<QueryContext>,
+    body: Body,
+    format: OutputFormatType,
+) -> Result<Response> {
+    let timezone = ctx.get_format_settings()?.timezone;
+    Ok(Response::builder()
+        .content_type(format.get_content_type())
+        .header("X-ClickHouse-Query-Id", ctx.get_id())
+        .header("X-ClickHouse-Format", format.to_string())
+        .header("X-ClickHouse-Timezone", timezone.name())
+        .body(body))
 }
 
 async fn execute(
@@ -107,7 +144,7 @@ async fn execute(
     format: OutputFormatType,
     params: StatementHandlerParams,
     handle: Option<JoinHandle<()>>,
-) -> Result<WithContentType<Body>> {
+) -> Result<Response> {
     let mut data_stream = interpreter.execute(ctx.clone()).await?;
     let format_setting = ctx.get_format_settings()?;
     let mut output_format = format.create_format(schema, format_setting);
@@ -164,7 +201,7 @@ async fn execute(
         handle.await.expect("must")
     }
 
-    Ok(Body::from_bytes_stream(stream).with_content_type(format.get_content_type()))
+    clickhouse_response(&ctx, Body::from_bytes_stream(stream), format)
 }
 
 #[poem::handler]
@@ -172,10 +209,10 @@ pub async fn clickhouse_handler_get(
     ctx: &HttpQueryContext,
     Query(params): Query<StatementHandlerParams>,
     headers: &HeaderMap,
-) -> PoemResult<WithContentType<Body>> {
+) -> PoemResult<Response> {
     let session = ctx.get_session(SessionType::ClickHouseHttpHandler);
-    if let Some(db) = &params.database {
-        session.set_current_database(db.clone());
+    if let Some(db) = params.database(headers).map_err(BadRequest)? {
+        session.set_current_database(db);
     }
     let context = session
         .create_query_context()
@@ -216,8 +253,8 @@ pub async fn clickhouse_handler_post(
     headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     let session = ctx.get_session(SessionType::ClickHouseHttpHandler);
-    if let Some(db) = &params.database {
-        session.set_current_database(db.clone());
+    if let Some(db) = params.database(headers).map_err(BadRequest)? {
+        session.set_current_database(db);
     }
     let ctx = session
         .create_query_context()
@@ -356,7 +393,7 @@ fn serialize_one_block(
     sql: &str,
     params: &StatementHandlerParams,
     default_format: OutputFormatType,
-) -> Result<WithContentType<Body>> {
+) -> Result<Response> {
     let format_setting = ctx.get_format_settings()?;
     let fmt = match CLickHouseFederated::get_format(sql) {
         Some(format) => OutputFormatType::from_str(format.as_str())?,
@@ -370,7 +407,7 @@ fn serialize_one_block(
     }
     res.append(&mut data);
     res.append(&mut output_format.finalize()?);
-    Ok(Body::from(res).with_content_type(fmt.get_content_type()))
+    clickhouse_response(&ctx, Body::from(res), fmt)
 }
 
 fn get_default_format(
@@ -23,6 +23,7 @@ use poem::listener::RustlsConfig;
 use poem::middleware::CatchPanic;
 use poem::middleware::NormalizePath;
 use poem::middleware::TrailingSlash;
+use poem::post;
 use poem::put;
 use poem::Endpoint;
 use poem::EndpointExt;
@@ -34,7 +35,10 @@ use crate::auth::AuthMgr;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::query_route;
+use crate::servers::http::v1::session_route;
 use crate::servers::http::v1::streaming_load;
+use crate::servers::http::v1::streaming_query;
+use crate::servers::http::v1::table_route;
 use crate::servers::Server;
 use crate::Config;
 
@@ -92,7 +96,10 @@ impl HttpHandler {
                 )
                 .nest("/clickhouse", clickhouse_router())
                 .nest("/v1/query", query_route())
+                .nest("/v1/session", session_route())
+                .nest("/v1/tables", table_route())
                 .at("/v1/streaming_load", put(streaming_load))
+                .at("/v1/streaming_query", post(streaming_query))
                 .at("/v1/upload_to_stage", put(upload_to_stage)),
             HttpHandlerKind::Clickhouse => Route::new().nest("/", clickhouse_router()),
         };
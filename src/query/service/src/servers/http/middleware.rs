@@ -27,6 +27,7 @@ use poem::Addr;
 use poem::Endpoint;
 use poem::Middleware;
 use poem::Request;
+use serde::Deserialize;
 use tracing::info;
 use tracing::warn;
 
@@ -61,16 +62,7 @@ fn get_credential(req: &Request, kind: HttpHandlerKind) -> Result<Credential> {
     };
     if auth_headers.is_empty() {
         if let HttpHandlerKind::Clickhouse = kind {
-            let (user, key) = (
-                req.headers().get("X-CLICKHOUSE-USER"),
-                req.headers().get("X-CLICKHOUSE-KEY"),
-            );
-            if let (Some(name), Some(password)) = (user, key) {
-                let c = Credential::Password {
-                    name: String::from_utf8(name.as_bytes().to_vec()).unwrap(),
-                    password: Some(password.as_bytes().to_vec()),
-                    hostname: client_ip,
-                };
+            if let Some(c) = get_clickhouse_credential(req, client_ip)? {
                 return Ok(c);
             }
         }
@@ -107,6 +99,43 @@ fn get_credential(req: &Request, kind: HttpHandlerKind) -> Result<Credential> {
     }
 }
 
+#[derive(Deserialize)]
+struct ClickHouseCredentialParams {
+    user: Option<String>,
+    password: Option<String>,
+}
+
+// clickhouse clients pass the user by the `X-ClickHouse-User` and `X-ClickHouse-Key`
+// headers, or the `user` and `password` params of the url.
+fn get_clickhouse_credential(
+    req: &Request,
+    client_ip: Option<String>,
+) -> Result<Option<Credential>> {
+    let (name, password) = match req.headers().get("X-CLICKHOUSE-USER") {
+        Some(name) => (
+            name.to_str()
+                .map_err(|_| ErrorCode::AuthenticateFailure("bad X-ClickHouse-User header"))?
+                .to_string(),
+            req.headers()
+                .get("X-CLICKHOUSE-KEY")
+                .map(|key| key.as_bytes().to_vec()),
+        ),
+        None => match req.params::<ClickHouseCredentialParams>() {
+            Ok(ClickHouseCredentialParams {
+                user: Some(name),
+                password,
+            }) => (name, password.map(String::into_bytes)),
+            _ => return Ok(None),
+        },
+    };
+
+    Ok(Some(Credential::Password {
+        name,
+        password: password.filter(|password| !password.is_empty()),
+        hostname: client_ip,
+    }))
+}
+
 impl<E: Endpoint> Middleware<E> for HTTPSessionMiddleware {
     type Output = HTTPSessionEndpoint<E>;
     fn transform(&self, ep: E) -> Self::Output {
@@ -17,6 +17,10 @@ use std::str::FromStr;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_formats::output_format::OutputFormatType;
+use common_formats::result_field_metadata;
+use common_formats::FIELD_META_DATABEND_TYPE;
+use common_formats::FIELD_META_TIMEZONE;
+use common_io::prelude::FormatSettings;
 use poem::error::BadRequest;
 use poem::error::Error as PoemError;
 use poem::error::InternalServerError;
@@ -30,10 +34,13 @@ use poem::web::Path;
 use poem::web::Query;
 use poem::Body;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
+use sha2::Digest;
+use sha2::Sha256;
 use tracing::error;
 use tracing::info;
 
@@ -65,17 +72,31 @@ pub fn make_kill_uri(query_id: &str) -> String {
     format!("/v1/query/{}/kill", query_id)
 }
 
+pub fn make_schema_uri(query_id: &str) -> String {
+    format!("/v1/query/{}/schema", query_id)
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct QueryError {
     pub code: u16,
     pub message: String,
+    // The name and the category of the code in the catalog, e.g. `UnknownTable` and `not_found`.
+    pub name: String,
+    pub category: String,
+    // Whether the same query may succeed if it's retried later.
+    pub retryable: bool,
+    pub user_error: bool,
 }
 
 impl QueryError {
-    fn from_error_code(e: &ErrorCode) -> Self {
+    pub(crate) fn from_error_code(e: &ErrorCode) -> Self {
         QueryError {
             code: e.code(),
             message: e.message(),
+            name: e.name().to_string(),
+            category: e.category().to_string(),
+            retryable: e.is_retryable(),
+            user_error: e.is_user_error(),
         }
     }
 }
@@ -104,6 +125,7 @@ pub struct QueryResponse {
     pub final_uri: Option<String>,
     pub next_uri: Option<String>,
     pub kill_uri: Option<String>,
+    pub schema_uri: Option<String>,
 }
 
 impl QueryResponse {
@@ -134,6 +156,7 @@ impl QueryResponse {
             stats_uri: Some(make_state_uri(&id)),
             final_uri: Some(make_final_uri(&id)),
             kill_uri: Some(make_kill_uri(&id)),
+            schema_uri: Some(make_schema_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
         }
     }
@@ -152,11 +175,77 @@ impl QueryResponse {
             stats_uri: None,
             final_uri: None,
             kill_uri: None,
+            schema_uri: None,
             error: Some(QueryError::from_error_code(err)),
         }
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QuerySchemaField {
+    pub name: String,
+    /// The databend type, without the nullability, e.g. `TIMESTAMP(6)`.
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub nullable: bool,
+    /// The time zone the timestamps are rendered in, the timestamp columns only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuerySchemaResponse {
+    pub id: String,
+    pub state: ExecuteStateKind,
+    /// The columns of the result, `None` until the query is planned, or if it fails to plan.
+    pub fields: Option<Vec<QuerySchemaField>>,
+    /// The hash of the names and the types of the columns, the clients caching the mappings of
+    /// the columns of a statement compare it to tell if the shape of the result changes, e.g.
+    /// after a column of the table is added or its type is altered.
+    pub fingerprint: Option<String>,
+}
+
+impl QuerySchemaResponse {
+    fn create(
+        id: String,
+        state: ExecuteStateKind,
+        schema: Option<DataSchemaRef>,
+        format_settings: &FormatSettings,
+    ) -> QuerySchemaResponse {
+        let fields = schema.map(|schema| {
+            schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    let metadata = result_field_metadata(field, format_settings);
+                    QuerySchemaField {
+                        name: field.name().clone(),
+                        data_type: metadata[FIELD_META_DATABEND_TYPE].clone(),
+                        nullable: field.is_nullable(),
+                        timezone: metadata.get(FIELD_META_TIMEZONE).cloned(),
+                    }
+                })
+                .collect::<Vec<_>>()
+        });
+        let fingerprint = fields.as_ref().map(|fields| {
+            let mut hasher = Sha256::new();
+            for field in fields {
+                hasher.update(format!(
+                    "{}\t{}\t{}\n",
+                    field.name, field.data_type, field.nullable
+                ));
+            }
+            format!("{:x}", hasher.finalize())
+        });
+        QuerySchemaResponse {
+            id,
+            state,
+            fields,
+            fingerprint,
+        }
+    }
+}
+
 #[poem::handler]
 async fn query_detach_handler(
     _ctx: &HttpQueryContext,
@@ -224,6 +313,27 @@ async fn query_page_handler(
     }
 }
 
+#[poem::handler]
+async fn query_schema_handler(
+    _ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+) -> PoemResult<Json<QuerySchemaResponse>> {
+    let http_query_manager = HttpQueryManager::instance();
+    match http_query_manager.get_query(&query_id).await {
+        Some(query) => {
+            let state = query.get_response_state_only().await.state.state;
+            let schema = query.get_result_schema().await;
+            Ok(Json(QuerySchemaResponse::create(
+                query_id,
+                state,
+                schema,
+                query.get_format_settings(),
+            )))
+        }
+        None => Err(query_id_not_found(query_id)),
+    }
+}
+
 #[poem::handler]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
@@ -266,9 +376,13 @@ pub fn query_route() -> Route {
     // Note: endpoints except /v1/query may change without notice, use uris in response instead
     Route::new()
         .at("/", post(query_handler))
-        .at("/:id", get(query_state_handler))
+        .at(
+            "/:id",
+            get(query_state_handler).delete(query_cancel_handler),
+        )
         .at("/:id/download", get(result_download_handler))
         .at("/:id/page/:page_no", get(query_page_handler))
+        .at("/:id/schema", get(query_schema_handler))
         .at(
             "/:id/kill",
             get(query_cancel_handler).post(query_cancel_handler),
@@ -297,7 +411,7 @@ async fn result_download_handler(
     ctx: &HttpQueryContext,
     Path(query_id): Path<String>,
     Query(params): Query<DownloadHandlerParams>,
-) -> PoemResult<Body> {
+) -> PoemResult<Response> {
     let default_format = "csv".to_string();
     let session = ctx.get_session(SessionType::HTTPQuery);
     let format =
@@ -323,6 +437,7 @@ async fn result_download_handler(
         .await
         .map_err(InternalServerError)?;
 
-    let body = Body::from_bytes_stream::<_, _, ErrorCode>(stream);
-    Ok(body)
+    Ok(Response::builder()
+        .content_type(format.get_content_type())
+        .body(Body::from_bytes_stream::<_, _, ErrorCode>(stream)))
 }
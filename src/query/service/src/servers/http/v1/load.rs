@@ -26,13 +26,17 @@ use common_io::prelude::parse_escape_string;
 use common_pipeline_sources::processors::sources::input_formats::InputContext;
 use common_pipeline_sources::processors::sources::input_formats::StreamingReadBatch;
 use futures::StreamExt;
+use opendal::io_util::CompressAlgorithm;
 use poem::error::BadRequest;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
 use poem::web::Json;
 use poem::web::Multipart;
+use poem::Body;
+use poem::FromRequest;
 use poem::Request;
+use poem::RequestBody;
 use serde::Deserialize;
 use serde::Serialize;
 use tokio::sync::mpsc::Sender;
@@ -81,12 +85,34 @@ fn remove_quote(s: &[u8]) -> &[u8] {
     r
 }
 
+/// The data to load, either the files of a `multipart/form-data` body, or the
+/// body itself, which may be sent with the chunked transfer encoding.
+enum LoadBody {
+    Multipart(Multipart),
+    Raw(Body),
+}
+
+impl LoadBody {
+    async fn from_request(req: &Request, body: Body) -> PoemResult<Self> {
+        let is_multipart = req
+            .content_type()
+            .map_or(false, |v| v.starts_with("multipart/"));
+        if is_multipart {
+            let multipart = Multipart::from_request(req, &mut RequestBody::new(body)).await?;
+            Ok(LoadBody::Multipart(multipart))
+        } else {
+            Ok(LoadBody::Raw(body))
+        }
+    }
+}
+
 #[poem::handler]
 pub async fn streaming_load(
     ctx: &HttpQueryContext,
     req: &Request,
-    mut multipart: Multipart,
+    body: Body,
 ) -> PoemResult<Json<LoadResponse>> {
+    let body = LoadBody::from_request(req, body).await?;
     let session = ctx.get_session(SessionType::HTTPStreamingLoad);
     let context = session
         .create_query_context()
@@ -145,7 +171,15 @@ pub async fn streaming_load(
                 tracing::info!("streaming load {:?}", input_context);
 
                 let handler = context.spawn(execute_query(context.clone(), plan));
-                let files = read_multi_part(multipart, tx, &input_context).await?;
+                let files = match body {
+                    LoadBody::Multipart(multipart) => {
+                        read_multi_part(multipart, tx, &input_context).await?
+                    }
+                    LoadBody::Raw(body) => {
+                        read_raw_body(body, tx, &input_context).await?;
+                        vec![]
+                    }
+                };
 
                 match handler.await {
                     Ok(Ok(_)) => Ok(Json(LoadResponse {
@@ -211,37 +245,73 @@ async fn read_multi_part(
                 tracing::debug!("Multipart start read {}", &filename);
                 files.push(filename.clone());
                 let mut async_reader = field.into_async_read();
-                let mut is_start = true;
-                loop {
-                    let mut batch = vec![0u8; input_context.read_batch_size];
-                    let n = read_full(&mut async_reader, &mut batch[0..])
-                        .await
-                        .map_err(InternalServerError)?;
-                    if n == 0 {
-                        break;
-                    } else {
-                        batch.truncate(n);
-                        tracing::debug!("Multipart read {} bytes", n);
-                        if let Err(e) = tx
-                            .send(Ok(StreamingReadBatch {
-                                data: batch,
-                                path: filename.clone(),
-                                is_start,
-                                compression,
-                            }))
-                            .await
-                        {
-                            tracing::warn!(" Multipart fail to send ReadBatch: {}", e);
-                        }
-                        is_start = false;
-                    }
-                }
+                send_batches(
+                    &mut async_reader,
+                    &filename,
+                    compression,
+                    &tx,
+                    input_context,
+                )
+                .await?;
             }
         }
     }
     Ok(files)
 }
 
+// The body is read as a single file without name, so the compression is only
+// decided by the `compression` setting.
+async fn read_raw_body(
+    body: Body,
+    tx: Sender<Result<StreamingReadBatch>>,
+    input_context: &Arc<InputContext>,
+) -> poem::Result<()> {
+    let path = "";
+    let compression = input_context
+        .get_compression_alg(path)
+        .map_err(BadRequest)?;
+    tracing::debug!("Streaming load start read body");
+    let mut async_reader = body.into_async_read();
+    send_batches(&mut async_reader, path, compression, &tx, input_context).await
+}
+
+// Reads the file in batches and sends them to the pipeline, the channel is bounded
+// so the reading waits for the batches to be consumed.
+async fn send_batches<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    path: &str,
+    compression: Option<CompressAlgorithm>,
+    tx: &Sender<Result<StreamingReadBatch>>,
+    input_context: &Arc<InputContext>,
+) -> poem::Result<()> {
+    let mut is_start = true;
+    loop {
+        let mut batch = vec![0u8; input_context.read_batch_size];
+        let n = read_full(reader, &mut batch[0..])
+            .await
+            .map_err(InternalServerError)?;
+        if n == 0 {
+            break;
+        } else {
+            batch.truncate(n);
+            tracing::debug!("Streaming load read {} bytes", n);
+            if let Err(e) = tx
+                .send(Ok(StreamingReadBatch {
+                    data: batch,
+                    path: path.to_string(),
+                    is_start,
+                    compression,
+                }))
+                .await
+            {
+                tracing::warn!(" Streaming load fail to send ReadBatch: {}", e);
+            }
+            is_start = false;
+        }
+    }
+    Ok(())
+}
+
 pub async fn read_full<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
     let mut buf = &mut buf[0..];
     let mut n = 0;
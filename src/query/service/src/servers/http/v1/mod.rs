@@ -16,13 +16,19 @@ mod http_query_handlers;
 pub mod json_block;
 mod load;
 mod query;
+mod session;
 mod stage;
+mod streaming_query;
+mod table_preview;
 
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
+pub use http_query_handlers::make_schema_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
 pub use http_query_handlers::QueryResponse;
+pub use http_query_handlers::QuerySchemaField;
+pub use http_query_handlers::QuerySchemaResponse;
 pub use http_query_handlers::QueryStats;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
@@ -32,7 +38,14 @@ pub use query::HttpQueryContext;
 pub use query::HttpQueryHandle;
 pub use query::HttpQueryManager;
 pub use query::HttpSessionConf;
+pub use session::session_route;
+pub use session::SessionResponse;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
+pub use streaming_query::streaming_query;
+pub use streaming_query::StreamingQueryFooter;
+pub use streaming_query::StreamingQueryHeader;
+pub use table_preview::table_route;
+pub use table_preview::TablePreviewResponse;
 
 pub use crate::servers::http::clickhouse_handler::clickhouse_router;
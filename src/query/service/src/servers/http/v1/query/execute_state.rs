@@ -71,7 +71,7 @@ pub struct Progresses {
 }
 
 impl Progresses {
-    fn from_context(ctx: &Arc<QueryContext>) -> Self {
+    pub(crate) fn from_context(ctx: &Arc<QueryContext>) -> Self {
         Progresses {
             scan_progress: ctx.get_scan_progress_value(),
             write_progress: ctx.get_write_progress_value(),
@@ -120,6 +120,8 @@ pub struct Executor {
     pub query_id: String,
     pub start_time: Instant,
     pub state: ExecuteState,
+    // The schema of the result, known once the query is planned.
+    pub result_schema: Option<DataSchemaRef>,
 }
 
 impl Executor {
@@ -228,6 +230,7 @@ impl ExecuteState {
 
         let is_select = matches!(&plan, Plan::Query { .. });
         let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        executor.write().await.result_schema = Some(interpreter.schema());
 
         if is_select {
             let running_state = ExecuteRunning {
@@ -368,7 +371,10 @@ impl HttpQueryHandle {
         });
 
         let query_ctx = ctx.clone();
-        let executor_settings = ExecutorSettings::try_create(&ctx.get_settings())?;
+        let executor_settings = ExecutorSettings::try_create(&ctx.get_settings())?
+            .with_max_execute_time(ctx.get_query_max_execution_time())
+            .with_max_memory_usage(ctx.get_query_max_memory_usage())
+            .with_query_memory_tracker(ctx.get_memory_tracker());
 
         let run = move || -> Result<()> {
             let mut pipelines = build_res.sources_pipelines;
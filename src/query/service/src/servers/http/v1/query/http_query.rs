@@ -21,8 +21,10 @@ use common_base::base::tokio;
 use common_base::base::tokio::sync::Mutex as TokioMutex;
 use common_base::base::tokio::sync::RwLock;
 use common_base::base::TrySpawn;
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FormatSettings;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -42,6 +44,7 @@ use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueryAffect;
+use crate::sessions::Session;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 use crate::storages::result::block_buffer::BlockBuffer;
@@ -60,6 +63,8 @@ pub struct HttpQueryRequest {
 const DEFAULT_MAX_ROWS_IN_BUFFER: usize = 5 * 1000 * 1000;
 const DEFAULT_MAX_ROWS_PER_PAGE: usize = 10000;
 const DEFAULT_WAIT_TIME_SECS: u32 = 1;
+// The max of the `result_timeout_secs` of the requests, the results are kept for a day at most.
+const MAX_RESULT_TIMEOUT_SECS: u64 = 24 * 3600;
 
 fn default_max_rows_in_buffer() -> usize {
     DEFAULT_MAX_ROWS_IN_BUFFER
@@ -81,6 +86,9 @@ pub struct PaginationConf {
     pub(crate) max_rows_in_buffer: usize,
     #[serde(default = "default_max_rows_per_page")]
     pub(crate) max_rows_per_page: usize,
+    // overwrite the `http_handler_result_timeout_millis` of the server if set.
+    #[serde(default)]
+    pub(crate) result_timeout_secs: Option<u64>,
 }
 
 impl Default for PaginationConf {
@@ -89,6 +97,7 @@ impl Default for PaginationConf {
             wait_time_secs: 1,
             max_rows_in_buffer: DEFAULT_MAX_ROWS_IN_BUFFER,
             max_rows_per_page: DEFAULT_MAX_ROWS_PER_PAGE,
+            result_timeout_secs: None,
         }
     }
 }
@@ -115,6 +124,35 @@ pub struct HttpSessionConf {
 }
 
 impl HttpSessionConf {
+    /// Sets the current database and the settings of the session.
+    pub(crate) fn apply_to_session(&self, session: &Arc<Session>) -> Result<()> {
+        if let Some(db) = &self.database {
+            session.set_current_database(db.clone());
+        }
+        if let Some(conf_settings) = &self.settings {
+            let settings = session.get_settings();
+            for (k, v) in conf_settings {
+                settings.set_settings(k.to_string(), v.to_string(), false)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The current database and the changed settings of the session.
+    pub(crate) fn from_session(session: &Arc<Session>) -> HttpSessionConf {
+        let settings = session
+            .get_changed_settings()
+            .get_setting_values_short()
+            .into_iter()
+            .map(|(k, v)| (k, v.to_string()))
+            .collect();
+        HttpSessionConf {
+            database: Some(session.get_current_database()),
+            keep_server_session_secs: None,
+            settings: Some(settings),
+        }
+    }
+
     fn apply_affect(&self, affect: &QueryAffect) -> HttpSessionConf {
         let mut ret = self.clone();
         match affect {
@@ -157,6 +195,8 @@ pub struct HttpQuery {
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     data: Arc<TokioMutex<PageManager>>,
+    // The settings the result is rendered with.
+    format_settings: FormatSettings,
     config: HttpQueryConfig,
     expire_at: Arc<TokioMutex<Option<Instant>>>,
 }
@@ -165,14 +205,22 @@ impl HttpQuery {
     pub(crate) async fn try_create(
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
-        config: HttpQueryConfig,
+        mut config: HttpQueryConfig,
     ) -> Result<Arc<HttpQuery>> {
+        if let Some(secs) = request.pagination.result_timeout_secs {
+            config.result_timeout_millis = secs.min(MAX_RESULT_TIMEOUT_SECS).saturating_mul(1000);
+        }
+
         let http_query_manager = HttpQueryManager::instance();
 
         let session = if let Some(id) = &request.session_id {
-            let session = http_query_manager.get_session(id).await.ok_or_else(|| {
-                ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
-            })?;
+            let user = ctx.get_session(SessionType::HTTPQuery).get_current_user()?;
+            let session = http_query_manager
+                .get_user_session(id, &user)
+                .await?
+                .ok_or_else(|| {
+                    ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
+                })?;
             let mut n = 1;
             while let ExpiringState::InUse(query_id) = session.expire_state() {
                 if let Some(last_query) = &http_query_manager.get_query(&query_id).await {
@@ -200,15 +248,7 @@ impl HttpQuery {
         };
 
         if let Some(session_conf) = &request.session {
-            if let Some(db) = &session_conf.database {
-                session.set_current_database(db.clone());
-            }
-            if let Some(conf_settings) = &session_conf.settings {
-                let settings = session.get_settings();
-                for (k, v) in conf_settings {
-                    settings.set_settings(k.to_string(), v.to_string(), false)?;
-                }
-            }
+            session_conf.apply_to_session(&session)?;
             if let Some(secs) = session_conf.keep_server_session_secs {
                 if secs > 0 && request.session_id.is_none() {
                     http_query_manager
@@ -231,6 +271,7 @@ impl HttpQuery {
             query_id: id.clone(),
             start_time,
             state: ExecuteState::Starting(ExecuteStarting { ctx: ctx.clone() }),
+            result_schema: None,
         }));
         let state_clone = state.clone();
         let ctx_clone = ctx.clone();
@@ -276,7 +317,7 @@ impl HttpQuery {
             request.pagination.max_rows_per_page,
             block_buffer,
             request.string_fields,
-            format_settings,
+            format_settings.clone(),
         )));
         let query = HttpQuery {
             id,
@@ -284,16 +325,13 @@ impl HttpQuery {
             request,
             state,
             data,
+            format_settings,
             config,
             expire_at: Arc::new(TokioMutex::new(None)),
         };
         Ok(Arc::new(query))
     }
 
-    pub fn is_async(&self) -> bool {
-        self.request.pagination.wait_time_secs == 0
-    }
-
     pub async fn get_response_page(&self, page_no: usize) -> Result<HttpQueryResponseInternal> {
         let data = Some(self.get_page(page_no).await?);
         let state = self.get_state().await;
@@ -323,6 +361,15 @@ impl HttpQuery {
         }
     }
 
+    /// The schema of the result, `None` if the query is not planned yet or fails to plan.
+    pub async fn get_result_schema(&self) -> Option<DataSchemaRef> {
+        self.state.read().await.result_schema.clone()
+    }
+
+    pub fn get_format_settings(&self) -> &FormatSettings {
+        &self.format_settings
+    }
+
     async fn get_state(&self) -> ResponseState {
         let state = self.state.read().await;
         let (exe_state, err) = state.state.extract();
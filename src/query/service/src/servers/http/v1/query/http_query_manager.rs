@@ -21,6 +21,7 @@ use common_base::base::tokio::sync::RwLock;
 use common_base::base::tokio::time::sleep;
 use common_base::base::Singleton;
 use common_exception::Result;
+use common_meta_types::UserInfo;
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use tracing::warn;
@@ -91,31 +92,24 @@ impl HttpQueryManager {
         let self_clone = self.clone();
         let query_id_clone = query_id.to_string();
         let query_clone = query.clone();
-        if query.is_async() {
-            tokio::spawn(async move {
-                while let Some(t) = query_clone.check_expire().await {
-                    sleep(t).await;
-                }
-                if self_clone.remove_query(&query_id_clone).await.is_none() {
-                    warn!("http query {} timeout, but fail to remove", &query_id_clone);
-                } else {
-                    warn!("http query {} timeout", &query_id_clone);
-                    query.detach().await;
-                }
-            });
-        };
+        // the pages of the results are kept until timeout if the client never fetch them all.
+        tokio::spawn(async move {
+            while let Some(t) = query_clone.check_expire().await {
+                sleep(t).await;
+            }
+            if self_clone.remove_query(&query_id_clone).await.is_none() {
+                warn!("http query {} timeout, but fail to remove", &query_id_clone);
+            } else {
+                warn!("http query {} timeout", &query_id_clone);
+                query.detach().await;
+            }
+        });
     }
 
     // not remove it until timeout or cancelled by user, even if query execution is aborted
     pub(crate) async fn remove_query(self: &Arc<Self>, query_id: &str) -> Option<Arc<HttpQuery>> {
         let mut queries = self.queries.write().await;
-        let q = queries.remove(query_id);
-        if let Some(q) = queries.remove(query_id) {
-            if q.is_async() {
-                q.update_expire_time(false).await;
-            }
-        }
-        q
+        queries.remove(query_id)
     }
 
     pub(crate) async fn get_session(self: &Arc<Self>, session_id: &str) -> Option<Arc<Session>> {
@@ -123,6 +117,20 @@ impl HttpQueryManager {
         sessions.get(session_id)
     }
 
+    /// The session kept in the server, only for the user who created it.
+    pub(crate) async fn get_user_session(
+        self: &Arc<Self>,
+        session_id: &str,
+        user: &UserInfo,
+    ) -> Result<Option<Arc<Session>>> {
+        match self.get_session(session_id).await {
+            Some(session) if session.get_current_user()?.identity() == user.identity() => {
+                Ok(Some(session))
+            }
+            _ => Ok(None),
+        }
+    }
+
     pub(crate) async fn add_session(self: &Arc<Self>, session: Arc<Session>, timeout: Duration) {
         let mut sessions = self.sessions.lock();
         sessions.insert(session.get_id(), session, Some(timeout));
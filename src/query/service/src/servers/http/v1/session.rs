@@ -0,0 +1,134 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use poem::error::BadRequest;
+use poem::error::Error as PoemError;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::get;
+use poem::http::StatusCode;
+use poem::post;
+use poem::web::Json;
+use poem::web::Path;
+use poem::Route;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::info;
+
+use super::HttpQueryContext;
+use super::HttpQueryManager;
+use super::HttpSessionConf;
+use crate::sessions::Session;
+use crate::sessions::SessionType;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SessionResponse {
+    pub session_id: String,
+    pub session: HttpSessionConf,
+}
+
+impl SessionResponse {
+    fn from_session(session: &Arc<Session>) -> SessionResponse {
+        SessionResponse {
+            session_id: session.get_id(),
+            session: HttpSessionConf::from_session(session),
+        }
+    }
+}
+
+fn session_id_not_found(session_id: String) -> PoemError {
+    PoemError::from_string(
+        format!("session id not found {}", session_id),
+        StatusCode::NOT_FOUND,
+    )
+}
+
+async fn get_user_session(
+    ctx: &HttpQueryContext,
+    session_id: &str,
+) -> PoemResult<Option<Arc<Session>>> {
+    let user = ctx
+        .get_session(SessionType::HTTPQuery)
+        .get_current_user()
+        .map_err(InternalServerError)?;
+    HttpQueryManager::instance()
+        .get_user_session(session_id, &user)
+        .await
+        .map_err(InternalServerError)
+}
+
+// The session is kept in the server until it is idle for `keep_server_session_secs`,
+// then used by the queries with the `session_id`.
+#[poem::handler]
+async fn session_create_handler(
+    ctx: &HttpQueryContext,
+    Json(conf): Json<HttpSessionConf>,
+) -> PoemResult<Json<SessionResponse>> {
+    let secs = match conf.keep_server_session_secs {
+        Some(secs) if secs > 0 => secs,
+        _ => {
+            return Err(BadRequest(ErrorCode::BadArguments(
+                "keep_server_session_secs should be positive",
+            )));
+        }
+    };
+
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    conf.apply_to_session(&session).map_err(BadRequest)?;
+    HttpQueryManager::instance()
+        .add_session(session.clone(), Duration::from_secs(secs))
+        .await;
+    info!("create http session {} for {}s", session.get_id(), secs);
+
+    let mut response = SessionResponse::from_session(&session);
+    response.session.keep_server_session_secs = Some(secs);
+    Ok(Json(response))
+}
+
+#[poem::handler]
+async fn session_state_handler(
+    ctx: &HttpQueryContext,
+    Path(session_id): Path<String>,
+) -> PoemResult<Json<SessionResponse>> {
+    match get_user_session(ctx, &session_id).await? {
+        Some(session) => Ok(Json(SessionResponse::from_session(&session))),
+        None => Err(session_id_not_found(session_id)),
+    }
+}
+
+// The running query of the session is killed too.
+#[poem::handler]
+async fn session_delete_handler(
+    ctx: &HttpQueryContext,
+    Path(session_id): Path<String>,
+) -> PoemResult<StatusCode> {
+    match get_user_session(ctx, &session_id).await? {
+        Some(session) => {
+            session.force_kill_session();
+            Ok(StatusCode::OK)
+        }
+        None => Err(session_id_not_found(session_id)),
+    }
+}
+
+pub fn session_route() -> Route {
+    Route::new().at("/", post(session_create_handler)).at(
+        "/:id",
+        get(session_state_handler).delete(session_delete_handler),
+    )
+}
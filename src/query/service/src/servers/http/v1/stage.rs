@@ -25,6 +25,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::HttpQueryContext;
+use crate::connections::resolve_stage_connection;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 use crate::storages::stage::StageTable;
@@ -63,6 +64,9 @@ pub async fn upload_to_stage(
         .get_stage(context.get_tenant().as_str(), stage_name)
         .await
         .map_err(InternalServerError)?;
+    let stage = resolve_stage_connection(&context.get_config(), &context.get_tenant(), stage)
+        .await
+        .map_err(InternalServerError)?;
 
     let rename_me_qry_ctx: Arc<dyn TableContext> = context.clone();
     let op = StageTable::get_op(&rename_me_qry_ctx, &stage).map_err(InternalServerError)?;
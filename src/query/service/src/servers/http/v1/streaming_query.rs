@@ -0,0 +1,353 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_stream::stream;
+use common_ast::parser::split_sql;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_formats::output_format::OutputFormatType;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+use poem::error::BadRequest;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::http::header;
+use poem::http::HeaderMap;
+use poem::web::Json;
+use poem::web::Query;
+use poem::Body;
+use poem::Response;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::info;
+
+use super::http_query_handlers::QueryError;
+use super::query::HttpQueryRequest;
+use super::query::Progresses;
+use super::HttpQueryContext;
+use super::QueryStats;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::v1::json_block::block_to_json_value;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+/// The first line of the response, before the rows.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StreamingQueryHeader {
+    pub id: String,
+    pub schema: DataSchemaRef,
+}
+
+/// The last line of the response, after the rows.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StreamingQueryFooter {
+    pub stats: QueryStats,
+    pub error: Option<QueryError>,
+}
+
+fn json_line<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+#[derive(Deserialize, Debug)]
+struct StreamingQueryParams {
+    /// The output format of the results, e.g. `parquet`, instead of the lines of json.
+    format: Option<String>,
+}
+
+/// The output format of the results, by the `format` parameter, or else by the first media type
+/// of the `Accept` header known, `None` for the lines of json.
+fn result_format(
+    params: &StreamingQueryParams,
+    headers: &HeaderMap,
+) -> Result<Option<OutputFormatType>> {
+    if let Some(name) = &params.format {
+        return OutputFormatType::from_str(name).map(Some);
+    }
+
+    let accept = match headers.get(header::ACCEPT).map(|v| v.to_str()) {
+        Some(Ok(accept)) => accept,
+        _ => return Ok(None),
+    };
+    for media_type in accept.split(',') {
+        let media_type = media_type.split(';').next().unwrap_or_default();
+        match media_type.trim().to_ascii_lowercase().as_str() {
+            "application/vnd.apache.arrow.stream" => {
+                return Ok(Some(OutputFormatType::ArrowStream));
+            }
+            "application/vnd.apache.parquet" | "application/x-parquet" => {
+                return Ok(Some(OutputFormatType::Parquet));
+            }
+            "text/csv" => return Ok(Some(OutputFormatType::CSVWithNames)),
+            "application/x-ndjson" | "application/json" | "*/*" => return Ok(None),
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+/// Runs the query and streams the results in newline-delimited json as the blocks are
+/// produced: the header of the query, one json array per row, then the footer with the
+/// stats and the error if failed. The rows are not buffered in the server, the pipeline
+/// is paused while the client is not reading.
+///
+/// The results are encoded in the output format instead if one is chosen by the `format`
+/// parameter or the `Accept` header, e.g. the arrow IPC stream, parquet or csv, see
+/// [`result_format`]. The id of the query is in the `X-DATABEND-QUERY-ID` header then, and the
+/// response is broken off if the query fails after the results are started.
+#[poem::handler]
+pub async fn streaming_query(
+    ctx: &HttpQueryContext,
+    headers: &HeaderMap,
+    Query(params): Query<StreamingQueryParams>,
+    Json(req): Json<HttpQueryRequest>,
+) -> PoemResult<Response> {
+    info!("receive http streaming query: {:?}", req);
+    if req.session_id.is_some() {
+        return Err(BadRequest(ErrorCode::BadArguments(
+            "session_id is not supported by the streaming query, use session instead",
+        )));
+    }
+    let format = result_format(&params, headers).map_err(BadRequest)?;
+
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    if let Some(session_conf) = &req.session {
+        session_conf
+            .apply_to_session(&session)
+            .map_err(BadRequest)?;
+    }
+
+    // The statements of a multi-statement query are run one by one in the session.
+    let string_fields = req.string_fields;
+    if let Ok(statements) = split_sql(&req.sql) {
+        if statements.len() > 1 {
+            if format.is_some() {
+                return Err(BadRequest(ErrorCode::BadArguments(
+                    "the output format is not supported by the multi-statement query",
+                )));
+            }
+            let continue_on_error = session
+                .get_settings()
+                .get_continue_on_error()
+                .map_err(InternalServerError)?;
+            let statements = statements.into_iter().map(|s| s.to_string()).collect();
+            let stream =
+                multi_statement_stream(session, statements, string_fields, continue_on_error);
+            return Ok(Response::builder()
+                .content_type("application/x-ndjson")
+                .body(Body::from_bytes_stream::<_, _, ErrorCode>(stream)));
+        }
+    }
+
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+    let start_time = Instant::now();
+
+    let mut planner = Planner::new(context.clone());
+    let (plan, _, _) = planner.plan_sql(&req.sql).await.map_err(BadRequest)?;
+    context.attach_query_str(plan.to_string(), &req.sql);
+    let interpreter = InterpreterFactory::get(context.clone(), &plan)
+        .await
+        .map_err(BadRequest)?;
+
+    let mut data_stream = interpreter
+        .execute(context.clone())
+        .await
+        .map_err(InternalServerError)?;
+    if let Some(format) = format {
+        return formatted_response(context, plan.schema(), data_stream, format).await;
+    }
+
+    let format_settings = context.get_format_settings().map_err(InternalServerError)?;
+    let header = json_line(&StreamingQueryHeader {
+        id: context.get_id(),
+        schema: plan.schema(),
+    })
+    .map_err(InternalServerError)?;
+
+    let stream = stream! {
+        yield Ok(header);
+        let mut error = None;
+        while let Some(block) = data_stream.next().await {
+            let lines = block.and_then(|block| {
+                let rows = block_to_json_value(&block, &format_settings, string_fields)?;
+                let mut lines = vec![];
+                for row in rows {
+                    lines.extend(json_line(&row)?);
+                }
+                Ok(lines)
+            });
+            match lines {
+                Ok(lines) => yield Ok(lines),
+                Err(err) => {
+                    error = Some(QueryError::from_error_code(&err));
+                    break;
+                }
+            }
+        }
+
+        // Stop the pipeline if the stream is broken.
+        drop(data_stream);
+        let stats = QueryStats {
+            progresses: Progresses::from_context(&context),
+            running_time_ms: start_time.elapsed().as_secs_f64() * 1000.0,
+        };
+        yield json_line(&StreamingQueryFooter { stats, error });
+    };
+
+    Ok(Response::builder()
+        .content_type("application/x-ndjson")
+        .body(Body::from_bytes_stream::<_, _, ErrorCode>(stream)))
+}
+
+/// The results encoded in the output format as the blocks are produced. The errors before the
+/// first block are returned as the status of the response, the format has no place for the
+/// errors after that, the body stream fails and the response is broken off.
+async fn formatted_response(
+    context: Arc<QueryContext>,
+    schema: DataSchemaRef,
+    mut data_stream: SendableDataBlockStream,
+    format: OutputFormatType,
+) -> PoemResult<Response> {
+    let format_settings = context.get_format_settings().map_err(InternalServerError)?;
+    let mut output_format = format.create_format(schema, format_settings);
+    let prefix = output_format
+        .serialize_prefix()
+        .map_err(InternalServerError)?;
+    let first_block = match data_stream.next().await {
+        Some(Err(err)) => return Err(InternalServerError(err)),
+        first_block => first_block,
+    };
+
+    let query_id = context.get_id();
+    let stream = stream! {
+        yield Ok(prefix);
+        let mut blocks = futures::stream::iter(first_block).chain(data_stream);
+        let mut failed = false;
+        while let Some(block) = blocks.next().await {
+            match block.and_then(|block| output_format.serialize_block(&block)) {
+                Ok(bytes) => yield Ok(bytes),
+                Err(err) => {
+                    failed = true;
+                    yield Err(err);
+                    break;
+                }
+            }
+        }
+        if !failed {
+            yield output_format.finalize();
+        }
+        // Hold the context until the results are all sent.
+        drop(context);
+    };
+
+    Ok(Response::builder()
+        .content_type(format.get_content_type())
+        .header("X-DATABEND-QUERY-ID", query_id)
+        .body(Body::from_bytes_stream::<_, _, ErrorCode>(stream)))
+}
+
+/// The lines of the statements of a multi-statement query, the header, the rows and the footer
+/// of each statement follow the ones of the previous statement. A statement failed to start
+/// has the header without the schema and the footer with the error. The statements after a
+/// failed one are not run, unless `continue_on_error` is set.
+fn multi_statement_stream(
+    session: Arc<Session>,
+    statements: Vec<String>,
+    string_fields: bool,
+    continue_on_error: bool,
+) -> impl Stream<Item = Result<Vec<u8>>> {
+    stream! {
+        for sql in statements {
+            let context = match session.create_query_context().await {
+                Ok(context) => context,
+                Err(err) => {
+                    yield Err(err);
+                    break;
+                }
+            };
+            let start_time = Instant::now();
+
+            let started = async {
+                let mut planner = Planner::new(context.clone());
+                let (plan, _, _) = planner.plan_sql(&sql).await?;
+                context.attach_query_str(plan.to_string(), &sql);
+                let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+                let data_stream = interpreter.execute(context.clone()).await?;
+                let format_settings = context.get_format_settings()?;
+                Ok::<_, ErrorCode>((plan.schema(), data_stream, format_settings))
+            }
+            .await;
+
+            let mut error = None;
+            match started {
+                Ok((schema, mut data_stream, format_settings)) => {
+                    yield json_line(&StreamingQueryHeader {
+                        id: context.get_id(),
+                        schema,
+                    });
+                    while let Some(block) = data_stream.next().await {
+                        let lines = block.and_then(|block| {
+                            let rows =
+                                block_to_json_value(&block, &format_settings, string_fields)?;
+                            let mut lines = vec![];
+                            for row in rows {
+                                lines.extend(json_line(&row)?);
+                            }
+                            Ok(lines)
+                        });
+                        match lines {
+                            Ok(lines) => yield Ok(lines),
+                            Err(err) => {
+                                error = Some(QueryError::from_error_code(&err));
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(err) => {
+                    yield json_line(&StreamingQueryHeader {
+                        id: context.get_id(),
+                        schema: Arc::new(DataSchema::empty()),
+                    });
+                    error = Some(QueryError::from_error_code(&err));
+                }
+            }
+
+            let failed = error.is_some();
+            let stats = QueryStats {
+                progresses: Progresses::from_context(&context),
+                running_time_ms: start_time.elapsed().as_secs_f64() * 1000.0,
+            };
+            yield json_line(&StreamingQueryFooter { stats, error });
+            if failed && !continue_on_error {
+                break;
+            }
+        }
+    }
+}
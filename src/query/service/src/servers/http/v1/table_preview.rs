@@ -0,0 +1,121 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use futures::TryStreamExt;
+use poem::error::BadRequest;
+use poem::error::Error as PoemError;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::get;
+use poem::http::StatusCode;
+use poem::web::Json;
+use poem::web::Path;
+use poem::web::Query;
+use poem::Route;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use tracing::info;
+
+use super::HttpQueryContext;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::v1::json_block::block_to_json_value;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+const DEFAULT_PREVIEW_ROWS: u64 = 100;
+const MAX_PREVIEW_ROWS: u64 = 1000;
+
+/// The rows of the table previewed, the same as the rows in the `data` of `/v1/query`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TablePreviewResponse {
+    pub schema: DataSchemaRef,
+    pub data: Vec<Vec<JsonValue>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TablePreviewParams {
+    /// The number of the rows, at most `MAX_PREVIEW_ROWS`.
+    limit: Option<u64>,
+}
+
+fn preview_error(err: ErrorCode) -> PoemError {
+    if err.code() == ErrorCode::UnknownDatabaseCode() || err.code() == ErrorCode::UnknownTableCode()
+    {
+        PoemError::from_string(err.message(), StatusCode::NOT_FOUND)
+    } else if err.code() == ErrorCode::PermissionDeniedCode() {
+        PoemError::from_string(err.message(), StatusCode::FORBIDDEN)
+    } else {
+        BadRequest(err)
+    }
+}
+
+// The first rows of the table, read by the query of the user, so the privileges, the row access
+// policies and the masking policies of the table apply as they do to the queries.
+#[poem::handler]
+async fn table_preview_handler(
+    ctx: &HttpQueryContext,
+    Path((database, table)): Path<(String, String)>,
+    Query(params): Query<TablePreviewParams>,
+) -> PoemResult<Json<TablePreviewResponse>> {
+    if database.contains('`') || table.contains('`') {
+        return Err(BadRequest(ErrorCode::BadArguments(
+            "the name of the database or the table should not contain backquotes",
+        )));
+    }
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PREVIEW_ROWS)
+        .min(MAX_PREVIEW_ROWS);
+    let sql = format!("SELECT * FROM `{database}`.`{table}` LIMIT {limit}");
+    info!("receive http table preview: {}", sql);
+
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+    let mut planner = Planner::new(context.clone());
+    let (plan, _, _) = planner.plan_sql(&sql).await.map_err(preview_error)?;
+    context.attach_query_str(plan.to_string(), &sql);
+    let interpreter = InterpreterFactory::get(context.clone(), &plan)
+        .await
+        .map_err(preview_error)?;
+    let blocks = interpreter
+        .execute(context.clone())
+        .await
+        .map_err(preview_error)?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(InternalServerError)?;
+
+    let format_settings = context.get_format_settings().map_err(InternalServerError)?;
+    let mut data = vec![];
+    for block in &blocks {
+        data.extend(
+            block_to_json_value(block, &format_settings, false).map_err(InternalServerError)?,
+        );
+    }
+    Ok(Json(TablePreviewResponse {
+        schema: plan.schema(),
+        data,
+    }))
+}
+
+pub fn table_route() -> Route {
+    Route::new().at("/:database/:table/preview", get(table_preview_handler))
+}
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mysql_compression;
+mod mysql_error;
 mod mysql_federated;
 mod mysql_handler;
 mod mysql_interactive_worker;
+mod mysql_load_data;
 mod mysql_metrics;
+mod mysql_params;
 mod mysql_session;
 #[allow(clippy::unused_io_amount)]
 mod reject_connection;
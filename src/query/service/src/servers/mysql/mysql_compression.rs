@@ -0,0 +1,501 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The compressed protocol of MySQL, negotiated by the `CLIENT_COMPRESS` (zlib) and the
+//! `CLIENT_ZSTD_COMPRESSION_ALGORITHM` (zstd) capabilities.
+//!
+//! The handshake is done by opensrv, which knows nothing about the compression, so the
+//! streams of the connection are wrapped below it: the capabilities are added to the
+//! initial handshake of the server, the algorithm is taken from the handshake response of
+//! the client, and both the streams switch to the compressed packets right after the OK
+//! packet of the authentication, as the MySQL server does.
+
+use std::io::Read;
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::ReadBuf;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use futures::ready;
+use metrics::counter;
+use parking_lot::Mutex;
+use tracing::info;
+
+use crate::servers::mysql::mysql_metrics::METRIC_MYSQL_COMPRESSION_RAW_BYTES;
+use crate::servers::mysql::mysql_metrics::METRIC_MYSQL_COMPRESSION_WIRE_BYTES;
+
+const CLIENT_COMPRESS: u32 = 0x0000_0020;
+const CLIENT_SSL: u32 = 0x0000_0800;
+const CLIENT_ZSTD_COMPRESSION_ALGORITHM: u32 = 0x0400_0000;
+
+// The size of the SSLRequest packet, which is a truncated handshake response.
+const SSL_REQUEST_LEN: usize = 32;
+// The packets shorter than it are not worth compressing, the same as MySQL.
+const MIN_COMPRESS_LENGTH: usize = 50;
+const MAX_PAYLOAD_LEN: usize = 0xFF_FFFF;
+const PACKET_HEADER_LEN: usize = 4;
+const COMPRESSED_HEADER_LEN: usize = 7;
+const DEFAULT_ZSTD_LEVEL: i32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Zlib,
+    Zstd(i32),
+}
+
+impl Compression {
+    fn name(&self) -> &'static str {
+        match self {
+            Compression::Zlib => "zlib",
+            Compression::Zstd(_) => "zstd",
+        }
+    }
+
+    fn compress(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Compression::Zlib => {
+                let mut encoder = ZlibEncoder::new(vec![], flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            Compression::Zstd(level) => zstd::bulk::compress(data, *level),
+        }
+    }
+
+    fn decompress(&self, data: &[u8], len: usize) -> std::io::Result<Vec<u8>> {
+        let decompressed = match self {
+            Compression::Zlib => {
+                let mut decompressed = Vec::with_capacity(len);
+                ZlibDecoder::new(data).read_to_end(&mut decompressed)?;
+                decompressed
+            }
+            Compression::Zstd(_) => zstd::bulk::decompress(data, len)?,
+        };
+        match decompressed.len() == len {
+            true => Ok(decompressed),
+            false => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "compressed packet of {} bytes decompressed to {} bytes",
+                    len,
+                    decompressed.len()
+                ),
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Phase {
+    // Waiting for the handshake response of the client.
+    Handshake,
+    // Authenticating the client, with the compression it asks for.
+    Authenticating(Compression),
+    Compressed(Compression),
+    Plain,
+}
+
+/// The negotiation of the compression, shared by the two streams of a connection.
+pub struct MySQLCompression {
+    phase: Mutex<Phase>,
+    // The next sequence id of the compressed packets, which follows the last one of the
+    // client in each command.
+    sequence_id: AtomicU8,
+}
+
+impl MySQLCompression {
+    /// The compression capabilities are offered to the client only if `enabled`.
+    pub fn create(enabled: bool) -> Arc<MySQLCompression> {
+        Arc::new(MySQLCompression {
+            phase: Mutex::new(match enabled {
+                true => Phase::Handshake,
+                false => Phase::Plain,
+            }),
+            sequence_id: AtomicU8::new(0),
+        })
+    }
+
+    fn phase(&self) -> Phase {
+        *self.phase.lock()
+    }
+
+    fn set_phase(&self, phase: Phase) {
+        *self.phase.lock() = phase;
+    }
+
+    // Parses the handshake response (or the SSLRequest) of the client.
+    fn on_handshake_response(&self, payload: &[u8]) {
+        if payload.len() < 4 {
+            return self.set_phase(Phase::Plain);
+        }
+        let capabilities = u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        let ssl_request = capabilities & CLIENT_SSL != 0 && payload.len() == SSL_REQUEST_LEN;
+
+        let compression = if ssl_request {
+            None
+        } else if capabilities & CLIENT_ZSTD_COMPRESSION_ALGORITHM != 0 {
+            // The level is the last byte of the response.
+            let level = match payload.last() {
+                Some(level) if *level != 0 => *level as i32,
+                _ => DEFAULT_ZSTD_LEVEL,
+            };
+            Some(Compression::Zstd(level))
+        } else if capabilities & CLIENT_COMPRESS != 0 {
+            Some(Compression::Zlib)
+        } else {
+            None
+        };
+
+        self.set_phase(match compression {
+            Some(compression) => Phase::Authenticating(compression),
+            None => Phase::Plain,
+        });
+    }
+}
+
+/// The stream from the client, which reads the compressed packets once negotiated.
+pub struct CompressedReader<R> {
+    inner: R,
+    compression: Arc<MySQLCompression>,
+    mode: Option<Compression>,
+    // The bytes of the first packet of the client, kept until it's complete.
+    handshake: Vec<u8>,
+    frame: Vec<u8>,
+    decompressed: Vec<u8>,
+    decompressed_pos: usize,
+}
+
+impl<R: AsyncRead + Unpin> CompressedReader<R> {
+    pub fn create(inner: R, compression: Arc<MySQLCompression>) -> Self {
+        CompressedReader {
+            inner,
+            compression,
+            mode: None,
+            handshake: vec![],
+            frame: vec![],
+            decompressed: vec![],
+            decompressed_pos: 0,
+        }
+    }
+
+    fn scan_handshake(&mut self, data: &[u8]) {
+        if self.compression.phase() != Phase::Handshake {
+            return;
+        }
+        self.handshake.extend_from_slice(data);
+        if self.handshake.len() < PACKET_HEADER_LEN {
+            return;
+        }
+        let len = packet_len(&self.handshake);
+        if self.handshake.len() >= PACKET_HEADER_LEN + len {
+            let payload = &self.handshake[PACKET_HEADER_LEN..PACKET_HEADER_LEN + len];
+            self.compression.on_handshake_response(payload);
+            self.handshake = vec![];
+        }
+    }
+
+    // Reads the next compressed packet into `decompressed`, false if the stream ends.
+    fn poll_frame(
+        &mut self,
+        cx: &mut Context<'_>,
+        compression: Compression,
+    ) -> Poll<std::io::Result<bool>> {
+        loop {
+            let needed = match self.frame.len() < COMPRESSED_HEADER_LEN {
+                true => COMPRESSED_HEADER_LEN,
+                false => COMPRESSED_HEADER_LEN + packet_len(&self.frame),
+            };
+            if self.frame.len() == needed {
+                break;
+            }
+
+            let mut buf = vec![0; needed - self.frame.len()];
+            let mut read_buf = ReadBuf::new(&mut buf);
+            ready!(Pin::new(&mut self.inner).poll_read(cx, &mut read_buf))?;
+            let filled = read_buf.filled();
+            if filled.is_empty() {
+                return match self.frame.is_empty() {
+                    true => Poll::Ready(Ok(false)),
+                    false => Poll::Ready(Err(std::io::ErrorKind::UnexpectedEof.into())),
+                };
+            }
+            self.frame.extend_from_slice(filled);
+        }
+
+        let frame = std::mem::take(&mut self.frame);
+        let sequence_id = frame[3];
+        let uncompressed_len = packet_len(&frame[4..]);
+        let payload = &frame[COMPRESSED_HEADER_LEN..];
+        self.compression
+            .sequence_id
+            .store(sequence_id.wrapping_add(1), Ordering::Relaxed);
+
+        self.decompressed = match uncompressed_len {
+            0 => payload.to_vec(),
+            len => compression.decompress(payload, len)?,
+        };
+        self.decompressed_pos = 0;
+        counter!(METRIC_MYSQL_COMPRESSION_WIRE_BYTES, frame.len() as u64, "direction" => "received", "algorithm" => compression.name());
+        counter!(METRIC_MYSQL_COMPRESSION_RAW_BYTES, self.decompressed.len() as u64, "direction" => "received", "algorithm" => compression.name());
+        Poll::Ready(Ok(true))
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for CompressedReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = &mut *self;
+        if this.mode.is_none() {
+            if let Phase::Compressed(compression) = this.compression.phase() {
+                this.mode = Some(compression);
+            }
+        }
+
+        let compression = match this.mode {
+            Some(compression) => compression,
+            None => {
+                let before = buf.filled().len();
+                ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+                this.scan_handshake(&buf.filled()[before..]);
+                return Poll::Ready(Ok(()));
+            }
+        };
+
+        while this.decompressed_pos == this.decompressed.len() {
+            if !ready!(this.poll_frame(cx, compression))? {
+                return Poll::Ready(Ok(()));
+            }
+        }
+        let len = std::cmp::min(
+            buf.remaining(),
+            this.decompressed.len() - this.decompressed_pos,
+        );
+        buf.put_slice(&this.decompressed[this.decompressed_pos..this.decompressed_pos + len]);
+        this.decompressed_pos += len;
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// The stream to the client, which writes the compressed packets once negotiated.
+pub struct CompressedWriter<W> {
+    inner: W,
+    compression: Arc<MySQLCompression>,
+    mode: Option<Compression>,
+    // Scanning the plain packets of the server until the compression is settled.
+    scanning: bool,
+    handshake_sent: bool,
+    scan: Vec<u8>,
+    out: Vec<u8>,
+    out_pos: usize,
+    raw_bytes: u64,
+    wire_bytes: u64,
+}
+
+impl<W: AsyncWrite + Unpin> CompressedWriter<W> {
+    pub fn create(inner: W, compression: Arc<MySQLCompression>) -> Self {
+        let scanning = compression.phase() != Phase::Plain;
+        CompressedWriter {
+            inner,
+            compression,
+            mode: None,
+            scanning,
+            handshake_sent: false,
+            scan: vec![],
+            out: vec![],
+            out_pos: 0,
+            raw_bytes: 0,
+            wire_bytes: 0,
+        }
+    }
+
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        while self.out_pos < self.out.len() {
+            let n = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.out[self.out_pos..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(std::io::ErrorKind::WriteZero.into()));
+            }
+            self.out_pos += n;
+        }
+        self.out.clear();
+        self.out_pos = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    // Passes the complete packets scanned through, until the compression is settled.
+    fn scan_packets(&mut self) -> std::io::Result<()> {
+        while self.scanning && self.scan.len() >= PACKET_HEADER_LEN {
+            let len = PACKET_HEADER_LEN + packet_len(&self.scan);
+            if self.scan.len() < len {
+                break;
+            }
+            let mut packet = self.scan.drain(..len).collect::<Vec<_>>();
+
+            if !self.handshake_sent {
+                self.handshake_sent = true;
+                add_compression_capabilities(&mut packet[PACKET_HEADER_LEN..]);
+                self.out.extend_from_slice(&packet);
+                continue;
+            }
+
+            self.out.extend_from_slice(&packet);
+            match (self.compression.phase(), packet.get(PACKET_HEADER_LEN)) {
+                (Phase::Plain, _) => self.scanning = false,
+                // The authentication succeeds.
+                (Phase::Authenticating(compression), Some(0x00)) => {
+                    info!("MySQL connection compressed by {}", compression.name());
+                    self.compression.set_phase(Phase::Compressed(compression));
+                    self.mode = Some(compression);
+                    self.scanning = false;
+                }
+                (Phase::Authenticating(_), Some(0xFF)) => {
+                    self.compression.set_phase(Phase::Plain);
+                    self.scanning = false;
+                }
+                _ => {}
+            }
+        }
+
+        if !self.scanning {
+            let rest = std::mem::take(&mut self.scan);
+            match self.mode {
+                Some(compression) => self.write_compressed(compression, &rest)?,
+                None => self.out.extend_from_slice(&rest),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_compressed(&mut self, compression: Compression, data: &[u8]) -> std::io::Result<()> {
+        for chunk in data.chunks(MAX_PAYLOAD_LEN) {
+            let sequence_id = self.compression.sequence_id.fetch_add(1, Ordering::Relaxed);
+            let compressed = match chunk.len() < MIN_COMPRESS_LENGTH {
+                true => None,
+                false => Some(compression.compress(chunk)?).filter(|c| c.len() < chunk.len()),
+            };
+            let (payload, uncompressed_len) = match &compressed {
+                Some(compressed) => (compressed.as_slice(), chunk.len()),
+                None => (chunk, 0),
+            };
+
+            self.out
+                .extend_from_slice(&(payload.len() as u32).to_le_bytes()[..3]);
+            self.out.push(sequence_id);
+            self.out
+                .extend_from_slice(&(uncompressed_len as u32).to_le_bytes()[..3]);
+            self.out.extend_from_slice(payload);
+
+            let wire_bytes = (COMPRESSED_HEADER_LEN + payload.len()) as u64;
+            self.raw_bytes += chunk.len() as u64;
+            self.wire_bytes += wire_bytes;
+            counter!(METRIC_MYSQL_COMPRESSION_WIRE_BYTES, wire_bytes, "direction" => "sent", "algorithm" => compression.name());
+            counter!(METRIC_MYSQL_COMPRESSION_RAW_BYTES, chunk.len() as u64, "direction" => "sent", "algorithm" => compression.name());
+        }
+        Ok(())
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for CompressedWriter<W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = &mut *self;
+        ready!(this.poll_drain(cx))?;
+
+        if this.scanning {
+            this.scan.extend_from_slice(buf);
+            this.scan_packets()?;
+        } else {
+            match this.mode {
+                Some(compression) => this.write_compressed(compression, buf)?,
+                None => return Pin::new(&mut this.inner).poll_write(cx, buf),
+            }
+        }
+
+        // The bytes are accepted, they are written to the inner stream as possible.
+        if let Poll::Ready(Err(cause)) = this.poll_drain(cx) {
+            return Poll::Ready(Err(cause));
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.poll_drain(cx))?;
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.poll_drain(cx))?;
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+impl<W> Drop for CompressedWriter<W> {
+    fn drop(&mut self) {
+        if let Some(compression) = self.mode {
+            info!(
+                "MySQL connection compressed by {} sent {} bytes as {} bytes",
+                compression.name(),
+                self.raw_bytes,
+                self.wire_bytes
+            );
+        }
+    }
+}
+
+fn packet_len(header: &[u8]) -> usize {
+    u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize
+}
+
+// Adds the compression capabilities to the initial handshake packet of protocol version 10:
+// the version, the server version ending with 0, the connection id, the first 8 bytes of the
+// auth plugin data and a filler, followed by the lower 2 bytes of the capabilities, the
+// character set, the status flags and the upper 2 bytes of the capabilities.
+fn add_compression_capabilities(payload: &mut [u8]) {
+    if payload.first() != Some(&10) {
+        return;
+    }
+    let version_end = match payload[1..].iter().position(|b| *b == 0) {
+        Some(pos) => 1 + pos + 1,
+        None => return,
+    };
+    let lower = version_end + 4 + 8 + 1;
+    let upper = lower + 2 + 1 + 2;
+    if payload.len() < upper + 2 {
+        return;
+    }
+
+    let mut capabilities = u32::from_le_bytes([
+        payload[lower],
+        payload[lower + 1],
+        payload[upper],
+        payload[upper + 1],
+    ]);
+    capabilities |= CLIENT_COMPRESS | CLIENT_ZSTD_COMPRESSION_ALGORITHM;
+    let bytes = capabilities.to_le_bytes();
+    payload[lower..lower + 2].copy_from_slice(&bytes[..2]);
+    payload[upper..upper + 2].copy_from_slice(&bytes[2..]);
+}
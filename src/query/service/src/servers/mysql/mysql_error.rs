@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCategory;
+use common_exception::ErrorCode;
+use opensrv_mysql::ErrorKind;
+
+/// The MySQL error of an error code, of which the SQLSTATE is sent to the clients. The codes
+/// with a MySQL counterpart are mapped to it, the others are mapped by their categories.
+pub fn error_kind(error: &ErrorCode) -> ErrorKind {
+    let overrides = [
+        (
+            ErrorCode::unknown_database_code(),
+            ErrorKind::ER_BAD_DB_ERROR,
+        ),
+        (
+            ErrorCode::unknown_column_code(),
+            ErrorKind::ER_BAD_FIELD_ERROR,
+        ),
+        (
+            ErrorCode::unknown_function_code(),
+            ErrorKind::ER_SP_DOES_NOT_EXIST,
+        ),
+        (
+            ErrorCode::database_already_exists_code(),
+            ErrorKind::ER_DB_CREATE_EXISTS,
+        ),
+        (
+            ErrorCode::authenticate_failure_code(),
+            ErrorKind::ER_ACCESS_DENIED_ERROR,
+        ),
+        (ErrorCode::overflow_code(), ErrorKind::ER_DATA_OUT_OF_RANGE),
+        (
+            ErrorCode::aborted_session_code(),
+            ErrorKind::ER_ABORTING_CONNECTION,
+        ),
+    ];
+    if let Some((_, kind)) = overrides.iter().find(|(code, _)| *code == error.code()) {
+        return *kind;
+    }
+
+    match error.category() {
+        ErrorCategory::Syntax => ErrorKind::ER_PARSE_ERROR,
+        ErrorCategory::Semantic => ErrorKind::ER_SYNTAX_ERROR,
+        ErrorCategory::NotFound => ErrorKind::ER_NO_SUCH_TABLE,
+        ErrorCategory::AlreadyExists => ErrorKind::ER_TABLE_EXISTS_ERROR,
+        ErrorCategory::Data => ErrorKind::ER_TRUNCATED_WRONG_VALUE,
+        ErrorCategory::Permission => ErrorKind::ER_SPECIFIC_ACCESS_DENIED_ERROR,
+        ErrorCategory::Unsupported => ErrorKind::ER_NOT_SUPPORTED_YET,
+        ErrorCategory::QuotaExceeded => ErrorKind::ER_OUT_OF_RESOURCES,
+        ErrorCategory::Overloaded => ErrorKind::ER_CON_COUNT_ERROR,
+        ErrorCategory::Conflict => ErrorKind::ER_LOCK_DEADLOCK,
+        ErrorCategory::Unavailable => ErrorKind::ER_NET_READ_ERROR,
+        ErrorCategory::Aborted => ErrorKind::ER_QUERY_INTERRUPTED,
+        ErrorCategory::Internal | ErrorCategory::Config | ErrorCategory::Storage => {
+            ErrorKind::ER_UNKNOWN_ERROR
+        }
+    }
+}
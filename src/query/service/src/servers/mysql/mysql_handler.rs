@@ -28,19 +28,24 @@ use futures::future::AbortRegistration;
 use futures::future::Abortable;
 use futures::StreamExt;
 use opensrv_mysql::*;
+use tokio_rustls::rustls::ServerConfig;
 use tokio_stream::wrappers::TcpListenerStream;
 use tracing::error;
 use tracing::info;
 use tracing::warn;
 
+use crate::servers::mysql::mysql_error::error_kind;
 use crate::servers::mysql::mysql_session::MySQLConnection;
 use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
+use crate::servers::tls::build_tls_server_config;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 
 pub struct MySQLHandler {
+    tls: Option<Arc<ServerConfig>>,
+    compression_enabled: bool,
     abort_handle: AbortHandle,
     abort_registration: Option<AbortRegistration>,
     join_handle: Option<JoinHandle<()>>,
@@ -50,6 +55,8 @@ impl MySQLHandler {
     pub fn create() -> Result<Box<dyn Server>> {
         let (abort_handle, registration) = AbortHandle::new_pair();
         Ok(Box::new(MySQLHandler {
+            tls: None,
+            compression_enabled: false,
             abort_handle,
             abort_registration: Some(registration),
             join_handle: None,
@@ -67,19 +74,34 @@ impl MySQLHandler {
     }
 
     fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+        let tls = self.tls.clone();
+        let compression_enabled = self.compression_enabled;
         stream.for_each(move |accept_socket| {
             let executor = rt.clone();
             let sessions = SessionManager::instance();
+            let tls = tls.clone();
             async move {
                 match accept_socket {
                     Err(error) => error!("Broken session connection: {}", error),
-                    Ok(socket) => MySQLHandler::accept_socket(sessions, executor, socket),
+                    Ok(socket) => MySQLHandler::accept_socket(
+                        sessions,
+                        executor,
+                        socket,
+                        tls,
+                        compression_enabled,
+                    ),
                 };
             }
         })
     }
 
-    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        compression_enabled: bool,
+    ) {
         executor.spawn(async move {
             match sessions.create_session(SessionType::MySQL).await {
                 Err(error) => {
@@ -88,7 +110,9 @@ impl MySQLHandler {
                 }
                 Ok(session) => {
                     info!("MySQL connection coming: {:?}", socket.peer_addr());
-                    if let Err(error) = MySQLConnection::run_on_stream(session, socket) {
+                    if let Err(error) =
+                        MySQLConnection::run_on_stream(session, socket, tls, compression_enabled)
+                    {
                         error!("Unexpected error occurred during query: {:?}", error);
                     };
                 }
@@ -97,11 +121,8 @@ impl MySQLHandler {
     }
 
     async fn reject_session(stream: TcpStream, error: ErrorCode) {
-        let (kind, message) = match error.code() {
-            41 => (ErrorKind::ER_TOO_MANY_USER_CONNECTIONS, error.message()),
-            _ => (ErrorKind::ER_INTERNAL_ERROR, error.message()),
-        };
-
+        let kind = error_kind(&error);
+        let message = error.message();
         if let Err(error) = RejectConnection::reject_mysql_connection(stream, kind, message).await {
             error!(
                 "Unexpected error occurred during reject connection: {:?}",
@@ -134,6 +155,18 @@ impl Server for MySQLHandler {
         match self.abort_registration.take() {
             None => Err(ErrorCode::LogicalError("MySQLHandler already running.")),
             Some(registration) => {
+                let conf = SessionManager::instance().get_conf();
+                if conf.tls_mysql_server_enabled() {
+                    info!("databend query tls mysql enabled");
+                    self.tls = Some(build_tls_server_config(
+                        "mysql",
+                        &conf.query.mysql_tls_server_cert,
+                        &conf.query.mysql_tls_server_key,
+                        &conf.query.mysql_tls_server_root_ca_cert,
+                    )?);
+                }
+                self.compression_enabled = conf.query.mysql_handler_compression_enabled;
+
                 let rejected_rt = Arc::new(Runtime::with_worker_threads(
                     1,
                     Some("mysql-handler".to_string()),
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::marker::PhantomData;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_ast::parser::split_sql;
+use common_base::base::tokio;
 use common_base::base::tokio::io::AsyncWrite;
 use common_base::base::TrySpawn;
 use common_datablocks::DataBlock;
@@ -23,6 +25,10 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_io::prelude::*;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
+use common_pipeline_sources::processors::sources::input_formats::InputContext;
+use common_pipeline_sources::processors::sources::input_formats::StreamingReadBatch;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_users::CertifiedInfo;
@@ -30,6 +36,9 @@ use common_users::UserApiProvider;
 use futures_util::StreamExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -40,9 +49,18 @@ use tracing::error;
 use tracing::info;
 use tracing::Instrument;
 
+use crate::auth::Credential;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_error::error_kind;
+use crate::servers::mysql::mysql_load_data::LoadDataStatement;
+use crate::servers::mysql::mysql_load_data::LoadDataStreams;
+use crate::servers::mysql::mysql_params::bind_params;
+use crate::servers::mysql::mysql_params::param_literal;
+use crate::servers::mysql::mysql_params::query_placeholders;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -51,7 +69,9 @@ use crate::servers::mysql::MySQLFederated;
 use crate::servers::mysql::MYSQL_VERSION;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
+use crate::sql::plans::InsertInputSource;
 use crate::sql::plans::Plan;
 use crate::sql::Planner;
 
@@ -76,20 +96,28 @@ fn has_result_set_by_plan(plan: &Plan) -> bool {
     )
 }
 
-struct InteractiveWorkerBase<W: AsyncWrite + Send + Unpin> {
+struct PreparedStatement {
+    query: String,
+    placeholders: Vec<usize>,
+}
+
+struct InteractiveWorkerBase {
     session: Arc<Session>,
-    generic_hold: PhantomData<W>,
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
+    load_data_streams: Option<LoadDataStreams>,
 }
 
-pub struct InteractiveWorker<W: AsyncWrite + Send + Unpin> {
-    base: InteractiveWorkerBase<W>,
+// The writer is decided by the connection, which may be switched to TLS in the handshake.
+pub struct InteractiveWorker {
+    base: InteractiveWorkerBase,
     version: String,
     salt: [u8; 20],
     client_addr: String,
 }
 
 #[async_trait::async_trait]
-impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorker<W> {
+impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorker {
     type Error = ErrorCode;
 
     fn version(&self) -> &str {
@@ -110,8 +138,19 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         "mysql_native_password"
     }
 
-    async fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+    async fn auth_plugin_for_username(&self, user: &[u8]) -> &str {
+        // The password of the LDAP user is verified by the LDAP server, so it is asked for
+        // in clear text.
+        let user_name = String::from_utf8_lossy(user);
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0];
+        let tenant = self.base.session.get_current_tenant();
+        match UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, &user_name, client_ip)
+            .await
+        {
+            Ok(user_info) if user_info.auth_info == AuthInfo::LDAP => "mysql_clear_password",
+            _ => "mysql_native_password",
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -212,18 +251,34 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
             ));
         }
 
-        let mut writer = DFQueryResultWriter::create(writer);
-
         let instant = Instant::now();
-        let query_result = self.base.do_query(query).await;
+        // The result of `LOAD DATA LOCAL INFILE` is written after the file, by the worker.
+        if self.base.load_data_streams.is_some() && LoadDataStatement::is_load_data(query) {
+            let load_result = self.base.do_load_data(query).await;
+            histogram!(
+                super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+                instant.elapsed()
+            );
+            return load_result;
+        }
 
+        let mut writer = DFQueryResultWriter::create(writer);
         let format = self.base.session.get_format_settings()?;
-        let mut write_result = writer.write(query_result, &format).await;
-
-        if let Err(cause) = write_result {
-            let suffix = format!("(while in query {})", query);
-            write_result = Err(cause.add_message_back(suffix));
-        }
+        let write_result = match split_sql(query) {
+            Ok(statements) if statements.len() > 1 => {
+                self.base
+                    .do_multi_statement_query(&statements, &mut writer, &format)
+                    .await
+            }
+            // The federated queries of the drivers may not be tokenized, they're run as a whole.
+            _ => {
+                let query_result = self.base.do_query(query).await;
+                writer.write(query_result, &format).await.map_err(|cause| {
+                    let suffix = format!("(while in query {})", query);
+                    cause.add_message_back(suffix)
+                })
+            }
+        };
 
         histogram!(
             super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
@@ -257,7 +312,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
     }
 }
 
-impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
+impl InteractiveWorkerBase {
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
         let user_name = &info.user_name;
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
@@ -265,51 +320,174 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
         let ctx = self.session.create_query_context().await?;
         let user_info = UserApiProvider::instance()
             .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
-            .await?;
+            .await;
+
+        // The authentications of the LDAP users are recorded by the auth manager.
+        if matches!(&user_info, Ok(user_info) if user_info.auth_info == AuthInfo::LDAP) {
+            // The clear text password is terminated by the null.
+            let password = info
+                .user_password
+                .strip_suffix(&[0])
+                .unwrap_or(&info.user_password);
+            let credential = Credential::Password {
+                name: user_name.clone(),
+                password: Some(password.to_vec()),
+                hostname: Some(client_ip.to_string()),
+            };
+            ctx.get_auth_manager()
+                .auth(self.session.clone(), &credential)
+                .await?;
+            return Ok(true);
+        }
+
+        let res = self
+            .authenticate_native(&ctx, user_info, &info, salt, client_ip)
+            .await;
+        let err = match &res {
+            Ok(true) => None,
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Err(e) => Some(e.clone()),
+        };
+        InterpreterAuditLog::log_auth(&self.session, user_name, Some(client_ip), err.as_ref());
+        res
+    }
 
+    async fn authenticate_native(
+        &self,
+        ctx: &QueryContext,
+        user_info: Result<UserInfo>,
+        info: &CertifiedInfo,
+        salt: &[u8],
+        client_ip: &str,
+    ) -> Result<bool> {
+        let user_info = user_info?;
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
         if authed {
+            ctx.get_auth_manager()
+                .check_network_policy(&self.session, &user_info, Some(client_ip))
+                .await?;
+            SessionManager::instance().check_user_connections(&self.session, &user_info)?;
             self.session.set_current_user(user_info);
         }
         Ok(authed)
     }
 
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+    async fn do_prepare<W: AsyncWrite + Send + Unpin>(
+        &mut self,
+        query: &str,
+        writer: StatementMetaWriter<'_, W>,
+    ) -> Result<()> {
+        let placeholders = query_placeholders(query);
+        let params = vec![
+            Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            };
+            placeholders.len()
+        ];
+
+        // The statements can't be planned without the parameters, e.g. `LIMIT ?`, are
+        // described without the columns, the columns are sent with the results anyway.
+        let columns = match self.describe_statement(query, &placeholders).await {
+            Ok(columns) => columns,
+            Err(cause) => {
+                info!("Describe prepared statement failed: {}, {}", query, cause);
+                vec![]
+            }
+        };
+
+        let statement_id = self.next_statement_id;
+        self.next_statement_id = self.next_statement_id.wrapping_add(1).max(1);
+        self.prepared_statements
+            .insert(statement_id, PreparedStatement {
+                query: query.to_string(),
+                placeholders,
+            });
+        writer.reply(statement_id, &params, &columns).await?;
         Ok(())
     }
 
-    async fn do_execute(
+    async fn describe_statement(&self, query: &str, placeholders: &[usize]) -> Result<Vec<Column>> {
+        let literals = vec!["NULL".to_string(); placeholders.len()];
+        let query = bind_params(query, placeholders, &literals)?;
+        if let Some(data_block) = self.federated_server_command_check(&query) {
+            return convert_schema(data_block.schema(), true);
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _, _) = planner.plan_sql(&query).await?;
+        match has_result_set_by_plan(&plan) {
+            true => convert_schema(&plan.schema(), true),
+            false => Ok(vec![]),
+        }
+    }
+
+    async fn do_execute<W: AsyncWrite + Send + Unpin>(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        param: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let query = match self.prepared_statements.get(&id) {
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement handler ({}) given to mysqld_stmt_execute",
+                id
+            ))),
+            Some(statement) => param
+                .into_iter()
+                .map(param_literal)
+                .collect::<Result<Vec<_>>>()
+                .and_then(|literals| {
+                    bind_params(&statement.query, &statement.placeholders, &literals)
+                }),
+        };
+
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+        let query_result = match &query {
+            Ok(query) => self.do_query(query).await,
+            Err(cause) => Err(cause.clone()),
+        };
+
+        let format = self.session.get_format_settings()?;
+        let mut write_result = writer.write(query_result, &format).await;
+        if let (Err(cause), Ok(query)) = (&write_result, &query) {
+            let suffix = format!("(while in query {})", query);
+            write_result = Err(cause.clone().add_message_back(suffix));
+        }
+        write_result
     }
 
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
     fn federated_server_command_check(&self, query: &str) -> Option<DataBlock> {
+        // COMMIT and ROLLBACK are only no-ops of the drivers outside of an explicit transaction.
+        if self.session.get_transaction().is_some() {
+            let upper = query.trim_start().to_uppercase();
+            if upper.starts_with("COMMIT") || upper.starts_with("ROLLBACK") {
+                return None;
+            }
+        }
+
         let federated = MySQLFederated::create();
         federated.check(query)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
     async fn do_query(&mut self, query: &str) -> Result<QueryResult> {
+        // The streams of the TLS connections are owned by opensrv, the file can't be read.
+        if LoadDataStatement::is_load_data(query) {
+            return Err(ErrorCode::UnImplement(
+                "LOAD DATA LOCAL INFILE is only supported as a single statement of the connections without TLS, use the streaming load of the HTTP handler instead",
+            ));
+        }
+
         match self.federated_server_command_check(query) {
             Some(data_block) => {
                 info!("Federated query: {}", query);
@@ -357,6 +535,170 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
         }
     }
 
+    // The statements are run one by one in the session, the results are written as the multiple
+    // results of the query. The statements after a failed one are not run, unless the
+    // `continue_on_error` is set, then the error is written as the info of an OK packet.
+    async fn do_multi_statement_query<W: AsyncWrite + Send + Unpin>(
+        &mut self,
+        statements: &[&str],
+        writer: &mut DFQueryResultWriter<'_, W>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        let continue_on_error = self.session.get_settings().get_continue_on_error()?;
+        for (idx, statement) in statements.iter().enumerate() {
+            let last = idx + 1 == statements.len();
+            let write_result = match self.do_query(statement).await {
+                Err(cause) if continue_on_error && !last => {
+                    writer.write_more_error(&cause).await.map(|_| true)
+                }
+                query_result if last => writer.write(query_result, format).await.map(|_| false),
+                query_result => writer.write_more(query_result, format).await,
+            };
+            match write_result {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(cause) => {
+                    let suffix = format!("(while in query {})", statement);
+                    return Err(cause.add_message_back(suffix));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The file of `LOAD DATA LOCAL INFILE` is asked for after the query, and is streamed into
+    // the insert of the statement while it's sent by the client. The failures of the statement
+    // are the response of the query, only the failures of the streams close the connection.
+    async fn do_load_data(&mut self, query: &str) -> Result<()> {
+        let instant = Instant::now();
+        let session = self.session.clone();
+        let streams = match self.load_data_streams.as_mut() {
+            Some(streams) => streams,
+            None => {
+                return Err(ErrorCode::LogicalError(
+                    "the streams of LOAD DATA are not set",
+                ));
+            }
+        };
+
+        let prepared = async {
+            let statement = LoadDataStatement::parse(query)?;
+            let context = session.create_query_context().await?;
+            for (name, value) in statement.settings() {
+                context.set_query_setting(name.to_string(), value)?;
+            }
+
+            let mut planner = Planner::new(context.clone());
+            let (mut plan, _, _) = planner.plan_sql(&statement.insert_sql()).await?;
+            context.attach_query_str(plan.to_string(), query);
+
+            let schema = plan.schema();
+            let (tx, rx) = tokio::sync::mpsc::channel(2);
+            match &mut plan {
+                Plan::Insert(insert) => match &mut insert.source {
+                    InsertInputSource::StreamingWithFormat(format, _, input_context_ref) => {
+                        let input_context = Arc::new(
+                            InputContext::try_create_from_insert(
+                                format.as_str(),
+                                rx,
+                                context.get_settings(),
+                                schema,
+                                context.get_scan_progress(),
+                                false,
+                            )
+                            .await?,
+                        );
+                        *input_context_ref = Some(input_context.clone());
+                        let compression =
+                            input_context.get_compression_alg(&statement.file_name)?;
+                        Ok((
+                            statement.file_name,
+                            context,
+                            plan,
+                            input_context,
+                            compression,
+                            tx,
+                        ))
+                    }
+                    _ => Err(ErrorCode::LogicalError(
+                        "LOAD DATA is not planned as a streaming insert",
+                    )),
+                },
+                _ => Err(ErrorCode::LogicalError(
+                    "LOAD DATA is not planned as an insert",
+                )),
+            }
+        }
+        .await;
+        // The response of the query is the request of the file, or the error.
+        let (file_name, context, plan, input_context, compression, tx) = match prepared {
+            Ok(prepared) => prepared,
+            Err(cause) => {
+                return streams
+                    .write_err(1, error_kind(&cause), &cause.to_string())
+                    .await;
+            }
+        };
+        streams.request_file(1, &file_name).await?;
+        info!("Load data query: {}", query);
+
+        let handler = context.spawn({
+            let context = context.clone();
+            async move {
+                let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+                let mut data_stream = interpreter.execute(context).await?;
+                while let Some(block) = data_stream.next().await {
+                    block?;
+                }
+                Ok::<_, ErrorCode>(())
+            }
+        });
+
+        // The whole file is read even if the insert fails, the client waits for the response
+        // only after the empty packet.
+        let mut is_start = true;
+        let mut batch = vec![];
+        let sequence_id = loop {
+            let (sequence_id, packet) = streams.read_packet().await?;
+            let end = packet.is_empty();
+            batch.extend_from_slice(&packet);
+            if (end && !batch.is_empty()) || batch.len() >= input_context.read_batch_size {
+                let read_batch = StreamingReadBatch {
+                    data: std::mem::take(&mut batch),
+                    path: file_name.clone(),
+                    is_start,
+                    compression,
+                };
+                if let Err(cause) = tx.send(Ok(read_batch)).await {
+                    tracing::warn!("Load data fail to send ReadBatch: {}", cause);
+                }
+                is_start = false;
+            }
+            if end {
+                break sequence_id;
+            }
+        };
+        drop(tx);
+
+        let load_result = handler.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        );
+        let sequence_id = sequence_id.wrapping_add(1);
+        match load_result.and_then(|result| result) {
+            Ok(_) => {
+                let rows = context.get_scan_progress_value().rows as u64;
+                let info = ContextProgressReporter::new(context, instant).progress_info();
+                streams.write_ok(sequence_id, rows, &info).await
+            }
+            Err(cause) => {
+                streams
+                    .write_err(sequence_id, error_kind(&cause), &cause.to_string())
+                    .await
+            }
+        }
+    }
+
     //#[tracing::instrument(level = "debug", skip(interpreter, context))]
     async fn exec_query(
         interpreter: Arc<dyn Interpreter>,
@@ -412,8 +754,8 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     }
 }
 
-impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
-    pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker<W> {
+impl InteractiveWorker {
+    pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
         let mut bs = vec![0u8; 20];
         let mut rng = rand::thread_rng();
         rng.fill_bytes(bs.as_mut());
@@ -426,10 +768,12 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
             }
         }
 
-        InteractiveWorker::<W> {
-            base: InteractiveWorkerBase::<W> {
+        InteractiveWorker {
+            base: InteractiveWorkerBase {
                 session,
-                generic_hold: PhantomData::default(),
+                prepared_statements: HashMap::new(),
+                next_statement_id: 1,
+                load_data_streams: None,
             },
             salt: scramble,
             version: format!(
@@ -440,6 +784,10 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
             client_addr,
         }
     }
+
+    pub fn set_load_data_streams(&mut self, streams: LoadDataStreams) {
+        self.base.load_data_streams = Some(streams);
+    }
 }
 
 struct ContextProgressReporter {
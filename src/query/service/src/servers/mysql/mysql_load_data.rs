@@ -0,0 +1,539 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `LOAD DATA LOCAL INFILE`, of which the file is asked for by the server after the query,
+//! and sent by the client in the packets that follow, ended by an empty packet.
+//!
+//! opensrv doesn't hand its streams to the shim, so the streams of the connection are shared
+//! below it, and the packets of the file are exchanged by the worker while opensrv waits for
+//! the query to be done. The streams switched to TLS by opensrv can't be shared, the
+//! statement is only supported by the plain (and the compressed) connections.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::io::ReadBuf;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::parse_escape_string;
+use opensrv_mysql::ErrorKind;
+use parking_lot::Mutex;
+
+const MAX_PAYLOAD_LEN: usize = 0xFF_FFFF;
+const LOCAL_INFILE_REQUEST: u8 = 0xFB;
+
+/// The stream from the client, shared by opensrv and the worker.
+#[derive(Clone)]
+pub struct SharedReader(Arc<Mutex<Box<dyn AsyncRead + Send + Unpin>>>);
+
+impl SharedReader {
+    pub fn create(inner: impl AsyncRead + Send + Unpin + 'static) -> Self {
+        SharedReader(Arc::new(Mutex::new(Box::new(inner))))
+    }
+}
+
+impl AsyncRead for SharedReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut **self.0.lock()).poll_read(cx, buf)
+    }
+}
+
+/// The stream to the client, shared by opensrv and the worker.
+#[derive(Clone)]
+pub struct SharedWriter(Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>);
+
+impl SharedWriter {
+    pub fn create(inner: impl AsyncWrite + Send + Unpin + 'static) -> Self {
+        SharedWriter(Arc::new(Mutex::new(Box::new(inner))))
+    }
+}
+
+impl AsyncWrite for SharedWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut **self.0.lock()).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut **self.0.lock()).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut **self.0.lock()).poll_shutdown(cx)
+    }
+}
+
+/// The packets of `LOAD DATA LOCAL INFILE`, exchanged on the shared streams. They're only
+/// used while opensrv waits for the query, when nothing is buffered by it, as the client
+/// sends nothing before the response of the query.
+pub struct LoadDataStreams {
+    reader: SharedReader,
+    writer: SharedWriter,
+}
+
+impl LoadDataStreams {
+    pub fn create(reader: SharedReader, writer: SharedWriter) -> Self {
+        LoadDataStreams { reader, writer }
+    }
+
+    /// Asks the client for the file.
+    pub async fn request_file(&mut self, sequence_id: u8, file_name: &str) -> Result<()> {
+        let mut payload = vec![LOCAL_INFILE_REQUEST];
+        payload.extend_from_slice(file_name.as_bytes());
+        self.write_packet(sequence_id, &payload).await
+    }
+
+    /// The next packet of the file with its sequence id, the file ends by an empty packet.
+    pub async fn read_packet(&mut self) -> Result<(u8, Vec<u8>)> {
+        let mut payload = vec![];
+        loop {
+            let mut header = [0u8; 4];
+            self.reader.read_exact(&mut header).await?;
+            let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+            let start = payload.len();
+            payload.resize(start + len, 0);
+            self.reader.read_exact(&mut payload[start..]).await?;
+            // The payloads of the max length are continued by the next packet.
+            if len < MAX_PAYLOAD_LEN {
+                return Ok((header[3], payload));
+            }
+        }
+    }
+
+    pub async fn write_ok(
+        &mut self,
+        sequence_id: u8,
+        affected_rows: u64,
+        info: &str,
+    ) -> Result<()> {
+        let mut payload = vec![0x00];
+        write_lenenc_int(&mut payload, affected_rows);
+        // The last insert id, the status flags and the warnings.
+        write_lenenc_int(&mut payload, 0);
+        payload.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        payload.extend_from_slice(info.as_bytes());
+        self.write_packet(sequence_id, &payload).await
+    }
+
+    pub async fn write_err(
+        &mut self,
+        sequence_id: u8,
+        kind: ErrorKind,
+        message: &str,
+    ) -> Result<()> {
+        let mut payload = vec![0xFF];
+        payload.extend_from_slice(&(kind as u16).to_le_bytes());
+        payload.push(b'#');
+        payload.extend_from_slice(kind.sqlstate());
+        payload.extend_from_slice(message.as_bytes());
+        self.write_packet(sequence_id, &payload).await
+    }
+
+    async fn write_packet(&mut self, sequence_id: u8, payload: &[u8]) -> Result<()> {
+        let len = std::cmp::min(payload.len(), MAX_PAYLOAD_LEN) as u32;
+        let mut packet = len.to_le_bytes()[..3].to_vec();
+        packet.push(sequence_id);
+        packet.extend_from_slice(&payload[..len as usize]);
+        self.writer.write_all(&packet).await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+}
+
+fn write_lenenc_int(buf: &mut Vec<u8>, value: u64) {
+    match value {
+        0..=250 => buf.push(value as u8),
+        251..=0xFFFF => {
+            buf.push(0xFC);
+            buf.extend_from_slice(&(value as u16).to_le_bytes());
+        }
+        0x10000..=0xFF_FFFF => {
+            buf.push(0xFD);
+            buf.extend_from_slice(&(value as u32).to_le_bytes()[..3]);
+        }
+        _ => {
+            buf.push(0xFE);
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Symbol(char),
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>> {
+    let chars = query.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c.is_whitespace() {
+            pos += 1;
+        } else if c == '(' || c == ')' || c == ',' || c == ';' {
+            tokens.push(Token::Symbol(c));
+            pos += 1;
+        } else if c == '\'' || c == '"' {
+            // The escapes are kept for `parse_escape_string`, the doubled quotes are unescaped.
+            let mut raw = String::new();
+            pos += 1;
+            loop {
+                match (chars.get(pos), chars.get(pos + 1)) {
+                    (None, _) => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "unterminated string in LOAD DATA: {}",
+                            query
+                        )));
+                    }
+                    (Some('\\'), Some(next)) => {
+                        raw.push('\\');
+                        raw.push(*next);
+                        pos += 2;
+                    }
+                    (Some(q), Some(next)) if *q == c && *next == c => {
+                        raw.push(c);
+                        pos += 2;
+                    }
+                    (Some(q), _) if *q == c => {
+                        pos += 1;
+                        break;
+                    }
+                    (Some(other), _) => {
+                        raw.push(*other);
+                        pos += 1;
+                    }
+                }
+            }
+            tokens.push(Token::Quoted(parse_escape_string(raw.as_bytes())));
+        } else {
+            let mut word = String::new();
+            while let Some(c) = chars.get(pos) {
+                if c.is_whitespace() || "(),;'\"".contains(*c) {
+                    break;
+                }
+                word.push(*c);
+                pos += 1;
+                // The quoted identifiers may contain anything but the quote.
+                if *c == '`' {
+                    while let Some(c) = chars.get(pos) {
+                        word.push(*c);
+                        pos += 1;
+                        if *c == '`' {
+                            break;
+                        }
+                    }
+                }
+            }
+            tokens.push(Token::Word(word));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn symbol(&mut self, symbol: char) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Symbol(c)) if *c == symbol => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        match self.keyword(keyword) {
+            true => Ok(()),
+            false => Err(self.unexpected(keyword)),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<()> {
+        match self.symbol(symbol) {
+            true => Ok(()),
+            false => Err(self.unexpected(&symbol.to_string())),
+        }
+    }
+
+    fn word(&mut self, expected: &str) -> Result<String> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(word)) => {
+                self.pos += 1;
+                Ok(word.clone())
+            }
+            _ => Err(self.unexpected(expected)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Quoted(s)) => {
+                self.pos += 1;
+                Ok(s.clone())
+            }
+            _ => Err(self.unexpected("string")),
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> ErrorCode {
+        let found = match self.tokens.get(self.pos) {
+            None => "end of statement".to_string(),
+            Some(Token::Word(word)) => word.clone(),
+            Some(Token::Quoted(s)) => format!("'{}'", s),
+            Some(Token::Symbol(c)) => c.to_string(),
+        };
+        ErrorCode::SyntaxException(format!(
+            "expected {} in LOAD DATA, found {}",
+            expected, found
+        ))
+    }
+}
+
+/// The statement of `LOAD DATA LOCAL INFILE`, which is run as the streaming insert of the
+/// file, in TSV or in CSV if the fields are not separated by tabs or are enclosed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LoadDataStatement {
+    pub file_name: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub field_terminator: String,
+    pub enclosure: Option<String>,
+    pub escape: Option<String>,
+    pub line_terminator: String,
+    pub ignore_lines: u64,
+}
+
+impl LoadDataStatement {
+    pub fn is_load_data(query: &str) -> bool {
+        let mut words = query.split_whitespace();
+        matches!(
+            (words.next(), words.next()),
+            (Some(load), Some(data)) if load.eq_ignore_ascii_case("load") && data.eq_ignore_ascii_case("data")
+        )
+    }
+
+    pub fn parse(query: &str) -> Result<LoadDataStatement> {
+        let mut parser = Parser {
+            tokens: tokenize(query)?,
+            pos: 0,
+        };
+        parser.expect_keyword("LOAD")?;
+        parser.expect_keyword("DATA")?;
+        let _ = parser.keyword("LOW_PRIORITY") || parser.keyword("CONCURRENT");
+        if !parser.keyword("LOCAL") {
+            return Err(ErrorCode::UnImplement(
+                "only LOAD DATA LOCAL INFILE is supported, the files of the server can't be loaded",
+            ));
+        }
+        parser.expect_keyword("INFILE")?;
+        let file_name = parser.string()?;
+        if parser.keyword("REPLACE") {
+            return Err(ErrorCode::UnImplement(
+                "REPLACE of LOAD DATA is not supported",
+            ));
+        }
+        // The rows are appended, there are no duplicates to ignore.
+        parser.keyword("IGNORE");
+        parser.expect_keyword("INTO")?;
+        parser.expect_keyword("TABLE")?;
+        let table = parser.word("table name")?;
+
+        if parser.keyword("PARTITION") {
+            return Err(ErrorCode::UnImplement(
+                "PARTITION of LOAD DATA is not supported",
+            ));
+        }
+        if parser.keyword("CHARACTER") {
+            parser.expect_keyword("SET")?;
+            let charset = parser.word("character set")?;
+            if !["utf8", "utf8mb4", "binary"]
+                .iter()
+                .any(|c| charset.eq_ignore_ascii_case(c))
+            {
+                return Err(ErrorCode::UnImplement(format!(
+                    "CHARACTER SET {} of LOAD DATA is not supported, the files are read as UTF-8",
+                    charset
+                )));
+            }
+        }
+
+        let mut statement = LoadDataStatement {
+            file_name,
+            table,
+            columns: vec![],
+            field_terminator: "\t".to_string(),
+            enclosure: None,
+            escape: None,
+            line_terminator: "\n".to_string(),
+            ignore_lines: 0,
+        };
+        if parser.keyword("FIELDS") || parser.keyword("COLUMNS") {
+            loop {
+                if parser.keyword("TERMINATED") {
+                    parser.expect_keyword("BY")?;
+                    statement.field_terminator = parser.string()?;
+                } else if parser.keyword("OPTIONALLY") || parser.keyword("ENCLOSED") {
+                    parser.keyword("ENCLOSED");
+                    parser.expect_keyword("BY")?;
+                    statement.enclosure = Some(parser.string()?).filter(|s| !s.is_empty());
+                } else if parser.keyword("ESCAPED") {
+                    parser.expect_keyword("BY")?;
+                    statement.escape = Some(parser.string()?).filter(|s| !s.is_empty());
+                } else {
+                    break;
+                }
+            }
+        }
+        if parser.keyword("LINES") {
+            loop {
+                if parser.keyword("STARTING") {
+                    return Err(ErrorCode::UnImplement(
+                        "LINES STARTING BY of LOAD DATA is not supported",
+                    ));
+                } else if parser.keyword("TERMINATED") {
+                    parser.expect_keyword("BY")?;
+                    statement.line_terminator = parser.string()?;
+                } else {
+                    break;
+                }
+            }
+        }
+        if parser.keyword("IGNORE") {
+            let lines = parser.word("number of lines")?;
+            statement.ignore_lines = lines.parse::<u64>().map_err(|_| {
+                ErrorCode::SyntaxException(format!(
+                    "expected number of lines in LOAD DATA, found {}",
+                    lines
+                ))
+            })?;
+            if !parser.keyword("LINES") {
+                parser.expect_keyword("ROWS")?;
+            }
+        }
+        if parser.symbol('(') {
+            loop {
+                let column = parser.word("column name")?;
+                if column.starts_with('@') {
+                    return Err(ErrorCode::UnImplement(
+                        "the user variables of LOAD DATA are not supported",
+                    ));
+                }
+                statement.columns.push(column);
+                if !parser.symbol(',') {
+                    break;
+                }
+            }
+            parser.expect_symbol(')')?;
+        }
+        if parser.keyword("SET") {
+            return Err(ErrorCode::UnImplement("SET of LOAD DATA is not supported"));
+        }
+        parser.symbol(';');
+        if parser.pos < parser.tokens.len() {
+            return Err(parser.unexpected("end of statement"));
+        }
+
+        statement.check()?;
+        Ok(statement)
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.field_terminator.len() != 1 {
+            return Err(ErrorCode::UnImplement(format!(
+                "FIELDS TERMINATED BY of LOAD DATA must be a single character, got {:?}",
+                self.field_terminator
+            )));
+        }
+        for (clause, value) in [
+            ("ENCLOSED BY", &self.enclosure),
+            ("ESCAPED BY", &self.escape),
+        ] {
+            if matches!(value, Some(value) if value.len() != 1) {
+                return Err(ErrorCode::UnImplement(format!(
+                    "{} of LOAD DATA must be a single character, got {:?}",
+                    clause, value
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn format(&self) -> &'static str {
+        match (&self.enclosure, self.field_terminator.as_str()) {
+            (None, "\t") => "TSV",
+            _ => "CSV",
+        }
+    }
+
+    /// The insert of the file, the data is streamed to it.
+    pub fn insert_sql(&self) -> String {
+        let columns = match self.columns.is_empty() {
+            true => "".to_string(),
+            false => format!(" ({})", self.columns.join(", ")),
+        };
+        format!(
+            "INSERT INTO {}{} FORMAT {}",
+            self.table,
+            columns,
+            self.format()
+        )
+    }
+
+    /// The settings of the insert, which decide how the file is parsed.
+    pub fn settings(&self) -> Vec<(&'static str, String)> {
+        let mut settings = vec![
+            ("field_delimiter", self.field_terminator.clone()),
+            ("record_delimiter", self.line_terminator.clone()),
+            ("skip_header", self.ignore_lines.to_string()),
+        ];
+        if let Some(enclosure) = &self.enclosure {
+            settings.push(("quote_char", enclosure.clone()));
+        }
+        if let (Some(escape), "CSV") = (&self.escape, self.format()) {
+            settings.push(("escape_char", escape.clone()));
+        }
+        settings
+    }
+}
@@ -14,3 +14,5 @@
 
 pub static METRIC_MYSQL_PROCESSOR_REQUEST_DURATION: &str = "mysql.process_request_duration";
 pub static METRIC_INTERPRETER_USEDTIME: &str = "interpreter.usedtime";
+pub static METRIC_MYSQL_COMPRESSION_RAW_BYTES: &str = "mysql.compression.raw_bytes";
+pub static METRIC_MYSQL_COMPRESSION_WIRE_BYTES: &str = "mysql.compression.wire_bytes";
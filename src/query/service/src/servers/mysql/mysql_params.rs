@@ -0,0 +1,218 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+/// The offsets of the `?` placeholders of the prepared statement, the ones in the
+/// quoted strings, the quoted identifiers and the comments are skipped.
+pub fn query_placeholders(query: &str) -> Vec<usize> {
+    let bytes = query.as_bytes();
+    let mut placeholders = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() {
+                    if quote != b'`' && bytes[i] == b'\\' {
+                        i += 2;
+                        continue;
+                    }
+                    if bytes[i] == quote {
+                        // The doubled quote is the escaped quote.
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'?' => {
+                placeholders.push(i);
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    placeholders
+}
+
+/// Replaces the placeholders of the query with the literals of the parameters in order.
+pub fn bind_params(query: &str, placeholders: &[usize], literals: &[String]) -> Result<String> {
+    if placeholders.len() != literals.len() {
+        return Err(ErrorCode::BadArguments(format!(
+            "the statement expects {} parameters, but {} given",
+            placeholders.len(),
+            literals.len()
+        )));
+    }
+
+    let mut bound = String::with_capacity(query.len());
+    let mut last = 0;
+    for (offset, literal) in placeholders.iter().zip(literals) {
+        bound.push_str(&query[last..*offset]);
+        bound.push_str(literal);
+        last = offset + 1;
+    }
+    bound.push_str(&query[last..]);
+    Ok(bound)
+}
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+fn utf8_param(value: &[u8]) -> Result<&str> {
+    std::str::from_utf8(value)
+        .map_err(|_| ErrorCode::BadBytes("invalid utf-8 sequence of the parameter"))
+}
+
+fn binary_param<const N: usize>(value: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&value[offset..offset + N]);
+    bytes
+}
+
+// The date and the datetime in the binary protocol are the year, the month, the day,
+// then the optional hour, minute, second and microsecond.
+fn datetime_literal(value: &[u8]) -> Result<String> {
+    if !matches!(value.len(), 0 | 4 | 7 | 11) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of the datetime parameter: {}",
+            value.len()
+        )));
+    }
+
+    let mut literal = match value.len() {
+        0 => "0000-00-00".to_string(),
+        _ => format!(
+            "{:04}-{:02}-{:02}",
+            u16::from_le_bytes(binary_param(value, 0)),
+            value[2],
+            value[3]
+        ),
+    };
+    if value.len() >= 7 {
+        literal.push_str(&format!(" {:02}:{:02}:{:02}", value[4], value[5], value[6]));
+    }
+    if value.len() == 11 {
+        let micros = u32::from_le_bytes(binary_param(value, 7));
+        literal.push_str(&format!(".{:06}", micros));
+    }
+    Ok(quote_literal(&literal))
+}
+
+// The time in the binary protocol is the sign, the days, the hour, minute, second and
+// the optional microsecond.
+fn time_literal(value: &[u8]) -> Result<String> {
+    if !matches!(value.len(), 0 | 8 | 12) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of the time parameter: {}",
+            value.len()
+        )));
+    }
+
+    if value.is_empty() {
+        return Ok(quote_literal("00:00:00"));
+    }
+
+    let sign = if value[0] == 1 { "-" } else { "" };
+    let hours = u32::from_le_bytes(binary_param(value, 1))
+        .checked_mul(24)
+        .and_then(|hours| hours.checked_add(value[5] as u32))
+        .ok_or_else(|| ErrorCode::BadBytes("the days of the time parameter are out of range"))?;
+    let mut literal = format!("{}{:02}:{:02}:{:02}", sign, hours, value[6], value[7]);
+    if value.len() == 12 {
+        let micros = u32::from_le_bytes(binary_param(value, 8));
+        literal.push_str(&format!(".{:06}", micros));
+    }
+    Ok(quote_literal(&literal))
+}
+
+// The negative numbers are parenthesized, so that the sign is not merged with the
+// operator before the placeholder, e.g. `1-?` is not bound as the comment `1--1`.
+fn signed_literal(literal: String) -> String {
+    match literal.starts_with('-') {
+        true => format!("({})", literal),
+        false => literal,
+    }
+}
+
+fn double_literal(value: f64) -> Result<String> {
+    match value.is_finite() {
+        true => Ok(signed_literal(value.to_string())),
+        false => Err(ErrorCode::BadArguments(format!(
+            "invalid numeric parameter: {}",
+            value
+        ))),
+    }
+}
+
+// The decimals are sent as the strings, they are kept in the query as numbers.
+fn decimal_literal(value: &str) -> Result<String> {
+    let is_number = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'))
+        && value.parse::<f64>().is_ok();
+    match is_number {
+        true => Ok(signed_literal(value.to_string())),
+        false => Err(ErrorCode::BadArguments(format!(
+            "invalid numeric parameter: {}",
+            value
+        ))),
+    }
+}
+
+/// Converts the parameter bound by the client in the binary protocol to the literal in the query.
+pub fn param_literal(param: ParamValue) -> Result<String> {
+    match param.value.into_inner() {
+        ValueInner::NULL => Ok("NULL".to_string()),
+        ValueInner::Int(value) => Ok(signed_literal(value.to_string())),
+        ValueInner::UInt(value) => Ok(value.to_string()),
+        ValueInner::Double(value) => double_literal(value),
+        ValueInner::Date(value) | ValueInner::Datetime(value) => datetime_literal(value),
+        ValueInner::Time(value) => time_literal(value),
+        ValueInner::Bytes(value) => match param.coltype {
+            ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL => {
+                decimal_literal(utf8_param(value)?)
+            }
+            _ => Ok(quote_literal(utf8_param(value)?)),
+        },
+    }
+}
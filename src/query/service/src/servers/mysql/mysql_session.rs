@@ -23,11 +23,20 @@ use common_base::base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use opensrv_mysql::plain_run_with_options;
+use opensrv_mysql::secure_run_with_options;
 use opensrv_mysql::AsyncMysqlIntermediary;
 use opensrv_mysql::IntermediaryOptions;
+use tokio_rustls::rustls::ServerConfig;
 use tracing::error;
 
+use crate::servers::mysql::mysql_compression::CompressedReader;
+use crate::servers::mysql::mysql_compression::CompressedWriter;
+use crate::servers::mysql::mysql_compression::MySQLCompression;
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
+use crate::servers::mysql::mysql_load_data::LoadDataStreams;
+use crate::servers::mysql::mysql_load_data::SharedReader;
+use crate::servers::mysql::mysql_load_data::SharedWriter;
 use crate::sessions::Session;
 
 // default size of resultset write buffer: 100KB
@@ -36,7 +45,19 @@ const DEFAULT_RESULT_SET_WRITE_BUFFER_SIZE: usize = 100 * 1024;
 pub struct MySQLConnection;
 
 impl MySQLConnection {
-    pub fn run_on_stream(session: Arc<Session>, stream: TcpStream) -> Result<()> {
+    pub fn run_on_stream(
+        session: Arc<Session>,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        compression_enabled: bool,
+    ) -> Result<()> {
+        // The plain connections would bypass the verification of the client certificates.
+        let ssl_required = tls.is_some()
+            && !session
+                .get_config()
+                .query
+                .mysql_tls_server_root_ca_cert
+                .is_empty();
         let blocking_stream = Self::convert_stream(stream)?;
         MySQLConnection::attach_session(&session, &blocking_stream)?;
 
@@ -46,13 +67,39 @@ impl MySQLConnection {
         Thread::spawn(move || {
             let join_handle = query_executor.spawn(async move {
                 let client_addr = non_blocking_stream.peer_addr().unwrap().to_string();
-                let interactive_worker = InteractiveWorker::create(session, client_addr);
+                let mut interactive_worker = InteractiveWorker::create(session, client_addr);
                 let opts = IntermediaryOptions {
                     process_use_statement_on_query: true,
                 };
                 let (r, w) = non_blocking_stream.into_split();
-                let w = BufWriter::with_capacity(DEFAULT_RESULT_SET_WRITE_BUFFER_SIZE, w);
-                AsyncMysqlIntermediary::run_with_options(interactive_worker, r, w, &opts).await
+                // The compression is not offered with TLS, as the streams are wrapped below the TLS.
+                let compression = MySQLCompression::create(compression_enabled && tls.is_none());
+                let r = SharedReader::create(CompressedReader::create(r, compression.clone()));
+                let w = SharedWriter::create(CompressedWriter::create(w, compression));
+                let load_data_streams = LoadDataStreams::create(r.clone(), w.clone());
+                let mut w = BufWriter::with_capacity(DEFAULT_RESULT_SET_WRITE_BUFFER_SIZE, w);
+
+                // The SSL is negotiated in the handshake, before the authentication.
+                let (is_ssl, init_params) = AsyncMysqlIntermediary::init_before_ssl(
+                    &mut interactive_worker,
+                    r,
+                    &mut w,
+                    &tls,
+                )
+                .await?;
+                match tls {
+                    Some(tls) if is_ssl => {
+                        secure_run_with_options(interactive_worker, w, opts, tls, init_params).await
+                    }
+                    _ if ssl_required => Err(std::io::Error::new(
+                        std::io::ErrorKind::PermissionDenied,
+                        "the client certificate is required, connect with SSL",
+                    )),
+                    _ => {
+                        interactive_worker.set_load_data_streams(load_data_streams);
+                        plain_run_with_options(interactive_worker, w, opts, init_params).await
+                    }
+                }
             });
             let _ = futures::executor::block_on(join_handle);
         });
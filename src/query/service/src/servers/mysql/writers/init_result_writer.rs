@@ -18,6 +18,8 @@ use common_exception::Result;
 use opensrv_mysql::*;
 use tracing::error;
 
+use crate::servers::mysql::mysql_error::error_kind;
+
 pub struct DFInitResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<InitWriter<'a, W>>,
 }
@@ -46,7 +48,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFInitResultWriter<'a, W> {
     async fn err(error: &ErrorCode, writer: InitWriter<'a, W>) -> Result<()> {
         error!("OnInit Error: {:?}", error);
         writer
-            .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+            .error(error_kind(error), error.to_string().as_bytes())
             .await?;
         Ok(())
     }
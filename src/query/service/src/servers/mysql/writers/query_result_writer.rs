@@ -18,9 +18,11 @@ use common_datavalues::remove_nullable;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
 use common_datavalues::DateConverter;
 use common_datavalues::TypeSerializer;
+use common_datavalues::TypeSerializerImpl;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ABORT_QUERY;
@@ -31,6 +33,8 @@ use futures_util::StreamExt;
 use opensrv_mysql::*;
 use tracing::error;
 
+use crate::servers::mysql::mysql_error::error_kind;
+
 /// Reports progress information as string, intend to be put into the mysql Ok packet.
 /// Mainly for decoupling with concrete type like `QueryContext`
 ///
@@ -63,13 +67,129 @@ impl QueryResult {
     }
 }
 
+fn convert_field_type(field: &DataField) -> Result<ColumnType> {
+    match remove_nullable(field.data_type()).data_type_id() {
+        TypeID::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+        TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+        TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        TypeID::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
+        TypeID::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
+        TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::Array => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::VariantObject => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        _ => Err(ErrorCode::UnImplement(format!(
+            "Unsupported column type:{:?}",
+            field.data_type()
+        ))),
+    }
+}
+
+// The columns in the binary protocol are of the exact types, as the values are encoded
+// by the types of the columns.
+fn convert_binary_field_type(field: &DataField) -> Result<(ColumnType, ColumnFlags)> {
+    let unsigned = ColumnFlags::UNSIGNED_FLAG;
+    match remove_nullable(field.data_type()).data_type_id() {
+        TypeID::Int8 => Ok((ColumnType::MYSQL_TYPE_TINY, ColumnFlags::empty())),
+        TypeID::Int16 => Ok((ColumnType::MYSQL_TYPE_SHORT, ColumnFlags::empty())),
+        TypeID::Int32 => Ok((ColumnType::MYSQL_TYPE_LONG, ColumnFlags::empty())),
+        TypeID::Int64 => Ok((ColumnType::MYSQL_TYPE_LONGLONG, ColumnFlags::empty())),
+        TypeID::UInt8 => Ok((ColumnType::MYSQL_TYPE_TINY, unsigned)),
+        TypeID::UInt16 => Ok((ColumnType::MYSQL_TYPE_SHORT, unsigned)),
+        TypeID::UInt32 => Ok((ColumnType::MYSQL_TYPE_LONG, unsigned)),
+        TypeID::UInt64 => Ok((ColumnType::MYSQL_TYPE_LONGLONG, unsigned)),
+        TypeID::Float64 => Ok((ColumnType::MYSQL_TYPE_DOUBLE, ColumnFlags::empty())),
+        TypeID::Boolean => Ok((ColumnType::MYSQL_TYPE_TINY, ColumnFlags::empty())),
+        TypeID::Interval => Ok((ColumnType::MYSQL_TYPE_LONGLONG, ColumnFlags::empty())),
+        _ => convert_field_type(field).map(|column_type| (column_type, ColumnFlags::empty())),
+    }
+}
+
+fn make_column_from_field(field: &DataField, binary: bool) -> Result<Column> {
+    let (coltype, colflags) = match binary {
+        true => convert_binary_field_type(field)?,
+        false => (convert_field_type(field)?, ColumnFlags::empty()),
+    };
+    Ok(Column {
+        table: "".to_string(),
+        column: field.name().to_string(),
+        coltype,
+        colflags,
+    })
+}
+
+/// The columns of the results, of the types for the binary protocol if `binary`.
+pub fn convert_schema(schema: &DataSchemaRef, binary: bool) -> Result<Vec<Column>> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| make_column_from_field(field, binary))
+        .collect()
+}
+
+// The values of the binary protocol are encoded by the types of the columns, see
+// `convert_binary_field_type`, the others are sent as the strings.
+fn write_binary_col<W: AsyncWrite + Send + Unpin>(
+    row_writer: &mut RowWriter<'_, W>,
+    data_type: &DataTypeImpl,
+    value: DataValue,
+    serializer: &TypeSerializerImpl,
+    row_index: usize,
+    format: &FormatSettings,
+) -> Result<()> {
+    let tz = &format.timezone;
+    match (data_type.data_type_id(), value) {
+        (TypeID::Boolean, DataValue::Boolean(v)) => row_writer.write_col(v as i8)?,
+        (TypeID::Date, DataValue::Int64(v)) => {
+            row_writer.write_col((v as i32).to_date(tz).naive_local())?
+        }
+        (TypeID::Timestamp, DataValue::Int64(v)) => {
+            row_writer.write_col(v.to_timestamp(tz).naive_local())?
+        }
+        (TypeID::Float32, DataValue::Float64(v)) => row_writer.write_col(v as f32)?,
+        (TypeID::Float64, DataValue::Float64(v)) => row_writer.write_col(v)?,
+        (TypeID::String, DataValue::String(v)) => row_writer.write_col(v)?,
+        (
+            TypeID::Interval | TypeID::Int64 | TypeID::Int32 | TypeID::Int16 | TypeID::Int8,
+            DataValue::Int64(v),
+        ) => row_writer.write_col(v)?,
+        (_, DataValue::UInt64(v)) => row_writer.write_col(v)?,
+        _ => row_writer.write_col(serializer.serialize_field(row_index, format)?)?,
+    }
+    Ok(())
+}
+
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
+    binary: bool,
 }
 
 impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: false,
+        }
+    }
+
+    /// The writer of the results of the prepared statements in the binary protocol.
+    pub fn create_binary(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: true,
+        }
     }
 
     pub async fn write(
@@ -77,20 +197,62 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         query_result: Result<QueryResult>,
         format: &FormatSettings,
     ) -> Result<()> {
+        self.write_result(query_result, format, false).await?;
+        Ok(())
+    }
+
+    /// Writes the result of a statement followed by the results of more statements, e.g. of a
+    /// multi-statement query. False if an error is written, which ends the results.
+    pub async fn write_more(
+        &mut self,
+        query_result: Result<QueryResult>,
+        format: &FormatSettings,
+    ) -> Result<bool> {
+        self.write_result(query_result, format, true).await
+    }
+
+    /// Writes the error of a statement followed by the results of more statements, as the info
+    /// of an OK packet, since an error packet ends the results.
+    pub async fn write_more_error(&mut self, error: &ErrorCode) -> Result<()> {
         if let Some(writer) = self.inner.take() {
-            match query_result {
-                Ok(query_result) => Self::ok(query_result, writer, format).await?,
-                Err(error) => Self::err(&error, writer).await?,
-            }
+            let ok = OkResponse {
+                info: format!("Error: {error}"),
+                ..Default::default()
+            };
+            self.inner = Some(writer.complete_one(ok).await?);
         }
         Ok(())
     }
 
+    async fn write_result(
+        &mut self,
+        query_result: Result<QueryResult>,
+        format: &FormatSettings,
+        more: bool,
+    ) -> Result<bool> {
+        if let Some(writer) = self.inner.take() {
+            self.inner = match query_result {
+                Ok(query_result) => {
+                    Self::ok(query_result, writer, self.binary, format, more).await?
+                }
+                Err(error) => {
+                    Self::err(&error, writer).await?;
+                    None
+                }
+            };
+        }
+        Ok(self.inner.is_some())
+    }
+
+    // The writer of the next result is returned if more results follow, none if an error is
+    // written.
     async fn ok(
         mut query_result: QueryResult,
         dataset_writer: QueryResultWriter<'a, W>,
+        binary: bool,
         format: &FormatSettings,
-    ) -> Result<()> {
+        more: bool,
+    ) -> Result<Option<QueryResultWriter<'a, W>>> {
         // XXX: num_columns == 0 may is error?
 
         if !query_result.has_result_set {
@@ -99,63 +261,28 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             while let Some(block) = blocks.next().await {
                 if let Err(e) = block {
                     dataset_writer
-                        .error(ErrorKind::ER_UNKNOWN_ERROR, &e.to_string().as_bytes())
+                        .error(error_kind(&e), &e.to_string().as_bytes())
                         .await?;
 
-                    return Ok(());
+                    return Ok(None);
                 }
             }
 
-            dataset_writer.completed(OkResponse::default()).await?;
-            return Ok(());
-        }
-
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
-            match remove_nullable(field.data_type()).data_type_id() {
-                TypeID::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                TypeID::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
-                TypeID::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Array => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::VariantObject => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                _ => Err(ErrorCode::UnImplement(format!(
-                    "Unsupported column type:{:?}",
-                    field.data_type()
-                ))),
+            if more {
+                return Ok(Some(
+                    dataset_writer.complete_one(OkResponse::default()).await?,
+                ));
             }
-        }
-
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
-            })
-        }
-
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
+            dataset_writer.completed(OkResponse::default()).await?;
+            return Ok(None);
         }
 
         let tz = format.timezone;
-        match convert_schema(&query_result.schema) {
-            Err(error) => Self::err(&error, dataset_writer).await,
+        match convert_schema(&query_result.schema, binary) {
+            Err(error) => {
+                Self::err(&error, dataset_writer).await?;
+                Ok(None)
+            }
             Ok(columns) => {
                 let mut row_writer = dataset_writer.start(&columns).await?;
 
@@ -164,12 +291,9 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     let block = match block {
                         Err(e) => {
                             row_writer
-                                .finish_error(
-                                    ErrorKind::ER_UNKNOWN_ERROR,
-                                    &e.to_string().as_bytes(),
-                                )
+                                .finish_error(error_kind(&e), &e.to_string().as_bytes())
                                 .await?;
-                            return Ok(());
+                            return Ok(None);
                         }
                         Ok(block) => block,
                     };
@@ -187,6 +311,18 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                         block.schema().fields()[col_index].data_type(),
                                     );
 
+                                    if binary {
+                                        write_binary_col(
+                                            &mut row_writer,
+                                            &data_type,
+                                            val,
+                                            serializer,
+                                            row_index,
+                                            format,
+                                        )?;
+                                        continue;
+                                    }
+
                                     match (data_type.data_type_id(), val.clone()) {
                                         (TypeID::Boolean, DataValue::Boolean(v)) => {
                                             row_writer.write_col(v as i8)?
@@ -248,23 +384,23 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                         }
                         Err(e) => {
                             row_writer
-                                .finish_error(
-                                    ErrorKind::ER_UNKNOWN_ERROR,
-                                    &e.to_string().as_bytes(),
-                                )
+                                .finish_error(error_kind(&e), &e.to_string().as_bytes())
                                 .await?;
-                            return Ok(());
+                            return Ok(None);
                         }
                     }
                 }
 
+                if more {
+                    return Ok(Some(row_writer.finish_one().await?));
+                }
                 let info = query_result
                     .extra_info
                     .map(|r| r.progress_info())
                     .unwrap_or_default();
                 row_writer.finish_with_info(&info).await?;
 
-                Ok(())
+                Ok(None)
             }
         }
     }
@@ -272,17 +408,10 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     async fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
         if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
             error!("OnQuery Error: {:?}", error);
-            writer
-                .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
-                .await?;
-        } else {
-            writer
-                .error(
-                    ErrorKind::ER_ABORTING_CONNECTION,
-                    error.to_string().as_bytes(),
-                )
-                .await?;
         }
+        writer
+            .error(error_kind(error), error.to_string().as_bytes())
+            .await?;
 
         Ok(())
     }
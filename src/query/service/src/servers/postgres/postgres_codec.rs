@@ -0,0 +1,463 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const PROTOCOL_VERSION: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// The messages larger than these are rejected, as the length is sent by the client. The
+// messages before the authentication, i.e. the startup and the password messages, are
+// limited as the server of PostgreSQL does.
+pub const MAX_STARTUP_MESSAGE_LENGTH: usize = 10000;
+pub const MAX_MESSAGE_LENGTH: usize = 1024 * 1024 * 1024;
+
+// The body of the message is read by the chunks, instead of being allocated by the length
+// sent by the client at once.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+// The buffered backend messages are written to the client once exceeding this size.
+const WRITE_BUFFER_SIZE: usize = 64 * 1024;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+/// The first message sent by the client, without the type byte.
+pub enum StartupMessage {
+    Startup { params: HashMap<String, String> },
+    SslRequest,
+    GssEncRequest,
+    CancelRequest { process_id: i32, secret_key: i32 },
+}
+
+pub enum FrontendMessage {
+    Password {
+        password: Vec<u8>,
+    },
+    Query {
+        query: String,
+    },
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    /// Describes the statement with `b'S'` or the portal with `b'P'`.
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    /// Closes the statement with `b'S'` or the portal with `b'P'`.
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+}
+
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+pub enum BackendMessage {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    ParameterStatus {
+        name: String,
+        value: String,
+    },
+    BackendKeyData {
+        process_id: i32,
+        secret_key: i32,
+    },
+    /// The transaction status is always idle, as there is no transaction block.
+    ReadyForQuery,
+    RowDescription(Vec<FieldDescription>),
+    DataRow(Vec<Option<Vec<u8>>>),
+    CommandComplete {
+        tag: String,
+    },
+    EmptyQueryResponse,
+    /// The error with the severity, the SQLSTATE code and the message.
+    ErrorResponse {
+        severity: &'static str,
+        code: &'static str,
+        message: String,
+    },
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    NoData,
+    PortalSuspended,
+    ParameterDescription(Vec<u32>),
+}
+
+struct MessageReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> MessageReader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(ErrorCode::BadBytes("unexpected end of the message"));
+        }
+        let (bytes, data) = self.data.split_at(len);
+        self.data = data;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn cstr(&mut self) -> Result<String> {
+        match self.data.iter().position(|b| *b == 0) {
+            None => Err(ErrorCode::BadBytes("unterminated string in the message")),
+            Some(pos) => {
+                let s = String::from_utf8(self.data[..pos].to_vec())?;
+                self.data = &self.data[pos + 1..];
+                Ok(s)
+            }
+        }
+    }
+
+    fn i16_list(&mut self) -> Result<Vec<i16>> {
+        let len = self.i16()?;
+        (0..len).map(|_| self.i16()).collect()
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    len: i32,
+    max_length: usize,
+) -> Result<Vec<u8>> {
+    if len < 4 || len as usize > max_length {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of the message: {}",
+            len
+        )));
+    }
+
+    let len = len as usize - 4;
+    let mut body = Vec::with_capacity(len.min(READ_CHUNK_SIZE));
+    let read = reader.take(len as u64).read_to_end(&mut body).await?;
+    if read != len {
+        return Err(ErrorCode::BadBytes("unexpected end of the message"));
+    }
+    Ok(body)
+}
+
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await?;
+    let body = read_body(reader, len, MAX_STARTUP_MESSAGE_LENGTH).await?;
+    let mut body = MessageReader { data: &body };
+
+    match body.i32()? {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest {
+            process_id: body.i32()?,
+            secret_key: body.i32()?,
+        }),
+        PROTOCOL_VERSION => {
+            let mut params = HashMap::new();
+            loop {
+                let name = body.cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                params.insert(name, body.cstr()?);
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        version => Err(ErrorCode::UnImplement(format!(
+            "unsupported frontend protocol {}.{}",
+            version >> 16,
+            version & 0xffff
+        ))),
+    }
+}
+
+/// Reads the next message, `None` if the client closed the connection. The messages longer
+/// than `max_length` are rejected.
+pub async fn read_message<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    max_length: usize,
+) -> Result<Option<FrontendMessage>> {
+    let typ = match reader.read_u8().await {
+        Ok(typ) => typ,
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    let len = reader.read_i32().await?;
+    let body = read_body(reader, len, max_length).await?;
+    let mut body = MessageReader { data: &body };
+
+    let message = match typ {
+        b'p' => FrontendMessage::Password {
+            password: body.cstr()?.into_bytes(),
+        },
+        b'Q' => FrontendMessage::Query {
+            query: body.cstr()?,
+        },
+        b'P' => {
+            let name = body.cstr()?;
+            let query = body.cstr()?;
+            let len = body.i16()?;
+            let param_types = (0..len)
+                .map(|_| Ok(body.i32()? as u32))
+                .collect::<Result<Vec<_>>>()?;
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = body.cstr()?;
+            let statement = body.cstr()?;
+            let param_formats = body.i16_list()?;
+            let len = body.i16()?;
+            let params = (0..len)
+                .map(|_| match body.i32()? {
+                    -1 => Ok(None),
+                    len if len < 0 => Err(ErrorCode::BadBytes(format!(
+                        "invalid length of the parameter: {}",
+                        len
+                    ))),
+                    len => Ok(Some(body.bytes(len as usize)?.to_vec())),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let result_formats = body.i16_list()?;
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: body.u8()?,
+            name: body.cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: body.cstr()?,
+            max_rows: body.i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: body.u8()?,
+            name: body.cstr()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        typ => {
+            return Err(ErrorCode::UnImplement(format!(
+                "unsupported frontend message type '{}'",
+                typ as char
+            )));
+        }
+    };
+    Ok(Some(message))
+}
+
+fn put_cstr(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(s.as_bytes());
+    buf.push(0);
+}
+
+impl BackendMessage {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let typ = match self {
+            BackendMessage::AuthenticationOk | BackendMessage::AuthenticationCleartextPassword => {
+                b'R'
+            }
+            BackendMessage::ParameterStatus { .. } => b'S',
+            BackendMessage::BackendKeyData { .. } => b'K',
+            BackendMessage::ReadyForQuery => b'Z',
+            BackendMessage::RowDescription(_) => b'T',
+            BackendMessage::DataRow(_) => b'D',
+            BackendMessage::CommandComplete { .. } => b'C',
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ErrorResponse { .. } => b'E',
+            BackendMessage::ParseComplete => b'1',
+            BackendMessage::BindComplete => b'2',
+            BackendMessage::CloseComplete => b'3',
+            BackendMessage::NoData => b'n',
+            BackendMessage::PortalSuspended => b's',
+            BackendMessage::ParameterDescription(_) => b't',
+        };
+        buf.push(typ);
+
+        // The length is written back once the body is encoded.
+        let start = buf.len();
+        buf.extend_from_slice(&[0; 4]);
+
+        match self {
+            BackendMessage::AuthenticationOk => buf.extend_from_slice(&0i32.to_be_bytes()),
+            BackendMessage::AuthenticationCleartextPassword => {
+                buf.extend_from_slice(&3i32.to_be_bytes())
+            }
+            BackendMessage::ParameterStatus { name, value } => {
+                put_cstr(buf, name);
+                put_cstr(buf, value);
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                buf.extend_from_slice(&process_id.to_be_bytes());
+                buf.extend_from_slice(&secret_key.to_be_bytes());
+            }
+            BackendMessage::ReadyForQuery => buf.push(b'I'),
+            BackendMessage::RowDescription(fields) => {
+                buf.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+                for field in fields {
+                    put_cstr(buf, &field.name);
+                    // The table oid and the attribute number, the columns are not
+                    // identified as the columns of the tables.
+                    buf.extend_from_slice(&0i32.to_be_bytes());
+                    buf.extend_from_slice(&0i16.to_be_bytes());
+                    buf.extend_from_slice(&field.type_oid.to_be_bytes());
+                    buf.extend_from_slice(&field.type_size.to_be_bytes());
+                    // The type modifier.
+                    buf.extend_from_slice(&(-1i32).to_be_bytes());
+                    buf.extend_from_slice(&field.format.to_be_bytes());
+                }
+            }
+            BackendMessage::DataRow(values) => {
+                buf.extend_from_slice(&(values.len() as i16).to_be_bytes());
+                for value in values {
+                    match value {
+                        None => buf.extend_from_slice(&(-1i32).to_be_bytes()),
+                        Some(value) => {
+                            buf.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                            buf.extend_from_slice(value);
+                        }
+                    }
+                }
+            }
+            BackendMessage::CommandComplete { tag } => put_cstr(buf, tag),
+            BackendMessage::ErrorResponse {
+                severity,
+                code,
+                message,
+            } => {
+                buf.push(b'S');
+                put_cstr(buf, severity);
+                buf.push(b'V');
+                put_cstr(buf, severity);
+                buf.push(b'C');
+                put_cstr(buf, code);
+                buf.push(b'M');
+                put_cstr(buf, message);
+                buf.push(0);
+            }
+            BackendMessage::ParameterDescription(types) => {
+                buf.extend_from_slice(&(types.len() as i16).to_be_bytes());
+                for typ in types {
+                    buf.extend_from_slice(&typ.to_be_bytes());
+                }
+            }
+            BackendMessage::EmptyQueryResponse
+            | BackendMessage::ParseComplete
+            | BackendMessage::BindComplete
+            | BackendMessage::CloseComplete
+            | BackendMessage::NoData
+            | BackendMessage::PortalSuspended => {}
+        }
+
+        let len = (buf.len() - start) as i32;
+        buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+}
+
+/// Buffers the backend messages, the messages are written to the client by `flush`, or
+/// once the buffer is full.
+pub struct MessageWriter<W: AsyncWrite + Unpin> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> MessageWriter<W> {
+    pub fn create(writer: W) -> Self {
+        MessageWriter {
+            writer,
+            buf: Vec::with_capacity(WRITE_BUFFER_SIZE),
+        }
+    }
+
+    pub async fn write(&mut self, message: BackendMessage) -> Result<()> {
+        message.encode(&mut self.buf);
+        if self.buf.len() >= WRITE_BUFFER_SIZE {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+
+    /// Writes a single byte out of the messages, as the answer of the SSL request.
+    pub async fn write_byte(&mut self, byte: u8) -> Result<()> {
+        self.buf.push(byte);
+        self.flush().await
+    }
+
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.writer.flush().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCategory;
+use common_exception::ErrorCode;
+
+/// The SQLSTATE of an error code. The codes with a PostgreSQL counterpart are mapped to it, the
+/// others are mapped to the class of their categories.
+pub fn sqlstate(error: &ErrorCode) -> &'static str {
+    let overrides = [
+        (ErrorCode::syntax_exception_code(), "42601"),
+        (ErrorCode::semantic_error_code(), "42601"),
+        (ErrorCode::unknown_database_code(), "3D000"),
+        (ErrorCode::unknown_table_code(), "42P01"),
+        (ErrorCode::unknown_column_code(), "42703"),
+        (ErrorCode::unknown_function_code(), "42883"),
+        (ErrorCode::database_already_exists_code(), "42P04"),
+        (ErrorCode::table_already_exists_code(), "42P07"),
+        (ErrorCode::overflow_code(), "22003"),
+        (ErrorCode::authenticate_failure_code(), "28000"),
+        (ErrorCode::memory_limit_exceeded_code(), "53200"),
+        (ErrorCode::too_many_user_connections_code(), "53300"),
+        (ErrorCode::aborted_query_code(), "57014"),
+        (ErrorCode::aborted_session_code(), "57P01"),
+        (ErrorCode::data_corruption_code(), "XX001"),
+    ];
+    if let Some((_, code)) = overrides.iter().find(|(code, _)| *code == error.code()) {
+        return code;
+    }
+
+    match error.category() {
+        ErrorCategory::Syntax | ErrorCategory::Semantic => "42601",
+        ErrorCategory::NotFound => "42704",
+        ErrorCategory::AlreadyExists => "42710",
+        ErrorCategory::Data => "22000",
+        ErrorCategory::Permission => "42501",
+        ErrorCategory::Unsupported => "0A000",
+        ErrorCategory::QuotaExceeded | ErrorCategory::Overloaded => "53000",
+        ErrorCategory::Conflict => "40001",
+        ErrorCategory::Unavailable => "08006",
+        ErrorCategory::Aborted => "57000",
+        ErrorCategory::Config => "F0000",
+        ErrorCategory::Storage => "58030",
+        ErrorCategory::Internal => "XX000",
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::postgres::POSTGRES_VERSION;
+
+pub struct PostgresFederated {
+    postgres_version: String,
+    databend_version: String,
+}
+
+impl PostgresFederated {
+    pub fn create() -> Self {
+        PostgresFederated {
+            postgres_version: POSTGRES_VERSION.to_string(),
+            databend_version: crate::version::DATABEND_COMMIT_VERSION.to_string(),
+        }
+    }
+
+    // Build block for select function or show variable.
+    // Format:
+    // |name|
+    // |value|
+    fn single_value_block(name: &str, value: &str) -> Option<DataBlock> {
+        Some(DataBlock::create(
+            DataSchemaRefExt::create(vec![DataField::new(name, StringType::new_impl())]),
+            vec![Series::from_data(vec![value])],
+        ))
+    }
+
+    // Check SHOW of the variables of PostgreSQL, which the drivers ask for on connecting.
+    fn federated_show_variables_check(&self, query: &str) -> Option<DataBlock> {
+        let rules: Vec<(&str, Option<DataBlock>)> = vec![
+            // psycopg2, JDBC.
+            (
+                "(?i)^(SHOW TRANSACTION ISOLATION LEVEL(.*))",
+                Self::single_value_block("transaction_isolation", "read committed"),
+            ),
+            (
+                "(?i)^(SHOW DEFAULT_TRANSACTION_ISOLATION(.*))",
+                Self::single_value_block("default_transaction_isolation", "read committed"),
+            ),
+            (
+                "(?i)^(SHOW STANDARD_CONFORMING_STRINGS(.*))",
+                Self::single_value_block("standard_conforming_strings", "on"),
+            ),
+            (
+                "(?i)^(SHOW SERVER_VERSION(.*))",
+                Self::single_value_block("server_version", &self.postgres_version),
+            ),
+            (
+                "(?i)^(SHOW CLIENT_ENCODING(.*))",
+                Self::single_value_block("client_encoding", "UTF8"),
+            ),
+        ];
+        FederatedHelper::block_match_rule(query, rules)
+    }
+
+    // Check for SET or others query, this is the final check of the federated query.
+    fn federated_mixed_check(&self, query: &str) -> Option<DataBlock> {
+        let rules: Vec<(&str, Option<DataBlock>)> = vec![
+            (
+                "(?i)^(SELECT VERSION\\(\\))",
+                Self::single_value_block(
+                    "version",
+                    &format!(
+                        "PostgreSQL {} (Databend {})",
+                        self.postgres_version, self.databend_version
+                    ),
+                ),
+            ),
+            // Txn.
+            ("(?i)^(BEGIN(.*))", None),
+            ("(?i)^(START TRANSACTION(.*))", None),
+            ("(?i)^(COMMIT(.*))", None),
+            ("(?i)^(END(.*))", None),
+            ("(?i)^(ROLLBACK(.*))", None),
+            // Set.
+            ("(?i)^(SET CLIENT_ENCODING(.*))", None),
+            ("(?i)^(SET NAMES(.*))", None),
+            ("(?i)^(SET EXTRA_FLOAT_DIGITS(.*))", None),
+            ("(?i)^(SET APPLICATION_NAME(.*))", None),
+            ("(?i)^(SET DATESTYLE(.*))", None),
+            ("(?i)^(SET INTERVALSTYLE(.*))", None),
+            ("(?i)^(SET SEARCH_PATH(.*))", None),
+            ("(?i)^(SET STATEMENT_TIMEOUT(.*))", None),
+            ("(?i)^(SET SESSION CHARACTERISTICS(.*))", None),
+            ("(?i)^(SET TRANSACTION(.*))", None),
+            ("(?i)^(DISCARD ALL)", None),
+        ];
+
+        FederatedHelper::block_match_rule(query, rules)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<DataBlock> {
+        let query = query.trim().trim_end_matches(';');
+
+        // First to check the show variables.
+        let show_variables = self.federated_show_variables_check(query);
+        if show_variables.is_some() {
+            return show_variables;
+        }
+
+        // Last check.
+        self.federated_mixed_check(query)
+    }
+}
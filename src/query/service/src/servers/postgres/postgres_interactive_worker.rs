@@ -0,0 +1,914 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_types::AuthInfo;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_users::UserApiProvider;
+use futures_util::StreamExt;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use rand::Rng;
+use tracing::error;
+use tracing::info;
+use tracing::Instrument;
+
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_codec::read_message;
+use crate::servers::postgres::postgres_codec::read_startup_message;
+use crate::servers::postgres::postgres_codec::BackendMessage;
+use crate::servers::postgres::postgres_codec::FieldDescription;
+use crate::servers::postgres::postgres_codec::FrontendMessage;
+use crate::servers::postgres::postgres_codec::MessageWriter;
+use crate::servers::postgres::postgres_codec::StartupMessage;
+use crate::servers::postgres::postgres_codec::FORMAT_TEXT;
+use crate::servers::postgres::postgres_codec::MAX_MESSAGE_LENGTH;
+use crate::servers::postgres::postgres_codec::MAX_STARTUP_MESSAGE_LENGTH;
+use crate::servers::postgres::postgres_error::sqlstate;
+use crate::servers::postgres::postgres_types::encode_field;
+use crate::servers::postgres::postgres_types::param_literal;
+use crate::servers::postgres::postgres_types::param_placeholder;
+use crate::servers::postgres::postgres_types::type_oid;
+use crate::servers::postgres::postgres_types::type_size;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::postgres_types::UNSPECIFIED_OID;
+use crate::servers::postgres::PostgresFederated;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
+
+// The sessions of the connections by the backend key data, to cancel the running
+// query by the cancel request sent in another connection.
+static BACKEND_KEYS: Lazy<Mutex<HashMap<(i32, i32), String>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn has_result_set_by_plan(plan: &Plan) -> bool {
+    matches!(
+        plan,
+        Plan::Query { .. }
+            | Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+            | Plan::Call(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescShare(_)
+            | Plan::ShowShares(_)
+            | Plan::ShowObjectGrantPrivileges(_)
+            | Plan::ShowGrantTenantsOfShare(_)
+            | Plan::DescribeTable(_)
+            | Plan::ShowGrants(_)
+            | Plan::ListStage(_)
+            | Plan::Presign(_)
+    )
+}
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+struct QueryResult {
+    schema: DataSchemaRef,
+    blocks: SendableDataBlockStream,
+    has_result_set: bool,
+    context: Option<Arc<QueryContext>>,
+    /// The unsent rows of the block pulled from the stream, with the offset of the next row.
+    block: Option<(DataBlock, usize)>,
+    rows: usize,
+}
+
+enum PortalState {
+    Bound,
+    Running(QueryResult),
+    Completed(String),
+}
+
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+    state: PortalState,
+}
+
+pub struct InteractiveWorker {
+    session: Arc<Session>,
+    client_addr: SocketAddr,
+    // Whether the connection is on SSL, the password is only asked for on the SSL connections.
+    ssl: bool,
+    process_id: i32,
+    secret_key: i32,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    // The messages of the extended query are discarded until the sync, after an error.
+    ignore_till_sync: bool,
+}
+
+impl InteractiveWorker {
+    pub fn create(session: Arc<Session>, client_addr: SocketAddr, ssl: bool) -> InteractiveWorker {
+        let mut rng = rand::thread_rng();
+        InteractiveWorker {
+            session,
+            client_addr,
+            ssl,
+            process_id: rng.gen_range(1..i32::MAX),
+            secret_key: rng.gen(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            ignore_till_sync: false,
+        }
+    }
+
+    /// Serves the connection, `startup` is the startup message already read from the client,
+    /// e.g. before the SSL is negotiated.
+    pub async fn run<R, W>(
+        mut self,
+        mut reader: R,
+        writer: W,
+        startup: Option<StartupMessage>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Unpin + Send,
+        W: AsyncWrite + Unpin + Send,
+    {
+        let mut writer = MessageWriter::create(writer);
+        let res = match self.startup(&mut reader, &mut writer, startup).await? {
+            false => Ok(()),
+            true => self.serve(&mut reader, &mut writer).await,
+        };
+
+        BACKEND_KEYS
+            .lock()
+            .remove(&(self.process_id, self.secret_key));
+        res
+    }
+
+    // Returns false if the connection is closed in the startup.
+    async fn startup<R, W>(
+        &mut self,
+        reader: &mut R,
+        writer: &mut MessageWriter<W>,
+        mut startup: Option<StartupMessage>,
+    ) -> Result<bool>
+    where
+        R: AsyncRead + Unpin + Send,
+        W: AsyncWrite + Unpin + Send,
+    {
+        let params = loop {
+            let message = match startup.take() {
+                Some(message) => message,
+                None => read_startup_message(reader).await?,
+            };
+            match message {
+                // The SSL is negotiated by the connection before, if it is enabled.
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    writer.write_byte(b'N').await?;
+                }
+                StartupMessage::CancelRequest {
+                    process_id,
+                    secret_key,
+                } => {
+                    Self::cancel(process_id, secret_key);
+                    return Ok(false);
+                }
+                StartupMessage::Startup { params } => break params,
+            }
+        };
+
+        let user = params.get("user").cloned().unwrap_or_default();
+        if let Err(cause) = self.authenticate(&user, reader, writer).await {
+            error!(
+                "PostgreSQL handler authenticate failed, \
+                    user_name: {}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                user, self.client_addr, cause
+            );
+            let message = format!("password authentication failed for user \"{}\"", user);
+            self.write_fatal(writer, "28P01", message).await?;
+            return Ok(false);
+        }
+
+        if let Some(database) = params.get("database").filter(|db| !db.is_empty()) {
+            let init_query = format!("USE `{}`", database);
+            if let Err(cause) = self.do_query(&init_query).await {
+                self.write_fatal(writer, "3D000", cause.message()).await?;
+                return Ok(false);
+            }
+        }
+
+        writer.write(BackendMessage::AuthenticationOk).await?;
+        let format = self.session.get_format_settings()?;
+        let application_name = params.get("application_name").cloned().unwrap_or_default();
+        let parameters = vec![
+            (
+                "server_version",
+                format!(
+                    "{}-{}",
+                    POSTGRES_VERSION,
+                    *crate::version::DATABEND_COMMIT_VERSION
+                ),
+            ),
+            ("server_encoding", "UTF8".to_string()),
+            ("client_encoding", "UTF8".to_string()),
+            ("DateStyle", "ISO, MDY".to_string()),
+            ("TimeZone", format.timezone.name().to_string()),
+            ("integer_datetimes", "on".to_string()),
+            ("standard_conforming_strings", "on".to_string()),
+            ("IntervalStyle", "postgres".to_string()),
+            ("application_name", application_name),
+        ];
+        for (name, value) in parameters {
+            writer
+                .write(BackendMessage::ParameterStatus {
+                    name: name.to_string(),
+                    value,
+                })
+                .await?;
+        }
+
+        BACKEND_KEYS
+            .lock()
+            .insert((self.process_id, self.secret_key), self.session.get_id());
+        writer
+            .write(BackendMessage::BackendKeyData {
+                process_id: self.process_id,
+                secret_key: self.secret_key,
+            })
+            .await?;
+        writer.write(BackendMessage::ReadyForQuery).await?;
+        writer.flush().await?;
+        Ok(true)
+    }
+
+    async fn authenticate<R, W>(
+        &self,
+        user: &str,
+        reader: &mut R,
+        writer: &mut MessageWriter<W>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Unpin + Send,
+        W: AsyncWrite + Unpin + Send,
+    {
+        let client_ip = self.client_addr.ip().to_string();
+        let tenant = self.session.get_current_tenant();
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, user, &client_ip)
+            .await?;
+
+        // The password is asked for only if the user has one. It is sent in clear text, as the
+        // hashes of the passwords stored can not verify the MD5 or SCRAM responses, so it is
+        // only asked for on the SSL connections.
+        let password = match user_info.auth_info {
+            AuthInfo::None => None,
+            _ if !self.ssl => {
+                return Err(ErrorCode::AuthenticateFailure(
+                    "the password authentication requires the SSL connection",
+                ));
+            }
+            _ => {
+                writer
+                    .write(BackendMessage::AuthenticationCleartextPassword)
+                    .await?;
+                writer.flush().await?;
+                match read_message(reader, MAX_STARTUP_MESSAGE_LENGTH).await? {
+                    Some(FrontendMessage::Password { password }) => Some(password),
+                    _ => return Err(ErrorCode::AuthenticateFailure("password required")),
+                }
+            }
+        };
+
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password,
+            hostname: Some(client_ip),
+        };
+        let ctx = self.session.create_query_context().await?;
+        ctx.get_auth_manager()
+            .auth(self.session.clone(), &credential)
+            .await
+    }
+
+    fn cancel(process_id: i32, secret_key: i32) {
+        let session_id = BACKEND_KEYS.lock().get(&(process_id, secret_key)).cloned();
+        if let Some(session) =
+            session_id.and_then(|id| SessionManager::instance().get_session_by_id(&id))
+        {
+            info!(
+                "Cancel the query of the PostgreSQL session {}",
+                session.get_id()
+            );
+            session.force_kill_query(ErrorCode::AbortedQuery(
+                "canceling statement due to user request",
+            ));
+        }
+    }
+
+    async fn serve<R, W>(&mut self, reader: &mut R, writer: &mut MessageWriter<W>) -> Result<()>
+    where
+        R: AsyncRead + Unpin + Send,
+        W: AsyncWrite + Unpin + Send,
+    {
+        loop {
+            let message = match read_message(reader, MAX_MESSAGE_LENGTH).await? {
+                None => return Ok(()),
+                Some(message) => message,
+            };
+
+            if self.session.is_aborting() {
+                let message = "Aborting this connection. because we are try aborting server.";
+                self.write_fatal(writer, "57P01", message.to_string())
+                    .await?;
+                return Err(ErrorCode::AbortedSession(message));
+            }
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Sync => {
+                    self.ignore_till_sync = false;
+                    writer.write(BackendMessage::ReadyForQuery).await?;
+                    writer.flush().await?;
+                }
+                FrontendMessage::Flush => writer.flush().await?,
+                FrontendMessage::Query { query } => {
+                    if let Err(cause) = self.simple_query(&query, writer).await {
+                        self.write_error(writer, &cause).await?;
+                    }
+                    writer.write(BackendMessage::ReadyForQuery).await?;
+                    writer.flush().await?;
+                }
+                _ if self.ignore_till_sync => {}
+                message => {
+                    if let Err(cause) = self.extended_query(message, writer).await {
+                        self.write_error(writer, &cause).await?;
+                        self.ignore_till_sync = true;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn simple_query<W>(&mut self, query: &str, writer: &mut MessageWriter<W>) -> Result<()>
+    where W: AsyncWrite + Unpin + Send {
+        // The simple query closes the unnamed statement and portal.
+        self.statements.remove("");
+        self.portals.remove("");
+
+        if is_empty_query(query) {
+            return writer.write(BackendMessage::EmptyQueryResponse).await;
+        }
+
+        let mut result = self.do_query(query).await?;
+        if result.has_result_set {
+            let fields = Self::field_descriptions(&result.schema, &[]);
+            writer.write(BackendMessage::RowDescription(fields)).await?;
+        }
+        self.write_rows(&mut result, &[], 0, writer).await?;
+        let tag = command_tag(query, &result);
+        writer.write(BackendMessage::CommandComplete { tag }).await
+    }
+
+    async fn extended_query<W>(
+        &mut self,
+        message: FrontendMessage,
+        writer: &mut MessageWriter<W>,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                mut param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+
+                let num_params = query_params(&query)
+                    .iter()
+                    .map(|(_, _, index)| *index)
+                    .max()
+                    .unwrap_or(0);
+                if param_types.len() < num_params {
+                    param_types.resize(num_params, UNSPECIFIED_OID);
+                }
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                writer.write(BackendMessage::ParseComplete).await
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let statement = self.statements.get(&statement).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" does not exist",
+                        statement
+                    ))
+                })?;
+                if params.len() != statement.param_types.len() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "bind message supplies {} parameters, but prepared statement requires {}",
+                        params.len(),
+                        statement.param_types.len()
+                    )));
+                }
+
+                let literals = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, param)| {
+                        let format = format_of(&param_formats, i);
+                        param_literal(statement.param_types[i], format, param)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let query = bind_params(&statement.query, &literals)?;
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                    state: PortalState::Bound,
+                });
+                writer.write(BackendMessage::BindComplete).await
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.statements.get(&name).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" does not exist",
+                        name
+                    ))
+                })?;
+                let placeholders = statement
+                    .param_types
+                    .iter()
+                    .map(|type_oid| param_placeholder(*type_oid))
+                    .collect::<Vec<_>>();
+                let query = bind_params(&statement.query, &placeholders)?;
+                let param_types = statement
+                    .param_types
+                    .iter()
+                    .map(|type_oid| match *type_oid {
+                        UNSPECIFIED_OID => TEXT_OID,
+                        type_oid => type_oid,
+                    })
+                    .collect::<Vec<_>>();
+
+                writer
+                    .write(BackendMessage::ParameterDescription(param_types))
+                    .await?;
+                let description = self.describe_query(&query, &[]).await?;
+                writer.write(description).await
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let portal = self.portals.get(&name).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name))
+                })?;
+                let description = match &portal.state {
+                    PortalState::Running(result) if result.has_result_set => {
+                        let fields =
+                            Self::field_descriptions(&result.schema, &portal.result_formats);
+                        BackendMessage::RowDescription(fields)
+                    }
+                    PortalState::Running(_) | PortalState::Completed(_) => BackendMessage::NoData,
+                    PortalState::Bound => {
+                        self.describe_query(&portal.query, &portal.result_formats)
+                            .await?
+                    }
+                };
+                writer.write(description).await
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                let mut state = match self.portals.get_mut(&portal) {
+                    None => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "portal \"{}\" does not exist",
+                            portal
+                        )));
+                    }
+                    Some(portal) => std::mem::replace(&mut portal.state, PortalState::Bound),
+                };
+
+                if let PortalState::Bound = state {
+                    let query = self.portals[&portal].query.clone();
+                    if is_empty_query(&query) {
+                        self.set_portal_state(&portal, PortalState::Completed(String::new()));
+                        return writer.write(BackendMessage::EmptyQueryResponse).await;
+                    }
+                    state = PortalState::Running(self.do_query(&query).await?);
+                }
+
+                match state {
+                    PortalState::Running(mut result) => {
+                        let formats = self.portals[&portal].result_formats.clone();
+                        let max_rows = max_rows.max(0) as usize;
+                        if !self
+                            .write_rows(&mut result, &formats, max_rows, writer)
+                            .await?
+                        {
+                            self.set_portal_state(&portal, PortalState::Running(result));
+                            return writer.write(BackendMessage::PortalSuspended).await;
+                        }
+
+                        let tag = command_tag(&self.portals[&portal].query, &result);
+                        self.set_portal_state(&portal, PortalState::Completed(tag.clone()));
+                        writer.write(BackendMessage::CommandComplete { tag }).await
+                    }
+                    PortalState::Completed(tag) if tag.is_empty() => {
+                        self.set_portal_state(&portal, PortalState::Completed(tag));
+                        writer.write(BackendMessage::EmptyQueryResponse).await
+                    }
+                    PortalState::Completed(tag) => {
+                        self.set_portal_state(&portal, PortalState::Completed(tag.clone()));
+                        writer.write(BackendMessage::CommandComplete { tag }).await
+                    }
+                    PortalState::Bound => unreachable!(),
+                }
+            }
+            FrontendMessage::Close { kind, name } => {
+                match kind {
+                    b'S' => self.statements.remove(&name).map(|_| ()),
+                    _ => self.portals.remove(&name).map(|_| ()),
+                };
+                writer.write(BackendMessage::CloseComplete).await
+            }
+            FrontendMessage::Describe { kind, .. } => Err(ErrorCode::BadArguments(format!(
+                "invalid describe message subtype '{}'",
+                kind as char
+            ))),
+            FrontendMessage::Password { .. } => Err(ErrorCode::BadArguments(
+                "unexpected password message after authentication",
+            )),
+            FrontendMessage::Query { .. }
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!(),
+        }
+    }
+
+    fn set_portal_state(&mut self, portal: &str, state: PortalState) {
+        if let Some(portal) = self.portals.get_mut(portal) {
+            portal.state = state;
+        }
+    }
+
+    async fn describe_query(&self, query: &str, formats: &[i16]) -> Result<BackendMessage> {
+        if is_empty_query(query) {
+            return Ok(BackendMessage::NoData);
+        }
+
+        if let Some(data_block) = PostgresFederated::create().check(query) {
+            return Ok(match data_block.num_columns() {
+                0 => BackendMessage::NoData,
+                _ => BackendMessage::RowDescription(Self::field_descriptions(
+                    data_block.schema(),
+                    formats,
+                )),
+            });
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        if !has_result_set_by_plan(&plan) {
+            return Ok(BackendMessage::NoData);
+        }
+
+        let interpreter = InterpreterFactory::get(context, &plan).await?;
+        let fields = Self::field_descriptions(&interpreter.schema(), formats);
+        Ok(BackendMessage::RowDescription(fields))
+    }
+
+    fn field_descriptions(schema: &DataSchemaRef, formats: &[i16]) -> Vec<FieldDescription> {
+        schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let type_oid = type_oid(field.data_type());
+                FieldDescription {
+                    name: field.name().clone(),
+                    type_oid,
+                    type_size: type_size(type_oid),
+                    format: format_of(formats, i),
+                }
+            })
+            .collect()
+    }
+
+    // Writes the rows of the result, returns false if the result is suspended by the
+    // limit of the rows, which is unlimited if zero.
+    async fn write_rows<W>(
+        &self,
+        result: &mut QueryResult,
+        formats: &[i16],
+        max_rows: usize,
+        writer: &mut MessageWriter<W>,
+    ) -> Result<bool>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        if !result.has_result_set {
+            // Drains the stream to finish the query, e.g. the insert.
+            while let Some(block) = result.blocks.next().await {
+                block?;
+            }
+            return Ok(true);
+        }
+
+        let settings = self.session.get_format_settings()?;
+        let type_oids = result
+            .schema
+            .fields()
+            .iter()
+            .map(|field| type_oid(field.data_type()))
+            .collect::<Vec<_>>();
+
+        let mut num_rows = 0;
+        loop {
+            let (block, mut row_index) = match result.block.take() {
+                Some(block) => block,
+                None => match result.blocks.next().await {
+                    None => return Ok(true),
+                    Some(block) => (block?, 0),
+                },
+            };
+
+            let serializers = block.get_serializers()?;
+            let first_row = row_index;
+            while row_index < block.num_rows() {
+                if max_rows > 0 && num_rows == max_rows {
+                    break;
+                }
+
+                let mut values = Vec::with_capacity(serializers.len());
+                for (col_index, serializer) in serializers.iter().enumerate() {
+                    let value = block.column(col_index).get_checked(row_index)?;
+                    if value.is_null() {
+                        values.push(None);
+                        continue;
+                    }
+                    values.push(Some(encode_field(
+                        serializer,
+                        &value,
+                        row_index,
+                        type_oids[col_index],
+                        format_of(formats, col_index),
+                        &settings,
+                    )?));
+                }
+                writer.write(BackendMessage::DataRow(values)).await?;
+                row_index += 1;
+                num_rows += 1;
+            }
+            result.rows += row_index - first_row;
+            drop(serializers);
+
+            if row_index < block.num_rows() {
+                result.block = Some((block, row_index));
+                return Ok(false);
+            }
+        }
+    }
+
+    async fn write_error<W>(&self, writer: &mut MessageWriter<W>, error: &ErrorCode) -> Result<()>
+    where W: AsyncWrite + Unpin + Send {
+        error!("OnQuery Error: {:?}", error);
+        writer
+            .write(BackendMessage::ErrorResponse {
+                severity: "ERROR",
+                code: sqlstate(error),
+                message: error.message(),
+            })
+            .await
+    }
+
+    async fn write_fatal<W>(
+        &self,
+        writer: &mut MessageWriter<W>,
+        code: &'static str,
+        message: String,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        writer
+            .write(BackendMessage::ErrorResponse {
+                severity: "FATAL",
+                code,
+                message,
+            })
+            .await?;
+        writer.flush().await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn do_query(&self, query: &str) -> Result<QueryResult> {
+        match PostgresFederated::create().check(query) {
+            Some(data_block) => {
+                info!("Federated query: {}", query);
+                let has_result_set = data_block.num_columns() > 0;
+                let schema = data_block.schema().clone();
+                Ok(QueryResult {
+                    schema: schema.clone(),
+                    blocks: DataBlockStream::create(schema, None, vec![data_block]).boxed(),
+                    has_result_set,
+                    context: None,
+                    block: None,
+                    rows: 0,
+                })
+            }
+            None => {
+                info!("Normal query: {}", query);
+                let context = self.session.create_query_context().await?;
+
+                let mut planner = Planner::new(context.clone());
+                let (plan, _, _) = planner.plan_sql(query).await?;
+
+                context.attach_query_str(plan.to_string(), query);
+                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+                let has_result_set = has_result_set_by_plan(&plan);
+
+                match interpreter {
+                    Ok(interpreter) => {
+                        let blocks = Self::exec_query(interpreter.clone(), &context).await?;
+                        Ok(QueryResult {
+                            schema: interpreter.schema(),
+                            blocks,
+                            has_result_set,
+                            context: Some(context),
+                            block: None,
+                            rows: 0,
+                        })
+                    }
+                    Err(e) => {
+                        InterpreterQueryLog::fail_to_start(context, e.clone());
+                        Err(e)
+                    }
+                }
+            }
+        }
+    }
+
+    async fn exec_query(
+        interpreter: Arc<dyn Interpreter>,
+        context: &Arc<QueryContext>,
+    ) -> Result<SendableDataBlockStream> {
+        let query_result = context.try_spawn({
+            let ctx = context.clone();
+            async move { interpreter.execute(ctx).await }.in_current_span()
+        })?;
+
+        query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+}
+
+fn format_of(formats: &[i16], index: usize) -> i16 {
+    match formats.len() {
+        0 => FORMAT_TEXT,
+        1 => formats[0],
+        _ => formats.get(index).cloned().unwrap_or(FORMAT_TEXT),
+    }
+}
+
+fn is_empty_query(query: &str) -> bool {
+    query.trim().trim_matches(';').trim().is_empty()
+}
+
+// The tag of the command complete, e.g. `SELECT 3` or `INSERT 0 3`.
+fn command_tag(query: &str, result: &QueryResult) -> String {
+    if result.has_result_set {
+        return format!("SELECT {}", result.rows);
+    }
+
+    let mut words = query
+        .split_whitespace()
+        .map(|word| word.trim_end_matches(';').to_uppercase());
+    match words.next() {
+        None => String::new(),
+        Some(word) if word == "INSERT" => {
+            let rows = result
+                .context
+                .as_ref()
+                .map(|ctx| ctx.get_write_progress_value().rows)
+                .unwrap_or(0);
+            format!("INSERT 0 {}", rows)
+        }
+        Some(word) if matches!(word.as_str(), "CREATE" | "DROP" | "ALTER") => match words.next() {
+            None => word,
+            Some(object) => format!("{} {}", word, object),
+        },
+        Some(word) => word,
+    }
+}
+
+// The positions of the parameters `$n` out of the literals, the quoted identifiers and
+// the comments, with the 1-based index of the parameters.
+fn query_params(query: &str) -> Vec<(usize, usize, usize)> {
+    let bytes = query.as_bytes();
+    let mut params = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() {
+                    if quote == b'\'' && bytes[i] == b'\\' {
+                        i += 2;
+                        continue;
+                    }
+                    if bytes[i] == quote {
+                        // The doubled quote is the escaped quote.
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'$' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                if let Ok(index) = query[start + 1..i].parse::<usize>() {
+                    if index > 0 {
+                        params.push((start, i, index));
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    params
+}
+
+fn bind_params(query: &str, literals: &[String]) -> Result<String> {
+    let mut bound = String::with_capacity(query.len());
+    let mut last = 0;
+    for (start, end, index) in query_params(query) {
+        let literal = literals
+            .get(index - 1)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("there is no parameter ${}", index)))?;
+        bound.push_str(&query[last..start]);
+        bound.push_str(literal);
+        last = end;
+    }
+    bound.push_str(&query[last..]);
+    Ok(bound)
+}
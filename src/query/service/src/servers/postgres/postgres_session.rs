@@ -0,0 +1,115 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::Shutdown;
+use std::sync::Arc;
+
+use common_base::base::tokio::io::split;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::io::BufReader;
+use common_base::base::tokio::net::TcpStream;
+use common_base::base::Runtime;
+use common_base::base::Thread;
+use common_base::base::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+use tracing::error;
+
+use crate::servers::postgres::postgres_codec::read_startup_message;
+use crate::servers::postgres::postgres_codec::StartupMessage;
+use crate::servers::postgres::postgres_interactive_worker::InteractiveWorker;
+use crate::sessions::Session;
+
+pub struct PostgresConnection;
+
+impl PostgresConnection {
+    pub fn run_on_stream(
+        session: Arc<Session>,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> Result<()> {
+        let blocking_stream = Self::convert_stream(stream)?;
+        PostgresConnection::attach_session(&session, &blocking_stream)?;
+
+        let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("postgres-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(async move {
+                if let Err(error) = Self::run_on_tcp(session, non_blocking_stream, tls).await {
+                    error!(
+                        "Unexpected error occurred in PostgreSQL session: {:?}",
+                        error
+                    );
+                }
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    // The SSL is negotiated by the first startup message, the following messages are on
+    // the SSL connection if the client requests it and the SSL is enabled.
+    async fn run_on_tcp(
+        session: Arc<Session>,
+        mut stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> Result<()> {
+        let client_addr = stream.peer_addr()?;
+        match (read_startup_message(&mut stream).await?, tls) {
+            (StartupMessage::SslRequest, Some(tls)) => {
+                stream.write_all(b"S").await?;
+                let stream = TlsAcceptor::from(tls).accept(stream).await?;
+                let (r, w) = split(stream);
+                let interactive_worker = InteractiveWorker::create(session, client_addr, true);
+                interactive_worker.run(BufReader::new(r), w, None).await
+            }
+            (startup, _) => {
+                let (r, w) = stream.into_split();
+                let interactive_worker = InteractiveWorker::create(session, client_addr, false);
+                interactive_worker
+                    .run(BufReader::new(r), w, Some(startup))
+                    .await
+            }
+        }
+    }
+
+    fn attach_session(session: &Arc<Session>, blocking_stream: &std::net::TcpStream) -> Result<()> {
+        let host = blocking_stream.peer_addr().ok();
+        let blocking_stream_ref = blocking_stream.try_clone()?;
+        session.attach(host, move || {
+            if let Err(error) = blocking_stream_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown PostgreSQL session io {}", error);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
+        let stream = stream.into_std().map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+        stream.set_nonblocking(false).map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+
+        Ok(stream)
+    }
+}
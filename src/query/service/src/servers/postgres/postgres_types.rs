@@ -0,0 +1,262 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Offset;
+use common_datavalues::prelude::TypeID;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_datavalues::DateConverter;
+use common_datavalues::TypeSerializer;
+use common_datavalues::TypeSerializerImpl;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+
+use crate::servers::postgres::postgres_codec::FORMAT_BINARY;
+
+// The oids of the types in `pg_type`.
+pub const UNSPECIFIED_OID: u32 = 0;
+pub const BOOL_OID: u32 = 16;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const NUMERIC_OID: u32 = 1700;
+
+// The dates and the timestamps in the binary format are from 2000-01-01.
+const POSTGRES_EPOCH_DAYS: i64 = 10957;
+const POSTGRES_EPOCH_MICROS: i64 = POSTGRES_EPOCH_DAYS * 86_400_000_000;
+
+/// The type of the column in the results, the unsigned integers are kept in the
+/// signed types wide enough, except `UInt64` kept in `numeric`.
+pub fn type_oid(data_type: &DataTypeImpl) -> u32 {
+    match remove_nullable(data_type).data_type_id() {
+        TypeID::Boolean => BOOL_OID,
+        TypeID::Int8 | TypeID::Int16 | TypeID::UInt8 => INT2_OID,
+        TypeID::Int32 | TypeID::UInt16 => INT4_OID,
+        TypeID::Int64 | TypeID::UInt32 => INT8_OID,
+        TypeID::UInt64 => NUMERIC_OID,
+        TypeID::Float32 => FLOAT4_OID,
+        TypeID::Float64 => FLOAT8_OID,
+        TypeID::Date => DATE_OID,
+        TypeID::Timestamp => TIMESTAMP_OID,
+        TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject => JSON_OID,
+        _ => TEXT_OID,
+    }
+}
+
+pub fn type_size(type_oid: u32) -> i16 {
+    match type_oid {
+        BOOL_OID => 1,
+        INT2_OID => 2,
+        INT4_OID | FLOAT4_OID | DATE_OID => 4,
+        INT8_OID | FLOAT8_OID | TIMESTAMP_OID => 8,
+        _ => -1,
+    }
+}
+
+/// Encodes the non-null value of the results in the text or the binary format, the
+/// types without the binary format here are sent as the ones of `text`.
+pub fn encode_field(
+    serializer: &TypeSerializerImpl,
+    value: &DataValue,
+    row_index: usize,
+    type_oid: u32,
+    format: i16,
+    settings: &FormatSettings,
+) -> Result<Vec<u8>> {
+    if format != FORMAT_BINARY {
+        return match type_oid {
+            BOOL_OID if value.as_bool()? => Ok(b"t".to_vec()),
+            BOOL_OID => Ok(b"f".to_vec()),
+            _ => Ok(serializer
+                .serialize_field(row_index, settings)?
+                .into_bytes()),
+        };
+    }
+
+    Ok(match type_oid {
+        BOOL_OID => vec![value.as_bool()? as u8],
+        INT2_OID => (value.as_i64()? as i16).to_be_bytes().to_vec(),
+        INT4_OID => (value.as_i64()? as i32).to_be_bytes().to_vec(),
+        INT8_OID => value.as_i64()?.to_be_bytes().to_vec(),
+        FLOAT4_OID => (value.as_f64()? as f32).to_be_bytes().to_vec(),
+        FLOAT8_OID => value.as_f64()?.to_be_bytes().to_vec(),
+        NUMERIC_OID => encode_numeric(value.as_u64()?),
+        DATE_OID => ((value.as_i64()? - POSTGRES_EPOCH_DAYS) as i32)
+            .to_be_bytes()
+            .to_vec(),
+        TIMESTAMP_OID => {
+            // The timestamp without time zone is the local time of the session.
+            let micros = value.as_i64()?;
+            let offset = micros
+                .to_timestamp(&settings.timezone)
+                .offset()
+                .fix()
+                .local_minus_utc() as i64;
+            (micros + offset * 1_000_000 - POSTGRES_EPOCH_MICROS)
+                .to_be_bytes()
+                .to_vec()
+        }
+        _ => serializer
+            .serialize_field(row_index, settings)?
+            .into_bytes(),
+    })
+}
+
+// The numeric in the binary format is the digits in base 10000 from the most significant,
+// with the weight of the first digit, the sign and the scale.
+fn encode_numeric(mut value: u64) -> Vec<u8> {
+    let mut digits = vec![];
+    while value > 0 {
+        digits.push((value % 10000) as i16);
+        value /= 10000;
+    }
+    digits.reverse();
+
+    let mut buf = Vec::with_capacity(8 + digits.len() * 2);
+    buf.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    buf.extend_from_slice(&(digits.len().max(1) as i16 - 1).to_be_bytes());
+    buf.extend_from_slice(&0i16.to_be_bytes());
+    buf.extend_from_slice(&0i16.to_be_bytes());
+    for digit in digits {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+    buf
+}
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+fn utf8_param(value: &[u8]) -> Result<&str> {
+    std::str::from_utf8(value)
+        .map_err(|_| ErrorCode::BadBytes("invalid utf-8 sequence of the parameter"))
+}
+
+// The numbers are kept in the query as they are, the others, e.g. `NaN`, are rejected.
+fn number_literal(value: String) -> Result<String> {
+    let is_number = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'))
+        && value.parse::<f64>().is_ok();
+    match is_number {
+        true => Ok(value),
+        false => Err(ErrorCode::BadArguments(format!(
+            "invalid numeric parameter: {}",
+            value
+        ))),
+    }
+}
+
+fn binary_param<const N: usize>(value: &[u8]) -> Result<[u8; N]> {
+    value.try_into().map_err(|_| {
+        ErrorCode::BadBytes(format!(
+            "invalid length of the binary parameter: {}",
+            value.len()
+        ))
+    })
+}
+
+// The type of the parameter in the query, the parameters are casted to the type of the
+// client, so that the described and the executed results are of the same types.
+fn param_type_name(type_oid: u32) -> Option<&'static str> {
+    match type_oid {
+        BOOL_OID => Some("BOOLEAN"),
+        INT2_OID => Some("INT16"),
+        INT4_OID => Some("INT32"),
+        INT8_OID => Some("INT64"),
+        FLOAT4_OID => Some("FLOAT32"),
+        FLOAT8_OID | NUMERIC_OID => Some("FLOAT64"),
+        TEXT_OID | VARCHAR_OID => Some("VARCHAR"),
+        JSON_OID => Some("VARIANT"),
+        DATE_OID => Some("DATE"),
+        TIMESTAMP_OID => Some("TIMESTAMP"),
+        _ => None,
+    }
+}
+
+/// The placeholder of the parameter to describe the statement before binding.
+pub fn param_placeholder(type_oid: u32) -> String {
+    typed_literal(type_oid, "NULL".to_string())
+}
+
+fn typed_literal(type_oid: u32, literal: String) -> String {
+    match param_type_name(type_oid) {
+        None => literal,
+        Some(type_name) => format!("CAST({} AS {})", literal, type_name),
+    }
+}
+
+/// Converts the parameter bound by the client to the literal in the query, the
+/// parameters of the unspecified type look like numbers are kept as numbers.
+pub fn param_literal(type_oid: u32, format: i16, value: &Option<Vec<u8>>) -> Result<String> {
+    let literal = match value {
+        None => "NULL".to_string(),
+        Some(value) if format == FORMAT_BINARY => binary_param_literal(type_oid, value)?,
+        Some(value) => text_param_literal(type_oid, utf8_param(value)?)?,
+    };
+    Ok(typed_literal(type_oid, literal))
+}
+
+fn binary_param_literal(type_oid: u32, value: &[u8]) -> Result<String> {
+    Ok(match type_oid {
+        BOOL_OID => match value.first() {
+            Some(0) => "FALSE".to_string(),
+            _ => "TRUE".to_string(),
+        },
+        INT2_OID => i16::from_be_bytes(binary_param(value)?).to_string(),
+        INT4_OID => i32::from_be_bytes(binary_param(value)?).to_string(),
+        INT8_OID => i64::from_be_bytes(binary_param(value)?).to_string(),
+        FLOAT4_OID => number_literal(f32::from_be_bytes(binary_param(value)?).to_string())?,
+        FLOAT8_OID => number_literal(f64::from_be_bytes(binary_param(value)?).to_string())?,
+        UNSPECIFIED_OID | TEXT_OID | VARCHAR_OID | JSON_OID => quote_literal(utf8_param(value)?),
+        _ => {
+            return Err(ErrorCode::UnImplement(format!(
+                "unsupported binary parameter of the type oid {}",
+                type_oid
+            )));
+        }
+    })
+}
+
+fn text_param_literal(type_oid: u32, value: &str) -> Result<String> {
+    match type_oid {
+        BOOL_OID => match value.to_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "invalid boolean parameter: {}",
+                value
+            ))),
+        },
+        INT2_OID | INT4_OID | INT8_OID | FLOAT4_OID | FLOAT8_OID | NUMERIC_OID => {
+            number_literal(value.to_string())
+        }
+        UNSPECIFIED_OID => match number_literal(value.to_string()) {
+            Ok(literal) => Ok(literal),
+            Err(_) => Ok(quote_literal(value)),
+        },
+        _ => Ok(quote_literal(value)),
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rustls_pemfile::Item;
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::PrivateKey;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::rustls::ServerConfig;
+
+// The certificate chain and the private key in PEM, the key is in PKCS#8, PKCS#1 or SEC1.
+// The clients must present a certificate signed by the CA of `root_ca_cert_path` if it is set.
+pub fn build_tls_server_config(
+    handler: &str,
+    cert_path: &str,
+    key_path: &str,
+    root_ca_cert_path: &str,
+) -> Result<Arc<ServerConfig>> {
+    let certs = read_certs(cert_path)?;
+
+    let key_file = File::open(key_path)?;
+    let mut key_reader = BufReader::new(key_file);
+    let key = loop {
+        match rustls_pemfile::read_one(&mut key_reader)? {
+            Some(Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key)) => break key,
+            Some(_) => continue,
+            None => {
+                return Err(ErrorCode::TLSConfigurationFailure(format!(
+                    "no private key found in {}",
+                    key_path
+                )));
+            }
+        }
+    };
+
+    let builder = ServerConfig::builder().with_safe_defaults();
+    let builder = match root_ca_cert_path.is_empty() {
+        true => builder.with_no_client_auth(),
+        false => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(root_ca_cert_path)? {
+                roots.add(&cert).map_err(|e| {
+                    ErrorCode::TLSConfigurationFailure(format!(
+                        "invalid {handler} client root ca cert {root_ca_cert_path}: {e}"
+                    ))
+                })?;
+            }
+            builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots))
+        }
+    };
+    let config = builder
+        .with_single_cert(certs, PrivateKey(key))
+        .map_err(|e| {
+            ErrorCode::TLSConfigurationFailure(format!(
+                "failed to load {handler} server tls config: {e}"
+            ))
+        })?;
+    Ok(Arc::new(config))
+}
+
+fn read_certs(path: &str) -> Result<Vec<Certificate>> {
+    let file = File::open(path)?;
+    Ok(rustls_pemfile::certs(&mut BufReader::new(file))?
+        .into_iter()
+        .map(Certificate)
+        .collect())
+}
@@ -0,0 +1,249 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalIORuntime;
+use common_base::base::TrySpawn;
+use common_catalog::table_context::BackgroundTaskInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::info;
+
+/// The work a background task runs on its schedule.
+#[async_trait::async_trait]
+pub trait BackgroundJob: Send + Sync {
+    /// Runs the job once, returns the summary of the run, e.g. the rows written.
+    async fn run(&self) -> Result<String>;
+
+    /// The work waiting for the next run, e.g. the buffered rows.
+    fn progress(&self) -> String;
+}
+
+#[derive(Default)]
+struct TaskState {
+    suspended: bool,
+    running: bool,
+    // Run once even if the task is suspended, by `ALTER TASK <name> EXECUTE`.
+    triggered: bool,
+    // Run before the interval elapses, e.g. the batch is full.
+    woken: bool,
+    runs: u64,
+    failures: u64,
+    last_run_on: Option<i64>,
+    last_run_duration: Option<Duration>,
+    last_result: Option<String>,
+    next_run_on: Option<i64>,
+}
+
+/// A job run every interval in the background of the node, it can be suspended, resumed or
+/// triggered to run at once by `ALTER TASK`.
+pub struct BackgroundTask {
+    name: String,
+    kind: String,
+    interval: Duration,
+    job: Weak<dyn BackgroundJob>,
+    state: Mutex<TaskState>,
+    notify: Notify,
+}
+
+impl BackgroundTask {
+    pub fn create(
+        name: &str,
+        kind: &str,
+        interval: Duration,
+        job: Weak<dyn BackgroundJob>,
+    ) -> Arc<BackgroundTask> {
+        Arc::new(BackgroundTask {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            interval,
+            job,
+            state: Mutex::new(TaskState::default()),
+            notify: Notify::new(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the job every interval until the job is dropped.
+    pub fn start(self: &Arc<Self>) {
+        let task = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                task.wait_next().await;
+                match task.job.upgrade() {
+                    Some(job) => task.run_once(job.as_ref()).await,
+                    None => break,
+                }
+            }
+        });
+    }
+
+    /// Runs the job before the interval elapses, unless the task is suspended.
+    pub fn wake(&self) {
+        self.state.lock().woken = true;
+        self.notify.notify_one();
+    }
+
+    /// Runs the job at once, even if the task is suspended.
+    pub fn trigger(&self) {
+        self.state.lock().triggered = true;
+        self.notify.notify_one();
+    }
+
+    pub fn suspend(&self) {
+        self.state.lock().suspended = true;
+        self.notify.notify_one();
+    }
+
+    pub fn resume(&self) {
+        self.state.lock().suspended = false;
+        self.notify.notify_one();
+    }
+
+    async fn wait_next(&self) {
+        loop {
+            let notified = self.notify.notified();
+            {
+                let mut state = self.state.lock();
+                if state.triggered || (state.woken && !state.suspended) {
+                    state.triggered = false;
+                    state.woken = false;
+                    return;
+                }
+                state.next_run_on = match state.suspended {
+                    true => None,
+                    false => Some(now_micros() + self.interval.as_micros() as i64),
+                };
+            }
+
+            if self.state.lock().suspended {
+                notified.await;
+                continue;
+            }
+
+            // Timed out, the interval elapses without any change of the state.
+            if tokio::time::timeout(self.interval, notified).await.is_err()
+                && !self.state.lock().suspended
+            {
+                return;
+            }
+        }
+    }
+
+    async fn run_once(&self, job: &dyn BackgroundJob) {
+        {
+            let mut state = self.state.lock();
+            state.running = true;
+            state.next_run_on = None;
+            state.last_run_on = Some(now_micros());
+        }
+
+        let instant = Instant::now();
+        let res = job.run().await;
+
+        let mut state = self.state.lock();
+        state.running = false;
+        state.runs += 1;
+        state.last_run_duration = Some(instant.elapsed());
+        state.last_result = Some(match res {
+            Ok(summary) => summary,
+            Err(cause) => {
+                state.failures += 1;
+                cause.to_string()
+            }
+        });
+    }
+
+    pub fn info(&self) -> BackgroundTaskInfo {
+        let progress = self
+            .job
+            .upgrade()
+            .map(|job| job.progress())
+            .unwrap_or_default();
+
+        let state = self.state.lock();
+        let state_name = match (state.running, state.suspended) {
+            (true, _) => "Running",
+            (false, true) => "Suspended",
+            (false, false) => "Scheduled",
+        };
+        BackgroundTaskInfo {
+            name: self.name.clone(),
+            kind: self.kind.clone(),
+            state: state_name.to_string(),
+            interval_secs: self.interval.as_secs(),
+            runs: state.runs,
+            failures: state.failures,
+            last_run_on: state.last_run_on,
+            last_run_duration_ms: state.last_run_duration.map(|d| d.as_millis() as u64),
+            last_result: state.last_result.clone(),
+            next_run_on: state.next_run_on,
+            progress,
+        }
+    }
+}
+
+/// The background tasks of the node, by their names.
+#[derive(Default)]
+pub struct BackgroundTasks {
+    tasks: RwLock<BTreeMap<String, Arc<BackgroundTask>>>,
+}
+
+impl BackgroundTasks {
+    pub fn create() -> Arc<BackgroundTasks> {
+        Arc::new(BackgroundTasks::default())
+    }
+
+    /// Registers the task and starts it.
+    pub fn start(&self, task: Arc<BackgroundTask>) {
+        info!("start background task {}", task.name());
+        task.start();
+        self.tasks.write().insert(task.name().to_string(), task);
+    }
+
+    pub fn get(&self, name: &str) -> Result<Arc<BackgroundTask>> {
+        match self.tasks.read().get(name) {
+            Some(task) => Ok(task.clone()),
+            None => Err(ErrorCode::UnknownBackgroundTask(format!(
+                "Unknown background task {}",
+                name
+            ))),
+        }
+    }
+
+    pub fn tasks_info(&self) -> Vec<BackgroundTaskInfo> {
+        self.tasks.read().values().map(|task| task.info()).collect()
+    }
+}
+
+fn now_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
+}
@@ -0,0 +1,273 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::OptimizeTableAction;
+use common_planner::plans::OptimizeTablePlan;
+use common_users::UserApiProvider;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::OptimizeTableInterpreter;
+use crate::sessions::BackgroundJob;
+use crate::sessions::BackgroundTask;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::storages::fuse::operations::CompactionEstimate;
+use crate::storages::fuse::FuseTable;
+use crate::Config;
+
+/// The background task compacting the fragmented tables.
+pub const COMPACTION_SCHEDULER_TASK: &str = "compaction_scheduler";
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// The time window of a day in UTC, it wraps past the midnight if it ends before it starts,
+/// e.g. `22:00-04:00`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompactionWindow {
+    // The minutes of the day.
+    start: u32,
+    end: u32,
+}
+
+impl CompactionWindow {
+    /// Parses the window of `HH:MM-HH:MM`, `None` if it's empty, i.e. at any time.
+    pub fn parse(window: &str) -> Result<Option<CompactionWindow>> {
+        let window = window.trim();
+        if window.is_empty() {
+            return Ok(None);
+        }
+
+        let bad_window = || {
+            ErrorCode::InvalidConfig(format!(
+                "auto_compaction_window '{}' is not of HH:MM-HH:MM",
+                window
+            ))
+        };
+        let parse_minute = |time: &str| -> Result<u32> {
+            let (hour, minute) = time.trim().split_once(':').ok_or_else(bad_window)?;
+            let hour = hour.parse::<u32>().map_err(|_| bad_window())?;
+            let minute = minute.parse::<u32>().map_err(|_| bad_window())?;
+            if hour > 23 || minute > 59 {
+                return Err(bad_window());
+            }
+            Ok(hour * 60 + minute)
+        };
+        let (start, end) = window.split_once('-').ok_or_else(bad_window)?;
+        Ok(Some(CompactionWindow {
+            start: parse_minute(start)?,
+            end: parse_minute(end)?,
+        }))
+    }
+
+    pub fn contains(&self, minute_of_day: u32) -> bool {
+        match self.start <= self.end {
+            true => self.start <= minute_of_day && minute_of_day < self.end,
+            false => self.start <= minute_of_day || minute_of_day < self.end,
+        }
+    }
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    leader: bool,
+    // The table being compacted.
+    compacting: Option<String>,
+}
+
+/// Compacts the fragmented tables of the tenant in the background, instead of running
+/// `OPTIMIZE TABLE ... COMPACT` by hand. The nodes of the cluster compete for a lease in the
+/// meta service, only the node holding it compacts the tables, so that the budget is global.
+///
+/// On each check, the tables of which the excess blocks reach `min_excess_blocks` are compacted
+/// one at a time by `max_threads` threads, the most fragmented first, until the compressed bytes
+/// of the compactions reach `max_bytes_per_run`. The tables are only checked in the window.
+pub struct CompactionScheduler {
+    tenant: String,
+    node_id: String,
+    interval: Duration,
+    min_excess_blocks: u64,
+    max_bytes_per_run: u64,
+    max_threads: u64,
+    window: String,
+    state: Mutex<SchedulerState>,
+    task: Arc<BackgroundTask>,
+}
+
+impl CompactionScheduler {
+    pub fn from_config(conf: &Config) -> Arc<CompactionScheduler> {
+        let interval = Duration::from_secs(conf.query.auto_compaction_interval_secs.max(1));
+        Arc::new_cyclic(|scheduler| {
+            let job: Weak<dyn BackgroundJob> = scheduler.clone();
+            CompactionScheduler {
+                tenant: conf.query.tenant_id.clone(),
+                node_id: uuid::Uuid::new_v4().to_string(),
+                interval,
+                min_excess_blocks: conf.query.auto_compaction_min_excess_blocks.max(1),
+                max_bytes_per_run: conf.query.auto_compaction_max_bytes_per_run,
+                max_threads: conf.query.auto_compaction_max_threads.max(1),
+                window: conf.query.auto_compaction_window.clone(),
+                state: Mutex::new(SchedulerState::default()),
+                task: BackgroundTask::create(COMPACTION_SCHEDULER_TASK, "Compact", interval, job),
+            }
+        })
+    }
+
+    pub fn task(&self) -> Arc<BackgroundTask> {
+        self.task.clone()
+    }
+
+    /// Compacts the fragmented tables within the budget, if the node is the leader and it's in
+    /// the window.
+    pub async fn check(&self) -> Result<String> {
+        if let Some(window) = CompactionWindow::parse(&self.window)? {
+            if !window.contains(utc_minute_of_day()) {
+                return Ok(format!("outside the window {}", self.window));
+            }
+        }
+
+        // The lease outlives a long compaction, which delays the next check.
+        let ttl_secs = (self.interval.as_secs() * 2).max(60);
+        let leader = UserApiProvider::instance()
+            .acquire_compaction_scheduler_lease(&self.tenant, &self.node_id, ttl_secs)
+            .await?;
+        self.state.lock().leader = leader;
+        if !leader {
+            return Ok("not the leader".to_string());
+        }
+
+        let mut candidates = self.fragmented_tables().await?;
+        let num_candidates = candidates.len();
+        candidates.sort_by(|a, b| b.2.excess_blocks.cmp(&a.2.excess_blocks));
+
+        let mut compacted = 0;
+        let mut spent_bytes = 0;
+        for (database, table, estimate) in candidates {
+            if spent_bytes > 0 && spent_bytes + estimate.bytes > self.max_bytes_per_run {
+                continue;
+            }
+            self.state.lock().compacting = Some(format!("{}.{}", database, table));
+            let res = self.compact(&database, &table).await;
+            self.state.lock().compacting = None;
+            match res {
+                Ok(_) => {
+                    info!(
+                        "compacted table {}.{} of {} excess blocks",
+                        database, table, estimate.excess_blocks
+                    );
+                    compacted += 1;
+                    spent_bytes += estimate.bytes;
+                }
+                Err(cause) => {
+                    error!("fail to compact table {}.{}: {:?}", database, table, cause);
+                }
+            }
+        }
+        Ok(format!(
+            "leader, {} of {} fragmented tables compacted, {} bytes",
+            compacted, num_candidates, spent_bytes
+        ))
+    }
+
+    // The fuse tables of which the excess blocks reach the threshold.
+    async fn fragmented_tables(&self) -> Result<Vec<(String, String, CompactionEstimate)>> {
+        let ctx = self.create_context().await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let mut tables = vec![];
+        for database in catalog.list_databases(&self.tenant).await? {
+            let database = database.name();
+            for table in catalog.list_tables(&self.tenant, database).await? {
+                let fuse_table = match FuseTable::try_from_table(table.as_ref()) {
+                    Ok(fuse_table) => fuse_table,
+                    Err(_) => continue,
+                };
+                match fuse_table.estimate_compaction(ctx.clone()).await {
+                    Ok(Some(estimate)) if estimate.excess_blocks >= self.min_excess_blocks => {
+                        tables.push((database.to_string(), table.name().to_string(), estimate));
+                    }
+                    Ok(_) => {}
+                    Err(cause) => {
+                        error!(
+                            "fail to estimate the compaction of table {}.{}: {:?}",
+                            database,
+                            table.name(),
+                            cause
+                        );
+                    }
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    async fn compact(&self, database: &str, table: &str) -> Result<()> {
+        // A context per compaction, the context caches the tables.
+        let ctx = self.create_context().await?;
+        ctx.get_settings().set_max_threads(self.max_threads)?;
+        let plan = OptimizeTablePlan {
+            catalog: CATALOG_DEFAULT.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            action: OptimizeTableAction::Compact,
+        };
+        let interpreter = OptimizeTableInterpreter::try_create(ctx, plan)?;
+        interpreter.execute2().await?;
+        Ok(())
+    }
+
+    async fn create_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("AutoCompaction".to_string()))
+            .await?;
+        session.set_current_tenant(self.tenant.clone());
+        session.create_query_context().await
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundJob for CompactionScheduler {
+    async fn run(&self) -> Result<String> {
+        self.check().await
+    }
+
+    fn progress(&self) -> String {
+        let state = self.state.lock();
+        match (&state.compacting, state.leader) {
+            (Some(table), _) => format!("leader, compacting {}", table),
+            (None, true) => "leader".to_string(),
+            (None, false) => "not the leader".to_string(),
+        }
+    }
+}
+
+fn utc_minute_of_day() -> u32 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    (secs / 60 % MINUTES_PER_DAY as u64) as u32
+}
@@ -0,0 +1,436 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_catalog::table_context::LoadedDictionaryInfo;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::TypeDeserializer;
+use common_datavalues::TypeFactory;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use common_meta_types::DictionaryFormat;
+use common_meta_types::DictionaryMeta;
+use common_meta_types::DictionarySource;
+use common_users::UserApiProvider;
+use mysql_async::prelude::Queryable;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::warn;
+
+use crate::Config;
+
+/// The rows of a dictionary loaded from its source.
+pub struct LoadedDictionary {
+    pub meta: DictionaryMeta,
+    /// The rows, the columns are in the order of the columns of the dictionary.
+    pub block: DataBlock,
+    // The row of each primary key, the last row wins if the key is duplicated.
+    rows: HashMap<DataValue, usize>,
+    loaded_at: Mutex<Instant>,
+}
+
+impl LoadedDictionary {
+    /// The schema of the columns of the dictionary.
+    pub fn schema(meta: &DictionaryMeta) -> Result<DataSchemaRef> {
+        let fields = meta
+            .columns
+            .iter()
+            .map(|column| {
+                let data_type = TypeFactory::instance().get(&column.data_type)?;
+                Ok(DataField::new(&column.name, data_type))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataSchemaRefExt::create(fields))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.block.num_rows()
+    }
+
+    /// The values of the attribute of the rows of the keys, `None` if there is no row of the
+    /// key. The keys are of the type of the primary key, or its nullable type.
+    pub fn get_values(&self, attribute: &str, keys: &ColumnRef) -> Result<Vec<Option<DataValue>>> {
+        let position = self.meta.column_position(attribute).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "dictionary {} has no attribute {}",
+                self.meta.name, attribute
+            ))
+        })?;
+        let column = self.block.column(position);
+        Ok((0..keys.len())
+            .map(|i| match keys.get(i) {
+                DataValue::Null => None,
+                key => self.rows.get(&key).map(|row| column.get(*row)),
+            })
+            .collect())
+    }
+
+    fn is_expired(&self) -> bool {
+        self.meta.lifetime_secs > 0
+            && self.loaded_at.lock().elapsed() >= Duration::from_secs(self.meta.lifetime_secs)
+    }
+}
+
+/// Caches the rows of the dictionaries in memory, the rows are loaded from the source on the first
+/// lookup, and loaded again on the lookup after they are older than the lifetime of the
+/// dictionary. The older rows are kept if they fail to load again.
+pub struct DictionaryCache {
+    // The local files and the sources of plain HTTP are read only if the insecure storages are
+    // allowed.
+    allow_insecure: bool,
+    dictionaries: RwLock<HashMap<(String, String), Arc<LoadedDictionary>>>,
+}
+
+impl DictionaryCache {
+    pub fn create(allow_insecure: bool) -> Arc<DictionaryCache> {
+        Arc::new(DictionaryCache {
+            allow_insecure,
+            dictionaries: RwLock::new(HashMap::new()),
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<DictionaryCache> {
+        DictionaryCache::create(conf.storage.allow_insecure)
+    }
+
+    /// The loaded rows of the dictionary of the tenant, loaded again if they are expired.
+    pub async fn get(&self, tenant: &str, name: &str) -> Result<Arc<LoadedDictionary>> {
+        let key = (tenant.to_string(), name.to_string());
+        let cached = self.dictionaries.read().get(&key).cloned();
+        if let Some(cached) = &cached {
+            if !cached.is_expired() {
+                return Ok(cached.clone());
+            }
+        }
+
+        let meta = UserApiProvider::instance()
+            .get_dictionary(tenant, name)
+            .await?;
+        match self.load(meta.clone()).await {
+            Ok(loaded) => {
+                let loaded = Arc::new(loaded);
+                self.dictionaries.write().insert(key, loaded.clone());
+                Ok(loaded)
+            }
+            // the dictionary of the same definition is kept, until it's loaded the next time
+            Err(cause) => match cached {
+                Some(cached) if cached.meta == meta => {
+                    warn!("fail to load dictionary {} again: {:?}", name, cause);
+                    *cached.loaded_at.lock() = Instant::now();
+                    Ok(cached)
+                }
+                _ => Err(cause),
+            },
+        }
+    }
+
+    /// The loaded rows of the dictionary, `None` if they are not loaded on the node.
+    pub fn get_loaded(&self, tenant: &str, name: &str) -> Option<Arc<LoadedDictionary>> {
+        let key = (tenant.to_string(), name.to_string());
+        self.dictionaries.read().get(&key).cloned()
+    }
+
+    /// The dictionaries of the tenant loaded on the node.
+    pub fn loaded_info(&self, tenant: &str) -> Vec<LoadedDictionaryInfo> {
+        self.dictionaries
+            .read()
+            .iter()
+            .filter(|((t, _), _)| t == tenant)
+            .map(|((_, name), loaded)| LoadedDictionaryInfo {
+                name: name.clone(),
+                num_rows: loaded.num_rows() as u64,
+            })
+            .collect()
+    }
+
+    /// The rows of the dictionary are removed, e.g. once it's dropped.
+    pub fn invalidate(&self, tenant: &str, name: &str) {
+        let key = (tenant.to_string(), name.to_string());
+        self.dictionaries.write().remove(&key);
+    }
+
+    /// Checks the source of the dictionary may be read by the node.
+    pub fn check_source(&self, source: &DictionarySource) -> Result<()> {
+        let insecure = match source {
+            DictionarySource::Mysql { .. } => false,
+            DictionarySource::Http { url, .. } => !url.to_lowercase().starts_with("https://"),
+            DictionarySource::File { .. } => true,
+        };
+        if insecure && !self.allow_insecure {
+            return Err(ErrorCode::StorageInsecure(format!(
+                "the {} source of the dictionary is insecure, it's allowed only if storage.allow_insecure is set",
+                source.source_type()
+            )));
+        }
+        Ok(())
+    }
+
+    async fn load(&self, meta: DictionaryMeta) -> Result<LoadedDictionary> {
+        self.check_source(&meta.source)?;
+        let schema = LoadedDictionary::schema(&meta)?;
+        let rows = match &meta.source {
+            DictionarySource::Mysql { url, table } => load_mysql(&meta, url, table).await?,
+            DictionarySource::Http { url, format } => {
+                let data = reqwest::get(url.as_str())
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(|e| source_error(&meta, e))?
+                    .bytes()
+                    .await
+                    .map_err(|e| source_error(&meta, e))?;
+                parse_rows(&meta, &data, *format)?
+            }
+            DictionarySource::File { path, format } => {
+                let data = tokio::fs::read(path)
+                    .await
+                    .map_err(|e| source_error(&meta, e))?;
+                parse_rows(&meta, &data, *format)?
+            }
+        };
+        build(meta, schema, rows)
+    }
+}
+
+// The fields of the rows of the source, the fields of the text sources are in the order of the
+// columns, `None` if the field is NULL.
+enum SourceRows {
+    Text(Vec<Vec<Option<Vec<u8>>>>),
+    Json(Vec<serde_json::Value>),
+}
+
+fn source_error(meta: &DictionaryMeta, cause: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::DictionarySourceError(format!(
+        "fail to load dictionary {} from its {} source: {}",
+        meta.name,
+        meta.source.source_type(),
+        cause
+    ))
+}
+
+async fn load_mysql(meta: &DictionaryMeta, url: &str, table: &str) -> Result<SourceRows> {
+    let opts = mysql_async::Opts::from_url(url).map_err(|e| source_error(meta, e))?;
+    let mut conn = mysql_async::Conn::new(opts)
+        .await
+        .map_err(|e| source_error(meta, e))?;
+    let columns = meta
+        .columns
+        .iter()
+        .map(|column| format!("`{}`", column.name.replace('`', "``")))
+        .collect::<Vec<_>>();
+    let query = format!("SELECT {} FROM {}", columns.join(", "), table);
+    // the values of the text protocol are the bytes of their text
+    let res: std::result::Result<Vec<mysql_async::Row>, _> = conn.query(query).await;
+    let _ = conn.disconnect().await;
+
+    let rows = res
+        .map_err(|e| source_error(meta, e))?
+        .into_iter()
+        .map(|row| {
+            row.unwrap()
+                .into_iter()
+                .map(|value| match value {
+                    mysql_async::Value::NULL => None,
+                    mysql_async::Value::Bytes(bytes) => Some(bytes),
+                    value => Some(value.as_sql(true).into_bytes()),
+                })
+                .collect()
+        })
+        .collect();
+    Ok(SourceRows::Text(rows))
+}
+
+// The CSV and TSV files have no header, the fields of `\N` are NULL.
+fn parse_rows(meta: &DictionaryMeta, data: &[u8], format: DictionaryFormat) -> Result<SourceRows> {
+    let null = |field: Vec<u8>| match field.as_slice() {
+        b"\\N" => None,
+        _ => Some(field),
+    };
+    let lines = || {
+        data.split(|b| *b == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .filter(|line| !line.is_empty())
+    };
+
+    match format {
+        DictionaryFormat::Csv => {
+            let records = parse_csv(data).map_err(|e| source_error(meta, e))?;
+            Ok(SourceRows::Text(
+                records
+                    .into_iter()
+                    .map(|fields| fields.into_iter().map(null).collect())
+                    .collect(),
+            ))
+        }
+        DictionaryFormat::Tsv => Ok(SourceRows::Text(
+            lines()
+                .map(|line| {
+                    line.split(|b| *b == b'\t')
+                        .map(|f| null(f.to_vec()))
+                        .collect()
+                })
+                .collect(),
+        )),
+        DictionaryFormat::Ndjson => lines()
+            .map(|line| serde_json::from_slice(line).map_err(|e| source_error(meta, e)))
+            .collect::<Result<Vec<_>>>()
+            .map(SourceRows::Json),
+    }
+}
+
+// The records of the CSV, the fields may be quoted by `"`, of which `""` is a quote.
+fn parse_csv(data: &[u8]) -> std::result::Result<Vec<Vec<Vec<u8>>>, String> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = vec![];
+    let mut quoted = false;
+    let mut i = 0;
+    while i < data.len() {
+        let b = data[i];
+        if quoted {
+            match b {
+                b'"' if data.get(i + 1) == Some(&b'"') => {
+                    field.push(b'"');
+                    i += 1;
+                }
+                b'"' => quoted = false,
+                _ => field.push(b),
+            }
+        } else {
+            match b {
+                b'"' if field.is_empty() => quoted = true,
+                b',' => record.push(std::mem::take(&mut field)),
+                b'\n' => {
+                    if field.last() == Some(&b'\r') {
+                        field.pop();
+                    }
+                    record.push(std::mem::take(&mut field));
+                    let fields = std::mem::take(&mut record);
+                    // the empty lines are skipped
+                    if fields.len() > 1 || !fields[0].is_empty() {
+                        records.push(fields);
+                    }
+                }
+                _ => field.push(b),
+            }
+        }
+        i += 1;
+    }
+    if quoted {
+        return Err("the quoted field is not closed".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+fn build(
+    meta: DictionaryMeta,
+    schema: DataSchemaRef,
+    rows: SourceRows,
+) -> Result<LoadedDictionary> {
+    let format = FormatSettings::default();
+    let num_rows = match &rows {
+        SourceRows::Text(rows) => rows.len(),
+        SourceRows::Json(rows) => rows.len(),
+    };
+    let mut deserializers = schema
+        .fields()
+        .iter()
+        .map(|field| field.data_type().create_deserializer(num_rows))
+        .collect::<Vec<_>>();
+
+    let columns = &meta.columns;
+    let bad_row = |row: usize, cause: String| {
+        ErrorCode::DictionarySourceError(format!(
+            "fail to load row {} of dictionary {}: {}",
+            row + 1,
+            meta.name,
+            cause
+        ))
+    };
+    match &rows {
+        SourceRows::Text(rows) => {
+            for (row, fields) in rows.iter().enumerate() {
+                if fields.len() != columns.len() {
+                    return Err(bad_row(
+                        row,
+                        format!("{} fields for {} columns", fields.len(), columns.len()),
+                    ));
+                }
+                for ((field, deserializer), column) in
+                    fields.iter().zip(deserializers.iter_mut()).zip(columns)
+                {
+                    match field {
+                        Some(field) => deserializer
+                            .de_whole_text(field, &format)
+                            .map_err(|e| bad_row(row, e.message()))?,
+                        None if !deserializer.de_null(&format) => {
+                            return Err(bad_row(row, format!("column {} is NULL", column.name)));
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+        SourceRows::Json(rows) => {
+            for (row, object) in rows.iter().enumerate() {
+                for (deserializer, column) in deserializers.iter_mut().zip(columns) {
+                    let value = object.get(&column.name).unwrap_or(&serde_json::Value::Null);
+                    deserializer
+                        .de_json(value, &format)
+                        .map_err(|e| bad_row(row, e.message()))?;
+                }
+            }
+        }
+    }
+
+    let columns = deserializers
+        .iter_mut()
+        .map(|deserializer| deserializer.finish_to_column())
+        .collect::<Vec<_>>();
+    let block = DataBlock::create(schema, columns);
+
+    let key_position = meta.column_position(&meta.primary_key).ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "the primary key {} is not a column of dictionary {}",
+            meta.primary_key, meta.name
+        ))
+    })?;
+    let key_column = block.column(key_position);
+    let mut keys = HashMap::with_capacity(block.num_rows());
+    for row in 0..block.num_rows() {
+        match key_column.get(row) {
+            DataValue::Null => {}
+            key => {
+                keys.insert(key, row);
+            }
+        }
+    }
+
+    Ok(LoadedDictionary {
+        meta,
+        block,
+        rows: keys,
+        loaded_at: Mutex::new(Instant::now()),
+    })
+}
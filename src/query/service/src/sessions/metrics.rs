@@ -12,5 +12,48 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_metrics::label_decrement_gauge_with_val_and_labels;
+use common_metrics::label_increment_gauge_with_val_and_labels;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_TENANT;
+
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
 pub static METRIC_SESSION_CONNECT_NUMBERS: &str = "session.connect_numbers";
 pub static METRIC_SESSION_CLOSE_NUMBERS: &str = "session.close_numbers";
+pub static METRIC_SESSION_CONNECTIONS: &str = "session.connections";
+pub static METRIC_QUERY_QUEUE_WAIT_DURATION: &str = "session.query_queue_wait_duration";
+pub static METRIC_QUERY_QUEUE_REJECTED_NUMBERS: &str = "session.query_queue_rejected_numbers";
+
+const LABEL_KEY_HANDLER: &str = "handler";
+
+fn connection_labels(typ: &SessionType) -> Vec<(&'static str, String)> {
+    let config = SessionManager::instance().get_conf();
+    vec![
+        (LABEL_KEY_TENANT, config.query.tenant_id),
+        (LABEL_KEY_CLUSTER, config.query.cluster_id),
+        (LABEL_KEY_HANDLER, typ.to_string()),
+    ]
+}
+
+/// Counts the connected user sessions by their handlers, the internal sessions are not counted.
+pub fn inc_session_connections(typ: &SessionType) {
+    if typ.is_user_session() {
+        label_increment_gauge_with_val_and_labels(
+            METRIC_SESSION_CONNECTIONS,
+            connection_labels(typ),
+            1.0,
+        );
+    }
+}
+
+pub fn dec_session_connections(typ: &SessionType) {
+    if typ.is_user_session() {
+        label_decrement_gauge_with_val_and_labels(
+            METRIC_SESSION_CONNECTIONS,
+            connection_labels(typ),
+            1.0,
+        );
+    }
+}
@@ -12,28 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod background_tasks;
+mod compaction_scheduler;
+mod dictionary_cache;
 mod metrics;
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
+mod query_log_persister;
+mod query_queue;
+mod query_result_cache;
+mod resource_group_queues;
 mod session;
 mod session_ctx;
+mod session_expiry;
 mod session_info;
 mod session_mgr;
 mod session_mgr_status;
 mod session_settings;
 mod session_status;
 mod session_type;
+mod task_scheduler;
 
+pub use background_tasks::BackgroundJob;
+pub use background_tasks::BackgroundTask;
+pub use background_tasks::BackgroundTasks;
 pub use common_catalog::table_context::TableContext;
+pub use compaction_scheduler::CompactionScheduler;
+pub use compaction_scheduler::CompactionWindow;
+pub use compaction_scheduler::COMPACTION_SCHEDULER_TASK;
+pub use dictionary_cache::DictionaryCache;
+pub use dictionary_cache::LoadedDictionary;
 pub use query_affect::QueryAffect;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
+pub use query_log_persister::QueryLogPersister;
+pub use query_log_persister::QUERY_LOG_DATABASE;
+pub use query_log_persister::QUERY_LOG_FLUSH_TASK;
+pub use query_log_persister::QUERY_LOG_TABLE;
+pub use query_queue::QueryPermit;
+pub use query_queue::QueryQueue;
+pub use query_result_cache::QueryResultCache;
+pub use query_result_cache::QueryResultCacheKey;
+pub use query_result_cache::QueryResultCacheWriter;
+pub use resource_group_queues::ResourceGroupQueues;
 pub use session::Session;
 pub use session_ctx::SessionContext;
+pub use session_expiry::SessionExpiry;
+pub use session_expiry::SESSION_EXPIRY_TASK;
 pub use session_info::ProcessInfo;
 pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_settings::Settings;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use task_scheduler::TaskScheduler;
+pub use task_scheduler::TASK_SCHEDULER_TASK;
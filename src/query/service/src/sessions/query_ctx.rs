@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
@@ -19,18 +20,29 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
+use std::time::Duration;
 
 use chrono_tz::Tz;
 use common_base::base::tokio::task::JoinHandle;
+use common_base::base::MemoryTracker;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_base::base::TrySpawn;
+use common_catalog::table_context::BackgroundTaskInfo;
+use common_catalog::table_context::LoadedDictionaryInfo;
+use common_catalog::table_context::QueryCacheInfo;
+use common_catalog::txn::Transaction;
 use common_contexts::DalContext;
 use common_contexts::DalMetrics;
 use common_datablocks::DataBlock;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::DictionaryProvider;
 use common_functions::scalars::FunctionContext;
+use common_functions::scalars::SequenceGenerator;
 use common_io::prelude::FormatSettings;
 use common_legacy_expression::LegacyExpression;
 use common_legacy_planners::PartInfoPtr;
@@ -40,15 +52,21 @@ use common_legacy_planners::SourceInfo;
 use common_legacy_planners::StageTableInfo;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::UserInfo;
+use common_users::UserApiProvider;
 use opendal::Operator;
 use parking_lot::RwLock;
 use tracing::debug;
+use tracing::Span;
 
 use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::QuerySpill;
+use crate::pipelines::processors::QueryTempStage;
+use crate::pipelines::processors::SpillManager;
+use crate::pipelines::processors::SpillTarget;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -135,6 +153,18 @@ impl QueryContext {
         Ok(())
     }
 
+    /// Pins the table read by the statements of the context, see `replay_query()`.
+    pub fn pin_read_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        read_table: Arc<dyn Table>,
+    ) {
+        self.shared
+            .pin_read_table(catalog, database, table, read_table)
+    }
+
     pub fn get_exchange_manager(&self) -> Arc<DataExchangeManager> {
         DataExchangeManager::instance()
     }
@@ -161,6 +191,11 @@ impl QueryContext {
         SessionManager::instance().get_session_by_id(id)
     }
 
+    // Get the session running the query by query id.
+    pub fn get_session_by_query_id(self: &Arc<Self>, query_id: &str) -> Option<Arc<Session>> {
+        SessionManager::instance().get_session_by_query_id(&self.get_tenant(), query_id)
+    }
+
     // Get session id by mysql connection id.
     pub fn get_id_by_mysql_conn_id(self: &Arc<Self>, conn_id: &Option<u32>) -> Option<String> {
         SessionManager::instance().get_id_by_mysql_conn_id(conn_id)
@@ -187,6 +222,97 @@ impl QueryContext {
     pub fn set_executor(&self, weak_ptr: Weak<PipelineExecutor>) {
         self.shared.set_executor(weak_ptr)
     }
+
+    pub fn set_cluster(&self, cluster: Arc<Cluster>) {
+        self.shared.set_cluster(cluster)
+    }
+
+    pub fn get_scan_progress_total(&self) -> Arc<Progress> {
+        self.shared.scan_progress_total.clone()
+    }
+
+    pub fn get_spill_progress(&self) -> Arc<Progress> {
+        self.shared.spill_progress.clone()
+    }
+
+    pub fn get_spill_progress_value(&self) -> ProgressValues {
+        self.shared.spill_progress.as_ref().get_values()
+    }
+
+    /// The spill of the query, created as the query spills first.
+    pub fn get_query_spill(&self) -> Arc<QuerySpill> {
+        self.shared
+            .query_spill
+            .lock()
+            .get_or_insert_with(|| SpillManager::instance().create_query_spill(&self.get_id()))
+            .clone()
+    }
+
+    /// The temporary stage of the query in the storage, created as the query uses it first.
+    pub fn get_query_temp_stage(&self) -> Result<Arc<QueryTempStage>> {
+        let mut temp_stage = self.shared.query_temp_stage.lock();
+        if let Some(temp_stage) = temp_stage.as_ref() {
+            return Ok(temp_stage.clone());
+        }
+        let created = QueryTempStage::create(self.get_storage_operator()?, &self.get_id());
+        *temp_stage = Some(created.clone());
+        Ok(created)
+    }
+
+    /// Where the query spills to, the temporary stage of the query in the storage if
+    /// `enable_spill_to_storage` is set, otherwise the local disk.
+    pub fn get_spill_target(&self) -> Result<SpillTarget> {
+        match self.get_settings().get_enable_spill_to_storage()? {
+            true => Ok(SpillTarget::Storage(self.get_query_temp_stage()?)),
+            false => Ok(SpillTarget::Disk(self.get_query_spill())),
+        }
+    }
+
+    pub fn set_queued_time(&self, queued_time: Duration) {
+        *self.shared.queued_time.write() = queued_time;
+    }
+
+    pub fn get_queued_time(&self) -> Duration {
+        *self.shared.queued_time.read()
+    }
+
+    pub fn get_query_tag(&self) -> String {
+        self.shared.get_query_tag()
+    }
+
+    pub fn set_query_max_memory_usage(&self, max_memory_usage: u64) {
+        *self.shared.max_memory_usage.write() = Some(max_memory_usage);
+    }
+
+    pub fn get_query_max_memory_usage(&self) -> Option<u64> {
+        *self.shared.max_memory_usage.read()
+    }
+
+    pub fn get_memory_tracker(&self) -> Arc<MemoryTracker> {
+        self.shared.memory_tracker.clone()
+    }
+
+    pub fn set_query_plan(&self, plan: String) {
+        self.shared.set_query_plan(plan)
+    }
+
+    /// Set the variables of the script the query runs in, `$x` is resolved to the variable of the
+    /// script before the one of the session.
+    pub fn set_script_variables(&self, variables: HashMap<String, (DataValue, DataTypeImpl)>) {
+        *self.shared.script_variables.write() = variables;
+    }
+
+    pub fn get_query_plan(&self) -> Option<String> {
+        self.shared.get_query_plan()
+    }
+
+    pub fn get_query_elapsed(&self) -> Duration {
+        self.shared.get_elapsed()
+    }
+
+    pub fn get_processors_busy_time(&self) -> Vec<(&'static str, usize, u64)> {
+        self.shared.get_processors_busy_time()
+    }
 }
 
 #[async_trait::async_trait]
@@ -278,6 +404,14 @@ impl TableContext for QueryContext {
     fn set_current_user(&self, user: UserInfo) {
         self.shared.set_current_user(user)
     }
+    // The role set by `SET ROLE`, or the default role of the current user.
+    fn get_current_role(&self) -> Option<String> {
+        self.get_current_session().get_current_role().or_else(|| {
+            self.get_current_user()
+                .ok()
+                .and_then(|user| user.option.default_role().cloned())
+        })
+    }
     fn get_fuse_version(&self) -> String {
         self.version.clone()
     }
@@ -307,6 +441,10 @@ impl TableContext for QueryContext {
         self.shared.get_query_kind()
     }
 
+    fn get_query_span(&self) -> Span {
+        self.shared.query_span.clone()
+    }
+
     // Get the storage data accessor operator from the session manager.
     fn get_storage_operator(&self) -> Result<Operator> {
         let operator = self.shared.storage_operator.clone();
@@ -332,14 +470,50 @@ impl TableContext for QueryContext {
         let tz = tz.parse::<Tz>().map_err(|_| {
             ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
         })?;
-        Ok(FunctionContext { tz })
+        Ok(FunctionContext {
+            tz,
+            sequence_generator: Some(Arc::new(TenantSequenceGenerator {
+                tenant: self.get_tenant(),
+            })),
+            dictionary_provider: Some(Arc::new(TenantDictionaryProvider {
+                tenant: self.get_tenant(),
+            })),
+        })
     }
     fn get_connection_id(&self) -> String {
         self.shared.get_connection_id()
     }
+    fn get_variable(&self, name: &str) -> Option<(DataValue, DataTypeImpl)> {
+        if let Some(variable) = self.shared.script_variables.read().get(name) {
+            return Some(variable.clone());
+        }
+        self.get_current_session().get_variable(name)
+    }
     fn get_settings(&self) -> Arc<Settings> {
         self.shared.get_settings()
     }
+    fn set_query_setting(&self, name: String, value: String) -> Result<()> {
+        self.shared.set_query_setting(name, value)
+    }
+    fn get_transaction(&self) -> Option<Arc<Transaction>> {
+        self.get_current_session().get_transaction()
+    }
+
+    fn set_query_max_execution_time(&self, max_execution_time: Duration) {
+        *self.shared.max_execution_time.write() = Some(max_execution_time);
+    }
+
+    fn get_query_max_execution_time(&self) -> Option<Duration> {
+        *self.shared.max_execution_time.read()
+    }
+
+    fn set_cacheable(&self, cacheable: bool) {
+        *self.shared.cacheable.write() = cacheable;
+    }
+
+    fn get_cacheable(&self) -> bool {
+        *self.shared.cacheable.read()
+    }
 
     fn get_cluster(&self) -> Arc<Cluster> {
         self.shared.get_cluster()
@@ -361,10 +535,40 @@ impl TableContext for QueryContext {
         self.shared.get_table(catalog, database, table).await
     }
 
+    async fn get_read_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let ctx = QueryContext::create_from_shared(self.shared.clone());
+        self.shared
+            .get_read_table(ctx, catalog, database, table)
+            .await
+    }
+
+    fn reset_read_tables(&self) {
+        self.shared.reset_read_tables()
+    }
+
     // Get all the processes list info.
     fn get_processes_info(&self) -> Vec<ProcessInfo> {
         SessionManager::instance().processes_info()
     }
+
+    fn get_query_cache_info(&self) -> Vec<QueryCacheInfo> {
+        let cache = &SessionManager::instance().query_result_cache;
+        cache.entries_info(&self.get_tenant())
+    }
+
+    fn get_background_tasks_info(&self) -> Vec<BackgroundTaskInfo> {
+        SessionManager::instance().background_tasks.tasks_info()
+    }
+
+    fn get_loaded_dictionaries_info(&self) -> Vec<LoadedDictionaryInfo> {
+        let cache = &SessionManager::instance().dictionary_cache;
+        cache.loaded_info(&self.get_tenant())
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -384,3 +588,37 @@ impl std::fmt::Debug for QueryContext {
         write!(f, "{:?}", self.get_current_user())
     }
 }
+
+// The values of the sequences are taken from the batches allocated to the node.
+struct TenantSequenceGenerator {
+    tenant: String,
+}
+
+impl SequenceGenerator for TenantSequenceGenerator {
+    fn next_values(&self, name: &str, count: u64) -> Result<Vec<i64>> {
+        let user_mgr = UserApiProvider::instance();
+        futures::executor::block_on(user_mgr.next_sequence_values(&self.tenant, name, count))
+    }
+}
+
+// The dictionaries are looked up in the rows loaded on the node, they are loaded by the binder
+// of the query and mostly loaded here already.
+struct TenantDictionaryProvider {
+    tenant: String,
+}
+
+impl DictionaryProvider for TenantDictionaryProvider {
+    fn get_values(
+        &self,
+        dictionary: &str,
+        attribute: &str,
+        keys: &ColumnRef,
+    ) -> Result<Vec<Option<DataValue>>> {
+        let cache = &SessionManager::instance().dictionary_cache;
+        let loaded = match cache.get_loaded(&self.tenant, dictionary) {
+            Some(loaded) => loaded,
+            None => futures::executor::block_on(cache.get(&self.tenant, dictionary))?,
+        };
+        loaded.get_values(attribute, keys)
+    }
+}
@@ -14,12 +14,20 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
 
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::MemoryTracker;
 use common_base::base::Progress;
 use common_base::base::Runtime;
 use common_contexts::DalContext;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::UserInfo;
@@ -27,23 +35,44 @@ use common_storage::StorageOperator;
 use opendal::Operator;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
+use tracing::info_span;
+use tracing::Span;
 use uuid::Uuid;
 
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManager;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::Cluster;
+use crate::connections::resolve_table_connection;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::QuerySpill;
+use crate::pipelines::processors::QueryTempStage;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
 use crate::sessions::Settings;
+use crate::sessions::TableContext;
+use crate::sessions::QUERY_LOG_DATABASE;
+use crate::sessions::QUERY_LOG_TABLE;
 use crate::sql::SQLCommon;
+use crate::storages::fuse::FuseTable;
 use crate::storages::Table;
 use crate::Config;
 
 type DatabaseAndTable = (String, String, String);
 
+/// The tables read by the statement. They are read as of the time point taken when the first of
+/// them is resolved, so the statement reads a consistent snapshot of all of them, even if the
+/// concurrent inserts land while it's bound.
+#[derive(Default)]
+pub(in crate::sessions) struct SnapshotRegistry {
+    time_point: Option<DateTime<Utc>>,
+    tables: HashMap<DatabaseAndTable, Arc<dyn Table>>,
+    // The tables pinned to be read by all the statements of the context, e.g. the snapshots of
+    // the replayed query.
+    pinned: HashMap<DatabaseAndTable, Arc<dyn Table>>,
+}
+
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
 ///     USE database_1;
@@ -57,19 +86,52 @@ pub struct QueryContextShared {
     pub(in crate::sessions) config: Config,
     /// scan_progress for scan metrics of datablocks (uncompressed)
     pub(in crate::sessions) scan_progress: Arc<Progress>,
+    /// scan_progress_total for the estimated rows and bytes to scan, by the partitions to read
+    pub(in crate::sessions) scan_progress_total: Arc<Progress>,
     /// write_progress for write/commit metrics of datablocks (uncompressed)
     pub(in crate::sessions) write_progress: Arc<Progress>,
     /// result_progress for metrics of result datablocks (uncompressed)
     pub(in crate::sessions) result_progress: Arc<Progress>,
+    /// spill_progress for metrics of datablocks spilled to the disk (serialized)
+    pub(in crate::sessions) spill_progress: Arc<Progress>,
+    /// query_spill for the files spilled by the query, removed as the query ends
+    pub(in crate::sessions) query_spill: Arc<Mutex<Option<Arc<QuerySpill>>>>,
+    /// query_temp_stage for the intermediate results of the query in the storage, removed as
+    /// the query ends
+    pub(in crate::sessions) query_temp_stage: Arc<Mutex<Option<Arc<QueryTempStage>>>>,
+    /// queued_time for the time the query waited in the query queue
+    pub(in crate::sessions) queued_time: Arc<RwLock<Duration>>,
+    /// created_time for the elapsed time of the query
+    pub(in crate::sessions) created_time: Instant,
+    /// query_span for the root of the spans of the query, which is exported with the trace
+    pub(in crate::sessions) query_span: Span,
+    /// settings of the query changed by the hints, instead of the ones of the session
+    pub(in crate::sessions) query_settings: Arc<RwLock<Option<Arc<Settings>>>>,
+    /// max_execution_time of the query by the hint, instead of the max_execute_time of the settings
+    pub(in crate::sessions) max_execution_time: Arc<RwLock<Option<Duration>>>,
+    /// max_memory_usage of the query by the resource group, instead of the one of the settings
+    pub(in crate::sessions) max_memory_usage: Arc<RwLock<Option<u64>>>,
+    /// memory_tracker for the memory of all the executors of the query, limited by max_memory_usage
+    pub(in crate::sessions) memory_tracker: Arc<MemoryTracker>,
+    /// script_variables for the variables of the script the query runs in, shadowing the
+    /// variables of the session
+    pub(in crate::sessions) script_variables:
+        Arc<RwLock<HashMap<String, (DataValue, DataTypeImpl)>>>,
+    /// cacheable unless the query calls the non-deterministic functions
+    pub(in crate::sessions) cacheable: Arc<RwLock<bool>>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
-    pub(in crate::sessions) cluster_cache: Arc<Cluster>,
+    pub(in crate::sessions) cluster_cache: Arc<RwLock<Arc<Cluster>>>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<String>>>,
+    /// running_query_plan for the explained plan of the query, captured for the slow query log
+    pub(in crate::sessions) running_query_plan: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) http_query: Arc<RwLock<Option<HttpQueryHandle>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    /// snapshot_registry for the tables read by the statement, as of the same point in time
+    pub(in crate::sessions) snapshot_registry: Arc<Mutex<SnapshotRegistry>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
     pub(in crate::sessions) auth_manager: Arc<AuthMgr>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
@@ -84,22 +146,44 @@ impl QueryContextShared {
         session: Arc<Session>,
         cluster_cache: Arc<Cluster>,
     ) -> Result<Arc<QueryContextShared>> {
+        let query_id = Uuid::new_v4().to_string();
+        let query_tag = session.get_query_tag(&session.get_settings());
+        let query_span = info_span!(
+            "query",
+            query_id = query_id.as_str(),
+            query_tag = query_tag.as_str()
+        );
         Ok(Arc::new(QueryContextShared {
             session,
-            cluster_cache,
+            cluster_cache: Arc::new(RwLock::new(cluster_cache)),
             config: config.clone(),
             catalog_manager: CatalogManager::instance(),
             storage_operator: StorageOperator::instance(),
-            init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
+            init_query_id: Arc::new(RwLock::new(query_id)),
             scan_progress: Arc::new(Progress::create()),
+            scan_progress_total: Arc::new(Progress::create()),
             result_progress: Arc::new(Progress::create()),
             write_progress: Arc::new(Progress::create()),
+            spill_progress: Arc::new(Progress::create()),
+            query_spill: Arc::new(Mutex::new(None)),
+            query_temp_stage: Arc::new(Mutex::new(None)),
+            queued_time: Arc::new(RwLock::new(Duration::default())),
+            created_time: Instant::now(),
+            query_span,
+            query_settings: Arc::new(RwLock::new(None)),
+            max_execution_time: Arc::new(RwLock::new(None)),
+            max_memory_usage: Arc::new(RwLock::new(None)),
+            memory_tracker: MemoryTracker::create(MemoryTracker::current()),
+            script_variables: Arc::new(RwLock::new(HashMap::new())),
+            cacheable: Arc::new(RwLock::new(true)),
             error: Arc::new(Mutex::new(None)),
             runtime: Arc::new(RwLock::new(None)),
             running_query: Arc::new(RwLock::new(None)),
             running_query_kind: Arc::new(RwLock::new(None)),
+            running_query_plan: Arc::new(RwLock::new(None)),
             http_query: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            snapshot_registry: Arc::new(Mutex::new(SnapshotRegistry::default())),
             dal_ctx: Arc::new(Default::default()),
             auth_manager: AuthMgr::create(config).await?,
             affect: Arc::new(Mutex::new(None)),
@@ -123,7 +207,11 @@ impl QueryContextShared {
     }
 
     pub fn get_cluster(&self) -> Arc<Cluster> {
-        self.cluster_cache.clone()
+        self.cluster_cache.read().clone()
+    }
+
+    pub fn set_cluster(&self, cluster: Arc<Cluster>) {
+        *self.cluster_cache.write() = cluster;
     }
 
     pub fn get_current_catalog(&self) -> String {
@@ -159,11 +247,21 @@ impl QueryContextShared {
     }
 
     pub fn get_settings(&self) -> Arc<Settings> {
-        self.session.get_settings()
+        match &*self.query_settings.read() {
+            Some(settings) => settings.clone(),
+            None => self.session.get_settings(),
+        }
+    }
+
+    pub fn set_query_setting(&self, name: String, value: String) -> Result<()> {
+        let mut query_settings = self.query_settings.write();
+        query_settings
+            .get_or_insert_with(|| self.session.get_settings().duplicate())
+            .set_settings(name, value, false)
     }
 
     pub fn get_changed_settings(&self) -> Arc<Settings> {
-        self.session.get_changed_settings()
+        Arc::new(self.get_settings().get_changed_settings())
     }
 
     pub fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()> {
@@ -192,16 +290,77 @@ impl QueryContextShared {
         }
     }
 
+    /// The table read by the statement, as of the time point of the statement if it's mutated
+    /// after then. Unlike `get_table()`, the table is resolved again by the next statement of
+    /// the context. The tables mutated by the statement are the ones of `get_table()`, since the
+    /// historical tables are read-only.
+    pub async fn get_read_table(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let time_point = {
+            let mut snapshot_registry = self.snapshot_registry.lock();
+            if let Some(read_table) = snapshot_registry.pinned.get(&table_meta_key) {
+                return Ok(read_table.clone());
+            }
+            if let Some(read_table) = snapshot_registry.tables.get(&table_meta_key) {
+                return Ok(read_table.clone());
+            }
+            *snapshot_registry.time_point.get_or_insert_with(Utc::now)
+        };
+
+        let mut read_table = self.resolve_table(catalog, database, table).await?;
+        if let Ok(fuse_table) = FuseTable::try_from_table(read_table.as_ref()) {
+            if let Some(historical_table) = fuse_table
+                .navigate_to_time_point_if_newer(ctx, time_point)
+                .await?
+            {
+                read_table = historical_table;
+            }
+        }
+
+        let mut snapshot_registry = self.snapshot_registry.lock();
+        Ok(snapshot_registry
+            .tables
+            .entry(table_meta_key)
+            .or_insert(read_table)
+            .clone())
+    }
+
+    pub fn reset_read_tables(&self) {
+        let mut snapshot_registry = self.snapshot_registry.lock();
+        snapshot_registry.time_point = None;
+        snapshot_registry.tables.clear();
+    }
+
+    /// Pins the table read by the statements of the context, instead of the one resolved by
+    /// `get_read_table()`.
+    pub fn pin_read_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        read_table: Arc<dyn Table>,
+    ) {
+        let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        self.snapshot_registry
+            .lock()
+            .pinned
+            .insert(table_meta_key, read_table);
+    }
+
     async fn get_table_to_cache(
         &self,
         catalog: &str,
         database: &str,
         table: &str,
     ) -> Result<Arc<dyn Table>> {
-        let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
-        let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let cache_table = self.resolve_table(catalog, database, table).await?;
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -211,6 +370,43 @@ impl QueryContextShared {
         }
     }
 
+    // Resolve the latest table of the name, which is not cached.
+    async fn resolve_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let tenant = self.get_tenant();
+        // The temporary tables of the session hide the tables of the same names.
+        if let Some(temporary_table) = self.session.get_temporary_table(catalog, database, table) {
+            return Ok(temporary_table);
+        }
+        let catalog = self.catalog_manager.get_catalog(catalog)?;
+        let mut resolved_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // The storages of the tables referencing connections are resolved each time they are read.
+        resolved_table =
+            resolve_table_connection(&self.config, &tenant, catalog.as_ref(), resolved_table)
+                .await?;
+        // The query log is read from its persistent table once the events are written to it.
+        if self.config.query.query_log_persistent_enabled
+            && database == "system"
+            && table == QUERY_LOG_TABLE
+        {
+            if let Ok(persistent_table) = catalog
+                .get_table(tenant.as_str(), QUERY_LOG_DATABASE, QUERY_LOG_TABLE)
+                .await
+            {
+                resolved_table = persistent_table;
+            }
+        }
+        // The statements of a transaction read the tables staged by it.
+        if let Some(txn) = self.session.get_transaction() {
+            resolved_table = txn.resolve_table(catalog.as_ref(), resolved_table)?;
+        }
+        Ok(resolved_table)
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -262,6 +458,14 @@ impl QueryContextShared {
             .unwrap_or_else(|| "Unknown".to_string())
     }
 
+    pub fn set_query_plan(&self, plan: String) {
+        *self.running_query_plan.write() = Some(plan);
+    }
+
+    pub fn get_query_plan(&self) -> Option<String> {
+        self.running_query_plan.read().clone()
+    }
+
     pub fn get_config(&self) -> Config {
         self.config.clone()
     }
@@ -284,4 +488,39 @@ impl QueryContextShared {
         let mut executor = self.executor.write();
         *executor = weak_ptr;
     }
+
+    pub fn get_elapsed(&self) -> Duration {
+        self.created_time.elapsed()
+    }
+
+    /// The tag of the query, by the settings of the query or else the user.
+    pub fn get_query_tag(&self) -> String {
+        self.session.get_query_tag(&self.get_settings())
+    }
+
+    /// The processors being executed, `None` if the query is not in the executor.
+    pub fn get_pipeline_stage(&self) -> Option<String> {
+        let executor = self.executor.read().upgrade()?;
+        match executor.is_finished() {
+            true => None,
+            false => Some(executor.processing_processors().join(", ")),
+        }
+    }
+
+    /// The busy time of the processors by their names, empty if the query is not in the executor.
+    pub fn get_processors_busy_time(&self) -> Vec<(&'static str, usize, u64)> {
+        match self.executor.read().upgrade() {
+            None => vec![],
+            Some(executor) => executor.processors_busy_time(),
+        }
+    }
+
+    /// The ids of the processor threads of the query, `None` if the query is not in the executor.
+    pub fn get_executor_threads(&self) -> Option<HashSet<u64>> {
+        let executor = self.executor.read().upgrade()?;
+        match executor.is_finished() {
+            true => None,
+            false => Some(executor.worker_threads()),
+        }
+    }
 }
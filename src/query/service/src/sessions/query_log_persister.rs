@@ -0,0 +1,277 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::DataType;
+use common_datavalues::MutableColumn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_storages_preludes::system::QueryLogElement;
+use common_storages_preludes::system::SystemLogElement;
+use parking_lot::Mutex;
+use tracing::error;
+
+use crate::interpreters::append2table;
+use crate::interpreters::execute_pipeline;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::BlocksSource;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::BackgroundJob;
+use crate::sessions::BackgroundTask;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::storages::fuse::OPT_KEY_DATABASE_ID;
+use crate::storages::Table;
+use crate::Config;
+
+/// The database of the persistent query log, created in each tenant on the first write.
+pub const QUERY_LOG_DATABASE: &str = "system_history";
+pub const QUERY_LOG_TABLE: &str = "query_log";
+/// The background task flushing the persistent query log.
+pub const QUERY_LOG_FLUSH_TASK: &str = "query_log_flush";
+
+/// Buffers the events of the query log and writes them to the `system_history.query_log` fuse
+/// table of their tenants in batches, in the background, so the queries don't wait for the writes.
+pub struct QueryLogPersister {
+    enabled: bool,
+    batch_rows: usize,
+    events: Mutex<Vec<QueryLogElement>>,
+    task: Arc<BackgroundTask>,
+}
+
+impl QueryLogPersister {
+    pub fn create(
+        enabled: bool,
+        flush_interval: Duration,
+        batch_rows: usize,
+    ) -> Arc<QueryLogPersister> {
+        Arc::new_cyclic(|persister| {
+            let job: Weak<dyn BackgroundJob> = persister.clone();
+            QueryLogPersister {
+                enabled,
+                batch_rows: batch_rows.max(1),
+                events: Mutex::new(vec![]),
+                task: BackgroundTask::create(QUERY_LOG_FLUSH_TASK, "Flush", flush_interval, job),
+            }
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<QueryLogPersister> {
+        QueryLogPersister::create(
+            conf.query.query_log_persistent_enabled,
+            Duration::from_secs(conf.query.query_log_flush_interval_secs),
+            conf.query.query_log_flush_batch_rows as usize,
+        )
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Buffers the event, the flush is woken up once the batch is full.
+    pub fn append(&self, event: &QueryLogElement) {
+        if !self.enabled {
+            return;
+        }
+
+        let full = {
+            let mut events = self.events.lock();
+            events.push(event.clone());
+            events.len() >= self.batch_rows
+        };
+
+        if full {
+            self.task.wake();
+        }
+    }
+
+    /// Takes the buffered events, grouped by their tenants.
+    pub fn take_events(&self) -> BTreeMap<String, Vec<QueryLogElement>> {
+        let events = std::mem::take(&mut *self.events.lock());
+
+        let mut tenant_events = BTreeMap::<String, Vec<QueryLogElement>>::new();
+        for event in events {
+            tenant_events
+                .entry(event.tenant_id.clone())
+                .or_default()
+                .push(event);
+        }
+        tenant_events
+    }
+
+    /// The task flushing the buffered events every flush interval, or earlier once the batch is
+    /// full, `None` if the persistent query log is disabled.
+    pub fn task(&self) -> Option<Arc<BackgroundTask>> {
+        match self.enabled {
+            true => Some(self.task.clone()),
+            false => None,
+        }
+    }
+
+    /// Writes the buffered events, the events failed to write are dropped with an error logged,
+    /// they are still kept in the in-memory query log.
+    pub async fn flush(&self) -> Result<usize> {
+        let mut written_rows = 0;
+        let mut last_cause = None;
+        for (tenant, events) in self.take_events() {
+            let rows = events.len();
+            match Self::write_events(&tenant, &events).await {
+                Ok(_) => written_rows += rows,
+                Err(cause) => {
+                    error!(
+                        "fail to persist {} events of query_log of tenant {}: {:?}",
+                        rows, tenant, cause
+                    );
+                    last_cause = Some(cause);
+                }
+            }
+        }
+
+        match last_cause {
+            None => Ok(written_rows),
+            Some(cause) => Err(cause),
+        }
+    }
+
+    async fn write_events(tenant: &str, events: &[QueryLogElement]) -> Result<()> {
+        // The writes are not user queries, they are neither queued nor logged in the query log.
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("PersistQueryLog".to_string()))
+            .await?;
+        session.set_current_tenant(tenant.to_string());
+        let ctx = session.create_query_context().await?;
+        let table = Self::get_or_create_table(&ctx).await?;
+
+        let output = OutputPort::create();
+        let blocks = Arc::new(Mutex::new(VecDeque::from(vec![to_data_block(events)?])));
+        let source = BlocksSource::create(ctx.clone(), output.clone(), blocks)?;
+        let mut builder = SourcePipeBuilder::create();
+        builder.add_source(output, source);
+
+        let mut build_res = PipelineBuildResult::create();
+        build_res.main_pipeline.add_pipe(builder.finalize());
+        append2table(
+            ctx.clone(),
+            table,
+            QueryLogElement::schema(),
+            &mut build_res,
+            false,
+            true,
+        )?;
+        execute_pipeline(ctx, build_res)
+    }
+
+    async fn get_or_create_table(ctx: &Arc<QueryContext>) -> Result<Arc<dyn Table>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        match catalog
+            .get_table(&tenant, QUERY_LOG_DATABASE, QUERY_LOG_TABLE)
+            .await
+        {
+            Err(cause) if cause.code() == ErrorCode::UnknownDatabase("").code() => {}
+            Err(cause) if cause.code() == ErrorCode::UnknownTable("").code() => {}
+            res => return res,
+        }
+
+        catalog
+            .create_database(CreateDatabaseReq {
+                if_not_exists: true,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: QUERY_LOG_DATABASE.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..Default::default()
+                },
+            })
+            .await?;
+
+        let database = catalog.get_database(&tenant, QUERY_LOG_DATABASE).await?;
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_DATABASE_ID.to_string(),
+            database.get_db_info().ident.db_id.to_string(),
+        );
+
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: true,
+                name_ident: TableNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: QUERY_LOG_DATABASE.to_string(),
+                    table_name: QUERY_LOG_TABLE.to_string(),
+                },
+                table_meta: TableMeta {
+                    schema: QueryLogElement::schema(),
+                    engine: "FUSE".to_string(),
+                    options,
+                    ..Default::default()
+                },
+            })
+            .await?;
+
+        catalog
+            .get_table(&tenant, QUERY_LOG_DATABASE, QUERY_LOG_TABLE)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundJob for QueryLogPersister {
+    async fn run(&self) -> Result<String> {
+        let written_rows = self.flush().await?;
+        Ok(format!("{} rows written", written_rows))
+    }
+
+    fn progress(&self) -> String {
+        format!("{} rows buffered", self.events.lock().len())
+    }
+}
+
+/// The block of the events, in the schema of the query log.
+fn to_data_block(events: &[QueryLogElement]) -> Result<DataBlock> {
+    let schema = QueryLogElement::schema();
+    let mut mutable_columns: Vec<Box<dyn MutableColumn>> = schema
+        .fields()
+        .iter()
+        .map(|field| field.data_type().create_mutable(events.len()))
+        .collect();
+
+    for event in events {
+        event.fill_to_data_block(&mut mutable_columns)?;
+    }
+
+    let columns = mutable_columns
+        .iter_mut()
+        .map(|column| column.to_column())
+        .collect();
+    Ok(DataBlock::create(schema, columns))
+}
@@ -0,0 +1,247 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metrics::label_counter;
+use metrics::histogram;
+use parking_lot::Mutex;
+
+use super::metrics::METRIC_QUERY_QUEUE_REJECTED_NUMBERS;
+use super::metrics::METRIC_QUERY_QUEUE_WAIT_DURATION;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::Config;
+
+#[derive(Default)]
+struct QueryQueueState {
+    running_queries: usize,
+    reserved_memory: u64,
+    next_ticket: u64,
+    queued_tickets: VecDeque<u64>,
+}
+
+/// Admits the queries to run in the order they arrive, while the running queries and the memory
+/// reserved by them are under the limits, the other queries wait in the bounded queue.
+pub struct QueryQueue {
+    max_running_queries: usize,
+    max_reserved_memory: u64,
+    max_queued_queries: usize,
+    timeout: Duration,
+    state: Mutex<QueryQueueState>,
+    notify: Notify,
+}
+
+impl QueryQueue {
+    pub fn create(
+        max_running_queries: usize,
+        max_reserved_memory: u64,
+        max_queued_queries: usize,
+        timeout: Duration,
+    ) -> Arc<QueryQueue> {
+        Arc::new(QueryQueue {
+            max_running_queries,
+            max_reserved_memory,
+            max_queued_queries,
+            timeout,
+            state: Mutex::new(QueryQueueState::default()),
+            notify: Notify::new(),
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<QueryQueue> {
+        QueryQueue::create(
+            conf.query.max_running_queries as usize,
+            conf.query.max_reserved_memory_usage,
+            conf.query.max_queued_queries as usize,
+            Duration::from_secs(conf.query.query_queue_timeout_secs),
+        )
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_running_queries != 0 || self.max_reserved_memory != 0
+    }
+
+    pub fn running_queries(&self) -> usize {
+        self.state.lock().running_queries
+    }
+
+    pub fn queued_queries(&self) -> usize {
+        self.state.lock().queued_tickets.len()
+    }
+
+    /// Waits until the query is admitted to run, the permit releases its reservation when dropped.
+    /// The query without the max_memory_usage (zero) reserves all the max_reserved_memory_usage.
+    pub async fn acquire(self: &Arc<Self>, max_memory_usage: u64) -> Result<QueryPermit> {
+        let instant = Instant::now();
+        let reserved_memory = match max_memory_usage {
+            0 => self.max_reserved_memory,
+            _ => max_memory_usage.min(self.max_reserved_memory),
+        };
+
+        let ticket = {
+            let mut state = self.state.lock();
+            if state.queued_tickets.is_empty() && self.can_admit(&state, reserved_memory) {
+                return Ok(self.admit(&mut state, reserved_memory, instant));
+            }
+
+            if state.queued_tickets.len() >= self.max_queued_queries {
+                return Err(ErrorCode::QueryQueueFull(format!(
+                    "The query is rejected, because {} queries are running and {} queries are waiting, exceeds the max_queued_queries {}",
+                    state.running_queries,
+                    state.queued_tickets.len(),
+                    self.max_queued_queries
+                )));
+            }
+
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.queued_tickets.push_back(ticket);
+            QueuedTicket {
+                queue: self.clone(),
+                ticket,
+            }
+        };
+
+        let deadline = tokio::time::Instant::from_std(instant + self.timeout);
+        loop {
+            // Registered before checking the state, so the releases in between are not missed.
+            let notified = self.notify.notified();
+            {
+                let mut state = self.state.lock();
+                if state.queued_tickets.front() == Some(&ticket.ticket)
+                    && self.can_admit(&state, reserved_memory)
+                {
+                    state.queued_tickets.pop_front();
+                    // The dropped ticket wakes the next query in the queue, it may be admitted as well.
+                    return Ok(self.admit(&mut state, reserved_memory, instant));
+                }
+            }
+
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return Err(ErrorCode::QueryQueueTimeout(format!(
+                    "The query is rejected, because it waits to run more than the query_queue_timeout_secs {}",
+                    self.timeout.as_secs()
+                )));
+            }
+        }
+    }
+
+    /// Admits the query of the user session, the queued time is reported in the query log, it's
+    /// accumulated if the query waits in the queue of its resource group as well.
+    pub async fn acquire_for_query(
+        self: &Arc<Self>,
+        ctx: &QueryContext,
+    ) -> Result<Option<QueryPermit>> {
+        if !self.is_enabled() || !ctx.get_current_session().get_type().is_user_session() {
+            return Ok(None);
+        }
+
+        let max_memory_usage = ctx.get_settings().get_max_memory_usage()?;
+        match self.acquire(max_memory_usage).await {
+            Ok(permit) => {
+                ctx.set_queued_time(ctx.get_queued_time() + permit.queued_time());
+                histogram!(METRIC_QUERY_QUEUE_WAIT_DURATION, permit.queued_time());
+                Ok(Some(permit))
+            }
+            Err(cause) => {
+                let tenant_id = ctx.get_tenant();
+                let cluster_id = ctx.get_config().query.cluster_id;
+                label_counter(METRIC_QUERY_QUEUE_REJECTED_NUMBERS, &tenant_id, &cluster_id);
+                Err(cause)
+            }
+        }
+    }
+
+    fn can_admit(&self, state: &QueryQueueState, reserved_memory: u64) -> bool {
+        if self.max_running_queries != 0 && state.running_queries >= self.max_running_queries {
+            return false;
+        }
+
+        // A query always runs if nothing is reserved, even it reserves more than the limit.
+        self.max_reserved_memory == 0
+            || state.reserved_memory == 0
+            || state.reserved_memory + reserved_memory <= self.max_reserved_memory
+    }
+
+    fn admit(
+        self: &Arc<Self>,
+        state: &mut QueryQueueState,
+        reserved_memory: u64,
+        instant: Instant,
+    ) -> QueryPermit {
+        state.running_queries += 1;
+        state.reserved_memory += reserved_memory;
+        QueryPermit {
+            queue: self.clone(),
+            reserved_memory,
+            queued_time: instant.elapsed(),
+        }
+    }
+}
+
+/// Removes the ticket from the queue if the waiting is cancelled or timed out.
+struct QueuedTicket {
+    queue: Arc<QueryQueue>,
+    ticket: u64,
+}
+
+impl Drop for QueuedTicket {
+    fn drop(&mut self) {
+        {
+            let mut state = self.queue.state.lock();
+            state.queued_tickets.retain(|ticket| *ticket != self.ticket);
+        }
+
+        self.queue.notify.notify_waiters();
+    }
+}
+
+/// The permit of the admitted query, it's held until the query is finished.
+pub struct QueryPermit {
+    queue: Arc<QueryQueue>,
+    reserved_memory: u64,
+    queued_time: Duration,
+}
+
+impl QueryPermit {
+    /// The time the query waited in the queue.
+    pub fn queued_time(&self) -> Duration {
+        self.queued_time
+    }
+
+    /// The memory reserved for the query in the queue, zero if the queue limits no memory.
+    pub fn reserved_memory(&self) -> u64 {
+        self.reserved_memory
+    }
+}
+
+impl Drop for QueryPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.queue.state.lock();
+            state.running_queries -= 1;
+            state.reserved_memory -= self.reserved_memory;
+        }
+
+        self.queue.notify.notify_waiters();
+    }
+}
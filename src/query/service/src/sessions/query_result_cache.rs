@@ -0,0 +1,324 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_catalog::table_context::QueryCacheInfo;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planner::MetadataRef;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_MASKING_POLICIES;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY;
+use crate::storages::fuse::OPT_KEY_SNAPSHOT_LOCATION;
+use crate::Config;
+
+/// The identical queries have the same key, the key changes once a table of the query is changed,
+/// because the tables of the fuse engine have a new snapshot for each change of the data, and a
+/// new seq of the table meta for each change of the table.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QueryResultCacheKey {
+    pub tenant: String,
+    pub user: String,
+    pub role: Option<String>,
+    pub database: String,
+    pub query: String,
+    pub settings: Vec<String>,
+    pub snapshots: Vec<String>,
+}
+
+impl QueryResultCacheKey {
+    /// The key of the formatted query, `None` if the query reads the tables of the other engines,
+    /// whose changes are not tracked by the snapshots, or the tables with the row access policy
+    /// or the masking policies, which may be altered without changing the tables.
+    pub fn try_create(
+        ctx: &QueryContext,
+        query: &str,
+        metadata: &MetadataRef,
+    ) -> Result<Option<QueryResultCacheKey>> {
+        let mut snapshots = vec![];
+        for table in metadata.read().tables() {
+            let table_ref = table.table();
+            let table_info = table_ref.get_table_info();
+            if table_info.engine() != "FUSE" {
+                return Ok(None);
+            }
+
+            let options = table_info.options();
+            if options.contains_key(OPT_KEY_ROW_ACCESS_POLICY)
+                || options.contains_key(OPT_KEY_MASKING_POLICIES)
+            {
+                return Ok(None);
+            }
+
+            let snapshot = options.get(OPT_KEY_SNAPSHOT_LOCATION);
+            snapshots.push(format!(
+                "{}.{}.{}#{}@{}",
+                table.catalog(),
+                table.database(),
+                table.name(),
+                table_info.ident.seq,
+                snapshot.cloned().unwrap_or_default()
+            ));
+        }
+
+        let settings = ctx
+            .get_settings()
+            .get_setting_values()
+            .into_iter()
+            .map(|(name, value, ..)| format!("{}={:?}", name, value))
+            .collect();
+
+        Ok(Some(QueryResultCacheKey {
+            tenant: ctx.get_tenant(),
+            user: ctx.get_current_user()?.identity().to_string(),
+            role: ctx.get_current_role(),
+            database: ctx.get_current_database(),
+            query: query.to_string(),
+            settings,
+            snapshots,
+        }))
+    }
+}
+
+struct QueryResultCacheEntry {
+    query_id: String,
+    query: String,
+    blocks: Vec<DataBlock>,
+    num_rows: usize,
+    result_bytes: usize,
+    hits: AtomicU64,
+    created_on: i64,
+    expires_on: i64,
+}
+
+#[derive(Default)]
+struct QueryResultCacheState {
+    entries: HashMap<QueryResultCacheKey, Arc<QueryResultCacheEntry>>,
+    bytes: usize,
+}
+
+/// Caches the result blocks of the queries in memory, the results expire after the ttl of the
+/// query, the oldest results are evicted if the memory exceeds the max bytes.
+pub struct QueryResultCache {
+    max_bytes: usize,
+    state: Mutex<QueryResultCacheState>,
+}
+
+impl QueryResultCache {
+    pub fn create(max_bytes: usize) -> Arc<QueryResultCache> {
+        Arc::new(QueryResultCache {
+            max_bytes,
+            state: Mutex::new(QueryResultCacheState::default()),
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<QueryResultCache> {
+        QueryResultCache::create(conf.query.query_result_cache_max_bytes as usize)
+    }
+
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// The cached blocks of the key, `None` if it's not cached or expired.
+    pub fn get(&self, key: &QueryResultCacheKey) -> Option<Vec<DataBlock>> {
+        let mut state = self.state.lock();
+        Self::remove_expired(&mut state, now_micros());
+
+        state.entries.get(key).map(|entry| {
+            entry.hits.fetch_add(1, Ordering::Relaxed);
+            entry.blocks.clone()
+        })
+    }
+
+    /// Caches the blocks of the key, the result larger than the max bytes is not cached.
+    pub fn put(
+        &self,
+        key: QueryResultCacheKey,
+        query_id: String,
+        blocks: Vec<DataBlock>,
+        ttl: Duration,
+    ) {
+        let result_bytes = blocks
+            .iter()
+            .map(|block| block.memory_size())
+            .sum::<usize>();
+        if result_bytes > self.max_bytes || ttl.is_zero() {
+            return;
+        }
+
+        let created_on = now_micros();
+        let mut state = self.state.lock();
+        Self::remove_expired(&mut state, created_on);
+        if let Some(entry) = state.entries.remove(&key) {
+            state.bytes -= entry.result_bytes;
+        }
+
+        while state.bytes + result_bytes > self.max_bytes {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.created_on)
+                .map(|(key, _)| key.clone());
+
+            match oldest.and_then(|oldest| state.entries.remove(&oldest)) {
+                None => break,
+                Some(entry) => state.bytes -= entry.result_bytes,
+            }
+        }
+
+        let entry = QueryResultCacheEntry {
+            query_id,
+            query: key.query.clone(),
+            num_rows: blocks.iter().map(|block| block.num_rows()).sum(),
+            blocks,
+            result_bytes,
+            hits: AtomicU64::new(0),
+            created_on,
+            expires_on: created_on + ttl.as_micros() as i64,
+        };
+        state.bytes += result_bytes;
+        state.entries.insert(key, Arc::new(entry));
+    }
+
+    /// The results cached for the tenant, which are not expired.
+    pub fn entries_info(&self, tenant: &str) -> Vec<QueryCacheInfo> {
+        let mut state = self.state.lock();
+        Self::remove_expired(&mut state, now_micros());
+
+        state
+            .entries
+            .iter()
+            .filter(|(key, _)| key.tenant == tenant)
+            .map(|(_, entry)| QueryCacheInfo {
+                query_id: entry.query_id.clone(),
+                query: entry.query.clone(),
+                num_rows: entry.num_rows as u64,
+                result_bytes: entry.result_bytes as u64,
+                hits: entry.hits.load(Ordering::Relaxed),
+                created_on: entry.created_on,
+                expires_on: entry.expires_on,
+            })
+            .collect()
+    }
+
+    fn remove_expired(state: &mut QueryResultCacheState, now: i64) {
+        let mut bytes = state.bytes;
+        state.entries.retain(|_, entry| {
+            let expired = entry.expires_on <= now;
+            if expired {
+                bytes -= entry.result_bytes;
+            }
+            !expired
+        });
+        state.bytes = bytes;
+    }
+}
+
+#[derive(Default)]
+struct QueryResultCacheWriterState {
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+    unfinished_inputs: usize,
+    discarded: bool,
+}
+
+/// Collects the result blocks of the query from the outputs of its pipeline, the result is cached
+/// only if all the outputs are read to the end and it doesn't exceed the max bytes of the cache.
+pub struct QueryResultCacheWriter {
+    cache: Arc<QueryResultCache>,
+    key: QueryResultCacheKey,
+    query_id: String,
+    ttl: Duration,
+    state: Mutex<QueryResultCacheWriterState>,
+}
+
+impl QueryResultCacheWriter {
+    pub fn create(
+        cache: Arc<QueryResultCache>,
+        key: QueryResultCacheKey,
+        query_id: String,
+        ttl: Duration,
+        inputs: usize,
+    ) -> Arc<QueryResultCacheWriter> {
+        Arc::new(QueryResultCacheWriter {
+            cache,
+            key,
+            query_id,
+            ttl,
+            state: Mutex::new(QueryResultCacheWriterState {
+                unfinished_inputs: inputs,
+                ..Default::default()
+            }),
+        })
+    }
+
+    pub fn append(&self, block: &DataBlock) {
+        let mut state = self.state.lock();
+        if state.discarded {
+            return;
+        }
+
+        state.bytes += block.memory_size();
+        if state.bytes > self.cache.max_bytes() {
+            state.discarded = true;
+            state.blocks.clear();
+            return;
+        }
+
+        state.blocks.push(block.clone());
+    }
+
+    /// The input is read to the end.
+    pub fn finish_input(&self) {
+        let mut state = self.state.lock();
+        state.unfinished_inputs = state.unfinished_inputs.saturating_sub(1);
+    }
+
+    /// The output is finished before the input, e.g. the client stops reading the result.
+    pub fn abort(&self) {
+        let mut state = self.state.lock();
+        state.discarded = true;
+        state.blocks.clear();
+    }
+
+    /// Caches the collected result when the pipeline finished without errors.
+    pub fn commit(&self) {
+        let mut state = self.state.lock();
+        if state.discarded || state.unfinished_inputs != 0 {
+            return;
+        }
+
+        let blocks = std::mem::take(&mut state.blocks);
+        self.cache
+            .put(self.key.clone(), self.query_id.clone(), blocks, self.ttl);
+    }
+}
+
+fn now_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as i64
+}
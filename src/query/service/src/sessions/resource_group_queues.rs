@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_types::ResourceGroup;
+use common_users::UserApiProvider;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+use crate::sessions::QueryPermit;
+use crate::sessions::QueryQueue;
+use crate::sessions::TableContext;
+use crate::Config;
+
+/// The query queues of the resource groups, the queries of a group wait in the queue of the
+/// group before the queue of the server, so the groups can't starve each other.
+pub struct ResourceGroupQueues {
+    max_queued_queries: usize,
+    timeout: Duration,
+    queues: Mutex<HashMap<String, (ResourceGroup, Arc<QueryQueue>)>>,
+}
+
+impl ResourceGroupQueues {
+    pub fn create(max_queued_queries: usize, timeout: Duration) -> Arc<ResourceGroupQueues> {
+        Arc::new(ResourceGroupQueues {
+            max_queued_queries,
+            timeout,
+            queues: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<ResourceGroupQueues> {
+        ResourceGroupQueues::create(
+            conf.query.max_queued_queries as usize,
+            Duration::from_secs(conf.query.query_queue_timeout_secs),
+        )
+    }
+
+    /// The queue of the group, it's recreated if the limits of the group are changed, the queries
+    /// admitted by the old queue are not counted in the new one.
+    pub fn get_queue(&self, tenant: &str, group: &ResourceGroup) -> Arc<QueryQueue> {
+        let key = format!("{}/{}", tenant, group.name);
+        let mut queues = self.queues.lock();
+        match queues.get(&key) {
+            Some((cached, queue)) if cached == group => queue.clone(),
+            _ => {
+                let queue = QueryQueue::create(
+                    group.max_concurrency as usize,
+                    group.max_memory_usage,
+                    self.max_queued_queries,
+                    self.timeout,
+                );
+                queues.insert(key, (group.clone(), queue.clone()));
+                queue
+            }
+        }
+    }
+
+    /// The resource group of the query, by the resource_group setting or else the option of the
+    /// user, `None` if the query runs in no group.
+    pub async fn get_group(ctx: &QueryContext) -> Result<Option<ResourceGroup>> {
+        if !ctx.get_current_session().get_type().is_user_session() {
+            return Ok(None);
+        }
+
+        let mut name = ctx.get_settings().get_resource_group()?;
+        if name.is_empty() {
+            match ctx.get_current_user()?.option.resource_group() {
+                None => return Ok(None),
+                Some(group) => name = group.clone(),
+            }
+        }
+
+        let tenant = ctx.get_tenant();
+        let group = UserApiProvider::instance()
+            .get_resource_group(&tenant, &name)
+            .await?;
+        Ok(Some(group))
+    }
+
+    /// Admits the query in the queue of its group, the memory of the query is limited by the
+    /// memory reserved in the queue if the group limits the memory.
+    pub async fn acquire_for_query(
+        &self,
+        ctx: &QueryContext,
+        group: &ResourceGroup,
+    ) -> Result<Option<QueryPermit>> {
+        let queue = self.get_queue(&ctx.get_tenant(), group);
+        let permit = queue.acquire_for_query(ctx).await?;
+        if let Some(permit) = &permit {
+            if group.max_memory_usage != 0 {
+                ctx.set_query_max_memory_usage(permit.reserved_memory());
+            }
+        }
+
+        Ok(permit)
+    }
+}
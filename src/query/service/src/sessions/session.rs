@@ -16,6 +16,10 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+use common_catalog::table::Table;
+use common_catalog::txn::Transaction;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
@@ -28,6 +32,8 @@ use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::metrics::dec_session_connections;
+use crate::sessions::metrics::inc_session_connections;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
@@ -53,6 +59,7 @@ impl Session {
         mysql_connection_id: Option<u32>,
     ) -> Result<Arc<Session>> {
         let status = Arc::new(Default::default());
+        inc_session_connections(&typ);
         Ok(Arc::new(Session {
             id,
             typ: RwLock::new(typ),
@@ -77,6 +84,8 @@ impl Session {
 
     pub fn set_type(&self, typ: SessionType) {
         let mut lock = self.typ.write();
+        dec_session_connections(&lock);
+        inc_session_connections(&typ);
         *lock = typ;
     }
 
@@ -112,6 +121,20 @@ impl Session {
         self.kill(/* shutdown io stream */);
     }
 
+    /// Closes the session expired by the idle timeout or the lifetime, the running query is
+    /// killed by the cause, and the connection is shut down without waiting.
+    pub fn expire(self: &Arc<Self>, cause: ErrorCode) {
+        self.force_kill_query(cause);
+        self.session_ctx.set_abort(true);
+        if let Some(io_shutdown) = self.session_ctx.take_io_shutdown_tx() {
+            let (tx, _rx) = oneshot::channel();
+            let _ = io_shutdown.send(tx);
+        }
+
+        let http_queries_manager = HttpQueryManager::instance();
+        http_queries_manager.kill_session(&self.id);
+    }
+
     pub fn force_kill_query(self: &Arc<Self>, cause: ErrorCode) {
         let session_ctx = self.session_ctx.clone();
 
@@ -208,10 +231,74 @@ impl Session {
         self.session_ctx.set_current_user(user);
     }
 
+    pub fn get_auth_role(self: &Arc<Self>) -> Option<String> {
+        self.session_ctx.get_auth_role()
+    }
+
     pub fn set_auth_role(self: &Arc<Self>, role: String) {
         self.session_ctx.set_auth_role(role)
     }
 
+    pub fn get_current_role(self: &Arc<Self>) -> Option<String> {
+        self.session_ctx.get_current_role()
+    }
+
+    pub fn set_current_role(self: &Arc<Self>, role: Option<String>) {
+        self.session_ctx.set_current_role(role)
+    }
+
+    pub fn get_variable(self: &Arc<Self>, name: &str) -> Option<(DataValue, DataTypeImpl)> {
+        self.session_ctx.get_variable(name)
+    }
+
+    pub fn set_variable(self: &Arc<Self>, name: String, value: DataValue, data_type: DataTypeImpl) {
+        self.session_ctx.set_variable(name, value, data_type)
+    }
+
+    pub fn unset_variable(self: &Arc<Self>, name: &str) {
+        self.session_ctx.unset_variable(name)
+    }
+
+    /// The explicit transaction of the session, None if it's not in one.
+    pub fn get_transaction(self: &Arc<Self>) -> Option<Arc<Transaction>> {
+        self.session_ctx.get_transaction()
+    }
+
+    pub fn set_transaction(self: &Arc<Self>, transaction: Option<Arc<Transaction>>) {
+        self.session_ctx.set_transaction(transaction)
+    }
+
+    /// The temporary table of the session, which hides the table of the same name in the catalog.
+    pub fn get_temporary_table(
+        self: &Arc<Self>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Option<Arc<dyn Table>> {
+        self.session_ctx
+            .get_temporary_table(catalog, database, table)
+    }
+
+    pub fn add_temporary_table(
+        self: &Arc<Self>,
+        catalog: &str,
+        database: &str,
+        table: Arc<dyn Table>,
+    ) {
+        self.session_ctx
+            .add_temporary_table(catalog, database, table)
+    }
+
+    pub fn remove_temporary_table(
+        self: &Arc<Self>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Option<Arc<dyn Table>> {
+        self.session_ctx
+            .remove_temporary_table(catalog, database, table)
+    }
+
     // returns all the roles the current session has, which includes the roles of
     // the current user and the roles granted on the authentication phase.
     pub fn get_all_roles(self: &Arc<Self>) -> Result<Vec<String>> {
@@ -235,11 +322,15 @@ impl Session {
             return Ok(());
         }
 
-        // TODO: take current role instead of all roles
-        let all_roles = self.get_all_roles()?;
+        // only the current role and the roles inherited by it are taken if `SET ROLE`,
+        // otherwise all the roles of the session.
+        let roles = match self.get_current_role() {
+            Some(current_role) => vec![current_role],
+            None => self.get_all_roles()?,
+        };
         let tenant = self.get_current_tenant();
         let role_verified = RoleCacheManager::instance()
-            .find_related_roles(&tenant, &all_roles)
+            .find_related_roles(&tenant, &roles)
             .await?
             .iter()
             .any(|r| r.grants.verify_privilege(object, privilege));
@@ -267,6 +358,19 @@ impl Session {
         self.session_ctx.apply_changed_settings(changed_settings)
     }
 
+    /// The tag of the queries by the query_tag setting, or else the default query tag of the user,
+    /// empty if neither is set.
+    pub fn get_query_tag(self: &Arc<Self>, settings: &Settings) -> String {
+        match settings.get_query_tag() {
+            Ok(tag) if !tag.is_empty() => tag,
+            _ => self
+                .session_ctx
+                .get_current_user()
+                .and_then(|user| user.option.default_query_tag().cloned())
+                .unwrap_or_default(),
+        }
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         // TODO(winter): use thread memory tracker
         0
@@ -284,6 +388,7 @@ impl Session {
 impl Drop for Session {
     fn drop(&mut self) {
         tracing::debug!("Drop session {}", self.id.clone());
+        dec_session_connections(&self.typ.read());
         SessionManager::instance().destroy_session(&self.id.clone());
     }
 }
@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::table::Table;
+use common_catalog::txn::Transaction;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_exception::Result;
 use common_meta_types::UserInfo;
 use common_settings::Settings;
@@ -36,7 +41,13 @@ pub struct SessionContext {
     current_tenant: RwLock<String>,
     current_user: RwLock<Option<UserInfo>>,
     auth_role: RwLock<Option<String>>,
+    current_role: RwLock<Option<String>>,
     client_host: RwLock<Option<SocketAddr>>,
+    variables: RwLock<HashMap<String, (DataValue, DataTypeImpl)>>,
+    transaction: RwLock<Option<Arc<Transaction>>>,
+    // The temporary tables of the session by the catalog, database and name, of the memory
+    // engine, their blocks are dropped with the session.
+    temporary_tables: RwLock<HashMap<(String, String, String), Arc<dyn Table>>>,
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     query_context_shared: RwLock<Weak<QueryContextShared>>,
 }
@@ -49,8 +60,12 @@ impl SessionContext {
             abort: Default::default(),
             current_user: Default::default(),
             auth_role: Default::default(),
+            current_role: Default::default(),
             current_tenant: Default::default(),
             client_host: Default::default(),
+            variables: Default::default(),
+            transaction: Default::default(),
+            temporary_tables: Default::default(),
             current_catalog: RwLock::new("default".to_string()),
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
@@ -142,6 +157,74 @@ impl SessionContext {
         *lock = Some(role);
     }
 
+    // Get current role. Current role is the role set by `SET ROLE`.
+    pub fn get_current_role(&self) -> Option<String> {
+        let lock = self.current_role.read();
+        lock.clone()
+    }
+
+    pub fn set_current_role(&self, role: Option<String>) {
+        let mut lock = self.current_role.write();
+        *lock = role;
+    }
+
+    pub fn get_variable(&self, name: &str) -> Option<(DataValue, DataTypeImpl)> {
+        let lock = self.variables.read();
+        lock.get(name).cloned()
+    }
+
+    pub fn set_variable(&self, name: String, value: DataValue, data_type: DataTypeImpl) {
+        let mut lock = self.variables.write();
+        lock.insert(name, (value, data_type));
+    }
+
+    pub fn unset_variable(&self, name: &str) {
+        let mut lock = self.variables.write();
+        lock.remove(name);
+    }
+
+    pub fn get_transaction(&self) -> Option<Arc<Transaction>> {
+        let lock = self.transaction.read();
+        lock.clone()
+    }
+
+    pub fn set_transaction(&self, transaction: Option<Arc<Transaction>>) {
+        let mut lock = self.transaction.write();
+        *lock = transaction
+    }
+
+    pub fn get_temporary_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Option<Arc<dyn Table>> {
+        let lock = self.temporary_tables.read();
+        let key = (catalog.to_string(), database.to_string(), table.to_string());
+        lock.get(&key).cloned()
+    }
+
+    pub fn add_temporary_table(&self, catalog: &str, database: &str, table: Arc<dyn Table>) {
+        let mut lock = self.temporary_tables.write();
+        let key = (
+            catalog.to_string(),
+            database.to_string(),
+            table.name().to_string(),
+        );
+        lock.insert(key, table);
+    }
+
+    pub fn remove_temporary_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Option<Arc<dyn Table>> {
+        let mut lock = self.temporary_tables.write();
+        let key = (catalog.to_string(), database.to_string(), table.to_string());
+        lock.remove(&key)
+    }
+
     pub fn get_client_host(&self) -> Option<SocketAddr> {
         let lock = self.client_host.read();
         *lock
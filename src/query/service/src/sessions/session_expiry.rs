@@ -0,0 +1,94 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use common_exception::Result;
+use parking_lot::Mutex;
+
+use crate::sessions::BackgroundJob;
+use crate::sessions::BackgroundTask;
+use crate::sessions::SessionManager;
+use crate::Config;
+
+/// The background task closing the expired sessions.
+pub const SESSION_EXPIRY_TASK: &str = "session_expiry";
+
+const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Closes the sessions of the clients idle for `idle_timeout`, or living for `max_lifetime`
+/// along with their running queries, zero to keep them.
+pub struct SessionExpiry {
+    idle_timeout: Duration,
+    max_lifetime: Duration,
+    // The sessions closed so far.
+    expired: Mutex<u64>,
+    task: Arc<BackgroundTask>,
+}
+
+impl SessionExpiry {
+    pub fn create(idle_timeout: Duration, max_lifetime: Duration) -> Arc<SessionExpiry> {
+        // The sessions are checked twice per timeout, a session is closed at most half of the
+        // timeout late.
+        let interval = [idle_timeout, max_lifetime]
+            .into_iter()
+            .filter(|timeout| !timeout.is_zero())
+            .min()
+            .map(|timeout| timeout / 2)
+            .unwrap_or(MAX_CHECK_INTERVAL)
+            .clamp(Duration::from_secs(1), MAX_CHECK_INTERVAL);
+
+        Arc::new_cyclic(|expiry| {
+            let job: Weak<dyn BackgroundJob> = expiry.clone();
+            SessionExpiry {
+                idle_timeout,
+                max_lifetime,
+                expired: Mutex::new(0),
+                task: BackgroundTask::create(SESSION_EXPIRY_TASK, "Expire", interval, job),
+            }
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<SessionExpiry> {
+        SessionExpiry::create(
+            Duration::from_secs(conf.query.session_idle_timeout_secs),
+            Duration::from_secs(conf.query.session_max_lifetime_secs),
+        )
+    }
+
+    /// The sessions expire at all, otherwise the task is not started.
+    pub fn is_enabled(&self) -> bool {
+        !self.idle_timeout.is_zero() || !self.max_lifetime.is_zero()
+    }
+
+    pub fn task(&self) -> Arc<BackgroundTask> {
+        self.task.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundJob for SessionExpiry {
+    async fn run(&self) -> Result<String> {
+        let expired =
+            SessionManager::instance().expire_sessions(self.idle_timeout, self.max_lifetime);
+        *self.expired.lock() += expired as u64;
+        Ok(format!("{} sessions closed", expired))
+    }
+
+    fn progress(&self) -> String {
+        format!("{} sessions closed so far", self.expired.lock())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::ProgressValues;
 pub use common_catalog::table_context::ProcessInfo;
@@ -51,6 +52,10 @@ impl Session {
             memory_usage,
             dal_metrics: Session::query_dal_metrics(status),
             scan_progress_value: Session::query_scan_progress_value(status),
+            scan_progress_total_value: Session::query_scan_progress_total_value(status),
+            elapsed: Session::query_elapsed(status),
+            pipeline_stage: Session::query_pipeline_stage(status),
+            query_tag: self.process_query_tag(status),
             mysql_connection_id: self.mysql_connection_id,
         }
     }
@@ -63,6 +68,13 @@ impl Session {
         }
     }
 
+    fn process_query_tag(self: &Arc<Self>, status: &SessionContext) -> String {
+        match status.get_query_context_shared() {
+            Some(context_shared) => context_shared.get_query_tag(),
+            None => self.get_query_tag(&self.get_settings()),
+        }
+    }
+
     fn process_extra_info(self: &Arc<Self>, status: &SessionContext) -> Option<String> {
         match self.get_type() {
             SessionType::FlightRPC => Session::rpc_extra_info(status),
@@ -96,4 +108,25 @@ impl Session {
             .as_ref()
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
+
+    fn query_scan_progress_total_value(status: &SessionContext) -> Option<ProgressValues> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.scan_progress_total.get_values())
+    }
+
+    fn query_elapsed(status: &SessionContext) -> Option<Duration> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.get_elapsed())
+    }
+
+    fn query_pipeline_stage(status: &SessionContext) -> Option<String> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .and_then(|context_shared| context_shared.get_pipeline_stage())
+    }
 }
@@ -20,12 +20,14 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_base::base::tokio;
 use common_base::base::SignalStream;
 use common_base::base::Singleton;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserInfo;
 use common_metrics::label_counter;
 use common_settings::Settings;
 use common_users::UserApiProvider;
@@ -36,11 +38,24 @@ use parking_lot::RwLock;
 use tracing::debug;
 use tracing::info;
 
+use crate::interpreters::AsyncInsertQueue;
 use crate::sessions::session::Session;
+use crate::sessions::BackgroundTasks;
+use crate::sessions::CompactionScheduler;
+use crate::sessions::DictionaryCache;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueryContextShared;
+use crate::sessions::QueryLogPersister;
+use crate::sessions::QueryQueue;
+use crate::sessions::QueryResultCache;
+use crate::sessions::ResourceGroupQueues;
 use crate::sessions::SessionContext;
+use crate::sessions::SessionExpiry;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sessions::TaskScheduler;
+use crate::sql::PlanCache;
+use crate::sql::PlanHintCache;
 use crate::Config;
 
 pub struct SessionManager {
@@ -52,13 +67,45 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+    pub query_queue: Arc<QueryQueue>,
+    pub resource_group_queues: Arc<ResourceGroupQueues>,
+    pub query_result_cache: Arc<QueryResultCache>,
+    pub plan_cache: Arc<PlanCache>,
+    pub plan_hint_cache: Arc<PlanHintCache>,
+    pub dictionary_cache: Arc<DictionaryCache>,
+    pub query_log_persister: Arc<QueryLogPersister>,
+    pub task_scheduler: Arc<TaskScheduler>,
+    pub compaction_scheduler: Arc<CompactionScheduler>,
+    pub async_insert_queue: Arc<AsyncInsertQueue>,
+    pub session_expiry: Arc<SessionExpiry>,
+    pub background_tasks: Arc<BackgroundTasks>,
 }
 
 static SESSION_MANAGER: OnceCell<Singleton<Arc<SessionManager>>> = OnceCell::new();
 
 impl SessionManager {
     pub fn init(conf: Config, v: Singleton<Arc<SessionManager>>) -> Result<()> {
-        v.init(Self::create(conf))?;
+        let session_manager = Self::create(conf);
+        if let Some(task) = session_manager.query_log_persister.task() {
+            session_manager.background_tasks.start(task);
+        }
+        // The tasks are not run in the management mode, of which the nodes run no queries, nor in
+        // the read only mode, of which the nodes write nothing.
+        if !session_manager.conf.query.management_mode && !session_manager.conf.query.read_only {
+            let task = session_manager.task_scheduler.task();
+            session_manager.background_tasks.start(task);
+            if session_manager.conf.query.auto_compaction_enabled {
+                let task = session_manager.compaction_scheduler.task();
+                session_manager.background_tasks.start(task);
+            }
+        }
+        let task = session_manager.async_insert_queue.task();
+        session_manager.background_tasks.start(task);
+        if session_manager.session_expiry.is_enabled() {
+            let task = session_manager.session_expiry.task();
+            session_manager.background_tasks.start(task);
+        }
+        v.init(session_manager)?;
 
         SESSION_MANAGER.set(v).ok();
         Ok(())
@@ -66,6 +113,16 @@ impl SessionManager {
 
     pub fn create(conf: Config) -> Arc<SessionManager> {
         let max_sessions = conf.query.max_active_sessions as usize;
+        let query_queue = QueryQueue::from_config(&conf);
+        let resource_group_queues = ResourceGroupQueues::from_config(&conf);
+        let query_result_cache = QueryResultCache::from_config(&conf);
+        let plan_cache = PlanCache::from_config(&conf);
+        let dictionary_cache = DictionaryCache::from_config(&conf);
+        let query_log_persister = QueryLogPersister::from_config(&conf);
+        let task_scheduler = TaskScheduler::from_config(&conf);
+        let compaction_scheduler = CompactionScheduler::from_config(&conf);
+        let async_insert_queue = AsyncInsertQueue::from_config(&conf);
+        let session_expiry = SessionExpiry::from_config(&conf);
         Arc::new(SessionManager {
             conf,
             max_sessions,
@@ -73,6 +130,18 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            query_queue,
+            resource_group_queues,
+            query_result_cache,
+            plan_cache,
+            plan_hint_cache: PlanHintCache::create(),
+            dictionary_cache,
+            query_log_persister,
+            task_scheduler,
+            compaction_scheduler,
+            async_insert_queue,
+            session_expiry,
+            background_tasks: BackgroundTasks::create(),
         })
     }
 
@@ -152,6 +221,115 @@ impl SessionManager {
         sessions.get(id).and_then(|weak_ptr| weak_ptr.upgrade())
     }
 
+    /// Get the session running the query of the tenant, the query may come from any handler.
+    pub fn get_session_by_query_id(&self, tenant: &str, query_id: &str) -> Option<Arc<Session>> {
+        let sessions = self.active_sessions.read();
+        sessions
+            .values()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .find(|session| {
+                session.get_current_tenant() == tenant
+                    && session.get_current_query_id().as_deref() == Some(query_id)
+            })
+    }
+
+    /// Get the shared context of the running query of any tenant, for the debug API of the node.
+    pub fn get_query_context_shared_by_query_id(
+        &self,
+        query_id: &str,
+    ) -> Option<Arc<QueryContextShared>> {
+        let sessions = self.active_sessions.read();
+        sessions
+            .values()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .find(|session| session.get_current_query_id().as_deref() == Some(query_id))
+            .and_then(|session| session.session_ctx.get_query_context_shared())
+    }
+
+    /// Checks the sessions of the user against max_connections_per_user, before the user is
+    /// set to the session.
+    pub fn check_user_connections(&self, session: &Arc<Session>, user: &UserInfo) -> Result<()> {
+        let max_connections = self.conf.query.max_connections_per_user as usize;
+        if max_connections == 0 {
+            return Ok(());
+        }
+
+        let tenant = session.get_current_tenant();
+        let identity = user.identity();
+        let connections = self
+            .client_sessions()
+            .iter()
+            .filter(|other| {
+                other.id != session.id
+                    && other.get_current_tenant() == tenant
+                    && other.session_ctx.get_current_user().map(|u| u.identity())
+                        == Some(identity.clone())
+            })
+            .count();
+        if connections >= max_connections {
+            return Err(ErrorCode::TooManyUserConnections(format!(
+                "The connections of user {} have exceeded max_connections_per_user config of {}",
+                identity, max_connections
+            )));
+        }
+        Ok(())
+    }
+
+    /// Closes the sessions of the clients idle for `idle_timeout`, or living for `max_lifetime`
+    /// along with their running queries, zero to keep them. Returns the sessions closed.
+    pub fn expire_sessions(&self, idle_timeout: Duration, max_lifetime: Duration) -> usize {
+        let now = Instant::now();
+        let mut expired = 0;
+        for session in self.client_sessions() {
+            if session.is_aborting() {
+                continue;
+            }
+            let cause = {
+                let status = session.get_status();
+                let status = status.read();
+                let lifetime = now.saturating_duration_since(status.session_started_at);
+                let idle_time = now.saturating_duration_since(status.last_access());
+                if !max_lifetime.is_zero() && lifetime >= max_lifetime {
+                    Some(ErrorCode::AbortedSession(format!(
+                        "Aborted session, because it has exceeded session_max_lifetime_secs config of {}",
+                        max_lifetime.as_secs()
+                    )))
+                } else if !idle_timeout.is_zero()
+                    && idle_time >= idle_timeout
+                    && session.get_current_query_id().is_none()
+                {
+                    Some(ErrorCode::AbortedSession(format!(
+                        "Aborted session, because it has been idle for session_idle_timeout_secs config of {}",
+                        idle_timeout.as_secs()
+                    )))
+                } else {
+                    None
+                }
+            };
+            if let Some(cause) = cause {
+                info!("Expire session {}: {}", session.id, cause.message());
+                session.expire(cause);
+                expired += 1;
+            }
+        }
+        expired
+    }
+
+    fn client_sessions(&self) -> Vec<Arc<Session>> {
+        // The sessions are dropped out of the lock, a session removes itself once dropped.
+        let sessions = {
+            let sessions = self.active_sessions.read();
+            sessions
+                .values()
+                .filter_map(|weak_ptr| weak_ptr.upgrade())
+                .collect::<Vec<_>>()
+        };
+        sessions
+            .into_iter()
+            .filter(|session| session.get_type().is_client_session())
+            .collect()
+    }
+
     pub fn get_id_by_mysql_conn_id(&self, mysql_conn_id: &Option<u32>) -> Option<String> {
         let sessions = self.mysql_conn_map.read();
         sessions.get(mysql_conn_id).cloned()
@@ -18,11 +18,15 @@ use std::fmt;
 pub enum SessionType {
     Clickhouse,
     MySQL,
+    Postgres,
     HTTPQuery,
     HTTPStreamingLoad,
     ClickHouseHttpHandler,
     FlightRPC,
+    FlightSQL,
     HTTPAPI(String),
+    // The run of a scheduled task, by the name of the task.
+    Task(String),
     Dummy,
     Fuzz,
 }
@@ -34,6 +38,18 @@ impl SessionType {
             SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
         )
     }
+
+    /// The sessions of the connections of the clients, including the HTTP sessions being
+    /// authenticated, rather than those of the node itself, e.g. the runs of the tasks.
+    pub fn is_client_session(&self) -> bool {
+        !matches!(
+            self,
+            SessionType::HTTPAPI(_)
+                | SessionType::Task(_)
+                | SessionType::FlightRPC
+                | SessionType::Fuzz
+        )
+    }
 }
 
 impl fmt::Display for SessionType {
@@ -42,11 +58,14 @@ impl fmt::Display for SessionType {
             SessionType::ClickHouseHttpHandler => "ClickhouseHTTPHandler".to_string(),
             SessionType::Clickhouse => "Clickhouse".to_string(),
             SessionType::MySQL => "MySQL".to_string(),
+            SessionType::Postgres => "Postgres".to_string(),
             SessionType::HTTPQuery => "HTTPQuery".to_string(),
             SessionType::HTTPStreamingLoad => "HTTPStreamingLoad".to_string(),
             SessionType::Dummy => "Dummy".to_string(),
             SessionType::FlightRPC => "FlightRPC".to_string(),
+            SessionType::FlightSQL => "FlightSQL".to_string(),
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
+            SessionType::Task(name) => format!("Task({})", name),
             SessionType::Fuzz => "Fuzz".to_string(),
         };
         write!(f, "{}", name)
@@ -0,0 +1,275 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::GlobalIORuntime;
+use common_base::base::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ScheduledTask;
+use common_storages_preludes::system::TaskHistoryElement;
+use common_storages_preludes::system::TaskHistoryQueue;
+use common_users::UserApiProvider;
+use futures::TryStreamExt;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::BackgroundJob;
+use crate::sessions::BackgroundTask;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+use crate::Config;
+
+/// The background task scheduling the scheduled tasks.
+pub const TASK_SCHEDULER_TASK: &str = "task_scheduler";
+
+const TICK_INTERVAL: Duration = Duration::from_secs(10);
+// The lease is renewed on each tick, another node takes over once the leader is gone for a while.
+const LEASE_TTL_SECS: u64 = 60;
+
+#[derive(Default)]
+struct SchedulerState {
+    leader: bool,
+    // The time of the last tick as the leader, the tasks scheduled since then are due.
+    last_tick: Option<i64>,
+}
+
+/// Runs the scheduled tasks of the tenant on their schedules, and the tasks after them once they
+/// succeed. The nodes of the cluster compete for a lease in the meta service, only the node
+/// holding it runs the tasks on their schedules, so that a task runs once on each schedule.
+pub struct TaskScheduler {
+    tenant: String,
+    node_id: String,
+    state: Mutex<SchedulerState>,
+    // The tasks running on the node, a task doesn't run again until its last run completes.
+    running: Arc<Mutex<HashSet<String>>>,
+    task: Arc<BackgroundTask>,
+}
+
+impl TaskScheduler {
+    pub fn create(tenant: &str) -> Arc<TaskScheduler> {
+        Arc::new_cyclic(|scheduler| {
+            let job: Weak<dyn BackgroundJob> = scheduler.clone();
+            TaskScheduler {
+                tenant: tenant.to_string(),
+                node_id: uuid::Uuid::new_v4().to_string(),
+                state: Mutex::new(SchedulerState::default()),
+                running: Arc::new(Mutex::new(HashSet::new())),
+                task: BackgroundTask::create(TASK_SCHEDULER_TASK, "Schedule", TICK_INTERVAL, job),
+            }
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<TaskScheduler> {
+        TaskScheduler::create(&conf.query.tenant_id)
+    }
+
+    pub fn task(&self) -> Arc<BackgroundTask> {
+        self.task.clone()
+    }
+
+    /// Runs the task on the node at once, by `ALTER TASK ... EXECUTE`, then the tasks after it if
+    /// it succeeds.
+    pub fn execute(&self, tenant: &str, task: ScheduledTask) -> Result<()> {
+        if !self.running.lock().insert(task.name.clone()) {
+            return Err(ErrorCode::TaskIsRunning(format!(
+                "task {} is running",
+                task.name
+            )));
+        }
+        self.spawn_run(tenant, task, "Execute", "");
+        Ok(())
+    }
+
+    /// Runs the tasks of the tenant due since the last tick.
+    pub async fn tick(&self) -> Result<String> {
+        let now = now_micros();
+        let leader = UserApiProvider::instance()
+            .acquire_task_scheduler_lease(&self.tenant, &self.node_id, LEASE_TTL_SECS)
+            .await?;
+        let since = {
+            let mut state = self.state.lock();
+            state.leader = leader;
+            if !leader {
+                state.last_tick = None;
+                return Ok("not the leader".to_string());
+            }
+            state.last_tick.replace(now).unwrap_or(now)
+        };
+
+        let mut runs = 0;
+        for task in UserApiProvider::instance().get_tasks(&self.tenant).await? {
+            if task.suspended {
+                continue;
+            }
+            let schedule = match task.cron_schedule() {
+                Ok(Some(schedule)) => schedule,
+                Ok(None) => continue,
+                Err(cause) => {
+                    error!("skip task {} of illegal schedule: {:?}", task.name, cause);
+                    continue;
+                }
+            };
+            let due = matches!(schedule.next_after(since), Some(next) if next <= now);
+            if !due {
+                continue;
+            }
+            if !self.running.lock().insert(task.name.clone()) {
+                info!("skip task {}, its last run is not completed", task.name);
+                continue;
+            }
+            self.spawn_run(&self.tenant, task, "Schedule", "");
+            runs += 1;
+        }
+        Ok(format!("leader, {} tasks started", runs))
+    }
+
+    // Runs the task, and the tasks after the succeeded ones in turn, the task must be in the
+    // running tasks.
+    fn spawn_run(&self, tenant: &str, task: ScheduledTask, trigger: &str, predecessor: &str) {
+        let tenant = tenant.to_string();
+        let running = self.running.clone();
+        let mut runs = VecDeque::from([(task, trigger.to_string(), predecessor.to_string())]);
+        GlobalIORuntime::instance().spawn(async move {
+            while let Some((task, trigger, predecessor)) = runs.pop_front() {
+                let succeeded = Self::run_task(&tenant, &task, &trigger, &predecessor).await;
+                running.lock().remove(&task.name);
+                if !succeeded {
+                    continue;
+                }
+
+                let tasks = match UserApiProvider::instance().get_tasks(&tenant).await {
+                    Ok(tasks) => tasks,
+                    Err(cause) => {
+                        error!(
+                            "fail to get the tasks after task {}: {:?}",
+                            task.name, cause
+                        );
+                        continue;
+                    }
+                };
+                for successor in tasks {
+                    if successor.suspended || successor.after.as_ref() != Some(&task.name) {
+                        continue;
+                    }
+                    if !running.lock().insert(successor.name.clone()) {
+                        info!(
+                            "skip task {}, its last run is not completed",
+                            successor.name
+                        );
+                        continue;
+                    }
+                    runs.push_back((successor, "Predecessor".to_string(), task.name.clone()));
+                }
+            }
+        });
+    }
+
+    // Runs the SQL of the task and records the run in the task history, returns whether it
+    // succeeded.
+    async fn run_task(
+        tenant: &str,
+        task: &ScheduledTask,
+        trigger: &str,
+        predecessor: &str,
+    ) -> bool {
+        let scheduled_time = now_micros();
+        let (query_id, res) = match Self::create_context(tenant, task).await {
+            Ok(ctx) => (ctx.get_id(), Self::execute_sql(ctx, &task.sql).await),
+            Err(cause) => (String::new(), Err(cause)),
+        };
+        if let Err(cause) = &res {
+            error!("task {} failed: {:?}", task.name, cause);
+        }
+
+        let (exception_code, exception_text) = match &res {
+            Ok(_) => (0, String::new()),
+            Err(cause) => (cause.code() as i32, cause.message()),
+        };
+        let event = TaskHistoryElement {
+            name: task.name.clone(),
+            query_id,
+            trigger: trigger.to_string(),
+            predecessor: predecessor.to_string(),
+            scheduled_time,
+            completed_time: now_micros(),
+            success: res.is_ok(),
+            exception_code,
+            exception_text,
+        };
+        if let Err(cause) = TaskHistoryQueue::instance().and_then(|queue| queue.append_data(event))
+        {
+            error!("fail to record the run of task {}: {:?}", task.name, cause);
+        }
+        res.is_ok()
+    }
+
+    // The context of the run, as the owner of the task, in the database the task is created in.
+    async fn create_context(tenant: &str, task: &ScheduledTask) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Task(task.name.clone()))
+            .await?;
+        session.set_current_tenant(tenant.to_string());
+        let owner = UserApiProvider::instance()
+            .get_user(tenant, task.owner.clone())
+            .await?;
+        session.set_current_user(owner);
+        session.set_current_database(task.database.clone());
+        session.create_query_context().await
+    }
+
+    async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _, _) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.to_string(), sql);
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundJob for TaskScheduler {
+    async fn run(&self) -> Result<String> {
+        self.tick().await
+    }
+
+    fn progress(&self) -> String {
+        let running = self.running.lock().len();
+        match self.state.lock().leader {
+            true => format!("leader, {} tasks running", running),
+            false => format!("not the leader, {} tasks running", running),
+        }
+    }
+}
+
+fn now_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
+}
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use common_ast::ast::FormatTreeNode;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::TypeID;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_legacy_planners::StageKind;
@@ -29,6 +33,7 @@ use super::Filter;
 use super::HashJoin;
 use super::Limit;
 use super::PhysicalPlan;
+use super::PlanStatsInfo;
 use super::Project;
 use super::Sort;
 use super::TableScan;
@@ -93,24 +98,29 @@ fn table_scan_to_format_tree(
                 .map_or("NONE".to_string(), |limit| limit.to_string())
         });
 
+    let mut children = vec![
+        FormatTreeNode::new(format!("table: {table_name}")),
+        FormatTreeNode::new(format!("read rows: {}", plan.source.statistics.read_rows)),
+        FormatTreeNode::new(format!("read bytes: {}", plan.source.statistics.read_bytes)),
+        FormatTreeNode::new(format!(
+            "partitions total: {}",
+            plan.source.statistics.partitions_total
+        )),
+        FormatTreeNode::new(format!(
+            "partitions scanned: {}",
+            plan.source.statistics.partitions_scanned
+        )),
+        FormatTreeNode::new(format!(
+            "push downs: [filters: [{filters}], limit: {limit}]"
+        )),
+    ];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
     Ok(FormatTreeNode::with_children(
         "TableScan".to_string(),
-        vec![
-            FormatTreeNode::new(format!("table: {table_name}")),
-            FormatTreeNode::new(format!("read rows: {}", plan.source.statistics.read_rows)),
-            FormatTreeNode::new(format!("read bytes: {}", plan.source.statistics.read_bytes)),
-            FormatTreeNode::new(format!(
-                "partitions total: {}",
-                plan.source.statistics.partitions_total
-            )),
-            FormatTreeNode::new(format!(
-                "partitions scanned: {}",
-                plan.source.statistics.partitions_scanned
-            )),
-            FormatTreeNode::new(format!(
-                "push downs: [filters: [{filters}], limit: {limit}]"
-            )),
-        ],
+        children,
     ))
 }
 
@@ -121,10 +131,16 @@ fn filter_to_format_tree(plan: &Filter, metadata: &MetadataRef) -> Result<Format
         .map(|scalar| scalar.pretty_display(metadata))
         .collect::<Result<Vec<_>>>()?
         .join(", ");
-    Ok(FormatTreeNode::with_children("Filter".to_string(), vec![
-        FormatTreeNode::new(format!("filters: [{filter}]")),
-        to_format_tree(&plan.input, metadata)?,
-    ]))
+    let mut children = vec![FormatTreeNode::new(format!("filters: [{filter}]"))];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
+    Ok(FormatTreeNode::with_children(
+        "Filter".to_string(),
+        children,
+    ))
 }
 
 fn project_to_format_tree(
@@ -154,12 +170,15 @@ fn eval_scalar_to_format_tree(
         .map(|(scalar, _)| scalar.pretty_display(metadata))
         .collect::<Result<Vec<_>>>()?
         .join(", ");
+    let mut children = vec![FormatTreeNode::new(format!("expressions: [{scalars}]"))];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
     Ok(FormatTreeNode::with_children(
         "EvalScalar".to_string(),
-        vec![
-            FormatTreeNode::new(format!("expressions: [{scalars}]")),
-            to_format_tree(&plan.input, metadata)?,
-        ],
+        children,
     ))
 }
 
@@ -184,13 +203,18 @@ fn aggregate_partial_to_format_tree(
         .map(|agg| agg.pretty_display(metadata))
         .collect::<Result<Vec<_>>>()?
         .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
     Ok(FormatTreeNode::with_children(
         "AggregatePartial".to_string(),
-        vec![
-            FormatTreeNode::new(format!("group by: [{group_by}]")),
-            FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
-            to_format_tree(&plan.input, metadata)?,
-        ],
+        children,
     ))
 }
 
@@ -215,13 +239,18 @@ fn aggregate_final_to_format_tree(
         .map(|agg| agg.pretty_display(metadata))
         .collect::<Result<Vec<_>>>()?
         .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
     Ok(FormatTreeNode::with_children(
         "AggregateFinal".to_string(),
-        vec![
-            FormatTreeNode::new(format!("group by: [{group_by}]")),
-            FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
-            to_format_tree(&plan.input, metadata)?,
-        ],
+        children,
     ))
 }
 
@@ -245,22 +274,30 @@ fn sort_to_format_tree(plan: &Sort, metadata: &MetadataRef) -> Result<FormatTree
         })
         .collect::<Result<Vec<_>>>()?
         .join(", ");
-    Ok(FormatTreeNode::with_children("Sort".to_string(), vec![
-        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
-        to_format_tree(&plan.input, metadata)?,
-    ]))
+    let mut children = vec![FormatTreeNode::new(format!("sort keys: [{sort_keys}]"))];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
+    Ok(FormatTreeNode::with_children("Sort".to_string(), children))
 }
 
 fn limit_to_format_tree(plan: &Limit, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
-    Ok(FormatTreeNode::with_children("Limit".to_string(), vec![
+    let mut children = vec![
         FormatTreeNode::new(format!(
             "limit: {}",
             plan.limit
                 .map_or("NONE".to_string(), |limit| limit.to_string())
         )),
         FormatTreeNode::new(format!("offset: {}", plan.offset)),
-        to_format_tree(&plan.input, metadata)?,
-    ]))
+    ];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
+    Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
 
 fn hash_join_to_format_tree(
@@ -292,22 +329,32 @@ fn hash_join_to_format_tree(
     build_child.payload = format!("{}(Build)", build_child.payload);
     probe_child.payload = format!("{}(Probe)", probe_child.payload);
 
-    Ok(FormatTreeNode::with_children("HashJoin".to_string(), vec![
+    let mut children = vec![
         FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("join strategy: {}", join_strategy(plan))),
         FormatTreeNode::new(format!("build keys: [{build_keys}]")),
         FormatTreeNode::new(format!("probe keys: [{probe_keys}]")),
         FormatTreeNode::new(format!("filters: [{filters}]")),
-        build_child,
-        probe_child,
-    ]))
+    ];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(build_child);
+    children.push(probe_child);
+    Ok(FormatTreeNode::with_children(
+        "HashJoin".to_string(),
+        children,
+    ))
 }
 
 fn exchange_to_format_tree(
     plan: &Exchange,
     metadata: &MetadataRef,
 ) -> Result<FormatTreeNode<String>> {
-    Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
-        FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "exchange type: {}",
+        match plan.kind {
             StageKind::Normal => format!(
                 "Hash({})",
                 plan.keys
@@ -318,17 +365,79 @@ fn exchange_to_format_tree(
             ),
             StageKind::Expansive => "Broadcast".to_string(),
             StageKind::Merge => "Merge".to_string(),
-        })),
-        to_format_tree(&plan.input, metadata)?,
-    ]))
+            StageKind::RoundRobin => "RoundRobin".to_string(),
+        }
+    ))];
+    children.extend(stat_info_to_format_tree(
+        &plan.stat_info,
+        &plan.output_schema()?,
+    ));
+    children.push(to_format_tree(&plan.input, metadata)?);
+    Ok(FormatTreeNode::with_children(
+        "Exchange".to_string(),
+        children,
+    ))
 }
 
 fn union_all_to_format_tree(
     plan: &UnionAll,
     metadata: &MetadataRef,
 ) -> Result<FormatTreeNode<String>> {
-    Ok(FormatTreeNode::with_children("UnionAll".to_string(), vec![
-        to_format_tree(&plan.left, metadata)?,
-        to_format_tree(&plan.right, metadata)?,
-    ]))
+    let mut children = stat_info_to_format_tree(&plan.stat_info, &plan.output_schema()?);
+    children.push(to_format_tree(&plan.left, metadata)?);
+    children.push(to_format_tree(&plan.right, metadata)?);
+    Ok(FormatTreeNode::with_children(
+        "UnionAll".to_string(),
+        children,
+    ))
+}
+
+/// The strategy of the join chosen by the optimizer, by the exchanges of the build side.
+fn join_strategy(plan: &HashJoin) -> &'static str {
+    match plan.build.as_ref() {
+        PhysicalPlan::Exchange(Exchange {
+            kind: StageKind::Expansive,
+            ..
+        }) => "BroadcastHashJoin",
+        PhysicalPlan::Exchange(Exchange {
+            kind: StageKind::Normal,
+            ..
+        }) => "ShuffleHashJoin",
+        _ => "HashJoin",
+    }
+}
+
+fn stat_info_to_format_tree(
+    stat_info: &Option<PlanStatsInfo>,
+    output_schema: &DataSchemaRef,
+) -> Vec<FormatTreeNode<String>> {
+    match stat_info {
+        None => vec![],
+        Some(stat_info) => {
+            let estimated_bytes = stat_info.estimated_rows * estimated_row_width(output_schema);
+            vec![
+                FormatTreeNode::new(format!("estimated rows: {:.2}", stat_info.estimated_rows)),
+                FormatTreeNode::new(format!("estimated bytes: {:.2}", estimated_bytes)),
+            ]
+        }
+    }
+}
+
+// The width of the values of the variable-length types, e.g. strings, is unknown to the
+// optimizer, they are estimated to be of the same width.
+const ESTIMATED_VARIABLE_LENGTH_WIDTH: usize = 16;
+
+fn estimated_row_width(schema: &DataSchemaRef) -> f64 {
+    schema
+        .fields()
+        .iter()
+        .map(
+            |field| match remove_nullable(field.data_type()).data_type_id() {
+                TypeID::Boolean => 1,
+                type_id => type_id
+                    .numeric_byte_size()
+                    .unwrap_or(ESTIMATED_VARIABLE_LENGTH_WIDTH),
+            },
+        )
+        .sum::<usize>() as f64
 }
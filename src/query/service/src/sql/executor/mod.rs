@@ -17,6 +17,7 @@ mod format;
 mod physical_plan;
 mod physical_plan_builder;
 mod physical_plan_display;
+mod physical_plan_validator;
 mod physical_plan_visitor;
 mod physical_scalar;
 mod pipeline_builder;
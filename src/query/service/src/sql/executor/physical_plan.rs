@@ -39,6 +39,12 @@ use crate::sql::ColumnBinding;
 
 pub type ColumnID = String;
 
+/// The estimates of the optimizer for the output of a plan, only used for display.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PlanStatsInfo {
+    pub estimated_rows: f64,
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct TableScan {
     pub name_mapping: BTreeMap<String, ColumnID>,
@@ -46,6 +52,7 @@ pub struct TableScan {
 
     /// Only used for display
     pub table_index: IndexType,
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl TableScan {
@@ -64,6 +71,9 @@ impl TableScan {
 pub struct Filter {
     pub input: Box<PhysicalPlan>,
     pub predicates: Vec<PhysicalScalar>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl Filter {
@@ -96,6 +106,9 @@ impl Project {
 pub struct EvalScalar {
     pub input: Box<PhysicalPlan>,
     pub scalars: Vec<(PhysicalScalar, ColumnID)>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl EvalScalar {
@@ -115,6 +128,9 @@ pub struct AggregatePartial {
     pub input: Box<PhysicalPlan>,
     pub group_by: Vec<ColumnID>,
     pub agg_funcs: Vec<AggregateFunctionDesc>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl AggregatePartial {
@@ -146,6 +162,9 @@ pub struct AggregateFinal {
     pub group_by: Vec<ColumnID>,
     pub agg_funcs: Vec<AggregateFunctionDesc>,
     pub before_group_by_schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl AggregateFinal {
@@ -173,6 +192,9 @@ pub struct Sort {
     pub order_by: Vec<SortDesc>,
     // limit = Limit.limit + Limit.offset
     pub limit: Option<usize>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl Sort {
@@ -186,6 +208,9 @@ pub struct Limit {
     pub input: Box<PhysicalPlan>,
     pub limit: Option<usize>,
     pub offset: usize,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl Limit {
@@ -204,6 +229,9 @@ pub struct HashJoin {
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl HashJoin {
@@ -287,6 +315,9 @@ pub struct Exchange {
     pub input: Box<PhysicalPlan>,
     pub kind: StageKind,
     pub keys: Vec<PhysicalScalar>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl Exchange {
@@ -338,6 +369,9 @@ pub struct UnionAll {
     pub right: Box<PhysicalPlan>,
     pub pairs: Vec<(String, String)>,
     pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
 }
 
 impl UnionAll {
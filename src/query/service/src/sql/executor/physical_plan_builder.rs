@@ -52,9 +52,11 @@ use crate::sql::executor::EvalScalar;
 use crate::sql::executor::ExpressionBuilderWithoutRenaming;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalScalar;
+use crate::sql::executor::PlanStatsInfo;
 use crate::sql::executor::SortDesc;
 use crate::sql::executor::UnionAll;
 use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::AggregateMode;
 use crate::sql::plans::Exchange;
@@ -113,6 +115,7 @@ impl PhysicalPlanBuilder {
     #[async_recursion::async_recursion]
     pub async fn build(&self, s_expr: &SExpr) -> Result<PhysicalPlan> {
         debug_assert!(check_physical(s_expr));
+        let stat_info = self.build_plan_stat_info(s_expr);
 
         match s_expr.plan() {
             RelOperator::PhysicalScan(scan) => {
@@ -153,6 +156,7 @@ impl PhysicalPlanBuilder {
                     name_mapping,
                     source: Box::new(source),
                     table_index: scan.table_index,
+                    stat_info,
                 }))
             }
             RelOperator::DummyTableScan(_) => {
@@ -168,6 +172,7 @@ impl PhysicalPlanBuilder {
                     name_mapping: BTreeMap::from([("dummy".to_string(), "dummy".to_string())]),
                     source: Box::new(source),
                     table_index: DUMMY_TABLE_INDEX,
+                    stat_info,
                 }))
             }
             RelOperator::PhysicalHashJoin(join) => {
@@ -213,6 +218,7 @@ impl PhysicalPlanBuilder {
                         .collect::<Result<_>>()?,
                     marker_index: join.marker_index,
                     from_correlated_subquery: join.from_correlated_subquery,
+                    stat_info,
                 }))
             }
 
@@ -229,6 +235,7 @@ impl PhysicalPlanBuilder {
                             Ok((builder.build(&item.scalar)?, item.index.to_string()))
                         })
                         .collect::<Result<_>>()?,
+                    stat_info,
                 }))
             }
 
@@ -245,6 +252,7 @@ impl PhysicalPlanBuilder {
                             builder.build(pred)
                         })
                         .collect::<Result<_>>()?,
+                    stat_info,
                 }))
             }
             RelOperator::Aggregate(agg) => {
@@ -299,6 +307,7 @@ impl PhysicalPlanBuilder {
                                     input,
                                     agg_funcs,
                                     group_by: group_items,
+                                    stat_info: stat_info.clone(),
                                 };
 
                                 let output_schema = aggregate_partial.output_schema()?;
@@ -317,12 +326,14 @@ impl PhysicalPlanBuilder {
                                             .clone(),
                                         display_name: "_group_by_key".to_string(),
                                     }],
+                                    stat_info,
                                 })
                             }
                             _ => PhysicalPlan::AggregatePartial(AggregatePartial {
                                 agg_funcs,
                                 group_by: group_items,
                                 input: Box::new(input),
+                                stat_info,
                             }),
                         }
                     }
@@ -384,6 +395,7 @@ impl PhysicalPlanBuilder {
                                     group_by: group_items,
                                     agg_funcs,
                                     before_group_by_schema,
+                                    stat_info,
                                 })
                             }
 
@@ -397,6 +409,7 @@ impl PhysicalPlanBuilder {
                                     group_by: group_items,
                                     agg_funcs,
                                     before_group_by_schema,
+                                    stat_info,
                                 })
                             }
 
@@ -420,11 +433,13 @@ impl PhysicalPlanBuilder {
                     })
                     .collect(),
                 limit: sort.limit,
+                stat_info,
             })),
             RelOperator::Limit(limit) => Ok(PhysicalPlan::Limit(Limit {
                 input: Box::new(self.build(s_expr.child(0)?).await?),
                 limit: limit.limit,
                 offset: limit.offset,
+                stat_info,
             })),
             RelOperator::Exchange(exchange) => {
                 let input = Box::new(self.build(s_expr.child(0)?).await?);
@@ -440,11 +455,13 @@ impl PhysicalPlanBuilder {
                     }
                     Exchange::Broadcast => StageKind::Expansive,
                     Exchange::Merge => StageKind::Merge,
+                    Exchange::RoundRobin => StageKind::RoundRobin,
                 };
                 Ok(PhysicalPlan::Exchange(PhysicalExchange {
                     input,
                     kind,
                     keys,
+                    stat_info,
                 }))
             }
             RelOperator::UnionAll(op) => {
@@ -464,6 +481,7 @@ impl PhysicalPlanBuilder {
                     right: Box::new(self.build(s_expr.child(1)?).await?),
                     pairs,
                     schema: DataSchemaRefExt::create(fields),
+                    stat_info,
                 }))
             }
             _ => Err(ErrorCode::LogicalError(format!(
@@ -473,6 +491,19 @@ impl PhysicalPlanBuilder {
         }
     }
 
+    /// The estimates of the optimizer from the relational property of the expression, the
+    /// exchanges enforced by the optimizer pass through the estimates of their inputs.
+    fn build_plan_stat_info(&self, s_expr: &SExpr) -> Option<PlanStatsInfo> {
+        let s_expr = match s_expr.plan() {
+            RelOperator::Exchange(_) => s_expr.child(0).ok()?,
+            _ => s_expr,
+        };
+        let rel_prop = RelExpr::with_s_expr(s_expr).derive_relational_prop().ok()?;
+        Some(PlanStatsInfo {
+            estimated_rows: rel_prop.cardinality,
+        })
+    }
+
     fn push_downs(
         &self,
         scan: &PhysicalScan,
@@ -570,11 +601,27 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        // The nearest neighbor search is pushed down as the ordering by the distance with limit.
+        let (order_by, limit) = match (order_by, &scan.vector_search) {
+            (None, Some(vector_search)) => {
+                let builder = ExpressionBuilderWithoutRenaming::create(self.metadata.clone());
+                let distance = builder.build(&vector_search.distance)?;
+                let order_by = LegacyExpression::Sort {
+                    expr: Box::new(distance.clone()),
+                    asc: true,
+                    nulls_first: false,
+                    origin_expr: Box::new(distance),
+                };
+                (Some(vec![order_by]), Some(vector_search.limit))
+            }
+            (order_by, _) => (order_by, scan.limit),
+        };
+
         Ok(Extras {
             projection: Some(projection),
             filters: push_down_filters.unwrap_or_default(),
             prewhere: prewhere_info,
-            limit: scan.limit,
+            limit,
             order_by: order_by.unwrap_or_default(),
         })
     }
@@ -0,0 +1,272 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::PhysicalPlan;
+use super::PhysicalScalar;
+
+impl PhysicalPlan {
+    /// Checks the columns each operator reads are in the output of its inputs, of the types the
+    /// operator expects, returns the problems found. The nullability of the types is not checked,
+    /// as the outer joins make the columns nullable after they are bound.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = vec![];
+        if let Err(cause) = self.validate_into(&mut problems) {
+            problems.push(cause.message());
+        }
+        problems
+    }
+
+    fn validate_into(&self, problems: &mut Vec<String>) -> Result<()> {
+        for child in self.children() {
+            child.validate_into(problems)?;
+        }
+        // the output of an invalid input can't be made, e.g. of the columns out of the input
+        if !problems.is_empty() {
+            return Ok(());
+        }
+
+        match self {
+            PhysicalPlan::TableScan(_) | PhysicalPlan::ExchangeSource(_) => {}
+            PhysicalPlan::Filter(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for predicate in plan.predicates.iter() {
+                    check_scalar("Filter", predicate, &input_schema, problems);
+                }
+            }
+            PhysicalPlan::Project(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for index in plan.projections.iter() {
+                    if *index >= input_schema.num_fields() {
+                        problems.push(format!(
+                            "Project: column {} is out of the {} columns of the input",
+                            index,
+                            input_schema.num_fields()
+                        ));
+                    }
+                }
+            }
+            PhysicalPlan::EvalScalar(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for (scalar, _) in plan.scalars.iter() {
+                    check_scalar("EvalScalar", scalar, &input_schema, problems);
+                }
+            }
+            PhysicalPlan::AggregatePartial(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for column in plan.group_by.iter() {
+                    check_column_name("AggregatePartial", column, &input_schema, problems);
+                }
+                for agg in plan.agg_funcs.iter() {
+                    for (arg, data_type) in agg.args.iter().zip(agg.sig.args.iter()) {
+                        check_column_index(
+                            "AggregatePartial",
+                            *arg,
+                            data_type,
+                            &input_schema,
+                            problems,
+                        );
+                    }
+                }
+            }
+            PhysicalPlan::AggregateFinal(plan) => {
+                for column in plan.group_by.iter() {
+                    check_column_name(
+                        "AggregateFinal",
+                        column,
+                        &plan.before_group_by_schema,
+                        problems,
+                    );
+                }
+            }
+            PhysicalPlan::Sort(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for desc in plan.order_by.iter() {
+                    check_column_name("Sort", &desc.order_by, &input_schema, problems);
+                }
+            }
+            PhysicalPlan::Limit(_) => {}
+            PhysicalPlan::HashJoin(plan) => {
+                let build_schema = plan.build.output_schema()?;
+                let probe_schema = plan.probe.output_schema()?;
+                if plan.build_keys.len() != plan.probe_keys.len() {
+                    problems.push(format!(
+                        "HashJoin: {} build keys and {} probe keys",
+                        plan.build_keys.len(),
+                        plan.probe_keys.len()
+                    ));
+                }
+                for key in plan.build_keys.iter() {
+                    check_scalar("HashJoin build key", key, &build_schema, problems);
+                }
+                for key in plan.probe_keys.iter() {
+                    check_scalar("HashJoin probe key", key, &probe_schema, problems);
+                }
+                let keys = plan.build_keys.iter().zip(plan.probe_keys.iter());
+                for (index, (build_key, probe_key)) in keys.enumerate() {
+                    let build_type = remove_nullable(&build_key.data_type());
+                    let probe_type = remove_nullable(&probe_key.data_type());
+                    if build_type.data_type_id() != probe_type.data_type_id() {
+                        problems.push(format!(
+                            "HashJoin: key {} is of type {} in the build and of type {} in the probe",
+                            index,
+                            build_type.name(),
+                            probe_type.name()
+                        ));
+                    }
+                }
+                // the other conditions are evaluated on the blocks of the probe and the build
+                let mut fields = probe_schema.fields().clone();
+                fields.extend(build_schema.fields().iter().cloned());
+                let merged_schema = DataSchemaRefExt::create(fields);
+                for condition in plan.other_conditions.iter() {
+                    check_scalar("HashJoin", condition, &merged_schema, problems);
+                }
+            }
+            PhysicalPlan::Exchange(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for key in plan.keys.iter() {
+                    check_scalar("Exchange", key, &input_schema, problems);
+                }
+            }
+            PhysicalPlan::ExchangeSink(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                for key in plan.keys.iter() {
+                    check_scalar("ExchangeSink", key, &input_schema, problems);
+                }
+            }
+            PhysicalPlan::UnionAll(plan) => {
+                let left_schema = plan.left.output_schema()?;
+                let right_schema = plan.right.output_schema()?;
+                if plan.pairs.len() != plan.schema.num_fields() {
+                    problems.push(format!(
+                        "UnionAll: {} pairs of columns for the {} columns of the output",
+                        plan.pairs.len(),
+                        plan.schema.num_fields()
+                    ));
+                }
+                for ((left, right), field) in plan.pairs.iter().zip(plan.schema.fields()) {
+                    check_column_name("UnionAll left", left, &left_schema, problems);
+                    check_column_name("UnionAll right", right, &right_schema, problems);
+                    for (side, schema, name) in [
+                        ("left", &left_schema, left),
+                        ("right", &right_schema, right),
+                    ] {
+                        if let Ok(input_field) = schema.field_with_name(name) {
+                            check_type(
+                                &format!("UnionAll {side}"),
+                                name,
+                                input_field.data_type(),
+                                field.data_type(),
+                                problems,
+                            );
+                        }
+                    }
+                }
+            }
+            PhysicalPlan::DistributedInsertSelect(plan) => {
+                let input_schema = plan.input.output_schema()?;
+                if input_schema.num_fields() != plan.select_schema.num_fields() {
+                    problems.push(format!(
+                        "DistributedInsertSelect: {} columns selected for the {} columns of the input",
+                        plan.select_schema.num_fields(),
+                        input_schema.num_fields()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_scalar(
+    operator: &str,
+    scalar: &PhysicalScalar,
+    input_schema: &DataSchemaRef,
+    problems: &mut Vec<String>,
+) {
+    match scalar {
+        PhysicalScalar::IndexedVariable {
+            index, data_type, ..
+        } => check_column_index(operator, *index, data_type, input_schema, problems),
+        PhysicalScalar::Constant { .. } => {}
+        PhysicalScalar::Function { args, .. } => {
+            for (arg, _) in args.iter() {
+                check_scalar(operator, arg, input_schema, problems);
+            }
+        }
+        PhysicalScalar::Cast { input, .. } => {
+            check_scalar(operator, input, input_schema, problems);
+        }
+    }
+}
+
+fn check_column_index(
+    operator: &str,
+    index: usize,
+    data_type: &DataTypeImpl,
+    input_schema: &DataSchemaRef,
+    problems: &mut Vec<String>,
+) {
+    if index >= input_schema.num_fields() {
+        problems.push(format!(
+            "{}: column {} is out of the {} columns of the input",
+            operator,
+            index,
+            input_schema.num_fields()
+        ));
+        return;
+    }
+    let field = input_schema.field(index);
+    check_type(
+        operator,
+        field.name(),
+        field.data_type(),
+        data_type,
+        problems,
+    );
+}
+
+fn check_column_name(
+    operator: &str,
+    name: &str,
+    input_schema: &DataSchemaRef,
+    problems: &mut Vec<String>,
+) {
+    if input_schema.field_with_name(name).is_err() {
+        problems.push(format!("{}: column {} is not in the input", operator, name));
+    }
+}
+
+fn check_type(
+    operator: &str,
+    name: &str,
+    input_type: &DataTypeImpl,
+    expected_type: &DataTypeImpl,
+    problems: &mut Vec<String>,
+) {
+    let input_type = remove_nullable(input_type);
+    let expected_type = remove_nullable(expected_type);
+    if input_type.data_type_id() != expected_type.data_type_id() {
+        problems.push(format!(
+            "{}: column {} of type {} is read as {}",
+            operator,
+            name,
+            input_type.name(),
+            expected_type.name()
+        ));
+    }
+}
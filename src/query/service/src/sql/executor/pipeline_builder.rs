@@ -30,6 +30,7 @@ use common_functions::scalars::FunctionContext;
 use common_functions::scalars::FunctionFactory;
 use common_pipeline_core::Pipe;
 use common_pipeline_sinks::processors::sinks::UnionReceiveSink;
+use common_planner::IndexType;
 
 use super::AggregateFinal;
 use super::AggregatePartial;
@@ -43,17 +44,22 @@ use super::Limit;
 use super::Project;
 use super::Sort;
 use super::TableScan;
+use crate::evaluator::AdaptiveFilter;
+use crate::evaluator::EvalNode;
 use crate::evaluator::Evaluator;
+use crate::evaluator::FusedPredicate;
 use crate::interpreters::fill_missing_columns;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::transforms::ChunkOperator;
 use crate::pipelines::processors::transforms::CompoundChunkOperator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
+use crate::pipelines::processors::transforms::TransformAdaptiveFilter;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformRuntimeFilter;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::AggregatorTransformParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -62,12 +68,14 @@ use crate::pipelines::processors::RightJoinCompactor;
 use crate::pipelines::processors::SinkBuildHashTable;
 use crate::pipelines::processors::Sinker;
 use crate::pipelines::processors::SortMergeCompactor;
+use crate::pipelines::processors::Spiller;
 use crate::pipelines::processors::TransformAggregator;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformHashJoinProbe;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformSortMerge;
 use crate::pipelines::processors::TransformSortPartial;
+use crate::pipelines::processors::TransformSortSpill;
 use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SinkPipeBuilder;
@@ -85,6 +93,16 @@ pub struct PipelineBuilder {
     ctx: Arc<QueryContext>,
     main_pipeline: Pipeline,
     pub pipelines: Vec<Pipeline>,
+    /// The runtime filter of the hash join to be applied to the scan of its probe side
+    runtime_filter: Option<PendingRuntimeFilter>,
+}
+
+// The runtime filter of the build keys of a hash join, pushed down to the scan of the
+// probe side with the probe keys evaluated by the output schema of the scan.
+struct PendingRuntimeFilter {
+    table_index: IndexType,
+    join_state: Arc<JoinHashTable>,
+    probe_keys: Vec<EvalNode>,
 }
 
 impl PipelineBuilder {
@@ -93,6 +111,7 @@ impl PipelineBuilder {
             ctx,
             pipelines: vec![],
             main_pipeline: Pipeline::create(),
+            runtime_filter: None,
         }
     }
 
@@ -143,12 +162,21 @@ impl PipelineBuilder {
     }
 
     fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<JoinHashTable>> {
+        // Spill both sides to the disk by partitions beyond the max_memory_usage
+        let spill = self.ctx.get_settings().get_max_memory_usage()? != 0;
         JoinHashTable::create_join_state(
             self.ctx.clone(),
             &join.build_keys,
             join.build.output_schema()?,
             join.probe.output_schema()?,
             HashJoinDesc::create(join)?,
+            match spill {
+                true => Some(Spiller::create(
+                    self.ctx.get_spill_target()?,
+                    self.ctx.get_spill_progress(),
+                )),
+                false => None,
+            },
         )
     }
 
@@ -259,7 +287,25 @@ impl PipelineBuilder {
                     output_schema: scan.output_schema()?,
                 }],
             ))
-        })
+        })?;
+
+        match self.runtime_filter.take() {
+            Some(runtime_filter) if runtime_filter.table_index == scan.table_index => {
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(TransformRuntimeFilter::create(
+                        input,
+                        output,
+                        func_ctx.clone(),
+                        runtime_filter.join_state.clone(),
+                        runtime_filter.probe_keys.clone(),
+                    ))
+                })
+            }
+            runtime_filter => {
+                self.runtime_filter = runtime_filter;
+                Ok(())
+            }
+        }
     }
 
     fn build_filter(&mut self, filter: &Filter) -> Result<()> {
@@ -286,6 +332,29 @@ impl PipelineBuilder {
             };
         }
         let func_ctx = self.ctx.try_get_function_context()?;
+        let fused = if self.ctx.get_settings().get_enable_fused_filter()? {
+            FusedPredicate::try_create(&predicate)
+        } else {
+            None
+        };
+
+        // The conjuncts are ordered at runtime only if they are not fused.
+        if fused.is_none()
+            && filter.predicates.len() > 1
+            && self.ctx.get_settings().get_enable_adaptive_filter()?
+        {
+            let conjuncts = Evaluator::eval_physical_scalars(&filter.predicates)?;
+            let adaptive_filter = AdaptiveFilter::create(conjuncts);
+            return self.main_pipeline.add_transform(|input, output| {
+                Ok(TransformAdaptiveFilter::create(
+                    input,
+                    output,
+                    func_ctx.clone(),
+                    adaptive_filter.clone(),
+                ))
+            });
+        }
+
         let predicate = Evaluator::eval_physical_scalar(&predicate)?;
 
         self.main_pipeline.add_transform(|input, output| {
@@ -295,6 +364,7 @@ impl PipelineBuilder {
                 func_ctx.clone(),
                 vec![ChunkOperator::Filter {
                     eval: predicate.clone(),
+                    fused: fused.clone(),
                 }],
             ))
         })?;
@@ -375,6 +445,11 @@ impl PipelineBuilder {
             &aggregate.agg_funcs,
         )?;
 
+        // Spill the groups to the disk beyond the max_memory_usage
+        let spill = self.ctx.get_settings().get_max_memory_usage()? != 0;
+        let spill_target = self.ctx.get_spill_target()?;
+        let spill_progress = self.ctx.get_spill_progress();
+
         self.main_pipeline.resize(1)?;
         self.main_pipeline.add_transform(|input, output| {
             TransformAggregator::try_create_final(
@@ -382,6 +457,7 @@ impl PipelineBuilder {
                 output.clone(),
                 AggregatorTransformParams::try_create(input, output, &params)?,
                 self.ctx.clone(),
+                spill.then(|| Spiller::create(spill_target.clone(), spill_progress.clone())),
             )
         })?;
 
@@ -450,6 +526,40 @@ impl PipelineBuilder {
             TransformSortPartial::try_create(input, output, sort.limit, sort_desc.clone())
         })?;
 
+        // Spill the sorted runs to the disk beyond the max_memory_usage
+        let settings = self.ctx.get_settings();
+        if settings.get_max_memory_usage()? != 0 {
+            let block_size = settings.get_max_block_size()? as usize;
+            let spill_target = self.ctx.get_spill_target()?;
+            let spill_progress = self.ctx.get_spill_progress();
+
+            // Merge
+            self.main_pipeline.add_transform(|input, output| {
+                TransformSortSpill::try_create(
+                    input,
+                    output,
+                    sort.limit,
+                    block_size,
+                    sort_desc.clone(),
+                    Spiller::create(spill_target.clone(), spill_progress.clone()),
+                )
+            })?;
+
+            self.main_pipeline.resize(1)?;
+
+            // Concat merge in single thread
+            return self.main_pipeline.add_transform(|input, output| {
+                TransformSortSpill::try_create(
+                    input,
+                    output,
+                    sort.limit,
+                    block_size,
+                    sort_desc.clone(),
+                    Spiller::create(spill_target.clone(), spill_progress.clone()),
+                )
+            });
+        }
+
         // Merge
         self.main_pipeline.add_transform(|input, output| {
             TransformSortMerge::try_create(
@@ -481,16 +591,18 @@ impl PipelineBuilder {
     }
 
     fn build_join_probe(&mut self, join: &HashJoin, state: Arc<JoinHashTable>) -> Result<()> {
+        self.runtime_filter = Self::probe_runtime_filter(join, state.clone())?;
         self.build_pipeline(&join.probe)?;
+        self.runtime_filter = None;
 
         self.main_pipeline.add_transform(|input, output| {
-            Ok(TransformHashJoinProbe::create(
+            TransformHashJoinProbe::try_create(
                 self.ctx.clone(),
                 input,
                 output,
                 state.clone(),
                 join.output_schema()?,
-            ))
+            )
         })?;
 
         if join.join_type == JoinType::Mark {
@@ -529,6 +641,105 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    // The runtime filter is only pushed down to the scan of the probe side reached through
+    // the filters and the projections, and only for the joins never returning the
+    // unmatched rows of the probe side.
+    //
+    // The probe side of a shuffled join is not filtered, as the build side of each node
+    // holds only a part of the keys, while a broadcast join is filtered by all the keys.
+    fn probe_runtime_filter(
+        join: &HashJoin,
+        state: Arc<JoinHashTable>,
+    ) -> Result<Option<PendingRuntimeFilter>> {
+        if !matches!(
+            join.join_type,
+            JoinType::Inner
+                | JoinType::LeftSemi
+                | JoinType::Right
+                | JoinType::RightSemi
+                | JoinType::RightAnti
+        ) || join.from_correlated_subquery
+            || join.probe_keys.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let scan = match Self::probe_side_scan(&join.probe) {
+            Some(scan) => scan,
+            None => return Ok(None),
+        };
+
+        let probe_schema = join.probe.output_schema()?;
+        let scan_schema = scan.output_schema()?;
+        let mut probe_keys = Vec::with_capacity(join.probe_keys.len());
+        for probe_key in join.probe_keys.iter() {
+            match Self::rebase_probe_key(probe_key, &probe_schema, &scan_schema) {
+                Some(probe_key) => probe_keys.push(Evaluator::eval_physical_scalar(&probe_key)?),
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(PendingRuntimeFilter {
+            table_index: scan.table_index,
+            join_state: state,
+            probe_keys,
+        }))
+    }
+
+    fn probe_side_scan(plan: &PhysicalPlan) -> Option<&TableScan> {
+        match plan {
+            PhysicalPlan::TableScan(scan) => Some(scan),
+            PhysicalPlan::Filter(filter) => Self::probe_side_scan(&filter.input),
+            PhysicalPlan::Project(project) => Self::probe_side_scan(&project.input),
+            PhysicalPlan::EvalScalar(eval_scalar) => Self::probe_side_scan(&eval_scalar.input),
+            _ => None,
+        }
+    }
+
+    // Rewrites the variables of the probe key by the columns of the same names in the
+    // output schema of the scan, `None` if the key is evaluated by the other columns.
+    fn rebase_probe_key(
+        probe_key: &PhysicalScalar,
+        probe_schema: &DataSchemaRef,
+        scan_schema: &DataSchemaRef,
+    ) -> Option<PhysicalScalar> {
+        Some(match probe_key {
+            PhysicalScalar::IndexedVariable {
+                index,
+                data_type,
+                display_name,
+            } => PhysicalScalar::IndexedVariable {
+                index: scan_schema
+                    .index_of(probe_schema.field(*index).name())
+                    .ok()?,
+                data_type: data_type.clone(),
+                display_name: display_name.clone(),
+            },
+            PhysicalScalar::Constant { .. } => probe_key.clone(),
+            PhysicalScalar::Function {
+                name,
+                args,
+                return_type,
+            } => PhysicalScalar::Function {
+                name: name.clone(),
+                args: args
+                    .iter()
+                    .map(|(arg, data_type)| {
+                        Some((
+                            Self::rebase_probe_key(arg, probe_schema, scan_schema)?,
+                            data_type.clone(),
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+                return_type: return_type.clone(),
+            },
+            PhysicalScalar::Cast { input, target } => PhysicalScalar::Cast {
+                input: Box::new(Self::rebase_probe_key(input, probe_schema, scan_schema)?),
+                target: target.clone(),
+            },
+        })
+    }
+
     pub fn build_exchange_source(&mut self, exchange_source: &ExchangeSource) -> Result<()> {
         let exchange_manager = self.ctx.get_exchange_manager();
         let build_res = exchange_manager.get_fragment_source(
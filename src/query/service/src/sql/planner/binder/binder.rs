@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::AlterTaskAction;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Statement;
+use common_ast::ast::TaskSchedule;
+use common_ast::parser::parse_script;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
@@ -24,20 +27,55 @@ use common_ast::UDFValidator;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::TypeFactory;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CronSchedule;
+use common_meta_types::DictionaryColumn;
+use common_meta_types::DictionaryMeta;
+use common_meta_types::DictionarySource;
+use common_meta_types::MaskingPolicy;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::ResourceGroup;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::ScheduledTask;
+use common_meta_types::Sequence;
 use common_meta_types::UserDefinedFunction;
+use common_planner::plans::AlterTaskPlan;
 use common_planner::plans::AlterUDFPlan;
 use common_planner::plans::CallPlan;
+use common_planner::plans::CreateDictionaryPlan;
+use common_planner::plans::CreateMaskingPolicyPlan;
+use common_planner::plans::CreateNetworkPolicyPlan;
+use common_planner::plans::CreateResourceGroupPlan;
 use common_planner::plans::CreateRolePlan;
+use common_planner::plans::CreateRowAccessPolicyPlan;
+use common_planner::plans::CreateSequencePlan;
+use common_planner::plans::CreateTaskPlan;
 use common_planner::plans::CreateUDFPlan;
+use common_planner::plans::DrainNodePlan;
+use common_planner::plans::DropDictionaryPlan;
+use common_planner::plans::DropMaskingPolicyPlan;
+use common_planner::plans::DropNetworkPolicyPlan;
+use common_planner::plans::DropResourceGroupPlan;
 use common_planner::plans::DropRolePlan;
+use common_planner::plans::DropRowAccessPolicyPlan;
+use common_planner::plans::DropSequencePlan;
 use common_planner::plans::DropStagePlan;
+use common_planner::plans::DropTaskPlan;
 use common_planner::plans::DropUDFPlan;
 use common_planner::plans::DropUserPlan;
+use common_planner::plans::ExecuteImmediatePlan;
+use common_planner::plans::SetRolePlan;
+use common_planner::plans::SetUserVariablePlan;
 use common_planner::plans::ShowGrantsPlan;
+use common_planner::plans::TaskAction;
+use common_planner::plans::UnsetUserVariablePlan;
 use common_planner::plans::UseDatabasePlan;
 use common_planner::MetadataRef;
+use common_users::parse_ip_list;
 
+use crate::sql::planner::semantic::normalize_identifier;
 use crate::sql::plans::Plan;
 use crate::sql::plans::RewriteKind;
 use crate::sql::BindContext;
@@ -93,6 +131,7 @@ impl<'a> Binder {
                     metadata: self.metadata.clone(),
                     bind_context: Box::new(bind_context),
                     rewrite_kind: None,
+                    formatted_ast: Some(stmt.to_string()),
                 }
             }
 
@@ -135,9 +174,11 @@ impl<'a> Binder {
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
+            Statement::BackupDatabase(stmt) => self.bind_backup_database(stmt).await?,
+            Statement::RestoreDatabase(stmt) => self.bind_restore_database(stmt).await?,
             Statement::UseDatabase { database } =>  {
                 Plan::UseDatabase(Box::new(UseDatabasePlan {
-                    database: database.name.clone(),
+                    database: normalize_identifier(database, &self.name_resolution_ctx).name,
                 }))
             }
             // Tables
@@ -147,13 +188,19 @@ impl<'a> Binder {
             Statement::ShowTablesStatus(stmt) => {
                 self.bind_show_tables_status(bind_context, stmt).await?
             }
+            Statement::ShowDropTables(stmt) => {
+                self.bind_show_drop_tables(bind_context, stmt).await?
+            }
             Statement::CreateTable(stmt) => self.bind_create_table(stmt).await?,
+            Statement::AttachTable(stmt) => self.bind_attach_table(stmt).await?,
             Statement::DropTable(stmt) => self.bind_drop_table(stmt).await?,
+            Statement::DetachTable(stmt) => self.bind_detach_table(stmt).await?,
             Statement::UndropTable(stmt) => self.bind_undrop_table(stmt).await?,
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
             Statement::RenameTable(stmt) => self.bind_rename_table(stmt).await?,
             Statement::TruncateTable(stmt) => self.bind_truncate_table(stmt).await?,
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(stmt).await?,
+            Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
 
             // Views
@@ -161,6 +208,16 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+
+            // Aggregating indexes
+            Statement::CreateAggregatingIndex(stmt) => {
+                self.bind_create_aggregating_index(stmt).await?
+            }
+            Statement::DropAggregatingIndex(stmt) => self.bind_drop_aggregating_index(stmt).await?,
+
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
             Statement::DropUser { if_exists, user } => Plan::DropUser(Box::new(DropUserPlan {
@@ -186,6 +243,9 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::SetRole { role_name } => Plan::SetRole(Box::new(SetRolePlan {
+                role_name: role_name.to_string(),
+            })),
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -204,7 +264,127 @@ impl<'a> Binder {
             Statement::RemoveStage { location, pattern } => {
                 self.bind_remove_stage(location, pattern).await?
             }
+
+            // Connections
+            Statement::ShowConnections => self.bind_rewrite_to_query(bind_context, "SELECT name, storage_type, storage_params, comment FROM system.connections ORDER BY name", RewriteKind::ShowConnections).await?,
+            Statement::DescribeConnection { connection_name } => {
+                let name = normalize_identifier(connection_name, &self.name_resolution_ctx).name;
+                self.bind_rewrite_to_query(bind_context, format!("SELECT * FROM system.connections WHERE name = '{name}'").as_str(), RewriteKind::DescribeConnection).await?
+            }
+            Statement::CreateConnection {
+                if_not_exists,
+                connection_name,
+                storage_type,
+                storage_params,
+            } => {
+                self.bind_create_connection(*if_not_exists, connection_name, storage_type, storage_params)
+                    .await?
+            }
+            Statement::DropConnection {
+                if_exists,
+                connection_name,
+            } => self.bind_drop_connection(*if_exists, connection_name).await?,
+
+            // Sequences
+            Statement::CreateSequence {
+                if_not_exists,
+                sequence_name,
+                start,
+                increment,
+                comment,
+            } => {
+                let increment = increment.unwrap_or(1);
+                if increment == 0 {
+                    return Err(ErrorCode::BadArguments(
+                        "the increment of a sequence can not be 0",
+                    ));
+                }
+                let name = normalize_identifier(sequence_name, &self.name_resolution_ctx).name;
+                let mut sequence = Sequence::new(&name, start.unwrap_or(1), increment);
+                sequence.comment = comment.clone().unwrap_or_default();
+                Plan::CreateSequence(Box::new(CreateSequencePlan {
+                    if_not_exists: *if_not_exists,
+                    sequence,
+                }))
+            }
+            Statement::DropSequence {
+                if_exists,
+                sequence_name,
+            } => Plan::DropSequence(Box::new(DropSequencePlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(sequence_name, &self.name_resolution_ctx).name,
+            })),
+            Statement::ShowSequences => self.bind_rewrite_to_query(bind_context, "SELECT name, start, increment, next_value, comment FROM system.sequences ORDER BY name", RewriteKind::ShowSequences).await?,
+
+            // Plan hints
+            Statement::CreatePlanHint {
+                if_not_exists,
+                hint_name,
+                statement,
+                hints,
+            } => {
+                self.bind_create_plan_hint(*if_not_exists, hint_name, statement, hints)
+                    .await?
+            }
+            Statement::DropPlanHint {
+                if_exists,
+                hint_name,
+            } => self.bind_drop_plan_hint(*if_exists, hint_name).await?,
+            Statement::ShowPlanHints => self.bind_rewrite_to_query(bind_context, "SELECT name, statement, hints FROM system.plan_hints ORDER BY name", RewriteKind::ShowPlanHints).await?,
+
+            // Cluster
+            Statement::DrainNode { node_id } => Plan::DrainNode(Box::new(DrainNodePlan {
+                node_id: node_id.clone(),
+            })),
+
+            Statement::CreateDictionary(stmt) => {
+                let mut columns = Vec::with_capacity(stmt.columns.len());
+                for column in &stmt.columns {
+                    let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+                    if columns.iter().any(|c: &DictionaryColumn| c.name == name) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "duplicated column {} of the dictionary",
+                            name
+                        )));
+                    }
+                    let data_type = column.data_type.to_string();
+                    TypeFactory::instance().get(&data_type)?;
+                    columns.push(DictionaryColumn { name, data_type });
+                }
+                let primary_key =
+                    normalize_identifier(&stmt.primary_key, &self.name_resolution_ctx).name;
+                if !columns.iter().any(|c| c.name == primary_key) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "the primary key {} is not a column of the dictionary",
+                        primary_key
+                    )));
+                }
+                let dictionary = DictionaryMeta {
+                    name: normalize_identifier(&stmt.dictionary_name, &self.name_resolution_ctx)
+                        .name,
+                    columns,
+                    primary_key,
+                    source: DictionarySource::from_options(&stmt.source_options)?,
+                    // the rows are loaded again every 5 minutes by default
+                    lifetime_secs: stmt.lifetime.unwrap_or(300),
+                    comment: stmt.comment.clone().unwrap_or_default(),
+                    created_on: 0,
+                };
+                Plan::CreateDictionary(Box::new(CreateDictionaryPlan {
+                    if_not_exists: stmt.if_not_exists,
+                    dictionary,
+                }))
+            }
+            Statement::DropDictionary {
+                if_exists,
+                dictionary_name,
+            } => Plan::DropDictionary(Box::new(DropDictionaryPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(dictionary_name, &self.name_resolution_ctx).name,
+            })),
+            Statement::ShowDictionaries => self.bind_rewrite_to_query(bind_context, "SELECT name, primary_key, columns, source, lifetime, rows, comment FROM system.dictionaries ORDER BY name", RewriteKind::ShowDictionaries).await?,
             Statement::Insert(stmt) => self.bind_insert(bind_context, stmt).await?,
+            Statement::Replace(stmt) => self.bind_replace(bind_context, stmt).await?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -276,6 +456,177 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 name: udf_name.to_string(),
             })),
+
+            // Row access policies
+            Statement::CreateRowAccessPolicy {
+                if_not_exists,
+                policy_name,
+                parameters,
+                definition,
+            } => {
+                let mut validator = UDFValidator {
+                    name: normalize_identifier(policy_name, &self.name_resolution_ctx).name,
+                    parameters: parameters.iter().map(|v| v.to_string()).collect(),
+                    ..Default::default()
+                };
+                validator.verify_definition_expr(definition)?;
+                let policy = RowAccessPolicy {
+                    name: validator.name,
+                    parameters: validator.parameters,
+                    definition: definition.to_string(),
+                };
+
+                Plan::CreateRowAccessPolicy(Box::new(CreateRowAccessPolicyPlan {
+                    if_not_exists: *if_not_exists,
+                    policy,
+                }))
+            }
+            Statement::DropRowAccessPolicy {
+                if_exists,
+                policy_name,
+            } => Plan::DropRowAccessPolicy(Box::new(DropRowAccessPolicyPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(policy_name, &self.name_resolution_ctx).name,
+            })),
+            Statement::CreateMaskingPolicy {
+                if_not_exists,
+                policy_name,
+                parameter,
+                return_type,
+                definition,
+            } => {
+                let mut validator = UDFValidator {
+                    name: normalize_identifier(policy_name, &self.name_resolution_ctx).name,
+                    parameters: vec![parameter.to_string()],
+                    ..Default::default()
+                };
+                validator.verify_definition_expr(definition)?;
+                let policy = MaskingPolicy {
+                    name: validator.name,
+                    parameter: parameter.to_string(),
+                    return_type: return_type.to_string(),
+                    definition: definition.to_string(),
+                };
+
+                Plan::CreateMaskingPolicy(Box::new(CreateMaskingPolicyPlan {
+                    if_not_exists: *if_not_exists,
+                    policy,
+                }))
+            }
+            Statement::DropMaskingPolicy {
+                if_exists,
+                policy_name,
+            } => Plan::DropMaskingPolicy(Box::new(DropMaskingPolicyPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(policy_name, &self.name_resolution_ctx).name,
+            })),
+            Statement::CreateNetworkPolicy {
+                if_not_exists,
+                policy_name,
+                allowed_ip_list,
+                blocked_ip_list,
+                comment,
+            } => {
+                parse_ip_list(allowed_ip_list)?;
+                parse_ip_list(blocked_ip_list)?;
+                let policy = NetworkPolicy::new(
+                    &normalize_identifier(policy_name, &self.name_resolution_ctx).name,
+                    allowed_ip_list.clone(),
+                    blocked_ip_list.clone(),
+                    comment.as_deref().unwrap_or_default(),
+                );
+
+                Plan::CreateNetworkPolicy(Box::new(CreateNetworkPolicyPlan {
+                    if_not_exists: *if_not_exists,
+                    policy,
+                }))
+            }
+            Statement::DropNetworkPolicy {
+                if_exists,
+                policy_name,
+            } => Plan::DropNetworkPolicy(Box::new(DropNetworkPolicyPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(policy_name, &self.name_resolution_ctx).name,
+            })),
+            Statement::CreateTask(stmt) => {
+                let (schedule, after) = match &stmt.schedule {
+                    TaskSchedule::Cron(schedule) => {
+                        CronSchedule::parse(schedule)?;
+                        (Some(schedule.clone()), None)
+                    }
+                    TaskSchedule::After(task_name) => {
+                        let after = normalize_identifier(task_name, &self.name_resolution_ctx).name;
+                        (None, Some(after))
+                    }
+                };
+                // the owner and the database are of the session creating the task
+                let task = ScheduledTask {
+                    name: normalize_identifier(&stmt.task_name, &self.name_resolution_ctx).name,
+                    schedule,
+                    after,
+                    sql: stmt.sql.to_string(),
+                    comment: stmt.comment.clone().unwrap_or_default(),
+                    ..Default::default()
+                };
+                Plan::CreateTask(Box::new(CreateTaskPlan {
+                    if_not_exists: stmt.if_not_exists,
+                    task,
+                }))
+            }
+            Statement::DropTask {
+                if_exists,
+                task_name,
+            } => Plan::DropTask(Box::new(DropTaskPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(task_name, &self.name_resolution_ctx).name,
+            })),
+            Statement::ShowTasks => self.bind_rewrite_to_query(bind_context, "SELECT name, state, schedule, after, owner, database, next_scheduled_on, comment, definition FROM system.tasks ORDER BY name", RewriteKind::ShowTasks).await?,
+            Statement::AlterTask { task_name, action } => {
+                Plan::AlterTask(Box::new(AlterTaskPlan {
+                    name: normalize_identifier(task_name, &self.name_resolution_ctx).name,
+                    action: match action {
+                        AlterTaskAction::Suspend => TaskAction::Suspend,
+                        AlterTaskAction::Resume => TaskAction::Resume,
+                        AlterTaskAction::Execute => TaskAction::Execute,
+                    },
+                }))
+            }
+            Statement::CreateResourceGroup {
+                if_not_exists,
+                group_name,
+                cpu_share,
+                max_memory_usage,
+                max_concurrency,
+                comment,
+            } => {
+                let cpu_share = cpu_share.unwrap_or(100);
+                if !(1..=100).contains(&cpu_share) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "CPU_SHARE must be between 1 and 100, but got {}",
+                        cpu_share
+                    )));
+                }
+                let group = ResourceGroup::new(
+                    &normalize_identifier(group_name, &self.name_resolution_ctx).name,
+                    cpu_share,
+                    max_memory_usage.unwrap_or_default(),
+                    max_concurrency.unwrap_or_default(),
+                    comment.as_deref().unwrap_or_default(),
+                );
+
+                Plan::CreateResourceGroup(Box::new(CreateResourceGroupPlan {
+                    if_not_exists: *if_not_exists,
+                    group,
+                }))
+            }
+            Statement::DropResourceGroup {
+                if_exists,
+                group_name,
+            } => Plan::DropResourceGroup(Box::new(DropResourceGroupPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(group_name, &self.name_resolution_ctx).name,
+            })),
+
             Statement::Call(stmt) => Plan::Call(Box::new(CallPlan {
                 name: stmt.name.clone(),
                 args: stmt.args.clone(),
@@ -291,11 +642,39 @@ impl<'a> Binder {
                 self.bind_set_variable(bind_context, *is_global, variable, value)
                     .await?
             }
+            // The variables are case-insensitive, as the `$x` references are.
+            Statement::SetUserVariable { variable, value } => {
+                Plan::SetUserVariable(Box::new(SetUserVariablePlan {
+                    variable: variable.name.to_lowercase(),
+                    expr: value.to_string(),
+                }))
+            }
+            Statement::UnsetUserVariable { variable } => {
+                Plan::UnsetUserVariable(Box::new(UnsetUserVariablePlan {
+                    variable: variable.name.to_lowercase(),
+                }))
+            }
+            // The script is parsed to report the syntax errors before it runs, but its statements
+            // are bound when they run, since they may depend on the former ones.
+            Statement::ExecuteImmediate { script } => {
+                let tokens = tokenize_sql(script)?;
+                let backtrace = Backtrace::new();
+                let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+                parse_script(&tokens, sql_dialect, &backtrace)?;
+                Plan::ExecuteImmediate(Box::new(ExecuteImmediatePlan {
+                    script: script.clone(),
+                }))
+            }
             Statement::KillStmt { kill_target, object_id } => {
                 self.bind_kill_stmt(bind_context, kill_target, object_id.as_str())
                     .await?
             }
 
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Rollback => Plan::Rollback,
+
             // share statements
             Statement::CreateShare(stmt) => {
                 self.bind_create_share(stmt).await?
@@ -337,7 +716,12 @@ impl<'a> Binder {
         let tokens = tokenize_sql(query)?;
         let backtrace = Backtrace::new();
         let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL, &backtrace)?;
-        let mut plan = self.bind_statement(bind_context, &stmt).await?;
+        // The rewritten queries refer to the system tables in lower case, whatever case the
+        // identifiers of the session are normalized to.
+        let name_resolution_ctx = std::mem::take(&mut self.name_resolution_ctx);
+        let plan = self.bind_statement(bind_context, &stmt).await;
+        self.name_resolution_ctx = name_resolution_ctx;
+        let mut plan = plan?;
 
         if let Plan::Query { rewrite_kind, .. } = &mut plan {
             *rewrite_kind = Some(rewrite_kind_r)
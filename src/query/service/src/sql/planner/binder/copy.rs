@@ -32,6 +32,7 @@ use common_legacy_planners::ReadDataSourcePlan;
 use common_legacy_planners::SourceInfo;
 use common_legacy_planners::StageTableInfo;
 use common_meta_types::FileFormatOptions;
+use common_meta_types::OnErrorMode;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::UserStageInfo;
 use common_storage::parse_uri_location;
@@ -39,6 +40,7 @@ use common_storage::UriLocation;
 use common_users::UserApiProvider;
 use tracing::debug;
 
+use crate::connections::resolve_stage_connection;
 use crate::sql::binder::Binder;
 use crate::sql::normalize_identifier;
 use crate::sql::plans::CopyPlanV2;
@@ -277,7 +279,8 @@ impl<'a> Binder {
             .get_table(dst_catalog_name, dst_database_name, dst_table_name)
             .await?;
 
-        let (storage_params, path) = parse_uri_location(src_uri_location)?;
+        let src_uri_location = self.resolve_uri_connection(src_uri_location).await?;
+        let (storage_params, path) = parse_uri_location(&src_uri_location)?;
         if !storage_params.is_secure() && !self.ctx.get_config().storage.allow_insecure {
             return Err(ErrorCode::StorageInsecure(
                 "copy from insecure storage is not allowed",
@@ -396,7 +399,8 @@ impl<'a> Binder {
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
 
-        let (storage_params, path) = parse_uri_location(dst_uri_location)?;
+        let dst_uri_location = self.resolve_uri_connection(dst_uri_location).await?;
+        let (storage_params, path) = parse_uri_location(&dst_uri_location)?;
         if !storage_params.is_secure() && !self.ctx.get_config().storage.allow_insecure {
             return Err(ErrorCode::StorageInsecure(
                 "copy into insecure storage is not allowed",
@@ -460,7 +464,8 @@ impl<'a> Binder {
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
 
-        let (storage_params, path) = parse_uri_location(dst_uri_location)?;
+        let dst_uri_location = self.resolve_uri_connection(dst_uri_location).await?;
+        let (storage_params, path) = parse_uri_location(&dst_uri_location)?;
         if !storage_params.is_secure() && !self.ctx.get_config().storage.allow_insecure {
             return Err(ErrorCode::StorageInsecure(
                 "copy into insecure storage is not allowed",
@@ -489,12 +494,21 @@ impl<'a> Binder {
 
         // Copy options.
         {
-            // TODO(xuanwo): COPY should handle on error.
             // on_error.
-            // if !stmt.on_error.is_empty() {
-            //     stage_info.copy_options.on_error =
-            //         OnErrorMode::from_str(&self.on_error).map_err(ErrorCode::SyntaxException)?;
-            // }
+            if !stmt.on_error.is_empty() {
+                let on_error =
+                    OnErrorMode::from_str(&stmt.on_error).map_err(ErrorCode::SyntaxException)?;
+                if matches!(
+                    on_error,
+                    OnErrorMode::SkipFile | OnErrorMode::SkipFileNum(_)
+                ) {
+                    return Err(ErrorCode::UnImplement(format!(
+                        "ON_ERROR = {} is not supported by COPY yet",
+                        stmt.on_error
+                    )));
+                }
+                stage.copy_options.on_error = on_error;
+            }
 
             // size_limit.
             if stmt.size_limit != 0 {
@@ -547,6 +561,7 @@ pub async fn parse_stage_location(
     let stage = UserApiProvider::instance()
         .get_stage(&ctx.get_tenant(), names[0])
         .await?;
+    let stage = resolve_stage_connection(&ctx.get_config(), &ctx.get_tenant(), stage).await?;
 
     let path = names.get(1).unwrap_or(&"").trim_start_matches('/');
 
@@ -567,6 +582,7 @@ pub async fn parse_stage_location_v2(
     let stage = UserApiProvider::instance()
         .get_stage(&ctx.get_tenant(), name)
         .await?;
+    let stage = resolve_stage_connection(&ctx.get_config(), &ctx.get_tenant(), stage).await?;
 
     // prefix must be endswith `/`, so we should trim path here.
     let relative_path = path.trim_start_matches('/').to_string();
@@ -618,11 +634,54 @@ pub fn parse_copy_file_format_options(
     .parse()
     .map_err(ErrorCode::UnknownCompressionType)?;
 
+    // Quote char.
+    let quote = parse_escape_string(
+        file_format_options
+            .get("quote")
+            .unwrap_or(&"".to_string())
+            .as_bytes(),
+    );
+
+    // Escape char.
+    let escape = parse_escape_string(
+        file_format_options
+            .get("escape")
+            .unwrap_or(&"".to_string())
+            .as_bytes(),
+    );
+    if quote.len() > 1 || escape.len() > 1 {
+        return Err(ErrorCode::SyntaxException(
+            "Quote and escape can only contain one char",
+        ));
+    }
+
+    // Null display.
+    let null_display = parse_escape_string(
+        file_format_options
+            .get("null_display")
+            .unwrap_or(&"".to_string())
+            .as_bytes(),
+    );
+
+    // Error on column count mismatch.
+    let error_on_column_count_mismatch = file_format_options
+        .get("error_on_column_count_mismatch")
+        .unwrap_or(&"true".to_string())
+        .to_lowercase()
+        .parse::<bool>()
+        .map_err(|_| {
+            ErrorCode::SyntaxException("error_on_column_count_mismatch must be true or false")
+        })?;
+
     Ok(FileFormatOptions {
         format: file_format,
         skip_header,
         field_delimiter,
         record_delimiter,
         compression,
+        quote,
+        escape,
+        null_display,
+        error_on_column_count_mismatch,
     })
 }
@@ -139,6 +139,9 @@ impl<'a> Binder {
                     .unwrap_or_else(|| self.ctx.get_current_database());
                 GrantObject::Database(catalog_name, database_name)
             }
+            AccountMgrLevel::FutureTables(database_name) => {
+                GrantObject::FutureTables(catalog_name, database_name.clone())
+            }
         }
     }
 
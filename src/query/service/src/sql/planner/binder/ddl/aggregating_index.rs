@@ -0,0 +1,277 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateAggregatingIndexStmt;
+use common_ast::ast::DropAggregatingIndexStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Query;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::TableReference;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planner::plans::AggregatingIndexAggregate;
+use common_planner::plans::AggregatingIndexDefinition;
+use common_planner::plans::CreateAggregatingIndexPlan;
+use common_planner::plans::DropAggregatingIndexPlan;
+
+use crate::sql::binder::Binder;
+use crate::sql::planner::semantic::normalize_identifier;
+use crate::sql::plans::Plan;
+use crate::storages::fuse::FuseTable;
+
+/// The aggregate functions of which the results can be aggregated again.
+const AGGREGATING_INDEX_FUNCTIONS: [&str; 4] = ["sum", "count", "min", "max"];
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_create_aggregating_index(
+        &mut self,
+        stmt: &CreateAggregatingIndexStmt<'a>,
+    ) -> Result<Plan> {
+        let CreateAggregatingIndexStmt {
+            if_not_exists,
+            index,
+            catalog,
+            database,
+            table,
+            query,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let index_name = normalize_identifier(index, &self.name_resolution_ctx).name;
+
+        let table_meta = self.ctx.get_table(&catalog, &database, &table).await?;
+        if FuseTable::try_from_table(table_meta.as_ref()).is_err() {
+            return Err(ErrorCode::IllegalAggregatingIndexFormat(format!(
+                "{}.{} is not a FUSE table, aggregating index can only be created on FUSE tables",
+                database, table
+            )));
+        }
+        let definition = self.resolve_aggregating_index_definition(
+            query,
+            &database,
+            &table,
+            table_meta.schema().as_ref(),
+        )?;
+
+        let plan = CreateAggregatingIndexPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+            definition,
+        };
+        Ok(Plan::CreateAggregatingIndex(Box::new(plan)))
+    }
+
+    pub(in crate::sql::planner::binder) async fn bind_drop_aggregating_index(
+        &mut self,
+        stmt: &DropAggregatingIndexStmt<'a>,
+    ) -> Result<Plan> {
+        let DropAggregatingIndexStmt {
+            if_exists,
+            index,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let index_name = normalize_identifier(index, &self.name_resolution_ctx).name;
+
+        let plan = DropAggregatingIndexPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropAggregatingIndex(Box::new(plan)))
+    }
+
+    /// The index is defined by a plain aggregation of the table, like
+    /// `SELECT a, b, sum(c), count(*) FROM t GROUP BY a, b`, whose results can be aggregated
+    /// again to answer the aggregations of the table by a subset of the group by columns.
+    fn resolve_aggregating_index_definition(
+        &self,
+        query: &Query<'a>,
+        database: &str,
+        table: &str,
+        schema: &DataSchema,
+    ) -> Result<AggregatingIndexDefinition> {
+        let illegal = |msg: &str| {
+            ErrorCode::IllegalAggregatingIndexFormat(format!(
+                "{}, the query of aggregating index must be like \
+                 `SELECT <column>, ..., <aggregate>(<column>), ... FROM <table> [GROUP BY <column>, ...]`",
+                msg
+            ))
+        };
+
+        let select = match &query.body {
+            SetExpr::Select(select)
+                if query.with.is_none()
+                    && query.order_by.is_empty()
+                    && query.limit.is_empty()
+                    && query.offset.is_none() =>
+            {
+                select
+            }
+            _ => return Err(illegal("not a plain SELECT")),
+        };
+        if select.distinct || select.selection.is_some() || select.having.is_some() {
+            return Err(illegal("DISTINCT, WHERE and HAVING are not allowed"));
+        }
+        match select.from.as_slice() {
+            [TableReference::Table {
+                catalog: None,
+                database: from_database,
+                table: from_table,
+                travel_point: None,
+                ..
+            }] if normalize_identifier(from_table, &self.name_resolution_ctx).name == table
+                && from_database.as_ref().map_or(true, |ident| {
+                    normalize_identifier(ident, &self.name_resolution_ctx).name == database
+                }) => {}
+            _ => return Err(illegal(&format!("not a query of the table {}", table))),
+        }
+
+        let column_name = |ident: &Identifier<'a>| -> Result<String> {
+            let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+            match schema.has_field(&name) {
+                true => Ok(name),
+                false => Err(ErrorCode::IllegalAggregatingIndexFormat(format!(
+                    "unknown column {} of the table {}",
+                    name, table
+                ))),
+            }
+        };
+
+        let mut group_by = Vec::with_capacity(select.group_by.len());
+        for expr in &select.group_by {
+            match expr {
+                Expr::ColumnRef {
+                    database: None,
+                    table: None,
+                    column,
+                    ..
+                } => {
+                    let column = column_name(column)?;
+                    if !group_by.contains(&column) {
+                        group_by.push(column);
+                    }
+                }
+                _ => return Err(illegal("only the columns can be grouped by")),
+            }
+        }
+
+        let mut aggregates: Vec<AggregatingIndexAggregate> = vec![];
+        for target in &select.select_list {
+            let expr = match target {
+                SelectTarget::AliasedExpr { expr, .. } => expr.as_ref(),
+                SelectTarget::QualifiedName(_) => return Err(illegal("wildcard is not allowed")),
+            };
+            let aggregate = match expr {
+                Expr::ColumnRef {
+                    database: None,
+                    table: None,
+                    column,
+                    ..
+                } => {
+                    let column = column_name(column)?;
+                    if !group_by.contains(&column) {
+                        return Err(illegal(&format!(
+                            "column {} must appear in the GROUP BY clause",
+                            column
+                        )));
+                    }
+                    continue;
+                }
+                Expr::CountAll { .. } => AggregatingIndexAggregate {
+                    func_name: "count".to_string(),
+                    column: None,
+                },
+                Expr::FunctionCall {
+                    distinct: false,
+                    name,
+                    args,
+                    params,
+                    ..
+                } if params.is_empty() => {
+                    let func_name = name.name.to_lowercase();
+                    if !AGGREGATING_INDEX_FUNCTIONS.contains(&func_name.as_str()) {
+                        return Err(illegal(&format!(
+                            "aggregate function {} is not supported",
+                            func_name
+                        )));
+                    }
+                    let column = match args.as_slice() {
+                        [Expr::ColumnRef {
+                            database: None,
+                            table: None,
+                            column,
+                            ..
+                        }] => column_name(column)?,
+                        _ => return Err(illegal("only the columns can be aggregated")),
+                    };
+                    // The count of a not null column is the count of rows, just like the binder
+                    // removes the argument of the count.
+                    let nullable = schema.field_with_name(&column)?.is_nullable();
+                    AggregatingIndexAggregate {
+                        column: if func_name == "count" && !nullable {
+                            None
+                        } else {
+                            Some(column)
+                        },
+                        func_name,
+                    }
+                }
+                _ => return Err(illegal("only the columns and the aggregates are allowed")),
+            };
+            if !aggregates.contains(&aggregate) {
+                aggregates.push(aggregate);
+            }
+        }
+        if aggregates.is_empty() {
+            return Err(illegal("no aggregates"));
+        }
+
+        Ok(AggregatingIndexDefinition {
+            group_by,
+            aggregates,
+        })
+    }
+}
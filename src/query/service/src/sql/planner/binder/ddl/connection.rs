@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_ast::ast::Identifier;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+use common_planner::plans::CreateConnectionPlan;
+use common_planner::plans::DropConnectionPlan;
+use common_storage::UriLocation;
+use opendal::Scheme;
+
+use crate::connections::resolve_uri_connection;
+use crate::sql::binder::Binder;
+use crate::sql::planner::semantic::normalize_identifier;
+use crate::sql::plans::Plan;
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_create_connection(
+        &mut self,
+        if_not_exists: bool,
+        connection_name: &Identifier<'a>,
+        storage_type: &str,
+        storage_params: &BTreeMap<String, String>,
+    ) -> Result<Plan> {
+        let storage_type = storage_type.to_lowercase();
+        storage_type.parse::<Scheme>().map_err(|e| {
+            ErrorCode::BadArguments(format!("unknown storage type {}: {}", storage_type, e))
+        })?;
+        if storage_params.contains_key("connection_name") {
+            return Err(ErrorCode::BadArguments(
+                "connection_name can't be a parameter of the connection",
+            ));
+        }
+
+        let connection = ConnectionInfo::new(
+            &normalize_identifier(connection_name, &self.name_resolution_ctx).name,
+            &storage_type,
+            storage_params.clone(),
+            "",
+        );
+        Ok(Plan::CreateConnection(Box::new(CreateConnectionPlan {
+            if_not_exists,
+            connection,
+        })))
+    }
+
+    pub(in crate::sql::planner::binder) async fn bind_drop_connection(
+        &mut self,
+        if_exists: bool,
+        connection_name: &Identifier<'a>,
+    ) -> Result<Plan> {
+        Ok(Plan::DropConnection(Box::new(DropConnectionPlan {
+            if_exists,
+            name: normalize_identifier(connection_name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    /// Resolves the connection referenced by `connection_name` in the connection of the location,
+    /// the parameters of the connection are overridden by the ones given with the location.
+    pub(in crate::sql::planner::binder) async fn resolve_uri_connection(
+        &self,
+        location: &UriLocation,
+    ) -> Result<UriLocation> {
+        resolve_uri_connection(&self.ctx.get_config(), &self.ctx.get_tenant(), location).await
+    }
+}
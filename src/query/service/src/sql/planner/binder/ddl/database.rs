@@ -17,14 +17,17 @@ use std::collections::BTreeMap;
 
 use common_ast::ast::AlterDatabaseAction;
 use common_ast::ast::AlterDatabaseStmt;
+use common_ast::ast::BackupDatabaseStmt;
 use common_ast::ast::CreateDatabaseStmt;
 use common_ast::ast::DatabaseEngine;
 use common_ast::ast::DropDatabaseStmt;
+use common_ast::ast::RestoreDatabaseStmt;
 use common_ast::ast::SQLProperty;
 use common_ast::ast::ShowCreateDatabaseStmt;
 use common_ast::ast::ShowDatabasesStmt;
 use common_ast::ast::ShowLimit;
 use common_ast::ast::UndropDatabaseStmt;
+use common_ast::ast::UriLocation as AstUriLocation;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::ToDataType;
@@ -36,12 +39,17 @@ use common_planner::plans::CreateDatabasePlan;
 use common_planner::plans::DropDatabasePlan;
 use common_planner::plans::RenameDatabaseEntity;
 use common_planner::plans::RenameDatabasePlan;
+use common_planner::plans::SetDatabaseCommentPlan;
 use common_planner::plans::ShowCreateDatabasePlan;
 use common_planner::plans::UndropDatabasePlan;
+use common_storage::parse_uri_location;
+use common_storage::UriLocation;
 
 use crate::sql::binder::Binder;
 use crate::sql::planner::semantic::normalize_identifier;
+use crate::sql::plans::BackupDatabasePlan;
 use crate::sql::plans::Plan;
+use crate::sql::plans::RestoreDatabasePlan;
 use crate::sql::plans::RewriteKind;
 use crate::sql::BindContext;
 
@@ -112,7 +120,7 @@ impl<'a> Binder {
 
         match &action {
             AlterDatabaseAction::RenameDatabase { new_db } => {
-                let new_database = new_db.name.clone();
+                let new_database = normalize_identifier(new_db, &self.name_resolution_ctx).name;
                 let entry = RenameDatabaseEntity {
                     if_exists: *if_exists,
                     catalog,
@@ -125,9 +133,68 @@ impl<'a> Binder {
                     entities: vec![entry],
                 })))
             }
+            AlterDatabaseAction::SetComment { comment } => {
+                Ok(Plan::SetDatabaseComment(Box::new(SetDatabaseCommentPlan {
+                    if_exists: *if_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    comment: comment.clone(),
+                })))
+            }
         }
     }
 
+    pub(in crate::sql::planner::binder) async fn bind_backup_database(
+        &self,
+        stmt: &BackupDatabaseStmt<'a>,
+    ) -> Result<Plan> {
+        let BackupDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+
+        Ok(Plan::BackupDatabase(Box::new(BackupDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            location: backup_location(location)?,
+        })))
+    }
+
+    pub(in crate::sql::planner::binder) async fn bind_restore_database(
+        &self,
+        stmt: &RestoreDatabaseStmt<'a>,
+    ) -> Result<Plan> {
+        let RestoreDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+
+        Ok(Plan::RestoreDatabase(Box::new(RestoreDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            location: backup_location(location)?,
+        })))
+    }
+
     pub(in crate::sql::planner::binder) async fn bind_drop_database(
         &self,
         stmt: &DropDatabaseStmt<'a>,
@@ -184,6 +251,7 @@ impl<'a> Binder {
             engine,
             options,
             from_share,
+            comment,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -199,7 +267,7 @@ impl<'a> Binder {
         } else {
             engine
         };
-        let meta = self.database_meta(engine, options, from_share)?;
+        let meta = self.database_meta(engine, options, from_share, comment)?;
 
         Ok(Plan::CreateDatabase(Box::new(CreateDatabasePlan {
             if_not_exists: *if_not_exists,
@@ -215,6 +283,7 @@ impl<'a> Binder {
         engine: &Option<DatabaseEngine>,
         options: &[SQLProperty],
         from_share: &Option<ShareNameIdent>,
+        comment: &Option<String>,
     ) -> Result<DatabaseMeta> {
         let options = options
             .iter()
@@ -232,7 +301,25 @@ impl<'a> Binder {
             engine_options,
             options,
             from_share: from_share.clone(),
+            comment: comment.clone().unwrap_or_default(),
             ..Default::default()
         })
     }
 }
+
+// The storage of a backup is rooted at the location, the connection of the location is
+// checked here instead of while copying.
+fn backup_location(location: &AstUriLocation) -> Result<UriLocation> {
+    let mut path = location.path.clone();
+    if !path.ends_with('/') {
+        path.push('/');
+    }
+    let location = UriLocation {
+        protocol: location.protocol.clone(),
+        name: location.name.clone(),
+        path,
+        connection: location.connection.clone(),
+    };
+    parse_uri_location(&location)?;
+    Ok(location)
+}
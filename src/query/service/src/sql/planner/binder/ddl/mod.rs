@@ -13,8 +13,12 @@
 // limitations under the License.
 
 mod account;
+mod aggregating_index;
+mod connection;
 mod database;
+mod plan_hint;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod view;
@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::Identifier;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Backtrace;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PlanHint;
+use common_planner::plans::CreatePlanHintPlan;
+use common_planner::plans::DropPlanHintPlan;
+
+use crate::sql::binder::Binder;
+use crate::sql::planner::semantic::normalize_identifier;
+use crate::sql::plans::Plan;
+use crate::sql::PlanCacheStatement;
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_create_plan_hint(
+        &mut self,
+        if_not_exists: bool,
+        hint_name: &Identifier<'a>,
+        statement: &str,
+        hints: &str,
+    ) -> Result<Plan> {
+        // The statement is matched by its shape, in which the literals are placeholders, so the
+        // hints apply to the statement with any literals.
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(statement)?;
+        let backtrace = Backtrace::new();
+        let (stmt, _) = parse_sql(&tokens, sql_dialect, &backtrace)?;
+        if !matches!(stmt, Statement::Query(_)) {
+            return Err(ErrorCode::BadArguments(
+                "the statement of a plan hint must be a query",
+            ));
+        }
+        let shape = match PlanCacheStatement::try_create(&tokens, sql_dialect) {
+            Some(statement) => statement.shape,
+            None => {
+                return Err(ErrorCode::BadArguments(
+                    "the statement of a plan hint can not have hints",
+                ));
+            }
+        };
+
+        check_hints(hints)?;
+        let plan_hint = PlanHint::new(
+            &normalize_identifier(hint_name, &self.name_resolution_ctx).name,
+            statement,
+            &shape,
+            hints,
+        );
+        Ok(Plan::CreatePlanHint(Box::new(CreatePlanHintPlan {
+            if_not_exists,
+            plan_hint,
+        })))
+    }
+
+    pub(in crate::sql::planner::binder) async fn bind_drop_plan_hint(
+        &mut self,
+        if_exists: bool,
+        hint_name: &Identifier<'a>,
+    ) -> Result<Plan> {
+        Ok(Plan::DropPlanHint(Box::new(DropPlanHintPlan {
+            if_exists,
+            name: normalize_identifier(hint_name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
+
+// The hints are put into the queries as they are, they must be the hints of a `SELECT`.
+fn check_hints(hints: &str) -> Result<()> {
+    if hints.contains("*/") {
+        return Err(ErrorCode::BadArguments(
+            "the hints of a plan hint can not contain */",
+        ));
+    }
+
+    let sql = format!("SELECT /*+ {} */ 1", hints);
+    let tokens = tokenize_sql(&sql)?;
+    let backtrace = Backtrace::new();
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL, &backtrace)?;
+    match stmt {
+        Statement::Query(query) if matches!(&query.body, SetExpr::Select(select) if !select.hints.is_empty()) => {
+            Ok(())
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "no known hint in {}",
+            hints
+        ))),
+    }
+}
@@ -28,6 +28,7 @@ use common_storage::UriLocation;
 
 use super::super::copy::parse_copy_file_format_options;
 use super::super::copy::parse_stage_location;
+use crate::connections::kept_location_storage;
 use crate::sql::binder::Binder;
 use crate::sql::plans::Plan;
 
@@ -103,9 +104,13 @@ impl<'a> Binder {
                 //     }
                 // }
 
-                let (stage_storage, path) = parse_uri_location(&uri)?;
+                // The parameters of the connection are not kept by the stage.
+                let (_, path) = parse_uri_location(&self.resolve_uri_connection(&uri).await?)?;
+                let (stage_storage, location) = kept_location_storage(&uri)?;
 
-                UserStageInfo::new_external_stage(stage_storage, &path)
+                let mut stage_info = UserStageInfo::new_external_stage(stage_storage, &path);
+                stage_info.stage_params.location = location;
+                stage_info
             }
         };
         stage_info.stage_name = stage_name.clone();
@@ -24,29 +24,53 @@ use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Backtrace;
 use common_ast::Dialect;
+use common_datavalues::remove_nullable;
 use common_datavalues::type_coercion::compare_coercion;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
 use common_datavalues::ToDataType;
 use common_datavalues::TypeFactory;
+use common_datavalues::TypeID;
 use common_datavalues::Vu8;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::Collation;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::TableCollations;
+use common_meta_types::TableRowAccessPolicy;
+use common_planner::plans::AddTableRowAccessPolicyPlan;
+use common_planner::plans::AddTableVirtualColumnPlan;
 use common_planner::plans::AlterTableClusterKeyPlan;
+use common_planner::plans::AnalyzeTablePlan;
 use common_planner::plans::DescribeTablePlan;
 use common_planner::plans::DropTableClusterKeyPlan;
 use common_planner::plans::DropTablePlan;
+use common_planner::plans::DropTableRowAccessPolicyPlan;
+use common_planner::plans::DropTableVirtualColumnPlan;
 use common_planner::plans::ExistsTablePlan;
 use common_planner::plans::OptimizeTableAction;
 use common_planner::plans::OptimizeTablePlan;
 use common_planner::plans::RenameTableEntity;
 use common_planner::plans::RenameTablePlan;
+use common_planner::plans::SetTableColumnCommentPlan;
+use common_planner::plans::SetTableColumnMaskingPolicyPlan;
+use common_planner::plans::SetTableOptionsPlan;
 use common_planner::plans::ShowCreateTablePlan;
+use common_planner::plans::SwapTablePlan;
 use common_planner::plans::TruncateTablePlan;
 use common_planner::plans::UndropTablePlan;
+use common_planner::plans::UnsetTableColumnMaskingPolicyPlan;
+use common_storage::parse_uri_location;
+use common_storage::StorageParams;
+use common_storage::UriLocation;
+use common_users::UserApiProvider;
+use regex::Regex;
 use tracing::debug;
 
+use super::super::copy::parse_copy_file_format_options;
+use crate::connections::keep_location_storage;
 use crate::sql::binder::scalar::ScalarBinder;
 use crate::sql::binder::Binder;
 use crate::sql::binder::Visibility;
@@ -57,7 +81,11 @@ use crate::sql::optimizer::OptimizerContext;
 use crate::sql::planner::semantic::normalize_identifier;
 use crate::sql::planner::semantic::IdentifierNormalizer;
 use crate::sql::plans::create_table_v2::CreateTablePlanV2;
+use crate::sql::plans::AddTableColumnPlan;
+use crate::sql::plans::AttachTablePlan;
 use crate::sql::plans::CastExpr;
+use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::FunctionCall;
 use crate::sql::plans::Plan;
 use crate::sql::plans::ReclusterTablePlan;
 use crate::sql::plans::RewriteKind;
@@ -65,7 +93,21 @@ use crate::sql::plans::Scalar;
 use crate::sql::BindContext;
 use crate::sql::ColumnBinding;
 use crate::sql::ScalarExpr;
+use crate::sql::OPT_KEY_COLLATIONS;
+use crate::sql::OPT_KEY_COMMENT;
 use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::storages::delta::DeltaTable;
+use crate::storages::delta::DeltaTableOptions;
+use crate::storages::external::ExternalTableOptions;
+use crate::storages::fuse::io::BlockCompression;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use crate::storages::fuse::FUSE_OPT_KEY_DEDUP_ON;
+use crate::storages::fuse::FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS;
+use crate::storages::iceberg::IcebergTable;
+use crate::storages::iceberg::IcebergTableOptions;
+use crate::storages::memory::MemoryTable;
+use crate::storages::random::RandomOptions;
 
 struct SelectBuilder {
     from: String,
@@ -247,6 +289,7 @@ impl<'a> Binder {
             catalog,
             database,
             table,
+            extended,
         } = stmt;
 
         let catalog = catalog
@@ -258,18 +301,31 @@ impl<'a> Binder {
             .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
             .unwrap_or_else(|| self.ctx.get_current_database());
         let table = normalize_identifier(table, &self.name_resolution_ctx).name;
-        let schema = DataSchemaRefExt::create(vec![
+        let mut fields = vec![
             DataField::new("Field", Vu8::to_data_type()),
             DataField::new("Type", Vu8::to_data_type()),
             DataField::new("Null", Vu8::to_data_type()),
             DataField::new("Default", Vu8::to_data_type()),
             DataField::new("Extra", Vu8::to_data_type()),
-        ]);
+        ];
+        if *extended {
+            // The statistics are NULL if the table doesn't keep them.
+            fields.extend([
+                DataField::new("Comment", Vu8::to_data_type()),
+                DataField::new_nullable("Uncompressed Size", u64::to_data_type()),
+                DataField::new_nullable("Compressed Size", u64::to_data_type()),
+                DataField::new_nullable("Compression Ratio", f64::to_data_type()),
+                DataField::new_nullable("Distinct Values", u64::to_data_type()),
+                DataField::new_nullable("Null Count", u64::to_data_type()),
+            ]);
+        }
+        let schema = DataSchemaRefExt::create(fields);
 
         Ok(Plan::DescribeTable(Box::new(DescribeTablePlan {
             catalog,
             database,
             table,
+            extended: *extended,
             schema,
         })))
     }
@@ -293,7 +349,7 @@ impl<'a> Binder {
         NULL AS Row_format, num_rows AS Rows, NULL AS Avg_row_length, data_size AS Data_length, \
         NULL AS Max_data_length, index_size AS Index_length, NULL AS Data_free, NULL AS Auto_increment, \
         created_on AS Create_time, NULL AS Update_time, NULL AS Check_time, NULL AS Collation, \
-        NULL AS Checksum, '' AS Comment"
+        NULL AS Checksum, comment AS Comment"
             .to_string();
 
         // Use `system.tables` AS the "base" table to construct the result-set of `SHOW TABLE STATUS ..`
@@ -325,6 +381,50 @@ impl<'a> Binder {
         self.bind_statement(bind_context, &stmt).await
     }
 
+    pub(in crate::sql::planner::binder) async fn bind_show_drop_tables(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &ShowDropTablesStmt<'a>,
+    ) -> Result<Plan> {
+        let ShowDropTablesStmt { database, limit } = stmt;
+
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+
+        // The dropped tables are listed by `system.tables_with_history` until they are out of
+        // the retention time, then they can not be undropped anymore.
+        let mut select_builder = SelectBuilder::from("system.tables_with_history");
+        select_builder
+            .with_column(format!("name AS Tables_in_{database}"))
+            .with_column("engine")
+            .with_column("created_on AS create_time")
+            .with_column("dropped_on AS drop_time")
+            .with_column("num_rows")
+            .with_column("data_size")
+            .with_order_by("name")
+            .with_order_by("dropped_on");
+
+        select_builder.with_filter(format!("database = '{database}'"));
+        select_builder.with_filter("dropped_on != 'NULL'");
+
+        let query = match limit {
+            None => select_builder.build(),
+            Some(ShowLimit::Like { pattern }) => {
+                select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                select_builder.build()
+            }
+            Some(ShowLimit::Where { selection }) => {
+                select_builder.with_filter(format!("({selection})"));
+                select_builder.build()
+            }
+        };
+        debug!("show drop tables rewrite to: {:?}", query);
+        self.bind_rewrite_to_query(bind_context, query.as_str(), RewriteKind::ShowTables)
+            .await
+    }
+
     pub(in crate::sql::planner::binder) async fn bind_create_table(
         &mut self,
         stmt: &CreateTableStmt<'a>,
@@ -339,7 +439,9 @@ impl<'a> Binder {
             cluster_by,
             as_query,
             transient,
+            temporary,
             engine,
+            external,
         } = stmt;
 
         let catalog = catalog
@@ -352,8 +454,38 @@ impl<'a> Binder {
             .unwrap_or_else(|| self.ctx.get_current_database());
         let table = normalize_identifier(table, &self.name_resolution_ctx).name;
 
-        // Take FUSE engine AS default engine
-        let engine = engine.unwrap_or(Engine::Fuse);
+        // Take FUSE engine AS default engine, and MEMORY engine for the temporary tables
+        let engine = match (external, *engine) {
+            (Some(_), Some(engine @ (Engine::Iceberg | Engine::Delta))) => engine,
+            (Some(_), Some(engine)) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "engine {} is not supported by external tables",
+                    engine
+                )));
+            }
+            (Some(_), None) => Engine::External,
+            (None, Some(engine @ (Engine::Iceberg | Engine::Delta))) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "engine {} is only supported by CREATE EXTERNAL TABLE",
+                    engine
+                )));
+            }
+            (None, Some(engine)) if *temporary && engine != Engine::Memory => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "engine {} is not supported by temporary tables, only MEMORY is supported",
+                    engine
+                )));
+            }
+            (None, None) if *temporary => Engine::Memory,
+            (None, engine) => engine.unwrap_or(Engine::Fuse),
+        };
+        // The iceberg and delta tables are read by the metadata kept in their locations.
+        let table_storage = match external {
+            Some(external) if matches!(engine, Engine::Iceberg | Engine::Delta) => {
+                Some(self.analyze_table_location(external, engine).await?)
+            }
+            _ => None,
+        };
         let mut options: BTreeMap<String, String> = BTreeMap::new();
         for table_option in table_options.iter() {
             self.insert_table_option_with_validation(
@@ -372,7 +504,8 @@ impl<'a> Binder {
         let (schema, field_default_exprs, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
-                self.analyze_create_table_schema(source).await?
+                self.analyze_create_table_schema(&database, &table, source)
+                    .await?
             }
             (None, Some(query)) => {
                 // `CREATE TABLE AS SELECT ...` without column definitions
@@ -394,8 +527,9 @@ impl<'a> Binder {
             }
             (Some(source), Some(query)) => {
                 // e.g. `CREATE TABLE t (i INT) AS SELECT * from old_t` with columns speicified
-                let (source_schema, source_default_exprs, source_coments) =
-                    self.analyze_create_table_schema(source).await?;
+                let (source_schema, source_default_exprs, source_coments) = self
+                    .analyze_create_table_schema(&database, &table, source)
+                    .await?;
                 let init_bind_context = BindContext::new();
                 let (_s_expr, bind_context) = self.bind_query(&init_bind_context, query).await?;
                 let query_fields: Vec<DataField> = bind_context
@@ -414,12 +548,36 @@ impl<'a> Binder {
                 Self::validate_create_table_schema(&schema)?;
                 (schema, source_default_exprs, source_coments)
             }
-            _ => Err(ErrorCode::BadArguments(
-                "Incorrect CREATE query: required list of column descriptions or AS section or SELECT..",
-            ))?,
+            (None, None) => match &table_storage {
+                // The columns of iceberg and delta tables are read from the table metadata if not given.
+                Some((storage, _)) => {
+                    let schema = match engine {
+                        Engine::Iceberg => IcebergTable::read_schema(&self.ctx, storage).await?,
+                        _ => DeltaTable::read_schema(&self.ctx, storage).await?,
+                    };
+                    let schema = Arc::new(schema);
+                    Self::validate_create_table_schema(&schema)?;
+                    (schema, vec![], vec![])
+                }
+                None => Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: required list of column descriptions or AS section or SELECT..",
+                ))?,
+            },
         };
 
+        if let Some(CreateTableSource::Columns(columns)) = source {
+            if let Some(collations) = self.analyze_column_collations(columns, &schema)? {
+                options.insert(OPT_KEY_COLLATIONS.to_owned(), collations);
+            }
+        }
+
         if engine == Engine::Fuse {
+            // The compression of the blocks is checked here, instead of failing the later writes.
+            BlockCompression::try_from_options(&options)?.check_columns(&schema)?;
+            FuseTable::check_index_columns(&options, &schema)?;
+            FuseTable::check_dedup_keys(&options, &schema)?;
+            FuseTable::parse_data_retention_time(&options)?;
+
             // Currently, [Table] can not accesses its database id yet, thus
             // here we keep the db id AS an entry of `table_meta.options`.
             //
@@ -436,6 +594,39 @@ impl<'a> Binder {
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
         }
 
+        if engine == Engine::Memory && MemoryTable::is_persistent(&options)? && *temporary {
+            return Err(ErrorCode::BadArguments(
+                "temporary tables can't be persistent",
+            ));
+        }
+
+        if engine == Engine::Random {
+            // The generators of the columns are checked here, instead of failing the later reads.
+            RandomOptions::try_from_options(&options)?.check_columns(&schema)?;
+        }
+
+        let engine_options = match (external, table_storage) {
+            (_, Some((storage, location))) => {
+                let mut engine_options = match engine {
+                    Engine::Iceberg => BTreeMap::try_from(&IcebergTableOptions {
+                        storage,
+                        snapshot_id: None,
+                    })?,
+                    _ => BTreeMap::try_from(&DeltaTableOptions {
+                        storage,
+                        version: None,
+                    })?,
+                };
+                keep_location_storage(&location, &mut engine_options)?;
+                engine_options
+            }
+            (Some(external), None) => {
+                self.analyze_external_table_source(external, &schema)
+                    .await?
+            }
+            (None, None) => BTreeMap::new(),
+        };
+
         let cluster_key = {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
@@ -447,6 +638,24 @@ impl<'a> Binder {
             }
         };
 
+        let auto_increment_sequences: Vec<String> = match source {
+            Some(CreateTableSource::Columns(columns)) => columns
+                .iter()
+                .filter(|column| column.auto_increment)
+                .map(|column| {
+                    let column = normalize_identifier(&column.name, &self.name_resolution_ctx);
+                    auto_increment_sequence_name(&database, &table, &column.name)
+                })
+                .collect(),
+            _ => vec![],
+        };
+        // The sequences are kept in the meta service, which would outlive the temporary table.
+        if *temporary && !auto_increment_sequences.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "AUTOINCREMENT columns are not supported by temporary tables",
+            ));
+        }
+
         let plan = CreateTablePlanV2 {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -455,10 +664,13 @@ impl<'a> Binder {
             table,
             schema,
             engine,
+            engine_options,
             options,
             field_default_exprs,
             field_comments,
             cluster_key,
+            auto_increment_sequences,
+            temporary: *temporary,
             as_select: if let Some(query) = as_query {
                 let bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -507,6 +719,75 @@ impl<'a> Binder {
         })))
     }
 
+    pub(in crate::sql::planner::binder) async fn bind_attach_table(
+        &mut self,
+        stmt: &AttachTableStmt<'a>,
+    ) -> Result<Plan> {
+        let AttachTableStmt {
+            catalog,
+            database,
+            table,
+            location,
+            read_only,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+
+        // The storage is rooted at the location of the table, the connection of the
+        // location is checked here instead of while attaching.
+        let mut path = location.path.clone();
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+        let location = UriLocation {
+            protocol: location.protocol.clone(),
+            name: location.name.clone(),
+            path,
+            connection: location.connection.clone(),
+        };
+        parse_uri_location(&self.resolve_uri_connection(&location).await?)?;
+
+        Ok(Plan::AttachTable(Box::new(AttachTablePlan {
+            tenant,
+            catalog,
+            database,
+            table,
+            location,
+            read_only: *read_only,
+        })))
+    }
+
+    /// `DETACH TABLE` is the `DROP TABLE` without `ALL`, the data of the table are kept.
+    pub(in crate::sql::planner::binder) async fn bind_detach_table(
+        &mut self,
+        stmt: &DetachTableStmt<'a>,
+    ) -> Result<Plan> {
+        let DetachTableStmt {
+            if_exists,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        self.bind_drop_table(&DropTableStmt {
+            if_exists: *if_exists,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table.clone(),
+            all: false,
+        })
+        .await
+    }
+
     pub(in crate::sql::planner::binder) async fn bind_undrop_table(
         &mut self,
         stmt: &UndropTableStmt<'a>,
@@ -589,6 +870,17 @@ impl<'a> Binder {
                     entities,
                 })))
             }
+            AlterTableAction::SwapWith { target_table } => {
+                Ok(Plan::SwapTable(Box::new(SwapTablePlan {
+                    if_exists: *if_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    target_table: normalize_identifier(target_table, &self.name_resolution_ctx)
+                        .name,
+                })))
+            }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let schema = self
                     .ctx
@@ -648,6 +940,233 @@ impl<'a> Binder {
                     push_downs,
                 })))
             }
+            AlterTableAction::SetOptions { set_options } => {
+                // Only the data retention time, the deduplication and the comment can be
+                // changed by now.
+                let settable = [
+                    FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS,
+                    FUSE_OPT_KEY_DEDUP_ON,
+                    FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS,
+                    OPT_KEY_COMMENT,
+                ];
+                if set_options.is_empty() {
+                    return Err(ErrorCode::BadOption("no table option to set"));
+                }
+                if let Some(key) = set_options.keys().find(|k| !settable.contains(&k.as_str())) {
+                    return Err(ErrorCode::BadOption(format!(
+                        "table option {} can not be set, expects one of {}",
+                        key,
+                        settable.join(", ")
+                    )));
+                }
+                FuseTable::parse_data_retention_time(set_options)?;
+                if set_options.contains_key(FUSE_OPT_KEY_DEDUP_ON)
+                    || set_options.contains_key(FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS)
+                {
+                    let schema = self
+                        .ctx
+                        .get_table(&catalog, &database, &table)
+                        .await?
+                        .schema();
+                    FuseTable::check_dedup_keys(set_options, &schema)?;
+                }
+
+                Ok(Plan::SetTableOptions(Box::new(SetTableOptionsPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    set_options: set_options.clone(),
+                })))
+            }
+            AlterTableAction::AddColumn { column } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let bind_context = BindContext::new();
+                let mut scalar_binder = ScalarBinder::new(
+                    &bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                if column.collation.is_some() {
+                    return Err(ErrorCode::SemanticError(
+                        "COLLATE is not supported by ADD COLUMN, the collations are set by CREATE TABLE",
+                    ));
+                }
+                let (field, default_expr, comment) = self
+                    .analyze_column_definition(&mut scalar_binder, &database, &table, column)
+                    .await?;
+                if schema.has_field(field.name()) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "column {} already exists",
+                        field.name()
+                    )));
+                }
+                let auto_increment_sequence = column
+                    .auto_increment
+                    .then(|| auto_increment_sequence_name(&database, &table, field.name()));
+
+                Ok(Plan::AddTableColumn(Box::new(AddTableColumnPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    field,
+                    default_expr,
+                    comment,
+                    auto_increment_sequence,
+                })))
+            }
+            AlterTableAction::AddVirtualColumn { column, expr } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let expr = self.analyze_virtual_column_expr(expr, schema).await?;
+
+                Ok(Plan::AddTableVirtualColumn(Box::new(
+                    AddTableVirtualColumnPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        column: normalize_identifier(column, &self.name_resolution_ctx).name,
+                        expr,
+                    },
+                )))
+            }
+            AlterTableAction::DropVirtualColumn { column } => Ok(Plan::DropTableVirtualColumn(
+                Box::new(DropTableVirtualColumnPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: normalize_identifier(column, &self.name_resolution_ctx).name,
+                }),
+            )),
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let policy = normalize_identifier(policy, &self.name_resolution_ctx).name;
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect::<Vec<_>>();
+
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                for column in columns.iter() {
+                    if !schema.has_field(column) {
+                        return Err(ErrorCode::UnknownColumn(format!(
+                            "column {} of the row access policy {} is not found in table {}",
+                            column, policy, table
+                        )));
+                    }
+                }
+                let parameters = UserApiProvider::instance()
+                    .get_row_access_policy(&tenant, &policy)
+                    .await?
+                    .parameters;
+                if parameters.len() != columns.len() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "row access policy {} requires {} columns, but got: {}",
+                        policy,
+                        parameters.len(),
+                        columns.len()
+                    )));
+                }
+
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: TableRowAccessPolicy::new(&policy, columns),
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy => Ok(Plan::DropTableRowAccessPolicy(Box::new(
+                DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                },
+            ))),
+            AlterTableAction::SetMaskingPolicy { column, policy } => {
+                let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let policy = normalize_identifier(policy, &self.name_resolution_ctx).name;
+
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                if !schema.has_field(&column) {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "column {} is not found in table {}",
+                        column, table
+                    )));
+                }
+                // The policy must exist when it is set.
+                UserApiProvider::instance()
+                    .get_masking_policy(&tenant, &policy)
+                    .await?;
+
+                Ok(Plan::SetTableColumnMaskingPolicy(Box::new(
+                    SetTableColumnMaskingPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        column,
+                        policy,
+                    },
+                )))
+            }
+            AlterTableAction::UnsetMaskingPolicy { column } => Ok(
+                Plan::UnsetTableColumnMaskingPolicy(Box::new(UnsetTableColumnMaskingPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: normalize_identifier(column, &self.name_resolution_ctx).name,
+                })),
+            ),
+            AlterTableAction::ModifyColumnComment { column, comment } => {
+                let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                if !schema.has_field(&column) {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "column {} is not found in table {}",
+                        column, table
+                    )));
+                }
+
+                Ok(Plan::SetTableColumnComment(Box::new(
+                    SetTableColumnCommentPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        column,
+                        comment: comment.clone(),
+                    },
+                )))
+            }
         }
     }
 
@@ -759,6 +1278,7 @@ impl<'a> Binder {
             AstOptimizeTableAction::All => OptimizeTableAction::All,
             AstOptimizeTableAction::Purge => OptimizeTableAction::Purge,
             AstOptimizeTableAction::Compact => OptimizeTableAction::Compact,
+            AstOptimizeTableAction::Verify => OptimizeTableAction::Verify,
         });
 
         Ok(Plan::OptimizeTable(Box::new(OptimizeTablePlan {
@@ -769,6 +1289,33 @@ impl<'a> Binder {
         })))
     }
 
+    pub(in crate::sql::planner::binder) async fn bind_analyze_table(
+        &mut self,
+        stmt: &AnalyzeTableStmt<'a>,
+    ) -> Result<Plan> {
+        let AnalyzeTableStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+
+        Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
+            catalog,
+            database,
+            table,
+        })))
+    }
+
     pub(in crate::sql::planner::binder) async fn bind_exists_table(
         &mut self,
         stmt: &ExistsTableStmt<'a>,
@@ -796,8 +1343,72 @@ impl<'a> Binder {
         })))
     }
 
+    // The field, the bound default expression and the comment of a column definition.
+    // The default expression of an AUTOINCREMENT column is `nextval()` of its sequence.
+    async fn analyze_column_definition<'b>(
+        &self,
+        scalar_binder: &mut ScalarBinder<'b>,
+        database: &str,
+        table: &str,
+        column: &ColumnDefinition<'b>,
+    ) -> Result<(DataField, Option<Scalar>, String)> {
+        let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+        let data_type = TypeFactory::instance().get(column.data_type.to_string())?;
+
+        let default_expr = if column.auto_increment {
+            if column.default_expr.is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {name} can not have both a default expression and AUTOINCREMENT"
+                )));
+            }
+            if !remove_nullable(&data_type).data_type_id().is_integer() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "AUTOINCREMENT column {name} must be of an integer type, but is of type {}",
+                    data_type
+                )));
+            }
+            let sequence = auto_increment_sequence_name(database, table, &name);
+            let nextval = Scalar::FunctionCall(FunctionCall {
+                arguments: vec![Scalar::ConstantExpr(ConstantExpr {
+                    value: DataValue::String(sequence.into_bytes()),
+                    data_type: Box::new(Vu8::to_data_type()),
+                })],
+                func_name: "nextval".to_string(),
+                arg_types: vec![Vu8::to_data_type()],
+                return_type: Box::new(i64::to_data_type()),
+            });
+            Some(Scalar::CastExpr(CastExpr {
+                argument: Box::new(nextval),
+                from_type: Box::new(i64::to_data_type()),
+                target_type: Box::new(data_type.clone()),
+            }))
+        } else if let Some(default_expr) = &column.default_expr {
+            let (mut expr, expr_type) = scalar_binder.bind(default_expr).await?;
+            if compare_coercion(&data_type, &expr_type).is_err() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {name} is of type {} but default expression is of type {}",
+                    data_type, expr_type
+                )));
+            }
+            if !expr_type.eq(&data_type) {
+                expr = Scalar::CastExpr(CastExpr {
+                    argument: Box::new(expr),
+                    from_type: Box::new(expr_type),
+                    target_type: Box::new(data_type.clone()),
+                })
+            }
+            Some(expr)
+        } else {
+            None
+        };
+        let comment = column.comment.clone().unwrap_or_default();
+        Ok((DataField::new(&name, data_type), default_expr, comment))
+    }
+
     async fn analyze_create_table_schema(
         &self,
+        database: &str,
+        table: &str,
         source: &CreateTableSource<'a>,
     ) -> Result<(DataSchemaRef, Vec<Option<Scalar>>, Vec<String>)> {
         let bind_context = BindContext::new();
@@ -814,29 +1425,12 @@ impl<'a> Binder {
                 let mut fields_default_expr = Vec::with_capacity(columns.len());
                 let mut fields_comments = Vec::with_capacity(columns.len());
                 for column in columns.iter() {
-                    let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
-                    let data_type = TypeFactory::instance().get(column.data_type.to_string())?;
-
-                    fields.push(DataField::new(&name, data_type.clone()));
-                    fields_default_expr.push({
-                        if let Some(default_expr) = &column.default_expr {
-                            let (mut expr, expr_type) = scalar_binder.bind(default_expr).await?;
-                            if compare_coercion(&data_type, &expr_type).is_err() {
-                                return Err(ErrorCode::SemanticError(format!("column {name} is of type {} but default expression is of type {}", data_type, expr_type)));
-                            }
-                            if !expr_type.eq(&data_type) {
-                                expr = Scalar::CastExpr(CastExpr {
-                                    argument: Box::new(expr),
-                                    from_type: Box::new(expr_type),
-                                    target_type: Box::new(data_type),
-                                })
-                            }
-                            Some(expr)
-                        } else {
-                            None
-                        }
-                    });
-                    fields_comments.push(column.comment.clone().unwrap_or_default());
+                    let (field, default_expr, comment) = self
+                        .analyze_column_definition(&mut scalar_binder, database, table, column)
+                        .await?;
+                    fields.push(field);
+                    fields_default_expr.push(default_expr);
+                    fields_comments.push(comment);
                 }
                 let schema = DataSchemaRefExt::create(fields);
                 Self::validate_create_table_schema(&schema)?;
@@ -862,6 +1456,38 @@ impl<'a> Binder {
         }
     }
 
+    // The value of the collations option of the columns, none if all of them are binary.
+    fn analyze_column_collations(
+        &self,
+        columns: &[ColumnDefinition<'a>],
+        schema: &DataSchemaRef,
+    ) -> Result<Option<String>> {
+        let mut collations = TableCollations::default();
+        for column in columns {
+            let collation = match &column.collation {
+                Some(collation) => Collation::from_name(collation)?,
+                None => continue,
+            };
+            let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+            let data_type = schema.field_with_name(&name)?.data_type();
+            if remove_nullable(data_type).data_type_id() != TypeID::String {
+                return Err(ErrorCode::SemanticError(format!(
+                    "COLLATE is only supported by the string columns, but column {name} is of type {}",
+                    data_type
+                )));
+            }
+            if !collation.is_binary() {
+                collations
+                    .columns
+                    .insert(name, collation.name().to_string());
+            }
+        }
+        if collations.columns.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(collations.to_option_value()?))
+    }
+
     /// Validate the schema of the table to be created.
     fn validate_create_table_schema(schema: &DataSchemaRef) -> Result<()> {
         // Check if there are duplicated column names
@@ -898,12 +1524,97 @@ impl<'a> Binder {
         }
     }
 
-    async fn analyze_cluster_keys(
-        &mut self,
-        cluster_by: &[Expr<'a>],
-        schema: DataSchemaRef,
-    ) -> Result<Vec<String>> {
-        // Build a temporary BindContext to resolve the expr
+    /// Builds the engine options of `CREATE EXTERNAL TABLE`, the connection of the
+    /// location and the file format are checked here instead of at query time.
+    async fn analyze_external_table_source(
+        &self,
+        external: &ExternalTableSource<'a>,
+        schema: &DataSchemaRef,
+    ) -> Result<BTreeMap<String, String>> {
+        let uri = UriLocation {
+            protocol: external.location.protocol.clone(),
+            name: external.location.name.clone(),
+            path: external.location.path.clone(),
+            connection: external.location.connection.clone(),
+        };
+        let (storage, path) = parse_uri_location(&self.resolve_uri_connection(&uri).await?)?;
+
+        let mut file_format = external.file_format.clone();
+        file_format
+            .entry("type".to_string())
+            .or_insert_with(|| "parquet".to_string());
+        let file_format = parse_copy_file_format_options(&file_format)?;
+
+        if let Some(pattern) = &external.pattern {
+            Regex::new(pattern).map_err(|e| {
+                ErrorCode::SyntaxException(format!("invalid pattern '{}': {}", pattern, e))
+            })?;
+        }
+
+        let mut partition_by = Vec::with_capacity(external.partition_by.len());
+        for column in &external.partition_by {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if schema.index_of(&column).is_err() {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "partition column {} is not a column of the table",
+                    column
+                )));
+            }
+            partition_by.push(column);
+        }
+        if !partition_by.is_empty() && file_format.format != StageFileFormatType::Parquet {
+            return Err(ErrorCode::BadArguments(
+                "partition columns are only supported by external tables of parquet files",
+            ));
+        }
+
+        // The parameters of the connection are not kept by the table.
+        let mut engine_options = BTreeMap::try_from(&ExternalTableOptions {
+            storage,
+            path,
+            pattern: external.pattern.clone(),
+            file_format,
+            partition_by,
+        })?;
+        keep_location_storage(&uri, &mut engine_options)?;
+        Ok(engine_options)
+    }
+
+    /// The storage of the location of an iceberg or delta table, along with the location, of which
+    /// the connection is resolved again when the table is read.
+    async fn analyze_table_location(
+        &self,
+        external: &ExternalTableSource<'a>,
+        engine: Engine,
+    ) -> Result<(StorageParams, UriLocation)> {
+        // The files of iceberg and delta tables are found by the metadata, instead of the options.
+        if !external.partition_by.is_empty()
+            || external.pattern.is_some()
+            || !external.file_format.is_empty()
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "PARTITION BY, PATTERN and FILE_FORMAT are not supported by {} tables",
+                engine
+            )));
+        }
+
+        // The storage is rooted at the location of the table.
+        let mut path = external.location.path.clone();
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+        let uri = UriLocation {
+            protocol: external.location.protocol.clone(),
+            name: external.location.name.clone(),
+            path,
+            connection: external.location.connection.clone(),
+        };
+        let (storage, _) = parse_uri_location(&self.resolve_uri_connection(&uri).await?)?;
+        Ok((storage, uri))
+    }
+
+    // Build a temporary BindContext to resolve the expressions over the columns of the schema
+    fn schema_bind_context(schema: &DataSchemaRef) -> BindContext {
         let mut bind_context = BindContext::new();
         for field in schema.fields() {
             let column = ColumnBinding {
@@ -917,6 +1628,15 @@ impl<'a> Binder {
             };
             bind_context.columns.push(column);
         }
+        bind_context
+    }
+
+    async fn analyze_cluster_keys(
+        &mut self,
+        cluster_by: &[Expr<'a>],
+        schema: DataSchemaRef,
+    ) -> Result<Vec<String>> {
+        let bind_context = Self::schema_bind_context(&schema);
         let mut scalar_binder = ScalarBinder::new(
             &bind_context,
             self.ctx.clone(),
@@ -947,6 +1667,47 @@ impl<'a> Binder {
         Ok(cluster_keys)
     }
 
+    async fn analyze_virtual_column_expr(
+        &mut self,
+        expr: &Expr<'a>,
+        schema: DataSchemaRef,
+    ) -> Result<String> {
+        let bind_context = Self::schema_bind_context(&schema);
+        let mut scalar_binder = ScalarBinder::new(
+            &bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        let (scalar, data_type) = scalar_binder.bind(expr).await?;
+        if !scalar.is_deterministic() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Virtual column expression `{:#}` is not deterministic",
+                expr
+            )));
+        }
+        // The blocks are pruned by the min and max values of the virtual columns,
+        // so the values must be cast to the scalar types.
+        let type_id = remove_nullable(&data_type).data_type_id();
+        if type_id.is_variant() || matches!(type_id, TypeID::Array | TypeID::Struct) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Virtual column expression `{:#}` must be cast to a scalar type, but got {:?}",
+                expr, type_id
+            )));
+        }
+
+        let mut expr = expr.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut expr,
+        );
+        Ok(format!("{:#}", &expr))
+    }
+
     fn concat_fields(
         &self,
         mut source_fields: Vec<DataField>,
@@ -964,3 +1725,9 @@ impl<'a> Binder {
         source_fields
     }
 }
+
+/// The sequence generating the values of an AUTOINCREMENT column. It's created with the table or
+/// the column, and is kept when the table is dropped.
+fn auto_increment_sequence_name(database: &str, table: &str, column: &str) -> String {
+    format!("{database}_{table}_{column}_seq")
+}
@@ -12,18 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
 use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
 use common_ast::ast::TableReference;
+use common_ast::parser::parse_expr;
+use common_ast::parser::token::Token;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
+use common_ast::Backtrace;
+use common_ast::VisitorMut;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_legacy_planners::DeletePlan;
 use common_legacy_planners::Projection;
+use common_meta_types::TableMaskingPolicies;
+use uuid::Uuid;
 
 use crate::sql::binder::Binder;
 use crate::sql::binder::ScalarBinder;
+use crate::sql::normalize_identifier;
 use crate::sql::plans::Plan;
 use crate::sql::BindContext;
+use crate::sql::NameResolutionContext;
 use crate::sql::ScalarExpr;
+use crate::sql::OPT_KEY_MASKING_POLICIES;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY;
 
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_delete(
@@ -32,21 +49,25 @@ impl<'a> Binder {
         table_reference: &'a TableReference<'a>,
         selection: &'a Option<Expr<'a>>,
     ) -> Result<Plan> {
-        let (catalog_name, database_name, table_name) = if let TableReference::Table {
+        let (catalog_name, database_name, table_name, alias) = if let TableReference::Table {
             catalog,
             database,
             table,
+            alias,
             ..
         } = table_reference
         {
             (
-                catalog
-                    .as_ref()
-                    .map_or_else(|| self.ctx.get_current_catalog(), |i| i.name.clone()),
-                database
-                    .as_ref()
-                    .map_or_else(|| self.ctx.get_current_database(), |i| i.name.clone()),
-                table.name.clone(),
+                catalog.as_ref().map_or_else(
+                    || self.ctx.get_current_catalog(),
+                    |i| normalize_identifier(i, &self.name_resolution_ctx).name,
+                ),
+                database.as_ref().map_or_else(
+                    || self.ctx.get_current_database(),
+                    |i| normalize_identifier(i, &self.name_resolution_ctx).name,
+                ),
+                normalize_identifier(table, &self.name_resolution_ctx).name,
+                alias,
             )
         } else {
             // we do not support USING clause yet
@@ -55,35 +76,90 @@ impl<'a> Binder {
             ));
         };
 
-        let (_, context) = self
-            .bind_table_reference(bind_context, table_reference)
-            .await?;
-
-        let mut scalar_binder = ScalarBinder::new(
-            &context,
-            self.ctx.clone(),
-            &self.name_resolution_ctx,
-            self.metadata.clone(),
-            &[],
-        );
-
         let table = self
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
 
+        // The columns are bound to the values of the table, the values masked for the queries
+        // are only seen by the predicate.
+        let table_index = self.metadata.write().add_table(
+            catalog_name.clone(),
+            database_name.clone(),
+            table.clone(),
+        );
+        let (_, mut context) = self
+            .bind_base_table(bind_context, &database_name, table_index)
+            .await?;
+        if let Some(alias) = alias {
+            context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
         let tbl_info = table.get_table_info();
         let table_id = tbl_info.ident;
 
+        // The rows invisible by the row access policy are not deleted, and the predicate is
+        // evaluated on the masked values, as the predicates of the queries.
+        let mut predicates = vec![];
+        if let Some(expr) = selection {
+            let predicate = match table.options().get(OPT_KEY_MASKING_POLICIES) {
+                Some(option_value) => self.mask_predicate(expr, option_value).await?,
+                None => expr.to_string(),
+            };
+            predicates.push(predicate);
+        }
+        if let Some(option_value) = table.options().get(OPT_KEY_ROW_ACCESS_POLICY) {
+            let (predicate, _) = self
+                .bind_row_access_predicate(&context, option_value)
+                .await?;
+            predicates.push(predicate);
+        }
+
         // @todo wait delete migrate to new planner
-        let col_indices: Vec<usize> = if let Some(expr) = selection {
-            let (scalar, _) = scalar_binder.bind(expr).await?;
-            scalar.used_columns().into_iter().collect()
-        } else {
-            vec![]
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let backtrace = Backtrace::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        // The functions of the session, which the policies may depend on, are folded into
+        // the literals, they can't be evaluated by the deletion.
+        let mut folder = ContextFunctionFolder {
+            values: HashMap::from([
+                (
+                    "current_role",
+                    self.ctx
+                        .get_current_role()
+                        .map_or(Literal::Null, Literal::String),
+                ),
+                (
+                    "current_user",
+                    Literal::String(self.ctx.get_current_user()?.identity().to_string()),
+                ),
+                (
+                    "current_database",
+                    Literal::String(self.ctx.get_current_database()),
+                ),
+            ]),
         };
-        let selection = selection.as_ref().map(|expr| format!("({})", expr));
-        let projection = Projection::Columns(col_indices);
+        let mut col_indices = BTreeSet::new();
+        let mut folded_predicates = Vec::with_capacity(predicates.len());
+        for predicate in &predicates {
+            let tokens = tokenize_sql(predicate)?;
+            let mut expr = parse_expr(&tokens, sql_dialect, &backtrace)?;
+            let (scalar, _) = scalar_binder.bind(&expr).await?;
+            col_indices.extend(scalar.used_columns());
+            walk_expr_mut(&mut folder, &mut expr);
+            folded_predicates.push(format!("({})", expr));
+        }
+        let selection = match folded_predicates.is_empty() {
+            true => None,
+            false => Some(format!("({})", folded_predicates.join(" AND "))),
+        };
+        let projection = Projection::Columns(col_indices.into_iter().collect());
 
         let plan = DeletePlan {
             catalog_name,
@@ -95,4 +171,82 @@ impl<'a> Binder {
         };
         Ok(Plan::Delete(Box::new(plan)))
     }
+
+    // The references of the masked columns in the predicate are replaced with the expressions
+    // of the masking policies. They're replaced with the placeholders in the AST first, and
+    // the placeholders are replaced in the formatted predicate.
+    async fn mask_predicate(&self, expr: &Expr<'a>, option_value: &str) -> Result<String> {
+        let attached = TableMaskingPolicies::try_from_option_value(option_value)?;
+        let mut rewriter = MaskedColumnRewriter {
+            name_resolution_ctx: &self.name_resolution_ctx,
+            placeholders: attached
+                .columns
+                .keys()
+                .map(|column| {
+                    let placeholder = format!("masked_{}", Uuid::new_v4().simple());
+                    (column.clone(), placeholder)
+                })
+                .collect(),
+        };
+        let mut expr = expr.clone();
+        walk_expr_mut(&mut rewriter, &mut expr);
+
+        let mut predicate = expr.to_string();
+        for (column, policy) in attached.columns.iter() {
+            let placeholder = format!("`{}`", rewriter.placeholders[column]);
+            if predicate.contains(&placeholder) {
+                let masked = self.masking_policy_expr(column, policy).await?;
+                predicate = predicate.replace(&placeholder, &format!("({})", masked));
+            }
+        }
+        Ok(predicate)
+    }
+}
+
+struct MaskedColumnRewriter<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    placeholders: HashMap<String, String>,
+}
+
+impl<'a> VisitorMut for MaskedColumnRewriter<'a> {
+    fn visit_column_ref(
+        &mut self,
+        _span: &mut &[Token<'_>],
+        database: &mut Option<Identifier<'_>>,
+        table: &mut Option<Identifier<'_>>,
+        column: &mut Identifier<'_>,
+    ) {
+        let name = normalize_identifier(column, self.name_resolution_ctx).name;
+        if let Some(placeholder) = self.placeholders.get(&name) {
+            *database = None;
+            *table = None;
+            column.name = placeholder.clone();
+            column.quote = Some('`');
+        }
+    }
+}
+
+struct ContextFunctionFolder {
+    values: HashMap<&'static str, Literal>,
+}
+
+impl VisitorMut for ContextFunctionFolder {
+    fn visit_expr(&mut self, expr: &mut Expr<'_>) {
+        if let Expr::FunctionCall { name, args, .. } = expr {
+            let name = match name.name.to_lowercase().as_str() {
+                "user" | "currentuser" => "current_user".to_string(),
+                "database" | "currentdatabase" => "current_database".to_string(),
+                name => name.to_string(),
+            };
+            if let (Some(lit), true) = (self.values.get(name.as_str()), args.is_empty()) {
+                let lit = match lit {
+                    Literal::String(value) => Literal::String(value.replace('\'', "''")),
+                    lit => lit.clone(),
+                };
+                *expr = Expr::Literal { span: &[], lit };
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
 }
@@ -14,10 +14,14 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Identifier;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
+use common_ast::ast::ReplaceStmt;
 use common_ast::ast::Statement;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::sql::binder::Binder;
@@ -28,7 +32,10 @@ use crate::sql::optimizer::OptimizerContext;
 use crate::sql::plans::Insert;
 use crate::sql::plans::InsertInputSource;
 use crate::sql::plans::Plan;
+use crate::sql::plans::Replace;
 use crate::sql::BindContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
 
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_insert(
@@ -44,6 +51,103 @@ impl<'a> Binder {
             source,
             overwrite,
         } = stmt;
+        let (catalog_name, database_name, table_name, table) =
+            self.resolve_insert_table(catalog, database, table).await?;
+        // The rows would be deduplicated against the rows overwritten.
+        if *overwrite && Self::is_dedup_table(table.as_ref()) {
+            return Err(ErrorCode::SemanticError(format!(
+                "INSERT OVERWRITE is not supported by table {} with dedup_on",
+                table_name
+            )));
+        }
+        let schema = self.bind_insert_schema(table.schema(), columns)?;
+        let input_source = self.bind_insert_source(bind_context, source).await?;
+
+        let plan = Insert {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id: table.get_id(),
+            schema,
+            overwrite: *overwrite,
+            source: input_source,
+        };
+
+        Ok(Plan::Insert(Box::new(plan)))
+    }
+
+    pub(in crate::sql::planner::binder) async fn bind_replace(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &ReplaceStmt<'a>,
+    ) -> Result<Plan> {
+        let ReplaceStmt {
+            catalog,
+            database,
+            table,
+            columns,
+            on_conflict_columns,
+            source,
+        } = stmt;
+        let (catalog_name, database_name, table_name, table) =
+            self.resolve_insert_table(catalog, database, table).await?;
+        // The rows replacing the existing ones would be dropped as the duplicates of them.
+        if Self::is_dedup_table(table.as_ref()) {
+            return Err(ErrorCode::SemanticError(format!(
+                "REPLACE INTO is not supported by table {} with dedup_on",
+                table_name
+            )));
+        }
+        let schema = self.bind_insert_schema(table.schema(), columns)?;
+        // The conflicting rows are identified by the values of the `ON` columns, which must be
+        // inserted.
+        let on_conflict_fields = self
+            .bind_insert_schema(table.schema(), on_conflict_columns)?
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        for field in &on_conflict_fields {
+            if !schema.has_field(field) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the ON column {} of REPLACE INTO must be inserted",
+                    field
+                )));
+            }
+        }
+        if let InsertSource::Streaming { format, .. } = source {
+            if format.to_uppercase() != "VALUES" {
+                return Err(ErrorCode::SemanticError(format!(
+                    "REPLACE INTO does not support the format {}, use VALUES or SELECT instead",
+                    format
+                )));
+            }
+        }
+        let input_source = self.bind_insert_source(bind_context, source).await?;
+
+        let plan = Replace {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id: table.get_id(),
+            schema,
+            on_conflict_fields,
+            source: input_source,
+        };
+
+        Ok(Plan::Replace(Box::new(plan)))
+    }
+
+    fn is_dedup_table(table: &dyn Table) -> bool {
+        !FuseTable::parse_dedup_keys(table.get_table_info().options()).is_empty()
+    }
+
+    async fn resolve_insert_table(
+        &self,
+        catalog: &Option<Identifier<'a>>,
+        database: &Option<Identifier<'a>>,
+        table: &Identifier<'a>,
+    ) -> Result<(String, String, String, Arc<dyn Table>)> {
         let catalog_name = catalog.as_ref().map_or_else(
             || self.ctx.get_current_catalog(),
             |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
@@ -57,26 +161,35 @@ impl<'a> Binder {
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
-        let table_id = table.get_id();
+        Ok((catalog_name, database_name, table_name, table))
+    }
 
-        let schema = if columns.is_empty() {
-            table.schema()
-        } else {
-            let schema = table.schema();
-            let fields = columns
-                .iter()
-                .map(|ident| {
-                    schema
-                        .field_with_name(
-                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
-                        )
-                        .map(|v| v.clone())
-                })
-                .collect::<Result<Vec<_>>>()?;
-            DataSchemaRefExt::create(fields)
-        };
+    // The schema of the columns inserted, all of the columns of the table if none is specified.
+    fn bind_insert_schema(
+        &self,
+        schema: DataSchemaRef,
+        columns: &[Identifier<'a>],
+    ) -> Result<DataSchemaRef> {
+        if columns.is_empty() {
+            return Ok(schema);
+        }
+        let fields = columns
+            .iter()
+            .map(|ident| {
+                schema
+                    .field_with_name(&normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .map(|v| v.clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataSchemaRefExt::create(fields))
+    }
 
-        let input_source: Result<InsertInputSource> = match source.clone() {
+    async fn bind_insert_source(
+        &mut self,
+        bind_context: &BindContext,
+        source: &InsertSource<'a>,
+    ) -> Result<InsertInputSource> {
+        match source.clone() {
             InsertSource::Streaming {
                 format,
                 rest_str,
@@ -102,18 +215,6 @@ impl<'a> Binder {
                 let optimized_plan = optimize(self.ctx.clone(), opt_ctx, select_plan)?;
                 Ok(InsertInputSource::SelectPlan(Box::new(optimized_plan)))
             }
-        };
-
-        let plan = Insert {
-            catalog: catalog_name,
-            database: database_name,
-            table: table_name,
-            table_id,
-            schema,
-            overwrite: *overwrite,
-            source: input_source?,
-        };
-
-        Ok(Plan::Insert(Box::new(plan)))
+        }
     }
 }
@@ -173,7 +173,8 @@ impl<'a> Binder {
                                         NameResolutionResult::Alias { scalar, .. } => scalar,
                                     },
 
-                                    alias: ident.name.clone(),
+                                    alias: normalize_identifier(ident, &self.name_resolution_ctx)
+                                        .name,
                                 });
                             }
                             Indirection::Star => {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use async_recursion::async_recursion;
 use common_ast::ast::Expr;
@@ -21,6 +22,7 @@ use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
 use common_ast::ast::OrderByExpr;
 use common_ast::ast::Query;
+use common_ast::ast::SelectHint;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
@@ -35,6 +37,8 @@ use common_planner::IndexType;
 use crate::sql::binder::scalar_common::split_conjunctions;
 use crate::sql::binder::CteInfo;
 use crate::sql::binder::Visibility;
+use crate::sql::normalize_identifier;
+use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::binder::scalar::ScalarBinder;
 use crate::sql::planner::binder::BindContext;
@@ -44,6 +48,8 @@ use crate::sql::plans::CastExpr;
 use crate::sql::plans::EvalScalar;
 use crate::sql::plans::Filter;
 use crate::sql::plans::JoinType;
+use crate::sql::plans::LogicalGet;
+use crate::sql::plans::RelOperator;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarItem;
 use crate::sql::plans::UnionAll;
@@ -69,6 +75,8 @@ impl<'a> Binder {
         stmt: &SelectStmt<'a>,
         order_by: &[OrderByExpr<'a>],
     ) -> Result<(SExpr, BindContext)> {
+        self.bind_set_var_hints(bind_context, &stmt.hints)?;
+
         let (mut s_expr, mut from_context) = if stmt.from.is_empty() {
             self.bind_one_table(bind_context, stmt).await?
         } else {
@@ -90,6 +98,10 @@ impl<'a> Binder {
                 .await?
         };
 
+        if !stmt.hints.is_empty() {
+            s_expr = self.bind_select_hints(&from_context, &stmt.hints, s_expr);
+        }
+
         if let Some(expr) = &stmt.selection {
             s_expr = self.bind_where(&from_context, expr, s_expr).await?;
         }
@@ -194,7 +206,8 @@ impl<'a> Binder {
     ) -> Result<(SExpr, BindContext)> {
         if let Some(with) = &query.with {
             for cte in with.ctes.iter() {
-                let table_name = cte.alias.name.name.clone();
+                let table_name =
+                    normalize_identifier(&cte.alias.name, &self.name_resolution_ctx).name;
                 if bind_context.ctes_map.read().contains_key(&table_name) {
                     return Err(ErrorCode::SemanticError(format!(
                         "duplicate cte {table_name}"
@@ -202,7 +215,12 @@ impl<'a> Binder {
                 }
                 let (s_expr, cte_bind_context) = self.bind_query(bind_context, &cte.query).await?;
                 let cte_info = CteInfo {
-                    columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
+                    columns_alias: cte
+                        .alias
+                        .columns
+                        .iter()
+                        .map(|c| normalize_identifier(c, &self.name_resolution_ctx).name)
+                        .collect(),
                     s_expr,
                     bind_context: cte_bind_context.clone(),
                 };
@@ -255,6 +273,45 @@ impl<'a> Binder {
         Ok((s_expr, bind_context))
     }
 
+    /// Marks the tables hinted by `BROADCAST` in the `FROM` clause, which are found by the
+    /// names or the aliases of the tables, the unknown tables are ignored. The
+    /// `MAX_EXECUTION_TIME` hint overrides the max_execute_time of the query.
+    fn bind_select_hints(
+        &self,
+        from_context: &BindContext,
+        hints: &[SelectHint<'a>],
+        s_expr: SExpr,
+    ) -> SExpr {
+        let mut columns = ColumnSet::new();
+        for hint in hints.iter() {
+            match hint {
+                SelectHint::Broadcast(tables) => {
+                    for table in tables.iter() {
+                        let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+                        columns.extend(
+                            from_context
+                                .columns
+                                .iter()
+                                .filter(|column| column.table_name.as_ref() == Some(&table))
+                                .map(|column| column.index),
+                        );
+                    }
+                }
+                SelectHint::MaxExecutionTime(millis) => {
+                    self.ctx
+                        .set_query_max_execution_time(Duration::from_millis(*millis));
+                }
+                // applied before the query is bound
+                SelectHint::SetVar { .. } => {}
+            }
+        }
+
+        if columns.is_empty() {
+            return s_expr;
+        }
+        mark_broadcast_tables(&s_expr, &columns)
+    }
+
     pub(super) async fn bind_where(
         &mut self,
         bind_context: &BindContext,
@@ -555,3 +612,22 @@ impl<'a> Binder {
         Ok((new_bind_context, pairs, left_expr, right_expr))
     }
 }
+
+fn mark_broadcast_tables(s_expr: &SExpr, columns: &ColumnSet) -> SExpr {
+    match s_expr.plan() {
+        RelOperator::LogicalGet(get) if !get.columns.is_disjoint(columns) => SExpr::create_leaf(
+            LogicalGet {
+                broadcast_hint: true,
+                ..get.clone()
+            }
+            .into(),
+        ),
+        _ => s_expr.replace_children(
+            s_expr
+                .children()
+                .iter()
+                .map(|child| mark_broadcast_tables(child, columns))
+                .collect(),
+        ),
+    }
+}
@@ -14,6 +14,7 @@
 
 use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
+use common_ast::ast::SelectHint;
 use common_exception::Result;
 use common_legacy_planners::SettingPlan;
 use common_legacy_planners::VarValue;
@@ -31,6 +32,34 @@ impl<'a> Binder {
         variable: &Identifier<'a>,
         value: &Literal,
     ) -> Result<Plan> {
+        let variable = variable.name.clone();
+        let value = self.bind_setting_value(bind_context, value)?;
+
+        let vars = vec![VarValue {
+            is_global,
+            variable,
+            value,
+        }];
+        Ok(Plan::SetVariable(Box::new(SettingPlan { vars })))
+    }
+
+    /// Applies the `SET_VAR` hints to the settings of the query before the query is bound, so
+    /// that the settings are changed for the whole query but not for the session.
+    pub(in crate::sql::planner::binder) fn bind_set_var_hints(
+        &self,
+        bind_context: &BindContext,
+        hints: &[SelectHint<'a>],
+    ) -> Result<()> {
+        for hint in hints.iter() {
+            if let SelectHint::SetVar { variable, value } = hint {
+                let value = self.bind_setting_value(bind_context, value)?;
+                self.ctx.set_query_setting(variable.name.clone(), value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn bind_setting_value(&self, bind_context: &BindContext, value: &Literal) -> Result<String> {
         let type_checker = TypeChecker::new(
             bind_context,
             self.ctx.clone(),
@@ -39,16 +68,7 @@ impl<'a> Binder {
             &[],
         );
 
-        let variable = variable.name.clone();
-
         let box (value, _data_type) = type_checker.resolve_literal(value, None)?;
-        let value = String::from_utf8(value.as_string()?)?;
-
-        let vars = vec![VarValue {
-            is_global,
-            variable,
-            value,
-        }];
-        Ok(Plan::SetVariable(Box::new(SettingPlan { vars })))
+        Ok(String::from_utf8(value.as_string()?)?)
     }
 }
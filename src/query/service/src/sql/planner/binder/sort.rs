@@ -21,6 +21,7 @@ use common_ast::ast::OrderByExpr;
 use common_ast::DisplayError;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::Collation;
 use common_planner::IndexType;
 
 use super::bind_context::NameResolutionResult;
@@ -30,6 +31,7 @@ use crate::sql::binder::Binder;
 use crate::sql::binder::ColumnBinding;
 use crate::sql::normalize_identifier;
 use crate::sql::optimizer::SExpr;
+use crate::sql::planner::semantic::column_collation;
 use crate::sql::planner::semantic::GroupingChecker;
 use crate::sql::plans::AggregateFunction;
 use crate::sql::plans::AndExpr;
@@ -69,6 +71,23 @@ impl<'a> Binder {
     ) -> Result<OrderItems<'a>> {
         let mut order_items = Vec::with_capacity(order_by.len());
         for order in order_by {
+            // The column of a collation is sorted by the keys of its strings, as `COLLATE`.
+            let collated;
+            let order =
+                match self.order_by_column_collation(from_context, projections, order, distinct)? {
+                    Some(collation) => {
+                        collated = OrderByExpr {
+                            expr: Expr::Collate {
+                                span: order.expr.span(),
+                                expr: Box::new(order.expr.clone()),
+                                collation: collation.name().to_string(),
+                            },
+                            ..order.clone()
+                        };
+                        &collated
+                    }
+                    None => order,
+                };
             match &order.expr {
                 Expr::ColumnRef {
                     database: ref database_name,
@@ -207,6 +226,54 @@ impl<'a> Binder {
         Ok(OrderItems { items: order_items })
     }
 
+    // The collation of the column the order item refers to, none if the item is not a column.
+    fn order_by_column_collation(
+        &self,
+        from_context: &BindContext,
+        projections: &[ColumnBinding],
+        order: &OrderByExpr<'a>,
+        distinct: bool,
+    ) -> Result<Option<Collation>> {
+        let (database, table, column) = match &order.expr {
+            Expr::ColumnRef {
+                database,
+                table,
+                column,
+                ..
+            } if !distinct => (database, table, column),
+            _ => return Ok(None),
+        };
+        let database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name);
+        let table = table
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name);
+        let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+
+        let index = match projections.iter().find(|item| {
+            BindContext::match_column_binding(
+                database.as_deref(),
+                table.as_deref(),
+                name.as_str(),
+                item,
+            )
+        }) {
+            Some(item) => item.index,
+            None => match from_context.resolve_name(
+                database.as_deref(),
+                table.as_deref(),
+                &name,
+                &column.span,
+                &[],
+            ) {
+                Ok(NameResolutionResult::Column(column)) => column.index,
+                _ => return Ok(None),
+            },
+        };
+        column_collation(&self.metadata, index)
+    }
+
     pub(super) async fn bind_order_by(
         &mut self,
         from_context: &BindContext,
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_recursion::async_recursion;
+use common_ast::ast::Identifier;
 use common_ast::ast::Indirection;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
@@ -21,33 +24,51 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
+use common_ast::parser::token::Token;
 use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
 use common_ast::Backtrace;
 use common_ast::Dialect;
 use common_ast::DisplayError;
+use common_ast::VisitorMut;
 use common_catalog::catalog::CATALOG_DEFAULT;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_legacy_expression::LegacyExpression;
+use common_meta_types::TableMaskingPolicies;
+use common_meta_types::TableRowAccessPolicy;
 use common_planner::IndexType;
+use common_users::UserApiProvider;
 
 use crate::sql::binder::scalar::ScalarBinder;
+use crate::sql::binder::scalar_common::split_conjunctions;
 use crate::sql::binder::Binder;
 use crate::sql::binder::ColumnBinding;
 use crate::sql::binder::CteInfo;
 use crate::sql::binder::Visibility;
+use crate::sql::optimizer::ColumnStat;
+use crate::sql::optimizer::ColumnStatSet;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::semantic::normalize_identifier;
 use crate::sql::planner::semantic::TypeChecker;
 use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::Filter;
 use crate::sql::plans::LogicalGet;
 use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarItem;
 use crate::sql::BindContext;
+use crate::sql::OPT_KEY_MASKING_POLICIES;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY;
+use crate::storages::fuse::FuseTable;
+use crate::storages::parquet::ParquetTable;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::NavigationPoint;
 use crate::storages::Table;
+use crate::table_functions::DictionaryTable;
 use crate::table_functions::TableFunction;
 
 impl<'a> Binder {
@@ -69,9 +90,8 @@ impl<'a> Binder {
         }
         let catalog = CATALOG_DEFAULT;
         let database = "system";
-        let tenant = self.ctx.get_tenant();
         let table_meta: Arc<dyn Table> = self
-            .resolve_data_source(tenant.as_str(), catalog, database, "one", &None)
+            .resolve_data_source(catalog, database, "one", &None)
             .await?;
         let table_index = self.metadata.write().add_table(
             CATALOG_DEFAULT.to_owned(),
@@ -83,6 +103,7 @@ impl<'a> Binder {
             .await
     }
 
+    #[async_recursion]
     pub(super) async fn bind_table_reference(
         &mut self,
         bind_context: &BindContext,
@@ -114,8 +135,6 @@ impl<'a> Binder {
                     .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
                     .unwrap_or_else(|| self.ctx.get_current_database());
 
-                let tenant = self.ctx.get_tenant();
-
                 let navigation_point = match travel_point {
                     Some(tp) => Some(self.resolve_data_travel_point(bind_context, tp).await?),
                     None => None,
@@ -124,7 +143,6 @@ impl<'a> Binder {
                 // Resolve table with catalog
                 let table_meta: Arc<dyn Table> = self
                     .resolve_data_source(
-                        tenant.as_str(),
                         catalog.as_str(),
                         database.as_str(),
                         table_name.as_str(),
@@ -150,14 +168,54 @@ impl<'a> Binder {
                         }
                     }
                     _ => {
-                        let table_index =
-                            self.metadata
-                                .write()
-                                .add_table(catalog, database.clone(), table_meta);
+                        let row_access_policy =
+                            table_meta.options().get(OPT_KEY_ROW_ACCESS_POLICY).cloned();
+                        let masking_policies =
+                            table_meta.options().get(OPT_KEY_MASKING_POLICIES).cloned();
+                        // The indexes hold the aggregations of all the rows of the current
+                        // snapshot, they can't answer the queries of the filtered or masked rows,
+                        // nor the ones of the history.
+                        let aggregating_indexes = if navigation_point.is_none()
+                            && row_access_policy.is_none()
+                            && masking_policies.is_none()
+                            && self.ctx.get_settings().get_enable_aggregating_index()?
+                        {
+                            FuseTable::parse_aggregating_indexes(table_meta.options())
+                        } else {
+                            vec![]
+                        };
+                        let table_index = self.metadata.write().add_table(
+                            catalog.clone(),
+                            database.clone(),
+                            table_meta,
+                        );
+                        for index in &aggregating_indexes {
+                            // The index being dropped is skipped, the query reads the table.
+                            if let Ok(index_table) =
+                                self.ctx.get_table(&catalog, &database, index).await
+                            {
+                                self.metadata.write().add_aggregating_index(
+                                    table_index,
+                                    catalog.clone(),
+                                    database.clone(),
+                                    index_table,
+                                );
+                            }
+                        }
 
-                        let (s_expr, mut bind_context) = self
+                        let (mut s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        if let Some(row_access_policy) = row_access_policy {
+                            s_expr = self
+                                .bind_row_access_policy(&bind_context, &row_access_policy, s_expr)
+                                .await?;
+                        }
+                        if let Some(masking_policies) = masking_policies {
+                            s_expr = self
+                                .bind_masking_policies(&mut bind_context, &masking_policies, s_expr)
+                                .await?;
+                        }
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -166,7 +224,7 @@ impl<'a> Binder {
                 }
             }
             TableReference::TableFunction {
-                span: _,
+                span,
                 name,
                 params,
                 alias,
@@ -183,6 +241,12 @@ impl<'a> Binder {
                     args.push(scalar_binder.bind(arg).await?);
                 }
 
+                // `IDENTIFIER(<string>)` refers to the table named by the string, e.g. `$t`.
+                if name.name.eq_ignore_ascii_case("identifier") {
+                    let table_ref = self.resolve_table_identifier(*span, name, &args, alias)?;
+                    return self.bind_table_reference(bind_context, &table_ref).await;
+                }
+
                 let expressions = args
                     .into_iter()
                     .map(|(scalar, _)| match scalar {
@@ -210,7 +274,16 @@ impl<'a> Binder {
                         &normalize_identifier(name, &self.name_resolution_ctx).name,
                         table_args,
                     )?;
-                let table = table_meta.as_table();
+                let mut table = table_meta.as_table();
+
+                // The schema of an external parquet location is only known after reading its files.
+                if let Some(parquet_table) = table.as_any().downcast_ref::<ParquetTable>() {
+                    table = parquet_table.infer_schema(self.ctx.clone()).await?;
+                }
+                // The schema of a dictionary is only known after loading it.
+                if let Some(dictionary_table) = table.as_any().downcast_ref::<DictionaryTable>() {
+                    table = dictionary_table.load_schema(self.ctx.clone()).await?;
+                }
 
                 let table_index = self.metadata.write().add_table(
                     CATALOG_DEFAULT.to_string(),
@@ -241,6 +314,59 @@ impl<'a> Binder {
         }
     }
 
+    /// Resolves `IDENTIFIER('[[<catalog>.]<database>.]<table>')` to the table it names.
+    fn resolve_table_identifier(
+        &self,
+        span: &'a [Token<'a>],
+        name: &Identifier<'a>,
+        args: &[(Scalar, DataTypeImpl)],
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<TableReference<'a>> {
+        let object_name = match args {
+            [
+                (
+                    Scalar::ConstantExpr(ConstantExpr {
+                        value: DataValue::String(value),
+                        ..
+                    }),
+                    _,
+                ),
+            ] => String::from_utf8(value.clone())?,
+            _ => {
+                return Err(ErrorCode::SemanticError(span.display_error(
+                    "IDENTIFIER expects the name of the table as a string".to_string(),
+                )));
+            }
+        };
+
+        let mut idents = object_name
+            .split('.')
+            .map(|part| Identifier {
+                name: part.to_string(),
+                quote: None,
+                span: name.span.clone(),
+            })
+            .collect::<Vec<_>>();
+        if idents.len() > 3 || idents.iter().any(|ident| ident.name.is_empty()) {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "Invalid table name '{}' of IDENTIFIER",
+                object_name
+            ))));
+        }
+
+        let table = idents.pop().unwrap();
+        let database = idents.pop();
+        let catalog = idents.pop();
+        Ok(TableReference::Table {
+            span,
+            catalog,
+            database,
+            table,
+            alias: alias.clone(),
+            travel_point: None,
+        })
+    }
+
     fn bind_cte(
         &mut self,
         bind_context: &BindContext,
@@ -253,10 +379,11 @@ impl<'a> Binder {
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
+                let col_alias = normalize_identifier(col_alias, &self.name_resolution_ctx).name;
                 if idx < cte_info.columns_alias.len() {
-                    cols_alias[idx] = col_alias.name.clone();
+                    cols_alias[idx] = col_alias;
                 } else {
-                    cols_alias.push(col_alias.name.clone());
+                    cols_alias.push(col_alias);
                 }
             }
         }
@@ -282,7 +409,7 @@ impl<'a> Binder {
         Ok((cte_info.s_expr.clone(), new_bind_context))
     }
 
-    async fn bind_base_table(
+    pub(in crate::sql::planner::binder) async fn bind_base_table(
         &mut self,
         bind_context: &BindContext,
         database_name: &str,
@@ -307,6 +434,24 @@ impl<'a> Binder {
             bind_context.add_column_binding(column_binding);
         }
         let stat = table.table().statistics(self.ctx.clone()).await?;
+        // The columns of the fields are added in the order of the schema, before the inner
+        // columns of the structs.
+        let mut table_column_stats = table.table().column_statistics(self.ctx.clone()).await?;
+        let column_stats = match stat.and_then(|s| s.num_rows) {
+            Some(num_rows) => columns
+                .iter()
+                .filter(|column| !column.has_path_indices())
+                .enumerate()
+                .filter_map(|(field_index, column)| {
+                    let statistics = table_column_stats.remove(&field_index)?;
+                    Some((column.index(), ColumnStat {
+                        num_rows,
+                        statistics,
+                    }))
+                })
+                .collect(),
+            None => ColumnStatSet::new(),
+        };
         Ok((
             SExpr::create_leaf(
                 LogicalGet {
@@ -315,8 +460,11 @@ impl<'a> Binder {
                     push_down_predicates: None,
                     limit: None,
                     order_by: None,
+                    vector_search: None,
                     statistics: stat,
+                    column_stats,
                     prewhere: None,
+                    broadcast_hint: false,
                 }
                 .into(),
             ),
@@ -324,17 +472,174 @@ impl<'a> Binder {
         ))
     }
 
+    // The rows of the table are filtered by the row access policy attached to the table.
+    async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        option_value: &str,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let (_, scalar) = self
+            .bind_row_access_predicate(bind_context, option_value)
+            .await?;
+        let filter_plan = Filter {
+            predicates: split_conjunctions(&scalar),
+            is_having: false,
+        };
+        Ok(SExpr::create_unary(filter_plan.into(), child))
+    }
+
+    // The predicate of the row access policy attached to the table, of which the parameters
+    // are replaced with the columns of the table.
+    pub(in crate::sql::planner::binder) async fn bind_row_access_predicate(
+        &mut self,
+        bind_context: &BindContext,
+        option_value: &str,
+    ) -> Result<(String, Scalar)> {
+        let attached = TableRowAccessPolicy::try_from_option_value(option_value)?;
+        // The query is not cached, since the policy may be altered.
+        self.ctx.set_cacheable(false);
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(&self.ctx.get_tenant(), &attached.policy)
+            .await?;
+        if policy.parameters.len() != attached.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} requires {} columns, but got: {}",
+                policy.name,
+                policy.parameters.len(),
+                attached.columns.len()
+            )));
+        }
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let backtrace = Backtrace::new();
+        let tokens = tokenize_sql(&policy.definition)?;
+        let mut expr = parse_expr(&tokens, sql_dialect, &backtrace)?;
+        walk_expr_mut(
+            &mut PolicyParameterRewriter {
+                columns: policy
+                    .parameters
+                    .into_iter()
+                    .zip(attached.columns)
+                    .collect(),
+            },
+            &mut expr,
+        );
+
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, data_type) = scalar_binder.bind(&expr).await?;
+        if remove_nullable(&data_type).data_type_id() != TypeID::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} must return boolean, but got {:?}",
+                policy.name,
+                data_type.data_type_id()
+            )));
+        }
+        Ok((expr.to_string(), scalar))
+    }
+
+    // The expression masking the value of the column by the masking policy, of which the
+    // parameter is replaced with the column. The result is casted to the return type of the
+    // policy.
+    pub(in crate::sql::planner::binder) async fn masking_policy_expr(
+        &self,
+        column: &str,
+        policy: &str,
+    ) -> Result<String> {
+        let policy = UserApiProvider::instance()
+            .get_masking_policy(&self.ctx.get_tenant(), policy)
+            .await?;
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let backtrace = Backtrace::new();
+        let definition = format!("CAST(({}) AS {})", policy.definition, policy.return_type);
+        let tokens = tokenize_sql(&definition)?;
+        let mut expr = parse_expr(&tokens, sql_dialect, &backtrace)?;
+        walk_expr_mut(
+            &mut PolicyParameterRewriter {
+                columns: HashMap::from([(policy.parameter, column.to_string())]),
+            },
+            &mut expr,
+        );
+        Ok(expr.to_string())
+    }
+
+    // The values of the columns are replaced with the results of the masking policies above
+    // the scan, the bindings of the columns refer to the masked values, so the values are
+    // masked in any expression of the query.
+    async fn bind_masking_policies(
+        &mut self,
+        bind_context: &mut BindContext,
+        option_value: &str,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let attached = TableMaskingPolicies::try_from_option_value(option_value)?;
+        // The query is not cached, since the policies may be altered.
+        self.ctx.set_cacheable(false);
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let backtrace = Backtrace::new();
+
+        let mut items = Vec::with_capacity(attached.columns.len());
+        let mut masked_columns = Vec::with_capacity(attached.columns.len());
+        for (column, policy) in attached.columns.iter() {
+            let position = match bind_context
+                .columns
+                .iter()
+                .position(|binding| &binding.column_name == column)
+            {
+                Some(position) => position,
+                None => continue,
+            };
+            let definition = self.masking_policy_expr(column, policy).await?;
+            let tokens = tokenize_sql(&definition)?;
+            let expr = parse_expr(&tokens, sql_dialect, &backtrace)?;
+
+            let mut scalar_binder = ScalarBinder::new(
+                bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, data_type) = scalar_binder.bind(&expr).await?;
+            let index =
+                self.metadata
+                    .write()
+                    .add_column(column.clone(), data_type.clone(), None, None);
+            items.push(ScalarItem { scalar, index });
+            masked_columns.push((position, index, data_type));
+        }
+
+        if items.is_empty() {
+            return Ok(child);
+        }
+        // The policies are bound to the unmasked values, before the bindings are replaced.
+        for (position, index, data_type) in masked_columns {
+            let binding = &mut bind_context.columns[position];
+            binding.index = index;
+            binding.data_type = Box::new(data_type);
+        }
+        Ok(SExpr::create_unary(EvalScalar { items }.into(), child))
+    }
+
     async fn resolve_data_source(
         &self,
-        tenant: &str,
         catalog_name: &str,
         database_name: &str,
         table_name: &str,
         travel_point: &Option<NavigationPoint>,
     ) -> Result<Arc<dyn Table>> {
-        // Resolve table with catalog
-        let catalog = self.catalogs.get_catalog(catalog_name)?;
-        let mut table_meta = catalog.get_table(tenant, database_name, table_name).await?;
+        // Resolve table by the context, the references of the same table read the same snapshot,
+        // and the tables of the query are read as of the same point in time.
+        let mut table_meta = self
+            .ctx
+            .get_read_table(catalog_name, database_name, table_name)
+            .await?;
 
         if let Some(tp) = travel_point {
             table_meta = table_meta.navigate_to(self.ctx.clone(), tp).await?;
@@ -375,3 +680,27 @@ impl<'a> Binder {
         }
     }
 }
+
+// Replaces the parameters of the policies with the quoted columns, so the
+// normalized names of the columns are kept.
+struct PolicyParameterRewriter {
+    columns: HashMap<String, String>,
+}
+
+impl VisitorMut for PolicyParameterRewriter {
+    fn visit_column_ref(
+        &mut self,
+        _span: &mut &[Token<'_>],
+        database: &mut Option<Identifier<'_>>,
+        table: &mut Option<Identifier<'_>>,
+        column: &mut Identifier<'_>,
+    ) {
+        if database.is_some() || table.is_some() {
+            return;
+        }
+        if let Some(name) = self.columns.get(&column.to_string()) {
+            column.name = name.clone();
+            column.quote = Some('`');
+        }
+    }
+}
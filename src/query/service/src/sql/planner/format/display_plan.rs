@@ -41,14 +41,21 @@ impl Plan {
             Plan::DropDatabase(drop_database) => Ok(format!("{:?}", drop_database)),
             Plan::UndropDatabase(undrop_database) => Ok(format!("{:?}", undrop_database)),
             Plan::RenameDatabase(rename_database) => Ok(format!("{:?}", rename_database)),
+            Plan::SetDatabaseComment(set_database_comment) => {
+                Ok(format!("{:?}", set_database_comment))
+            }
+            Plan::BackupDatabase(backup_database) => Ok(format!("{:?}", backup_database)),
+            Plan::RestoreDatabase(restore_database) => Ok(format!("{:?}", restore_database)),
 
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(format!("{:?}", show_create_table)),
             Plan::CreateTable(create_table) => Ok(format!("{:?}", create_table)),
+            Plan::AttachTable(attach_table) => Ok(format!("{:?}", attach_table)),
             Plan::DropTable(drop_table) => Ok(format!("{:?}", drop_table)),
             Plan::UndropTable(undrop_table) => Ok(format!("{:?}", undrop_table)),
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
+            Plan::SwapTable(swap_table) => Ok(format!("{:?}", swap_table)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -56,8 +63,32 @@ impl Plan {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
+            Plan::SetTableOptions(set_table_options) => Ok(format!("{:?}", set_table_options)),
+            Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
+            Plan::AddTableVirtualColumn(add_table_virtual_column) => {
+                Ok(format!("{:?}", add_table_virtual_column))
+            }
+            Plan::DropTableVirtualColumn(drop_table_virtual_column) => {
+                Ok(format!("{:?}", drop_table_virtual_column))
+            }
+            Plan::AddTableRowAccessPolicy(add_table_row_access_policy) => {
+                Ok(format!("{:?}", add_table_row_access_policy))
+            }
+            Plan::DropTableRowAccessPolicy(drop_table_row_access_policy) => {
+                Ok(format!("{:?}", drop_table_row_access_policy))
+            }
+            Plan::SetTableColumnMaskingPolicy(set_table_column_masking_policy) => {
+                Ok(format!("{:?}", set_table_column_masking_policy))
+            }
+            Plan::UnsetTableColumnMaskingPolicy(unset_table_column_masking_policy) => {
+                Ok(format!("{:?}", unset_table_column_masking_policy))
+            }
+            Plan::SetTableColumnComment(set_table_column_comment) => {
+                Ok(format!("{:?}", set_table_column_comment))
+            }
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
+            Plan::AnalyzeTable(analyze_table) => Ok(format!("{:?}", analyze_table)),
             Plan::ExistsTable(exists_table) => Ok(format!("{:?}", exists_table)),
 
             // Views
@@ -65,8 +96,17 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
+
+            // Aggregating indexes
+            Plan::CreateAggregatingIndex(create_index) => Ok(format!("{:?}", create_index)),
+            Plan::DropAggregatingIndex(drop_index) => Ok(format!("{:?}", drop_index)),
+
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
+            Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
 
             // Stages
@@ -81,11 +121,49 @@ impl Plan {
             Plan::ShowGrants(show_grants) => Ok(format!("{:?}", show_grants)),
             Plan::RevokePriv(revoke_priv) => Ok(format!("{:?}", revoke_priv)),
             Plan::RevokeRole(revoke_role) => Ok(format!("{:?}", revoke_role)),
+            Plan::SetRole(set_role) => Ok(format!("{:?}", set_role)),
             Plan::CreateUser(create_user) => Ok(format!("{:?}", create_user)),
             Plan::DropUser(drop_user) => Ok(format!("{:?}", drop_user)),
             Plan::CreateUDF(create_user_udf) => Ok(format!("{:?}", create_user_udf)),
             Plan::AlterUDF(alter_user_udf) => Ok(format!("{alter_user_udf:?}")),
             Plan::DropUDF(drop_udf) => Ok(format!("{drop_udf:?}")),
+            Plan::CreateRowAccessPolicy(create_row_access_policy) => {
+                Ok(format!("{create_row_access_policy:?}"))
+            }
+            Plan::DropRowAccessPolicy(drop_row_access_policy) => {
+                Ok(format!("{drop_row_access_policy:?}"))
+            }
+            Plan::CreateMaskingPolicy(create_masking_policy) => {
+                Ok(format!("{create_masking_policy:?}"))
+            }
+            Plan::DropMaskingPolicy(drop_masking_policy) => Ok(format!("{drop_masking_policy:?}")),
+            Plan::CreateNetworkPolicy(create_network_policy) => {
+                Ok(format!("{create_network_policy:?}"))
+            }
+            Plan::DropNetworkPolicy(drop_network_policy) => Ok(format!("{drop_network_policy:?}")),
+            Plan::CreateTask(create_task) => Ok(format!("{create_task:?}")),
+            Plan::DropTask(drop_task) => Ok(format!("{drop_task:?}")),
+            Plan::AlterTask(alter_task) => Ok(format!("{alter_task:?}")),
+            Plan::CreateResourceGroup(create_resource_group) => {
+                Ok(format!("{create_resource_group:?}"))
+            }
+            Plan::DropResourceGroup(drop_resource_group) => Ok(format!("{drop_resource_group:?}")),
+            // The secrets of the connection are never displayed.
+            Plan::CreateConnection(create_connection) => Ok(format!(
+                "CreateConnectionPlan {{ if_not_exists: {}, name: {:?}, storage_type: {:?}, storage_params: {:?} }}",
+                create_connection.if_not_exists,
+                create_connection.connection.name,
+                create_connection.connection.storage_type,
+                create_connection.connection.masked_storage_params()
+            )),
+            Plan::DropConnection(drop_connection) => Ok(format!("{drop_connection:?}")),
+            Plan::CreateSequence(create_sequence) => Ok(format!("{create_sequence:?}")),
+            Plan::DropSequence(drop_sequence) => Ok(format!("{drop_sequence:?}")),
+            Plan::CreatePlanHint(create_plan_hint) => Ok(format!("{create_plan_hint:?}")),
+            Plan::DropPlanHint(drop_plan_hint) => Ok(format!("{drop_plan_hint:?}")),
+            Plan::DrainNode(drain_node) => Ok(format!("{drain_node:?}")),
+            Plan::CreateDictionary(create_dictionary) => Ok(format!("{create_dictionary:?}")),
+            Plan::DropDictionary(drop_dictionary) => Ok(format!("{drop_dictionary:?}")),
             Plan::AlterUser(alter_user) => Ok(format!("{:?}", alter_user)),
             Plan::CreateRole(create_role) => Ok(format!("{:?}", create_role)),
             Plan::DropRole(drop_role) => Ok(format!("{:?}", drop_role)),
@@ -93,8 +171,14 @@ impl Plan {
             Plan::Presign(presign) => Ok(format!("{:?}", presign)),
 
             Plan::SetVariable(p) => Ok(format!("{:?}", p)),
+            Plan::SetUserVariable(p) => Ok(format!("{:?}", p)),
+            Plan::UnsetUserVariable(p) => Ok(format!("{:?}", p)),
+            Plan::ExecuteImmediate(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Rollback => Ok("Rollback".to_string()),
 
             Plan::CreateShare(p) => Ok(format!("{:?}", p)),
             Plan::DropShare(p) => Ok(format!("{:?}", p)),
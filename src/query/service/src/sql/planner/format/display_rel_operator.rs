@@ -192,6 +192,9 @@ pub fn format_exchange(
         Exchange::Merge => {
             write!(f, "Exchange(Merge)")
         }
+        Exchange::RoundRobin => {
+            write!(f, "Exchange(RoundRobin)")
+        }
     }
 }
 
@@ -14,6 +14,8 @@
 
 mod format;
 mod metadata;
+mod plan_cache;
+mod plan_hint;
 #[allow(clippy::module_inception)]
 mod planner;
 mod semantic;
@@ -26,6 +28,12 @@ pub use binder::BindContext;
 pub use binder::Binder;
 pub use binder::ColumnBinding;
 pub use binder::Visibility;
+pub use plan_cache::PlanCache;
+pub use plan_cache::PlanCacheKey;
+pub use plan_cache::PlanCacheStatement;
+pub use plan_cache::PlanCacheTable;
+pub use plan_hint::hint_sql;
+pub use plan_hint::PlanHintCache;
 pub use planner::Planner;
 pub use plans::ScalarExpr;
 pub use semantic::normalize_identifier;
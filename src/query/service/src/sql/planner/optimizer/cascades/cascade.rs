@@ -176,7 +176,14 @@ impl CascadesOptimizer {
             .map(|index| self.find_optimal_plan(*index))
             .collect::<Result<Vec<_>>>()?;
 
-        let result = SExpr::create(m_expr.plan.clone(), children, None, None);
+        // Keep the relational property of the group, which is used to choose the
+        // distribution of the plan.
+        let result = SExpr::create(
+            m_expr.plan.clone(),
+            children,
+            None,
+            Some(Box::new(group.relational_prop.clone())),
+        );
 
         Ok(result)
     }
@@ -0,0 +1,302 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::DataField;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_planner::plans::AggregatingIndexDefinition;
+use common_planner::ColumnEntry;
+use common_planner::IndexType;
+use common_planner::MetadataRef;
+use common_planner::TableEntry;
+
+use crate::sql::binder::ColumnBinding;
+use crate::sql::binder::Visibility;
+use crate::sql::optimizer::ColumnStatSet;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::Aggregate;
+use crate::sql::plans::AggregateFunction;
+use crate::sql::plans::AggregateMode;
+use crate::sql::plans::BoundColumnRef;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::Filter;
+use crate::sql::plans::LogicalGet;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
+use crate::sql::OPT_KEY_AGGREGATING_INDEX_DEFINITION;
+
+/// Rewrites the aggregations of a table into the aggregations of its aggregating index, of which
+/// the group by columns cover the group by and filtered columns of the aggregation, e.g. by the
+/// index `SELECT a, b, sum(c), count(*) FROM t GROUP BY a, b`
+///
+///   Aggregate (a; sum(c), count(*))         Aggregate (a; sum(sum(c)), sum(count(*)))
+///    \                                       \
+///     EvalScalar (a, c)                 =>    Filter (b > 1)
+///      \                                       \
+///       Filter (b > 1)                          EvalScalar (a, b: the columns of the index)
+///        \                                       \
+///         t                                       idx
+///
+/// The group by and filtered columns are replaced with the ones of the index, the aggregates are
+/// aggregated again from the pre-aggregated columns of the index.
+pub fn rewrite_aggregating_indexes(metadata: MetadataRef, s_expr: SExpr) -> Result<SExpr> {
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| rewrite_aggregating_indexes(metadata.clone(), child.clone()))
+        .collect::<Result<Vec<_>>>()?;
+    let s_expr = s_expr.replace_children(children);
+
+    let aggregate = match s_expr.plan() {
+        RelOperator::Aggregate(aggregate)
+            if aggregate.mode == AggregateMode::Initial && !aggregate.from_distinct =>
+        {
+            aggregate
+        }
+        _ => return Ok(s_expr),
+    };
+
+    // Aggregate -> [EvalScalar of the columns] -> [Filter] -> LogicalGet.
+    let mut input = s_expr.child(0)?;
+    if let RelOperator::EvalScalar(eval_scalar) = input.plan() {
+        let passthrough = eval_scalar.items.iter().all(|item| match &item.scalar {
+            Scalar::BoundColumnRef(column) => column.column.index == item.index,
+            _ => false,
+        });
+        if !passthrough {
+            return Ok(s_expr);
+        }
+        input = input.child(0)?;
+    }
+    let filter = match input.plan() {
+        RelOperator::Filter(filter) if !filter.is_having => {
+            input = input.child(0)?;
+            Some(filter.clone())
+        }
+        _ => None,
+    };
+    let get = match input.plan() {
+        RelOperator::LogicalGet(get)
+            if get.push_down_predicates.is_none()
+                && get.prewhere.is_none()
+                && get.limit.is_none()
+                && get.order_by.is_none() =>
+        {
+            get
+        }
+        _ => return Ok(s_expr),
+    };
+
+    let indexes = metadata
+        .read()
+        .aggregating_indexes(get.table_index)
+        .to_vec();
+    if indexes.is_empty() {
+        return Ok(s_expr);
+    }
+    let columns = metadata
+        .read()
+        .columns_by_table_index(get.table_index)
+        .into_iter()
+        .filter(|column| !column.has_path_indices())
+        .map(|column| (column.index(), column.name().to_string()))
+        .collect::<HashMap<_, _>>();
+
+    for index in &indexes {
+        if let Some(rewritten) =
+            try_rewrite(&metadata, aggregate, filter.as_ref(), &columns, index)?
+        {
+            return Ok(rewritten);
+        }
+    }
+    Ok(s_expr)
+}
+
+// The aggregation of the index, if the index can answer the aggregation of the table.
+fn try_rewrite(
+    metadata: &MetadataRef,
+    aggregate: &Aggregate,
+    filter: Option<&Filter>,
+    columns: &HashMap<IndexType, String>,
+    index: &TableEntry,
+) -> Result<Option<SExpr>> {
+    let definition = match index
+        .table()
+        .options()
+        .get(OPT_KEY_AGGREGATING_INDEX_DEFINITION)
+        .map(|value| AggregatingIndexDefinition::from_table_option(value.as_str()))
+    {
+        Some(Ok(definition)) => definition,
+        _ => return Ok(None),
+    };
+
+    // The group by and filtered columns must be the group by columns of the index.
+    let mut group_columns = Vec::new();
+    for item in &aggregate.group_items {
+        match &item.scalar {
+            Scalar::BoundColumnRef(column) => group_columns.push(column.column.index),
+            _ => return Ok(None),
+        }
+    }
+    if let Some(filter) = filter {
+        for predicate in &filter.predicates {
+            group_columns.extend(predicate.used_columns());
+        }
+    }
+    group_columns.sort_unstable();
+    group_columns.dedup();
+    let mut group_names = Vec::with_capacity(group_columns.len());
+    for column in &group_columns {
+        match columns.get(column) {
+            Some(name) if definition.group_by.contains(name) => group_names.push(name.clone()),
+            _ => return Ok(None),
+        }
+    }
+
+    // Every aggregate must be one of the index, the count without argument is `count(*)`.
+    let mut aggregates = Vec::with_capacity(aggregate.aggregate_functions.len());
+    for item in &aggregate.aggregate_functions {
+        let func = match &item.scalar {
+            Scalar::AggregateFunction(func) if !func.distinct && func.params.is_empty() => func,
+            _ => return Ok(None),
+        };
+        let column = match func.args.as_slice() {
+            [] => None,
+            [Scalar::BoundColumnRef(column)] => match columns.get(&column.column.index) {
+                Some(name) => Some(name.clone()),
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let func_name = func.func_name.to_lowercase();
+        match definition
+            .aggregates
+            .iter()
+            .find(|aggregate| aggregate.func_name == func_name && aggregate.column == column)
+        {
+            Some(index_aggregate) => aggregates.push((item, func, index_aggregate.clone())),
+            None => return Ok(None),
+        }
+    }
+
+    let index_table_index = metadata.write().add_table(
+        index.catalog().to_string(),
+        index.database().to_string(),
+        index.table(),
+    );
+    let index_columns = metadata
+        .read()
+        .columns_by_table_index(index_table_index)
+        .into_iter()
+        .filter(|column| !column.has_path_indices())
+        .map(|column| (column.name().to_string(), column))
+        .collect::<HashMap<_, _>>();
+    let column_ref = |column: &ColumnEntry| -> Scalar {
+        BoundColumnRef {
+            column: ColumnBinding {
+                database_name: Some(index.database().to_string()),
+                table_name: Some(index.name().to_string()),
+                column_name: column.name().to_string(),
+                index: column.index(),
+                data_type: Box::new(column.data_type().clone()),
+                visibility: Visibility::Visible,
+            },
+        }
+        .into()
+    };
+
+    let mut aggregate_functions = Vec::with_capacity(aggregates.len());
+    let mut items = Vec::with_capacity(group_columns.len() + aggregates.len());
+    for (item, func, index_aggregate) in aggregates {
+        let index_column = match index_columns.get(&index_aggregate.column_name()) {
+            Some(column) => column,
+            None => return Ok(None),
+        };
+        // The aggregate of the index column must be of the type of the original one.
+        let func_name = index_aggregate.rollup_func_name();
+        let rollup =
+            AggregateFunctionFactory::instance().get(func_name, vec![], vec![DataField::new(
+                index_column.name(),
+                index_column.data_type().clone(),
+            )])?;
+        if rollup.return_type()? != *func.return_type {
+            return Ok(None);
+        }
+        items.push(ScalarItem {
+            scalar: column_ref(index_column),
+            index: index_column.index(),
+        });
+        aggregate_functions.push(ScalarItem {
+            scalar: AggregateFunction {
+                display_name: func.display_name.clone(),
+                func_name: func_name.to_string(),
+                distinct: false,
+                params: vec![],
+                args: vec![column_ref(index_column)],
+                return_type: func.return_type.clone(),
+            }
+            .into(),
+            index: item.index,
+        });
+    }
+    // The columns of the table are evaluated from the ones of the index, the group items and the
+    // filter keep referring to them.
+    for (column, name) in group_columns.iter().zip(group_names.iter()) {
+        let index_column = match index_columns.get(name) {
+            Some(column) => column,
+            None => return Ok(None),
+        };
+        items.push(ScalarItem {
+            scalar: column_ref(index_column),
+            index: *column,
+        });
+    }
+
+    let mut input = SExpr::create_leaf(
+        LogicalGet {
+            table_index: index_table_index,
+            columns: index_columns
+                .values()
+                .map(|column| column.index())
+                .collect(),
+            push_down_predicates: None,
+            limit: None,
+            order_by: None,
+            vector_search: None,
+            prewhere: None,
+            broadcast_hint: false,
+            statistics: None,
+            column_stats: ColumnStatSet::new(),
+        }
+        .into(),
+    );
+    input = SExpr::create_unary(EvalScalar { items }.into(), input);
+    if let Some(filter) = filter {
+        input = SExpr::create_unary(filter.clone().into(), input);
+    }
+    Ok(Some(SExpr::create_unary(
+        Aggregate {
+            mode: AggregateMode::Initial,
+            group_items: aggregate.group_items.clone(),
+            aggregate_functions,
+            from_distinct: false,
+        }
+        .into(),
+        input,
+    )))
+}
@@ -399,8 +399,11 @@ impl SubqueryRewriter {
                     push_down_predicates: None,
                     limit: None,
                     order_by: None,
+                    vector_search: None,
                     statistics: None,
+                    column_stats: Default::default(),
                     prewhere: None,
+                    broadcast_hint: false,
                 }
                 .into(),
             );
@@ -0,0 +1,217 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::wrap_nullable;
+use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_datavalues::StringType;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planner::IndexType;
+use common_planner::MetadataRef;
+
+use crate::sql::binder::wrap_cast_if_needed;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::Filter;
+use crate::sql::plans::FunctionCall;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::LogicalInnerJoin;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
+use crate::table_functions::DictionaryTable;
+
+/// Rewrites the joins against `dictionary('<dictionary>')` on its primary key into the lookups of
+/// the dictionary, the columns of the dictionary are evaluated by `dict_get_or_default()` of the
+/// key of the other side, e.g.
+///
+///   Join (o.user_id = u.id)           EvalScalar (u.name: dict_get(users, name, o.user_id))
+///   /   \                        =>    \
+///  o    dictionary('users') u         Filter (dict_has(users, o.user_id)), of inner joins
+///                                      \
+///                                       o
+///
+/// Only the joins of the single equi-condition on the primary key are rewritten, the other side
+/// of an outer join must be the preserved side.
+pub fn rewrite_dictionary_joins(metadata: MetadataRef, s_expr: SExpr) -> Result<SExpr> {
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| rewrite_dictionary_joins(metadata.clone(), child.clone()))
+        .collect::<Result<Vec<_>>>()?;
+    let s_expr = s_expr.replace_children(children);
+
+    let join = match s_expr.plan() {
+        RelOperator::LogicalInnerJoin(join) => join,
+        _ => return Ok(s_expr),
+    };
+    if join.left_conditions.len() != 1
+        || !join.other_conditions.is_empty()
+        || join.from_correlated_subquery
+    {
+        return Ok(s_expr);
+    }
+
+    let (dictionary_left, dictionary_right) = match join.join_type {
+        JoinType::Inner => (true, true),
+        JoinType::Left => (false, true),
+        JoinType::Right => (true, false),
+        _ => return Ok(s_expr),
+    };
+    if dictionary_right {
+        if let Some(lookup) = try_lookup(&metadata, join, &s_expr, 1)? {
+            return Ok(lookup);
+        }
+    }
+    if dictionary_left {
+        if let Some(lookup) = try_lookup(&metadata, join, &s_expr, 0)? {
+            return Ok(lookup);
+        }
+    }
+    Ok(s_expr)
+}
+
+// The lookup of the join, if the child of the position is a dictionary joined on its primary key.
+fn try_lookup(
+    metadata: &MetadataRef,
+    join: &LogicalInnerJoin,
+    s_expr: &SExpr,
+    position: usize,
+) -> Result<Option<SExpr>> {
+    let get = match s_expr.child(position)?.plan() {
+        RelOperator::LogicalGet(get) => get,
+        _ => return Ok(None),
+    };
+    let (dictionary_condition, key) = if position == 0 {
+        (&join.left_conditions[0], &join.right_conditions[0])
+    } else {
+        (&join.right_conditions[0], &join.left_conditions[0])
+    };
+
+    let (dictionary_name, primary_key) = {
+        let metadata = metadata.read();
+        let table = metadata.table(get.table_index).table();
+        let dictionary = match table.as_any().downcast_ref::<DictionaryTable>() {
+            Some(dictionary) => dictionary,
+            None => return Ok(None),
+        };
+        (
+            dictionary.dictionary_name().to_string(),
+            dictionary.primary_key().to_string(),
+        )
+    };
+    // The key is of the type of the primary key, the binder casts both sides of the condition if
+    // they are of different types.
+    let primary_key_index = match dictionary_condition {
+        Scalar::BoundColumnRef(column) if column.column.column_name == primary_key => {
+            column.column.index
+        }
+        _ => return Ok(None),
+    };
+
+    let columns = metadata.read().columns_by_table_index(get.table_index);
+    let data_type = |index: IndexType| -> Option<DataTypeImpl> {
+        columns
+            .iter()
+            .find(|c| c.index() == index)
+            .map(|c| c.data_type().clone())
+    };
+    let primary_key_type = match data_type(primary_key_index) {
+        Some(data_type) => data_type,
+        None => return Ok(None),
+    };
+    let key_type = key.data_type();
+    let key = if key_type.is_nullable() {
+        wrap_cast_if_needed(key.clone(), &wrap_nullable(&primary_key_type))
+    } else {
+        wrap_cast_if_needed(key.clone(), &primary_key_type)
+    };
+
+    let mut input = s_expr.child(1 - position)?.clone();
+    let inner = join.join_type == JoinType::Inner;
+    if inner {
+        let lookup = dict_get(&dictionary_name, &primary_key, &key, &primary_key_type)?;
+        input = SExpr::create_unary(
+            Filter {
+                predicates: vec![function_call("is_not_null", vec![lookup])?],
+                is_having: false,
+            }
+            .into(),
+            input,
+        );
+    }
+
+    let mut items = Vec::with_capacity(columns.len());
+    for column in &columns {
+        let mut scalar = dict_get(&dictionary_name, column.name(), &key, column.data_type())?;
+        // The rows of no keys are filtered out of the inner joins.
+        if inner && !column.data_type().is_nullable() {
+            scalar = function_call("assume_not_null", vec![scalar])?;
+        }
+        items.push(ScalarItem {
+            scalar,
+            index: column.index(),
+        });
+    }
+    Ok(Some(SExpr::create_unary(
+        EvalScalar { items }.into(),
+        input,
+    )))
+}
+
+// `dict_get_or_default(dictionary, attribute, key, NULL)`.
+fn dict_get(
+    dictionary: &str,
+    attribute: &str,
+    key: &Scalar,
+    attribute_type: &DataTypeImpl,
+) -> Result<Scalar> {
+    let string_constant = |value: &str| -> Scalar {
+        ConstantExpr {
+            value: DataValue::String(value.as_bytes().to_vec()),
+            data_type: Box::new(StringType::new_impl()),
+        }
+        .into()
+    };
+    let default = ConstantExpr {
+        value: DataValue::Null,
+        data_type: Box::new(wrap_nullable(attribute_type)),
+    }
+    .into();
+    function_call("dict_get_or_default", vec![
+        string_constant(dictionary),
+        string_constant(attribute),
+        key.clone(),
+        default,
+    ])
+}
+
+fn function_call(func_name: &str, arguments: Vec<Scalar>) -> Result<Scalar> {
+    let arg_types = arguments
+        .iter()
+        .map(|arg| arg.data_type())
+        .collect::<Vec<_>>();
+    let func = FunctionFactory::instance().get(func_name, &arg_types.iter().collect::<Vec<_>>())?;
+    Ok(FunctionCall {
+        arguments,
+        func_name: func_name.to_string(),
+        arg_types,
+        return_type: Box::new(func.return_type()),
+    }
+    .into())
+}
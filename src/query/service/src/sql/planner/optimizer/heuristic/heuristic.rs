@@ -20,7 +20,9 @@ use once_cell::sync::Lazy;
 
 use super::prune_unused_columns::UnusedColumnPruner;
 use crate::sessions::TableContext;
+use crate::sql::optimizer::heuristic::aggregating_index::rewrite_aggregating_indexes;
 use crate::sql::optimizer::heuristic::decorrelate::decorrelate_subquery;
+use crate::sql::optimizer::heuristic::dictionary_join::rewrite_dictionary_joins;
 use crate::sql::optimizer::heuristic::implement::HeuristicImplementor;
 use crate::sql::optimizer::heuristic::prewhere_optimization::PrewhereOptimizer;
 use crate::sql::optimizer::heuristic::RuleList;
@@ -42,6 +44,7 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownLimitOuterJoin,
         RuleID::PushDownLimitScan,
         RuleID::PushDownSortScan,
+        RuleID::PushDownSortEvalScalarScan,
         RuleID::PushDownFilterEvalScalar,
         RuleID::PushDownFilterJoin,
         RuleID::FoldCountAggregate,
@@ -80,6 +83,8 @@ impl HeuristicOptimizer {
 
     fn pre_optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
         let result = decorrelate_subquery(self.metadata.clone(), s_expr)?;
+        let result = rewrite_dictionary_joins(self.metadata.clone(), result)?;
+        let result = rewrite_aggregating_indexes(self.metadata.clone(), result)?;
         Ok(result)
     }
 
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregating_index;
 mod decorrelate;
+mod dictionary_join;
 #[allow(clippy::module_inception)]
 mod heuristic;
 mod implement;
@@ -89,8 +89,11 @@ impl UnusedColumnPruner {
                     push_down_predicates: p.push_down_predicates.clone(),
                     limit: p.limit,
                     order_by: p.order_by.clone(),
+                    vector_search: p.vector_search.clone(),
                     statistics: p.statistics,
+                    column_stats: p.column_stats.clone(),
                     prewhere,
+                    broadcast_hint: p.broadcast_hint,
                 })))
             }
             RelOperator::LogicalInnerJoin(p) => {
@@ -37,11 +37,14 @@ pub use optimizer::OptimizerConfig;
 pub use optimizer::OptimizerContext;
 pub use pattern_extractor::PatternExtractor;
 pub use property::ColumnSet;
+pub use property::ColumnStat;
+pub use property::ColumnStatSet;
 pub use property::Distribution;
 pub use property::PhysicalProperty;
 pub use property::RelExpr;
 pub use property::RelationalProperty;
 pub use property::RequiredProperty;
+pub use property::SelectivityEstimator;
 pub use rule::RuleFactory;
 pub use rule::RuleID;
 pub use rule::RuleSet;
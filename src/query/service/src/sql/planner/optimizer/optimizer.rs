@@ -58,6 +58,7 @@ pub fn optimize(
             bind_context,
             metadata,
             rewrite_kind,
+            formatted_ast,
         } => Ok(Plan::Query {
             s_expr: Box::new(optimize_query(
                 ctx,
@@ -69,6 +70,7 @@ pub fn optimize(
             bind_context,
             metadata,
             rewrite_kind,
+            formatted_ast,
         }),
         Plan::Explain { kind, plan } => match kind {
             ExplainKind::Raw | ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
@@ -28,7 +28,12 @@ pub fn require_property(required: &RequiredProperty, s_expr: &SExpr) -> Result<S
         .iter()
         .map(|child| require_property(required, child))
         .collect::<Result<Vec<SExpr>>>()?;
-    let optimized_expr = SExpr::create(s_expr.plan().clone(), optimized_children, None, None);
+    let optimized_expr = SExpr::create(
+        s_expr.plan().clone(),
+        optimized_children,
+        None,
+        s_expr.rel_prop.clone(),
+    );
 
     let rel_expr = RelExpr::with_s_expr(&optimized_expr);
     let mut children = Vec::with_capacity(s_expr.arity());
@@ -49,7 +54,7 @@ pub fn require_property(required: &RequiredProperty, s_expr: &SExpr) -> Result<S
         optimized_expr.plan().clone(),
         children,
         None,
-        None,
+        s_expr.rel_prop.clone(),
     ))
 }
 
@@ -61,7 +66,13 @@ fn enforce_property(s_expr: &SExpr, required: &RequiredProperty) -> Result<SExpr
 
 pub fn enforce_distribution(distribution: &Distribution, s_expr: &SExpr) -> Result<SExpr> {
     match distribution {
-        Distribution::Random | Distribution::Any => Ok(s_expr.clone()),
+        Distribution::Any => Ok(s_expr.clone()),
+
+        // Redistribute the serial data without keys.
+        Distribution::Random => Ok(SExpr::create_unary(
+            Exchange::RoundRobin.into(),
+            s_expr.clone(),
+        )),
 
         Distribution::Serial => Ok(SExpr::create_unary(Exchange::Merge.into(), s_expr.clone())),
 
@@ -25,3 +25,6 @@ pub use property::Distribution;
 pub use property::PhysicalProperty;
 pub use property::RelationalProperty;
 pub use property::RequiredProperty;
+pub use stat::ColumnStat;
+pub use stat::ColumnStatSet;
+pub use stat::SelectivityEstimator;
@@ -16,6 +16,7 @@ use std::collections::HashSet;
 
 use common_planner::IndexType;
 
+use super::ColumnStatSet;
 use crate::sql::plans::Scalar;
 
 pub type ColumnSet = HashSet<IndexType>;
@@ -42,6 +43,11 @@ pub struct RelationalProperty {
     // We can get the precise row count of a table in databend,
     // which information is useful to optimize some queries like `COUNT(*)`.
     pub precise_cardinality: Option<u64>,
+    // The statistics of the output columns, which are used to estimate the
+    // selectivity of the predicates.
+    pub column_stats: ColumnStatSet,
+    // Whether the relation is hinted to be broadcasted in the distributed joins.
+    pub broadcast_hint: bool,
 }
 
 #[derive(Default, Clone)]
@@ -70,6 +76,8 @@ impl Distribution {
     pub fn satisfied_by(&self, distribution: &Distribution) -> bool {
         // (required, delivered)
         match (&self, distribution) {
+            // A `Random` distribution requires the data to be spread over the nodes.
+            (Distribution::Random, Distribution::Serial) => false,
             (Distribution::Any, _)
             | (Distribution::Random, _)
             | (Distribution::Serial, Distribution::Serial)
@@ -11,3 +11,185 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+
+use std::collections::HashMap;
+
+use common_catalog::table::TableColumnStatistics;
+use common_datavalues::DataValue;
+use common_planner::IndexType;
+
+use crate::sql::plans::ComparisonOp;
+use crate::sql::plans::Scalar;
+
+pub type ColumnStatSet = HashMap<IndexType, ColumnStat>;
+
+/// The statistics of a column of a table, derived from the `LogicalGet` of the table.
+#[derive(Debug, Clone)]
+pub struct ColumnStat {
+    /// The number of rows of the table.
+    pub num_rows: u64,
+    pub statistics: TableColumnStatistics,
+}
+
+/// Estimates the selectivity of the predicates of a filter with the statistics of the columns,
+/// the predicates that can't be estimated, e.g. the columns without the statistics, are taken
+/// as they keep all the rows.
+pub struct SelectivityEstimator<'a> {
+    column_stats: &'a ColumnStatSet,
+}
+
+impl<'a> SelectivityEstimator<'a> {
+    pub fn new(column_stats: &'a ColumnStatSet) -> Self {
+        Self { column_stats }
+    }
+
+    pub fn compute_selectivity(&self, predicate: &Scalar) -> f64 {
+        match predicate {
+            Scalar::AndExpr(and) => {
+                self.compute_selectivity(&and.left) * self.compute_selectivity(&and.right)
+            }
+            Scalar::OrExpr(or) => {
+                let left = self.compute_selectivity(&or.left);
+                let right = self.compute_selectivity(&or.right);
+                left + right - left * right
+            }
+            Scalar::ComparisonExpr(comparison) => {
+                match (comparison.left.as_ref(), comparison.right.as_ref()) {
+                    (Scalar::BoundColumnRef(column), constant) => constant_value(constant)
+                        .and_then(|value| {
+                            self.compute_comparison(column.column.index, &comparison.op, value)
+                        })
+                        .unwrap_or(1.0),
+                    (constant, Scalar::BoundColumnRef(column)) => constant_value(constant)
+                        .and_then(|value| {
+                            let op = reverse_op(&comparison.op);
+                            self.compute_comparison(column.column.index, &op, value)
+                        })
+                        .unwrap_or(1.0),
+                    _ => 1.0,
+                }
+            }
+            Scalar::FunctionCall(func) if func.arguments.len() == 1 => {
+                let stat = match &func.arguments[0] {
+                    Scalar::BoundColumnRef(column) => self.column_stats.get(&column.column.index),
+                    _ => None,
+                };
+                match (func.func_name.as_str(), stat) {
+                    ("is_null", Some(stat)) => null_fraction(stat),
+                    ("is_not_null", Some(stat)) => 1.0 - null_fraction(stat),
+                    _ => 1.0,
+                }
+            }
+            _ => 1.0,
+        }
+    }
+
+    fn compute_comparison(
+        &self,
+        column: IndexType,
+        op: &ComparisonOp,
+        value: &DataValue,
+    ) -> Option<f64> {
+        let stat = self.column_stats.get(&column)?;
+        let statistics = &stat.statistics;
+        if statistics.min.is_null() || !is_comparable(&statistics.min, value) {
+            return None;
+        }
+
+        let selectivity = match op {
+            ComparisonOp::Equal | ComparisonOp::NotEqual => {
+                let equal = if value < &statistics.min || value > &statistics.max {
+                    0.0
+                } else {
+                    1.0 / statistics.number_of_distinct_values?.max(1) as f64
+                };
+                match op {
+                    ComparisonOp::Equal => equal,
+                    _ => 1.0 - equal,
+                }
+            }
+            ComparisonOp::LT => less_fraction(statistics, value, false),
+            ComparisonOp::LTE => less_fraction(statistics, value, true),
+            ComparisonOp::GT => 1.0 - less_fraction(statistics, value, true),
+            ComparisonOp::GTE => 1.0 - less_fraction(statistics, value, false),
+        };
+        // The rows of nulls don't match the comparisons.
+        Some(selectivity * (1.0 - null_fraction(stat)))
+    }
+}
+
+fn constant_value(scalar: &Scalar) -> Option<&DataValue> {
+    match scalar {
+        Scalar::ConstantExpr(constant) if !constant.value.is_null() => Some(&constant.value),
+        Scalar::CastExpr(cast) => match cast.argument.as_ref() {
+            Scalar::ConstantExpr(constant) if constant.value.is_numeric() => Some(&constant.value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn reverse_op(op: &ComparisonOp) -> ComparisonOp {
+    match op {
+        ComparisonOp::GT => ComparisonOp::LT,
+        ComparisonOp::LT => ComparisonOp::GT,
+        ComparisonOp::GTE => ComparisonOp::LTE,
+        ComparisonOp::LTE => ComparisonOp::GTE,
+        op => op.clone(),
+    }
+}
+
+// The values of different types are compared as numbers.
+fn is_comparable(left: &DataValue, right: &DataValue) -> bool {
+    left.value_type() == right.value_type() || (left.is_numeric() && right.is_numeric())
+}
+
+fn null_fraction(stat: &ColumnStat) -> f64 {
+    match stat.num_rows {
+        0 => 0.0,
+        num_rows => (stat.statistics.null_count as f64 / num_rows as f64).min(1.0),
+    }
+}
+
+// The fraction of the non-null values less than (or equal to) the value, the values are
+// assumed to be uniformly distributed in a bucket of the histogram, or between the min
+// and the max values if there is no histogram.
+fn less_fraction(statistics: &TableColumnStatistics, value: &DataValue, inclusive: bool) -> f64 {
+    let whole_range = [(statistics.max.clone(), 1)];
+    let buckets: &[(DataValue, u64)] = match &statistics.histogram {
+        Some(buckets) if !buckets.is_empty() => buckets.as_slice(),
+        _ => &whole_range,
+    };
+
+    let total = buckets
+        .iter()
+        .map(|(_, num_values)| *num_values)
+        .sum::<u64>();
+    if total == 0 {
+        return 0.0;
+    }
+    let mut lower = &statistics.min;
+    let mut less = 0.0;
+    for (upper, num_values) in buckets {
+        if upper < value || (inclusive && upper == value) {
+            less += *num_values as f64;
+            lower = upper;
+            continue;
+        }
+        if lower < value {
+            less += *num_values as f64 * interpolate(lower, upper, value);
+        }
+        break;
+    }
+    less / total as f64
+}
+
+// The position of the value in the range, the string values are taken as in the middle.
+fn interpolate(lower: &DataValue, upper: &DataValue, value: &DataValue) -> f64 {
+    match (lower.as_f64(), upper.as_f64(), value.as_f64()) {
+        (Ok(lower), Ok(upper), Ok(value)) if upper > lower => {
+            ((value - lower) / (upper - lower)).clamp(0.0, 1.0)
+        }
+        _ => 0.5,
+    }
+}
@@ -30,6 +30,7 @@ use crate::sql::optimizer::rule::rewrite::RulePushDownFilterScan;
 use crate::sql::optimizer::rule::rewrite::RulePushDownLimitOuterJoin;
 use crate::sql::optimizer::rule::rewrite::RulePushDownLimitScan;
 use crate::sql::optimizer::rule::rewrite::RulePushDownLimitSort;
+use crate::sql::optimizer::rule::rewrite::RulePushDownSortEvalScalarScan;
 use crate::sql::optimizer::rule::rewrite::RulePushDownSortScan;
 use crate::sql::optimizer::rule::rewrite::RuleSplitAggregate;
 use crate::sql::optimizer::rule::rule_implement_get::RuleImplementGet;
@@ -54,6 +55,9 @@ impl RuleFactory {
             RuleID::PushDownFilterScan => Ok(Box::new(RulePushDownFilterScan::new())),
             RuleID::PushDownLimitScan => Ok(Box::new(RulePushDownLimitScan::new())),
             RuleID::PushDownSortScan => Ok(Box::new(RulePushDownSortScan::new())),
+            RuleID::PushDownSortEvalScalarScan => {
+                Ok(Box::new(RulePushDownSortEvalScalarScan::new()))
+            }
             RuleID::PushDownLimitOuterJoin => Ok(Box::new(RulePushDownLimitOuterJoin::new())),
             RuleID::PushDownLimitSort => Ok(Box::new(RulePushDownLimitSort::new())),
             RuleID::EliminateFilter => Ok(Box::new(RuleEliminateFilter::new())),
@@ -54,6 +54,7 @@ pub enum RuleID {
     PushDownLimitSort,
     PushDownLimitScan,
     PushDownSortScan,
+    PushDownSortEvalScalarScan,
     EliminateEvalScalar,
     EliminateFilter,
     MergeEvalScalar,
@@ -81,6 +82,7 @@ impl Display for RuleID {
             RuleID::PushDownLimitSort => write!(f, "PushDownLimitSort"),
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
+            RuleID::PushDownSortEvalScalarScan => write!(f, "PushDownSortEvalScalarScan"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
             RuleID::MergeEvalScalar => write!(f, "MergeEvalScalar"),
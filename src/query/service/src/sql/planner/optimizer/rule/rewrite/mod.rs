@@ -25,6 +25,7 @@ mod rule_push_down_filter_scan;
 mod rule_push_down_limit_join;
 mod rule_push_down_limit_scan;
 mod rule_push_down_limit_sort;
+mod rule_push_down_sort_eval_scalar_scan;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 
@@ -41,5 +42,6 @@ pub use rule_push_down_filter_scan::RulePushDownFilterScan;
 pub use rule_push_down_limit_join::RulePushDownLimitOuterJoin;
 pub use rule_push_down_limit_scan::RulePushDownLimitScan;
 pub use rule_push_down_limit_sort::RulePushDownLimitSort;
+pub use rule_push_down_sort_eval_scalar_scan::RulePushDownSortEvalScalarScan;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
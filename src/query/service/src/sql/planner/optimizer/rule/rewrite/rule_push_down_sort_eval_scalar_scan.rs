@@ -0,0 +1,125 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_functions::scalars::VectorDistance;
+
+use crate::sql::optimizer::rule::Rule;
+use crate::sql::optimizer::rule::TransformState;
+use crate::sql::optimizer::RuleID;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::LogicalGet;
+use crate::sql::plans::PatternPlan;
+use crate::sql::plans::RelOp;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::Sort;
+use crate::sql::plans::VectorSearch;
+
+/// Input:  Sort(ascending distance, limit)
+///           \
+///          EvalScalar
+///             \
+///             LogicalGet
+///
+/// Output:
+///         Sort(ascending distance, limit)
+///           \
+///          EvalScalar
+///             \
+///             LogicalGet(padding vector_search)
+///
+/// The distance is `cosine_distance()` or `l2_distance()` of a column of the table and a
+/// constant vector, evaluated by the EvalScalar.
+pub struct RulePushDownSortEvalScalarScan {
+    id: RuleID,
+    pattern: SExpr,
+}
+
+impl RulePushDownSortEvalScalarScan {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::PushDownSortEvalScalarScan,
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Sort,
+                }
+                .into(),
+                SExpr::create_unary(
+                    PatternPlan {
+                        plan_type: RelOp::EvalScalar,
+                    }
+                    .into(),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::LogicalGet,
+                        }
+                        .into(),
+                    ),
+                ),
+            ),
+        }
+    }
+}
+
+impl Rule for RulePushDownSortEvalScalarScan {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformState) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut get: LogicalGet = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+
+        let (item, limit) = match (sort.items.as_slice(), sort.limit) {
+            ([item], Some(limit)) if item.asc && get.vector_search.is_none() => (item, limit),
+            _ => return Ok(()),
+        };
+        let distance = match eval_scalar.items.iter().find(|v| v.index == item.index) {
+            Some(v) => &v.scalar,
+            None => return Ok(()),
+        };
+        let is_vector_search = match distance {
+            Scalar::FunctionCall(func) if VectorDistance::from_name(&func.func_name).is_some() => {
+                match func.arguments.as_slice() {
+                    [Scalar::BoundColumnRef(column), Scalar::ConstantExpr(_)]
+                    | [Scalar::ConstantExpr(_), Scalar::BoundColumnRef(column)] => {
+                        get.columns.contains(&column.column.index)
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+        if !is_vector_search {
+            return Ok(());
+        }
+
+        get.vector_search = Some(VectorSearch {
+            distance: distance.clone(),
+            limit,
+        });
+        let get = SExpr::create_leaf(RelOperator::LogicalGet(get));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![get]);
+        state.add_result(s_expr.replace_children(vec![eval_scalar_expr]));
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
@@ -0,0 +1,588 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use common_ast::parser::token::Token;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::unescape::unescape;
+use common_ast::Dialect;
+use common_base::base::tokio;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::SchemaChange;
+use common_meta_store::MetaStore;
+use common_planner::Metadata;
+use common_planner::MetadataRef;
+use futures::stream::select_all;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::info;
+use tracing::warn;
+
+use crate::sessions::TableContext;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::Exchange;
+use crate::sql::plans::Plan;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::Config;
+
+/// The shape of a statement, in which the literals are replaced with `?`, e.g.
+/// `SELECT a FROM t WHERE b = ? AND c > ?` of `SELECT a FROM t WHERE b = 1 AND c > 'x'`.
+///
+/// The statements of the same shape are planned alike if their literals are only compared with
+/// the columns, the plan of one is reused for another by replacing the constants of the literals.
+#[derive(Clone, Debug)]
+pub struct PlanCacheStatement {
+    pub shape: String,
+    /// The text of the literals.
+    pub literals: Vec<String>,
+    /// The constants the literals are resolved to.
+    pub parameters: Vec<ConstantExpr>,
+    /// The texts of the other tokens in lower case, without quotes.
+    names: HashSet<String>,
+}
+
+impl PlanCacheStatement {
+    /// The statement of the tokens, `None` if the statement has hints, whose plans are not cached.
+    pub fn try_create(tokens: &[Token], dialect: Dialect) -> Option<PlanCacheStatement> {
+        let mut shape = Vec::with_capacity(tokens.len());
+        let mut literals = vec![];
+        let mut parameters = vec![];
+        let mut names = HashSet::new();
+        for token in tokens {
+            let text = token.text();
+            let value = match token.kind {
+                TokenKind::EOI => continue,
+                TokenKind::Hint => return None,
+                TokenKind::LiteralInteger => text.parse::<u64>().ok().map(DataValue::UInt64),
+                TokenKind::LiteralFloat => text.parse::<f64>().ok().map(DataValue::Float64),
+                TokenKind::QuotedString
+                    if text
+                        .chars()
+                        .next()
+                        .filter(|c| dialect.is_string_quote(*c))
+                        .is_some() =>
+                {
+                    unescape(&text[1..text.len() - 1], '\'')
+                        .map(|unescaped| DataValue::String(unescaped.into_bytes()))
+                }
+                _ => None,
+            };
+
+            match value {
+                Some(value) => {
+                    shape.push("?");
+                    literals.push(text.to_string());
+                    parameters.push(ConstantExpr {
+                        data_type: Box::new(value.data_type()),
+                        value,
+                    });
+                }
+                None => {
+                    shape.push(text);
+                    names.insert(text.trim_matches(|c| c == '"' || c == '`').to_lowercase());
+                }
+            }
+        }
+
+        Some(PlanCacheStatement {
+            shape: shape.join(" "),
+            literals,
+            parameters,
+            names,
+        })
+    }
+
+    fn parameter_types(&self) -> Vec<String> {
+        self.parameters
+            .iter()
+            .map(|parameter| parameter.data_type.name())
+            .collect()
+    }
+}
+
+/// The plans are cached by the statements of the same session state, the statements of a shape
+/// share a plan if it's parameterized by the literals, or else each statement has its own plan.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PlanCacheKey {
+    pub tenant: String,
+    pub user: String,
+    pub role: Option<String>,
+    pub catalog: String,
+    pub database: String,
+    pub settings: Vec<String>,
+    pub distributed: bool,
+    pub shape: String,
+    pub parameter_types: Vec<String>,
+    /// The literals of the statement, `None` for the plan parameterized by the literals.
+    pub literals: Option<Vec<String>>,
+}
+
+impl PlanCacheKey {
+    /// The keys of the parameterized plan and the plan of the statement.
+    pub fn try_create(
+        ctx: &dyn TableContext,
+        statement: &PlanCacheStatement,
+    ) -> Result<(PlanCacheKey, PlanCacheKey)> {
+        let settings = ctx
+            .get_settings()
+            .get_setting_values()
+            .into_iter()
+            .map(|(name, value, ..)| format!("{}={:?}", name, value))
+            .collect();
+
+        let parameterized = PlanCacheKey {
+            tenant: ctx.get_tenant(),
+            user: ctx.get_current_user()?.identity().to_string(),
+            role: ctx.get_current_role(),
+            catalog: ctx.get_current_catalog(),
+            database: ctx.get_current_database(),
+            settings,
+            distributed: !ctx.get_cluster().is_empty(),
+            shape: statement.shape.clone(),
+            parameter_types: statement.parameter_types(),
+            literals: None,
+        };
+        let exact = PlanCacheKey {
+            literals: Some(statement.literals.clone()),
+            ..parameterized.clone()
+        };
+        Ok((parameterized, exact))
+    }
+}
+
+/// The version of a table the plan is bound with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanCacheTable {
+    pub catalog: String,
+    pub database: String,
+    pub name: String,
+    pub table_id: u64,
+    pub seq: u64,
+}
+
+struct PlanCacheEntry {
+    plan: Plan,
+    metadata: Metadata,
+    format: Option<String>,
+    tables: Vec<PlanCacheTable>,
+    // The constants the parameterized plan is planned with.
+    parameters: Vec<ConstantExpr>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct PlanCacheState {
+    watching: bool,
+    // Bumped by each invalidation: a plan bound before the latest invalidation may be stale,
+    // and is not cached.
+    version: u64,
+    tick: u64,
+    entries: HashMap<PlanCacheKey, PlanCacheEntry>,
+}
+
+/// Caches the optimized plans of the queries, to skip the parsing, binding and optimizing of the
+/// repeated queries, e.g. the ones of the dashboards.
+///
+/// Like the databases cached by the catalog, the cache is in effect only while the changes of the
+/// schema are watched from the meta-service: a changed table invalidates the plans of it, a
+/// created, dropped or renamed table or database invalidates all the plans, since it may change
+/// what the names of the queries are resolved to. The versions of the tables are checked again
+/// before a plan is reused.
+pub struct PlanCache {
+    max_entries: usize,
+    state: Mutex<PlanCacheState>,
+}
+
+impl PlanCache {
+    pub fn create(max_entries: usize) -> Arc<PlanCache> {
+        Arc::new(PlanCache {
+            max_entries,
+            state: Mutex::new(PlanCacheState::default()),
+        })
+    }
+
+    pub fn from_config(conf: &Config) -> Arc<PlanCache> {
+        PlanCache::create(conf.query.plan_cache_max_entries as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The version to pass to `insert()`, taken before planning the statement.
+    pub fn version(&self) -> u64 {
+        self.state.lock().version
+    }
+
+    /// The cached plan of the statement with the tables it's bound with, the parameterized plan
+    /// takes the constants of the statement.
+    pub fn get(
+        &self,
+        key: &PlanCacheKey,
+        statement: &PlanCacheStatement,
+        sql: &str,
+    ) -> Option<(Plan, MetadataRef, Option<String>, Vec<PlanCacheTable>)> {
+        let mut state = self.state.lock();
+        state.tick += 1;
+        let tick = state.tick;
+        let entry = state.entries.get_mut(key)?;
+        entry.last_used = tick;
+
+        let (s_expr, bind_context, rewrite_kind, formatted_ast) = match &entry.plan {
+            Plan::Query {
+                s_expr,
+                bind_context,
+                rewrite_kind,
+                formatted_ast,
+                ..
+            } => (s_expr, bind_context, rewrite_kind, formatted_ast),
+            _ => return None,
+        };
+        let (s_expr, formatted_ast) = match key.literals {
+            Some(_) => (s_expr.clone(), formatted_ast.clone()),
+            None => {
+                let mut s_expr = s_expr.as_ref().clone();
+                replace_parameters(&mut s_expr, &entry.parameters, &statement.parameters);
+                (Box::new(s_expr), Some(sql.to_string()))
+            }
+        };
+
+        let metadata = Arc::new(RwLock::new(entry.metadata.clone()));
+        let plan = Plan::Query {
+            s_expr,
+            metadata: metadata.clone(),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            formatted_ast,
+        };
+        Some((plan, metadata, entry.format.clone(), entry.tables.clone()))
+    }
+
+    /// Caches the plan of the statement if the plan is a query of the fuse tables. The plan is
+    /// parameterized if the literals of the statement are only compared with the columns.
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert(
+        &self,
+        version: u64,
+        keys: (PlanCacheKey, PlanCacheKey),
+        statement: &PlanCacheStatement,
+        current_database: &str,
+        plan: &Plan,
+        metadata: &MetadataRef,
+        format: &Option<String>,
+        tables: Vec<PlanCacheTable>,
+    ) {
+        let (s_expr, bind_context) = match plan {
+            Plan::Query {
+                s_expr,
+                bind_context,
+                ..
+            } => (s_expr, bind_context),
+            _ => return,
+        };
+
+        let parameterized = is_parameterizable(s_expr, statement)
+            && bind_context.columns.iter().all(|column| {
+                statement
+                    .literals
+                    .iter()
+                    .all(|literal| !column.column_name.contains(literal.as_str()))
+            })
+            // The plan of a view or an index has the constants not of the statement.
+            && tables.iter().all(|table| {
+                statement.names.contains(&table.name.to_lowercase())
+                    && (table.database == current_database
+                        || statement.names.contains(&table.database.to_lowercase()))
+            });
+        let key = match parameterized {
+            true => keys.0,
+            false => keys.1,
+        };
+
+        let mut state = self.state.lock();
+        if !state.watching || state.version != version || self.max_entries == 0 {
+            return;
+        }
+
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.max_entries {
+            let least_used = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_used) = least_used {
+                state.entries.remove(&least_used);
+            }
+        }
+
+        state.tick += 1;
+        let entry = PlanCacheEntry {
+            plan: plan.clone(),
+            metadata: metadata.read().clone(),
+            format: format.clone(),
+            tables,
+            parameters: statement.parameters.clone(),
+            last_used: state.tick,
+        };
+        state.entries.insert(key, entry);
+    }
+
+    /// Removes the plans of the changed table.
+    pub fn remove_table(&self, table_id: u64) {
+        let mut state = self.state.lock();
+        state.version += 1;
+        state
+            .entries
+            .retain(|_, entry| entry.tables.iter().all(|table| table.table_id != table_id));
+    }
+
+    pub fn invalidate(&self, change: &SchemaChange) {
+        match change {
+            SchemaChange::DatabaseName(_) | SchemaChange::TableName(_) => {
+                let mut state = self.state.lock();
+                state.version += 1;
+                state.entries.clear();
+            }
+            SchemaChange::Table(table_id) => self.remove_table(table_id.table_id),
+            // Each change of a table changes its database too, the versions of the tables are
+            // enough to invalidate the plans.
+            SchemaChange::Database(_) => {}
+        }
+    }
+
+    /// The changes made while not watching are unknown, so all the plans are removed both when
+    /// the watch starts and when it stops.
+    pub fn set_watching(&self, watching: bool) {
+        let mut state = self.state.lock();
+        state.watching = watching;
+        state.version += 1;
+        state.entries.clear();
+    }
+
+    /// Spawns the task watching the changes of the schema for the cache, it reconnects if the
+    /// watch streams are broken, and exits once the cache is dropped.
+    pub fn spawn_watcher(self: &Arc<Self>, meta: MetaStore) {
+        if meta.is_local() {
+            return;
+        }
+
+        let cache = Arc::downgrade(self);
+        tokio::spawn(async move {
+            loop {
+                if let Err(cause) = Self::watch(&cache, &meta).await {
+                    warn!("plan cache watch is broken, retry later: {}", cause);
+                }
+
+                match cache.upgrade() {
+                    None => return,
+                    Some(c) => c.set_watching(false),
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        });
+    }
+
+    async fn watch(cache: &Weak<Self>, meta: &MetaStore) -> Result<()> {
+        let mut streams = Vec::new();
+        for req in SchemaChange::watch_requests() {
+            match meta.watch(req).await? {
+                Some(stream) => streams.push(stream),
+                None => return Ok(()),
+            }
+        }
+
+        match cache.upgrade() {
+            None => return Ok(()),
+            Some(c) => c.set_watching(true),
+        }
+        info!("plan cache starts watching the schema changes");
+
+        let mut events = select_all(streams);
+        while let Some(resp) = events.next().await {
+            let event = match resp?.event {
+                None => continue,
+                Some(event) => event,
+            };
+
+            let c = match cache.upgrade() {
+                None => return Ok(()),
+                Some(c) => c,
+            };
+            if let Some(change) = SchemaChange::from_key(&event.key) {
+                c.invalidate(&change);
+            }
+        }
+
+        Err(ErrorCode::MetaServiceError(
+            "the watch stream of the schema changes is closed",
+        ))
+    }
+}
+
+// The literals are parameters of the plan if each of them is resolved to a distinct constant,
+// which is compared with the others directly, and never used in the other ways, e.g. as the
+// arguments of the functions, whose results may have been folded into the plan.
+fn is_parameterizable(s_expr: &SExpr, statement: &PlanCacheStatement) -> bool {
+    let parameters = &statement.parameters;
+    for (i, parameter) in parameters.iter().enumerate() {
+        if parameters[..i]
+            .iter()
+            .any(|other| other.value == parameter.value)
+        {
+            return false;
+        }
+    }
+
+    let mut compared = vec![false; parameters.len()];
+    let mut used_otherwise = false;
+    let mut has_subquery = false;
+    let mut s_expr = s_expr.clone();
+    visit_s_expr_scalars(&mut s_expr, &mut |scalar| {
+        let visited = visit_constants(scalar, false, &mut |constant, operand| {
+            if let Some(i) = parameters.iter().position(|p| p.value == constant.value) {
+                match operand && parameters[i].data_type.name() == constant.data_type.name() {
+                    true => compared[i] = true,
+                    false => used_otherwise = true,
+                }
+            }
+        });
+        has_subquery |= !visited;
+    });
+    !used_otherwise && !has_subquery && compared.iter().all(|compared| *compared)
+}
+
+// Replaces the constants of the old parameters with the new ones of the same types.
+fn replace_parameters(s_expr: &mut SExpr, old: &[ConstantExpr], new: &[ConstantExpr]) {
+    visit_s_expr_scalars(s_expr, &mut |scalar| {
+        visit_constants(scalar, false, &mut |constant, _| {
+            if let Some(i) = old.iter().position(|p| p.value == constant.value) {
+                constant.value = new[i].value.clone();
+            }
+        });
+    });
+}
+
+fn visit_s_expr_scalars(s_expr: &mut SExpr, f: &mut impl FnMut(&mut Scalar)) {
+    let scalars: Vec<&mut Scalar> = match &mut s_expr.plan {
+        RelOperator::LogicalGet(get) => get
+            .push_down_predicates
+            .iter_mut()
+            .flatten()
+            .chain(
+                get.prewhere
+                    .iter_mut()
+                    .flat_map(|p| p.predicates.iter_mut()),
+            )
+            .chain(get.vector_search.iter_mut().map(|v| &mut v.distance))
+            .collect(),
+        RelOperator::PhysicalScan(scan) => scan
+            .push_down_predicates
+            .iter_mut()
+            .flatten()
+            .chain(
+                scan.prewhere
+                    .iter_mut()
+                    .flat_map(|p| p.predicates.iter_mut()),
+            )
+            .chain(scan.vector_search.iter_mut().map(|v| &mut v.distance))
+            .collect(),
+        RelOperator::LogicalInnerJoin(join) => join
+            .left_conditions
+            .iter_mut()
+            .chain(join.right_conditions.iter_mut())
+            .chain(join.other_conditions.iter_mut())
+            .collect(),
+        RelOperator::PhysicalHashJoin(join) => join
+            .build_keys
+            .iter_mut()
+            .chain(join.probe_keys.iter_mut())
+            .chain(join.other_conditions.iter_mut())
+            .collect(),
+        RelOperator::EvalScalar(eval_scalar) => eval_scalar
+            .items
+            .iter_mut()
+            .map(|item| &mut item.scalar)
+            .collect(),
+        RelOperator::Filter(filter) => filter.predicates.iter_mut().collect(),
+        RelOperator::Aggregate(aggregate) => aggregate
+            .group_items
+            .iter_mut()
+            .chain(aggregate.aggregate_functions.iter_mut())
+            .map(|item| &mut item.scalar)
+            .collect(),
+        RelOperator::Exchange(Exchange::Hash(keys)) => keys.iter_mut().collect(),
+        RelOperator::Exchange(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_)
+        | RelOperator::UnionAll(_)
+        | RelOperator::DummyTableScan(_)
+        | RelOperator::Pattern(_) => vec![],
+    };
+    for scalar in scalars {
+        f(scalar);
+    }
+    for child in s_expr.children.iter_mut() {
+        visit_s_expr_scalars(child, f);
+    }
+}
+
+// Visits the constants of the scalar with whether they are the operands of the comparisons,
+// false if the scalar has subqueries, whose constants are not visited.
+fn visit_constants(
+    scalar: &mut Scalar,
+    operand: bool,
+    f: &mut impl FnMut(&mut ConstantExpr, bool),
+) -> bool {
+    match scalar {
+        Scalar::BoundColumnRef(_) => true,
+        Scalar::ConstantExpr(constant) => {
+            f(constant, operand);
+            true
+        }
+        Scalar::AndExpr(expr) => {
+            visit_constants(&mut expr.left, false, f) & visit_constants(&mut expr.right, false, f)
+        }
+        Scalar::OrExpr(expr) => {
+            visit_constants(&mut expr.left, false, f) & visit_constants(&mut expr.right, false, f)
+        }
+        Scalar::ComparisonExpr(expr) => {
+            visit_constants(&mut expr.left, true, f) & visit_constants(&mut expr.right, true, f)
+        }
+        Scalar::AggregateFunction(func) => func
+            .args
+            .iter_mut()
+            .fold(true, |res, arg| visit_constants(arg, false, f) & res),
+        Scalar::FunctionCall(func) => func
+            .arguments
+            .iter_mut()
+            .fold(true, |res, arg| visit_constants(arg, false, f) & res),
+        Scalar::CastExpr(cast) => visit_constants(&mut cast.argument, false, f),
+        Scalar::SubqueryExpr(_) => false,
+    }
+}
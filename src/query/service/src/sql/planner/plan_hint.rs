@@ -0,0 +1,100 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_ast::parser::token::Token;
+use common_ast::parser::token::TokenKind;
+use common_meta_types::PlanHint;
+use common_users::UserApiProvider;
+use parking_lot::RwLock;
+use tracing::warn;
+
+// The hints created or dropped on the other nodes are in effect on a node in this long.
+const PLAN_HINT_CACHE_TTL: Duration = Duration::from_secs(10);
+
+struct CachedPlanHints {
+    // The hints by the shapes of their statements.
+    plan_hints: Arc<HashMap<String, PlanHint>>,
+    loaded_at: Instant,
+}
+
+/// Caches the plan hints of the tenants, which are looked up by each query. The hints of a
+/// tenant are loaded from the meta-service again once they are cached for the TTL, or at once
+/// after a hint is created or dropped on the node.
+pub struct PlanHintCache {
+    ttl: Duration,
+    tenants: RwLock<HashMap<String, CachedPlanHints>>,
+}
+
+impl PlanHintCache {
+    pub fn create() -> Arc<PlanHintCache> {
+        Arc::new(PlanHintCache {
+            ttl: PLAN_HINT_CACHE_TTL,
+            tenants: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// The plan hints of the tenant by the shapes of their statements.
+    pub async fn get_plan_hints(&self, tenant: &str) -> Arc<HashMap<String, PlanHint>> {
+        if let Some(cached) = self.tenants.read().get(tenant) {
+            if cached.loaded_at.elapsed() < self.ttl {
+                return cached.plan_hints.clone();
+            }
+        }
+
+        let plan_hints = match UserApiProvider::instance().get_plan_hints(tenant).await {
+            Ok(plan_hints) => Arc::new(
+                plan_hints
+                    .into_iter()
+                    .map(|plan_hint| (plan_hint.shape.clone(), plan_hint))
+                    .collect(),
+            ),
+            // The queries are planned with the hints loaded before rather than failed, the hints
+            // are loaded again after the TTL.
+            Err(cause) => {
+                warn!("Cannot load the plan hints of tenant {}: {}", tenant, cause);
+                self.tenants
+                    .read()
+                    .get(tenant)
+                    .map(|cached| cached.plan_hints.clone())
+                    .unwrap_or_default()
+            }
+        };
+        self.tenants
+            .write()
+            .insert(tenant.to_string(), CachedPlanHints {
+                plan_hints: plan_hints.clone(),
+                loaded_at: Instant::now(),
+            });
+        plan_hints
+    }
+
+    pub fn invalidate(&self, tenant: &str) {
+        self.tenants.write().remove(tenant);
+    }
+}
+
+/// The SQL with the hints put after its first `SELECT`, as if they were written there, `None`
+/// if the SQL has no `SELECT`.
+pub fn hint_sql(sql: &str, tokens: &[Token], hints: &str) -> Option<String> {
+    let select = tokens
+        .iter()
+        .find(|token| token.kind == TokenKind::SELECT)?;
+    let (head, tail) = sql.split_at(select.span.end);
+    Some(format!("{} /*+ {} */{}", head, hints, tail))
+}
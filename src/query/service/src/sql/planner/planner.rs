@@ -19,17 +19,28 @@ use common_ast::parser::token::Token;
 use common_ast::parser::token::TokenKind;
 use common_ast::parser::token::Tokenizer;
 use common_ast::Backtrace;
+use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_exception::Result;
 use common_planner::Metadata;
 use common_planner::MetadataRef;
 use parking_lot::RwLock;
+use tracing::info;
+use tracing::info_span;
+use tracing::Instrument;
 
 use crate::catalogs::CatalogManagerHelper;
+use crate::replay::ReplayBundle;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
 use crate::sql::optimizer::optimize;
 use crate::sql::optimizer::OptimizerConfig;
 use crate::sql::optimizer::OptimizerContext;
+use crate::sql::planner::plan_cache::PlanCache;
+use crate::sql::planner::plan_cache::PlanCacheKey;
+use crate::sql::planner::plan_cache::PlanCacheStatement;
+use crate::sql::planner::plan_cache::PlanCacheTable;
+use crate::sql::planner::plan_hint::hint_sql;
 use crate::sql::plans::Plan;
 use crate::sql::Binder;
 use crate::sql::NameResolutionContext;
@@ -47,9 +58,16 @@ impl Planner {
     }
 
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, MetadataRef, Option<String>)> {
+        // The tables are read as of the point in time of the statement, not of the former one.
+        self.ctx.reset_read_tables();
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
 
+        // The hints pinned to the shape of the statement are applied as if they were written in
+        // the statement.
+        let hinted_sql = self.apply_plan_hint(sql, sql_dialect).await;
+        let sql = hinted_sql.as_deref().unwrap_or(sql);
+
         // Step 1: Tokenize the SQL.
         let mut tokenizer = Tokenizer::new(sql).peekable();
 
@@ -73,11 +91,38 @@ impl Planner {
             (&mut tokenizer).collect::<Result<_>>()?
         };
 
+        // The plans of the queries out of the transactions are reused by the statements of the
+        // same shape, the version of the cache is taken before planning to skip caching the plan
+        // bound with the tables changed meanwhile.
+        let mut cache_statement = None;
+        let enable_replay_capture = settings.get_enable_replay_capture()?;
+        if !is_insert_stmt
+            && settings.get_enable_plan_cache()?
+            && !enable_replay_capture
+            && self.ctx.get_transaction().is_none()
+        {
+            if let Some(statement) = PlanCacheStatement::try_create(&tokens, sql_dialect) {
+                let plan_cache = SessionManager::instance().plan_cache.clone();
+                let keys = PlanCacheKey::try_create(self.ctx.as_ref(), &statement)?;
+                if let Some(res) = self
+                    .get_cached_plan(&plan_cache, sql, &statement, &keys)
+                    .await
+                {
+                    return Ok(res);
+                }
+                let version = plan_cache.version();
+                cache_statement = Some((plan_cache, statement, keys, version));
+            }
+        }
+
+        // The stages of the planning are traced as the children of the span of the query.
+        let query_span = self.ctx.get_query_span();
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
                 let backtrace = Backtrace::new();
-                let (stmt, format) = parse_sql(&tokens, sql_dialect, &backtrace)?;
+                let (stmt, format) = info_span!(parent: &query_span, "parse")
+                    .in_scope(|| parse_sql(&tokens, sql_dialect, &backtrace))?;
 
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
                 let metadata = Arc::new(RwLock::new(Metadata::default()));
@@ -88,13 +133,17 @@ impl Planner {
                     name_resolution_ctx,
                     metadata.clone(),
                 );
-                let plan = binder.bind(&stmt).await?;
+                let plan = binder
+                    .bind(&stmt)
+                    .instrument(info_span!(parent: &query_span, "bind"))
+                    .await?;
 
                 // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
                 let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
                     enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
                 }));
-                let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
+                let optimized_plan = info_span!(parent: &query_span, "optimize")
+                    .in_scope(|| optimize(self.ctx.clone(), opt_ctx, plan))?;
 
                 Ok((optimized_plan, metadata.clone(), format))
             }
@@ -122,8 +171,151 @@ impl Planner {
                     tokens.extend(iter);
                 };
             } else {
+                if let (
+                    Ok((plan, metadata, format)),
+                    Some((plan_cache, statement, keys, version)),
+                ) = (&res, cache_statement)
+                {
+                    self.cache_plan(
+                        &plan_cache,
+                        version,
+                        keys,
+                        &statement,
+                        plan,
+                        metadata,
+                        format,
+                    );
+                }
+                if let (Ok((plan @ Plan::Query { .. }, _, _)), true) = (&res, enable_replay_capture)
+                {
+                    self.capture_replay_bundle(sql, plan).await;
+                }
                 return res;
             }
         }
     }
+
+    // The statement with the hints of the plan hint of its shape, if any. Only the queries are
+    // looked up, the other statements are not tokenized twice.
+    async fn apply_plan_hint(&self, sql: &str, sql_dialect: Dialect) -> Option<String> {
+        let tenant = self.ctx.get_tenant();
+        let plan_hints = SessionManager::instance()
+            .plan_hint_cache
+            .get_plan_hints(&tenant)
+            .await;
+        if plan_hints.is_empty() {
+            return None;
+        }
+
+        let mut tokenizer = Tokenizer::new(sql).peekable();
+        let first = tokenizer
+            .peek()
+            .and_then(|token| Some(token.as_ref().ok()?.kind));
+        if !matches!(
+            first,
+            Some(TokenKind::SELECT | TokenKind::WITH | TokenKind::LParen)
+        ) {
+            return None;
+        }
+        let tokens = tokenizer.collect::<Result<Vec<_>>>().ok()?;
+        let statement = PlanCacheStatement::try_create(&tokens, sql_dialect)?;
+        let plan_hint = plan_hints.get(&statement.shape)?;
+        info!(
+            "Apply the plan hint {} to the query {}",
+            plan_hint.name,
+            self.ctx.get_id()
+        );
+        hint_sql(sql, &tokens, &plan_hint.hints)
+    }
+
+    // The query is planned anyway if its inputs fail to be captured.
+    async fn capture_replay_bundle(&self, sql: &str, plan: &Plan) {
+        let res = match ReplayBundle::capture(self.ctx.clone(), sql, plan).await {
+            Ok(bundle) => bundle.save(self.ctx.as_ref()).await,
+            Err(cause) => Err(cause),
+        };
+        if let Err(cause) = res {
+            tracing::warn!(
+                "Cannot capture the replay bundle of the query {}: {}",
+                self.ctx.get_id(),
+                cause
+            );
+        }
+    }
+
+    // The cached plan of the statement, if the tables of the plan are not changed.
+    async fn get_cached_plan(
+        &self,
+        plan_cache: &PlanCache,
+        sql: &str,
+        statement: &PlanCacheStatement,
+        keys: &(PlanCacheKey, PlanCacheKey),
+    ) -> Option<(Plan, MetadataRef, Option<String>)> {
+        for key in [&keys.0, &keys.1] {
+            let (plan, metadata, format, tables) = match plan_cache.get(key, statement, sql) {
+                Some(cached) => cached,
+                None => continue,
+            };
+
+            for table in &tables {
+                match self
+                    .ctx
+                    .get_table(&table.catalog, &table.database, &table.name)
+                    .await
+                {
+                    Ok(current)
+                        if current.get_id() == table.table_id
+                            && current.get_table_info().ident.seq == table.seq => {}
+                    _ => return None,
+                }
+            }
+            return Some((plan, metadata, format));
+        }
+        None
+    }
+
+    // Only the plans of the cacheable queries of the fuse tables are cached, the changes of the
+    // other tables are not watched.
+    #[allow(clippy::too_many_arguments)]
+    fn cache_plan(
+        &self,
+        plan_cache: &PlanCache,
+        version: u64,
+        keys: (PlanCacheKey, PlanCacheKey),
+        statement: &PlanCacheStatement,
+        plan: &Plan,
+        metadata: &MetadataRef,
+        format: &Option<String>,
+    ) {
+        if !matches!(plan, Plan::Query { .. }) || !self.ctx.get_cacheable() {
+            return;
+        }
+
+        let mut tables = vec![];
+        for table in metadata.read().tables() {
+            let table_ref = table.table();
+            let table_info = table_ref.get_table_info();
+            if table_info.engine() != "FUSE" {
+                return;
+            }
+            tables.push(PlanCacheTable {
+                catalog: table.catalog().to_string(),
+                database: table.database().to_string(),
+                name: table.name().to_string(),
+                table_id: table_info.ident.table_id,
+                seq: table_info.ident.seq,
+            });
+        }
+
+        plan_cache.insert(
+            version,
+            keys,
+            statement,
+            &self.ctx.get_current_database(),
+            plan,
+            metadata,
+            format,
+            tables,
+        );
+    }
 }
@@ -0,0 +1,52 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_storage::UriLocation;
+
+/// Backs up the tables of the database with their data to the location.
+#[derive(Clone, Debug)]
+pub struct BackupDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+
+    // the location of the backup, such as `s3://bucket/backup/db/`
+    pub location: UriLocation,
+}
+
+impl BackupDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Restores the database backed up to the location as a new database.
+#[derive(Clone, Debug)]
+pub struct RestoreDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+
+    pub location: UriLocation,
+}
+
+impl RestoreDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
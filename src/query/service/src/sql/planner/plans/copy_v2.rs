@@ -16,7 +16,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_legacy_planners::ReadDataSourcePlan;
 use common_meta_types::MetaId;
 use common_meta_types::UserStageInfo;
@@ -76,6 +76,20 @@ pub enum CopyPlanV2 {
     },
 }
 
+impl CopyPlanV2 {
+    /// The schema of the result, copy into stage returns the files written.
+    pub fn schema(&self) -> DataSchemaRef {
+        match self {
+            CopyPlanV2::IntoTable { .. } => DataSchemaRefExt::create(vec![]),
+            CopyPlanV2::IntoStage { .. } => DataSchemaRefExt::create(vec![
+                DataField::new("file_name", Vu8::to_data_type()),
+                DataField::new("file_size", u64::to_data_type()),
+                DataField::new("row_count", u64::to_data_type()),
+            ]),
+        }
+    }
+}
+
 impl Debug for CopyPlanV2 {
     // Ignore the schema.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
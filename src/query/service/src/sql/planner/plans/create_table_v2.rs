@@ -32,10 +32,15 @@ pub struct CreateTablePlanV2 {
 
     pub schema: DataSchemaRef,
     pub engine: Engine,
+    pub engine_options: TableOptions,
     pub options: TableOptions,
     pub field_default_exprs: Vec<Option<Scalar>>,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    // The sequences of the AUTOINCREMENT columns, created before the table.
+    pub auto_increment_sequences: Vec<String>,
+    // The temporary table is kept in the session, instead of the catalog.
+    pub temporary: bool,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -107,6 +107,8 @@ impl LogicalOperator for EvalScalar {
             outer_columns,
             cardinality,
             precise_cardinality,
+            column_stats: input_prop.column_stats,
+            broadcast_hint: input_prop.broadcast_hint,
         })
     }
 }
@@ -29,6 +29,7 @@ pub enum Exchange {
     Hash(Vec<Scalar>),
     Broadcast,
     Merge,
+    RoundRobin,
 }
 
 impl Operator for Exchange {
@@ -60,6 +61,7 @@ impl PhysicalOperator for Exchange {
                 Exchange::Hash(hash_keys) => Distribution::Hash(hash_keys.clone()),
                 Exchange::Broadcast => Distribution::Broadcast,
                 Exchange::Merge => Distribution::Serial,
+                Exchange::RoundRobin => Distribution::Random,
             },
         })
     }
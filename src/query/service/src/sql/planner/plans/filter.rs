@@ -19,6 +19,7 @@ use crate::sql::optimizer::PhysicalProperty;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
 use crate::sql::optimizer::RequiredProperty;
+use crate::sql::optimizer::SelectivityEstimator;
 use crate::sql::plans::LogicalOperator;
 use crate::sql::plans::Operator;
 use crate::sql::plans::PhysicalOperator;
@@ -87,9 +88,15 @@ impl LogicalOperator for Filter {
         }
         outer_columns = outer_columns.difference(&output_columns).cloned().collect();
 
-        // Derive cardinality. We can not estimate the cardinality of the filter until we have
-        // NDV(Number of Distinct Values), so we pass it through.
-        let cardinality = input_prop.cardinality;
+        // Derive cardinality with the selectivity of the predicates, which is estimated by the
+        // statistics of the columns, e.g. NDV(Number of Distinct Values) and histograms.
+        let estimator = SelectivityEstimator::new(&input_prop.column_stats);
+        let selectivity = self
+            .predicates
+            .iter()
+            .map(|predicate| estimator.compute_selectivity(predicate))
+            .product::<f64>();
+        let cardinality = input_prop.cardinality * selectivity;
 
         Ok(RelationalProperty {
             output_columns,
@@ -98,6 +105,8 @@ impl LogicalOperator for Filter {
             // TODO(leiysky): if the predicate is always true, then we can pass through
             // precise cardinality
             precise_cardinality: None,
+            column_stats: input_prop.column_stats,
+            broadcast_hint: input_prop.broadcast_hint,
         })
     }
 }
@@ -26,6 +26,10 @@ use crate::sql::plans::PhysicalOperator;
 use crate::sql::plans::RelOp;
 use crate::sql::plans::Scalar;
 
+/// The build side with the estimated cardinality up to the threshold is broadcasted to
+/// the nodes of the probe side, instead of shuffling both sides by the join keys.
+const BROADCAST_JOIN_THRESHOLD: f64 = 100_000.0;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PhysicalHashJoin {
     pub build_keys: Vec<Scalar>,
@@ -58,12 +62,58 @@ impl Operator for PhysicalHashJoin {
     }
 }
 
+impl PhysicalHashJoin {
+    /// Whether to broadcast the build side rather than shuffling both sides, which
+    /// moves less data if the build side is small, while the probe side is kept as is.
+    /// The build side with the tables hinted by `BROADCAST` is always broadcasted.
+    ///
+    /// Broadcasting is only correct if the rows of the build side need not be tracked
+    /// across the nodes, i.e. the join doesn't output the unmatched rows of the build side.
+    fn broadcast_build_side(&self, rel_expr: &RelExpr) -> bool {
+        if !matches!(
+            self.join_type,
+            JoinType::Inner
+                | JoinType::Left
+                | JoinType::LeftSemi
+                | JoinType::LeftAnti
+                | JoinType::Cross
+                | JoinType::Single
+        ) {
+            return false;
+        }
+
+        // Broadcast the side with the hinted tables, even if it's large.
+        if let Ok(build_prop) = rel_expr.derive_relational_prop_child(1) {
+            if build_prop.broadcast_hint {
+                return true;
+            }
+        }
+
+        // Shuffle if the cardinalities are not estimated.
+        match (
+            rel_expr.derive_relational_prop_child(0),
+            rel_expr.derive_relational_prop_child(1),
+        ) {
+            (Ok(probe_prop), Ok(build_prop)) => {
+                build_prop.cardinality <= BROADCAST_JOIN_THRESHOLD
+                    && build_prop.cardinality < probe_prop.cardinality
+            }
+            _ => false,
+        }
+    }
+}
+
 impl PhysicalOperator for PhysicalHashJoin {
     fn derive_physical_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<PhysicalProperty> {
         let probe_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_prop = rel_expr.derive_physical_prop_child(1)?;
 
         match (&probe_prop.distribution, &build_prop.distribution) {
+            // The build side is broadcasted to each of the nodes of the probe side, so
+            // the result is distributed as the probe side.
+            (_, Distribution::Broadcast) => Ok(PhysicalProperty {
+                distribution: probe_prop.distribution.clone(),
+            }),
             // If the distribution of probe side is Random, we will pass through
             // the distribution of build side.
             (Distribution::Random, _) => Ok(PhysicalProperty {
@@ -87,13 +137,22 @@ impl PhysicalOperator for PhysicalHashJoin {
         let probe_physical_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_physical_prop = rel_expr.derive_physical_prop_child(1)?;
 
-        if probe_physical_prop.distribution == Distribution::Serial
-            || build_physical_prop.distribution == Distribution::Serial
+        let broadcast = self.broadcast_build_side(rel_expr);
+        if build_physical_prop.distribution == Distribution::Serial
+            || (probe_physical_prop.distribution == Distribution::Serial && !broadcast)
         {
             // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
+        } else if broadcast {
+            if child_index == 0 {
+                // The probe side is spread over the nodes, round robin if it's serial,
+                // and each node probes the build side broadcasted to it.
+                required.distribution = Distribution::Random;
+            } else {
+                required.distribution = Distribution::Broadcast;
+            }
         } else {
-            // A simple heuristic, we will always enforce `Hash` distribution for join
+            // Otherwise enforce `Hash` distribution for both sides by the join keys
             if child_index == 0 {
                 required.distribution = Distribution::Hash(self.probe_keys.clone());
             } else {
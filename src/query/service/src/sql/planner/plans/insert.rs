@@ -73,6 +73,47 @@ impl Insert {
     }
 }
 
+/// `REPLACE INTO`, the rows of the table with the same values of `on_conflict_fields` as the rows
+/// inserted are deleted, in the same commit as the rows are inserted.
+#[derive(Clone)]
+pub struct Replace {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    pub schema: DataSchemaRef,
+    pub on_conflict_fields: Vec<String>,
+    pub source: InsertInputSource,
+}
+
+impl Replace {
+    /// The insertion of the rows, committed by replacing the conflicting rows.
+    pub fn to_insert(&self) -> Insert {
+        Insert {
+            catalog: self.catalog.clone(),
+            database: self.database.clone(),
+            table: self.table.clone(),
+            table_id: self.table_id,
+            schema: self.schema.clone(),
+            overwrite: false,
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Replace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Replace")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("table_id", &self.table_id)
+            .field("schema", &self.schema)
+            .field("on_conflict_fields", &self.on_conflict_fields)
+            .finish()
+    }
+}
+
 impl std::fmt::Debug for Insert {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Insert")
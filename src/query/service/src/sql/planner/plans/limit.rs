@@ -81,6 +81,8 @@ impl LogicalOperator for Limit {
                 _ => input_prop.cardinality,
             },
             precise_cardinality: None,
+            column_stats: input_prop.column_stats,
+            broadcast_hint: input_prop.broadcast_hint,
         })
     }
 }
@@ -18,6 +18,7 @@ use common_planner::IndexType;
 use itertools::Itertools;
 
 use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::ColumnStatSet;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
 use crate::sql::plans::LogicalOperator;
@@ -37,6 +38,15 @@ pub struct Prewhere {
     pub predicates: Vec<Scalar>,
 }
 
+/// The nearest neighbor search pushed down to the scan, the rows are ordered by the distance like
+/// `cosine_distance(embedding, [0.1, 0.2])` and limited, by which the blocks of the tables with
+/// the vector indexes are pruned.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VectorSearch {
+    pub distance: Scalar,
+    pub limit: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct LogicalGet {
     pub table_index: IndexType,
@@ -44,10 +54,14 @@ pub struct LogicalGet {
     pub push_down_predicates: Option<Vec<Scalar>>,
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
+    pub vector_search: Option<VectorSearch>,
     pub prewhere: Option<Prewhere>,
+    // whether the table is hinted to be broadcasted in the distributed joins
+    pub broadcast_hint: bool,
 
     // statistics will be ignored in comparison and hashing
     pub statistics: Option<TableStatistics>,
+    pub column_stats: ColumnStatSet,
 }
 
 impl PartialEq for LogicalGet {
@@ -102,6 +116,13 @@ impl LogicalOperator for LogicalGet {
                 .as_ref()
                 .map_or(0.0, |stat| stat.num_rows.map_or(0.0, |num| num as f64)),
             precise_cardinality: self.statistics.as_ref().and_then(|stat| stat.num_rows),
+            column_stats: self
+                .column_stats
+                .iter()
+                .filter(|(index, _)| self.columns.contains(index))
+                .map(|(index, stat)| (*index, stat.clone()))
+                .collect(),
+            broadcast_hint: self.broadcast_hint,
         })
     }
 }
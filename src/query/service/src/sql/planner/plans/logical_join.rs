@@ -178,11 +178,17 @@ impl LogicalOperator for LogicalInnerJoin {
             JoinType::RightSemi | JoinType::RightAnti => right_prop.cardinality,
         };
 
+        let mut column_stats = left_prop.column_stats;
+        column_stats.extend(right_prop.column_stats);
+
         Ok(RelationalProperty {
             output_columns,
             outer_columns,
             cardinality,
             precise_cardinality: None,
+            column_stats,
+            // The hint is only applied to the joins with the hinted tables.
+            broadcast_hint: false,
         })
     }
 }
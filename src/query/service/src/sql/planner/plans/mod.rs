@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod add_table_column;
 mod aggregate;
+mod attach_table;
+mod backup_database;
 mod copy_v2;
 pub mod create_table_v2;
 mod dummy_table_scan;
@@ -37,7 +40,11 @@ mod union_all;
 use std::fmt::Display;
 use std::sync::Arc;
 
+pub use add_table_column::AddTableColumnPlan;
 pub use aggregate::*;
+pub use attach_table::AttachTablePlan;
+pub use backup_database::BackupDatabasePlan;
+pub use backup_database::RestoreDatabasePlan;
 use common_ast::ast::ExplainKind;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
@@ -46,26 +53,56 @@ use common_datavalues::DataSchemaRefExt;
 use common_datavalues::StringType;
 use common_legacy_planners::DeletePlan;
 use common_legacy_planners::SettingPlan;
+use common_planner::plans::AddTableRowAccessPolicyPlan;
+use common_planner::plans::AddTableVirtualColumnPlan;
 use common_planner::plans::AlterTableClusterKeyPlan;
+use common_planner::plans::AlterTaskPlan;
 use common_planner::plans::AlterUDFPlan;
 use common_planner::plans::AlterUserPlan;
 use common_planner::plans::AlterViewPlan;
+use common_planner::plans::AnalyzeTablePlan;
 use common_planner::plans::CallPlan;
+use common_planner::plans::CreateAggregatingIndexPlan;
+use common_planner::plans::CreateConnectionPlan;
 use common_planner::plans::CreateDatabasePlan;
+use common_planner::plans::CreateDictionaryPlan;
+use common_planner::plans::CreateMaskingPolicyPlan;
+use common_planner::plans::CreateNetworkPolicyPlan;
+use common_planner::plans::CreatePlanHintPlan;
+use common_planner::plans::CreateResourceGroupPlan;
 use common_planner::plans::CreateRolePlan;
+use common_planner::plans::CreateRowAccessPolicyPlan;
+use common_planner::plans::CreateSequencePlan;
 use common_planner::plans::CreateStagePlan;
+use common_planner::plans::CreateStreamPlan;
+use common_planner::plans::CreateTaskPlan;
 use common_planner::plans::CreateUDFPlan;
 use common_planner::plans::CreateUserPlan;
 use common_planner::plans::CreateViewPlan;
 use common_planner::plans::DescribeTablePlan;
+use common_planner::plans::DrainNodePlan;
+use common_planner::plans::DropAggregatingIndexPlan;
+use common_planner::plans::DropConnectionPlan;
 use common_planner::plans::DropDatabasePlan;
+use common_planner::plans::DropDictionaryPlan;
+use common_planner::plans::DropMaskingPolicyPlan;
+use common_planner::plans::DropNetworkPolicyPlan;
+use common_planner::plans::DropPlanHintPlan;
+use common_planner::plans::DropResourceGroupPlan;
 use common_planner::plans::DropRolePlan;
+use common_planner::plans::DropRowAccessPolicyPlan;
+use common_planner::plans::DropSequencePlan;
 use common_planner::plans::DropStagePlan;
+use common_planner::plans::DropStreamPlan;
 use common_planner::plans::DropTableClusterKeyPlan;
 use common_planner::plans::DropTablePlan;
+use common_planner::plans::DropTableRowAccessPolicyPlan;
+use common_planner::plans::DropTableVirtualColumnPlan;
+use common_planner::plans::DropTaskPlan;
 use common_planner::plans::DropUDFPlan;
 use common_planner::plans::DropUserPlan;
 use common_planner::plans::DropViewPlan;
+use common_planner::plans::ExecuteImmediatePlan;
 use common_planner::plans::ExistsTablePlan;
 use common_planner::plans::GrantPrivilegePlan;
 use common_planner::plans::GrantRolePlan;
@@ -77,12 +114,21 @@ use common_planner::plans::RenameDatabasePlan;
 use common_planner::plans::RenameTablePlan;
 use common_planner::plans::RevokePrivilegePlan;
 use common_planner::plans::RevokeRolePlan;
+use common_planner::plans::SetDatabaseCommentPlan;
+use common_planner::plans::SetRolePlan;
+use common_planner::plans::SetTableColumnCommentPlan;
+use common_planner::plans::SetTableColumnMaskingPolicyPlan;
+use common_planner::plans::SetTableOptionsPlan;
+use common_planner::plans::SetUserVariablePlan;
 use common_planner::plans::ShowCreateDatabasePlan;
 use common_planner::plans::ShowCreateTablePlan;
 use common_planner::plans::ShowGrantsPlan;
+use common_planner::plans::SwapTablePlan;
 use common_planner::plans::TruncateTablePlan;
 use common_planner::plans::UndropDatabasePlan;
 use common_planner::plans::UndropTablePlan;
+use common_planner::plans::UnsetTableColumnMaskingPolicyPlan;
+use common_planner::plans::UnsetUserVariablePlan;
 use common_planner::plans::UseDatabasePlan;
 use common_planner::MetadataRef;
 pub use copy_v2::CopyPlanV2;
@@ -97,9 +143,11 @@ pub use hash_join::PhysicalHashJoin;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
 pub use insert::InsertValueBlock;
+pub use insert::Replace;
 pub use limit::Limit;
 pub use logical_get::LogicalGet;
 pub use logical_get::Prewhere;
+pub use logical_get::VectorSearch;
 pub use logical_join::JoinType;
 pub use logical_join::LogicalInnerJoin;
 pub use operator::*;
@@ -125,6 +173,8 @@ pub enum Plan {
         metadata: MetadataRef,
         bind_context: Box<BindContext>,
         rewrite_kind: Option<RewriteKind>,
+        /// The formatted statement, the result of the query is cached by it.
+        formatted_ast: Option<String>,
     },
 
     Explain {
@@ -150,24 +200,40 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    SetDatabaseComment(Box<SetDatabaseCommentPlan>),
+    BackupDatabase(Box<BackupDatabasePlan>),
+    RestoreDatabase(Box<RestoreDatabasePlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
     // Tables
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlanV2>),
+    AttachTable(Box<AttachTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
+    SwapTable(Box<SwapTablePlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
+    SetTableOptions(Box<SetTableOptionsPlan>),
+    AddTableColumn(Box<AddTableColumnPlan>),
+    AddTableVirtualColumn(Box<AddTableVirtualColumnPlan>),
+    DropTableVirtualColumn(Box<DropTableVirtualColumnPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
+    SetTableColumnMaskingPolicy(Box<SetTableColumnMaskingPolicyPlan>),
+    UnsetTableColumnMaskingPolicy(Box<UnsetTableColumnMaskingPolicyPlan>),
+    SetTableColumnComment(Box<SetTableColumnCommentPlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
+    AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
 
     // Insert
     Insert(Box<Insert>),
+    Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
 
     // Views
@@ -175,6 +241,14 @@ pub enum Plan {
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Aggregating indexes
+    CreateAggregatingIndex(Box<CreateAggregatingIndexPlan>),
+    DropAggregatingIndex(Box<DropAggregatingIndexPlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -185,6 +259,46 @@ pub enum Plan {
     AlterUDF(Box<AlterUDFPlan>),
     DropUDF(Box<DropUDFPlan>),
 
+    // Row access policies
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+
+    // Masking policies
+    CreateMaskingPolicy(Box<CreateMaskingPolicyPlan>),
+    DropMaskingPolicy(Box<DropMaskingPolicyPlan>),
+
+    // Network policies
+    CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
+    DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
+
+    // Tasks
+    CreateTask(Box<CreateTaskPlan>),
+    DropTask(Box<DropTaskPlan>),
+    AlterTask(Box<AlterTaskPlan>),
+
+    // Resource groups
+    CreateResourceGroup(Box<CreateResourceGroupPlan>),
+    DropResourceGroup(Box<DropResourceGroupPlan>),
+
+    // Connections
+    CreateConnection(Box<CreateConnectionPlan>),
+    DropConnection(Box<DropConnectionPlan>),
+
+    // Sequences
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
+    // Plan hints
+    CreatePlanHint(Box<CreatePlanHintPlan>),
+    DropPlanHint(Box<DropPlanHintPlan>),
+
+    // Cluster
+    DrainNode(Box<DrainNodePlan>),
+
+    // Dictionaries
+    CreateDictionary(Box<CreateDictionaryPlan>),
+    DropDictionary(Box<DropDictionaryPlan>),
+
     // Role
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
@@ -193,6 +307,7 @@ pub enum Plan {
     ShowGrants(Box<ShowGrantsPlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
     RevokeRole(Box<RevokeRolePlan>),
+    SetRole(Box<SetRolePlan>),
 
     // Stages
     ListStage(Box<ListPlan>),
@@ -205,8 +320,18 @@ pub enum Plan {
 
     // Set
     SetVariable(Box<SettingPlan>),
+    SetUserVariable(Box<SetUserVariablePlan>),
+    UnsetUserVariable(Box<UnsetUserVariablePlan>),
     Kill(Box<KillPlan>),
 
+    // Scripting
+    ExecuteImmediate(Box<ExecuteImmediatePlan>),
+
+    // Transaction
+    Begin,
+    Commit,
+    Rollback,
+
     // Share
     CreateShare(Box<CreateSharePlan>),
     DropShare(Box<DropSharePlan>),
@@ -235,6 +360,12 @@ pub enum RewriteKind {
     ShowUsers,
     ShowStages,
     DescribeStage,
+    ShowConnections,
+    DescribeConnection,
+    ShowSequences,
+    ShowPlanHints,
+    ShowDictionaries,
+    ShowTasks,
     ShowRoles,
 }
 
@@ -250,21 +381,40 @@ impl Display for Plan {
             Plan::UndropDatabase(_) => write!(f, "UndropDatabase"),
             Plan::UseDatabase(_) => write!(f, "UseDatabase"),
             Plan::RenameDatabase(_) => write!(f, "RenameDatabase"),
+            Plan::SetDatabaseComment(_) => write!(f, "SetDatabaseComment"),
+            Plan::BackupDatabase(_) => write!(f, "BackupDatabase"),
+            Plan::RestoreDatabase(_) => write!(f, "RestoreDatabase"),
             Plan::ShowCreateTable(_) => write!(f, "ShowCreateTable"),
             Plan::DescribeTable(_) => write!(f, "DescribeTable"),
             Plan::CreateTable(_) => write!(f, "CreateTable"),
+            Plan::AttachTable(_) => write!(f, "AttachTable"),
             Plan::DropTable(_) => write!(f, "DropTable"),
             Plan::UndropTable(_) => write!(f, "UndropTable"),
             Plan::RenameTable(_) => write!(f, "RenameTable"),
+            Plan::SwapTable(_) => write!(f, "SwapTable"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
+            Plan::SetTableOptions(_) => write!(f, "SetTableOptions"),
+            Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
+            Plan::AddTableVirtualColumn(_) => write!(f, "AddTableVirtualColumn"),
+            Plan::DropTableVirtualColumn(_) => write!(f, "DropTableVirtualColumn"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
+            Plan::SetTableColumnMaskingPolicy(_) => write!(f, "SetTableColumnMaskingPolicy"),
+            Plan::UnsetTableColumnMaskingPolicy(_) => write!(f, "UnsetTableColumnMaskingPolicy"),
+            Plan::SetTableColumnComment(_) => write!(f, "SetTableColumnComment"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
+            Plan::AnalyzeTable(_) => write!(f, "AnalyzeTable"),
             Plan::ExistsTable(_) => write!(f, "ExistsTable"),
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateAggregatingIndex(_) => write!(f, "CreateAggregatingIndex"),
+            Plan::DropAggregatingIndex(_) => write!(f, "DropAggregatingIndex"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -279,15 +429,43 @@ impl Display for Plan {
             Plan::ShowGrants(_) => write!(f, "ShowGrants"),
             Plan::RevokePriv(_) => write!(f, "RevokePriv"),
             Plan::RevokeRole(_) => write!(f, "RevokeRole"),
+            Plan::SetRole(_) => write!(f, "SetRole"),
             Plan::CreateUDF(_) => write!(f, "CreateUDF"),
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::CreateMaskingPolicy(_) => write!(f, "CreateMaskingPolicy"),
+            Plan::DropMaskingPolicy(_) => write!(f, "DropMaskingPolicy"),
+            Plan::CreateNetworkPolicy(_) => write!(f, "CreateNetworkPolicy"),
+            Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
+            Plan::CreateTask(_) => write!(f, "CreateTask"),
+            Plan::DropTask(_) => write!(f, "DropTask"),
+            Plan::AlterTask(_) => write!(f, "AlterTask"),
+            Plan::CreateResourceGroup(_) => write!(f, "CreateResourceGroup"),
+            Plan::DropResourceGroup(_) => write!(f, "DropResourceGroup"),
+            Plan::CreateConnection(_) => write!(f, "CreateConnection"),
+            Plan::DropConnection(_) => write!(f, "DropConnection"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
+            Plan::CreatePlanHint(_) => write!(f, "CreatePlanHint"),
+            Plan::DropPlanHint(_) => write!(f, "DropPlanHint"),
+            Plan::DrainNode(_) => write!(f, "DrainNode"),
+            Plan::CreateDictionary(_) => write!(f, "CreateDictionary"),
+            Plan::DropDictionary(_) => write!(f, "DropDictionary"),
             Plan::Insert(_) => write!(f, "Insert"),
+            Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
+            Plan::SetUserVariable(_) => write!(f, "SetUserVariable"),
+            Plan::UnsetUserVariable(_) => write!(f, "UnsetUserVariable"),
+            Plan::ExecuteImmediate(_) => write!(f, "ExecuteImmediate"),
             Plan::Kill(_) => write!(f, "Kill"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Rollback => write!(f, "Rollback"),
             Plan::CreateShare(_) => write!(f, "CreateShare"),
             Plan::DropShare(_) => write!(f, "DropShare"),
             Plan::GrantShareObject(_) => write!(f, "GrantShareObject"),
@@ -316,28 +494,47 @@ impl Plan {
             Plan::Explain { .. } | Plan::ExplainAst { .. } | Plan::ExplainSyntax { .. } => {
                 DataSchemaRefExt::create(vec![DataField::new("explain", StringType::new_impl())])
             }
-            Plan::Copy(_) => Arc::new(DataSchema::empty()),
+            Plan::Copy(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
             Plan::CreateDatabase(plan) => plan.schema(),
             Plan::UseDatabase(_) => Arc::new(DataSchema::empty()),
             Plan::DropDatabase(plan) => plan.schema(),
             Plan::UndropDatabase(plan) => plan.schema(),
             Plan::RenameDatabase(plan) => plan.schema(),
+            Plan::SetDatabaseComment(plan) => plan.schema(),
+            Plan::BackupDatabase(plan) => plan.schema(),
+            Plan::RestoreDatabase(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::CreateTable(plan) => plan.schema(),
+            Plan::AttachTable(plan) => plan.schema(),
             Plan::DropTable(plan) => plan.schema(),
             Plan::UndropTable(plan) => plan.schema(),
             Plan::RenameTable(plan) => plan.schema(),
+            Plan::SwapTable(plan) => plan.schema(),
             Plan::AlterTableClusterKey(plan) => plan.schema(),
             Plan::DropTableClusterKey(plan) => plan.schema(),
             Plan::ReclusterTable(plan) => plan.schema(),
+            Plan::SetTableOptions(plan) => plan.schema(),
+            Plan::AddTableColumn(plan) => plan.schema(),
+            Plan::AddTableVirtualColumn(plan) => plan.schema(),
+            Plan::DropTableVirtualColumn(plan) => plan.schema(),
+            Plan::AddTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::SetTableColumnMaskingPolicy(plan) => plan.schema(),
+            Plan::UnsetTableColumnMaskingPolicy(plan) => plan.schema(),
+            Plan::SetTableColumnComment(plan) => plan.schema(),
             Plan::TruncateTable(plan) => plan.schema(),
             Plan::OptimizeTable(plan) => plan.schema(),
+            Plan::AnalyzeTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateStream(plan) => plan.schema(),
+            Plan::DropStream(plan) => plan.schema(),
+            Plan::CreateAggregatingIndex(plan) => plan.schema(),
+            Plan::DropAggregatingIndex(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -352,15 +549,41 @@ impl Plan {
             Plan::RemoveStage(plan) => plan.schema(),
             Plan::RevokePriv(_) => Arc::new(DataSchema::empty()),
             Plan::RevokeRole(_) => Arc::new(DataSchema::empty()),
+            Plan::SetRole(plan) => plan.schema(),
             Plan::CreateUDF(_) => Arc::new(DataSchema::empty()),
             Plan::AlterUDF(_) => Arc::new(DataSchema::empty()),
             Plan::DropUDF(_) => Arc::new(DataSchema::empty()),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::CreateMaskingPolicy(plan) => plan.schema(),
+            Plan::DropMaskingPolicy(plan) => plan.schema(),
+            Plan::CreateNetworkPolicy(plan) => plan.schema(),
+            Plan::DropNetworkPolicy(plan) => plan.schema(),
+            Plan::CreateTask(plan) => plan.schema(),
+            Plan::DropTask(plan) => plan.schema(),
+            Plan::AlterTask(plan) => plan.schema(),
+            Plan::CreateResourceGroup(plan) => plan.schema(),
+            Plan::DropResourceGroup(plan) => plan.schema(),
+            Plan::CreateConnection(plan) => plan.schema(),
+            Plan::DropConnection(plan) => plan.schema(),
+            Plan::CreateSequence(plan) => plan.schema(),
+            Plan::DropSequence(plan) => plan.schema(),
+            Plan::CreatePlanHint(plan) => plan.schema(),
+            Plan::DropPlanHint(plan) => plan.schema(),
+            Plan::DrainNode(plan) => plan.schema(),
+            Plan::CreateDictionary(plan) => plan.schema(),
+            Plan::DropDictionary(plan) => plan.schema(),
             Plan::Insert(plan) => plan.schema(),
+            Plan::Replace(_) => Arc::new(DataSchema::empty()),
             Plan::Delete(_) => Arc::new(DataSchema::empty()),
             Plan::Call(_) => Arc::new(DataSchema::empty()),
             Plan::Presign(plan) => plan.schema(),
             Plan::SetVariable(plan) => plan.schema(),
+            Plan::SetUserVariable(plan) => plan.schema(),
+            Plan::UnsetUserVariable(plan) => plan.schema(),
+            Plan::ExecuteImmediate(plan) => plan.schema(),
             Plan::Kill(_) => Arc::new(DataSchema::empty()),
+            Plan::Begin | Plan::Commit | Plan::Rollback => Arc::new(DataSchema::empty()),
             Plan::CreateShare(plan) => plan.schema(),
             Plan::DropShare(plan) => plan.schema(),
             Plan::GrantShareObject(plan) => plan.schema(),
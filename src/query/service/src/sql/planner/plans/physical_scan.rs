@@ -19,6 +19,7 @@ use common_planner::IndexType;
 use itertools::Itertools;
 
 use super::logical_get::Prewhere;
+use super::logical_get::VectorSearch;
 use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::Distribution;
 use crate::sql::optimizer::PhysicalProperty;
@@ -38,6 +39,7 @@ pub struct PhysicalScan {
     pub push_down_predicates: Option<Vec<Scalar>>,
     pub limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
+    pub vector_search: Option<VectorSearch>,
     pub prewhere: Option<Prewhere>,
 }
 
@@ -85,6 +85,8 @@ impl LogicalOperator for UnionAll {
             outer_columns,
             cardinality,
             precise_cardinality,
+            column_stats: Default::default(),
+            broadcast_hint: false,
         })
     }
 }
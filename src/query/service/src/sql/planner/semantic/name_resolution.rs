@@ -20,6 +20,9 @@ use common_settings::Settings;
 pub struct NameResolutionContext {
     pub unquoted_ident_case_sensitive: bool,
     pub quoted_ident_case_sensitive: bool,
+    // The case-insensitive identifiers are normalized to upper case rather than lower case,
+    // e.g. the unquoted identifiers of Snowflake.
+    pub ident_uppercase: bool,
 }
 
 impl Default for NameResolutionContext {
@@ -27,6 +30,7 @@ impl Default for NameResolutionContext {
         Self {
             unquoted_ident_case_sensitive: false,
             quoted_ident_case_sensitive: true,
+            ident_uppercase: false,
         }
     }
 }
@@ -35,12 +39,16 @@ impl TryFrom<&Settings> for NameResolutionContext {
     type Error = common_exception::ErrorCode;
 
     fn try_from(settings: &Settings) -> common_exception::Result<Self> {
-        let unquoted_ident_case_sensitive = settings.get_unquoted_ident_case_sensitive()?;
+        // `unquoted_ident_case_sensitive = 1` is kept the same as `unquoted_ident_case = 'preserve'`.
+        let unquoted_ident_case = settings.get_unquoted_ident_case()?;
+        let unquoted_ident_case_sensitive =
+            settings.get_unquoted_ident_case_sensitive()? || unquoted_ident_case == "preserve";
         let quoted_ident_case_sensitive = settings.get_quoted_ident_case_sensitive()?;
 
         Ok(Self {
             unquoted_ident_case_sensitive,
             quoted_ident_case_sensitive,
+            ident_uppercase: unquoted_ident_case == "upper",
         })
     }
 }
@@ -55,8 +63,13 @@ pub fn normalize_identifier<'a>(
     {
         ident.clone()
     } else {
+        let name = if context.ident_uppercase {
+            ident.name.to_uppercase()
+        } else {
+            ident.name.to_lowercase()
+        };
         Identifier {
-            name: ident.name.to_lowercase(),
+            name,
             quote: ident.quote,
             span: ident.span.clone(),
         }
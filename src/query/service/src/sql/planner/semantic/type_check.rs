@@ -31,7 +31,9 @@ use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_ast::DisplayError;
 use common_catalog::catalog::CatalogManager;
+use common_datavalues::remove_nullable;
 use common_datavalues::type_coercion::merge_types;
+use common_datavalues::wrap_nullable;
 use common_datavalues::ArrayType;
 use common_datavalues::DataField;
 use common_datavalues::DataType;
@@ -50,9 +52,12 @@ use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
 use common_functions::scalars::CastFunction;
+use common_functions::scalars::Collation;
 use common_functions::scalars::FunctionFactory;
 use common_functions::scalars::TupleFunction;
 use common_legacy_expression::validate_function_arg;
+use common_meta_types::TableCollations;
+use common_planner::IndexType;
 use common_planner::MetadataRef;
 use common_users::UserApiProvider;
 
@@ -60,6 +65,8 @@ use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
 use crate::catalogs::CatalogManagerHelper;
 use crate::evaluator::Evaluator;
+use crate::sessions::LoadedDictionary;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
 use crate::sql::binder::wrap_cast_if_needed;
 use crate::sql::binder::Binder;
@@ -80,6 +87,7 @@ use crate::sql::plans::SubqueryExpr;
 use crate::sql::plans::SubqueryType;
 use crate::sql::BindContext;
 use crate::sql::ScalarExpr;
+use crate::sql::OPT_KEY_COLLATIONS;
 
 /// A helper for type checking.
 ///
@@ -291,10 +299,12 @@ impl<'a> TypeChecker<'a> {
                 not,
                 ..
             } => {
+                // The strings of a collation are compared one by one, by their keys.
                 if list.len() > 3
                     && list
                         .iter()
                         .all(|e| matches!(e, Expr::Literal { lit, .. } if lit != &Literal::Null))
+                    && !self.has_collation(expr).await?
                 {
                     let tuple_expr = Expr::Tuple {
                         span,
@@ -371,10 +381,10 @@ impl<'a> TypeChecker<'a> {
                     // Rewrite `expr BETWEEN low AND high`
                     // into `expr >= low AND expr <= high`
                     let box (ge_func, left_type) = self
-                        .resolve_function(span, ">=", &[expr.as_ref(), low.as_ref()], None)
+                        .resolve_binary_op(span, &BinaryOperator::Gte, expr, low, None)
                         .await?;
                     let box (le_func, right_type) = self
-                        .resolve_function(span, "<=", &[expr.as_ref(), high.as_ref()], None)
+                        .resolve_binary_op(span, &BinaryOperator::Lte, expr, high, None)
                         .await?;
                     let func =
                         FunctionFactory::instance().get("and", &[&left_type, &right_type])?;
@@ -391,10 +401,10 @@ impl<'a> TypeChecker<'a> {
                     // Rewrite `expr NOT BETWEEN low AND high`
                     // into `expr < low OR expr > high`
                     let box (lt_func, left_type) = self
-                        .resolve_function(span, "<", &[expr.as_ref(), low.as_ref()], None)
+                        .resolve_binary_op(span, &BinaryOperator::Lt, expr, low, None)
                         .await?;
                     let box (gt_func, right_type) = self
-                        .resolve_function(span, ">", &[expr.as_ref(), high.as_ref()], None)
+                        .resolve_binary_op(span, &BinaryOperator::Gt, expr, high, None)
                         .await?;
                     let func = FunctionFactory::instance().get("or", &[&left_type, &right_type])?;
                     Box::new((
@@ -806,6 +816,24 @@ impl<'a> TypeChecker<'a> {
                 ))
             }
 
+            Expr::Collate {
+                span,
+                expr,
+                collation,
+            } => {
+                let collation = Collation::from_name(collation)
+                    .map_err(|e| ErrorCode::SemanticError(span.display_error(e.message())))?;
+                let box (scalar, data_type) = self.resolve(expr, required_type).await?;
+                if remove_nullable(&data_type).data_type_id() != TypeID::String {
+                    return Err(ErrorCode::SemanticError(span.display_error(format!(
+                        "COLLATE is only supported by the strings, but the expression is of type {}",
+                        data_type
+                    ))));
+                }
+                self.resolve_collation_key(span, scalar, data_type, &collation)
+                    .await?
+            }
+
             Expr::Extract {
                 span, kind, expr, ..
             } => {
@@ -929,6 +957,11 @@ impl<'a> TypeChecker<'a> {
             feature.num_arguments,
         )?;
 
+        // The result of the query calling the non-deterministic functions is not cached.
+        if !feature.is_deterministic {
+            self.ctx.set_cacheable(false);
+        }
+
         let func = FunctionFactory::instance()
             .get(func_name, &arg_types_ref)
             .map_err(|e| ErrorCode::SemanticError(span.display_error(e.message())))?;
@@ -1010,8 +1043,7 @@ impl<'a> TypeChecker<'a> {
             | BinaryOperator::Eq
             | BinaryOperator::NotEq => {
                 let op = ComparisonOp::try_from(op)?;
-                let box (left, _) = self.resolve(left, None).await?;
-                let box (right, _) = self.resolve(right, None).await?;
+                let box (left, right) = self.resolve_collated_operands(span, left, right).await?;
                 let func = FunctionFactory::instance()
                     .get(op.to_func_name(), &[&left.data_type(), &right.data_type()])?;
                 Ok(Box::new((
@@ -1058,6 +1090,116 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    // The operands of a comparison, the strings are compared by their keys if either of them has
+    // a collation. The collation by `COLLATE` takes precedence over the collations of the columns.
+    async fn resolve_collated_operands(
+        &mut self,
+        span: &[Token<'_>],
+        left: &Expr<'_>,
+        right: &Expr<'_>,
+    ) -> Result<Box<(Scalar, Scalar)>> {
+        let box (left_scalar, left_type) = self.resolve(left, None).await?;
+        let box (right_scalar, right_type) = self.resolve(right, None).await?;
+        if remove_nullable(&left_type).data_type_id() != TypeID::String
+            || remove_nullable(&right_type).data_type_id() != TypeID::String
+        {
+            return Ok(Box::new((left_scalar, right_scalar)));
+        }
+
+        let collation = match (explicit_collation(left), explicit_collation(right)) {
+            (Some(l), Some(r)) => {
+                let (l, r) = (Collation::from_name(l)?, Collation::from_name(r)?);
+                if l != r {
+                    return Err(ErrorCode::SemanticError(span.display_error(format!(
+                        "the strings of different collations {} and {} can not be compared",
+                        l.name(),
+                        r.name()
+                    ))));
+                }
+                // Both of the operands are the keys already.
+                return Ok(Box::new((left_scalar, right_scalar)));
+            }
+            (Some(collation), None) | (None, Some(collation)) => Collation::from_name(collation)?,
+            (None, None) => {
+                let left_collation = self.scalar_collation(&left_scalar)?;
+                let right_collation = self.scalar_collation(&right_scalar)?;
+                match (left_collation, right_collation) {
+                    (Some(l), Some(r)) if l != r => {
+                        return Err(ErrorCode::SemanticError(span.display_error(format!(
+                            "the columns of different collations {} and {} can not be compared, use COLLATE to choose one",
+                            l.name(),
+                            r.name()
+                        ))));
+                    }
+                    (Some(collation), _) | (None, Some(collation)) => collation,
+                    (None, None) => return Ok(Box::new((left_scalar, right_scalar))),
+                }
+            }
+        };
+
+        let left_scalar = match explicit_collation(left) {
+            Some(_) => left_scalar,
+            None => {
+                self.resolve_collation_key(span, left_scalar, left_type, &collation)
+                    .await?
+                    .0
+            }
+        };
+        let right_scalar = match explicit_collation(right) {
+            Some(_) => right_scalar,
+            None => {
+                self.resolve_collation_key(span, right_scalar, right_type, &collation)
+                    .await?
+                    .0
+            }
+        };
+        Ok(Box::new((left_scalar, right_scalar)))
+    }
+
+    // The key of the string in the collation, the string itself if the collation is binary.
+    async fn resolve_collation_key(
+        &mut self,
+        span: &[Token<'_>],
+        scalar: Scalar,
+        data_type: DataTypeImpl,
+        collation: &Collation,
+    ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        if collation.is_binary() {
+            return Ok(Box::new((scalar, data_type)));
+        }
+        let collation = ConstantExpr {
+            value: DataValue::String(collation.name().as_bytes().to_vec()),
+            data_type: Box::new(StringType::new_impl()),
+        };
+        self.resolve_scalar_function_call(
+            span,
+            "collation_key",
+            vec![scalar, collation.into()],
+            vec![data_type, StringType::new_impl()],
+            None,
+        )
+        .await
+    }
+
+    // Whether the strings of the expression are collated, by `COLLATE` or by the column.
+    async fn has_collation(&mut self, expr: &Expr<'_>) -> Result<bool> {
+        if explicit_collation(expr).is_some() {
+            return Ok(true);
+        }
+        let box (scalar, _) = self.resolve(expr, None).await?;
+        Ok(self.scalar_collation(&scalar)?.is_some())
+    }
+
+    // The collation of the column, none if the scalar is not a column or the column is binary.
+    fn scalar_collation(&self, scalar: &Scalar) -> Result<Option<Collation>> {
+        match scalar {
+            Scalar::BoundColumnRef(column_ref) => {
+                column_collation(&self.metadata, column_ref.column.index)
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// Resolve unary expressions.
     #[async_recursion::async_recursion]
     pub async fn resolve_unary_op(
@@ -1428,8 +1570,13 @@ impl<'a> TypeChecker<'a> {
                 | "user"
                 | "currentuser"
                 | "current_user"
+                | "current_role"
                 | "connection_id"
                 | "timezone"
+                | "getvariable"
+                | "dict_get"
+                | "dict_get_or_default"
+                | "dict_has"
                 | "nullif"
                 | "ifnull"
                 | "coalesce"
@@ -1477,16 +1624,32 @@ impl<'a> TypeChecker<'a> {
                 ),
                 Err(e) => Some(Err(e)),
             },
-            ("connection_id", &[]) => Some(
+            ("current_role", &[]) => Some(
                 self.resolve(
                     &Expr::Literal {
                         span,
-                        lit: Literal::String(self.ctx.get_connection_id()),
+                        lit: self
+                            .ctx
+                            .get_current_role()
+                            .map_or(Literal::Null, Literal::String),
                     },
                     None,
                 )
                 .await,
             ),
+            ("connection_id", &[]) => {
+                self.ctx.set_cacheable(false);
+                Some(
+                    self.resolve(
+                        &Expr::Literal {
+                            span,
+                            lit: Literal::String(self.ctx.get_connection_id()),
+                        },
+                        None,
+                    )
+                    .await,
+                )
+            }
             ("timezone", &[]) => {
                 let tz = self.ctx.get_settings().get_timezone().unwrap();
                 Some(
@@ -1500,6 +1663,74 @@ impl<'a> TypeChecker<'a> {
                     .await,
                 )
             }
+            ("getvariable", &[arg]) => {
+                // The variable is resolved to its value, or NULL if it's not set in the session.
+                // The query is not cached, since the variable may be set to another value.
+                self.ctx.set_cacheable(false);
+                let name =
+                    match arg {
+                        Expr::Literal {
+                            lit: Literal::String(name),
+                            ..
+                        } => name.to_lowercase(),
+                        _ => {
+                            return Some(Err(ErrorCode::SemanticError(arg.span().display_error(
+                            "getvariable expects the name of the variable as a string literal"
+                                .to_string(),
+                        ))));
+                        }
+                    };
+
+                match self.ctx.get_variable(&name) {
+                    Some((value, data_type)) => Some(Ok(Box::new((
+                        ConstantExpr {
+                            value,
+                            data_type: Box::new(data_type.clone()),
+                        }
+                        .into(),
+                        data_type,
+                    )))),
+                    None => Some(
+                        self.resolve(
+                            &Expr::Literal {
+                                span,
+                                lit: Literal::Null,
+                            },
+                            None,
+                        )
+                        .await,
+                    ),
+                }
+            }
+            ("dict_get", &[dictionary, attribute, key]) => Some(
+                self.resolve_dict_get(span, dictionary, Some(*attribute), key, None)
+                    .await,
+            ),
+            ("dict_get_or_default", &[dictionary, attribute, key, default]) => Some(
+                self.resolve_dict_get(span, dictionary, Some(*attribute), key, Some(*default))
+                    .await,
+            ),
+            ("dict_has", &[dictionary, key]) => {
+                // Rewrite dict_has(d, k) to is_not_null(dict_get(d, <primary key>, k))
+                let lookup = match self
+                    .resolve_dict_get(span, dictionary, None, key, None)
+                    .await
+                {
+                    Ok(lookup) => lookup,
+                    Err(e) => return Some(Err(e)),
+                };
+                let (lookup, data_type) = *lookup;
+                Some(
+                    self.resolve_scalar_function_call(
+                        span,
+                        "is_not_null",
+                        vec![lookup],
+                        vec![data_type],
+                        None,
+                    )
+                    .await,
+                )
+            }
             ("nullif", &[arg_x, arg_y]) => {
                 // Rewrite nullif(x, y) to if(x = y, null, x)
                 Some(
@@ -1729,6 +1960,105 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Resolves the lookup of the dictionary to `dict_get_or_default(dictionary, attribute, key,
+    /// default)`, the key is cast to the type of the primary key, and the default to the type of
+    /// the attribute. The default is NULL if it's not given, and the attribute is the primary key
+    /// if it's not given.
+    #[async_recursion::async_recursion]
+    async fn resolve_dict_get(
+        &mut self,
+        span: &[Token<'_>],
+        dictionary: &Expr<'_>,
+        attribute: Option<&Expr<'_>>,
+        key: &Expr<'_>,
+        default: Option<&Expr<'_>>,
+    ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        // The query is not cached, since the rows of the dictionary are loaded again.
+        self.ctx.set_cacheable(false);
+        let string_literal = |arg: &Expr<'_>, what: &str| match arg {
+            Expr::Literal {
+                lit: Literal::String(value),
+                ..
+            } => Ok(value.clone()),
+            _ => Err(ErrorCode::SemanticError(arg.span().display_error(format!(
+                "the {} of the dictionary lookup must be a string literal",
+                what
+            )))),
+        };
+        let dictionary = string_literal(dictionary, "dictionary")?;
+
+        // The rows are loaded here, the lookups of the query find them loaded on the node.
+        let loaded = SessionManager::instance()
+            .dictionary_cache
+            .get(&self.ctx.get_tenant(), &dictionary)
+            .await?;
+        let schema = LoadedDictionary::schema(&loaded.meta)?;
+        let attribute = match attribute {
+            Some(attribute) => string_literal(attribute, "attribute")?,
+            None => loaded.meta.primary_key.clone(),
+        };
+        let attribute_type = schema
+            .field_with_name(&attribute)
+            .map_err(|_| {
+                ErrorCode::SemanticError(span.display_error(format!(
+                    "dictionary {} has no attribute {}",
+                    dictionary, attribute
+                )))
+            })?
+            .data_type()
+            .clone();
+
+        let (key, key_type) = *self.resolve(key, None).await?;
+        let primary_key_type = schema
+            .field_with_name(&loaded.meta.primary_key)?
+            .data_type()
+            .clone();
+        let key = if key_type.is_nullable() || key_type.is_null() {
+            wrap_cast_if_needed(key, &wrap_nullable(&primary_key_type))
+        } else {
+            wrap_cast_if_needed(key, &primary_key_type)
+        };
+
+        let default = match default {
+            Some(default) => {
+                let (default, default_type) = *self.resolve(default, None).await?;
+                if default_type.is_nullable() || default_type.is_null() {
+                    wrap_cast_if_needed(default, &wrap_nullable(&attribute_type))
+                } else {
+                    wrap_cast_if_needed(default, &attribute_type)
+                }
+            }
+            None => ConstantExpr {
+                value: DataValue::Null,
+                data_type: Box::new(wrap_nullable(&attribute_type)),
+            }
+            .into(),
+        };
+
+        let string_constant = |value: String| -> Scalar {
+            ConstantExpr {
+                value: DataValue::String(value.into_bytes()),
+                data_type: Box::new(StringType::new_impl()),
+            }
+            .into()
+        };
+        let arguments = vec![
+            string_constant(dictionary),
+            string_constant(attribute),
+            key,
+            default,
+        ];
+        let arguments_types = arguments.iter().map(|arg| arg.data_type()).collect();
+        self.resolve_scalar_function_call(
+            span,
+            "dict_get_or_default",
+            arguments,
+            arguments_types,
+            None,
+        )
+        .await
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_udf(
         &mut self,
@@ -1740,6 +2070,8 @@ impl<'a> TypeChecker<'a> {
             .get_udf(self.ctx.get_tenant().as_str(), func_name)
             .await;
         if let Ok(udf) = udf {
+            // The query is not cached, since the function may be altered.
+            self.ctx.set_cacheable(false);
             let parameters = udf.parameters;
             if parameters.len() != arguments.len() {
                 return Err(ErrorCode::SyntaxException(span.display_error(format!(
@@ -1967,6 +2299,17 @@ impl<'a> TypeChecker<'a> {
                     ),
                     target_type: target_type.clone(),
                 }),
+                Expr::Collate {
+                    span,
+                    expr,
+                    collation,
+                } => Ok(Expr::Collate {
+                    span,
+                    expr: Box::new(
+                        self.clone_expr_with_replacement(expr.as_ref(), replacement_fn)?,
+                    ),
+                    collation: collation.clone(),
+                }),
                 Expr::Extract { span, kind, expr } => Ok(Expr::Extract {
                     span,
                     kind: *kind,
@@ -2133,3 +2476,30 @@ impl<'a> TypeChecker<'a> {
         }
     }
 }
+
+// The collation given by `COLLATE` of the expression.
+fn explicit_collation<'e>(expr: &'e Expr<'_>) -> Option<&'e str> {
+    match expr {
+        Expr::Collate { collation, .. } => Some(collation.as_str()),
+        _ => None,
+    }
+}
+
+/// The collation of the column of a table, set by `CREATE TABLE`, none if it is binary.
+pub fn column_collation(metadata: &MetadataRef, index: IndexType) -> Result<Option<Collation>> {
+    let metadata = metadata.read();
+    let column = metadata.column(index);
+    let table_index = match column.table_index() {
+        Some(table_index) if !column.has_path_indices() => table_index,
+        _ => return Ok(None),
+    };
+    let table = metadata.table(table_index).table();
+    let collations = match table.options().get(OPT_KEY_COLLATIONS) {
+        Some(value) => TableCollations::try_from_option_value(value)?,
+        None => return Ok(None),
+    };
+    match collations.columns.get(column.name()) {
+        Some(collation) => Ok(Some(Collation::from_name(collation)?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,522 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::parquet::read::read_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use percent_encoding::percent_decode_str;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::storages::parquet::read_parquet_chunks;
+
+pub const DELTA_LOG_DIR: &str = "_delta_log/";
+pub const COLUMN_MAPPING_MODE: &str = "delta.columnMapping.mode";
+
+// The reader features of https://github.com/delta-io/delta/blob/master/PROTOCOL.md#table-features
+// that the tables can be read with, the data files of the other ones such as the deletion
+// vectors can't be read as they are.
+const SUPPORTED_READER_FEATURES: &[&str] = &["columnMapping"];
+// The columns of the actions in the checkpoints, the others are not used to read the table.
+const CHECKPOINT_COLUMNS: &[&str] = &["add", "remove", "metaData", "protocol"];
+const CHECKPOINT_BLOCK_SIZE: usize = 8192;
+
+/// An action of a commit, only one of the kinds is set. The kinds that don't change
+/// the files or the schema of the table, such as `commitInfo` and `txn`, are ignored.
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    pub add: Option<Add>,
+    pub remove: Option<Remove>,
+    pub meta_data: Option<Metadata>,
+    pub protocol: Option<Protocol>,
+}
+
+/// A data file added to the table, the path is relative to the table location and url encoded.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Add {
+    pub path: String,
+    // Keyed by the physical names of the partition columns, the values are null or
+    // empty for the NULL partitions.
+    #[serde(default)]
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<Value>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Remove {
+    pub path: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Protocol {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+/// The statistics of the columns of a data file, keyed by the physical names.
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStats {
+    pub num_records: Option<u64>,
+    #[serde(default)]
+    pub min_values: HashMap<String, Value>,
+    #[serde(default)]
+    pub max_values: HashMap<String, Value>,
+    #[serde(default)]
+    pub null_count: HashMap<String, Value>,
+}
+
+impl Add {
+    /// The location of the data file relative to the table location.
+    pub fn location(&self) -> Result<String> {
+        // The files of the shallow clones are kept in the other locations.
+        if self.path.contains("://") {
+            return Err(ErrorCode::DeltaError(format!(
+                "data file {} out of the table location is not supported",
+                self.path
+            )));
+        }
+        let path = percent_decode_str(&self.path).decode_utf8_lossy();
+        Ok(path.trim_start_matches('/').to_string())
+    }
+
+    pub fn stats(&self) -> Option<FileStats> {
+        serde_json::from_str(self.stats.as_ref()?).ok()
+    }
+}
+
+impl Metadata {
+    /// Whether the columns are stored in the data files by the physical names, of
+    /// both the `name` and the `id` modes.
+    pub fn column_mapping(&self) -> bool {
+        matches!(
+            self.configuration
+                .get(COLUMN_MAPPING_MODE)
+                .map(|m| m.as_str()),
+            Some("name") | Some("id")
+        )
+    }
+}
+
+impl Protocol {
+    pub fn check_readable(&self) -> Result<()> {
+        let readable = match self.min_reader_version {
+            1 | 2 => true,
+            3 => self
+                .reader_features
+                .iter()
+                .flatten()
+                .all(|f| SUPPORTED_READER_FEATURES.contains(&f.as_str())),
+            _ => false,
+        };
+        match readable {
+            true => Ok(()),
+            false => Err(ErrorCode::UnImplement(format!(
+                "delta table of reader version {} with features {:?} is not supported",
+                self.min_reader_version,
+                self.reader_features.as_deref().unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+/// A file of the `_delta_log` directory that the versions of the table are read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaLogFile {
+    Commit(i64),
+    /// A part of the checkpoint of the version, the single-file checkpoints are of the part 1 of 1.
+    Checkpoint {
+        version: i64,
+        part: u32,
+        parts: u32,
+    },
+}
+
+/// Parses the name of the log file, e.g. `00000000000000000010.json` and
+/// `00000000000000000010.checkpoint.0000000001.0000000002.parquet`.
+pub fn parse_log_file_name(name: &str) -> Option<DeltaLogFile> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 {
+        return None;
+    }
+    let version = version.parse::<i64>().ok()?;
+    match rest.split('.').collect::<Vec<_>>().as_slice() {
+        ["json"] => Some(DeltaLogFile::Commit(version)),
+        ["checkpoint", "parquet"] => Some(DeltaLogFile::Checkpoint {
+            version,
+            part: 1,
+            parts: 1,
+        }),
+        ["checkpoint", part, parts, "parquet"] => Some(DeltaLogFile::Checkpoint {
+            version,
+            part: part.parse().ok()?,
+            parts: parts.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+fn commit_location(version: i64) -> String {
+    format!("{}{:020}.json", DELTA_LOG_DIR, version)
+}
+
+fn checkpoint_location(version: i64, part: u32, parts: u32) -> String {
+    match parts {
+        1 => format!("{}{:020}.checkpoint.parquet", DELTA_LOG_DIR, version),
+        _ => format!(
+            "{}{:020}.checkpoint.{:010}.{:010}.parquet",
+            DELTA_LOG_DIR, version, part, parts
+        ),
+    }
+}
+
+/// The commits and the checkpoints listed from the `_delta_log` directory.
+#[derive(Debug, Default)]
+pub struct DeltaLog {
+    // The commit of each version, with the last modified time in milliseconds if listed with.
+    commits: BTreeMap<i64, Option<i64>>,
+    // The parts of the checkpoints of each version, as `(part, parts)`.
+    checkpoints: BTreeMap<i64, Vec<(u32, u32)>>,
+}
+
+impl DeltaLog {
+    /// Lists the log of the table, the operator is rooted at the location of the table.
+    ///
+    /// The last modified times of the commits are only read for the time travel.
+    pub async fn list(operator: &Operator, with_commit_times: bool) -> Result<DeltaLog> {
+        let mut log = DeltaLog::default();
+        let mut entries = operator.object(DELTA_LOG_DIR).list().await?;
+        while let Some(de) = entries.try_next().await? {
+            if !de.mode().is_file() {
+                continue;
+            }
+
+            let path = de.path();
+            let name = &path[path.rfind('/').map(|pos| pos + 1).unwrap_or(0)..];
+            match parse_log_file_name(name) {
+                Some(DeltaLogFile::Commit(version)) => {
+                    let commit_time = match with_commit_times {
+                        true => de
+                            .metadata()
+                            .await?
+                            .last_modified()
+                            .map(|t| (t.unix_timestamp_nanos() / 1_000_000) as i64),
+                        false => None,
+                    };
+                    log.commits.insert(version, commit_time);
+                }
+                Some(DeltaLogFile::Checkpoint {
+                    version,
+                    part,
+                    parts,
+                }) => log
+                    .checkpoints
+                    .entry(version)
+                    .or_default()
+                    .push((part, parts)),
+                None => continue,
+            }
+        }
+        Ok(log)
+    }
+
+    pub fn latest_version(&self) -> Option<i64> {
+        self.commits.keys().next_back().copied()
+    }
+
+    /// The latest version committed at or before the time.
+    pub fn version_at(&self, timestamp_millis: i64) -> Option<i64> {
+        self.commits
+            .iter()
+            .rev()
+            .find(|(_, commit_time)| matches!(commit_time, Some(t) if *t <= timestamp_millis))
+            .map(|(version, _)| *version)
+    }
+
+    // The latest checkpoint at or before the version whose parts are all found,
+    // as the version and the number of parts.
+    fn checkpoint_before(&self, version: i64) -> Option<(i64, u32)> {
+        self.checkpoints
+            .range(..=version)
+            .rev()
+            .find_map(|(version, parts)| {
+                // Multiple checkpoints of different numbers of parts may be written for a version.
+                parts
+                    .iter()
+                    .map(|(_, n)| *n)
+                    .find(|n| (1..=*n).all(|part| parts.contains(&(part, *n))))
+                    .map(|n| (*version, n))
+            })
+    }
+}
+
+/// The state of a version of the table, replayed from the log.
+#[derive(Debug)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    pub metadata: Metadata,
+    // The active data files sorted by the paths.
+    pub files: Vec<Add>,
+}
+
+/// Reads the state of the version of the table, the latest version if not given.
+///
+/// The state is read from the latest checkpoint at or before the version, with the
+/// commits after it replayed one by one.
+pub async fn read_snapshot(
+    operator: &Operator,
+    log: &DeltaLog,
+    version: Option<i64>,
+) -> Result<DeltaSnapshot> {
+    let version = match version {
+        Some(version) => version,
+        None => log.latest_version().ok_or_else(|| {
+            ErrorCode::DeltaError("no commit found in the _delta_log of delta table")
+        })?,
+    };
+
+    let mut replay = LogReplay::default();
+    let mut start = 0;
+    if let Some((checkpoint, parts)) = log.checkpoint_before(version) {
+        let actions = futures::future::try_join_all(
+            (1..=parts).map(|part| read_checkpoint(operator, checkpoint, part, parts)),
+        )
+        .await?;
+        for action in actions.into_iter().flatten() {
+            replay.apply(action, true);
+        }
+        start = checkpoint + 1;
+    }
+
+    // The commits of the versions before the checkpoints may be cleaned, while
+    // the ones after must all be kept.
+    if let Some(missing) = (start..=version).find(|v| !log.commits.contains_key(v)) {
+        return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+            "commit {} of version {} of delta table not found",
+            commit_location(missing),
+            version
+        )));
+    }
+    let commits =
+        futures::future::try_join_all((start..=version).map(|v| read_commit(operator, v))).await?;
+    for action in commits.into_iter().flatten() {
+        replay.apply(action, false);
+    }
+    replay.finish(version)
+}
+
+#[derive(Default)]
+struct LogReplay {
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+    files: HashMap<String, Add>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action, from_checkpoint: bool) {
+        if let Some(add) = action.add {
+            self.files.insert(add.path.clone(), add);
+        }
+        // The removes of the checkpoints are the tombstones of the files removed before.
+        if let Some(remove) = action.remove {
+            if !from_checkpoint {
+                self.files.remove(&remove.path);
+            }
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(protocol) = action.protocol {
+            self.protocol = Some(protocol);
+        }
+    }
+
+    fn finish(self, version: i64) -> Result<DeltaSnapshot> {
+        let protocol = self
+            .protocol
+            .ok_or_else(|| ErrorCode::DeltaError("protocol of delta table not found"))?;
+        protocol.check_readable()?;
+        let metadata = self
+            .metadata
+            .ok_or_else(|| ErrorCode::DeltaError("metadata of delta table not found"))?;
+
+        let mut files = self.files.into_values().collect::<Vec<_>>();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files,
+        })
+    }
+}
+
+/// Parses the actions of a commit, one json object per line.
+pub fn parse_commit(location: &str, data: &[u8]) -> Result<Vec<Action>> {
+    let data = std::str::from_utf8(data).map_err(|e| {
+        ErrorCode::DeltaError(format!("invalid commit {} of delta table: {}", location, e))
+    })?;
+    data.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| {
+                ErrorCode::DeltaError(format!("invalid action of commit {}: {}", location, e))
+            })
+        })
+        .collect()
+}
+
+async fn read_commit(operator: &Operator, version: i64) -> Result<Vec<Action>> {
+    let location = commit_location(version);
+    let data = operator.object(&location).read().await?;
+    parse_commit(&location, &data)
+}
+
+async fn read_checkpoint(
+    operator: &Operator,
+    version: i64,
+    part: u32,
+    parts: u32,
+) -> Result<Vec<Action>> {
+    let location = checkpoint_location(version, part, parts);
+    let data = operator.object(&location).read().await?;
+    let file_meta = read_metadata(&mut Cursor::new(&data))
+        .map_err(|e| ErrorCode::ParquetError(format!("read meta failed, {}, {:?}", location, e)))?;
+    let fields = infer_schema(&file_meta)?
+        .fields
+        .into_iter()
+        .filter(|f| CHECKPOINT_COLUMNS.contains(&f.name.as_str()))
+        .collect::<Vec<_>>();
+
+    // Each row of the checkpoint is an action, whose columns are converted to the
+    // json values to be parsed as the actions of the commits.
+    let mut actions = vec![];
+    let chunks = read_parquet_chunks(&data, &fields, CHECKPOINT_BLOCK_SIZE)
+        .map_err(|e| e.add_message(format!(" checkpoint {}", location)))?;
+    for chunk in chunks {
+        for row in 0..chunk.len() {
+            let action = fields
+                .iter()
+                .zip(chunk.arrays().iter())
+                .map(|(field, array)| (field.name.clone(), array_value(array.as_ref(), row)))
+                .collect();
+            actions.push(serde_json::from_value(Value::Object(action)).map_err(|e| {
+                ErrorCode::DeltaError(format!("invalid action of checkpoint {}: {}", location, e))
+            })?);
+        }
+    }
+    Ok(actions)
+}
+
+fn array_value(array: &dyn Array, row: usize) -> Value {
+    if array.is_null(row) {
+        return Value::Null;
+    }
+
+    macro_rules! downcast {
+        ($array_type:ty) => {
+            array.as_any().downcast_ref::<$array_type>().unwrap()
+        };
+    }
+
+    match array.data_type().to_logical_type() {
+        ArrowType::Boolean => Value::Bool(downcast!(BooleanArray).value(row)),
+        ArrowType::Int8 => Value::from(downcast!(PrimitiveArray<i8>).value(row)),
+        ArrowType::Int16 => Value::from(downcast!(PrimitiveArray<i16>).value(row)),
+        ArrowType::Int32 | ArrowType::Date32 => {
+            Value::from(downcast!(PrimitiveArray<i32>).value(row))
+        }
+        ArrowType::Int64 | ArrowType::Timestamp(_, _) => {
+            Value::from(downcast!(PrimitiveArray<i64>).value(row))
+        }
+        ArrowType::Float32 => Value::from(downcast!(PrimitiveArray<f32>).value(row)),
+        ArrowType::Float64 => Value::from(downcast!(PrimitiveArray<f64>).value(row)),
+        ArrowType::Utf8 => Value::from(downcast!(Utf8Array<i32>).value(row)),
+        ArrowType::LargeUtf8 => Value::from(downcast!(Utf8Array<i64>).value(row)),
+        ArrowType::Binary => Value::from(String::from_utf8_lossy(
+            downcast!(BinaryArray<i32>).value(row),
+        )),
+        ArrowType::Struct(fields) => {
+            let array = downcast!(StructArray);
+            let values = fields
+                .iter()
+                .zip(array.values().iter())
+                .map(|(field, values)| (field.name.clone(), array_value(values.as_ref(), row)))
+                .collect();
+            Value::Object(values)
+        }
+        ArrowType::List(_) => {
+            let values = downcast!(ListArray<i32>).value(row);
+            Value::Array(
+                (0..values.len())
+                    .map(|i| array_value(values.as_ref(), i))
+                    .collect(),
+            )
+        }
+        ArrowType::Map(_, _) => {
+            // The entries of the maps are the structs of the keys and the values.
+            let entries = downcast!(MapArray).value(row);
+            let entries = match entries.as_any().downcast_ref::<StructArray>() {
+                Some(entries) if entries.values().len() == 2 => entries,
+                _ => return Value::Null,
+            };
+            let (keys, values) = (&entries.values()[0], &entries.values()[1]);
+            let map = (0..entries.len())
+                .map(|i| {
+                    let key = match array_value(keys.as_ref(), i) {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    (key, array_value(values.as_ref(), i))
+                })
+                .collect();
+            Value::Object(map)
+        }
+        _ => Value::Null,
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use common_datavalues::type_primitive::Float32Type;
+use common_datavalues::type_primitive::Float64Type;
+use common_datavalues::type_primitive::Int16Type;
+use common_datavalues::type_primitive::Int32Type;
+use common_datavalues::type_primitive::Int64Type;
+use common_datavalues::type_primitive::Int8Type;
+use common_datavalues::type_string::StringType;
+use common_datavalues::ArrayType;
+use common_datavalues::BooleanType;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_datavalues::DateType;
+use common_datavalues::NullableType;
+use common_datavalues::TimestampType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde_json::Value;
+
+const PHYSICAL_NAME: &str = "delta.columnMapping.physicalName";
+// The strings of the statistics are truncated to the prefixes of this length.
+const STATS_STRING_PREFIX_LENGTH: usize = 32;
+
+/// The schema of a delta table, kept as the `schemaString` of the metadata in the
+/// json format of the spark `StructType`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct DeltaSchema {
+    pub fields: Vec<StructField>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct StructField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: Value,
+    pub nullable: bool,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, Value>,
+}
+
+impl DeltaSchema {
+    pub fn parse(schema_string: &str) -> Result<DeltaSchema> {
+        serde_json::from_str(schema_string)
+            .map_err(|e| ErrorCode::DeltaError(format!("invalid schema of delta table: {}", e)))
+    }
+
+    pub fn to_data_schema(&self) -> Result<DataSchema> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| {
+                Ok(DataField::new(
+                    &f.name,
+                    to_data_type(&f.field_type, f.nullable)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataSchema::new(fields))
+    }
+
+    pub fn field_by_name(&self, name: &str) -> Option<&StructField> {
+        self.fields
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl StructField {
+    /// The name of the column in the data files, which is the physical name of the
+    /// field if the column mapping is enabled.
+    pub fn physical_name(&self, column_mapping: bool) -> &str {
+        match self.metadata.get(PHYSICAL_NAME).and_then(|v| v.as_str()) {
+            Some(name) if column_mapping => name,
+            _ => &self.name,
+        }
+    }
+}
+
+// https://github.com/delta-io/delta/blob/master/PROTOCOL.md#schema-serialization-format
+fn to_data_type(field_type: &Value, nullable: bool) -> Result<DataTypeImpl> {
+    let unsupported =
+        || ErrorCode::IllegalDataType(format!("unsupported delta type {}", field_type));
+
+    let data_type = match field_type {
+        Value::String(name) => match name.as_str() {
+            "boolean" => DataTypeImpl::Boolean(BooleanType::default()),
+            "byte" => DataTypeImpl::Int8(Int8Type::default()),
+            "short" => DataTypeImpl::Int16(Int16Type::default()),
+            "integer" => DataTypeImpl::Int32(Int32Type::default()),
+            "long" => DataTypeImpl::Int64(Int64Type::default()),
+            "float" => DataTypeImpl::Float32(Float32Type::default()),
+            "double" => DataTypeImpl::Float64(Float64Type::default()),
+            "date" => DataTypeImpl::Date(DateType::default()),
+            "timestamp" | "timestamp_ntz" => DataTypeImpl::Timestamp(TimestampType::create(6)),
+            "string" | "binary" => DataTypeImpl::String(StringType::default()),
+            _ => return Err(unsupported()),
+        },
+        Value::Object(object) if object.get("type").and_then(|v| v.as_str()) == Some("array") => {
+            let element = object.get("elementType").ok_or_else(unsupported)?;
+            let contains_null = object
+                .get("containsNull")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
+            DataTypeImpl::Array(ArrayType::create(to_data_type(element, contains_null)?))
+        }
+        _ => return Err(unsupported()),
+    };
+
+    match nullable {
+        true => Ok(NullableType::new_impl(data_type)),
+        false => Ok(data_type),
+    }
+}
+
+/// Decodes the min or max value of a column in the statistics of a data file.
+///
+/// None is returned for the types that the files are not pruned by, the timestamps
+/// are truncated to milliseconds in the statistics, so they can't bound the values.
+pub fn decode_stats_value(field_type: &Value, value: &Value) -> Option<DataValue> {
+    let value = match field_type.as_str()? {
+        "byte" | "short" | "integer" | "long" => DataValue::Int64(value.as_i64()?),
+        "float" | "double" => DataValue::Float64(value.as_f64()?),
+        "date" => {
+            let date = NaiveDate::parse_from_str(value.as_str()?, "%Y-%m-%d").ok()?;
+            let days = date.signed_duration_since(NaiveDate::from_ymd(1970, 1, 1));
+            DataValue::Int64(days.num_days())
+        }
+        "string" => {
+            // The truncated prefix is less than the max value.
+            let value = value.as_str()?;
+            if value.chars().count() >= STATS_STRING_PREFIX_LENGTH {
+                return None;
+            }
+            DataValue::String(value.as_bytes().to_vec())
+        }
+        _ => return None,
+    };
+    Some(value)
+}
@@ -0,0 +1,374 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::ColumnStatistics;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableInfo;
+use common_storage::init_operator;
+use common_storage::StorageParams;
+use opendal::Operator;
+
+use super::delta_log::read_snapshot;
+use super::delta_log::Add;
+use super::delta_log::DeltaLog;
+use super::delta_schema::decode_stats_value;
+use super::delta_schema::DeltaSchema;
+use super::delta_schema::StructField;
+use super::delta_table_options::DeltaTableOptions;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::index::RangeFilter;
+use crate::storages::parquet::parse_partition_value;
+use crate::storages::parquet::read_row_group_partitions;
+use crate::storages::parquet::read_row_groups;
+use crate::storages::parquet::ParquetFile;
+use crate::storages::parquet::DEFAULT_PARTITION;
+use crate::storages::NavigationPoint;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+pub const DELTA_ENGINE: &str = "DELTA";
+
+/// A delta lake table of a location, created by `CREATE EXTERNAL TABLE ... ENGINE = DELTA`.
+///
+/// The log is read from the location every time the table is queried, the latest
+/// version is read unless the table is navigated to another one by time travel.
+pub struct DeltaTable {
+    table_info: TableInfo,
+    options: DeltaTableOptions,
+}
+
+// A column of the table, with the field of the delta schema of the same name.
+struct DeltaColumn<'a> {
+    field: &'a DataField,
+    delta_field: &'a StructField,
+    is_partition: bool,
+}
+
+impl DeltaTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = DeltaTableOptions::try_from(table_info.engine_options())?;
+        Ok(Box::new(DeltaTable {
+            table_info,
+            options,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: DELTA_ENGINE.to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Reads the schema of the latest version of the delta table of the storage.
+    pub async fn read_schema(
+        ctx: &Arc<dyn TableContext>,
+        storage: &StorageParams,
+    ) -> Result<DataSchema> {
+        let operator = get_operator(ctx, storage)?;
+        let log = DeltaLog::list(&operator, false).await?;
+        let snapshot = read_snapshot(&operator, &log, None).await?;
+        DeltaSchema::parse(&snapshot.metadata.schema_string)?.to_data_schema()
+    }
+
+    fn get_operator(&self, ctx: &Arc<dyn TableContext>) -> Result<Operator> {
+        get_operator(ctx, &self.options.storage)
+    }
+
+    // Matches the columns of the table with the fields of the delta schema by name,
+    // the columns of the table may be a part of the fields.
+    fn columns<'a>(
+        &self,
+        table_schema: &'a DataSchemaRef,
+        schema: &'a DeltaSchema,
+        partition_columns: &[String],
+    ) -> Result<Vec<DeltaColumn<'a>>> {
+        table_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let delta_field = schema.field_by_name(field.name()).ok_or_else(|| {
+                    ErrorCode::DeltaError(format!(
+                        "column {} not found in the schema of delta table",
+                        field.name()
+                    ))
+                })?;
+                Ok(DeltaColumn {
+                    field,
+                    delta_field,
+                    is_partition: partition_columns.contains(&delta_field.name),
+                })
+            })
+            .collect()
+    }
+
+    // Builds the parquet file of the data file, the partition values and the names
+    // of the columns in the file are keyed by the names of the table columns.
+    fn parquet_file(
+        &self,
+        add: &Add,
+        columns: &[DeltaColumn],
+        column_mapping: bool,
+    ) -> Result<ParquetFile> {
+        if add.deletion_vector.is_some() {
+            return Err(ErrorCode::UnImplement(format!(
+                "deletion vector of data file {} of delta table is not supported",
+                add.path
+            )));
+        }
+
+        let mut partition_values = BTreeMap::new();
+        let mut column_names = BTreeMap::new();
+        for column in columns {
+            let physical_name = column.delta_field.physical_name(column_mapping);
+            if column.is_partition {
+                let value = add
+                    .partition_values
+                    .get(physical_name)
+                    .and_then(|v| v.as_deref())
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or(DEFAULT_PARTITION);
+                partition_values.insert(column.field.name().clone(), value.to_string());
+            } else if physical_name != column.field.name() {
+                column_names.insert(column.field.name().clone(), physical_name.to_string());
+            }
+        }
+
+        Ok(ParquetFile {
+            location: add.location()?,
+            size: add.size as u64,
+            partition_values,
+            column_names,
+        })
+    }
+}
+
+fn get_operator(ctx: &Arc<dyn TableContext>, storage: &StorageParams) -> Result<Operator> {
+    if !storage.is_secure() && !ctx.get_config().storage.allow_insecure {
+        return Err(ErrorCode::StorageInsecure(
+            "read from insecure storage is not allowed",
+        ));
+    }
+    Ok(init_operator(storage)?)
+}
+
+/// Prunes the data files by the filters of the push downs, with the statistics of the
+/// columns and the values of the partition columns kept in the log.
+struct DeltaPruner<'a> {
+    range_filter: RangeFilter,
+    columns: &'a [DeltaColumn<'a>],
+    column_mapping: bool,
+}
+
+impl<'a> DeltaPruner<'a> {
+    fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        table_schema: &DataSchemaRef,
+        columns: &'a [DeltaColumn<'a>],
+        column_mapping: bool,
+        push_downs: &Option<Extras>,
+    ) -> Result<Option<Self>> {
+        match push_downs {
+            Some(extras) if !extras.filters.is_empty() => Ok(Some(DeltaPruner {
+                range_filter: RangeFilter::try_create(
+                    ctx.clone(),
+                    &extras.filters,
+                    table_schema.clone(),
+                )?,
+                columns,
+                column_mapping,
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    fn file_may_match(&self, add: &Add, file: &ParquetFile) -> bool {
+        let file_stats = add.stats();
+        let num_rows = file_stats
+            .as_ref()
+            .and_then(|s| s.num_records)
+            .unwrap_or(u64::MAX);
+
+        let mut stats = HashMap::new();
+        for (index, column) in self.columns.iter().enumerate() {
+            if column.is_partition {
+                let value = &file.partition_values[column.field.name()];
+                if let Ok(value) = parse_partition_value(column.field, value) {
+                    let null_count = match value {
+                        DataValue::Null => num_rows,
+                        _ => 0,
+                    };
+                    stats.insert(index as u32, ColumnStatistics {
+                        min: value.clone(),
+                        max: value,
+                        null_count,
+                        in_memory_size: 0,
+                        distinct_of_values: None,
+                        histogram: None,
+                    });
+                }
+                continue;
+            }
+
+            let file_stats = match &file_stats {
+                Some(file_stats) => file_stats,
+                None => continue,
+            };
+            let name = column.delta_field.physical_name(self.column_mapping);
+            let field_type = &column.delta_field.field_type;
+            let min = file_stats.min_values.get(name);
+            let max = file_stats.max_values.get(name);
+            let null_count = file_stats.null_count.get(name).and_then(|v| v.as_u64());
+            if let (Some(min), Some(max), Some(null_count)) = (min, max, null_count) {
+                let bounds = (
+                    decode_stats_value(field_type, min),
+                    decode_stats_value(field_type, max),
+                );
+                if let (Some(min), Some(max)) = bounds {
+                    stats.insert(index as u32, ColumnStatistics {
+                        min,
+                        max,
+                        null_count,
+                        in_memory_size: 0,
+                        distinct_of_values: None,
+                        histogram: None,
+                    });
+                }
+            }
+        }
+
+        self.range_filter.eval(&stats, num_rows).unwrap_or(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let operator = self.get_operator(&ctx)?;
+        let log = DeltaLog::list(&operator, false).await?;
+        let snapshot = read_snapshot(&operator, &log, self.options.version).await?;
+        let schema = DeltaSchema::parse(&snapshot.metadata.schema_string)?;
+        let column_mapping = snapshot.metadata.column_mapping();
+
+        let table_schema = self.table_info.schema();
+        let columns = self.columns(&table_schema, &schema, &snapshot.metadata.partition_columns)?;
+        let pruner =
+            DeltaPruner::try_create(&ctx, &table_schema, &columns, column_mapping, &push_downs)?;
+
+        let mut files = Vec::with_capacity(snapshot.files.len());
+        for add in &snapshot.files {
+            let file = self.parquet_file(add, &columns, column_mapping)?;
+            if pruner
+                .as_ref()
+                .map_or(true, |p| p.file_may_match(add, &file))
+            {
+                files.push(file);
+            }
+        }
+
+        // The row groups are found by the names of the columns in the footers, which
+        // are not the names of the table columns if the column mapping is enabled.
+        let push_downs = match column_mapping {
+            true => None,
+            false => push_downs,
+        };
+        let file_fields = columns
+            .iter()
+            .filter(|c| !c.is_partition)
+            .map(|c| c.field.clone())
+            .collect::<Vec<_>>();
+        let file_schema = Arc::new(DataSchema::new(file_fields));
+        read_row_group_partitions(ctx, &operator, &file_schema, &files, &push_downs).await
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let operator = self.get_operator(&ctx)?;
+        read_row_groups(ctx, operator, plan, pipeline)
+    }
+
+    async fn navigate_to(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        instant: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        let operator = self.get_operator(&ctx)?;
+        let with_commit_times = matches!(instant, NavigationPoint::TimePoint(_));
+        let log = DeltaLog::list(&operator, with_commit_times).await?;
+        let version = match instant {
+            NavigationPoint::SnapshotID(version) => {
+                let version = version.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!("invalid delta table version '{}'", version))
+                })?;
+                match log.latest_version() {
+                    Some(latest) if version >= 0 && version <= latest => Some(version),
+                    _ => None,
+                }
+            }
+            NavigationPoint::TimePoint(time_point) => log.version_at(time_point.timestamp_millis()),
+        };
+        let version = version
+            .ok_or_else(|| ErrorCode::TableHistoricalDataNotFound("No historical data found"))?;
+
+        // The table is rebuilt from the table info while building the pipeline,
+        // so the version is kept in the engine options.
+        let options = DeltaTableOptions {
+            version: Some(version),
+            ..self.options.clone()
+        };
+        let mut table_info = self.table_info.clone();
+        table_info.meta.engine_options = BTreeMap::try_from(&options)?;
+        Ok(Arc::new(DeltaTable {
+            table_info,
+            options,
+        }))
+    }
+}
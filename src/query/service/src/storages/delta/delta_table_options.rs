@@ -0,0 +1,67 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::StorageParams;
+
+pub const STORAGE: &str = "storage";
+pub const VERSION: &str = "version";
+
+// represents a delta table, kept in the engine options of the table
+//
+// storage,  the storage rooted at the location of the delta table, such as: s3 bucket `mybucket` with root `/lake/t/`
+// version,  the version of the table to read, only set for the tables of time travel, the latest version is read if absent
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeltaTableOptions {
+    pub storage: StorageParams,
+    pub version: Option<i64>,
+}
+
+impl TryFrom<&DeltaTableOptions> for BTreeMap<String, String> {
+    type Error = ErrorCode;
+    fn try_from(options: &DeltaTableOptions) -> Result<BTreeMap<String, String>> {
+        let mut map = BTreeMap::new();
+        map.insert(
+            STORAGE.to_string(),
+            serde_json::to_string(&options.storage)?,
+        );
+        if let Some(version) = options.version {
+            map.insert(VERSION.to_string(), version.to_string());
+        }
+        Ok(map)
+    }
+}
+
+impl TryFrom<&BTreeMap<String, String>> for DeltaTableOptions {
+    type Error = ErrorCode;
+    fn try_from(options: &BTreeMap<String, String>) -> Result<DeltaTableOptions> {
+        let storage = options.get(STORAGE).ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("Delta engine table missing {} key", STORAGE))
+        })?;
+        let version = match options.get(VERSION) {
+            Some(id) => Some(id.parse::<i64>().map_err(|e| {
+                ErrorCode::TableInfoError(format!("invalid {} '{}': {}", VERSION, id, e))
+            })?),
+            None => None,
+        };
+        Ok(DeltaTableOptions {
+            storage: serde_json::from_str(storage)?,
+            version,
+        })
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod delta_log;
+mod delta_schema;
+mod delta_table;
+mod delta_table_options;
+
+pub use delta_log::parse_commit;
+pub use delta_log::parse_log_file_name;
+pub use delta_log::Action;
+pub use delta_log::Add;
+pub use delta_log::DeltaLogFile;
+pub use delta_log::Metadata;
+pub use delta_log::Protocol;
+pub use delta_schema::decode_stats_value;
+pub use delta_schema::DeltaSchema;
+pub use delta_table::DeltaTable;
+pub use delta_table::DELTA_ENGINE;
+pub use delta_table_options::DeltaTableOptions;
@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::PartInfo;
+use common_legacy_planners::PartInfoPtr;
+use common_meta_types::StageFileCompression;
+use common_pipeline_sources::processors::sources::input_formats::FileInfo;
+use common_pipeline_sources::processors::sources::input_formats::InputContext;
+use common_pipeline_sources::processors::sources::input_formats::SplitInfo;
+
+/// A file of an external table of the formats other than parquet,
+/// which are read by the input formats.
+///
+/// The splits of the file are got by the `read_partitions`, and kept in one part
+/// to be read by one node, so building the pipeline does not visit the file.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
+pub struct ExternalFilePartInfo {
+    pub location: String,
+    pub size: u64,
+    // The offset and the size of each split of the file.
+    pub splits: Vec<(usize, usize)>,
+}
+
+#[typetag::serde(name = "external_file")]
+impl PartInfo for ExternalFilePartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<ExternalFilePartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+impl ExternalFilePartInfo {
+    pub fn create(
+        location: &str,
+        size: u64,
+        splits: Vec<(usize, usize)>,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(ExternalFilePartInfo {
+            location: location.to_string(),
+            size,
+            splits,
+        }))
+    }
+
+    /// Group the splits by their files, the splits of a file are next to each other.
+    pub fn create_parts(splits: &[Arc<SplitInfo>]) -> Vec<Arc<Box<dyn PartInfo>>> {
+        let mut files: Vec<(&Arc<FileInfo>, Vec<(usize, usize)>)> = vec![];
+        for split in splits {
+            match files.last_mut() {
+                Some((file, offsets)) if file.path == split.file.path => {
+                    offsets.push((split.offset, split.size))
+                }
+                _ => files.push((&split.file, vec![(split.offset, split.size)])),
+            }
+        }
+        files
+            .into_iter()
+            .map(|(file, offsets)| Self::create(&file.path, file.size as u64, offsets))
+            .collect()
+    }
+
+    pub fn to_splits(&self, compression: StageFileCompression) -> Result<Vec<Arc<SplitInfo>>> {
+        let compress_alg = InputContext::get_compression_alg_copy(compression, &self.location)?;
+        let file = Arc::new(FileInfo {
+            path: self.location.clone(),
+            size: self.size as usize,
+            num_splits: self.splits.len(),
+            compress_alg,
+        });
+        let splits = self.splits.iter().enumerate();
+        Ok(splits
+            .map(|(seq_in_file, (offset, size))| {
+                Arc::new(SplitInfo {
+                    file: file.clone(),
+                    seq_in_file,
+                    offset: *offset,
+                    size: *size,
+                    format_info: None,
+                })
+            })
+            .collect())
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&ExternalFilePartInfo> {
+        match info.as_any().downcast_ref::<ExternalFilePartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::LogicalError(
+                "Cannot downcast from PartInfo to ExternalFilePartInfo.",
+            )),
+        }
+    }
+}
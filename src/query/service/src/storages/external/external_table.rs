@@ -0,0 +1,260 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableInfo;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::UserStageInfo;
+use common_pipeline_sources::processors::sources::input_formats::CopyIntoPlan;
+use common_pipeline_sources::processors::sources::input_formats::InputContext;
+use common_pipeline_sources::processors::sources::input_formats::StatisticsFilter;
+use common_storage::init_operator;
+use opendal::Operator;
+use regex::Regex;
+
+use super::external_part::ExternalFilePartInfo;
+use super::external_table_options::ExternalTableOptions;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::EmptySource;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::index::RangeFilter;
+use crate::storages::parquet::list_files;
+use crate::storages::parquet::parse_partition_values;
+use crate::storages::parquet::prune_partitioned_files;
+use crate::storages::parquet::read_row_group_partitions;
+use crate::storages::parquet::read_row_groups;
+use crate::storages::parquet::ParquetFile;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+pub const EXTERNAL_ENGINE: &str = "EXTERNAL";
+
+/// A table over the files of a location, created by `CREATE EXTERNAL TABLE`.
+///
+/// Nothing is stored by databend except the table meta, the files are listed
+/// and read every time the table is queried.
+pub struct ExternalTable {
+    table_info: TableInfo,
+    options: ExternalTableOptions,
+}
+
+impl ExternalTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = ExternalTableOptions::try_from(table_info.engine_options())?;
+        Ok(Box::new(ExternalTable {
+            table_info,
+            options,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: EXTERNAL_ENGINE.to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn get_operator(&self, ctx: &Arc<dyn TableContext>) -> Result<Operator> {
+        if !self.options.storage.is_secure() && !ctx.get_config().storage.allow_insecure {
+            return Err(ErrorCode::StorageInsecure(
+                "read from insecure storage is not allowed",
+            ));
+        }
+        Ok(init_operator(&self.options.storage)?)
+    }
+
+    fn stage_info(&self) -> UserStageInfo {
+        UserStageInfo {
+            file_format_options: self.options.file_format.clone(),
+            ..UserStageInfo::new_external_stage(self.options.storage.clone(), &self.options.path)
+        }
+    }
+
+    fn is_parquet(&self) -> bool {
+        self.options.file_format.format == StageFileFormatType::Parquet
+    }
+
+    /// Lists the files matching the path and the pattern, with the values of
+    /// the partition columns parsed from their directories.
+    async fn list_files(&self, operator: &Operator) -> Result<Vec<ParquetFile>> {
+        let pattern = match &self.options.pattern {
+            Some(pattern) => Some(Regex::new(pattern).map_err(|e| {
+                ErrorCode::BadArguments(format!("invalid pattern '{}': {}", pattern, e))
+            })?),
+            None => None,
+        };
+
+        let mut files = vec![];
+        for (location, size) in list_files(operator, &self.options.path).await? {
+            if let Some(pattern) = &pattern {
+                if !pattern.is_match(&location) {
+                    continue;
+                }
+            }
+
+            let partition_values = parse_partition_values(&location, &self.options.partition_by)?;
+            files.push(ParquetFile {
+                location,
+                size,
+                partition_values,
+                ..Default::default()
+            });
+        }
+        Ok(files)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    // Only the parquet files are read by columns, the other formats output
+    // all the columns of the table.
+    fn benefit_column_prune(&self) -> bool {
+        self.is_parquet()
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let operator = self.get_operator(&ctx)?;
+        let files = self.list_files(&operator).await?;
+
+        if !self.is_parquet() {
+            let read_bytes = files.iter().map(|f| f.size as usize).sum();
+            let plan = CopyIntoPlan {
+                stage_info: self.stage_info(),
+                files: files.into_iter().map(|f| f.location).collect(),
+            };
+            let format = InputContext::get_input_format(&self.options.file_format.format)?;
+            let splits = format
+                .get_splits(
+                    &plan,
+                    &operator,
+                    &ctx.get_settings(),
+                    &self.table_info.schema(),
+                )
+                .await?;
+            let partitions = ExternalFilePartInfo::create_parts(&splits);
+            let statistics =
+                Statistics::new_estimated(0, read_bytes, partitions.len(), partitions.len());
+            return Ok((statistics, partitions));
+        }
+
+        let schema = self.table_info.schema();
+        let partition_fields = self
+            .options
+            .partition_by
+            .iter()
+            .map(|column| schema.field_with_name(column).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        let partition_schema = Arc::new(DataSchema::new(partition_fields));
+        let files = prune_partitioned_files(&ctx, partition_schema, files, &push_downs)?;
+        let file_fields = schema
+            .fields()
+            .iter()
+            .filter(|f| !self.options.partition_by.contains(f.name()))
+            .cloned()
+            .collect::<Vec<_>>();
+        let file_schema = Arc::new(DataSchema::new(file_fields));
+        read_row_group_partitions(ctx, &operator, &file_schema, &files, &push_downs).await
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let operator = self.get_operator(&ctx)?;
+        if self.is_parquet() {
+            return read_row_groups(ctx, operator, plan, pipeline);
+        }
+
+        if plan.parts.is_empty() {
+            let output = OutputPort::create();
+            pipeline.add_pipe(Pipe::SimplePipe {
+                inputs_port: vec![],
+                outputs_port: vec![output.clone()],
+                processors: vec![EmptySource::create(output)?],
+            });
+
+            return Ok(());
+        }
+
+        // The splits of the files are got by the read_partitions.
+        let compression = self.options.file_format.compression;
+        let mut files = Vec::with_capacity(plan.parts.len());
+        let mut splits = vec![];
+        for part in &plan.parts {
+            let part = ExternalFilePartInfo::from_part(part)?;
+            files.push(part.location.clone());
+            splits.extend(part.to_splits(compression)?);
+        }
+        let copy_plan = CopyIntoPlan {
+            stage_info: self.stage_info(),
+            files,
+        };
+        let mut input_ctx = InputContext::try_create_from_splits(
+            operator,
+            ctx.get_settings(),
+            ctx.get_format_settings()?,
+            plan.source_info.schema(),
+            copy_plan,
+            splits,
+            ctx.get_scan_progress(),
+        )?;
+
+        // The parts of the files, e.g. the stripes of orc, are pruned by their statistics.
+        if let Some(extras) = &plan.push_downs {
+            if !extras.filters.is_empty() {
+                let range_filter = RangeFilter::try_create(
+                    ctx.clone(),
+                    &extras.filters,
+                    plan.source_info.schema(),
+                )?;
+                let statistics_filter: StatisticsFilter =
+                    Arc::new(move |stats: &StatisticsOfColumns, rows: u64| {
+                        range_filter.eval(stats, rows).unwrap_or(true)
+                    });
+                input_ctx = input_ctx.with_statistics_filter(statistics_filter);
+            }
+        }
+
+        let input_ctx = Arc::new(input_ctx);
+        input_ctx.format.exec_copy(input_ctx.clone(), pipeline)
+    }
+}
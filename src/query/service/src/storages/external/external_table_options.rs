@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::FileFormatOptions;
+use common_storage::StorageParams;
+
+pub const STORAGE: &str = "storage";
+pub const PATH: &str = "path";
+pub const PATTERN: &str = "pattern";
+pub const FILE_FORMAT: &str = "file_format";
+pub const PARTITION_BY: &str = "partition_by";
+
+// represents the files of an external table, kept in the engine options of the table
+//
+// storage,  the storage of the files, such as: s3 bucket `mybucket` of region `us-east-2`
+// path,  the path of the files in the storage, may end with glob wildcards, such as: /data/*.parquet
+// pattern,  the regex the paths of the files must match
+// file_format,  the format of the files
+// partition_by,  the columns whose values are parsed from the path, such as: "year", "month"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalTableOptions {
+    pub storage: StorageParams,
+    pub path: String,
+    pub pattern: Option<String>,
+    pub file_format: FileFormatOptions,
+    pub partition_by: Vec<String>,
+}
+
+impl TryFrom<&ExternalTableOptions> for BTreeMap<String, String> {
+    type Error = ErrorCode;
+    fn try_from(options: &ExternalTableOptions) -> Result<BTreeMap<String, String>> {
+        let mut map = BTreeMap::new();
+        map.insert(
+            STORAGE.to_string(),
+            serde_json::to_string(&options.storage)?,
+        );
+        map.insert(PATH.to_string(), options.path.clone());
+        if let Some(pattern) = &options.pattern {
+            map.insert(PATTERN.to_string(), pattern.clone());
+        }
+        map.insert(
+            FILE_FORMAT.to_string(),
+            serde_json::to_string(&options.file_format)?,
+        );
+        if !options.partition_by.is_empty() {
+            map.insert(PARTITION_BY.to_string(), options.partition_by.join(" "));
+        }
+        Ok(map)
+    }
+}
+
+impl TryFrom<&BTreeMap<String, String>> for ExternalTableOptions {
+    type Error = ErrorCode;
+    fn try_from(options: &BTreeMap<String, String>) -> Result<ExternalTableOptions> {
+        let get = |key: &str| {
+            options.get(key).ok_or_else(|| {
+                ErrorCode::TableInfoError(format!("External engine table missing {} key", key))
+            })
+        };
+
+        let partition_by = options
+            .get(PARTITION_BY)
+            .map(|v| v.split(' ').map(str::to_string).collect())
+            .unwrap_or_default();
+        Ok(ExternalTableOptions {
+            storage: serde_json::from_str(get(STORAGE)?)?,
+            path: get(PATH)?.clone(),
+            pattern: options.get(PATTERN).cloned(),
+            file_format: serde_json::from_str(get(FILE_FORMAT)?)?,
+            partition_by,
+        })
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::FixedSizeBinaryArray;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::io::avro::avro_schema;
+use common_arrow::arrow::io::avro::read;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// A value of the records of the avro files of iceberg, the manifest lists and the manifests.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AvroValue {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Record(Vec<(String, AvroValue)>),
+    Array(Vec<AvroValue>),
+    /// Values of the types that are not used by the metadata, such as decimals.
+    Unsupported,
+}
+
+impl AvroValue {
+    pub fn field(&self, name: &str) -> Option<&AvroValue> {
+        match self {
+            AvroValue::Record(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            AvroValue::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            AvroValue::Int(v) => Some(*v as i64),
+            AvroValue::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AvroValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            AvroValue::Bytes(v) => Some(v),
+            AvroValue::String(v) => Some(v.as_bytes()),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> &[AvroValue] {
+        match self {
+            AvroValue::Array(values) => values,
+            _ => &[],
+        }
+    }
+}
+
+/// Reads all the records of an avro object container file.
+pub fn read_avro_records(path: &str, data: Vec<u8>) -> Result<Vec<AvroValue>> {
+    let avro_error =
+        |e: &dyn std::fmt::Debug| ErrorCode::AvroError(format!("fail to read {}: {:?}", path, e));
+
+    let mut cursor = Cursor::new(data);
+    let meta = avro_schema::read::read_metadata(&mut cursor).map_err(|e| avro_error(&e))?;
+    let fields = read::infer_schema(&meta.record)?.fields;
+    let names = fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+
+    // The blocks follow the header that has been read from the cursor.
+    let reader = read::Reader::new(cursor, meta, fields, None);
+    let mut records = vec![];
+    for chunk in reader {
+        let chunk = chunk.map_err(|e| avro_error(&e))?;
+        for row in 0..chunk.len() {
+            let record = names
+                .iter()
+                .zip(chunk.arrays().iter())
+                .map(|(name, array)| (name.clone(), array_value(array.as_ref(), row)))
+                .collect();
+            records.push(AvroValue::Record(record));
+        }
+    }
+    Ok(records)
+}
+
+fn array_value(array: &dyn Array, row: usize) -> AvroValue {
+    if array.is_null(row) {
+        return AvroValue::Null;
+    }
+
+    macro_rules! downcast {
+        ($array_type:ty) => {
+            array.as_any().downcast_ref::<$array_type>().unwrap()
+        };
+    }
+
+    match array.data_type().to_logical_type() {
+        ArrowType::Boolean => AvroValue::Boolean(downcast!(BooleanArray).value(row)),
+        ArrowType::Int32 | ArrowType::Date32 => {
+            AvroValue::Int(downcast!(PrimitiveArray<i32>).value(row))
+        }
+        ArrowType::Int64 | ArrowType::Timestamp(_, _) | ArrowType::Time64(_) => {
+            AvroValue::Long(downcast!(PrimitiveArray<i64>).value(row))
+        }
+        ArrowType::Float32 => AvroValue::Float(downcast!(PrimitiveArray<f32>).value(row)),
+        ArrowType::Float64 => AvroValue::Double(downcast!(PrimitiveArray<f64>).value(row)),
+        ArrowType::Utf8 => AvroValue::String(downcast!(Utf8Array<i32>).value(row).to_string()),
+        ArrowType::Binary => AvroValue::Bytes(downcast!(BinaryArray<i32>).value(row).to_vec()),
+        ArrowType::FixedSizeBinary(_) => {
+            AvroValue::Bytes(downcast!(FixedSizeBinaryArray).value(row).to_vec())
+        }
+        ArrowType::Struct(fields) => {
+            let array = downcast!(StructArray);
+            let values = fields
+                .iter()
+                .zip(array.values().iter())
+                .map(|(field, values)| (field.name.clone(), array_value(values.as_ref(), row)))
+                .collect();
+            AvroValue::Record(values)
+        }
+        ArrowType::List(_) => {
+            let values = downcast!(ListArray<i32>).value(row);
+            let values = (0..values.len())
+                .map(|i| array_value(values.as_ref(), i))
+                .collect();
+            AvroValue::Array(values)
+        }
+        _ => AvroValue::Unsupported,
+    }
+}
@@ -0,0 +1,535 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::type_primitive::Float32Type;
+use common_datavalues::type_primitive::Float64Type;
+use common_datavalues::type_primitive::Int32Type;
+use common_datavalues::type_primitive::Int64Type;
+use common_datavalues::type_string::StringType;
+use common_datavalues::ArrayType;
+use common_datavalues::BooleanType;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_datavalues::DateType;
+use common_datavalues::NullableType;
+use common_datavalues::TimestampType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde::Deserialize;
+
+use super::iceberg_avro::read_avro_records;
+use super::iceberg_avro::AvroValue;
+
+const METADATA_DIR: &str = "metadata/";
+const VERSION_HINT: &str = "metadata/version-hint.text";
+
+// The content of the files listed by the manifests.
+pub const CONTENT_DATA: i32 = 0;
+pub const CONTENT_POSITION_DELETES: i32 = 1;
+pub const CONTENT_EQUALITY_DELETES: i32 = 2;
+
+// The content of the manifests listed by the manifest lists.
+const MANIFEST_CONTENT_DELETES: i32 = 1;
+
+// The entries of the removed files are kept by the manifests until the snapshots expire.
+const STATUS_DELETED: i64 = 2;
+
+/// The reserved field id of the `file_path` column of the position delete files.
+pub const DELETE_FILE_PATH_FIELD_ID: i32 = 2147483546;
+
+/// The metadata file of an iceberg table, of format v1 or v2.
+///
+/// See https://iceberg.apache.org/spec/#table-metadata
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: i32,
+    pub location: String,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    #[serde(default)]
+    pub schemas: Vec<IcebergSchema>,
+    // The only schema of the tables of v1, which may be written without `schemas`.
+    #[serde(default)]
+    pub schema: Option<IcebergSchema>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    // The only partition spec of the tables of v1, whose id is 0.
+    #[serde(default)]
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergSchema {
+    #[serde(default)]
+    pub schema_id: i32,
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct NestedField {
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub field_type: serde_json::Value,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionField {
+    pub name: String,
+    pub transform: String,
+    pub source_id: i32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(default)]
+    pub parent_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    // The snapshots of v1 may list the manifests directly instead, which isn't supported.
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+}
+
+impl TableMetadata {
+    /// Returns the snapshot of the id, or the current snapshot if the id is not given.
+    ///
+    /// None is returned for the tables without any snapshot.
+    pub fn snapshot(&self, snapshot_id: Option<i64>) -> Result<Option<&Snapshot>> {
+        let snapshot_id = match snapshot_id.or(self.current_snapshot_id) {
+            Some(id) if id != -1 => id,
+            _ => return Ok(None),
+        };
+        match self.snapshots.iter().find(|s| s.snapshot_id == snapshot_id) {
+            Some(snapshot) => Ok(Some(snapshot)),
+            None => Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "snapshot {} not found in iceberg table {}",
+                snapshot_id, self.location
+            ))),
+        }
+    }
+
+    /// Returns the last snapshot committed before the time point, among the
+    /// ancestors of the current snapshot.
+    pub fn snapshot_at(&self, timestamp_ms: i64) -> Result<Option<&Snapshot>> {
+        let mut snapshot = self.snapshot(None)?;
+        while let Some(s) = snapshot {
+            if s.timestamp_ms <= timestamp_ms {
+                return Ok(Some(s));
+            }
+            snapshot = s
+                .parent_snapshot_id
+                .and_then(|parent| self.snapshots.iter().find(|p| p.snapshot_id == parent));
+        }
+        Ok(None)
+    }
+
+    /// Returns the schema the snapshot is written with, or the current schema.
+    pub fn schema(&self, snapshot: Option<&Snapshot>) -> Result<&IcebergSchema> {
+        let schema_id = snapshot
+            .and_then(|s| s.schema_id)
+            .or(self.current_schema_id);
+        schema_id
+            .and_then(|id| self.schemas.iter().find(|s| s.schema_id == id))
+            .or(self.schema.as_ref())
+            .ok_or_else(|| {
+                ErrorCode::IcebergError(format!(
+                    "schema not found in metadata of iceberg table {}",
+                    self.location
+                ))
+            })
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&[PartitionField]> {
+        match self.partition_specs.iter().find(|s| s.spec_id == spec_id) {
+            Some(spec) => Some(&spec.fields),
+            None if spec_id == 0 => self.partition_spec.as_deref(),
+            None => None,
+        }
+    }
+
+    /// Returns the path relative to the table location of the files, which are
+    /// recorded with the full uri, e.g. `s3://bucket/db/t/data/00000.parquet`.
+    pub fn relative_path(&self, path: &str) -> Result<String> {
+        let location = strip_scheme(&self.location).trim_end_matches('/');
+        match strip_scheme(path).strip_prefix(location) {
+            Some(path) => Ok(path.trim_start_matches('/').to_string()),
+            None => Err(ErrorCode::IcebergError(format!(
+                "file {} is not in the location {} of iceberg table",
+                path, self.location
+            ))),
+        }
+    }
+}
+
+fn strip_scheme(path: &str) -> &str {
+    path.split_once("://").map(|(_, path)| path).unwrap_or(path)
+}
+
+impl IcebergSchema {
+    pub fn to_data_schema(&self) -> Result<DataSchema> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| {
+                Ok(DataField::new(
+                    &f.name,
+                    to_data_type(&f.field_type, f.required)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataSchema::new(fields))
+    }
+
+    /// Finds the top level field of the column, whose name is case insensitive.
+    pub fn field_by_name(&self, name: &str) -> Option<&NestedField> {
+        self.fields
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn field_by_id(&self, id: i32) -> Option<&NestedField> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+}
+
+// https://iceberg.apache.org/spec/#schemas-and-data-types
+fn to_data_type(field_type: &serde_json::Value, required: bool) -> Result<DataTypeImpl> {
+    let unsupported =
+        || ErrorCode::IllegalDataType(format!("unsupported iceberg type {}", field_type));
+
+    let data_type = match field_type {
+        serde_json::Value::String(name) => match name.as_str() {
+            "boolean" => DataTypeImpl::Boolean(BooleanType::default()),
+            "int" => DataTypeImpl::Int32(Int32Type::default()),
+            "long" => DataTypeImpl::Int64(Int64Type::default()),
+            "float" => DataTypeImpl::Float32(Float32Type::default()),
+            "double" => DataTypeImpl::Float64(Float64Type::default()),
+            "date" => DataTypeImpl::Date(DateType::default()),
+            "timestamp" | "timestamptz" => DataTypeImpl::Timestamp(TimestampType::create(6)),
+            "string" | "binary" => DataTypeImpl::String(StringType::default()),
+            _ => return Err(unsupported()),
+        },
+        serde_json::Value::Object(object)
+            if object.get("type").and_then(|v| v.as_str()) == Some("list") =>
+        {
+            let element = object.get("element").ok_or_else(unsupported)?;
+            let element_required = object
+                .get("element-required")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            DataTypeImpl::Array(ArrayType::create(to_data_type(element, element_required)?))
+        }
+        _ => return Err(unsupported()),
+    };
+
+    match required {
+        true => Ok(data_type),
+        false => Ok(NullableType::new_impl(data_type)),
+    }
+}
+
+/// Decodes the lower or upper bound of a column of the primitive type, which is
+/// kept in the manifests with the single-value serialization.
+///
+/// None is returned for the types that the files are not pruned by.
+pub fn decode_bound(field_type: &serde_json::Value, bytes: &[u8]) -> Option<DataValue> {
+    // The bounds written before an `int` column is promoted to `long` are still of 4 bytes,
+    // so are the ones of `float` promoted to `double`.
+    let integer = || match bytes.len() {
+        4 => Some(i32::from_le_bytes(bytes.try_into().ok()?) as i64),
+        8 => Some(i64::from_le_bytes(bytes.try_into().ok()?)),
+        _ => None,
+    };
+    let float = || match bytes.len() {
+        4 => Some(f32::from_le_bytes(bytes.try_into().ok()?) as f64),
+        8 => Some(f64::from_le_bytes(bytes.try_into().ok()?)),
+        _ => None,
+    };
+
+    let value = match field_type.as_str()? {
+        "boolean" => DataValue::Boolean(*bytes.first()? != 0),
+        "int" | "long" | "date" | "timestamp" | "timestamptz" => DataValue::Int64(integer()?),
+        "float" | "double" => DataValue::Float64(float()?),
+        "string" => DataValue::String(bytes.to_vec()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Converts the value of a partition of the identity transform, None for the
+/// types that the files are not pruned by.
+pub fn partition_value(value: &AvroValue) -> Option<DataValue> {
+    let value = match value {
+        AvroValue::Null => DataValue::Null,
+        AvroValue::Boolean(v) => DataValue::Boolean(*v),
+        AvroValue::Int(v) => DataValue::Int64(*v as i64),
+        AvroValue::Long(v) => DataValue::Int64(*v),
+        AvroValue::Float(v) => DataValue::Float64(*v as f64),
+        AvroValue::Double(v) => DataValue::Float64(*v),
+        AvroValue::String(v) => DataValue::String(v.as_bytes().to_vec()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Reads the latest metadata of the table, the operator is rooted at the location of the table.
+pub async fn read_table_metadata(operator: &Operator) -> Result<TableMetadata> {
+    let path = latest_metadata_path(operator).await?;
+    let data = operator.object(&path).read().await?;
+    serde_json::from_slice(&data).map_err(|e| {
+        ErrorCode::IcebergError(format!(
+            "fail to parse iceberg table metadata {}: {}",
+            path, e
+        ))
+    })
+}
+
+async fn latest_metadata_path(operator: &Operator) -> Result<String> {
+    // The hint is written by the hadoop catalog, the other catalogs keep the
+    // location of the latest metadata by themselves.
+    let hint = operator.object(VERSION_HINT);
+    if hint.is_exist().await? {
+        let version = String::from_utf8_lossy(&hint.read().await?)
+            .trim()
+            .to_string();
+        return Ok(format!("{}v{}.metadata.json", METADATA_DIR, version));
+    }
+
+    let mut latest: Option<(u64, String)> = None;
+    let mut entries = operator.object(METADATA_DIR).list().await?;
+    while let Some(de) = entries.try_next().await? {
+        if !de.mode().is_file() {
+            continue;
+        }
+
+        let path = de.path();
+        let name = &path[path.rfind('/').map(|pos| pos + 1).unwrap_or(0)..];
+        if let Some(version) = metadata_version(name) {
+            if latest.as_ref().map_or(true, |(v, _)| version > *v) {
+                latest = Some((version, path.to_string()));
+            }
+        }
+    }
+
+    latest
+        .map(|(_, path)| path)
+        .ok_or_else(|| ErrorCode::IcebergError("no metadata file found in iceberg table"))
+}
+
+/// Parses the version of the name of a metadata file, which is either
+/// `v<version>.metadata.json` or `<version>-<uuid>.metadata.json`.
+pub fn metadata_version(name: &str) -> Option<u64> {
+    let name = name.strip_suffix(".metadata.json")?;
+    let name = name.strip_prefix('v').unwrap_or(name);
+    name.split('-').next()?.parse().ok()
+}
+
+/// A manifest listed by the manifest list of a snapshot.
+#[derive(Clone, Debug)]
+pub struct ManifestFile {
+    pub manifest_path: String,
+    pub partition_spec_id: i32,
+    pub is_deletes: bool,
+    pub sequence_number: i64,
+    pub partitions: Vec<FieldSummary>,
+}
+
+/// The summary of the values of a partition field of the files of a manifest.
+#[derive(Clone, Debug)]
+pub struct FieldSummary {
+    pub contains_null: bool,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+/// A data file or delete file listed by a manifest.
+#[derive(Clone, Debug)]
+pub struct DataFile {
+    pub content: i32,
+    pub file_path: String,
+    pub file_format: String,
+    pub spec_id: i32,
+    pub partition: Vec<(String, AvroValue)>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub sequence_number: i64,
+    pub null_value_counts: HashMap<i32, i64>,
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+    pub equality_ids: Vec<i32>,
+}
+
+pub async fn read_manifest_list(
+    operator: &Operator,
+    metadata: &TableMetadata,
+    snapshot: &Snapshot,
+) -> Result<Vec<ManifestFile>> {
+    let manifest_list = snapshot.manifest_list.as_ref().ok_or_else(|| {
+        ErrorCode::UnImplement(format!(
+            "iceberg snapshot {} without manifest list is not supported",
+            snapshot.snapshot_id
+        ))
+    })?;
+    let path = metadata.relative_path(manifest_list)?;
+    let data = operator.object(&path).read().await?;
+
+    let mut manifests = vec![];
+    for record in read_avro_records(&path, data)? {
+        let partitions = record
+            .field("partitions")
+            .map(AvroValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|summary| FieldSummary {
+                contains_null: summary
+                    .field("contains_null")
+                    .and_then(AvroValue::as_bool)
+                    .unwrap_or(true),
+                lower_bound: summary
+                    .field("lower_bound")
+                    .and_then(AvroValue::as_bytes)
+                    .map(|v| v.to_vec()),
+                upper_bound: summary
+                    .field("upper_bound")
+                    .and_then(AvroValue::as_bytes)
+                    .map(|v| v.to_vec()),
+            })
+            .collect();
+
+        // The manifests of v1 have neither the content nor the sequence number.
+        let content = optional_i64(&record, "content").unwrap_or(0) as i32;
+        manifests.push(ManifestFile {
+            manifest_path: required_str(&record, "manifest_path")?.to_string(),
+            partition_spec_id: optional_i64(&record, "partition_spec_id").unwrap_or(0) as i32,
+            is_deletes: content == MANIFEST_CONTENT_DELETES,
+            sequence_number: optional_i64(&record, "sequence_number").unwrap_or(0),
+            partitions,
+        });
+    }
+    Ok(manifests)
+}
+
+/// Reads the live files of the manifest.
+pub async fn read_manifest(
+    operator: &Operator,
+    metadata: &TableMetadata,
+    manifest: &ManifestFile,
+) -> Result<Vec<DataFile>> {
+    let path = metadata.relative_path(&manifest.manifest_path)?;
+    let data = operator.object(&path).read().await?;
+
+    let mut files = vec![];
+    for record in read_avro_records(&path, data)? {
+        if required_i64(&record, "status")? == STATUS_DELETED {
+            continue;
+        }
+
+        // The null sequence numbers of the entries are inherited from the manifest.
+        let sequence_number =
+            optional_i64(&record, "sequence_number").unwrap_or(manifest.sequence_number);
+        let data_file = record
+            .field("data_file")
+            .ok_or_else(|| missing("data_file"))?;
+        let partition = match data_file.field("partition") {
+            Some(AvroValue::Record(values)) => values.clone(),
+            _ => vec![],
+        };
+        files.push(DataFile {
+            content: optional_i64(data_file, "content").unwrap_or(0) as i32,
+            file_path: required_str(data_file, "file_path")?.to_string(),
+            file_format: required_str(data_file, "file_format")?.to_uppercase(),
+            spec_id: manifest.partition_spec_id,
+            partition,
+            record_count: required_i64(data_file, "record_count")?,
+            file_size_in_bytes: required_i64(data_file, "file_size_in_bytes")?,
+            sequence_number,
+            null_value_counts: int_map(data_file, "null_value_counts", AvroValue::as_i64),
+            lower_bounds: int_map(data_file, "lower_bounds", |v| Some(v.as_bytes()?.to_vec())),
+            upper_bounds: int_map(data_file, "upper_bounds", |v| Some(v.as_bytes()?.to_vec())),
+            equality_ids: data_file
+                .field("equality_ids")
+                .map(AvroValue::as_array)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|v| Some(v.as_i64()? as i32))
+                .collect(),
+        });
+    }
+    Ok(files)
+}
+
+fn missing(name: &str) -> ErrorCode {
+    ErrorCode::IcebergError(format!("missing field {} in iceberg manifest", name))
+}
+
+fn optional_i64(record: &AvroValue, name: &str) -> Option<i64> {
+    record.field(name).and_then(AvroValue::as_i64)
+}
+
+fn required_i64(record: &AvroValue, name: &str) -> Result<i64> {
+    optional_i64(record, name).ok_or_else(|| missing(name))
+}
+
+fn required_str<'a>(record: &'a AvroValue, name: &str) -> Result<&'a str> {
+    record
+        .field(name)
+        .and_then(AvroValue::as_str)
+        .ok_or_else(|| missing(name))
+}
+
+// The maps keyed by the field ids are written as arrays of key-value records.
+fn int_map<T>(
+    record: &AvroValue,
+    name: &str,
+    value: impl Fn(&AvroValue) -> Option<T>,
+) -> HashMap<i32, T> {
+    record
+        .field(name)
+        .map(AvroValue::as_array)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|entry| {
+            let key = entry.field("key")?.as_i64()? as i32;
+            Some((key, value(entry.field("value")?)?))
+        })
+        .collect()
+}
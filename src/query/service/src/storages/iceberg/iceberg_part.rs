@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::PartInfo;
+use common_legacy_planners::PartInfoPtr;
+
+/// A data file of an iceberg table, with the delete files that apply to it.
+///
+/// The locations are relative to the table location, while the `file_path` is the one
+/// recorded by the manifest, which the position delete files refer to the data file by.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
+pub struct IcebergPartInfo {
+    pub location: String,
+    pub file_path: String,
+    pub size: u64,
+    pub position_deletes: Vec<String>,
+    pub equality_deletes: Vec<IcebergEqualityDeletes>,
+}
+
+/// An equality delete file, whose rows are the values of the columns of the deleted rows.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergEqualityDeletes {
+    pub location: String,
+    pub columns: Vec<String>,
+}
+
+#[typetag::serde(name = "iceberg")]
+impl PartInfo for IcebergPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+impl IcebergPartInfo {
+    pub fn create(
+        location: String,
+        file_path: String,
+        size: u64,
+        position_deletes: Vec<String>,
+        equality_deletes: Vec<IcebergEqualityDeletes>,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(IcebergPartInfo {
+            location,
+            file_path,
+            size,
+            position_deletes,
+            equality_deletes,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&IcebergPartInfo> {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::LogicalError(
+                "Cannot downcast from PartInfo to IcebergPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use opendal::Operator;
+
+use super::iceberg_part::IcebergEqualityDeletes;
+use super::iceberg_part::IcebergPartInfo;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
+use crate::sessions::TableContext;
+use crate::storages::parquet::read_parquet_chunks;
+
+/// Reads the data files of the partitions one by one, with the rows of the
+/// delete files that apply to them removed.
+pub struct IcebergSource {
+    ctx: Arc<dyn TableContext>,
+    operator: Operator,
+    // The schema of the table, where the columns of the equality deletes are found.
+    schema: DataSchemaRef,
+    projected_schema: DataSchemaRef,
+    block_size: usize,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl IcebergSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        schema: DataSchemaRef,
+        projected_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let block_size = ctx.get_settings().get_max_block_size()? as usize;
+        AsyncSourcer::create(ctx.clone(), output, IcebergSource {
+            ctx,
+            operator,
+            schema,
+            projected_schema,
+            block_size,
+            blocks: VecDeque::new(),
+        })
+    }
+
+    async fn read_part(&self, part: &IcebergPartInfo) -> Result<Vec<DataBlock>> {
+        let deleted_positions = self.read_position_deletes(part).await?;
+        let mut equality_deletes = Vec::with_capacity(part.equality_deletes.len());
+        for deletes in &part.equality_deletes {
+            let keys = self.read_equality_deletes(deletes).await?;
+            equality_deletes.push((&deletes.columns, keys));
+        }
+
+        // The columns of the equality deletes are read along with the projected ones.
+        let mut fields = self.projected_schema.fields().clone();
+        for (columns, _) in &equality_deletes {
+            for column in columns.iter() {
+                if !fields.iter().any(|f| f.name() == column) {
+                    fields.push(self.schema.field_with_name(column)?.clone());
+                }
+            }
+        }
+        let read_schema = DataSchemaRefExt::create(fields);
+
+        let data = self.operator.object(&part.location).read().await?;
+        let blocks = read_parquet_blocks(&data, &read_schema, self.block_size)?;
+        if deleted_positions.is_empty() && equality_deletes.is_empty() {
+            return Ok(blocks);
+        }
+
+        let mut position = 0;
+        let mut result = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let mut keep = (position..position + num_rows as i64)
+                .map(|pos| !deleted_positions.contains(&pos))
+                .collect::<Vec<_>>();
+            position += num_rows as i64;
+
+            for (columns, keys) in &equality_deletes {
+                let columns = columns
+                    .iter()
+                    .map(|c| block.try_column_by_name(c))
+                    .collect::<Result<Vec<_>>>()?;
+                for (row, keep) in keep.iter_mut().enumerate() {
+                    if *keep {
+                        let key = columns.iter().map(|c| c.get(row)).collect::<Vec<_>>();
+                        *keep = !keys.contains(&key);
+                    }
+                }
+            }
+
+            let block = DataBlock::filter_block(block, &Series::from_data(keep))?;
+            result.push(block.resort(self.projected_schema.clone())?);
+        }
+        Ok(result)
+    }
+
+    // Collects the deleted positions of the data file, the position delete files
+    // may contain the positions of the other data files too.
+    async fn read_position_deletes(&self, part: &IcebergPartInfo) -> Result<HashSet<i64>> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("file_path", Vu8::to_data_type()),
+            DataField::new("pos", i64::to_data_type()),
+        ]);
+        let file_path = DataValue::String(part.file_path.as_bytes().to_vec());
+
+        let mut positions = HashSet::new();
+        for location in &part.position_deletes {
+            let data = self.operator.object(location).read().await?;
+            let blocks = read_parquet_blocks(&data, &schema, self.block_size)
+                .map_err(|e| e.add_message(format!(" position delete file {}", location)))?;
+            for block in blocks {
+                let (paths, pos) = (block.column(0), block.column(1));
+                for row in 0..block.num_rows() {
+                    if paths.get(row) == file_path {
+                        positions.insert(pos.get(row).as_i64()?);
+                    }
+                }
+            }
+        }
+        Ok(positions)
+    }
+
+    async fn read_equality_deletes(
+        &self,
+        deletes: &IcebergEqualityDeletes,
+    ) -> Result<HashSet<Vec<DataValue>>> {
+        let fields = deletes
+            .columns
+            .iter()
+            .map(|c| self.schema.field_with_name(c).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        let schema = DataSchemaRefExt::create(fields);
+
+        let data = self.operator.object(&deletes.location).read().await?;
+        let blocks = read_parquet_blocks(&data, &schema, self.block_size)
+            .map_err(|e| e.add_message(format!(" equality delete file {}", deletes.location)))?;
+
+        let mut keys = HashSet::new();
+        for block in blocks {
+            for row in 0..block.num_rows() {
+                keys.insert(block.columns().iter().map(|c| c.get(row)).collect());
+            }
+        }
+        Ok(keys)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergSource {
+    const NAME: &'static str = "IcebergSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+
+            let partitions = self.ctx.try_get_partitions(1)?;
+            let part = match partitions.first() {
+                Some(part) => IcebergPartInfo::from_part(part)?,
+                None => return Ok(None),
+            };
+            let blocks = self
+                .read_part(part)
+                .await
+                .map_err(|e| e.add_message(format!(" iceberg data file {}", part.location)))?;
+            self.blocks = blocks.into();
+        }
+    }
+}
+
+// Reads the columns of the schema from the parquet file in memory, found by name.
+fn read_parquet_blocks(
+    data: &[u8],
+    schema: &DataSchemaRef,
+    block_size: usize,
+) -> Result<Vec<DataBlock>> {
+    read_parquet_chunks(data, &schema.to_arrow().fields, block_size)?
+        .iter()
+        .map(|chunk| DataBlock::from_chunk(schema, chunk))
+        .collect()
+}
@@ -0,0 +1,527 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::ColumnStatistics;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::Projection;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableInfo;
+use common_storage::init_operator;
+use common_storage::StorageParams;
+use opendal::Operator;
+
+use super::iceberg_metadata::decode_bound;
+use super::iceberg_metadata::partition_value;
+use super::iceberg_metadata::read_manifest;
+use super::iceberg_metadata::read_manifest_list;
+use super::iceberg_metadata::read_table_metadata;
+use super::iceberg_metadata::DataFile;
+use super::iceberg_metadata::IcebergSchema;
+use super::iceberg_metadata::ManifestFile;
+use super::iceberg_metadata::NestedField;
+use super::iceberg_metadata::TableMetadata;
+use super::iceberg_metadata::CONTENT_DATA;
+use super::iceberg_metadata::CONTENT_POSITION_DELETES;
+use super::iceberg_metadata::DELETE_FILE_PATH_FIELD_ID;
+use super::iceberg_part::IcebergEqualityDeletes;
+use super::iceberg_part::IcebergPartInfo;
+use super::iceberg_source::IcebergSource;
+use super::iceberg_table_options::IcebergTableOptions;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::EmptySource;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::TableContext;
+use crate::storages::index::RangeFilter;
+use crate::storages::NavigationPoint;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+pub const ICEBERG_ENGINE: &str = "ICEBERG";
+
+/// An iceberg table of a location, created by `CREATE EXTERNAL TABLE ... ENGINE = ICEBERG`.
+///
+/// The metadata is read from the location every time the table is queried, the
+/// current snapshot is read unless the table is navigated to another one by time travel.
+pub struct IcebergTable {
+    table_info: TableInfo,
+    options: IcebergTableOptions,
+}
+
+impl IcebergTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = IcebergTableOptions::try_from(table_info.engine_options())?;
+        Ok(Box::new(IcebergTable {
+            table_info,
+            options,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: ICEBERG_ENGINE.to_string(),
+            comment: "ICEBERG Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Reads the current schema of the iceberg table of the storage.
+    pub async fn read_schema(
+        ctx: &Arc<dyn TableContext>,
+        storage: &StorageParams,
+    ) -> Result<DataSchema> {
+        let operator = get_operator(ctx, storage)?;
+        let metadata = read_table_metadata(&operator).await?;
+        metadata.schema(None)?.to_data_schema()
+    }
+
+    fn get_operator(&self, ctx: &Arc<dyn TableContext>) -> Result<Operator> {
+        get_operator(ctx, &self.options.storage)
+    }
+
+    // Builds the parts of the data files, with the delete files that apply to them.
+    fn build_partitions(
+        &self,
+        metadata: &TableMetadata,
+        schema: &IcebergSchema,
+        data_files: &[DataFile],
+        delete_files: &[DataFile],
+    ) -> Result<Partitions> {
+        let table_schema = self.table_info.schema();
+        let column_name = |field_id: &i32| -> Result<String> {
+            let field = schema.field_by_id(*field_id).ok_or_else(|| {
+                ErrorCode::IcebergError(format!(
+                    "equality delete field {} not found in iceberg schema",
+                    field_id
+                ))
+            })?;
+            table_schema
+                .fields()
+                .iter()
+                .find(|f| f.name().eq_ignore_ascii_case(&field.name))
+                .map(|f| f.name().clone())
+                .ok_or_else(|| {
+                    ErrorCode::IcebergError(format!(
+                        "equality delete column {} is not a column of the table",
+                        field.name
+                    ))
+                })
+        };
+
+        for file in data_files.iter().chain(delete_files.iter()) {
+            if file.file_format != "PARQUET" {
+                return Err(ErrorCode::UnImplement(format!(
+                    "iceberg file {} of format {} is not supported",
+                    file.file_path, file.file_format
+                )));
+            }
+        }
+
+        let mut partitions = Vec::with_capacity(data_files.len());
+        for file in data_files {
+            let mut position_deletes = vec![];
+            let mut equality_deletes = vec![];
+            for delete in delete_files.iter().filter(|d| delete_applies(d, file)) {
+                let location = metadata.relative_path(&delete.file_path)?;
+                match delete.content {
+                    CONTENT_POSITION_DELETES => position_deletes.push(location),
+                    _ => equality_deletes.push(IcebergEqualityDeletes {
+                        location,
+                        columns: delete
+                            .equality_ids
+                            .iter()
+                            .map(&column_name)
+                            .collect::<Result<Vec<_>>>()?,
+                    }),
+                }
+            }
+
+            partitions.push(IcebergPartInfo::create(
+                metadata.relative_path(&file.file_path)?,
+                file.file_path.clone(),
+                file.file_size_in_bytes as u64,
+                position_deletes,
+                equality_deletes,
+            ));
+        }
+        Ok(partitions)
+    }
+}
+
+fn get_operator(ctx: &Arc<dyn TableContext>, storage: &StorageParams) -> Result<Operator> {
+    if !storage.is_secure() && !ctx.get_config().storage.allow_insecure {
+        return Err(ErrorCode::StorageInsecure(
+            "read from insecure storage is not allowed",
+        ));
+    }
+    Ok(init_operator(storage)?)
+}
+
+// Whether the delete file applies to the data file, see https://iceberg.apache.org/spec/#scan-planning
+fn delete_applies(delete: &DataFile, data: &DataFile) -> bool {
+    // The position deletes apply to the data files of the same commit too,
+    // while the equality deletes only apply to the ones committed before.
+    let sequence_matched = match delete.content {
+        CONTENT_POSITION_DELETES => data.sequence_number <= delete.sequence_number,
+        _ => data.sequence_number < delete.sequence_number,
+    };
+    // The deletes of the unpartitioned specs apply to the data files of any partitions.
+    let partition_matched = delete.partition.is_empty()
+        || (delete.spec_id == data.spec_id && delete.partition == data.partition);
+    if !sequence_matched || !partition_matched {
+        return false;
+    }
+
+    // The position deletes of a single data file are bounded by its path.
+    if delete.content == CONTENT_POSITION_DELETES {
+        let lower = delete.lower_bounds.get(&DELETE_FILE_PATH_FIELD_ID);
+        let upper = delete.upper_bounds.get(&DELETE_FILE_PATH_FIELD_ID);
+        if let (Some(lower), Some(upper)) = (lower, upper) {
+            if lower == upper && lower.as_slice() != data.file_path.as_bytes() {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Prunes the manifests and the data files by the filters of the push downs, with the
+/// summaries of the partitions and the bounds of the columns kept in the manifests.
+///
+/// Only the partitions of the identity transforms are used, the values of the
+/// other transforms can't be compared with the columns.
+struct IcebergPruner<'a> {
+    metadata: &'a TableMetadata,
+    range_filter: Option<RangeFilter>,
+    // The index in the table schema and the iceberg field of each column.
+    columns: Vec<(u32, &'a NestedField)>,
+}
+
+impl<'a> IcebergPruner<'a> {
+    fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        table_schema: &DataSchemaRef,
+        metadata: &'a TableMetadata,
+        schema: &'a IcebergSchema,
+        push_downs: &Option<Extras>,
+    ) -> Result<Self> {
+        let range_filter = match push_downs {
+            Some(extras) if !extras.filters.is_empty() => Some(RangeFilter::try_create(
+                ctx.clone(),
+                &extras.filters,
+                table_schema.clone(),
+            )?),
+            _ => None,
+        };
+        let columns = table_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, f)| Some((index as u32, schema.field_by_name(f.name())?)))
+            .collect();
+        Ok(IcebergPruner {
+            metadata,
+            range_filter,
+            columns,
+        })
+    }
+
+    fn column(&self, field_id: i32) -> Option<&(u32, &'a NestedField)> {
+        self.columns.iter().find(|(_, f)| f.id == field_id)
+    }
+
+    fn manifest_may_match(&self, manifest: &ManifestFile) -> bool {
+        let (range_filter, spec) = match (
+            &self.range_filter,
+            self.metadata.partition_spec(manifest.partition_spec_id),
+        ) {
+            (Some(range_filter), Some(spec)) => (range_filter, spec),
+            _ => return true,
+        };
+
+        let mut stats = HashMap::new();
+        for (partition, summary) in spec.iter().zip(manifest.partitions.iter()) {
+            if partition.transform != "identity" {
+                continue;
+            }
+            let (index, field) = match self.column(partition.source_id) {
+                Some(column) => column,
+                None => continue,
+            };
+            let bounds = match (&summary.lower_bound, &summary.upper_bound) {
+                (Some(lower), Some(upper)) => (
+                    decode_bound(&field.field_type, lower),
+                    decode_bound(&field.field_type, upper),
+                ),
+                _ => continue,
+            };
+            if let (Some(min), Some(max)) = bounds {
+                stats.insert(*index, ColumnStatistics {
+                    min,
+                    max,
+                    null_count: summary.contains_null as u64,
+                    in_memory_size: 0,
+                    distinct_of_values: None,
+                    histogram: None,
+                });
+            }
+        }
+
+        // The number of rows is not kept by the summaries, the null counts only
+        // tell whether there are nulls.
+        range_filter.eval(&stats, u64::MAX).unwrap_or(true)
+    }
+
+    fn file_may_match(&self, file: &DataFile) -> bool {
+        let range_filter = match &self.range_filter {
+            Some(range_filter) => range_filter,
+            None => return true,
+        };
+
+        let mut stats = HashMap::new();
+        for (index, field) in &self.columns {
+            let null_count = file.null_value_counts.get(&field.id);
+            let lower = file.lower_bounds.get(&field.id);
+            let upper = file.upper_bounds.get(&field.id);
+            if let (Some(null_count), Some(lower), Some(upper)) = (null_count, lower, upper) {
+                let bounds = (
+                    decode_bound(&field.field_type, lower),
+                    decode_bound(&field.field_type, upper),
+                );
+                if let (Some(min), Some(max)) = bounds {
+                    stats.insert(*index, ColumnStatistics {
+                        min,
+                        max,
+                        null_count: *null_count as u64,
+                        in_memory_size: 0,
+                        distinct_of_values: None,
+                        histogram: None,
+                    });
+                }
+            }
+        }
+
+        // The values of the identity partitions are the bounds of the columns too,
+        // in case the bounds are not written.
+        if let Some(spec) = self.metadata.partition_spec(file.spec_id) {
+            for partition in spec.iter().filter(|p| p.transform == "identity") {
+                let index = match self.column(partition.source_id) {
+                    Some((index, _)) => *index,
+                    None => continue,
+                };
+                let value = file
+                    .partition
+                    .iter()
+                    .find(|(name, _)| name == &partition.name)
+                    .and_then(|(_, value)| partition_value(value));
+                if let Some(value) = value {
+                    let null_count = match value {
+                        DataValue::Null => file.record_count as u64,
+                        _ => 0,
+                    };
+                    stats.entry(index).or_insert(ColumnStatistics {
+                        min: value.clone(),
+                        max: value,
+                        null_count,
+                        in_memory_size: 0,
+                        distinct_of_values: None,
+                        histogram: None,
+                    });
+                }
+            }
+        }
+
+        range_filter
+            .eval(&stats, file.record_count as u64)
+            .unwrap_or(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let operator = self.get_operator(&ctx)?;
+        let metadata = read_table_metadata(&operator).await?;
+        let snapshot = match metadata.snapshot(self.options.snapshot_id)? {
+            Some(snapshot) => snapshot,
+            None => return Ok((Statistics::default(), vec![])),
+        };
+        let schema = metadata.schema(Some(snapshot))?;
+        let pruner = IcebergPruner::try_create(
+            &ctx,
+            &self.table_info.schema(),
+            &metadata,
+            schema,
+            &push_downs,
+        )?;
+
+        // The delete manifests are not pruned, the deletes of a partition are
+        // not bounded by the values of the columns.
+        let manifests = read_manifest_list(&operator, &metadata, snapshot)
+            .await?
+            .into_iter()
+            .filter(|m| m.is_deletes || pruner.manifest_may_match(m))
+            .collect::<Vec<_>>();
+        let files = futures::future::try_join_all(
+            manifests
+                .iter()
+                .map(|manifest| read_manifest(&operator, &metadata, manifest)),
+        )
+        .await?;
+
+        let mut partitions_total = 0;
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+        for file in files.into_iter().flatten() {
+            if file.content != CONTENT_DATA {
+                delete_files.push(file);
+                continue;
+            }
+
+            partitions_total += 1;
+            if pruner.file_may_match(&file) {
+                data_files.push(file);
+            }
+        }
+
+        let read_rows = data_files.iter().map(|f| f.record_count as usize).sum();
+        let read_bytes = data_files
+            .iter()
+            .map(|f| f.file_size_in_bytes as usize)
+            .sum();
+        let partitions = self.build_partitions(&metadata, schema, &data_files, &delete_files)?;
+        let statistics =
+            Statistics::new_estimated(read_rows, read_bytes, partitions.len(), partitions_total);
+        Ok((statistics, partitions))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if plan.parts.is_empty() {
+            let output = OutputPort::create();
+            pipeline.add_pipe(Pipe::SimplePipe {
+                inputs_port: vec![],
+                outputs_port: vec![output.clone()],
+                processors: vec![EmptySource::create(output)?],
+            });
+
+            return Ok(());
+        }
+
+        let projection = match plan.projections() {
+            Projection::Columns(indices) => indices,
+            Projection::InnerColumns(_) => {
+                return Err(ErrorCode::UnImplement(
+                    "does not support projection inner columns",
+                ));
+            }
+        };
+        let schema = plan.source_info.schema();
+        let projected_schema = Arc::new(schema.project(&projection));
+        let operator = self.get_operator(&ctx)?;
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for _index in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                IcebergSource::create(
+                    ctx.clone(),
+                    output,
+                    operator.clone(),
+                    schema.clone(),
+                    projected_schema.clone(),
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn navigate_to(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        instant: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        let operator = self.get_operator(&ctx)?;
+        let metadata = read_table_metadata(&operator).await?;
+        let snapshot = match instant {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                let snapshot_id = snapshot_id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "invalid iceberg snapshot id '{}'",
+                        snapshot_id
+                    ))
+                })?;
+                metadata.snapshot(Some(snapshot_id))?
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                metadata.snapshot_at(time_point.timestamp_millis())?
+            }
+        };
+        let snapshot = snapshot
+            .ok_or_else(|| ErrorCode::TableHistoricalDataNotFound("No historical data found"))?;
+
+        // The table is rebuilt from the table info while building the pipeline,
+        // so the snapshot is kept in the engine options.
+        let options = IcebergTableOptions {
+            snapshot_id: Some(snapshot.snapshot_id),
+            ..self.options.clone()
+        };
+        let mut table_info = self.table_info.clone();
+        table_info.meta.engine_options = BTreeMap::try_from(&options)?;
+        Ok(Arc::new(IcebergTable {
+            table_info,
+            options,
+        }))
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::StorageParams;
+
+pub const STORAGE: &str = "storage";
+pub const SNAPSHOT_ID: &str = "snapshot_id";
+
+// represents an iceberg table, kept in the engine options of the table
+//
+// storage,  the storage rooted at the location of the iceberg table, such as: s3 bucket `mybucket` with root `/warehouse/db/t/`
+// snapshot_id,  the snapshot to read, only set for the tables of time travel, the current snapshot is read if absent
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcebergTableOptions {
+    pub storage: StorageParams,
+    pub snapshot_id: Option<i64>,
+}
+
+impl TryFrom<&IcebergTableOptions> for BTreeMap<String, String> {
+    type Error = ErrorCode;
+    fn try_from(options: &IcebergTableOptions) -> Result<BTreeMap<String, String>> {
+        let mut map = BTreeMap::new();
+        map.insert(
+            STORAGE.to_string(),
+            serde_json::to_string(&options.storage)?,
+        );
+        if let Some(snapshot_id) = options.snapshot_id {
+            map.insert(SNAPSHOT_ID.to_string(), snapshot_id.to_string());
+        }
+        Ok(map)
+    }
+}
+
+impl TryFrom<&BTreeMap<String, String>> for IcebergTableOptions {
+    type Error = ErrorCode;
+    fn try_from(options: &BTreeMap<String, String>) -> Result<IcebergTableOptions> {
+        let storage = options.get(STORAGE).ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("Iceberg engine table missing {} key", STORAGE))
+        })?;
+        let snapshot_id = match options.get(SNAPSHOT_ID) {
+            Some(id) => Some(id.parse::<i64>().map_err(|e| {
+                ErrorCode::TableInfoError(format!("invalid {} '{}': {}", SNAPSHOT_ID, id, e))
+            })?),
+            None => None,
+        };
+        Ok(IcebergTableOptions {
+            storage: serde_json::from_str(storage)?,
+            snapshot_id,
+        })
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod iceberg_avro;
+mod iceberg_metadata;
+mod iceberg_part;
+mod iceberg_source;
+mod iceberg_table;
+mod iceberg_table_options;
+
+pub use iceberg_avro::AvroValue;
+pub use iceberg_metadata::decode_bound;
+pub use iceberg_metadata::metadata_version;
+pub use iceberg_metadata::TableMetadata;
+pub use iceberg_part::IcebergEqualityDeletes;
+pub use iceberg_part::IcebergPartInfo;
+pub use iceberg_table::IcebergTable;
+pub use iceberg_table::ICEBERG_ENGINE;
+pub use iceberg_table_options::IcebergTableOptions;
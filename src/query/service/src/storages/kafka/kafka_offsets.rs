@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+use common_users::UserApiProvider;
+
+const KAFKA_OFFSETS_KEY_PREFIX: &str = "__fd_kafka_offsets";
+
+/// The offsets of the partitions of a topic stored for a consumer in the meta service,
+/// the offset of a partition is the next one to read.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KafkaOffsets {
+    // The seq of the stored offsets, 0 if nothing is stored yet.
+    pub seq: u64,
+    pub offsets: BTreeMap<i32, i64>,
+}
+
+impl KafkaOffsets {
+    pub async fn load(tenant: &str, consumer: &str, topic: &str) -> Result<KafkaOffsets> {
+        let key = offsets_key(tenant, consumer, topic)?;
+        let kv_api = UserApiProvider::instance().get_meta_store_client();
+        match kv_api.get_kv(&key).await? {
+            Some(seq_v) => Ok(KafkaOffsets {
+                seq: seq_v.seq,
+                offsets: serde_json::from_slice(&seq_v.data)?,
+            }),
+            None => Ok(KafkaOffsets::default()),
+        }
+    }
+
+    /// Replaces the stored offsets with the new ones.
+    ///
+    /// The update is conditioned on the seq of the loaded offsets, so that two queries
+    /// consuming the same topic with a consumer concurrently can not both store them.
+    pub async fn store(
+        &self,
+        tenant: &str,
+        consumer: &str,
+        topic: &str,
+        offsets: &BTreeMap<i32, i64>,
+    ) -> Result<()> {
+        let key = offsets_key(tenant, consumer, topic)?;
+        let kv_api = UserApiProvider::instance().get_meta_store_client();
+        let reply = kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(self.seq),
+                Operation::Update(serde_json::to_vec(offsets)?),
+                None,
+            ))
+            .await?;
+        if !reply.changed() {
+            return Err(ErrorCode::KafkaError(format!(
+                "offsets of topic {} of consumer {} have been changed by another query",
+                topic, consumer
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn offsets_key(tenant: &str, consumer: &str, topic: &str) -> Result<String> {
+    Ok(format!(
+        "{}/{}/{}/{}",
+        KAFKA_OFFSETS_KEY_PREFIX,
+        escape_for_key(tenant)?,
+        escape_for_key(consumer)?,
+        escape_for_key(topic)?
+    ))
+}
@@ -0,0 +1,175 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::avro::avro_schema::schema::Record;
+use common_arrow::arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use common_arrow::arrow::io::avro::read;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_expression::LegacyExpression;
+
+use crate::storages::fuse::table_functions::string_value;
+
+pub const FORMAT: &str = "format";
+pub const AVRO_SCHEMA: &str = "avro_schema";
+pub const CONSUMER: &str = "consumer";
+
+/// The format of the values of the records.
+#[derive(Clone, Debug)]
+pub enum KafkaFormat {
+    /// A json document, read as a VARIANT.
+    Json,
+    /// A datum of the record schema in the avro binary encoding, the fields of the
+    /// record are read as the columns.
+    Avro { record: Record, fields: Vec<Field> },
+}
+
+// the arguments of `read_kafka('<brokers>', '<topic>'[, '<key>=<value>', ...])`
+//
+// brokers,      the bootstrap brokers separated by commas, such as: `127.0.0.1:9092,127.0.0.1:9093`
+// topic,        the topic to read, all the partitions of it are read
+// format,       `json` (default) or `avro`
+// avro_schema,  the json of the avro record schema the values are written with, required by `avro`
+// consumer,     the name the offsets read are stored under, the topic is read from the earliest offsets and nothing is stored if absent
+#[derive(Clone, Debug)]
+pub struct KafkaOptions {
+    pub brokers: Vec<String>,
+    pub topic: String,
+    pub format: KafkaFormat,
+    pub consumer: Option<String>,
+}
+
+impl KafkaOptions {
+    pub fn from_args(table_func_name: &str, table_args: &[LegacyExpression]) -> Result<Self> {
+        if table_args.len() < 2 {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} requires the brokers and the topic as the first two arguments",
+                table_func_name
+            )));
+        }
+
+        let brokers = string_value(&table_args[0])?
+            .split(',')
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty())
+            .collect::<Vec<_>>();
+        if brokers.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} requires at least one broker",
+                table_func_name
+            )));
+        }
+        let topic = string_value(&table_args[1])?;
+
+        let mut options = BTreeMap::new();
+        for arg in &table_args[2..] {
+            let option = string_value(arg)?;
+            match option.split_once('=') {
+                Some((key, value)) => {
+                    options.insert(key.trim().to_lowercase(), value.trim().to_string());
+                }
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid option '{}' of {}, expecting '<key>=<value>'",
+                        option, table_func_name
+                    )));
+                }
+            }
+        }
+
+        let avro_schema = options.remove(AVRO_SCHEMA);
+        let format = match options.remove(FORMAT).map(|f| f.to_lowercase()).as_deref() {
+            None | Some("json") => KafkaFormat::Json,
+            Some("avro") => {
+                let avro_schema = avro_schema.ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "{} of format avro requires the option {}",
+                        table_func_name, AVRO_SCHEMA
+                    ))
+                })?;
+                parse_avro_format(&avro_schema)?
+            }
+            Some(other) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "unsupported format '{}' of {}, expecting json or avro",
+                    other, table_func_name
+                )));
+            }
+        };
+        let consumer = options.remove(CONSUMER);
+        if let Some(key) = options.keys().next() {
+            return Err(ErrorCode::BadArguments(format!(
+                "unknown option '{}' of {}",
+                key, table_func_name
+            )));
+        }
+
+        Ok(KafkaOptions {
+            brokers,
+            topic,
+            format,
+            consumer,
+        })
+    }
+
+    /// The columns of the records, `_topic`, `_partition`, `_offset`, `_key` and `_timestamp`,
+    /// followed by the `value` of the json format or the fields of the avro record.
+    pub fn schema(&self) -> Result<DataSchemaRef> {
+        let mut fields = vec![
+            DataField::new("_topic", Vu8::to_data_type()),
+            DataField::new("_partition", i32::to_data_type()),
+            DataField::new("_offset", i64::to_data_type()),
+            DataField::new_nullable("_key", Vu8::to_data_type()),
+            DataField::new("_timestamp", TimestampType::new_impl(3)),
+        ];
+        match &self.format {
+            KafkaFormat::Json => fields.push(DataField::new("value", VariantType::new_impl())),
+            KafkaFormat::Avro {
+                fields: avro_fields,
+                ..
+            } => {
+                for field in avro_fields {
+                    let field = DataField::from(field);
+                    if fields.iter().any(|f| f.name() == field.name()) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "field {} of the avro schema conflicts with the columns of the records",
+                            field.name()
+                        )));
+                    }
+                    fields.push(field);
+                }
+            }
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+fn parse_avro_format(avro_schema: &str) -> Result<KafkaFormat> {
+    let schema = serde_json::from_str::<AvroSchema>(avro_schema)
+        .map_err(|e| ErrorCode::BadArguments(format!("invalid {}: {}", AVRO_SCHEMA, e)))?;
+    match schema {
+        AvroSchema::Record(record) => {
+            let fields = read::infer_schema(&record)?.fields;
+            Ok(KafkaFormat::Avro { record, fields })
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "{} must be a record schema",
+            AVRO_SCHEMA
+        ))),
+    }
+}
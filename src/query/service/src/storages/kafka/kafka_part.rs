@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::PartInfo;
+use common_legacy_planners::PartInfoPtr;
+
+/// The records of a partition of the topic in the offsets `[start_offset, end_offset)`.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
+pub struct KafkaPartInfo {
+    pub topic: String,
+    pub partition: i32,
+    pub start_offset: i64,
+    pub end_offset: i64,
+}
+
+#[typetag::serde(name = "kafka")]
+impl PartInfo for KafkaPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<KafkaPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+impl KafkaPartInfo {
+    pub fn create(
+        topic: String,
+        partition: i32,
+        start_offset: i64,
+        end_offset: i64,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(KafkaPartInfo {
+            topic,
+            partition,
+            start_offset,
+            end_offset,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&KafkaPartInfo> {
+        match info.as_any().downcast_ref::<KafkaPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::LogicalError(
+                "Cannot downcast from PartInfo to KafkaPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::io::avro::avro_schema::file::Block;
+use common_arrow::arrow::io::avro::read;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rskafka::client::partition::PartitionClient;
+use rskafka::client::Client;
+use rskafka::record::RecordAndOffset;
+
+use super::kafka_options::KafkaFormat;
+use super::kafka_options::KafkaOptions;
+use super::kafka_part::KafkaPartInfo;
+use super::kafka_table::connect;
+use super::kafka_table::kafka_error;
+use super::kafka_table::partition_client;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
+use crate::sessions::TableContext;
+
+// The records of at most this size are fetched at a time.
+const FETCH_MAX_BYTES: i32 = 1024 * 1024;
+const FETCH_MAX_WAIT_MS: i32 = 500;
+
+// A partition being read, with the offset of the next record to fetch.
+struct PartitionReader {
+    client: PartitionClient,
+    topic: String,
+    partition: i32,
+    next_offset: i64,
+    end_offset: i64,
+}
+
+/// Reads the records of the partitions one by one, a block of the records fetched at a time.
+pub struct KafkaSource {
+    ctx: Arc<dyn TableContext>,
+    options: KafkaOptions,
+    schema: DataSchemaRef,
+    projected_schema: DataSchemaRef,
+    client: Option<Client>,
+    reader: Option<PartitionReader>,
+}
+
+impl KafkaSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        options: KafkaOptions,
+        schema: DataSchemaRef,
+        projected_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, KafkaSource {
+            ctx,
+            options,
+            schema,
+            projected_schema,
+            client: None,
+            reader: None,
+        })
+    }
+
+    async fn next_reader(&mut self) -> Result<Option<PartitionReader>> {
+        let partitions = self.ctx.try_get_partitions(1)?;
+        let part = match partitions.first() {
+            Some(part) => KafkaPartInfo::from_part(part)?,
+            None => return Ok(None),
+        };
+
+        if self.client.is_none() {
+            self.client = Some(connect(&self.options.brokers).await?);
+        }
+        let client = self.client.as_ref().unwrap();
+        Ok(Some(PartitionReader {
+            client: partition_client(client, &part.topic, part.partition).await?,
+            topic: part.topic.clone(),
+            partition: part.partition,
+            next_offset: part.start_offset,
+            end_offset: part.end_offset,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for KafkaSource {
+    const NAME: &'static str = "KafkaSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if !matches!(&self.reader, Some(r) if r.next_offset < r.end_offset) {
+                self.reader = self.next_reader().await?;
+            }
+            let reader = match &mut self.reader {
+                Some(reader) => reader,
+                None => return Ok(None),
+            };
+
+            let (records, _) = reader
+                .client
+                .fetch_records(reader.next_offset, 1..FETCH_MAX_BYTES, FETCH_MAX_WAIT_MS)
+                .await
+                .map_err(kafka_error)?;
+            // The records of a compressed batch before the offset are fetched too, and
+            // the offsets of the compacted topics may have gaps.
+            let records = records
+                .into_iter()
+                .filter(|r| r.offset >= reader.next_offset && r.offset < reader.end_offset)
+                .collect::<Vec<_>>();
+            match records.last() {
+                Some(last) => reader.next_offset = last.offset + 1,
+                None => {
+                    reader.next_offset = reader.end_offset;
+                    continue;
+                }
+            }
+
+            let block = records_to_block(
+                &self.options.format,
+                &self.schema,
+                &reader.topic,
+                reader.partition,
+                &records,
+            )?;
+            if !block.is_empty() {
+                return Ok(Some(block.resort(self.projected_schema.clone())?));
+            }
+        }
+    }
+}
+
+/// Builds a block of the schema of the table from the records of a partition.
+///
+/// The records without values, the tombstones of the compacted topics, are skipped.
+pub fn records_to_block(
+    format: &KafkaFormat,
+    schema: &DataSchemaRef,
+    topic: &str,
+    partition: i32,
+    records: &[RecordAndOffset],
+) -> Result<DataBlock> {
+    let records = records
+        .iter()
+        .filter_map(|r| r.record.value.as_ref().map(|value| (r, value)))
+        .collect::<Vec<_>>();
+    let num_rows = records.len();
+
+    let mut columns = vec![
+        Series::from_data(vec![topic.as_bytes().to_vec(); num_rows]),
+        Series::from_data(vec![partition; num_rows]),
+        Series::from_data(records.iter().map(|(r, _)| r.offset).collect::<Vec<_>>()),
+        Series::from_data(
+            records
+                .iter()
+                .map(|(r, _)| r.record.key.clone())
+                .collect::<Vec<_>>(),
+        ),
+        Series::from_data(
+            records
+                .iter()
+                .map(|(r, _)| r.record.timestamp.timestamp_millis() * 1000)
+                .collect::<Vec<_>>(),
+        ),
+    ];
+
+    let record_error = |r: &RecordAndOffset, e: &dyn std::fmt::Display| {
+        ErrorCode::KafkaError(format!(
+            "fail to read the record at offset {} of partition {} of topic {}: {}",
+            r.offset, partition, topic, e
+        ))
+    };
+    match format {
+        KafkaFormat::Json => {
+            let mut values = Vec::with_capacity(num_rows);
+            for (record, value) in &records {
+                let value = serde_json::from_slice::<serde_json::Value>(value)
+                    .map_err(|e| record_error(record, &e))?;
+                values.push(VariantValue::from(value));
+            }
+            columns.push(Series::from_data(values));
+        }
+        KafkaFormat::Avro { record, fields } => {
+            // The datums of the records are read as a block of an avro file.
+            let block = Block {
+                number_of_rows: num_rows,
+                data: records
+                    .iter()
+                    .flat_map(|(_, v)| v.iter().copied())
+                    .collect(),
+            };
+            let projection = vec![true; fields.len()];
+            let chunk =
+                read::deserialize(&block, fields, &record.fields, &projection).map_err(|e| {
+                    ErrorCode::KafkaError(format!(
+                        "fail to read the avro records of partition {} of topic {}: {}",
+                        partition, topic, e
+                    ))
+                })?;
+            let avro_schema = DataSchemaRefExt::create(schema.fields()[columns.len()..].to_vec());
+            let avro_block = DataBlock::from_chunk(&avro_schema, &chunk)?;
+            columns.extend(avro_block.columns().iter().cloned());
+        }
+    }
+
+    Ok(DataBlock::create(schema.clone(), columns))
+}
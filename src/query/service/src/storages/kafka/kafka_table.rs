@@ -0,0 +1,288 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::Projection;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use parking_lot::RwLock;
+use rskafka::client::partition::OffsetAt;
+use rskafka::client::partition::PartitionClient;
+use rskafka::client::Client;
+use rskafka::client::ClientBuilder;
+
+use super::kafka_offsets::KafkaOffsets;
+use super::kafka_options::KafkaOptions;
+use super::kafka_part::KafkaPartInfo;
+use super::kafka_source::KafkaSource;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::EmptySource;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const KAFKA_ENGINE: &str = "ReadKafka";
+
+/// Table function `read_kafka('<brokers>', '<topic>'[, '<key>=<value>', ...])`.
+///
+/// The records of all the partitions of the topic up to the latest offsets found
+/// while reading the partitions are read. With the option `consumer`, the reading
+/// starts from the offsets stored for the consumer, which are moved forward by
+/// [`KafkaTable::commit_offsets`] once an `INSERT INTO ... SELECT` from the function
+/// has been committed, so each record is appended to the table once.
+pub struct KafkaTable {
+    table_info: TableInfo,
+    table_args: Vec<LegacyExpression>,
+    options: KafkaOptions,
+
+    // The stored offsets of the consumer and the next offsets after the records
+    // read by `read_partitions`.
+    consumed: RwLock<Option<(KafkaOffsets, BTreeMap<i32, i64>)>>,
+}
+
+impl KafkaTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let table_args = table_args.unwrap_or_default();
+        let options = KafkaOptions::from_args(table_func_name, &table_args)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: options.schema()?,
+                engine: KAFKA_ENGINE.to_string(),
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(KafkaTable {
+            table_info,
+            table_args,
+            options,
+            consumed: RwLock::new(None),
+        }))
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&KafkaTable> {
+        tbl.as_any().downcast_ref::<KafkaTable>().ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "expects table of engine {}, but got {}",
+                KAFKA_ENGINE,
+                tbl.engine()
+            ))
+        })
+    }
+
+    /// Stores the offsets of the consumer after the records read by this query.
+    pub async fn commit_offsets(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let consumer = match &self.options.consumer {
+            Some(consumer) => consumer,
+            None => return Ok(()),
+        };
+        let consumed = self.consumed.write().take();
+        match consumed {
+            Some((stored, offsets)) if stored.offsets != offsets => {
+                stored
+                    .store(&ctx.get_tenant(), consumer, &self.options.topic, &offsets)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for KafkaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let topic = &self.options.topic;
+        let client = connect(&self.options.brokers).await?;
+        let partitions = client
+            .list_topics()
+            .await
+            .map_err(kafka_error)?
+            .into_iter()
+            .find(|t| &t.name == topic)
+            .map(|t| t.partitions)
+            .ok_or_else(|| ErrorCode::KafkaError(format!("topic {} not found", topic)))?;
+
+        let stored = match &self.options.consumer {
+            Some(consumer) => KafkaOffsets::load(&ctx.get_tenant(), consumer, topic).await?,
+            None => KafkaOffsets::default(),
+        };
+
+        let mut parts = Vec::with_capacity(partitions.len());
+        let mut offsets = BTreeMap::new();
+        let mut read_rows = 0;
+        for partition in partitions.iter() {
+            let partition_client = partition_client(&client, topic, *partition).await?;
+            let earliest = partition_client
+                .get_offset(OffsetAt::Earliest)
+                .await
+                .map_err(kafka_error)?;
+            let latest = partition_client
+                .get_offset(OffsetAt::Latest)
+                .await
+                .map_err(kafka_error)?;
+
+            // The records of the stored offset may have been removed by the retention.
+            let start = match stored.offsets.get(partition) {
+                Some(offset) => (*offset).max(earliest),
+                None => earliest,
+            };
+            if start < latest {
+                parts.push(KafkaPartInfo::create(
+                    topic.clone(),
+                    *partition,
+                    start,
+                    latest,
+                ));
+                read_rows += (latest - start) as usize;
+            }
+            offsets.insert(*partition, start.max(latest));
+        }
+
+        *self.consumed.write() = Some((stored, offsets));
+        let statistics = Statistics::new_estimated(read_rows, 0, parts.len(), partitions.len());
+        Ok((statistics, parts))
+    }
+
+    fn table_args(&self) -> Option<Vec<LegacyExpression>> {
+        Some(self.table_args.clone())
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if plan.parts.is_empty() {
+            let output = OutputPort::create();
+            pipeline.add_pipe(Pipe::SimplePipe {
+                inputs_port: vec![],
+                outputs_port: vec![output.clone()],
+                processors: vec![EmptySource::create(output)?],
+            });
+
+            return Ok(());
+        }
+
+        let projection = match plan.projections() {
+            Projection::Columns(indices) => indices,
+            Projection::InnerColumns(_) => {
+                return Err(ErrorCode::UnImplement(
+                    "does not support projection inner columns",
+                ));
+            }
+        };
+        let schema = self.table_info.schema();
+        let projected_schema = Arc::new(schema.project(&projection));
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for _index in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                KafkaSource::create(
+                    ctx.clone(),
+                    output,
+                    self.options.clone(),
+                    schema.clone(),
+                    projected_schema.clone(),
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+}
+
+impl TableFunction for KafkaTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+pub(super) async fn connect(brokers: &[String]) -> Result<Client> {
+    ClientBuilder::new(brokers.to_vec())
+        .build()
+        .await
+        .map_err(kafka_error)
+}
+
+pub(super) async fn partition_client(
+    client: &Client,
+    topic: &str,
+    partition: i32,
+) -> Result<PartitionClient> {
+    client
+        .partition_client(topic, partition)
+        .await
+        .map_err(kafka_error)
+}
+
+pub(super) fn kafka_error(e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::KafkaError(format!("kafka error: {}", e))
+}
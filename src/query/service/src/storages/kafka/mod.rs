@@ -0,0 +1,27 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod kafka_offsets;
+mod kafka_options;
+mod kafka_part;
+mod kafka_source;
+mod kafka_table;
+
+pub use kafka_offsets::KafkaOffsets;
+pub use kafka_options::KafkaFormat;
+pub use kafka_options::KafkaOptions;
+pub use kafka_part::KafkaPartInfo;
+pub use kafka_source::records_to_block;
+pub use kafka_table::KafkaTable;
+pub use kafka_table::KAFKA_ENGINE;
@@ -13,14 +13,20 @@
 // limitations under the License.
 
 pub mod cache;
+pub mod delta;
+pub mod external;
 pub use common_storages_fuse as fuse;
+pub mod iceberg;
+pub mod kafka;
 pub use common_storages_index as index;
+pub mod parquet;
 pub mod result;
 pub mod stage;
 mod storage_factory;
 mod storage_table;
 mod storage_table_read_plan;
 mod storage_table_read_wrap;
+pub mod stream;
 pub mod system;
 
 pub use common_catalog::table::NavigationPoint;
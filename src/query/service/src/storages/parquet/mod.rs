@@ -0,0 +1,34 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod parquet_part;
+mod parquet_partition;
+mod parquet_source;
+mod parquet_table;
+
+pub use parquet_part::parse_partition_value;
+pub use parquet_part::ParquetFile;
+pub use parquet_part::ParquetPartInfo;
+pub use parquet_part::DEFAULT_PARTITION;
+pub use parquet_partition::hive_partition_segments;
+pub use parquet_partition::infer_partition_fields;
+pub use parquet_partition::parse_partition_values;
+pub(crate) use parquet_partition::prune_partitioned_files;
+pub(crate) use parquet_table::list_files;
+pub(crate) use parquet_table::read_parquet_chunks;
+pub(crate) use parquet_table::read_row_group_partitions;
+pub(crate) use parquet_table::read_row_groups;
+pub use parquet_table::split_glob_path;
+pub use parquet_table::ParquetTable;
+pub use parquet_table::PARQUET_ENGINE;
@@ -0,0 +1,133 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataField;
+use common_datavalues::DataValue;
+use common_datavalues::TypeID;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::PartInfo;
+use common_legacy_planners::PartInfoPtr;
+
+/// The directory name of the partitions whose values are NULL, as hive writes them.
+pub const DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// A parquet file to be read.
+///
+/// The columns with values in `partition_values`, parsed from the path of the
+/// file, are not stored in the file, and filled with the values instead.
+///
+/// The columns are found in the file by the names of the fields, except the ones
+/// in `column_names`, whose names in the file differ, such as the delta tables of
+/// the column mapping.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParquetFile {
+    pub location: String,
+    pub size: u64,
+    pub partition_values: BTreeMap<String, String>,
+    pub column_names: BTreeMap<String, String>,
+}
+
+/// One row group of a parquet file.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
+pub struct ParquetPartInfo {
+    pub location: String,
+    pub file_size: u64,
+    pub row_group: usize,
+    pub num_rows: usize,
+    pub partition_values: BTreeMap<String, String>,
+    pub column_names: BTreeMap<String, String>,
+}
+
+#[typetag::serde(name = "parquet")]
+impl PartInfo for ParquetPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<ParquetPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+}
+
+impl ParquetPartInfo {
+    /// The name of the column of the field in the file.
+    pub fn column_name<'a>(&'a self, field_name: &'a str) -> &'a str {
+        self.column_names
+            .get(field_name)
+            .map(|name| name.as_str())
+            .unwrap_or(field_name)
+    }
+
+    pub fn create(file: &ParquetFile, row_group: usize, num_rows: usize) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(ParquetPartInfo {
+            location: file.location.clone(),
+            file_size: file.size,
+            row_group,
+            num_rows,
+            partition_values: file.partition_values.clone(),
+            column_names: file.column_names.clone(),
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&ParquetPartInfo> {
+        match info.as_any().downcast_ref::<ParquetPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::LogicalError(
+                "Cannot downcast from PartInfo to ParquetPartInfo.",
+            )),
+        }
+    }
+}
+
+/// Parses the value of a partition column in the path of a file, e.g. `2022` of `year=2022/`.
+pub fn parse_partition_value(field: &DataField, value: &str) -> Result<DataValue> {
+    if value == DEFAULT_PARTITION && field.is_nullable() {
+        return Ok(DataValue::Null);
+    }
+
+    let invalid = || {
+        ErrorCode::BadBytes(format!(
+            "invalid value '{}' of partition column {}",
+            value,
+            field.name()
+        ))
+    };
+    let type_id = remove_nullable(field.data_type()).data_type_id();
+    if type_id.is_string() {
+        Ok(DataValue::String(value.as_bytes().to_vec()))
+    } else if type_id.is_signed_integer() {
+        value.parse().map(DataValue::Int64).map_err(|_| invalid())
+    } else if type_id.is_unsigned_integer() {
+        value.parse().map(DataValue::UInt64).map_err(|_| invalid())
+    } else if type_id.is_floating() {
+        value.parse().map(DataValue::Float64).map_err(|_| invalid())
+    } else if type_id == TypeID::Boolean {
+        value.parse().map(DataValue::Boolean).map_err(|_| invalid())
+    } else {
+        Err(ErrorCode::UnImplement(format!(
+            "unsupported type {:?} of partition column {}",
+            field.data_type(),
+            field.name()
+        )))
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::ColumnStatistics;
+use common_legacy_planners::Extras;
+
+use crate::sessions::TableContext;
+use crate::storages::index::RangeFilter;
+use crate::storages::parquet::parquet_part::parse_partition_value;
+use crate::storages::parquet::parquet_part::ParquetFile;
+use crate::storages::parquet::parquet_part::DEFAULT_PARTITION;
+
+/// The `<column>=<value>` segments of the directories of the location, in the order of the
+/// path, e.g. `year=2022` and `month=10` of `/data/year=2022/month=10/0.parquet`.
+pub fn hive_partition_segments(location: &str) -> Vec<(&str, &str)> {
+    let dirs = match location.rfind('/') {
+        Some(pos) => &location[..pos],
+        None => "",
+    };
+    dirs.split('/')
+        .filter_map(|s| s.split_once('='))
+        .filter(|(column, _)| !column.is_empty())
+        .collect()
+}
+
+/// Parses the values of the partition columns from the directories of the location.
+pub fn parse_partition_values(
+    location: &str,
+    partition_columns: &[String],
+) -> Result<BTreeMap<String, String>> {
+    if partition_columns.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+
+    let segments = hive_partition_segments(location)
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let mut values = BTreeMap::new();
+    for column in partition_columns {
+        let value = segments.get(column.as_str()).ok_or_else(|| {
+            ErrorCode::TableInfoError(format!(
+                "file {} has no value of partition column {}",
+                location, column
+            ))
+        })?;
+        values.insert(column.clone(), value.to_string());
+    }
+    Ok(values)
+}
+
+/// Infers the partition columns from the hive style directories of the files under `dir`,
+/// e.g. `year` and `month` of `<dir>/year=2022/month=10/0.parquet`.
+///
+/// The columns are inferred only if all the files have the same columns in the same order,
+/// and the columns of the file schema are never taken as partition columns. A column is
+/// `Int64` if all its values are integers, otherwise `String`, and it's nullable if any of
+/// its values is the default partition of hive.
+pub fn infer_partition_fields(
+    dir: &str,
+    files: &[ParquetFile],
+    file_schema: &DataSchema,
+) -> Vec<DataField> {
+    let dir = dir.trim_matches('/');
+    let segments_of = |file: &ParquetFile| {
+        let location = file.location.trim_start_matches('/');
+        hive_partition_segments(location.strip_prefix(dir).unwrap_or(location))
+    };
+
+    let first = match files.first() {
+        Some(first) => segments_of(first),
+        None => return vec![],
+    };
+    let columns = first
+        .iter()
+        .map(|(column, _)| *column)
+        .filter(|column| !file_schema.has_field(column))
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return vec![];
+    }
+
+    let mut values: Vec<Vec<String>> = vec![Vec::with_capacity(files.len()); columns.len()];
+    for file in files {
+        let segments = segments_of(file)
+            .into_iter()
+            .filter(|(column, _)| !file_schema.has_field(column))
+            .collect::<Vec<_>>();
+        if segments.len() != columns.len()
+            || segments
+                .iter()
+                .zip(&columns)
+                .any(|((c, _), column)| c != column)
+        {
+            tracing::warn!(
+                "ignore the partition columns of the files, {} is not partitioned as {:?}",
+                file.location,
+                columns
+            );
+            return vec![];
+        }
+        for (index, (_, value)) in segments.into_iter().enumerate() {
+            values[index].push(value.to_string());
+        }
+    }
+
+    columns
+        .iter()
+        .zip(values.iter())
+        .map(|(column, values)| {
+            let nullable = values.iter().any(|v| v == DEFAULT_PARTITION);
+            let is_integer = values
+                .iter()
+                .filter(|v| *v != DEFAULT_PARTITION)
+                .all(|v| v.parse::<i64>().is_ok());
+            let data_type = match is_integer {
+                true => i64::to_data_type(),
+                false => Vu8::to_data_type(),
+            };
+            match nullable {
+                true => DataField::new_nullable(column, data_type),
+                false => DataField::new(column, data_type),
+            }
+        })
+        .collect()
+}
+
+/// Prunes the files by the filters on the partition columns, before any of the files is read.
+pub(crate) fn prune_partitioned_files(
+    ctx: &Arc<dyn TableContext>,
+    partition_schema: DataSchemaRef,
+    files: Vec<ParquetFile>,
+    push_downs: &Option<Extras>,
+) -> Result<Vec<ParquetFile>> {
+    let filters = match push_downs {
+        Some(extras) if !extras.filters.is_empty() => &extras.filters,
+        _ => return Ok(files),
+    };
+    if partition_schema.num_fields() == 0 {
+        return Ok(files);
+    }
+
+    let range_filter = RangeFilter::try_create(ctx.clone(), filters, partition_schema.clone())?;
+    let mut pruned = Vec::with_capacity(files.len());
+    for file in files {
+        let mut stats = HashMap::with_capacity(partition_schema.num_fields());
+        for (index, field) in partition_schema.fields().iter().enumerate() {
+            let value = parse_partition_value(field, &file.partition_values[field.name()])?;
+            let null_count = if value == DataValue::Null { 1 } else { 0 };
+            stats.insert(index as u32, ColumnStatistics {
+                min: value.clone(),
+                max: value,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: None,
+                histogram: None,
+            });
+        }
+
+        if range_filter.eval(&stats, 1)? {
+            pruned.push(file);
+        }
+    }
+    Ok(pruned)
+}
@@ -0,0 +1,324 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::parquet::read::to_deserializer;
+use common_arrow::arrow::io::parquet::read::RowGroupDeserializer;
+use common_arrow::parquet::metadata::FileMetaData;
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::PartInfoPtr;
+use opendal::Operator;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+use crate::sessions::TableContext;
+use crate::storages::parquet::parquet_part::parse_partition_value;
+use crate::storages::parquet::parquet_part::ParquetPartInfo;
+use crate::storages::parquet::parquet_table::read_file_meta;
+
+// The column chunks of each projected field, paired with their indexes in the row group.
+type ColumnChunks = Vec<(Vec<usize>, Vec<Vec<u8>>)>;
+
+enum State {
+    /// Read the column chunks of the row group
+    /// IO bound
+    ReadData(PartInfoPtr),
+
+    /// Deserialize blocks from the column chunks
+    /// CPU bound
+    Deserialize(PartInfoPtr, Arc<FileMetaData>, ColumnChunks),
+
+    Generated(VecDeque<DataBlock>),
+    Finish,
+}
+
+pub struct ParquetSource {
+    state: State,
+    ctx: Arc<dyn TableContext>,
+    scan_progress: Arc<Progress>,
+    output: Arc<OutputPort>,
+    operator: Operator,
+    schema: DataSchemaRef,
+    arrow_fields: Vec<Field>,
+    block_size: usize,
+    // Footer of the last read file, the row groups of a file are usually read one after another.
+    file_meta: Option<(String, Arc<FileMetaData>)>,
+}
+
+impl ParquetSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        let block_size = ctx.get_settings().get_max_block_size()? as usize;
+        let arrow_fields = schema.to_arrow().fields;
+        let mut partitions = ctx.try_get_partitions(1)?;
+        let state = match partitions.is_empty() {
+            true => State::Finish,
+            false => State::ReadData(partitions.remove(0)),
+        };
+
+        Ok(ProcessorPtr::create(Box::new(ParquetSource {
+            state,
+            ctx,
+            scan_progress,
+            output,
+            operator,
+            schema,
+            arrow_fields,
+            block_size,
+            file_meta: None,
+        })))
+    }
+
+    fn try_get_partitions(&mut self) -> Result<()> {
+        let mut partitions = self.ctx.try_get_partitions(1)?;
+        self.state = match partitions.is_empty() {
+            true => State::Finish,
+            false => State::ReadData(partitions.remove(0)),
+        };
+        Ok(())
+    }
+
+    async fn get_file_meta(&mut self, part: &ParquetPartInfo) -> Result<Arc<FileMetaData>> {
+        if let Some((location, file_meta)) = &self.file_meta {
+            if location == &part.location {
+                return Ok(file_meta.clone());
+            }
+        }
+
+        let file_meta =
+            Arc::new(read_file_meta(&self.operator, &part.location, part.file_size).await?);
+        self.file_meta = Some((part.location.clone(), file_meta.clone()));
+        Ok(file_meta)
+    }
+
+    async fn read_columns(
+        &self,
+        part: &ParquetPartInfo,
+        file_meta: &FileMetaData,
+    ) -> Result<ColumnChunks> {
+        let row_group = file_meta.row_groups.get(part.row_group).ok_or_else(|| {
+            ErrorCode::ParquetError(format!(
+                "row group {} not found in parquet file {}",
+                part.row_group, part.location
+            ))
+        })?;
+
+        let object = self.operator.object(&part.location);
+        let mut columns = Vec::with_capacity(self.arrow_fields.len());
+        for field in &self.arrow_fields {
+            // The partition columns are not stored in the file.
+            if part.partition_values.contains_key(&field.name) {
+                continue;
+            }
+
+            // Nested fields are stored in multiple column chunks.
+            let column_name = part.column_name(&field.name);
+            let indexes = row_group
+                .columns()
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.descriptor().path_in_schema[0].eq_ignore_ascii_case(column_name))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            if indexes.is_empty() {
+                return Err(ErrorCode::ParquetError(format!(
+                    "couldn't find column:{} in parquet file {}",
+                    column_name, part.location
+                )));
+            }
+
+            let chunks = futures::future::try_join_all(indexes.iter().map(|index| {
+                let (offset, length) = row_group.columns()[*index].byte_range();
+                object.range_read(offset..offset + length)
+            }))
+            .await?;
+            columns.push((indexes, chunks));
+        }
+        Ok(columns)
+    }
+
+    fn deserialize(
+        &self,
+        part: &ParquetPartInfo,
+        file_meta: &FileMetaData,
+        columns: ColumnChunks,
+    ) -> Result<VecDeque<DataBlock>> {
+        let row_group = &file_meta.row_groups[part.row_group];
+        let num_rows = row_group.num_rows();
+
+        let mut blocks = VecDeque::new();
+        if columns.is_empty() && !part.partition_values.is_empty() {
+            // Only the partition columns are read, there is nothing to deserialize.
+            let mut remaining_rows = num_rows;
+            while remaining_rows > 0 {
+                let rows = std::cmp::min(remaining_rows, self.block_size);
+                blocks.push_back(self.fill_partition_columns(part, DataBlock::empty(), rows)?);
+                remaining_rows -= rows;
+            }
+            return Ok(blocks);
+        }
+
+        let (fields, arrow_fields): (Vec<_>, Vec<_>) = self
+            .schema
+            .fields()
+            .iter()
+            .zip(self.arrow_fields.iter())
+            .filter(|(_, f)| !part.partition_values.contains_key(&f.name))
+            .unzip();
+        let read_schema = Arc::new(DataSchema::new(fields.into_iter().cloned().collect()));
+
+        let mut array_iters = Vec::with_capacity(columns.len());
+        for ((indexes, chunks), field) in columns.into_iter().zip(arrow_fields.into_iter()) {
+            let column_metas = indexes
+                .iter()
+                .map(|index| &row_group.columns()[*index])
+                .zip(chunks.into_iter())
+                .collect::<Vec<_>>();
+            array_iters.push(to_deserializer(
+                column_metas,
+                field.clone(),
+                num_rows,
+                Some(self.block_size),
+                None,
+            )?);
+        }
+
+        for chunk in RowGroupDeserializer::new(array_iters, num_rows, None) {
+            let chunk = chunk?;
+            if chunk.is_empty() {
+                break;
+            }
+            let block = DataBlock::from_chunk(&read_schema, &chunk)?;
+            blocks.push_back(self.fill_partition_columns(part, block, chunk.len())?);
+        }
+        Ok(blocks)
+    }
+
+    // Builds the block of the schema, taking the partition columns from the values of the part
+    // and the others from the block deserialized from the file.
+    fn fill_partition_columns(
+        &self,
+        part: &ParquetPartInfo,
+        block: DataBlock,
+        num_rows: usize,
+    ) -> Result<DataBlock> {
+        if part.partition_values.is_empty() {
+            return Ok(block);
+        }
+
+        let mut columns = Vec::with_capacity(self.schema.num_fields());
+        for field in self.schema.fields() {
+            let column = match part.partition_values.get(field.name()) {
+                Some(value) => {
+                    let value = parse_partition_value(field, value)?;
+                    field.data_type().create_constant_column(&value, num_rows)?
+                }
+                None => block.try_column_by_name(field.name())?.clone(),
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ParquetSource {
+    fn name(&self) -> &'static str {
+        "ParquetSource"
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let State::Generated(blocks) = &mut self.state {
+            match blocks.pop_front() {
+                Some(data_block) => {
+                    let progress_values = ProgressValues {
+                        rows: data_block.num_rows(),
+                        bytes: data_block.memory_size(),
+                    };
+                    self.scan_progress.incr(&progress_values);
+                    self.output.push_data(Ok(data_block));
+                    return Ok(Event::NeedConsume);
+                }
+                None => self.try_get_partitions()?,
+            }
+        }
+
+        match self.state {
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            State::ReadData(_) => Ok(Event::Async),
+            State::Deserialize(_, _, _) => Ok(Event::Sync),
+            State::Generated(_) => Err(ErrorCode::LogicalError("It's a bug.")),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::Deserialize(part, file_meta, columns) => {
+                let part = ParquetPartInfo::from_part(&part)?;
+                let blocks = self
+                    .deserialize(part, &file_meta, columns)
+                    .map_err(|e| e.add_message(format!(" parquet file {}", part.location)))?;
+                self.state = State::Generated(blocks);
+                Ok(())
+            }
+            _ => Err(ErrorCode::LogicalError("It's a bug.")),
+        }
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::ReadData(part) => {
+                let part_info = ParquetPartInfo::from_part(&part)?;
+                let file_meta = self.get_file_meta(part_info).await?;
+                let columns = self.read_columns(part_info, &file_meta).await?;
+                self.state = State::Deserialize(part, file_meta, columns);
+                Ok(())
+            }
+            _ => Err(ErrorCode::LogicalError("It's a bug.")),
+        }
+    }
+}
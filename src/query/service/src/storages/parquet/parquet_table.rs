@@ -0,0 +1,683 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::read_metadata_async;
+use common_arrow::arrow::io::parquet::read::statistics;
+use common_arrow::arrow::io::parquet::read::to_deserializer;
+use common_arrow::arrow::io::parquet::read::RowGroupDeserializer;
+use common_arrow::parquet::metadata::FileMetaData;
+use common_arrow::parquet::metadata::RowGroupMetaData;
+use common_arrow::parquet::read::read_metadata;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::IntoColumn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::ColumnStatistics;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::Projection;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::SourceInfo;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storage::init_operator;
+use common_storage::parse_uri_location;
+use common_storage::StorageParams;
+use common_storage::UriLocation;
+use common_users::UserApiProvider;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Operator;
+use regex::Regex;
+
+use crate::connections::resolve_stage_connection;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::EmptySource;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::TableContext;
+use crate::storages::fuse::table_functions::string_value;
+use crate::storages::index::RangeFilter;
+use crate::storages::parquet::parquet_part::ParquetFile;
+use crate::storages::parquet::parquet_part::ParquetPartInfo;
+use crate::storages::parquet::parquet_partition::infer_partition_fields;
+use crate::storages::parquet::parquet_partition::parse_partition_values;
+use crate::storages::parquet::parquet_partition::prune_partitioned_files;
+use crate::storages::parquet::parquet_source::ParquetSource;
+use crate::storages::stage::StageTable;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const PARQUET_ENGINE: &str = "ReadParquet";
+// The stage of the location found while inferring the schema, kept in the engine
+// options since the table may be rebuilt from the table args.
+const STAGE_OPTION: &str = "stage";
+// The max number of the footers read at the same time.
+const FOOTER_READ_CONCURRENCY: usize = 16;
+
+/// Table function `read_parquet('<uri>'[, '<key>=<value>', ...])` or
+/// `read_parquet('@<stage_name>[/<path>]')`.
+///
+/// The location may end with a glob pattern (`*` and `?`) on the file name, the
+/// remaining arguments are the connection options of the storage,
+/// e.g. `'aws_key_id=minioadmin'`. The files of a stage are read with the
+/// connection of the stage, so no connection options are allowed with it.
+pub struct ParquetTable {
+    table_info: TableInfo,
+    table_args: Vec<LegacyExpression>,
+    location: FileLocation,
+    // Files matched by the location with their sizes, listed while inferring the schema.
+    files: Vec<ParquetFile>,
+    // The columns inferred from the hive style directories of the files, which are not
+    // stored in the files.
+    partition_columns: Vec<String>,
+}
+
+// The location of the files, an uri with the connection options or a named stage.
+#[derive(Clone)]
+enum FileLocation {
+    Uri {
+        storage_params: StorageParams,
+        path: String,
+    },
+    // The stage is looked up in the meta service while inferring the schema.
+    Stage {
+        name: String,
+        path: String,
+    },
+}
+
+impl ParquetTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let table_args = table_args.unwrap_or_default();
+        if table_args.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} requires the location of the files as the first argument",
+                table_func_name
+            )));
+        }
+
+        let location = string_value(&table_args[0])?;
+        let mut connection = BTreeMap::new();
+        for arg in &table_args[1..] {
+            let option = string_value(arg)?;
+            match option.split_once('=') {
+                Some((key, value)) => {
+                    connection.insert(key.trim().to_lowercase(), value.trim().to_string());
+                }
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid connection option '{}' of {}, expecting '<key>=<value>'",
+                        option, table_func_name
+                    )));
+                }
+            }
+        }
+
+        let location = match location.strip_prefix('@') {
+            Some(stage) => {
+                if !connection.is_empty() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "connection options of {} are not allowed with stage '{}'",
+                        table_func_name, location
+                    )));
+                }
+                let (name, path) = stage.split_once('/').unwrap_or((stage, ""));
+                if name.is_empty() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid stage location '{}'",
+                        location
+                    )));
+                }
+                FileLocation::Stage {
+                    name: name.to_string(),
+                    path: path.to_string(),
+                }
+            }
+            None => {
+                let uri = parse_location(&location, connection)?;
+                let (storage_params, path) = parse_uri_location(&uri)?;
+                FileLocation::Uri {
+                    storage_params,
+                    path,
+                }
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Arc::new(DataSchema::empty()),
+                engine: PARQUET_ENGINE.to_string(),
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(ParquetTable {
+            table_info,
+            table_args,
+            location,
+            files: vec![],
+            partition_columns: vec![],
+        }))
+    }
+
+    /// Lists the files of the location, and returns a table whose schema is
+    /// inferred from the footer of the first file, followed by the partition
+    /// columns inferred from the `<column>=<value>` directories of the files.
+    pub async fn infer_schema(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let mut table_info = self.table_info.clone();
+        let (operator, path) = match &self.location {
+            FileLocation::Uri {
+                storage_params,
+                path,
+            } => {
+                if !storage_params.is_secure() && !ctx.get_config().storage.allow_insecure {
+                    return Err(ErrorCode::StorageInsecure(
+                        "read from insecure storage is not allowed",
+                    ));
+                }
+                (init_operator(storage_params)?, path)
+            }
+            FileLocation::Stage { name, path } => {
+                let stage = UserApiProvider::instance()
+                    .get_stage(&ctx.get_tenant(), name)
+                    .await?;
+                let stage =
+                    resolve_stage_connection(&ctx.get_config(), &ctx.get_tenant(), stage).await?;
+                let operator = StageTable::get_op(&ctx, &stage)?;
+                table_info
+                    .meta
+                    .engine_options
+                    .insert(STAGE_OPTION.to_string(), serde_json::to_string(&stage)?);
+                (operator, path)
+            }
+        };
+
+        let mut files = list_files(&operator, path)
+            .await?
+            .into_iter()
+            .map(|(location, size)| ParquetFile {
+                location,
+                size,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let first = files.first().ok_or_else(|| {
+            ErrorCode::BadArguments(format!("no parquet file matches '{}'", path))
+        })?;
+
+        let file_meta = read_file_meta(&operator, &first.location, first.size).await?;
+        let arrow_schema = infer_schema(&file_meta)?;
+        let file_schema = DataSchema::from(arrow_schema);
+
+        let (dir, _) = split_glob_path(path)?;
+        let partition_fields = infer_partition_fields(&dir, &files, &file_schema);
+        let partition_columns = partition_fields
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        for file in files.iter_mut() {
+            file.partition_values = parse_partition_values(&file.location, &partition_columns)?;
+        }
+
+        let mut fields = file_schema.fields().clone();
+        fields.extend(partition_fields);
+        table_info.meta.schema = Arc::new(DataSchema::new(fields));
+
+        Ok(Arc::new(ParquetTable {
+            table_info,
+            table_args: self.table_args.clone(),
+            location: self.location.clone(),
+            files,
+            partition_columns,
+        }))
+    }
+
+    // Gets the operator of the location, the stage is taken from the table info
+    // of the inferred schema.
+    fn get_operator(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        table_info: &TableInfo,
+    ) -> Result<Operator> {
+        match &self.location {
+            FileLocation::Uri { storage_params, .. } => Ok(init_operator(storage_params)?),
+            FileLocation::Stage { name, .. } => {
+                let stage = table_info
+                    .meta
+                    .engine_options
+                    .get(STAGE_OPTION)
+                    .ok_or_else(|| {
+                        ErrorCode::LogicalError(format!("stage '{}' is not resolved", name))
+                    })?;
+                StageTable::get_op(ctx, &serde_json::from_str(stage)?)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ParquetTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let operator = self.get_operator(&ctx, &self.table_info)?;
+        let schema = self.table_info.schema();
+        let (partition_fields, file_fields): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .cloned()
+            .partition(|f| self.partition_columns.contains(f.name()));
+
+        // The files are pruned by the partition columns before reading their footers.
+        let partition_schema = Arc::new(DataSchema::new(partition_fields));
+        let files =
+            prune_partitioned_files(&ctx, partition_schema, self.files.clone(), &push_downs)?;
+        let file_schema = Arc::new(DataSchema::new(file_fields));
+        read_row_group_partitions(ctx, &operator, &file_schema, &files, &push_downs).await
+    }
+
+    fn table_args(&self) -> Option<Vec<LegacyExpression>> {
+        Some(self.table_args.clone())
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let operator = match &plan.source_info {
+            SourceInfo::TableSource(table_info) => self.get_operator(&ctx, table_info)?,
+            SourceInfo::StageSource(_) => self.get_operator(&ctx, &self.table_info)?,
+        };
+        read_row_groups(ctx, operator, plan, pipeline)
+    }
+}
+
+impl TableFunction for ParquetTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+/// Builds the pipeline to read the row groups of the partitions of the plan.
+pub(crate) fn read_row_groups(
+    ctx: Arc<dyn TableContext>,
+    operator: Operator,
+    plan: &ReadDataSourcePlan,
+    pipeline: &mut Pipeline,
+) -> Result<()> {
+    if plan.parts.is_empty() {
+        let output = OutputPort::create();
+        pipeline.add_pipe(Pipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![EmptySource::create(output)?],
+        });
+
+        return Ok(());
+    }
+
+    // The table may be rebuilt from the table args while building the pipeline,
+    // so the schema is taken from the plan instead of the table info.
+    let projection = match plan.projections() {
+        Projection::Columns(indices) => indices,
+        Projection::InnerColumns(_) => {
+            return Err(ErrorCode::UnImplement(
+                "does not support projection inner columns",
+            ));
+        }
+    };
+    let schema = plan.source_info.schema();
+    let projected_schema = Arc::new(schema.project(&projection));
+
+    let max_threads = ctx.get_settings().get_max_threads()? as usize;
+    let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+
+    let mut source_builder = SourcePipeBuilder::create();
+    for _index in 0..std::cmp::max(1, max_threads) {
+        let output = OutputPort::create();
+        source_builder.add_source(
+            output.clone(),
+            ParquetSource::create(
+                ctx.clone(),
+                output,
+                operator.clone(),
+                projected_schema.clone(),
+            )?,
+        );
+    }
+
+    pipeline.add_pipe(source_builder.finalize());
+    Ok(())
+}
+
+fn parse_location(location: &str, connection: BTreeMap<String, String>) -> Result<UriLocation> {
+    // The location is not parsed as an url, since the glob wildcards are not valid in it.
+    let invalid = || ErrorCode::BadArguments(format!("invalid uri location '{}'", location));
+    let (protocol, rest) = location.split_once("://").ok_or_else(invalid)?;
+    let (name, path) = match rest.find('/') {
+        Some(pos) => (&rest[..pos], &rest[pos..]),
+        None => (rest, "/"),
+    };
+    if protocol.is_empty() || name.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(UriLocation {
+        protocol: protocol.to_lowercase(),
+        name: name.to_string(),
+        path: path.to_string(),
+        connection,
+    })
+}
+
+/// Splits the path into the directory to list, and the pattern that the listed
+/// files must match if the path contains glob wildcards.
+pub fn split_glob_path(path: &str) -> Result<(String, Option<Regex>)> {
+    let path = path.trim_start_matches('/');
+    let wildcard = match path.find(|c| c == '*' || c == '?') {
+        None => return Ok((path.to_string(), None)),
+        Some(pos) => pos,
+    };
+
+    let dir = match path[..wildcard].rfind('/') {
+        Some(pos) => &path[..=pos],
+        None => "",
+    };
+
+    let mut pattern = String::with_capacity(path.len() + 2);
+    pattern.push('^');
+    for c in path.chars() {
+        match c {
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    pattern.push('$');
+
+    let regex = Regex::new(&pattern)
+        .map_err(|e| ErrorCode::BadArguments(format!("invalid glob '{}': {}", path, e)))?;
+    Ok((dir.to_string(), Some(regex)))
+}
+
+/// Lists the files of the path and their sizes, the path may contain glob wildcards.
+pub(crate) async fn list_files(operator: &Operator, path: &str) -> Result<Vec<(String, u64)>> {
+    let (dir, pattern) = split_glob_path(path)?;
+
+    let mut files = vec![];
+    if pattern.is_none() && !dir.is_empty() && !dir.ends_with('/') {
+        let meta = operator.object(&dir).metadata().await?;
+        if !meta.mode().is_dir() {
+            files.push((dir, meta.content_length()));
+            return Ok(files);
+        }
+    }
+
+    let dir = if dir.is_empty() { "/".to_string() } else { dir };
+    let mut ds = operator.batch().walk_top_down(&dir)?;
+    while let Some(de) = ds.try_next().await? {
+        if !de.mode().is_file() {
+            continue;
+        }
+
+        let location = de.path().to_string();
+        let matched = match &pattern {
+            Some(pattern) => pattern.is_match(location.trim_start_matches('/')),
+            None => true,
+        };
+        if matched {
+            let meta = de.metadata().await?;
+            files.push((location, meta.content_length()));
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+pub(crate) async fn read_file_meta(
+    operator: &Operator,
+    location: &str,
+    size: u64,
+) -> Result<FileMetaData> {
+    let mut reader = operator.object(location).seekable_reader(..size);
+    read_metadata_async(&mut reader)
+        .await
+        .map_err(|e| ErrorCode::ParquetError(format!("read meta failed, {}, {:?}", location, e)))
+}
+
+/// Reads the columns of the fields from the parquet file in memory, found by name.
+pub(crate) fn read_parquet_chunks(
+    data: &[u8],
+    fields: &[Field],
+    block_size: usize,
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let file_meta = read_metadata(&mut Cursor::new(data))
+        .map_err(|e| ErrorCode::ParquetError(format!("read meta failed, {:?}", e)))?;
+
+    let mut chunks = vec![];
+    for row_group in &file_meta.row_groups {
+        let num_rows = row_group.num_rows();
+        let mut array_iters = Vec::with_capacity(fields.len());
+        for field in fields {
+            // Nested fields are stored in multiple column chunks.
+            let mut column_metas = vec![];
+            for column in row_group.columns() {
+                if !column.descriptor().path_in_schema[0].eq_ignore_ascii_case(&field.name) {
+                    continue;
+                }
+                let (offset, length) = column.byte_range();
+                let chunk = data
+                    .get(offset as usize..(offset + length) as usize)
+                    .ok_or_else(|| {
+                        ErrorCode::ParquetError(format!(
+                            "column chunk of column:{} out of the parquet file",
+                            field.name
+                        ))
+                    })?;
+                column_metas.push((column, chunk.to_vec()));
+            }
+            if column_metas.is_empty() {
+                return Err(ErrorCode::ParquetError(format!(
+                    "couldn't find column:{} in parquet file",
+                    field.name
+                )));
+            }
+
+            array_iters.push(to_deserializer(
+                column_metas,
+                field.clone(),
+                num_rows,
+                Some(block_size),
+                None,
+            )?);
+        }
+
+        for chunk in RowGroupDeserializer::new(array_iters, num_rows, None) {
+            let chunk = chunk?;
+            if chunk.is_empty() {
+                break;
+            }
+            chunks.push(chunk);
+        }
+    }
+    Ok(chunks)
+}
+
+/// Builds a partition for each row group of the files, the row groups are
+/// pruned by the filters of the push downs with the statistics in the footers.
+///
+/// The schema only contains the columns stored in the files, the filters on the
+/// partition columns are expected to be applied to the files already.
+pub(crate) async fn read_row_group_partitions(
+    ctx: Arc<dyn TableContext>,
+    operator: &Operator,
+    schema: &DataSchemaRef,
+    files: &[ParquetFile],
+    push_downs: &Option<Extras>,
+) -> Result<(Statistics, Partitions)> {
+    let arrow_schema = schema.to_arrow();
+    let range_filter = match push_downs {
+        Some(extras) if !extras.filters.is_empty() => Some(RangeFilter::try_create(
+            ctx.clone(),
+            &extras.filters,
+            schema.clone(),
+        )?),
+        _ => None,
+    };
+
+    // The footers are read concurrently, but not all at once for the globs of many files.
+    let mut file_metas = futures::stream::iter(files.iter().enumerate())
+        .map(|(index, file)| async move {
+            let file_meta = read_file_meta(operator, &file.location, file.size).await?;
+            Ok::<_, ErrorCode>((index, file_meta))
+        })
+        .buffer_unordered(FOOTER_READ_CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await?;
+    file_metas.sort_by_key(|(index, _)| *index);
+
+    let mut read_rows = 0;
+    let mut read_bytes = 0;
+    let mut partitions_total = 0;
+    let mut partitions = vec![];
+    for (file_index, file_meta) in file_metas.iter() {
+        let file = &files[*file_index];
+        for (index, row_group) in file_meta.row_groups.iter().enumerate() {
+            partitions_total += 1;
+            if let Some(range_filter) = &range_filter {
+                if !row_group_may_match(range_filter, schema, &arrow_schema, row_group) {
+                    continue;
+                }
+            }
+
+            read_rows += row_group.num_rows();
+            read_bytes += row_group.total_byte_size();
+            partitions.push(ParquetPartInfo::create(file, index, row_group.num_rows()));
+        }
+    }
+
+    let statistics =
+        Statistics::new_estimated(read_rows, read_bytes, partitions.len(), partitions_total);
+    Ok((statistics, partitions))
+}
+
+fn row_group_may_match(
+    range_filter: &RangeFilter,
+    schema: &DataSchemaRef,
+    arrow_schema: &ArrowSchema,
+    row_group: &RowGroupMetaData,
+) -> bool {
+    match row_group_statistics(schema, arrow_schema, row_group) {
+        Ok(stats) => range_filter
+            .eval(&stats, row_group.num_rows() as u64)
+            .unwrap_or(true),
+        Err(e) => {
+            tracing::warn!("ignore row group statistics of parquet file: {}", e);
+            true
+        }
+    }
+}
+
+// Collects the min/max statistics of the row group, keyed by the field index of the schema.
+// Columns without statistics or of types that can't be compared are skipped.
+fn row_group_statistics(
+    schema: &DataSchemaRef,
+    arrow_schema: &ArrowSchema,
+    row_group: &RowGroupMetaData,
+) -> Result<StatisticsOfColumns> {
+    let mut stats = HashMap::with_capacity(schema.num_fields());
+    for (index, field) in schema.fields().iter().enumerate() {
+        let type_id = remove_nullable(field.data_type()).data_type_id();
+        if !(type_id.is_numeric() || type_id.is_string() || type_id.is_date()) {
+            continue;
+        }
+
+        let column_stats =
+            statistics::deserialize(&arrow_schema.fields[index], std::slice::from_ref(row_group))?;
+        if column_stats.min_value.is_null(0) || column_stats.max_value.is_null(0) {
+            continue;
+        }
+
+        let null_count = if column_stats.null_count.is_null(0) {
+            0
+        } else {
+            column_stats.null_count.into_column().get(0).as_u64()?
+        };
+
+        stats.insert(index as u32, ColumnStatistics {
+            min: column_stats.min_value.into_column().get(0),
+            max: column_stats.max_value.into_column().get(0),
+            null_count,
+            in_memory_size: 0,
+            distinct_of_values: None,
+            histogram: None,
+        });
+    }
+    Ok(stats)
+}
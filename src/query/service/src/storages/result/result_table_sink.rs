@@ -187,6 +187,7 @@ impl Processor for ResultTableSink {
                 self.accumulator.add_block(
                     size,
                     meta_data,
+                    &data,
                     block_statistics,
                     bloom_index_location,
                     bloom_index_size,
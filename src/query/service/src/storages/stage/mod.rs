@@ -16,3 +16,4 @@ mod stage_table;
 mod stage_table_sink;
 
 pub use stage_table::StageTable;
+pub use stage_table_sink::UnloadedFile;
@@ -37,6 +37,7 @@ use parking_lot::Mutex;
 use tracing::info;
 
 use super::stage_table_sink::StageTableSink;
+use super::stage_table_sink::UnloadedFile;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
@@ -49,6 +50,8 @@ pub struct StageTable {
     // fn get_table_info(&self) -> &TableInfo).
     table_info_placeholder: TableInfo,
     input_context: Mutex<Option<Arc<InputContext>>>,
+    // Files written by the sinks of the table.
+    unloaded_files: Arc<Mutex<Vec<UnloadedFile>>>,
 }
 
 impl StageTable {
@@ -59,9 +62,17 @@ impl StageTable {
             table_info,
             table_info_placeholder,
             input_context: Default::default(),
+            unloaded_files: Default::default(),
         }))
     }
 
+    /// Returns the files written into the stage, sorted by path.
+    pub fn unloaded_files(&self) -> Vec<UnloadedFile> {
+        let mut files = self.unloaded_files.lock().clone();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files
+    }
+
     fn get_input_context(&self) -> Option<Arc<InputContext>> {
         let guard = self.input_context.lock();
         guard.clone()
@@ -158,6 +169,7 @@ impl Table for StageTable {
                     Some(output),
                     uuid.clone(),
                     gid,
+                    self.unloaded_files.clone(),
                 )
             })?;
         }
@@ -177,6 +189,7 @@ impl Table for StageTable {
                 None,
                 uuid,
                 gid,
+                self.unloaded_files.clone(),
             )?,
         );
 
@@ -29,9 +29,18 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use opendal::Operator;
+use parking_lot::Mutex;
 
 use crate::sessions::TableContext;
 
+/// A file written by `COPY INTO <location>`, returned as the result of the copy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnloadedFile {
+    pub path: String,
+    pub size: usize,
+    pub rows: usize,
+}
+
 #[derive(Debug)]
 enum State {
     None,
@@ -59,6 +68,10 @@ pub struct StageTableSink {
 
     single: bool,
     max_file_size: usize,
+
+    // Rows serialized into the working buffer.
+    num_rows: usize,
+    unloaded_files: Arc<Mutex<Vec<UnloadedFile>>>,
 }
 
 impl StageTableSink {
@@ -72,6 +85,7 @@ impl StageTableSink {
 
         uuid: String,
         group_id: usize,
+        unloaded_files: Arc<Mutex<Vec<UnloadedFile>>>,
     ) -> Result<ProcessorPtr> {
         let format_name = format!("{:?}", table_info.stage_info.file_format_options.format);
 
@@ -118,6 +132,8 @@ impl StageTableSink {
             group_id,
             batch_id: 0,
             max_file_size,
+            num_rows: 0,
+            unloaded_files,
         })))
     }
 
@@ -168,16 +184,18 @@ impl Processor for StageTableSink {
         }
 
         if self.input.is_finished() {
-            if self.output_format.buffer_size() > 0 {
-                let bs = self.output_format.finalize()?;
-                self.working_buffer.extend_from_slice(&bs);
-            }
+            // The formats writing out the blocks as they come, e.g. parquet, may hold the end of
+            // the file with nothing buffered.
+            let bs = self.output_format.finalize()?;
+            self.working_buffer.extend_from_slice(&bs);
             let data = std::mem::take(&mut self.working_buffer);
             if data.len() >= self.max_file_size || (!data.is_empty() && self.output.is_none()) {
                 self.state = State::NeedWrite(data, None);
                 self.working_datablocks.clear();
                 return Ok(Event::Async);
             }
+            // The rows are not written, but pushed to the next sink.
+            self.num_rows = 0;
 
             match (&self.output, self.working_datablocks.is_empty()) {
                 (Some(output), false) => {
@@ -222,6 +240,7 @@ impl Processor for StageTableSink {
 
                         let bs = self.output_format.serialize_block(&small_block)?;
                         self.working_buffer.extend_from_slice(bs.as_slice());
+                        self.num_rows += small_block.num_rows();
 
                         if self.working_buffer.len() + self.output_format.buffer_size()
                             >= self.max_file_size
@@ -243,6 +262,7 @@ impl Processor for StageTableSink {
                 } else {
                     let bs = self.output_format.serialize_block(&datablock)?;
                     self.working_buffer.extend_from_slice(bs.as_slice());
+                    self.num_rows += datablock.num_rows();
                 }
 
                 // hold this datablock
@@ -270,6 +290,11 @@ impl Processor for StageTableSink {
 
                 let object = self.data_accessor.object(&path);
                 object.write(bytes.as_slice()).await?;
+                self.unloaded_files.lock().push(UnloadedFile {
+                    path,
+                    size: bytes.len(),
+                    rows: std::mem::take(&mut self.num_rows),
+                });
 
                 match remainng_block {
                     Some(block) => self.state = State::NeedSerialize(block),
@@ -22,9 +22,13 @@ use common_meta_app::schema::TableInfo;
 use parking_lot::RwLock;
 
 use super::random::RandomTable;
+use crate::storages::delta::DeltaTable;
+use crate::storages::external::ExternalTable;
 use crate::storages::fuse::FuseTable;
+use crate::storages::iceberg::IcebergTable;
 use crate::storages::memory::MemoryTable;
 use crate::storages::null::NullTable;
+use crate::storages::stream::StreamTable;
 use crate::storages::view::ViewTable;
 use crate::storages::StorageContext;
 use crate::storages::Table;
@@ -98,6 +102,30 @@ impl StorageFactory {
             descriptor: Arc::new(ViewTable::description),
         });
 
+        // Register STREAM table engine
+        creators.insert("STREAM".to_string(), Storage {
+            creator: Arc::new(StreamTable::try_create),
+            descriptor: Arc::new(StreamTable::description),
+        });
+
+        // Register EXTERNAL table engine
+        creators.insert("EXTERNAL".to_string(), Storage {
+            creator: Arc::new(ExternalTable::try_create),
+            descriptor: Arc::new(ExternalTable::description),
+        });
+
+        // Register ICEBERG table engine
+        creators.insert("ICEBERG".to_string(), Storage {
+            creator: Arc::new(IcebergTable::try_create),
+            descriptor: Arc::new(IcebergTable::description),
+        });
+
+        // Register DELTA table engine
+        creators.insert("DELTA".to_string(), Storage {
+            creator: Arc::new(DeltaTable::try_create),
+            descriptor: Arc::new(DeltaTable::description),
+        });
+
         // Register RANDOM table engine
         creators.insert("RANDOM".to_string(), Storage {
             creator: Arc::new(RandomTable::try_create),
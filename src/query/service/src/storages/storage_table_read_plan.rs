@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_base::base::ProgressValues;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_exception::Result;
@@ -54,7 +55,15 @@ impl ToReadDataSourcePlan for dyn Table {
         catalog: String,
         push_downs: Option<Extras>,
     ) -> Result<ReadDataSourcePlan> {
-        let (statistics, parts) = self.read_partitions(ctx, push_downs.clone()).await?;
+        let (statistics, parts) = self
+            .read_partitions(ctx.clone(), push_downs.clone())
+            .await?;
+
+        // The estimated total of the scan, for the progress of the query in system.processes.
+        ctx.get_scan_progress_total().incr(&ProgressValues {
+            rows: statistics.read_rows,
+            bytes: statistics.read_bytes,
+        });
 
         let table_info = self.get_table_info();
         let table_meta = &table_info.meta;
@@ -47,7 +47,8 @@ impl<T: Table> TableStreamReadWrap for T {
 
         let settings = ctx.get_settings();
         pipeline.set_max_threads(settings.get_max_threads()? as usize);
-        let executor_settings = ExecutorSettings::try_create(&settings)?;
+        let executor_settings = ExecutorSettings::try_create(&settings)?
+            .with_query_memory_tracker(ctx.get_memory_tracker());
 
         let executor = PipelinePullingExecutor::try_create(pipeline, executor_settings)?;
         ctx.set_executor(Arc::downgrade(&executor.get_inner()));
@@ -67,7 +68,8 @@ impl TableStreamReadWrap for dyn Table {
 
         let settings = ctx.get_settings();
         pipeline.set_max_threads(settings.get_max_threads()? as usize);
-        let executor_settings = ExecutorSettings::try_create(&settings)?;
+        let executor_settings = ExecutorSettings::try_create(&settings)?
+            .with_query_memory_tracker(ctx.get_memory_tracker());
         let executor = PipelinePullingExecutor::try_create(pipeline, executor_settings)?;
         ctx.set_executor(Arc::downgrade(&executor.get_inner()));
         Ok(Box::pin(PullingExecutorStream::create(executor)?))
@@ -0,0 +1,211 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use parking_lot::RwLock;
+
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::fuse::io::IOPriority;
+use crate::storages::fuse::FuseTable;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+pub const OPT_KEY_TABLE_CATALOG: &str = "table_catalog";
+pub const OPT_KEY_TABLE_DATABASE: &str = "table_database";
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// Location of the base table snapshot that has already been consumed.
+pub const OPT_KEY_STREAM_OFFSET: &str = "stream_offset";
+
+/// A stream tracks the rows appended to a FUSE table since its offset.
+///
+/// Reading a stream returns the blocks of the segments that the offset snapshot
+/// does not reference. The offset is moved forward by [`StreamTable::advance`],
+/// which is committed with the rows of an `INSERT INTO ... SELECT` from the stream.
+pub struct StreamTable {
+    table_info: TableInfo,
+    table_catalog: String,
+    table_database: String,
+    table_name: String,
+    table_id: u64,
+    offset: Option<String>,
+
+    // Snapshot location of the base table observed by `read_partitions`.
+    consumed: RwLock<Option<String>>,
+}
+
+impl StreamTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let get_option = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::LogicalError(format!("Need `{}` when creating StreamTable", key))
+            })
+        };
+
+        // The streams created before the catalog of the base table is stored are of the default
+        // catalog, as the stream itself.
+        let table_catalog = options
+            .get(OPT_KEY_TABLE_CATALOG)
+            .cloned()
+            .unwrap_or_else(|| table_info.catalog().to_string());
+        let table_database = get_option(OPT_KEY_TABLE_DATABASE)?;
+        let table_name = get_option(OPT_KEY_TABLE_NAME)?;
+        let table_id = get_option(OPT_KEY_TABLE_ID)?.parse::<u64>().map_err(|e| {
+            ErrorCode::LogicalError(format!(
+                "Invalid `{}` of StreamTable: {}",
+                OPT_KEY_TABLE_ID, e
+            ))
+        })?;
+        let offset = options.get(OPT_KEY_STREAM_OFFSET).cloned();
+
+        Ok(Box::new(StreamTable {
+            table_info,
+            table_catalog,
+            table_database,
+            table_name,
+            table_id,
+            offset,
+            consumed: RwLock::new(None),
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&StreamTable> {
+        tbl.as_any().downcast_ref::<StreamTable>().ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "expects table of engine STREAM, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    async fn base_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let table = ctx
+            .get_table(&self.table_catalog, &self.table_database, &self.table_name)
+            .await?;
+        if table.get_id() != self.table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Base table {}.{} of stream {} has been dropped or recreated",
+                self.table_database, self.table_name, self.table_info.desc
+            )));
+        }
+        Ok(table)
+    }
+
+    /// Move the offset of the stream to the base table snapshot read by this query.
+    ///
+    /// In a transaction, the new offset is staged with the tables mutated, and committed in the
+    /// same meta transaction as them. Otherwise the update is conditioned on the version of the
+    /// stream, so that two queries consuming the same stream concurrently can not both advance it.
+    pub async fn advance(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        catalog: &str,
+        database: &str,
+    ) -> Result<()> {
+        let consumed = match self.consumed.read().clone() {
+            Some(loc) if Some(&loc) != self.offset.as_ref() => loc,
+            _ => return Ok(()),
+        };
+
+        if let Some(txn) = ctx.get_transaction() {
+            let mut table_info = self.table_info.clone();
+            table_info
+                .meta
+                .options
+                .insert(OPT_KEY_STREAM_OFFSET.to_string(), consumed);
+            return txn.stage_table(catalog, table_info);
+        }
+
+        let req = UpsertTableOptionReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            options: HashMap::from([(OPT_KEY_STREAM_OFFSET.to_string(), Some(consumed))]),
+        };
+        ctx.get_catalog(catalog)?
+            .upsert_table_option(&ctx.get_tenant(), database, req)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let table = self.base_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let result = fuse_table
+            .do_read_changes(ctx, self.offset.as_deref(), push_downs)
+            .await?;
+        *self.consumed.write() = fuse_table.snapshot_loc();
+        Ok(result)
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        // The partitions point to blocks of the base table, read them as the base table does.
+        let mut table_info = self.table_info.clone();
+        table_info.ident.table_id = self.table_id;
+        FuseTable::do_create(table_info, true)?.do_read2(
+            ctx,
+            plan,
+            pipeline,
+            IOPriority::Interactive,
+        )
+    }
+}
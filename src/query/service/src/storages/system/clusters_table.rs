@@ -39,11 +39,23 @@ impl SyncSystemTable for ClustersTable {
     }
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let cluster_nodes = ctx.get_cluster().get_nodes();
+        let cluster = ctx.get_cluster();
+        let mut cluster_nodes = cluster.get_nodes();
+        cluster_nodes.extend(cluster.get_draining_nodes());
 
         let mut names = MutableStringColumn::with_capacity(cluster_nodes.len());
         let mut addresses = MutableStringColumn::with_capacity(cluster_nodes.len());
         let mut addresses_port = MutablePrimitiveColumn::<u16>::with_capacity(cluster_nodes.len());
+        let mut versions = Vec::with_capacity(cluster_nodes.len());
+        let mut cpu_nums = Vec::with_capacity(cluster_nodes.len());
+        let mut last_heartbeats = Vec::with_capacity(cluster_nodes.len());
+        let mut running_queries = Vec::with_capacity(cluster_nodes.len());
+        let mut memory_usages = Vec::with_capacity(cluster_nodes.len());
+        let mut memory_cache_bytes = Vec::with_capacity(cluster_nodes.len());
+        let mut disk_cache_bytes = Vec::with_capacity(cluster_nodes.len());
+        let mut running_fragments = Vec::with_capacity(cluster_nodes.len());
+        let mut draining = Vec::with_capacity(cluster_nodes.len());
+        let mut drained = Vec::with_capacity(cluster_nodes.len());
 
         for cluster_node in &cluster_nodes {
             let (ip, port) = cluster_node.ip_port()?;
@@ -51,12 +63,41 @@ impl SyncSystemTable for ClustersTable {
             names.append_value(cluster_node.id.as_bytes());
             addresses.append_value(ip.as_bytes());
             addresses_port.append_value(port);
+            versions.push(cluster_node.binary_version.as_bytes().to_vec());
+            cpu_nums.push(cluster_node.cpu_nums);
+
+            // The node registered by an older version doesn't publish the stats.
+            let stats = &cluster_node.stats;
+            last_heartbeats.push(match stats.heartbeat_on {
+                0 => None,
+                heartbeat_on => Some(heartbeat_on as i64 * 1000),
+            });
+            running_queries.push(stats.running_queries);
+            memory_usages.push(stats.memory_usage);
+            memory_cache_bytes.push(stats.memory_cache_bytes);
+            disk_cache_bytes.push(stats.disk_cache_bytes);
+            running_fragments.push(stats.running_fragments);
+            draining.push(cluster_node.draining);
+            // As of the last heartbeat, the node is safe to terminate once it's drained.
+            drained.push(
+                cluster_node.draining && stats.running_queries == 0 && stats.running_fragments == 0,
+            );
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
             names.finish().arc(),
             addresses.finish().arc(),
             addresses_port.finish().arc(),
+            Series::from_data(versions),
+            Series::from_data(cpu_nums),
+            Series::from_data(last_heartbeats),
+            Series::from_data(running_queries),
+            Series::from_data(memory_usages),
+            Series::from_data(memory_cache_bytes),
+            Series::from_data(disk_cache_bytes),
+            Series::from_data(running_fragments),
+            Series::from_data(draining),
+            Series::from_data(drained),
         ]))
     }
 }
@@ -67,6 +108,16 @@ impl ClustersTable {
             DataField::new("name", Vu8::to_data_type()),
             DataField::new("host", Vu8::to_data_type()),
             DataField::new("port", u16::to_data_type()),
+            DataField::new("version", Vu8::to_data_type()),
+            DataField::new("cpu_nums", u64::to_data_type()),
+            DataField::new_nullable("last_heartbeat", TimestampType::new_impl(3)),
+            DataField::new("running_queries", u64::to_data_type()),
+            DataField::new("memory_usage", i64::to_data_type()),
+            DataField::new("memory_cache_bytes", u64::to_data_type()),
+            DataField::new("disk_cache_bytes", u64::to_data_type()),
+            DataField::new("running_fragments", u64::to_data_type()),
+            DataField::new("draining", bool::to_data_type()),
+            DataField::new("drained", bool::to_data_type()),
         ]);
 
         let table_info = TableInfo {
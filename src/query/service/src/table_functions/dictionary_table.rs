@@ -0,0 +1,219 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::sessions::LoadedDictionary;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+use crate::storages::fuse::table_functions::string_literal;
+use crate::storages::fuse::table_functions::string_value;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+const DICTIONARY: &str = "dictionary";
+
+/// `dictionary('<dictionary>')` reads the loaded rows of the dictionary, the columns are the
+/// columns of the dictionary.
+pub struct DictionaryTable {
+    table_info: TableInfo,
+    arg_dictionary_name: String,
+    // The primary key of the dictionary, known after it's loaded.
+    primary_key: String,
+}
+
+impl DictionaryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let arg_dictionary_name = match &table_args {
+            Some(args) if args.len() == 1 => string_value(&args[0])?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "expecting dictionary name (as one string literal), but got {:?}",
+                    table_args
+                )));
+            }
+        };
+
+        // The schema is of the dictionary, it's only known after the dictionary is loaded.
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: DataSchemaRefExt::create(vec![]),
+                engine: DICTIONARY.to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(DictionaryTable {
+            table_info,
+            arg_dictionary_name,
+            primary_key: String::new(),
+        }))
+    }
+
+    /// Loads the dictionary, and returns a table of the schema of the dictionary.
+    pub async fn load_schema(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let loaded = SessionManager::instance()
+            .dictionary_cache
+            .get(&ctx.get_tenant(), &self.arg_dictionary_name)
+            .await?;
+        let mut table_info = self.table_info.clone();
+        table_info.meta.schema = LoadedDictionary::schema(&loaded.meta)?;
+        Ok(Arc::new(DictionaryTable {
+            table_info,
+            arg_dictionary_name: self.arg_dictionary_name.clone(),
+            primary_key: loaded.meta.primary_key.clone(),
+        }))
+    }
+
+    pub fn dictionary_name(&self) -> &str {
+        &self.arg_dictionary_name
+    }
+
+    pub fn primary_key(&self) -> &str {
+        &self.primary_key
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DictionaryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<LegacyExpression>> {
+        Some(vec![string_literal(self.arg_dictionary_name.as_str())])
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(Pipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![DictionarySource::create(
+                ctx,
+                output,
+                self.table_info.schema(),
+                self.arg_dictionary_name.clone(),
+            )?],
+        });
+
+        Ok(())
+    }
+}
+
+impl TableFunction for DictionaryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct DictionarySource {
+    finished: bool,
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    dictionary_name: String,
+}
+
+impl DictionarySource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        dictionary_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, DictionarySource {
+            finished: false,
+            ctx,
+            schema,
+            dictionary_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for DictionarySource {
+    const NAME: &'static str = DICTIONARY;
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let loaded = SessionManager::instance()
+            .dictionary_cache
+            .get(&self.ctx.get_tenant(), &self.dictionary_name)
+            .await?;
+        // The dictionary may be replaced by another of the same name since the query is planned.
+        if LoadedDictionary::schema(&loaded.meta)? != self.schema {
+            return Err(ErrorCode::UnknownDictionary(format!(
+                "dictionary {} is changed during the query",
+                self.dictionary_name
+            )));
+        }
+        Ok(Some(loaded.block.clone()))
+    }
+}
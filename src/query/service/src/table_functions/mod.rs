@@ -13,13 +13,16 @@
 //  limitations under the License.
 
 mod async_crash_me;
+mod dictionary_table;
 mod memory_block_part;
 mod numbers_part;
 mod numbers_table;
 mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
+mod table_meta_history_table;
 
+pub use dictionary_table::DictionaryTable;
 pub use memory_block_part::generate_numbers_parts;
 pub use numbers_part::NumbersPartInfo;
 pub use numbers_table::NumbersTable;
@@ -27,8 +27,12 @@ use crate::storages::fuse::table_functions::ClusteringInformationTable;
 use crate::storages::fuse::table_functions::FuseBlockTable;
 use crate::storages::fuse::table_functions::FuseSegmentTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
+use crate::storages::kafka::KafkaTable;
+use crate::storages::parquet::ParquetTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
+use crate::table_functions::table_meta_history_table::TableMetaHistoryTable;
+use crate::table_functions::DictionaryTable;
 use crate::table_functions::NumbersTable;
 use crate::table_functions::TableFunction;
 
@@ -116,6 +120,26 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ClusteringInformationTable::create)),
         );
 
+        creators.insert(
+            "table_meta_history".to_string(),
+            (next_id(), Arc::new(TableMetaHistoryTable::create)),
+        );
+
+        creators.insert(
+            "dictionary".to_string(),
+            (next_id(), Arc::new(DictionaryTable::create)),
+        );
+
+        creators.insert(
+            "read_parquet".to_string(),
+            (next_id(), Arc::new(ParquetTable::create)),
+        );
+
+        creators.insert(
+            "read_kafka".to_string(),
+            (next_id(), Arc::new(KafkaTable::create)),
+        );
+
         creators.insert(
             "sync_crash_me".to_string(),
             (next_id(), Arc::new(SyncCrashMeTable::create)),
@@ -0,0 +1,250 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::format_data_type_sql;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::ReadDataSourcePlan;
+use common_legacy_planners::Statistics;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableMetaHistory;
+use common_meta_app::schema::TABLE_STATE_OPTION_KEYS;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::fuse::table_functions::string_literal;
+use crate::storages::fuse::table_functions::string_value;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+const TABLE_META_HISTORY: &str = "table_meta_history";
+
+/// `table_meta_history('<database>', '<table>')` lists the versions of the schema and the options
+/// of the table stored in the meta-service, one row of each version.
+pub struct TableMetaHistoryTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl TableMetaHistoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (arg_database_name, arg_table_name) = match &table_args {
+            Some(args) if args.len() == 2 => (string_value(&args[0])?, string_value(&args[1])?),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "expecting database and table name (as two string literals), but got {:?}",
+                    table_args
+                )));
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: TABLE_META_HISTORY.to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(TableMetaHistoryTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("version", u64::to_data_type()),
+            DataField::new("updated_on", TimestampType::new_impl(6)),
+            DataField::new("schema", Vu8::to_data_type()),
+            DataField::new("options", Vu8::to_data_type()),
+            DataField::new_nullable("cluster_key", Vu8::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TableMetaHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<LegacyExpression>> {
+        Some(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ])
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(Pipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![TableMetaHistorySource::create(
+                ctx,
+                output,
+                self.arg_database_name.clone(),
+                self.arg_table_name.clone(),
+            )?],
+        });
+
+        Ok(())
+    }
+}
+
+impl TableFunction for TableMetaHistoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct TableMetaHistorySource {
+    finished: bool,
+    ctx: Arc<dyn TableContext>,
+    database_name: String,
+    table_name: String,
+}
+
+impl TableMetaHistorySource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        database_name: String,
+        table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, TableMetaHistorySource {
+            finished: false,
+            ctx,
+            database_name,
+            table_name,
+        })
+    }
+
+    fn to_block(history: &TableMetaHistory) -> DataBlock {
+        let len = history.versions.len();
+        let mut versions = Vec::with_capacity(len);
+        let mut updated_ons = Vec::with_capacity(len);
+        let mut schemas = Vec::with_capacity(len);
+        let mut options = Vec::with_capacity(len);
+        let mut cluster_keys = Vec::with_capacity(len);
+        let mut comments = Vec::with_capacity(len);
+        for version in &history.versions {
+            let meta = &version.table_meta;
+            versions.push(version.version);
+            updated_ons.push(meta.updated_on.timestamp_micros());
+            schemas.push(
+                meta.schema
+                    .fields()
+                    .iter()
+                    .map(|f| format!("`{}` {}", f.name(), format_data_type_sql(f.data_type())))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+                    .into_bytes(),
+            );
+            // The options are sorted by the keys of the BTreeMap.
+            options.push(
+                meta.options
+                    .iter()
+                    .filter(|(k, _)| !TABLE_STATE_OPTION_KEYS.contains(&k.as_str()))
+                    .map(|(k, v)| format!("{}='{}'", k, v))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .into_bytes(),
+            );
+            cluster_keys.push(meta.default_cluster_key.clone().map(|k| k.into_bytes()));
+            comments.push(meta.comment.as_bytes().to_vec());
+        }
+
+        DataBlock::create(TableMetaHistoryTable::schema(), vec![
+            Series::from_data(versions),
+            Series::from_data(updated_ons),
+            Series::from_data(schemas),
+            Series::from_data(options),
+            Series::from_data(cluster_keys),
+            Series::from_data(comments),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TableMetaHistorySource {
+    const NAME: &'static str = TABLE_META_HISTORY;
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+        let table = catalog
+            .get_table(&tenant, &self.database_name, &self.table_name)
+            .await?;
+        let history = catalog.get_table_meta_history(table.get_id()).await?;
+        Ok(Some(Self::to_block(&history)))
+    }
+}
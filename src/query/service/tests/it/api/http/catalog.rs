@@ -0,0 +1,54 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_meta_api::CatalogDump;
+use databend_query::api::http::v1::catalog::catalog_dump_handler;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::Planner;
+use poem::get;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::Endpoint;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalog_dump() -> common_exception::Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql("create database db_dump").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter.execute(ctx.clone()).await?;
+
+    let router = Route::new().at("/v1/catalog/dump", get(catalog_dump_handler));
+    let response = router
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/catalog/dump"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().into_string().await.unwrap();
+    let dump = CatalogDump::parse(body.lines().map(|l| l.to_string())).unwrap();
+    assert!(dump.kvs.iter().any(|(k, _)| k.ends_with("/db_dump")));
+    assert!(dump.kvs.iter().all(|(_, v)| v.seq <= dump.max_seq));
+    Ok(())
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod catalog;
 mod cluster;
 mod config;
 mod logs;
+mod query_pprof;
 mod status;
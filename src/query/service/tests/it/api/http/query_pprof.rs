@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::api::http::debug::query_pprof::debug_query_pprof_handler;
+use poem::get;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::Endpoint;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::TestGlobalServices;
+
+#[tokio::test]
+async fn test_query_pprof_unknown_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(crate::tests::ConfigBuilder::create().build()).await?;
+
+    let test_router = Route::new().at(
+        "/debug/pprof/query/:query_id",
+        get(debug_query_pprof_handler),
+    );
+    let error = test_router
+        .call(
+            Request::builder()
+                .uri(Uri::from_static(
+                    "/debug/pprof/query/unknown_query?seconds=1",
+                ))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
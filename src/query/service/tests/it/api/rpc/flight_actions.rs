@@ -0,0 +1,30 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow_format::flight::data::Action;
+use common_exception::Result;
+use databend_query::api::FlightAction;
+
+#[test]
+fn test_kill_query_action() -> Result<()> {
+    let action: Action = FlightAction::KillQuery(String::from("query-1")).try_into()?;
+    assert_eq!(action.r#type, "KillQuery");
+
+    match action.try_into() {
+        Ok(FlightAction::KillQuery(query_id)) => assert_eq!(query_id, "query-1"),
+        other => panic!("unexpected action {:?}", other),
+    }
+
+    Ok(())
+}
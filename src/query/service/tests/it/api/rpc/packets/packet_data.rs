@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_base::base::tokio;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -21,6 +22,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::UInt8Column;
 use common_exception::Result;
+use databend_query::api::FragmentData;
 use databend_query::api::PrecommitBlock;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -37,3 +39,19 @@ async fn test_precommit_ser_and_deser() -> Result<()> {
     assert_eq!(test_precommit, PrecommitBlock::read(&mut read)?);
     Ok(())
 }
+
+#[test]
+fn test_fragment_data_ser_and_deser() -> Result<()> {
+    let data_schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", UInt8Type::new_impl()),
+        DataField::new("b", StringType::new_impl()),
+    ]));
+    let data_block = DataBlock::create(data_schema.clone(), vec![
+        Series::from_data(vec![1u8, 2, 3]),
+        Series::from_data(vec!["x", "y", "z"]),
+    ]);
+
+    let fragment_data = FragmentData::serialize(&data_block, &WriteOptions::default())?;
+    assert_eq!(data_block, fragment_data.deserialize(&data_schema)?);
+    Ok(())
+}
@@ -32,6 +32,11 @@ use crate::tests::tls_constants::TEST_CA_CERT;
 use crate::tests::tls_constants::TEST_CN_NAME;
 use crate::tests::tls_constants::TEST_SERVER_CERT;
 use crate::tests::tls_constants::TEST_SERVER_KEY;
+use crate::tests::tls_constants::TEST_TLS_CA_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_KEY;
+use crate::tests::tls_constants::TEST_TLS_SERVER_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_KEY;
 use crate::tests::ConfigBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -49,6 +54,7 @@ async fn test_tls_rpc_server() -> Result<()> {
     let tls_conf = Some(RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: TEST_CA_CERT.to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     });
 
     // normal case
@@ -67,6 +73,45 @@ async fn test_tls_rpc_server() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tls_rpc_server_client_certificate() -> Result<()> {
+    let mut rpc_service = RpcService::create(
+        ConfigBuilder::create()
+            .rpc_tls_server_key(TEST_TLS_SERVER_KEY)
+            .rpc_tls_server_cert(TEST_TLS_SERVER_CERT)
+            .rpc_tls_server_root_ca_cert(TEST_TLS_CA_CERT)
+            .build(),
+    )?;
+
+    let mut listener_address = SocketAddr::from_str("127.0.0.1:0")?;
+    listener_address = rpc_service.start(listener_address).await?;
+
+    // The client presenting a certificate signed by the CA is accepted.
+    let tls_conf = Some(RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        rpc_tls_client_cert: TEST_TLS_CLIENT_CERT.to_string(),
+        rpc_tls_client_key: TEST_TLS_CLIENT_KEY.to_string(),
+    });
+    let conn = ConnectionFactory::create_rpc_channel(listener_address, None, tls_conf).await?;
+    let mut f_client = FlightServiceClient::new(conn);
+    let r = f_client.list_actions(Empty {}).await;
+    assert!(r.is_ok());
+
+    // The client without a certificate is rejected.
+    let tls_conf = Some(RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
+    });
+    let conn = ConnectionFactory::create_rpc_channel(listener_address, None, tls_conf).await?;
+    let mut f_client = FlightServiceClient::new(conn);
+    let r = f_client.list_actions(Empty {}).await;
+    assert!(r.is_err());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tls_rpc_server_invalid_server_config() -> Result<()> {
     // setup, invalid cert locations
@@ -92,6 +137,7 @@ async fn test_tls_rpc_server_invalid_client_config() -> Result<()> {
     let client_conf = RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: "../tests/data/certs/nowhere.pem".to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     };
 
     let r = ConnectionFactory::create_rpc_channel("fake:1234", None, Some(client_conf)).await;
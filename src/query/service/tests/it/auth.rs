@@ -16,7 +16,11 @@ use base64::encode_config;
 use base64::URL_SAFE_NO_PAD;
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::NetworkPolicy;
 use common_meta_types::UserIdentity;
+use common_meta_types::UserInfo;
+use common_meta_types::UserOption;
 use common_users::CustomClaims;
 use common_users::EnsureUser;
 use common_users::UserApiProvider;
@@ -294,3 +298,275 @@ async fn test_jwt_auth_mgr_with_management() -> Result<()> {
         Ok(())
     }
 }
+
+fn jwks(keys: &[(&str, &RS256KeyPair)]) -> String {
+    let keys = keys
+        .iter()
+        .map(|(kid, key_pair)| {
+            let rsa_components = key_pair.public_key().to_components();
+            let e = encode_config(rsa_components.e, URL_SAFE_NO_PAD);
+            let n = encode_config(rsa_components.n, URL_SAFE_NO_PAD);
+            serde_json::json!({"kty": "RSA", "kid": kid, "e": e, "n": n, })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({ "keys": keys }).to_string()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_jwt_auth_mgr_with_claims_mapping() -> Result<()> {
+    let kid = "test_kid";
+    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    let template =
+        ResponseTemplate::new(200).set_body_raw(jwks(&[(kid, &key_pair)]), "application/json");
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template)
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.jwt_key_file = format!("http://{}{}", server.address(), json_path);
+    conf.query.jwt_user_claim = "email".to_string();
+    conf.query.jwt_role_claim = "db_role".to_string();
+    let (_guard, ctx) = crate::tests::create_query_context_with_config(conf, None).await?;
+    let auth_mgr = ctx.get_auth_manager();
+
+    // without the user claim
+    {
+        let claims = Claims::with_custom_claims(CustomClaims::new(), Duration::from_hours(2))
+            .with_subject("test".to_string());
+        let token = key_pair.sign(claims)?;
+
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                hostname: None,
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(
+            "Code: 1051, displayText = missing field `email` in jwt.",
+            res.err().unwrap().to_string()
+        );
+    }
+
+    // with the user claim and the role claim
+    {
+        let custom_claims = CustomClaims::new()
+            .with_claim("email", "test@example.com")
+            .with_claim("db_role", "test-auth-role")
+            .with_ensure_user(EnsureUser::default());
+        let claims = Claims::with_custom_claims(custom_claims, Duration::from_hours(2))
+            .with_subject("test".to_string());
+        let token = key_pair.sign(claims)?;
+
+        auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                hostname: None,
+            })
+            .await?;
+        let user_info = ctx.get_current_user()?;
+        assert_eq!(user_info.name, "test@example.com");
+
+        let roles = ctx.get_current_session().get_all_roles()?;
+        assert!(roles.contains(&"test-auth-role".to_string()));
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_jwt_auth_mgr_with_key_rotation() -> Result<()> {
+    let key_pair1 = RS256KeyPair::generate(2048)?.with_key_id("kid1");
+    let key_pair2 = RS256KeyPair::generate(2048)?.with_key_id("kid2");
+    let key_pair3 = RS256KeyPair::generate(2048)?.with_key_id("kid3");
+
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    // The keys before the rotation are returned only once.
+    let template =
+        ResponseTemplate::new(200).set_body_raw(jwks(&[("kid1", &key_pair1)]), "application/json");
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template)
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    let template = ResponseTemplate::new(200).set_body_raw(
+        jwks(&[("kid1", &key_pair1), ("kid2", &key_pair2)]),
+        "application/json",
+    );
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.jwt_key_file = format!("http://{}{}", server.address(), json_path);
+    let (_guard, ctx) = crate::tests::create_query_context_with_config(conf, None).await?;
+    let auth_mgr = ctx.get_auth_manager();
+
+    let sign = |key_pair: &RS256KeyPair| {
+        let custom_claims = CustomClaims::new().with_ensure_user(EnsureUser::default());
+        let claims = Claims::with_custom_claims(custom_claims, Duration::from_hours(2))
+            .with_subject("test".to_string());
+        key_pair.sign(claims)
+    };
+
+    // signed by the key before the rotation
+    auth_mgr
+        .auth(ctx.get_current_session(), &Credential::Jwt {
+            token: sign(&key_pair1)?,
+            hostname: None,
+        })
+        .await?;
+
+    // signed by the rotated key, the keys are reloaded
+    auth_mgr
+        .auth(ctx.get_current_session(), &Credential::Jwt {
+            token: sign(&key_pair2)?,
+            hostname: None,
+        })
+        .await?;
+
+    // signed by the unknown key, the keys are not reloaded again in the interval
+    let res = auth_mgr
+        .auth(ctx.get_current_session(), &Credential::Jwt {
+            token: sign(&key_pair3)?,
+            hostname: None,
+        })
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_ldap() -> Result<()> {
+    let tenant = "test";
+    let user_name = "ldap_user";
+    let credential = |password: Option<&str>| Credential::Password {
+        name: user_name.to_string(),
+        password: password.map(|p| p.as_bytes().to_vec()),
+        hostname: None,
+    };
+
+    // ldap not configured
+    {
+        let conf = crate::tests::ConfigBuilder::create().config();
+        let (_guard, ctx) = crate::tests::create_query_context_with_config(conf, None).await?;
+        UserApiProvider::instance()
+            .add_user(tenant, UserInfo::new(user_name, "%", AuthInfo::LDAP), false)
+            .await?;
+
+        let res = ctx
+            .get_auth_manager()
+            .auth(ctx.get_current_session(), &credential(Some("password")))
+            .await;
+        assert_eq!(
+            "Code: 1051, displayText = ldap auth not configured.",
+            res.err().unwrap().to_string()
+        );
+    }
+
+    // the password is required before connecting to the ldap server
+    {
+        let mut conf = crate::tests::ConfigBuilder::create().config();
+        conf.query.ldap_url = "ldap://127.0.0.1:1".to_string();
+        conf.query.ldap_search_base = "ou=people,dc=example,dc=com".to_string();
+        let (_guard, ctx) = crate::tests::create_query_context_with_config(conf, None).await?;
+        UserApiProvider::instance()
+            .add_user(tenant, UserInfo::new(user_name, "%", AuthInfo::LDAP), false)
+            .await?;
+        let auth_mgr = ctx.get_auth_manager();
+
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &credential(None))
+            .await;
+        assert_eq!(
+            "Code: 1051, displayText = password required.",
+            res.err().unwrap().to_string()
+        );
+
+        // the bind with the empty password is unauthenticated
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &credential(Some("")))
+            .await;
+        assert_eq!(
+            "Code: 1051, displayText = password required.",
+            res.err().unwrap().to_string()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_network_policy() -> Result<()> {
+    let tenant = "test";
+    let user_name = "np_user";
+    let credential = |hostname: Option<&str>| Credential::Password {
+        name: user_name.to_string(),
+        password: None,
+        hostname: hostname.map(|h| h.to_string()),
+    };
+
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let policy = NetworkPolicy::new(
+        "office",
+        vec!["192.168.0.0/16".to_string()],
+        vec!["192.168.1.99".to_string()],
+        "",
+    );
+    UserApiProvider::instance()
+        .add_network_policy(tenant, policy, false)
+        .await?;
+    let mut user_info = UserInfo::new(user_name, "%", AuthInfo::None);
+    user_info.option = UserOption::default().with_network_policy(Some("office".to_string()));
+    UserApiProvider::instance()
+        .add_user(tenant, user_info, false)
+        .await?;
+    let auth_mgr = ctx.get_auth_manager();
+
+    // the ip in the allowed list
+    auth_mgr
+        .auth(ctx.get_current_session(), &credential(Some("192.168.0.1")))
+        .await?;
+
+    // the ip in the blocked list
+    let res = auth_mgr
+        .auth(ctx.get_current_session(), &credential(Some("192.168.1.99")))
+        .await;
+    assert_eq!(
+        "Code: 2635, displayText = client ip 192.168.1.99 of user 'np_user' is blocked by network policy office.",
+        res.err().unwrap().to_string()
+    );
+
+    // the ip not in the allowed list
+    let res = auth_mgr
+        .auth(ctx.get_current_session(), &credential(Some("10.0.0.1")))
+        .await;
+    assert_eq!(
+        "Code: 2635, displayText = client ip 10.0.0.1 of user 'np_user' is not allowed by network policy office.",
+        res.err().unwrap().to_string()
+    );
+
+    // the policy used by the user can't be dropped
+    let res = UserApiProvider::instance()
+        .drop_network_policy(tenant, "office", false)
+        .await;
+    assert_eq!(
+        "Code: 2634, displayText = network policy office is used by user 'np_user'.",
+        res.err().unwrap().to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_meta_api::SchemaChange;
+use common_meta_app::schema::DBIdTableName;
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseIdent;
+use common_meta_app::schema::DatabaseInfo;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use databend_query::catalogs::default::DatabaseCache;
+
+fn db_info(db_id: u64, db_name: &str) -> Arc<DatabaseInfo> {
+    Arc::new(DatabaseInfo {
+        ident: DatabaseIdent { db_id, seq: 1 },
+        name_ident: DatabaseNameIdent {
+            tenant: "t1".to_string(),
+            db_name: db_name.to_string(),
+        },
+        meta: Default::default(),
+    })
+}
+
+fn table_info(table_id: u64, table_name: &str) -> Arc<TableInfo> {
+    Arc::new(TableInfo {
+        ident: TableIdent { table_id, seq: 1 },
+        name: table_name.to_string(),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_database_cache() {
+    let cache = DatabaseCache::default();
+
+    // Nothing is cached without a watch.
+    cache.insert(cache.version(), db_info(1, "db1"));
+    assert!(cache.get("t1", "db1").is_none());
+
+    cache.set_watching(true);
+    cache.insert(cache.version(), db_info(1, "db1"));
+    cache.insert(cache.version(), db_info(2, "db2"));
+    assert_eq!(Some(1), cache.get("t1", "db1").map(|d| d.ident.db_id));
+
+    // The changes of the tables keep the databases.
+    cache.invalidate(&SchemaChange::Table(TableId { table_id: 3 }));
+    assert!(cache.get("t1", "db1").is_some());
+
+    // A change of the name or of the meta removes the database.
+    cache.invalidate(&SchemaChange::DatabaseName(DatabaseNameIdent {
+        tenant: "t1".to_string(),
+        db_name: "db1".to_string(),
+    }));
+    assert!(cache.get("t1", "db1").is_none());
+    cache.invalidate(&SchemaChange::Database(DatabaseId { db_id: 2 }));
+    assert!(cache.get("t1", "db2").is_none());
+
+    // A database read before an invalidation is not cached.
+    let version = cache.version();
+    cache.invalidate(&SchemaChange::Database(DatabaseId { db_id: 1 }));
+    cache.insert(version, db_info(1, "db1"));
+    assert!(cache.get("t1", "db1").is_none());
+
+    // Stopping the watch clears the cache.
+    cache.insert(cache.version(), db_info(1, "db1"));
+    cache.set_watching(false);
+    assert!(cache.get("t1", "db1").is_none());
+}
+
+#[test]
+fn test_database_cache_tables() {
+    // The tables are cached only if enabled.
+    let cache = DatabaseCache::default();
+    cache.set_watching(true);
+    cache.insert_table(cache.version(), 1, table_info(10, "t1"));
+    assert!(cache.get_table(1, "t1").is_none());
+
+    let cache = DatabaseCache::create(true);
+    cache.set_watching(true);
+    cache.insert(cache.version(), db_info(1, "db1"));
+    cache.insert_table(cache.version(), 1, table_info(10, "t1"));
+    cache.insert_table(cache.version(), 1, table_info(11, "t2"));
+    cache.insert_table(cache.version(), 1, table_info(12, "t3"));
+    assert_eq!(Some(10), cache.get_table(1, "t1").map(|t| t.ident.table_id));
+
+    // A change of the name or of the meta removes the table.
+    cache.invalidate(&SchemaChange::TableName(DBIdTableName {
+        db_id: 1,
+        table_name: "t1".to_string(),
+    }));
+    assert!(cache.get_table(1, "t1").is_none());
+    cache.invalidate(&SchemaChange::Table(TableId { table_id: 11 }));
+    assert!(cache.get_table(1, "t2").is_none());
+
+    // A table read before an invalidation is not cached.
+    let version = cache.version();
+    cache.invalidate(&SchemaChange::Table(TableId { table_id: 10 }));
+    cache.insert_table(version, 1, table_info(10, "t1"));
+    assert!(cache.get_table(1, "t1").is_none());
+
+    // Renaming the database removes its tables.
+    assert!(cache.get_table(1, "t3").is_some());
+    cache.invalidate(&SchemaChange::DatabaseName(DatabaseNameIdent {
+        tenant: "t1".to_string(),
+        db_name: "db1".to_string(),
+    }));
+    assert!(cache.get_table(1, "t3").is_none());
+
+    // Stopping the watch clears the cache.
+    cache.insert_table(cache.version(), 1, table_info(12, "t3"));
+    cache.set_watching(false);
+    assert!(cache.get_table(1, "t3").is_none());
+}
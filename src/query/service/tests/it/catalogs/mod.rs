@@ -12,5 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod database_cache;
 mod database_catalog;
 mod immutable_catalogs;
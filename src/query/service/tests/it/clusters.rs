@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::base::tokio;
+use common_catalog::cluster_info::Cluster;
 use common_exception::Result;
+use common_meta_types::NodeInfo;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::clusters::ClusterHelper;
 use pretty_assertions::assert_eq;
@@ -66,6 +70,34 @@ async fn test_remove_invalid_nodes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_cluster_excludes_draining_nodes() -> Result<()> {
+    let node = |id: &str, draining: bool| {
+        let mut node = NodeInfo::create(id.to_string(), 8, format!("{}:9091", id));
+        node.draining = draining;
+        Arc::new(node)
+    };
+    let cluster = Cluster::create(
+        vec![
+            node("local", true),
+            node("node_1", false),
+            node("node_2", true),
+        ],
+        "local".to_string(),
+    );
+
+    // The local node still runs the queries coordinated by itself.
+    let nodes = cluster.get_nodes();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].id, "local");
+    assert_eq!(nodes[1].id, "node_1");
+
+    let draining_nodes = cluster.get_draining_nodes();
+    assert_eq!(draining_nodes.len(), 1);
+    assert_eq!(draining_nodes[0].id, "node_2");
+    Ok(())
+}
+
 // TODO:(Winter) need KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
@@ -31,10 +31,21 @@ config_file = ""
 [query]
 tenant_id = "admin"
 cluster_id = ""
+cluster_heartbeat_timeout_secs = 60
 num_cpus = 0
 mysql_handler_host = "127.0.0.1"
 mysql_handler_port = 3307
+mysql_handler_compression_enabled = true
+postgres_handler_host = "127.0.0.1"
+postgres_handler_port = 5433
+postgres_tls_server_cert = ""
+postgres_tls_server_key = ""
+flight_sql_handler_host = "127.0.0.1"
+flight_sql_handler_port = 8900
 max_active_sessions = 256
+session_idle_timeout_secs = 0
+session_max_lifetime_secs = 0
+max_connections_per_user = 0
 clickhouse_handler_host = "127.0.0.1"
 clickhouse_handler_port = 9000
 clickhouse_http_handler_host = "127.0.0.1"
@@ -43,18 +54,26 @@ http_handler_host = "127.0.0.1"
 http_handler_port = 8000
 http_handler_result_timeout_millis = 10000
 flight_api_address = "127.0.0.1:9090"
+flight_exchange_compression = "none"
+flight_exchange_credits = 16
 admin_api_address = "127.0.0.1:8080"
 metric_api_address = "127.0.0.1:7070"
 http_handler_tls_server_cert = ""
 http_handler_tls_server_key = ""
 http_handler_tls_server_root_ca_cert = ""
+mysql_tls_server_cert = ""
+mysql_tls_server_key = ""
+mysql_tls_server_root_ca_cert = ""
 api_tls_server_cert = ""
 api_tls_server_key = ""
 api_tls_server_root_ca_cert = ""
 rpc_tls_server_cert = ""
 rpc_tls_server_key = ""
+rpc_tls_server_root_ca_cert = ""
 rpc_tls_query_server_root_ca_cert = ""
 rpc_tls_query_service_domain_name = "localhost"
+rpc_tls_query_client_cert = ""
+rpc_tls_query_client_key = ""
 table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
@@ -65,12 +84,52 @@ table_cache_segment_count = 10240
 table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
 table_disk_cache_root = "_cache"
-table_disk_cache_mb_size = 1024
+table_disk_cache_mb_size = 0
 management_mode = false
+read_only = false
 jwt_key_file = ""
+jwt_key_refresh_interval_secs = 900
+jwt_user_claim = "sub"
+jwt_role_claim = "role"
+ldap_url = ""
+ldap_bind_dn_template = ""
+ldap_search_bind_dn = ""
+ldap_search_bind_password = ""
+ldap_search_base = ""
+ldap_search_filter = "(uid={user})"
+ldap_group_attribute = "memberOf"
+ldap_group_role_mapping = ""
+audit_log_enabled = false
+audit_log_filter = "auth,ddl,dml,privilege"
+audit_log_max_rows = 10000
+audit_log_retention_secs = 0
+connection_encryption_key = ""
+connection_kms_url = ""
+connection_kms_token = ""
+spill_local_disk_path = ""
+spill_query_quota_bytes = 0
+spill_encryption_enabled = false
+max_running_queries = 0
+max_reserved_memory_usage = 0
+max_queued_queries = 100
+query_queue_timeout_secs = 60
+query_result_cache_max_bytes = 268435456
+plan_cache_max_entries = 1024
+storage_io_max_requests = 64
+storage_io_coalesce_gap_bytes = 65536
+query_log_persistent_enabled = false
+query_log_flush_interval_secs = 10
+query_log_flush_batch_rows = 1000
 async_insert_max_data_size = 10000
+async_insert_max_rows = 10000
 async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
+auto_compaction_enabled = false
+auto_compaction_interval_secs = 300
+auto_compaction_min_excess_blocks = 100
+auto_compaction_max_bytes_per_run = 1073741824
+auto_compaction_max_threads = 2
+auto_compaction_window = ""
 users = []
 
 [log]
@@ -87,6 +146,11 @@ dir = "./.databend/logs"
 on = false
 level = "INFO"
 
+[log.tracing]
+on = false
+level = "INFO"
+otlp_endpoint = "http://127.0.0.1:4317"
+
 [meta]
 embedded_dir = "./.databend/meta_embedded"
 address = ""
@@ -432,6 +496,26 @@ fn test_env_config_gcs() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_invalid_cluster_heartbeat_timeout() -> Result<()> {
+    temp_env::with_vars(
+        vec![
+            ("QUERY_CLUSTER_HEARTBEAT_TIMEOUT_SECS", Some("0")),
+            ("CONFIG_FILE", None),
+        ],
+        || {
+            let err = Config::load().expect_err("zero heartbeat timeout must be rejected");
+            assert!(
+                err.message().contains("cluster_heartbeat_timeout_secs"),
+                "{}",
+                err.message()
+            );
+        },
+    );
+
+    Ok(())
+}
+
 /// Test whether override works as expected.
 #[test]
 fn test_override_config() -> Result<()> {
@@ -448,6 +532,9 @@ num_cpus = 0
 mysql_handler_host = "127.0.0.1"
 mysql_handler_port = 3307
 max_active_sessions = 256
+session_idle_timeout_secs = 0
+session_max_lifetime_secs = 0
+max_connections_per_user = 0
 clickhouse_handler_host = "127.0.0.1"
 clickhouse_handler_port = 9000
 clickhouse_http_handler_host = "127.0.0.1"
@@ -478,12 +565,52 @@ table_cache_segment_count = 10240
 table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
 table_disk_cache_root = "_cache"
-table_disk_cache_mb_size = 1024
+table_disk_cache_mb_size = 0
 management_mode = false
+read_only = false
 jwt_key_file = ""
+jwt_key_refresh_interval_secs = 900
+jwt_user_claim = "sub"
+jwt_role_claim = "role"
+ldap_url = ""
+ldap_bind_dn_template = ""
+ldap_search_bind_dn = ""
+ldap_search_bind_password = ""
+ldap_search_base = ""
+ldap_search_filter = "(uid={user})"
+ldap_group_attribute = "memberOf"
+ldap_group_role_mapping = ""
+audit_log_enabled = false
+audit_log_filter = "auth,ddl,dml,privilege"
+audit_log_max_rows = 10000
+audit_log_retention_secs = 0
+connection_encryption_key = ""
+connection_kms_url = ""
+connection_kms_token = ""
+spill_local_disk_path = ""
+spill_query_quota_bytes = 0
+spill_encryption_enabled = false
+max_running_queries = 0
+max_reserved_memory_usage = 0
+max_queued_queries = 100
+query_queue_timeout_secs = 60
+query_result_cache_max_bytes = 268435456
+plan_cache_max_entries = 1024
+storage_io_max_requests = 64
+storage_io_coalesce_gap_bytes = 65536
+query_log_persistent_enabled = false
+query_log_flush_interval_secs = 10
+query_log_flush_batch_rows = 1000
 async_insert_max_data_size = 10000
+async_insert_max_rows = 10000
 async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
+auto_compaction_enabled = false
+auto_compaction_interval_secs = 300
+auto_compaction_min_excess_blocks = 100
+auto_compaction_max_bytes_per_run = 1073741824
+auto_compaction_max_threads = 2
+auto_compaction_window = ""
 users = []
 
 [log]
@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::StorageParams;
+use common_users::UserApiProvider;
+use databend_query::connections::decrypt_connection_params;
+use databend_query::connections::encrypt_connection_params;
+use databend_query::connections::resolve_stage_connection;
+use databend_query::connections::OPT_KEY_STORAGE;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+
+use crate::tests::create_query_context_with_config;
+use crate::tests::ConfigBuilder;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_connection_params_encryption() -> Result<()> {
+    let params = BTreeMap::from([
+        (
+            "endpoint_url".to_string(),
+            "http://127.0.0.1:9900".to_string(),
+        ),
+        ("secret_access_key".to_string(), "minioadmin".to_string()),
+    ]);
+
+    // The secrets can't be kept without the key.
+    let conf = ConfigBuilder::create().build();
+    let res = encrypt_connection_params(&conf, params.clone()).await;
+    assert!(res.is_err());
+
+    let mut conf = ConfigBuilder::create().build();
+    conf.query.connection_encryption_key = "test_key".to_string();
+    let encrypted = encrypt_connection_params(&conf, params.clone()).await?;
+    assert_eq!(encrypted["endpoint_url"], "http://127.0.0.1:9900");
+    assert!(encrypted["secret_access_key"].starts_with("v1:"));
+    assert_ne!(encrypted["secret_access_key"], "minioadmin");
+
+    let decrypted = decrypt_connection_params(&conf, encrypted.clone()).await?;
+    assert_eq!(decrypted, params);
+
+    // The secrets encrypted by another key can't be decrypted.
+    conf.query.connection_encryption_key = "another_key".to_string();
+    let res = decrypt_connection_params(&conf, encrypted).await;
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::illegal_connection_format_code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_connection_resolved_at_read_time() -> Result<()> {
+    let mut conf = ConfigBuilder::create().build();
+    conf.query.connection_encryption_key = "test_key".to_string();
+    let (_guard, ctx) = create_query_context_with_config(conf.clone(), None).await?;
+    let tenant = ctx.get_tenant();
+
+    let create_connection = |secret: &str| {
+        format!(
+            "CREATE CONNECTION c1 STORAGE_TYPE = 's3' endpoint_url = 'http://127.0.0.1:9900' \
+             access_key_id = 'ak' secret_access_key = '{}'",
+            secret
+        )
+    };
+    execute(&ctx, &create_connection("sk1")).await?;
+    execute(
+        &ctx,
+        "CREATE STAGE s1 url = 's3://testbucket/data/' connection = (connection_name = 'c1')",
+    )
+    .await?;
+    execute(
+        &ctx,
+        "CREATE EXTERNAL TABLE t1 (a INT) LOCATION = 's3://testbucket/data/' \
+         connection = (connection_name = 'c1') FILE_FORMAT = (type = 'CSV')",
+    )
+    .await?;
+
+    // The parameters of the connection are not kept.
+    let stage = UserApiProvider::instance().get_stage(&tenant, "s1").await?;
+    assert!(stage.stage_params.location.is_some());
+    match &stage.stage_params.storage {
+        StorageParams::S3(s3) => {
+            assert_eq!(s3.access_key_id, "");
+            assert_eq!(s3.secret_access_key, "");
+        }
+        other => panic!("unexpected storage {:?}", other),
+    }
+    let catalog = ctx.get_catalog("default")?;
+    let table = catalog.get_table(&tenant, "default", "t1").await?;
+    assert!(!table.get_table_info().engine_options()[OPT_KEY_STORAGE].contains("sk1"));
+
+    // The connection is resolved each time the storage is used, by its current parameters.
+    execute(&ctx, "DROP CONNECTION c1").await?;
+    execute(&ctx, &create_connection("sk2")).await?;
+
+    let stage = resolve_stage_connection(&conf, &tenant, stage).await?;
+    match &stage.stage_params.storage {
+        StorageParams::S3(s3) => {
+            assert_eq!(s3.endpoint_url, "http://127.0.0.1:9900");
+            assert_eq!(s3.access_key_id, "ak");
+            assert_eq!(s3.secret_access_key, "sk2");
+        }
+        other => panic!("unexpected storage {:?}", other),
+    }
+    let table = ctx.get_table("default", "default", "t1").await?;
+    assert!(table.get_table_info().engine_options()[OPT_KEY_STORAGE].contains("sk2"));
+
+    Ok(())
+}
+
+async fn execute(ctx: &Arc<QueryContext>, sql: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter.execute(ctx.clone()).await?;
+    Ok(())
+}
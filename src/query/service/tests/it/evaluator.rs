@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use common_base::base::tokio;
-use common_datavalues::DataValue;
-use common_datavalues::Float64Type;
-use common_datavalues::Int32Type;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::Result;
+use databend_query::evaluator::AdaptiveFilter;
 use databend_query::evaluator::Evaluator;
+use databend_query::evaluator::FusedPredicate;
 use databend_query::sessions::TableContext;
+use databend_query::sql::executor::PhysicalScalar;
 use databend_query::sql::plans::ConstantExpr;
 use databend_query::sql::plans::FunctionCall;
 use databend_query::sql::plans::Scalar;
@@ -68,3 +70,213 @@ async fn test_eval_const() -> Result<()> {
     assert_eq!(result_type, Float64Type::new_impl());
     Ok(())
 }
+
+fn column(index: usize, data_type: DataTypeImpl) -> PhysicalScalar {
+    PhysicalScalar::IndexedVariable {
+        index,
+        data_type,
+        display_name: format!("c{}", index),
+    }
+}
+
+fn function(name: &str, args: Vec<PhysicalScalar>) -> PhysicalScalar {
+    typed_function(name, args, BooleanType::new_impl())
+}
+
+fn typed_function(
+    name: &str,
+    args: Vec<PhysicalScalar>,
+    return_type: DataTypeImpl,
+) -> PhysicalScalar {
+    PhysicalScalar::Function {
+        name: name.to_string(),
+        args: args
+            .into_iter()
+            .map(|arg| {
+                let data_type = arg.data_type();
+                (arg, data_type)
+            })
+            .collect(),
+        return_type,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_eval_fused_predicate() -> Result<()> {
+    let int32 = Int32Type::new_impl();
+    let ten = PhysicalScalar::Constant {
+        value: DataValue::Int64(10),
+        data_type: int32.clone(),
+    };
+    // (c0 > 10 AND NOT (c0 = c1)) OR 10 >= c1
+    let predicate = function("or", vec![
+        function("and", vec![
+            function(">", vec![column(0, int32.clone()), ten.clone()]),
+            function("not", vec![function("=", vec![
+                column(0, int32.clone()),
+                column(1, int32.clone()),
+            ])]),
+        ]),
+        function(">=", vec![ten, column(1, int32.clone())]),
+    ]);
+
+    let (_guard, ctx) = create_query_context().await?;
+    let func_ctx = ctx.try_get_function_context()?;
+    let fused = FusedPredicate::try_create(&predicate).unwrap();
+    let eval = Evaluator::eval_physical_scalar(&predicate)?;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c0", int32.clone()),
+        DataField::new("c1", int32.clone()),
+    ]);
+    // more than one chunk of 64 rows, with a partial one
+    let rows = 150;
+    let block = DataBlock::create(schema, vec![
+        Series::from_data((0..rows).map(|i| i % 20).collect::<Vec<i32>>()),
+        Series::from_data((0..rows).map(|i| (i * 7) % 23).collect::<Vec<i32>>()),
+    ]);
+
+    let result = fused.eval(&block).unwrap();
+    let expect = eval.eval(&func_ctx, &block)?;
+    let expect: &BooleanColumn = Series::check_get(expect.vector())?;
+    assert_eq!(
+        result.values().iter().collect::<Vec<_>>(),
+        expect.values().iter().collect::<Vec<_>>()
+    );
+
+    // the nullable columns are left to the interpreter
+    let nullable_schema = DataSchemaRefExt::create(vec![
+        DataField::new("c0", NullableType::new_impl(int32.clone())),
+        DataField::new("c1", int32.clone()),
+    ]);
+    let nullable_block = DataBlock::create(nullable_schema, vec![
+        Series::from_data(vec![Some(1i32), None]),
+        Series::from_data(vec![1i32, 2]),
+    ]);
+    assert!(fused.eval(&nullable_block).is_none());
+
+    // so are the functions not fused
+    let plus = PhysicalScalar::Function {
+        name: "plus".to_string(),
+        args: vec![
+            (column(0, int32.clone()), int32.clone()),
+            (column(1, int32.clone()), int32.clone()),
+        ],
+        return_type: Int64Type::new_impl(),
+    };
+    let predicate = function("<", vec![plus, column(0, int32)]);
+    assert!(FusedPredicate::try_create(&predicate).is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_adaptive_filter() -> Result<()> {
+    let int32 = Int32Type::new_impl();
+    let constant = |value: i64| PhysicalScalar::Constant {
+        value: DataValue::Int64(value),
+        data_type: int32.clone(),
+    };
+    // c0 >= 0 keeps all the rows, c1 < 5 filters out most of them
+    let conjuncts = vec![
+        function(">=", vec![column(0, int32.clone()), constant(0)]),
+        function("<", vec![column(1, int32.clone()), constant(5)]),
+    ];
+
+    let (_guard, ctx) = create_query_context().await?;
+    let func_ctx = ctx.try_get_function_context()?;
+    let mut filter = AdaptiveFilter::create(Evaluator::eval_physical_scalars(&conjuncts)?);
+    assert_eq!(filter.order(), &[0, 1]);
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c0", int32.clone()),
+        DataField::new("c1", int32),
+    ]);
+    // the blocks sampled and the blocks filtered in the measured order
+    for block_index in 0..8 {
+        let rows = 100;
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(
+                (0..rows)
+                    .map(|i| block_index * rows + i)
+                    .collect::<Vec<i32>>(),
+            ),
+            Series::from_data((0..rows).map(|i| (i * 7) % 23).collect::<Vec<i32>>()),
+        ]);
+
+        let result = filter.filter(&func_ctx, block)?;
+        let expect = (0..rows)
+            .filter(|i| (i * 7) % 23 < 5)
+            .map(|i| block_index * rows + i)
+            .collect::<Vec<i32>>();
+        let c0: &Int32Column = Series::check_get(result.column(0))?;
+        assert_eq!(c0.values(), expect.as_slice());
+    }
+    assert_eq!(filter.order(), &[1, 0]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_eval_conditional_short_circuit() -> Result<()> {
+    let int32 = Int32Type::new_impl();
+    let constant = |value: i64| PhysicalScalar::Constant {
+        value: DataValue::Int64(value),
+        data_type: int32.clone(),
+    };
+    // 10 div c0 fails on the rows where c0 = 0
+    let div = typed_function(
+        "div",
+        vec![constant(10), column(0, int32.clone())],
+        int32.clone(),
+    );
+
+    let (_guard, ctx) = create_query_context().await?;
+    let func_ctx = ctx.try_get_function_context()?;
+    let schema = DataSchemaRefExt::create(vec![DataField::new("c0", int32.clone())]);
+    let block = DataBlock::create(schema, vec![Series::from_data(vec![0i32, 1, 2, 0, 5])]);
+
+    // CASE WHEN c0 = 0 THEN -1 ELSE 10 div c0 END
+    let case = typed_function(
+        "multi_if",
+        vec![
+            function("=", vec![column(0, int32.clone()), constant(0)]),
+            constant(-1),
+            div.clone(),
+        ],
+        int32.clone(),
+    );
+    let result = Evaluator::eval_physical_scalar(&case)?.eval(&func_ctx, &block)?;
+    let result: &Int32Column = Series::check_get(result.vector())?;
+    assert_eq!(result.values(), &[-1, 10, 5, -1, 2]);
+
+    // c0 <> 0 AND 10 div c0 > 2
+    let and = function("and", vec![
+        function("<>", vec![column(0, int32.clone()), constant(0)]),
+        function(">", vec![div.clone(), constant(2)]),
+    ]);
+    let result = Evaluator::eval_physical_scalar(&and)?.eval(&func_ctx, &block)?;
+    let result = DataBlock::cast_to_nonull_boolean(result.vector())?.convert_full_column();
+    let result: &BooleanColumn = Series::check_get(&result)?;
+    assert_eq!(result.values().iter().collect::<Vec<_>>(), vec![
+        false, true, true, false, false
+    ]);
+
+    // c0 = 0 OR 10 div c0 > 2
+    let or = function("or", vec![
+        function("=", vec![column(0, int32.clone()), constant(0)]),
+        function(">", vec![div.clone(), constant(2)]),
+    ]);
+    let result = Evaluator::eval_physical_scalar(&or)?.eval(&func_ctx, &block)?;
+    let result = DataBlock::cast_to_nonull_boolean(result.vector())?.convert_full_column();
+    let result: &BooleanColumn = Series::check_get(&result)?;
+    assert_eq!(result.values().iter().collect::<Vec<_>>(), vec![
+        true, true, true, true, false
+    ]);
+
+    // while the division alone fails on all the rows
+    let result = Evaluator::eval_physical_scalar(&div)?.eval(&func_ctx, &block);
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::PasswordHashMethod;
+use common_meta_types::UserInfo;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::Planner;
+
+use crate::tests::create_query_context_with_config;
+use crate::tests::ConfigBuilder;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_read_only_mode_access() -> Result<()> {
+    let mut conf = ConfigBuilder::create().build();
+    conf.query.read_only = true;
+    let (_guard, ctx) = create_query_context_with_config(conf, None).await?;
+
+    let reads = [
+        "SELECT * FROM numbers(10)",
+        "SHOW TABLES",
+        "EXPLAIN SELECT 1",
+        "SET max_threads = 4",
+        "USE default",
+    ];
+    for sql in reads {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _, _) = planner.plan_sql(sql).await?;
+        assert!(
+            InterpreterFactory::get(ctx.clone(), &plan).await.is_ok(),
+            "{} is allowed in read-only mode",
+            sql
+        );
+    }
+
+    let writes = [
+        "CREATE TABLE t(a INT)",
+        "CREATE DATABASE db1",
+        "SET GLOBAL max_threads = 4",
+        "DROP DATABASE IF EXISTS db1",
+    ];
+    for sql in writes {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _, _) = planner.plan_sql(sql).await?;
+        match InterpreterFactory::get(ctx.clone(), &plan).await {
+            Ok(_) => panic!("{} is rejected in read-only mode", sql),
+            Err(e) => assert_eq!(
+                e.code(),
+                ErrorCode::read_only_mode_permission_denied_code(),
+                "{}",
+                sql
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_alter_user_self_access() -> Result<()> {
+    let user_info = UserInfo::new("u1", "%", AuthInfo::Password {
+        hash_method: PasswordHashMethod::Sha256,
+        hash_value: Vec::from("pass"),
+    });
+    let (_guard, ctx) =
+        create_query_context_with_config(ConfigBuilder::create().build(), Some(user_info)).await?;
+
+    // The users without privileges can change their own passwords.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner
+        .plan_sql("ALTER USER USER() IDENTIFIED BY 'new_pass'")
+        .await?;
+    assert!(InterpreterFactory::get(ctx.clone(), &plan).await.is_ok());
+
+    // But not their own options.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner
+        .plan_sql("ALTER USER USER() WITH DEFAULT_ROLE = 'role1'")
+        .await?;
+    match InterpreterFactory::get(ctx.clone(), &plan).await {
+        Ok(_) => panic!("the options of the user require the CreateUser privilege"),
+        Err(e) => assert_eq!(e.code(), ErrorCode::permission_denied_code()),
+    }
+
+    Ok(())
+}
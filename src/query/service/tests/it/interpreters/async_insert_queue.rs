@@ -0,0 +1,115 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::AsyncInsertQueue;
+use databend_query::sessions::TableContext;
+use databend_query::sql::plans::Insert;
+use databend_query::sql::plans::Plan;
+use databend_query::sql::Planner;
+
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+fn block(insert: &Insert, rows: usize) -> DataBlock {
+    let values = (0..rows as i32).collect::<Vec<_>>();
+    DataBlock::create(insert.schema(), vec![Series::from_data(values)])
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_async_insert_queue_batches() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let sql = format!(
+        "INSERT INTO {}.{} (id) VALUES (1)",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let (plan, _, _) = Planner::new(ctx.clone()).plan_sql(&sql).await?;
+    let insert = match plan {
+        Plan::Insert(insert) => *insert,
+        _ => unreachable!(),
+    };
+
+    let queue = AsyncInsertQueue::create(
+        u64::MAX,
+        3,
+        Duration::from_secs(3600),
+        Duration::from_secs(0),
+    );
+
+    // The inserts into the same table are coalesced into one batch, until it's due.
+    let _rx1 = queue.push(&ctx, &insert, block(&insert, 1))?;
+    let _rx2 = queue.push(&ctx, &insert, block(&insert, 1))?;
+    assert!(queue.take_due(Instant::now()).is_empty());
+
+    // The inserts with the other settings are in the other batch.
+    ctx.get_settings().set_max_threads(3)?;
+    let _rx3 = queue.push(&ctx, &insert, block(&insert, 1))?;
+    let batches = queue.take_due(Instant::now() + Duration::from_secs(7200));
+    assert_eq!(batches.len(), 2);
+    let mut inserts = batches
+        .iter()
+        .map(|(_, batch)| batch.num_inserts())
+        .collect::<Vec<_>>();
+    inserts.sort();
+    assert_eq!(inserts, vec![1, 2]);
+    drop(batches);
+
+    // The inserts of the other roles are in the other batch, it's written with their roles.
+    let _rx = queue.push(&ctx, &insert, block(&insert, 1))?;
+    ctx.get_current_session()
+        .set_current_role(Some("async_insert_role".to_string()));
+    let _rx = queue.push(&ctx, &insert, block(&insert, 1))?;
+    ctx.get_current_session().set_current_role(None);
+    let batches = queue.take_due(Instant::now() + Duration::from_secs(7200));
+    assert_eq!(batches.len(), 2);
+    drop(batches);
+
+    // The batch is written on behalf of the user of the inserts.
+    let rx = queue.push(&ctx, &insert, block(&insert, 3))?;
+    assert_eq!(queue.flush().await?, 3);
+    AsyncInsertQueue::wait(rx, Duration::from_secs(10)).await?;
+
+    // A full batch is due at once.
+    let _rx4 = queue.push(&ctx, &insert, block(&insert, 3))?;
+    let batches = queue.take_due(Instant::now());
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].1.num_rows(), 3);
+
+    // The batch dropped without being written fails the insert waiting for it.
+    let rx5 = queue.push(&ctx, &insert, block(&insert, 1))?;
+    let batches = queue.take_due(Instant::now() + Duration::from_secs(7200));
+    drop(batches);
+    let res = AsyncInsertQueue::wait(rx5, Duration::from_secs(10)).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::aborted_query_code());
+
+    // The wait times out if the batch is not written.
+    let rx6 = queue.push(&ctx, &insert, block(&insert, 1))?;
+    let res = AsyncInsertQueue::wait(rx6, Duration::from_millis(10)).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::async_insert_timeout_error_code()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use databend_query::interpreters::ExecutorsHashRing;
+
+fn executors(ids: &[usize]) -> Vec<String> {
+    ids.iter().map(|id| format!("node-{}", id)).collect()
+}
+
+fn hashes(count: usize) -> Vec<u64> {
+    (0..count)
+        .map(|index| {
+            let mut hasher = DefaultHasher::new();
+            format!("1/{}/_b/{}.parquet", index % 7, index).hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect()
+}
+
+fn locate(executors: &[String], hashes: &[u64]) -> Vec<String> {
+    let ring = ExecutorsHashRing::create(executors);
+    hashes
+        .iter()
+        .map(|hash| executors[ring.locate(*hash)].clone())
+        .collect()
+}
+
+#[test]
+fn test_executors_hash_ring_stability() {
+    let hashes = hashes(1000);
+    let executors = executors(&[1, 2, 3, 4]);
+    let located = locate(&executors, &hashes);
+
+    // The same executors in another order.
+    let mut reversed = executors.clone();
+    reversed.reverse();
+    assert_eq!(located, locate(&executors, &hashes));
+    assert_eq!(located, locate(&reversed, &hashes));
+}
+
+#[test]
+fn test_executors_hash_ring_minimal_reshuffle() {
+    let hashes = hashes(1000);
+    let located = locate(&executors(&[1, 2, 3, 4]), &hashes);
+
+    // Only the hashes located to the new executor are moved.
+    let joined = locate(&executors(&[1, 2, 3, 4, 5]), &hashes);
+    let mut moved = 0;
+    for (before, after) in located.iter().zip(&joined) {
+        if before != after {
+            assert_eq!(after, "node-5");
+            moved += 1;
+        }
+    }
+    assert!(moved > 100 && moved < 300, "moved {} of 1000", moved);
+
+    // Only the hashes located to the left executor are moved.
+    let left = locate(&executors(&[1, 2, 4]), &hashes);
+    for (before, after) in located.iter().zip(&left) {
+        if before != after {
+            assert_eq!(before, "node-3");
+        }
+    }
+}
+
+#[test]
+fn test_executors_hash_ring_distribution() {
+    let hashes = hashes(10000);
+    let executors = executors(&[1, 2, 3, 4, 5]);
+    let ring = ExecutorsHashRing::create(&executors);
+
+    let mut loads = vec![0; executors.len()];
+    for hash in &hashes {
+        loads[ring.locate(*hash)] += 1;
+    }
+    for load in &loads {
+        assert!(*load > 1000 && *load < 3000, "loads {:?}", loads);
+    }
+
+    // The loads are bounded to the average load times the load factor.
+    let capacity = ExecutorsHashRing::capacity(hashes.len(), executors.len());
+    let mut bounded_loads = vec![0; executors.len()];
+    for executor in ring.locate_bounded(&hashes) {
+        bounded_loads[executor] += 1;
+    }
+    assert_eq!(bounded_loads.iter().sum::<usize>(), hashes.len());
+    assert!(bounded_loads.iter().all(|load| *load <= capacity));
+
+    // The hot location is spilled to the other executors instead of overloading one.
+    let hot = vec![hashes[0]; 100];
+    let mut hot_loads = vec![0; executors.len()];
+    for executor in ring.locate_bounded(&hot) {
+        hot_loads[executor] += 1;
+    }
+    assert_eq!(hot_loads.iter().max(), Some(&25));
+}
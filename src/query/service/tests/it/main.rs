@@ -18,10 +18,13 @@ mod auth;
 mod catalogs;
 mod clusters;
 mod configs;
+mod connections;
 mod context_function;
 mod evaluator;
+mod interpreters;
 mod metrics;
 mod pipelines;
+mod replay;
 mod servers;
 mod sessions;
 mod sql;
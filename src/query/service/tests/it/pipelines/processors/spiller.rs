@@ -0,0 +1,170 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::Progress;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::pipelines::processors::SpillManager;
+use databend_query::pipelines::processors::Spiller;
+use databend_query::sessions::TableContext;
+
+fn test_blocks() -> (DataSchemaRef, Vec<DataBlock>) {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![1i64, 2]),
+            Series::from_data(vec!["b1", "b2"]),
+        ]),
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![3i64]),
+            Series::from_data(vec!["b3"]),
+        ]),
+    ];
+    (schema, blocks)
+}
+
+#[test]
+fn test_spiller() -> Result<()> {
+    test_spiller_with_encryption(false)?;
+    test_spiller_with_encryption(true)
+}
+
+fn test_spiller_with_encryption(encrypted: bool) -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let spill_manager = SpillManager::create(dir.path(), 0, encrypted)?;
+    let query_spill = spill_manager.create_query_spill("query_id");
+    let spill_dir = query_spill.dir().to_path_buf();
+    let progress = Arc::new(Progress::create());
+    let spiller = Spiller::create(query_spill.clone(), progress.clone());
+    drop(query_spill);
+
+    let (schema, blocks) = test_blocks();
+    let mut spilled = spiller.spill(schema, &blocks)?;
+    assert_eq!(progress.get_values().rows, 3);
+    assert!(progress.get_values().bytes > 0);
+
+    let mut read_blocks = vec![];
+    while let Some(block) = spilled.next_block()? {
+        read_blocks.push(block);
+    }
+    assert_eq!(read_blocks.len(), 2);
+    assert_blocks_eq(
+        vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | b1 |",
+            "| 2 | b2 |",
+            "| 3 | b3 |",
+            "+---+----+",
+        ],
+        &read_blocks,
+    );
+
+    // The file and the directory of the query are removed with the last spilled file.
+    drop(spilled);
+    drop(spiller);
+    assert!(!spill_dir.exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_spill_quota() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let (schema, blocks) = test_blocks();
+
+    let spill_manager = SpillManager::create(dir.path(), 1, false)?;
+    let query_spill = spill_manager.create_query_spill("query_id");
+    let spiller = Spiller::create(query_spill.clone(), Arc::new(Progress::create()));
+    let res = spiller.spill(schema, &blocks);
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::SpillQuotaExceeded("").code())
+    );
+    assert_eq!(query_spill.get_spilled_bytes(), 0);
+    assert_eq!(std::fs::read_dir(query_spill.dir())?.count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_spill_manager_removes_stale_dirs() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    // The directory left by a crashed process, whose lock is not held.
+    let stale_dir = dir.path().join("stale");
+    std::fs::create_dir_all(stale_dir.join("query_id"))?;
+    std::fs::write(stale_dir.join("LOCK"), "")?;
+    std::fs::write(stale_dir.join("query_id").join("spilled"), "")?;
+
+    let _running = SpillManager::create(dir.path(), 0, false)?;
+    assert!(!stale_dir.exists());
+
+    // The directory of the running process is kept.
+    let _next = SpillManager::create(dir.path(), 0, false)?;
+    assert_eq!(std::fs::read_dir(dir.path())?.count(), 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spill_to_storage() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_settings(
+        "enable_spill_to_storage".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+    let temp_stage = ctx.get_query_temp_stage()?;
+    assert!(temp_stage.prefix().starts_with("_query_temp/"));
+
+    let progress = Arc::new(Progress::create());
+    let spiller = Spiller::create(ctx.get_spill_target()?, progress.clone());
+    let (schema, blocks) = test_blocks();
+    let mut spilled = spiller.spill(schema, &blocks)?;
+    assert_eq!(progress.get_values().rows, 3);
+    assert!(temp_stage.get_written_bytes() > 0);
+
+    let mut read_blocks = vec![];
+    while let Some(block) = spilled.next_block()? {
+        read_blocks.push(block);
+    }
+    assert_blocks_eq(
+        vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | b1 |",
+            "| 2 | b2 |",
+            "| 3 | b3 |",
+            "+---+----+",
+        ],
+        &read_blocks,
+    );
+
+    drop(spilled);
+    assert_eq!(temp_stage.get_written_bytes(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::replay::replay_query;
+use databend_query::replay::ReplayBundle;
+use databend_query::sessions::TableContext;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_replay_query() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+    let session = ctx.get_current_session();
+    execute_command(ctx.clone(), &format!("create table {db}.t(a int)")).await?;
+    execute_command(ctx.clone(), &format!("insert into {db}.t values (1), (2)")).await?;
+
+    // Capture the query.
+    execute_command(ctx.clone(), "set enable_replay_capture = 1").await?;
+    execute_command(ctx.clone(), "set max_threads = 3").await?;
+    let capture_ctx = session.create_query_context().await?;
+    let sql = format!("select sum(a) from {db}.t");
+    expects_ok(
+        "captured",
+        execute_query(capture_ctx.clone(), &sql).await,
+        vec![
+            "+--------+",
+            "| sum(a) |",
+            "+--------+",
+            "| 3      |",
+            "+--------+",
+        ],
+    )
+    .await?;
+    execute_command(ctx.clone(), "set enable_replay_capture = 0").await?;
+    execute_command(ctx.clone(), "set max_threads = 8").await?;
+
+    let bundle = ReplayBundle::load(ctx.as_ref(), &capture_ctx.get_id()).await?;
+    assert_eq!(bundle.sql, sql);
+    assert_eq!(bundle.current_database, "default");
+    assert_eq!(bundle.settings.get("max_threads").unwrap(), "3");
+    assert!(!bundle.settings.contains_key("enable_replay_capture"));
+    assert_eq!(bundle.tables.len(), 1);
+    assert_eq!(bundle.tables[0].database, db);
+    assert_eq!(bundle.tables[0].segments.len(), 1);
+
+    // The replayed query reads the data captured, not the rows inserted after then.
+    execute_command(ctx.clone(), &format!("insert into {db}.t values (3)")).await?;
+    let replay_ctx = session.create_query_context().await?;
+    let blocks = replay_query(replay_ctx.clone(), &bundle).await?;
+    common_datablocks::assert_blocks_sorted_eq(
+        vec![
+            "+--------+",
+            "| sum(a) |",
+            "+--------+",
+            "| 3      |",
+            "+--------+",
+        ],
+        blocks.as_slice(),
+    );
+    assert_eq!(replay_ctx.get_settings().get_max_threads()?, 3);
+
+    // The query planned differently can't be replayed.
+    let mut bundle = bundle;
+    bundle.plan = "Scan".to_string();
+    let replay_ctx = session.create_query_context().await?;
+    let res = replay_query(replay_ctx, &bundle).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::ReplayBundleMismatchCode()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::flight::deserialize_schemas;
+use common_arrow::arrow_format::flight::data::Action;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
+use common_arrow::arrow_format::flight::data::HandshakeRequest;
+use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_grpc::ConnectionFactory;
+use common_meta_types::AuthInfo;
+use common_meta_types::PasswordHashMethod;
+use common_meta_types::UserIdentity;
+use common_meta_types::UserInfo;
+use common_users::UserApiProvider;
+use databend_query::servers::flight_sql::flight_sql_messages::ActionCreatePreparedStatementRequest;
+use databend_query::servers::flight_sql::flight_sql_messages::ActionCreatePreparedStatementResult;
+use databend_query::servers::flight_sql::flight_sql_messages::Any;
+use databend_query::servers::flight_sql::flight_sql_messages::CommandPreparedStatementQuery;
+use databend_query::servers::flight_sql::flight_sql_messages::CommandStatementQuery;
+use databend_query::servers::flight_sql::flight_sql_messages::CREATE_PREPARED_STATEMENT;
+use databend_query::servers::FlightSQLHandler;
+use futures::StreamExt;
+use prost::Message;
+use tonic::transport::Channel;
+use tonic::Request;
+
+use crate::tests::ConfigBuilder;
+use crate::tests::TestGlobalServices;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_statement_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = FlightSQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut client = create_client(listening).await?;
+    let token = handshake(&mut client).await;
+
+    let command = Any::pack(&CommandStatementQuery {
+        query: "SELECT number, number + 1 FROM numbers(3)".to_string(),
+        transaction_id: None,
+    });
+    let (num_columns, num_rows) = fetch(&mut client, &token, command.encode_to_vec()).await;
+    assert_eq!(num_columns, 2);
+    assert_eq!(num_rows, 3);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_prepared_statement_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = FlightSQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut client = create_client(listening).await?;
+    let token = handshake(&mut client).await;
+
+    let body = ActionCreatePreparedStatementRequest {
+        query: "SELECT 1, 2, 3".to_string(),
+        transaction_id: None,
+    };
+    let action = Action {
+        r#type: CREATE_PREPARED_STATEMENT.to_string(),
+        body: Any::pack(&body).encode_to_vec(),
+    };
+    let mut results = client
+        .do_action(authorized(action, &token))
+        .await
+        .unwrap()
+        .into_inner();
+    let result = results.next().await.unwrap().unwrap();
+    let prepared =
+        Any::decode_from(&result.body)?.unpack::<ActionCreatePreparedStatementResult>()?;
+    assert!(!prepared.dataset_schema.is_empty());
+
+    let command = Any::pack(&CommandPreparedStatementQuery {
+        prepared_statement_handle: prepared.prepared_statement_handle,
+    });
+    let (num_columns, num_rows) = fetch(&mut client, &token, command.encode_to_vec()).await;
+    assert_eq!(num_columns, 3);
+    assert_eq!(num_rows, 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unauthenticated() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = FlightSQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut client = create_client(listening).await?;
+
+    let command = Any::pack(&CommandStatementQuery {
+        query: "SELECT 1".to_string(),
+        transaction_id: None,
+    });
+    let descriptor = FlightDescriptor {
+        r#type: 2,
+        cmd: command.encode_to_vec(),
+        path: vec![],
+    };
+    let status = client
+        .get_flight_info(authorized(descriptor, "unknown-token"))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_token_of_dropped_user() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = FlightSQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut client = create_client(listening).await?;
+
+    let tenant = "test";
+    let hash_method = PasswordHashMethod::Sha256;
+    let auth_info = AuthInfo::Password {
+        hash_value: hash_method.hash(b"flight_password"),
+        hash_method,
+    };
+    UserApiProvider::instance()
+        .add_user(tenant, UserInfo::new("flight_user", "%", auth_info), false)
+        .await?;
+    let token = handshake_as(&mut client, "flight_user:flight_password").await;
+
+    let command = Any::pack(&CommandStatementQuery {
+        query: "SELECT 1".to_string(),
+        transaction_id: None,
+    });
+    let descriptor = FlightDescriptor {
+        r#type: 2,
+        cmd: command.encode_to_vec(),
+        path: vec![],
+    };
+    client
+        .get_flight_info(authorized(descriptor.clone(), &token))
+        .await
+        .unwrap();
+
+    // The token is revoked once the user is dropped.
+    UserApiProvider::instance()
+        .drop_user(tenant, UserIdentity::new("flight_user", "%"), false)
+        .await?;
+    let status = client
+        .get_flight_info(authorized(descriptor, &token))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    Ok(())
+}
+
+async fn create_client(listening: SocketAddr) -> Result<FlightServiceClient<Channel>> {
+    let channel = ConnectionFactory::create_rpc_channel(listening, None, None).await?;
+    Ok(FlightServiceClient::new(channel))
+}
+
+fn authorized<T>(message: T, token: &str) -> Request<T> {
+    let mut request = Request::new(message);
+    let authorization = format!("Bearer {}", token).parse().unwrap();
+    request
+        .metadata_mut()
+        .insert("authorization", authorization);
+    request
+}
+
+async fn handshake(client: &mut FlightServiceClient<Channel>) -> String {
+    handshake_as(client, "root:").await
+}
+
+async fn handshake_as(client: &mut FlightServiceClient<Channel>, user_password: &str) -> String {
+    let handshake_request = HandshakeRequest {
+        protocol_version: 0,
+        payload: vec![],
+    };
+    let mut request = Request::new(futures::stream::iter(vec![handshake_request]));
+    let authorization = format!("Basic {}", base64::encode(user_password));
+    request
+        .metadata_mut()
+        .insert("authorization", authorization.parse().unwrap());
+
+    let response = client.handshake(request).await.unwrap();
+    let authorization = response.metadata().get("authorization").unwrap();
+    assert!(authorization.to_str().unwrap().starts_with("Bearer "));
+
+    let mut responses = response.into_inner();
+    let response = responses.next().await.unwrap().unwrap();
+    String::from_utf8(response.payload).unwrap()
+}
+
+// Returns the number of the columns and the rows of the results of the command.
+async fn fetch(
+    client: &mut FlightServiceClient<Channel>,
+    token: &str,
+    command: Vec<u8>,
+) -> (usize, usize) {
+    let descriptor = FlightDescriptor {
+        r#type: 2,
+        cmd: command,
+        path: vec![],
+    };
+    let info = client
+        .get_flight_info(authorized(descriptor, token))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(info.endpoint.len(), 1);
+
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let messages = client
+        .do_get(authorized(ticket, token))
+        .await
+        .unwrap()
+        .into_inner()
+        .map(|message| message.unwrap())
+        .collect::<Vec<FlightData>>()
+        .await;
+
+    let (schema, ipc_schema) = deserialize_schemas(&messages[0].data_header).unwrap();
+    let mut num_rows = 0;
+    for message in &messages[1..] {
+        let chunk =
+            deserialize_batch(message, &schema.fields, &ipc_schema, &Default::default()).unwrap();
+        num_rows += chunk.len();
+    }
+    (schema.fields.len(), num_rows)
+}
@@ -364,6 +364,54 @@ async fn test_multi_partition() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_clickhouse_headers() -> PoemResult<()> {
+    let config = ConfigBuilder::create().build();
+    let _guard = TestGlobalServices::setup(config.clone()).await.unwrap();
+    let server = Server::new(config).await.unwrap();
+
+    {
+        let uri = "/?query=select%20currentDatabase()".parse::<Uri>().unwrap();
+        let req = Request::builder()
+            .uri(uri)
+            .header("X-ClickHouse-User", "root")
+            .header("X-ClickHouse-Database", "system")
+            .finish();
+        let response = server.endpoint.get_response(req).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers().clone();
+        assert_eq!(headers.get("X-ClickHouse-Format").unwrap(), "TSV");
+        assert_eq!(headers.get("X-ClickHouse-Timezone").unwrap(), "UTC");
+        assert!(headers.get("X-ClickHouse-Query-Id").is_some());
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(&body, "system\n");
+    }
+
+    {
+        let uri = "/?user=root&password=&query=select%201%20format%20CSV"
+            .parse::<Uri>()
+            .unwrap();
+        let req = Request::builder().uri(uri).finish();
+        let response = server.endpoint.get_response(req).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("X-ClickHouse-Format").unwrap(),
+            "CSV"
+        );
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(&body, "1\n");
+    }
+
+    {
+        let uri = "/?user=unknown&query=select%201".parse::<Uri>().unwrap();
+        let req = Request::builder().uri(uri).finish();
+        let response = server.endpoint.get_response(req).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(())
+}
+
 struct QueryBuilder {
     sql: String,
     body: Option<Body>,
@@ -14,11 +14,16 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Cursor;
 use std::io::Read;
 use std::time::Duration;
 
 use base64::encode_config;
 use base64::URL_SAFE_NO_PAD;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_arrow::arrow::io::parquet::read::read_metadata;
 use common_base::base::get_free_tcp_port;
 use common_base::base::tokio;
 use common_exception::ErrorCode;
@@ -32,11 +37,21 @@ use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
+use databend_query::servers::http::v1::make_schema_uri;
 use databend_query::servers::http::v1::make_state_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::session_route;
+use databend_query::servers::http::v1::streaming_query;
+use databend_query::servers::http::v1::table_route;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::QuerySchemaField;
+use databend_query::servers::http::v1::QuerySchemaResponse;
+use databend_query::servers::http::v1::SessionResponse;
+use databend_query::servers::http::v1::StreamingQueryFooter;
+use databend_query::servers::http::v1::StreamingQueryHeader;
+use databend_query::servers::http::v1::TablePreviewResponse;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
@@ -50,6 +65,7 @@ use num::ToPrimitive;
 use poem::http::header;
 use poem::http::Method;
 use poem::http::StatusCode;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Request;
@@ -402,7 +418,12 @@ async fn test_http_session() -> Result<()> {
     let json = serde_json::json!({"sql": "select * from x", "session_id": session_id});
     let (status, result) = post_json_to_endpoint(&ep, &json).await?;
     assert_eq!(status, StatusCode::OK, "{:?}", result);
-    assert!(result.error.is_some(), "{:?}", result);
+    let error = result.error.as_ref().unwrap();
+    assert_eq!(error.code, ErrorCode::unknown_table_code(), "{:?}", result);
+    assert_eq!(error.name, "UnknownTable", "{:?}", result);
+    assert_eq!(error.category, "not_found", "{:?}", result);
+    assert!(!error.retryable, "{:?}", result);
+    assert!(error.user_error, "{:?}", result);
 
     let json = serde_json::json!({"sql": "select 1", "session_id": session_id});
     let (status, result) = post_json_to_endpoint(&ep, &json).await?;
@@ -412,6 +433,65 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_http_session_route() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let config = ConfigBuilder::create().build();
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::create(config).await?);
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .nest("/v1/session", session_route())
+        .with(session_middleware);
+
+    // keep_server_session_secs is required.
+    let json = serde_json::json!({"database": "system"});
+    let response = post_json_to_uri(&ep, "/v1/session", &json).await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let json = serde_json::json!({"database": "system", "keep_server_session_secs": 10});
+    let response = post_json_to_uri(&ep, "/v1/session", &json).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<SessionResponse>(&body)?;
+    assert_eq!(result.session.database, Some("system".to_string()));
+    assert_eq!(result.session.keep_server_session_secs, Some(10));
+    let session_id = result.session_id;
+    let session_uri = format!("/v1/session/{}", session_id);
+
+    let json = serde_json::json!({"sql": "set max_block_size = 777", "session_id": session_id});
+    let response = post_json_to_uri(&ep, "/v1/query", &json).await?;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let response = get_uri(&ep, &session_uri).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<SessionResponse>(&body)?;
+    assert_eq!(result.session.database, Some("system".to_string()));
+    let settings = result.session.settings.unwrap_or_default();
+    assert_eq!(settings.get("max_block_size"), Some(&"777".to_string()));
+
+    let json = serde_json::json!({"sql": "select database()", "session_id": session_id});
+    let response = post_json_to_uri(&ep, "/v1/query", &json).await?;
+    let (_, result) = check_response(response).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data[0][0], "system", "{:?}", result);
+
+    let response = delete_uri(&ep, &session_uri).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = get_uri(&ep, &session_uri).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let json = serde_json::json!({"sql": "select 1", "session_id": session_id});
+    let response = post_json_to_uri(&ep, "/v1/query", &json).await?;
+    let (_, result) = check_response(response).await?;
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[ignore = "flaky, sleep time unreliable"]
 async fn test_result_timeout() -> Result<()> {
@@ -448,6 +528,35 @@ async fn test_result_timeout() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_result_timeout_of_request() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select * from numbers(10)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 1, "max_rows_per_page": 2, "result_timeout_secs": 1}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let next_uri = make_page_uri(&result.id, 1);
+    assert_eq!(result.next_uri, Some(next_uri.clone()), "{:?}", result);
+
+    let response = get_uri(&ep, &next_uri).await;
+    assert_eq!(response.status(), StatusCode::OK, "{:?}", result);
+
+    // the pages not fetched are dropped after the timeout since the last request.
+    sleep(Duration::from_secs(3)).await;
+    let response = get_uri(&ep, &make_page_uri(&result.id, 2)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND, "{:?}", result);
+
+    // the timeout is clamped to the max of the server instead of overflowing.
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 1, "max_rows_per_page": 2, "result_timeout_secs": u64::MAX}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let response = get_uri(&ep, &make_page_uri(&result.id, 1)).await;
+    assert_eq!(response.status(), StatusCode::OK, "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_system_tables() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -637,6 +746,268 @@ async fn test_query_log() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_async_query_cancel() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select sleep(2)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 0}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Running, "{:?}", result);
+
+    let state_uri = make_state_uri(&result.id);
+    let (status, state) = get_uri_checked(&ep, &state_uri).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", state);
+    assert_eq!(state.state, ExecuteStateKind::Running, "{:?}", state);
+
+    let response = delete_uri(&ep, &state_uri).await;
+    assert_eq!(response.status(), StatusCode::OK, "{:?}", result);
+
+    let response = get_uri(&ep, &state_uri).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND, "{:?}", result);
+    let response = delete_uri(&ep, &state_uri).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND, "{:?}", result);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_streaming_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let config = ConfigBuilder::create().build();
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::create(config).await?);
+    let ep = Route::new()
+        .at("/v1/streaming_query", post(streaming_query))
+        .with(session_middleware);
+
+    let sql = "select number, number * 2 from numbers(10)";
+    let json = serde_json::json!({"sql": sql, "session": {"settings": {"max_block_size": "3"}}});
+    let response = post_json_to_uri(&ep, "/v1/streaming_query", &json).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some("application/x-ndjson"),
+        "{:?}",
+        response
+    );
+
+    let body = response.into_body().into_string().await.unwrap();
+    let lines = body.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 12, "{}", body);
+    let header = serde_json::from_str::<StreamingQueryHeader>(lines[0])?;
+    assert!(!header.id.is_empty());
+    assert_eq!(header.schema.num_fields(), 2);
+    for (i, line) in lines[1..11].iter().enumerate() {
+        let row = serde_json::from_str::<Vec<serde_json::Value>>(line)?;
+        assert_eq!(row, vec![serde_json::json!(i), serde_json::json!(i * 2)]);
+    }
+    let footer = serde_json::from_str::<StreamingQueryFooter>(lines[11])?;
+    assert!(footer.error.is_none(), "{:?}", footer.error);
+    assert_eq!(footer.stats.progresses.result_progress.rows, 10);
+
+    // The errors of the planner are returned before the stream.
+    let json = serde_json::json!({"sql": "select * from not_exists"});
+    let response = post_json_to_uri(&ep, "/v1/streaming_query", &json).await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_streaming_multi_statement_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let config = ConfigBuilder::create().build();
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::create(config).await?);
+    let ep = Route::new()
+        .at("/v1/streaming_query", post(streaming_query))
+        .with(session_middleware);
+
+    // The header, the rows and the footer of each statement, the statements after a failed
+    // one are not run.
+    let sql = "set max_threads = 3; select 1; select * from not_exists; select 2";
+    let json = serde_json::json!({ "sql": sql });
+    let response = post_json_to_uri(&ep, "/v1/streaming_query", &json).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let lines = body.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 7, "{}", body);
+    let footer = serde_json::from_str::<StreamingQueryFooter>(lines[1])?;
+    assert!(footer.error.is_none(), "{:?}", footer.error);
+    let header = serde_json::from_str::<StreamingQueryHeader>(lines[2])?;
+    assert_eq!(header.schema.num_fields(), 1);
+    let row = serde_json::from_str::<Vec<serde_json::Value>>(lines[3])?;
+    assert_eq!(row, vec![serde_json::json!(1)]);
+    let header = serde_json::from_str::<StreamingQueryHeader>(lines[5])?;
+    assert_eq!(header.schema.num_fields(), 0);
+    let footer = serde_json::from_str::<StreamingQueryFooter>(lines[6])?;
+    assert!(footer.error.is_some());
+
+    let json = serde_json::json!({
+        "sql": sql,
+        "session": {"settings": {"continue_on_error": "1"}}
+    });
+    let response = post_json_to_uri(&ep, "/v1/streaming_query", &json).await?;
+    let body = response.into_body().into_string().await.unwrap();
+    let lines = body.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 10, "{}", body);
+    let row = serde_json::from_str::<Vec<serde_json::Value>>(lines[8])?;
+    assert_eq!(row, vec![serde_json::json!(2)]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_table_preview() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let config = ConfigBuilder::create().build();
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::create(config).await?);
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .nest("/v1/tables", table_route())
+        .with(session_middleware);
+
+    for sql in [
+        "create table t_preview(a int, b string)",
+        "insert into t_preview values (1, 'x'), (2, 'y'), (3, 'z')",
+    ] {
+        let (status, result) = post_sql_to_endpoint(&ep, sql, 3).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    }
+
+    // The rows are bounded by the limit.
+    let response = get_uri(&ep, "/v1/tables/default/t_preview/preview?limit=2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let preview = serde_json::from_str::<TablePreviewResponse>(&body)?;
+    assert_eq!(preview.schema.num_fields(), 2);
+    assert_eq!(preview.data.len(), 2, "{}", body);
+
+    let response = get_uri(&ep, "/v1/tables/default/t_preview/preview").await;
+    let body = response.into_body().into_string().await.unwrap();
+    let preview = serde_json::from_str::<TablePreviewResponse>(&body)?;
+    assert_eq!(preview.data.len(), 3, "{}", body);
+
+    let response = get_uri(&ep, "/v1/tables/default/not_exists/preview").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_streaming_query_formats() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let config = ConfigBuilder::create().build();
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::create(config).await?);
+    let ep = Route::new()
+        .at("/v1/streaming_query", post(streaming_query))
+        .with(session_middleware);
+
+    let sql = "select number, number * 2 from numbers(10)";
+    let json = serde_json::json!({"sql": sql, "session": {"settings": {"max_block_size": "3"}}});
+
+    // The format parameter.
+    let uri = "/v1/streaming_query?format=csv";
+    let response = post_json_to_uri(&ep, uri, &json).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some("text/csv; charset=UTF-8; header=absent")
+    );
+    assert!(response.headers().contains_key("X-DATABEND-QUERY-ID"));
+    let body = response.into_body().into_string().await.unwrap();
+    let lines = body.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 10, "{}", body);
+    assert_eq!(lines[3], "3,6");
+
+    // The Accept header.
+    let response = post_json_with_accept(
+        &ep,
+        "/v1/streaming_query",
+        &json,
+        "application/vnd.apache.arrow.stream",
+    )
+    .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some("application/vnd.apache.arrow.stream")
+    );
+    let body = response.into_body().into_vec().await.unwrap();
+    let mut reader = Cursor::new(body);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema.fields.len(), 2);
+    let mut rows = 0;
+    for state in StreamReader::new(reader, metadata, None) {
+        if let StreamState::Some(chunk) = state? {
+            rows += chunk.len();
+        }
+    }
+    assert_eq!(rows, 10);
+
+    let response =
+        post_json_with_accept(&ep, "/v1/streaming_query", &json, "application/x-parquet").await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_vec().await.unwrap();
+    let metadata = read_metadata(&mut Cursor::new(body))?;
+    assert_eq!(metadata.num_rows, 10);
+
+    // The lines of json if no format is accepted.
+    let response =
+        post_json_with_accept(&ep, "/v1/streaming_query", &json, "text/html, */*").await?;
+    assert_eq!(response.content_type(), Some("application/x-ndjson"));
+
+    let response = post_json_to_uri(&ep, "/v1/streaming_query?format=xml", &json).await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let json = serde_json::json!({"sql": "select 1; select 2"});
+    let response = post_json_to_uri(&ep, uri, &json).await?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+async fn post_json_with_accept(
+    ep: &impl Endpoint,
+    uri: &str,
+    json: &serde_json::Value,
+    accept: &str,
+) -> Result<Response> {
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri(uri.parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, accept)
+        .typed_header(basic)
+        .body(serde_json::to_vec(json)?);
+    Ok(ep.get_response(req).await)
+}
+
+async fn post_json_to_uri(
+    ep: &impl Endpoint,
+    uri: &str,
+    json: &serde_json::Value,
+) -> Result<Response> {
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri(uri.parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(basic)
+        .body(serde_json::to_vec(json)?);
+    Ok(ep.get_response(req).await)
+}
+
 async fn delete_query(ep: &EndpointType, query_id: &str) -> StatusCode {
     let uri = make_final_uri(query_id);
     let resp = get_uri(ep, &uri).await;
@@ -669,6 +1040,19 @@ async fn get_uri(ep: &EndpointType, uri: &str) -> Response {
     .unwrap_or_else(|err| err.into_response())
 }
 
+async fn delete_uri(ep: &EndpointType, uri: &str) -> Response {
+    let basic = headers::Authorization::basic("root", "");
+    ep.call(
+        Request::builder()
+            .uri(uri.parse().unwrap())
+            .method(Method::DELETE)
+            .typed_header(basic)
+            .finish(),
+    )
+    .await
+    .unwrap_or_else(|err| err.into_response())
+}
+
 async fn get_uri_checked(ep: &EndpointType, uri: &str) -> Result<(StatusCode, QueryResponse)> {
     let response = get_uri(ep, uri).await;
     check_response(response).await
@@ -1107,6 +1491,101 @@ async fn test_download() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_download_arrow() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, to_timestamp(number) as t from numbers(2)";
+    let (status, result) = post_sql_to_endpoint_new_session(&ep, sql, 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+
+    let uri = format!("/v1/query/{}/download?format=arrow", result.id);
+    let resp = get_uri(&ep, &uri).await;
+    assert_eq!(resp.status(), StatusCode::OK, "{:?}", resp);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/vnd.apache.arrow.stream"
+    );
+    let body = resp.into_body().into_vec().await.unwrap();
+
+    // the fields carry their databend types
+    let mut reader = Cursor::new(body);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let fields = &metadata.schema.fields;
+    assert_eq!(fields[0].metadata["databend.type"], "BIGINT UNSIGNED");
+    assert_eq!(fields[0].metadata["databend.nullable"], "false");
+    assert_eq!(fields[1].metadata["databend.type"], "TIMESTAMP(6)");
+    assert_eq!(fields[1].metadata["databend.timezone"], "UTC");
+
+    let mut rows = 0;
+    for state in StreamReader::new(reader, metadata, None) {
+        if let StreamState::Some(chunk) = state? {
+            rows += chunk.len();
+        }
+    }
+    assert_eq!(rows, 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_schema() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, to_timestamp(number) as t from numbers(2)";
+    let (status, result) = post_sql_to_endpoint_new_session(&ep, sql, 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let schema_uri = result.schema_uri.clone().unwrap();
+    assert_eq!(schema_uri, make_schema_uri(&result.id));
+
+    let resp = get_uri(&ep, &schema_uri).await;
+    assert_eq!(resp.status(), StatusCode::OK, "{:?}", resp);
+    let body = resp.into_body().into_string().await.unwrap();
+    let schema = serde_json::from_str::<QuerySchemaResponse>(&body)?;
+    assert_eq!(schema.id, result.id);
+    assert_eq!(
+        schema.fields,
+        Some(vec![
+            QuerySchemaField {
+                name: "number".to_string(),
+                data_type: "BIGINT UNSIGNED".to_string(),
+                nullable: false,
+                timezone: None,
+            },
+            QuerySchemaField {
+                name: "t".to_string(),
+                data_type: "TIMESTAMP(6)".to_string(),
+                nullable: false,
+                timezone: Some("UTC".to_string()),
+            },
+        ])
+    );
+    let fingerprint = schema.fingerprint.unwrap();
+
+    // the same shape has the same fingerprint
+    let sql = "select number + 1 as number, to_timestamp(number) as t from numbers(3)";
+    let (_, result) = post_sql_to_endpoint_new_session(&ep, sql, 1).await?;
+    let resp = get_uri(&ep, &make_schema_uri(&result.id)).await;
+    let body = resp.into_body().into_string().await.unwrap();
+    let schema = serde_json::from_str::<QuerySchemaResponse>(&body)?;
+    assert_eq!(schema.fingerprint, Some(fingerprint.clone()));
+
+    // a different shape has a different fingerprint
+    let sql = "select number, to_date(number) as t from numbers(2)";
+    let (_, result) = post_sql_to_endpoint_new_session(&ep, sql, 1).await?;
+    let resp = get_uri(&ep, &make_schema_uri(&result.id)).await;
+    let body = resp.into_body().into_string().await.unwrap();
+    let schema = serde_json::from_str::<QuerySchemaResponse>(&body)?;
+    assert_ne!(schema.fingerprint, Some(fingerprint));
+
+    // not exist
+    let resp = get_uri(&ep, &make_schema_uri("123")).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_download_non_select() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
+use bytes::Bytes;
 use common_base::base::tokio;
 use common_base::base::Runtime;
 use common_base::base::TrySpawn;
@@ -23,12 +25,20 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use databend_query::servers::MySQLHandler;
+use futures::StreamExt;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
+use mysql_async::OptsBuilder;
 use mysql_async::Row;
+use mysql_async::SslOpts;
 use tokio::sync::Barrier;
 
+use crate::tests::tls_constants::TEST_TLS_CA_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_IDENTITY;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_PASSWORD;
+use crate::tests::tls_constants::TEST_TLS_SERVER_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_KEY;
 use crate::tests::ConfigBuilder;
 use crate::tests::TestGlobalServices;
 
@@ -49,6 +59,342 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_error_sqlstate() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    // UnknownTable is sent as ER_NO_SUCH_TABLE.
+    match connection
+        .query_drop("SELECT * FROM system.not_exists")
+        .await
+    {
+        Err(mysql_async::Error::Server(error)) => {
+            assert_eq!(error.code, 1146, "{:?}", error);
+            assert_eq!(error.state, "42S02", "{:?}", error);
+        }
+        other => panic!("Expected a server error, got {:?}", other),
+    }
+
+    // SyntaxException is sent as ER_PARSE_ERROR.
+    match connection.query_drop("SELEC 1").await {
+        Err(mysql_async::Error::Server(error)) => {
+            assert_eq!(error.code, 1064, "{:?}", error);
+            assert_eq!(error.state, "42000", "{:?}", error);
+        }
+        other => panic!("Expected a server error, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_tls_connection() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .mysql_tls_server_cert(TEST_TLS_SERVER_CERT)
+        .mysql_tls_server_key(TEST_TLS_SERVER_KEY)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let ssl_opts = SslOpts::default().with_danger_accept_invalid_certs(true);
+    let opts = OptsBuilder::default()
+        .ip_or_hostname("127.0.0.1")
+        .tcp_port(runnable_server.port())
+        .user(Some("root"))
+        .ssl_opts(ssl_opts);
+    let mut connection = mysql_async::Conn::new(opts)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "TLS connection")?;
+
+    let rows: Vec<(u8,)> = connection
+        .query("SELECT 1")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![(1,)]);
+
+    // The plain connections are still accepted.
+    create_connection(runnable_server.port()).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_tls_client_certificate() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .mysql_tls_server_cert(TEST_TLS_SERVER_CERT)
+        .mysql_tls_server_key(TEST_TLS_SERVER_KEY)
+        .mysql_tls_server_root_ca_cert(TEST_TLS_CA_CERT)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let connect = |ssl_opts: SslOpts| {
+        let opts = OptsBuilder::default()
+            .ip_or_hostname("127.0.0.1")
+            .tcp_port(runnable_server.port())
+            .user(Some("root"))
+            .ssl_opts(ssl_opts);
+        mysql_async::Conn::new(opts)
+    };
+
+    // The client presenting a certificate signed by the CA is accepted.
+    let ssl_opts = SslOpts::default()
+        .with_danger_accept_invalid_certs(true)
+        .with_pkcs12_path(Some(Path::new(TEST_TLS_CLIENT_IDENTITY)))
+        .with_password(Some(TEST_TLS_CLIENT_PASSWORD));
+    let mut connection = connect(ssl_opts)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "TLS connection")?;
+    let rows: Vec<(u8,)> = connection
+        .query("SELECT 1")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![(1,)]);
+
+    // The client without a certificate is rejected.
+    let ssl_opts = SslOpts::default().with_danger_accept_invalid_certs(true);
+    assert!(connect(ssl_opts).await.is_err());
+
+    // The plain connections are rejected, as they would bypass the verification.
+    assert!(create_connection(runnable_server.port()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_compressed_connection() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let opts = OptsBuilder::default()
+        .ip_or_hostname("127.0.0.1")
+        .tcp_port(runnable_server.port())
+        .user(Some("root"))
+        .compression(Some(mysql_async::Compression::default()));
+    let mut connection = mysql_async::Conn::new(opts)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Compressed connection")?;
+
+    // The result set spans many compressed packets.
+    let rows: Vec<(u64, String)> = connection
+        .query("SELECT number, repeat('x', 100) FROM numbers(10000) ORDER BY number")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows.len(), 10000);
+    assert_eq!(rows[9999], (9999, "x".repeat(100)));
+
+    // The next commands are compressed as well.
+    let rows: Vec<(u8,)> = connection
+        .query("SELECT 1")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![(1,)]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_load_data_local_infile() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+    connection
+        .query_drop("CREATE TABLE t(a INT, b VARCHAR) ENGINE = Memory")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table")?;
+
+    // The file is sent in several packets.
+    connection.set_infile_handler(async move {
+        let chunks = [Bytes::from("1\ta\n2\tb\n"), Bytes::from("3\tc\n")];
+        Ok(futures::stream::iter(chunks).map(Ok).boxed())
+    });
+    connection
+        .query_drop("LOAD DATA LOCAL INFILE 'data.tsv' INTO TABLE t")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Load data")?;
+
+    connection.set_infile_handler(async move {
+        let chunks = [Bytes::from("a,b\n4,\"d,e\"\n5,f\n")];
+        Ok(futures::stream::iter(chunks).map(Ok).boxed())
+    });
+    connection
+        .query_drop(
+            "LOAD DATA LOCAL INFILE 'data.csv' INTO TABLE t FIELDS TERMINATED BY ',' ENCLOSED BY '\"' IGNORE 1 LINES (a, b)",
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Load data")?;
+
+    let rows: Vec<(u8, String)> = connection
+        .query("SELECT a, b FROM t ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![
+        (1, "a".to_string()),
+        (2, "b".to_string()),
+        (3, "c".to_string()),
+        (4, "d,e".to_string()),
+        (5, "f".to_string()),
+    ]);
+
+    // The failed insert is the response of the query, the connection is still usable.
+    connection.set_infile_handler(async move {
+        let chunks = [Bytes::from("6\tg\n")];
+        Ok(futures::stream::iter(chunks).map(Ok).boxed())
+    });
+    let result = connection
+        .query_drop("LOAD DATA LOCAL INFILE 'data.tsv' INTO TABLE not_exists")
+        .await;
+    assert!(result.is_err());
+    let result = connection
+        .query_drop("LOAD DATA INFILE 'data.tsv' INTO TABLE t")
+        .await;
+    let error = result.expect_err("LOAD DATA without LOCAL should be rejected");
+    assert!(error.to_string().contains("LOCAL"), "{}", error);
+
+    let rows: Vec<(u8,)> = connection
+        .query("SELECT 1")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![(1,)]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let statement = connection
+        .prep("SELECT ? + 1, ?, number FROM numbers(3) WHERE number < ? ORDER BY number")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare")?;
+    assert_eq!(statement.num_params(), 3);
+    assert_eq!(statement.columns().len(), 3);
+
+    let rows: Vec<(i64, String, u64)> = connection
+        .exec(&statement, (41, "it's ?", 2))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute")?;
+    assert_eq!(rows, vec![
+        (42, "it's ?".to_string(), 0),
+        (42, "it's ?".to_string(), 1)
+    ]);
+
+    let rows: Vec<(Option<i64>, Option<String>, u64)> = connection
+        .exec(&statement, (None::<i64>, None::<String>, 1))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute with nulls")?;
+    assert_eq!(rows, vec![(None, None, 0)]);
+
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close")?;
+
+    let rows: Vec<(f64,)> = connection
+        .exec("SELECT ? * 2", (1.5f64,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute double")?;
+    assert_eq!(rows, vec![(3.0,)]);
+
+    // The negative parameters are not merged with the operators before them.
+    let rows: Vec<(i64, f64)> = connection
+        .exec("SELECT 1-?, 1-?", (-1i64, -1.5f64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute negative")?;
+    assert_eq!(rows, vec![(2, 2.5)]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_multi_statement_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = MySQLHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let mut result = connection
+        .query_iter("SET max_threads = 3; SELECT 1; SELECT 'a;b', 2")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Multi-statement query")?;
+    let rows: Vec<(u8,)> = result
+        .collect()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "First result")?;
+    assert!(rows.is_empty());
+    let rows: Vec<(u8,)> = result
+        .collect()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Second result")?;
+    assert_eq!(rows, vec![(1,)]);
+    let rows: Vec<(String, u8)> = result
+        .collect()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Third result")?;
+    assert_eq!(rows, vec![("a;b".to_string(), 2)]);
+    drop(result);
+
+    // The statements run in the same session.
+    let rows: Vec<(String,)> = connection
+        .query("SELECT value FROM system.settings WHERE name = 'max_threads'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![("3".to_string(),)]);
+
+    // The statements after a failed one are not run.
+    let result = connection
+        .query_drop("SET max_threads = 4; SELECT * FROM not_exists; SET max_threads = 5")
+        .await;
+    assert!(result.is_err());
+    let rows: Vec<(String,)> = connection
+        .query("SELECT value FROM system.settings WHERE name = 'max_threads'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![("4".to_string(),)]);
+
+    // Unless continue_on_error is set.
+    connection
+        .query_drop("SET continue_on_error = 1; SELECT * FROM not_exists; SET max_threads = 5")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Continue on error")?;
+    let rows: Vec<(String,)> = connection
+        .query("SELECT value FROM system.settings WHERE name = 'max_threads'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query")?;
+    assert_eq!(rows, vec![("5".to_string(),)]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let _guard =
@@ -70,7 +416,7 @@ async fn test_rejected_session_with_sequence() -> Result<()> {
                 assert_eq!(error.code(), 1067);
                 assert_eq!(
                     error.message(),
-                    "Reject connection, cause: Server error: `ERROR HY000 (1815): The current accept connection has exceeded max_active_sessions config'"
+                    "Reject connection, cause: Server error: `ERROR 08004 (1040): The current accept connection has exceeded max_active_sessions config'"
                 );
             }
         };
@@ -109,7 +455,7 @@ async fn test_rejected_session_with_parallel() -> Result<()> {
                 assert_eq!(error.code(), 1067);
                 assert_eq!(
                     error.message(),
-                    "Reject connection, cause: Server error: `ERROR HY000 (1815): The current accept connection has exceeded max_active_sessions config'"
+                    "Reject connection, cause: Server error: `ERROR 08004 (1040): The current accept connection has exceeded max_active_sessions config'"
                 );
                 CreateServerResult::Rejected
             }
@@ -0,0 +1,61 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::assert_blocks_eq;
+use common_exception::Result;
+use databend_query::servers::PostgresFederated;
+
+#[test]
+fn test_postgres_federated() -> Result<()> {
+    let federated = PostgresFederated::create();
+
+    //
+    {
+        let query = "select 1";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    // txn
+    {
+        let query = "BEGIN;";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some(block) = result {
+            assert!(block.is_empty())
+        }
+    }
+
+    // show variables
+    {
+        let query = "SHOW TRANSACTION ISOLATION LEVEL";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some(block) = result {
+            let expect = vec![
+                "+-----------------------+",
+                "| transaction_isolation |",
+                "+-----------------------+",
+                "| read committed        |",
+                "+-----------------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_types::AuthInfo;
+use common_meta_types::PasswordHashMethod;
+use databend_query::servers::PostgresHandler;
+use tokio_postgres::types::Type;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+use crate::tests::ConfigBuilder;
+use crate::tests::TestGlobalServices;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let client = create_connection(runnable_server.port()).await?;
+    let messages = client
+        .simple_query("SELECT 1, 2, 3")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Simple query")?;
+
+    let row = match &messages[0] {
+        SimpleQueryMessage::Row(row) => row,
+        _ => panic!("Expected a row"),
+    };
+    assert_eq!(row.get(0), Some("1"));
+    assert_eq!(row.get(2), Some("3"));
+    assert!(matches!(
+        messages[1],
+        SimpleQueryMessage::CommandComplete(1)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let client = create_connection(runnable_server.port()).await?;
+    let statement = client
+        .prepare_typed("SELECT $1, $2", &[Type::INT4, Type::VARCHAR])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare")?;
+    let rows = client
+        .query(&statement, &[&42i32, &"it's"])
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute")?;
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<_, i32>(0), 42);
+    assert_eq!(rows[0].get::<_, &str>(1), "it's");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_rejected_session() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(ConfigBuilder::create().max_active_sessions(1).build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // Accepted connection
+    let _client = create_connection(listening.port()).await?;
+
+    // Rejected connection
+    match create_connection(listening.port()).await {
+        Ok(_) => panic!("Expected rejected connection"),
+        Err(error) => assert!(
+            error
+                .message()
+                .contains("The current accept connection has exceeded max_active_sessions config")
+        ),
+    };
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_password_requires_ssl() -> Result<()> {
+    let hash_method = PasswordHashMethod::Sha256;
+    let auth_info = AuthInfo::Password {
+        hash_value: hash_method.hash(b"pg_password"),
+        hash_method,
+    };
+    let _guard = TestGlobalServices::setup(
+        ConfigBuilder::create()
+            .add_user("pg_user", auth_info)
+            .build(),
+    )
+    .await?;
+
+    let mut handler = PostgresHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // The password is not asked for in clear text out of the SSL connection.
+    let config = format!(
+        "host=127.0.0.1 port={} user=pg_user password=pg_password dbname=default",
+        listening.port()
+    );
+    match tokio_postgres::connect(&config, NoTls).await {
+        Ok(_) => panic!("Expected rejected connection"),
+        Err(error) => assert!(
+            error
+                .to_string()
+                .contains("password authentication failed for user \"pg_user\"")
+        ),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_oversized_startup_message() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // The startup message longer than 10000 bytes is rejected before its body is read.
+    let mut stream = TcpStream::connect(listening).await?;
+    stream.write_i32(1024 * 1024).await?;
+    stream.write_i32(196608).await?;
+    stream.write_all(b"user\0root\0\0").await?;
+
+    let mut buf = [0; 1];
+    let read = tokio::time::timeout(Duration::from_secs(10), stream.read(&mut buf))
+        .await
+        .map_err_to_code(ErrorCode::Timeout, || "Read the closed connection")?;
+    assert!(matches!(read, Ok(0) | Err(_)));
+
+    Ok(())
+}
+
+async fn create_connection(port: u16) -> Result<Client> {
+    let config = format!("host=127.0.0.1 port={} user=root dbname=default", port);
+    let (client, connection) = tokio_postgres::connect(&config, NoTls)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")?;
+    tokio::spawn(connection);
+    Ok(client)
+}
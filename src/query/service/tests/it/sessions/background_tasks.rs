@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::BackgroundJob;
+use databend_query::sessions::BackgroundTask;
+use databend_query::sessions::BackgroundTasks;
+
+#[derive(Default)]
+struct CountingJob {
+    runs: AtomicU64,
+}
+
+#[async_trait::async_trait]
+impl BackgroundJob for CountingJob {
+    async fn run(&self) -> Result<String> {
+        let runs = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(format!("run {}", runs))
+    }
+
+    fn progress(&self) -> String {
+        "nothing buffered".to_string()
+    }
+}
+
+async fn wait_runs(job: &CountingJob, runs: u64) {
+    while job.runs.load(Ordering::SeqCst) < runs {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_background_tasks() -> Result<()> {
+    let (_guard, _ctx) = crate::tests::create_query_context().await?;
+
+    let job = Arc::new(CountingJob::default());
+    let weak_job: Weak<dyn BackgroundJob> = Arc::downgrade(&job);
+    let task = BackgroundTask::create("counting", "Test", Duration::from_secs(3600), weak_job);
+    let tasks = BackgroundTasks::create();
+    tasks.start(task);
+
+    let task = tasks.get("counting")?;
+    let info = task.info();
+    assert_eq!(info.state, "Scheduled");
+    assert_eq!(info.interval_secs, 3600);
+    assert_eq!(info.runs, 0);
+    assert!(info.last_run_on.is_none());
+    assert_eq!(info.progress, "nothing buffered");
+
+    // Woken up before the interval elapses.
+    task.wake();
+    wait_runs(&job, 1).await;
+
+    // The suspended task doesn't run once woken up, but runs once triggered.
+    task.suspend();
+    task.wake();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(job.runs.load(Ordering::SeqCst), 1);
+    assert_eq!(task.info().state, "Suspended");
+    assert!(task.info().next_run_on.is_none());
+
+    task.trigger();
+    wait_runs(&job, 2).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let info = task.info();
+    assert_eq!(info.state, "Suspended");
+    assert_eq!(info.runs, 2);
+    assert_eq!(info.failures, 0);
+    assert_eq!(info.last_result.as_deref(), Some("run 2"));
+    assert!(info.last_run_on.is_some());
+
+    task.resume();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let info = task.info();
+    assert_eq!(info.state, "Scheduled");
+    assert!(info.next_run_on.is_some());
+
+    let unknown = tasks.get("unknown");
+    assert_eq!(
+        unknown.err().map(|cause| cause.code()),
+        Some(ErrorCode::UnknownBackgroundTaskCode())
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::CompactionWindow;
+
+#[test]
+fn test_compaction_window() -> Result<()> {
+    // Any time.
+    assert!(CompactionWindow::parse("")?.is_none());
+
+    let window = CompactionWindow::parse("01:30-05:00")?.unwrap();
+    assert!(!window.contains(60 + 29));
+    assert!(window.contains(60 + 30));
+    assert!(window.contains(4 * 60 + 59));
+    assert!(!window.contains(5 * 60));
+
+    // Wraps past the midnight.
+    let window = CompactionWindow::parse(" 22:00 - 04:00 ")?.unwrap();
+    assert!(window.contains(23 * 60));
+    assert!(window.contains(0));
+    assert!(window.contains(3 * 60 + 59));
+    assert!(!window.contains(4 * 60));
+    assert!(!window.contains(12 * 60));
+
+    for window in ["22:00", "24:00-01:00", "01:60-02:00", "a:00-01:00"] {
+        assert_eq!(
+            CompactionWindow::parse(window)
+                .err()
+                .map(|cause| cause.code()),
+            Some(ErrorCode::InvalidConfigCode())
+        );
+    }
+    Ok(())
+}
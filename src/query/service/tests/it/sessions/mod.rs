@@ -11,7 +11,13 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+mod background_tasks;
+mod compaction_scheduler;
 mod query_ctx;
+mod query_log_persister;
+mod query_queue;
+mod query_result_cache;
+mod resource_group_queues;
 mod session;
 mod session_context;
 mod session_setting;
@@ -0,0 +1,96 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use databend_query::sessions::QueryLogPersister;
+use databend_query::storages::system::LogType;
+use databend_query::storages::system::QueryLogElement;
+
+fn create_event(tenant_id: &str, query_id: &str) -> QueryLogElement {
+    QueryLogElement {
+        log_type: LogType::Finish,
+        handler_type: "MySQL".to_string(),
+        tenant_id: tenant_id.to_string(),
+        cluster_id: "test_cluster".to_string(),
+        sql_user: "root".to_string(),
+        sql_user_quota: "".to_string(),
+        sql_user_privileges: "".to_string(),
+        query_id: query_id.to_string(),
+        query_kind: "Query".to_string(),
+        query_text: "SELECT 1".to_string(),
+        query_tag: "".to_string(),
+        event_date: 0,
+        event_time: 0,
+        current_database: "default".to_string(),
+        databases: "".to_string(),
+        tables: "".to_string(),
+        columns: "".to_string(),
+        projections: "".to_string(),
+        written_rows: 0,
+        written_bytes: 0,
+        written_io_bytes: 0,
+        written_io_bytes_cost_ms: 0,
+        scan_rows: 0,
+        scan_bytes: 0,
+        scan_io_bytes: 0,
+        scan_io_bytes_cost_ms: 0,
+        scan_partitions: 0,
+        total_partitions: 0,
+        result_rows: 1,
+        result_bytes: 1,
+        cpu_usage: 0,
+        memory_usage: 0,
+        queued_time_ms: 0,
+        client_info: "".to_string(),
+        client_address: "".to_string(),
+        exception_code: 0,
+        exception_text: "".to_string(),
+        stack_trace: "".to_string(),
+        server_version: "".to_string(),
+        session_settings: "".to_string(),
+        extra: "".to_string(),
+    }
+}
+
+#[test]
+fn test_query_log_persister_buffer() -> Result<()> {
+    let persister = QueryLogPersister::create(true, Duration::from_secs(10), 1000);
+    persister.append(&create_event("tenant_1", "query_1"));
+    persister.append(&create_event("tenant_2", "query_2"));
+    persister.append(&create_event("tenant_1", "query_3"));
+
+    let events = persister.take_events();
+    assert_eq!(events.len(), 2);
+    let query_ids = events["tenant_1"]
+        .iter()
+        .map(|event| event.query_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(query_ids, vec!["query_1", "query_3"]);
+    assert_eq!(events["tenant_2"].len(), 1);
+
+    // The events are taken once.
+    assert!(persister.take_events().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_query_log_persister_disabled() -> Result<()> {
+    let persister = QueryLogPersister::create(false, Duration::from_secs(10), 1000);
+    persister.append(&create_event("tenant_1", "query_1"));
+    assert!(!persister.is_enabled());
+    assert!(persister.take_events().is_empty());
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::QueryQueue;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_queue_running_queries() -> Result<()> {
+    let queue = QueryQueue::create(1, 0, 1, Duration::from_secs(10));
+
+    let permit = queue.acquire(0).await?;
+    assert_eq!(queue.running_queries(), 1);
+
+    // The second query waits, the third one is rejected.
+    let waiting = {
+        let queue = queue.clone();
+        tokio::spawn(async move { queue.acquire(0).await })
+    };
+    while queue.queued_queries() != 1 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let rejected = queue.acquire(0).await;
+    assert_eq!(
+        rejected.err().map(|cause| cause.code()),
+        Some(ErrorCode::QueryQueueFullCode())
+    );
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    drop(permit);
+
+    let permit = waiting.await.unwrap()?;
+    assert!(permit.queued_time() >= Duration::from_millis(50));
+    assert_eq!(queue.running_queries(), 1);
+    assert_eq!(queue.queued_queries(), 0);
+
+    drop(permit);
+    assert_eq!(queue.running_queries(), 0);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_queue_reserved_memory() -> Result<()> {
+    let queue = QueryQueue::create(0, 100, 10, Duration::from_millis(100));
+
+    let permit1 = queue.acquire(60).await?;
+    let permit2 = queue.acquire(40).await?;
+
+    // The reservation exceeds the limit until the queries finish.
+    let timeout = queue.acquire(10).await;
+    assert_eq!(
+        timeout.err().map(|cause| cause.code()),
+        Some(ErrorCode::QueryQueueTimeoutCode())
+    );
+    assert_eq!(queue.queued_queries(), 0);
+
+    // The query without the max_memory_usage reserves all the memory.
+    drop(permit1);
+    drop(permit2);
+    let permit = queue.acquire(0).await?;
+    let timeout = queue.acquire(10).await;
+    assert!(timeout.is_err());
+
+    drop(permit);
+    let _permit = queue.acquire(10).await?;
+    assert_eq!(queue.running_queries(), 1);
+    Ok(())
+}
@@ -0,0 +1,168 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::sessions::QueryResultCache;
+use databend_query::sessions::QueryResultCacheKey;
+use databend_query::sessions::QueryResultCacheWriter;
+
+fn create_key(query: &str, snapshot: &str) -> QueryResultCacheKey {
+    QueryResultCacheKey {
+        tenant: "test".to_string(),
+        user: "'root'@'%'".to_string(),
+        role: None,
+        database: "default".to_string(),
+        query: query.to_string(),
+        settings: vec![],
+        snapshots: vec![format!("default.default.t#1@{}", snapshot)],
+    }
+}
+
+fn create_block(rows: i32) -> DataBlock {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+    DataBlock::create(schema, vec![Series::from_data(
+        (0..rows).collect::<Vec<_>>(),
+    )])
+}
+
+#[test]
+fn test_query_result_cache_get_put() -> Result<()> {
+    let cache = QueryResultCache::create(1024 * 1024);
+    let key = create_key("SELECT * FROM t", "snapshot_1");
+    assert!(cache.get(&key).is_none());
+
+    cache.put(
+        key.clone(),
+        "query_1".to_string(),
+        vec![create_block(3), create_block(2)],
+        Duration::from_secs(60),
+    );
+
+    let blocks = cache.get(&key).unwrap();
+    assert_eq!(
+        blocks.iter().map(|block| block.num_rows()).sum::<usize>(),
+        5
+    );
+
+    // The table is changed, the result of the old snapshot is not served.
+    assert!(
+        cache
+            .get(&create_key("SELECT * FROM t", "snapshot_2"))
+            .is_none()
+    );
+    assert!(
+        cache
+            .get(&create_key("SELECT a FROM t", "snapshot_1"))
+            .is_none()
+    );
+
+    let info = cache.entries_info("test");
+    assert_eq!(info.len(), 1);
+    assert_eq!(info[0].query_id, "query_1");
+    assert_eq!(info[0].num_rows, 5);
+    assert_eq!(info[0].hits, 1);
+    assert!(cache.entries_info("other").is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_result_cache_expired() -> Result<()> {
+    let cache = QueryResultCache::create(1024 * 1024);
+    let key = create_key("SELECT * FROM t", "snapshot_1");
+
+    cache.put(
+        key.clone(),
+        "query_1".to_string(),
+        vec![create_block(3)],
+        Duration::from_millis(50),
+    );
+    assert!(cache.get(&key).is_some());
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(cache.get(&key).is_none());
+    assert!(cache.entries_info("test").is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_query_result_cache_evict_oldest() -> Result<()> {
+    let block = create_block(100);
+    let cache = QueryResultCache::create(block.memory_size() * 2);
+
+    let ttl = Duration::from_secs(60);
+    let keys = (0..3)
+        .map(|i| create_key(&format!("SELECT {}", i), "snapshot_1"))
+        .collect::<Vec<_>>();
+    for (i, key) in keys.iter().enumerate() {
+        cache.put(
+            key.clone(),
+            format!("query_{}", i),
+            vec![block.clone()],
+            ttl,
+        );
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    assert!(cache.get(&keys[0]).is_none());
+    assert!(cache.get(&keys[1]).is_some());
+    assert!(cache.get(&keys[2]).is_some());
+
+    // The result larger than the cache is not cached.
+    let key = create_key("SELECT large", "snapshot_1");
+    let large = create_block(1000);
+    cache.put(key.clone(), "query_3".to_string(), vec![large], ttl);
+    assert!(cache.get(&key).is_none());
+    assert_eq!(cache.entries_info("test").len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_query_result_cache_writer() -> Result<()> {
+    let cache = QueryResultCache::create(1024 * 1024);
+    let ttl = Duration::from_secs(60);
+
+    // The result is cached once all the inputs are finished.
+    let key = create_key("SELECT 1", "snapshot_1");
+    let writer =
+        QueryResultCacheWriter::create(cache.clone(), key.clone(), "query_1".to_string(), ttl, 2);
+    writer.append(&create_block(3));
+    writer.finish_input();
+    writer.commit();
+    assert!(cache.get(&key).is_none());
+
+    writer.append(&create_block(2));
+    writer.finish_input();
+    writer.commit();
+    let blocks = cache.get(&key).unwrap();
+    assert_eq!(
+        blocks.iter().map(|block| block.num_rows()).sum::<usize>(),
+        5
+    );
+
+    // The result is not cached if the output is not read to the end.
+    let key = create_key("SELECT 2", "snapshot_1");
+    let writer =
+        QueryResultCacheWriter::create(cache.clone(), key.clone(), "query_2".to_string(), ttl, 1);
+    writer.append(&create_block(3));
+    writer.abort();
+    writer.finish_input();
+    writer.commit();
+    assert!(cache.get(&key).is_none());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_meta_types::ResourceGroup;
+use databend_query::sessions::ResourceGroupQueues;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resource_group_queues() -> Result<()> {
+    let queues = ResourceGroupQueues::create(10, Duration::from_secs(10));
+    let etl = ResourceGroup::new("etl", 50, 0, 1, "");
+    let adhoc = ResourceGroup::new("adhoc", 10, 0, 1, "");
+
+    // The groups are limited in their own queues.
+    let etl_queue = queues.get_queue("tenant", &etl);
+    let _etl_permit = etl_queue.acquire(0).await?;
+    assert_eq!(etl_queue.running_queries(), 1);
+    assert!(Arc::ptr_eq(&etl_queue, &queues.get_queue("tenant", &etl)));
+
+    let adhoc_queue = queues.get_queue("tenant", &adhoc);
+    let _adhoc_permit = adhoc_queue.acquire(0).await?;
+    assert_eq!(adhoc_queue.running_queries(), 1);
+    assert!(!Arc::ptr_eq(&etl_queue, &queues.get_queue("other", &etl)));
+
+    // The queue is recreated if the limits of the group are changed.
+    let etl = ResourceGroup::new("etl", 50, 0, 2, "");
+    let new_etl_queue = queues.get_queue("tenant", &etl);
+    assert!(!Arc::ptr_eq(&etl_queue, &new_etl_queue));
+    assert_eq!(new_etl_queue.running_queries(), 0);
+
+    // The threads of the queries are scaled by the cpu_share.
+    assert_eq!(etl.max_threads(16), 8);
+    assert_eq!(adhoc.max_threads(4), 1);
+    Ok(())
+}
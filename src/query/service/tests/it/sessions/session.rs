@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use common_base::base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
 
 use crate::tests::ConfigBuilder;
 use crate::tests::TestGlobalServices;
@@ -46,6 +52,14 @@ async fn test_session() -> Result<()> {
         assert_eq!(actual, 3);
     }
 
+    // Current role.
+    {
+        assert_eq!(session.get_current_role(), None);
+
+        session.set_current_role(Some("role1".to_string()));
+        assert_eq!(session.get_current_role(), Some("role1".to_string()));
+    }
+
     Ok(())
 }
 
@@ -69,3 +83,100 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_by_query_id() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let session_manager = SessionManager::instance();
+    let session = session_manager.create_session(SessionType::Dummy).await?;
+    let ctx = session.create_query_context().await?;
+
+    let found = session_manager.get_session_by_query_id("test", &ctx.get_id());
+    assert_eq!(found.map(|found| found.get_id()), Some(session.get_id()));
+
+    // The query of the other tenants is not found.
+    let found = session_manager.get_session_by_query_id("tenant2", &ctx.get_id());
+    assert!(found.is_none());
+    let found = session_manager.get_session_by_query_id("test", "unknown_query_id");
+    assert!(found.is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_max_connections_per_user() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(ConfigBuilder::create().max_connections_per_user(1).build())
+            .await?;
+    let session_manager = SessionManager::instance();
+    let user = UserInfo::new("u1", "%", AuthInfo::None);
+
+    let session1 = session_manager.create_session(SessionType::MySQL).await?;
+    session_manager.check_user_connections(&session1, &user)?;
+    session1.set_current_user(user.clone());
+
+    // The connection of the user exceeds the limit, those of the others don't.
+    let session2 = session_manager.create_session(SessionType::MySQL).await?;
+    let res = session_manager.check_user_connections(&session2, &user);
+    assert_eq!(
+        res.err().map(|cause| cause.code()),
+        Some(ErrorCode::TooManyUserConnectionsCode())
+    );
+    let other = UserInfo::new("u2", "%", AuthInfo::None);
+    session_manager.check_user_connections(&session2, &other)?;
+
+    // The sessions of the node itself are not counted.
+    drop(session1);
+    let task_session = session_manager
+        .create_session(SessionType::Task("t1".to_string()))
+        .await?;
+    task_session.set_current_user(user.clone());
+    session_manager.check_user_connections(&session2, &user)?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_expiry() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let session_manager = SessionManager::instance();
+    let idle_timeout = Duration::from_millis(100);
+    let max_lifetime = Duration::from_millis(300);
+
+    let idle_session = session_manager.create_session(SessionType::MySQL).await?;
+    let busy_session = session_manager.create_session(SessionType::MySQL).await?;
+    let ctx = busy_session.create_query_context().await?;
+    assert_eq!(
+        session_manager.expire_sessions(idle_timeout, max_lifetime),
+        0
+    );
+
+    // The session running a query is not idle.
+    tokio::time::sleep(idle_timeout).await;
+    assert_eq!(
+        session_manager.expire_sessions(idle_timeout, max_lifetime),
+        1
+    );
+    assert!(idle_session.is_aborting());
+    assert!(!busy_session.is_aborting());
+
+    // The running query is killed along with the session once it lives too long.
+    tokio::time::sleep(max_lifetime).await;
+    assert_eq!(
+        session_manager.expire_sessions(idle_timeout, max_lifetime),
+        1
+    );
+    assert!(busy_session.is_aborting());
+    drop(ctx);
+
+    // Zero keeps the sessions.
+    let session = session_manager.create_session(SessionType::MySQL).await?;
+    tokio::time::sleep(idle_timeout).await;
+    assert_eq!(
+        session_manager.expire_sessions(Duration::ZERO, Duration::ZERO),
+        0
+    );
+    assert!(!session.is_aborting());
+
+    Ok(())
+}
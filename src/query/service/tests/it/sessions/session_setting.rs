@@ -16,6 +16,7 @@ use common_base::base::tokio;
 use common_exception::Result;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
+use databend_query::sessions::Settings;
 
 use crate::tests::TestGlobalServices;
 
@@ -35,5 +36,39 @@ async fn test_session_setting() -> Result<()> {
         assert_eq!(actual, expect);
     }
 
+    // The global settings are the defaults of the new sessions.
+    {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let settings = session.get_settings();
+        assert_eq!(settings.get_max_threads()?, 3);
+        assert_eq!(setting_level(&settings, "max_threads"), "GLOBAL");
+        assert_eq!(setting_level(&settings, "max_block_size"), "DEFAULT");
+
+        settings.set_settings("max_threads".to_string(), "4".to_string(), false)?;
+        assert_eq!(settings.get_max_threads()?, 4);
+        assert_eq!(setting_level(&settings, "max_threads"), "SESSION");
+    }
+
+    // The session settings are not seen by the other sessions.
+    {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let settings = session.get_settings();
+        assert_eq!(settings.get_max_threads()?, 3);
+        assert_eq!(setting_level(&settings, "max_threads"), "GLOBAL");
+    }
+
     Ok(())
 }
+
+fn setting_level(settings: &Settings, name: &str) -> String {
+    settings
+        .get_setting_values()
+        .into_iter()
+        .find(|value| value.0 == name)
+        .map(|value| value.3)
+        .unwrap_or_default()
+}
@@ -13,4 +13,6 @@
 // limitations under the License.
 
 mod format;
+mod plan_cache;
+mod plan_hint;
 mod semantic;
@@ -0,0 +1,144 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_api::SchemaChange;
+use common_meta_app::schema::TableId;
+use databend_query::sql::PlanCache;
+use databend_query::sql::PlanCacheKey;
+use databend_query::sql::PlanCacheStatement;
+use databend_query::sql::PlanCacheTable;
+use databend_query::sql::Planner;
+
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+fn statement(sql: &str, dialect: Dialect) -> Option<PlanCacheStatement> {
+    let tokens = tokenize_sql(sql).unwrap();
+    PlanCacheStatement::try_create(&tokens, dialect)
+}
+
+#[test]
+fn test_plan_cache_statement() -> Result<()> {
+    let s1 = statement("SELECT a FROM t WHERE b = 1 AND c > 'x'", Dialect::MySQL).unwrap();
+    let s2 = statement("SELECT a FROM t WHERE b = 300 AND c > 'y'", Dialect::MySQL).unwrap();
+    assert_eq!(s1.shape, "SELECT a FROM t WHERE b = ? AND c > ?");
+    assert_eq!(s1.shape, s2.shape);
+    assert_eq!(s1.literals, vec!["1", "'x'"]);
+    assert_eq!(s2.literals, vec!["300", "'y'"]);
+
+    // The double quoted strings are identifiers of PostgreSQL.
+    let s = statement("SELECT \"a\" FROM t WHERE b = \"x\"", Dialect::MySQL).unwrap();
+    assert_eq!(s.literals, vec!["\"a\"", "\"x\""]);
+    let s = statement("SELECT \"a\" FROM t WHERE b = \"x\"", Dialect::PostgreSQL).unwrap();
+    assert!(s.literals.is_empty());
+
+    // The plans of the statements with hints are not cached.
+    assert!(statement("SELECT /*+ SET_VAR(max_threads=1) */ 1", Dialect::MySQL).is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_plan_cache_parameterized() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let cache = PlanCache::create(16);
+    let sql = format!("SELECT id FROM {}.{} WHERE id > 1", db, tbl);
+    let (plan, metadata, format) = Planner::new(ctx.clone()).plan_sql(&sql).await?;
+    let tables = metadata
+        .read()
+        .tables()
+        .iter()
+        .map(|table| {
+            let table_info = table.table().get_table_info().clone();
+            PlanCacheTable {
+                catalog: table.catalog().to_string(),
+                database: table.database().to_string(),
+                name: table.name().to_string(),
+                table_id: table_info.ident.table_id,
+                seq: table_info.ident.seq,
+            }
+        })
+        .collect::<Vec<_>>();
+    let table_id = tables[0].table_id;
+
+    // Nothing is cached while the schema changes are not watched.
+    let s1 = statement(&sql, Dialect::PostgreSQL).unwrap();
+    let keys = PlanCacheKey::try_create(ctx.as_ref(), &s1)?;
+    cache.insert(
+        cache.version(),
+        keys.clone(),
+        &s1,
+        &ctx.get_current_database(),
+        &plan,
+        &metadata,
+        &format,
+        tables.clone(),
+    );
+    assert!(cache.is_empty());
+
+    cache.set_watching(true);
+    cache.insert(
+        cache.version(),
+        keys.clone(),
+        &s1,
+        &ctx.get_current_database(),
+        &plan,
+        &metadata,
+        &format,
+        tables.clone(),
+    );
+    assert_eq!(cache.len(), 1);
+
+    // The plan is parameterized by the literal compared with the column.
+    let sql2 = format!("SELECT id FROM {}.{} WHERE id > 2", db, tbl);
+    let s2 = statement(&sql2, Dialect::PostgreSQL).unwrap();
+    let keys2 = PlanCacheKey::try_create(ctx.as_ref(), &s2)?;
+    assert!(cache.get(&keys2.1, &s2, &sql2).is_none());
+    let (cached, _, _, cached_tables) = cache.get(&keys2.0, &s2, &sql2).unwrap();
+    assert_eq!(cached_tables, tables);
+    let cached = format!("{:?}", cached);
+    assert!(cached.contains("value: 2"), "{}", cached);
+    assert!(!cached.contains("value: 1,"), "{}", cached);
+
+    // The literal of another type has another plan.
+    let sql3 = format!("SELECT id FROM {}.{} WHERE id > 1000", db, tbl);
+    let s3 = statement(&sql3, Dialect::PostgreSQL).unwrap();
+    let keys3 = PlanCacheKey::try_create(ctx.as_ref(), &s3)?;
+    assert!(cache.get(&keys3.0, &s3, &sql3).is_none());
+
+    // The plans of the changed table are invalidated, and the plan bound before is not cached.
+    let version = cache.version();
+    cache.invalidate(&SchemaChange::Table(TableId { table_id }));
+    assert!(cache.is_empty());
+    cache.insert(
+        version,
+        keys,
+        &s1,
+        &ctx.get_current_database(),
+        &plan,
+        &metadata,
+        &format,
+        tables,
+    );
+    assert!(cache.is_empty());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::Planner;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_plan_hint() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let sql = format!(
+        "CREATE PLAN HINT slow_scan FOR $$SELECT id FROM {}.{} WHERE id > 1$$ USING 'MAX_EXECUTION_TIME(1000)'",
+        db, tbl
+    );
+    execute_command(ctx.clone(), &sql).await?;
+
+    // The queries of the other shapes are not hinted.
+    let sql = format!("SELECT id FROM {}.{} WHERE id < 1", db, tbl);
+    Planner::new(ctx.clone()).plan_sql(&sql).await?;
+    assert_eq!(ctx.get_query_max_execution_time(), None);
+
+    // The queries of the same shape are hinted, whatever their literals are.
+    let sql = format!("SELECT id FROM {}.{} WHERE id > 5", db, tbl);
+    Planner::new(ctx.clone()).plan_sql(&sql).await?;
+    assert_eq!(
+        ctx.get_query_max_execution_time(),
+        Some(Duration::from_secs(1))
+    );
+
+    let sql = "CREATE PLAN HINT h FOR $$SELECT 1$$ USING 'NO_SUCH_HINT(t)'";
+    let res = execute_command(ctx.clone(), sql).await;
+    assert!(res.is_err());
+
+    let sql = "CREATE PLAN HINT h FOR $$DROP TABLE t$$ USING 'BROADCAST(t)'";
+    let res = execute_command(ctx.clone(), sql).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::bad_arguments_code());
+
+    execute_command(ctx.clone(), "DROP PLAN HINT slow_scan").await?;
+    Ok(())
+}
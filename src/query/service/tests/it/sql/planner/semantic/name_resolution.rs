@@ -72,6 +72,7 @@ fn test_normalize_identifier_quoted_case_insensitive() {
     let ctx = NameResolutionContext {
         unquoted_ident_case_sensitive: false,
         quoted_ident_case_sensitive: false,
+        ident_uppercase: false,
     };
 
     {
@@ -99,6 +100,7 @@ fn test_normalize_identifier_unquoted_case_sensitive() {
     let ctx = NameResolutionContext {
         unquoted_ident_case_sensitive: true,
         quoted_ident_case_sensitive: true,
+        ident_uppercase: false,
     };
 
     {
@@ -121,6 +123,45 @@ fn test_normalize_identifier_unquoted_case_sensitive() {
     }
 }
 
+#[test]
+fn test_normalize_identifier_uppercase() {
+    let ctx = NameResolutionContext {
+        unquoted_ident_case_sensitive: false,
+        quoted_ident_case_sensitive: true,
+        ident_uppercase: true,
+    };
+
+    {
+        // Unquoted
+        let ident = Identifier {
+            name: "FooBar Это ИДЕНТификатор".to_string(),
+            quote: None,
+            span: Token {
+                source: "",
+                kind: TokenKind::Ident,
+                span: (0..0),
+            },
+        };
+        let norm_name = normalize_identifier(&ident, &ctx).name;
+        assert_eq!(norm_name, "FOOBAR ЭТО ИДЕНТИФИКАТОР");
+    }
+
+    {
+        // Quoted
+        let ident = Identifier {
+            name: "FooBar Это ИДЕНТификатор".to_string(),
+            quote: Some('"'),
+            span: Token {
+                source: "",
+                kind: TokenKind::Ident,
+                span: (0..0),
+            },
+        };
+        let norm_name = normalize_identifier(&ident, &ctx).name;
+        assert_eq!(norm_name, "FooBar Это ИДЕНТификатор");
+    }
+}
+
 #[test]
 fn test_normalize_identifiers_in_expr() {
     let tokens = tokenize_sql("exists(select func(\"T\".A+1) as B)").unwrap();
@@ -0,0 +1,97 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_pipeline_sources::processors::sources::input_formats::input_format_avro::AvroFileInMemory;
+
+// The file is written with the schema of the record
+//   {id: long, name: string, score: [null, double], day: date, color: enum, weight: float}
+// in 3 blocks of 10, 10 and 5 records. The id of the rows are from 0 to 24, the score is NULL
+// for the ids divisible by 10, and the half of the id for the others.
+fn read_readings_file(schema: &DataSchemaRef, fill_null: bool) -> Result<AvroFileInMemory> {
+    let path = format!(
+        "{}/../../../tests/data/readings.avro",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let data = std::fs::read(&path)?;
+    AvroFileInMemory::try_create(&path, data, schema, fill_null)
+}
+
+#[test]
+fn test_avro_read_blocks() -> Result<()> {
+    // The fields are matched by name, `weight` is not read.
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("color", Vu8::to_data_type()),
+        DataField::new("id", i32::to_data_type()),
+        DataField::new("name", Vu8::to_data_type()),
+        DataField::new_nullable("score", f64::to_data_type()),
+        DataField::new("day", DateType::new_impl()),
+    ]);
+    let file = read_readings_file(&schema, false)?;
+    let blocks = file.read_blocks(&schema)?;
+
+    // Each block of the file is read into a block.
+    let rows = blocks.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![10, 10, 5]);
+
+    let block = &blocks[1];
+    assert_eq!(block.num_columns(), 5);
+    // The enums are read as their symbols, the longs are cast to the ints.
+    assert_eq!(block.column(0).get(0), DataValue::String(b"green".to_vec()));
+    assert_eq!(block.column(1).get(0), DataValue::Int64(10));
+    assert_eq!(
+        block.column(2).get(9),
+        DataValue::String(b"name_19".to_vec())
+    );
+    // The unions with null are read as nullable.
+    assert_eq!(block.column(3).get(0), DataValue::Null);
+    assert_eq!(block.column(3).get(1), DataValue::Float64(5.5));
+    // The dates are the days since the epoch.
+    assert_eq!(block.column(4).get(0), DataValue::Int64(19010));
+
+    let block = &blocks[2];
+    assert_eq!(block.column(1).get(4), DataValue::Int64(24));
+    assert_eq!(block.column(3).get(4), DataValue::Float64(12.0));
+
+    Ok(())
+}
+
+#[test]
+fn test_avro_missing_fields() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", i64::to_data_type()),
+        DataField::new_nullable("note", Vu8::to_data_type()),
+    ]);
+
+    // The fields missing from the file are errors by default.
+    let err = read_readings_file(&schema, false).err().unwrap();
+    assert!(err.message().contains("note"), "{}", err.message());
+
+    // Or loaded as NULL.
+    let file = read_readings_file(&schema, true)?;
+    let blocks = file.read_blocks(&schema)?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 25);
+    for block in blocks {
+        let column = block.column(1);
+        assert!((0..block.num_rows()).all(|row| column.null_at(row)));
+    }
+
+    // At least one of the fields has to be found.
+    let schema =
+        DataSchemaRefExt::create(vec![DataField::new_nullable("note", Vu8::to_data_type())]);
+    assert!(read_readings_file(&schema, true).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,194 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_storage::StorageParams;
+use common_storage::StorageS3Config;
+use databend_query::storages::delta::decode_stats_value;
+use databend_query::storages::delta::parse_commit;
+use databend_query::storages::delta::parse_log_file_name;
+use databend_query::storages::delta::DeltaLogFile;
+use databend_query::storages::delta::DeltaSchema;
+use databend_query::storages::delta::DeltaTableOptions;
+use databend_query::storages::delta::Protocol;
+
+const COMMIT: &str = r#"{"commitInfo":{"timestamp":1665000100000,"operation":"WRITE"}}
+{"protocol":{"minReaderVersion":2,"minWriterVersion":5}}
+{"metaData":{"id":"5f6e1c2a","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":false,\"metadata\":{\"delta.columnMapping.id\":1,\"delta.columnMapping.physicalName\":\"col-0b3c\"}},{\"name\":\"name\",\"type\":\"string\",\"nullable\":true,\"metadata\":{\"delta.columnMapping.id\":2,\"delta.columnMapping.physicalName\":\"col-7e1a\"}},{\"name\":\"dt\",\"type\":\"string\",\"nullable\":true,\"metadata\":{\"delta.columnMapping.id\":3,\"delta.columnMapping.physicalName\":\"col-9d42\"}}]}","partitionColumns":["dt"],"configuration":{"delta.columnMapping.mode":"name"},"createdTime":1665000100000}}
+{"add":{"path":"col-9d42=2022-10-01/part-00000%20a.snappy.parquet","partitionValues":{"col-9d42":"2022-10-01"},"size":1024,"modificationTime":1665000100000,"dataChange":true,"stats":"{\"numRecords\":3,\"minValues\":{\"col-0b3c\":1,\"col-7e1a\":\"a\"},\"maxValues\":{\"col-0b3c\":3,\"col-7e1a\":\"c\"},\"nullCount\":{\"col-0b3c\":0,\"col-7e1a\":1}}"}}
+{"remove":{"path":"col-9d42=2022-09-30/part-00000.snappy.parquet","deletionTimestamp":1665000100000,"dataChange":true}}
+"#;
+
+#[test]
+fn test_delta_table_options() -> Result<()> {
+    let options = DeltaTableOptions {
+        storage: StorageParams::S3(StorageS3Config {
+            bucket: "bucket".to_string(),
+            root: "/lake/t/".to_string(),
+            ..Default::default()
+        }),
+        version: None,
+    };
+    let engine_options = BTreeMap::try_from(&options)?;
+    assert!(!engine_options.contains_key("version"));
+    assert_eq!(DeltaTableOptions::try_from(&engine_options)?, options);
+
+    let options = DeltaTableOptions {
+        version: Some(12),
+        ..options
+    };
+    let engine_options = BTreeMap::try_from(&options)?;
+    assert_eq!(engine_options.get("version"), Some(&"12".to_string()));
+    assert_eq!(DeltaTableOptions::try_from(&engine_options)?, options);
+
+    Ok(())
+}
+
+#[test]
+fn test_delta_log_file_name() {
+    assert_eq!(
+        parse_log_file_name("00000000000000000010.json"),
+        Some(DeltaLogFile::Commit(10))
+    );
+    assert_eq!(
+        parse_log_file_name("00000000000000000010.checkpoint.parquet"),
+        Some(DeltaLogFile::Checkpoint {
+            version: 10,
+            part: 1,
+            parts: 1
+        })
+    );
+    assert_eq!(
+        parse_log_file_name("00000000000000000020.checkpoint.0000000002.0000000003.parquet"),
+        Some(DeltaLogFile::Checkpoint {
+            version: 20,
+            part: 2,
+            parts: 3
+        })
+    );
+    assert_eq!(parse_log_file_name("_last_checkpoint"), None);
+    assert_eq!(parse_log_file_name("00000000000000000010.crc"), None);
+    assert_eq!(parse_log_file_name("10.json"), None);
+}
+
+#[test]
+fn test_delta_commit() -> Result<()> {
+    let actions = parse_commit("_delta_log/00000000000000000000.json", COMMIT.as_bytes())?;
+    // The commit info is ignored.
+    assert_eq!(actions.len(), 5);
+    assert!(actions[0].add.is_none() && actions[0].meta_data.is_none());
+
+    let protocol = actions[1].protocol.as_ref().unwrap();
+    protocol.check_readable()?;
+
+    let metadata = actions[2].meta_data.as_ref().unwrap();
+    assert!(metadata.column_mapping());
+    assert_eq!(metadata.partition_columns, vec!["dt".to_string()]);
+
+    let add = actions[3].add.as_ref().unwrap();
+    assert_eq!(
+        add.location()?,
+        "col-9d42=2022-10-01/part-00000 a.snappy.parquet"
+    );
+    assert_eq!(
+        add.partition_values.get("col-9d42"),
+        Some(&Some("2022-10-01".to_string()))
+    );
+    let stats = add.stats().unwrap();
+    assert_eq!(stats.num_records, Some(3));
+    assert_eq!(stats.null_count["col-7e1a"], serde_json::json!(1));
+
+    assert_eq!(
+        actions[4].remove.as_ref().unwrap().path,
+        "col-9d42=2022-09-30/part-00000.snappy.parquet"
+    );
+
+    assert!(parse_commit("_delta_log/00000000000000000001.json", b"{\"add\":").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_delta_protocol() -> Result<()> {
+    let protocol: Protocol = serde_json::from_str(
+        r#"{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["columnMapping"]}"#,
+    )?;
+    protocol.check_readable()?;
+
+    let protocol: Protocol = serde_json::from_str(
+        r#"{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["deletionVectors"]}"#,
+    )?;
+    assert!(protocol.check_readable().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_delta_schema() -> Result<()> {
+    let actions = parse_commit("_delta_log/00000000000000000000.json", COMMIT.as_bytes())?;
+    let metadata = actions[2].meta_data.as_ref().unwrap();
+    let schema = DeltaSchema::parse(&metadata.schema_string)?;
+
+    let data_schema = schema.to_data_schema()?;
+    assert_eq!(data_schema.num_fields(), 3);
+    assert!(!data_schema.field(0).is_nullable());
+    assert!(data_schema.field(1).is_nullable());
+
+    let field = schema.field_by_name("NAME").unwrap();
+    assert_eq!(field.physical_name(true), "col-7e1a");
+    assert_eq!(field.physical_name(false), "name");
+    assert!(schema.field_by_name("other").is_none());
+
+    let schema = DeltaSchema::parse(
+        r#"{"type":"struct","fields":[{"name":"d","type":"decimal(10,2)","nullable":true,"metadata":{}}]}"#,
+    )?;
+    assert!(schema.to_data_schema().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_delta_decode_stats_value() {
+    let long = serde_json::json!("long");
+    assert_eq!(
+        decode_stats_value(&long, &serde_json::json!(42)),
+        Some(DataValue::Int64(42))
+    );
+    assert_eq!(
+        decode_stats_value(&serde_json::json!("double"), &serde_json::json!(1.5)),
+        Some(DataValue::Float64(1.5))
+    );
+    assert_eq!(
+        decode_stats_value(&serde_json::json!("date"), &serde_json::json!("1970-01-11")),
+        Some(DataValue::Int64(10))
+    );
+    assert_eq!(
+        decode_stats_value(&serde_json::json!("string"), &serde_json::json!("abc")),
+        Some(DataValue::String(b"abc".to_vec()))
+    );
+    // The strings of the prefix length may be truncated.
+    let truncated = "a".repeat(32);
+    assert_eq!(
+        decode_stats_value(&serde_json::json!("string"), &serde_json::json!(truncated)),
+        None
+    );
+    assert_eq!(
+        decode_stats_value(
+            &serde_json::json!("timestamp"),
+            &serde_json::json!("2022-10-01T00:00:00.000Z")
+        ),
+        None
+    );
+    assert_eq!(decode_stats_value(&long, &serde_json::json!("NaN")), None);
+}
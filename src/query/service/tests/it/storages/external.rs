@@ -0,0 +1,129 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::FileFormatOptions;
+use common_meta_types::StageFileCompression;
+use common_meta_types::StageFileFormatType;
+use common_pipeline_sources::processors::sources::input_formats::FileInfo;
+use common_pipeline_sources::processors::sources::input_formats::SplitInfo;
+use common_storage::StorageParams;
+use common_storage::StorageS3Config;
+use databend_query::storages::external::ExternalFilePartInfo;
+use databend_query::storages::external::ExternalTableOptions;
+use opendal::io_util::CompressAlgorithm;
+
+#[test]
+fn test_external_table_options() -> Result<()> {
+    let options = ExternalTableOptions {
+        storage: StorageParams::S3(StorageS3Config {
+            bucket: "bucket".to_string(),
+            root: "/data/".to_string(),
+            ..Default::default()
+        }),
+        path: "/ontime/".to_string(),
+        pattern: Some(".*[.]parquet".to_string()),
+        file_format: FileFormatOptions {
+            format: StageFileFormatType::Parquet,
+            ..Default::default()
+        },
+        partition_by: vec!["year".to_string(), "month".to_string()],
+    };
+
+    let engine_options = BTreeMap::try_from(&options)?;
+    assert_eq!(engine_options.get("path"), Some(&"/ontime/".to_string()));
+    assert_eq!(
+        engine_options.get("partition_by"),
+        Some(&"year month".to_string())
+    );
+    assert_eq!(ExternalTableOptions::try_from(&engine_options)?, options);
+
+    // Without pattern and partition columns.
+    let options = ExternalTableOptions {
+        pattern: None,
+        partition_by: vec![],
+        ..options
+    };
+    let engine_options = BTreeMap::try_from(&options)?;
+    assert!(!engine_options.contains_key("pattern"));
+    assert!(!engine_options.contains_key("partition_by"));
+    assert_eq!(ExternalTableOptions::try_from(&engine_options)?, options);
+
+    // The storage is required.
+    let mut engine_options = engine_options;
+    engine_options.remove("storage");
+    assert!(ExternalTableOptions::try_from(&engine_options).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_external_file_parts() -> Result<()> {
+    let file_splits = |path: &str, size: usize, offsets: Vec<(usize, usize)>| {
+        let file = Arc::new(FileInfo {
+            path: path.to_string(),
+            size,
+            num_splits: offsets.len(),
+            compress_alg: None,
+        });
+        offsets
+            .into_iter()
+            .enumerate()
+            .map(|(seq_in_file, (offset, size))| {
+                Arc::new(SplitInfo {
+                    file: file.clone(),
+                    seq_in_file,
+                    offset,
+                    size,
+                    format_info: None,
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut splits = file_splits("a.csv", 300, vec![(0, 200), (200, 100)]);
+    splits.extend(file_splits("b.csv.gz", 50, vec![(0, 50)]));
+
+    // The splits of a file are kept in one part.
+    let parts = ExternalFilePartInfo::create_parts(&splits);
+    assert_eq!(parts.len(), 2);
+    let a = ExternalFilePartInfo::from_part(&parts[0])?;
+    assert_eq!(a, &ExternalFilePartInfo {
+        location: "a.csv".to_string(),
+        size: 300,
+        splits: vec![(0, 200), (200, 100)],
+    });
+
+    let a_splits = a.to_splits(StageFileCompression::Auto)?;
+    assert_eq!(a_splits.len(), 2);
+    assert_eq!(a_splits[1].seq_in_file, 1);
+    assert_eq!(a_splits[1].offset, 200);
+    assert_eq!(a_splits[1].size, 100);
+    assert_eq!(a_splits[1].file.num_splits, 2);
+    assert!(a_splits[1].file.compress_alg.is_none());
+
+    // The compression is got from the path of the file.
+    let b = ExternalFilePartInfo::from_part(&parts[1])?;
+    let b_splits = b.to_splits(StageFileCompression::Auto)?;
+    assert_eq!(b_splits.len(), 1);
+    assert_eq!(b_splits[0].file.size, 50);
+    assert!(matches!(
+        b_splits[0].file.compress_alg,
+        Some(CompressAlgorithm::Gzip)
+    ));
+
+    Ok(())
+}
@@ -12,10 +12,34 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_cache::Cache;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_fuse_meta::caches::new_bytes_cache;
+use common_fuse_meta::caches::new_disk_bytes_cache;
+use common_fuse_meta::meta::Compression;
+use common_fuse_meta::meta::SegmentInfo;
+use common_fuse_meta::meta::Statistics;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
+use databend_query::storages::fuse::io::checksum;
+use databend_query::storages::fuse::io::coalesce_ranges;
+use databend_query::storages::fuse::io::decode_segment;
+use databend_query::storages::fuse::io::encode_segment;
+use databend_query::storages::fuse::io::verify_checksum;
+use databend_query::storages::fuse::io::BlockCompression;
+use databend_query::storages::fuse::io::ColumnCompression;
+use databend_query::storages::fuse::io::ColumnDataCacheTiers;
+use databend_query::storages::fuse::io::IOPriority;
+use databend_query::storages::fuse::io::IOScheduler;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use opendal::Operator;
+use tempfile::TempDir;
 use uuid::Uuid;
 
 #[test]
@@ -29,5 +53,209 @@ fn test_meta_locations() -> Result<()> {
     let uuid = Uuid::new_v4();
     let snapshot_loc = locs.snapshot_location_from_uuid(&uuid, TableSnapshot::VERSION)?;
     assert!(snapshot_loc.starts_with(test_prefix));
+    assert_eq!(
+        TableMetaLocationGenerator::snapshot_id(&snapshot_loc),
+        uuid.simple().to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_block_compression() -> Result<()> {
+    let options = BTreeMap::from([
+        ("compression".to_string(), "zstd(9)".to_string()),
+        (
+            "column_compression".to_string(),
+            "b = snappy, c=none".to_string(),
+        ),
+    ]);
+    let compression = BlockCompression::try_from_options(&options)?;
+    assert_eq!(compression.get("a"), ColumnCompression {
+        compression: Compression::Zstd,
+        level: Some(9),
+    });
+    assert_eq!(compression.get("b").compression, Compression::Snappy);
+    assert_eq!(compression.get("c").compression, Compression::Uncompressed);
+    assert_eq!(compression.get_default().to_string(), "zstd(9)");
+    assert_eq!(compression.get("c").to_string(), "none");
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i32::to_data_type()),
+        DataField::new("b", i32::to_data_type()),
+    ]);
+    assert!(compression.check_columns(&schema).is_err());
+
+    // lz4 is used if not specified
+    let compression = BlockCompression::try_from_options(&BTreeMap::new())?;
+    assert_eq!(compression.get("a").compression, Compression::Lz4Raw);
+
+    for invalid in ["lzo", "zstd(100)", "snappy(1)", "zstd(x)"] {
+        let options = BTreeMap::from([("compression".to_string(), invalid.to_string())]);
+        assert!(BlockCompression::try_from_options(&options).is_err());
+    }
+    let options = BTreeMap::from([("column_compression".to_string(), "a".to_string())]);
+    assert!(BlockCompression::try_from_options(&options).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_coalesce_ranges() {
+    let ranges = vec![100..200, 0..10, 20..30, 1000..1100];
+    assert_eq!(coalesce_ranges(&ranges, 16, 1024), vec![
+        (0..30, vec![1, 2]),
+        (100..200, vec![0]),
+        (1000..1100, vec![3]),
+    ]);
+
+    // the coalesced read is not larger than the max bytes
+    assert_eq!(coalesce_ranges(&ranges, 1024, 256), vec![
+        (0..200, vec![1, 2, 0]),
+        (1000..1100, vec![3]),
+    ]);
+}
+
+#[test]
+fn test_segment_checksum() -> Result<()> {
+    let segment = SegmentInfo::new(vec![], Statistics::default());
+    let data = encode_segment(&segment)?;
+    let json = decode_segment("seg", &data)?;
+    assert_eq!(json, serde_json::to_vec(&segment)?.as_slice());
+
+    // the segments written before the checksums are not verified
+    assert_eq!(decode_segment("seg", json)?, json);
+
+    // corrupted
+    let mut corrupted = data.clone();
+    corrupted[1] ^= 0xff;
+    let err = decode_segment("seg", &corrupted).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::DataCorruption("").code());
+    assert!(err.message().contains("object seg is corrupted"));
+
+    assert!(verify_checksum("chunk", b"abc", checksum(b"abc")).is_ok());
+    assert!(verify_checksum("chunk", b"abd", checksum(b"abc")).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_io_scheduler_read_ranges() -> Result<()> {
+    let operator = Operator::new(opendal::services::memory::Builder::default().build()?);
+    let data = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+    operator.object("block").write(data.clone()).await?;
+
+    let scheduler = IOScheduler::create(2, 64);
+    let ranges = vec![1000..1100, 0..10, 20..30, 4000..4096];
+    let chunks = scheduler
+        .read_ranges(&operator, "block", &ranges, IOPriority::Interactive)
+        .await?;
+    assert_eq!(chunks.len(), ranges.len());
+    for (range, chunk) in ranges.iter().zip(chunks) {
+        assert_eq!(chunk, data[range.start as usize..range.end as usize]);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_io_scheduler_priority() -> Result<()> {
+    let operator = Operator::new(opendal::services::memory::Builder::default().build()?);
+    let scheduler = IOScheduler::create(2, 0);
+    let first = scheduler.acquire(&operator, IOPriority::Interactive).await;
+    let second = scheduler.acquire(&operator, IOPriority::Interactive).await;
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut handles = vec![];
+    // the background request waits before the interactive one
+    for priority in [IOPriority::Background, IOPriority::Interactive] {
+        let scheduler = scheduler.clone();
+        let operator = operator.clone();
+        let tx = tx.clone();
+        handles.push(tokio::spawn(async move {
+            let _permit = scheduler.acquire(&operator, priority).await;
+            tx.send(priority).unwrap();
+        }));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    drop(first);
+    assert_eq!(rx.recv().await, Some(IOPriority::Interactive));
+    drop(second);
+    assert_eq!(rx.recv().await, Some(IOPriority::Background));
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_column_data_cache_memory_tier() -> Result<()> {
+    let memory = new_bytes_cache(10);
+    let cache = ColumnDataCacheTiers::create(Some(memory.clone()), None, "tenant", "cluster");
+
+    // miss
+    assert_eq!(cache.get("1/_b/a.parquet", 0, 4).await, None);
+
+    // hit
+    cache.put("1/_b/a.parquet", 0, b"abcd").await;
+    assert_eq!(
+        cache.get("1/_b/a.parquet", 0, 4).await,
+        Some(b"abcd".to_vec())
+    );
+    // the columns of a block are cached individually
+    assert_eq!(cache.get("1/_b/a.parquet", 1, 4).await, None);
+
+    // the least recently used chunk is evicted by the bytes
+    cache.put("1/_b/b.parquet", 0, b"efghijkl").await;
+    assert_eq!(cache.get("1/_b/a.parquet", 0, 4).await, None);
+    assert_eq!(
+        cache.get("1/_b/b.parquet", 0, 8).await,
+        Some(b"efghijkl".to_vec())
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_column_data_cache_disk_tier() -> Result<()> {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path().join("cache");
+    let memory = new_bytes_cache(1024);
+    let disk = new_disk_bytes_cache(root.to_str().unwrap(), 10)?;
+    let cache = ColumnDataCacheTiers::create(
+        Some(memory.clone()),
+        Some(disk.clone()),
+        "tenant",
+        "cluster",
+    );
+
+    // miss
+    assert_eq!(cache.get("1/_b/a.parquet", 0, 4).await, None);
+
+    cache.put("1/_b/a.parquet", 0, b"abcd").await;
+    assert_eq!(disk.read().len(), 1);
+    assert_eq!(disk.read().size(), 4);
+
+    // hit of the disk tier, which populates the memory tier
+    memory.write().clear();
+    assert_eq!(
+        cache.get("1/_b/a.parquet", 0, 4).await,
+        Some(b"abcd".to_vec())
+    );
+    assert_eq!(memory.read().len(), 1);
+
+    // the least recently used file is evicted by the bytes
+    cache.put("1/_b/b.parquet", 0, b"efghijkl").await;
+    assert_eq!(disk.read().len(), 1);
+    memory.write().clear();
+    assert_eq!(cache.get("1/_b/a.parquet", 0, 4).await, None);
+    assert_eq!(
+        cache.get("1/_b/b.parquet", 0, 8).await,
+        Some(b"efghijkl".to_vec())
+    );
+
+    // the chunks larger than the disk tier are kept in memory only
+    cache.put("1/_b/c.parquet", 0, b"0123456789ab").await;
+    assert_eq!(disk.read().len(), 1);
+    assert_eq!(
+        cache.get("1/_b/c.parquet", 0, 12).await,
+        Some(b"0123456789ab".to_vec())
+    );
     Ok(())
 }
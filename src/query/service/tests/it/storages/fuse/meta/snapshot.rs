@@ -15,6 +15,7 @@
 use std::ops::Add;
 
 use common_datavalues::DataSchema;
+use common_fuse_meta::meta::AnalyzedSegments;
 use common_fuse_meta::meta::TableSnapshot;
 use uuid::Uuid;
 
@@ -72,3 +73,23 @@ fn snapshot_timestamp_time_skew_tolerance() {
     let prev_ts = prev.timestamp.unwrap();
     assert!(current_ts > prev_ts)
 }
+
+#[test]
+fn snapshot_analyzed_segments_from_previous() {
+    let mut prev = default_snapshot();
+    prev.segments = vec![("seg_1".to_string(), 1), ("seg_2".to_string(), 1)];
+    prev.analyzed_segments = AnalyzedSegments {
+        segments: prev.segments.clone(),
+        next_virtual_column_id: 1,
+    };
+
+    let current = TableSnapshot::from_previous(&prev);
+    assert_eq!(current.analyzed_segments, prev.analyzed_segments);
+
+    // the snapshots written by the earlier versions are not analyzed
+    let json = serde_json::to_value(&prev).unwrap();
+    let mut json = json.as_object().unwrap().clone();
+    json.remove("analyzed_segments");
+    let legacy: TableSnapshot = serde_json::from_value(json.into()).unwrap();
+    assert_eq!(legacy.analyzed_segments, AnalyzedSegments::default());
+}
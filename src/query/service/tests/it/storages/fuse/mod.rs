@@ -21,3 +21,4 @@ mod statistics;
 mod table;
 mod table_functions;
 mod table_test_fixture;
+mod virtual_column;
@@ -0,0 +1,105 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::StorageParams;
+use common_storage::UriLocation;
+use databend_query::interpreters::AttachTableInterpreter;
+use databend_query::interpreters::Interpreter;
+use databend_query::sessions::TableContext;
+use databend_query::sql::plans::AttachTablePlan;
+use databend_query::storages::fuse::FuseTable;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_attach_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(2, &fixture).await?;
+
+    // attach a table to the location of the default table
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let root = match &ctx.get_config().storage.params {
+        StorageParams::Fs(cfg) => cfg.root.clone(),
+        _ => unreachable!("the storage of the fixture is fs"),
+    };
+    let plan = AttachTablePlan {
+        tenant: fixture.default_tenant(),
+        catalog: fixture.default_catalog_name(),
+        database: db.clone(),
+        table: "attached".to_string(),
+        location: UriLocation {
+            protocol: "fs".to_string(),
+            name: "".to_string(),
+            path: format!(
+                "{}/{}/",
+                root.trim_end_matches('/'),
+                fuse_table.meta_location_generator().prefix()
+            ),
+            connection: BTreeMap::new(),
+        },
+        read_only: true,
+    };
+    let interpreter = AttachTableInterpreter::try_create(ctx.clone(), plan)?;
+    interpreter.execute(ctx.clone()).await?;
+
+    // the data of the table are read from the location
+    let qry = format!("select count(*) as c from {}.attached", db);
+    let blocks = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+---+", //
+        "| c |", //
+        "+---+", //
+        "| 6 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the table attached in read-only mode is not writable
+    let qry = format!("insert into {}.attached select * from {}.{}", db, db, tbl);
+    let res = execute_command(ctx.clone(), qry.as_str()).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::table_not_writable_code()
+    );
+
+    // the data are kept after the table is detached
+    let qry = format!("detach table {}.attached", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let qry = format!("select count(*) as c from {}.{}", db, tbl);
+    let blocks = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
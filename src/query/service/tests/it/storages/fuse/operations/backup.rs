@@ -0,0 +1,111 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::StorageParams;
+use common_storage::UriLocation;
+use databend_query::interpreters::BackupDatabaseInterpreter;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::RestoreDatabaseInterpreter;
+use databend_query::sessions::TableContext;
+use databend_query::sql::plans::BackupDatabasePlan;
+use databend_query::sql::plans::RestoreDatabasePlan;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_backup_and_restore_database() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(2, &fixture).await?;
+
+    let root = match &ctx.get_config().storage.params {
+        StorageParams::Fs(cfg) => cfg.root.clone(),
+        _ => unreachable!("the storage of the fixture is fs"),
+    };
+    let location = UriLocation {
+        protocol: "fs".to_string(),
+        name: "".to_string(),
+        path: format!("{}/backup/", root.trim_end_matches('/')),
+        connection: BTreeMap::new(),
+    };
+    let backup = BackupDatabasePlan {
+        tenant: fixture.default_tenant(),
+        catalog: fixture.default_catalog_name(),
+        database: db.clone(),
+        location: location.clone(),
+    };
+    let interpreter = BackupDatabaseInterpreter::try_create(ctx.clone(), backup.clone())?;
+    interpreter.execute(ctx.clone()).await?;
+
+    // the second backup copies the data appended since the first one
+    append_sample_data(1, &fixture).await?;
+    let interpreter = BackupDatabaseInterpreter::try_create(ctx.clone(), backup)?;
+    interpreter.execute(ctx.clone()).await?;
+
+    let restore = RestoreDatabasePlan {
+        tenant: fixture.default_tenant(),
+        catalog: fixture.default_catalog_name(),
+        database: "restored".to_string(),
+        location: location.clone(),
+    };
+    let interpreter = RestoreDatabaseInterpreter::try_create(ctx.clone(), restore.clone())?;
+    interpreter.execute(ctx.clone()).await?;
+
+    let qry = format!("select count(*) as c from restored.{}", tbl);
+    let blocks = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+---+", //
+        "| c |", //
+        "+---+", //
+        "| 9 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the restored database is not restored again
+    let interpreter = RestoreDatabaseInterpreter::try_create(ctx.clone(), restore)?;
+    let res = interpreter.execute(ctx.clone()).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::database_already_exists_code()
+    );
+
+    // the location keeps the backup of the default database only
+    let backup = BackupDatabasePlan {
+        tenant: fixture.default_tenant(),
+        catalog: fixture.default_catalog_name(),
+        database: "restored".to_string(),
+        location,
+    };
+    let interpreter = BackupDatabaseInterpreter::try_create(ctx.clone(), backup)?;
+    let res = interpreter.execute(ctx.clone()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::bad_arguments_code());
+
+    Ok(())
+}
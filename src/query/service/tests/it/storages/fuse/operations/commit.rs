@@ -11,10 +11,17 @@
 //  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
+use std::sync::Arc;
+
 use common_base::base::tokio;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_datablocks::DataBlock;
 use common_exception::Result;
+use common_fuse_meta::meta::Statistics;
+use common_fuse_meta::meta::TableSnapshot;
+use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::io::TableMetaLocationGenerator;
 use common_storages_fuse::FuseTable;
 use futures::TryStreamExt;
 
@@ -79,6 +86,85 @@ async fn test_fuse_occ_retry() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_mutation_rebased_onto_append() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // insert one row `id = 1` into the table
+    let table = fixture.latest_default_table().await?;
+    let stream = TestFixture::gen_sample_blocks_stream_ex(1, 1, 1);
+    let blocks = stream.try_collect().await?;
+    fixture
+        .append_commit_blocks(table, blocks, false, true)
+        .await?;
+
+    // a mutation starts from the table with the row `id = 1`
+    let base_table = fixture.latest_default_table().await?;
+    let base_table = FuseTable::try_from_table(base_table.as_ref())?;
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
+    let loc = base_table.snapshot_loc().unwrap();
+    let version = TableMetaLocationGenerator::snapshot_version(loc.as_str());
+    let base_snapshot = reader.read(loc.as_str(), None, version).await?;
+
+    // meanwhile, another row `id = 5` is inserted and committed
+    let table = fixture.latest_default_table().await?;
+    let stream = TestFixture::gen_sample_blocks_stream_ex(1, 1, 5);
+    let blocks = stream.try_collect().await?;
+    fixture
+        .append_commit_blocks(table, blocks, false, true)
+        .await?;
+
+    // the mutation deletes the row `id = 1`, and is committed onto the insertion
+    let mut new_snapshot = TableSnapshot::from_previous(&base_snapshot);
+    new_snapshot.segments = vec![];
+    new_snapshot.summary = Statistics::default();
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    FuseTable::commit_mutation(
+        &table_ctx,
+        &base_table.get_operator(ctx.as_ref())?,
+        base_table.get_table_info(),
+        base_table.meta_location_generator(),
+        base_snapshot,
+        new_snapshot,
+    )
+    .await?;
+
+    let qry = format!("select * from {}.{} order by id ", db, tbl);
+    let blocks = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+
+    let expected = vec![
+        "+----+----------+", //
+        "| id | t        |", //
+        "+----+----------+", //
+        "| 5  | (10, 15) |", //
+        "+----+----------+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let qry = format!("select count(*) from {}.{}", db, tbl);
+    let blocks = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+", //
+        "| count(*) |", //
+        "+----------+", //
+        "| 1        |", //
+        "+----------+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_last_snapshot_hint() -> Result<()> {
     let fixture = TestFixture::new().await;
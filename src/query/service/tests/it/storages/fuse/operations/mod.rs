@@ -12,6 +12,8 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod attach;
+mod backup;
 mod commit;
 mod mutation;
 mod navigate;
@@ -19,3 +21,4 @@ mod optimize;
 mod purge_drop;
 mod purge_truncate;
 mod read_plan;
+mod table_lock;
@@ -29,10 +29,12 @@ use common_fuse_meta::meta::Statistics;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
 use databend_query::sessions::TableContext;
+use databend_query::storages::fuse::io::BlockCompression;
 use databend_query::storages::fuse::io::SegmentWriter;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::operations::DeletionMutator;
 use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
+use databend_query::storages::index::IndexColumns;
 use uuid::Uuid;
 
 use crate::storages::fuse::table_test_fixture::TestFixture;
@@ -95,9 +97,12 @@ async fn test_deletion_mutator_multiple_empty_segments() -> Result<()> {
     let table_ctx: Arc<dyn TableContext> = ctx as Arc<dyn TableContext>;
     let mut mutator = DeletionMutator::try_create(
         table_ctx,
+        data_accessor.clone(),
         location_generator,
         Arc::new(base_snapshot),
         ClusterStatsGenerator::default(),
+        BlockCompression::default(),
+        IndexColumns::default(),
     )?;
 
     // clear half of the segments
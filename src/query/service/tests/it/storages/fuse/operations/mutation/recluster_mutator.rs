@@ -122,6 +122,7 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
 
     let mut mutator = ReclusterMutator::try_create(
         ctx,
+        data_accessor.clone(),
         location_generator,
         base_snapshot,
         1.0,
@@ -14,6 +14,7 @@
 
 use std::ops::Add;
 use std::ops::Sub;
+use std::sync::Arc;
 use std::time::Duration;
 
 use common_base::base::tokio;
@@ -22,12 +23,14 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_storages_fuse::io::SnapshotHistoryReader;
 use databend_query::pipelines::Pipeline;
+use databend_query::sessions::TableContext;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::FuseTable;
 use databend_query::storages::Table;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
 use crate::storages::fuse::table_test_fixture::execute_query;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
@@ -79,7 +82,7 @@ async fn test_fuse_navigate() -> Result<()> {
     // 2. grab the history
     let table = fixture.latest_default_table().await?;
     let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-    let reader = MetaReaders::table_snapshot_reader(ctx.clone());
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
     let loc = fuse_table.snapshot_loc().unwrap();
     assert_eq!(second_snapshot, loc);
     let version = TableMetaLocationGenerator::snapshot_version(loc.as_str());
@@ -121,6 +124,57 @@ async fn test_fuse_navigate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_read_table_of_statement() -> Result<()> {
+    // - the statement starts reading, then a concurrent insertion lands
+    // - the table read by the statement afterwards is the one before the insertion
+    // - the next statement reads the insertion
+
+    // 1. Setup
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let catalog = fixture.default_catalog_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values (1, (2, 3))", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let first_snapshot = FuseTable::try_from_table(table.as_ref())?.snapshot_loc();
+
+    // 2. the statement starts reading
+    ctx.reset_read_tables();
+    ctx.get_read_table(&catalog, "system", "one").await?;
+    tokio::time::sleep(Duration::from_millis(2)).await;
+
+    // 3. a concurrent insertion lands
+    let other_ctx = ctx.get_current_session().create_query_context().await?;
+    let qry = format!("insert into {}.{} values (2, (4, 6))", db, tbl);
+    execute_command(other_ctx, qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let second_snapshot = FuseTable::try_from_table(table.as_ref())?.snapshot_loc();
+    assert_ne!(first_snapshot, second_snapshot);
+
+    // 4. the statement reads the table before the insertion, the same one each time
+    let read_table = ctx.get_read_table(&catalog, &db, &tbl).await?;
+    assert_eq!(
+        first_snapshot,
+        FuseTable::try_from_table(read_table.as_ref())?.snapshot_loc()
+    );
+    let read_again = ctx.get_read_table(&catalog, &db, &tbl).await?;
+    assert!(Arc::ptr_eq(&read_table, &read_again));
+
+    // 5. the next statement reads the insertion
+    ctx.reset_read_tables();
+    let read_table = ctx.get_read_table(&catalog, &db, &tbl).await?;
+    assert_eq!(
+        second_snapshot,
+        FuseTable::try_from_table(read_table.as_ref())?.snapshot_loc()
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_fuse_historical_table_is_read_only() -> Result<()> {
     // 1. Setup
@@ -140,7 +194,7 @@ async fn test_fuse_historical_table_is_read_only() -> Result<()> {
     let table = fixture.latest_default_table().await?;
     let fuse_table = FuseTable::try_from_table(table.as_ref())?;
     let loc = fuse_table.snapshot_loc().unwrap();
-    let reader = MetaReaders::table_snapshot_reader(ctx.clone());
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
     let version = TableMetaLocationGenerator::snapshot_version(loc.as_str());
     let snapshots: Vec<_> = reader
         .snapshot_history(loc, version, fuse_table.meta_location_generator().clone())
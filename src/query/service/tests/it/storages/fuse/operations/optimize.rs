@@ -13,7 +13,12 @@
 //  limitations under the License.
 
 use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_datablocks::DataBlock;
 use common_exception::Result;
+use databend_query::interpreters::CreateTableInterpreterV2;
+use databend_query::interpreters::Interpreter;
+use databend_query::storages::fuse::FuseTable;
 use futures::TryStreamExt;
 
 use crate::storages::fuse::table_test_fixture::append_sample_data;
@@ -109,3 +114,86 @@ async fn test_fuse_snapshot_optimize_compact() -> Result<()> {
     )
     .await
 }
+
+#[tokio::test]
+async fn test_fuse_snapshot_optimize_verify() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    // nothing is corrupted
+    let table = fixture.latest_default_table().await?;
+    assert!(table.verify(ctx.clone()).await?.is_empty());
+
+    // corrupts the first column chunk of the block, which begins after the magic of parquet
+    let qry = format!("select block_location from fuse_block('{}', '{}')", db, tbl);
+    let blocks: Vec<DataBlock> = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect()
+        .await?;
+    let location = String::from_utf8(blocks[0].column(0).get(0).as_string()?)?;
+    let operator = FuseTable::try_from_table(table.as_ref())?.get_operator(ctx.as_ref())?;
+    let mut data = operator.object(&location).read().await?;
+    data[4] ^= 0xff;
+    operator.object(&location).write(data).await?;
+
+    let corrupted = table.verify(ctx.clone()).await?;
+    assert_eq!(corrupted.len(), 1);
+    assert!(corrupted[0].0.starts_with(&location));
+    assert!(corrupted[0].1.contains("is corrupted"));
+
+    let qry = format!("optimize table {}.{} verify", db, tbl);
+    let blocks: Vec<DataBlock> = execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_estimate_compaction() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let create_table_plan = fixture.create_normal_table_plan();
+    let interpreter = CreateTableInterpreterV2::try_create(ctx.clone(), create_table_plan)?;
+    interpreter.execute(ctx.clone()).await?;
+
+    // nothing to compact in an empty table
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert!(fuse_table.estimate_compaction(ctx.clone()).await?.is_none());
+
+    // insert 5 blocks, which fit in 1 block
+    for _ in 0..5 {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+        let blocks = stream.try_collect().await?;
+        fixture
+            .append_commit_blocks(table.clone(), blocks, false, true)
+            .await?;
+    }
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let estimate = fuse_table.estimate_compaction(ctx.clone()).await?.unwrap();
+    assert_eq!(estimate.block_count, 5);
+    assert_eq!(estimate.excess_blocks, 4);
+    assert!(estimate.bytes > 0);
+
+    let qry = format!("optimize table {}.{} compact", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let estimate = fuse_table.estimate_compaction(ctx.clone()).await?.unwrap();
+    assert_eq!(estimate.block_count, 1);
+    assert_eq!(estimate.excess_blocks, 0);
+    assert_eq!(estimate.bytes, 0);
+    Ok(())
+}
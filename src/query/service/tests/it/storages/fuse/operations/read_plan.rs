@@ -45,12 +45,16 @@ fn test_to_partitions() -> Result<()> {
         max: DataValue::Int64(2),
         null_count: 0,
         in_memory_size: col_size as u64,
+        distinct_of_values: None,
+        histogram: None,
     };
 
     let col_metas_gen = |col_size| ColumnMeta {
         offset: 0,
         len: col_size as u64,
         num_values: 0,
+        compression: None,
+        checksum: None,
     };
 
     let col_leaves_gen = |col_id| ColumnLeaf {
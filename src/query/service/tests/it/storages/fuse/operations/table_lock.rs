@@ -0,0 +1,68 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_lock::TableLockGuard;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_lock_conflict() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let catalog = ctx.get_catalog(&fixture.default_catalog_name())?;
+    let guard = TableLockGuard::try_lock(
+        catalog,
+        table.get_table_info().ident.table_id,
+        "other-query",
+        "optimize",
+        Duration::from_secs(10),
+    )
+    .await?
+    .expect("the default catalog keeps the table locks");
+
+    // the mutations of the locked table fail fast
+    for qry in [
+        format!("optimize table {}.{} compact", db, tbl),
+        format!("delete from {}.{} where id > 0", db, tbl),
+    ] {
+        let res = execute_command(ctx.clone(), &qry).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::TableLockConflict("").code(),
+            "{}",
+            qry
+        );
+    }
+
+    // and succeed once the lock is released
+    guard.release().await?;
+    let qry = format!("optimize table {}.{} compact", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+
+    Ok(())
+}
@@ -78,6 +78,7 @@ async fn test_block_pruner() -> Result<()> {
         table: test_tbl_name.to_string(),
         schema: test_schema.clone(),
         engine: Engine::Fuse,
+        engine_options: Default::default(),
         options: [
             (FUSE_OPT_KEY_ROW_PER_BLOCK.to_owned(), num_blocks_opt),
             (FUSE_OPT_KEY_BLOCK_PER_SEGMENT.to_owned(), "1".to_owned()),
@@ -88,6 +89,8 @@ async fn test_block_pruner() -> Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
+        temporary: false,
     };
 
     let interpreter = CreateTableInterpreterV2::try_create(ctx.clone(), create_table_plan)?;
@@ -142,7 +145,7 @@ async fn test_block_pruner() -> Result<()> {
         .get(OPT_KEY_SNAPSHOT_LOCATION)
         .unwrap();
 
-    let reader = MetaReaders::table_snapshot_reader(ctx.clone());
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
     let snapshot = reader.read(snapshot_loc.as_str(), None, 1).await?;
 
     // nothing is pruned
@@ -223,6 +226,7 @@ async fn test_block_pruner_monotonic() -> Result<()> {
         table: test_tbl_name.to_string(),
         schema: test_schema.clone(),
         engine: Engine::Fuse,
+        engine_options: Default::default(),
         options: [
             (FUSE_OPT_KEY_ROW_PER_BLOCK.to_owned(), num_blocks_opt),
             // for the convenience of testing, let one seegment contains one block
@@ -235,6 +239,8 @@ async fn test_block_pruner_monotonic() -> Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
+        temporary: false,
     };
 
     let catalog = ctx.get_catalog("default")?;
@@ -283,7 +289,7 @@ async fn test_block_pruner_monotonic() -> Result<()> {
         .options()
         .get(OPT_KEY_SNAPSHOT_LOCATION)
         .unwrap();
-    let reader = MetaReaders::table_snapshot_reader(ctx.clone());
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
     let snapshot = reader.read(snapshot_loc.as_str(), None, 1).await?;
 
     // a + b > 20; some blocks pruned
@@ -24,6 +24,7 @@ use common_legacy_expression::col;
 use common_legacy_expression::lit;
 use common_pipeline_transforms::processors::ExpressionExecutor;
 use databend_query::storages::fuse::io::BlockCompactor;
+use databend_query::storages::fuse::io::BlockCompression;
 use databend_query::storages::fuse::io::BlockWriter;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::statistics::gen_columns_statistics;
@@ -31,6 +32,9 @@ use databend_query::storages::fuse::statistics::reducers;
 use databend_query::storages::fuse::statistics::BlockStatistics;
 use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
 use databend_query::storages::fuse::statistics::StatisticsAccumulator;
+use databend_query::storages::fuse::HISTOGRAM_MAX_BUCKETS;
+use databend_query::storages::fuse::VIRTUAL_COLUMN_ID_START;
+use databend_query::storages::index::IndexColumns;
 use opendal::Operator;
 
 use crate::storages::fuse::table_test_fixture::TestFixture;
@@ -71,6 +75,81 @@ fn test_ft_tuple_stats_block_stats() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_distinct_and_histogram() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+    let values = (0..2000).map(|v| v % 100).collect::<Vec<i32>>();
+    let block = DataBlock::create(schema, vec![Series::from_data(values)]);
+    let r = gen_columns_statistics(&block)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.distinct_of_values, Some(100));
+
+    let histogram = col_stats.histogram.as_ref().unwrap();
+    assert!(histogram.buckets.len() <= HISTOGRAM_MAX_BUCKETS);
+    assert_eq!(histogram.num_values(), 2000);
+    assert_eq!(
+        histogram.buckets.last().unwrap().upper_bound,
+        DataValue::Int64(99)
+    );
+    // the buckets are ordered by the upper bounds
+    assert!(
+        histogram
+            .buckets
+            .windows(2)
+            .all(|w| w[0].upper_bound <= w[1].upper_bound)
+    );
+
+    // the nulls are not counted by the histogram
+    let schema = DataSchemaRefExt::create(vec![DataField::new_nullable("a", i32::to_data_type())]);
+    let column = Series::from_data(vec![Some(1), None, Some(3), None]);
+    let block = DataBlock::create(schema, vec![column]);
+    let r = gen_columns_statistics(&block)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.null_count, 2);
+    assert_eq!(col_stats.histogram.as_ref().unwrap().num_values(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_ft_stats_distinct_and_histogram_reduce() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+    let gen_stats = |values: Vec<i32>| {
+        let block = DataBlock::create(schema.clone(), vec![Series::from_data(values)]);
+        gen_columns_statistics(&block)
+    };
+    let low = gen_stats((0..100).collect())?;
+    let high = gen_stats((100..200).collect())?;
+
+    // the distinct values of the disjoint ranges are added up
+    let r = reducers::reduce_block_statistics(&[&low, &high])?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.distinct_of_values, Some(200));
+    let histogram = col_stats.histogram.as_ref().unwrap();
+    assert!(histogram.buckets.len() <= HISTOGRAM_MAX_BUCKETS);
+    assert_eq!(histogram.num_values(), 200);
+    assert_eq!(
+        histogram.buckets.last().unwrap().upper_bound,
+        DataValue::Int64(199)
+    );
+
+    // the overlapped ones are estimated by the max
+    let r = reducers::reduce_block_statistics(&[&low, &low])?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.distinct_of_values, Some(100));
+    assert_eq!(col_stats.histogram.as_ref().unwrap().num_values(), 200);
+
+    // the statistics written by the earlier versions are not merged
+    let mut legacy = low.clone();
+    let legacy_stats = legacy.get_mut(&0).unwrap();
+    legacy_stats.distinct_of_values = None;
+    legacy_stats.histogram = None;
+    let r = reducers::reduce_block_statistics(&[&legacy, &high])?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.distinct_of_values, None);
+    assert!(col_stats.histogram.is_none());
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     let num_of_blocks = 10;
@@ -115,6 +194,8 @@ fn test_reduce_block_statistics_in_memory_size() -> common_exception::Result<()>
                 max: DataValue::Null,
                 null_count: 1,
                 in_memory_size: 1,
+                distinct_of_values: None,
+                histogram: None,
             }))
         })
     };
@@ -138,6 +219,35 @@ fn test_reduce_block_statistics_in_memory_size() -> common_exception::Result<()>
     Ok(())
 }
 
+#[test]
+fn test_reduce_block_statistics_virtual_columns() -> common_exception::Result<()> {
+    let stats = |v: i64| ColumnStatistics {
+        min: DataValue::Int64(v),
+        max: DataValue::Int64(v),
+        null_count: 0,
+        in_memory_size: 8,
+        distinct_of_values: Some(1),
+        histogram: None,
+    };
+    let virtual_id = VIRTUAL_COLUMN_ID_START;
+
+    // the block written before the virtual column is added has no statistics of it
+    let with_virtual = HashMap::from([(0, stats(1)), (virtual_id, stats(10))]);
+    let without_virtual = HashMap::from([(0, stats(2))]);
+    let r = reducers::reduce_block_statistics(&[with_virtual.clone(), without_virtual])?;
+    assert_eq!(r.len(), 1);
+    assert_eq!(r.get(&0).unwrap().max, DataValue::Int64(2));
+
+    // the empty statistics are ignored
+    let other_virtual = HashMap::from([(0, stats(3)), (virtual_id, stats(20))]);
+    let r = reducers::reduce_block_statistics(&[with_virtual, HashMap::new(), other_virtual])?;
+    assert_eq!(r.len(), 2);
+    let virtual_stats = r.get(&virtual_id).unwrap();
+    assert_eq!(virtual_stats.min, DataValue::Int64(10));
+    assert_eq!(virtual_stats.max, DataValue::Int64(20));
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_accumulator() -> common_exception::Result<()> {
     let blocks = TestFixture::gen_sample_blocks(10, 1);
@@ -145,11 +255,17 @@ async fn test_accumulator() -> common_exception::Result<()> {
 
     let operator = Operator::new(opendal::services::memory::Builder::default().build()?);
     let loc_generator = TableMetaLocationGenerator::with_prefix("/".to_owned());
+    let compression = BlockCompression::default();
 
     for item in blocks {
         let block = item?;
         let block_statistics = BlockStatistics::from(&block, "does_not_matter".to_owned(), None)?;
-        let block_writer = BlockWriter::new(&operator, &loc_generator);
+        let block_writer = BlockWriter::new(
+            &operator,
+            &loc_generator,
+            &compression,
+            &IndexColumns::default(),
+        );
         let block_meta = block_writer.write(block, None).await?;
         stats_acc.add_with_block_meta(block_meta, block_statistics)?;
     }
@@ -300,6 +300,7 @@ async fn test_fuse_alter_table_cluster_key() -> Result<()> {
         table: fixture.default_table_name(),
         schema: TestFixture::default_schema(),
         engine: Engine::Fuse,
+        engine_options: Default::default(),
         options: [
             // database id is required for FUSE
             (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -309,6 +310,8 @@ async fn test_fuse_alter_table_cluster_key() -> Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
+        temporary: false,
     };
 
     // create test table
@@ -337,7 +340,7 @@ async fn test_fuse_alter_table_cluster_key() -> Result<()> {
         .options()
         .get(OPT_KEY_SNAPSHOT_LOCATION)
         .unwrap();
-    let reader = MetaReaders::table_snapshot_reader(ctx.clone());
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
     let snapshot = reader.read(snapshot_loc.as_str(), None, 1).await?;
     let expected = Some((0, "(id)".to_string()));
     assert_eq!(snapshot.cluster_key_meta, expected);
@@ -363,7 +366,7 @@ async fn test_fuse_alter_table_cluster_key() -> Result<()> {
         .options()
         .get(OPT_KEY_SNAPSHOT_LOCATION)
         .unwrap();
-    let reader = MetaReaders::table_snapshot_reader(ctx);
+    let reader = MetaReaders::table_snapshot_reader(ctx.clone(), ctx.get_storage_operator()?);
     let snapshot = reader.read(snapshot_loc.as_str(), None, 1).await?;
     let expected = None;
     assert_eq!(snapshot.cluster_key_meta, expected);
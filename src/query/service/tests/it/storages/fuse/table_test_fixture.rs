@@ -78,6 +78,8 @@ impl TestFixture {
             // use `TempDir` as root path (auto clean)
             root: tmp_dir.path().to_str().unwrap().to_string(),
         });
+        // the tables can be attached to the locations of the `fs` storage
+        conf.storage.allow_insecure = true;
 
         let (_guard, ctx) = crate::tests::create_query_context_with_config(conf, None)
             .await
@@ -150,6 +152,7 @@ impl TestFixture {
             table: self.default_table_name(),
             schema: TestFixture::default_schema(),
             engine: Engine::Fuse,
+            engine_options: Default::default(),
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -159,6 +162,8 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            auto_increment_sequences: vec![],
+            temporary: false,
         }
     }
 
@@ -172,6 +177,7 @@ impl TestFixture {
             table: self.default_table_name(),
             schema: TestFixture::default_schema(),
             engine: Engine::Fuse,
+            engine_options: Default::default(),
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -181,6 +187,8 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
+            temporary: false,
         }
     }
 
@@ -0,0 +1,154 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_legacy_expression::col;
+use common_legacy_expression::lit;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_parser::ExpressionParser;
+use databend_query::sessions::TableContext;
+use databend_query::storages::fuse::VirtualColumns;
+use databend_query::storages::fuse::OPT_KEY_VIRTUAL_COLUMNS;
+use databend_query::storages::fuse::VIRTUAL_COLUMN_ID_START;
+use serde_json::json;
+
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+fn test_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("id", i64::to_data_type()),
+        DataField::new("data", VariantType::new_impl()),
+    ])
+}
+
+#[test]
+fn test_virtual_columns_add_and_drop() -> Result<()> {
+    let schema = test_schema();
+    let mut virtual_columns = VirtualColumns::default();
+    virtual_columns.add("uid", "data:user.id::STRING", &schema)?;
+
+    // the names are unique among the columns and the virtual columns
+    assert!(
+        virtual_columns
+            .add("uid", "data:user.name::STRING", &schema)
+            .is_err()
+    );
+    assert!(
+        virtual_columns
+            .add("id", "data:user.name::STRING", &schema)
+            .is_err()
+    );
+    // the expression must cast a path of a variant column
+    assert!(virtual_columns.add("a", "id + 1", &schema).is_err());
+    assert!(virtual_columns.add("b", "data:user", &schema).is_err());
+
+    let options = BTreeMap::from([(
+        OPT_KEY_VIRTUAL_COLUMNS.to_string(),
+        virtual_columns.to_option_value()?,
+    )]);
+    let mut virtual_columns = VirtualColumns::try_from_options(&options)?;
+    let ids = virtual_columns.columns().map(|c| c.id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![VIRTUAL_COLUMN_ID_START]);
+
+    // the ids of the dropped virtual columns are not reused
+    virtual_columns.drop("uid")?;
+    assert!(virtual_columns.is_empty());
+    assert!(virtual_columns.drop("uid").is_err());
+    virtual_columns.add("uid", "data:user.id::STRING", &schema)?;
+    let ids = virtual_columns.columns().map(|c| c.id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![VIRTUAL_COLUMN_ID_START + 1]);
+
+    Ok(())
+}
+
+#[test]
+fn test_virtual_columns_rewrite_filters() -> Result<()> {
+    let schema = test_schema();
+    let mut virtual_columns = VirtualColumns::default();
+    virtual_columns.add("uid", "data:user.id::STRING", &schema)?;
+    let column_name = format!("_virtual_column_{}", VIRTUAL_COLUMN_ID_START);
+
+    // the path accessed by the legacy parser
+    let filters = ExpressionParser::parse_exprs("data:user.id::STRING = 'a'")?;
+    let rewritten = virtual_columns.rewrite_filters(&filters, &schema).unwrap();
+    assert_eq!(rewritten.filters, vec![
+        col(&column_name).eq(lit("a".as_bytes()))
+    ]);
+    assert_eq!(rewritten.schema.num_fields(), 3);
+    assert_eq!(rewritten.schema.field(2).name(), &column_name);
+    assert_eq!(rewritten.column_ids, vec![(VIRTUAL_COLUMN_ID_START, 2)]);
+
+    // the path accessed by the new planner
+    let get = |expr: LegacyExpression, key: &str| LegacyExpression::ScalarFunction {
+        op: "get".to_string(),
+        args: vec![expr, lit(key.as_bytes())],
+    };
+    let path = LegacyExpression::Cast {
+        expr: Box::new(get(get(col("data"), "user"), "id")),
+        data_type: NullableType::new_impl(StringType::new_impl()),
+        pg_style: false,
+    };
+    let filters = vec![path.eq(lit("a".as_bytes())).and(col("id").gt(lit(1i64)))];
+    let rewritten = virtual_columns.rewrite_filters(&filters, &schema).unwrap();
+    assert_eq!(rewritten.filters, vec![
+        col(&column_name)
+            .eq(lit("a".as_bytes()))
+            .and(col("id").gt(lit(1i64)))
+    ]);
+
+    // the other paths, or the other types
+    let filters = ExpressionParser::parse_exprs("data:user.name::STRING = 'a'")?;
+    assert!(virtual_columns.rewrite_filters(&filters, &schema).is_none());
+    let filters = ExpressionParser::parse_exprs("data:user.id::INT64 = 1")?;
+    assert!(virtual_columns.rewrite_filters(&filters, &schema).is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_virtual_columns_stats() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx: std::sync::Arc<dyn TableContext> = fixture.ctx();
+
+    let schema = test_schema();
+    let mut virtual_columns = VirtualColumns::default();
+    virtual_columns.add("uid", "data:user.id::INT64", &schema)?;
+    virtual_columns.add("name", "data:user.name::INT64", &schema)?;
+
+    let block = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 3]),
+        Series::from_data(vec![
+            VariantValue::from(json!({"user": {"id": 2}})),
+            VariantValue::from(json!({"user": {"id": 1, "name": "a"}})),
+            VariantValue::from(json!({"user": {"id": 3}})),
+        ]),
+    ]);
+    let stats = virtual_columns
+        .stats_generator(ctx, &schema)?
+        .gen_stats(&block);
+
+    // the block is not pruned by the virtual column of the values failed to cast
+    assert_eq!(stats.len(), 1);
+    let uid_stats = stats.get(&VIRTUAL_COLUMN_ID_START).unwrap();
+    assert_eq!(uid_stats.min, DataValue::Int64(1));
+    assert_eq!(uid_stats.max, DataValue::Int64(3));
+    assert_eq!(uid_stats.null_count, 0);
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_storage::StorageParams;
+use common_storage::StorageS3Config;
+use databend_query::storages::iceberg::decode_bound;
+use databend_query::storages::iceberg::metadata_version;
+use databend_query::storages::iceberg::IcebergTableOptions;
+use databend_query::storages::iceberg::TableMetadata;
+
+const TABLE_METADATA: &str = r#"{
+  "format-version": 2,
+  "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+  "location": "s3://bucket/warehouse/db/t",
+  "last-sequence-number": 2,
+  "last-updated-ms": 1665000200000,
+  "last-column-id": 2,
+  "current-schema-id": 1,
+  "schemas": [
+    {"type": "struct", "schema-id": 0, "fields": [
+      {"id": 1, "name": "id", "required": true, "type": "long"}
+    ]},
+    {"type": "struct", "schema-id": 1, "fields": [
+      {"id": 1, "name": "id", "required": true, "type": "long"},
+      {"id": 2, "name": "dt", "required": false, "type": "date"}
+    ]}
+  ],
+  "default-spec-id": 0,
+  "partition-specs": [
+    {"spec-id": 0, "fields": [{"name": "dt", "transform": "identity", "source-id": 2, "field-id": 1000}]}
+  ],
+  "current-snapshot-id": 2,
+  "snapshots": [
+    {"snapshot-id": 1, "sequence-number": 1, "timestamp-ms": 1665000100000,
+     "manifest-list": "s3://bucket/warehouse/db/t/metadata/snap-1.avro", "schema-id": 0},
+    {"snapshot-id": 2, "parent-snapshot-id": 1, "sequence-number": 2, "timestamp-ms": 1665000200000,
+     "manifest-list": "s3://bucket/warehouse/db/t/metadata/snap-2.avro", "schema-id": 1}
+  ]
+}"#;
+
+#[test]
+fn test_iceberg_table_options() -> Result<()> {
+    let options = IcebergTableOptions {
+        storage: StorageParams::S3(StorageS3Config {
+            bucket: "bucket".to_string(),
+            root: "/warehouse/db/t/".to_string(),
+            ..Default::default()
+        }),
+        snapshot_id: None,
+    };
+    let engine_options = BTreeMap::try_from(&options)?;
+    assert!(!engine_options.contains_key("snapshot_id"));
+    assert_eq!(IcebergTableOptions::try_from(&engine_options)?, options);
+
+    let options = IcebergTableOptions {
+        snapshot_id: Some(8744736658442914487),
+        ..options
+    };
+    let engine_options = BTreeMap::try_from(&options)?;
+    assert_eq!(
+        engine_options.get("snapshot_id"),
+        Some(&"8744736658442914487".to_string())
+    );
+    assert_eq!(IcebergTableOptions::try_from(&engine_options)?, options);
+
+    Ok(())
+}
+
+#[test]
+fn test_iceberg_table_metadata() -> Result<()> {
+    let metadata: TableMetadata = serde_json::from_str(TABLE_METADATA)?;
+
+    // The current snapshot and the schema it is written with.
+    let snapshot = metadata.snapshot(None)?.unwrap();
+    assert_eq!(snapshot.snapshot_id, 2);
+    let schema = metadata.schema(Some(snapshot))?.to_data_schema()?;
+    assert_eq!(schema.num_fields(), 2);
+    assert!(!schema.field(0).is_nullable());
+    assert!(schema.field(1).is_nullable());
+
+    let snapshot = metadata.snapshot(Some(1))?.unwrap();
+    assert_eq!(metadata.schema(Some(snapshot))?.fields.len(), 1);
+    assert!(metadata.snapshot(Some(3)).is_err());
+
+    // Time travel to the snapshots committed before the time points.
+    let snapshot = metadata.snapshot_at(1665000150000)?.unwrap();
+    assert_eq!(snapshot.snapshot_id, 1);
+    let snapshot = metadata.snapshot_at(1665000200000)?.unwrap();
+    assert_eq!(snapshot.snapshot_id, 2);
+    assert!(metadata.snapshot_at(1665000000000)?.is_none());
+
+    assert_eq!(metadata.partition_spec(0).unwrap()[0].source_id, 2);
+    assert!(metadata.partition_spec(1).is_none());
+
+    assert_eq!(
+        metadata.relative_path("s3a://bucket/warehouse/db/t/data/dt=2022-10-01/0.parquet")?,
+        "data/dt=2022-10-01/0.parquet"
+    );
+    assert!(
+        metadata
+            .relative_path("s3://bucket/other/0.parquet")
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_iceberg_metadata_version() {
+    assert_eq!(metadata_version("v3.metadata.json"), Some(3));
+    assert_eq!(
+        metadata_version("00012-c0a3bd27-7c0a-4b59-a2b4-c5e3b73d1591.metadata.json"),
+        Some(12)
+    );
+    assert_eq!(metadata_version("version-hint.text"), None);
+    assert_eq!(metadata_version("snap-1.avro"), None);
+}
+
+#[test]
+fn test_iceberg_decode_bound() {
+    let long = serde_json::json!("long");
+    assert_eq!(
+        decode_bound(&long, &42i64.to_le_bytes()),
+        Some(DataValue::Int64(42))
+    );
+    // The bounds written before the column is promoted from `int`.
+    assert_eq!(
+        decode_bound(&long, &(-7i32).to_le_bytes()),
+        Some(DataValue::Int64(-7))
+    );
+    assert_eq!(
+        decode_bound(&serde_json::json!("double"), &1.5f64.to_le_bytes()),
+        Some(DataValue::Float64(1.5))
+    );
+    assert_eq!(
+        decode_bound(&serde_json::json!("string"), b"abc"),
+        Some(DataValue::String(b"abc".to_vec()))
+    );
+    assert_eq!(
+        decode_bound(&serde_json::json!("boolean"), &[1]),
+        Some(DataValue::Boolean(true))
+    );
+    assert_eq!(decode_bound(&serde_json::json!("decimal(9,2)"), &[1]), None);
+    assert_eq!(decode_bound(&long, &[1, 2, 3]), None);
+}
@@ -0,0 +1,132 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_legacy_expression::LegacyExpression;
+use databend_query::storages::kafka::records_to_block;
+use databend_query::storages::kafka::KafkaOptions;
+use rskafka::record::Record;
+use rskafka::record::RecordAndOffset;
+
+const AVRO_SCHEMA: &str = r#"{"type":"record","name":"r","fields":[{"name":"id","type":"long"},{"name":"name","type":"string"}]}"#;
+
+fn args(args: &[&str]) -> Vec<LegacyExpression> {
+    args.iter()
+        .map(|v| LegacyExpression::create_literal(DataValue::String(v.as_bytes().to_vec())))
+        .collect()
+}
+
+fn record(offset: i64, key: Option<&str>, value: Option<&[u8]>) -> RecordAndOffset {
+    RecordAndOffset {
+        record: Record {
+            key: key.map(|k| k.as_bytes().to_vec()),
+            value: value.map(|v| v.to_vec()),
+            headers: BTreeMap::new(),
+            timestamp: Utc.timestamp_millis(1665000100000),
+        },
+        offset,
+    }
+}
+
+#[test]
+fn test_kafka_options() -> Result<()> {
+    let options = KafkaOptions::from_args(
+        "read_kafka",
+        &args(&["127.0.0.1:9092, 127.0.0.1:9093", "t", "consumer=c1"]),
+    )?;
+    assert_eq!(options.brokers, vec!["127.0.0.1:9092", "127.0.0.1:9093"]);
+    assert_eq!(options.topic, "t");
+    assert_eq!(options.consumer, Some("c1".to_string()));
+    let schema = options.schema()?;
+    assert_eq!(schema.num_fields(), 6);
+    assert_eq!(schema.field(5).name(), "value");
+
+    let avro_schema = format!("avro_schema={}", AVRO_SCHEMA);
+    let options = KafkaOptions::from_args(
+        "read_kafka",
+        &args(&["b:9092", "t", "format=avro", &avro_schema]),
+    )?;
+    assert_eq!(options.consumer, None);
+    let schema = options.schema()?;
+    assert_eq!(schema.num_fields(), 7);
+    assert_eq!(schema.field(5).name(), "id");
+    assert_eq!(schema.field(6).name(), "name");
+
+    let invalid = [
+        vec!["b:9092"],
+        vec!["", "t"],
+        vec!["b:9092", "t", "consumer"],
+        vec!["b:9092", "t", "format=csv"],
+        vec!["b:9092", "t", "format=avro"],
+        vec!["b:9092", "t", "format=avro", "avro_schema=\"long\""],
+        vec!["b:9092", "t", "group=c1"],
+    ];
+    for args_ in invalid {
+        assert!(KafkaOptions::from_args("read_kafka", &args(&args_)).is_err());
+    }
+
+    // The fields of the record can not be named as the columns of the records.
+    let avro_schema =
+        r#"avro_schema={"type":"record","name":"r","fields":[{"name":"_offset","type":"long"}]}"#;
+    let options = KafkaOptions::from_args(
+        "read_kafka",
+        &args(&["b:9092", "t", "format=avro", avro_schema]),
+    )?;
+    assert!(options.schema().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_kafka_records_to_block() -> Result<()> {
+    let options = KafkaOptions::from_args("read_kafka", &args(&["b:9092", "t"]))?;
+    let records = vec![
+        record(3, Some("k"), Some(br#"{"a":1}"#)),
+        // The tombstones are skipped.
+        record(4, Some("k"), None),
+        record(5, None, Some(b"[1,2]")),
+    ];
+    let block = records_to_block(&options.format, &options.schema()?, "t", 1, &records)?;
+    assert_eq!(block.num_rows(), 2);
+    assert_eq!(block.column(1).get(0), DataValue::Int64(1));
+    assert_eq!(block.column(2).get(1), DataValue::Int64(5));
+    assert_eq!(block.column(3).get(1), DataValue::Null);
+    assert_eq!(
+        block.column(5).get(0),
+        DataValue::Variant(VariantValue::from(serde_json::json!({"a": 1})))
+    );
+
+    let invalid = vec![record(6, None, Some(b"{"))];
+    assert!(records_to_block(&options.format, &options.schema()?, "t", 1, &invalid).is_err());
+
+    let avro_schema = format!("avro_schema={}", AVRO_SCHEMA);
+    let options = KafkaOptions::from_args(
+        "read_kafka",
+        &args(&["b:9092", "t", "format=avro", &avro_schema]),
+    )?;
+    // The zigzag encoded longs followed by the length prefixed strings.
+    let records = vec![
+        record(0, None, Some(&[0x02, 0x02, b'a'])),
+        record(1, None, Some(&[0x04, 0x04, b'b', b'c'])),
+    ];
+    let block = records_to_block(&options.format, &options.schema()?, "t", 0, &records)?;
+    assert_eq!(block.num_rows(), 2);
+    assert_eq!(block.column(5).get(1), DataValue::Int64(2));
+    assert_eq!(block.column(6).get(1), DataValue::String(b"bc".to_vec()));
+    Ok(())
+}
@@ -23,6 +23,7 @@ use common_meta_app::schema::TableMeta;
 use databend_query::sessions::TableContext;
 use databend_query::sql::plans::create_table_v2::TableOptions;
 use databend_query::storages::memory::MemoryTable;
+use databend_query::storages::Table;
 use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
@@ -193,3 +194,81 @@ async fn test_memorytable() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_persistent_memorytable() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", u32::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+    ]);
+    let table_info = TableInfo {
+        desc: "'default'.'a'".into(),
+        name: "a".into(),
+        ident: Default::default(),
+        meta: TableMeta {
+            schema: schema.clone(),
+            engine: "Memory".to_string(),
+            options: TableOptions::from([("persistent".to_string(), "true".to_string())]),
+            ..Default::default()
+        },
+    };
+    // The memory data is empty in a new storage context, as after a restart.
+    let restart = |table_info: &TableInfo| {
+        let table_info = table_info.clone();
+        async move { MemoryTable::try_create(crate::tests::create_storage_context().await?, table_info) }
+    };
+    let read_all = |table: Box<dyn Table>| {
+        let ctx = ctx.clone();
+        async move {
+            let source_plan = table.read_plan(ctx.clone(), None).await?;
+            ctx.try_set_partitions(source_plan.parts.clone())?;
+            let stream = table.read(ctx, &source_plan).await?;
+            stream.try_collect::<Vec<_>>().await
+        }
+    };
+
+    let table = restart(&table_info).await?;
+    let block = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![1u32, 2]),
+        Series::from_data(vec![Some("x"), None]),
+    ]);
+    let block2 = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![3u32]),
+        Series::from_data(vec![Some("z")]),
+    ]);
+    table
+        .commit_insertion(ctx.clone(), vec![block], false)
+        .await?;
+    table
+        .commit_insertion(ctx.clone(), vec![block2], false)
+        .await?;
+
+    let result = read_all(restart(&table_info).await?).await?;
+    assert_blocks_sorted_eq(
+        vec![
+            "+---+------+",
+            "| a | b    |",
+            "+---+------+",
+            "| 1 | x    |",
+            "| 2 | NULL |",
+            "| 3 | z    |",
+            "+---+------+",
+        ],
+        &result,
+    );
+
+    restart(&table_info)
+        .await?
+        .truncate(ctx.clone(), false)
+        .await?;
+    let result = read_all(restart(&table_info).await?).await?;
+    assert_blocks_sorted_eq(vec!["++", "++"], &result);
+
+    let mut table_info = table_info;
+    table_info.meta.options = TableOptions::from([("persistent".to_string(), "1".to_string())]);
+    assert!(
+        MemoryTable::try_create(crate::tests::create_storage_context().await?, table_info).is_err()
+    );
+    Ok(())
+}
@@ -12,10 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
+mod delta;
+mod external;
 mod fuse;
+mod iceberg;
 mod index;
+mod kafka;
 mod memory;
 mod null;
+mod orc;
+mod parquet;
+mod random;
 mod result;
 mod statistics;
 mod system;
@@ -0,0 +1,143 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_legacy_expression::*;
+use common_pipeline_sources::processors::sources::input_formats::input_format_orc::OrcFileInMemory;
+use common_pipeline_sources::processors::sources::input_formats::StatisticsFilter;
+use databend_query::storages::index::RangeFilter;
+
+use crate::tests::create_query_context;
+
+// The file has 3 stripes of 100 rows, the ids of the stripe i are in [100 * i, 100 * i + 99],
+// and the score of each row is the half of the id.
+fn read_stripes_file(schema: &DataSchemaRef) -> Result<OrcFileInMemory> {
+    let path = format!(
+        "{}/../../../tests/data/stripes.orc",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let data = std::fs::read(&path)?;
+    OrcFileInMemory::try_create(&path, data, schema)
+}
+
+#[test]
+fn test_orc_read_stripes() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("score", f64::to_data_type()),
+        DataField::new_nullable("id", i64::to_data_type()),
+    ]);
+    let file = read_stripes_file(&schema)?;
+    assert_eq!(file.meta.footer.stripes.len(), 3);
+    assert_eq!(file.stripes_to_read(None), vec![0, 1, 2]);
+
+    for stripe in 0..3 {
+        let chunk = file.read_stripe(stripe)?;
+        let block = DataBlock::from_chunk(&schema, &chunk)?;
+        assert_eq!(block.num_rows(), 100);
+        // The columns are read in the order of the schema, not the one of the file.
+        let first_id = (stripe * 100) as i64;
+        assert_eq!(
+            block.column(0).get(0),
+            DataValue::Float64(first_id as f64 / 2.0)
+        );
+        assert_eq!(block.column(1).get(0), DataValue::Int64(first_id));
+        assert_eq!(block.column(1).get(99), DataValue::Int64(first_id + 99));
+    }
+
+    let stats = file.stripe_statistics(1);
+    let score = stats.get(&0).unwrap();
+    assert_eq!(score.min, DataValue::Float64(50.0));
+    assert_eq!(score.max, DataValue::Float64(99.5));
+    assert_eq!(score.null_count, 0);
+    let id = stats.get(&1).unwrap();
+    assert_eq!(id.min, DataValue::Int64(100));
+    assert_eq!(id.max, DataValue::Int64(199));
+
+    // The columns not in the schema are not read.
+    let schema = DataSchemaRefExt::create(vec![DataField::new_nullable("id", i64::to_data_type())]);
+    let file = read_stripes_file(&schema)?;
+    let chunk = file.read_stripe(2)?;
+    assert_eq!(chunk.arrays().len(), 1);
+
+    // The columns not in the file fail the read.
+    let schema = DataSchemaRefExt::create(vec![DataField::new_nullable("x", i64::to_data_type())]);
+    assert!(read_stripes_file(&schema).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_orc_prune_stripes() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("id", i64::to_data_type()),
+        DataField::new_nullable("score", f64::to_data_type()),
+    ]);
+    let file = read_stripes_file(&schema)?;
+
+    struct Test {
+        name: &'static str,
+        expr: LegacyExpression,
+        expect: Vec<usize>,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "id >= 150",
+            expr: col("id").gt_eq(lit(150i64)),
+            expect: vec![1, 2],
+        },
+        Test {
+            name: "id = 42",
+            expr: col("id").eq(lit(42i64)),
+            expect: vec![0],
+        },
+        Test {
+            name: "score < 10",
+            expr: col("score").lt(lit(10.0f64)),
+            expect: vec![0],
+        },
+        Test {
+            name: "id > 1000",
+            expr: col("id").gt(lit(1000i64)),
+            expect: vec![],
+        },
+        Test {
+            name: "id < 50 or score > 120",
+            expr: col("id").lt(lit(50i64)).or(col("score").gt(lit(120.0f64))),
+            expect: vec![0, 2],
+        },
+    ];
+
+    let (_guard, ctx) = create_query_context().await?;
+    for test in tests {
+        let range_filter = RangeFilter::try_create(ctx.clone(), &[test.expr], schema.clone())?;
+        let filter: StatisticsFilter = Arc::new(move |stats: &StatisticsOfColumns, rows: u64| {
+            range_filter.eval(stats, rows).unwrap_or(true)
+        });
+        assert_eq!(
+            file.stripes_to_read(Some(&filter)),
+            test.expect,
+            "{}",
+            test.name
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_legacy_expression::LegacyExpression;
+use databend_query::storages::parquet::hive_partition_segments;
+use databend_query::storages::parquet::infer_partition_fields;
+use databend_query::storages::parquet::parse_partition_value;
+use databend_query::storages::parquet::parse_partition_values;
+use databend_query::storages::parquet::split_glob_path;
+use databend_query::storages::parquet::ParquetFile;
+use databend_query::storages::parquet::ParquetTable;
+use databend_query::storages::parquet::DEFAULT_PARTITION;
+
+#[test]
+fn test_split_glob_path() -> Result<()> {
+    // No wildcards, the path is listed as it is.
+    let (dir, pattern) = split_glob_path("/data/ontime.parquet")?;
+    assert_eq!(dir, "data/ontime.parquet");
+    assert!(pattern.is_none());
+
+    let (dir, pattern) = split_glob_path("/data/2022/ontime_*.parquet")?;
+    assert_eq!(dir, "data/2022/");
+    let pattern = pattern.unwrap();
+    assert!(pattern.is_match("data/2022/ontime_1.parquet"));
+    assert!(pattern.is_match("data/2022/ontime_.parquet"));
+    assert!(!pattern.is_match("data/2022/ontime_1.csv"));
+    assert!(!pattern.is_match("data/2022/nested/ontime_1.parquet"));
+
+    let (dir, pattern) = split_glob_path("/data/*/ontime_?.parquet")?;
+    assert_eq!(dir, "data/");
+    let pattern = pattern.unwrap();
+    assert!(pattern.is_match("data/2022/ontime_1.parquet"));
+    assert!(!pattern.is_match("data/2022/ontime_10.parquet"));
+
+    // Regex meta characters are matched literally.
+    let (dir, pattern) = split_glob_path("/*.parquet")?;
+    assert_eq!(dir, "");
+    let pattern = pattern.unwrap();
+    assert!(pattern.is_match("a.parquet"));
+    assert!(!pattern.is_match("a_parquet"));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_partition_value() -> Result<()> {
+    let field = DataField::new("year", i32::to_data_type());
+    assert_eq!(
+        parse_partition_value(&field, "2022")?,
+        DataValue::Int64(2022)
+    );
+    assert!(parse_partition_value(&field, "x").is_err());
+    assert!(parse_partition_value(&field, DEFAULT_PARTITION).is_err());
+
+    let field = DataField::new("month", u8::to_data_type());
+    assert_eq!(parse_partition_value(&field, "10")?, DataValue::UInt64(10));
+
+    let field = DataField::new("city", Vu8::to_data_type());
+    assert_eq!(
+        parse_partition_value(&field, "shanghai")?,
+        DataValue::String(b"shanghai".to_vec())
+    );
+
+    // The default partition of hive is NULL if the column is nullable.
+    let field = DataField::new_nullable("city", Vu8::to_data_type());
+    assert_eq!(
+        parse_partition_value(&field, DEFAULT_PARTITION)?,
+        DataValue::Null
+    );
+
+    let field = DataField::new("day", DateType::new_impl());
+    assert!(parse_partition_value(&field, "2022-10-01").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_read_parquet_args() -> Result<()> {
+    let args = |args: &[&str]| {
+        Some(
+            args.iter()
+                .map(|v| LegacyExpression::create_literal(DataValue::String(v.as_bytes().to_vec())))
+                .collect(),
+        )
+    };
+
+    ParquetTable::create(
+        "system",
+        "read_parquet",
+        1,
+        args(&["s3://testbucket/data/*.parquet", "aws_key_id=minioadmin"]),
+    )?;
+    ParquetTable::create("system", "read_parquet", 1, args(&["@s/data/*.parquet"]))?;
+    ParquetTable::create("system", "read_parquet", 1, args(&["@s"]))?;
+
+    // The connection of a stage is kept in the meta service.
+    assert!(
+        ParquetTable::create(
+            "system",
+            "read_parquet",
+            1,
+            args(&["@s/data/*.parquet", "aws_key_id=minioadmin"])
+        )
+        .is_err()
+    );
+    assert!(ParquetTable::create("system", "read_parquet", 1, args(&["@/data"])).is_err());
+    assert!(
+        ParquetTable::create(
+            "system",
+            "read_parquet",
+            1,
+            args(&["s3://testbucket", "key"])
+        )
+        .is_err()
+    );
+    assert!(ParquetTable::create("system", "read_parquet", 1, None).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_infer_partition_fields() -> Result<()> {
+    assert_eq!(
+        hive_partition_segments("/data/year=2022/month=10/0.parquet"),
+        vec![("year", "2022"), ("month", "10")]
+    );
+    assert!(hive_partition_segments("/data/a=b.parquet").is_empty());
+
+    let file = |location: &str| ParquetFile {
+        location: location.to_string(),
+        ..Default::default()
+    };
+    let file_schema = DataSchema::new(vec![DataField::new("id", i64::to_data_type())]);
+
+    let files = vec![
+        file("/data/year=2021/city=beijing/0.parquet"),
+        file("/data/year=2022/city=__HIVE_DEFAULT_PARTITION__/0.parquet"),
+    ];
+    assert_eq!(infer_partition_fields("data/", &files, &file_schema), vec![
+        DataField::new("year", i64::to_data_type()),
+        DataField::new_nullable("city", Vu8::to_data_type()),
+    ]);
+    assert_eq!(
+        parse_partition_values(&files[0].location, &["year".to_string()])?
+            .get("year")
+            .cloned(),
+        Some("2021".to_string())
+    );
+
+    // The directories above the location are not partitions.
+    let files = vec![file("/year=2022/data/0.parquet")];
+    assert!(infer_partition_fields("year=2022/data/", &files, &file_schema).is_empty());
+
+    // The columns stored in the files are not partitions.
+    let files = vec![file("/data/id=1/0.parquet")];
+    assert!(infer_partition_fields("data/", &files, &file_schema).is_empty());
+
+    // The files must be partitioned the same.
+    let files = vec![
+        file("/data/year=2021/0.parquet"),
+        file("/data/month=10/0.parquet"),
+    ];
+    assert!(infer_partition_fields("data/", &files, &file_schema).is_empty());
+
+    Ok(())
+}
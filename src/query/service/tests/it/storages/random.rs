@@ -0,0 +1,131 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::storages::random::column_rng;
+use databend_query::storages::random::generate_column;
+use databend_query::storages::random::Distribution;
+use databend_query::storages::random::RandomOptions;
+
+fn options(items: &[(&str, &str)]) -> BTreeMap<String, String> {
+    items
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_random_options() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("i", i32::to_data_type()),
+        DataField::new("f", f64::to_data_type()),
+        DataField::new("s", Vu8::to_data_type()),
+        DataField::new_nullable("n", i64::to_data_type()),
+    ]);
+
+    let random_options = RandomOptions::try_from_options(&options(&[
+        ("seed", "42"),
+        (
+            "column_distribution",
+            "i=zipf(100, 1.2), f = normal(0, 1.5)",
+        ),
+        ("column_length", "s=3-8"),
+        ("column_null_ratio", "n=0.1"),
+    ]))?;
+    random_options.check_columns(&schema)?;
+    assert_eq!(random_options.seed, Some(42));
+    assert_eq!(
+        random_options.get("i").distribution,
+        Some(Distribution::Zipf { n: 100, s: 1.2 })
+    );
+    assert_eq!(
+        random_options.get("f").distribution,
+        Some(Distribution::Normal {
+            mean: 0.0,
+            std_dev: 1.5
+        })
+    );
+    assert_eq!(random_options.get("s").length, Some((3, 8)));
+    assert_eq!(random_options.get("n").null_ratio, Some(0.1));
+    assert_eq!(random_options.get("x"), Default::default());
+
+    let invalid = [
+        ("seed", "-1"),
+        ("column_distribution", "i=uniform(2, 1)"),
+        ("column_distribution", "i=zipf(0.5, 1)"),
+        ("column_distribution", "i=poisson(1)"),
+        ("column_distribution", "i=uniform(1, 2), i=uniform(1, 3)"),
+        ("column_length", "s=8-3"),
+        ("column_null_ratio", "n=2"),
+        ("column_null_ratio", "n"),
+    ];
+    for (key, value) in invalid {
+        assert!(RandomOptions::try_from_options(&options(&[(key, value)])).is_err());
+    }
+
+    // The options must apply to the types of the columns.
+    let mismatched = [
+        ("column_distribution", "s=uniform(1, 2)"),
+        ("column_length", "i=5"),
+        ("column_null_ratio", "i=0.5"),
+        ("column_null_ratio", "x=0.5"),
+    ];
+    for (key, value) in mismatched {
+        let random_options = RandomOptions::try_from_options(&options(&[(key, value)]))?;
+        assert!(random_options.check_columns(&schema).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_random_generate_column() -> Result<()> {
+    let random_options = RandomOptions::try_from_options(&options(&[
+        ("column_distribution", "i=uniform(1, 10), z=zipf(10, 2)"),
+        ("column_length", "s=3-8"),
+        ("column_null_ratio", "n=1"),
+    ]))?;
+
+    let generate = |name: &str, data_type: DataTypeImpl, seed: Option<u64>| {
+        let mut rng = column_rng(seed, name);
+        generate_column(&data_type, &random_options.get(name), &mut rng, 1000)
+    };
+
+    let column = generate("i", i32::to_data_type(), Some(1))?;
+    let values = (0..column.len())
+        .map(|row| column.get(row).as_i64())
+        .collect::<Result<Vec<_>>>()?;
+    assert!(values.iter().all(|v| (1..=10).contains(v)));
+    // The same seed generates the same values.
+    assert_eq!(column, generate("i", i32::to_data_type(), Some(1))?);
+    assert_ne!(column, generate("i", i32::to_data_type(), Some(2))?);
+
+    let column = generate("z", u8::to_data_type(), Some(1))?;
+    let ones = (0..column.len())
+        .filter(|row| column.get(*row) == DataValue::UInt64(1))
+        .count();
+    assert!(ones > 500);
+
+    let column = generate("s", Vu8::to_data_type(), None)?;
+    for row in 0..column.len() {
+        let length = column.get(row).as_string()?.len();
+        assert!((3..=8).contains(&length));
+    }
+
+    let column = generate("n", NullableType::new_impl(i64::to_data_type()), None)?;
+    assert!((0..column.len()).all(|row| column.get(row).is_null()));
+    Ok(())
+}
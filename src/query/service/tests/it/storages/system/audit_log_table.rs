@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::audit_category;
+use databend_query::storages::system::AuditLogTable;
+use databend_query::storages::Table;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_audit_log_table() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let table = AuditLogTable::create(1, 10);
+
+    // the audit log is append-only
+    let res = table.truncate(ctx, false).await;
+    assert_eq!(
+        "Code: 1063, displayText = 'system'.'audit_log' is append-only, can't be truncated.",
+        res.err().unwrap().to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_audit_category() {
+    assert_eq!(audit_category("GrantPriv"), Some("privilege"));
+    assert_eq!(audit_category("CreateUser"), Some("privilege"));
+    assert_eq!(audit_category("CreateNetworkPolicy"), Some("privilege"));
+    assert_eq!(audit_category("Insert"), Some("dml"));
+    assert_eq!(audit_category("Delete"), Some("dml"));
+    assert_eq!(audit_category("CreateTable"), Some("ddl"));
+    assert_eq!(audit_category("AlterTableClusterKey"), Some("ddl"));
+    assert_eq!(audit_category("UndropDatabase"), Some("ddl"));
+    assert_eq!(audit_category("Query"), None);
+    assert_eq!(audit_category("SetVariable"), None);
+}
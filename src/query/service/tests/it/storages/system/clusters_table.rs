@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_datavalues::prelude::*;
 use common_exception::Result;
 use databend_query::storages::system::ClustersTable;
 use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
+use databend_query::DATABEND_COMMIT_VERSION;
 use futures::TryStreamExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -29,7 +31,21 @@ async fn test_clusters_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 3);
+    assert_eq!(block.num_columns(), 13);
+    assert_eq!(block.num_rows(), 1);
+
+    // The local node registered to the meta-service with its version and the heartbeat time.
+    let version = block.try_column_by_name("version")?.get_checked(0)?;
+    assert_eq!(
+        version,
+        DataValue::String(DATABEND_COMMIT_VERSION.as_bytes().to_vec())
+    );
+    let last_heartbeat = block.try_column_by_name("last_heartbeat")?.get_checked(0)?;
+    assert!(!last_heartbeat.is_null());
+
+    // The local node is not draining.
+    let draining = block.try_column_by_name("draining")?.get_checked(0)?;
+    assert_eq!(draining, DataValue::Boolean(false));
 
     Ok(())
 }
@@ -54,6 +54,9 @@ async fn test_configs_table() -> Result<()> {
         "| log     | query_enabled                        | false                          |             |",
         "| log     | stderr.level                         | DEBUG                          |             |",
         "| log     | stderr.on                            | true                           |             |",
+        "| log     | tracing.level                        | INFO                           |             |",
+        "| log     | tracing.on                           | false                          |             |",
+        "| log     | tracing.otlp_endpoint                | http://127.0.0.1:4317          |             |",
         "| meta    | address                              |                                |             |",
         "| meta    | auto_sync_interval                   | 10                             |             |",
         "| meta    | client_timeout_in_second             | 10                             |             |",
@@ -69,14 +72,33 @@ async fn test_configs_table() -> Result<()> {
         "| query   | api_tls_server_root_ca_cert          |                                |             |",
         "| query   | async_insert_busy_timeout            | 200                            |             |",
         "| query   | async_insert_max_data_size           | 10000                          |             |",
+        "| query   | async_insert_max_rows                | 10000                          |             |",
         "| query   | async_insert_stale_timeout           | 0                              |             |",
+        "| query   | audit_log_enabled                    | false                          |             |",
+        "| query   | audit_log_filter                     | auth,ddl,dml,privilege         |             |",
+        "| query   | audit_log_max_rows                   | 10000                          |             |",
+        "| query   | audit_log_retention_secs             | 0                              |             |",
+        "| query   | auto_compaction_enabled              | false                          |             |",
+        "| query   | auto_compaction_interval_secs        | 300                            |             |",
+        "| query   | auto_compaction_max_bytes_per_run    | 1073741824                     |             |",
+        "| query   | auto_compaction_max_threads          | 2                              |             |",
+        "| query   | auto_compaction_min_excess_blocks    | 100                            |             |",
+        "| query   | auto_compaction_window               |                                |             |",
         "| query   | clickhouse_handler_host              | 127.0.0.1                      |             |",
         "| query   | clickhouse_handler_port              | 9000                           |             |",
         "| query   | clickhouse_http_handler_host         | 127.0.0.1                      |             |",
         "| query   | clickhouse_http_handler_port         | 8124                           |             |",
+        "| query   | cluster_heartbeat_timeout_secs       | 60                             |             |",
         "| query   | cluster_id                           |                                |             |",
+        "| query   | connection_encryption_key            |                                |             |",
+        "| query   | connection_kms_token                 |                                |             |",
+        "| query   | connection_kms_url                   |                                |             |",
         "| query   | database_engine_github_enabled       | true                           |             |",
         "| query   | flight_api_address                   | 127.0.0.1:9090                 |             |",
+        "| query   | flight_exchange_compression          | none                           |             |",
+        "| query   | flight_exchange_credits              | 16                             |             |",
+        "| query   | flight_sql_handler_host              | 127.0.0.1                      |             |",
+        "| query   | flight_sql_handler_port              | 8900                           |             |",
         "| query   | http_handler_host                    | 127.0.0.1                      |             |",
         "| query   | http_handler_port                    | 8000                           |             |",
         "| query   | http_handler_result_timeout_millis   | 10000                          |             |",
@@ -84,22 +106,62 @@ async fn test_configs_table() -> Result<()> {
         "| query   | http_handler_tls_server_key          |                                |             |",
         "| query   | http_handler_tls_server_root_ca_cert |                                |             |",
         "| query   | jwt_key_file                         |                                |             |",
+        "| query   | jwt_key_refresh_interval_secs        | 900                            |             |",
+        "| query   | jwt_role_claim                       | role                           |             |",
+        "| query   | jwt_user_claim                       | sub                            |             |",
+        "| query   | ldap_bind_dn_template                |                                |             |",
+        "| query   | ldap_group_attribute                 | memberOf                       |             |",
+        "| query   | ldap_group_role_mapping              |                                |             |",
+        "| query   | ldap_search_base                     |                                |             |",
+        "| query   | ldap_search_bind_dn                  |                                |             |",
+        "| query   | ldap_search_bind_password            |                                |             |",
+        "| query   | ldap_search_filter                   | (uid={user})                   |             |",
+        "| query   | ldap_url                             |                                |             |",
         "| query   | management_mode                      | false                          |             |",
         "| query   | max_active_sessions                  | 256                            |             |",
+        "| query   | max_connections_per_user             | 0                              |             |",
         "| query   | max_query_log_size                   | 10000                          |             |",
+        "| query   | max_queued_queries                   | 100                            |             |",
+        "| query   | max_reserved_memory_usage            | 0                              |             |",
+        "| query   | max_running_queries                  | 0                              |             |",
         "| query   | metric_api_address                   | 127.0.0.1:7070                 |             |",
+        "| query   | mysql_handler_compression_enabled    | true                           |             |",
         "| query   | mysql_handler_host                   | 127.0.0.1                      |             |",
         "| query   | mysql_handler_port                   | 3307                           |             |",
+        "| query   | mysql_tls_server_cert                |                                |             |",
+        "| query   | mysql_tls_server_key                 |                                |             |",
+        "| query   | mysql_tls_server_root_ca_cert        |                                |             |",
         "| query   | num_cpus                             | 0                              |             |",
+        "| query   | plan_cache_max_entries               | 1024                           |             |",
+        "| query   | postgres_handler_host                | 127.0.0.1                      |             |",
+        "| query   | postgres_handler_port                | 5433                           |             |",
+        "| query   | postgres_tls_server_cert             |                                |             |",
+        "| query   | postgres_tls_server_key              |                                |             |",
+        "| query   | query_log_flush_batch_rows           | 1000                           |             |",
+        "| query   | query_log_flush_interval_secs        | 10                             |             |",
+        "| query   | query_log_persistent_enabled         | false                          |             |",
+        "| query   | query_queue_timeout_secs             | 60                             |             |",
+        "| query   | query_result_cache_max_bytes         | 268435456                      |             |",
+        "| query   | read_only                            | false                          |             |",
+        "| query   | rpc_tls_query_client_cert            |                                |             |",
+        "| query   | rpc_tls_query_client_key             |                                |             |",
         "| query   | rpc_tls_query_server_root_ca_cert    |                                |             |",
         "| query   | rpc_tls_query_service_domain_name    | localhost                      |             |",
         "| query   | rpc_tls_server_cert                  |                                |             |",
         "| query   | rpc_tls_server_key                   |                                |             |",
+        "| query   | rpc_tls_server_root_ca_cert          |                                |             |",
+        "| query   | session_idle_timeout_secs            | 0                              |             |",
+        "| query   | session_max_lifetime_secs            | 0                              |             |",
+        "| query   | spill_encryption_enabled             | false                          |             |",
+        "| query   | spill_local_disk_path                |                                |             |",
+        "| query   | spill_query_quota_bytes              | 0                              |             |",
+        "| query   | storage_io_coalesce_gap_bytes        | 65536                          |             |",
+        "| query   | storage_io_max_requests              | 64                             |             |",
         "| query   | table_cache_block_meta_count         | 102400                         |             |",
         "| query   | table_cache_enabled                  | false                          |             |",
         "| query   | table_cache_segment_count            | 10240                          |             |",
         "| query   | table_cache_snapshot_count           | 256                            |             |",
-        "| query   | table_disk_cache_mb_size             | 1024                           |             |",
+        "| query   | table_disk_cache_mb_size             | 0                              |             |",
         "| query   | table_disk_cache_root                | _cache                         |             |",
         "| query   | table_engine_memory_enabled          | true                           |             |",
         "| query   | table_memory_cache_mb_size           | 256                            |             |",
@@ -188,6 +250,9 @@ async fn test_configs_table_redact() -> Result<()> {
         "| log     | query_enabled                        | false                          |             |",
         "| log     | stderr.level                         | DEBUG                          |             |",
         "| log     | stderr.on                            | true                           |             |",
+        "| log     | tracing.level                        | INFO                           |             |",
+        "| log     | tracing.on                           | false                          |             |",
+        "| log     | tracing.otlp_endpoint                | http://127.0.0.1:4317          |             |",
         "| meta    | address                              |                                |             |",
         "| meta    | auto_sync_interval                   | 10                             |             |",
         "| meta    | client_timeout_in_second             | 10                             |             |",
@@ -203,14 +268,33 @@ async fn test_configs_table_redact() -> Result<()> {
         "| query   | api_tls_server_root_ca_cert          |                                |             |",
         "| query   | async_insert_busy_timeout            | 200                            |             |",
         "| query   | async_insert_max_data_size           | 10000                          |             |",
+        "| query   | async_insert_max_rows                | 10000                          |             |",
         "| query   | async_insert_stale_timeout           | 0                              |             |",
+        "| query   | audit_log_enabled                    | false                          |             |",
+        "| query   | audit_log_filter                     | auth,ddl,dml,privilege         |             |",
+        "| query   | audit_log_max_rows                   | 10000                          |             |",
+        "| query   | audit_log_retention_secs             | 0                              |             |",
+        "| query   | auto_compaction_enabled              | false                          |             |",
+        "| query   | auto_compaction_interval_secs        | 300                            |             |",
+        "| query   | auto_compaction_max_bytes_per_run    | 1073741824                     |             |",
+        "| query   | auto_compaction_max_threads          | 2                              |             |",
+        "| query   | auto_compaction_min_excess_blocks    | 100                            |             |",
+        "| query   | auto_compaction_window               |                                |             |",
         "| query   | clickhouse_handler_host              | 127.0.0.1                      |             |",
         "| query   | clickhouse_handler_port              | 9000                           |             |",
         "| query   | clickhouse_http_handler_host         | 127.0.0.1                      |             |",
         "| query   | clickhouse_http_handler_port         | 8124                           |             |",
+        "| query   | cluster_heartbeat_timeout_secs       | 60                             |             |",
         "| query   | cluster_id                           |                                |             |",
+        "| query   | connection_encryption_key            |                                |             |",
+        "| query   | connection_kms_token                 |                                |             |",
+        "| query   | connection_kms_url                   |                                |             |",
         "| query   | database_engine_github_enabled       | true                           |             |",
         "| query   | flight_api_address                   | 127.0.0.1:9090                 |             |",
+        "| query   | flight_exchange_compression          | none                           |             |",
+        "| query   | flight_exchange_credits              | 16                             |             |",
+        "| query   | flight_sql_handler_host              | 127.0.0.1                      |             |",
+        "| query   | flight_sql_handler_port              | 8900                           |             |",
         "| query   | http_handler_host                    | 127.0.0.1                      |             |",
         "| query   | http_handler_port                    | 8000                           |             |",
         "| query   | http_handler_result_timeout_millis   | 10000                          |             |",
@@ -218,22 +302,62 @@ async fn test_configs_table_redact() -> Result<()> {
         "| query   | http_handler_tls_server_key          |                                |             |",
         "| query   | http_handler_tls_server_root_ca_cert |                                |             |",
         "| query   | jwt_key_file                         |                                |             |",
+        "| query   | jwt_key_refresh_interval_secs        | 900                            |             |",
+        "| query   | jwt_role_claim                       | role                           |             |",
+        "| query   | jwt_user_claim                       | sub                            |             |",
+        "| query   | ldap_bind_dn_template                |                                |             |",
+        "| query   | ldap_group_attribute                 | memberOf                       |             |",
+        "| query   | ldap_group_role_mapping              |                                |             |",
+        "| query   | ldap_search_base                     |                                |             |",
+        "| query   | ldap_search_bind_dn                  |                                |             |",
+        "| query   | ldap_search_bind_password            |                                |             |",
+        "| query   | ldap_search_filter                   | (uid={user})                   |             |",
+        "| query   | ldap_url                             |                                |             |",
         "| query   | management_mode                      | false                          |             |",
         "| query   | max_active_sessions                  | 256                            |             |",
+        "| query   | max_connections_per_user             | 0                              |             |",
         "| query   | max_query_log_size                   | 10000                          |             |",
+        "| query   | max_queued_queries                   | 100                            |             |",
+        "| query   | max_reserved_memory_usage            | 0                              |             |",
+        "| query   | max_running_queries                  | 0                              |             |",
         "| query   | metric_api_address                   | 127.0.0.1:7070                 |             |",
+        "| query   | mysql_handler_compression_enabled    | true                           |             |",
         "| query   | mysql_handler_host                   | 127.0.0.1                      |             |",
         "| query   | mysql_handler_port                   | 3307                           |             |",
+        "| query   | mysql_tls_server_cert                |                                |             |",
+        "| query   | mysql_tls_server_key                 |                                |             |",
+        "| query   | mysql_tls_server_root_ca_cert        |                                |             |",
         "| query   | num_cpus                             | 0                              |             |",
+        "| query   | plan_cache_max_entries               | 1024                           |             |",
+        "| query   | postgres_handler_host                | 127.0.0.1                      |             |",
+        "| query   | postgres_handler_port                | 5433                           |             |",
+        "| query   | postgres_tls_server_cert             |                                |             |",
+        "| query   | postgres_tls_server_key              |                                |             |",
+        "| query   | query_log_flush_batch_rows           | 1000                           |             |",
+        "| query   | query_log_flush_interval_secs        | 10                             |             |",
+        "| query   | query_log_persistent_enabled         | false                          |             |",
+        "| query   | query_queue_timeout_secs             | 60                             |             |",
+        "| query   | query_result_cache_max_bytes         | 268435456                      |             |",
+        "| query   | read_only                            | false                          |             |",
+        "| query   | rpc_tls_query_client_cert            |                                |             |",
+        "| query   | rpc_tls_query_client_key             |                                |             |",
         "| query   | rpc_tls_query_server_root_ca_cert    |                                |             |",
         "| query   | rpc_tls_query_service_domain_name    | localhost                      |             |",
         "| query   | rpc_tls_server_cert                  |                                |             |",
         "| query   | rpc_tls_server_key                   |                                |             |",
+        "| query   | rpc_tls_server_root_ca_cert          |                                |             |",
+        "| query   | session_idle_timeout_secs            | 0                              |             |",
+        "| query   | session_max_lifetime_secs            | 0                              |             |",
+        "| query   | spill_encryption_enabled             | false                          |             |",
+        "| query   | spill_local_disk_path                |                                |             |",
+        "| query   | spill_query_quota_bytes              | 0                              |             |",
+        "| query   | storage_io_coalesce_gap_bytes        | 65536                          |             |",
+        "| query   | storage_io_max_requests              | 64                             |             |",
         "| query   | table_cache_block_meta_count         | 102400                         |             |",
         "| query   | table_cache_enabled                  | false                          |             |",
         "| query   | table_cache_segment_count            | 10240                          |             |",
         "| query   | table_cache_snapshot_count           | 256                            |             |",
-        "| query   | table_disk_cache_mb_size             | 1024                           |             |",
+        "| query   | table_disk_cache_mb_size             | 0                              |             |",
         "| query   | table_disk_cache_root                | _cache                         |             |",
         "| query   | table_engine_memory_enabled          | true                           |             |",
         "| query   | table_memory_cache_mb_size           | 256                            |             |",
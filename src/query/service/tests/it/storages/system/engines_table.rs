@@ -30,15 +30,19 @@ async fn test_engines_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
 
     let expected = vec![
-        "+--------+-----------------------------+",
-        "| Engine | Comment                     |",
-        "+--------+-----------------------------+",
-        "| NULL   | NULL Storage Engine         |",
-        "| FUSE   | FUSE Storage Engine         |",
-        "| RANDOM | RANDOM Storage Engine       |",
-        "| MEMORY | MEMORY Storage Engine       |",
-        "| VIEW   | VIEW STORAGE (LOGICAL VIEW) |",
-        "+--------+-----------------------------+",
+        "+----------+-----------------------------+",
+        "| Engine   | Comment                     |",
+        "+----------+-----------------------------+",
+        "| DELTA    | DELTA Storage Engine        |",
+        "| EXTERNAL | EXTERNAL Storage Engine     |",
+        "| NULL     | NULL Storage Engine         |",
+        "| FUSE     | FUSE Storage Engine         |",
+        "| ICEBERG  | ICEBERG Storage Engine      |",
+        "| RANDOM   | RANDOM Storage Engine       |",
+        "| MEMORY   | MEMORY Storage Engine       |",
+        "| STREAM   | STREAM Storage Engine       |",
+        "| VIEW     | VIEW STORAGE (LOGICAL VIEW) |",
+        "+----------+-----------------------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected.clone(), result.as_slice());
 
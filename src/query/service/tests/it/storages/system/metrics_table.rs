@@ -21,6 +21,8 @@ use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_metrics_table() -> Result<()> {
     init_default_metrics_recorder();
@@ -43,3 +45,22 @@ async fn test_metrics_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_metrics_table_executor() -> Result<()> {
+    init_default_metrics_recorder();
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    execute_command(ctx.clone(), "SELECT sum(number) FROM numbers(1000)").await?;
+
+    let table = MetricsTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let output = pretty_format_blocks(result.as_slice())?;
+    assert!(output.contains("executor_workers"));
+    assert!(output.contains("executor_pending_tasks"));
+    assert!(output.contains("executor_worker_busy_microseconds"));
+
+    Ok(())
+}
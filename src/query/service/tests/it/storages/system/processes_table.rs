@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_progress() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+    let session = ctx.get_current_session();
+
+    // The process of the query itself, of which the estimated total is of the numbers to scan.
+    let ctx = session.create_query_context().await?;
+    let sql = format!(
+        "select distinct p.scan_progress_total_rows, p.scan_progress_total_bytes, \
+        p.elapsed_ms is not null as has_elapsed from system.processes p, numbers(10) n where p.id = '{}'",
+        session.get_id()
+    );
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(&sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let blocks = interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let expected = vec![
+        "+--------------------------+---------------------------+-------------+",
+        "| scan_progress_total_rows | scan_progress_total_bytes | has_elapsed |",
+        "+--------------------------+---------------------------+-------------+",
+        "| 10                       | 80                        | true        |",
+        "+--------------------------+---------------------------+-------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+    Ok(())
+}
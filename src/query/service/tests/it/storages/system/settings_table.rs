@@ -34,29 +34,51 @@ async fn test_settings_table() -> Result<()> {
         "+--------------------------------+------------+------------+---------+----------------------------------------------------------------------------------------------------+--------+",
         "| name                           | value      | default    | level   | description                                                                                        | type   |",
         "+--------------------------------+------------+------------+---------+----------------------------------------------------------------------------------------------------+--------+",
-        "| compression                    | None       | None       | SESSION | Format compression, default value: None                                                            | String |",
-        "| empty_as_default               | 1          | 1          | SESSION | Format empty_as_default, default value: 1                                                          | UInt64 |",
-        "| enable_async_insert            | 0          | 0          | SESSION | Whether the client open async insert mode, default value: 0                                        | UInt64 |",
-        "| enable_cbo                     | 1          | 1          | SESSION | If enable cost based optimization, default value: 1                                                | UInt64 |",
-        "| enable_new_processor_framework | 1          | 1          | SESSION | Enable new processor framework if value != 0, default value: 1                                     | UInt64 |",
-        "| enable_planner_v2              | 1          | 1          | SESSION | Enable planner v2 by setting this variable to 1, default value: 1                                  | UInt64 |",
-        "| field_delimiter                | ,          | ,          | SESSION | Format field delimiter, default value: ,                                                           | String |",
-        "| flight_client_timeout          | 60         | 60         | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds | UInt64 |",
-        "| input_read_buffer_size         | 1048576    | 1048576    | SESSION | The size of buffer in bytes for input with format. By default, it is 1MB.                          | UInt64 |",
-        "| group_by_two_level_threshold   | 10000      | 10000      | SESSION | The threshold of keys to open two-level aggregation, default value: 10000                          | UInt64 |",
-        "| max_block_size                 | 10000      | 10000      | SESSION | Maximum block size for reading                                                                     | UInt64 |",
-        "| max_execute_time               | 0          | 0          | SESSION | The maximum query execution time. it means no limit if the value is zero. default value: 0         | UInt64 |",
+        "| avro_incompatible_field        | error      | error      | DEFAULT | Action on avro fields missing or incompatible with the table: \"error\" or \"null\", default: \"error\"  | String |",
+        "| compression                    | None       | None       | DEFAULT | Format compression, default value: None                                                            | String |",
+        "| continue_on_error              | 0          | 0          | DEFAULT | Whether the statements of a multi-statement query after a failed one run. default: 0               | UInt64 |",
+        "| double_quote                   | dialect    | dialect    | DEFAULT | What double quotes quote: \"identifier\", \"string\" or \"dialect\" (by sql_dialect). default: \"dialect\" | String |",
+        "| empty_as_default               | 1          | 1          | DEFAULT | Format empty_as_default, default value: 1                                                          | UInt64 |",
+        "| enable_adaptive_filter         | 0          | 0          | DEFAULT | Orders the conjuncts of a filter at runtime by their measured cost and selectivity. default: 0     | UInt64 |",
+        "| enable_aggregating_index       | 1          | 1          | DEFAULT | Answers the aggregations of the tables from their aggregating indexes. default: 1                  | UInt64 |",
+        "| enable_async_insert            | 0          | 0          | DEFAULT | Whether the client open async insert mode, default value: 0                                        | UInt64 |",
+        "| enable_cbo                     | 1          | 1          | DEFAULT | If enable cost based optimization, default value: 1                                                | UInt64 |",
+        "| enable_dictionary_encoding     | 0          | 0          | DEFAULT | Keeps the dictionaries of the string columns read from the fuse tables. default: 0                 | UInt64 |",
+        "| enable_fused_filter            | 0          | 0          | DEFAULT | Evaluates the comparisons of the numeric columns in a filter in one pass. default: 0               | UInt64 |",
+        "| enable_new_processor_framework | 1          | 1          | DEFAULT | Enable new processor framework if value != 0, default value: 1                                     | UInt64 |",
+        "| enable_plan_cache              | 0          | 0          | DEFAULT | Reuses the plans of the queries of the same shape while the tables are not changed. default: 0     | UInt64 |",
+        "| enable_planner_v2              | 1          | 1          | DEFAULT | Enable planner v2 by setting this variable to 1, default value: 1                                  | UInt64 |",
+        "| enable_query_result_cache      | 0          | 0          | DEFAULT | Serves the identical queries from the cached results while the tables are not changed. default: 0  | UInt64 |",
+        "| enable_replay_capture          | 0          | 0          | DEFAULT | Captures the inputs of the queries into the replay bundles in the storage. default: 0              | UInt64 |",
+        "| enable_spill_to_storage        | 0          | 0          | DEFAULT | Spills to the temporary stage of the query in the storage, instead of the local disk. default: 0   | UInt64 |",
+        "| error_on_column_count_mismatch | 1          | 1          | DEFAULT | Whether CSV rows with a different number of fields from the table are errors, default value: 1     | UInt64 |",
+        "| escape_char                    | ''         | ''         | DEFAULT | The escape char for CSV, empty for none. default value: ''.                                        | String |",
+        "| field_delimiter                | ,          | ,          | DEFAULT | Format field delimiter, default value: ,                                                           | String |",
+        "| flight_client_timeout          | 60         | 60         | DEFAULT | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds | UInt64 |",
+        "| input_read_buffer_size         | 1048576    | 1048576    | DEFAULT | The size of buffer in bytes for input with format. By default, it is 1MB.                          | UInt64 |",
+        "| group_by_two_level_threshold   | 10000      | 10000      | DEFAULT | The threshold of keys to open two-level aggregation, default value: 10000                          | UInt64 |",
+        "| max_block_size                 | 10000      | 10000      | DEFAULT | Maximum block size for reading                                                                     | UInt64 |",
+        "| max_execute_time               | 0          | 0          | DEFAULT | The maximum query execution time. it means no limit if the value is zero. default value: 0         | UInt64 |",
+        "| max_memory_usage               | 0          | 0          | DEFAULT | The maximum memory usage of the query in bytes, no limit if the value is zero. default value: 0    | UInt64 |",
         "| max_threads                    | 2          | 16         | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.  | UInt64 |",
-        "| quote_char                     | '\"'        | '\"'        | SESSION | The quote char for CSV. default value: '\"'.                                                        | String |",
-        "| quoted_ident_case_sensitive    | 1          | 1          | SESSION | Case sensitivity of quoted identifiers, default value: 1 (aka case-sensitive)                      | UInt64 |",
-        "| record_delimiter               | \"\\n\"       | \"\\n\"       | SESSION | Format record_delimiter, default value: \"\\n\"                                                       | String |",
-        "| skip_header                    | 0          | 0          | SESSION | Whether to skip the input header, default value: 0                                                 | UInt64 |",
-        "| sql_dialect                    | PostgreSQL | PostgreSQL | SESSION | SQL dialect, support \"PostgreSQL\" and \"MySQL\", default value: \"PostgreSQL\"                         | String |",
-        "| storage_read_buffer_size       | 1048576    | 1048576    | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                     | UInt64 |",
-        "| timezone                       | UTC        | UTC        | SESSION | Timezone, default value: UTC,                                                                      | String |",
-        "| unquoted_ident_case_sensitive  | 0          | 0          | SESSION | Case sensitivity of unquoted identifiers, default value: 0 (aka case-insensitive)                  | UInt64 |",
-        "| wait_for_async_insert          | 1          | 1          | SESSION | Whether the client wait for the reply of async insert, default value: 1                            | UInt64 |",
-        "| wait_for_async_insert_timeout  | 100        | 100        | SESSION | The timeout in seconds for waiting for processing of async insert, default value: 100              | UInt64 |",
+        "| missing_field_as               | null       | null       | DEFAULT | Action on NDJSON fields missing from a row: \"error\", \"null\" or \"field_default\", default: \"null\"    | String |",
+        "| null_display                   | '\\N'       | '\\N'       | DEFAULT | The string loaded as NULL for CSV. default value: '\\N'.                                            | String |",
+        "| query_result_cache_ttl_secs    | 300        | 300        | DEFAULT | The seconds the query results are cached for. default: 300                                         | UInt64 |",
+        "| query_tag                      | ''         | ''         | DEFAULT | The tag of the queries in the logs, the default tag of the user is used if it's empty. default: \"\" | String |",
+        "| quote_char                     | '\"'        | '\"'        | DEFAULT | The quote char for CSV. default value: '\"'.                                                        | String |",
+        "| quoted_ident_case_sensitive    | 1          | 1          | DEFAULT | Case sensitivity of quoted identifiers, default value: 1 (aka case-sensitive)                      | UInt64 |",
+        "| record_delimiter               | \"\\n\"       | \"\\n\"       | DEFAULT | Format record_delimiter, default value: \"\\n\"                                                       | String |",
+        "| resource_group                 | ''         | ''         | DEFAULT | The resource group the queries run in, the one of the user is used if it's empty. default: \"\"      | String |",
+        "| skip_header                    | 0          | 0          | DEFAULT | Whether to skip the input header, default value: 0                                                 | UInt64 |",
+        "| slow_query_threshold_ms        | 0          | 0          | DEFAULT | Write the queries slower than the milliseconds to the slow query log, disabled if zero. default: 0 | UInt64 |",
+        "| sql_dialect                    | PostgreSQL | PostgreSQL | DEFAULT | SQL dialect, support \"PostgreSQL\" and \"MySQL\", default value: \"PostgreSQL\"                         | String |",
+        "| storage_read_buffer_size       | 1048576    | 1048576    | DEFAULT | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                     | UInt64 |",
+        "| table_lock_expire_secs         | 10         | 10         | DEFAULT | The seconds the lock of a table held by a mutation lasts unless it is extended. default: 10        | UInt64 |",
+        "| timezone                       | UTC        | UTC        | DEFAULT | Timezone, default value: UTC,                                                                      | String |",
+        "| unquoted_ident_case            | lower      | lower      | DEFAULT | The case unquoted identifiers are normalized to: \"lower\", \"upper\" or \"preserve\". default: \"lower\"  | String |",
+        "| unquoted_ident_case_sensitive  | 0          | 0          | DEFAULT | Case sensitivity of unquoted identifiers, default value: 0 (aka case-insensitive)                  | UInt64 |",
+        "| wait_for_async_insert          | 1          | 1          | DEFAULT | Whether the client wait for the reply of async insert, default value: 1                            | UInt64 |",
+        "| wait_for_async_insert_timeout  | 100        | 100        | DEFAULT | The timeout in seconds for waiting for processing of async insert, default value: 100              | UInt64 |",
         "+--------------------------------+------------+------------+---------+----------------------------------------------------------------------------------------------------+--------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
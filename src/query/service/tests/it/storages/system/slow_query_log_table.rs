@@ -0,0 +1,79 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use databend_query::interpreters::format_processors_profile;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+
+async fn execute_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(sql).await?;
+    ctx.attach_query_str(plan.to_string(), sql);
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_slow_query_log_table() -> Result<()> {
+    let (_guard, ctx) = crate::tests::create_query_context().await?;
+
+    // The query faster than the threshold is not logged.
+    ctx.get_settings().set_slow_query_threshold_ms(60_000)?;
+    execute_sql(&ctx, "select sleep(0.1) from numbers(1)").await?;
+
+    ctx.get_settings().set_slow_query_threshold_ms(50)?;
+    execute_sql(&ctx, "select sleep(0.2) from numbers(1)").await?;
+
+    ctx.get_settings().set_slow_query_threshold_ms(0)?;
+    let blocks = execute_sql(
+        &ctx,
+        "select query_text from system.slow_query_log where plan like '%TableScan%' \
+        and processors_profile != '' and query_duration_ms >= 200",
+    )
+    .await?;
+    let expected = vec![
+        "+-----------------------------------+",
+        "| query_text                        |",
+        "+-----------------------------------+",
+        "| select sleep(0.2) from numbers(1) |",
+        "+-----------------------------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+    Ok(())
+}
+
+#[test]
+fn test_format_processors_profile() {
+    let profile = vec![
+        ("SyncReadParquetDataSource", 2, 12345),
+        ("TransformFilter", 4, 10),
+    ];
+    assert_eq!(
+        format_processors_profile(&profile),
+        "SyncReadParquetDataSource × 2: 12.345ms\nTransformFilter × 4: 0.010ms"
+    );
+}
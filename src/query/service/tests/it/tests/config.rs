@@ -57,6 +57,11 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn max_connections_per_user(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.max_connections_per_user = value;
+        self
+    }
+
     pub fn jwt_key_file(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.jwt_key_file = value.into();
         self
@@ -113,6 +118,21 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn mysql_tls_server_key(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.mysql_tls_server_key = value.into();
+        self
+    }
+
+    pub fn mysql_tls_server_cert(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.mysql_tls_server_cert = value.into();
+        self
+    }
+
+    pub fn mysql_tls_server_root_ca_cert(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.mysql_tls_server_root_ca_cert = value.into();
+        self
+    }
+
     pub fn rpc_tls_server_key(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.rpc_tls_server_key = value.into();
         self
@@ -123,6 +143,11 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn rpc_tls_server_root_ca_cert(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.rpc_tls_server_root_ca_cert = value.into();
+        self
+    }
+
     pub fn query_flight_address(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.flight_api_address = value.into();
         self
@@ -31,8 +31,10 @@ use common_users::UserApiProvider;
 use databend_query::api::DataExchangeManager;
 use databend_query::catalogs::CatalogManagerHelper;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::pipelines::processors::SpillManager;
 use databend_query::servers::http::v1::HttpQueryManager;
 use databend_query::sessions::SessionManager;
+use databend_query::storages::fuse::io::IOScheduler;
 use databend_query::Config;
 use once_cell::sync::OnceCell;
 use opendal::Operator;
@@ -114,6 +116,11 @@ impl TestGlobalServices {
 
         StorageOperator::init(&config.storage, global_services.clone()).await?;
         CacheManager::init(&config.query, global_services.clone())?;
+        IOScheduler::init(
+            config.query.storage_io_max_requests as usize,
+            config.query.storage_io_coalesce_gap_bytes,
+        );
+        SpillManager::init(&config)?;
         CatalogManager::init(&config, global_services.clone()).await?;
         HttpQueryManager::init(&config, global_services.clone()).await?;
         DataExchangeManager::init(config.clone(), global_services.clone())?;
@@ -20,8 +20,8 @@ pub const TEST_CN_NAME: &str = "localhost";
 pub const TEST_TLS_CA_CERT: &str = "../../../tests/certs/tls/cfssl/ca/ca.pem";
 pub const TEST_TLS_SERVER_CERT: &str = "../../../tests/certs/tls/cfssl/server/server.pem";
 pub const TEST_TLS_SERVER_KEY: &str = "../../../tests/certs/tls/cfssl/server/pkcs8-server-key.pem";
-// pub const TEST_TLS_CLIENT_CERT: &'static str = "../../../tests/certs/tls/cfssl/client/client.pem";
-// pub const TEST_TLS_CLIENT_KEY: &'static str = "../../../tests/certs/tls/cfssl/client/pkcs8-client-key.pem";
+pub const TEST_TLS_CLIENT_CERT: &str = "../../../tests/certs/tls/cfssl/client/client.pem";
+pub const TEST_TLS_CLIENT_KEY: &str = "../../../tests/certs/tls/cfssl/client/pkcs8-client-key.pem";
 pub const TEST_TLS_CLIENT_IDENTITY: &str =
     "../../../tests/certs/tls/cfssl/client/client-identity.pfx";
 pub const TEST_TLS_CLIENT_PASSWORD: &str = "databend";
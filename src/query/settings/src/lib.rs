@@ -33,9 +33,11 @@ use common_users::UserApiProvider;
 use itertools::Itertools;
 use parking_lot::RwLock;
 
+/// The level the value of the setting comes from, the session level takes precedence over the
+/// global level, which takes precedence over the default.
 #[derive(Clone)]
 enum ScopeLevel {
-    #[allow(dead_code)]
+    Default,
     Global,
     Session,
 }
@@ -43,6 +45,9 @@ enum ScopeLevel {
 impl Debug for ScopeLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
+            ScopeLevel::Default => {
+                write!(f, "DEFAULT")
+            }
             ScopeLevel::Global => {
                 write!(f, "GLOBAL")
             }
@@ -80,22 +85,7 @@ impl Settings {
     ) -> Result<Arc<Settings>> {
         let settings = Self::default_settings(&tenant);
 
-        let ret = {
-            // Overwrite settings from metasrv
-            let global_settings = user_api
-                .get_setting_api_client(&tenant)?
-                .get_settings()
-                .await?;
-
-            for global_setting in global_settings {
-                let name = global_setting.name;
-                let val = global_setting.value.as_string()?;
-                settings.set_settings(name, val, false)?;
-            }
-            settings
-        };
-
-        // Overwrite settings from conf.
+        // Overwrite the default settings from conf.
         {
             // Set max threads.
             let cpus = if conf.query.num_cpus == 0 {
@@ -103,10 +93,33 @@ impl Settings {
             } else {
                 conf.query.num_cpus
             };
-            ret.set_max_threads(cpus)?;
+            let mut values = settings.settings.write();
+            if let Some(setting) = values.get_mut("max_threads") {
+                setting.user_setting.value = UserSettingValue::UInt64(cpus);
+            }
+        }
+
+        // Overwrite settings from metasrv, they are set by `SET GLOBAL` in any node of the tenant.
+        let global_settings = user_api
+            .get_setting_api_client(&tenant)?
+            .get_settings()
+            .await?;
+
+        for global_setting in global_settings {
+            let name = global_setting.name;
+            // The setting may be removed since it's set.
+            if !settings.has_setting(&name) {
+                continue;
+            }
+
+            let val = global_setting.value.as_string()?;
+            settings.set_settings(name.clone(), val, false)?;
+            if let Some(setting) = settings.settings.write().get_mut(&name) {
+                setting.level = ScopeLevel::Global;
+            }
         }
 
-        Ok(ret)
+        Ok(settings)
     }
 
     pub fn default_settings(tenant: &str) -> Arc<Settings> {
@@ -118,7 +131,7 @@ impl Settings {
                     "max_block_size",
                     UserSettingValue::UInt64(10000),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Maximum block size for reading",
                 possible_values: None,
             },
@@ -126,7 +139,7 @@ impl Settings {
             SettingValue {
                 default_value: UserSettingValue::UInt64(16),
                 user_setting: UserSetting::create("max_threads", UserSettingValue::UInt64(16)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The maximum number of threads to execute the request. By default, it is determined automatically.",
                 possible_values: None,
             },
@@ -137,7 +150,7 @@ impl Settings {
                     "flight_client_timeout",
                     UserSettingValue::UInt64(60),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds",
                 possible_values: None,
             },
@@ -148,7 +161,7 @@ impl Settings {
                     "storage_read_buffer_size",
                     UserSettingValue::UInt64(1024 * 1024),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.",
                 possible_values: None,
             },
@@ -158,7 +171,7 @@ impl Settings {
                     "input_read_buffer_size",
                     UserSettingValue::UInt64(1024 * 1024),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The size of buffer in bytes for input with format. By default, it is 1MB.",
                 possible_values: None,
             },
@@ -169,7 +182,7 @@ impl Settings {
                     "enable_new_processor_framework",
                     UserSettingValue::UInt64(1),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Enable new processor framework if value != 0, default value: 1",
                 possible_values: None,
             },
@@ -177,7 +190,7 @@ impl Settings {
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create("enable_planner_v2", UserSettingValue::UInt64(1)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Enable planner v2 by setting this variable to 1, default value: 1",
                 possible_values: None,
             },
@@ -187,7 +200,7 @@ impl Settings {
                     "record_delimiter",
                     UserSettingValue::String("\n".to_owned()),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format record_delimiter, default value: \"\\n\"",
                 possible_values: None,
             },
@@ -197,21 +210,21 @@ impl Settings {
                     "field_delimiter",
                     UserSettingValue::String(",".to_owned()),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format field delimiter, default value: ,",
                 possible_values: None,
             },
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create("empty_as_default", UserSettingValue::UInt64(1)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format empty_as_default, default value: 1",
                 possible_values: None,
             },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create("skip_header", UserSettingValue::UInt64(0)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Whether to skip the input header, default value: 0",
                 possible_values: None,
             },
@@ -221,7 +234,7 @@ impl Settings {
                     "compression",
                     UserSettingValue::String("None".to_owned()),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format compression, default value: None",
                 possible_values: None,
             },
@@ -231,7 +244,7 @@ impl Settings {
                     "timezone",
                     UserSettingValue::String("UTC".to_owned()),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Timezone, default value: UTC,",
                 possible_values: None,
             },
@@ -241,7 +254,7 @@ impl Settings {
                     "group_by_two_level_threshold",
                     UserSettingValue::UInt64(10000),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The threshold of keys to open two-level aggregation, default value: 10000",
                 possible_values: None,
             },
@@ -251,7 +264,7 @@ impl Settings {
                     "enable_async_insert",
                     UserSettingValue::UInt64(0),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Whether the client open async insert mode, default value: 0",
                 possible_values: None,
             },
@@ -261,7 +274,7 @@ impl Settings {
                     "wait_for_async_insert",
                     UserSettingValue::UInt64(1),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Whether the client wait for the reply of async insert, default value: 1",
                 possible_values: None,
             },
@@ -271,7 +284,7 @@ impl Settings {
                     "wait_for_async_insert_timeout",
                     UserSettingValue::UInt64(100),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The timeout in seconds for waiting for processing of async insert, default value: 100",
                 possible_values: None,
             },
@@ -281,7 +294,7 @@ impl Settings {
                     "unquoted_ident_case_sensitive",
                     UserSettingValue::UInt64(0),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Case sensitivity of unquoted identifiers, default value: 0 (aka case-insensitive)",
                 possible_values: None,
             },
@@ -291,45 +304,265 @@ impl Settings {
                     "quoted_ident_case_sensitive",
                     UserSettingValue::UInt64(1),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Case sensitivity of quoted identifiers, default value: 1 (aka case-sensitive)",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::String("lower".to_owned()),
+                user_setting: UserSetting::create(
+                    "unquoted_ident_case",
+                    UserSettingValue::String("lower".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The case unquoted identifiers are normalized to: \"lower\", \"upper\" or \"preserve\". default: \"lower\"",
+                possible_values: Some(vec!["lower", "upper", "preserve"]),
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("dialect".to_owned()),
+                user_setting: UserSetting::create(
+                    "double_quote",
+                    UserSettingValue::String("dialect".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "What double quotes quote: \"identifier\", \"string\" or \"dialect\" (by sql_dialect). default: \"dialect\"",
+                possible_values: Some(vec!["identifier", "string", "dialect"]),
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("continue_on_error", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Whether the statements of a multi-statement query after a failed one run. default: 0",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::String("PostgreSQL".to_owned()),
                 user_setting: UserSetting::create(
                     "sql_dialect",
                     UserSettingValue::String("PostgreSQL".to_owned()),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "SQL dialect, support \"PostgreSQL\" and \"MySQL\", default value: \"PostgreSQL\"",
                 possible_values: Some(vec!["PostgreSQL", "MySQL"]),
             },
             SettingValue {
                 default_value: UserSettingValue::UInt64(1),
                 user_setting: UserSetting::create("enable_cbo", UserSettingValue::UInt64(1)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "If enable cost based optimization, default value: 1",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_fused_filter",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Evaluates the comparisons of the numeric columns in a filter in one pass. default: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_dictionary_encoding",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Keeps the dictionaries of the string columns read from the fuse tables. default: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_adaptive_filter",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Orders the conjuncts of a filter at runtime by their measured cost and selectivity. default: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "enable_aggregating_index",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Answers the aggregations of the tables from their aggregating indexes. default: 1",
+                possible_values: None,
+            },
             // max_execute_time
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create("max_execute_time", UserSettingValue::UInt64(0)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The maximum query execution time. it means no limit if the value is zero. default value: 0",
                 possible_values: None,
             },
+            // max_memory_usage
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("max_memory_usage", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum memory usage of the query in bytes, no limit if the value is zero. default value: 0",
+                possible_values: None,
+            },
+            // resource_group
+            SettingValue {
+                default_value: UserSettingValue::String("".to_owned()),
+                user_setting: UserSetting::create(
+                    "resource_group",
+                    UserSettingValue::String("".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The resource group the queries run in, the one of the user is used if it's empty. default: \"\"",
+                possible_values: None,
+            },
+            // query_tag
+            SettingValue {
+                default_value: UserSettingValue::String("".to_owned()),
+                user_setting: UserSetting::create(
+                    "query_tag",
+                    UserSettingValue::String("".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The tag of the queries in the logs, the default tag of the user is used if it's empty. default: \"\"",
+                possible_values: None,
+            },
+            // enable_query_result_cache
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_query_result_cache",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Serves the identical queries from the cached results while the tables are not changed. default: 0",
+                possible_values: None,
+            },
+            // query_result_cache_ttl_secs
+            SettingValue {
+                default_value: UserSettingValue::UInt64(300),
+                user_setting: UserSetting::create(
+                    "query_result_cache_ttl_secs",
+                    UserSettingValue::UInt64(300),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The seconds the query results are cached for. default: 300",
+                possible_values: None,
+            },
+            // enable_plan_cache
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create("enable_plan_cache", UserSettingValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Reuses the plans of the queries of the same shape while the tables are not changed. default: 0",
+                possible_values: None,
+            },
+            // enable_replay_capture
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_replay_capture",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Captures the inputs of the queries into the replay bundles in the storage. default: 0",
+                possible_values: None,
+            },
+            // enable_spill_to_storage
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "enable_spill_to_storage",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Spills to the temporary stage of the query in the storage, instead of the local disk. default: 0",
+                possible_values: None,
+            },
+            // slow_query_threshold_ms
+            SettingValue {
+                default_value: UserSettingValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "slow_query_threshold_ms",
+                    UserSettingValue::UInt64(0),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Write the queries slower than the milliseconds to the slow query log, disabled if zero. default: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(10),
+                user_setting: UserSetting::create(
+                    "table_lock_expire_secs",
+                    UserSettingValue::UInt64(10),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The seconds the lock of a table held by a mutation lasts unless it is extended. default: 10",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::String("\"".to_owned()),
                 user_setting: UserSetting::create(
                     "quote_char",
                     UserSettingValue::String("\"".to_owned()),
                 ),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The quote char for CSV. default value: '\"'.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::String("".to_owned()),
+                user_setting: UserSetting::create(
+                    "escape_char",
+                    UserSettingValue::String("".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The escape char for CSV, empty for none. default value: ''.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("\\N".to_owned()),
+                user_setting: UserSetting::create(
+                    "null_display",
+                    UserSettingValue::String("\\N".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "The string loaded as NULL for CSV. default value: '\\N'.",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "error_on_column_count_mismatch",
+                    UserSettingValue::UInt64(1),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Whether CSV rows with a different number of fields from the table are errors, default value: 1",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("error".to_owned()),
+                user_setting: UserSetting::create(
+                    "avro_incompatible_field",
+                    UserSettingValue::String("error".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Action on avro fields missing or incompatible with the table: \"error\" or \"null\", default: \"error\"",
+                possible_values: Some(vec!["error", "null"]),
+            },
+            SettingValue {
+                default_value: UserSettingValue::String("null".to_owned()),
+                user_setting: UserSetting::create(
+                    "missing_field_as",
+                    UserSettingValue::String("null".to_owned()),
+                ),
+                level: ScopeLevel::Default,
+                desc: "Action on NDJSON fields missing from a row: \"error\", \"null\" or \"field_default\", default: \"null\"",
+                possible_values: Some(vec!["error", "null", "field_default"]),
+            },
         ];
 
         let settings: Arc<RwLock<HashMap<String, SettingValue>>> =
@@ -378,6 +611,16 @@ impl Settings {
         self.try_set_u64("max_execute_time", val, false)
     }
 
+    // Get max_memory_usage.
+    pub fn get_max_memory_usage(&self) -> Result<u64> {
+        self.try_get_u64("max_memory_usage")
+    }
+
+    // Set max_memory_usage.
+    pub fn set_max_memory_usage(&self, val: u64) -> Result<()> {
+        self.try_set_u64("max_memory_usage", val, false)
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         let key = "flight_client_timeout";
@@ -423,6 +666,36 @@ impl Settings {
             .and_then(|v| v.user_setting.value.as_string())
     }
 
+    pub fn get_escape_char(&self) -> Result<String> {
+        let key = "escape_char";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_null_display(&self) -> Result<String> {
+        let key = "null_display";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_error_on_column_count_mismatch(&self) -> Result<bool> {
+        static KEY: &str = "error_on_column_count_mismatch";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_avro_incompatible_field(&self) -> Result<String> {
+        let key = "avro_incompatible_field";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_missing_field_as(&self) -> Result<String> {
+        let key = "missing_field_as";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
     pub fn get_compression(&self) -> Result<String> {
         let key = "compression";
         self.check_and_get_setting_value(key)
@@ -445,6 +718,60 @@ impl Settings {
             .and_then(|v| v.user_setting.value.as_string())
     }
 
+    // Get the resource group of the queries, empty if not set.
+    pub fn get_resource_group(&self) -> Result<String> {
+        let key = "resource_group";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    // Get the tag of the queries, empty if not set.
+    pub fn get_query_tag(&self) -> Result<String> {
+        let key = "query_tag";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_enable_query_result_cache(&self) -> Result<bool> {
+        static KEY: &str = "enable_query_result_cache";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
+        self.try_get_u64("query_result_cache_ttl_secs")
+    }
+
+    pub fn get_enable_plan_cache(&self) -> Result<bool> {
+        static KEY: &str = "enable_plan_cache";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_replay_capture(&self) -> Result<bool> {
+        static KEY: &str = "enable_replay_capture";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_spill_to_storage(&self) -> Result<bool> {
+        static KEY: &str = "enable_spill_to_storage";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_slow_query_threshold_ms(&self) -> Result<u64> {
+        self.try_get_u64("slow_query_threshold_ms")
+    }
+
+    pub fn set_slow_query_threshold_ms(&self, val: u64) -> Result<()> {
+        self.try_set_u64("slow_query_threshold_ms", val, false)
+    }
+
+    pub fn get_table_lock_expire_secs(&self) -> Result<u64> {
+        self.try_get_u64("table_lock_expire_secs")
+    }
+
     // Get group by two level threshold
     pub fn get_group_by_two_level_threshold(&self) -> Result<u64> {
         let key = "group_by_two_level_threshold";
@@ -511,6 +838,12 @@ impl Settings {
         self.try_set_u64(KEY, v, false)
     }
 
+    pub fn get_continue_on_error(&self) -> Result<bool> {
+        static KEY: &str = "continue_on_error";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
     pub fn get_enable_cbo(&self) -> Result<bool> {
         static KEY: &str = "enable_cbo";
         let v = self.try_get_u64(KEY)?;
@@ -523,7 +856,49 @@ impl Settings {
         self.try_set_u64(KEY, v, false)
     }
 
+    pub fn get_enable_fused_filter(&self) -> Result<bool> {
+        static KEY: &str = "enable_fused_filter";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_dictionary_encoding(&self) -> Result<bool> {
+        static KEY: &str = "enable_dictionary_encoding";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_adaptive_filter(&self) -> Result<bool> {
+        static KEY: &str = "enable_adaptive_filter";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_enable_aggregating_index(&self) -> Result<bool> {
+        static KEY: &str = "enable_aggregating_index";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn get_unquoted_ident_case(&self) -> Result<String> {
+        let key = "unquoted_ident_case";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    // The dialects differ only in the double quotes, which are identifiers of PostgreSQL and
+    // strings of MySQL, and `double_quote` overrides the one of the `sql_dialect`.
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
+        let key = "double_quote";
+        let double_quote = self
+            .check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())?;
+        match double_quote.as_str() {
+            "identifier" => return Ok(Dialect::PostgreSQL),
+            "string" => return Ok(Dialect::MySQL),
+            _ => {}
+        }
+
         let key = "sql_dialect";
         self.check_and_get_setting_value(key)
             .and_then(|v| v.user_setting.value.as_string())
@@ -589,6 +964,8 @@ impl Settings {
             });
             let _ = futures::executor::block_on(set_handle).unwrap()?;
             setting.level = ScopeLevel::Global;
+        } else {
+            setting.level = ScopeLevel::Session;
         }
 
         Ok(())
@@ -612,6 +989,8 @@ impl Settings {
             });
             let _ = futures::executor::block_on(set_handle).unwrap()?;
             setting.level = ScopeLevel::Global;
+        } else {
+            setting.level = ScopeLevel::Session;
         }
 
         Ok(())
@@ -663,6 +1042,14 @@ impl Settings {
         }
     }
 
+    /// A copy of the settings, changing which leaves these settings as they are.
+    pub fn duplicate(&self) -> Arc<Settings> {
+        Arc::new(Settings {
+            settings: Arc::new(RwLock::new(self.settings.read().clone())),
+            tenant: self.tenant.clone(),
+        })
+    }
+
     pub fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()> {
         let mut settings = self.settings.write();
         let values = changed_settings.get_setting_values();
@@ -15,14 +15,20 @@
 use std::sync::Arc;
 
 use common_base::base::Singleton;
+use common_cache::Cache;
 use common_config::QueryConfig;
 use common_exception::Result;
 use once_cell::sync::OnceCell;
+use tracing::warn;
 
 use crate::caches::memory_cache::new_bytes_cache;
+use crate::caches::memory_cache::new_disk_bytes_cache;
 use crate::caches::memory_cache::BloomIndexCache;
 use crate::caches::memory_cache::BloomIndexMetaCache;
 use crate::caches::memory_cache::BytesCache;
+use crate::caches::memory_cache::ColumnDataCache;
+use crate::caches::memory_cache::ColumnDataDiskCache;
+use crate::caches::memory_cache::DiskBytesCache;
 use crate::caches::memory_cache::FileMetaDataCache;
 use crate::caches::new_item_cache;
 use crate::caches::ItemCache;
@@ -43,6 +49,8 @@ pub struct CacheManager {
     bloom_index_cache: Option<BloomIndexCache>,
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
     file_meta_data_cache: Option<FileMetaDataCache>,
+    column_data_cache: Option<ColumnDataCache>,
+    column_data_disk_cache: Option<ColumnDataDiskCache>,
     cluster_id: String,
     tenant_id: String,
 }
@@ -61,6 +69,8 @@ impl CacheManager {
                 bloom_index_cache: None,
                 bloom_index_meta_cache: None,
                 file_meta_data_cache: None,
+                column_data_cache: None,
+                column_data_disk_cache: None,
                 cluster_id: config.cluster_id.clone(),
                 tenant_id: config.tenant_id.clone(),
             }))?;
@@ -74,12 +84,21 @@ impl CacheManager {
 
             let file_meta_data_cache = Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS);
 
+            let column_data_cache =
+                Self::new_bytes_cache(config.table_memory_cache_mb_size * 1024 * 1024);
+            let column_data_disk_cache = Self::new_disk_bytes_cache(
+                &config.table_disk_cache_root,
+                config.table_disk_cache_mb_size * 1024 * 1024,
+            );
+
             v.init(Arc::new(Self {
                 table_snapshot_cache,
                 segment_info_cache,
                 bloom_index_cache,
                 bloom_index_meta_cache,
                 file_meta_data_cache,
+                column_data_cache,
+                column_data_disk_cache,
                 cluster_id: config.cluster_id.clone(),
                 tenant_id: config.tenant_id.clone(),
             }))?;
@@ -117,6 +136,30 @@ impl CacheManager {
         self.file_meta_data_cache.clone()
     }
 
+    pub fn get_column_data_cache(&self) -> Option<ColumnDataCache> {
+        self.column_data_cache.clone()
+    }
+
+    pub fn get_column_data_disk_cache(&self) -> Option<ColumnDataDiskCache> {
+        self.column_data_disk_cache.clone()
+    }
+
+    /// The bytes of the caches in memory metered by bytes, the bloom filters and the column chunks.
+    pub fn get_memory_cache_bytes(&self) -> u64 {
+        [&self.bloom_index_cache, &self.column_data_cache]
+            .into_iter()
+            .flatten()
+            .map(|cache| cache.read().size())
+            .sum()
+    }
+
+    pub fn get_disk_cache_bytes(&self) -> u64 {
+        self.column_data_disk_cache
+            .as_ref()
+            .map(|cache| cache.read().size())
+            .unwrap_or_default()
+    }
+
     pub fn get_tenant_id(&self) -> &str {
         self.tenant_id.as_str()
     }
@@ -140,4 +183,22 @@ impl CacheManager {
             None
         }
     }
+
+    // The disk cache is an optional tier, the query node starts without it if the directory
+    // can not be used.
+    fn new_disk_bytes_cache(root: &str, capacity: u64) -> Option<DiskBytesCache> {
+        if capacity == 0 {
+            return None;
+        }
+        match new_disk_bytes_cache(root, capacity) {
+            Ok(cache) => Some(cache),
+            Err(cause) => {
+                warn!(
+                    "failed to create the table disk cache at {}, it's disabled: {}",
+                    root, cause
+                );
+                None
+            }
+        }
+    }
 }
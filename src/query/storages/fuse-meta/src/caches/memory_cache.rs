@@ -20,6 +20,8 @@ use common_cache::Cache;
 use common_cache::Count;
 use common_cache::DefaultHashBuilder;
 use common_cache::LruCache;
+use common_cache::LruDiskCache;
+use common_exception::Result;
 use parking_lot::RwLock;
 
 use crate::meta::SegmentInfo;
@@ -40,6 +42,14 @@ pub fn new_bytes_cache(capacity: u64) -> BytesCache {
     Arc::new(RwLock::new(c))
 }
 
+/// Bytes cached as files under a local directory, limited to `capacity` bytes.
+pub type DiskBytesCache = Arc<RwLock<LruDiskCache>>;
+
+pub fn new_disk_bytes_cache(root: &str, capacity: u64) -> Result<DiskBytesCache> {
+    let c = LruDiskCache::new(root, capacity)?;
+    Ok(Arc::new(RwLock::new(c)))
+}
+
 pub type SegmentInfoCache = ItemCache<SegmentInfo>;
 pub type TableSnapshotCache = ItemCache<TableSnapshot>;
 /// Cache bloom filter.
@@ -50,3 +60,10 @@ pub type BloomIndexCache = BytesCache;
 pub type BloomIndexMetaCache = ItemCache<FileMetaData>;
 
 pub type FileMetaDataCache = ItemCache<FileMetaData>;
+
+/// Cache of column chunks of blocks.
+/// For each block, columns are cached individually.
+pub type ColumnDataCache = BytesCache;
+/// On-disk tier of the column chunk cache, used when a chunk is evicted from or
+/// not yet loaded into the [ColumnDataCache]
+pub type ColumnDataDiskCache = DiskBytesCache;
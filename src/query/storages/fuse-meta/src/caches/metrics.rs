@@ -12,13 +12,26 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
-use common_metrics::label_counter;
-use common_metrics::label_counter_with_val;
+use std::collections::HashMap;
+
+use common_metrics::label_counter_with_val_and_labels;
+use common_metrics::label_gauge_with_val_and_labels;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_TENANT;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 
 const CACHE_READ_BYTES_FROM_REMOTE: &str = "cache_read_bytes_from_remote";
 const CACHE_READ_BYTES_FROM_LOCAL: &str = "cache_read_bytes_from_local";
 const CACHE_ACCESS_COUNT: &str = "cache_access_count";
 const CACHE_ACCESS_HIT_COUNT: &str = "cache_access_hit_count";
+const CACHE_HIT_RATIO: &str = "cache_hit_ratio";
+
+const LABEL_KEY_CACHE: &str = "cache";
+
+/// The accesses and the hits of the caches since the process started, by the labels.
+static CACHE_ACCESSES: Lazy<Mutex<HashMap<Vec<(&'static str, String)>, (u64, u64)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 
 pub struct TenantLabel {
     pub tenant_id: String,
@@ -27,31 +40,46 @@ pub struct TenantLabel {
 
 pub struct CacheDeferMetrics {
     pub tenant_label: TenantLabel,
+    /// Name of the cache being accessed, e.g. `SEGMENT_INFO_CACHE`
+    pub cache_name: String,
     pub cache_hit: bool,
     pub read_bytes: u64,
 }
 
+impl CacheDeferMetrics {
+    fn labels(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (LABEL_KEY_TENANT, self.tenant_label.tenant_id.clone()),
+            (LABEL_KEY_CLUSTER, self.tenant_label.cluster_id.clone()),
+            (LABEL_KEY_CACHE, self.cache_name.clone()),
+        ]
+    }
+}
+
 impl Drop for CacheDeferMetrics {
     fn drop(&mut self) {
-        let label = &self.tenant_label;
-        let tenant_id = &label.tenant_id;
-        let cluster_id = &label.cluster_id;
+        label_counter_with_val_and_labels(CACHE_ACCESS_COUNT, self.labels(), 1);
+        let hit_ratio = {
+            let mut accesses = CACHE_ACCESSES.lock();
+            let (access_count, hit_count) = accesses.entry(self.labels()).or_default();
+            *access_count += 1;
+            *hit_count += self.cache_hit as u64;
+            *hit_count as f64 / *access_count as f64
+        };
+        label_gauge_with_val_and_labels(CACHE_HIT_RATIO, self.labels(), hit_ratio);
 
-        label_counter(CACHE_ACCESS_COUNT, tenant_id, cluster_id);
         if self.cache_hit {
-            label_counter(CACHE_ACCESS_HIT_COUNT, tenant_id, cluster_id);
-            label_counter_with_val(
+            label_counter_with_val_and_labels(CACHE_ACCESS_HIT_COUNT, self.labels(), 1);
+            label_counter_with_val_and_labels(
                 CACHE_READ_BYTES_FROM_LOCAL,
+                self.labels(),
                 self.read_bytes,
-                tenant_id,
-                cluster_id,
             );
         } else {
-            label_counter_with_val(
+            label_counter_with_val_and_labels(
                 CACHE_READ_BYTES_FROM_REMOTE,
+                self.labels(),
                 self.read_bytes,
-                tenant_id,
-                cluster_id,
             );
         }
     }
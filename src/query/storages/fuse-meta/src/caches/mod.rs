@@ -17,7 +17,11 @@ mod memory_cache;
 mod metrics;
 
 pub use cache::CacheManager;
+pub use memory_cache::new_bytes_cache;
+pub use memory_cache::new_disk_bytes_cache;
 pub use memory_cache::new_item_cache;
+pub use memory_cache::ColumnDataCache;
+pub use memory_cache::ColumnDataDiskCache;
 pub use memory_cache::ItemCache;
 pub use memory_cache::SegmentInfoCache;
 pub use memory_cache::TableSnapshotCache;
@@ -37,6 +37,33 @@ pub struct ColumnStatistics {
     #[serde(alias = "unset_bits")]
     pub null_count: u64,
     pub in_memory_size: u64,
+    // The estimated number of distinct values, None if not collected, e.g. the
+    // statistics written by the earlier versions.
+    #[serde(default)]
+    pub distinct_of_values: Option<u64>,
+    #[serde(default)]
+    pub histogram: Option<Histogram>,
+}
+
+/// An equi-height histogram of the non-null values of a column.
+///
+/// The buckets are ordered by the upper bounds, the lower bound of a bucket is the
+/// upper bound of the previous one, or the min value of the column for the first one.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct Histogram {
+    pub buckets: Vec<HistogramBucket>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct HistogramBucket {
+    pub upper_bound: DataValue,
+    pub num_values: u64,
+}
+
+impl Histogram {
+    pub fn num_values(&self) -> u64 {
+        self.buckets.iter().map(|b| b.num_values).sum()
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
@@ -82,6 +109,10 @@ where Self: Sized
 pub enum Compression {
     Lz4,
     Lz4Raw,
+    Snappy,
+    Zstd,
+    Gzip,
+    Uncompressed,
 }
 
 impl Compression {
@@ -25,6 +25,8 @@ pub use common::ClusterStatistics;
 pub use common::ColumnId;
 pub use common::ColumnStatistics;
 pub use common::Compression;
+pub use common::Histogram;
+pub use common::HistogramBucket;
 pub use common::Location;
 pub use common::SnapshotId;
 pub use common::Statistics;
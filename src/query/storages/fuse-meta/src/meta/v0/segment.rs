@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use crate::meta::common::ColumnStatistics;
+use crate::meta::common::Compression;
 use crate::meta::ColumnId;
 use crate::meta::Statistics;
 
@@ -48,6 +49,16 @@ pub struct ColumnMeta {
     pub len: u64,
     /// num of "rows"
     pub num_values: u64,
+    /// compression algo of the column
+    ///
+    /// If not specified, the `compression` of the block is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<Compression>,
+    /// CRC32 of the column chunk
+    ///
+    /// Not specified for the blocks written before the checksums.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<u32>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
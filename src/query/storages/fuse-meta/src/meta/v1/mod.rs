@@ -19,4 +19,5 @@ mod snapshot;
 pub use index::BlockBloomFilterIndex;
 pub use segment::BlockMeta;
 pub use segment::SegmentInfo;
+pub use snapshot::AnalyzedSegments;
 pub use snapshot::TableSnapshot;
@@ -23,6 +23,7 @@ use serde::Serialize;
 
 use crate::meta::common::FormatVersion;
 use crate::meta::ClusterKey;
+use crate::meta::ColumnId;
 use crate::meta::Location;
 use crate::meta::SnapshotId;
 use crate::meta::Statistics;
@@ -57,6 +58,19 @@ pub struct TableSnapshot {
 
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
+
+    /// The segments whose statistics are collected by the last ANALYZE TABLE, the segments
+    /// added or rewritten since then are analyzed by the next one.
+    #[serde(default)]
+    pub analyzed_segments: AnalyzedSegments,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnalyzedSegments {
+    pub segments: Vec<Location>,
+    /// The next id of the virtual columns of the table when analyzed, all the segments are
+    /// analyzed again once a virtual column is added.
+    pub next_virtual_column_id: ColumnId,
 }
 
 impl TableSnapshot {
@@ -86,13 +100,14 @@ impl TableSnapshot {
             summary,
             segments,
             cluster_key_meta,
+            analyzed_segments: AnalyzedSegments::default(),
         }
     }
 
     pub fn from_previous(previous: &TableSnapshot) -> Self {
         let id = Uuid::new_v4();
         let clone = previous.clone();
-        Self::new(
+        let mut snapshot = Self::new(
             id,
             &clone.timestamp,
             Some((clone.snapshot_id, clone.format_version)),
@@ -100,7 +115,9 @@ impl TableSnapshot {
             clone.summary,
             clone.segments,
             clone.cluster_key_meta,
-        )
+        );
+        snapshot.analyzed_segments = clone.analyzed_segments;
+        snapshot
     }
 
     pub fn format_version(&self) -> u64 {
@@ -121,6 +138,7 @@ impl From<v0::TableSnapshot> for TableSnapshot {
             summary: s.summary,
             segments: s.segments.into_iter().map(|l| (l, 0)).collect(),
             cluster_key_meta: None,
+            analyzed_segments: AnalyzedSegments::default(),
         }
     }
 }
@@ -16,6 +16,18 @@ pub const FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "block_size_threshold
 pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+pub const FUSE_OPT_KEY_COMPRESSION: &str = "compression";
+pub const FUSE_OPT_KEY_COLUMN_COMPRESSION: &str = "column_compression";
+pub const FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS: &str = "inverted_index_columns";
+pub const FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS: &str = "vector_index_columns";
+pub const FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "data_retention_time_in_days";
+pub const FUSE_OPT_KEY_DEDUP_ON: &str = "dedup_on";
+pub const FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS: &str = "dedup_window_segments";
+
+// The engine options of the tables attached to the existing locations by `ATTACH TABLE`.
+pub const FUSE_OPT_KEY_ATTACH_STORAGE: &str = "storage";
+pub const FUSE_OPT_KEY_ATTACH_STORAGE_PREFIX: &str = "storage_prefix";
+pub const FUSE_OPT_KEY_ATTACH_READ_ONLY: &str = "read_only";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -28,3 +40,14 @@ pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD: usize = 100 * 1024 * 1024;
 pub const DEFAULT_ROW_PER_BLOCK: usize = 1000 * 1000;
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.01;
+// The rows ingested into a table with `dedup_on` are checked against the keys of the rows in
+// this many of the most recent segments.
+pub const DEFAULT_DEDUP_WINDOW_SEGMENTS: usize = 100;
+
+// The histograms of the columns are built from the samples of the rows of a block.
+pub const HISTOGRAM_MAX_BUCKETS: usize = 32;
+pub const HISTOGRAM_SAMPLE_SIZE: usize = 1024;
+
+// The statistics of the virtual columns are kept by the ids from this one, after the
+// ids of the columns of the schema.
+pub const VIRTUAL_COLUMN_ID_START: u32 = 1 << 30;
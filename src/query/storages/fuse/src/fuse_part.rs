@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType as ArrowType;
@@ -31,14 +34,25 @@ pub struct ColumnMeta {
     pub offset: u64,
     pub length: u64,
     pub num_values: u64,
+    pub compression: Compression,
+    /// CRC32 of the column chunk, none for the blocks written before the checksums.
+    pub checksum: Option<u32>,
 }
 
 impl ColumnMeta {
-    pub fn create(offset: u64, length: u64, num_values: u64) -> ColumnMeta {
+    pub fn create(
+        offset: u64,
+        length: u64,
+        num_values: u64,
+        compression: Compression,
+        checksum: Option<u32>,
+    ) -> ColumnMeta {
         ColumnMeta {
             offset,
             length,
             num_values,
+            compression,
+            checksum,
         }
     }
 }
@@ -66,6 +80,12 @@ impl PartInfo for FusePartInfo {
             Some(other) => self == other,
         }
     }
+
+    fn location_hash(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.location.hash(&mut hasher);
+        Some(hasher.finish())
+    }
 }
 
 impl FusePartInfo {
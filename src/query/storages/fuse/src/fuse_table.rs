@@ -13,15 +13,27 @@
 //  limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
 use common_catalog::catalog::StorageDescription;
+use common_catalog::table::ColumnFiller;
+use common_catalog::table::TableColumnSize;
+use common_catalog::table::TableColumnStatistics;
 use common_catalog::table_context::TableContext;
 use common_catalog::table_mutator::TableMutator;
 use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataTypeImpl;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_fuse_meta::meta::AnalyzedSegments;
 use common_fuse_meta::meta::ClusterKey;
 use common_fuse_meta::meta::Statistics as FuseStatistics;
 use common_fuse_meta::meta::TableSnapshot;
@@ -34,25 +46,49 @@ use common_legacy_planners::Partitions;
 use common_legacy_planners::ReadDataSourcePlan;
 use common_legacy_planners::Statistics;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_storage::init_operator;
+use common_storage::StorageParams;
 use common_storages_util::storage_context::StorageContext;
 use common_storages_util::table_storage_prefix::table_storage_prefix;
+use opendal::Operator;
 use uuid::Uuid;
 
+use crate::index::IndexColumns;
+use crate::index::InvertedIndex;
+use crate::index::VectorIndex;
 use crate::io::BlockCompactor;
+use crate::io::BlockCompression;
+use crate::io::IOPriority;
 use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::AppendOperationLogEntry;
 use crate::pipelines::Pipeline;
+use crate::ColumnLeaves;
 use crate::NavigationPoint;
 use crate::Table;
 use crate::TableStatistics;
+use crate::VirtualColumnStatsGenerator;
+use crate::VirtualColumns;
 use crate::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
+use crate::DEFAULT_DEDUP_WINDOW_SEGMENTS;
 use crate::DEFAULT_ROW_PER_BLOCK;
+use crate::FUSE_OPT_KEY_ATTACH_READ_ONLY;
+use crate::FUSE_OPT_KEY_ATTACH_STORAGE;
+use crate::FUSE_OPT_KEY_ATTACH_STORAGE_PREFIX;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use crate::FUSE_OPT_KEY_DEDUP_ON;
+use crate::FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS;
+use crate::FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
+use crate::FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS;
+use crate::OPT_KEY_AGGREGATING_INDEXES;
 use crate::OPT_KEY_DATABASE_ID;
 use crate::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use crate::OPT_KEY_SNAPSHOT_LOCATION;
+use crate::OPT_KEY_VIRTUAL_COLUMNS;
 
 #[derive(Clone)]
 pub struct FuseTable {
@@ -61,7 +97,12 @@ pub struct FuseTable {
 
     pub(crate) cluster_keys: Vec<LegacyExpression>,
     pub(crate) cluster_key_meta: Option<ClusterKey>,
+    pub(crate) virtual_columns: VirtualColumns,
     pub(crate) read_only: bool,
+
+    // The operator of the storage of the table attached by `ATTACH TABLE`, the tables
+    // created by `CREATE TABLE` are kept in the storage of the query.
+    pub(crate) operator: Option<Operator>,
 }
 
 impl FuseTable {
@@ -77,13 +118,25 @@ impl FuseTable {
         if let Some((_, order)) = &cluster_key_meta {
             cluster_keys = ExpressionParser::parse_exprs(order)?;
         }
+        let virtual_columns = VirtualColumns::try_from_options(table_info.options())?;
+        let engine_options = table_info.engine_options();
+        let operator = match engine_options.get(FUSE_OPT_KEY_ATTACH_STORAGE) {
+            Some(storage) => {
+                let storage: StorageParams = serde_json::from_str(storage)?;
+                Some(init_operator(&storage)?)
+            }
+            None => None,
+        };
+        let read_only = read_only || engine_options.contains_key(FUSE_OPT_KEY_ATTACH_READ_ONLY);
 
         Ok(Box::new(FuseTable {
             table_info,
             cluster_keys,
             cluster_key_meta,
+            virtual_columns,
             meta_location_generator: TableMetaLocationGenerator::with_prefix(storage_prefix),
             read_only,
+            operator,
         }))
     }
 
@@ -100,6 +153,14 @@ impl FuseTable {
     }
 
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
+        // the attached table is kept in the prefix of the table it's attached to
+        if let Some(prefix) = table_info
+            .engine_options()
+            .get(FUSE_OPT_KEY_ATTACH_STORAGE_PREFIX)
+        {
+            return Ok(prefix.clone());
+        }
+
         let table_id = table_info.ident.table_id;
         let db_id = table_info
             .options()
@@ -113,13 +174,23 @@ impl FuseTable {
         Ok(table_storage_prefix(db_id, table_id))
     }
 
+    /// The operator of the storage of the table, the storage of the location the table
+    /// is attached to, or the storage of the query.
+    pub fn get_operator(&self, ctx: &dyn TableContext) -> Result<Operator> {
+        match &self.operator {
+            Some(operator) => Ok(operator.clone().layer(ctx.get_dal_context().clone())),
+            None => ctx.get_storage_operator(),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
-    pub(crate) async fn read_table_snapshot(
+    pub async fn read_table_snapshot(
         &self,
         ctx: Arc<dyn TableContext>,
     ) -> Result<Option<Arc<TableSnapshot>>> {
         if let Some(loc) = self.snapshot_loc() {
-            let reader = MetaReaders::table_snapshot_reader(ctx);
+            let operator = self.get_operator(ctx.as_ref())?;
+            let reader = MetaReaders::table_snapshot_reader(ctx, operator);
             let ver = self.snapshot_format_version();
             Ok(Some(reader.read(loc.as_str(), None, ver).await?))
         } else {
@@ -181,6 +252,202 @@ impl FuseTable {
         );
         BlockCompactor::new(max_rows_per_block, min_rows_per_block, max_bytes_per_block)
     }
+
+    pub(crate) fn get_block_compression(&self) -> Result<BlockCompression> {
+        BlockCompression::try_from_options(self.table_info.options())
+    }
+
+    /// The string columns of the inverted indexes built at the writes of the blocks, by the
+    /// option `inverted_index_columns = '<column>, ...'`.
+    pub fn parse_inverted_index_columns(options: &BTreeMap<String, String>) -> Vec<String> {
+        Self::parse_index_columns(options, FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS)
+    }
+
+    /// The array columns of the vector indexes built at the writes of the blocks, by the
+    /// option `vector_index_columns = '<column>, ...'`.
+    pub fn parse_vector_index_columns(options: &BTreeMap<String, String>) -> Vec<String> {
+        Self::parse_index_columns(options, FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS)
+    }
+
+    fn parse_index_columns(options: &BTreeMap<String, String>, key: &str) -> Vec<String> {
+        match options.get(key) {
+            None => vec![],
+            Some(v) => v
+                .split(',')
+                .map(|column| column.trim())
+                .filter(|column| !column.is_empty())
+                .map(|column| column.to_string())
+                .collect(),
+        }
+    }
+
+    /// Check that the columns of the inverted indexes are all string columns of the table, and
+    /// the columns of the vector indexes are all array columns of numbers.
+    pub fn check_index_columns(
+        options: &BTreeMap<String, String>,
+        schema: &DataSchema,
+    ) -> Result<()> {
+        let check = |columns: Vec<String>,
+                     index: &str,
+                     expected: &str,
+                     is_supported_type: fn(&DataTypeImpl) -> bool| {
+            for column in columns {
+                match schema.column_with_name(&column) {
+                    Some((_, field)) if is_supported_type(field.data_type()) => {}
+                    Some(_) => {
+                        return Err(ErrorCode::BadOption(format!(
+                            "{} index of non-{} column: {}",
+                            index, expected, column
+                        )));
+                    }
+                    None => {
+                        return Err(ErrorCode::BadOption(format!(
+                            "{} index of unknown column: {}",
+                            index, column
+                        )));
+                    }
+                }
+            }
+            Ok(())
+        };
+        check(
+            Self::parse_inverted_index_columns(options),
+            "inverted",
+            "string",
+            InvertedIndex::is_supported_type,
+        )?;
+        check(
+            Self::parse_vector_index_columns(options),
+            "vector",
+            "vector",
+            VectorIndex::is_supported_type,
+        )
+    }
+
+    pub(crate) fn get_index_columns(&self) -> IndexColumns {
+        IndexColumns {
+            inverted: Self::parse_inverted_index_columns(self.table_info.options()),
+            vector: Self::parse_vector_index_columns(self.table_info.options()),
+        }
+    }
+
+    /// The columns identifying the rows ingested, by the option `dedup_on = '<column>, ...'`.
+    /// The rows with the same values of them as the rows ingested before are dropped.
+    pub fn parse_dedup_keys(options: &BTreeMap<String, String>) -> Vec<String> {
+        Self::parse_index_columns(options, FUSE_OPT_KEY_DEDUP_ON)
+    }
+
+    /// Check that the columns of `dedup_on` are the columns of the table, and
+    /// `dedup_window_segments` is a positive number.
+    pub fn check_dedup_keys(options: &BTreeMap<String, String>, schema: &DataSchema) -> Result<()> {
+        for column in Self::parse_dedup_keys(options) {
+            if schema.column_with_name(&column).is_none() {
+                return Err(ErrorCode::BadOption(format!(
+                    "dedup_on of unknown column: {}",
+                    column
+                )));
+            }
+        }
+        if let Some(v) = options.get(FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS) {
+            match v.parse::<usize>() {
+                Ok(n) if n > 0 => {}
+                _ => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "dedup_window_segments must be a positive number, got {}",
+                        v
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_dedup_window_segments(&self) -> usize {
+        self.get_option(
+            FUSE_OPT_KEY_DEDUP_WINDOW_SEGMENTS,
+            DEFAULT_DEDUP_WINDOW_SEGMENTS,
+        )
+    }
+
+    /// The names of the aggregating indexes of the table, by the option
+    /// `aggregating_indexes = '<index>, ...'`, the indexes are the tables in the same database.
+    pub fn parse_aggregating_indexes(options: &BTreeMap<String, String>) -> Vec<String> {
+        match options.get(OPT_KEY_AGGREGATING_INDEXES) {
+            None => vec![],
+            Some(v) => v
+                .split(',')
+                .map(|index| index.trim())
+                .filter(|index| !index.is_empty())
+                .map(|index| index.to_string())
+                .collect(),
+        }
+    }
+
+    pub fn virtual_columns(&self) -> &VirtualColumns {
+        &self.virtual_columns
+    }
+
+    pub(crate) fn get_virtual_column_stats_gen(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<VirtualColumnStatsGenerator> {
+        self.virtual_columns
+            .stats_generator(ctx, &self.table_info.schema())
+    }
+
+    // The version of the table is matched, so the concurrent changes of the virtual
+    // columns are not lost.
+    async fn update_virtual_columns(
+        &self,
+        ctx: &dyn TableContext,
+        virtual_columns: &VirtualColumns,
+    ) -> Result<()> {
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        let req = UpsertTableOptionReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_VIRTUAL_COLUMNS.to_string(),
+                Some(virtual_columns.to_option_value()?),
+            )]),
+        };
+        let tenant = ctx.get_tenant();
+        let db_name = ctx.get_current_database();
+        catalog.upsert_table_option(&tenant, &db_name, req).await?;
+        Ok(())
+    }
+
+    /// Parse the table option `data_retention_time_in_days`, None if it's not set.
+    pub fn parse_data_retention_time(
+        options: &BTreeMap<String, String>,
+    ) -> Result<Option<Duration>> {
+        match options.get(FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+            None => Ok(None),
+            Some(v) => {
+                let days = v.trim().parse::<u32>().map_err(|_| {
+                    ErrorCode::BadOption(format!(
+                        "invalid {}: {}, expects a number of days",
+                        FUSE_OPT_KEY_DATA_RETENTION_TIME_IN_DAYS, v
+                    ))
+                })?;
+                Ok(Some(Duration::days(days as i64)))
+            }
+        }
+    }
+
+    /// The historical snapshots replaced after the cutoff are in the data retention time
+    /// of the table, they are kept by the purge and can be navigated to by time travel.
+    ///
+    /// None if the data retention time is not set, then all the historical snapshots can
+    /// be navigated to, until they are purged.
+    pub(crate) fn data_retention_cutoff(&self) -> Result<Option<DateTime<Utc>>> {
+        let retention_time = Self::parse_data_retention_time(self.table_info.options())?;
+        Ok(retention_time.map(|v| {
+            Utc::now()
+                .checked_sub_signed(v)
+                .unwrap_or(DateTime::<Utc>::MIN_UTC)
+        }))
+    }
 }
 
 #[async_trait::async_trait]
@@ -218,6 +485,7 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         cluster_key_str: String,
     ) -> Result<()> {
+        self.check_mutable()?;
         let mut new_table_meta = self.get_table_info().meta.clone();
         new_table_meta = new_table_meta.push_cluster_key(cluster_key_str);
         let cluster_key_meta = new_table_meta.cluster_key();
@@ -227,13 +495,21 @@ impl Table for FuseTable {
         let prev_version = self.snapshot_format_version();
         let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
+        let (summary, segments, analyzed_segments) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.analyzed_segments.clone(),
+            )
         } else {
-            (FuseStatistics::default(), vec![])
+            (
+                FuseStatistics::default(),
+                vec![],
+                AnalyzedSegments::default(),
+            )
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -242,12 +518,14 @@ impl Table for FuseTable {
             segments,
             cluster_key_meta,
         );
+        new_snapshot.analyzed_segments = analyzed_segments;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
 
         FuseTable::commit_to_meta_server(
             ctx.as_ref(),
+            &self.get_operator(ctx.as_ref())?,
             &table_info,
             &self.meta_location_generator,
             new_snapshot,
@@ -256,6 +534,7 @@ impl Table for FuseTable {
     }
 
     async fn drop_table_cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        self.check_mutable()?;
         if self.cluster_key_meta.is_none() {
             return Ok(());
         }
@@ -270,13 +549,21 @@ impl Table for FuseTable {
         let prev_version = self.snapshot_format_version();
         let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
+        let (summary, segments, analyzed_segments) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.analyzed_segments.clone(),
+            )
         } else {
-            (FuseStatistics::default(), vec![])
+            (
+                FuseStatistics::default(),
+                vec![],
+                AnalyzedSegments::default(),
+            )
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -285,12 +572,14 @@ impl Table for FuseTable {
             segments,
             None,
         );
+        new_snapshot.analyzed_segments = analyzed_segments;
 
         let mut table_info = self.table_info.clone();
         table_info.meta = new_table_meta;
 
         FuseTable::commit_to_meta_server(
             ctx.as_ref(),
+            &self.get_operator(ctx.as_ref())?,
             &table_info,
             &self.meta_location_generator,
             new_snapshot,
@@ -298,6 +587,38 @@ impl Table for FuseTable {
         .await
     }
 
+    async fn add_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        field: DataField,
+        comment: String,
+        fill: ColumnFiller,
+    ) -> Result<()> {
+        self.check_mutable()?;
+        self.do_add_column(ctx, field, comment, fill).await
+    }
+
+    async fn add_virtual_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column: String,
+        expr: String,
+    ) -> Result<()> {
+        self.check_mutable()?;
+        let mut virtual_columns = self.virtual_columns.clone();
+        virtual_columns.add(&column, &expr, &self.schema())?;
+        self.update_virtual_columns(ctx.as_ref(), &virtual_columns)
+            .await
+    }
+
+    async fn drop_virtual_column(&self, ctx: Arc<dyn TableContext>, column: String) -> Result<()> {
+        self.check_mutable()?;
+        let mut virtual_columns = self.virtual_columns.clone();
+        virtual_columns.drop(&column)?;
+        self.update_virtual_columns(ctx.as_ref(), &virtual_columns)
+            .await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_read_partitions", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn read_partitions(
         &self,
@@ -314,7 +635,7 @@ impl Table for FuseTable {
         plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
-        self.do_read2(ctx, plan, pipeline)
+        self.do_read2(ctx, plan, pipeline, IOPriority::Interactive)
     }
 
     fn append2(
@@ -343,6 +664,22 @@ impl Table for FuseTable {
         self.do_commit(ctx, append_log_entries, overwrite).await
     }
 
+    #[tracing::instrument(level = "debug", name = "fuse_table_commit_replace", skip(self, ctx, operations), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn commit_replace(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        on_conflict_fields: &[String],
+    ) -> Result<()> {
+        self.check_mutable()?;
+        let append_log_entries = operations
+            .iter()
+            .map(AppendOperationLogEntry::try_from)
+            .collect::<Result<Vec<AppendOperationLogEntry>>>()?;
+        self.do_replace(ctx, append_log_entries, on_conflict_fields)
+            .await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_truncate", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
         self.check_mutable()?;
@@ -355,6 +692,10 @@ impl Table for FuseTable {
         self.do_gc(&ctx, keep_last_snapshot).await
     }
 
+    async fn verify(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<(String, String)>> {
+        self.do_verify(ctx).await
+    }
+
     async fn statistics(&self, _ctx: Arc<dyn TableContext>) -> Result<Option<TableStatistics>> {
         let s = &self.table_info.meta.statistics;
         Ok(Some(TableStatistics {
@@ -365,6 +706,112 @@ impl Table for FuseTable {
         }))
     }
 
+    async fn column_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<HashMap<usize, TableColumnStatistics>> {
+        let snapshot = match self.read_table_snapshot(ctx).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(HashMap::new()),
+        };
+
+        // The statistics are keyed by the leaves of the columns, the nested columns are skipped.
+        let column_leaves = ColumnLeaves::new_from_schema(&self.table_info.schema().to_arrow());
+        let mut column_statistics = HashMap::new();
+        for (index, column_leaf) in column_leaves.column_leaves.iter().enumerate() {
+            if column_leaf.children.is_some() {
+                continue;
+            }
+            let leaf_id = column_leaf.leaf_ids[0] as u32;
+            if let Some(stats) = snapshot.summary.col_stats.get(&leaf_id) {
+                column_statistics.insert(index, TableColumnStatistics {
+                    min: stats.min.clone(),
+                    max: stats.max.clone(),
+                    null_count: stats.null_count,
+                    number_of_distinct_values: stats.distinct_of_values,
+                    histogram: stats.histogram.as_ref().map(|histogram| {
+                        histogram
+                            .buckets
+                            .iter()
+                            .map(|bucket| (bucket.upper_bound.clone(), bucket.num_values))
+                            .collect()
+                    }),
+                });
+            }
+        }
+        Ok(column_statistics)
+    }
+
+    async fn column_sizes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<HashMap<usize, TableColumnSize>> {
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(HashMap::new()),
+        };
+
+        // The compressed sizes are kept by the blocks only, the segments are read.
+        let operator = self.get_operator(ctx.as_ref())?;
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref(), operator);
+        let mut compressed_sizes = HashMap::<u32, u64>::new();
+        for (location, version) in &snapshot.segments {
+            let segment = segment_reader.read(location, None, *version).await?;
+            for block_meta in &segment.blocks {
+                for (leaf_id, col_meta) in &block_meta.col_metas {
+                    *compressed_sizes.entry(*leaf_id).or_default() += col_meta.len;
+                }
+            }
+        }
+
+        // The sizes of the nested columns are summed over their leaves.
+        let column_leaves = ColumnLeaves::new_from_schema(&self.table_info.schema().to_arrow());
+        let mut column_sizes = HashMap::new();
+        for (index, column_leaf) in column_leaves.column_leaves.iter().enumerate() {
+            let mut size = TableColumnSize::default();
+            for leaf_id in &column_leaf.leaf_ids {
+                let leaf_id = *leaf_id as u32;
+                if let Some(stats) = snapshot.summary.col_stats.get(&leaf_id) {
+                    size.uncompressed_size += stats.in_memory_size;
+                }
+                size.compressed_size += compressed_sizes.get(&leaf_id).copied().unwrap_or(0);
+            }
+            column_sizes.insert(index, size);
+        }
+        Ok(column_sizes)
+    }
+
+    fn compression(&self) -> Option<String> {
+        self.get_block_compression()
+            .ok()
+            .map(|compression| compression.get_default().to_string())
+    }
+
+    fn column_compressions(&self) -> HashMap<usize, String> {
+        let compression = match self.get_block_compression() {
+            Ok(compression) => compression,
+            Err(_) => return HashMap::new(),
+        };
+        self.table_info
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| (index, compression.get(field.name()).to_string()))
+            .collect()
+    }
+
+    fn snapshot_id(&self) -> Option<String> {
+        self.snapshot_loc()
+            .map(|location| TableMetaLocationGenerator::snapshot_id(&location))
+    }
+
+    #[tracing::instrument(level = "debug", name = "fuse_table_analyze", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        self.check_mutable()?;
+        self.do_analyze(&ctx).await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_navigate_to", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn navigate_to(
         &self,
@@ -383,6 +830,7 @@ impl Table for FuseTable {
 
     #[tracing::instrument(level = "debug", name = "fuse_table_delete", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn delete(&self, ctx: Arc<dyn TableContext>, delete_plan: DeletePlan) -> Result<()> {
+        self.check_mutable()?;
         self.do_delete(ctx, &delete_plan).await
     }
 
@@ -391,6 +839,7 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
     ) -> Result<Option<Arc<dyn TableMutator>>> {
+        self.check_mutable()?;
         self.do_compact(ctx, pipeline).await
     }
 
@@ -400,6 +849,7 @@ impl Table for FuseTable {
         pipeline: &mut Pipeline,
         push_downs: Option<Extras>,
     ) -> Result<Option<Arc<dyn TableMutator>>> {
+        self.check_mutable()?;
         self.do_recluster(ctx, pipeline, push_downs).await
     }
 }
@@ -0,0 +1,71 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! CRC32 checksums of the objects of the fuse tables.
+//!
+//! The checksums of the column chunks of a block are kept in the column metas of the block,
+//! and the checksum of a segment is appended to the segment file as a trailer line, after
+//! the json of the segment. The objects written before the checksums are not verified.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::SegmentInfo;
+
+const SEGMENT_CHECKSUM_PREFIX: &[u8] = b"\n#crc32:";
+const SEGMENT_CHECKSUM_LEN: usize = SEGMENT_CHECKSUM_PREFIX.len() + 8;
+
+pub fn checksum(data: &[u8]) -> u32 {
+    crc32fast::hash(data)
+}
+
+pub fn verify_checksum(key: &str, data: &[u8], expected: u32) -> Result<()> {
+    let actual = checksum(data);
+    if actual != expected {
+        return Err(ErrorCode::DataCorruption(format!(
+            "object {} is corrupted, expect checksum {:08x}, but got {:08x}",
+            key, expected, actual
+        )));
+    }
+    Ok(())
+}
+
+/// The content of the segment file, the json of the segment followed by its checksum.
+pub fn encode_segment(segment: &SegmentInfo) -> Result<Vec<u8>> {
+    let mut data = serde_json::to_vec(segment)?;
+    let crc = checksum(&data);
+    data.extend_from_slice(SEGMENT_CHECKSUM_PREFIX);
+    data.extend_from_slice(format!("{:08x}", crc).as_bytes());
+    Ok(data)
+}
+
+/// Verifies the checksum of the segment file, and returns the json of the segment.
+pub fn decode_segment<'a>(key: &str, data: &'a [u8]) -> Result<&'a [u8]> {
+    if data.len() < SEGMENT_CHECKSUM_LEN {
+        return Ok(data);
+    }
+    let (json, trailer) = data.split_at(data.len() - SEGMENT_CHECKSUM_LEN);
+    let hex = match trailer.strip_prefix(SEGMENT_CHECKSUM_PREFIX) {
+        Some(hex) => hex,
+        // written before the checksums
+        None => return Ok(data),
+    };
+    let expected = std::str::from_utf8(hex)
+        .ok()
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| {
+            ErrorCode::DataCorruption(format!("object {} has an invalid checksum", key))
+        })?;
+    verify_checksum(key, json, expected)?;
+    Ok(json)
+}
@@ -100,6 +100,13 @@ impl TableMetaLocationGenerator {
         }
     }
 
+    /// The id of the snapshot at the location, which is the name of the file without the suffix
+    /// of the version.
+    pub fn snapshot_id(location: impl AsRef<str>) -> String {
+        let name = location.as_ref().rsplit('/').next().unwrap_or_default();
+        name.trim_end_matches(SNAPSHOT_V1.suffix()).to_string()
+    }
+
     pub fn gen_last_snapshot_hint_location(&self) -> String {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
@@ -12,21 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod checksum;
 mod locations;
 mod read;
 mod write;
 
+pub use checksum::checksum;
+pub use checksum::decode_segment;
+pub use checksum::encode_segment;
+pub use checksum::verify_checksum;
 pub use locations::TableMetaLocationGenerator;
+pub use read::coalesce_ranges;
+pub use read::column_chunk_key;
 pub use read::load_bloom_filter_by_columns;
 pub use read::BlockBloomFilterIndexReader;
 pub use read::BlockReader;
+pub use read::ColumnDataCacheTiers;
+pub use read::IOPermit;
+pub use read::IOPriority;
+pub use read::IOScheduler;
 pub use read::MetaReaders;
 pub use read::SegmentInfoReader;
 pub use read::SnapshotHistoryReader;
 pub use read::TableSnapshotReader;
+pub use read::VersionedReader;
 pub use write::write_block;
 pub use write::write_data;
 pub use write::write_meta;
 pub use write::BlockCompactor;
+pub use write::BlockCompression;
 pub use write::BlockWriter;
+pub use write::ColumnCompression;
 pub use write::SegmentWriter;
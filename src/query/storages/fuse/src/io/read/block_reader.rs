@@ -15,9 +15,17 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::IntegerType;
+use common_arrow::arrow::datatypes::PhysicalType;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
 use common_arrow::arrow::io::parquet::read::ArrayIter;
 use common_arrow::arrow::io::parquet::read::RowGroupDeserializer;
@@ -25,11 +33,16 @@ use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::parquet::compression::Compression as ParquetCompression;
 use common_arrow::parquet::metadata::ColumnDescriptor;
 use common_arrow::parquet::metadata::SchemaDescriptor;
+use common_arrow::parquet::page::CompressedPage;
+use common_arrow::parquet::page::DataPageHeader;
 use common_arrow::parquet::read::BasicDecompressor;
+use common_arrow::parquet::read::PageFilter;
 use common_arrow::parquet::read::PageMetaData;
 use common_arrow::parquet::read::PageReader;
+use common_base::base::GlobalIORuntime;
+use common_base::base::TrySpawn;
 use common_datablocks::DataBlock;
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
@@ -37,8 +50,6 @@ use common_fuse_meta::meta::Compression;
 use common_legacy_planners::PartInfoPtr;
 use common_legacy_planners::Projection;
 use futures::AsyncReadExt;
-use futures::StreamExt;
-use futures::TryStreamExt;
 use opendal::Object;
 use opendal::Operator;
 use tracing::debug_span;
@@ -48,6 +59,10 @@ use crate::fuse_part::ColumnLeaf;
 use crate::fuse_part::ColumnLeaves;
 use crate::fuse_part::ColumnMeta;
 use crate::fuse_part::FusePartInfo;
+use crate::io::read::ColumnDataCacheTiers;
+use crate::io::verify_checksum;
+use crate::io::IOPriority;
+use crate::io::IOScheduler;
 
 #[derive(Clone)]
 pub struct BlockReader {
@@ -56,6 +71,9 @@ pub struct BlockReader {
     projected_schema: DataSchemaRef,
     column_leaves: ColumnLeaves,
     parquet_schema_descriptor: SchemaDescriptor,
+    // Reads the string columns encoded by dictionaries as `DictionaryColumn`.
+    read_dictionary: bool,
+    io_priority: IOPriority,
 }
 
 impl BlockReader {
@@ -63,6 +81,17 @@ impl BlockReader {
         operator: Operator,
         schema: DataSchemaRef,
         projection: Projection,
+    ) -> Result<Arc<BlockReader>> {
+        Self::create_with_dictionary(operator, schema, projection, false)
+    }
+
+    /// Creates the reader, which keeps the dictionaries of the string columns encoded by
+    /// dictionaries in the blocks if `read_dictionary` is true.
+    pub fn create_with_dictionary(
+        operator: Operator,
+        schema: DataSchemaRef,
+        projection: Projection,
+        read_dictionary: bool,
     ) -> Result<Arc<BlockReader>> {
         let projected_schema = match projection {
             Projection::Columns(ref indices) => DataSchemaRef::new(schema.project(indices)),
@@ -81,35 +110,51 @@ impl BlockReader {
             projected_schema,
             parquet_schema_descriptor,
             column_leaves,
+            read_dictionary,
+            io_priority: IOPriority::Interactive,
         }))
     }
 
+    /// The reader whose reads are scheduled at the priority, the reads are interactive by default.
+    pub fn with_io_priority(&self, io_priority: IOPriority) -> Arc<BlockReader> {
+        let mut reader = self.clone();
+        reader.io_priority = io_priority;
+        Arc::new(reader)
+    }
+
     pub fn schema(&self) -> DataSchemaRef {
         self.projected_schema.clone()
     }
 
     fn to_array_iter(
+        &self,
         metas: Vec<&ColumnMeta>,
         chunks: Vec<Vec<u8>>,
         rows: usize,
         column_descriptors: Vec<&ColumnDescriptor>,
-        field: Field,
-        compression: &Compression,
+        mut field: Field,
+        pages_filter: Option<PageFilter>,
     ) -> Result<ArrayIter<'static>> {
+        if self.read_dictionary
+            && field.data_type == ArrowType::LargeBinary
+            && Self::is_dictionary_encoded(&metas, &chunks, &column_descriptors)?
+        {
+            field.data_type =
+                ArrowType::Dictionary(IntegerType::UInt32, Box::new(ArrowType::LargeBinary), false);
+        }
+
         let columns = metas
             .iter()
             .zip(chunks.into_iter().zip(column_descriptors.iter()))
             .map(|(meta, (chunk, column_descriptor))| {
-                let page_meta_data = PageMetaData {
-                    column_start: meta.offset,
-                    num_values: meta.num_values as i64,
-                    compression: Self::to_parquet_compression(compression)?,
-                    descriptor: column_descriptor.descriptor.clone(),
+                let pages_filter: PageFilter = match &pages_filter {
+                    Some(pages_filter) => pages_filter.clone(),
+                    None => Arc::new(|_, _| true),
                 };
                 let pages = PageReader::new_with_page_meta(
                     std::io::Cursor::new(chunk),
-                    page_meta_data,
-                    Arc::new(|_, _| true),
+                    Self::page_meta_data(meta, column_descriptor)?,
+                    pages_filter,
                     vec![],
                     usize::MAX,
                 );
@@ -131,6 +176,105 @@ impl BlockReader {
         )?)
     }
 
+    // If the column chunk of a string column begins with a dictionary page, all its data pages
+    // are encoded by the dictionary, see `serialize_data_blocks_with_column_compression()`.
+    fn is_dictionary_encoded(
+        metas: &[&ColumnMeta],
+        chunks: &[Vec<u8>],
+        column_descriptors: &[&ColumnDescriptor],
+    ) -> Result<bool> {
+        if chunks.len() != 1 {
+            return Ok(false);
+        }
+
+        let mut pages = PageReader::new_with_page_meta(
+            std::io::Cursor::new(chunks[0].as_slice()),
+            Self::page_meta_data(metas[0], column_descriptors[0])?,
+            Arc::new(|_, _| true),
+            vec![],
+            usize::MAX,
+        );
+        match pages.next() {
+            Some(page) => {
+                let page = page.map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+                Ok(matches!(page, CompressedPage::Dict(_)))
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn page_meta_data(
+        meta: &ColumnMeta,
+        column_descriptor: &ColumnDescriptor,
+    ) -> Result<PageMetaData> {
+        Ok(PageMetaData {
+            column_start: meta.offset,
+            num_values: meta.num_values as i64,
+            compression: Self::to_parquet_compression(&meta.compression)?,
+            descriptor: column_descriptor.descriptor.clone(),
+        })
+    }
+
+    // Selects the data pages of a column holding any row of `filter`, returns the filter of
+    // the rows in the selected pages, and the filter of the selected pages for the page
+    // reader. Returns None if all the pages are selected.
+    fn select_pages(
+        meta: &ColumnMeta,
+        chunk: &[u8],
+        column_descriptor: &ColumnDescriptor,
+        filter: &Bitmap,
+    ) -> Result<Option<(Bitmap, PageFilter)>> {
+        // Only the headers of the pages are read to know the rows of each page.
+        let page_rows = Arc::new(Mutex::new(vec![]));
+        let pages = {
+            let page_rows = page_rows.clone();
+            PageReader::new_with_page_meta(
+                std::io::Cursor::new(chunk),
+                Self::page_meta_data(meta, column_descriptor)?,
+                Arc::new(move |_, header: &DataPageHeader| {
+                    let rows = match header {
+                        DataPageHeader::V1(header) => header.num_values,
+                        DataPageHeader::V2(header) => header.num_rows,
+                    };
+                    page_rows.lock().unwrap().push(rows as usize);
+                    false
+                }),
+                vec![],
+                usize::MAX,
+            )
+        };
+        for page in pages {
+            page.map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        }
+        let page_rows = std::mem::take(&mut *page_rows.lock().unwrap());
+        if page_rows.iter().sum::<usize>() != filter.len() {
+            return Ok(None);
+        }
+
+        let mut offset = 0;
+        let mut selected = Vec::with_capacity(page_rows.len());
+        let mut selected_filter = MutableBitmap::with_capacity(filter.len());
+        for rows in page_rows {
+            let page_filter = filter.clone().slice(offset, rows);
+            let page_selected = page_filter.unset_bits() < rows;
+            if page_selected {
+                selected_filter.extend_from_bitmap(&page_filter);
+            }
+            selected.push(page_selected);
+            offset += rows;
+        }
+        if selected.iter().all(|v| *v) {
+            return Ok(None);
+        }
+
+        let page = AtomicUsize::new(0);
+        let pages_filter: PageFilter = Arc::new(move |_, _| {
+            let page = page.fetch_add(1, Ordering::Relaxed);
+            selected.get(page).copied().unwrap_or(true)
+        });
+        Ok(Some((selected_filter.into(), pages_filter)))
+    }
+
     // TODO refine these
 
     #[tracing::instrument(level = "debug", skip_all)]
@@ -146,8 +290,7 @@ impl BlockReader {
         meta: &BlockMeta,
     ) -> Result<(usize, Vec<ArrayIter<'static>>)> {
         let num_rows = meta.row_count as usize;
-        let num_cols = self.projection.len();
-        let mut column_chunk_futs = Vec::with_capacity(num_cols);
+        let mut column_ranges = Vec::with_capacity(self.projection.len());
         let mut columns_meta: HashMap<usize, ColumnMeta> =
             HashMap::with_capacity(meta.col_metas.len());
 
@@ -155,26 +298,31 @@ impl BlockReader {
         let indices = Self::build_projection_indices(&columns);
         for index in indices {
             let column_meta = &meta.col_metas[&(index as u32)];
-            let column_reader = self.operator.object(&meta.location.0);
-            let fut = async move {
-                let column_chunk = column_reader
-                    .range_read(column_meta.offset..column_meta.offset + column_meta.len)
-                    .await?;
-                Ok::<_, ErrorCode>((index, column_chunk))
-            }
-            .instrument(debug_span!("read_col_chunk"));
-            column_chunk_futs.push(fut);
+            column_ranges.push((
+                index,
+                column_meta.offset,
+                column_meta.len,
+                column_meta.checksum,
+            ));
 
             columns_meta.insert(
                 index,
-                ColumnMeta::create(column_meta.offset, column_meta.len, column_meta.num_values),
+                ColumnMeta::create(
+                    column_meta.offset,
+                    column_meta.len,
+                    column_meta.num_values,
+                    column_meta
+                        .compression
+                        .unwrap_or_else(|| meta.compression()),
+                    column_meta.checksum,
+                ),
             );
         }
 
         let num_cols = columns_meta.len();
-        let chunks = futures::stream::iter(column_chunk_futs)
-            .buffered(std::cmp::min(10, num_cols))
-            .try_collect::<Vec<_>>()
+        let chunks = self
+            .read_column_chunks(&meta.location.0, column_ranges, false)
+            .instrument(debug_span!("read_col_chunks"))
             .await?;
 
         let mut chunk_map: HashMap<usize, Vec<u8>> = chunks.into_iter().collect();
@@ -200,13 +348,13 @@ impl BlockReader {
                 column_chunks.push(column_chunk);
                 column_descriptors.push(column_descriptor);
             }
-            columns_array_iter.push(Self::to_array_iter(
+            columns_array_iter.push(self.to_array_iter(
                 column_metas,
                 column_chunks,
                 num_rows,
                 column_descriptors,
                 field,
-                &meta.compression(),
+                None,
             )?);
         }
 
@@ -218,28 +366,24 @@ impl BlockReader {
 
         // TODO: add prefetch column data.
         let num_rows = part.nums_rows;
-        let num_cols = self.projection.len();
-        let mut column_chunk_futs = Vec::with_capacity(num_cols);
-
         let columns = self.column_leaves.get_by_projection(&self.projection)?;
-        let indices = Self::build_projection_indices(&columns);
-        for index in indices {
-            let column_meta = &part.columns_meta[&index];
-            let column_reader = self.operator.object(&part.location);
-            let fut = async move {
-                let (idx, column_chunk) =
-                    Self::read_column(column_reader, index, column_meta.offset, column_meta.length)
-                        .await?;
-                Ok::<_, ErrorCode>((idx, column_chunk))
-            }
-            .instrument(debug_span!("read_col_chunk"));
-            column_chunk_futs.push(fut);
-        }
+        let column_ranges = Self::build_projection_indices(&columns)
+            .into_iter()
+            .map(|index| {
+                let column_meta = &part.columns_meta[&index];
+                (
+                    index,
+                    column_meta.offset,
+                    column_meta.length,
+                    column_meta.checksum,
+                )
+            })
+            .collect::<Vec<_>>();
 
-        let num_cols = column_chunk_futs.len();
-        let chunks = futures::stream::iter(column_chunk_futs)
-            .buffered(std::cmp::min(10, num_cols))
-            .try_collect::<Vec<_>>()
+        let num_cols = column_ranges.len();
+        let chunks = self
+            .read_column_chunks(&part.location, column_ranges, true)
+            .instrument(debug_span!("read_col_chunks"))
             .await?;
 
         let mut chunk_map: HashMap<usize, Vec<u8>> = chunks.into_iter().collect();
@@ -265,13 +409,13 @@ impl BlockReader {
                 column_chunks.push(column_chunk);
                 column_descriptors.push(column_descriptor);
             }
-            columns_array_iter.push(Self::to_array_iter(
+            columns_array_iter.push(self.to_array_iter(
                 column_metas,
                 column_chunks,
                 num_rows,
                 column_descriptors,
                 field,
-                &part.compression,
+                None,
             )?);
         }
 
@@ -310,13 +454,13 @@ impl BlockReader {
                 column_chunks.push(column_chunk);
                 column_descriptors.push(column_descriptor);
             }
-            columns_array_iter.push(Self::to_array_iter(
+            columns_array_iter.push(self.to_array_iter(
                 column_metas,
                 column_chunks,
                 num_rows,
                 column_descriptors,
                 field,
-                &part.compression,
+                None,
             )?);
         }
 
@@ -325,23 +469,194 @@ impl BlockReader {
         self.try_next_block(&mut deserializer)
     }
 
-    pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<Vec<(usize, Vec<u8>)>> {
-        let part = FusePartInfo::from_part(&part)?;
-        let mut join_handlers = Vec::with_capacity(self.projection.len());
+    /// Deserializes the columns of the part concurrently in the IO runtime, rather than one by
+    /// one as `deserialize()` does, a wide block is slow to decode in one thread.
+    pub async fn deserialize_parallel(
+        self: &Arc<Self>,
+        part: PartInfoPtr,
+        chunks: Vec<(usize, Vec<u8>)>,
+    ) -> Result<DataBlock> {
+        let columns = self.column_leaves.get_by_projection(&self.projection)?;
+        if columns.len() <= 1 {
+            return self.deserialize(part, chunks);
+        }
+
+        let mut chunk_map: HashMap<usize, Vec<u8>> = chunks.into_iter().collect();
+        let mut cnt_map = Self::build_projection_count_map(&columns);
+        let runtime = GlobalIORuntime::instance();
+        let mut join_handlers = Vec::with_capacity(columns.len());
+        for (column, data_field) in columns.iter().zip(self.projected_schema.fields()) {
+            let mut column_chunks = Vec::with_capacity(column.leaf_ids.len());
+            for index in &column.leaf_ids {
+                let cnt = cnt_map.get_mut(index).unwrap();
+                *cnt -= 1;
+                let column_chunk = if cnt > &mut 0 {
+                    chunk_map.get(index).unwrap().clone()
+                } else {
+                    chunk_map.remove(index).unwrap()
+                };
+                column_chunks.push(column_chunk);
+            }
+
+            let reader = self.clone();
+            let part = part.clone();
+            let field = column.field.clone();
+            let leaf_ids = column.leaf_ids.clone();
+            let nullable = data_field.is_nullable();
+            join_handlers.push(runtime.try_spawn(async move {
+                let part = FusePartInfo::from_part(&part)?;
+                reader.deserialize_column(part, &leaf_ids, column_chunks, field, nullable)
+            })?);
+        }
+
+        let mut data_columns = Vec::with_capacity(join_handlers.len());
+        for join_handler in join_handlers {
+            let data_column = join_handler
+                .await
+                .map_err(|e| ErrorCode::TokioError(format!("runtime join error. {}", e)))??;
+            data_columns.push(data_column);
+        }
+
+        Ok(DataBlock::create(
+            self.projected_schema.clone(),
+            data_columns,
+        ))
+    }
+
+    fn deserialize_column(
+        &self,
+        part: &FusePartInfo,
+        leaf_ids: &[usize],
+        chunks: Vec<Vec<u8>>,
+        field: Field,
+        nullable: bool,
+    ) -> Result<ColumnRef> {
+        let column_metas = leaf_ids
+            .iter()
+            .map(|index| &part.columns_meta[index])
+            .collect::<Vec<_>>();
+        let column_descriptors = leaf_ids
+            .iter()
+            .map(|index| &self.parquet_schema_descriptor.columns()[*index])
+            .collect::<Vec<_>>();
+        let mut array_iter = self.to_array_iter(
+            column_metas,
+            chunks,
+            part.nums_rows,
+            column_descriptors,
+            field,
+            None,
+        )?;
+        let array = match array_iter.next() {
+            None => return Err(ErrorCode::ParquetError("fail to get a chunk")),
+            Some(array) => array?,
+        };
+        Ok(match nullable {
+            true => array.into_nullable_column(),
+            false => array.into_column(),
+        })
+    }
+
+    /// Deserializes the rows of the part in `filter` only, the data pages of a column holding
+    /// none of these rows are neither decompressed nor decoded.
+    pub fn deserialize_filtered(
+        &self,
+        part: PartInfoPtr,
+        chunks: Vec<(usize, Vec<u8>)>,
+        filter: &ColumnRef,
+    ) -> Result<DataBlock> {
+        if filter.is_const() {
+            let block = self.deserialize(part, chunks)?;
+            return DataBlock::filter_block(block, filter);
+        }
+        let filter: &BooleanColumn = Series::check_get(filter)?;
 
+        let part = FusePartInfo::from_part(&part)?;
+        let mut chunk_map: HashMap<usize, Vec<u8>> = chunks.into_iter().collect();
         let columns = self.column_leaves.get_by_projection(&self.projection)?;
-        let indices = Self::build_projection_indices(&columns);
-        for index in indices {
-            let column_meta = &part.columns_meta[&index];
-            join_handlers.push(Self::read_column(
-                self.operator.object(&part.location),
-                index,
-                column_meta.offset,
-                column_meta.length,
-            ));
+        let mut cnt_map = Self::build_projection_count_map(&columns);
+        let mut data_columns = Vec::with_capacity(columns.len());
+        for (column, data_field) in columns.iter().zip(self.projected_schema.fields()) {
+            let field = column.field.clone();
+            let indices = &column.leaf_ids;
+            let mut column_metas = Vec::with_capacity(indices.len());
+            let mut column_chunks = Vec::with_capacity(indices.len());
+            let mut column_descriptors = Vec::with_capacity(indices.len());
+            for index in indices {
+                let column_meta = &part.columns_meta[index];
+                let cnt = cnt_map.get_mut(index).unwrap();
+                *cnt -= 1;
+                let column_chunk = if cnt > &mut 0 {
+                    chunk_map.get(index).unwrap().clone()
+                } else {
+                    chunk_map.remove(index).unwrap()
+                };
+                let column_descriptor = &self.parquet_schema_descriptor.columns()[*index];
+                column_metas.push(column_meta);
+                column_chunks.push(column_chunk);
+                column_descriptors.push(column_descriptor);
+            }
+
+            // The rows of a page are the values of the page only if the column is not nested.
+            let selected = match indices.len() == 1 && !is_nested(&field) {
+                true => Self::select_pages(
+                    column_metas[0],
+                    &column_chunks[0],
+                    column_descriptors[0],
+                    filter.values(),
+                )?,
+                false => None,
+            };
+            let (column_filter, pages_filter) = match selected {
+                Some((column_filter, pages_filter)) => (column_filter, Some(pages_filter)),
+                None => (filter.values().clone(), None),
+            };
+
+            let mut array_iter = self.to_array_iter(
+                column_metas,
+                column_chunks,
+                column_filter.len(),
+                column_descriptors,
+                field,
+                pages_filter,
+            )?;
+            let array = match array_iter.next() {
+                None => return Err(ErrorCode::ParquetError("fail to get a chunk")),
+                Some(array) => array?,
+            };
+            let data_column = match data_field.is_nullable() {
+                true => array.into_nullable_column(),
+                false => array.into_column(),
+            };
+            data_columns.push(data_column.filter(&BooleanColumn::from_arrow_data(column_filter)));
         }
 
-        futures::future::try_join_all(join_handlers).await
+        Ok(DataBlock::create(
+            self.projected_schema.clone(),
+            data_columns,
+        ))
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<Vec<(usize, Vec<u8>)>> {
+        let part = FusePartInfo::from_part(&part)?;
+        let columns = self.column_leaves.get_by_projection(&self.projection)?;
+        let column_ranges = Self::build_projection_indices(&columns)
+            .into_iter()
+            .map(|index| {
+                let column_meta = &part.columns_meta[&index];
+                (
+                    index,
+                    column_meta.offset,
+                    column_meta.length,
+                    column_meta.checksum,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.read_column_chunks(&part.location, column_ranges, true)
+            .instrument(debug_span!("read_col_chunks"))
+            .await
     }
 
     pub async fn read_column(
@@ -357,6 +672,59 @@ impl BlockReader {
         Ok((index, chunk))
     }
 
+    /// Reads the chunks of the columns `(index, offset, length, checksum)` of the block at
+    /// `location`, the chunks missing in the column data caches are read by the IO scheduler,
+    /// which coalesces the adjacent chunks, and populate the caches if `cached` is true.
+    ///
+    /// The chunks read from the storage are verified by their checksums before being cached,
+    /// the chunks of the blocks written before the checksums are not verified.
+    ///
+    /// Blocks are immutable once written, the location and column index identify a chunk.
+    async fn read_column_chunks(
+        &self,
+        location: &str,
+        columns: Vec<(usize, u64, u64, Option<u32>)>,
+        cached: bool,
+    ) -> Result<Vec<(usize, Vec<u8>)>> {
+        let cache = match cached {
+            true => ColumnDataCacheTiers::instance(),
+            false => None,
+        };
+        let mut chunks = Vec::with_capacity(columns.len());
+        let mut missed = Vec::with_capacity(columns.len());
+        for (index, offset, length, checksum) in columns {
+            let cached_chunk = match &cache {
+                Some(cache) => cache.get(location, index, length).await,
+                None => None,
+            };
+            match cached_chunk {
+                Some(chunk) => chunks.push((index, chunk)),
+                None => missed.push((index, offset, length, checksum)),
+            }
+        }
+        if missed.is_empty() {
+            return Ok(chunks);
+        }
+
+        let ranges = missed
+            .iter()
+            .map(|(_, offset, length, _)| *offset..*offset + *length)
+            .collect::<Vec<_>>();
+        let missed_chunks = IOScheduler::instance()
+            .read_ranges(&self.operator, location, &ranges, self.io_priority)
+            .await?;
+        for ((index, _, _, checksum), chunk) in missed.into_iter().zip(missed_chunks) {
+            if let Some(checksum) = checksum {
+                verify_checksum(&column_chunk_key(location, index), &chunk, checksum)?;
+            }
+            if let Some(cache) = &cache {
+                cache.put(location, index, &chunk).await;
+            }
+            chunks.push((index, chunk));
+        }
+        Ok(chunks)
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn read(&self, part: PartInfoPtr) -> Result<DataBlock> {
         let (num_rows, columns_array_iter) = self.read_columns(part).await?;
@@ -394,6 +762,10 @@ impl BlockReader {
                 Err(ErrorCode::StorageOther(err_msg))
             }
             Compression::Lz4Raw => Ok(ParquetCompression::Lz4Raw),
+            Compression::Snappy => Ok(ParquetCompression::Snappy),
+            Compression::Zstd => Ok(ParquetCompression::Zstd),
+            Compression::Gzip => Ok(ParquetCompression::Gzip),
+            Compression::Uncompressed => Ok(ParquetCompression::Uncompressed),
         }
     }
 
@@ -424,3 +796,20 @@ impl BlockReader {
         cnt_map
     }
 }
+
+/// The key of the chunk of the column `index` of the block at `location` in the errors.
+pub fn column_chunk_key(location: &str, index: usize) -> String {
+    format!("{location}#column-{index}")
+}
+
+fn is_nested(field: &Field) -> bool {
+    matches!(
+        field.data_type.to_physical_type(),
+        PhysicalType::List
+            | PhysicalType::FixedSizeList
+            | PhysicalType::LargeList
+            | PhysicalType::Struct
+            | PhysicalType::Union
+            | PhysicalType::Map
+    )
+}
@@ -77,7 +77,9 @@ mod util_v1 {
     use common_base::base::GlobalIORuntime;
     use common_base::base::Runtime;
     use common_base::base::TrySpawn;
+    use common_fuse_meta::caches::CacheDeferMetrics;
     use common_fuse_meta::caches::CacheManager;
+    use common_fuse_meta::caches::TenantLabel;
 
     use super::*;
     use crate::io::MetaReaders;
@@ -91,7 +93,7 @@ mod util_v1 {
         path: &str,
         length: u64,
     ) -> Result<DataBlock> {
-        let file_meta = load_index_meta(&ctx, path, length).await?;
+        let file_meta = load_index_meta(&ctx, &dal, path, length).await?;
         if file_meta.row_groups.len() != 1 {
             return Err(ErrorCode::StorageOther(format!(
                 "invalid v1 bloom index filter index, number of row group should be 1, but found {} row groups",
@@ -206,12 +208,24 @@ mod util_v1 {
             .find(|(_, c)| c.descriptor().path_in_schema[0] == col_name)
         {
             let cache_key = format!("{path}-{idx}");
-            if let Some(bloom_index_cache) = CacheManager::instance().get_bloom_index_cache() {
+            let cache_manager = CacheManager::instance();
+            if let Some(bloom_index_cache) = cache_manager.get_bloom_index_cache() {
                 {
+                    let mut metrics = CacheDeferMetrics {
+                        tenant_label: TenantLabel {
+                            tenant_id: cache_manager.get_tenant_id().to_owned(),
+                            cluster_id: cache_manager.get_cluster_id().to_owned(),
+                        },
+                        cache_name: "BLOOM_INDEX_DATA_CACHE".to_owned(),
+                        cache_hit: false,
+                        read_bytes: col_meta.metadata().total_compressed_size as u64,
+                    };
+
                     // get by cache
                     let mut bloom_index_cache_guard = bloom_index_cache.write();
 
                     if let Some(bytes) = bloom_index_cache_guard.get(&cache_key) {
+                        metrics.cache_hit = true;
                         return Ok((bytes.clone(), idx));
                     }
                 }
@@ -255,14 +269,16 @@ mod util_v1 {
     #[tracing::instrument(level = "debug", skip_all)]
     async fn load_index_meta(
         ctx: &Arc<dyn TableContext>,
+        dal: &Operator,
         path: &str,
         length: u64,
     ) -> Result<Arc<FileMetaData>> {
         let storage_runtime = GlobalIORuntime::instance();
         let ctx_cloned = ctx.clone();
+        let dal = dal.clone();
         let path_owned = path.to_owned();
         async move {
-            let reader = MetaReaders::file_meta_data_reader(ctx_cloned);
+            let reader = MetaReaders::file_meta_data_reader(ctx_cloned, dal);
             // Format of FileMetaData is not versioned, version argument is ignored by the underlying reader,
             // so we just pass a zero to reader
             let version = 0;
@@ -0,0 +1,182 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+use std::sync::Arc;
+
+use common_base::base::tokio::task::spawn_blocking;
+use common_fuse_meta::caches::CacheDeferMetrics;
+use common_fuse_meta::caches::CacheManager;
+use common_fuse_meta::caches::ColumnDataCache;
+use common_fuse_meta::caches::ColumnDataDiskCache;
+use common_fuse_meta::caches::TenantLabel;
+use tracing::warn;
+use uuid::Uuid;
+
+const COLUMN_DATA_CACHE: &str = "COLUMN_DATA_CACHE";
+const COLUMN_DATA_DISK_CACHE: &str = "COLUMN_DATA_DISK_CACHE";
+
+/// The memory and the disk tiers of the cache of the column chunks of the blocks.
+///
+/// Blocks are immutable once written, the location and the column index identify a chunk.
+/// The chunks read from the disk tier are put into the memory tier. The files of the disk tier
+/// are read and written by the blocking threads, and the lock of the disk tier is only held to
+/// look up and to record the files, so that the readers do not wait for the disk IO of others.
+#[derive(Clone)]
+pub struct ColumnDataCacheTiers {
+    memory: Option<ColumnDataCache>,
+    disk: Option<ColumnDataDiskCache>,
+    tenant_id: String,
+    cluster_id: String,
+}
+
+impl ColumnDataCacheTiers {
+    pub fn create(
+        memory: Option<ColumnDataCache>,
+        disk: Option<ColumnDataDiskCache>,
+        tenant_id: &str,
+        cluster_id: &str,
+    ) -> Self {
+        ColumnDataCacheTiers {
+            memory,
+            disk,
+            tenant_id: tenant_id.to_string(),
+            cluster_id: cluster_id.to_string(),
+        }
+    }
+
+    /// The tiers of the cache manager, None if both of them are disabled.
+    pub fn instance() -> Option<Self> {
+        let cache_manager = CacheManager::instance();
+        let memory = cache_manager.get_column_data_cache();
+        let disk = cache_manager.get_column_data_disk_cache();
+        if memory.is_none() && disk.is_none() {
+            return None;
+        }
+        Some(Self::create(
+            memory,
+            disk,
+            cache_manager.get_tenant_id(),
+            cache_manager.get_cluster_id(),
+        ))
+    }
+
+    pub async fn get(&self, location: &str, index: usize, length: u64) -> Option<Vec<u8>> {
+        let cache_key = Self::cache_key(location, index);
+
+        if let Some(cache) = &self.memory {
+            let mut metrics = self.metrics(COLUMN_DATA_CACHE, length);
+            if let Some(bytes) = cache.write().get(&cache_key) {
+                metrics.cache_hit = true;
+                return Some(bytes.as_ref().clone());
+            }
+        }
+
+        if let Some(cache) = &self.disk {
+            let mut metrics = self.metrics(COLUMN_DATA_DISK_CACHE, length);
+            if let Some(chunk) =
+                Self::read_disk_cache(cache.clone(), cache_key.clone(), length).await
+            {
+                metrics.cache_hit = true;
+                if let Some(cache) = &self.memory {
+                    cache.write().put(cache_key, Arc::new(chunk.clone()));
+                }
+                return Some(chunk);
+            }
+        }
+        None
+    }
+
+    pub async fn put(&self, location: &str, index: usize, chunk: &[u8]) {
+        let cache_key = Self::cache_key(location, index);
+        if let Some(cache) = &self.disk {
+            Self::write_disk_cache(cache.clone(), cache_key.clone(), chunk.to_vec()).await;
+        }
+        if let Some(cache) = &self.memory {
+            cache.write().put(cache_key, Arc::new(chunk.to_vec()));
+        }
+    }
+
+    fn cache_key(location: &str, index: usize) -> String {
+        format!("{location}-{index}")
+    }
+
+    fn metrics(&self, cache_name: &str, read_bytes: u64) -> CacheDeferMetrics {
+        CacheDeferMetrics {
+            tenant_label: TenantLabel {
+                tenant_id: self.tenant_id.clone(),
+                cluster_id: self.cluster_id.clone(),
+            },
+            cache_name: cache_name.to_owned(),
+            cache_hit: false,
+            read_bytes,
+        }
+    }
+
+    async fn read_disk_cache(
+        cache: ColumnDataDiskCache,
+        key: String,
+        length: u64,
+    ) -> Option<Vec<u8>> {
+        let read = spawn_blocking(move || {
+            // The file is opened, and marked as used, under the lock, but read out of it.
+            let mut file = cache.write().get_file(&key).ok()?;
+            let mut chunk = vec![0; length as usize];
+            match file.read_exact(&mut chunk) {
+                Ok(_) => Some(chunk),
+                Err(cause) => {
+                    warn!(
+                        "failed to read column chunk {} from disk cache: {}",
+                        key, cause
+                    );
+                    None
+                }
+            }
+        });
+        read.await.ok().flatten()
+    }
+
+    // Failing to populate the disk cache should not fail the query.
+    async fn write_disk_cache(cache: ColumnDataDiskCache, key: String, chunk: Vec<u8>) {
+        let write = spawn_blocking(move || {
+            if !cache.read().can_store(chunk.len() as u64) {
+                return;
+            }
+            // The chunk is written to a temporary file out of the lock, which is then moved
+            // into the cache under the lock.
+            let tmp_path = cache
+                .read()
+                .path()
+                .join(format!(".tmp-{}", Uuid::new_v4().simple()));
+            let written = std::fs::File::create(&tmp_path).and_then(|mut f| f.write_all(&chunk));
+            if let Err(cause) = written {
+                warn!(
+                    "failed to put column chunk {} into disk cache: {}",
+                    key, cause
+                );
+                let _ = std::fs::remove_file(&tmp_path);
+                return;
+            }
+            if let Err(cause) = cache.write().insert_file(&key, &tmp_path) {
+                warn!(
+                    "failed to put column chunk {} into disk cache: {}",
+                    key, cause
+                );
+                let _ = std::fs::remove_file(&tmp_path);
+            }
+        });
+        let _ = write.await;
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_base::base::tokio::sync::oneshot;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::AsyncReadExt;
+use once_cell::sync::OnceCell;
+use opendal::Operator;
+use tracing::debug_span;
+use tracing::Instrument;
+
+/// The adjacent ranges are not coalesced into a read larger than it.
+const MAX_COALESCED_READ_BYTES: u64 = 16 * 1024 * 1024;
+
+static IO_SCHEDULER: OnceCell<Arc<IOScheduler>> = OnceCell::new();
+
+/// The priority of the reads of the storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IOPriority {
+    /// The reads of the queries, served before the background reads.
+    Interactive,
+    /// The reads of the background jobs, e.g. the compaction and the recluster, served when no
+    /// interactive reads are waiting, and taking at most half of the requests of a backend.
+    Background,
+}
+
+/// Schedules the reads of the object storages: the adjacent ranges of an object are coalesced
+/// into one request, the concurrent requests of each backend are capped, and the interactive
+/// reads are served before the background reads.
+pub struct IOScheduler {
+    max_requests: usize,
+    coalesce_gap_bytes: u64,
+    backends: Mutex<HashMap<String, Arc<BackendQueue>>>,
+}
+
+impl IOScheduler {
+    /// `max_requests` is the maximum of the concurrent requests of each backend, unlimited if 0.
+    /// The ranges whose gap is at most `coalesce_gap_bytes` are read by one request.
+    pub fn create(max_requests: usize, coalesce_gap_bytes: u64) -> Arc<IOScheduler> {
+        let max_requests = match max_requests {
+            0 => usize::MAX,
+            n => n,
+        };
+        Arc::new(IOScheduler {
+            max_requests,
+            coalesce_gap_bytes,
+            backends: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn init(max_requests: usize, coalesce_gap_bytes: u64) {
+        IO_SCHEDULER
+            .set(Self::create(max_requests, coalesce_gap_bytes))
+            .ok();
+    }
+
+    /// The scheduler of the process, the reads are neither capped nor coalesced if it is not
+    /// initialized, e.g. in the tests of the storages.
+    pub fn instance() -> Arc<IOScheduler> {
+        IO_SCHEDULER.get_or_init(|| Self::create(0, 0)).clone()
+    }
+
+    /// Waits for a request to the backend of the operator, which is released as the permit drops.
+    pub async fn acquire(&self, operator: &Operator, priority: IOPriority) -> IOPermit {
+        let queue = self.backend_queue(operator);
+        let receiver = {
+            let mut state = queue.state.lock().unwrap();
+            if queue.can_run(&state, priority) {
+                state.start(priority);
+                return IOPermit {
+                    queue: queue.clone(),
+                    priority,
+                };
+            }
+            let (sender, receiver) = oneshot::channel();
+            match priority {
+                IOPriority::Interactive => state.interactive.push_back(sender),
+                IOPriority::Background => state.background.push_back(sender),
+            }
+            receiver
+        };
+        // The waiters are only removed from the queue to be given the permits.
+        receiver
+            .await
+            .expect("the waiter of the IO scheduler must be given a permit")
+    }
+
+    /// Reads the ranges of the object, the ranges close to each other are read by one request.
+    pub async fn read_ranges(
+        &self,
+        operator: &Operator,
+        path: &str,
+        ranges: &[Range<u64>],
+        priority: IOPriority,
+    ) -> Result<Vec<Vec<u8>>> {
+        let reads = coalesce_ranges(ranges, self.coalesce_gap_bytes, MAX_COALESCED_READ_BYTES)
+            .into_iter()
+            .map(|(range, members)| {
+                async move {
+                    let _permit = self.acquire(operator, priority).await;
+                    let mut data = vec![0; (range.end - range.start) as usize];
+                    let mut reader = operator.object(path).range_reader(range.clone()).await?;
+                    reader.read_exact(&mut data).await?;
+                    Ok::<_, ErrorCode>((range.start, members, data))
+                }
+                .instrument(debug_span!("read_ranges"))
+            });
+        let reads = futures::future::try_join_all(reads).await?;
+
+        let mut chunks = vec![vec![]; ranges.len()];
+        for (start, members, data) in reads {
+            for member in members {
+                let range = &ranges[member];
+                let offset = (range.start - start) as usize;
+                let len = (range.end - range.start) as usize;
+                chunks[member] = data[offset..offset + len].to_vec();
+            }
+        }
+        Ok(chunks)
+    }
+
+    fn backend_queue(&self, operator: &Operator) -> Arc<BackendQueue> {
+        let metadata = operator.metadata();
+        let backend = format!(
+            "{}://{}{}",
+            metadata.scheme(),
+            metadata.name(),
+            metadata.root()
+        );
+        self.backends
+            .lock()
+            .unwrap()
+            .entry(backend)
+            .or_insert_with(|| {
+                Arc::new(BackendQueue {
+                    max_requests: self.max_requests,
+                    max_background_requests: std::cmp::max(1, self.max_requests / 2),
+                    state: Mutex::new(QueueState::default()),
+                })
+            })
+            .clone()
+    }
+}
+
+/// Coalesces the ranges into the reads, each of them is the range read and the indices of the
+/// ranges in it. The ranges whose gap is at most `gap` are coalesced, unless the read would be
+/// larger than `max_bytes`.
+pub fn coalesce_ranges(
+    ranges: &[Range<u64>],
+    gap: u64,
+    max_bytes: u64,
+) -> Vec<(Range<u64>, Vec<usize>)> {
+    let mut indices = (0..ranges.len()).collect::<Vec<_>>();
+    indices.sort_by_key(|i| ranges[*i].start);
+
+    let mut reads: Vec<(Range<u64>, Vec<usize>)> = Vec::with_capacity(ranges.len());
+    for i in indices {
+        let range = &ranges[i];
+        if let Some((read, members)) = reads.last_mut() {
+            let end = std::cmp::max(read.end, range.end);
+            if range.start <= read.end.saturating_add(gap) && end - read.start <= max_bytes {
+                read.end = end;
+                members.push(i);
+                continue;
+            }
+        }
+        reads.push((range.clone(), vec![i]));
+    }
+    reads
+}
+
+/// The requests to a backend being sent and waiting.
+struct BackendQueue {
+    max_requests: usize,
+    max_background_requests: usize,
+    state: Mutex<QueueState>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    running: usize,
+    running_background: usize,
+    interactive: VecDeque<oneshot::Sender<IOPermit>>,
+    background: VecDeque<oneshot::Sender<IOPermit>>,
+}
+
+impl QueueState {
+    fn start(&mut self, priority: IOPriority) {
+        self.running += 1;
+        if priority == IOPriority::Background {
+            self.running_background += 1;
+        }
+    }
+}
+
+impl BackendQueue {
+    fn can_run(&self, state: &QueueState, priority: IOPriority) -> bool {
+        match priority {
+            IOPriority::Interactive => {
+                state.running < self.max_requests && state.interactive.is_empty()
+            }
+            IOPriority::Background => {
+                state.running < self.max_requests
+                    && state.running_background < self.max_background_requests
+                    && state.interactive.is_empty()
+                    && state.background.is_empty()
+            }
+        }
+    }
+
+    // Gives the free requests to the waiters, the interactive ones first.
+    fn dispatch(self: &Arc<Self>) {
+        loop {
+            let (sender, permit) = {
+                let mut state = self.state.lock().unwrap();
+                if state.running >= self.max_requests {
+                    return;
+                }
+                let (sender, priority) = match state.interactive.pop_front() {
+                    Some(sender) => (sender, IOPriority::Interactive),
+                    None if state.running_background < self.max_background_requests => {
+                        match state.background.pop_front() {
+                            Some(sender) => (sender, IOPriority::Background),
+                            None => return,
+                        }
+                    }
+                    None => return,
+                };
+                state.start(priority);
+                (sender, IOPermit {
+                    queue: self.clone(),
+                    priority,
+                })
+            };
+            // The permit of a cancelled waiter is dropped, which gives it to the next waiter.
+            let _ = sender.send(permit);
+        }
+    }
+}
+
+/// A request to a backend, the next waiter is served as it drops.
+pub struct IOPermit {
+    queue: Arc<BackendQueue>,
+    priority: IOPriority,
+}
+
+impl Drop for IOPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.queue.state.lock().unwrap();
+            state.running -= 1;
+            if self.priority == IOPriority::Background {
+                state.running_background -= 1;
+            }
+        }
+        self.queue.dispatch();
+    }
+}
@@ -14,8 +14,10 @@
 
 use std::sync::Arc;
 
+use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::parquet::metadata::FileMetaData;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::caches::CacheManager;
 use common_fuse_meta::caches::TenantLabel;
@@ -27,9 +29,12 @@ use common_storages_util::cached_reader::CachedReader;
 use common_storages_util::cached_reader::HasTenantLabel;
 use common_storages_util::cached_reader::Loader;
 use futures::io::BufReader;
+use futures::AsyncReadExt;
 use opendal::BytesReader;
+use opendal::Operator;
 
 use super::versioned_reader::VersionedReader;
+use crate::io::checksum::decode_segment;
 
 /// Provider of [BufReader]
 ///
@@ -37,36 +42,50 @@ use super::versioned_reader::VersionedReader;
 /// of an [BufReader] can be deferred or avoided (e.g. if hits cache).
 #[async_trait::async_trait]
 pub trait BufReaderProvider {
-    async fn buf_reader(&self, path: &str, len: Option<u64>) -> Result<BufReader<BytesReader>>;
+    async fn buf_reader(
+        &self,
+        operator: &Operator,
+        path: &str,
+        len: Option<u64>,
+    ) -> Result<BufReader<BytesReader>>;
 }
 
 pub type SegmentInfoReader<'a> = CachedReader<SegmentInfo, LoaderWrapper<&'a dyn TableContext>>;
 pub type TableSnapshotReader = CachedReader<TableSnapshot, LoaderWrapper<Arc<dyn TableContext>>>;
-pub type BloomIndexFileMetaDataReader = CachedReader<FileMetaData, Arc<dyn TableContext>>;
+pub type BloomIndexFileMetaDataReader =
+    CachedReader<FileMetaData, LoaderWrapper<Arc<dyn TableContext>>>;
 
+/// The readers of the meta data of the tables, the meta data are read by the operator of
+/// the storage of the table, see [crate::FuseTable::get_operator].
 pub struct MetaReaders;
 
 impl MetaReaders {
-    pub fn segment_info_reader(ctx: &dyn TableContext) -> SegmentInfoReader {
+    pub fn segment_info_reader(ctx: &dyn TableContext, operator: Operator) -> SegmentInfoReader {
         SegmentInfoReader::new(
             CacheManager::instance().get_table_segment_cache(),
-            LoaderWrapper(ctx),
+            LoaderWrapper(ctx, operator),
             "SEGMENT_INFO_CACHE".to_owned(),
         )
     }
 
-    pub fn table_snapshot_reader(ctx: Arc<dyn TableContext>) -> TableSnapshotReader {
+    pub fn table_snapshot_reader(
+        ctx: Arc<dyn TableContext>,
+        operator: Operator,
+    ) -> TableSnapshotReader {
         TableSnapshotReader::new(
             CacheManager::instance().get_table_snapshot_cache(),
-            LoaderWrapper(ctx),
+            LoaderWrapper(ctx, operator),
             "SNAPSHOT_CACHE".to_owned(),
         )
     }
 
-    pub fn file_meta_data_reader(ctx: Arc<dyn TableContext>) -> BloomIndexFileMetaDataReader {
+    pub fn file_meta_data_reader(
+        ctx: Arc<dyn TableContext>,
+        operator: Operator,
+    ) -> BloomIndexFileMetaDataReader {
         BloomIndexFileMetaDataReader::new(
             CacheManager::instance().get_bloom_index_meta_cache(),
-            ctx,
+            LoaderWrapper(ctx, operator),
             "BLOOM_INDEX_FILE_META_DATA_CACHE".to_owned(),
         )
     }
@@ -74,7 +93,7 @@ impl MetaReaders {
 
 // workaround for the orphan rules
 // Loader and types of table meta data are all defined outside (of this crate)
-pub struct LoaderWrapper<T>(T);
+pub struct LoaderWrapper<T>(T, Operator);
 
 #[async_trait::async_trait]
 impl<T> Loader<TableSnapshot> for LoaderWrapper<T>
@@ -87,7 +106,7 @@ where T: BufReaderProvider + Sync + Send
         version: u64,
     ) -> Result<TableSnapshot> {
         let version = SnapshotVersion::try_from(version)?;
-        let reader = self.0.buf_reader(key, length_hint).await?;
+        let reader = self.0.buf_reader(&self.1, key, length_hint).await?;
         version.read(reader).await
     }
 }
@@ -98,15 +117,42 @@ where T: BufReaderProvider + Sync + Send
 {
     async fn load(&self, key: &str, length_hint: Option<u64>, version: u64) -> Result<SegmentInfo> {
         let version = SegmentInfoVersion::try_from(version)?;
-        let reader = self.0.buf_reader(key, length_hint).await?;
-        version.read(reader).await
+        let mut reader = self.0.buf_reader(&self.1, key, length_hint).await?;
+        let mut data = vec![];
+        reader.read_to_end(&mut data).await?;
+        version.read(decode_segment(key, &data)?).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<FileMetaData> for LoaderWrapper<Arc<dyn TableContext>> {
+    async fn load(
+        &self,
+        key: &str,
+        length_hint: Option<u64>,
+        _version: u64,
+    ) -> Result<FileMetaData> {
+        let object = self.1.object(key);
+        let reader = match length_hint {
+            Some(len) => object.seekable_reader(..len),
+            None => object.seekable_reader(..),
+        };
+        let buffer_size = self.0.get_settings().get_storage_read_buffer_size()?;
+        let mut buf_reader = BufReader::with_capacity(buffer_size as usize, reader);
+        read_metadata_async(&mut buf_reader)
+            .await
+            .map_err(|err| ErrorCode::ParquetError(format!("read meta failed, {}, {:?}", key, err)))
     }
 }
 
 #[async_trait::async_trait]
 impl BufReaderProvider for &dyn TableContext {
-    async fn buf_reader(&self, path: &str, len: Option<u64>) -> Result<BufReader<BytesReader>> {
-        let operator = self.get_storage_operator()?;
+    async fn buf_reader(
+        &self,
+        operator: &Operator,
+        path: &str,
+        len: Option<u64>,
+    ) -> Result<BufReader<BytesReader>> {
         let object = operator.object(path);
 
         let len = match len {
@@ -129,8 +175,13 @@ impl BufReaderProvider for &dyn TableContext {
 
 #[async_trait::async_trait]
 impl BufReaderProvider for Arc<dyn TableContext> {
-    async fn buf_reader(&self, path: &str, len: Option<u64>) -> Result<BufReader<BytesReader>> {
-        self.as_ref().buf_reader(path, len).await
+    async fn buf_reader(
+        &self,
+        operator: &Operator,
+        path: &str,
+        len: Option<u64>,
+    ) -> Result<BufReader<BytesReader>> {
+        self.as_ref().buf_reader(operator, path, len).await
     }
 }
 
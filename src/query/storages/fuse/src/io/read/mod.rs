@@ -14,14 +14,23 @@
 
 mod block_reader;
 mod bloom_index_reader;
+mod column_data_cache;
+mod io_scheduler;
 mod meta_readers;
 mod snapshot_history_reader;
 mod versioned_reader;
 
+pub use block_reader::column_chunk_key;
 pub use block_reader::BlockReader;
 pub use bloom_index_reader::load_bloom_filter_by_columns;
 pub use bloom_index_reader::BlockBloomFilterIndexReader;
+pub use column_data_cache::ColumnDataCacheTiers;
+pub use io_scheduler::coalesce_ranges;
+pub use io_scheduler::IOPermit;
+pub use io_scheduler::IOPriority;
+pub use io_scheduler::IOScheduler;
 pub use meta_readers::MetaReaders;
 pub use meta_readers::SegmentInfoReader;
 pub use meta_readers::TableSnapshotReader;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use versioned_reader::VersionedReader;
@@ -43,6 +43,13 @@ impl BlockCompactor {
         false
     }
 
+    /// The number of the perfect blocks holding the rows of the bytes.
+    pub fn perfect_block_count(&self, row_count: usize, block_size: usize) -> usize {
+        let by_rows = (row_count + self.max_rows_per_block - 1) / self.max_rows_per_block;
+        let by_bytes = (block_size + self.max_bytes_per_block - 1) / self.max_bytes_per_block;
+        by_rows.max(by_bytes).max(1)
+    }
+
     pub fn check_for_recluster(&self, total_rows: usize, total_bytes: usize) -> bool {
         if total_rows <= self.min_rows_per_block && total_bytes <= self.max_bytes_per_block {
             return true;
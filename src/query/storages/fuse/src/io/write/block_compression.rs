@@ -0,0 +1,204 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_arrow::parquet::compression::CompressionOptions;
+use common_arrow::parquet::compression::GzipLevel;
+use common_arrow::parquet::compression::ZstdLevel;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::Compression;
+
+use crate::FUSE_OPT_KEY_COLUMN_COMPRESSION;
+use crate::FUSE_OPT_KEY_COMPRESSION;
+
+/// The codec, and optionally the level, used to compress a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnCompression {
+    pub compression: Compression,
+    pub level: Option<i32>,
+}
+
+impl Default for ColumnCompression {
+    fn default() -> Self {
+        ColumnCompression {
+            compression: Compression::Lz4Raw,
+            level: None,
+        }
+    }
+}
+
+impl ColumnCompression {
+    /// Parse `<codec>[(<level>)]`, e.g. `zstd`, `zstd(9)`, `gzip(6)`, `snappy`, `lz4`, `none`.
+    pub fn try_parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (name, level) = match s.strip_suffix(')').and_then(|v| v.split_once('(')) {
+            Some((name, level)) => {
+                let level = level.trim().parse::<i32>().map_err(|_| {
+                    ErrorCode::BadOption(format!("invalid compression level: {}", s))
+                })?;
+                (name.trim(), Some(level))
+            }
+            None => (s, None),
+        };
+
+        let compression = match name.to_lowercase().as_str() {
+            "lz4" => Compression::Lz4Raw,
+            "snappy" => Compression::Snappy,
+            "zstd" => Compression::Zstd,
+            "gzip" => Compression::Gzip,
+            "none" => Compression::Uncompressed,
+            _ => {
+                return Err(ErrorCode::BadOption(format!(
+                    "unsupported compression: {}, expects one of lz4, snappy, zstd, gzip and none",
+                    name
+                )));
+            }
+        };
+        if level.is_some() && !matches!(compression, Compression::Zstd | Compression::Gzip) {
+            return Err(ErrorCode::BadOption(format!(
+                "compression level is only supported by zstd and gzip: {}",
+                s
+            )));
+        }
+
+        let column_compression = ColumnCompression { compression, level };
+        // check the level is in the range of the codec
+        column_compression.to_parquet_options()?;
+        Ok(column_compression)
+    }
+
+    pub fn to_parquet_options(&self) -> Result<CompressionOptions> {
+        let level_error =
+            |level: i32| ErrorCode::BadOption(format!("invalid compression level: {}", level));
+        match (self.compression, self.level) {
+            (Compression::Lz4, _) => Err(ErrorCode::StorageOther(
+                "the legacy compression algorithm [Lz4] can not be used to write blocks",
+            )),
+            (Compression::Lz4Raw, _) => Ok(CompressionOptions::Lz4Raw),
+            (Compression::Snappy, _) => Ok(CompressionOptions::Snappy),
+            (Compression::Zstd, None) => Ok(CompressionOptions::Zstd(None)),
+            (Compression::Zstd, Some(level)) => {
+                let level = ZstdLevel::try_new(level).map_err(|_| level_error(level))?;
+                Ok(CompressionOptions::Zstd(Some(level)))
+            }
+            (Compression::Gzip, None) => Ok(CompressionOptions::Gzip(None)),
+            (Compression::Gzip, Some(level)) => {
+                let level = u8::try_from(level)
+                    .ok()
+                    .and_then(|v| GzipLevel::try_new(v).ok())
+                    .ok_or_else(|| level_error(level))?;
+                Ok(CompressionOptions::Gzip(Some(level)))
+            }
+            (Compression::Uncompressed, _) => Ok(CompressionOptions::Uncompressed),
+        }
+    }
+}
+
+impl Display for ColumnCompression {
+    // In the syntax of the table options, the legacy lz4 is shown as lz4 as well.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self.compression {
+            Compression::Lz4 | Compression::Lz4Raw => "lz4",
+            Compression::Snappy => "snappy",
+            Compression::Zstd => "zstd",
+            Compression::Gzip => "gzip",
+            Compression::Uncompressed => "none",
+        };
+        match self.level {
+            Some(level) => write!(f, "{}({})", name, level),
+            None => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The compression of the columns of the blocks of a fuse table, specified by the table options
+///
+/// - `compression = '<codec>[(<level>)]'` for all the columns, `lz4` if not specified.
+/// - `column_compression = '<column>=<codec>[(<level>)], ...'` to override some of the columns.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockCompression {
+    default: ColumnCompression,
+    columns: HashMap<String, ColumnCompression>,
+}
+
+impl BlockCompression {
+    pub fn try_from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let default = match options.get(FUSE_OPT_KEY_COMPRESSION) {
+            Some(v) => ColumnCompression::try_parse(v)?,
+            None => ColumnCompression::default(),
+        };
+
+        let mut columns = HashMap::new();
+        if let Some(v) = options.get(FUSE_OPT_KEY_COLUMN_COMPRESSION) {
+            for item in v
+                .split(',')
+                .map(|item| item.trim())
+                .filter(|s| !s.is_empty())
+            {
+                let (column, compression) = item.split_once('=').ok_or_else(|| {
+                    ErrorCode::BadOption(format!(
+                        "invalid column compression: {}, expects `<column>=<codec>`",
+                        item
+                    ))
+                })?;
+                let column = column.trim().to_string();
+                let compression = ColumnCompression::try_parse(compression)?;
+                if columns.insert(column.clone(), compression).is_some() {
+                    return Err(ErrorCode::BadOption(format!(
+                        "duplicated column compression: {}",
+                        column
+                    )));
+                }
+            }
+        }
+        Ok(BlockCompression { default, columns })
+    }
+
+    /// Check that the overridden columns are all columns of the table.
+    pub fn check_columns(&self, schema: &DataSchema) -> Result<()> {
+        for column in self.columns.keys() {
+            if schema.index_of(column).is_err() {
+                return Err(ErrorCode::BadOption(format!(
+                    "column compression of unknown column: {}",
+                    column
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The compression of the columns not overridden.
+    pub fn get_default(&self) -> ColumnCompression {
+        self.default
+    }
+
+    pub fn get(&self, column: &str) -> ColumnCompression {
+        self.columns.get(column).cloned().unwrap_or(self.default)
+    }
+
+    /// The parquet compression options of each field of the schema.
+    pub fn to_parquet_options(&self, schema: &DataSchema) -> Result<Vec<CompressionOptions>> {
+        schema
+            .fields()
+            .iter()
+            .map(|f| self.get(f.name()).to_parquet_options())
+            .collect()
+    }
+}
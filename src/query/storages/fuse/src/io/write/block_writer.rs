@@ -12,19 +12,24 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::collections::HashMap;
+
 use common_arrow::parquet::compression::CompressionOptions;
-use common_arrow::parquet::metadata::ThriftFileMetaData;
-use common_datablocks::serialize_data_blocks;
+use common_datablocks::serialize_data_blocks_with_column_compression;
 use common_datablocks::serialize_data_blocks_with_compression;
 use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
 use common_fuse_meta::meta::ClusterStatistics;
+use common_fuse_meta::meta::ColumnId;
+use common_fuse_meta::meta::ColumnMeta;
 use common_fuse_meta::meta::Location;
 use opendal::Operator;
 use uuid::Uuid;
 
 use crate::index::BloomFilter;
+use crate::index::IndexColumns;
+use crate::io::BlockCompression;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
@@ -35,16 +40,22 @@ const DEFAULT_BLOCK_WRITE_BUFFER_SIZE: usize = 100 * 1024 * 1024;
 pub struct BlockWriter<'a> {
     location_generator: &'a TableMetaLocationGenerator,
     data_accessor: &'a Operator,
+    compression: &'a BlockCompression,
+    index_columns: &'a IndexColumns,
 }
 
 impl<'a> BlockWriter<'a> {
     pub fn new(
         data_accessor: &'a Operator,
         location_generator: &'a TableMetaLocationGenerator,
+        compression: &'a BlockCompression,
+        index_columns: &'a IndexColumns,
     ) -> Self {
         Self {
             location_generator,
             data_accessor,
+            compression,
+            index_columns,
         }
     }
 
@@ -62,8 +73,8 @@ impl<'a> BlockWriter<'a> {
         let (bloom_filter_index_size, bloom_filter_index_location) = self
             .build_block_index(data_accessor, &block, block_id)
             .await?;
-        let (file_size, file_meta_data) = write_block(block, data_accessor, &location.0).await?;
-        let col_metas = util::column_metas(&file_meta_data)?;
+        let (file_size, col_metas) =
+            write_block(block, data_accessor, &location.0, self.compression).await?;
         let block_meta = BlockMeta::new(
             row_count,
             block_size,
@@ -94,7 +105,7 @@ impl<'a> BlockWriter<'a> {
         block: &DataBlock,
         block_id: Uuid,
     ) -> Result<(u64, Location)> {
-        let bloom_index = BloomFilter::try_create(&[block])?;
+        let bloom_index = BloomFilter::try_create(&[block], self.index_columns)?;
         let index_block = bloom_index.bloom_block;
         let location = self
             .location_generator
@@ -116,12 +127,20 @@ pub async fn write_block(
     block: DataBlock,
     data_accessor: &Operator,
     location: &str,
-) -> Result<(u64, ThriftFileMetaData)> {
+    compression: &BlockCompression,
+) -> Result<(u64, HashMap<ColumnId, ColumnMeta>)> {
     let mut buf = Vec::with_capacity(DEFAULT_BLOCK_WRITE_BUFFER_SIZE);
     let schema = block.schema().clone();
-    let result = serialize_data_blocks(vec![block], &schema, &mut buf)?;
+    let compressions = compression.to_parquet_options(&schema)?;
+    let (file_size, file_meta) = serialize_data_blocks_with_column_compression(
+        vec![block],
+        &schema,
+        &mut buf,
+        &compressions,
+    )?;
+    let col_metas = util::column_metas(&file_meta, &buf)?;
     write_data(&buf, data_accessor, location).await?;
-    Ok(result)
+    Ok((file_size, col_metas))
 }
 
 pub async fn write_data(data: &[u8], data_accessor: &Operator, location: &str) -> Result<()> {
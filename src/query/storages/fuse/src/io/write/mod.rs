@@ -13,11 +13,14 @@
 // limitations under the License.
 
 mod block_compactor;
+mod block_compression;
 mod block_writer;
 mod meta_writer;
 mod segment_writer;
 
 pub use block_compactor::BlockCompactor;
+pub use block_compression::BlockCompression;
+pub use block_compression::ColumnCompression;
 pub use block_writer::write_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockWriter;
@@ -22,7 +22,8 @@ use common_fuse_meta::meta::SegmentInfo;
 use common_fuse_meta::meta::Versioned;
 use opendal::Operator;
 
-use crate::io::write_meta;
+use crate::io::encode_segment;
+use crate::io::write_data;
 use crate::io::TableMetaLocationGenerator;
 
 pub struct SegmentWriter<'a> {
@@ -46,7 +47,8 @@ impl<'a> SegmentWriter<'a> {
     pub async fn write_segment(&self, segment: SegmentInfo) -> Result<Location> {
         let segment_path = self.location_generator.gen_segment_info_location();
         let segment_location = (segment_path, SegmentInfo::VERSION);
-        write_meta(self.data_accessor, segment_location.0.as_str(), &segment).await?;
+        let data = encode_segment(&segment)?;
+        write_data(&data, self.data_accessor, segment_location.0.as_str()).await?;
 
         if let Some(ref cache) = self.cache {
             let cache = &mut cache.write();
@@ -24,6 +24,7 @@ pub mod operations;
 pub mod pruning;
 pub mod statistics;
 pub mod table_functions;
+mod virtual_column;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table::TableStatistics;
@@ -35,6 +36,10 @@ pub use fuse_part::ColumnLeaf;
 pub use fuse_part::ColumnLeaves;
 pub use fuse_table::FuseTable;
 pub use table_option_keys::*;
+pub use virtual_column::VirtualColumn;
+pub use virtual_column::VirtualColumnFilters;
+pub use virtual_column::VirtualColumnStatsGenerator;
+pub use virtual_column::VirtualColumns;
 
 mod sessions {
     pub use common_catalog::table_context::TableContext;
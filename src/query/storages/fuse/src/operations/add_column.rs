@@ -0,0 +1,130 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::ColumnFiller;
+use common_catalog::table_context::TableContext;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_legacy_planners::Projection;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+
+use crate::io::BlockWriter;
+use crate::operations::mutation::block_filter::all_the_columns_ids;
+use crate::operations::mutation::BaseMutator;
+use crate::pruning::BlockPruner;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Adds the column at the end of the schema. The blocks of the existing rows are rewritten
+    /// with the values of the new column made by `fill`, and the new snapshot is committed with
+    /// the new schema at once, so no block of the table lacks the column.
+    pub async fn do_add_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        field: DataField,
+        comment: String,
+        fill: ColumnFiller,
+    ) -> Result<()> {
+        if self
+            .virtual_columns
+            .columns()
+            .any(|c| c.name == field.name().as_str())
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "column {} already exists",
+                field.name()
+            )));
+        }
+
+        let schema = self.table_info.schema();
+        let mut fields = schema.fields().clone();
+        fields.push(field.clone());
+        let mut new_table_info = self.table_info.clone();
+        new_table_info.meta.schema = DataSchemaRefExt::create(fields);
+        // the comments of the columns may be missing, e.g. of a table created by a query
+        let field_comments = &mut new_table_info.meta.field_comments;
+        field_comments.resize(schema.num_fields(), "".to_string());
+        field_comments.push(comment);
+
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return self.update_schema(ctx.as_ref(), new_table_info).await,
+        };
+
+        let operator = self.get_operator(ctx.as_ref())?;
+        let mut mutator = BaseMutator::try_create(
+            ctx.clone(),
+            operator.clone(),
+            self.meta_location_generator.clone(),
+            snapshot.clone(),
+        )?;
+        let block_metas = BlockPruner::new(snapshot)
+            .with_operator(operator.clone())
+            .prune(&ctx, schema, &None)
+            .await?;
+
+        let reader =
+            self.create_block_reader(&ctx, Projection::Columns(all_the_columns_ids(self)))?;
+        let compression = self.get_block_compression()?;
+        let index_columns = self.get_index_columns();
+        let block_writer = BlockWriter::new(
+            &operator,
+            &self.meta_location_generator,
+            &compression,
+            &index_columns,
+        );
+        for (seg_idx, block_meta) in block_metas {
+            let block = reader.read_with_block_meta(&block_meta).await?;
+            let column = fill(&block)?;
+            let block = block.add_column(column, field.clone())?;
+            // the cluster key is not of the new column, the cluster statistics are kept
+            let new_block_meta = block_writer
+                .write(block, block_meta.cluster_stats.clone())
+                .await?;
+            mutator.add_mutation(seg_idx, block_meta.location.clone(), Some(new_block_meta));
+        }
+
+        let (segments, summary) = mutator.generate_segments().await?;
+        let mut new_snapshot = mutator.into_new_snapshot(segments, summary).await?;
+        new_snapshot.schema = new_table_info.schema().as_ref().clone();
+        Self::commit_to_meta_server(
+            ctx.as_ref(),
+            &operator,
+            &new_table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+        )
+        .await
+    }
+
+    // The table has no data, only the schema is changed.
+    async fn update_schema(&self, ctx: &dyn TableContext, new_table_info: TableInfo) -> Result<()> {
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta: new_table_info.meta,
+        };
+        let tenant = ctx.get_tenant();
+        let db_name = ctx.get_current_database();
+        catalog.update_table_meta(&tenant, &db_name, req).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_fuse_meta::meta::AnalyzedSegments;
+use common_legacy_planners::Projection;
+use tracing::debug;
+
+use crate::io::MetaReaders;
+use crate::operations::mutation::block_filter::all_the_columns_ids;
+use crate::operations::mutation::BaseMutator;
+use crate::statistics::gen_columns_statistics;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Recollects the statistics of the columns of the blocks, the blocks are kept
+    /// as they are, while the segments and the snapshot are rewritten with the new
+    /// statistics, e.g. the blocks written by the earlier versions without the number
+    /// of distinct values and the histograms, or without the statistics of the virtual
+    /// columns added later.
+    ///
+    /// Only the segments added or rewritten since the last analyze are read, the segments
+    /// analyzed are recorded in the new snapshot.
+    pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) if snapshot.summary.block_count > 0 => snapshot,
+            // nothing to analyze
+            _ => return Ok(()),
+        };
+
+        let operator = self.get_operator(ctx.as_ref())?;
+        let mut mutator = BaseMutator::try_create(
+            ctx.clone(),
+            operator.clone(),
+            self.meta_location_generator.clone(),
+            snapshot.clone(),
+        )?;
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref(), operator);
+        let block_reader =
+            self.create_block_reader(ctx, Projection::Columns(all_the_columns_ids(self)))?;
+        let virtual_stats_gen = self.get_virtual_column_stats_gen(ctx.clone())?;
+
+        // The virtual columns added since the last analyze are collected on all the segments.
+        let next_virtual_column_id = self.virtual_columns.next_column_id();
+        let analyzed = &snapshot.analyzed_segments;
+        let analyzed_segments = if analyzed.next_virtual_column_id == next_virtual_column_id {
+            analyzed.segments.iter().collect::<HashSet<_>>()
+        } else {
+            HashSet::new()
+        };
+        let mut analyzed_blocks = 0;
+        for (seg_idx, segment_location) in snapshot.segments.iter().enumerate() {
+            if analyzed_segments.contains(segment_location) {
+                continue;
+            }
+            let (location, version) = segment_location;
+            let segment = segment_reader.read(location, None, *version).await?;
+            analyzed_blocks += segment.blocks.len();
+            for block_meta in &segment.blocks {
+                let block = block_reader.read_with_block_meta(block_meta).await?;
+                let mut new_block_meta = block_meta.clone();
+                new_block_meta.col_stats = gen_columns_statistics(&block)?;
+                new_block_meta
+                    .col_stats
+                    .extend(virtual_stats_gen.gen_stats(&block));
+                mutator.add_mutation(seg_idx, block_meta.location.clone(), Some(new_block_meta));
+            }
+        }
+
+        if analyzed_blocks == 0 {
+            // nothing added since the last analyze
+            return Ok(());
+        }
+
+        debug!(
+            "analyzed {} of {} blocks of table {}",
+            analyzed_blocks,
+            snapshot.summary.block_count,
+            self.name()
+        );
+        let (segments, summary) = mutator.generate_segments().await?;
+        let mut new_snapshot = mutator.into_new_snapshot(segments, summary).await?;
+        new_snapshot.analyzed_segments = AnalyzedSegments {
+            segments: new_snapshot.segments.clone(),
+            next_virtual_column_id,
+        };
+        Self::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.get_operator(ctx.as_ref())?,
+            self.get_table_info(),
+            &self.meta_location_generator,
+            new_snapshot,
+        )
+        .await
+    }
+}
@@ -29,7 +29,9 @@ use common_pipeline_transforms::processors::transforms::TransformSortPartial;
 use common_pipeline_transforms::processors::ExpressionExecutor;
 
 use crate::io::BlockCompactor;
+use crate::operations::DedupState;
 use crate::operations::FuseTableSink;
+use crate::operations::TransformDedup;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
@@ -45,6 +47,20 @@ impl FuseTable {
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
 
+        // The rows ingested before are dropped before the blocks are compacted.
+        let dedup_keys = Self::parse_dedup_keys(self.table_info.options());
+        if !dedup_keys.is_empty() {
+            let dedup_state = DedupState::create(self, dedup_keys);
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformDedup::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    ctx.clone(),
+                    dedup_state.clone(),
+                )
+            })?;
+        }
+
         let block_compactor = self.get_block_compactor();
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             TransformCompact::try_create(
@@ -78,7 +94,10 @@ impl FuseTable {
             })?;
         }
 
-        let da = ctx.get_storage_operator()?;
+        let compression = self.get_block_compression()?;
+        let index_columns = self.get_index_columns();
+        let virtual_stats_gen = self.get_virtual_column_stats_gen(ctx.clone())?;
+        let da = self.get_operator(ctx.as_ref())?;
         if need_output {
             pipeline.add_transform(|transform_input_port, transform_output_port| {
                 FuseTableSink::try_create(
@@ -88,6 +107,9 @@ impl FuseTable {
                     da.clone(),
                     self.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
+                    virtual_stats_gen.clone(),
+                    compression.clone(),
+                    index_columns.clone(),
                     Some(transform_output_port),
                 )
             })?;
@@ -104,6 +126,9 @@ impl FuseTable {
                         da.clone(),
                         self.meta_location_generator().clone(),
                         cluster_stats_gen.clone(),
+                        virtual_stats_gen.clone(),
+                        compression.clone(),
+                        index_columns.clone(),
                         None,
                     )?,
                 );
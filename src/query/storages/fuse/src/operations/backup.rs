@@ -0,0 +1,200 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::SegmentInfo;
+use common_fuse_meta::meta::TableSnapshot;
+use common_fuse_meta::meta::Versioned;
+use opendal::Operator;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::io::encode_segment;
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Copies the current snapshot of the table, with the segments and the blocks it refers
+    /// to, into `target` at the same paths as in the storage of the table. Returns the
+    /// location of the copied snapshot, or None if the table has no snapshot.
+    ///
+    /// `last_backup` is the location of the snapshot copied into `target` by the last backup,
+    /// the segments it refers to are kept in `target` with their blocks and are not copied
+    /// again, nor is anything copied if the table is not changed since then.
+    pub async fn do_backup(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        target: &Operator,
+        last_backup: Option<&str>,
+    ) -> Result<Option<String>> {
+        let snapshot_loc = match self.snapshot_loc() {
+            None => return Ok(None),
+            Some(loc) if Some(loc.as_str()) == last_backup => return Ok(Some(loc)),
+            Some(loc) => loc,
+        };
+
+        let mut backed_up = HashSet::new();
+        if let Some(last_backup) = last_backup {
+            let reader = MetaReaders::table_snapshot_reader(ctx.clone(), target.clone());
+            let ver = TableMetaLocationGenerator::snapshot_version(last_backup);
+            let last_snapshot = reader.read(last_backup, None, ver).await?;
+            backed_up.extend(last_snapshot.segments.iter().map(|(loc, _)| loc.clone()));
+        }
+
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+        let operator = self.get_operator(ctx.as_ref())?;
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref(), operator.clone());
+        let mut copied = 0;
+        for (location, ver) in &snapshot.segments {
+            if backed_up.contains(location) {
+                continue;
+            }
+
+            // The blocks are copied before the segment, a backup broken in the middle leaves
+            // no segment referring to the blocks not copied.
+            let segment = segment_reader.read(location, None, *ver).await?;
+            for block_meta in &segment.blocks {
+                copy_object(&operator, target, &block_meta.location.0).await?;
+                if let Some((bloom_index_location, _)) = &block_meta.bloom_filter_index_location {
+                    copy_object(&operator, target, bloom_index_location).await?;
+                }
+            }
+            copy_object(&operator, target, location).await?;
+            copied += 1;
+        }
+        copy_object(&operator, target, &snapshot_loc).await?;
+
+        info!(
+            "backed up snapshot {} of table {}, {} of {} segments copied",
+            snapshot_loc,
+            self.table_info.desc,
+            copied,
+            snapshot.segments.len()
+        );
+        Ok(Some(snapshot_loc))
+    }
+
+    /// Copies the snapshot kept in `source` by a backup, with the segments and the blocks it
+    /// refers to, into the table, and commits it as the snapshot of the table.
+    ///
+    /// The copied files are kept in the prefix of the table, so the copied snapshot and
+    /// segments refer to the files by the paths in the prefix of the table, and the copied
+    /// snapshot has no previous snapshot.
+    pub async fn do_restore(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &Operator,
+        snapshot_loc: &str,
+    ) -> Result<()> {
+        self.check_mutable()?;
+
+        let reader = MetaReaders::table_snapshot_reader(ctx.clone(), source.clone());
+        let ver = TableMetaLocationGenerator::snapshot_version(snapshot_loc);
+        let snapshot = reader.read(snapshot_loc, None, ver).await?;
+
+        let operator = self.get_operator(ctx.as_ref())?;
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref(), source.clone());
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        for (location, ver) in &snapshot.segments {
+            let segment = segment_reader.read(location, None, *ver).await?;
+            let mut blocks = Vec::with_capacity(segment.blocks.len());
+            for block_meta in &segment.blocks {
+                let mut block_meta = block_meta.clone();
+                let (block_location, _) = &mut block_meta.location;
+                let relocated = self.relocate(block_location)?;
+                copy_relocated(source, &operator, block_location, &relocated).await?;
+                *block_location = relocated;
+                if let Some((bloom_index_location, _)) = &mut block_meta.bloom_filter_index_location
+                {
+                    let relocated = self.relocate(bloom_index_location)?;
+                    copy_relocated(source, &operator, bloom_index_location, &relocated).await?;
+                    *bloom_index_location = relocated;
+                }
+                blocks.push(block_meta);
+            }
+
+            let segment_location = self.meta_location_generator.gen_segment_info_location();
+            let new_segment = SegmentInfo::new(blocks, segment.summary.clone());
+            write_data(&encode_segment(&new_segment)?, &operator, &segment_location).await?;
+            segments.push((segment_location, SegmentInfo::VERSION));
+        }
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            snapshot.schema.clone(),
+            snapshot.summary.clone(),
+            segments,
+            snapshot.cluster_key_meta.clone(),
+        );
+        Self::commit_to_meta_server(
+            ctx.as_ref(),
+            &operator,
+            self.get_table_info(),
+            &self.meta_location_generator,
+            new_snapshot,
+        )
+        .await
+    }
+
+    // The path of the file in the prefix of the table, such as `1/2/_b/x.parquet` into
+    // `3/4/_b/x.parquet` for the table kept in the prefix `3/4`.
+    fn relocate(&self, path: &str) -> Result<String> {
+        let mut parts = path.rsplitn(3, '/');
+        match (parts.next(), parts.next()) {
+            (Some(name), Some(dir)) if !name.is_empty() && !dir.is_empty() => Ok(format!(
+                "{}/{}/{}",
+                self.meta_location_generator.prefix(),
+                dir,
+                name
+            )),
+            _ => Err(ErrorCode::StorageOther(format!(
+                "invalid location of the file of the table {}",
+                path
+            ))),
+        }
+    }
+}
+
+// The files of the tables are never changed once written, a file kept in `target` is the
+// same one, e.g. copied by a backup broken in the middle, or a block shared by segments.
+async fn copy_object(source: &Operator, target: &Operator, path: &str) -> Result<()> {
+    copy_relocated(source, target, path, path).await
+}
+
+async fn copy_relocated(
+    source: &Operator,
+    target: &Operator,
+    path: &str,
+    target_path: &str,
+) -> Result<()> {
+    let object = target.object(target_path);
+    if object.is_exist().await? {
+        return Ok(());
+    }
+    let data = source.object(path).read().await?;
+    object.write(data).await?;
+    Ok(())
+}
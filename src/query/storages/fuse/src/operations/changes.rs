@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::Location;
+use common_fuse_meta::meta::TableSnapshot;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Partitions;
+use common_legacy_planners::Statistics;
+use opendal::Operator;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::BlockPruner;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Read the partitions of the blocks appended since the snapshot located at `since`.
+    ///
+    /// The changes are tracked by the blocks: the blocks referenced by the `since` snapshot are
+    /// considered consumed, even if their segments are merged into new segments since then, so
+    /// only the blocks of the rows appended are returned. If a consumed block is rewritten, e.g.
+    /// by a compaction or a DELETE, the rows of the new blocks can not be told apart from the
+    /// consumed ones, and `StreamInvalidated` is returned.
+    /// If `since` is `None`, all the blocks of the current snapshot are returned.
+    pub async fn do_read_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        since: Option<&str>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok((Statistics::default(), vec![])),
+        };
+
+        let operator = self.get_operator(ctx.as_ref())?;
+        let (consumed_segments, consumed_blocks) = match since {
+            Some(loc) if Some(loc) == self.snapshot_loc().as_deref() => {
+                return Ok((Statistics::default(), vec![]));
+            }
+            Some(loc) => {
+                let reader = MetaReaders::table_snapshot_reader(ctx.clone(), operator.clone());
+                let ver = TableMetaLocationGenerator::snapshot_version(loc);
+                let since_snapshot = reader.read(loc, None, ver).await?;
+                self.consumed_blocks(ctx.as_ref(), &operator, &since_snapshot, &snapshot)
+                    .await?
+            }
+            None => (HashSet::new(), HashSet::new()),
+        };
+
+        let mut delta = TableSnapshot::clone(&snapshot);
+        delta
+            .segments
+            .retain(|loc| !consumed_segments.contains(loc));
+
+        let block_metas = BlockPruner::new(Arc::new(delta))
+            .with_operator(operator)
+            .prune(&ctx, self.table_info.schema(), &push_downs)
+            .await?
+            .into_iter()
+            .map(|(_, v)| v)
+            .filter(|v| !consumed_blocks.contains(&v.location.0))
+            .collect::<Vec<_>>();
+        let partitions_total = block_metas.len();
+        self.read_partitions_with_metas(ctx, push_downs, block_metas, partitions_total)
+    }
+
+    // The segments of `since` kept by `current` as they are, and the blocks of the other segments
+    // of `since`, which must be kept by the new segments of `current`.
+    async fn consumed_blocks(
+        &self,
+        ctx: &dyn TableContext,
+        operator: &Operator,
+        since: &TableSnapshot,
+        current: &TableSnapshot,
+    ) -> Result<(HashSet<Location>, HashSet<String>)> {
+        let current_segments = current.segments.iter().collect::<HashSet<_>>();
+        let (kept, merged): (Vec<_>, Vec<_>) = since
+            .segments
+            .iter()
+            .partition(|loc| current_segments.contains(loc));
+        let consumed_segments = kept.into_iter().cloned().collect::<HashSet<_>>();
+        if merged.is_empty() {
+            return Ok((consumed_segments, HashSet::new()));
+        }
+
+        let reader = MetaReaders::segment_info_reader(ctx, operator.clone());
+        let mut consumed_blocks = HashSet::new();
+        for (loc, ver) in merged {
+            let segment = reader.read(loc, None, *ver).await?;
+            consumed_blocks.extend(segment.blocks.iter().map(|b| b.location.0.clone()));
+        }
+
+        let mut kept_blocks = 0;
+        for (loc, ver) in current
+            .segments
+            .iter()
+            .filter(|loc| !consumed_segments.contains(*loc))
+        {
+            let segment = reader.read(loc, None, *ver).await?;
+            kept_blocks += segment
+                .blocks
+                .iter()
+                .filter(|b| consumed_blocks.contains(&b.location.0))
+                .count();
+        }
+        if kept_blocks != consumed_blocks.len() {
+            return Err(ErrorCode::StreamInvalidated(format!(
+                "the consumed blocks of table {} are rewritten, the stream must be recreated",
+                self.table_info.desc
+            )));
+        }
+        Ok((consumed_segments, consumed_blocks))
+    }
+}
@@ -17,6 +17,7 @@ use std::time::Duration;
 use std::time::Instant;
 
 use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
 use backoff::ExponentialBackoffBuilder;
 use common_base::base::ProgressValues;
 use common_cache::Cache;
@@ -43,6 +44,7 @@ use tracing::warn;
 use uuid::Uuid;
 
 use crate::io::write_meta;
+use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::AppendOperationLogEntry;
 use crate::operations::TableOperationLog;
@@ -66,33 +68,22 @@ impl FuseTable {
         let mut latest: Arc<dyn Table>;
 
         let mut retry_times = 0;
+        let mut backoff = Self::occ_backoff();
 
-        // The initial retry delay in millisecond. By default,  it is 5 ms.
-        let init_delay = OCC_DEFAULT_BACKOFF_INIT_DELAY_MS;
-
-        // The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing.
-        // By default, it is 20 seconds.
-        let max_delay = OCC_DEFAULT_BACKOFF_MAX_DELAY_MS;
-
-        // The maximum elapsed time after the occ starts, beyond which there will be no more retries.
-        // By default, it is 2 minutes
-        let max_elapsed = OCC_DEFAULT_BACKOFF_MAX_ELAPSED_MS;
-
-        // To simplify the settings, using fixed common values for randomization_factor and multiplier
-        let mut backoff = ExponentialBackoffBuilder::new()
-            .with_initial_interval(init_delay)
-            .with_max_interval(max_delay)
-            .with_randomization_factor(0.5)
-            .with_multiplier(2.0)
-            .with_max_elapsed_time(Some(max_elapsed))
-            .build();
+        // The rows are written once, however many times the commit is retried.
+        let (_, summary) = Self::merge_append_operations(&operation_log)?;
+        ctx.get_write_progress().incr(&ProgressValues {
+            rows: summary.row_count as usize,
+            bytes: summary.uncompressed_byte_size as usize,
+        });
 
         let transient = self.transient();
         loop {
             match tbl.try_commit(ctx.clone(), &operation_log, overwrite).await {
                 Ok(_) => {
                     break {
-                        if transient {
+                        // The history is kept until the transaction, if any, is committed.
+                        if transient && ctx.get_transaction().is_none() {
                             // Removes historical data, if table is transient
                             warn!(
                                 "transient table detected, purging historical data. ({})",
@@ -116,37 +107,41 @@ impl FuseTable {
                         Ok(())
                     };
                 }
-                Err(e) if self::utils::is_error_recoverable(&e, transient) => match backoff
-                    .next_backoff()
-                {
-                    Some(d) => {
-                        let name = tbl.table_info.name.clone();
-                        debug!(
-                            "got error TableVersionMismatched, tx will be retried {} ms later. table name {}, identity {}",
-                            d.as_millis(),
-                            name.as_str(),
-                            tbl.table_info.ident
-                        );
-                        common_base::base::tokio::time::sleep(d).await;
-                        latest = tbl.latest(ctx.as_ref()).await?;
-                        tbl = FuseTable::try_from_table(latest.as_ref())?;
-                        retry_times += 1;
-                        continue;
-                    }
-                    None => {
-                        info!("aborting operations");
-                        let _ = self::utils::abort_operations(ctx.as_ref(), operation_log).await;
-                        break Err(ErrorCode::OCCRetryFailure(format!(
-                            "can not fulfill the tx after retries({} times, {} ms), aborted. table name {}, identity {}",
-                            retry_times,
-                            Instant::now()
-                                .duration_since(backoff.start_time)
-                                .as_millis(),
-                            tbl.table_info.name.as_str(),
-                            tbl.table_info.ident,
-                        )));
+                Err(e) if self::utils::is_error_recoverable(&e, transient) => {
+                    match backoff.next_backoff() {
+                        Some(d) => {
+                            let name = tbl.table_info.name.clone();
+                            debug!(
+                                "got error TableVersionMismatched, tx will be retried {} ms later. table name {}, identity {}",
+                                d.as_millis(),
+                                name.as_str(),
+                                tbl.table_info.ident
+                            );
+                            common_base::base::tokio::time::sleep(d).await;
+                            latest = tbl.latest(ctx.as_ref()).await?;
+                            tbl = FuseTable::try_from_table(latest.as_ref())?;
+                            retry_times += 1;
+                            continue;
+                        }
+                        None => {
+                            info!("aborting operations");
+                            let _ = self::utils::abort_operations(
+                                &tbl.get_operator(ctx.as_ref())?,
+                                operation_log,
+                            )
+                            .await;
+                            break Err(ErrorCode::OCCRetryFailure(format!(
+                                "can not fulfill the tx after retries({} times, {} ms), aborted. table name {}, identity {}",
+                                retry_times,
+                                Instant::now()
+                                    .duration_since(backoff.start_time)
+                                    .as_millis(),
+                                tbl.table_info.name.as_str(),
+                                tbl.table_info.ident,
+                            )));
+                        }
                     }
-                },
+                }
                 Err(e) => break Err(e),
             }
         }
@@ -165,12 +160,6 @@ impl FuseTable {
         let schema = self.table_info.meta.schema.as_ref().clone();
         let (segments, summary) = Self::merge_append_operations(operation_log)?;
 
-        let progress_values = ProgressValues {
-            rows: summary.row_count as usize,
-            bytes: summary.uncompressed_byte_size as usize,
-        };
-        ctx.get_write_progress().incr(&progress_values);
-
         let segments = segments
             .into_iter()
             .map(|loc| (loc, SegmentInfo::VERSION))
@@ -208,6 +197,7 @@ impl FuseTable {
 
         FuseTable::commit_to_meta_server(
             ctx.as_ref(),
+            &self.get_operator(ctx.as_ref())?,
             &self.table_info,
             &self.meta_location_generator,
             new_snapshot,
@@ -239,7 +229,7 @@ impl FuseTable {
             new_segments.append(&mut segments)
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_snapshot_timestamp,
             prev_snapshot_id,
@@ -248,11 +238,143 @@ impl FuseTable {
             new_segments,
             cluster_key_meta,
         );
+        // the segments appended are left to the next ANALYZE TABLE
+        if let Some(snapshot) = &previous {
+            new_snapshot.analyzed_segments = snapshot.analyzed_segments.clone();
+        }
         Ok(new_snapshot)
     }
 
+    /// Commits the new snapshot of a mutation of the base snapshot, e.g. a DELETE or a compaction.
+    ///
+    /// If the table is changed by the others in the meantime, the new snapshot is re-based onto
+    /// the latest snapshot and committed again, as long as the others only append to the table,
+    /// i.e. the latest snapshot keeps the segments of the base snapshot as they are and adds new
+    /// segments before them. Otherwise the conflict is returned, as the segments mutated may be
+    /// mutated by the others as well.
+    pub async fn commit_mutation(
+        ctx: &Arc<dyn TableContext>,
+        operator: &Operator,
+        table_info: &TableInfo,
+        location_generator: &TableMetaLocationGenerator,
+        base_snapshot: Arc<TableSnapshot>,
+        new_snapshot: TableSnapshot,
+    ) -> Result<()> {
+        let mut table_info = table_info.clone();
+        let mut base_snapshot = base_snapshot;
+        let mut new_snapshot = new_snapshot;
+        let mut retry_times = 0;
+        let mut backoff = Self::occ_backoff();
+        loop {
+            let cause = match FuseTable::commit_to_meta_server(
+                ctx.as_ref(),
+                operator,
+                &table_info,
+                location_generator,
+                new_snapshot.clone(),
+            )
+            .await
+            {
+                Err(cause) if cause.code() == ErrorCode::table_version_mismatched_code() => cause,
+                res => return res,
+            };
+
+            let delay = match backoff.next_backoff() {
+                Some(delay) => delay,
+                None => {
+                    return Err(ErrorCode::OCCRetryFailure(format!(
+                        "can not fulfill the tx after retries({} times, {} ms), aborted. table name {}, identity {}",
+                        retry_times,
+                        Instant::now()
+                            .duration_since(backoff.start_time)
+                            .as_millis(),
+                        table_info.name.as_str(),
+                        table_info.ident,
+                    )));
+                }
+            };
+            debug!(
+                "got error TableVersionMismatched, mutation will be re-based and retried {} ms later. table name {}, identity {}",
+                delay.as_millis(),
+                table_info.name.as_str(),
+                table_info.ident
+            );
+            common_base::base::tokio::time::sleep(delay).await;
+
+            let catalog = ctx.get_catalog(table_info.catalog())?;
+            let (ident, meta) = catalog
+                .get_table_meta_by_id(table_info.ident.table_id)
+                .await?;
+            table_info = TableInfo {
+                ident,
+                meta: meta.as_ref().clone(),
+                ..table_info
+            };
+            let latest = catalog.get_table_by_info(&table_info)?;
+            let latest = FuseTable::try_from_table(latest.as_ref())?;
+            let latest_snapshot = match latest.read_table_snapshot(ctx.clone()).await? {
+                Some(latest_snapshot) => latest_snapshot,
+                None => return Err(cause),
+            };
+            new_snapshot = match Self::rebase_mutation(
+                ctx.as_ref(),
+                operator,
+                &base_snapshot,
+                &latest_snapshot,
+                &new_snapshot,
+            )
+            .await?
+            {
+                Some(rebased) => rebased,
+                None => return Err(cause),
+            };
+            base_snapshot = latest_snapshot;
+            retry_times += 1;
+        }
+    }
+
+    // Re-bases the new snapshot of a mutation of the base snapshot onto the latest snapshot, None
+    // if the latest snapshot is not made by appending to the base snapshot.
+    async fn rebase_mutation(
+        ctx: &dyn TableContext,
+        operator: &Operator,
+        base_snapshot: &TableSnapshot,
+        latest_snapshot: &TableSnapshot,
+        new_snapshot: &TableSnapshot,
+    ) -> Result<Option<TableSnapshot>> {
+        // the segments appended are of the schema and the cluster key of the latest snapshot
+        if latest_snapshot.schema != base_snapshot.schema
+            || new_snapshot.schema != base_snapshot.schema
+            || latest_snapshot.cluster_key_meta != base_snapshot.cluster_key_meta
+            || !latest_snapshot.segments.ends_with(&base_snapshot.segments)
+        {
+            return Ok(None);
+        }
+
+        let appended = &latest_snapshot.segments
+            [..latest_snapshot.segments.len() - base_snapshot.segments.len()];
+        let segment_reader = MetaReaders::segment_info_reader(ctx, operator.clone());
+        let mut summary = new_snapshot.summary.clone();
+        for (location, version) in appended {
+            let segment = segment_reader.read(location, None, *version).await?;
+            summary = statistics::merge_statistics(&summary, &segment.summary)?;
+        }
+
+        let mut rebased = TableSnapshot::from_previous(latest_snapshot);
+        rebased.segments = appended
+            .iter()
+            .chain(new_snapshot.segments.iter())
+            .cloned()
+            .collect();
+        rebased.summary = summary;
+        // the segments appended are left to the next ANALYZE TABLE
+        rebased.analyzed_segments = new_snapshot.analyzed_segments.clone();
+        Ok(Some(rebased))
+    }
+
     pub async fn commit_to_meta_server(
         ctx: &dyn TableContext,
+        operator: &Operator,
         table_info: &TableInfo,
         location_generator: &TableMetaLocationGenerator,
         snapshot: TableSnapshot,
@@ -261,8 +383,7 @@ impl FuseTable {
             .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
 
         // 1. write down snapshot
-        let operator = ctx.get_storage_operator()?;
-        write_meta(&operator, &snapshot_location, &snapshot).await?;
+        write_meta(operator, &snapshot_location, &snapshot).await?;
 
         // 2. prepare table meta
         let mut new_table_meta = table_info.meta.clone();
@@ -284,7 +405,23 @@ impl FuseTable {
             index_data_bytes: stats.index_size,
         };
 
-        // 3. prepare the request
+        // 3. the new meta is staged by the transaction, if any, and committed with the others
+        if let Some(txn) = ctx.get_transaction() {
+            let catalog = table_info.meta.catalog.clone();
+            let mut table_info = table_info.clone();
+            table_info.meta = new_table_meta;
+            if let Err(e) = txn.stage_table(&catalog, table_info) {
+                let _ = operator.object(&snapshot_location).delete().await;
+                return Err(e);
+            }
+            if let Some(snapshot_cache) = CacheManager::instance().get_table_snapshot_cache() {
+                let cache = &mut snapshot_cache.write();
+                cache.put(snapshot_location, Arc::new(snapshot));
+            }
+            return Ok(());
+        }
+
+        // 4. prepare the request
         let catalog = ctx.get_catalog(&table_info.meta.catalog)?;
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -295,7 +432,7 @@ impl FuseTable {
             new_table_meta,
         };
 
-        // 3. let's roll
+        // 5. let's roll
         let tenant = ctx.get_tenant();
         let db_name = ctx.get_current_database();
         let reply = catalog.update_table_meta(&tenant, &db_name, req).await;
@@ -306,7 +443,7 @@ impl FuseTable {
                     cache.put(snapshot_location.clone(), Arc::new(snapshot));
                 }
                 // try keep a hit file of last snapshot
-                Self::write_last_snapshot_hint(&operator, location_generator, snapshot_location)
+                Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
                     .await;
                 Ok(())
             }
@@ -348,6 +485,28 @@ impl FuseTable {
         Ok((seg_locs, s))
     }
 
+    fn occ_backoff() -> ExponentialBackoff {
+        // The initial retry delay in millisecond. By default,  it is 5 ms.
+        let init_delay = OCC_DEFAULT_BACKOFF_INIT_DELAY_MS;
+
+        // The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing.
+        // By default, it is 20 seconds.
+        let max_delay = OCC_DEFAULT_BACKOFF_MAX_DELAY_MS;
+
+        // The maximum elapsed time after the occ starts, beyond which there will be no more retries.
+        // By default, it is 2 minutes
+        let max_elapsed = OCC_DEFAULT_BACKOFF_MAX_ELAPSED_MS;
+
+        // To simplify the settings, using fixed common values for randomization_factor and multiplier
+        ExponentialBackoffBuilder::new()
+            .with_initial_interval(init_delay)
+            .with_max_interval(max_delay)
+            .with_randomization_factor(0.5)
+            .with_multiplier(2.0)
+            .with_max_elapsed_time(Some(max_elapsed))
+            .build()
+    }
+
     async fn latest(&self, ctx: &dyn TableContext) -> Result<Arc<dyn Table>> {
         let name = self.table_info.name.clone();
         let tid = self.table_info.ident.table_id;
@@ -397,16 +556,12 @@ impl FuseTable {
 mod utils {
     use std::collections::BTreeMap;
 
-    use common_catalog::table_context::TableContext;
-
     use super::*;
     #[inline]
     pub async fn abort_operations(
-        ctx: &dyn TableContext,
+        operator: &Operator,
         operation_log: TableOperationLog,
     ) -> Result<()> {
-        let operator = ctx.get_storage_operator()?;
-
         for entry in operation_log {
             for block in &entry.segment_info.blocks {
                 let block_location = &block.location.0;
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_fuse_meta::meta::Statistics;
 use common_legacy_planners::ReadDataSourcePlan;
 use common_legacy_planners::SourceInfo;
 use common_pipeline_core::processors::port::InputPort;
@@ -23,6 +24,8 @@ use common_pipeline_core::SinkPipeBuilder;
 use common_pipeline_transforms::processors::transforms::TransformCompact;
 
 use super::FuseTableSink;
+use crate::io::BlockCompactor;
+use crate::io::IOPriority;
 use crate::operations::CompactMutator;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -32,7 +35,60 @@ use crate::TableMutator;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
+/// The compaction of a table estimated by the summary of its snapshot, without reading the
+/// segments.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompactionEstimate {
+    pub block_count: u64,
+    /// The blocks beyond the perfect blocks holding the rows of the table, i.e. the blocks a
+    /// compaction removes by merging the small blocks.
+    pub excess_blocks: u64,
+    /// The compressed bytes of the blocks a compaction reads and writes again.
+    pub bytes: u64,
+}
+
+impl CompactionEstimate {
+    pub fn from_summary(summary: &Statistics, block_compactor: &BlockCompactor) -> Self {
+        let perfect_blocks = block_compactor.perfect_block_count(
+            summary.row_count as usize,
+            summary.uncompressed_byte_size as usize,
+        ) as u64;
+        let excess_blocks = summary.block_count.saturating_sub(perfect_blocks);
+        // A merged block is made of two small blocks at least, so the blocks rewritten are at
+        // most twice the blocks removed.
+        let bytes = match summary.block_count {
+            0 => 0,
+            n => summary.compressed_byte_size * (2 * excess_blocks).min(n) / n,
+        };
+        CompactionEstimate {
+            block_count: summary.block_count,
+            excess_blocks,
+            bytes,
+        }
+    }
+}
+
 impl FuseTable {
+    /// Estimates the compaction of the table, `None` if the table is not compacted by
+    /// `OPTIMIZE TABLE ... COMPACT`, e.g. it's empty, read only or has the cluster keys, of
+    /// which the blocks are merged by the reclustering.
+    pub async fn estimate_compaction(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<CompactionEstimate>> {
+        if self.read_only || self.cluster_key_meta.is_some() {
+            return Ok(None);
+        }
+        let snapshot = match self.read_table_snapshot(ctx).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+        Ok(Some(CompactionEstimate::from_summary(
+            &snapshot.summary,
+            &self.get_block_compactor(),
+        )))
+    }
+
     pub(crate) async fn do_compact(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -56,6 +112,7 @@ impl FuseTable {
 
         let mut mutator = CompactMutator::try_create(
             ctx.clone(),
+            self.get_operator(ctx.as_ref())?,
             base_snapshot,
             block_compactor.clone(),
             self.meta_location_generator().clone(),
@@ -88,7 +145,7 @@ impl FuseTable {
         };
 
         ctx.try_set_partitions(plan.parts.clone())?;
-        self.do_read2(ctx.clone(), &plan, pipeline)?;
+        self.do_read2(ctx.clone(), &plan, pipeline, IOPriority::Background)?;
 
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             TransformCompact::try_create(
@@ -98,6 +155,9 @@ impl FuseTable {
             )
         })?;
 
+        let compression = self.get_block_compression()?;
+        let index_columns = self.get_index_columns();
+        let virtual_stats_gen = self.get_virtual_column_stats_gen(ctx.clone())?;
         let mut sink_pipeline_builder = SinkPipeBuilder::create();
         for _ in 0..pipeline.output_len() {
             let input_port = InputPort::create();
@@ -110,6 +170,9 @@ impl FuseTable {
                     mutator.get_storage_operator(),
                     self.meta_location_generator().clone(),
                     ClusterStatsGenerator::default(),
+                    virtual_stats_gen.clone(),
+                    compression.clone(),
+                    index_columns.clone(),
                     None,
                 )?,
             );
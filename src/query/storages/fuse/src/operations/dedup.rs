@@ -0,0 +1,251 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_fuse_meta::meta::TableSnapshot;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Projection;
+
+use super::replace::conflict_filter;
+use super::replace::conflict_keys;
+use super::replace::ConflictKey;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+use crate::pruning::BlockPruner;
+use crate::FuseTable;
+
+/// The state of the deduplication of an insert, shared by the transforms of all its pipes.
+pub struct DedupState {
+    table: FuseTable,
+    dedup_keys: Vec<String>,
+    window_segments: usize,
+    // The keys of the rows kept by the insert so far.
+    seen: Mutex<HashSet<ConflictKey>>,
+}
+
+impl DedupState {
+    pub fn create(table: &FuseTable, dedup_keys: Vec<String>) -> Arc<DedupState> {
+        Arc::new(DedupState {
+            table: table.clone(),
+            dedup_keys,
+            window_segments: table.get_dedup_window_segments(),
+            seen: Mutex::new(HashSet::new()),
+        })
+    }
+
+    // The recent segments of the table, in which the keys are looked up.
+    async fn recent_snapshot(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<Option<Arc<TableSnapshot>>> {
+        match self.table.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) if snapshot.summary.row_count != 0 => {
+                let mut recent = snapshot.as_ref().clone();
+                // The segments appended are put before the older ones.
+                recent.segments.truncate(self.window_segments);
+                Ok(Some(Arc::new(recent)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // The keys existing in the snapshot, out of `keys`.
+    async fn existing_keys(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot: Arc<TableSnapshot>,
+        keys: &HashSet<ConflictKey>,
+    ) -> Result<HashSet<ConflictKey>> {
+        let schema = self.table.schema();
+        let push_downs = Some(Extras {
+            projection: None,
+            filters: vec![conflict_filter(&self.dedup_keys, keys)],
+            prewhere: None,
+            limit: None,
+            order_by: vec![],
+        });
+        let block_metas = BlockPruner::new(snapshot)
+            .with_virtual_columns(self.table.virtual_columns.clone())
+            .with_index_columns(self.table.get_index_columns())
+            .with_operator(self.table.get_operator(ctx.as_ref())?)
+            .prune(ctx, schema.clone(), &push_downs)
+            .await?;
+
+        // The blocks kept by the bloom filters may not have the keys, they are checked by
+        // reading the keys.
+        let key_indices = self
+            .dedup_keys
+            .iter()
+            .map(|name| schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        let key_reader = self
+            .table
+            .create_block_reader(ctx, Projection::Columns(key_indices))?;
+        let mut existing = HashSet::new();
+        for (_, block_meta) in block_metas {
+            let block = key_reader.read_with_block_meta(&block_meta).await?;
+            existing.extend(
+                conflict_keys(&block, &self.dedup_keys)?
+                    .into_iter()
+                    .flatten()
+                    .filter(|key| keys.contains(key)),
+            );
+        }
+        Ok(existing)
+    }
+
+    /// Drops the rows of the block of which the keys exist in the recent segments of the table,
+    /// or in the rows kept by the insert before. The rows with NULL keys are always kept.
+    pub async fn dedup(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot: Option<Arc<TableSnapshot>>,
+        block: DataBlock,
+    ) -> Result<DataBlock> {
+        let keys = conflict_keys(&block, &self.dedup_keys)?;
+        let unseen = {
+            let seen = self.seen.lock().unwrap();
+            keys.iter()
+                .flatten()
+                .filter(|key| !seen.contains(*key))
+                .cloned()
+                .collect::<HashSet<_>>()
+        };
+        let existing = match snapshot {
+            Some(snapshot) if !unseen.is_empty() => {
+                self.existing_keys(ctx, snapshot, &unseen).await?
+            }
+            _ => HashSet::new(),
+        };
+
+        let keeps = {
+            let mut seen = self.seen.lock().unwrap();
+            keys.into_iter()
+                .map(|key| match key {
+                    None => true,
+                    Some(key) => !existing.contains(&key) && seen.insert(key),
+                })
+                .collect::<Vec<_>>()
+        };
+        if keeps.iter().all(|keep| *keep) {
+            return Ok(block);
+        }
+        let filter = BooleanColumn::from_owned_iterator(keeps.into_iter());
+        DataBlock::filter_block_with_bool_column(block, &filter)
+    }
+}
+
+/// Drops the rows ingested of which the keys are ingested before, by the table option
+/// `dedup_on`, for the sources replaying the rows, e.g. the streams delivered at least once.
+pub struct TransformDedup {
+    input_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    ctx: Arc<dyn TableContext>,
+    state: Arc<DedupState>,
+    // The snapshot is read before the first block, the keys are looked up in the table as of it.
+    snapshot: Option<Option<Arc<TableSnapshot>>>,
+}
+
+impl TransformDedup {
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        ctx: Arc<dyn TableContext>,
+        state: Arc<DedupState>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(TransformDedup {
+            input_data: None,
+            output_data: None,
+            input_port,
+            output_port,
+            ctx,
+            state,
+            snapshot: None,
+        })))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformDedup {
+    fn name(&self) -> &'static str {
+        "Dedup"
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output_port.is_finished() {
+            self.input_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output_port.can_push() {
+            self.input_port.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data) = self.output_data.take() {
+            self.output_port.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Async);
+        }
+
+        if self.input_port.has_data() {
+            self.input_data = Some(self.input_port.pull_data().unwrap()?);
+            return Ok(Event::Async);
+        }
+
+        if self.input_port.is_finished() {
+            self.output_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input_port.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(data) = self.input_data.take() {
+            if self.snapshot.is_none() {
+                self.snapshot = Some(self.state.recent_snapshot(&self.ctx).await?);
+            }
+            let snapshot = self.snapshot.clone().flatten();
+            let data = self.state.dedup(&self.ctx, snapshot, data).await?;
+            if data.num_rows() > 0 {
+                self.output_data = Some(data);
+            }
+        }
+        Ok(())
+    }
+}
@@ -81,9 +81,12 @@ impl FuseTable {
         let cluster_stats_gen = self.cluster_stats_gen(ctx.clone())?;
         let mut deletion_collector = DeletionMutator::try_create(
             ctx.clone(),
+            self.get_operator(ctx.as_ref())?,
             self.meta_location_generator.clone(),
             snapshot.clone(),
             cluster_stats_gen,
+            self.get_block_compression()?,
+            self.get_index_columns(),
         )?;
         let schema = self.table_info.schema();
         // TODO refine pruner
@@ -96,6 +99,9 @@ impl FuseTable {
         };
         let push_downs = Some(extras);
         let block_metas = BlockPruner::new(snapshot.clone())
+            .with_virtual_columns(self.virtual_columns.clone())
+            .with_index_columns(self.get_index_columns())
+            .with_operator(self.get_operator(ctx.as_ref())?)
             .prune(&ctx, schema, &push_downs)
             .await?;
 
@@ -122,27 +128,32 @@ impl FuseTable {
                 }
             }
         }
-        self.commit_deletion(ctx.as_ref(), deletion_collector).await
+        self.commit_deletion(&ctx, snapshot, deletion_collector)
+            .await
     }
 
     async fn commit_deletion(
         &self,
-        ctx: &dyn TableContext,
+        ctx: &Arc<dyn TableContext>,
+        base_snapshot: &Arc<TableSnapshot>,
         del_holder: DeletionMutator,
     ) -> Result<()> {
         let new_snapshot = del_holder.into_new_snapshot().await?;
-        Self::commit_to_meta_server(
+        Self::commit_mutation(
             ctx,
+            &self.get_operator(ctx.as_ref())?,
             self.get_table_info(),
             &self.meta_location_generator,
+            base_snapshot.clone(),
             new_snapshot,
         )
-        .await?;
-        // TODO check if error is recoverable, and try to resolve the conflict
-        Ok(())
+        .await
     }
 
-    fn cluster_stats_gen(&self, ctx: Arc<dyn TableContext>) -> Result<ClusterStatsGenerator> {
+    pub(crate) fn cluster_stats_gen(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<ClusterStatsGenerator> {
         if self.cluster_key_meta.is_none() {
             return Ok(ClusterStatsGenerator::default());
         }
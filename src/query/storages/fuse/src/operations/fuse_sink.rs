@@ -20,7 +20,7 @@ use common_arrow::parquet::compression::CompressionOptions;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
 use common_cache::Cache;
 use common_catalog::table_context::TableContext;
-use common_datablocks::serialize_data_blocks;
+use common_datablocks::serialize_data_blocks_with_column_compression;
 use common_datablocks::serialize_data_blocks_with_compression;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
@@ -35,6 +35,7 @@ use opendal::Operator;
 
 use super::AppendOperationLogEntry;
 use crate::io;
+use crate::io::BlockCompression;
 use crate::io::TableMetaLocationGenerator;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
@@ -43,6 +44,7 @@ use crate::pipelines::processors::Processor;
 use crate::statistics::BlockStatistics;
 use crate::statistics::ClusterStatsGenerator;
 use crate::statistics::StatisticsAccumulator;
+use crate::VirtualColumnStatsGenerator;
 
 struct BloomIndexState {
     data: Vec<u8>,
@@ -82,6 +84,9 @@ pub struct FuseTableSink {
     meta_locations: TableMetaLocationGenerator,
     accumulator: StatisticsAccumulator,
     cluster_stats_gen: ClusterStatsGenerator,
+    virtual_stats_gen: VirtualColumnStatsGenerator,
+    compression: BlockCompression,
+    index_columns: IndexColumns,
 
     // A dummy output port for distributed insert select to connect Exchange Sink.
     output: Option<Arc<OutputPort>>,
@@ -95,6 +100,9 @@ impl FuseTableSink {
         data_accessor: Operator,
         meta_locations: TableMetaLocationGenerator,
         cluster_stats_gen: ClusterStatsGenerator,
+        virtual_stats_gen: VirtualColumnStatsGenerator,
+        compression: BlockCompression,
+        index_columns: IndexColumns,
         output: Option<Arc<OutputPort>>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
@@ -106,6 +114,9 @@ impl FuseTableSink {
             accumulator: Default::default(),
             num_block_threshold: num_block_threshold as u64,
             cluster_stats_gen,
+            virtual_stats_gen,
+            compression,
+            index_columns,
             output,
         })))
     }
@@ -167,7 +178,7 @@ impl Processor for FuseTableSink {
 
                 let bloom_index_state = {
                     // write index
-                    let bloom_index = BloomFilter::try_create(&[&block])?;
+                    let bloom_index = BloomFilter::try_create(&[&block], &self.index_columns)?;
                     let index_block = bloom_index.bloom_block;
                     let location = self.meta_locations.block_bloom_index_location(&block_id);
                     let mut data = Vec::with_capacity(100 * 1024);
@@ -185,12 +196,21 @@ impl Processor for FuseTableSink {
                     }
                 };
 
-                let block_statistics =
+                let mut block_statistics =
                     BlockStatistics::from(&block, block_location.0, cluster_stats)?;
+                block_statistics
+                    .block_column_statistics
+                    .extend(self.virtual_stats_gen.gen_stats(&block));
                 // we need a configuration of block size threshold here
                 let mut data = Vec::with_capacity(100 * 1024 * 1024);
                 let schema = block.schema().clone();
-                let (size, meta_data) = serialize_data_blocks(vec![block], &schema, &mut data)?;
+                let compressions = self.compression.to_parquet_options(&schema)?;
+                let (size, meta_data) = serialize_data_blocks_with_column_compression(
+                    vec![block],
+                    &schema,
+                    &mut data,
+                    &compressions,
+                )?;
 
                 self.state = State::Serialized {
                     data,
@@ -214,7 +234,7 @@ impl Processor for FuseTableSink {
                 });
 
                 self.state = State::SerializedSegment {
-                    data: serde_json::to_vec(&segment_info)?,
+                    data: io::encode_segment(&segment_info)?,
                     location: self.meta_locations.gen_segment_info_location(),
                     segment: Arc::new(segment_info),
                 }
@@ -267,6 +287,7 @@ impl Processor for FuseTableSink {
                 self.accumulator.add_block(
                     size,
                     *meta_data,
+                    &data,
                     block_statistics,
                     Some(bloom_index_state.location),
                     bloom_filter_index_size,
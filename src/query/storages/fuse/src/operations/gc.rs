@@ -55,7 +55,8 @@ impl FuseTable {
             return Ok(());
         };
 
-        let reader = MetaReaders::table_snapshot_reader(ctx.clone());
+        let reader =
+            MetaReaders::table_snapshot_reader(ctx.clone(), self.get_operator(ctx.as_ref())?);
 
         let (prev_id, prev_ver) = if let Some((id, ver)) = last_snapshot.prev_snapshot_id {
             (id, ver)
@@ -90,7 +91,7 @@ impl FuseTable {
                 .push((last_snapshot.snapshot_id, self.snapshot_format_version()));
         }
 
-        let segments_referenced_by_gc_root: HashSet<Location> = if !keep_last_snapshot {
+        let mut segments_referenced_by_gc_root: HashSet<Location> = if !keep_last_snapshot {
             //  segment gc root references nothing;
             HashSet::new()
         } else {
@@ -98,6 +99,12 @@ impl FuseTable {
             HashSet::from_iter(last_snapshot.segments.clone())
         };
 
+        // the previous snapshots in the data retention time are kept with the last snapshot
+        let retention_cutoff = match keep_last_snapshot {
+            true => self.data_retention_cutoff()?,
+            false => None,
+        };
+
         // segments which no longer need to be kept
         let mut segments_to_be_deleted: HashSet<_> = HashSet::new();
         {
@@ -106,9 +113,25 @@ impl FuseTable {
             }
 
             // collects
-            // - all the previous snapshots
+            // - all the previous snapshots, which are not in the data retention time
             // - segments referenced by previous snapshots, but not by gc_root
+            //
+            // a snapshot is in the data retention time if it is replaced by the newer one
+            // after the cutoff, the retained snapshots (which are the newest ones) are
+            // visited first, and their segments are added to the gc_root.
+            let mut replaced_at = last_snapshot.timestamp;
             while let Some(s) = snapshot_history.try_next().await? {
+                let retained = match (&retention_cutoff, &replaced_at) {
+                    (Some(cutoff), Some(ts)) => ts >= cutoff,
+                    _ => false,
+                };
+                if retained {
+                    replaced_at = s.timestamp;
+                    segments_referenced_by_gc_root.extend(s.segments.iter().cloned());
+                    continue;
+                }
+
+                replaced_at = None;
                 snapshots_to_be_deleted.push((s.snapshot_id, s.format_version()));
                 for seg in &s.segments {
                     if !segments_referenced_by_gc_root.contains(seg) {
@@ -144,7 +167,7 @@ impl FuseTable {
         segments: impl Iterator<Item = &Location>,
     ) -> Result<HashSet<String>> {
         let mut result = HashSet::new();
-        let reader = MetaReaders::segment_info_reader(ctx);
+        let reader = MetaReaders::segment_info_reader(ctx, self.get_operator(ctx)?);
         for l in segments {
             let (segment_location, ver) = l;
             let r = reader.read(segment_location, None, *ver).await;
@@ -176,8 +199,8 @@ impl FuseTable {
         segments: impl Iterator<Item = &Location>,
         root: &HashSet<String>,
     ) -> Result<()> {
-        let reader = MetaReaders::segment_info_reader(ctx);
-        let accessor = ctx.get_storage_operator()?;
+        let accessor = self.get_operator(ctx)?;
+        let reader = MetaReaders::segment_info_reader(ctx, accessor.clone());
         for l in segments {
             let (x, ver) = l;
             let res = reader.read(x, None, *ver).await?;
@@ -236,7 +259,7 @@ impl FuseTable {
         segments_to_be_deleted: HashSet<Location>,
         snapshots_to_be_deleted: Vec<(SnapshotId, u64)>,
     ) -> Result<()> {
-        let accessor = ctx.get_storage_operator()?;
+        let accessor = self.get_operator(ctx)?;
 
         // order matters, should always remove the blocks first, segment 2nd, snapshot last,
         // so that if something goes wrong, e.g. process crashed, gc task can be "picked up" and continued
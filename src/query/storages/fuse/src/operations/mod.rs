@@ -12,9 +12,14 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod add_column;
+mod analyze;
 mod append;
+mod backup;
+mod changes;
 mod commit;
 mod compact;
+mod dedup;
 mod delete;
 mod fuse_sink;
 mod gc;
@@ -24,10 +29,15 @@ mod operation_log;
 mod read;
 mod read_partitions;
 mod recluster;
+mod replace;
 mod truncate;
+mod verify;
 
 pub mod util;
 
+pub use compact::CompactionEstimate;
+pub use dedup::DedupState;
+pub use dedup::TransformDedup;
 pub use fuse_sink::FuseTableSink;
 pub use mutation::delete_from_block;
 pub use mutation::CompactMutator;
@@ -55,14 +55,13 @@ pub struct CompactMutator {
 impl CompactMutator {
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
+        data_accessor: Operator,
         base_snapshot: Arc<TableSnapshot>,
         block_compactor: BlockCompactor,
         location_generator: TableMetaLocationGenerator,
         block_per_seg: usize,
         is_cluster: bool,
     ) -> Result<Self> {
-        let data_accessor = ctx.get_storage_operator()?;
-
         Ok(Self {
             ctx,
             base_snapshot,
@@ -97,7 +96,8 @@ impl TableMutator for CompactMutator {
         // Blocks that need to be reorganized into new segments.
         let mut remain_blocks = Vec::new();
         let mut summarys = Vec::new();
-        let reader = MetaReaders::segment_info_reader(self.ctx.as_ref());
+        let reader =
+            MetaReaders::segment_info_reader(self.ctx.as_ref(), self.data_accessor.clone());
 
         for segment_location in &snapshot.segments {
             let (x, ver) = (segment_location.0.clone(), segment_location.1);
@@ -177,10 +177,12 @@ impl TableMutator for CompactMutator {
         new_snapshot.segments.append(&mut merged_segments);
         new_snapshot.summary = merge_statistics(&self.summary, &merged_summary)?;
 
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
+        FuseTable::commit_mutation(
+            &ctx,
+            &self.data_accessor,
             table_info,
             &self.location_generator,
+            snapshot,
             new_snapshot,
         )
         .await
@@ -20,7 +20,10 @@ use common_exception::Result;
 use common_fuse_meta::meta::ClusterStatistics;
 use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::TableSnapshot;
+use opendal::Operator;
 
+use crate::index::IndexColumns;
+use crate::io::BlockCompression;
 use crate::io::BlockWriter;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::mutation::BaseMutator;
@@ -34,19 +37,27 @@ pub enum Deletion {
 pub struct DeletionMutator {
     base_mutator: BaseMutator,
     cluster_stats_gen: ClusterStatsGenerator,
+    compression: BlockCompression,
+    index_columns: IndexColumns,
 }
 
 impl DeletionMutator {
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
+        data_accessor: Operator,
         location_generator: TableMetaLocationGenerator,
         base_snapshot: Arc<TableSnapshot>,
         cluster_stats_gen: ClusterStatsGenerator,
+        compression: BlockCompression,
+        index_columns: IndexColumns,
     ) -> Result<Self> {
-        let base_mutator = BaseMutator::try_create(ctx, location_generator, base_snapshot)?;
+        let base_mutator =
+            BaseMutator::try_create(ctx, data_accessor, location_generator, base_snapshot)?;
         Ok(Self {
             base_mutator,
             cluster_stats_gen,
+            compression,
+            index_columns,
         })
     }
 
@@ -71,6 +82,8 @@ impl DeletionMutator {
             let block_writer = BlockWriter::new(
                 &self.base_mutator.data_accessor,
                 &self.base_mutator.location_generator,
+                &self.compression,
+                &self.index_columns,
             );
             let cluster_stats = self
                 .cluster_stats_gen
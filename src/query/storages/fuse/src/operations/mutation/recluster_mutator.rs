@@ -25,6 +25,7 @@ use common_fuse_meta::meta::SegmentInfo;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
 use common_meta_app::schema::TableInfo;
+use opendal::Operator;
 
 use crate::io::BlockCompactor;
 use crate::io::TableMetaLocationGenerator;
@@ -50,13 +51,15 @@ pub struct ReclusterMutator {
 impl ReclusterMutator {
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
+        data_accessor: Operator,
         location_generator: TableMetaLocationGenerator,
         base_snapshot: Arc<TableSnapshot>,
         threshold: f64,
         block_compactor: BlockCompactor,
         blocks_map: BTreeMap<i32, Vec<(usize, BlockMeta)>>,
     ) -> Result<Self> {
-        let base_mutator = BaseMutator::try_create(ctx, location_generator, base_snapshot)?;
+        let base_mutator =
+            BaseMutator::try_create(ctx, data_accessor, location_generator, base_snapshot)?;
         Ok(Self {
             base_mutator,
             blocks_map,
@@ -221,15 +224,17 @@ impl TableMutator for ReclusterMutator {
         segments.append(&mut merged_segments);
         summary = merge_statistics(&summary, &merged_summary)?;
 
+        let base_snapshot = base_mutator.base_snapshot.clone();
         let new_snapshot = base_mutator.into_new_snapshot(segments, summary).await?;
 
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
+        FuseTable::commit_mutation(
+            &ctx,
+            &self.base_mutator.data_accessor,
             table_info,
             &self.base_mutator.location_generator,
+            base_snapshot,
             new_snapshot,
         )
-        .await?;
-        Ok(())
+        .await
     }
 }
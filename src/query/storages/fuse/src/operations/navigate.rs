@@ -34,6 +34,14 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         time_point: DateTime<Utc>,
     ) -> Result<Arc<FuseTable>> {
+        if let Some(cutoff) = self.data_retention_cutoff()? {
+            if time_point < cutoff {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "time point {} is beyond the data retention time of table {}",
+                    time_point, self.table_info.desc
+                )));
+            }
+        }
         self.find(ctx, |snapshot| {
             if let Some(ts) = snapshot.timestamp {
                 ts <= time_point
@@ -43,6 +51,29 @@ impl FuseTable {
         })
         .await
     }
+    /// The table as of the time point if its current snapshot is committed after the time point,
+    /// e.g. by a concurrent insert, none if it's not.
+    pub async fn navigate_to_time_point_if_newer(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        time_point: DateTime<Utc>,
+    ) -> Result<Option<Arc<FuseTable>>> {
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+        if snapshot.timestamp.map_or(true, |ts| ts <= time_point) {
+            return Ok(None);
+        }
+        match self.navigate_to_time_point(ctx, time_point).await {
+            Ok(table) => Ok(Some(table)),
+            // The table has no snapshot of the time point, e.g. it's created after then, the
+            // current snapshot is read.
+            Err(e) if e.code() == ErrorCode::TableHistoricalDataNotFoundCode() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     pub async fn navigate_to_snapshot(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -71,7 +102,8 @@ impl FuseTable {
         };
 
         let snapshot_version = self.snapshot_format_version();
-        let reader = MetaReaders::table_snapshot_reader(ctx);
+        let operator = self.get_operator(ctx.as_ref())?;
+        let reader = MetaReaders::table_snapshot_reader(ctx, operator);
 
         // grab the table history
         // snapshots are order by timestamp DESC.
@@ -82,12 +114,28 @@ impl FuseTable {
         );
 
         // Find the instant which matches ths given `time_point`.
+        //
+        // The historical snapshots replaced before the cutoff of the data retention time
+        // are not navigable, they may have been purged.
+        let retention_cutoff = self.data_retention_cutoff()?;
         let mut instant = None;
+        let mut replaced_at = None;
+        let mut is_current = true;
         while let Some(snapshot) = snapshots.try_next().await? {
+            if let Some(cutoff) = &retention_cutoff {
+                if !is_current && replaced_at.map_or(true, |ts| ts < *cutoff) {
+                    return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                        "No historical data found in the data retention time of table {}",
+                        self.table_info.desc
+                    )));
+                }
+            }
             if pred(snapshot.as_ref()) {
                 instant = Some(snapshot);
                 break;
             }
+            replaced_at = snapshot.timestamp;
+            is_current = false;
         }
 
         if let Some(snapshot) = instant {
@@ -37,6 +37,7 @@ use common_pipeline_core::SourcePipeBuilder;
 use common_pipeline_transforms::processors::ExpressionExecutor;
 
 use crate::io::BlockReader;
+use crate::io::IOPriority;
 use crate::operations::read::State::Generated;
 use crate::FuseTable;
 
@@ -46,9 +47,10 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         projection: Projection,
     ) -> Result<Arc<BlockReader>> {
-        let operator = ctx.get_storage_operator()?;
+        let operator = self.get_operator(ctx.as_ref())?;
         let table_schema = self.table_info.schema();
-        BlockReader::create(operator, table_schema, projection)
+        let read_dictionary = ctx.get_settings().get_enable_dictionary_encoding()?;
+        BlockReader::create_with_dictionary(operator, table_schema, projection, read_dictionary)
     }
 
     pub fn projection_of_push_downs(&self, push_downs: &Option<Extras>) -> Projection {
@@ -80,10 +82,16 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
+        io_priority: IOPriority,
     ) -> Result<()> {
         let table_schema = self.table_info.schema();
         let projection = self.projection_of_push_downs(&plan.push_downs);
-        let output_reader = self.create_block_reader(&ctx, projection)?; // for deserialize output blocks
+        let create_block_reader = |projection: Projection| -> Result<Arc<BlockReader>> {
+            Ok(self
+                .create_block_reader(&ctx, projection)?
+                .with_io_priority(io_priority))
+        };
+        let output_reader = create_block_reader(projection)?; // for deserialize output blocks
 
         let (output_reader, prewhere_reader, prewhere_filter, remain_reader) =
             if let Some(prewhere) = self.prewhere_of_push_downs(&plan.push_downs) {
@@ -100,14 +108,12 @@ impl FuseTable {
                     vec![prewhere.filter.clone()],
                     false,
                 )?;
-                let output_reader =
-                    self.create_block_reader(&ctx, prewhere.output_columns.clone())?;
-                let prewhere_reader =
-                    self.create_block_reader(&ctx, prewhere.prewhere_columns.clone())?;
+                let output_reader = create_block_reader(prewhere.output_columns.clone())?;
+                let prewhere_reader = create_block_reader(prewhere.prewhere_columns.clone())?;
                 let remain_reader = if prewhere.remain_columns.is_empty() {
                     None
                 } else {
-                    Some((*self.create_block_reader(&ctx, prewhere.remain_columns)?).clone())
+                    Some((*create_block_reader(prewhere.remain_columns)?).clone())
                 };
 
                 (
@@ -160,7 +166,7 @@ enum State {
     ReadDataPrewhere(PartInfoPtr),
     ReadDataRemain(PartInfoPtr, PrewhereData),
     PrewhereFilter(PartInfoPtr, DataChunks),
-    Deserialize(PartInfoPtr, DataChunks, Option<PrewhereData>),
+    Deserialize(PartInfoPtr, DataChunks, PrewhereData),
     Generated(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -288,46 +294,34 @@ impl Processor for FuseTableSource {
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::Deserialize(part, chunks, prewhere_data) => {
-                let data_block = if let Some(PrewhereData {
+                let PrewhereData {
                     data_block: mut prewhere_blocks,
                     filter,
-                }) = prewhere_data
-                {
-                    let block = if chunks.is_empty() {
-                        prewhere_blocks
-                    } else if let Some(remain_reader) = self.remain_reader.as_ref() {
-                        let remain_block = remain_reader.deserialize(part, chunks)?;
-                        for (col, field) in remain_block
-                            .columns()
-                            .iter()
-                            .zip(remain_block.schema().fields())
-                        {
-                            prewhere_blocks =
-                                prewhere_blocks.add_column(col.clone(), field.clone())?;
-                        }
-                        prewhere_blocks
-                    } else {
-                        return Err(ErrorCode::LogicalError("It's a bug. Need remain reader"));
-                    };
-                    // the last step of prewhere
-                    let progress_values = ProgressValues {
-                        rows: block.num_rows(),
-                        bytes: block.memory_size(),
-                    };
-                    self.scan_progress.incr(&progress_values);
-                    DataBlock::filter_block(block, &filter)?
-                } else {
-                    let block = self.output_reader.deserialize(part, chunks)?;
-                    let progress_values = ProgressValues {
-                        rows: block.num_rows(),
-                        bytes: block.memory_size(),
-                    };
-                    self.scan_progress.incr(&progress_values);
-
-                    block
+                } = prewhere_data;
+                let mut progress_values = ProgressValues {
+                    rows: prewhere_blocks.num_rows(),
+                    bytes: prewhere_blocks.memory_size(),
                 };
-
-                self.generate_one_block(data_block)?;
+                prewhere_blocks = DataBlock::filter_block(prewhere_blocks, &filter)?;
+                if !chunks.is_empty() {
+                    // the last step of prewhere, the remain columns are read in the rows
+                    // kept by the filter only.
+                    let remain_reader =
+                        self.remain_reader.as_ref().as_ref().ok_or_else(|| {
+                            ErrorCode::LogicalError("It's a bug. Need remain reader")
+                        })?;
+                    let remain_block = remain_reader.deserialize_filtered(part, chunks, &filter)?;
+                    progress_values.bytes += remain_block.memory_size();
+                    for (col, field) in remain_block
+                        .columns()
+                        .iter()
+                        .zip(remain_block.schema().fields())
+                    {
+                        prewhere_blocks = prewhere_blocks.add_column(col.clone(), field.clone())?;
+                    }
+                }
+                self.scan_progress.incr(&progress_values);
+                self.generate_one_block(prewhere_blocks)?;
                 Ok(())
             }
             State::PrewhereFilter(part, chunks) => {
@@ -381,15 +375,24 @@ impl Processor for FuseTableSource {
                 if self.prewhere_filter.is_some() {
                     self.state = State::PrewhereFilter(part, chunks);
                 } else {
-                    // all needed columns are read.
-                    self.state = State::Deserialize(part, chunks, None)
+                    // all needed columns are read, they are decoded concurrently.
+                    let block = self
+                        .output_reader
+                        .deserialize_parallel(part, chunks)
+                        .await?;
+                    let progress_values = ProgressValues {
+                        rows: block.num_rows(),
+                        bytes: block.memory_size(),
+                    };
+                    self.scan_progress.incr(&progress_values);
+                    self.generate_one_block(block)?;
                 }
                 Ok(())
             }
             State::ReadDataRemain(part, prewhere_data) => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = remain_reader.read_columns_data(part.clone()).await?;
-                    self.state = State::Deserialize(part, chunks, Some(prewhere_data));
+                    self.state = State::Deserialize(part, chunks, prewhere_data);
                     Ok(())
                 } else {
                     return Err(ErrorCode::LogicalError("It's a bug. No remain reader"));
@@ -46,6 +46,9 @@ impl FuseTable {
                 }
 
                 let block_metas = BlockPruner::new(snapshot.clone())
+                    .with_virtual_columns(self.virtual_columns.clone())
+                    .with_index_columns(self.get_index_columns())
+                    .with_operator(self.get_operator(ctx.as_ref())?)
                     .prune(&ctx, self.table_info.schema(), &push_downs)
                     .await?
                     .into_iter()
@@ -199,7 +202,15 @@ impl FuseTable {
         for (idx, column_meta) in &meta.col_metas {
             columns_meta.insert(
                 *idx as usize,
-                ColumnMeta::create(column_meta.offset, column_meta.len, column_meta.num_values),
+                ColumnMeta::create(
+                    column_meta.offset,
+                    column_meta.len,
+                    column_meta.num_values,
+                    column_meta
+                        .compression
+                        .unwrap_or_else(|| meta.compression()),
+                    column_meta.checksum,
+                ),
             );
         }
 
@@ -230,7 +241,15 @@ impl FuseTable {
 
                 columns_meta.insert(
                     *index,
-                    ColumnMeta::create(column_meta.offset, column_meta.len, column_meta.num_values),
+                    ColumnMeta::create(
+                        column_meta.offset,
+                        column_meta.len,
+                        column_meta.num_values,
+                        column_meta
+                            .compression
+                            .unwrap_or_else(|| meta.compression()),
+                        column_meta.checksum,
+                    ),
                 );
             }
         }
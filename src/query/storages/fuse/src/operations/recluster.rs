@@ -31,6 +31,7 @@ use common_pipeline_transforms::processors::transforms::TransformCompact;
 use common_pipeline_transforms::processors::transforms::TransformSortMerge;
 use common_pipeline_transforms::processors::transforms::TransformSortPartial;
 
+use crate::io::IOPriority;
 use crate::operations::FuseTableSink;
 use crate::operations::ReclusterMutator;
 use crate::pruning::BlockPruner;
@@ -62,6 +63,7 @@ impl FuseTable {
 
         let schema = self.table_info.schema();
         let block_metas = BlockPruner::new(snapshot.clone())
+            .with_operator(self.get_operator(ctx.as_ref())?)
             .prune(&ctx, schema, &push_downs)
             .await?;
 
@@ -93,6 +95,7 @@ impl FuseTable {
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
         let mut mutator = ReclusterMutator::try_create(
             ctx.clone(),
+            self.get_operator(ctx.as_ref())?,
             self.meta_location_generator.clone(),
             snapshot,
             threshold,
@@ -126,7 +129,7 @@ impl FuseTable {
         };
 
         ctx.try_set_partitions(plan.parts.clone())?;
-        self.do_read2(ctx.clone(), &plan, pipeline)?;
+        self.do_read2(ctx.clone(), &plan, pipeline, IOPriority::Background)?;
 
         let cluster_stats_gen = self.get_cluster_stats_gen(
             ctx.clone(),
@@ -177,7 +180,10 @@ impl FuseTable {
             )
         })?;
 
-        let da = ctx.get_storage_operator()?;
+        let compression = self.get_block_compression()?;
+        let index_columns = self.get_index_columns();
+        let virtual_stats_gen = self.get_virtual_column_stats_gen(ctx.clone())?;
+        let da = self.get_operator(ctx.as_ref())?;
         let mut sink_pipeline_builder = SinkPipeBuilder::create();
         for _ in 0..pipeline.output_len() {
             let input_port = InputPort::create();
@@ -190,6 +196,9 @@ impl FuseTable {
                     da.clone(),
                     self.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
+                    virtual_stats_gen.clone(),
+                    compression.clone(),
+                    index_columns.clone(),
                     None,
                 )?,
             );
@@ -0,0 +1,227 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_base::base::ProgressValues;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_fuse_meta::meta::SegmentInfo;
+use common_fuse_meta::meta::Versioned;
+use common_legacy_expression::col;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_planners::Extras;
+use common_legacy_planners::Projection;
+
+use crate::operations::mutation::block_filter::all_the_columns_ids;
+use crate::operations::AppendOperationLogEntry;
+use crate::operations::DeletionMutator;
+use crate::pruning::BlockPruner;
+use crate::statistics;
+use crate::FuseTable;
+
+// The blocks of the conflicting rows are pruned by the point queries of the keys, by the bloom
+// filters as well as by the ranges of the blocks, if the keys are not too many. Otherwise they
+// are pruned by the ranges of the keys only.
+const MAX_POINT_QUERY_KEYS: usize = 1000;
+
+pub(super) type ConflictKey = Vec<DataValue>;
+
+impl FuseTable {
+    /// Commits the segments appended, and deletes the rows of the table with the same values of
+    /// `on_conflict_fields` as the rows appended, in one snapshot.
+    pub async fn do_replace(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        operation_log: Vec<AppendOperationLogEntry>,
+        on_conflict_fields: &[String],
+    ) -> Result<()> {
+        let base_snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) if snapshot.summary.row_count != 0 => snapshot,
+            // nothing to replace
+            _ => return self.do_commit(ctx, operation_log, false).await,
+        };
+
+        // the keys of the rows appended
+        let schema = self.table_info.schema();
+        let key_indices = on_conflict_fields
+            .iter()
+            .map(|name| schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        let key_reader = self.create_block_reader(&ctx, Projection::Columns(key_indices))?;
+        let mut keys = HashSet::new();
+        for entry in &operation_log {
+            for block_meta in &entry.segment_info.blocks {
+                let block = key_reader.read_with_block_meta(block_meta).await?;
+                keys.extend(
+                    conflict_keys(&block, on_conflict_fields)?
+                        .into_iter()
+                        .flatten(),
+                );
+            }
+        }
+        if keys.is_empty() {
+            return self.do_commit(ctx, operation_log, false).await;
+        }
+
+        let operator = self.get_operator(ctx.as_ref())?;
+        let push_downs = Some(Extras {
+            projection: None,
+            filters: vec![conflict_filter(on_conflict_fields, &keys)],
+            prewhere: None,
+            limit: None,
+            order_by: vec![],
+        });
+        let block_metas = BlockPruner::new(base_snapshot.clone())
+            .with_virtual_columns(self.virtual_columns.clone())
+            .with_index_columns(self.get_index_columns())
+            .with_operator(operator.clone())
+            .prune(&ctx, schema, &push_downs)
+            .await?;
+
+        // delete the conflicting rows block by block
+        let mut deletion_collector = DeletionMutator::try_create(
+            ctx.clone(),
+            operator.clone(),
+            self.meta_location_generator.clone(),
+            base_snapshot.clone(),
+            self.cluster_stats_gen(ctx.clone())?,
+            self.get_block_compression()?,
+            self.get_index_columns(),
+        )?;
+        let block_reader =
+            self.create_block_reader(&ctx, Projection::Columns(all_the_columns_ids(self)))?;
+        for (seg_idx, block_meta) in block_metas {
+            let block = block_reader.read_with_block_meta(&block_meta).await?;
+            let remains = conflict_keys(&block, on_conflict_fields)?
+                .into_iter()
+                .map(|key| !matches!(key, Some(key) if keys.contains(&key)))
+                .collect::<Vec<_>>();
+            if remains.iter().all(|remain| *remain) {
+                // false positive, we should keep the whole block
+                continue;
+            }
+            let filter = BooleanColumn::from_owned_iterator(remains.into_iter());
+            let block = DataBlock::filter_block_with_bool_column(block, &filter)?;
+            deletion_collector
+                .replace_with(
+                    seg_idx,
+                    block_meta.location.clone(),
+                    block_meta.cluster_stats.clone(),
+                    block,
+                )
+                .await?;
+        }
+
+        // the segments appended are put before the segments of the base snapshot, as appending
+        let mut new_snapshot = deletion_collector.into_new_snapshot().await?;
+        let (segments, summary) = Self::merge_append_operations(&operation_log)?;
+        ctx.get_write_progress().incr(&ProgressValues {
+            rows: summary.row_count as usize,
+            bytes: summary.uncompressed_byte_size as usize,
+        });
+        new_snapshot.segments = segments
+            .into_iter()
+            .map(|loc| (loc, SegmentInfo::VERSION))
+            .chain(std::mem::take(&mut new_snapshot.segments))
+            .collect();
+        new_snapshot.summary = statistics::merge_statistics(&summary, &new_snapshot.summary)?;
+
+        Self::commit_mutation(
+            &ctx,
+            &operator,
+            self.get_table_info(),
+            &self.meta_location_generator,
+            base_snapshot,
+            new_snapshot,
+        )
+        .await
+    }
+}
+
+// The keys of the rows of the block, none for the rows with NULL keys, which never conflict.
+pub(super) fn conflict_keys(
+    block: &DataBlock,
+    fields: &[String],
+) -> Result<Vec<Option<ConflictKey>>> {
+    let columns = fields
+        .iter()
+        .map(|name| block.try_column_by_name(name))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((0..block.num_rows())
+        .map(|row| {
+            let key = columns
+                .iter()
+                .map(|column| column.get(row))
+                .collect::<ConflictKey>();
+            match key.iter().any(|value| value.is_null()) {
+                true => None,
+                false => Some(key),
+            }
+        })
+        .collect())
+}
+
+pub(super) fn conflict_filter(fields: &[String], keys: &HashSet<ConflictKey>) -> LegacyExpression {
+    if keys.len() <= MAX_POINT_QUERY_KEYS {
+        let points = keys
+            .iter()
+            .map(|key| {
+                let equations = fields
+                    .iter()
+                    .zip(key)
+                    .map(|(name, value)| {
+                        col(name).eq(LegacyExpression::create_literal(value.clone()))
+                    })
+                    .collect();
+                balanced_tree(equations, LegacyExpression::and)
+            })
+            .collect();
+        balanced_tree(points, LegacyExpression::or)
+    } else {
+        let ranges = fields
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let min = keys.iter().map(|key| &key[i]).min().unwrap();
+                let max = keys.iter().map(|key| &key[i]).max().unwrap();
+                col(name)
+                    .gt_eq(LegacyExpression::create_literal(min.clone()))
+                    .and(col(name).lt_eq(LegacyExpression::create_literal(max.clone())))
+            })
+            .collect();
+        balanced_tree(ranges, LegacyExpression::and)
+    }
+}
+
+// Combines the expressions as a balanced tree, which is not too deep to evaluate recursively.
+fn balanced_tree(
+    mut exprs: Vec<LegacyExpression>,
+    combine: fn(&LegacyExpression, LegacyExpression) -> LegacyExpression,
+) -> LegacyExpression {
+    while exprs.len() > 1 {
+        exprs = exprs
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => combine(left, right.clone()),
+                _ => pair[0].clone(),
+            })
+            .collect();
+    }
+    exprs.pop().unwrap()
+}
@@ -14,13 +14,21 @@
 
 use std::collections::HashMap;
 
+use common_arrow::parquet::compression::Compression as ParquetCompression;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::ColumnId;
 use common_fuse_meta::meta::ColumnMeta;
+use common_fuse_meta::meta::Compression;
 
-pub fn column_metas(file_meta: &ThriftFileMetaData) -> Result<HashMap<ColumnId, ColumnMeta>> {
+use crate::io::checksum;
+
+/// The metas of the columns of the parquet file `data`, with the checksums of the column chunks.
+pub fn column_metas(
+    file_meta: &ThriftFileMetaData,
+    data: &[u8],
+) -> Result<HashMap<ColumnId, ColumnMeta>> {
     // currently we use one group only
     let num_row_groups = file_meta.row_groups.len();
     if num_row_groups != 1 {
@@ -45,10 +53,19 @@ pub fn column_metas(file_meta: &ThriftFileMetaData) -> Result<HashMap<ColumnId,
                     "column start and length should not be negative"
                 );
                 let num_values = chunk_meta.num_values as u64;
+                let compression = ParquetCompression::try_from(chunk_meta.codec).map_err(|e| {
+                    ErrorCode::ParquetError(format!("unrecognized compression. {} ", e))
+                })?;
+                let (offset, len) = (col_start as u64, col_len as u64);
+                let chunk_checksum = data
+                    .get(offset as usize..(offset + len) as usize)
+                    .map(checksum);
                 let res = ColumnMeta {
-                    offset: col_start as u64,
-                    len: col_len as u64,
+                    offset,
+                    len,
                     num_values,
+                    compression: Some(to_meta_compression(compression)?),
+                    checksum: chunk_checksum,
                 };
                 col_metas.insert(idx as u32, res);
             }
@@ -62,3 +79,17 @@ pub fn column_metas(file_meta: &ThriftFileMetaData) -> Result<HashMap<ColumnId,
     }
     Ok(col_metas)
 }
+
+fn to_meta_compression(compression: ParquetCompression) -> Result<Compression> {
+    match compression {
+        ParquetCompression::Lz4Raw => Ok(Compression::Lz4Raw),
+        ParquetCompression::Snappy => Ok(Compression::Snappy),
+        ParquetCompression::Zstd => Ok(Compression::Zstd),
+        ParquetCompression::Gzip => Ok(Compression::Gzip),
+        ParquetCompression::Uncompressed => Ok(Compression::Uncompressed),
+        other => Err(ErrorCode::ParquetError(format!(
+            "unsupported compression of blocks: {:?}",
+            other
+        ))),
+    }
+}
@@ -0,0 +1,107 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::BlockMeta;
+use common_fuse_meta::meta::SegmentInfo;
+use common_fuse_meta::meta::SegmentInfoVersion;
+use opendal::Operator;
+
+use crate::io::column_chunk_key;
+use crate::io::decode_segment;
+use crate::io::verify_checksum;
+use crate::io::VersionedReader;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Reads all the segments and blocks of the current snapshot from the storage, bypassing
+    /// the caches, and verifies them by their checksums.
+    ///
+    /// Returns the keys of the corrupted objects with the causes, the objects written before
+    /// the checksums are only checked to be readable.
+    pub async fn do_verify(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<(String, String)>> {
+        let snapshot = match self.read_table_snapshot(ctx.clone()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(vec![]),
+        };
+
+        let operator = self.get_operator(ctx.as_ref())?;
+        let mut corrupted = vec![];
+        for (location, version) in &snapshot.segments {
+            let segment = match Self::verify_segment(&operator, location, *version).await {
+                Ok(segment) => segment,
+                Err(cause) => {
+                    corrupted.push((location.clone(), cause.message()));
+                    continue;
+                }
+            };
+            for block_meta in &segment.blocks {
+                corrupted.extend(Self::verify_block(&operator, block_meta).await);
+            }
+        }
+        Ok(corrupted)
+    }
+
+    async fn verify_segment(
+        operator: &Operator,
+        location: &str,
+        version: u64,
+    ) -> Result<SegmentInfo> {
+        let data = operator.object(location).read().await?;
+        let json = decode_segment(location, &data)?;
+        SegmentInfoVersion::try_from(version)?.read(json).await
+    }
+
+    async fn verify_block(operator: &Operator, block_meta: &BlockMeta) -> Vec<(String, String)> {
+        let location = &block_meta.location.0;
+        let data = match operator.object(location).read().await {
+            Ok(data) => data,
+            Err(cause) => return vec![(location.clone(), ErrorCode::from(cause).message())],
+        };
+
+        let mut col_metas = block_meta.col_metas.iter().collect::<Vec<_>>();
+        col_metas.sort_by_key(|(id, _)| **id);
+        let mut corrupted = vec![];
+        for (id, col_meta) in col_metas {
+            let key = column_chunk_key(location, *id as usize);
+            let (start, end) = (
+                col_meta.offset as usize,
+                (col_meta.offset + col_meta.len) as usize,
+            );
+            let chunk = match data.get(start..end) {
+                Some(chunk) => chunk,
+                None => {
+                    corrupted.push((
+                        key,
+                        format!(
+                            "object {} is truncated, expect {} bytes at least",
+                            location, end
+                        ),
+                    ));
+                    continue;
+                }
+            };
+            if let Some(checksum) = col_meta.checksum {
+                if let Err(cause) = verify_checksum(&key, chunk, checksum) {
+                    corrupted.push((key, cause.message()));
+                }
+            }
+        }
+        corrupted
+    }
+}
@@ -23,6 +23,7 @@ use common_legacy_expression::ExpressionVisitor;
 use common_legacy_expression::LegacyExpression;
 use common_legacy_expression::Recursion;
 use common_storages_index::BloomFilter;
+use common_storages_index::InvertedIndex;
 use opendal::Operator;
 
 use crate::io::BlockBloomFilterIndexReader;
@@ -107,10 +108,14 @@ impl BloomFilterPruner for BloomFilterIndexPruner {
 /// if `filter_expr` is none, or is not applicable, e.g. have no point queries
 /// a [NonPruner] will be return, which prunes nothing.
 /// otherwise, a [BloomFilterIndexer] backed pruner will be return
+///
+/// the `match()` and `query()` of the `inverted_index_columns` are also applicable, which are
+/// pruned by the inverted indexes kept in the bloom filter block.
 pub fn new_bloom_filter_pruner(
     ctx: &Arc<dyn TableContext>,
     filter_exprs: Option<&[LegacyExpression]>,
     schema: &DataSchemaRef,
+    inverted_index_columns: &[String],
     dal: Operator,
 ) -> Result<Arc<dyn BloomFilterPruner + Send + Sync>> {
     if let Some(exprs) = filter_exprs {
@@ -126,13 +131,19 @@ pub fn new_bloom_filter_pruner(
             })
             .unwrap();
 
-        let point_query_cols = columns_names_of_eq_expressions(&expr)?;
-        if !point_query_cols.is_empty() {
-            // convert to bloom filter block's column names
-            let filter_block_cols = point_query_cols
-                .into_iter()
-                .map(|n| BloomFilter::to_bloom_column_name(&n))
-                .collect();
+        let (point_query_cols, text_match_cols) = columns_names_of_eq_expressions(&expr)?;
+        // convert to bloom filter block's column names
+        let filter_block_cols = point_query_cols
+            .into_iter()
+            .map(|n| BloomFilter::to_bloom_column_name(&n))
+            .chain(
+                text_match_cols
+                    .into_iter()
+                    .filter(|n| inverted_index_columns.contains(n))
+                    .map(|n| InvertedIndex::to_inverted_column_name(&n)),
+            )
+            .collect::<Vec<_>>();
+        if !filter_block_cols.is_empty() {
             return Ok(Arc::new(BloomFilterIndexPruner::new(
                 ctx.clone(),
                 filter_block_cols,
@@ -185,6 +196,8 @@ mod util {
     struct PointQueryVisitor {
         // names of columns which used by point query kept here
         columns: HashSet<String>,
+        // names of columns which used by `match()` and `query()` kept here
+        text_match_columns: HashSet<String>,
     }
 
     impl ExpressionVisitor for PointQueryVisitor {
@@ -203,18 +216,37 @@ mod util {
                         _ => Ok(Recursion::Continue(self)),
                     }
                 }
+                LegacyExpression::ScalarFunction { op, args }
+                    if matches!(op.to_lowercase().as_str(), "match" | "query")
+                        && args.len() == 2 =>
+                {
+                    match (&args[0], &args[1]) {
+                        (LegacyExpression::Column(column), LegacyExpression::Literal { .. }) => {
+                            self.text_match_columns.insert(column.clone());
+                            Ok(Recursion::Stop(self))
+                        }
+                        _ => Ok(Recursion::Continue(self)),
+                    }
+                }
                 _ => Ok(Recursion::Continue(self)),
             }
         }
     }
 
-    pub fn columns_names_of_eq_expressions(filter_expr: &LegacyExpression) -> Result<Vec<String>> {
+    /// Returns the names of the columns of the point queries, and of the text matches.
+    pub fn columns_names_of_eq_expressions(
+        filter_expr: &LegacyExpression,
+    ) -> Result<(Vec<String>, Vec<String>)> {
         let visitor = PointQueryVisitor {
             columns: HashSet::new(),
+            text_match_columns: HashSet::new(),
         };
 
-        filter_expr
-            .accept(visitor)
-            .map(|r| r.columns.into_iter().collect())
+        filter_expr.accept(visitor).map(|r| {
+            (
+                r.columns.into_iter().collect(),
+                r.text_match_columns.into_iter().collect(),
+            )
+        })
     }
 }
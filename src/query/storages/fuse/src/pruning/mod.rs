@@ -17,5 +17,6 @@ mod limiter;
 mod pruning_executor;
 mod range_pruner;
 mod topn_pruner;
+mod vector_pruner;
 
 pub use pruning_executor::BlockPruner;
@@ -28,23 +28,57 @@ use common_legacy_planners::Extras;
 use futures::future;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use opendal::Operator;
 use tracing::Instrument;
 
 use super::bloom_pruner;
+use crate::index::IndexColumns;
 use crate::io::MetaReaders;
 use crate::pruning::limiter;
 use crate::pruning::range_pruner;
 use crate::pruning::topn_pruner;
+use crate::pruning::vector_pruner;
+use crate::VirtualColumns;
 
 pub struct BlockPruner {
     table_snapshot: Arc<TableSnapshot>,
+    virtual_columns: VirtualColumns,
+    index_columns: IndexColumns,
+    operator: Option<Operator>,
 }
 
 const FUTURE_BUFFER_SIZE: usize = 10;
 
 impl BlockPruner {
     pub fn new(table_snapshot: Arc<TableSnapshot>) -> Self {
-        Self { table_snapshot }
+        Self {
+            table_snapshot,
+            virtual_columns: VirtualColumns::default(),
+            index_columns: IndexColumns::default(),
+            operator: None,
+        }
+    }
+
+    /// Prunes the blocks also by the statistics of the virtual columns, if the paths of
+    /// them are used by the filters.
+    pub fn with_virtual_columns(mut self, virtual_columns: VirtualColumns) -> Self {
+        self.virtual_columns = virtual_columns;
+        self
+    }
+
+    /// Prunes the blocks also by the inverted indexes of the columns, if the `match()` or
+    /// `query()` of them are used by the filters, and by the vector indexes of the columns, if
+    /// the nearest neighbors of them are searched.
+    pub fn with_index_columns(mut self, index_columns: IndexColumns) -> Self {
+        self.index_columns = index_columns;
+        self
+    }
+
+    /// Reads the segments and the bloom indexes by the operator of the storage of the
+    /// table, the storage of the query is used if not set.
+    pub fn with_operator(mut self, operator: Operator) -> Self {
+        self.operator = Some(operator);
+        self
     }
 
     // Sync version of method `prune`
@@ -83,6 +117,11 @@ impl BlockPruner {
 
         let filter_expressions = push_down.as_ref().map(|extra| extra.filters.as_slice());
 
+        let dal = match &self.operator {
+            Some(operator) => operator.clone(),
+            None => ctx.get_storage_operator()?,
+        };
+
         let is_filter_empty = match filter_expressions {
             None => true,
             Some(filters) => filters.is_empty(),
@@ -91,7 +130,7 @@ impl BlockPruner {
         // shortcut, just returns all the blocks.
         // we use the original limit (from push_down) here, since order_by alone, can use shortcut
         if push_down.as_ref().and_then(|p| p.limit).is_none() && is_filter_empty {
-            return Self::all_the_blocks(segment_locs, ctx.as_ref()).await;
+            return Self::all_the_blocks(segment_locs, ctx.as_ref(), dal).await;
         }
 
         // 1. prepare pruners
@@ -101,13 +140,21 @@ impl BlockPruner {
 
         // prepare the range filter.
         // if filter_expression is none, an dummy pruner will be returned, which prunes nothing
-        let range_filter_pruner =
-            range_pruner::new_range_filter_pruner(ctx, filter_expressions, &schema)?;
+        let range_filter_pruner = range_pruner::new_range_filter_pruner(
+            ctx,
+            filter_expressions,
+            &schema,
+            &self.virtual_columns,
+        )?;
 
         // prepare the bloom filter, if filter_expression is none, an dummy pruner will be returned
-        let dal = ctx.get_storage_operator()?;
-        let bloom_filter_pruner =
-            bloom_pruner::new_bloom_filter_pruner(ctx, filter_expressions, &schema, dal)?;
+        let bloom_filter_pruner = bloom_pruner::new_bloom_filter_pruner(
+            ctx,
+            filter_expressions,
+            &schema,
+            &self.index_columns.inverted,
+            dal.clone(),
+        )?;
 
         // 2. kick off
         //
@@ -128,6 +175,7 @@ impl BlockPruner {
         let mut join_handlers = Vec::with_capacity(segment_locs.len());
         for (idx, (seg_loc, ver)) in segment_locs.into_iter().enumerate() {
             let ctx = ctx.clone();
+            let dal = dal.clone();
             let range_filter_pruner = range_filter_pruner.clone();
             let bloom_filter_pruner = bloom_filter_pruner.clone();
             let limiter = limiter.clone();
@@ -136,7 +184,7 @@ impl BlockPruner {
                 let _permit = semaphore.acquire().await.map_err(|e| {
                     ErrorCode::StorageOther(format!("acquire permit failure, {}", e))
                 })?;
-                let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+                let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref(), dal);
                 if limiter.exceeded() {
                     // before read segment info, check if limit already exceeded
                     return Ok(vec![]);
@@ -206,8 +254,23 @@ impl BlockPruner {
             let push_down = push_down.as_ref().unwrap();
             let limit = push_down.limit.unwrap();
             let sort = push_down.order_by.clone();
-            let tpruner = topn_pruner::TopNPrunner::new(schema, sort, limit);
-            return tpruner.prune(metas);
+            let tpruner = topn_pruner::TopNPrunner::new(schema.clone(), sort, limit);
+            let metas = tpruner.prune(metas)?;
+
+            // if the ordering is the distance to a vector, use the vector pruner
+            if is_filter_empty && push_down.prewhere.is_none() {
+                if let Some(vpruner) = vector_pruner::VectorPruner::try_create(
+                    ctx,
+                    &push_down.order_by,
+                    limit,
+                    &self.index_columns.vector,
+                    schema,
+                    dal,
+                )? {
+                    return vpruner.prune(metas).await;
+                }
+            }
+            return Ok(metas);
         }
 
         Ok(metas)
@@ -216,19 +279,23 @@ impl BlockPruner {
     async fn all_the_blocks(
         segment_locs: Vec<Location>,
         ctx: &dyn TableContext,
+        dal: Operator,
     ) -> Result<Vec<(usize, BlockMeta)>> {
         let segment_num = segment_locs.len();
         let block_metas = futures::stream::iter(segment_locs.into_iter().enumerate())
-            .map(|(idx, (seg_loc, ver))| async move {
-                let segment_reader = MetaReaders::segment_info_reader(ctx);
-                let segment_info = segment_reader.read(seg_loc, None, ver).await?;
-                Ok::<_, ErrorCode>(
-                    segment_info
-                        .blocks
-                        .clone()
-                        .into_iter()
-                        .map(move |item| (idx, item)),
-                )
+            .map(|(idx, (seg_loc, ver))| {
+                let dal = dal.clone();
+                async move {
+                    let segment_reader = MetaReaders::segment_info_reader(ctx, dal);
+                    let segment_info = segment_reader.read(seg_loc, None, ver).await?;
+                    Ok::<_, ErrorCode>(
+                        segment_info
+                            .blocks
+                            .clone()
+                            .into_iter()
+                            .map(move |item| (idx, item)),
+                    )
+                }
             })
             .buffered(std::cmp::min(FUTURE_BUFFER_SIZE, segment_num))
             .try_collect::<Vec<_>>()
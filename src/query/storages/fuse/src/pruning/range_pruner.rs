@@ -17,10 +17,13 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
+use common_fuse_meta::meta::ColumnId;
 use common_fuse_meta::meta::StatisticsOfColumns;
 use common_legacy_expression::LegacyExpression;
 use common_storages_index::RangeFilter;
 
+use crate::VirtualColumns;
+
 pub trait RangeFilterPruner {
     // returns ture, if target should NOT be pruned (false positive allowed)
     fn should_keep(&self, input: &StatisticsOfColumns, row_count: u64) -> bool;
@@ -55,13 +58,46 @@ impl RangeFilterPruner for RangeFilter {
     }
 }
 
+// The range filter of the filters with the paths of the virtual columns, the statistics
+// of the virtual columns are moved to the indexes of them in the schema of the filter.
+struct VirtualColumnRangeFilter {
+    range_filter: RangeFilter,
+    column_ids: Vec<(ColumnId, ColumnId)>,
+}
+
+impl RangeFilterPruner for VirtualColumnRangeFilter {
+    fn should_keep(&self, stats: &StatisticsOfColumns, row_count: u64) -> bool {
+        let mut stats = stats.clone();
+        for (id, index) in &self.column_ids {
+            match stats.remove(id) {
+                Some(stat) => {
+                    stats.insert(*index, stat);
+                }
+                // the block is written before the virtual column is added
+                None => return true,
+            }
+        }
+        self.range_filter.should_keep(&stats, row_count)
+    }
+}
+
 pub fn new_range_filter_pruner<'a>(
     ctx: &Arc<dyn TableContext>,
     filter_expr: Option<&'a [LegacyExpression]>,
     schema: &'a DataSchemaRef,
+    virtual_columns: &VirtualColumns,
 ) -> Result<Arc<dyn RangeFilterPruner + Send + Sync>> {
     Ok(match filter_expr {
         Some(exprs) if !exprs.is_empty() => {
+            if let Some(filters) = virtual_columns.rewrite_filters(exprs, schema) {
+                let range_filter =
+                    RangeFilter::try_create(ctx.clone(), &filters.filters, filters.schema)?;
+                return Ok(Arc::new(VirtualColumnRangeFilter {
+                    range_filter,
+                    column_ids: filters.column_ids,
+                }));
+            }
+
             let range_filter = RangeFilter::try_create(ctx.clone(), exprs, schema.clone())?;
             match range_filter.try_eval_const() {
                 Ok(v) => {
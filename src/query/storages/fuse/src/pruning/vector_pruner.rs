@@ -0,0 +1,189 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_functions::scalars::VectorDistance;
+use common_fuse_meta::meta::BlockMeta;
+use common_legacy_expression::LegacyExpression;
+use common_storages_index::BloomFilter;
+use common_storages_index::VectorBound;
+use common_storages_index::VectorIndex;
+use futures::StreamExt;
+use opendal::Operator;
+
+use crate::io::BlockBloomFilterIndexReader;
+
+const FUTURE_BUFFER_SIZE: usize = 10;
+
+/// Prunes the blocks of the nearest neighbor search, like
+/// `ORDER BY cosine_distance(embedding, [0.1, 0.2]) LIMIT 10`, by the vector indexes.
+///
+/// The clusters of the indexes bound the distances of their vectors, the threshold is the least
+/// upper bound of the clusters having enough vectors, and the blocks of which all the clusters
+/// are farther than the threshold are pruned.
+pub(crate) struct VectorPruner {
+    ctx: Arc<dyn TableContext>,
+    column: String,
+    distance: VectorDistance,
+    query: Vec<f64>,
+    limit: usize,
+    schema: DataSchemaRef,
+    dal: Operator,
+}
+
+impl VectorPruner {
+    /// Returns none if the ordering is not the ascending distance from a column of the vector
+    /// index to a constant vector.
+    pub(crate) fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        order_by: &[LegacyExpression],
+        limit: usize,
+        vector_index_columns: &[String],
+        schema: DataSchemaRef,
+        dal: Operator,
+    ) -> Result<Option<Self>> {
+        let expr = match order_by {
+            [
+                LegacyExpression::Sort {
+                    expr, asc: true, ..
+                },
+            ] => expr,
+            _ => return Ok(None),
+        };
+        let (distance, args) = match expr.as_ref() {
+            LegacyExpression::ScalarFunction { op, args } => match VectorDistance::from_name(op) {
+                Some(distance) => (distance, args),
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let (column, values) = match args.as_slice() {
+            [
+                LegacyExpression::Column(column),
+                LegacyExpression::Literal {
+                    value: DataValue::Array(values),
+                    ..
+                },
+            ]
+            | [
+                LegacyExpression::Literal {
+                    value: DataValue::Array(values),
+                    ..
+                },
+                LegacyExpression::Column(column),
+            ] => (column, values),
+            _ => return Ok(None),
+        };
+        if !vector_index_columns.contains(column) {
+            return Ok(None);
+        }
+        let query = match values
+            .iter()
+            .map(|v| v.as_f64())
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(query) => query,
+            Err(_) => return Ok(None),
+        };
+
+        Ok(Some(Self {
+            ctx: ctx.clone(),
+            column: column.clone(),
+            distance,
+            query,
+            limit,
+            schema,
+            dal,
+        }))
+    }
+
+    pub(crate) async fn prune(
+        &self,
+        metas: Vec<(usize, BlockMeta)>,
+    ) -> Result<Vec<(usize, BlockMeta)>> {
+        let index_columns = vec![VectorIndex::to_vector_column_name(&self.column)];
+        let bounds = futures::stream::iter(metas.iter())
+            .map(|(_, block_meta)| self.block_bounds(block_meta, &index_columns))
+            .buffered(FUTURE_BUFFER_SIZE)
+            .collect::<Vec<_>>()
+            .await;
+
+        // The least upper bound of the nearest clusters having enough vectors.
+        let mut uppers = bounds
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|bound| (bound.upper, bound.count))
+            .collect::<Vec<_>>();
+        uppers.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut count = 0;
+        let mut threshold = None;
+        for (upper, n) in uppers {
+            count += n;
+            if count >= self.limit as u64 {
+                threshold = Some(upper);
+                break;
+            }
+        }
+        let threshold = match threshold {
+            Some(threshold) => threshold,
+            None => return Ok(metas),
+        };
+
+        Ok(metas
+            .into_iter()
+            .zip(bounds)
+            .filter(|(_, bounds)| match bounds {
+                Some(bounds) => bounds.iter().any(|bound| bound.lower <= threshold),
+                None => true,
+            })
+            .map(|(meta, _)| meta)
+            .collect())
+    }
+
+    // The bounds of the clusters of the block, none if the block has no vector index or the
+    // index is unbounded, then the block is always kept.
+    async fn block_bounds(
+        &self,
+        block_meta: &BlockMeta,
+        index_columns: &[String],
+    ) -> Option<Vec<VectorBound>> {
+        let location = block_meta.bloom_filter_index_location.as_ref()?;
+        let vector_index = location
+            .read_bloom_filter_index(
+                self.ctx.clone(),
+                self.dal.clone(),
+                index_columns,
+                block_meta.bloom_filter_index_size,
+            )
+            .await
+            .and_then(|index| {
+                BloomFilter::from_bloom_block(self.schema.clone(), index.into_data())?
+                    .try_get_vector_index(&self.column)
+            });
+        match vector_index {
+            Ok(vector_index) => vector_index?.bounds(self.distance, &self.query),
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                tracing::warn!("failed to apply vector index, keeping the block. {}", e);
+                None
+            }
+        }
+    }
+}
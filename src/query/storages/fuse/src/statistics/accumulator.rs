@@ -46,11 +46,12 @@ impl StatisticsAccumulator {
         &mut self,
         file_size: u64,
         file_meta: ThriftFileMetaData,
+        data: &[u8],
         block_statistics: BlockStatistics,
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
     ) -> Result<()> {
-        let col_metas = column_metas(&file_meta)?;
+        let col_metas = column_metas(&file_meta, data)?;
         self.add(
             file_size,
             col_metas,
@@ -13,16 +13,22 @@
 //  limitations under the License.
 
 use common_datablocks::DataBlock;
+use common_datavalues::ColumnRef;
 use common_datavalues::ColumnWithField;
 use common_datavalues::DataField;
 use common_datavalues::DataValue;
 use common_exception::Result;
 use common_functions::aggregates::eval_aggr;
 use common_fuse_meta::meta::ColumnStatistics;
+use common_fuse_meta::meta::Histogram;
+use common_fuse_meta::meta::HistogramBucket;
 use common_fuse_meta::meta::StatisticsOfColumns;
 use common_storages_index::MinMaxIndex;
 use common_storages_index::SupportedType;
 
+use crate::HISTOGRAM_MAX_BUCKETS;
+use crate::HISTOGRAM_SAMPLE_SIZE;
+
 pub fn gen_columns_statistics(data_block: &DataBlock) -> Result<StatisticsOfColumns> {
     let mut statistics = StatisticsOfColumns::new();
 
@@ -43,7 +49,8 @@ pub fn gen_columns_statistics(data_block: &DataBlock) -> Result<StatisticsOfColu
         let rows = col.len();
 
         let mins = eval_aggr("min", vec![], &[column_field.clone()], rows)?;
-        let maxs = eval_aggr("max", vec![], &[column_field], rows)?;
+        let maxs = eval_aggr("max", vec![], &[column_field.clone()], rows)?;
+        let distinct = eval_aggr("uniq", vec![], &[column_field], rows)?;
 
         if mins.len() > 0 {
             min = mins.get(0);
@@ -58,12 +65,20 @@ pub fn gen_columns_statistics(data_block: &DataBlock) -> Result<StatisticsOfColu
             (false, None) => 0,
         };
 
+        let distinct_of_values = match distinct.len() {
+            0 => None,
+            _ => distinct.get(0).as_u64().ok(),
+        };
+
         let in_memory_size = col.memory_size() as u64;
+        let histogram = gen_histogram(col, (rows - unset_bits) as u64, &max);
         let col_stats = ColumnStatistics {
             min,
             max,
             null_count: unset_bits as u64,
             in_memory_size,
+            distinct_of_values,
+            histogram,
         };
 
         statistics.insert(idx as u32, col_stats);
@@ -71,6 +86,47 @@ pub fn gen_columns_statistics(data_block: &DataBlock) -> Result<StatisticsOfColu
     Ok(statistics)
 }
 
+// Builds the equi-height histogram of the non-null values of the column from a
+// systematic sample of the rows, the heights are scaled to the number of the values.
+fn gen_histogram(col: &ColumnRef, num_values: u64, max: &DataValue) -> Option<Histogram> {
+    let rows = col.len();
+    let step = ((rows + HISTOGRAM_SAMPLE_SIZE - 1) / HISTOGRAM_SAMPLE_SIZE).max(1);
+    let mut samples = (0..rows)
+        .step_by(step)
+        .map(|row| col.get(row))
+        .filter(|v| !v.is_null())
+        .collect::<Vec<_>>();
+    if samples.is_empty() {
+        return None;
+    }
+    samples.sort();
+
+    let num_samples = samples.len();
+    let num_buckets = HISTOGRAM_MAX_BUCKETS.min(num_samples);
+    let mut buckets = Vec::with_capacity(num_buckets);
+    let (mut begin, mut assigned) = (0, 0);
+    for bucket in 1..=num_buckets {
+        let end = bucket * num_samples / num_buckets;
+        // The values of a bucket are estimated by its share of the samples,
+        // the last bucket takes the rest to keep the total.
+        let bucket_values = match bucket == num_buckets {
+            true => num_values - assigned,
+            false => (end - begin) as u64 * num_values / num_samples as u64,
+        };
+        buckets.push(HistogramBucket {
+            upper_bound: samples[end - 1].clone(),
+            num_values: bucket_values,
+        });
+        begin = end;
+        assigned += bucket_values;
+    }
+    // The max value may be missed by the samples.
+    if let Some(last) = buckets.last_mut() {
+        last.upper_bound = max.clone();
+    }
+    Some(Histogram { buckets })
+}
+
 pub mod traverse {
     use common_datavalues::ColumnRef;
     use common_datavalues::DataTypeImpl;
@@ -20,9 +20,14 @@ use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
 use common_fuse_meta::meta::ColumnId;
 use common_fuse_meta::meta::ColumnStatistics;
+use common_fuse_meta::meta::Histogram;
+use common_fuse_meta::meta::HistogramBucket;
 use common_fuse_meta::meta::Statistics;
 use common_fuse_meta::meta::StatisticsOfColumns;
 
+use crate::HISTOGRAM_MAX_BUCKETS;
+use crate::VIRTUAL_COLUMN_ID_START;
+
 pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
     stats_of_columns: &[T],
 ) -> Result<StatisticsOfColumns> {
@@ -43,9 +48,20 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
     // from : `HashMap<ColumnId, Vec<&ColumnStatistics>)>`
     // to   : `type BlockStatistics = HashMap<ColumnId, ColumnStatistics>`
     let len = stats_of_columns.len();
+    // The statistics of the virtual columns are missing in the blocks written before the
+    // virtual columns are added, they are reduced only if all the blocks have them.
+    // The empty ones are the statistics of no block, like the default summary.
+    let non_empty = stats_of_columns
+        .iter()
+        .filter(|s| !s.borrow().is_empty())
+        .count();
     col_to_stats_lit
         .iter()
         .try_fold(HashMap::with_capacity(len), |mut acc, (id, stats)| {
+            if *id >= VIRTUAL_COLUMN_ID_START && stats.len() < non_empty {
+                return Ok(acc);
+            }
+
             let mut min_stats = Vec::with_capacity(stats.len());
             let mut max_stats = Vec::with_capacity(stats.len());
             let mut null_count = 0;
@@ -87,11 +103,82 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 max,
                 null_count,
                 in_memory_size,
+                distinct_of_values: reduce_distinct_of_values(stats),
+                histogram: reduce_histograms(stats),
             });
             Ok(acc)
         })
 }
 
+// The distinct values of the statistics with disjoint ranges are added up, those of the
+// overlapped ones are estimated by the max, as the values may be the same ones.
+fn reduce_distinct_of_values(stats: &[&ColumnStatistics]) -> Option<u64> {
+    let mut ranges = Vec::with_capacity(stats.len());
+    for col_stats in stats {
+        let distinct_of_values = col_stats.distinct_of_values?;
+        if distinct_of_values > 0 {
+            ranges.push((&col_stats.min, &col_stats.max, distinct_of_values));
+        }
+    }
+    ranges.sort_by(|x, y| x.0.cmp(y.0));
+
+    let mut total = 0;
+    let mut overlapped: Option<(&DataValue, u64)> = None;
+    for (min, max, distinct_of_values) in ranges {
+        overlapped = match overlapped {
+            Some((upper, distinct)) if min <= upper => {
+                Some((upper.max(max), distinct.max(distinct_of_values)))
+            }
+            Some((_, distinct)) => {
+                total += distinct;
+                Some((max, distinct_of_values))
+            }
+            None => Some((max, distinct_of_values)),
+        };
+    }
+    Some(total + overlapped.map_or(0, |(_, distinct)| distinct))
+}
+
+// Merges the histograms into one of about `HISTOGRAM_MAX_BUCKETS` buckets, the buckets
+// are ordered by the upper bounds, and the adjacent ones are combined into the same height.
+fn reduce_histograms(stats: &[&ColumnStatistics]) -> Option<Histogram> {
+    let mut buckets = Vec::new();
+    for col_stats in stats {
+        match &col_stats.histogram {
+            Some(histogram) => buckets.extend(histogram.buckets.iter()),
+            // The values counted by the histograms are the non-null ones.
+            None if col_stats.min.is_null() => continue,
+            None => return None,
+        }
+    }
+    if buckets.is_empty() {
+        return None;
+    }
+    if stats.len() == 1 {
+        return stats[0].histogram.clone();
+    }
+    buckets.sort_by(|x, y| x.upper_bound.cmp(&y.upper_bound));
+
+    let total = buckets.iter().map(|b| b.num_values).sum::<u64>();
+    let height = ((total + HISTOGRAM_MAX_BUCKETS as u64 - 1) / HISTOGRAM_MAX_BUCKETS as u64).max(1);
+    let mut merged = Vec::with_capacity(HISTOGRAM_MAX_BUCKETS);
+    let mut num_values = 0;
+    for (i, bucket) in buckets.iter().enumerate() {
+        num_values += bucket.num_values;
+        let is_last = i == buckets.len() - 1;
+        // The buckets of the same upper bound are kept together.
+        let bound_changes = is_last || buckets[i + 1].upper_bound != bucket.upper_bound;
+        if is_last || (bound_changes && num_values >= height) {
+            merged.push(HistogramBucket {
+                upper_bound: bucket.upper_bound.clone(),
+                num_values,
+            });
+            num_values = 0;
+        }
+    }
+    Some(Histogram { buckets: merged })
+}
+
 pub fn merge_statistics(l: &Statistics, r: &Statistics) -> Result<Statistics> {
     let s = Statistics {
         row_count: l.row_count + r.row_count,
@@ -49,7 +49,8 @@ impl<'a> FuseSegment<'a> {
             let snapshot_location = tbl
                 .meta_location_generator
                 .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot_version)?;
-            let reader = MetaReaders::table_snapshot_reader(self.ctx.clone());
+            let operator = tbl.get_operator(self.ctx.as_ref())?;
+            let reader = MetaReaders::table_snapshot_reader(self.ctx.clone(), operator);
             let mut snapshot_stream = reader.snapshot_history(
                 snapshot_location,
                 snapshot_version,
@@ -76,9 +77,10 @@ impl<'a> FuseSegment<'a> {
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
         let mut file_location: Vec<Vec<u8>> = Vec::with_capacity(len);
 
+        let operator = self.table.get_operator(self.ctx.as_ref())?;
         for segment_location in segments {
             let (location, version) = (segment_location.0.clone(), segment_location.1);
-            let reader = MetaReaders::segment_info_reader(self.ctx.as_ref());
+            let reader = MetaReaders::segment_info_reader(self.ctx.as_ref(), operator.clone());
             let segment_info = reader.read(&location, None, version).await?;
 
             format_versions.push(version);
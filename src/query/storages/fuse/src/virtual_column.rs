@@ -0,0 +1,423 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_datavalues::Series;
+use common_datavalues::SeriesFrom;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::parse_path_keys;
+use common_fuse_meta::meta::ColumnId;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_parser::ExpressionParser;
+use common_pipeline_transforms::processors::ExpressionExecutor;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::statistics::gen_columns_statistics;
+use crate::OPT_KEY_VIRTUAL_COLUMNS;
+use crate::VIRTUAL_COLUMN_ID_START;
+
+/// A virtual column of a fuse table, added by `ALTER TABLE ... ADD VIRTUAL COLUMN <name> AS <expr>`.
+///
+/// The expression casts a path of a variant column to a scalar type, like `data:user.id::STRING`.
+/// The values are evaluated while the blocks are written, and the statistics of them are kept
+/// with the statistics of the columns of the blocks by the id of the virtual column, so that the
+/// blocks can be pruned by the filters of the same path.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumn {
+    pub id: ColumnId,
+    pub name: String,
+    pub expr: String,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct VirtualColumnsOption {
+    // The ids are not reused after the virtual columns are dropped, since the statistics
+    // of the dropped ones are still kept in the blocks written before.
+    next_column_id: ColumnId,
+    columns: Vec<VirtualColumn>,
+}
+
+// A cast of a path of a variant column. The paths of the legacy parser are accessed by
+// `get_path`, while those of the filters bound by the new planner are the nested `get`,
+// so they are compared in this form.
+#[derive(Clone, Debug, PartialEq)]
+struct VariantPath {
+    column: String,
+    keys: Vec<DataValue>,
+    data_type: DataTypeImpl,
+}
+
+/// The virtual columns of a fuse table, kept in the table option `virtual_columns`.
+#[derive(Clone, Debug, Default)]
+pub struct VirtualColumns {
+    next_column_id: ColumnId,
+    columns: Vec<(VirtualColumn, LegacyExpression, VariantPath)>,
+}
+
+/// The filters with the paths of the virtual columns rewritten to the columns appended to
+/// the schema, the statistics of the virtual columns are evaluated at the indexes of them.
+pub struct VirtualColumnFilters {
+    pub filters: Vec<LegacyExpression>,
+    pub schema: DataSchemaRef,
+    /// The ids of the used virtual columns, with the indexes of them in the schema.
+    pub column_ids: Vec<(ColumnId, ColumnId)>,
+}
+
+impl VirtualColumns {
+    pub fn try_from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let option: VirtualColumnsOption = match options.get(OPT_KEY_VIRTUAL_COLUMNS) {
+            Some(v) => serde_json::from_str(v).map_err(|e| {
+                ErrorCode::BadOption(format!("invalid {}: {}", OPT_KEY_VIRTUAL_COLUMNS, e))
+            })?,
+            None => return Ok(Self::default()),
+        };
+
+        let columns = option
+            .columns
+            .into_iter()
+            .map(|column| {
+                let (expr, path) = parse_virtual_column_expr(&column.expr)?;
+                Ok((column, expr, path))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(VirtualColumns {
+            next_column_id: option.next_column_id,
+            columns,
+        })
+    }
+
+    pub fn to_option_value(&self) -> Result<String> {
+        let option = VirtualColumnsOption {
+            next_column_id: self.next_column_id,
+            columns: self.columns().cloned().collect(),
+        };
+        Ok(serde_json::to_string(&option)?)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = &VirtualColumn> {
+        self.columns.iter().map(|(column, _, _)| column)
+    }
+
+    /// The id of the next virtual column added, which changes once a virtual column is added.
+    pub fn next_column_id(&self) -> ColumnId {
+        self.next_column_id
+    }
+
+    /// Adds a virtual column of the expression, which must cast a path of a variant column
+    /// of the schema.
+    pub fn add(&mut self, name: &str, expr: &str, schema: &DataSchemaRef) -> Result<()> {
+        if schema.has_field(name) || self.columns().any(|c| c.name == name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "column {} already exists",
+                name
+            )));
+        }
+
+        let (legacy_expr, path) = parse_virtual_column_expr(expr)?;
+        let field = schema.field_with_name(&path.column)?;
+        if !remove_nullable(field.data_type())
+            .data_type_id()
+            .is_variant()
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "virtual column expression {} must access a path of a variant column, but {} is {:?}",
+                expr,
+                path.column,
+                field.data_type().data_type_id()
+            )));
+        }
+        legacy_expr.to_data_type(schema)?;
+
+        let id = self.next_column_id.max(VIRTUAL_COLUMN_ID_START);
+        self.next_column_id = id + 1;
+        let column = VirtualColumn {
+            id,
+            name: name.to_string(),
+            expr: expr.to_string(),
+        };
+        self.columns.push((column, legacy_expr, path));
+        Ok(())
+    }
+
+    pub fn drop(&mut self, name: &str) -> Result<()> {
+        match self.columns().position(|c| c.name == name) {
+            Some(index) => {
+                self.columns.remove(index);
+                Ok(())
+            }
+            None => Err(ErrorCode::UnknownColumn(format!(
+                "virtual column {} not found",
+                name
+            ))),
+        }
+    }
+
+    /// Creates the generator of the statistics of the virtual columns for the blocks of the
+    /// schema, the virtual columns of the dropped columns are skipped.
+    pub fn stats_generator(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        schema: &DataSchemaRef,
+    ) -> Result<VirtualColumnStatsGenerator> {
+        let mut executors = Vec::with_capacity(self.columns.len());
+        for (column, expr, _) in &self.columns {
+            let field = match expr.to_data_field(schema) {
+                Ok(field) => field,
+                Err(_) => continue,
+            };
+            let executor = ExpressionExecutor::try_create(
+                ctx.clone(),
+                "virtual column expression executor",
+                schema.clone(),
+                DataSchemaRefExt::create(vec![field]),
+                vec![expr.clone()],
+                false,
+            )?;
+            executors.push((column.id, executor));
+        }
+        Ok(VirtualColumnStatsGenerator { executors })
+    }
+
+    /// Rewrites the paths of the virtual columns in the filters, None if no virtual column
+    /// is used by the filters.
+    pub fn rewrite_filters(
+        &self,
+        filters: &[LegacyExpression],
+        schema: &DataSchemaRef,
+    ) -> Option<VirtualColumnFilters> {
+        if self.columns.is_empty() {
+            return None;
+        }
+
+        let mut rewriter = FilterRewriter {
+            columns: &self.columns,
+            schema,
+            fields: schema.fields().clone(),
+            column_ids: vec![],
+        };
+        let filters = filters
+            .iter()
+            .map(|expr| rewriter.rewrite(expr))
+            .collect::<Vec<_>>();
+        if rewriter.column_ids.is_empty() {
+            return None;
+        }
+
+        Some(VirtualColumnFilters {
+            filters,
+            schema: Arc::new(DataSchema::new(rewriter.fields)),
+            column_ids: rewriter.column_ids,
+        })
+    }
+}
+
+/// Collects the statistics of the virtual columns of the blocks.
+#[derive(Clone, Default)]
+pub struct VirtualColumnStatsGenerator {
+    executors: Vec<(ColumnId, ExpressionExecutor)>,
+}
+
+impl VirtualColumnStatsGenerator {
+    pub fn gen_stats(&self, data_block: &DataBlock) -> StatisticsOfColumns {
+        let mut stats = HashMap::with_capacity(self.executors.len());
+        for (id, executor) in &self.executors {
+            // The block is still written if the values can't be cast, it is just not
+            // pruned by the virtual column.
+            match executor
+                .execute(data_block)
+                .and_then(|block| gen_columns_statistics(&block))
+            {
+                Ok(mut col_stats) => {
+                    if let Some(col_stats) = col_stats.remove(&0) {
+                        stats.insert(*id, col_stats);
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "failed to collect statistics of virtual column {}: {}",
+                        id,
+                        e
+                    )
+                }
+            }
+        }
+        stats
+    }
+}
+
+struct FilterRewriter<'a> {
+    columns: &'a [(VirtualColumn, LegacyExpression, VariantPath)],
+    schema: &'a DataSchemaRef,
+    fields: Vec<DataField>,
+    column_ids: Vec<(ColumnId, ColumnId)>,
+}
+
+impl<'a> FilterRewriter<'a> {
+    fn rewrite(&mut self, expr: &LegacyExpression) -> LegacyExpression {
+        if let Some(name) = variant_path(expr).and_then(|path| self.virtual_column(&path)) {
+            return LegacyExpression::Column(name);
+        }
+
+        match expr {
+            LegacyExpression::Alias(alias, expr) => {
+                LegacyExpression::Alias(alias.clone(), Box::new(self.rewrite(expr)))
+            }
+            LegacyExpression::UnaryExpression { op, expr } => LegacyExpression::UnaryExpression {
+                op: op.clone(),
+                expr: Box::new(self.rewrite(expr)),
+            },
+            LegacyExpression::BinaryExpression { left, op, right } => {
+                LegacyExpression::BinaryExpression {
+                    left: Box::new(self.rewrite(left)),
+                    op: op.clone(),
+                    right: Box::new(self.rewrite(right)),
+                }
+            }
+            LegacyExpression::ScalarFunction { op, args } => LegacyExpression::ScalarFunction {
+                op: op.clone(),
+                args: args.iter().map(|arg| self.rewrite(arg)).collect(),
+            },
+            LegacyExpression::Cast {
+                expr,
+                data_type,
+                pg_style,
+            } => LegacyExpression::Cast {
+                expr: Box::new(self.rewrite(expr)),
+                data_type: data_type.clone(),
+                pg_style: *pg_style,
+            },
+            _ => expr.clone(),
+        }
+    }
+
+    // The name of the column of the virtual column of the path in the schema, which is
+    // appended to the schema the first time it is used.
+    fn virtual_column(&mut self, path: &VariantPath) -> Option<String> {
+        let (column, expr, _) = self.columns.iter().find(|(_, _, p)| p == path)?;
+        let name = format!("_virtual_column_{}", column.id);
+        if self.column_ids.iter().all(|(id, _)| *id != column.id) {
+            let data_type = expr.to_data_type(self.schema).ok()?;
+            self.column_ids
+                .push((column.id, self.fields.len() as ColumnId));
+            self.fields.push(DataField::new(&name, data_type));
+        }
+        Some(name)
+    }
+}
+
+fn parse_virtual_column_expr(expr: &str) -> Result<(LegacyExpression, VariantPath)> {
+    let mut exprs = ExpressionParser::parse_exprs(expr)?;
+    match (exprs.pop(), exprs.is_empty()) {
+        (Some(legacy_expr), true) => match variant_path(&legacy_expr) {
+            Some(path) => Ok((legacy_expr, path)),
+            None => Err(ErrorCode::BadArguments(format!(
+                "virtual column expression {} must cast a path of a variant column, like `v:k1.k2::STRING`",
+                expr
+            ))),
+        },
+        _ => Err(ErrorCode::BadArguments(format!(
+            "invalid virtual column expression {}",
+            expr
+        ))),
+    }
+}
+
+fn variant_path(expr: &LegacyExpression) -> Option<VariantPath> {
+    match expr {
+        LegacyExpression::Cast {
+            expr, data_type, ..
+        } => {
+            let (column, keys) = path_keys(expr)?;
+            if keys.is_empty() {
+                return None;
+            }
+            Some(VariantPath {
+                column,
+                keys,
+                data_type: remove_nullable(data_type),
+            })
+        }
+        _ => None,
+    }
+}
+
+// The column and the keys of the path accessed by the expression.
+fn path_keys(expr: &LegacyExpression) -> Option<(String, Vec<DataValue>)> {
+    match expr {
+        LegacyExpression::Column(name) => Some((name.clone(), vec![])),
+        LegacyExpression::MapAccess { args, .. } => path_keys_of_get_path(args),
+        LegacyExpression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("get_path") => {
+            path_keys_of_get_path(args)
+        }
+        LegacyExpression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("get") => {
+            match args.as_slice() {
+                [inner, LegacyExpression::Literal { value, .. }] => {
+                    let (column, mut keys) = path_keys(inner)?;
+                    keys.push(normalize_path_key(value)?);
+                    Some((column, keys))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn path_keys_of_get_path(args: &[LegacyExpression]) -> Option<(String, Vec<DataValue>)> {
+    match args {
+        [
+            inner,
+            LegacyExpression::Literal {
+                value: DataValue::String(path),
+                ..
+            },
+        ] => {
+            let (column, mut keys) = path_keys(inner)?;
+            let path = Series::from_data(vec![path.clone()]);
+            let mut path_keys = parse_path_keys(&path).ok()?;
+            for key in path_keys.pop()? {
+                keys.push(normalize_path_key(&key)?);
+            }
+            Some((column, keys))
+        }
+        _ => None,
+    }
+}
+
+fn normalize_path_key(key: &DataValue) -> Option<DataValue> {
+    match key {
+        DataValue::String(_) | DataValue::UInt64(_) => Some(key.clone()),
+        DataValue::Int64(v) if *v >= 0 => Some(DataValue::UInt64(*v as u64)),
+        _ => None,
+    }
+}
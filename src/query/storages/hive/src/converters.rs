@@ -82,18 +82,15 @@ pub fn try_into_table_info(
     };
     let schema = Arc::new(try_into_schema(fields, partition_keys.clone())?);
 
-    let location = if let Some(storage) = &hms_table.sd {
-        storage
-            .location
-            .as_ref()
-            .map(|location| location.to_string())
-    } else {
-        None
+    let (location, input_format) = match &hms_table.sd {
+        Some(storage) => (storage.location.clone(), storage.input_format.clone()),
+        None => (None, None),
     };
 
     let table_options = HiveTableOptions {
         partition_keys,
         location,
+        input_format,
     };
 
     let meta = TableMeta {
@@ -50,6 +50,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReply;
@@ -248,6 +250,15 @@ impl Catalog for HiveCatalog {
         ))
     }
 
+    async fn update_database_comment(
+        &self,
+        _req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot update the comment of database in HIVE catalog",
+        ))
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info.clone())?);
         Ok(res)
@@ -0,0 +1,183 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::orc::format;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_arrow::arrow::io::orc::read;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+use crate::hive_partition::HivePartInfo;
+use crate::hive_partition_filler::HivePartitionFiller;
+
+/// Reads the projected columns of the ORC files of hive tables.
+///
+/// The metadata of ORC can only be read from a seekable reader, so a file is read
+/// into memory as a whole, and deserialized into a block for each stripe.
+#[derive(Clone)]
+pub struct HiveOrcBlockReader {
+    operator: Operator,
+    schema: DataSchemaRef,
+    projection: Vec<usize>,
+    projected_schema: DataSchemaRef,
+    hive_partition_filler: Option<HivePartitionFiller>,
+}
+
+impl HiveOrcBlockReader {
+    pub fn create(
+        operator: Operator,
+        schema: DataSchemaRef,
+        projection: Vec<usize>,
+        hive_partition_filler: Option<HivePartitionFiller>,
+    ) -> Result<Arc<HiveOrcBlockReader>> {
+        let projected_schema = DataSchemaRef::new(schema.project(&projection));
+        Ok(Arc::new(HiveOrcBlockReader {
+            operator,
+            schema,
+            projection,
+            projected_schema,
+            hive_partition_filler,
+        }))
+    }
+
+    pub async fn read_file(&self, part: &HivePartInfo) -> Result<Vec<u8>> {
+        Ok(self.operator.object(&part.filename).read().await?)
+    }
+
+    pub fn read_blocks(&self, data: Vec<u8>, part: &HivePartInfo) -> Result<Vec<DataBlock>> {
+        self.do_read_blocks(data, part)
+            .map_err(|e| e.add_message(format!(" filename of hive part {}", part.filename)))
+    }
+
+    fn do_read_blocks(&self, data: Vec<u8>, part: &HivePartInfo) -> Result<Vec<DataBlock>> {
+        let mut cursor = Cursor::new(&data);
+        let meta = format::read::read_metadata(&mut cursor).map_err(orc_error)?;
+        let fields = self.get_fields(&meta)?;
+        let data_types = self
+            .projected_schema
+            .fields()
+            .iter()
+            .map(|f| f.to_arrow().data_type)
+            .collect::<Vec<_>>();
+
+        let mut blocks = Vec::with_capacity(meta.footer.stripes.len());
+        for (stripe, info) in meta.footer.stripes.iter().enumerate() {
+            let num_rows = info.number_of_rows() as usize;
+            if num_rows == 0 {
+                continue;
+            }
+
+            let block = if fields.is_empty() {
+                DataBlock::empty()
+            } else {
+                let chunk = read_stripe(&mut cursor, &meta, stripe, &fields, &data_types)?;
+                DataBlock::from_chunk(&self.projected_schema, &chunk)?
+            };
+            let block = match &self.hive_partition_filler {
+                Some(filler) => filler.fill_data(block, part, num_rows)?,
+                None => block,
+            };
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    // Finds the orc column id and the arrow field of each projected column by name.
+    //
+    // Files written by the older versions of hive name the columns `_col0`, `_col1`, ...,
+    // whose columns are taken by the position in the table instead.
+    fn get_fields(&self, meta: &FileMetadata) -> Result<Vec<(u32, Field)>> {
+        let infer_schema = read::infer_schema(&meta.footer)?;
+        // ORC schemas always start with a struct, whose children are the top level fields.
+        let root = meta
+            .footer
+            .types
+            .first()
+            .ok_or_else(|| ErrorCode::OrcError("orc file without schema"))?;
+
+        let mut fields = Vec::with_capacity(self.projection.len());
+        for index in &self.projection {
+            let name = self.schema.field(*index).name();
+            let position_name = format!("_col{}", index);
+            let pos = infer_schema
+                .fields
+                .iter()
+                .position(|f| f.name.eq_ignore_ascii_case(name))
+                .or_else(|| {
+                    infer_schema
+                        .fields
+                        .iter()
+                        .position(|f| f.name == position_name)
+                })
+                .ok_or_else(|| {
+                    ErrorCode::OrcError(format!("couldn't find column:{} in orc file", name))
+                })?;
+            let column_id = root.subtypes.get(pos).cloned().ok_or_else(|| {
+                ErrorCode::OrcError(format!("column {} not found in orc types", name))
+            })?;
+            fields.push((column_id, infer_schema.fields[pos].clone()));
+        }
+        Ok(fields)
+    }
+}
+
+fn read_stripe(
+    cursor: &mut Cursor<&Vec<u8>>,
+    meta: &FileMetadata,
+    stripe: usize,
+    fields: &[(u32, Field)],
+    data_types: &[ArrowType],
+) -> Result<Chunk<Box<dyn Array>>> {
+    let mut scratch = vec![];
+    let footer =
+        format::read::read_stripe_footer(cursor, meta, stripe, &mut scratch).map_err(orc_error)?;
+
+    let mut arrays = Vec::with_capacity(fields.len());
+    for ((column_id, field), data_type) in fields.iter().zip(data_types.iter()) {
+        let column = format::read::read_stripe_column(
+            cursor,
+            meta,
+            stripe,
+            footer.clone(),
+            *column_id,
+            vec![],
+        )
+        .map_err(orc_error)?;
+        let array = read::deserialize(field.data_type.clone(), &column)?;
+        // The types of the files may be narrower than the ones of the table, e.g. `int` of
+        // a column altered to `bigint`.
+        let array = match array.data_type() == data_type {
+            true => array,
+            false => cast::cast(array.as_ref(), data_type, CastOptions::default())?,
+        };
+        arrays.push(array);
+    }
+    Ok(Chunk::try_new(arrays)?)
+}
+
+fn orc_error(e: format::error::Error) -> ErrorCode {
+    ErrorCode::OrcError(format!("fail to read orc file: {:?}", e))
+}
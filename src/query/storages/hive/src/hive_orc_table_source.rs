@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::processors::sources::AsyncSource;
+use common_pipeline_sources::processors::sources::AsyncSourcer;
+
+use crate::hive_orc_block_reader::HiveOrcBlockReader;
+use crate::HivePartInfo;
+
+/// Reads the ORC files of the partitions one by one, each partition is a whole file.
+pub struct HiveOrcTableSource {
+    ctx: Arc<dyn TableContext>,
+    block_reader: Arc<HiveOrcBlockReader>,
+    // The blocks of the stripes of the file being read.
+    blocks: VecDeque<DataBlock>,
+}
+
+impl HiveOrcTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        block_reader: Arc<HiveOrcBlockReader>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, HiveOrcTableSource {
+            ctx,
+            block_reader,
+            blocks: VecDeque::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for HiveOrcTableSource {
+    const NAME: &'static str = "HiveOrcEngineSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+
+            let partitions = self.ctx.try_get_partitions(1)?;
+            let part = match partitions.first() {
+                Some(part) => HivePartInfo::from_part(part)?,
+                None => return Ok(None),
+            };
+            let data = self.block_reader.read_file(part).await?;
+            self.blocks = self.block_reader.read_blocks(data, part)?.into();
+        }
+    }
+}
@@ -81,6 +81,8 @@ impl HivePartitionPruner {
                     max: v,
                     null_count: 0,
                     in_memory_size: 0,
+                    distinct_of_values: None,
+                    histogram: None,
                 };
                 stats.insert(index as u32, column_stats);
             }
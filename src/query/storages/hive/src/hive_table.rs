@@ -48,7 +48,10 @@ use opendal::Operator;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
+use crate::hive_orc_block_reader::HiveOrcBlockReader;
+use crate::hive_orc_table_source::HiveOrcTableSource;
 use crate::hive_parquet_block_reader::HiveParquetBlockReader;
 use crate::hive_partition_filler::HivePartitionFiller;
 use crate::hive_table_source::HiveTableSource;
@@ -102,6 +105,10 @@ impl HiveTable {
         plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        if self.table_options.file_format()? == HiveFileFormat::Orc {
+            return self.do_read_orc(ctx, plan, pipeline);
+        }
+
         let push_downs = &plan.push_downs;
         let block_reader = self.create_block_reader(&ctx, push_downs)?;
 
@@ -173,11 +180,11 @@ impl HiveTable {
             .collect::<HashSet<_>>()
     }
 
-    fn create_block_reader(
+    // Returns the projected columns stored in the files, and the filler of the partition columns.
+    fn get_projection(
         &self,
-        ctx: &Arc<dyn TableContext>,
         push_downs: &Option<Extras>,
-    ) -> Result<Arc<HiveParquetBlockReader>> {
+    ) -> Result<(Vec<usize>, Option<HivePartitionFiller>)> {
         let projection = if let Some(Extras {
             projection: Some(prj),
             ..
@@ -207,13 +214,55 @@ impl HiveTable {
         } else {
             None
         };
+        Ok((projection, hive_partition_filler))
+    }
 
+    fn create_block_reader(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        push_downs: &Option<Extras>,
+    ) -> Result<Arc<HiveParquetBlockReader>> {
+        let (projection, hive_partition_filler) = self.get_projection(push_downs)?;
         let operator = ctx.get_storage_operator()?;
         let table_schema = self.table_info.schema();
-        // todo, support csv, orc format
         HiveParquetBlockReader::create(operator, table_schema, projection, hive_partition_filler)
     }
 
+    fn create_orc_block_reader(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        push_downs: &Option<Extras>,
+    ) -> Result<Arc<HiveOrcBlockReader>> {
+        let (projection, hive_partition_filler) = self.get_projection(push_downs)?;
+        let operator = ctx.get_storage_operator()?;
+        let table_schema = self.table_info.schema();
+        HiveOrcBlockReader::create(operator, table_schema, projection, hive_partition_filler)
+    }
+
+    fn do_read_orc(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let block_reader = self.create_orc_block_reader(&ctx, &plan.push_downs)?;
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for _index in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                HiveOrcTableSource::create(ctx.clone(), output, block_reader.clone())?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
     fn get_column_schemas(&self, columns: Vec<String>) -> Result<Arc<DataSchema>> {
         let mut fields = Vec::with_capacity(columns.len());
         for column in columns {
@@ -340,7 +389,12 @@ impl HiveTable {
         let dirs = self.get_query_locations(ctx.clone(), &push_downs).await?;
         let all_files = self.list_files_from_dirs(ctx.clone(), dirs).await?;
 
-        let splitter = HiveFileSplitter::create(128 * 1024 * 1024_u64);
+        // The row groups of parquet files are read by ranges, while orc files are read as a whole.
+        let min_split_size = match self.table_options.file_format()? {
+            HiveFileFormat::Parquet => 128 * 1024 * 1024_u64,
+            HiveFileFormat::Orc => u64::MAX,
+        };
+        let splitter = HiveFileSplitter::create(min_split_size);
         let partitions = splitter.get_splits(all_files);
 
         tracing::info!("read partition, elapsed:{:?}", start.elapsed());
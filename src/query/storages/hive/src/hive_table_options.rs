@@ -20,15 +20,45 @@ use common_exception::Result;
 
 pub const PARTITION_KEYS: &str = "partition_keys";
 pub const LOCATION: &str = "location";
+pub const INPUT_FORMAT: &str = "input_format";
 
 // represents hive table schema info
 //
 // partition_keys,  hive partition keys, such as:  "p_date", "p_hour"
 // location,  hive table location, such as: hdfs://namenode:8020/user/hive/warehouse/a.db/b.table/
+// input_format,  the input format class of the table, such as: org.apache.hadoop.hive.ql.io.orc.OrcInputFormat
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HiveTableOptions {
     pub partition_keys: Option<Vec<String>>,
     pub location: Option<String>,
+    pub input_format: Option<String>,
+}
+
+/// The formats of the data files of hive tables that can be read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HiveFileFormat {
+    Parquet,
+    Orc,
+}
+
+impl HiveTableOptions {
+    pub fn file_format(&self) -> Result<HiveFileFormat> {
+        // Tables without the input format are taken as parquet, which was the only supported format.
+        let input_format = match &self.input_format {
+            Some(input_format) => input_format.to_lowercase(),
+            None => return Ok(HiveFileFormat::Parquet),
+        };
+        if input_format.contains("parquet") {
+            Ok(HiveFileFormat::Parquet)
+        } else if input_format.contains("orc") {
+            Ok(HiveFileFormat::Orc)
+        } else {
+            Err(ErrorCode::UnImplement(format!(
+                "unsupported input format of hive table: {}",
+                input_format
+            )))
+        }
+    }
 }
 
 impl From<HiveTableOptions> for BTreeMap<String, String> {
@@ -42,6 +72,9 @@ impl From<HiveTableOptions> for BTreeMap<String, String> {
         options
             .location
             .map(|v| map.insert(LOCATION.to_string(), v));
+        options
+            .input_format
+            .map(|v| map.insert(INPUT_FORMAT.to_string(), v));
         map
     }
 }
@@ -64,6 +97,7 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
         let options = HiveTableOptions {
             partition_keys,
             location: Some(location),
+            input_format: options.get(INPUT_FORMAT).cloned(),
         };
         Ok(options)
     }
@@ -73,6 +107,7 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
 mod tests {
     use std::collections::BTreeMap;
 
+    use super::HiveFileFormat;
     use super::HiveTableOptions;
 
     fn do_test_hive_table_options(hive_table_options: HiveTableOptions) {
@@ -86,6 +121,7 @@ mod tests {
         let hive_table_options = HiveTableOptions {
             partition_keys: Some(vec!["a".to_string(), "b".to_string()]),
             location: Some("test".to_string()),
+            input_format: Some("org.apache.hadoop.hive.ql.io.orc.OrcInputFormat".to_string()),
         };
 
         do_test_hive_table_options(hive_table_options);
@@ -93,7 +129,28 @@ mod tests {
         let empty = HiveTableOptions {
             partition_keys: None,
             location: Some("test".to_string()),
+            input_format: None,
         };
         do_test_hive_table_options(empty);
     }
+
+    #[test]
+    fn test_hive_file_format() {
+        let mut options = HiveTableOptions {
+            partition_keys: None,
+            location: Some("test".to_string()),
+            input_format: None,
+        };
+        assert_eq!(options.file_format().unwrap(), HiveFileFormat::Parquet);
+
+        options.input_format =
+            Some("org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat".to_string());
+        assert_eq!(options.file_format().unwrap(), HiveFileFormat::Parquet);
+
+        options.input_format = Some("org.apache.hadoop.hive.ql.io.orc.OrcInputFormat".to_string());
+        assert_eq!(options.file_format().unwrap(), HiveFileFormat::Orc);
+
+        options.input_format = Some("org.apache.hadoop.mapred.TextInputFormat".to_string());
+        assert!(options.file_format().is_err());
+    }
 }
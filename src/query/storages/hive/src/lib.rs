@@ -19,6 +19,8 @@ mod hive_blocks;
 mod hive_catalog;
 mod hive_database;
 mod hive_file_splitter;
+mod hive_orc_block_reader;
+mod hive_orc_table_source;
 mod hive_parquet_block_reader;
 mod hive_partition;
 mod hive_partition_filler;
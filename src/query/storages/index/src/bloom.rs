@@ -18,13 +18,26 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::TextQuery;
 use common_legacy_expression::LegacyExpression;
 
 use crate::filters::Filter;
 use crate::filters::FilterBuilder;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
+use crate::InvertedIndex;
 use crate::SupportedType;
+use crate::VectorIndex;
+
+/// The columns of the indexes kept in the bloom filter block besides the bloom filters, by the
+/// options of the table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexColumns {
+    // the string columns of the inverted indexes
+    pub inverted: Vec<String>,
+    // the array columns of the vector indexes
+    pub vector: Vec<String>,
+}
 
 /// BloomFilter represents multiple  bloom filters (per column) in data block.
 ///
@@ -106,8 +119,10 @@ impl BloomFilter {
     /// Create a bloom filter block from source data.
     ///
     /// All input blocks should be belong to a Parquet file, e.g. the block array represents the parquet file in memory.
-
-    pub fn try_create(blocks: &[&DataBlock]) -> Result<Self> {
+    ///
+    /// The inverted indexes of the string columns and the vector indexes of the array columns of
+    /// `index_columns` are also kept in the block, the other columns of them are ignored.
+    pub fn try_create(blocks: &[&DataBlock], index_columns: &IndexColumns) -> Result<Self> {
         if blocks.is_empty() {
             return Err(ErrorCode::BadArguments("data blocks is empty"));
         }
@@ -140,7 +155,47 @@ impl BloomFilter {
             }
         }
 
-        let bloom_schema = Arc::new(Self::to_bloom_schema(source_schema.as_ref()));
+        let mut bloom_fields = Self::to_bloom_schema(source_schema.as_ref())
+            .fields()
+            .clone();
+        for column_name in &index_columns.inverted {
+            let i = match source_schema.column_with_name(column_name) {
+                Some((i, field)) if InvertedIndex::is_supported_type(field.data_type()) => i,
+                _ => continue,
+            };
+            let columns = blocks
+                .iter()
+                .map(|block| block.column(i))
+                .collect::<Vec<_>>();
+            let inverted_index = InvertedIndex::try_create(&columns)?;
+
+            let inverted_value = DataValue::String(inverted_index.to_bytes());
+            let inverted_column: ColumnRef =
+                inverted_value.as_const_column(&StringType::new_impl(), 1)?;
+            bloom_columns.push(inverted_column);
+            let inverted_column_name = InvertedIndex::to_inverted_column_name(column_name);
+            bloom_fields.push(DataField::new(&inverted_column_name, Vu8::to_data_type()));
+        }
+        for column_name in &index_columns.vector {
+            let i = match source_schema.column_with_name(column_name) {
+                Some((i, field)) if VectorIndex::is_supported_type(field.data_type()) => i,
+                _ => continue,
+            };
+            let columns = blocks
+                .iter()
+                .map(|block| block.column(i))
+                .collect::<Vec<_>>();
+            let vector_index = VectorIndex::try_create(&columns)?;
+
+            let vector_value = DataValue::String(vector_index.to_bytes());
+            let vector_column: ColumnRef =
+                vector_value.as_const_column(&StringType::new_impl(), 1)?;
+            bloom_columns.push(vector_column);
+            let vector_column_name = VectorIndex::to_vector_column_name(column_name);
+            bloom_fields.push(DataField::new(&vector_column_name, Vu8::to_data_type()));
+        }
+
+        let bloom_schema = Arc::new(DataSchema::new(bloom_fields));
         let bloom_block = DataBlock::create(bloom_schema.clone(), bloom_columns);
         Ok(Self {
             source_schema,
@@ -198,10 +253,53 @@ impl BloomFilter {
                     _ => Ok(BloomFilterExprEvalResult::NotApplicable),
                 }
             }
+            LegacyExpression::ScalarFunction { op, args } if args.len() == 2 => {
+                match op.to_lowercase().as_str() {
+                    "match" => self.eval_text_match(&args[0], &args[1], false),
+                    "query" => self.eval_text_match(&args[0], &args[1], true),
+                    _ => Ok(BloomFilterExprEvalResult::NotApplicable),
+                }
+            }
             _ => Ok(BloomFilterExprEvalResult::NotApplicable),
         }
     }
 
+    // Evaluate the text match expression like "match(message, 'connection refused')" by the
+    // inverted index of the column
+    fn eval_text_match(
+        &self,
+        column: &LegacyExpression,
+        text: &LegacyExpression,
+        is_query: bool,
+    ) -> Result<BloomFilterExprEvalResult> {
+        let (column, text) = match (column, text) {
+            (LegacyExpression::Column(column), LegacyExpression::Literal { value, .. })
+                if !value.is_null() =>
+            {
+                (column, value.as_string()?)
+            }
+            _ => return Ok(BloomFilterExprEvalResult::NotApplicable),
+        };
+        let inverted_column = InvertedIndex::to_inverted_column_name(column);
+        if !self.bloom_block.schema().has_field(&inverted_column) {
+            return Ok(BloomFilterExprEvalResult::NotApplicable);
+        }
+
+        let text = String::from_utf8_lossy(&text);
+        let query = if is_query {
+            TextQuery::parse(&text)?
+        } else {
+            TextQuery::from_match(&text)
+        };
+        let inverted_bytes = self.bloom_block.first(&inverted_column)?.as_string()?;
+        let inverted_index = InvertedIndex::from_bytes(&inverted_bytes)?;
+        if query.may_match(|token| inverted_index.contains(token)) {
+            Ok(BloomFilterExprEvalResult::Unknown)
+        } else {
+            Ok(BloomFilterExprEvalResult::False)
+        }
+    }
+
     // Evaluate the equivalent expression like "name='Alice'"
     fn eval_equivalent_expression(
         &self,
@@ -293,4 +391,14 @@ impl BloomFilter {
         let (bloom_filter, _size) = Xor8Filter::from_bytes(bloom_bytes.as_ref())?;
         Ok(bloom_filter)
     }
+
+    /// Find and returns the vector index by name, none if the column has no vector index
+    pub fn try_get_vector_index(&self, column_name: &str) -> Result<Option<VectorIndex>> {
+        let vector_column = VectorIndex::to_vector_column_name(column_name);
+        if !self.bloom_block.schema().has_field(&vector_column) {
+            return Ok(None);
+        }
+        let vector_bytes = self.bloom_block.first(&vector_column)?.as_string()?;
+        VectorIndex::from_bytes(&vector_bytes).map(Some)
+    }
 }
@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::tokenize;
+
+use crate::remove_nullable;
+
+/// InvertedIndex keeps the distinct tokens of the values of a string column in data block.
+///
+/// The tokens are split by `tokenize`, the same as the `match()` and `query()` functions, so
+/// the block can be pruned if it has none of the tokens required by the function.
+/// The index is stored in the bloom filter block, with field name 'Inverted(column_name)'.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndex {
+    // sorted and deduplicated
+    tokens: Vec<String>,
+}
+
+impl InvertedIndex {
+    pub fn to_inverted_column_name(column_name: &str) -> String {
+        format!("Inverted({})", column_name)
+    }
+
+    /// Only the string columns are supported, nulls are not added to the index.
+    pub fn is_supported_type(data_type: &DataTypeImpl) -> bool {
+        remove_nullable(data_type).data_type_id() == TypeID::String
+    }
+
+    /// Create the index of the same column of all the blocks.
+    pub fn try_create(columns: &[&ColumnRef]) -> Result<Self> {
+        let mut tokens = BTreeSet::new();
+        for column in columns {
+            let viewer = Vu8::try_create_viewer(column)?;
+            for value in viewer.iter() {
+                tokens.extend(tokenize(&String::from_utf8_lossy(value)));
+            }
+        }
+        Ok(Self {
+            tokens: tokens.into_iter().collect(),
+        })
+    }
+
+    pub fn contains(&self, token: &str) -> bool {
+        self.tokens
+            .binary_search_by(|v| v.as_str().cmp(token))
+            .is_ok()
+    }
+
+    pub fn num_tokens(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// The tokens separated by '\n', which is never a part of the tokens.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.tokens.join("\n").into_bytes()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid inverted index, cause {}", e)))?;
+        let tokens = text
+            .split('\n')
+            .filter(|token| !token.is_empty())
+            .map(|token| token.to_string())
+            .collect();
+        Ok(Self { tokens })
+    }
+}
@@ -20,12 +20,18 @@ use common_datavalues::NullableType;
 mod bloom;
 pub mod filters;
 pub mod index_min_max;
+mod inverted;
 pub mod range_filter;
+mod vector;
 
 pub use bloom::BloomFilter;
 pub use bloom::BloomFilterExprEvalResult;
+pub use bloom::IndexColumns;
 pub use index_min_max::*;
+pub use inverted::InvertedIndex;
 pub use range_filter::*;
+pub use vector::VectorBound;
+pub use vector::VectorIndex;
 
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum IndexSchemaVersion {
@@ -0,0 +1,312 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::is_vector_type;
+use common_functions::scalars::read_vector;
+use common_functions::scalars::VectorDistance;
+
+const MAX_CLUSTERS: usize = 16;
+const KMEANS_ITERATIONS: usize = 4;
+// The relative error of the distances tolerated by the bounds, the centroids are kept in f32.
+const EPSILON: f64 = 1e-5;
+
+/// VectorIndex keeps the vectors of an array column in data block as the IVF-style clusters, each
+/// cluster is a ball of the centroid and the radius covering its vectors.
+///
+/// The clusters are built by a few iterations of k-means, of the vectors for `l2_distance()`
+/// and of the normalized vectors for `cosine_distance()`, of which the cosine distance is the
+/// half of the square of the euclidean distance. So the distances from a vector to the vectors of
+/// a cluster are bounded by the distance to the centroid plus and minus the radius, and the block
+/// can be pruned from the nearest neighbor search if it is sure to be farther than enough vectors.
+///
+/// The blocks having the nulls, the zero vectors or the vectors of different dimensions are
+/// unbounded, which are never pruned.
+/// The index is stored in the bloom filter block, with field name 'Vector(column_name)'.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorIndex {
+    dimension: usize,
+    unbounded: bool,
+    clusters: Vec<VectorCluster>,
+    normalized_clusters: Vec<VectorCluster>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct VectorCluster {
+    centroid: Vec<f32>,
+    radius: f64,
+    count: u64,
+}
+
+/// The bounds of the distances from the query vector to the vectors of a cluster.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VectorBound {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: u64,
+}
+
+impl VectorIndex {
+    pub fn to_vector_column_name(column_name: &str) -> String {
+        format!("Vector({})", column_name)
+    }
+
+    /// Only the arrays of numbers are supported.
+    pub fn is_supported_type(data_type: &DataTypeImpl) -> bool {
+        is_vector_type(data_type)
+    }
+
+    /// Create the index of the same column of all the blocks.
+    pub fn try_create(columns: &[&ColumnRef]) -> Result<Self> {
+        let mut vectors: Vec<Vec<f64>> = vec![];
+        let mut unbounded = false;
+        for column in columns {
+            let column = column.convert_full_column();
+            let (array_column, validity) = if column.is_nullable() {
+                let nullable: &NullableColumn = Series::check_get(&column)?;
+                (
+                    Series::check_get::<ArrayColumn>(nullable.inner())?.clone(),
+                    Some(nullable.ensure_validity().clone()),
+                )
+            } else {
+                (Series::check_get::<ArrayColumn>(&column)?.clone(), None)
+            };
+            for row in 0..array_column.len() {
+                if matches!(&validity, Some(validity) if !validity.get_bit(row)) {
+                    unbounded = true;
+                    continue;
+                }
+                let mut vector = vec![];
+                match read_vector(&array_column, row, &mut vector) {
+                    Ok(_) => vectors.push(vector),
+                    Err(_) => unbounded = true,
+                }
+            }
+        }
+
+        let dimension = vectors.first().map_or(0, |vector| vector.len());
+        if vectors.iter().any(|vector| vector.len() != dimension) {
+            unbounded = true;
+        }
+        let normalized = vectors
+            .iter()
+            .map(|vector| {
+                let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+                vector.iter().map(|x| x / norm).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        if normalized.iter().flatten().any(|x| !x.is_finite()) {
+            unbounded = true;
+        }
+        if unbounded || vectors.is_empty() {
+            return Ok(Self {
+                dimension,
+                unbounded,
+                clusters: vec![],
+                normalized_clusters: vec![],
+            });
+        }
+
+        Ok(Self {
+            dimension,
+            unbounded,
+            clusters: build_clusters(&vectors),
+            normalized_clusters: build_clusters(&normalized),
+        })
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.unbounded
+    }
+
+    pub fn num_clusters(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// The bounds of the distances from the query vector to the vectors of each cluster, none if
+    /// the index is unbounded or of another dimension.
+    pub fn bounds(&self, distance: VectorDistance, query: &[f64]) -> Option<Vec<VectorBound>> {
+        if self.unbounded || query.len() != self.dimension {
+            return None;
+        }
+        match distance {
+            VectorDistance::L2 => Some(
+                self.clusters
+                    .iter()
+                    .map(|cluster| {
+                        let d = euclidean(query, &cluster.centroid);
+                        VectorBound {
+                            lower: ((d - cluster.radius) * (1.0 - EPSILON)).max(0.0),
+                            upper: (d + cluster.radius) * (1.0 + EPSILON),
+                            count: cluster.count,
+                        }
+                    })
+                    .collect(),
+            ),
+            VectorDistance::Cosine => {
+                let norm = query.iter().map(|x| x * x).sum::<f64>().sqrt();
+                let query = query.iter().map(|x| x / norm).collect::<Vec<_>>();
+                if query.iter().any(|x| !x.is_finite()) {
+                    return None;
+                }
+                Some(
+                    self.normalized_clusters
+                        .iter()
+                        .map(|cluster| {
+                            let d = euclidean(&query, &cluster.centroid);
+                            let lower = (d - cluster.radius).max(0.0);
+                            let upper = d + cluster.radius;
+                            VectorBound {
+                                lower: lower * lower / 2.0 * (1.0 - EPSILON) - EPSILON,
+                                upper: upper * upper / 2.0 * (1.0 + EPSILON) + EPSILON,
+                                count: cluster.count,
+                            }
+                        })
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    /// The dimension, the flag of unbounded, the numbers of the clusters, and the clusters of
+    /// the count, the radius and the centroid, in little endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        bytes.push(self.unbounded as u8);
+        for clusters in [&self.clusters, &self.normalized_clusters] {
+            bytes.extend_from_slice(&(clusters.len() as u32).to_le_bytes());
+            for cluster in clusters.iter() {
+                bytes.extend_from_slice(&cluster.count.to_le_bytes());
+                bytes.extend_from_slice(&cluster.radius.to_le_bytes());
+                for x in &cluster.centroid {
+                    bytes.extend_from_slice(&x.to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = BytesReader { bytes, pos: 0 };
+        let dimension = u32::from_le_bytes(reader.read()?) as usize;
+        let unbounded = reader.read::<1>()?[0] != 0;
+        let mut clusters = [vec![], vec![]];
+        for clusters in clusters.iter_mut() {
+            let len = u32::from_le_bytes(reader.read()?) as usize;
+            for _ in 0..len {
+                let count = u64::from_le_bytes(reader.read()?);
+                let radius = f64::from_le_bytes(reader.read()?);
+                let mut centroid = Vec::with_capacity(dimension);
+                for _ in 0..dimension {
+                    centroid.push(f32::from_le_bytes(reader.read()?));
+                }
+                clusters.push(VectorCluster {
+                    centroid,
+                    radius,
+                    count,
+                });
+            }
+        }
+        let [clusters, normalized_clusters] = clusters;
+        Ok(Self {
+            dimension,
+            unbounded,
+            clusters,
+            normalized_clusters,
+        })
+    }
+}
+
+struct BytesReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BytesReader<'a> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let end = self.pos + N;
+        if end > self.bytes.len() {
+            return Err(ErrorCode::StorageOther(
+                "invalid vector index, unexpected end",
+            ));
+        }
+        let mut buf = [0; N];
+        buf.copy_from_slice(&self.bytes[self.pos..end]);
+        self.pos = end;
+        Ok(buf)
+    }
+}
+
+fn euclidean<T: Copy + Into<f64>>(a: &[f64], b: &[T]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - (*y).into()) * (x - (*y).into()))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// The clusters of the vectors by k-means, of which the initial centroids are the evenly spaced
+// vectors, so the index of the same vectors is always the same.
+fn build_clusters(vectors: &[Vec<f64>]) -> Vec<VectorCluster> {
+    let k = ((vectors.len() as f64).sqrt() as usize).clamp(1, MAX_CLUSTERS);
+    let mut centroids = (0..k)
+        .map(|i| vectors[i * vectors.len() / k].clone())
+        .collect::<Vec<_>>();
+    let mut assignments = vec![0; vectors.len()];
+    for iteration in 0..=KMEANS_ITERATIONS {
+        for (vector, assignment) in vectors.iter().zip(assignments.iter_mut()) {
+            *assignment = (0..k)
+                .min_by(|a, b| {
+                    euclidean(vector, &centroids[*a]).total_cmp(&euclidean(vector, &centroids[*b]))
+                })
+                .unwrap();
+        }
+        if iteration == KMEANS_ITERATIONS {
+            break;
+        }
+        let mut sums = vec![vec![0.0; vectors[0].len()]; k];
+        let mut counts = vec![0usize; k];
+        for (vector, assignment) in vectors.iter().zip(assignments.iter()) {
+            counts[*assignment] += 1;
+            for (sum, x) in sums[*assignment].iter_mut().zip(vector.iter()) {
+                *sum += x;
+            }
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            if count > 0 {
+                *centroid = sum.into_iter().map(|x| x / count as f64).collect();
+            }
+        }
+    }
+
+    // The radius is of the centroid rounded to f32, which is the one kept in the index.
+    let mut clusters = centroids
+        .into_iter()
+        .map(|centroid| VectorCluster {
+            centroid: centroid.into_iter().map(|x| x as f32).collect(),
+            radius: 0.0,
+            count: 0,
+        })
+        .collect::<Vec<_>>();
+    for (vector, assignment) in vectors.iter().zip(assignments.iter()) {
+        let cluster = &mut clusters[*assignment];
+        cluster.radius = cluster.radius.max(euclidean(vector, &cluster.centroid));
+        cluster.count += 1;
+    }
+    clusters.retain(|cluster| cluster.count > 0);
+    clusters
+}
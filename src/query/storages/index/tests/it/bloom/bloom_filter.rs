@@ -28,6 +28,7 @@ use common_datavalues::ToDataType;
 use common_exception::Result;
 use common_storages_index::BloomFilter;
 use common_storages_index::BloomFilterExprEvalResult;
+use common_storages_index::IndexColumns;
 
 #[test]
 fn test_column_type_support() -> Result<()> {
@@ -47,7 +48,7 @@ fn test_column_type_support() -> Result<()> {
         Series::from_data(strs),
     ]);
 
-    let index = BloomFilter::try_create(&[&block])?;
+    let index = BloomFilter::try_create(&[&block], &IndexColumns::default())?;
 
     // only one index column should be generated
     assert_eq!(1, index.bloom_block.columns().len());
@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_legacy_expression::col;
+use common_legacy_expression::lit;
+use common_legacy_expression::LegacyExpression;
+use common_storages_index::BloomFilter;
+use common_storages_index::BloomFilterExprEvalResult;
+use common_storages_index::IndexColumns;
+use common_storages_index::InvertedIndex;
+
+fn text_match(func: &str, column: &str, text: &str) -> LegacyExpression {
+    LegacyExpression::ScalarFunction {
+        op: func.to_string(),
+        args: vec![col(column), lit(text.as_bytes())],
+    }
+}
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", i64::to_data_type()),
+        DataField::new("message", StringType::new_impl()),
+        DataField::new("level", NullableType::new_impl(StringType::new_impl())),
+    ]);
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![1i64, 2]),
+        Series::from_data(vec!["Connection refused: db-1", "request timeout"]),
+        Series::from_data(vec![Some("ERROR"), None]),
+    ]);
+
+    let columns = IndexColumns {
+        inverted: vec!["message".to_string(), "level".to_string(), "id".to_string()],
+        vector: vec![],
+    };
+    let index = BloomFilter::try_create(&[&block], &columns)?;
+
+    // only the string columns have the inverted indexes
+    let schema = index.bloom_block.schema();
+    assert!(schema.has_field(&InvertedIndex::to_inverted_column_name("message")));
+    assert!(schema.has_field(&InvertedIndex::to_inverted_column_name("level")));
+    assert!(!schema.has_field(&InvertedIndex::to_inverted_column_name("id")));
+
+    let bytes = index.bloom_block.first("Inverted(message)")?.as_string()?;
+    let inverted_index = InvertedIndex::from_bytes(&bytes)?;
+    assert_eq!(inverted_index.num_tokens(), 6);
+    assert!(inverted_index.contains("refused"));
+    assert!(!inverted_index.contains("Refused"));
+
+    let cases = [
+        (
+            text_match("match", "message", "connection REFUSED"),
+            BloomFilterExprEvalResult::Unknown,
+        ),
+        (
+            text_match("match", "message", "connection timeout panic"),
+            BloomFilterExprEvalResult::False,
+        ),
+        (
+            text_match("query", "message", "panic OR timeout"),
+            BloomFilterExprEvalResult::Unknown,
+        ),
+        (
+            text_match("query", "message", "panic OR -timeout"),
+            BloomFilterExprEvalResult::Unknown,
+        ),
+        (
+            text_match("query", "message", "panic OR oom"),
+            BloomFilterExprEvalResult::False,
+        ),
+        (
+            text_match("match", "level", "warn"),
+            BloomFilterExprEvalResult::False,
+        ),
+        (
+            text_match("match", "id", "1"),
+            BloomFilterExprEvalResult::NotApplicable,
+        ),
+        (
+            text_match("match", "message", "panic").and(col("id").eq(lit(3i64))),
+            BloomFilterExprEvalResult::False,
+        ),
+    ];
+    for (expr, expected) in cases {
+        assert_eq!(index.eval(&expr)?, expected, "{:?}", expr);
+    }
+    Ok(())
+}
@@ -13,4 +13,6 @@
 // limitations under the License.
 
 mod bloom_filter;
+mod inverted_index;
+mod vector_index;
 mod xor8;
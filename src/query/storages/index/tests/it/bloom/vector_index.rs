@@ -0,0 +1,130 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::VectorDistance;
+use common_storages_index::BloomFilter;
+use common_storages_index::IndexColumns;
+use common_storages_index::VectorIndex;
+
+fn vectors(vectors: &[Vec<f32>]) -> ColumnRef {
+    let mut offsets = vec![0i64];
+    for vector in vectors {
+        offsets.push(offsets.last().unwrap() + vector.len() as i64);
+    }
+    Arc::new(ArrayColumn::from_data(
+        ArrayType::new_impl(Float32Type::new_impl()),
+        offsets.into(),
+        Series::from_data(vectors.concat()),
+    ))
+}
+
+fn vector_index(column: ColumnRef) -> Result<VectorIndex> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "embedding",
+        ArrayType::new_impl(Float32Type::new_impl()),
+    )]);
+    let block = DataBlock::create(schema, vec![column]);
+    let columns = IndexColumns {
+        inverted: vec![],
+        vector: vec!["embedding".to_string()],
+    };
+    let index = BloomFilter::try_create(&[&block], &columns)?;
+    Ok(index.try_get_vector_index("embedding")?.unwrap())
+}
+
+#[test]
+fn test_vector_index() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", i64::to_data_type()),
+        DataField::new("embedding", ArrayType::new_impl(Float32Type::new_impl())),
+    ]);
+    // the points of the 10x10 grid
+    let points = (0..100)
+        .map(|i| vec![(i % 10) as f32 + 1.0, (i / 10) as f32 * 0.5 + 1.0])
+        .collect::<Vec<_>>();
+    let block = DataBlock::create(schema, vec![
+        Series::from_data((0..100).collect::<Vec<i64>>()),
+        vectors(&points),
+    ]);
+
+    let columns = IndexColumns {
+        inverted: vec![],
+        vector: vec!["embedding".to_string(), "id".to_string()],
+    };
+    let index = BloomFilter::try_create(&[&block], &columns)?;
+
+    // only the array columns have the vector indexes
+    let schema = index.bloom_block.schema();
+    assert!(schema.has_field(&VectorIndex::to_vector_column_name("embedding")));
+    assert!(!schema.has_field(&VectorIndex::to_vector_column_name("id")));
+    assert!(index.try_get_vector_index("id")?.is_none());
+
+    let vector_index = index.try_get_vector_index("embedding")?.unwrap();
+    assert_eq!(vector_index.num_clusters(), 10);
+    assert!(!vector_index.is_unbounded());
+    assert_eq!(
+        VectorIndex::from_bytes(&vector_index.to_bytes())?,
+        vector_index
+    );
+
+    // the distances of all the vectors are within the bounds
+    let points = points
+        .iter()
+        .map(|point| point.iter().map(|x| *x as f64).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for query in [vec![0.1, 0.0], vec![5.5, 3.0], vec![-3.0, 20.0]] {
+        for distance in [VectorDistance::L2, VectorDistance::Cosine] {
+            let bounds = vector_index.bounds(distance, &query).unwrap();
+            assert_eq!(bounds.iter().map(|bound| bound.count).sum::<u64>(), 100);
+            let lower = bounds.iter().map(|b| b.lower).fold(f64::MAX, f64::min);
+            let upper = bounds.iter().map(|b| b.upper).fold(f64::MIN, f64::max);
+            for point in &points {
+                let d = distance.eval(&query, point);
+                assert!(lower <= d && d <= upper, "{:?} {:?} {}", distance, query, d);
+            }
+        }
+    }
+
+    // the query vector of another dimension, and the zero vector has no direction
+    assert!(vector_index.bounds(VectorDistance::L2, &[1.0]).is_none());
+    assert!(
+        vector_index
+            .bounds(VectorDistance::Cosine, &[0.0, 0.0])
+            .is_none()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unbounded_vector_index() -> Result<()> {
+    // the zero vector
+    let index = vector_index(vectors(&[vec![1.0, 2.0], vec![0.0, 0.0]]))?;
+    assert!(index.is_unbounded());
+    assert_eq!(index.num_clusters(), 0);
+    assert!(index.bounds(VectorDistance::L2, &[1.0, 2.0]).is_none());
+
+    // the vectors of different dimensions
+    let index = vector_index(vectors(&[vec![1.0, 2.0], vec![1.0, 2.0, 3.0]]))?;
+    assert!(index.is_unbounded());
+
+    let index = vector_index(vectors(&[vec![1.0, 2.0], vec![3.0, 4.0]]))?;
+    assert!(!index.is_unbounded());
+    assert_eq!(index.num_clusters(), 1);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::storages::view::view_table::QUERY;
+use crate::storages::view::ViewTable;
+use crate::storages::Table;
+
+// There is no primary key or foreign key in the tables, the view is always empty.
+pub struct KeyColumnUsageTable {}
+
+impl KeyColumnUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let query = "SELECT
+            'default' AS constraint_catalog,
+            database AS constraint_schema,
+            '' AS constraint_name,
+            'default' AS table_catalog,
+            database AS table_schema,
+            table AS table_name,
+            name AS column_name,
+            0 AS ordinal_position,
+            NULL AS position_in_unique_constraint,
+            NULL AS referenced_table_schema,
+            NULL AS referenced_table_name,
+            NULL AS referenced_column_name
+        FROM system.columns
+        WHERE 1 = 0;";
+
+        let mut options = BTreeMap::new();
+        options.insert(QUERY.to_string(), query.to_string());
+        let table_info = TableInfo {
+            desc: "'INFORMATION_SCHEMA'.'KEY_COLUMN_USAGE'".to_string(),
+            name: "KEY_COLUMN_USAGE".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                options,
+                engine: "VIEW".to_string(),
+                ..Default::default()
+            },
+        };
+
+        ViewTable::create(table_info)
+    }
+}
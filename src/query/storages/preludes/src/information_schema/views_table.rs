@@ -28,7 +28,7 @@ pub struct ViewsTable {}
 impl ViewsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT
-            database AS table_catalog,
+            'default' AS table_catalog,
             database AS table_schema,
             name AS table_name,
             NULL AS view_definition,
@@ -13,11 +13,18 @@
 //  limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::io::Cursor;
+use std::io::ErrorKind;
 use std::sync::Arc;
 
+use common_arrow::arrow::io::parquet::read::read_metadata;
+use common_arrow::arrow::io::parquet::read::FileReader;
+use common_datablocks::serialize_data_blocks;
 use common_datablocks::DataBlock;
+use common_datablocks::InMemoryBlocks;
 use common_datavalues::ColumnRef;
 use common_datavalues::DataType;
 use common_datavalues::Series;
@@ -31,8 +38,9 @@ use common_legacy_planners::Projection;
 use common_legacy_planners::ReadDataSourcePlan;
 use common_legacy_planners::Statistics;
 use common_meta_app::schema::TableInfo;
+use futures::lock::MutexGuard;
+use opendal::Operator;
 use parking_lot::Mutex;
-use parking_lot::RwLock;
 
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
@@ -49,31 +57,64 @@ use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
 
+pub const MEMORY_OPT_KEY_PERSISTENT: &str = "persistent";
+
 pub struct MemoryTable {
     table_info: TableInfo,
-    blocks: Arc<RwLock<Vec<DataBlock>>>,
+    data: Arc<InMemoryBlocks>,
+    // Whether the blocks are written to a snapshot in the storage on every commit,
+    // and loaded from it by the first query after the server is restarted.
+    persistent: bool,
 }
 
 impl MemoryTable {
     pub fn try_create(ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
         let table_id = &table_info.ident.table_id;
-        let blocks = {
+        let persistent = Self::is_persistent(table_info.options())?;
+        let data = {
             let mut in_mem_data = ctx.in_memory_data.write();
             let x = in_mem_data.get(table_id);
             match x {
                 None => {
-                    let blocks = Arc::new(RwLock::new(vec![]));
-                    in_mem_data.insert(*table_id, blocks.clone());
-                    blocks
+                    let data = Arc::new(InMemoryBlocks::default());
+                    in_mem_data.insert(*table_id, data.clone());
+                    data
                 }
-                Some(blocks) => blocks.clone(),
+                Some(data) => data.clone(),
             }
         };
 
-        let table = Self { table_info, blocks };
+        let table = Self {
+            table_info,
+            data,
+            persistent,
+        };
         Ok(Box::new(table))
     }
 
+    /// Creates the table of its own blocks, not shared with the other instances of the table by
+    /// the id, e.g. of the temporary tables, whose blocks are dropped with the table.
+    pub fn create_temporary(table_info: TableInfo) -> Box<dyn Table> {
+        Box::new(Self {
+            table_info,
+            data: Arc::new(InMemoryBlocks::default()),
+            persistent: false,
+        })
+    }
+
+    /// Parse the `persistent = true|false` option of the table, false by default.
+    pub fn is_persistent(options: &BTreeMap<String, String>) -> Result<bool> {
+        match options.get(MEMORY_OPT_KEY_PERSISTENT) {
+            None => Ok(false),
+            Some(v) => v.trim().to_lowercase().parse::<bool>().map_err(|_| {
+                ErrorCode::BadOption(format!(
+                    "invalid value of option {}: {}, expects true or false",
+                    MEMORY_OPT_KEY_PERSISTENT, v
+                ))
+            }),
+        }
+    }
+
     pub fn description() -> StorageDescription {
         StorageDescription {
             engine_name: "MEMORY".to_string(),
@@ -82,8 +123,61 @@ impl MemoryTable {
         }
     }
 
+    fn snapshot_location(&self) -> String {
+        format!(
+            "_memory/{}/snapshot.parquet",
+            self.table_info.ident.table_id
+        )
+    }
+
+    // Loads the blocks from the snapshot if they are not loaded yet, the returned guard
+    // is held by the commits until the new snapshot is written.
+    async fn load_snapshot(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<Option<MutexGuard<'_, bool>>> {
+        if !self.persistent {
+            return Ok(None);
+        }
+
+        let mut loaded = self.data.snapshot_loaded.lock().await;
+        if !*loaded {
+            let operator = ctx.get_storage_operator()?;
+            let blocks = match operator.object(&self.snapshot_location()).read().await {
+                Ok(bytes) => self.deserialize_blocks(bytes)?,
+                Err(e) if e.kind() == ErrorKind::NotFound => vec![],
+                Err(e) => return Err(e.into()),
+            };
+            *self.data.blocks.write() = blocks;
+            *loaded = true;
+        }
+        Ok(Some(loaded))
+    }
+
+    // Writes the blocks to the snapshot, each block is a row group of the parquet file.
+    async fn write_snapshot(&self, operator: Operator, blocks: Vec<DataBlock>) -> Result<()> {
+        let location = self.snapshot_location();
+        if blocks.is_empty() {
+            return Ok(operator.object(&location).delete().await?);
+        }
+        let mut bytes = vec![];
+        serialize_data_blocks(blocks, self.table_info.schema(), &mut bytes)?;
+        Ok(operator.object(&location).write(bytes).await?)
+    }
+
+    fn deserialize_blocks(&self, bytes: Vec<u8>) -> Result<Vec<DataBlock>> {
+        let schema = self.table_info.schema();
+        let mut reader = Cursor::new(bytes);
+        let metadata = read_metadata(&mut reader)?;
+        let arrow_schema = schema.to_arrow();
+        let chunks = FileReader::new(reader, metadata.row_groups, arrow_schema, None, None, None);
+        chunks
+            .map(|chunk| DataBlock::from_chunk(&schema, &chunk?))
+            .collect()
+    }
+
     fn get_read_data_blocks(&self) -> Arc<Mutex<VecDeque<DataBlock>>> {
-        let data_blocks = self.blocks.read();
+        let data_blocks = self.data.blocks.read();
         let mut read_data_blocks = VecDeque::with_capacity(data_blocks.len());
 
         for data_block in data_blocks.iter() {
@@ -138,7 +232,8 @@ impl Table for MemoryTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<Extras>,
     ) -> Result<(Statistics, Partitions)> {
-        let blocks = self.blocks.read();
+        drop(self.load_snapshot(&ctx).await?);
+        let blocks = self.data.blocks.read();
 
         let statistics = match push_downs {
             Some(push_downs) => {
@@ -239,20 +334,36 @@ impl Table for MemoryTable {
             .get_metrics()
             .inc_write_bytes(written_bytes);
 
+        // The snapshot is written before the blocks are changed, the commits of a
+        // persistent table are in order as the guard of the snapshot is held.
+        if let Some(_guard) = self.load_snapshot(&ctx).await? {
+            let mut blocks = match overwrite {
+                true => vec![],
+                false => self.data.blocks.read().clone(),
+            };
+            blocks.extend(operations);
+            self.write_snapshot(ctx.get_storage_operator()?, blocks.clone())
+                .await?;
+            *self.data.blocks.write() = blocks;
+            return Ok(());
+        }
+
+        let mut blocks = self.data.blocks.write();
         if overwrite {
-            let mut blocks = self.blocks.write();
             blocks.clear();
         }
-        let mut blocks = self.blocks.write();
         for block in operations {
             blocks.push(block);
         }
         Ok(())
     }
 
-    async fn truncate(&self, _ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
-        let mut blocks = self.blocks.write();
-        blocks.clear();
+    async fn truncate(&self, ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
+        if let Some(_guard) = self.load_snapshot(&ctx).await? {
+            self.write_snapshot(ctx.get_storage_operator()?, vec![])
+                .await?;
+        }
+        self.data.blocks.write().clear();
         Ok(())
     }
 }
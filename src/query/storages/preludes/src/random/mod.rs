@@ -12,8 +12,19 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod random_generator;
+mod random_options;
 mod random_parts;
 mod random_table;
 
+pub use random_generator::column_rng;
+pub use random_generator::generate_column;
+pub use random_options::ColumnGenerator;
+pub use random_options::Distribution;
+pub use random_options::RandomOptions;
+pub use random_options::RANDOM_OPT_KEY_COLUMN_DISTRIBUTION;
+pub use random_options::RANDOM_OPT_KEY_COLUMN_LENGTH;
+pub use random_options::RANDOM_OPT_KEY_COLUMN_NULL_RATIO;
+pub use random_options::RANDOM_OPT_KEY_SEED;
 pub use random_parts::RandomPartInfo;
 pub use random_table::RandomTable;
@@ -0,0 +1,174 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rand::distributions::Alphanumeric;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+use rand_distr::Distribution as _;
+use rand_distr::Normal;
+use rand_distr::Zipf;
+
+use super::random_options::ColumnGenerator;
+use super::random_options::Distribution;
+
+const DEFAULT_STRING_LENGTH: usize = 5;
+const DEFAULT_NULL_RATIO: f64 = 0.5;
+
+/// The rng of a column of a part, seeded by the seed of the part and the name of the
+/// column, so the values of a column do not depend on the other columns read.
+pub fn column_rng(part_seed: Option<u64>, column: &str) -> SmallRng {
+    match part_seed {
+        Some(seed) => {
+            // FNV-1a
+            let hash = column.bytes().fold(0xcbf29ce484222325u64, |h, b| {
+                (h ^ b as u64).wrapping_mul(0x100000001b3)
+            });
+            SmallRng::seed_from_u64(seed ^ hash)
+        }
+        None => SmallRng::from_entropy(),
+    }
+}
+
+enum Sampler {
+    // The whole range of the type.
+    Full,
+    Uniform { min: f64, max: f64 },
+    Zipf(Zipf<f64>),
+    Normal(Normal<f64>),
+}
+
+impl Sampler {
+    fn try_create(distribution: Option<Distribution>) -> Result<Sampler> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            ErrorCode::BadOption(format!("invalid distribution {:?}: {}", distribution, e))
+        };
+        match distribution {
+            None => Ok(Sampler::Full),
+            Some(Distribution::Uniform { min, max }) => Ok(Sampler::Uniform { min, max }),
+            Some(Distribution::Zipf { n, s }) => {
+                Ok(Sampler::Zipf(Zipf::new(n, s).map_err(|e| invalid(&e))?))
+            }
+            Some(Distribution::Normal { mean, std_dev }) => Ok(Sampler::Normal(
+                Normal::new(mean, std_dev).map_err(|e| invalid(&e))?,
+            )),
+        }
+    }
+
+    fn sample_integer(&self, type_id: TypeID, rng: &mut SmallRng) -> DataValue {
+        let (type_min, type_max) = integer_range(type_id);
+        let value = match self {
+            Sampler::Full => rng.gen_range(type_min..=type_max),
+            Sampler::Uniform { min, max } => {
+                let (min, max) = (min.ceil() as i128, max.floor() as i128);
+                match min <= max {
+                    true => rng.gen_range(min..=max),
+                    // No integer in the range.
+                    false => min,
+                }
+            }
+            Sampler::Zipf(zipf) => zipf.sample(rng) as i128,
+            Sampler::Normal(normal) => normal.sample(rng).round() as i128,
+        };
+        let value = value.clamp(type_min, type_max);
+        match type_id.is_unsigned_integer() {
+            true => DataValue::UInt64(value as u64),
+            false => DataValue::Int64(value as i64),
+        }
+    }
+
+    fn sample_float(&self, type_id: TypeID, rng: &mut SmallRng) -> DataValue {
+        let value = match self {
+            Sampler::Full if type_id == TypeID::Float32 => rng.gen::<f32>() as f64,
+            Sampler::Full => rng.gen::<f64>(),
+            Sampler::Uniform { min, max } => rng.gen_range(*min..=*max),
+            Sampler::Zipf(zipf) => zipf.sample(rng),
+            Sampler::Normal(normal) => normal.sample(rng),
+        };
+        DataValue::Float64(value)
+    }
+}
+
+fn integer_range(type_id: TypeID) -> (i128, i128) {
+    match type_id {
+        TypeID::Int8 => (i8::MIN as i128, i8::MAX as i128),
+        TypeID::Int16 => (i16::MIN as i128, i16::MAX as i128),
+        TypeID::Int32 => (i32::MIN as i128, i32::MAX as i128),
+        TypeID::UInt8 => (0, u8::MAX as i128),
+        TypeID::UInt16 => (0, u16::MAX as i128),
+        TypeID::UInt32 => (0, u32::MAX as i128),
+        TypeID::UInt64 => (0, u64::MAX as i128),
+        _ => (i64::MIN as i128, i64::MAX as i128),
+    }
+}
+
+/// Generates a column of `rows` values with the rng.
+///
+/// The booleans, numbers, strings, dates, timestamps and the nullable ones of them
+/// are generated as the generator specifies. The other types are generated by
+/// [`DataType::create_random_column`], with no respect to the rng.
+pub fn generate_column(
+    data_type: &DataTypeImpl,
+    generator: &ColumnGenerator,
+    rng: &mut SmallRng,
+    rows: usize,
+) -> Result<ColumnRef> {
+    let inner_type = remove_nullable(data_type);
+    let type_id = inner_type.data_type_id();
+    let supported = type_id.is_numeric()
+        || type_id.is_string()
+        || matches!(type_id, TypeID::Boolean | TypeID::Date | TypeID::Timestamp);
+    if !supported {
+        return Ok(data_type.create_random_column(rows));
+    }
+
+    let null_ratio = match data_type.is_nullable() {
+        true => generator.null_ratio.unwrap_or(DEFAULT_NULL_RATIO),
+        false => 0.0,
+    };
+    let (min_length, max_length) = generator
+        .length
+        .unwrap_or((DEFAULT_STRING_LENGTH, DEFAULT_STRING_LENGTH));
+    let sampler = Sampler::try_create(generator.distribution)?;
+
+    let mut values = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        if null_ratio > 0.0 && rng.gen_bool(null_ratio) {
+            values.push(DataValue::Null);
+            continue;
+        }
+
+        let value = match type_id {
+            TypeID::Boolean => DataValue::Boolean(rng.gen()),
+            TypeID::Date => DataValue::Int64(rng.gen_range(DATE_MIN..=DATE_MAX) as i64),
+            TypeID::Timestamp => DataValue::Int64(rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX)),
+            _ if type_id.is_string() => {
+                let length = rng.gen_range(min_length..=max_length);
+                DataValue::String(
+                    (&mut *rng)
+                        .sample_iter(&Alphanumeric)
+                        .take(length)
+                        .collect(),
+                )
+            }
+            _ if type_id.is_floating() => sampler.sample_float(type_id, rng),
+            _ => sampler.sample_integer(type_id, rng),
+        };
+        values.push(value);
+    }
+    data_type.create_column(&values)
+}
@@ -0,0 +1,258 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub const RANDOM_OPT_KEY_SEED: &str = "seed";
+pub const RANDOM_OPT_KEY_COLUMN_DISTRIBUTION: &str = "column_distribution";
+pub const RANDOM_OPT_KEY_COLUMN_LENGTH: &str = "column_length";
+pub const RANDOM_OPT_KEY_COLUMN_NULL_RATIO: &str = "column_null_ratio";
+
+/// The distribution of the values of a numeric column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    /// The values in `[min, max]`.
+    Uniform {
+        min: f64,
+        max: f64,
+    },
+    /// The ranks in `[1, n]`, the frequency of a rank is inversely proportional to
+    /// its power of the exponent `s`.
+    Zipf {
+        n: u64,
+        s: f64,
+    },
+    Normal {
+        mean: f64,
+        std_dev: f64,
+    },
+}
+
+impl Distribution {
+    /// Parse `uniform(<min>, <max>)`, `zipf(<n>, <s>)` or `normal(<mean>, <std_dev>)`.
+    pub fn try_parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let invalid = || {
+            ErrorCode::BadOption(format!(
+                "invalid distribution: {}, expects one of uniform(<min>, <max>), zipf(<n>, <s>) and normal(<mean>, <std_dev>)",
+                s
+            ))
+        };
+        let (name, args) = s
+            .strip_suffix(')')
+            .and_then(|v| v.split_once('('))
+            .ok_or_else(invalid)?;
+        let args = args
+            .split(',')
+            .map(|v| v.trim().parse::<f64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>>>()?;
+        if args.len() != 2 || args.iter().any(|v| !v.is_finite()) {
+            return Err(invalid());
+        }
+
+        let (a, b) = (args[0], args[1]);
+        match name.trim().to_lowercase().as_str() {
+            "uniform" if a <= b => Ok(Distribution::Uniform { min: a, max: b }),
+            "zipf" if a >= 1.0 && a.fract() == 0.0 && b > 0.0 => {
+                Ok(Distribution::Zipf { n: a as u64, s: b })
+            }
+            "normal" if b >= 0.0 => Ok(Distribution::Normal {
+                mean: a,
+                std_dev: b,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// How the values of a column are generated, the defaults are used for the unset ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ColumnGenerator {
+    /// The distribution of a numeric column, values of the whole range of the type if None.
+    pub distribution: Option<Distribution>,
+    /// The range of the lengths of the strings, 5 characters if None.
+    pub length: Option<(usize, usize)>,
+    /// The ratio of NULLs of a nullable column, a half if None.
+    pub null_ratio: Option<f64>,
+}
+
+/// The data generated by a random table, specified by the table options
+///
+/// - `seed = <n>` to generate the same rows for every query, random rows if not specified.
+/// - `column_distribution = '<column>=<distribution>, ...'` for the numeric columns.
+/// - `column_length = '<column>=<length>|<min>-<max>, ...'` for the string columns.
+/// - `column_null_ratio = '<column>=<ratio>, ...'` for the nullable columns.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RandomOptions {
+    pub seed: Option<u64>,
+    columns: HashMap<String, ColumnGenerator>,
+}
+
+impl RandomOptions {
+    pub fn try_from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let seed = match options.get(RANDOM_OPT_KEY_SEED) {
+            Some(v) => Some(
+                v.trim()
+                    .parse::<u64>()
+                    .map_err(|_| ErrorCode::BadOption(format!("invalid seed: {}", v)))?,
+            ),
+            None => None,
+        };
+
+        let mut columns: HashMap<String, ColumnGenerator> = HashMap::new();
+        if let Some(v) = options.get(RANDOM_OPT_KEY_COLUMN_DISTRIBUTION) {
+            for (column, value) in parse_column_items(RANDOM_OPT_KEY_COLUMN_DISTRIBUTION, v)? {
+                let generator = columns.entry(column.clone()).or_default();
+                if generator.distribution.is_some() {
+                    return Err(duplicated(RANDOM_OPT_KEY_COLUMN_DISTRIBUTION, &column));
+                }
+                generator.distribution = Some(Distribution::try_parse(&value)?);
+            }
+        }
+        if let Some(v) = options.get(RANDOM_OPT_KEY_COLUMN_LENGTH) {
+            for (column, value) in parse_column_items(RANDOM_OPT_KEY_COLUMN_LENGTH, v)? {
+                let generator = columns.entry(column.clone()).or_default();
+                if generator.length.is_some() {
+                    return Err(duplicated(RANDOM_OPT_KEY_COLUMN_LENGTH, &column));
+                }
+                generator.length = Some(parse_length(&value)?);
+            }
+        }
+        if let Some(v) = options.get(RANDOM_OPT_KEY_COLUMN_NULL_RATIO) {
+            for (column, value) in parse_column_items(RANDOM_OPT_KEY_COLUMN_NULL_RATIO, v)? {
+                let generator = columns.entry(column.clone()).or_default();
+                if generator.null_ratio.is_some() {
+                    return Err(duplicated(RANDOM_OPT_KEY_COLUMN_NULL_RATIO, &column));
+                }
+                let ratio = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|v| (0.0..=1.0).contains(v))
+                    .ok_or_else(|| {
+                        ErrorCode::BadOption(format!(
+                            "invalid null ratio: {}, expects a number in [0, 1]",
+                            value
+                        ))
+                    })?;
+                generator.null_ratio = Some(ratio);
+            }
+        }
+        Ok(RandomOptions { seed, columns })
+    }
+
+    /// Check that the columns are columns of the table, of the types the options apply to.
+    pub fn check_columns(&self, schema: &DataSchema) -> Result<()> {
+        for (column, generator) in &self.columns {
+            let field = schema.field_with_name(column).map_err(|_| {
+                ErrorCode::BadOption(format!("random options of unknown column: {}", column))
+            })?;
+            let data_type = field.data_type();
+            let type_id = remove_nullable(data_type).data_type_id();
+            if generator.distribution.is_some() && !type_id.is_numeric() {
+                return Err(ErrorCode::BadOption(format!(
+                    "distribution of column {} of type {}, expects a numeric column",
+                    column,
+                    data_type.name()
+                )));
+            }
+            if generator.length.is_some() && !type_id.is_string() {
+                return Err(ErrorCode::BadOption(format!(
+                    "length of column {} of type {}, expects a string column",
+                    column,
+                    data_type.name()
+                )));
+            }
+            if generator.null_ratio.is_some() && !data_type.is_nullable() {
+                return Err(ErrorCode::BadOption(format!(
+                    "null ratio of column {} of type {}, expects a nullable column",
+                    column,
+                    data_type.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn has_generators(&self) -> bool {
+        !self.columns.is_empty()
+    }
+
+    pub fn get(&self, column: &str) -> ColumnGenerator {
+        self.columns.get(column).cloned().unwrap_or_default()
+    }
+}
+
+// Splits `<column>=<value>, ...` by the commas out of the parentheses.
+fn parse_column_items(key: &str, s: &str) -> Result<Vec<(String, String)>> {
+    let mut items = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&s[start..]);
+
+    items
+        .into_iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let (column, value) = item.split_once('=').ok_or_else(|| {
+                ErrorCode::BadOption(format!(
+                    "invalid {}: {}, expects `<column>=<value>`",
+                    key, item
+                ))
+            })?;
+            Ok((column.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+// Parse `<length>` or `<min>-<max>`.
+fn parse_length(s: &str) -> Result<(usize, usize)> {
+    let invalid = || {
+        ErrorCode::BadOption(format!(
+            "invalid length: {}, expects `<length>` or `<min>-<max>`",
+            s
+        ))
+    };
+    let parse = |v: &str| v.trim().parse::<usize>().map_err(|_| invalid());
+    let (min, max) = match s.split_once('-') {
+        Some((min, max)) => (parse(min)?, parse(max)?),
+        None => (parse(s)?, parse(s)?),
+    };
+    if min > max {
+        return Err(invalid());
+    }
+    Ok((min, max))
+}
+
+fn duplicated(key: &str, column: &str) -> ErrorCode {
+    ErrorCode::BadOption(format!("duplicated {} of column: {}", key, column))
+}
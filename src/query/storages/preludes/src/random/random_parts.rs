@@ -23,6 +23,8 @@ use common_legacy_planners::PartInfoPtr;
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct RandomPartInfo {
     pub rows: usize,
+    /// The seed of the rows of the part, random rows are generated if None.
+    pub seed: Option<u64>,
 }
 
 #[typetag::serde(name = "random")]
@@ -40,8 +42,8 @@ impl PartInfo for RandomPartInfo {
 }
 
 impl RandomPartInfo {
-    pub fn create(rows: usize) -> Arc<Box<dyn PartInfo>> {
-        Arc::new(Box::new(RandomPartInfo { rows }))
+    pub fn create(rows: usize, seed: Option<u64>) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(RandomPartInfo { rows, seed }))
     }
 
     pub fn from_part(info: &PartInfoPtr) -> Result<&RandomPartInfo> {
@@ -26,6 +26,9 @@ use common_legacy_planners::ReadDataSourcePlan;
 use common_legacy_planners::Statistics;
 use common_meta_app::schema::TableInfo;
 
+use super::column_rng;
+use super::generate_column;
+use super::RandomOptions;
 use super::RandomPartInfo;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::ProcessorPtr;
@@ -40,11 +43,16 @@ use crate::storages::Table;
 
 pub struct RandomTable {
     table_info: TableInfo,
+    options: RandomOptions,
 }
 
 impl RandomTable {
     pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
-        Ok(Box::new(Self { table_info }))
+        let options = RandomOptions::try_from_options(table_info.options())?;
+        Ok(Box::new(Self {
+            table_info,
+            options,
+        }))
     }
 
     pub fn description() -> StorageDescription {
@@ -55,22 +63,26 @@ impl RandomTable {
         }
     }
 
-    pub fn generate_random_parts(workers: usize, total: usize) -> Partitions {
+    /// Splits the rows into the parts of the workers, the seed of each part is derived
+    /// from the seed of the table and the index of the part.
+    pub fn generate_random_parts(workers: usize, total: usize, seed: Option<u64>) -> Partitions {
         let part_size = total / workers;
         let mut part_remain = total % workers;
+        let part_seed =
+            |index: usize| seed.map(|seed| seed ^ (index as u64).wrapping_mul(0x9e3779b97f4a7c15));
 
         let mut partitions = Vec::with_capacity(workers);
         if part_size == 0 {
-            partitions.push(RandomPartInfo::create(total));
+            partitions.push(RandomPartInfo::create(total, part_seed(0)));
         } else {
-            for _ in 0..workers {
+            for index in 0..workers {
                 let rows = if part_remain > 0 {
                     part_remain -= 1;
                     part_size + 1
                 } else {
                     part_size
                 };
-                partitions.push(RandomPartInfo::create(rows));
+                partitions.push(RandomPartInfo::create(rows, part_seed(index)));
             }
         }
         partitions
@@ -134,7 +146,7 @@ impl Table for RandomTable {
         if worker_num > parts_num {
             worker_num = parts_num;
         }
-        let parts = Self::generate_random_parts(worker_num, total_rows);
+        let parts = Self::generate_random_parts(worker_num, total_rows, self.options.seed);
 
         Ok((statistics, parts))
     }
@@ -171,7 +183,13 @@ impl Table for RandomTable {
             let parts = RandomPartInfo::from_part(&plan.parts[index])?;
             builder.add_source(
                 output.clone(),
-                RandomSource::create(ctx.clone(), output, output_schema.clone(), parts.rows)?,
+                RandomSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    self.options.clone(),
+                    parts,
+                )?,
             );
         }
 
@@ -182,8 +200,10 @@ impl Table for RandomTable {
 
 struct RandomSource {
     schema: DataSchemaRef,
+    options: RandomOptions,
     /// how many rows are needed to generate
     rows: usize,
+    seed: Option<u64>,
 }
 
 impl RandomSource {
@@ -191,9 +211,15 @@ impl RandomSource {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         schema: DataSchemaRef,
-        rows: usize,
+        options: RandomOptions,
+        part: &RandomPartInfo,
     ) -> Result<ProcessorPtr> {
-        SyncSourcer::create(ctx, output, RandomSource { schema, rows })
+        SyncSourcer::create(ctx, output, RandomSource {
+            schema,
+            options,
+            rows: part.rows,
+            seed: part.seed,
+        })
     }
 }
 
@@ -210,8 +236,16 @@ impl SyncSource for RandomSource {
             .schema
             .fields()
             .iter()
-            .map(|f| f.data_type().create_random_column(self.rows))
-            .collect();
+            .map(|f| {
+                let mut rng = column_rng(self.seed, f.name());
+                generate_column(
+                    f.data_type(),
+                    &self.options.get(f.name()),
+                    &mut rng,
+                    self.rows,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         // The partition garantees the number of rows is less than or equal to `max_block_size`.
         // And we generate all the `self.rows` at once.
@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde::Serialize;
+
+use crate::system::query_log_table::datetime_str;
+use crate::system::SystemLogElement;
+use crate::system::SystemLogQueue;
+use crate::system::SystemLogTable;
+
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    // One of `auth`, `ddl`, `dml` and `privilege`.
+    pub category: String,
+    // The kind of the query, or `Login` of the authentication.
+    pub action: String,
+
+    // User.
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub user: String,
+
+    // Client.
+    pub handler_type: String,
+    pub client_address: String,
+
+    // Query.
+    pub query_id: String,
+    pub query_text: String,
+    pub query_tag: String,
+
+    // Result.
+    pub success: bool,
+    pub exception_code: i32,
+    pub exception_text: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+    const APPEND_ONLY: bool = true;
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("event_time", TimestampType::new_impl(3)),
+            DataField::new("category", Vu8::to_data_type()),
+            DataField::new("action", Vu8::to_data_type()),
+            // User.
+            DataField::new("tenant_id", Vu8::to_data_type()),
+            DataField::new("cluster_id", Vu8::to_data_type()),
+            DataField::new("user", Vu8::to_data_type()),
+            // Client.
+            DataField::new("handler_type", Vu8::to_data_type()),
+            DataField::new("client_address", Vu8::to_data_type()),
+            // Query.
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("query_tag", Vu8::to_data_type()),
+            // Result.
+            DataField::new("success", bool::to_data_type()),
+            DataField::new("exception_code", i32::to_data_type()),
+            DataField::new("exception_text", Vu8::to_data_type()),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<Box<dyn MutableColumn>>) -> Result<()> {
+        let strings = [
+            &self.category,
+            &self.action,
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.user,
+            &self.handler_type,
+            &self.client_address,
+            &self.query_id,
+            &self.query_text,
+            &self.query_tag,
+        ];
+
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Int64(self.event_time))?;
+        for value in strings {
+            columns
+                .next()
+                .unwrap()
+                .append_data_value(DataValue::String(value.as_bytes().to_vec()))?;
+        }
+        // Result.
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Boolean(self.success))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Int64(self.exception_code as i64))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::String(self.exception_text.as_bytes().to_vec()))
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
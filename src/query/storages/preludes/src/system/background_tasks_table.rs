@@ -0,0 +1,115 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::system::SyncOneBlockSystemTable;
+use crate::system::SyncSystemTable;
+
+/// The background tasks of the node, with their schedules and their last runs.
+pub struct BackgroundTasksTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for BackgroundTasksTable {
+    const NAME: &'static str = "system.background_tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tasks = ctx.get_background_tasks_info();
+
+        let mut names = Vec::with_capacity(tasks.len());
+        let mut kinds = Vec::with_capacity(tasks.len());
+        let mut states = Vec::with_capacity(tasks.len());
+        let mut interval_secs = Vec::with_capacity(tasks.len());
+        let mut runs = Vec::with_capacity(tasks.len());
+        let mut failures = Vec::with_capacity(tasks.len());
+        let mut last_run_ons = Vec::with_capacity(tasks.len());
+        let mut last_run_durations = Vec::with_capacity(tasks.len());
+        let mut last_results = Vec::with_capacity(tasks.len());
+        let mut next_run_ons = Vec::with_capacity(tasks.len());
+        let mut progresses = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            names.push(task.name.into_bytes());
+            kinds.push(task.kind.into_bytes());
+            states.push(task.state.into_bytes());
+            interval_secs.push(task.interval_secs);
+            runs.push(task.runs);
+            failures.push(task.failures);
+            last_run_ons.push(task.last_run_on);
+            last_run_durations.push(task.last_run_duration_ms);
+            last_results.push(task.last_result.map(|result| result.into_bytes()));
+            next_run_ons.push(task.next_run_on);
+            progresses.push(task.progress.into_bytes());
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(names),
+            Series::from_data(kinds),
+            Series::from_data(states),
+            Series::from_data(interval_secs),
+            Series::from_data(runs),
+            Series::from_data(failures),
+            Series::from_data(last_run_ons),
+            Series::from_data(last_run_durations),
+            Series::from_data(last_results),
+            Series::from_data(next_run_ons),
+            Series::from_data(progresses),
+        ]))
+    }
+}
+
+impl BackgroundTasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("kind", Vu8::to_data_type()),
+            DataField::new("state", Vu8::to_data_type()),
+            DataField::new("interval_secs", u64::to_data_type()),
+            DataField::new("runs", u64::to_data_type()),
+            DataField::new("failures", u64::to_data_type()),
+            DataField::new_nullable("last_run_on", TimestampType::new_impl(6)),
+            DataField::new_nullable("last_run_duration_ms", u64::to_data_type()),
+            DataField::new_nullable("last_result", Vu8::to_data_type()),
+            DataField::new_nullable("next_run_on", TimestampType::new_impl(6)),
+            DataField::new("progress", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'background_tasks'".to_string(),
+            name: "background_tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTasks".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(BackgroundTasksTable { table_info })
+    }
+}
@@ -12,15 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table::TableColumnSize;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use tracing::warn;
 
 use crate::sessions::TableContext;
 use crate::storages::system::table::AsyncOneBlockSystemTable;
@@ -31,6 +34,18 @@ pub struct ColumnsTable {
     table_info: TableInfo,
 }
 
+// A column of a table, with the storage detail of the table.
+struct ColumnRow {
+    database: String,
+    table: String,
+    field: DataField,
+    comment: String,
+    engine: String,
+    compression: String,
+    num_rows: Option<u64>,
+    size: Option<TableColumnSize>,
+}
+
 #[async_trait::async_trait]
 impl AsyncSystemTable for ColumnsTable {
     const NAME: &'static str = "system.columns";
@@ -48,11 +63,17 @@ impl AsyncSystemTable for ColumnsTable {
         let mut default_kinds: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut default_exprs: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut is_nullables: Vec<bool> = Vec::with_capacity(rows.len());
+        let mut engines: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
+        let mut compressions: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
+        let mut num_rows: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut data_sizes: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut data_compressed_sizes: Vec<Option<u64>> = Vec::with_capacity(rows.len());
         let mut comments: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
-        for (database_name, table_name, field) in rows.into_iter() {
+        for row in rows.into_iter() {
+            let field = row.field;
             names.push(field.name().clone().into_bytes());
-            tables.push(table_name.into_bytes());
-            databases.push(database_name.into_bytes());
+            tables.push(row.table.into_bytes());
+            databases.push(row.database.into_bytes());
 
             let non_null_type = remove_nullable(field.data_type());
             let data_type = format_data_type_sql(&non_null_type);
@@ -67,7 +88,12 @@ impl AsyncSystemTable for ColumnsTable {
             default_kinds.push(default_kind.into_bytes());
             default_exprs.push(default_expr.into_bytes());
             is_nullables.push(field.is_nullable());
-            comments.push("".to_string().into_bytes());
+            engines.push(row.engine.into_bytes());
+            compressions.push(row.compression.into_bytes());
+            num_rows.push(row.num_rows);
+            data_sizes.push(row.size.as_ref().map(|size| size.uncompressed_size));
+            data_compressed_sizes.push(row.size.as_ref().map(|size| size.compressed_size));
+            comments.push(row.comment.into_bytes());
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -78,6 +104,11 @@ impl AsyncSystemTable for ColumnsTable {
             Series::from_data(default_kinds),
             Series::from_data(default_exprs),
             Series::from_data(is_nullables),
+            Series::from_data(engines),
+            Series::from_data(compressions),
+            Series::from_data(num_rows),
+            Series::from_data(data_sizes),
+            Series::from_data(data_compressed_sizes),
             Series::from_data(comments),
         ]))
     }
@@ -93,6 +124,11 @@ impl ColumnsTable {
             DataField::new("default_kind", Vu8::to_data_type()),
             DataField::new("default_expression", Vu8::to_data_type()),
             DataField::new("is_nullable", bool::to_data_type()),
+            DataField::new("engine", Vu8::to_data_type()),
+            DataField::new("compression", Vu8::to_data_type()),
+            DataField::new_nullable("num_rows", u64::to_data_type()),
+            DataField::new_nullable("data_size", u64::to_data_type()),
+            DataField::new_nullable("data_compressed_size", u64::to_data_type()),
             DataField::new("comment", Vu8::to_data_type()),
         ]);
 
@@ -110,22 +146,49 @@ impl ColumnsTable {
         AsyncOneBlockSystemTable::create(ColumnsTable { table_info })
     }
 
-    async fn dump_table_columns(
-        &self,
-        ctx: Arc<dyn TableContext>,
-    ) -> Result<Vec<(String, String, DataField)>> {
+    async fn dump_table_columns(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<ColumnRow>> {
         let tenant = ctx.get_tenant();
         let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
         let databases = catalog.list_databases(tenant.as_str()).await?;
 
-        let mut rows: Vec<(String, String, DataField)> = vec![];
+        let mut rows: Vec<ColumnRow> = vec![];
         for database in databases {
             for table in catalog
                 .list_tables(tenant.as_str(), database.name())
                 .await?
             {
-                for field in table.schema().fields() {
-                    rows.push((database.name().into(), table.name().into(), field.clone()))
+                let comments = table.field_comments();
+                let compressions = table.column_compressions();
+                let num_rows = table
+                    .statistics(ctx.clone())
+                    .await?
+                    .and_then(|stats| stats.num_rows);
+                // The sizes are summed over the segments of the table, the columns are still
+                // listed without the sizes if the segments can't be read.
+                let mut sizes = match table.column_sizes(ctx.clone()).await {
+                    Ok(sizes) => sizes,
+                    Err(cause) => {
+                        warn!(
+                            "Cannot read the column sizes of table {}.{}: {}",
+                            database.name(),
+                            table.name(),
+                            cause
+                        );
+                        HashMap::new()
+                    }
+                };
+                for (index, field) in table.schema().fields().iter().enumerate() {
+                    let comment = comments.get(index).cloned().unwrap_or_default();
+                    rows.push(ColumnRow {
+                        database: database.name().into(),
+                        table: table.name().into(),
+                        field: field.clone(),
+                        comment,
+                        engine: table.engine().into(),
+                        compression: compressions.get(&index).cloned().unwrap_or_default(),
+                        num_rows,
+                        size: sizes.remove(&index),
+                    })
                 }
             }
         }
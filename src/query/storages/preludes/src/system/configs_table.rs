@@ -48,7 +48,12 @@ impl SyncSystemTable for ConfigsTable {
         let mut groups: Vec<String> = vec![];
         let mut descs: Vec<String> = vec![];
 
-        let query_config = config.query;
+        let mut query_config = config.query;
+        query_config.ldap_search_bind_password =
+            mask_string(&query_config.ldap_search_bind_password, 3);
+        query_config.connection_encryption_key =
+            mask_string(&query_config.connection_encryption_key, 3);
+        query_config.connection_kms_token = mask_string(&query_config.connection_kms_token, 3);
         let query_config_value = serde_json::to_value(query_config)?;
         ConfigsTable::extract_config(
             &mut names,
@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct ConnectionsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ConnectionsTable {
+    const NAME: &'static str = "system.connections";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let connections = UserApiProvider::instance().get_connections(&tenant).await?;
+        let mut name: Vec<Vec<u8>> = Vec::with_capacity(connections.len());
+        let mut storage_type: Vec<Vec<u8>> = Vec::with_capacity(connections.len());
+        let mut storage_params: Vec<Vec<u8>> = Vec::with_capacity(connections.len());
+        let mut comment: Vec<Vec<u8>> = Vec::with_capacity(connections.len());
+        for connection in connections.into_iter() {
+            name.push(connection.name.clone().into_bytes());
+            storage_type.push(connection.storage_type.clone().into_bytes());
+            // The secrets are never displayed, even encrypted.
+            storage_params.push(format!("{:?}", connection.masked_storage_params()).into_bytes());
+            comment.push(connection.comment.clone().into_bytes());
+        }
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(name),
+            Series::from_data(storage_type),
+            Series::from_data(storage_params),
+            Series::from_data(comment),
+        ]))
+    }
+}
+
+impl ConnectionsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("storage_type", Vu8::to_data_type()),
+            DataField::new("storage_params", Vu8::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'connections'".to_string(),
+            name: "connections".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemConnections".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(ConnectionsTable { table_info })
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+/// The dictionaries of the tenant, the rows are of the dictionaries loaded on the node.
+pub struct DictionariesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for DictionariesTable {
+    const NAME: &'static str = "system.dictionaries";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let dictionaries = UserApiProvider::instance()
+            .get_dictionaries(&tenant)
+            .await?;
+        let loaded = ctx
+            .get_loaded_dictionaries_info()
+            .into_iter()
+            .map(|info| (info.name, info.num_rows))
+            .collect::<HashMap<_, _>>();
+
+        let mut name: Vec<Vec<u8>> = Vec::with_capacity(dictionaries.len());
+        let mut primary_key: Vec<Vec<u8>> = Vec::with_capacity(dictionaries.len());
+        let mut columns: Vec<Vec<u8>> = Vec::with_capacity(dictionaries.len());
+        let mut source: Vec<Vec<u8>> = Vec::with_capacity(dictionaries.len());
+        let mut lifetime: Vec<u64> = Vec::with_capacity(dictionaries.len());
+        let mut rows: Vec<Option<u64>> = Vec::with_capacity(dictionaries.len());
+        let mut comment: Vec<Vec<u8>> = Vec::with_capacity(dictionaries.len());
+        let mut created_on: Vec<i64> = Vec::with_capacity(dictionaries.len());
+        for dictionary in dictionaries.into_iter() {
+            rows.push(loaded.get(&dictionary.name).copied());
+            name.push(dictionary.name.into_bytes());
+            primary_key.push(dictionary.primary_key.into_bytes());
+            columns.push(
+                dictionary
+                    .columns
+                    .iter()
+                    .map(|column| format!("`{}` {}", column.name, column.data_type))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+                    .into_bytes(),
+            );
+            // The password of the source is masked.
+            source.push(dictionary.source.to_string().into_bytes());
+            lifetime.push(dictionary.lifetime_secs);
+            comment.push(dictionary.comment.into_bytes());
+            created_on.push(dictionary.created_on);
+        }
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(name),
+            Series::from_data(primary_key),
+            Series::from_data(columns),
+            Series::from_data(source),
+            Series::from_data(lifetime),
+            Series::from_data(rows),
+            Series::from_data(comment),
+            Series::from_data(created_on),
+        ]))
+    }
+}
+
+impl DictionariesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("primary_key", Vu8::to_data_type()),
+            DataField::new("columns", Vu8::to_data_type()),
+            DataField::new("source", Vu8::to_data_type()),
+            DataField::new("lifetime", u64::to_data_type()),
+            DataField::new_nullable("rows", u64::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
+            DataField::new("created_on", TimestampType::new_impl(6)),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'dictionaries'".to_string(),
+            name: "dictionaries".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemDictionaries".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(DictionariesTable { table_info })
+    }
+}
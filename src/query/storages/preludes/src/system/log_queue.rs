@@ -44,6 +44,8 @@ use parking_lot::RwLock;
 
 pub trait SystemLogElement: Send + Sync + Clone {
     const TABLE_NAME: &'static str;
+    /// The events of the append-only log can't be truncated.
+    const APPEND_ONLY: bool = false;
 
     fn schema() -> DataSchemaRef;
 
@@ -116,6 +118,16 @@ impl<Event: SystemLogElement + 'static> SystemLogQueue<Event> {
 
         Ok(())
     }
+
+    /// Removes the events not matching the predicate, e.g. the expired ones.
+    pub fn retain(&self, f: impl Fn(&Event) -> bool) {
+        let mut write_guard = self.data.write();
+        for event in write_guard.event_queue.iter_mut() {
+            if matches!(event, Some(e) if !f(e)) {
+                *event = None;
+            }
+        }
+    }
 }
 
 pub struct SystemLogTable<Event: SystemLogElement> {
@@ -200,6 +212,13 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
     }
 
     async fn truncate(&self, _ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
+        if Event::APPEND_ONLY {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "'system'.'{}' is append-only, can't be truncated",
+                Event::TABLE_NAME
+            )));
+        }
+
         let log_queue = SystemLogQueue::<Event>::instance()?;
         let mut write_guard = log_queue.data.write();
 
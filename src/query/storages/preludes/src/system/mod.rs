@@ -12,36 +12,52 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log_table;
+mod background_tasks_table;
 mod clustering_history_table;
 mod columns_table;
 mod configs_table;
+mod connections_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
+mod dictionaries_table;
 mod engines_table;
 mod functions_table;
 mod log_queue;
 mod metrics_table;
 mod one_table;
+mod plan_hints_table;
 mod processes_table;
+mod query_cache_table;
 mod query_log_table;
 mod roles_table;
+mod sequences_table;
 mod settings_table;
+mod slow_query_log_table;
 mod stages_table;
 mod table;
 mod tables_table;
+mod task_history_table;
+mod tasks_table;
 mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
+pub use background_tasks_table::BackgroundTasksTable;
 pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
+pub use connections_table::ConnectionsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
+pub use dictionaries_table::DictionariesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use log_queue::SystemLogElement;
@@ -49,19 +65,29 @@ pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
+pub use plan_hints_table::PlanHintsTable;
 pub use processes_table::ProcessesTable;
+pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
+pub use sequences_table::SequencesTable;
 pub use settings_table::SettingsTable;
+pub use slow_query_log_table::SlowQueryLogElement;
+pub use slow_query_log_table::SlowQueryLogQueue;
+pub use slow_query_log_table::SlowQueryLogTable;
 pub use stages_table::StagesTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryElement;
+pub use task_history_table::TaskHistoryQueue;
+pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::TasksTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
 pub use users_table::UsersTable;
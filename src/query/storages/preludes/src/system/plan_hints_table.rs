@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct PlanHintsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PlanHintsTable {
+    const NAME: &'static str = "system.plan_hints";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let plan_hints = UserApiProvider::instance().get_plan_hints(&tenant).await?;
+        let mut name: Vec<Vec<u8>> = Vec::with_capacity(plan_hints.len());
+        let mut statement: Vec<Vec<u8>> = Vec::with_capacity(plan_hints.len());
+        let mut shape: Vec<Vec<u8>> = Vec::with_capacity(plan_hints.len());
+        let mut hints: Vec<Vec<u8>> = Vec::with_capacity(plan_hints.len());
+        for plan_hint in plan_hints.into_iter() {
+            name.push(plan_hint.name.into_bytes());
+            statement.push(plan_hint.statement.into_bytes());
+            shape.push(plan_hint.shape.into_bytes());
+            hints.push(plan_hint.hints.into_bytes());
+        }
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(name),
+            Series::from_data(statement),
+            Series::from_data(shape),
+            Series::from_data(hints),
+        ]))
+    }
+}
+
+impl PlanHintsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("statement", Vu8::to_data_type()),
+            DataField::new("shape", Vu8::to_data_type()),
+            DataField::new("hints", Vu8::to_data_type()),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'plan_hints'".to_string(),
+            name: "plan_hints".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanHints".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(PlanHintsTable { table_info })
+    }
+}
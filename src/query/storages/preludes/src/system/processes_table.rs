@@ -57,7 +57,12 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_dal_metrics_write_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_scan_progress_total_rows = Vec::with_capacity(processes_info.len());
+        let mut processes_scan_progress_total_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_elapsed_ms = Vec::with_capacity(processes_info.len());
+        let mut processes_pipeline_stage = Vec::with_capacity(processes_info.len());
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
+        let mut processes_query_tag = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             processes_id.push(process_info.id.clone().into_bytes());
@@ -78,7 +83,21 @@ impl SyncSystemTable for ProcessesTable {
                 ProcessesTable::process_scan_progress_values(&process_info.scan_progress_value);
             processes_scan_progress_read_rows.push(scan_progress_read_rows);
             processes_scan_progress_read_bytes.push(scan_progress_read_bytes);
+            let (scan_progress_total_rows, scan_progress_total_bytes) =
+                ProcessesTable::process_scan_progress_values(
+                    &process_info.scan_progress_total_value,
+                );
+            processes_scan_progress_total_rows.push(scan_progress_total_rows);
+            processes_scan_progress_total_bytes.push(scan_progress_total_bytes);
+            processes_elapsed_ms.push(
+                process_info
+                    .elapsed
+                    .map(|elapsed| elapsed.as_millis() as u64),
+            );
+            processes_pipeline_stage
+                .push(process_info.pipeline_stage.clone().map(|s| s.into_bytes()));
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
+            processes_query_tag.push(process_info.query_tag.clone().into_bytes());
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -94,7 +113,12 @@ impl SyncSystemTable for ProcessesTable {
             Series::from_data(processes_dal_metrics_write_bytes),
             Series::from_data(processes_scan_progress_read_rows),
             Series::from_data(processes_scan_progress_read_bytes),
+            Series::from_data(processes_scan_progress_total_rows),
+            Series::from_data(processes_scan_progress_total_bytes),
+            Series::from_data(processes_elapsed_ms),
+            Series::from_data(processes_pipeline_stage),
             Series::from_data(processes_mysql_connection_id),
+            Series::from_data(processes_query_tag),
         ]))
     }
 }
@@ -114,7 +138,12 @@ impl ProcessesTable {
             DataField::new_nullable("dal_metrics_write_bytes", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_rows", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_bytes", u64::to_data_type()),
+            DataField::new_nullable("scan_progress_total_rows", u64::to_data_type()),
+            DataField::new_nullable("scan_progress_total_bytes", u64::to_data_type()),
+            DataField::new_nullable("elapsed_ms", u64::to_data_type()),
+            DataField::new_nullable("pipeline_stage", Vu8::to_data_type()),
             DataField::new_nullable("mysql_connection_id", u32::to_data_type()),
+            DataField::new("query_tag", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::system::SyncOneBlockSystemTable;
+use crate::system::SyncSystemTable;
+
+/// The query results cached in the memory of the node for the tenant.
+pub struct QueryCacheTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for QueryCacheTable {
+    const NAME: &'static str = "system.query_cache";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let entries = ctx.get_query_cache_info();
+
+        let mut query_ids = Vec::with_capacity(entries.len());
+        let mut queries = Vec::with_capacity(entries.len());
+        let mut num_rows = Vec::with_capacity(entries.len());
+        let mut result_bytes = Vec::with_capacity(entries.len());
+        let mut hits = Vec::with_capacity(entries.len());
+        let mut created_ons = Vec::with_capacity(entries.len());
+        let mut expires_ons = Vec::with_capacity(entries.len());
+        for entry in entries {
+            query_ids.push(entry.query_id.into_bytes());
+            queries.push(entry.query.into_bytes());
+            num_rows.push(entry.num_rows);
+            result_bytes.push(entry.result_bytes);
+            hits.push(entry.hits);
+            created_ons.push(entry.created_on);
+            expires_ons.push(entry.expires_on);
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(query_ids),
+            Series::from_data(queries),
+            Series::from_data(num_rows),
+            Series::from_data(result_bytes),
+            Series::from_data(hits),
+            Series::from_data(created_ons),
+            Series::from_data(expires_ons),
+        ]))
+    }
+}
+
+impl QueryCacheTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("query", Vu8::to_data_type()),
+            DataField::new("num_rows", u64::to_data_type()),
+            DataField::new("result_bytes", u64::to_data_type()),
+            DataField::new("hits", u64::to_data_type()),
+            DataField::new("created_on", TimestampType::new_impl(6)),
+            DataField::new("expires_on", TimestampType::new_impl(6)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_cache'".to_string(),
+            name: "query_cache".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryCache".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(QueryCacheTable { table_info })
+    }
+}
@@ -38,7 +38,7 @@ where S: Serializer {
     s.serialize_str(t.format("%Y-%m-%d").to_string().as_str())
 }
 
-fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
+pub(crate) fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
 where S: Serializer {
     let t = NaiveDateTime::from_timestamp(
         dt / 1_000_000,
@@ -68,6 +68,7 @@ pub struct QueryLogElement {
     pub query_id: String,
     pub query_kind: String,
     pub query_text: String,
+    pub query_tag: String,
 
     #[serde(serialize_with = "date_str")]
     pub event_date: i32,
@@ -96,6 +97,7 @@ pub struct QueryLogElement {
     pub result_bytes: u64,
     pub cpu_usage: u32,
     pub memory_usage: u64,
+    pub queued_time_ms: u64,
 
     // Client.
     pub client_info: String,
@@ -135,6 +137,7 @@ impl SystemLogElement for QueryLogElement {
             DataField::new("query_id", Vu8::to_data_type()),
             DataField::new("query_kind", Vu8::to_data_type()),
             DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("query_tag", Vu8::to_data_type()),
             DataField::new("event_date", DateType::new_impl()),
             DataField::new("event_time", TimestampType::new_impl(3)),
             // Schema.
@@ -158,6 +161,7 @@ impl SystemLogElement for QueryLogElement {
             DataField::new("result_bytes", u64::to_data_type()),
             DataField::new("cpu_usage", u32::to_data_type()),
             DataField::new("memory_usage", u64::to_data_type()),
+            DataField::new("queued_time_ms", u64::to_data_type()),
             // Client.
             DataField::new("client_info", Vu8::to_data_type()),
             DataField::new("client_address", Vu8::to_data_type()),
@@ -221,6 +225,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .append_data_value(DataValue::String(self.query_text.as_bytes().to_vec()))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::String(self.query_tag.as_bytes().to_vec()))?;
         columns
             .next()
             .unwrap()
@@ -307,6 +315,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .append_data_value(DataValue::UInt64(self.memory_usage))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::UInt64(self.queued_time_ms))?;
         // Client.
         columns
             .next()
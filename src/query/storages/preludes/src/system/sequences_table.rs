@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct SequencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for SequencesTable {
+    const NAME: &'static str = "system.sequences";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let sequences = UserApiProvider::instance().get_sequences(&tenant).await?;
+        let mut name: Vec<Vec<u8>> = Vec::with_capacity(sequences.len());
+        let mut start: Vec<i64> = Vec::with_capacity(sequences.len());
+        let mut increment: Vec<i64> = Vec::with_capacity(sequences.len());
+        let mut next_value: Vec<i64> = Vec::with_capacity(sequences.len());
+        let mut comment: Vec<Vec<u8>> = Vec::with_capacity(sequences.len());
+        for sequence in sequences.into_iter() {
+            name.push(sequence.name.into_bytes());
+            start.push(sequence.start);
+            increment.push(sequence.increment);
+            // The values allocated to the nodes are not counted below the next value.
+            next_value.push(sequence.next_value);
+            comment.push(sequence.comment.into_bytes());
+        }
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(name),
+            Series::from_data(start),
+            Series::from_data(increment),
+            Series::from_data(next_value),
+            Series::from_data(comment),
+        ]))
+    }
+}
+
+impl SequencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("start", i64::to_data_type()),
+            DataField::new("increment", i64::to_data_type()),
+            DataField::new("next_value", i64::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'sequences'".to_string(),
+            name: "sequences".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSequences".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(SequencesTable { table_info })
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde::Serialize;
+
+use crate::system::query_log_table::datetime_str;
+use crate::system::SystemLogElement;
+use crate::system::SystemLogQueue;
+use crate::system::SystemLogTable;
+
+#[derive(Clone, Serialize)]
+pub struct SlowQueryLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+
+    // User.
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub sql_user: String,
+
+    // Query.
+    pub query_id: String,
+    pub query_kind: String,
+    pub query_text: String,
+    pub current_database: String,
+    pub session_settings: String,
+    // The physical plan with the estimated rows of the operators, as EXPLAIN.
+    pub plan: String,
+    // The number of the processors and their busy time by the names of the processors.
+    pub processors_profile: String,
+
+    // Stats.
+    pub query_duration_ms: u64,
+    pub queued_time_ms: u64,
+    pub scan_rows: u64,
+    pub scan_bytes: u64,
+    pub written_rows: u64,
+    pub written_bytes: u64,
+    pub spilled_rows: u64,
+    pub spilled_bytes: u64,
+    pub result_rows: u64,
+    pub result_bytes: u64,
+    pub memory_usage: u64,
+
+    // Exception.
+    pub exception_code: i32,
+    pub exception_text: String,
+}
+
+impl SystemLogElement for SlowQueryLogElement {
+    const TABLE_NAME: &'static str = "slow_query_log";
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("event_time", TimestampType::new_impl(3)),
+            // User.
+            DataField::new("tenant_id", Vu8::to_data_type()),
+            DataField::new("cluster_id", Vu8::to_data_type()),
+            DataField::new("sql_user", Vu8::to_data_type()),
+            // Query.
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("query_kind", Vu8::to_data_type()),
+            DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("current_database", Vu8::to_data_type()),
+            DataField::new("session_settings", Vu8::to_data_type()),
+            DataField::new("plan", Vu8::to_data_type()),
+            DataField::new("processors_profile", Vu8::to_data_type()),
+            // Stats.
+            DataField::new("query_duration_ms", u64::to_data_type()),
+            DataField::new("queued_time_ms", u64::to_data_type()),
+            DataField::new("scan_rows", u64::to_data_type()),
+            DataField::new("scan_bytes", u64::to_data_type()),
+            DataField::new("written_rows", u64::to_data_type()),
+            DataField::new("written_bytes", u64::to_data_type()),
+            DataField::new("spilled_rows", u64::to_data_type()),
+            DataField::new("spilled_bytes", u64::to_data_type()),
+            DataField::new("result_rows", u64::to_data_type()),
+            DataField::new("result_bytes", u64::to_data_type()),
+            DataField::new("memory_usage", u64::to_data_type()),
+            // Exception.
+            DataField::new("exception_code", i32::to_data_type()),
+            DataField::new("exception_text", Vu8::to_data_type()),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<Box<dyn MutableColumn>>) -> Result<()> {
+        let strings = [
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.sql_user,
+            &self.query_id,
+            &self.query_kind,
+            &self.query_text,
+            &self.current_database,
+            &self.session_settings,
+            &self.plan,
+            &self.processors_profile,
+        ];
+        let stats = [
+            self.query_duration_ms,
+            self.queued_time_ms,
+            self.scan_rows,
+            self.scan_bytes,
+            self.written_rows,
+            self.written_bytes,
+            self.spilled_rows,
+            self.spilled_bytes,
+            self.result_rows,
+            self.result_bytes,
+            self.memory_usage,
+        ];
+
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Int64(self.event_time))?;
+        for value in strings {
+            columns
+                .next()
+                .unwrap()
+                .append_data_value(DataValue::String(value.as_bytes().to_vec()))?;
+        }
+        for value in stats {
+            columns
+                .next()
+                .unwrap()
+                .append_data_value(DataValue::UInt64(value))?;
+        }
+        // Exception.
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Int64(self.exception_code as i64))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::String(self.exception_text.as_bytes().to_vec()))
+    }
+}
+
+pub type SlowQueryLogQueue = SystemLogQueue<SlowQueryLogElement>;
+pub type SlowQueryLogTable = SystemLogTable<SlowQueryLogElement>;
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_storages_util::table_option_keys::OPT_KEY_COMMENT;
 
 use crate::catalogs::Catalog;
 use crate::sessions::TableContext;
@@ -146,6 +147,26 @@ where TablesTable<T>: HistoryAware
                     .unwrap_or_else(|| "".to_owned())
             })
             .collect();
+        // The compression and the snapshot are kept by the table meta, no storage is read.
+        let compressions: Vec<String> = database_tables
+            .iter()
+            .map(|(_, v)| v.compression().unwrap_or_default())
+            .collect();
+        let snapshot_ids: Vec<String> = database_tables
+            .iter()
+            .map(|(_, v)| v.snapshot_id().unwrap_or_default())
+            .collect();
+
+        let comments: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(_, v)| {
+                v.get_table_info()
+                    .options()
+                    .get(OPT_KEY_COMMENT)
+                    .map(|comment| comment.as_bytes())
+                    .unwrap_or_default()
+            })
+            .collect();
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(databases),
@@ -158,6 +179,9 @@ where TablesTable<T>: HistoryAware
             Series::from_data(data_size),
             Series::from_data(data_compressed_size),
             Series::from_data(index_size),
+            Series::from_data(compressions),
+            Series::from_data(snapshot_ids),
+            Series::from_data(comments),
         ]))
     }
 }
@@ -177,6 +201,9 @@ where TablesTable<T>: HistoryAware
             DataField::new_nullable("data_size", u64::to_data_type()),
             DataField::new_nullable("data_compressed_size", u64::to_data_type()),
             DataField::new_nullable("index_size", u64::to_data_type()),
+            DataField::new("compression", Vu8::to_data_type()),
+            DataField::new("snapshot_id", Vu8::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
         ])
     }
 
@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde::Serialize;
+
+use crate::system::query_log_table::datetime_str;
+use crate::system::SystemLogElement;
+use crate::system::SystemLogQueue;
+use crate::system::SystemLogTable;
+
+/// A run of a scheduled task on the node.
+#[derive(Clone, Serialize)]
+pub struct TaskHistoryElement {
+    pub name: String,
+    pub query_id: String,
+    // One of `Schedule`, `Predecessor` and `Execute`.
+    pub trigger: String,
+    // The predecessor task of the run triggered by it.
+    pub predecessor: String,
+    #[serde(serialize_with = "datetime_str")]
+    pub scheduled_time: i64,
+    #[serde(serialize_with = "datetime_str")]
+    pub completed_time: i64,
+
+    // Result.
+    pub success: bool,
+    pub exception_code: i32,
+    pub exception_text: String,
+}
+
+impl SystemLogElement for TaskHistoryElement {
+    const TABLE_NAME: &'static str = "task_history";
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("trigger", Vu8::to_data_type()),
+            DataField::new("predecessor", Vu8::to_data_type()),
+            DataField::new("scheduled_time", TimestampType::new_impl(6)),
+            DataField::new("completed_time", TimestampType::new_impl(6)),
+            // Result.
+            DataField::new("success", bool::to_data_type()),
+            DataField::new("exception_code", i32::to_data_type()),
+            DataField::new("exception_text", Vu8::to_data_type()),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<Box<dyn MutableColumn>>) -> Result<()> {
+        let strings = [&self.name, &self.query_id, &self.trigger, &self.predecessor];
+
+        let mut columns = columns.iter_mut();
+        for value in strings {
+            columns
+                .next()
+                .unwrap()
+                .append_data_value(DataValue::String(value.as_bytes().to_vec()))?;
+        }
+        for time in [self.scheduled_time, self.completed_time] {
+            columns
+                .next()
+                .unwrap()
+                .append_data_value(DataValue::Int64(time))?;
+        }
+        // Result.
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Boolean(self.success))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::Int64(self.exception_code as i64))?;
+        columns
+            .next()
+            .unwrap()
+            .append_data_value(DataValue::String(self.exception_text.as_bytes().to_vec()))
+    }
+}
+
+pub type TaskHistoryQueue = SystemLogQueue<TaskHistoryElement>;
+pub type TaskHistoryTable = SystemLogTable<TaskHistoryElement>;
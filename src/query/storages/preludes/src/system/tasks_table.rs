@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+/// The scheduled tasks of the tenant, with the next times they are scheduled to run.
+pub struct TasksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TasksTable {
+    const NAME: &'static str = "system.tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+
+        let mut name: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+        let mut state: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+        let mut schedule: Vec<Option<Vec<u8>>> = Vec::with_capacity(tasks.len());
+        let mut after: Vec<Option<Vec<u8>>> = Vec::with_capacity(tasks.len());
+        let mut owner: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+        let mut database: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+        let mut next_scheduled_on: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
+        let mut comment: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+        let mut definition: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+        let mut created_on: Vec<i64> = Vec::with_capacity(tasks.len());
+        for task in tasks.into_iter() {
+            // The suspended tasks and the tasks running after their predecessors are not scheduled.
+            let next_on = match task.suspended {
+                true => None,
+                false => task
+                    .cron_schedule()
+                    .ok()
+                    .flatten()
+                    .and_then(|schedule| schedule.next_after(now)),
+            };
+            let task_state = match task.suspended {
+                true => "Suspended",
+                false => "Started",
+            };
+
+            name.push(task.name.into_bytes());
+            state.push(task_state.as_bytes().to_vec());
+            schedule.push(task.schedule.map(|schedule| schedule.into_bytes()));
+            after.push(task.after.map(|after| after.into_bytes()));
+            owner.push(task.owner.to_string().into_bytes());
+            database.push(task.database.into_bytes());
+            next_scheduled_on.push(next_on);
+            comment.push(task.comment.into_bytes());
+            definition.push(task.sql.into_bytes());
+            created_on.push(task.created_on);
+        }
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(name),
+            Series::from_data(state),
+            Series::from_data(schedule),
+            Series::from_data(after),
+            Series::from_data(owner),
+            Series::from_data(database),
+            Series::from_data(next_scheduled_on),
+            Series::from_data(comment),
+            Series::from_data(definition),
+            Series::from_data(created_on),
+        ]))
+    }
+}
+
+impl TasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("state", Vu8::to_data_type()),
+            DataField::new_nullable("schedule", Vu8::to_data_type()),
+            DataField::new_nullable("after", Vu8::to_data_type()),
+            DataField::new("owner", Vu8::to_data_type()),
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new_nullable("next_scheduled_on", TimestampType::new_impl(6)),
+            DataField::new("comment", Vu8::to_data_type()),
+            DataField::new("definition", Vu8::to_data_type()),
+            DataField::new("created_on", TimestampType::new_impl(6)),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'tasks'".to_string(),
+            name: "tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemUserTasks".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(TasksTable { table_info })
+    }
+}
@@ -18,6 +18,20 @@ use once_cell::sync::Lazy;
 
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
+/// The comment of the table, set by `COMMENT = '...'` of `CREATE TABLE` or `ALTER TABLE`
+pub const OPT_KEY_COMMENT: &str = "comment";
+/// The virtual columns of the table, managed by `ALTER TABLE ... ADD/DROP VIRTUAL COLUMN`
+pub const OPT_KEY_VIRTUAL_COLUMNS: &str = "virtual_columns";
+/// The row access policy attached to the table, managed by `ALTER TABLE ... ADD/DROP ROW ACCESS POLICY`
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
+/// The masking policies of the columns, managed by `ALTER TABLE ... MODIFY COLUMN ... SET/UNSET MASKING POLICY`
+pub const OPT_KEY_MASKING_POLICIES: &str = "masking_policies";
+/// The collations of the string columns, set by `<column> <type> COLLATE '<collation>'` of `CREATE TABLE`
+pub const OPT_KEY_COLLATIONS: &str = "collations";
+/// The aggregating indexes of the table, managed by `CREATE/DROP AGGREGATING INDEX ... ON <table>`
+pub const OPT_KEY_AGGREGATING_INDEXES: &str = "aggregating_indexes";
+/// The definition of the aggregating index stored in the table, set by `CREATE AGGREGATING INDEX`
+pub const OPT_KEY_AGGREGATING_INDEX_DEFINITION: &str = "aggregating_index_definition";
 
 /// Legacy table snapshot location key
 ///
@@ -36,6 +50,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_VIRTUAL_COLUMNS);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_MASKING_POLICIES);
+    r.insert(OPT_KEY_COLLATIONS);
+    r.insert(OPT_KEY_AGGREGATING_INDEXES);
+    r.insert(OPT_KEY_AGGREGATING_INDEX_DEFINITION);
     r
 });
 
@@ -44,6 +64,12 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_VIRTUAL_COLUMNS);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_MASKING_POLICIES);
+    r.insert(OPT_KEY_COLLATIONS);
+    r.insert(OPT_KEY_AGGREGATING_INDEXES);
+    r.insert(OPT_KEY_AGGREGATING_INDEX_DEFINITION);
     r
 });
 
@@ -0,0 +1,72 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ConnectionInfo;
+
+use crate::UserApiProvider;
+
+/// Connection operations.
+impl UserApiProvider {
+    // Add a new connection.
+    pub async fn add_connection(
+        &self,
+        tenant: &str,
+        connection: ConnectionInfo,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let connection_api_client = self.get_connection_api_client(tenant)?;
+        match connection_api_client.add_connection(connection).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::connection_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a connection by name.
+    pub async fn get_connection(&self, tenant: &str, name: &str) -> Result<ConnectionInfo> {
+        let connection_api_client = self.get_connection_api_client(tenant)?;
+        Ok(connection_api_client.get_connection(name, None).await?.data)
+    }
+
+    // Get all connections for the tenant.
+    pub async fn get_connections(&self, tenant: &str) -> Result<Vec<ConnectionInfo>> {
+        let connection_api_client = self.get_connection_api_client(tenant)?;
+        match connection_api_client.get_connections().await {
+            Err(e) => Err(e.add_message_back("(while get connections).")),
+            Ok(connections) => Ok(connections),
+        }
+    }
+
+    // Drop a connection by name.
+    pub async fn drop_connection(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let connection_api_client = self.get_connection_api_client(tenant)?;
+        match connection_api_client.drop_connection(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop connection)"))
+                }
+            }
+        }
+    }
+}
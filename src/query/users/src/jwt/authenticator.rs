@@ -12,18 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use jwtk::jwk::RemoteJwksVerifier;
 use jwtk::HeaderAndClaims;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
 use serde::Deserialize;
 use serde::Serialize;
 
+// The keys are reloaded at most once in the interval when a token fails to verify, e.g.
+// the token is signed by a new key rotated in before the keys are refreshed.
+const MIN_KEY_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
 pub struct JwtAuthenticator {
     // Todo(youngsofun): verify settings, like issuer
-    verifier: RemoteJwksVerifier,
+    key_file: String,
+    refresh_interval: Duration,
+    verifier: RwLock<Arc<RemoteJwksVerifier>>,
+    reloaded_at: Mutex<Option<Instant>>,
 }
 
 #[derive(Default, Deserialize, Serialize)]
@@ -36,6 +48,9 @@ pub struct CustomClaims {
     pub tenant_id: Option<String>,
     pub role: Option<String>,
     pub ensure_user: Option<EnsureUser>,
+    /// The other claims, e.g. the ones taken as the user name and the role by the config.
+    #[serde(flatten)]
+    pub others: BTreeMap<String, serde_json::Value>,
 }
 
 impl CustomClaims {
@@ -44,6 +59,7 @@ impl CustomClaims {
             tenant_id: None,
             role: None,
             ensure_user: None,
+            others: BTreeMap::new(),
         }
     }
 
@@ -61,28 +77,60 @@ impl CustomClaims {
         self.ensure_user = Some(ensure_user);
         self
     }
+
+    pub fn with_claim(mut self, name: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.others.insert(name.to_string(), value.into());
+        self
+    }
 }
 
 impl JwtAuthenticator {
-    pub async fn try_create(jwt_key_file: String) -> Result<Option<Self>> {
+    pub async fn try_create(
+        jwt_key_file: String,
+        refresh_interval: Duration,
+    ) -> Result<Option<Self>> {
         if jwt_key_file.is_empty() {
             return Ok(None);
         }
-        let mut verifier =
-            RemoteJwksVerifier::new(jwt_key_file, None, Duration::from_secs(15 * 60));
+        let verifier = Self::create_verifier(&jwt_key_file, refresh_interval);
+        Ok(Some(JwtAuthenticator {
+            key_file: jwt_key_file,
+            refresh_interval,
+            verifier: RwLock::new(Arc::new(verifier)),
+            reloaded_at: Mutex::new(None),
+        }))
+    }
+
+    fn create_verifier(key_file: &str, refresh_interval: Duration) -> RemoteJwksVerifier {
+        let mut verifier = RemoteJwksVerifier::new(key_file.to_string(), None, refresh_interval);
         verifier.set_require_kid(false);
-        Ok(Some(JwtAuthenticator { verifier }))
+        verifier
     }
 
     pub async fn parse_jwt(&self, token: &str) -> Result<HeaderAndClaims<CustomClaims>> {
-        match self.verifier.verify::<CustomClaims>(token).await {
-            Ok(c) => match c.claims().sub {
-                None => Err(ErrorCode::AuthenticateFailure(
-                    "missing field `subject` in jwt",
-                )),
-                Some(_) => Ok(c),
+        let verifier = self.verifier.read().clone();
+        match verifier.verify::<CustomClaims>(token).await {
+            Ok(c) => Ok(c),
+            Err(e) => match self.reload_verifier() {
+                None => Err(ErrorCode::AuthenticateFailure(e.to_string())),
+                Some(verifier) => verifier
+                    .verify::<CustomClaims>(token)
+                    .await
+                    .map_err(|e| ErrorCode::AuthenticateFailure(e.to_string())),
             },
-            Err(e) => Err(ErrorCode::AuthenticateFailure(e.to_string())),
         }
     }
+
+    // Replaces the verifier with a new one, so the keys are fetched again by the next verify.
+    fn reload_verifier(&self) -> Option<Arc<RemoteJwksVerifier>> {
+        let mut reloaded_at = self.reloaded_at.lock();
+        if matches!(*reloaded_at, Some(at) if at.elapsed() < MIN_KEY_RELOAD_INTERVAL) {
+            return None;
+        }
+        *reloaded_at = Some(Instant::now());
+
+        let verifier = Arc::new(Self::create_verifier(&self.key_file, self.refresh_interval));
+        *self.verifier.write() = verifier.clone();
+        Some(verifier)
+    }
 }
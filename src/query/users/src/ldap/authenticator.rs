@@ -0,0 +1,248 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use ldap3::dn_escape;
+use ldap3::ldap_escape;
+use ldap3::Ldap;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+use ldap3::LdapError;
+use ldap3::LdapResult;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+use tracing::warn;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+// The result code of the bind with the wrong DN or password.
+const INVALID_CREDENTIALS: u32 = 49;
+const USER_PLACEHOLDER: &str = "{user}";
+
+#[derive(Clone, Debug, Default)]
+pub struct LdapConfig {
+    pub url: String,
+    pub bind_dn_template: String,
+    pub search_bind_dn: String,
+    pub search_bind_password: String,
+    pub search_base: String,
+    pub search_filter: String,
+    pub group_attribute: String,
+    pub group_role_mapping: String,
+}
+
+/// Verifies the passwords of the users against the LDAP server, either by binding as the
+/// DN of the template, or by searching the DN of the user first and then binding as it.
+pub struct LdapAuthenticator {
+    config: LdapConfig,
+    // The roles granted to the members of the groups, keyed by the lowercase group.
+    group_roles: BTreeMap<String, String>,
+}
+
+impl LdapAuthenticator {
+    pub fn try_create(config: LdapConfig) -> Result<Option<Self>> {
+        if config.url.is_empty() {
+            return Ok(None);
+        }
+        if config.bind_dn_template.is_empty() && config.search_base.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "either ldap_bind_dn_template or ldap_search_base must be set",
+            ));
+        }
+        let group_roles = parse_group_role_mapping(&config.group_role_mapping)?;
+        Ok(Some(LdapAuthenticator {
+            config,
+            group_roles,
+        }))
+    }
+
+    /// Binds as the user with the password, returns the groups of the user.
+    pub async fn authenticate(&self, user: &str, password: &[u8]) -> Result<Vec<String>> {
+        // The simple bind with an empty password is an unauthenticated bind, which succeeds
+        // whatever the DN is.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure("password required"));
+        }
+        let password = std::str::from_utf8(password).map_err(|_| {
+            ErrorCode::AuthenticateFailure("invalid utf-8 sequence of the password")
+        })?;
+
+        let mut ldap = self.connect().await?;
+        let groups = self.bind_as_user(&mut ldap, user, password).await;
+        if let Err(cause) = ldap.unbind().await {
+            warn!("LDAP unbind failed: {}", cause);
+        }
+        groups
+    }
+
+    /// The roles granted by the mapping to the members of the groups.
+    pub fn mapped_roles(&self, groups: &[String]) -> BTreeSet<String> {
+        groups
+            .iter()
+            .filter_map(|group| self.group_roles.get(&group.to_lowercase()))
+            .cloned()
+            .collect()
+    }
+
+    /// The roles in the mapping, they are granted or revoked by the groups at login, the
+    /// other roles of the users are left as they are.
+    pub fn managed_roles(&self) -> BTreeSet<String> {
+        self.group_roles.values().cloned().collect()
+    }
+
+    async fn connect(&self) -> Result<Ldap> {
+        let settings = LdapConnSettings::new().set_conn_timeout(CONNECT_TIMEOUT);
+        let (conn, ldap) = LdapConnAsync::with_settings(settings, &self.config.url)
+            .await
+            .map_err(ldap_error)?;
+        tokio::spawn(async move {
+            if let Err(cause) = conn.drive().await {
+                warn!("LDAP connection failed: {}", cause);
+            }
+        });
+        Ok(ldap)
+    }
+
+    async fn bind_as_user(
+        &self,
+        ldap: &mut Ldap,
+        user: &str,
+        password: &str,
+    ) -> Result<Vec<String>> {
+        let group_attribute = self.config.group_attribute.as_str();
+        if !self.config.bind_dn_template.is_empty() {
+            let user_dn = self
+                .config
+                .bind_dn_template
+                .replace(USER_PLACEHOLDER, &dn_escape(user));
+            check_bind(ldap.simple_bind(&user_dn, password).await)?;
+
+            // The groups are read from the entry of the user bound as it.
+            let entry = search_entry(
+                ldap,
+                &user_dn,
+                Scope::Base,
+                "(objectClass=*)",
+                group_attribute,
+            )
+            .await?;
+            return Ok(entry
+                .map(|entry| entry_groups(&entry, group_attribute))
+                .unwrap_or_default());
+        }
+
+        if !self.config.search_bind_dn.is_empty() {
+            ldap.simple_bind(
+                &self.config.search_bind_dn,
+                &self.config.search_bind_password,
+            )
+            .await
+            .and_then(|res| res.success())
+            .map_err(ldap_error)?;
+        }
+        let filter = self
+            .config
+            .search_filter
+            .replace(USER_PLACEHOLDER, &ldap_escape(user));
+        let entry = search_entry(
+            ldap,
+            &self.config.search_base,
+            Scope::Subtree,
+            &filter,
+            group_attribute,
+        )
+        .await?
+        .ok_or_else(|| ErrorCode::AuthenticateFailure(format!("unknown ldap user {}", user)))?;
+        check_bind(ldap.simple_bind(&entry.dn, password).await)?;
+        Ok(entry_groups(&entry, group_attribute))
+    }
+}
+
+// The mapping is in the form of `<group>:<role>;<group>:<role>`, the groups are DNs
+// containing `:` rarely, so the role is the part after the last `:`.
+fn parse_group_role_mapping(mapping: &str) -> Result<BTreeMap<String, String>> {
+    let mut group_roles = BTreeMap::new();
+    for item in mapping.split(';').map(|item| item.trim()) {
+        if item.is_empty() {
+            continue;
+        }
+        match item.rsplit_once(':') {
+            Some((group, role)) if !group.trim().is_empty() && !role.trim().is_empty() => {
+                group_roles.insert(group.trim().to_lowercase(), role.trim().to_string());
+            }
+            _ => {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "invalid ldap group role mapping: {}, expect <group>:<role>",
+                    item
+                )));
+            }
+        }
+    }
+    Ok(group_roles)
+}
+
+fn check_bind(res: std::result::Result<LdapResult, LdapError>) -> Result<()> {
+    match res {
+        Ok(res) if res.rc == 0 => Ok(()),
+        Ok(res) if res.rc == INVALID_CREDENTIALS => {
+            Err(ErrorCode::AuthenticateFailure("wrong password"))
+        }
+        Ok(res) => Err(ErrorCode::AuthenticateFailure(format!(
+            "ldap bind failed, rc: {}, {}",
+            res.rc, res.text
+        ))),
+        Err(cause) => Err(ldap_error(cause)),
+    }
+}
+
+async fn search_entry(
+    ldap: &mut Ldap,
+    base: &str,
+    scope: Scope,
+    filter: &str,
+    attribute: &str,
+) -> Result<Option<SearchEntry>> {
+    let (entries, _) = ldap
+        .search(base, scope, filter, vec![attribute])
+        .await
+        .and_then(|res| res.success())
+        .map_err(ldap_error)?;
+    match entries.len() {
+        0 => Ok(None),
+        1 => Ok(entries.into_iter().next().map(SearchEntry::construct)),
+        n => Err(ErrorCode::AuthenticateFailure(format!(
+            "ambiguous ldap user, {} entries found",
+            n
+        ))),
+    }
+}
+
+// The names of the attributes are case-insensitive.
+fn entry_groups(entry: &SearchEntry, attribute: &str) -> Vec<String> {
+    entry
+        .attrs
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(attribute))
+        .flat_map(|(_, values)| values.clone())
+        .collect()
+}
+
+fn ldap_error(cause: LdapError) -> ErrorCode {
+    ErrorCode::AuthenticateFailure(format!("ldap error: {}", cause))
+}
@@ -12,8 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod connection;
+mod dictionary;
 mod jwt;
+mod ldap;
+mod masking_policy;
+mod network_policy;
+mod plan_hint;
+mod resource_group;
 mod role_mgr;
+mod row_access_policy;
+mod sequence;
+mod task;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -26,6 +36,8 @@ pub mod role_cache_mgr;
 pub mod role_util;
 
 pub use jwt::*;
+pub use ldap::*;
+pub use network_policy::parse_ip_list;
 pub use role_cache_mgr::RoleCacheManager;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
@@ -0,0 +1,77 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MaskingPolicy;
+
+use crate::UserApiProvider;
+
+/// Masking policy operations.
+impl UserApiProvider {
+    // Add a new masking policy.
+    pub async fn add_masking_policy(
+        &self,
+        tenant: &str,
+        policy: MaskingPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let policy_api_client = self.get_masking_policy_api_client(tenant)?;
+        match policy_api_client.add_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::masking_policy_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a masking policy by name.
+    pub async fn get_masking_policy(&self, tenant: &str, name: &str) -> Result<MaskingPolicy> {
+        let policy_api_client = self.get_masking_policy_api_client(tenant)?;
+        Ok(policy_api_client.get_policy(name, None).await?.data)
+    }
+
+    // Get all masking policies for the tenant.
+    pub async fn get_masking_policies(&self, tenant: &str) -> Result<Vec<MaskingPolicy>> {
+        let policy_api_client = self.get_masking_policy_api_client(tenant)?;
+        match policy_api_client.get_policies().await {
+            Err(e) => Err(e.add_message_back("(while get masking policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a masking policy by name.
+    pub async fn drop_masking_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let policy_api_client = self.get_masking_policy_api_client(tenant)?;
+        match policy_api_client.drop_policy(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop masking policy)"))
+                }
+            }
+        }
+    }
+}
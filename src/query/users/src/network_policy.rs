@@ -0,0 +1,165 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::UserInfo;
+use ipnet::IpNet;
+
+use crate::UserApiProvider;
+
+/// Network policy operations.
+impl UserApiProvider {
+    // Add a new network policy.
+    pub async fn add_network_policy(
+        &self,
+        tenant: &str,
+        policy: NetworkPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        parse_ip_list(&policy.allowed_ip_list)?;
+        parse_ip_list(&policy.blocked_ip_list)?;
+
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        match policy_api_client.add_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::network_policy_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a network policy by name.
+    pub async fn get_network_policy(&self, tenant: &str, name: &str) -> Result<NetworkPolicy> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        Ok(policy_api_client.get_policy(name, None).await?.data)
+    }
+
+    // Get all network policies for the tenant.
+    pub async fn get_network_policies(&self, tenant: &str) -> Result<Vec<NetworkPolicy>> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        match policy_api_client.get_policies().await {
+            Err(e) => Err(e.add_message_back("(while get network policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a network policy by name, the policy attached to the users can't be dropped.
+    pub async fn drop_network_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let users = self.get_users(tenant).await?;
+        if let Some(user) = users
+            .iter()
+            .find(|user| user.option.network_policy().map(|p| p.as_str()) == Some(name))
+        {
+            return Err(ErrorCode::NetworkPolicyIsUsedByUser(format!(
+                "network policy {} is used by user '{}'",
+                name, user.name
+            )));
+        }
+
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        match policy_api_client.drop_policy(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop network policy)"))
+                }
+            }
+        }
+    }
+
+    // Check the client ip against the network policy of the user, the blocked list is
+    // checked first, then the ip must be in the allowed list.
+    pub async fn check_network_policy(
+        &self,
+        tenant: &str,
+        user: &UserInfo,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
+        let name = match user.option.network_policy() {
+            None => return Ok(()),
+            Some(name) => name,
+        };
+        let policy = self.get_network_policy(tenant, name).await?;
+
+        let ip = client_ip
+            .and_then(|ip| ip.parse::<IpAddr>().ok())
+            .map(canonical_ip)
+            .ok_or_else(|| {
+                ErrorCode::NetworkPolicyDenied(format!(
+                    "unknown client ip {:?} of user '{}' with network policy {}",
+                    client_ip, user.name, name
+                ))
+            })?;
+        if parse_ip_list(&policy.blocked_ip_list)?
+            .iter()
+            .any(|net| net.contains(&ip))
+        {
+            return Err(ErrorCode::NetworkPolicyDenied(format!(
+                "client ip {} of user '{}' is blocked by network policy {}",
+                ip, user.name, name
+            )));
+        }
+        if !parse_ip_list(&policy.allowed_ip_list)?
+            .iter()
+            .any(|net| net.contains(&ip))
+        {
+            return Err(ErrorCode::NetworkPolicyDenied(format!(
+                "client ip {} of user '{}' is not allowed by network policy {}",
+                ip, user.name, name
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Parses the IPs and the CIDRs of the network policy, an IP is the network of itself.
+pub fn parse_ip_list(ip_list: &[String]) -> Result<Vec<IpNet>> {
+    ip_list
+        .iter()
+        .map(|item| {
+            let item = item.trim();
+            item.parse::<IpNet>()
+                .or_else(|_| item.parse::<IpAddr>().map(IpNet::from))
+                .map_err(|_| {
+                    ErrorCode::BadArguments(format!("invalid ip or cidr in the ip list: {}", item))
+                })
+        })
+        .collect()
+}
+
+// The IPv4 clients connected to the IPv6 listeners are in the IPv4-mapped addresses.
+fn canonical_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => IpAddr::V4(v4),
+            None => ip,
+        },
+        IpAddr::V4(_) => ip,
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PlanHint;
+
+use crate::UserApiProvider;
+
+/// Plan hint operations.
+impl UserApiProvider {
+    // Add a new plan hint.
+    pub async fn add_plan_hint(
+        &self,
+        tenant: &str,
+        plan_hint: PlanHint,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let plan_hint_api_client = self.get_plan_hint_api_client(tenant)?;
+        match plan_hint_api_client.add_plan_hint(plan_hint).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::plan_hint_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a plan hint by name.
+    pub async fn get_plan_hint(&self, tenant: &str, name: &str) -> Result<PlanHint> {
+        let plan_hint_api_client = self.get_plan_hint_api_client(tenant)?;
+        Ok(plan_hint_api_client.get_plan_hint(name, None).await?.data)
+    }
+
+    // Get all plan hints for the tenant.
+    pub async fn get_plan_hints(&self, tenant: &str) -> Result<Vec<PlanHint>> {
+        let plan_hint_api_client = self.get_plan_hint_api_client(tenant)?;
+        match plan_hint_api_client.get_plan_hints().await {
+            Err(e) => Err(e.add_message_back("(while get plan hints).")),
+            Ok(plan_hints) => Ok(plan_hints),
+        }
+    }
+
+    // Drop a plan hint by name.
+    pub async fn drop_plan_hint(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let plan_hint_api_client = self.get_plan_hint_api_client(tenant)?;
+        match plan_hint_api_client.drop_plan_hint(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop plan hint)"))
+                }
+            }
+        }
+    }
+}
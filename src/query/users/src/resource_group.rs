@@ -0,0 +1,88 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ResourceGroup;
+
+use crate::UserApiProvider;
+
+/// Resource group operations.
+impl UserApiProvider {
+    // Add a new resource group.
+    pub async fn add_resource_group(
+        &self,
+        tenant: &str,
+        group: ResourceGroup,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let group_api_client = self.get_resource_group_api_client(tenant)?;
+        match group_api_client.add_group(group).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::resource_group_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a resource group by name.
+    pub async fn get_resource_group(&self, tenant: &str, name: &str) -> Result<ResourceGroup> {
+        let group_api_client = self.get_resource_group_api_client(tenant)?;
+        Ok(group_api_client.get_group(name, None).await?.data)
+    }
+
+    // Get all resource groups for the tenant.
+    pub async fn get_resource_groups(&self, tenant: &str) -> Result<Vec<ResourceGroup>> {
+        let group_api_client = self.get_resource_group_api_client(tenant)?;
+        match group_api_client.get_groups().await {
+            Err(e) => Err(e.add_message_back("(while get resource groups).")),
+            Ok(groups) => Ok(groups),
+        }
+    }
+
+    // Drop a resource group by name, the group assigned to the users can't be dropped.
+    pub async fn drop_resource_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let users = self.get_users(tenant).await?;
+        if let Some(user) = users
+            .iter()
+            .find(|user| user.option.resource_group().map(|g| g.as_str()) == Some(name))
+        {
+            return Err(ErrorCode::ResourceGroupIsUsedByUser(format!(
+                "resource group {} is used by user '{}'",
+                name, user.name
+            )));
+        }
+
+        let group_api_client = self.get_resource_group_api_client(tenant)?;
+        match group_api_client.drop_group(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop resource group)"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::Sequence;
+
+use crate::UserApiProvider;
+
+// The number of values of a sequence allocated to the node at once, at least.
+const SEQUENCE_BATCH_SIZE: u64 = 1000;
+
+/// The values of a sequence allocated to the node and not used yet.
+pub(crate) struct SequenceBatch {
+    next_value: i64,
+    increment: i64,
+    remaining: u64,
+}
+
+impl SequenceBatch {
+    fn take(&mut self, count: u64, values: &mut Vec<i64>) {
+        let count = count.min(self.remaining);
+        for _ in 0..count {
+            values.push(self.next_value);
+            self.next_value += self.increment;
+        }
+        self.remaining -= count;
+    }
+}
+
+/// Sequence operations.
+impl UserApiProvider {
+    // Add a new sequence.
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        sequence: Sequence,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        match sequence_api_client.add_sequence(sequence).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::sequence_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a sequence by name.
+    pub async fn get_sequence(&self, tenant: &str, name: &str) -> Result<Sequence> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        Ok(sequence_api_client.get_sequence(name, None).await?.data)
+    }
+
+    // Get all sequences for the tenant.
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<Sequence>> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        match sequence_api_client.get_sequences().await {
+            Err(e) => Err(e.add_message_back("(while get sequences).")),
+            Ok(sequences) => Ok(sequences),
+        }
+    }
+
+    // Drop a sequence by name.
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        // The values allocated to this node are dropped as well, those allocated to the others
+        // are dropped once the sequence is found unknown by them.
+        let key = (tenant.to_string(), name.to_string());
+        self.sequence_batches.lock().remove(&key);
+
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        match sequence_api_client.drop_sequence(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop sequence)"))
+                }
+            }
+        }
+    }
+
+    // The next `count` values of a sequence. The values are taken from the batch allocated to
+    // this node, a new batch is allocated from the meta service once it's used up, so the values
+    // are unique but not in order across the nodes.
+    pub async fn next_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<Vec<i64>> {
+        let key = (tenant.to_string(), name.to_string());
+        let mut values = Vec::with_capacity(count as usize);
+        if let Some(batch) = self.sequence_batches.lock().get_mut(&key) {
+            batch.take(count, &mut values);
+        }
+
+        let remaining = count - values.len() as u64;
+        if remaining > 0 {
+            let allocate = remaining.max(SEQUENCE_BATCH_SIZE);
+            let sequence_api_client = self.get_sequence_api_client(tenant)?;
+            let sequence = sequence_api_client.allocate_values(name, allocate).await?;
+            let mut batch = SequenceBatch {
+                next_value: sequence.next_value,
+                increment: sequence.increment,
+                remaining: allocate,
+            };
+            batch.take(remaining, &mut values);
+            self.sequence_batches.lock().insert(key, batch);
+        }
+        Ok(values)
+    }
+}
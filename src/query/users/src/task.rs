@@ -0,0 +1,117 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::ScheduledTask;
+use common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// Scheduled task operations.
+impl UserApiProvider {
+    // Add a new task.
+    pub async fn add_task(
+        &self,
+        tenant: &str,
+        task: ScheduledTask,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        match task_api_client.add_task(task).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::task_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a task by name, with its seq of the update.
+    pub async fn get_task(&self, tenant: &str, name: &str) -> Result<SeqV<ScheduledTask>> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client.get_task(name, None).await
+    }
+
+    // Get all tasks for the tenant.
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<ScheduledTask>> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        match task_api_client.get_tasks().await {
+            Err(e) => Err(e.add_message_back("(while get tasks).")),
+            Ok(tasks) => Ok(tasks),
+        }
+    }
+
+    // Suspend or resume a task.
+    pub async fn set_task_suspended(
+        &self,
+        tenant: &str,
+        name: &str,
+        suspended: bool,
+    ) -> Result<()> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        let seq_value = task_api_client.get_task(name, None).await?;
+        let mut task = seq_value.data;
+        task.suspended = suspended;
+        match task_api_client.update_task(task, Some(seq_value.seq)).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.add_message_back("(while alter task)")),
+        }
+    }
+
+    // Drop a task by name.
+    pub async fn drop_task(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        match task_api_client.drop_task(name, None).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Acquire or renew the lease of the scheduler of the tenant's tasks for the node.
+    pub async fn acquire_task_scheduler_lease(
+        &self,
+        tenant: &str,
+        node_id: &str,
+        ttl_secs: u64,
+    ) -> Result<bool> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client
+            .acquire_scheduler_lease(node_id, ttl_secs)
+            .await
+    }
+
+    // Acquire or renew the lease of the scheduler of the tenant's background compactions for
+    // the node.
+    pub async fn acquire_compaction_scheduler_lease(
+        &self,
+        tenant: &str,
+        node_id: &str,
+        ttl_secs: u64,
+    ) -> Result<bool> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client
+            .acquire_compaction_scheduler_lease(node_id, ttl_secs)
+            .await
+    }
+}
@@ -12,19 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_base::base::Singleton;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
+use common_management::ConnectionApi;
+use common_management::ConnectionMgr;
+use common_management::DictionaryApi;
+use common_management::DictionaryMgr;
+use common_management::MaskingPolicyApi;
+use common_management::MaskingPolicyMgr;
+use common_management::NetworkPolicyApi;
+use common_management::NetworkPolicyMgr;
+use common_management::PlanHintApi;
+use common_management::PlanHintMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
+use common_management::ResourceGroupApi;
+use common_management::ResourceGroupMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
+use common_management::SequenceApi;
+use common_management::SequenceMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
+use common_management::TaskApi;
+use common_management::TaskMgr;
 use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
@@ -34,13 +53,18 @@ use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
 use common_meta_types::AuthInfo;
 use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
 
 use crate::idm_config::IDMConfig;
+use crate::sequence::SequenceBatch;
 
 pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn KVApi>,
     idm_config: IDMConfig,
+    // The values of the sequences allocated to the node and not used yet, by the tenant and the
+    // name of the sequence.
+    pub(crate) sequence_batches: Mutex<HashMap<(String, String), SequenceBatch>>,
 }
 
 static USER_API_PROVIDER: OnceCell<Singleton<Arc<UserApiProvider>>> = OnceCell::new();
@@ -66,6 +90,7 @@ impl UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            sequence_batches: Mutex::new(HashMap::new()),
         }))
     }
 
@@ -96,6 +121,63 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_masking_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn MaskingPolicyApi>> {
+        Ok(Arc::new(MaskingPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_network_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn NetworkPolicyApi>> {
+        Ok(Arc::new(NetworkPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_resource_group_api_client(&self, tenant: &str) -> Result<Arc<dyn ResourceGroupApi>> {
+        Ok(Arc::new(ResourceGroupMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_connection_api_client(&self, tenant: &str) -> Result<Arc<dyn ConnectionApi>> {
+        Ok(Arc::new(ConnectionMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_dictionary_api_client(&self, tenant: &str) -> Result<Arc<dyn DictionaryApi>> {
+        Ok(Arc::new(DictionaryMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_sequence_api_client(&self, tenant: &str) -> Result<Arc<dyn SequenceApi>> {
+        Ok(Arc::new(SequenceMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_plan_hint_api_client(&self, tenant: &str) -> Result<Arc<dyn PlanHintApi>> {
+        Ok(Arc::new(PlanHintMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<dyn TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_exception::Result;
+use common_users::LdapAuthenticator;
+use common_users::LdapConfig;
+use pretty_assertions::assert_eq;
+
+fn test_ldap_config(group_role_mapping: &str) -> LdapConfig {
+    LdapConfig {
+        url: "ldap://127.0.0.1:389".to_string(),
+        search_base: "ou=people,dc=example,dc=com".to_string(),
+        search_filter: "(uid={user})".to_string(),
+        group_attribute: "memberOf".to_string(),
+        group_role_mapping: group_role_mapping.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_ldap_authenticator_create() -> Result<()> {
+    // Disabled without the url.
+    assert!(LdapAuthenticator::try_create(LdapConfig::default())?.is_none());
+
+    // Either the bind dn template or the search base is required.
+    let config = LdapConfig {
+        url: "ldap://127.0.0.1:389".to_string(),
+        ..Default::default()
+    };
+    let res = LdapAuthenticator::try_create(config);
+    assert_eq!(res.err().map(|e| e.code()), Some(2002));
+
+    // The mapping item without the role.
+    let res = LdapAuthenticator::try_create(test_ldap_config("cn=admins,dc=example,dc=com"));
+    assert_eq!(res.err().map(|e| e.code()), Some(2002));
+
+    Ok(())
+}
+
+#[test]
+fn test_ldap_authenticator_roles() -> Result<()> {
+    let mapping = "cn=admins,ou=groups,dc=example,dc=com:admin; \
+        cn=analysts,ou=groups,dc=example,dc=com:analyst;cn=dev,ou=groups,dc=example,dc=com:analyst";
+    let authenticator = LdapAuthenticator::try_create(test_ldap_config(mapping))?.unwrap();
+
+    assert_eq!(
+        authenticator.managed_roles(),
+        BTreeSet::from(["admin".to_string(), "analyst".to_string()])
+    );
+
+    // The groups are matched case-insensitively, the unmapped groups are ignored.
+    let groups = vec![
+        "CN=Analysts,OU=Groups,DC=example,DC=com".to_string(),
+        "cn=sales,ou=groups,dc=example,dc=com".to_string(),
+    ];
+    assert_eq!(
+        authenticator.mapped_roles(&groups),
+        BTreeSet::from(["analyst".to_string()])
+    );
+    assert_eq!(authenticator.mapped_roles(&[]), BTreeSet::new());
+
+    Ok(())
+}
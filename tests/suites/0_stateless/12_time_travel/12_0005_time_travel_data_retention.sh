@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0005 which keeps the historical data for a day
+echo "create table t12_0005(c int) DATA_RETENTION_TIME_IN_DAYS = 1" | $MYSQL_CLIENT_CONNECT
+echo "two insertions"
+echo "insert into t12_0005 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(3)" | $MYSQL_CLIENT_CONNECT
+
+## Get the previous snapshot id of the latest snapshot
+SNAPSHOT_ID=$(echo "select previous_snapshot_id from fuse_snapshot('default','t12_0005') where row_count=3 " | $MYSQL_CLIENT_CONNECT)
+
+echo "the historical snapshot in the data retention time is kept by purge"
+echo "optimize table t12_0005 purge" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from fuse_snapshot('default','t12_0005')" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+
+echo "the historical snapshot out of the data retention time can not be navigated to"
+echo "alter table t12_0005 set DATA_RETENTION_TIME_IN_DAYS = 0" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "2013"
+echo "select count(*) from t12_0005 at (TIMESTAMP => '2022-01-01 00:00:00'::TIMESTAMP)" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "2013"
+
+echo "the historical snapshot out of the data retention time is removed by purge"
+echo "optimize table t12_0005 purge" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from fuse_snapshot('default','t12_0005')" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005" | $MYSQL_CLIENT_CONNECT
+
+echo "only the data retention time can be set"
+echo "alter table t12_0005 set row_per_block = 10" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "1022"
+
+## Drop table.
+echo "drop table t12_0005" | $MYSQL_CLIENT_CONNECT
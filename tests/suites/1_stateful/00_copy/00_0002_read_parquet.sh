@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+connection="'aws_key_id=minioadmin', 'aws_secret_key=minioadmin', 'endpoint_url=${STORAGE_S3_ENDPOINT_URL}'"
+
+read_parquet_cases=(
+  # read a single file
+  "select count(1), avg(\`Year\`), sum(\`DayOfWeek\`) from read_parquet('s3://testbucket/admin/data/ontime_200.parquet', ${connection})"
+  # read files matching a glob
+  "select count(1), avg(\`Year\`), sum(\`DayOfWeek\`) from read_parquet('s3://testbucket/admin/data/ontime_200*.parquet', ${connection})"
+  # row groups are pruned by their statistics
+  "select count(1) from read_parquet('s3://testbucket/admin/data/ontime_200.parquet', ${connection}) where \`Year\` > 3000"
+  # read files of a named stage with the connection of the stage
+  "select count(1), avg(\`Year\`), sum(\`DayOfWeek\`) from read_parquet('@read_parquet_stage/ontime_200*.parquet')"
+)
+
+echo "drop stage if exists read_parquet_stage;" | $MYSQL_CLIENT_CONNECT
+echo "create stage read_parquet_stage url = 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}');" | $MYSQL_CLIENT_CONNECT
+
+for i in "${read_parquet_cases[@]}"; do
+  echo "$i" | $MYSQL_CLIENT_CONNECT
+done
+
+echo "drop stage if exists read_parquet_stage;" | $MYSQL_CLIENT_CONNECT
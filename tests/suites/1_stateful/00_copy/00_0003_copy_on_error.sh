@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_on_error;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists on_error_stage;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table test_on_error(a int, b string);" | $MYSQL_CLIENT_CONNECT
+echo "create stage on_error_stage url = 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}');" | $MYSQL_CLIENT_CONNECT
+
+## The rows of the file failed to be parsed abort the copy by default
+echo "copy into test_on_error from @on_error_stage PATTERN = 'copy_on_error.*csv$' FILE_FORMAT = (type = 'CSV')" | $MYSQL_CLIENT_CONNECT 2>/dev/null
+echo "select count(1) from test_on_error" | $MYSQL_CLIENT_CONNECT
+
+## The rows are skipped if continue on error
+echo "copy into test_on_error from @on_error_stage PATTERN = 'copy_on_error.*csv$' FILE_FORMAT = (type = 'CSV') ON_ERROR = CONTINUE" | $MYSQL_CLIENT_CONNECT
+echo "select a, b from test_on_error order by a" | $MYSQL_CLIENT_CONNECT
+
+## The file is loaded already
+echo "copy into test_on_error from @on_error_stage PATTERN = 'copy_on_error.*csv$' FILE_FORMAT = (type = 'CSV') ON_ERROR = CONTINUE" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from test_on_error" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_on_error;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists on_error_stage;" | $MYSQL_CLIENT_CONNECT
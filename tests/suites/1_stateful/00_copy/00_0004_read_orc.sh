@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# stripes.orc has 3 stripes of 100 rows, the ids of the stripe i are in [100 * i, 100 * i + 99],
+# and the score of each row is the half of the id.
+connection="connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}')"
+
+echo "drop table if exists orc_stripes;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists orc_stripes_external;" | $MYSQL_CLIENT_CONNECT
+
+## copy all the stripes
+echo "create table orc_stripes (id bigint, score double);" | $MYSQL_CLIENT_CONNECT
+echo "copy into orc_stripes from 's3://testbucket/admin/data/stripes.orc' ${connection} FILE_FORMAT = (type = 'ORC');" | $MYSQL_CLIENT_CONNECT
+echo "select count(1), sum(id), sum(score) from orc_stripes;" | $MYSQL_CLIENT_CONNECT
+
+## the stripes are pruned by their statistics
+echo "create external table orc_stripes_external (id bigint, score double) location = 's3://testbucket/admin/data/' ${connection} pattern = 'stripes[.]orc' file_format = (type = 'ORC');" | $MYSQL_CLIENT_CONNECT
+
+read_orc_cases=(
+  "select count(1), min(id), max(id) from orc_stripes_external"
+  "select count(1), min(id), max(id) from orc_stripes_external where id >= 150"
+  "select count(1), min(id), max(id) from orc_stripes_external where score < 10"
+  "select count(1) from orc_stripes_external where id > 1000"
+)
+
+for i in "${read_orc_cases[@]}"; do
+  echo "$i" | $MYSQL_CLIENT_CONNECT
+done
+
+echo "drop table orc_stripes;" | $MYSQL_CLIENT_CONNECT
+echo "drop table orc_stripes_external;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# readings.avro has 25 records in 3 blocks, with the schema of
+#   {id: long, name: string, score: [null, double], day: date, color: enum, weight: float}
+connection="connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}')"
+copy_readings="copy into avro_readings from 's3://testbucket/admin/data/readings.avro' ${connection} FILE_FORMAT = (type = 'AVRO') force = true;"
+
+echo "drop table if exists avro_readings;" | $MYSQL_CLIENT_CONNECT
+
+## the fields are matched by name, the longs are cast to ints and the enums are read as their symbols
+echo "create table avro_readings (color varchar, id int, name varchar, score double null, day date);" | $MYSQL_CLIENT_CONNECT
+echo "$copy_readings" | $MYSQL_CLIENT_CONNECT
+echo "select count(1), sum(id), count(score), sum(score), min(day), max(day) from avro_readings;" | $MYSQL_CLIENT_CONNECT
+echo "select color, count(1) from avro_readings group by color order by color;" | $MYSQL_CLIENT_CONNECT
+echo "drop table avro_readings;" | $MYSQL_CLIENT_CONNECT
+
+## the fields missing from the file are errors, or loaded as NULL
+echo "create table avro_readings (id int, note varchar null);" | $MYSQL_CLIENT_CONNECT
+echo "$copy_readings" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "expected to find column: note"
+echo "set avro_incompatible_field = 'null'; $copy_readings select count(1), count(note) from avro_readings;" | $MYSQL_CLIENT_CONNECT
+echo "drop table avro_readings;" | $MYSQL_CLIENT_CONNECT
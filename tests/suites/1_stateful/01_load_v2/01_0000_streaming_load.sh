@@ -66,6 +66,16 @@ curl -H "insert_sql:insert into ontime_streaming_load format NdJson" -H "skip_he
 echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
 echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
 
+# load csv gz in a chunked body
+curl -H "insert_sql:insert into ontime_streaming_load format Csv" -H "skip_header:1" -H "compression:gzip" -H "Transfer-Encoding: chunked" --data-binary @/tmp/ontime_200.csv.gz -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
+
+# load parquet in a chunked body
+curl -H "insert_sql:insert into ontime_streaming_load format Parquet" -H "Transfer-Encoding: chunked" --data-binary @/tmp/ontime_200.parquet -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
+
 
 # load parquet with less schema
 echo 'CREATE TABLE ontime_less
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists csv_dialect;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE csv_dialect
+(
+    a INT,
+    b VARCHAR NULL,
+    c INT NULL
+);" | $MYSQL_CLIENT_CONNECT
+
+printf "id|name|value\n1|'it\\\\'s'|NA\n2|'x|y'\n3|z|30|extra\n" > /tmp/csv_dialect.csv
+
+# the rows with missing or extra fields fail by default
+curl -H "insert_sql:insert into csv_dialect format CSV" -H "skip_header:1" -H 'field_delimiter: |' -H "quote_char: \'" -H "escape_char: \\\\" -H "null_display: NA" -F "upload=@/tmp/csv_dialect.csv" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count() from csv_dialect" | $MYSQL_CLIENT_CONNECT
+
+curl -H "insert_sql:insert into csv_dialect format CSV" -H "skip_header:1" -H 'field_delimiter: |' -H "quote_char: \'" -H "escape_char: \\\\" -H "null_display: NA" -H "error_on_column_count_mismatch: 0" -F "upload=@/tmp/csv_dialect.csv" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from csv_dialect order by a" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table csv_dialect;" | $MYSQL_CLIENT_CONNECT
+rm /tmp/csv_dialect.csv
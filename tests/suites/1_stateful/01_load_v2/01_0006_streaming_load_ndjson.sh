@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists ndjson_load;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE ndjson_load
+(
+    a INT,
+    b VARCHAR NULL,
+    c INT
+);" | $MYSQL_CLIENT_CONNECT
+
+# the quoted numbers and the booleans are coerced to the types of the columns
+printf '{"a":"1","b":2}\n{"a":2,"b":true,"c":"3"}\n' > /tmp/ndjson_load.ndjson
+curl -H "insert_sql:insert into ndjson_load format NDJSON" -H "missing_field_as: field_default" -F "upload=@/tmp/ndjson_load.ndjson" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+
+# a missing non-nullable column can not be loaded as NULL
+printf '{"a":3}\n' > /tmp/ndjson_load.ndjson
+curl -H "insert_sql:insert into ndjson_load format NDJSON" -F "upload=@/tmp/ndjson_load.ndjson" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+
+printf '{"a":4,"c":4}\n' > /tmp/ndjson_load.ndjson
+curl -H "insert_sql:insert into ndjson_load format NDJSON" -H "missing_field_as: error" -F "upload=@/tmp/ndjson_load.ndjson" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count() from ndjson_load" | $MYSQL_CLIENT_CONNECT
+
+printf '{"a":5,"c":5}\n' > /tmp/ndjson_load.ndjson
+curl -H "insert_sql:insert into ndjson_load format NDJSON" -F "upload=@/tmp/ndjson_load.ndjson" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from ndjson_load order by a" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table ndjson_load;" | $MYSQL_CLIENT_CONNECT
+rm /tmp/ndjson_load.ndjson